@@ -1,7 +1,9 @@
 //! Simple test to verify instrument generation works.
 
 use speccade_backend_audio::generate;
-use speccade_spec::recipe::audio::{AudioV1Params, Envelope, NoteSpec, Synthesis, Waveform};
+use speccade_spec::recipe::audio::{
+    AudioV1Params, ChannelMode, Envelope, NoteSpec, Synthesis, Waveform,
+};
 use speccade_spec::{AssetType, OutputFormat, OutputSpec, Recipe, Spec};
 
 fn main() {
@@ -18,17 +20,28 @@ fn main() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
             envelope: Envelope {
                 attack: 0.01,
                 decay: 0.1,
                 sustain: 0.7,
                 release: 0.2,
-            },
+            }
+            .into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -38,6 +51,11 @@ fn main() {
         loop_config: None,
         generate_loop_points: true,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = Spec::builder("test-instrument", AssetType::Audio)
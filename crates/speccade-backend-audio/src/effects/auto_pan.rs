@@ -0,0 +1,167 @@
+//! Auto-pan effect implementation.
+//!
+//! Sweeps the stereo balance with a sine low-frequency oscillator.
+
+use speccade_spec::recipe::audio::Waveform;
+
+use crate::error::{AudioError, AudioResult};
+use crate::mixer::StereoOutput;
+use crate::modulation::lfo::Lfo;
+use crate::rng::{create_rng, derive_component_seed};
+
+/// Applies auto-pan to stereo audio.
+///
+/// Mono sources reach the effect chain as identical left/right channels, so
+/// the sweep turns them into a moving stereo image.
+///
+/// # Arguments
+/// * `stereo` - Stereo audio to process in-place
+/// * `rate` - LFO rate in Hz (0.1-20.0)
+/// * `depth` - Pan swing (0.0-1.0)
+/// * `phase` - Initial LFO phase in cycles (0.0-1.0)
+/// * `sample_rate` - Sample rate in Hz
+/// * `seed` - RNG seed for the LFO
+///
+/// # Algorithm
+/// ```text
+/// pan = depth * sin(2 * PI * (rate * t + phase))   // -1 = left, 1 = right
+/// left *= min(1 - pan, 1)
+/// right *= min(1 + pan, 1)
+/// ```
+///
+/// A depth of 0.0 leaves the input untouched, bit for bit.
+pub fn apply(
+    stereo: &mut StereoOutput,
+    rate: f64,
+    depth: f64,
+    phase: f64,
+    sample_rate: f64,
+    seed: u32,
+) -> AudioResult<()> {
+    if !(0.1..=20.0).contains(&rate) {
+        return Err(AudioError::invalid_param(
+            "auto_pan.rate",
+            format!("must be 0.1-20.0 Hz, got {}", rate),
+        ));
+    }
+    if !(0.0..=1.0).contains(&depth) {
+        return Err(AudioError::invalid_param(
+            "auto_pan.depth",
+            format!("must be 0.0-1.0, got {}", depth),
+        ));
+    }
+    if !(0.0..=1.0).contains(&phase) {
+        return Err(AudioError::invalid_param(
+            "auto_pan.phase",
+            format!("must be 0.0-1.0, got {}", phase),
+        ));
+    }
+
+    if stereo.left.is_empty() || depth == 0.0 {
+        return Ok(());
+    }
+
+    let mut rng = create_rng(derive_component_seed(seed, "auto_pan"));
+    let mut lfo = Lfo::new(Waveform::Sine, rate, sample_rate, phase);
+
+    for (left, right) in stereo.left.iter_mut().zip(stereo.right.iter_mut()) {
+        let pan = depth * (lfo.next_sample(&mut rng) * 2.0 - 1.0);
+        *left *= (1.0 - pan).min(1.0);
+        *right *= (1.0 + pan).min(1.0);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(num_samples: usize) -> StereoOutput {
+        StereoOutput {
+            left: vec![0.5; num_samples],
+            right: vec![0.5; num_samples],
+        }
+    }
+
+    fn energy(samples: &[f64]) -> f64 {
+        samples.iter().map(|s| s * s).sum()
+    }
+
+    #[test]
+    fn test_auto_pan_moves_energy_between_channels() {
+        let sample_rate = 44100.0;
+        let rate = 2.0;
+        let mut stereo = constant(44100);
+
+        apply(&mut stereo, rate, 1.0, 0.0, sample_rate, 42).unwrap();
+
+        // First half of each period leans right, second half leans left.
+        let half = (sample_rate / rate / 2.0) as usize;
+        for (i, (left, right)) in stereo
+            .left
+            .chunks(half)
+            .zip(stereo.right.chunks(half))
+            .enumerate()
+        {
+            let (el, er) = (energy(left), energy(right));
+            if i % 2 == 0 {
+                assert!(er > el * 2.0, "half {}: L {} R {}", i, el, er);
+            } else {
+                assert!(el > er * 2.0, "half {}: L {} R {}", i, el, er);
+            }
+        }
+
+        // Full depth swings each channel to silence once per period.
+        let min_left = stereo.left.iter().fold(f64::MAX, |a, &b| a.min(b));
+        let min_right = stereo.right.iter().fold(f64::MAX, |a, &b| a.min(b));
+        assert!(min_left < 1e-3 && min_right < 1e-3);
+    }
+
+    #[test]
+    fn test_auto_pan_phase_offsets_sweep() {
+        let mut a = constant(22050);
+        let mut b = constant(22050);
+        apply(&mut a, 2.0, 1.0, 0.0, 44100.0, 42).unwrap();
+        apply(&mut b, 2.0, 1.0, 0.5, 44100.0, 42).unwrap();
+
+        // Half a cycle apart mirrors the channels.
+        for i in 0..a.left.len() {
+            assert!((a.left[i] - b.right[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_auto_pan_zero_depth_bypasses() {
+        let samples: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.01).sin()).collect();
+        let mut stereo = StereoOutput {
+            left: samples.clone(),
+            right: samples.clone(),
+        };
+
+        apply(&mut stereo, 3.0, 0.0, 0.25, 44100.0, 42).unwrap();
+
+        assert_eq!(stereo.left, samples);
+        assert_eq!(stereo.right, samples);
+    }
+
+    #[test]
+    fn test_auto_pan_deterministic() {
+        let mut a = constant(4410);
+        let mut b = constant(4410);
+        apply(&mut a, 5.0, 0.6, 0.1, 44100.0, 3).unwrap();
+        apply(&mut b, 5.0, 0.6, 0.1, 44100.0, 3).unwrap();
+        assert_eq!(a.left, b.left);
+        assert_eq!(a.right, b.right);
+    }
+
+    #[test]
+    fn test_auto_pan_parameter_validation() {
+        let mut stereo = constant(100);
+        assert!(apply(&mut stereo, 0.05, 0.5, 0.0, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 25.0, 0.5, 0.0, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 2.0, 1.5, 0.0, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 2.0, 0.5, 1.5, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 2.0, 0.5, 0.0, 44100.0, 0).is_ok());
+    }
+}
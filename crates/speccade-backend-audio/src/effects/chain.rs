@@ -2,11 +2,11 @@
 //!
 //! This module provides the basic effect chain application logic.
 
-use speccade_spec::recipe::audio::Effect;
+use speccade_spec::recipe::audio::{Effect, Waveform};
 
 use super::{
-    auto_filter, cabinet, chorus, delay, distortion, dynamics, eq, flanger, multi_tap_delay,
-    reverb, ring_mod, rotary, stereo, tape, transient,
+    auto_filter, auto_pan, cabinet, chorus, convolution, delay, distortion, dynamics, eq, flanger,
+    multi_tap_delay, phase_vocoder, reverb, ring_mod, rotary, stereo, tape, transient, tremolo,
 };
 use crate::error::AudioResult;
 use crate::mixer::{MixerOutput, StereoOutput};
@@ -71,39 +71,69 @@ pub fn apply_single_effect(
             room_size,
             damping,
             wet,
+            dry,
             width,
         } => {
-            reverb::apply(stereo, *room_size, *damping, *wet, *width, sample_rate)?;
+            reverb::apply(
+                stereo,
+                *room_size,
+                *damping,
+                *wet,
+                dry.unwrap_or(1.0 - *wet),
+                *width,
+                sample_rate,
+                seed,
+            )?;
         }
         Effect::Delay {
             time_ms,
             feedback,
             wet,
             ping_pong,
+            tone,
         } => {
-            delay::apply(stereo, *time_ms, *feedback, *wet, *ping_pong, sample_rate)?;
+            delay::apply(
+                stereo,
+                *time_ms,
+                *feedback,
+                *wet,
+                *ping_pong,
+                *tone,
+                sample_rate,
+            )?;
         }
         Effect::Chorus {
             rate,
             depth,
             wet,
             voices,
+            spread,
         } => {
-            chorus::apply(stereo, *rate, *depth, *wet, *voices, sample_rate)?;
+            chorus::apply(stereo, *rate, *depth, *wet, *voices, *spread, sample_rate)?;
         }
         Effect::Phaser {
             rate,
             depth,
             stages,
+            feedback,
             wet,
         } => {
-            chorus::apply_phaser(stereo, *rate, *depth, *stages, *wet, sample_rate)?;
+            chorus::apply_phaser(
+                stereo,
+                *rate,
+                *depth,
+                *stages,
+                feedback.unwrap_or(0.0),
+                *wet,
+                sample_rate,
+            )?;
         }
         Effect::Bitcrush {
             bits,
             sample_rate_reduction,
+            downsample,
         } => {
-            distortion::apply_bitcrush(stereo, *bits, *sample_rate_reduction);
+            distortion::apply_bitcrush(stereo, *bits, *sample_rate_reduction, *downsample);
         }
         Effect::Waveshaper { drive, curve, wet } => {
             distortion::apply_waveshaper(stereo, *drive, curve, *wet);
@@ -176,6 +206,23 @@ pub fn apply_single_effect(
                 sample_rate,
             )?;
         }
+        Effect::Gate {
+            threshold_db,
+            attack_ms,
+            hold_ms,
+            release_ms,
+            range_db,
+        } => {
+            dynamics::apply_gate(
+                stereo,
+                *threshold_db,
+                *attack_ms,
+                *hold_ms,
+                *release_ms,
+                *range_db,
+                sample_rate,
+            )?;
+        }
         Effect::StereoWidener {
             width,
             mode,
@@ -237,8 +284,18 @@ pub fn apply_single_effect(
         Effect::RotarySpeaker { rate, depth, wet } => {
             rotary::apply(stereo, *rate, *depth, *wet, sample_rate)?;
         }
-        Effect::RingModulator { frequency, mix } => {
-            ring_mod::apply(stereo, *frequency, *mix, sample_rate)?;
+        Effect::RingModulator {
+            frequency,
+            mix,
+            waveform,
+        } => {
+            ring_mod::apply(
+                stereo,
+                *frequency,
+                waveform.unwrap_or(Waveform::Sine),
+                *mix,
+                sample_rate,
+            )?;
         }
         Effect::GranularDelay {
             time_ms,
@@ -264,6 +321,34 @@ pub fn apply_single_effect(
         } => {
             dynamics::apply_true_peak_limiter(stereo, *ceiling_db, *release_ms, sample_rate)?;
         }
+        Effect::PitchShift { semitones } => {
+            phase_vocoder::apply_pitch_shift(stereo, *semitones)?;
+        }
+        Effect::Freeze { at_seconds } => {
+            phase_vocoder::apply_freeze(stereo, *at_seconds, sample_rate)?;
+        }
+        Effect::ConvolutionReverb { impulse, wet, dry } => {
+            convolution::apply(
+                stereo,
+                *impulse,
+                *wet,
+                dry.unwrap_or(1.0 - *wet),
+                sample_rate,
+            )?;
+        }
+        Effect::Tremolo { rate, depth, shape } => {
+            tremolo::apply(
+                stereo,
+                *rate,
+                *depth,
+                shape.unwrap_or(Waveform::Sine),
+                sample_rate,
+                seed,
+            )?;
+        }
+        Effect::AutoPan { rate, depth, phase } => {
+            auto_pan::apply(stereo, *rate, *depth, *phase, sample_rate, seed)?;
+        }
     }
     Ok(())
 }
@@ -2,7 +2,7 @@
 
 use crate::error::{AudioError, AudioResult};
 use crate::mixer::StereoOutput;
-use std::f64::consts::PI;
+use std::f64::consts::{PI, SQRT_2};
 
 const TWO_PI: f64 = 2.0 * PI;
 
@@ -42,12 +42,19 @@ impl ModulatedDelayLine {
 }
 
 /// Applies chorus effect to stereo audio.
+///
+/// Each voice is a modulated delay tap; voice LFO phases are spread evenly across one cycle
+/// and derived from the sample index, so the output is fully deterministic. Without `spread`
+/// the right-channel LFOs lag the left by a fixed 0.5 rad. With `spread` (0.0-1.0) the voices
+/// are panned across the stereo field and the right-channel LFOs lag by up to a quarter
+/// cycle, so a mono input is widened to stereo. A `wet` of 0.0 bypasses the effect.
 pub fn apply(
     stereo: &mut StereoOutput,
     rate: f64,
     depth: f64,
     wet: f64,
     voices: u8,
+    spread: Option<f64>,
     sample_rate: f64,
 ) -> AudioResult<()> {
     // Validate parameters
@@ -75,6 +82,18 @@ pub fn apply(
             format!("must be 1-4, got {}", voices),
         ));
     }
+    if let Some(spread) = spread {
+        if !(0.0..=1.0).contains(&spread) {
+            return Err(AudioError::invalid_param(
+                "chorus.spread",
+                format!("must be 0.0-1.0, got {}", spread),
+            ));
+        }
+    }
+
+    if wet == 0.0 {
+        return Ok(());
+    }
 
     // Chorus parameters
     let base_delay_ms = 20.0; // Base delay in milliseconds
@@ -94,6 +113,19 @@ pub fn apply(
     let dry = 1.0 - wet;
     let voice_gain = wet / voices as f64;
 
+    // Right-channel LFO lag and per-voice (left, right) gains.
+    let right_phase_offset = spread.map_or(0.5, |spread| spread * PI / 2.0);
+    let voice_pans: Vec<(f64, f64)> = (0..voices)
+        .map(|voice_idx| match spread {
+            Some(spread) if voices > 1 => {
+                let pan = spread * (2.0 * voice_idx as f64 / (voices - 1) as f64 - 1.0);
+                let angle = (pan + 1.0) * PI / 4.0;
+                (angle.cos() * SQRT_2, angle.sin() * SQRT_2)
+            }
+            _ => (1.0, 1.0),
+        })
+        .collect();
+
     for i in 0..num_samples {
         let in_left = stereo.left[i];
         let in_right = stereo.right[i];
@@ -105,7 +137,7 @@ pub fn apply(
         let mut out_right = in_right * dry;
 
         // Generate multiple voices with phase offsets
-        for voice_idx in 0..voices {
+        for (voice_idx, &(gain_left, gain_right)) in voice_pans.iter().enumerate() {
             let phase_offset = (voice_idx as f64 * TWO_PI) / voices as f64;
             let t = i as f64 / sample_rate;
             let lfo = ((TWO_PI * rate * t) + phase_offset).sin();
@@ -114,13 +146,14 @@ pub fn apply(
             let modulation = depth * max_delay_samples * (lfo * 0.5 + 0.5);
             let delay_samples = base_delay_samples + modulation;
 
-            // Slightly different LFO for stereo width
-            let lfo_right = ((TWO_PI * rate * t) + phase_offset + 0.5).sin();
+            // Lagged LFO for stereo width
+            let lfo_right = ((TWO_PI * rate * t) + phase_offset + right_phase_offset).sin();
             let modulation_right = depth * max_delay_samples * (lfo_right * 0.5 + 0.5);
             let delay_samples_right = base_delay_samples + modulation_right;
 
-            out_left += delay_left.read_interpolated(delay_samples) * voice_gain;
-            out_right += delay_right.read_interpolated(delay_samples_right) * voice_gain;
+            out_left += delay_left.read_interpolated(delay_samples) * voice_gain * gain_left;
+            out_right +=
+                delay_right.read_interpolated(delay_samples_right) * voice_gain * gain_right;
         }
 
         output_left.push(out_left);
@@ -133,40 +166,42 @@ pub fn apply(
     Ok(())
 }
 
-/// Simple allpass filter for phaser.
-struct AllpassFilter {
-    feedback: f64,
-    buffer: Vec<f64>,
-    index: usize,
-}
-
-impl AllpassFilter {
-    fn new(delay_samples: usize, feedback: f64) -> Self {
-        Self {
-            feedback,
-            buffer: vec![0.0; delay_samples.max(1)],
-            index: 0,
-        }
-    }
-
-    fn process(&mut self, input: f64) -> f64 {
-        let delayed = self.buffer[self.index];
-        let output = -input + delayed;
-
-        self.buffer[self.index] = input + delayed * self.feedback;
+/// Lower and upper allpass break frequencies of the phaser sweep in Hz.
+const PHASER_MIN_HZ: f64 = 200.0;
+const PHASER_MAX_HZ: f64 = 4000.0;
 
-        self.index = (self.index + 1) % self.buffer.len();
+/// First-order allpass section whose coefficient can change every sample.
+#[derive(Clone, Copy, Default)]
+struct FirstOrderAllpass {
+    x1: f64,
+    y1: f64,
+}
 
+impl FirstOrderAllpass {
+    /// `coeff` is `(tan(pi * fc / sr) - 1) / (tan(pi * fc / sr) + 1)`, giving
+    /// a -90 degree phase shift at the break frequency `fc`.
+    fn process(&mut self, input: f64, coeff: f64) -> f64 {
+        let output = coeff * input + self.x1 - coeff * self.y1;
+        self.x1 = input;
+        self.y1 = output;
         output
     }
 }
 
 /// Applies phaser effect to stereo audio.
+///
+/// Cascaded first-order allpass sections share a break frequency swept
+/// logarithmically around the middle of 200-4000 Hz by a sine LFO (`depth`
+/// 1.0 covers the whole range). Mixing the phase-shifted signal with the dry
+/// one cuts moving notches, deepest at `wet = 0.5`; `feedback` routes the
+/// chain output back into its input. Both channels use the same coefficients
+/// so stereo images stay phase coherent, and `wet = 0` is an exact bypass.
 pub fn apply_phaser(
     stereo: &mut StereoOutput,
     rate: f64,
     depth: f64,
     stages: u8,
+    feedback: f64,
     wet: f64,
     sample_rate: f64,
 ) -> AudioResult<()> {
@@ -189,6 +224,12 @@ pub fn apply_phaser(
             format!("must be 2-12, got {}", stages),
         ));
     }
+    if !(-0.95..=0.95).contains(&feedback) {
+        return Err(AudioError::invalid_param(
+            "phaser.feedback",
+            format!("must be -0.95 to 0.95, got {}", feedback),
+        ));
+    }
     if !(0.0..=1.0).contains(&wet) {
         return Err(AudioError::invalid_param(
             "phaser.wet",
@@ -196,57 +237,223 @@ pub fn apply_phaser(
         ));
     }
 
-    // Create allpass filter stages
-    let min_delay = 2;
-    let max_delay = 10;
-
-    let num_samples = stereo.left.len();
-    let mut output_left = Vec::with_capacity(num_samples);
-    let mut output_right = Vec::with_capacity(num_samples);
+    if wet == 0.0 {
+        return Ok(());
+    }
 
     let dry = 1.0 - wet;
+    let log_center = (PHASER_MIN_HZ * PHASER_MAX_HZ).sqrt().ln();
+    let log_span = (PHASER_MAX_HZ / PHASER_MIN_HZ).ln() * depth;
 
-    // Create separate allpass chains for left and right
-    let mut allpasses_left: Vec<_> = (0..stages)
-        .map(|i| {
-            let delay = min_delay + (i as usize * (max_delay - min_delay)) / stages.max(1) as usize;
-            AllpassFilter::new(delay, 0.7)
-        })
-        .collect();
+    let mut chain_left = vec![FirstOrderAllpass::default(); stages as usize];
+    let mut chain_right = chain_left.clone();
+    let mut last_left = 0.0;
+    let mut last_right = 0.0;
 
-    let mut allpasses_right: Vec<_> = (0..stages)
-        .map(|i| {
-            let delay = min_delay + (i as usize * (max_delay - min_delay)) / stages.max(1) as usize;
-            AllpassFilter::new(delay + 1, 0.7) // Slight offset for stereo
-        })
-        .collect();
+    for i in 0..stereo.left.len() {
+        let t = i as f64 / sample_rate;
+        let lfo = (TWO_PI * rate * t).sin() * 0.5;
+        let break_hz = (log_center + lfo * log_span).exp();
+        let tan = (PI * break_hz / sample_rate).tan();
+        let coeff = (tan - 1.0) / (tan + 1.0);
 
-    for i in 0..num_samples {
         let in_left = stereo.left[i];
         let in_right = stereo.right[i];
 
-        // LFO modulation (for future use if we want to modulate allpass parameters)
-        let _t = i as f64 / sample_rate;
-        let _lfo = ((TWO_PI * rate * _t).sin() * 0.5 + 0.5) * depth;
+        let mut processed_left = in_left + feedback * last_left;
+        for allpass in &mut chain_left {
+            processed_left = allpass.process(processed_left, coeff);
+        }
+        let mut processed_right = in_right + feedback * last_right;
+        for allpass in &mut chain_right {
+            processed_right = allpass.process(processed_right, coeff);
+        }
+        last_left = processed_left;
+        last_right = processed_right;
+
+        // Mix wet/dry
+        stereo.left[i] = in_left * dry + processed_left * wet;
+        stereo.right[i] = in_right * dry + processed_right * wet;
+    }
 
-        // Process through allpass chain
-        let mut processed_left = in_left;
-        for allpass in &mut allpasses_left {
-            processed_left = allpass.process(processed_left);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_spectrum::{bin, power_spectrum};
+
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    /// Mono test signal: a few inharmonic partials, identical in both channels.
+    fn mono_input(num_samples: usize) -> StereoOutput {
+        let left: Vec<f64> = (0..num_samples)
+            .map(|i| {
+                let t = i as f64 / SAMPLE_RATE;
+                [220.0, 347.0, 913.0, 2210.0]
+                    .iter()
+                    .map(|f| 0.2 * (TWO_PI * f * t).sin())
+                    .sum()
+            })
+            .collect();
+        StereoOutput {
+            right: left.clone(),
+            left,
         }
+    }
 
-        let mut processed_right = in_right;
-        for allpass in &mut allpasses_right {
-            processed_right = allpass.process(processed_right);
+    fn correlation(a: &[f64], b: &[f64]) -> f64 {
+        let n = a.len() as f64;
+        let mean_a = a.iter().sum::<f64>() / n;
+        let mean_b = b.iter().sum::<f64>() / n;
+        let mut cov = 0.0;
+        let mut var_a = 0.0;
+        let mut var_b = 0.0;
+        for (x, y) in a.iter().zip(b) {
+            cov += (x - mean_a) * (y - mean_b);
+            var_a += (x - mean_a).powi(2);
+            var_b += (y - mean_b).powi(2);
         }
+        cov / (var_a * var_b).sqrt()
+    }
 
-        // Mix wet/dry
-        output_left.push(in_left * dry + processed_left * wet);
-        output_right.push(in_right * dry + processed_right * wet);
+    #[test]
+    fn test_chorus_is_deterministic() {
+        let mut first = mono_input(22050);
+        let mut second = first.clone();
+        apply(&mut first, 1.5, 0.5, 0.5, 4, Some(0.8), SAMPLE_RATE).unwrap();
+        apply(&mut second, 1.5, 0.5, 0.5, 4, Some(0.8), SAMPLE_RATE).unwrap();
+        assert_eq!(first.left, second.left);
+        assert_eq!(first.right, second.right);
     }
 
-    stereo.left = output_left;
-    stereo.right = output_right;
+    #[test]
+    fn test_chorus_zero_wet_is_identity() {
+        let original = mono_input(4096);
+        let mut stereo = original.clone();
+        apply(&mut stereo, 1.5, 0.5, 0.0, 3, Some(1.0), SAMPLE_RATE).unwrap();
+        assert_eq!(stereo.left, original.left);
+        assert_eq!(stereo.right, original.right);
+    }
 
-    Ok(())
+    #[test]
+    fn test_chorus_spread_decorrelates_mono_input() {
+        let dry = mono_input(44100);
+        assert!(correlation(&dry.left, &dry.right) > 0.999);
+
+        let mut wide = dry.clone();
+        apply(&mut wide, 0.8, 0.6, 0.7, 4, Some(1.0), SAMPLE_RATE).unwrap();
+        let wide_correlation = correlation(&wide.left, &wide.right);
+
+        let mut narrow = dry.clone();
+        apply(&mut narrow, 0.8, 0.6, 0.7, 4, Some(0.0), SAMPLE_RATE).unwrap();
+        let narrow_correlation = correlation(&narrow.left, &narrow.right);
+
+        assert!(
+            wide_correlation < 0.9,
+            "spread output still correlated: {}",
+            wide_correlation
+        );
+        assert!(
+            wide_correlation < narrow_correlation,
+            "spread {} vs no spread {}",
+            wide_correlation,
+            narrow_correlation
+        );
+    }
+
+    #[test]
+    fn test_chorus_rejects_out_of_range_spread() {
+        let mut stereo = mono_input(64);
+        assert!(apply(&mut stereo, 1.0, 0.5, 0.5, 2, Some(1.5), SAMPLE_RATE).is_err());
+    }
+
+    /// Deterministic white noise, identical in both channels.
+    fn noise_input(num_samples: usize) -> StereoOutput {
+        let mut state: u32 = 0x1234_5678;
+        let left: Vec<f64> = (0..num_samples)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f64 / u32::MAX as f64) * 0.5 - 0.25
+            })
+            .collect();
+        StereoOutput {
+            right: left.clone(),
+            left,
+        }
+    }
+
+    /// Hann-windowed power in `[low, high]` Hz of `samples[start..start + len]`.
+    fn band_power(samples: &[f64], start: usize, len: usize, low: f64, high: f64) -> f64 {
+        let spectrum = power_spectrum(&samples[start..start + len]);
+        spectrum[bin(low, len, SAMPLE_RATE)..=bin(high, len, SAMPLE_RATE)]
+            .iter()
+            .sum()
+    }
+
+    #[test]
+    fn test_phaser_sweeps_notches_over_noise() {
+        let dry = noise_input(88200);
+        let mut wet = dry.clone();
+        apply_phaser(&mut wet, 0.5, 1.0, 4, 0.0, 0.5, SAMPLE_RATE).unwrap();
+
+        // At 0.5 s the sweep peaks at 4 kHz and at 1.5 s it bottoms out at
+        // 200 Hz. Four stages put the first notch at ~0.41x the break
+        // frequency, so it sits near 1657 Hz in the first window and is gone
+        // from there in the second.
+        let window = 4096;
+        let gain = |center_s: f64, low: f64, high: f64| {
+            let start = (center_s * SAMPLE_RATE) as usize - window / 2;
+            band_power(&wet.left, start, window, low, high)
+                / band_power(&dry.left, start, window, low, high)
+        };
+
+        let high_notch_early = gain(0.5, 1500.0, 1800.0);
+        let high_notch_late = gain(1.5, 1500.0, 1800.0);
+        assert!(
+            high_notch_early < 0.25 * high_notch_late,
+            "1.5-1.8 kHz gain {} at 0.5 s vs {} at 1.5 s",
+            high_notch_early,
+            high_notch_late
+        );
+
+        // The second notch (~2.41x break) lands near 480 Hz at the low end
+        let low_notch_early = gain(0.5, 430.0, 530.0);
+        let low_notch_late = gain(1.5, 430.0, 530.0);
+        assert!(
+            low_notch_late < 0.25 * low_notch_early,
+            "430-530 Hz gain {} at 1.5 s vs {} at 0.5 s",
+            low_notch_late,
+            low_notch_early
+        );
+    }
+
+    #[test]
+    fn test_phaser_zero_wet_is_identity() {
+        let original = noise_input(4096);
+        let mut stereo = original.clone();
+        apply_phaser(&mut stereo, 1.0, 0.8, 6, 0.5, 0.0, SAMPLE_RATE).unwrap();
+        assert_eq!(stereo.left, original.left);
+        assert_eq!(stereo.right, original.right);
+    }
+
+    #[test]
+    fn test_phaser_keeps_stereo_phase_coherent() {
+        let mut stereo = noise_input(22050);
+        apply_phaser(&mut stereo, 2.0, 0.9, 8, 0.6, 0.5, SAMPLE_RATE).unwrap();
+        assert_eq!(stereo.left, stereo.right);
+
+        let mut again = noise_input(22050);
+        apply_phaser(&mut again, 2.0, 0.9, 8, 0.6, 0.5, SAMPLE_RATE).unwrap();
+        assert_eq!(again.left, stereo.left);
+    }
+
+    #[test]
+    fn test_phaser_rejects_unstable_feedback() {
+        let mut stereo = noise_input(64);
+        assert!(apply_phaser(&mut stereo, 1.0, 0.5, 4, 0.99, 0.5, SAMPLE_RATE).is_err());
+    }
 }
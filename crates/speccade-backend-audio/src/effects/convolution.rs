@@ -0,0 +1,264 @@
+//! Convolution reverb with a library of named impulse responses.
+//!
+//! Impulse responses are generated procedurally rather than loaded from files:
+//! each name maps to a fixed set of room parameters and a seed derived from the
+//! name alone, so a given name always produces the same response regardless of
+//! the spec seed. A response is a few discrete early reflections followed by an
+//! exponentially decaying noise tail that darkens over time. Left and right
+//! channels use independent noise for a decorrelated stereo image.
+//!
+//! Convolution is done in the frequency domain with a single zero-padded FFT per
+//! channel, and the output keeps the input length.
+
+use rand::Rng;
+use rustfft::num_complex::Complex;
+use rustfft::FftPlanner;
+use speccade_spec::recipe::audio::ReverbImpulse;
+
+use crate::error::{AudioError, AudioResult};
+use crate::mixer::StereoOutput;
+use crate::rng::{create_rng, derive_component_seed};
+
+/// Seed all impulse responses are derived from, independent of the spec seed.
+const LIBRARY_SEED: u32 = 0;
+
+/// Room parameters for a named impulse response.
+struct ImpulseProfile {
+    /// Time for the tail to decay by 60 dB, in seconds.
+    rt60: f64,
+    /// Gap before the first reflection, in seconds.
+    pre_delay: f64,
+    /// Number of discrete early reflections.
+    early_reflections: usize,
+    /// Window the early reflections are spread over, in seconds.
+    early_span: f64,
+    /// How quickly high frequencies die away relative to the tail (0.0-1.0).
+    damping: f64,
+}
+
+fn profile(impulse: ReverbImpulse) -> ImpulseProfile {
+    match impulse {
+        ReverbImpulse::SmallRoom => ImpulseProfile {
+            rt60: 0.4,
+            pre_delay: 0.003,
+            early_reflections: 6,
+            early_span: 0.02,
+            damping: 0.6,
+        },
+        ReverbImpulse::Hall => ImpulseProfile {
+            rt60: 2.4,
+            pre_delay: 0.025,
+            early_reflections: 10,
+            early_span: 0.08,
+            damping: 0.4,
+        },
+        ReverbImpulse::Plate => ImpulseProfile {
+            rt60: 1.6,
+            pre_delay: 0.0,
+            early_reflections: 0,
+            early_span: 0.0,
+            damping: 0.1,
+        },
+    }
+}
+
+/// Builds the stereo impulse response for `impulse` at `sample_rate`.
+///
+/// Each channel is normalized to unit energy so the wet level is comparable
+/// across the library.
+pub fn impulse_response(impulse: ReverbImpulse, sample_rate: f64) -> StereoOutput {
+    StereoOutput {
+        left: build_channel(impulse, "left", sample_rate),
+        right: build_channel(impulse, "right", sample_rate),
+    }
+}
+
+fn build_channel(impulse: ReverbImpulse, channel: &str, sample_rate: f64) -> Vec<f64> {
+    let profile = profile(impulse);
+    let key = format!("convolution_reverb/{}/{}", impulse.as_str(), channel);
+    let mut rng = create_rng(derive_component_seed(LIBRARY_SEED, &key));
+
+    let pre_delay = (profile.pre_delay * sample_rate).round() as usize;
+    let tail_len = (profile.rt60 * sample_rate).ceil() as usize;
+    let mut ir = vec![0.0; pre_delay + tail_len + 1];
+
+    // Diffuse tail: noise under a -60 dB/rt60 envelope, lowpassed more as it decays
+    let mut lowpass = 0.0;
+    for i in 0..=tail_len {
+        let t = i as f64 / sample_rate;
+        let envelope = 10.0_f64.powf(-3.0 * t / profile.rt60);
+        let coeff = (profile.damping * t / profile.rt60).min(0.95);
+        let noise: f64 = rng.gen_range(-1.0..1.0);
+        lowpass = noise * (1.0 - coeff) + lowpass * coeff;
+        ir[pre_delay + i] = lowpass * envelope;
+    }
+
+    // Early reflections sit on top of the start of the tail
+    let span = (profile.early_span * sample_rate) as usize;
+    for n in 0..profile.early_reflections {
+        let offset = pre_delay + rng.gen_range(0..=span);
+        let sign = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+        let gain = 0.8_f64.powi(n as i32);
+        ir[offset] += sign * gain;
+    }
+
+    let energy: f64 = ir.iter().map(|s| s * s).sum();
+    if energy > 0.0 {
+        let norm = energy.sqrt().recip();
+        ir.iter_mut().for_each(|s| *s *= norm);
+    }
+    ir
+}
+
+/// Applies convolution reverb with a named impulse response.
+///
+/// # Arguments
+/// * `stereo` - Stereo audio to process in-place
+/// * `impulse` - Impulse response from the built-in library
+/// * `wet` - Wet level (0.0-1.0)
+/// * `dry` - Dry level (0.0-1.0)
+/// * `sample_rate` - Sample rate in Hz
+pub fn apply(
+    stereo: &mut StereoOutput,
+    impulse: ReverbImpulse,
+    wet: f64,
+    dry: f64,
+    sample_rate: f64,
+) -> AudioResult<()> {
+    if !(0.0..=1.0).contains(&wet) {
+        return Err(AudioError::invalid_param(
+            "convolution_reverb.wet",
+            format!("must be 0.0-1.0, got {}", wet),
+        ));
+    }
+    if !(0.0..=1.0).contains(&dry) {
+        return Err(AudioError::invalid_param(
+            "convolution_reverb.dry",
+            format!("must be 0.0-1.0, got {}", dry),
+        ));
+    }
+
+    let ir = impulse_response(impulse, sample_rate);
+    for (channel, ir) in [(&mut stereo.left, &ir.left), (&mut stereo.right, &ir.right)] {
+        let reverb = convolve(channel, ir);
+        for (sample, reverb) in channel.iter_mut().zip(reverb) {
+            *sample = *sample * dry + reverb * wet;
+        }
+    }
+
+    Ok(())
+}
+
+/// Convolves `signal` with `ir`, truncated to the length of `signal`.
+fn convolve(signal: &[f64], ir: &[f64]) -> Vec<f64> {
+    if signal.is_empty() || ir.is_empty() {
+        return vec![0.0; signal.len()];
+    }
+
+    let size = (signal.len() + ir.len() - 1).next_power_of_two();
+    let mut planner = FftPlanner::new();
+    let forward = planner.plan_fft_forward(size);
+    let inverse = planner.plan_fft_inverse(size);
+
+    let to_spectrum = |samples: &[f64]| {
+        let mut buf: Vec<Complex<f64>> = samples.iter().map(|&s| Complex::new(s, 0.0)).collect();
+        buf.resize(size, Complex::new(0.0, 0.0));
+        forward.process(&mut buf);
+        buf
+    };
+
+    let mut spectrum = to_spectrum(signal);
+    for (a, b) in spectrum.iter_mut().zip(to_spectrum(ir)) {
+        *a *= b;
+    }
+    inverse.process(&mut spectrum);
+
+    let scale = 1.0 / size as f64;
+    spectrum[..signal.len()]
+        .iter()
+        .map(|c| c.re * scale)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    /// Runs a unit impulse through the reverb and returns the wet output.
+    fn impulse_tail(impulse: ReverbImpulse) -> StereoOutput {
+        let len = (3.5 * SAMPLE_RATE) as usize;
+        let mut stereo = StereoOutput {
+            left: vec![0.0; len],
+            right: vec![0.0; len],
+        };
+        stereo.left[0] = 1.0;
+        stereo.right[0] = 1.0;
+        apply(&mut stereo, impulse, 1.0, 0.0, SAMPLE_RATE).unwrap();
+        stereo
+    }
+
+    /// Seconds until the output stays 60 dB below its peak.
+    fn tail_seconds(samples: &[f64]) -> f64 {
+        let peak = samples.iter().fold(0.0_f64, |m, s| m.max(s.abs()));
+        let last = samples.iter().rposition(|s| s.abs() > peak * 1e-3).unwrap();
+        last as f64 / SAMPLE_RATE
+    }
+
+    #[test]
+    fn test_named_impulses_have_distinct_reproducible_tails() {
+        let mut tails = Vec::new();
+        for impulse in ReverbImpulse::ALL {
+            let first = impulse_tail(impulse);
+            let second = impulse_tail(impulse);
+            assert_eq!(
+                first.left, second.left,
+                "{:?} should be reproducible",
+                impulse
+            );
+            assert_eq!(first.right, second.right);
+            assert_ne!(first.left, first.right, "channels should be decorrelated");
+            tails.push(tail_seconds(&first.left));
+        }
+
+        let [small_room, hall, plate] = tails[..] else {
+            unreachable!()
+        };
+        assert!(small_room < 0.5, "small_room tail {}s", small_room);
+        assert!(plate > 1.0 && plate < 1.8, "plate tail {}s", plate);
+        assert!(hall > 2.0 && hall < 3.0, "hall tail {}s", hall);
+    }
+
+    #[test]
+    fn test_impulse_response_is_deterministic_and_normalized() {
+        for impulse in ReverbImpulse::ALL {
+            let ir = impulse_response(impulse, SAMPLE_RATE);
+            assert_eq!(ir.left, impulse_response(impulse, SAMPLE_RATE).left);
+            let energy: f64 = ir.left.iter().map(|s| s * s).sum();
+            assert!((energy - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_zero_wet_passes_dry_signal() {
+        let mut stereo = StereoOutput {
+            left: vec![0.5, -0.25, 0.125],
+            right: vec![0.1, 0.2, 0.3],
+        };
+        let original = stereo.clone();
+        apply(&mut stereo, ReverbImpulse::Hall, 0.0, 1.0, SAMPLE_RATE).unwrap();
+        assert_eq!(stereo.left, original.left);
+        assert_eq!(stereo.right, original.right);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_levels() {
+        let mut stereo = StereoOutput {
+            left: vec![0.0; 4],
+            right: vec![0.0; 4],
+        };
+        assert!(apply(&mut stereo, ReverbImpulse::Plate, 1.5, 0.5, SAMPLE_RATE).is_err());
+        assert!(apply(&mut stereo, ReverbImpulse::Plate, 0.5, -0.1, SAMPLE_RATE).is_err());
+    }
+}
@@ -39,6 +39,26 @@ impl DelayLine {
     }
 }
 
+/// One-pole lowpass used to darken the feedback path.
+struct FeedbackTone {
+    coeff: f64,
+    state: f64,
+}
+
+impl FeedbackTone {
+    fn new(cutoff_hz: f64, sample_rate: f64) -> Self {
+        Self {
+            coeff: 1.0 - (-2.0 * std::f64::consts::PI * cutoff_hz / sample_rate).exp(),
+            state: 0.0,
+        }
+    }
+
+    fn process(&mut self, input: f64) -> f64 {
+        self.state += self.coeff * (input - self.state);
+        self.state
+    }
+}
+
 /// Applies delay effect to stereo audio.
 pub fn apply(
     stereo: &mut StereoOutput,
@@ -46,12 +66,21 @@ pub fn apply(
     feedback: f64,
     wet: f64,
     ping_pong: bool,
+    tone: Option<f64>,
     sample_rate: f64,
 ) -> AudioResult<()> {
     // Create a constant time curve for the non-modulated case
     let num_samples = stereo.left.len();
     let time_curve = vec![time_ms; num_samples];
-    apply_with_modulation(stereo, &time_curve, feedback, wet, ping_pong, sample_rate)
+    apply_with_modulation(
+        stereo,
+        &time_curve,
+        feedback,
+        wet,
+        ping_pong,
+        tone,
+        sample_rate,
+    )
 }
 
 /// Applies delay effect to stereo audio with per-sample time modulation.
@@ -62,6 +91,7 @@ pub fn apply(
 /// * `feedback` - Feedback amount (0.0-0.95)
 /// * `wet` - Wet/dry mix (0.0-1.0)
 /// * `ping_pong` - Enable ping-pong stereo delay
+/// * `tone` - Optional lowpass cutoff in Hz applied to the feedback path
 /// * `sample_rate` - Sample rate in Hz
 pub fn apply_with_modulation(
     stereo: &mut StereoOutput,
@@ -69,6 +99,7 @@ pub fn apply_with_modulation(
     feedback: f64,
     wet: f64,
     ping_pong: bool,
+    tone: Option<f64>,
     sample_rate: f64,
 ) -> AudioResult<()> {
     // Validate parameters
@@ -84,6 +115,14 @@ pub fn apply_with_modulation(
             format!("must be 0.0-1.0, got {}", wet),
         ));
     }
+    if let Some(cutoff) = tone {
+        if !(cutoff > 0.0 && cutoff < sample_rate / 2.0) {
+            return Err(AudioError::invalid_param(
+                "delay.tone",
+                format!("must be between 0 and Nyquist, got {}", cutoff),
+            ));
+        }
+    }
 
     // Find max delay time to size the buffer
     let max_time_ms = time_curve
@@ -103,6 +142,13 @@ pub fn apply_with_modulation(
 
     let dry = 1.0 - wet;
 
+    let mut tone_left = tone.map(|cutoff| FeedbackTone::new(cutoff, sample_rate));
+    let mut tone_right = tone.map(|cutoff| FeedbackTone::new(cutoff, sample_rate));
+    let darken = |filter: &mut Option<FeedbackTone>, sample: f64| match filter {
+        Some(filter) => filter.process(sample),
+        None => sample,
+    };
+
     for i in 0..num_samples {
         let in_left = stereo.left[i];
         let in_right = stereo.right[i];
@@ -116,8 +162,8 @@ pub fn apply_with_modulation(
             let delayed_right = delay_right.read_interpolated(delay_samples);
             let delayed_left = delay_left.read_interpolated(delay_samples);
 
-            let fb_left = in_left + delayed_right * feedback;
-            let fb_right = in_right + delayed_left * feedback;
+            let fb_left = in_left + darken(&mut tone_left, delayed_right) * feedback;
+            let fb_right = in_right + darken(&mut tone_right, delayed_left) * feedback;
 
             delay_left.write(fb_left);
             delay_right.write(fb_right);
@@ -129,8 +175,8 @@ pub fn apply_with_modulation(
             let delayed_left = delay_left.read_interpolated(delay_samples);
             let delayed_right = delay_right.read_interpolated(delay_samples);
 
-            let fb_left = in_left + delayed_left * feedback;
-            let fb_right = in_right + delayed_right * feedback;
+            let fb_left = in_left + darken(&mut tone_left, delayed_left) * feedback;
+            let fb_right = in_right + darken(&mut tone_right, delayed_right) * feedback;
 
             delay_left.write(fb_left);
             delay_right.write(fb_right);
@@ -145,3 +191,79 @@ pub fn apply_with_modulation(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn impulse(num_samples: usize) -> StereoOutput {
+        let mut left = vec![0.0; num_samples];
+        left[0] = 1.0;
+        StereoOutput {
+            right: left.clone(),
+            left,
+        }
+    }
+
+    #[test]
+    fn test_first_echo_lands_on_exact_sample() {
+        let sample_rate = 48000.0;
+        let mut stereo = impulse(4800);
+        apply(&mut stereo, 10.0, 0.5, 0.5, false, None, sample_rate).unwrap();
+
+        // 10 ms at 48 kHz is exactly 480 samples
+        let echoes: Vec<usize> = (1..stereo.left.len())
+            .filter(|&i| stereo.left[i].abs() > 1e-9)
+            .collect();
+        assert_eq!(
+            echoes,
+            vec![480, 960, 1440, 1920, 2400, 2880, 3360, 3840, 4320]
+        );
+
+        // Each repeat is attenuated by the feedback amount
+        assert_eq!(stereo.left[480], 0.5);
+        for pair in echoes.windows(2) {
+            let ratio = stereo.left[pair[1]] / stereo.left[pair[0]];
+            assert!((ratio - 0.5).abs() < 1e-12, "ratio {}", ratio);
+        }
+    }
+
+    #[test]
+    fn test_ping_pong_alternates_channels() {
+        let mut stereo = StereoOutput {
+            left: {
+                let mut left = vec![0.0; 400];
+                left[0] = 1.0;
+                left
+            },
+            right: vec![0.0; 400],
+        };
+        apply(&mut stereo, 1.0, 0.5, 1.0, true, None, 100_000.0).unwrap();
+
+        // 1 ms at 100 kHz = 100 samples; repeats bounce left, right, left
+        assert_eq!(stereo.left[100], 1.0);
+        assert_eq!(stereo.right[100], 0.0);
+        assert_eq!(stereo.right[200], 0.5);
+        assert_eq!(stereo.left[200], 0.0);
+        assert_eq!(stereo.left[300], 0.25);
+    }
+
+    #[test]
+    fn test_tone_darkens_repeats_and_is_deterministic() {
+        let sample_rate = 48000.0;
+        let mut bright = impulse(4800);
+        apply(&mut bright, 10.0, 0.8, 1.0, false, None, sample_rate).unwrap();
+        let mut dark = impulse(4800);
+        apply(&mut dark, 10.0, 0.8, 1.0, false, Some(1000.0), sample_rate).unwrap();
+        let mut again = impulse(4800);
+        apply(&mut again, 10.0, 0.8, 1.0, false, Some(1000.0), sample_rate).unwrap();
+
+        assert_eq!(dark.left, again.left);
+        // The first echo is not filtered; later repeats lose their peak
+        assert_eq!(dark.left[480], bright.left[480]);
+        assert!(dark.left[960] < bright.left[960] * 0.5);
+
+        let mut stereo = impulse(16);
+        assert!(apply(&mut stereo, 10.0, 0.5, 0.5, false, Some(0.0), sample_rate).is_err());
+    }
+}
@@ -1,44 +1,80 @@
 //! Distortion effects: bitcrusher and waveshaper.
 
 use crate::mixer::StereoOutput;
+use crate::oversample;
 use speccade_spec::recipe::audio::WaveshaperCurve;
 
 /// Applies bitcrush effect to stereo audio.
-pub fn apply_bitcrush(stereo: &mut StereoOutput, bits: u8, sample_rate_reduction: f64) {
+///
+/// Samples are quantized to `2^bits` levels, decimated by `sample_rate_reduction`, then held
+/// for `downsample` frames. At `bits = 16` with no rate reduction or downsampling the audio is
+/// left untouched.
+pub fn apply_bitcrush(
+    stereo: &mut StereoOutput,
+    bits: u8,
+    sample_rate_reduction: f64,
+    downsample: u32,
+) {
     let bits = bits.clamp(1, 16);
     let sr_reduction = sample_rate_reduction.max(1.0);
+    let downsample = downsample.max(1) as usize;
 
-    // Calculate quantization step
-    let levels = (1 << bits) as f64; // 2^bits
-    let step = 2.0 / levels; // Range is -1.0 to 1.0
+    // 16 bits is the output resolution, so quantizing there would only add rounding noise.
+    let quantize_bits = (bits < 16).then_some(bits);
 
-    let mut phase = 0.0;
+    if quantize_bits.is_some() || sr_reduction > 1.0 {
+        let mut phase = 0.0;
 
-    for i in 0..stereo.left.len() {
-        // Sample rate reduction
-        if phase >= 1.0 {
-            phase -= 1.0;
+        for i in 0..stereo.left.len() {
+            // Sample rate reduction
+            if i == 0 || phase >= 1.0 {
+                if i > 0 {
+                    phase -= 1.0;
+                }
+
+                // Bit depth reduction (quantization)
+                if let Some(bits) = quantize_bits {
+                    stereo.left[i] = quantize(stereo.left[i], bits);
+                    stereo.right[i] = quantize(stereo.right[i], bits);
+                }
+            } else {
+                // Hold previous sample
+                stereo.left[i] = stereo.left[i - 1];
+                stereo.right[i] = stereo.right[i - 1];
+            }
 
-            // Bit depth reduction (quantization)
-            stereo.left[i] = quantize(stereo.left[i], step);
-            stereo.right[i] = quantize(stereo.right[i], step);
-        } else if i > 0 {
-            // Hold previous sample
-            stereo.left[i] = stereo.left[i - 1];
-            stereo.right[i] = stereo.right[i - 1];
+            phase += 1.0 / sr_reduction;
         }
+    }
 
-        phase += 1.0 / sr_reduction;
+    // Integer decimation: every frame repeats the first frame of its block.
+    if downsample > 1 {
+        for i in 0..stereo.left.len() {
+            let held = i - i % downsample;
+            stereo.left[i] = stereo.left[held];
+            stereo.right[i] = stereo.right[held];
+        }
     }
 }
 
-/// Quantizes a sample to a specific bit depth.
-fn quantize(sample: f64, step: f64) -> f64 {
-    (sample / step).round() * step
+/// Quantizes a sample to `2^bits` levels, like a signed integer of that width.
+fn quantize(sample: f64, bits: u8) -> f64 {
+    let half = (1u32 << (bits - 1)) as f64;
+    // Adding 0.0 folds -0.0 into 0.0, so silence maps to a single level.
+    (sample * half).round().clamp(-half, half - 1.0) / half + 0.0
 }
 
+/// Oversampling factor for the waveshaper's nonlinearity.
+const WAVESHAPER_OVERSAMPLE: usize = 2;
+
 /// Applies waveshaper distortion to stereo audio.
+///
+/// A drive of 0.0 bypasses the effect and leaves the audio untouched.
 pub fn apply_waveshaper(stereo: &mut StereoOutput, drive: f64, curve: &WaveshaperCurve, wet: f64) {
+    if drive == 0.0 {
+        return;
+    }
+
     // Create a constant drive curve for the non-modulated case
     let num_samples = stereo.left.len();
     let drive_curve = vec![drive; num_samples];
@@ -47,6 +83,9 @@ pub fn apply_waveshaper(stereo: &mut StereoOutput, drive: f64, curve: &Waveshape
 
 /// Applies waveshaper distortion to stereo audio with per-sample drive modulation.
 ///
+/// The curve runs at [`WAVESHAPER_OVERSAMPLE`] times the sample rate so the
+/// harmonics it adds above Nyquist are filtered out instead of aliasing.
+///
 /// # Arguments
 /// * `stereo` - Stereo audio to process
 /// * `drive_curve` - Per-sample drive values (clamped to 1.0-100.0)
@@ -61,30 +100,32 @@ pub fn apply_waveshaper_with_modulation(
     let wet = wet.clamp(0.0, 1.0);
     let dry = 1.0 - wet;
 
-    for i in 0..stereo.left.len() {
-        let in_left = stereo.left[i];
-        let in_right = stereo.right[i];
-
-        // Get modulated drive for this sample
-        let drive = drive_curve.get(i).copied().unwrap_or(1.0).clamp(1.0, 100.0);
+    for channel in [&mut stereo.left, &mut stereo.right] {
+        let shaped = shape_oversampled(channel, drive_curve, curve);
+        for (sample, out) in channel.iter_mut().zip(shaped) {
+            *sample = *sample * dry + out * wet;
+        }
+    }
+}
 
-        // Apply drive
-        let driven_left = in_left * drive;
-        let driven_right = in_right * drive;
+/// Drives one channel through the curve at the oversampled rate, with makeup
+/// gain compensating for the drive.
+fn shape_oversampled(input: &[f64], drive_curve: &[f64], curve: &WaveshaperCurve) -> Vec<f64> {
+    let mut upsampled = oversample::interpolate(input, WAVESHAPER_OVERSAMPLE);
 
-        // Apply waveshaping curve
-        let shaped_left = apply_curve(driven_left, curve);
-        let shaped_right = apply_curve(driven_right, curve);
+    for (i, sample) in upsampled.iter_mut().enumerate() {
+        // Get modulated drive for this sample
+        let drive = drive_curve
+            .get(i / WAVESHAPER_OVERSAMPLE)
+            .copied()
+            .unwrap_or(1.0)
+            .clamp(1.0, 100.0);
 
-        // Apply makeup gain (compensate for drive)
         let makeup = 1.0 / drive.sqrt();
-        let out_left = shaped_left * makeup;
-        let out_right = shaped_right * makeup;
-
-        // Mix wet/dry
-        stereo.left[i] = in_left * dry + out_left * wet;
-        stereo.right[i] = in_right * dry + out_right * wet;
+        *sample = apply_curve(*sample * drive, curve) * makeup;
     }
+
+    oversample::decimate(&upsampled, WAVESHAPER_OVERSAMPLE, input.len())
 }
 
 /// Applies a waveshaping curve to a sample.
@@ -107,5 +148,109 @@ fn apply_curve(sample: f64, curve: &WaveshaperCurve) -> f64 {
             use std::f64::consts::PI;
             (sample * PI / 2.0).sin()
         }
+        WaveshaperCurve::Foldback => {
+            // Reflect off the +/-1.0 rails (triangle fold with period 4)
+            let folded = (sample + 1.0).rem_euclid(4.0);
+            if folded < 2.0 {
+                folded - 1.0
+            } else {
+                3.0 - folded
+            }
+        }
+        WaveshaperCurve::Cubic => {
+            // Smoothstep-style cubic that saturates at +/-1.0
+            let x = sample.clamp(-1.0, 1.0);
+            1.5 * x - 0.5 * x * x * x
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine_stereo(num_samples: usize) -> StereoOutput {
+        let left: Vec<f64> = (0..num_samples)
+            .map(|i| 0.5 * (std::f64::consts::TAU * 440.0 * i as f64 / 44100.0).sin())
+            .collect();
+        StereoOutput {
+            right: left.clone(),
+            left,
+        }
+    }
+
+    #[test]
+    fn test_bitcrush_full_resolution_is_identity() {
+        let original = sine_stereo(1024);
+        let mut stereo = original.clone();
+        apply_bitcrush(&mut stereo, 16, 1.0, 1);
+        assert_eq!(stereo.left, original.left);
+        assert_eq!(stereo.right, original.right);
+    }
+
+    #[test]
+    fn test_bitcrush_4_bits_has_at_most_16_levels() {
+        // Full-scale sine sweep from 100 Hz to 8 kHz.
+        let num_samples = 44100;
+        let mut phase = 0.0;
+        let left: Vec<f64> = (0..num_samples)
+            .map(|i| {
+                let freq = 100.0 + 7900.0 * i as f64 / num_samples as f64;
+                phase += std::f64::consts::TAU * freq / 44100.0;
+                phase.sin()
+            })
+            .collect();
+        let mut stereo = StereoOutput {
+            right: left.clone(),
+            left,
+        };
+
+        apply_bitcrush(&mut stereo, 4, 1.0, 1);
+
+        let levels: std::collections::HashSet<u64> =
+            stereo.left.iter().map(|s| s.to_bits()).collect();
+        assert!(levels.len() <= 16, "got {} levels", levels.len());
+    }
+
+    #[test]
+    fn test_bitcrush_downsample_holds_frames() {
+        let mut stereo = sine_stereo(64);
+        apply_bitcrush(&mut stereo, 16, 1.0, 4);
+        for block in stereo.left.chunks(4) {
+            assert!(block.iter().all(|&s| s == block[0]));
+        }
+    }
+
+    #[test]
+    fn test_waveshaper_zero_drive_is_identity() {
+        let original = sine_stereo(1024);
+        for curve in [WaveshaperCurve::HardClip, WaveshaperCurve::Foldback] {
+            let mut stereo = original.clone();
+            apply_waveshaper(&mut stereo, 0.0, &curve, 1.0);
+            assert_eq!(stereo.left, original.left);
+            assert_eq!(stereo.right, original.right);
+        }
+    }
+
+    #[test]
+    fn test_waveshaper_curves_are_bounded_and_odd() {
+        let curves = [
+            WaveshaperCurve::Tanh,
+            WaveshaperCurve::SoftClip,
+            WaveshaperCurve::HardClip,
+            WaveshaperCurve::Sine,
+            WaveshaperCurve::Foldback,
+            WaveshaperCurve::Cubic,
+        ];
+        for curve in &curves {
+            for i in -200..=200 {
+                let x = i as f64 * 0.05;
+                let y = apply_curve(x, curve);
+                assert!(y.abs() <= 1.0 + 1e-12, "{:?}({}) = {}", curve, x, y);
+                assert!((y + apply_curve(-x, curve)).abs() < 1e-12);
+            }
+        }
+        assert!((apply_curve(1.5, &WaveshaperCurve::Foldback) - 0.5).abs() < 1e-12);
+        assert!((apply_curve(3.5, &WaveshaperCurve::Foldback) + 0.5).abs() < 1e-12);
     }
 }
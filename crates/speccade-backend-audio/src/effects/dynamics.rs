@@ -1,7 +1,12 @@
-//! Dynamics processing: compressor and limiter.
+//! Dynamics processing: compressors, limiters and gates.
 
 use crate::error::{AudioError, AudioResult};
-use crate::mixer::StereoOutput;
+use crate::mixer::{MixerOutput, StereoOutput};
+use crate::peak::true_peak_envelope;
+use speccade_spec::recipe::audio::{Compressor, Limiter};
+
+/// Release time of the master limiter, in ms.
+const MASTER_LIMITER_RELEASE_MS: f64 = 50.0;
 
 /// Converts linear amplitude to decibels.
 fn amp_to_db(amp: f64) -> f64 {
@@ -95,6 +100,201 @@ pub fn apply_compressor(
     Ok(())
 }
 
+/// Applies the master-bus compressor to a mixed output.
+///
+/// This is a feed-forward peak compressor: the detector follows the sample
+/// peak across all channels, the static curve (with an optional soft knee)
+/// maps it to a gain reduction, and that reduction is smoothed with the
+/// attack and release time constants. Channels share one gain so the stereo
+/// image is preserved.
+///
+/// Returns the peak gain reduction in dB (0 when the signal never crossed
+/// the knee).
+pub fn apply_master_compressor(
+    output: &mut MixerOutput,
+    compressor: &Compressor,
+    sample_rate: f64,
+) -> AudioResult<f64> {
+    let Compressor {
+        threshold_db,
+        ratio,
+        attack_ms,
+        release_ms,
+        makeup_db,
+        knee_db,
+    } = *compressor;
+
+    if !(-60.0..=0.0).contains(&threshold_db) {
+        return Err(AudioError::invalid_param(
+            "master_compressor.threshold_db",
+            format!("must be -60 to 0, got {}", threshold_db),
+        ));
+    }
+    if !(1.0..=20.0).contains(&ratio) {
+        return Err(AudioError::invalid_param(
+            "master_compressor.ratio",
+            format!("must be 1.0-20.0, got {}", ratio),
+        ));
+    }
+    if !(0.1..=100.0).contains(&attack_ms) {
+        return Err(AudioError::invalid_param(
+            "master_compressor.attack_ms",
+            format!("must be 0.1-100, got {}", attack_ms),
+        ));
+    }
+    if !(10.0..=1000.0).contains(&release_ms) {
+        return Err(AudioError::invalid_param(
+            "master_compressor.release_ms",
+            format!("must be 10-1000, got {}", release_ms),
+        ));
+    }
+    if !(0.0..=24.0).contains(&knee_db) {
+        return Err(AudioError::invalid_param(
+            "master_compressor.knee_db",
+            format!("must be 0-24, got {}", knee_db),
+        ));
+    }
+
+    let attack_coeff = (-1.0 / (attack_ms * 0.001 * sample_rate)).exp();
+    let release_coeff = (-1.0 / (release_ms * 0.001 * sample_rate)).exp();
+    let makeup_gain = db_to_amp(makeup_db);
+    let slope = 1.0 - 1.0 / ratio;
+
+    // Static gain reduction in dB for a detector level in dB
+    let static_reduction = |level_db: f64| {
+        let over_db = level_db - threshold_db;
+        if 2.0 * over_db <= -knee_db {
+            0.0
+        } else if 2.0 * over_db.abs() < knee_db {
+            slope * (over_db + knee_db / 2.0).powi(2) / (2.0 * knee_db)
+        } else {
+            slope * over_db
+        }
+    };
+
+    let mut reduction_db = 0.0;
+    let mut peak_reduction_db: f64 = 0.0;
+    let mut compute_gain = |peak: f64| {
+        let target = static_reduction(amp_to_db(peak));
+        let coeff = if target > reduction_db {
+            attack_coeff
+        } else {
+            release_coeff
+        };
+        reduction_db = coeff * reduction_db + (1.0 - coeff) * target;
+        peak_reduction_db = peak_reduction_db.max(reduction_db);
+        db_to_amp(-reduction_db) * makeup_gain
+    };
+
+    match output {
+        MixerOutput::Mono(samples) => {
+            for sample in samples.iter_mut() {
+                *sample *= compute_gain(sample.abs());
+            }
+        }
+        MixerOutput::Stereo(stereo) => {
+            for (left, right) in stereo.left.iter_mut().zip(stereo.right.iter_mut()) {
+                let gain = compute_gain(left.abs().max(right.abs()));
+                *left *= gain;
+                *right *= gain;
+            }
+        }
+    }
+
+    Ok(peak_reduction_db)
+}
+
+/// Applies the master-bus brick-wall limiter to a finished output.
+///
+/// The detector is the 4x-oversampled true-peak envelope of all channels, so
+/// inter-sample overs are caught as well as sample overs. Gain is computed
+/// over the whole buffer in two deterministic passes: a backward pass ramps
+/// the gain down over `lookahead_ms` ahead of each peak, and a forward pass
+/// lets it recover with a fixed release. Neither pass ever raises the gain
+/// above what the ceiling requires, and channels share one gain.
+pub fn apply_master_limiter(
+    output: &mut MixerOutput,
+    limiter: &Limiter,
+    sample_rate: f64,
+) -> AudioResult<()> {
+    let Limiter {
+        ceiling_db,
+        lookahead_ms,
+    } = *limiter;
+
+    if !(-24.0..=0.0).contains(&ceiling_db) {
+        return Err(AudioError::invalid_param(
+            "master_limiter.ceiling_db",
+            format!("must be -24 to 0, got {}", ceiling_db),
+        ));
+    }
+    if !(0.1..=20.0).contains(&lookahead_ms) {
+        return Err(AudioError::invalid_param(
+            "master_limiter.lookahead_ms",
+            format!("must be 0.1-20, got {}", lookahead_ms),
+        ));
+    }
+
+    let envelope = match output {
+        MixerOutput::Mono(samples) => true_peak_envelope(samples),
+        MixerOutput::Stereo(stereo) => true_peak_envelope(&stereo.left)
+            .into_iter()
+            .zip(true_peak_envelope(&stereo.right))
+            .map(|(left, right)| left.max(right))
+            .collect(),
+    };
+
+    let ceiling = db_to_amp(ceiling_db);
+    let lookahead_samples = (lookahead_ms * 0.001 * sample_rate).round().max(1.0);
+    let attack_step = 1.0 / lookahead_samples;
+    let release_coeff = (-1.0 / (MASTER_LIMITER_RELEASE_MS * 0.001 * sample_rate)).exp();
+
+    // Gain each sample needs to stay under the ceiling
+    let mut gains: Vec<f64> = envelope
+        .iter()
+        .map(|&peak| if peak > ceiling { ceiling / peak } else { 1.0 })
+        .collect();
+
+    // Backward pass: start reducing gain up to a lookahead ahead of each peak
+    let mut next = 1.0;
+    for gain in gains.iter_mut().rev() {
+        *gain = gain.min(next + attack_step);
+        next = *gain;
+    }
+
+    // Forward pass: recover smoothly, but never above the required gain
+    let mut current = 1.0;
+    for gain in gains.iter_mut() {
+        current = if *gain < current {
+            *gain
+        } else {
+            release_coeff * current + (1.0 - release_coeff) * *gain
+        };
+        *gain = current;
+    }
+
+    match output {
+        MixerOutput::Mono(samples) => {
+            for (sample, gain) in samples.iter_mut().zip(&gains) {
+                *sample *= gain;
+            }
+        }
+        MixerOutput::Stereo(stereo) => {
+            for ((left, right), gain) in stereo
+                .left
+                .iter_mut()
+                .zip(stereo.right.iter_mut())
+                .zip(&gains)
+            {
+                *left *= gain;
+                *right *= gain;
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Applies brick-wall limiting to stereo audio with lookahead.
 ///
 /// A limiter is a dynamics processor that prevents output from exceeding the ceiling level.
@@ -231,47 +431,102 @@ pub fn apply_gate_expander(
     release_ms: f64,
     range_db: f64,
     sample_rate: f64,
+) -> AudioResult<()> {
+    gate_expander(
+        stereo,
+        "gate_expander",
+        threshold_db,
+        ratio,
+        attack_ms,
+        hold_ms,
+        release_ms,
+        range_db,
+        sample_rate,
+    )
+}
+
+/// Applies a downward noise gate to stereo audio.
+///
+/// Once the signal falls below the threshold and the hold time has passed,
+/// the gain drops to `range_db`; it reopens on the next peak above the
+/// threshold. A `range_db` of 0 leaves the audio untouched.
+pub fn apply_gate(
+    stereo: &mut StereoOutput,
+    threshold_db: f64,
+    attack_ms: f64,
+    hold_ms: f64,
+    release_ms: f64,
+    range_db: f64,
+    sample_rate: f64,
+) -> AudioResult<()> {
+    gate_expander(
+        stereo,
+        "gate",
+        threshold_db,
+        f64::INFINITY,
+        attack_ms,
+        hold_ms,
+        release_ms,
+        range_db,
+        sample_rate,
+    )
+}
+
+/// Shared gate/expander implementation; `effect` prefixes parameter errors.
+#[allow(clippy::too_many_arguments)]
+fn gate_expander(
+    stereo: &mut StereoOutput,
+    effect: &str,
+    threshold_db: f64,
+    ratio: f64,
+    attack_ms: f64,
+    hold_ms: f64,
+    release_ms: f64,
+    range_db: f64,
+    sample_rate: f64,
 ) -> AudioResult<()> {
     // Validate parameters
     if !(-60.0..=0.0).contains(&threshold_db) {
         return Err(AudioError::invalid_param(
-            "gate_expander.threshold_db",
+            format!("{}.threshold_db", effect),
             format!("must be -60 to 0, got {}", threshold_db),
         ));
     }
     if !(1.0..=f64::INFINITY).contains(&ratio) {
         return Err(AudioError::invalid_param(
-            "gate_expander.ratio",
+            format!("{}.ratio", effect),
             format!("must be >= 1.0, got {}", ratio),
         ));
     }
     if !(0.1..=50.0).contains(&attack_ms) {
         return Err(AudioError::invalid_param(
-            "gate_expander.attack_ms",
+            format!("{}.attack_ms", effect),
             format!("must be 0.1-50, got {}", attack_ms),
         ));
     }
     if !(0.0..=500.0).contains(&hold_ms) {
         return Err(AudioError::invalid_param(
-            "gate_expander.hold_ms",
+            format!("{}.hold_ms", effect),
             format!("must be 0-500, got {}", hold_ms),
         ));
     }
     if !(10.0..=2000.0).contains(&release_ms) {
         return Err(AudioError::invalid_param(
-            "gate_expander.release_ms",
+            format!("{}.release_ms", effect),
             format!("must be 10-2000, got {}", release_ms),
         ));
     }
     if !(-80.0..=0.0).contains(&range_db) {
         return Err(AudioError::invalid_param(
-            "gate_expander.range_db",
+            format!("{}.range_db", effect),
             format!("must be -80 to 0, got {}", range_db),
         ));
     }
 
     let num_samples = stereo.left.len();
-    if num_samples == 0 {
+    // With no attenuation range the gain is always unity; skip processing so
+    // the output is bit-identical to the input.
+    if num_samples == 0 || range_db == 0.0 {
         return Ok(());
     }
 
@@ -314,6 +569,9 @@ pub fn apply_gate_expander(
             // In hold period: keep gate open
             hold_counter -= 1;
             1.0
+        } else if ratio.is_infinite() {
+            // Hard gate: closed means fully down to range_db
+            range_linear
         } else {
             // Below threshold and hold expired: apply expansion/gating
             let envelope_db = amp_to_db(envelope);
@@ -1,4 +1,4 @@
-//! Tests for dynamics processing (compressor, limiter, gate/expander).
+//! Tests for dynamics processing (compressor, limiter, gate/expander, gate).
 
 use super::*;
 use crate::mixer::StereoOutput;
@@ -421,3 +421,213 @@ fn test_true_peak_limiter_streaming_settings() {
     let result = apply_true_peak_limiter(&mut stereo, -1.0, 100.0, 44100.0);
     assert!(result.is_ok());
 }
+
+fn master_compressor(threshold_db: f64, ratio: f64, knee_db: f64) -> Compressor {
+    Compressor {
+        threshold_db,
+        ratio,
+        attack_ms: 1.0,
+        release_ms: 50.0,
+        makeup_db: 0.0,
+        knee_db,
+    }
+}
+
+#[test]
+fn test_master_compressor_attenuates_transient_by_ratio() {
+    let sample_rate = 48000.0;
+    // 100ms at -40 dBFS, then a 100ms burst at 0 dBFS
+    let mut samples = vec![0.01; 4800];
+    samples.extend(std::iter::repeat_n(1.0, 4800));
+    let mut output = MixerOutput::Mono(samples);
+
+    let peak_reduction = apply_master_compressor(
+        &mut output,
+        &master_compressor(-20.0, 4.0, 0.0),
+        sample_rate,
+    )
+    .unwrap();
+
+    let MixerOutput::Mono(samples) = output else {
+        panic!("expected mono output");
+    };
+    // Below threshold the signal passes untouched
+    assert_eq!(samples[4000], 0.01);
+    // 20 dB over a -20 dB threshold at 4:1 comes out 5 dB over: -15 dBFS
+    let settled_db = amp_to_db(samples[9000]);
+    assert!(
+        (settled_db - -15.0).abs() < 0.1,
+        "settled level {settled_db} dB"
+    );
+    assert!((peak_reduction - 15.0).abs() < 0.1, "{peak_reduction}");
+}
+
+#[test]
+fn test_master_compressor_soft_knee_and_stereo_link() {
+    let mut output = MixerOutput::Stereo(StereoOutput {
+        left: vec![db_to_amp(-20.0); 4800],
+        right: vec![0.0; 4800],
+    });
+    // At the threshold a soft knee already reduces gain, a hard knee does not
+    let soft = apply_master_compressor(&mut output, &master_compressor(-20.0, 4.0, 12.0), 48000.0)
+        .unwrap();
+    assert!(soft > 0.5 && soft < 3.0, "{soft}");
+
+    let MixerOutput::Stereo(stereo) = &output else {
+        panic!("expected stereo output");
+    };
+    assert!(stereo.right.iter().all(|&s| s == 0.0));
+
+    let mut hard_output = MixerOutput::Mono(vec![db_to_amp(-20.0); 4800]);
+    let hard = apply_master_compressor(
+        &mut hard_output,
+        &master_compressor(-20.0, 4.0, 0.0),
+        48000.0,
+    )
+    .unwrap();
+    assert!(hard < 1e-6, "{hard}");
+}
+
+#[test]
+fn test_master_compressor_rejects_invalid_knee() {
+    let mut output = MixerOutput::Mono(vec![0.5; 16]);
+    assert!(
+        apply_master_compressor(&mut output, &master_compressor(-20.0, 4.0, 30.0), 48000.0)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_master_limiter_catches_inter_sample_overs() {
+    // Quarter-rate sine whose samples sit at +/-0.9 (-0.9 dBFS) while the
+    // waveform between them peaks near +2.1 dBTP
+    let samples: Vec<f64> = (0..4800)
+        .map(|i| {
+            0.9 * std::f64::consts::SQRT_2
+                * (std::f64::consts::FRAC_PI_2 * i as f64 + std::f64::consts::FRAC_PI_4).sin()
+        })
+        .collect();
+    let limiter = Limiter {
+        ceiling_db: -0.5,
+        lookahead_ms: 5.0,
+    };
+    assert!(amp_to_db(crate::peak::sample_peak(&samples)) < limiter.ceiling_db);
+
+    let mut output = MixerOutput::Stereo(StereoOutput::from_mono(samples));
+    apply_master_limiter(&mut output, &limiter, 48000.0).unwrap();
+
+    let MixerOutput::Stereo(stereo) = output else {
+        panic!("expected stereo output");
+    };
+    for channel in [&stereo.left, &stereo.right] {
+        let true_peak_db = amp_to_db(crate::peak::true_peak(channel));
+        assert!(
+            true_peak_db <= limiter.ceiling_db + 0.05,
+            "true peak {true_peak_db} dBTP"
+        );
+    }
+    assert_eq!(stereo.left, stereo.right);
+}
+
+#[test]
+fn test_master_limiter_rejects_invalid_params() {
+    let mut output = MixerOutput::Mono(vec![0.5; 16]);
+    let positive_ceiling = Limiter {
+        ceiling_db: 1.0,
+        lookahead_ms: 5.0,
+    };
+    assert!(apply_master_limiter(&mut output, &positive_ceiling, 48000.0).is_err());
+    let no_lookahead = Limiter {
+        ceiling_db: -1.0,
+        lookahead_ms: 0.0,
+    };
+    assert!(apply_master_limiter(&mut output, &no_lookahead, 48000.0).is_err());
+}
+
+/// White noise with an exponential decay, identical in both channels.
+fn decaying_noise_burst(seconds: f64, decay_s: f64, sample_rate: f64) -> StereoOutput {
+    use rand::Rng;
+
+    let mut rng = crate::rng::create_rng(7);
+    let samples: Vec<f64> = (0..(seconds * sample_rate) as usize)
+        .map(|i| {
+            let t = i as f64 / sample_rate;
+            rng.gen_range(-1.0..1.0) * (-t / decay_s).exp()
+        })
+        .collect();
+    StereoOutput {
+        left: samples.clone(),
+        right: samples,
+    }
+}
+
+/// RMS of `samples` over `[start_s, end_s)`.
+fn windowed_rms(samples: &[f64], start_s: f64, end_s: f64, sample_rate: f64) -> f64 {
+    let window = &samples[(start_s * sample_rate) as usize..(end_s * sample_rate) as usize];
+    (window.iter().map(|s| s * s).sum::<f64>() / window.len() as f64).sqrt()
+}
+
+#[test]
+fn test_gate_attenuates_decaying_tail() {
+    let sample_rate = 44100.0;
+    let input = decaying_noise_burst(0.5, 0.06, sample_rate);
+    let mut gated = input.clone();
+
+    apply_gate(&mut gated, -30.0, 1.0, 10.0, 20.0, -40.0, sample_rate).unwrap();
+
+    // The loud onset sits well above the threshold and passes unchanged.
+    let onset_in = windowed_rms(&input.left, 0.0, 0.02, sample_rate);
+    let onset_out = windowed_rms(&gated.left, 0.0, 0.02, sample_rate);
+    assert!(
+        ((onset_out - onset_in) / onset_in).abs() < 1e-9,
+        "onset changed: {} -> {}",
+        onset_in,
+        onset_out
+    );
+
+    // The quiet tail is pulled down towards range_db.
+    let tail_in = windowed_rms(&input.left, 0.35, 0.5, sample_rate);
+    let tail_out = windowed_rms(&gated.left, 0.35, 0.5, sample_rate);
+    let reduction_db = 20.0 * (tail_out / tail_in).log10();
+    assert!(reduction_db < -30.0, "tail reduced by {} dB", reduction_db);
+    assert_eq!(gated.left, gated.right);
+}
+
+#[test]
+fn test_gate_is_deterministic() {
+    let input = decaying_noise_burst(0.2, 0.04, 44100.0);
+    let mut first = input.clone();
+    let mut second = input;
+
+    apply_gate(&mut first, -24.0, 2.0, 5.0, 50.0, -60.0, 44100.0).unwrap();
+    apply_gate(&mut second, -24.0, 2.0, 5.0, 50.0, -60.0, 44100.0).unwrap();
+
+    assert_eq!(first.left, second.left);
+    assert_eq!(first.right, second.right);
+}
+
+#[test]
+fn test_gate_zero_range_is_bypass() {
+    let input = decaying_noise_burst(0.2, 0.04, 44100.0);
+    let mut gated = input.clone();
+
+    apply_gate(&mut gated, -20.0, 1.0, 0.0, 50.0, 0.0, 44100.0).unwrap();
+
+    assert_eq!(gated.left, input.left);
+    assert_eq!(gated.right, input.right);
+}
+
+#[test]
+fn test_gate_invalid_params() {
+    let mut stereo = StereoOutput {
+        left: vec![0.5],
+        right: vec![0.5],
+    };
+
+    let err = apply_gate(&mut stereo, -70.0, 1.0, 50.0, 100.0, -60.0, 44100.0).unwrap_err();
+    assert!(err.to_string().contains("gate.threshold_db"), "{}", err);
+    assert!(apply_gate(&mut stereo, -30.0, 60.0, 50.0, 100.0, -60.0, 44100.0).is_err());
+    assert!(apply_gate(&mut stereo, -30.0, 1.0, 600.0, 100.0, -60.0, 44100.0).is_err());
+    assert!(apply_gate(&mut stereo, -30.0, 1.0, 50.0, 5.0, -60.0, 44100.0).is_err());
+    assert!(apply_gate(&mut stereo, -30.0, 1.0, 50.0, 100.0, 1.0, 44100.0).is_err());
+}
@@ -4,9 +4,11 @@
 //! after synthesis and mixing.
 
 pub mod auto_filter;
+pub mod auto_pan;
 pub mod cabinet;
 mod chain;
 pub mod chorus;
+pub mod convolution;
 pub mod delay;
 pub mod delay_line;
 pub mod distortion;
@@ -15,14 +17,17 @@ pub mod eq;
 pub mod flanger;
 pub mod granular_delay;
 pub mod multi_tap_delay;
+pub mod phase_vocoder;
 pub mod reverb;
 pub mod ring_mod;
 pub mod rotary;
+pub mod sidechain;
 pub mod stereo;
 pub mod tape;
 pub mod transient;
+pub mod tremolo;
 
-use speccade_spec::recipe::audio::{Effect, LfoModulation, ModulationTarget};
+use speccade_spec::recipe::audio::{Effect, LfoModulation, ModulationTarget, Waveform};
 
 use crate::error::AudioResult;
 use crate::mixer::{MixerOutput, StereoOutput};
@@ -168,8 +173,10 @@ fn apply_effect_with_lfo(
             room_size,
             damping,
             wet,
+            dry,
             width,
         } => {
+            let dry = dry.unwrap_or(1.0 - *wet);
             if let Some((ref lfo_curve, amount)) = reverb_size_curve {
                 let room_size_mod_curve: Vec<f64> = lfo_curve
                     .iter()
@@ -182,11 +189,22 @@ fn apply_effect_with_lfo(
                     &room_size_mod_curve,
                     *damping,
                     *wet,
+                    dry,
                     *width,
                     sample_rate,
+                    seed,
                 )?;
             } else {
-                reverb::apply(stereo, *room_size, *damping, *wet, *width, sample_rate)?;
+                reverb::apply(
+                    stereo,
+                    *room_size,
+                    *damping,
+                    *wet,
+                    dry,
+                    *width,
+                    sample_rate,
+                    seed,
+                )?;
             }
         }
         Effect::Delay {
@@ -194,6 +212,7 @@ fn apply_effect_with_lfo(
             feedback,
             wet,
             ping_pong,
+            tone,
         } => {
             if let Some((ref lfo_curve, amount_ms)) = delay_time_curve {
                 let time_curve: Vec<f64> = lfo_curve
@@ -208,10 +227,19 @@ fn apply_effect_with_lfo(
                     *feedback,
                     *wet,
                     *ping_pong,
+                    *tone,
                     sample_rate,
                 )?;
             } else {
-                delay::apply(stereo, *time_ms, *feedback, *wet, *ping_pong, sample_rate)?;
+                delay::apply(
+                    stereo,
+                    *time_ms,
+                    *feedback,
+                    *wet,
+                    *ping_pong,
+                    *tone,
+                    sample_rate,
+                )?;
             }
         }
         Effect::Chorus {
@@ -219,25 +247,37 @@ fn apply_effect_with_lfo(
             depth,
             wet,
             voices,
+            spread,
         } => {
-            chorus::apply(stereo, *rate, *depth, *wet, *voices, sample_rate)?;
+            chorus::apply(stereo, *rate, *depth, *wet, *voices, *spread, sample_rate)?;
         }
         Effect::Phaser {
             rate,
             depth,
             stages,
+            feedback,
             wet,
         } => {
-            chorus::apply_phaser(stereo, *rate, *depth, *stages, *wet, sample_rate)?;
+            chorus::apply_phaser(
+                stereo,
+                *rate,
+                *depth,
+                *stages,
+                feedback.unwrap_or(0.0),
+                *wet,
+                sample_rate,
+            )?;
         }
         Effect::Bitcrush {
             bits,
             sample_rate_reduction,
+            downsample,
         } => {
-            distortion::apply_bitcrush(stereo, *bits, *sample_rate_reduction);
+            distortion::apply_bitcrush(stereo, *bits, *sample_rate_reduction, *downsample);
         }
-        Effect::Waveshaper { drive, curve, wet } => {
-            if let Some((ref lfo_curve, amount)) = distortion_drive_curve {
+        Effect::Waveshaper { drive, curve, wet } => match distortion_drive_curve {
+            // A zero drive bypasses the waveshaper, even under LFO modulation
+            Some((lfo_curve, amount)) if *drive > 0.0 => {
                 let drive_mod_curve: Vec<f64> = lfo_curve
                     .iter()
                     .map(|&lfo_value| {
@@ -245,10 +285,9 @@ fn apply_effect_with_lfo(
                     })
                     .collect();
                 distortion::apply_waveshaper_with_modulation(stereo, &drive_mod_curve, curve, *wet);
-            } else {
-                distortion::apply_waveshaper(stereo, *drive, curve, *wet);
             }
-        }
+            _ => distortion::apply_waveshaper(stereo, *drive, curve, *wet),
+        },
         Effect::Compressor {
             threshold_db,
             ratio,
@@ -331,6 +370,23 @@ fn apply_effect_with_lfo(
                 sample_rate,
             )?;
         }
+        Effect::Gate {
+            threshold_db,
+            attack_ms,
+            hold_ms,
+            release_ms,
+            range_db,
+        } => {
+            dynamics::apply_gate(
+                stereo,
+                *threshold_db,
+                *attack_ms,
+                *hold_ms,
+                *release_ms,
+                *range_db,
+                sample_rate,
+            )?;
+        }
         Effect::StereoWidener {
             width,
             mode,
@@ -443,8 +499,18 @@ fn apply_effect_with_lfo(
         Effect::RotarySpeaker { rate, depth, wet } => {
             rotary::apply(stereo, *rate, *depth, *wet, sample_rate)?;
         }
-        Effect::RingModulator { frequency, mix } => {
-            ring_mod::apply(stereo, *frequency, *mix, sample_rate)?;
+        Effect::RingModulator {
+            frequency,
+            mix,
+            waveform,
+        } => {
+            ring_mod::apply(
+                stereo,
+                *frequency,
+                waveform.unwrap_or(Waveform::Sine),
+                *mix,
+                sample_rate,
+            )?;
         }
         Effect::GranularDelay {
             time_ms,
@@ -490,6 +556,34 @@ fn apply_effect_with_lfo(
         } => {
             dynamics::apply_true_peak_limiter(stereo, *ceiling_db, *release_ms, sample_rate)?;
         }
+        Effect::PitchShift { semitones } => {
+            phase_vocoder::apply_pitch_shift(stereo, *semitones)?;
+        }
+        Effect::Freeze { at_seconds } => {
+            phase_vocoder::apply_freeze(stereo, *at_seconds, sample_rate)?;
+        }
+        Effect::ConvolutionReverb { impulse, wet, dry } => {
+            convolution::apply(
+                stereo,
+                *impulse,
+                *wet,
+                dry.unwrap_or(1.0 - *wet),
+                sample_rate,
+            )?;
+        }
+        Effect::Tremolo { rate, depth, shape } => {
+            tremolo::apply(
+                stereo,
+                *rate,
+                *depth,
+                shape.unwrap_or(Waveform::Sine),
+                sample_rate,
+                seed,
+            )?;
+        }
+        Effect::AutoPan { rate, depth, phase } => {
+            auto_pan::apply(stereo, *rate, *depth, *phase, sample_rate, seed)?;
+        }
     }
     Ok(())
 }
@@ -0,0 +1,417 @@
+//! Phase vocoder effects: pitch shift and spectral freeze.
+//!
+//! Both effects run a short-time Fourier transform with a Hann window at 75%
+//! overlap. Each bin's true frequency is estimated from the phase advance
+//! between consecutive frames, and output phases are accumulated from those
+//! frequencies so partials stay coherent across frames.
+//!
+//! - Pitch shift moves every bin to `k * ratio` and scales its frequency by the
+//!   same ratio, keeping the original duration. A ratio of 1.0 reconstructs
+//!   the input up to floating-point error.
+//! - Freeze captures the spectrum at a point in time and keeps resynthesizing it
+//!   with steadily advancing phases, sustaining that moment indefinitely.
+//!
+//! Processing is fully deterministic: no randomness is involved and channels
+//! are processed independently.
+
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use rustfft::num_complex::Complex;
+use rustfft::{Fft, FftPlanner};
+
+use crate::error::{AudioError, AudioResult};
+use crate::mixer::StereoOutput;
+
+/// FFT frame size in samples.
+const FFT_SIZE: usize = 2048;
+
+/// Number of overlapping frames per FFT window.
+const OVERLAP: usize = 4;
+
+/// Hop size between frames in samples.
+const HOP_SIZE: usize = FFT_SIZE / OVERLAP;
+
+/// Overlap-add gain of a squared Hann window at 75% overlap.
+const WINDOW_GAIN: f64 = 1.5;
+
+/// Crossfade length from the dry signal into the frozen spectrum, in samples.
+const FREEZE_CROSSFADE: usize = HOP_SIZE;
+
+/// Applies a phase-vocoder pitch shift to stereo audio.
+///
+/// # Arguments
+/// * `stereo` - Stereo audio to process in-place
+/// * `semitones` - Pitch shift in semitones (-24 to +24)
+pub fn apply_pitch_shift(stereo: &mut StereoOutput, semitones: f64) -> AudioResult<()> {
+    if !(-24.0..=24.0).contains(&semitones) {
+        return Err(AudioError::invalid_param(
+            "pitch_shift.semitones",
+            format!("must be -24.0 to 24.0, got {}", semitones),
+        ));
+    }
+
+    let ratio = 2.0_f64.powf(semitones / 12.0);
+    let vocoder = PhaseVocoder::new();
+    stereo.left = vocoder.pitch_shift(&stereo.left, ratio);
+    stereo.right = vocoder.pitch_shift(&stereo.right, ratio);
+
+    Ok(())
+}
+
+/// Applies a phase-vocoder spectral freeze to stereo audio.
+///
+/// Audio before `at_seconds` is left unchanged; from `at_seconds` onward the
+/// captured spectrum is sustained until the end of the buffer.
+///
+/// # Arguments
+/// * `stereo` - Stereo audio to process in-place
+/// * `at_seconds` - Capture time in seconds (clamped to the buffer length)
+/// * `sample_rate` - Sample rate in Hz
+pub fn apply_freeze(
+    stereo: &mut StereoOutput,
+    at_seconds: f64,
+    sample_rate: f64,
+) -> AudioResult<()> {
+    if !at_seconds.is_finite() || at_seconds < 0.0 {
+        return Err(AudioError::invalid_param(
+            "freeze.at_seconds",
+            format!("must be >= 0.0, got {}", at_seconds),
+        ));
+    }
+
+    let num_samples = stereo.left.len();
+    if num_samples == 0 {
+        return Ok(());
+    }
+
+    let capture = ((at_seconds * sample_rate).round() as usize).min(num_samples - 1);
+    let vocoder = PhaseVocoder::new();
+    stereo.left = vocoder.freeze(&stereo.left, capture);
+    stereo.right = vocoder.freeze(&stereo.right, capture);
+
+    Ok(())
+}
+
+/// Shared STFT machinery for the phase vocoder effects.
+struct PhaseVocoder {
+    fft: Arc<dyn Fft<f64>>,
+    ifft: Arc<dyn Fft<f64>>,
+    window: Vec<f64>,
+}
+
+impl PhaseVocoder {
+    fn new() -> Self {
+        let mut planner = FftPlanner::new();
+        let window = (0..FFT_SIZE)
+            .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / FFT_SIZE as f64).cos())
+            .collect();
+        Self {
+            fft: planner.plan_fft_forward(FFT_SIZE),
+            ifft: planner.plan_fft_inverse(FFT_SIZE),
+            window,
+        }
+    }
+
+    /// Windows the frame starting at `start` (zero outside the input) and
+    /// returns its positive-frequency bins.
+    fn analyze(&self, input: &[f64], start: isize) -> Vec<Complex<f64>> {
+        let mut buffer: Vec<Complex<f64>> = (0..FFT_SIZE)
+            .map(|i| {
+                let index = start + i as isize;
+                let sample = if index >= 0 && (index as usize) < input.len() {
+                    input[index as usize]
+                } else {
+                    0.0
+                };
+                Complex::new(sample * self.window[i], 0.0)
+            })
+            .collect();
+        self.fft.process(&mut buffer);
+        buffer.truncate(FFT_SIZE / 2 + 1);
+        buffer
+    }
+
+    /// Inverse-transforms positive-frequency bins and overlap-adds the windowed
+    /// frame into `output` at `start`.
+    fn synthesize(&self, bins: &[Complex<f64>], output: &mut [f64], start: isize) {
+        let mut buffer = vec![Complex::new(0.0, 0.0); FFT_SIZE];
+        buffer[..bins.len()].copy_from_slice(bins);
+        for k in 1..FFT_SIZE / 2 {
+            buffer[FFT_SIZE - k] = bins[k].conj();
+        }
+        self.ifft.process(&mut buffer);
+
+        let scale = 1.0 / (FFT_SIZE as f64 * WINDOW_GAIN);
+        for (i, value) in buffer.iter().enumerate() {
+            let index = start + i as isize;
+            if index >= 0 && (index as usize) < output.len() {
+                output[index as usize] += value.re * self.window[i] * scale;
+            }
+        }
+    }
+
+    /// Shifts the pitch of `input` by `ratio` while keeping its length.
+    fn pitch_shift(&self, input: &[f64], ratio: f64) -> Vec<f64> {
+        let num_bins = FFT_SIZE / 2 + 1;
+        let expected_advance = 2.0 * PI * HOP_SIZE as f64 / FFT_SIZE as f64;
+
+        let mut output = vec![0.0; input.len()];
+        let mut last_phase = vec![0.0; num_bins];
+        let mut phase_sum = vec![0.0; num_bins];
+
+        // Frames start one window before the input so every sample gets full overlap.
+        let mut start = -(FFT_SIZE as isize) + HOP_SIZE as isize;
+        while start < input.len() as isize {
+            let bins = self.analyze(input, start);
+
+            // Analysis: magnitude and true frequency (in bins) for every bin.
+            let mut shifted_magnitude = vec![0.0; num_bins];
+            let mut shifted_frequency = vec![0.0; num_bins];
+            for (k, bin) in bins.iter().enumerate() {
+                let phase = bin.arg();
+                let deviation = wrap_phase(phase - last_phase[k] - k as f64 * expected_advance);
+                last_phase[k] = phase;
+                let true_bin = k as f64 + deviation / expected_advance;
+
+                let target = (k as f64 * ratio).round() as usize;
+                if target < num_bins {
+                    shifted_magnitude[target] += bin.norm();
+                    shifted_frequency[target] = true_bin * ratio;
+                }
+            }
+
+            // Synthesis: accumulate phase from the shifted frequencies.
+            let frame: Vec<Complex<f64>> = (0..num_bins)
+                .map(|k| {
+                    phase_sum[k] =
+                        wrap_phase(phase_sum[k] + shifted_frequency[k] * expected_advance);
+                    Complex::from_polar(shifted_magnitude[k], phase_sum[k])
+                })
+                .collect();
+            self.synthesize(&frame, &mut output, start);
+
+            start += HOP_SIZE as isize;
+        }
+
+        output
+    }
+
+    /// Sustains the spectrum centred on sample `capture` from that point on.
+    fn freeze(&self, input: &[f64], capture: usize) -> Vec<f64> {
+        let num_bins = FFT_SIZE / 2 + 1;
+        let expected_advance = 2.0 * PI * HOP_SIZE as f64 / FFT_SIZE as f64;
+
+        // Two consecutive analysis frames give each bin's true phase advance per hop.
+        let capture_start = capture as isize - (FFT_SIZE / 2) as isize;
+        let first = self.analyze(input, capture_start);
+        let second = self.analyze(input, capture_start + HOP_SIZE as isize);
+        let advance: Vec<f64> = (0..num_bins)
+            .map(|k| {
+                let nominal = k as f64 * expected_advance;
+                nominal + wrap_phase(second[k].arg() - first[k].arg() - nominal)
+            })
+            .collect();
+
+        // Resynthesize the captured frame at every hop, starting a full window
+        // before the capture point so the frozen signal is at steady state there.
+        let mut frozen = vec![0.0; input.len()];
+        let mut hop = -(OVERLAP as isize) + 1;
+        loop {
+            let start = capture_start + hop * HOP_SIZE as isize;
+            if start >= input.len() as isize {
+                break;
+            }
+            let frame: Vec<Complex<f64>> = first
+                .iter()
+                .zip(&advance)
+                .map(|(bin, &advance)| {
+                    Complex::from_polar(bin.norm(), bin.arg() + advance * hop as f64)
+                })
+                .collect();
+            self.synthesize(&frame, &mut frozen, start);
+            hop += 1;
+        }
+
+        let mut output = input.to_vec();
+        for (i, sample) in output.iter_mut().enumerate().skip(capture) {
+            let fade = ((i - capture) as f64 / FREEZE_CROSSFADE as f64).min(1.0);
+            *sample = *sample * (1.0 - fade) + frozen[i] * fade;
+        }
+        output
+    }
+}
+
+/// Wraps a phase to [-PI, PI].
+fn wrap_phase(phase: f64) -> f64 {
+    phase - 2.0 * PI * (phase / (2.0 * PI)).round()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_spectrum::rms;
+
+    const SAMPLE_RATE: f64 = 44100.0;
+
+    fn sine(frequency: f64, num_samples: usize) -> Vec<f64> {
+        (0..num_samples)
+            .map(|i| 0.5 * (2.0 * PI * frequency * i as f64 / SAMPLE_RATE).sin())
+            .collect()
+    }
+
+    /// Fundamental estimated from upward zero crossings in `samples`.
+    fn zero_crossing_frequency(samples: &[f64]) -> f64 {
+        let crossings: Vec<f64> = samples
+            .windows(2)
+            .enumerate()
+            .filter(|(_, w)| w[0] < 0.0 && w[1] >= 0.0)
+            .map(|(i, w)| i as f64 + w[0] / (w[0] - w[1]))
+            .collect();
+        let span = crossings.last().unwrap() - crossings.first().unwrap();
+        (crossings.len() - 1) as f64 * SAMPLE_RATE / span
+    }
+
+    #[test]
+    fn test_pitch_shift_zero_is_near_noop() {
+        let input = sine(440.0, 22050);
+        let mut stereo = StereoOutput {
+            left: input.clone(),
+            right: input.clone(),
+        };
+
+        apply_pitch_shift(&mut stereo, 0.0).unwrap();
+
+        assert_eq!(stereo.left.len(), input.len());
+        let max_error = stereo
+            .left
+            .iter()
+            .zip(&input)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0_f64, f64::max);
+        assert!(max_error < 1e-6, "max error {}", max_error);
+    }
+
+    #[test]
+    fn test_pitch_shift_octave_doubles_fundamental() {
+        let input = sine(440.0, 44100);
+        let mut stereo = StereoOutput {
+            left: input.clone(),
+            right: input,
+        };
+
+        apply_pitch_shift(&mut stereo, 12.0).unwrap();
+
+        // Skip the edges, where frames only partially overlap the signal.
+        let interior = &stereo.left[FFT_SIZE..stereo.left.len() - FFT_SIZE];
+        let fundamental = zero_crossing_frequency(interior);
+        assert!(
+            (fundamental - 880.0).abs() < 5.0,
+            "expected ~880 Hz, got {}",
+            fundamental
+        );
+    }
+
+    #[test]
+    fn test_pitch_shift_down_octave_halves_fundamental() {
+        let input = sine(440.0, 44100);
+        let mut stereo = StereoOutput {
+            left: input.clone(),
+            right: input,
+        };
+
+        apply_pitch_shift(&mut stereo, -12.0).unwrap();
+
+        let interior = &stereo.left[FFT_SIZE..stereo.left.len() - FFT_SIZE];
+        let fundamental = zero_crossing_frequency(interior);
+        assert!(
+            (fundamental - 220.0).abs() < 5.0,
+            "expected ~220 Hz, got {}",
+            fundamental
+        );
+    }
+
+    #[test]
+    fn test_pitch_shift_parameter_validation() {
+        let mut stereo = StereoOutput {
+            left: vec![0.0; 100],
+            right: vec![0.0; 100],
+        };
+        assert!(apply_pitch_shift(&mut stereo, 25.0).is_err());
+        assert!(apply_pitch_shift(&mut stereo, -25.0).is_err());
+    }
+
+    #[test]
+    fn test_freeze_sustains_captured_spectrum() {
+        // Tone for the first half, silence for the second.
+        let mut input = sine(440.0, 44100);
+        for sample in input.iter_mut().skip(22050) {
+            *sample = 0.0;
+        }
+        let mut stereo = StereoOutput {
+            left: input.clone(),
+            right: input.clone(),
+        };
+
+        apply_freeze(&mut stereo, 0.25, SAMPLE_RATE).unwrap();
+
+        // Audio before the capture point is untouched.
+        assert_eq!(stereo.left[..11025], input[..11025]);
+
+        // The tone keeps sounding after the input went silent.
+        let source_level = rms(&input[8000..11000]);
+        let frozen_level = rms(&stereo.left[33000..36000]);
+        assert!(
+            (frozen_level / source_level - 1.0).abs() < 0.1,
+            "frozen level {} vs source {}",
+            frozen_level,
+            source_level
+        );
+        let fundamental = zero_crossing_frequency(&stereo.left[30000..40000]);
+        assert!(
+            (fundamental - 440.0).abs() < 5.0,
+            "expected ~440 Hz, got {}",
+            fundamental
+        );
+    }
+
+    #[test]
+    fn test_freeze_parameter_validation() {
+        let mut stereo = StereoOutput {
+            left: vec![0.0; 100],
+            right: vec![0.0; 100],
+        };
+        assert!(apply_freeze(&mut stereo, -0.1, SAMPLE_RATE).is_err());
+        // Capture points past the end are clamped.
+        assert!(apply_freeze(&mut stereo, 10.0, SAMPLE_RATE).is_ok());
+    }
+
+    #[test]
+    fn test_phase_vocoder_determinism() {
+        let input = sine(330.0, 8820);
+        let mut stereo1 = StereoOutput {
+            left: input.clone(),
+            right: input.clone(),
+        };
+        let mut stereo2 = stereo1.clone();
+
+        apply_pitch_shift(&mut stereo1, 7.0).unwrap();
+        apply_pitch_shift(&mut stereo2, 7.0).unwrap();
+        apply_freeze(&mut stereo1, 0.1, SAMPLE_RATE).unwrap();
+        apply_freeze(&mut stereo2, 0.1, SAMPLE_RATE).unwrap();
+
+        assert_eq!(stereo1.left, stereo2.left);
+        assert_eq!(stereo1.right, stereo2.right);
+    }
+
+    #[test]
+    fn test_phase_vocoder_empty_input() {
+        let mut stereo = StereoOutput {
+            left: vec![],
+            right: vec![],
+        };
+        assert!(apply_pitch_shift(&mut stereo, 5.0).is_ok());
+        assert!(apply_freeze(&mut stereo, 0.0, SAMPLE_RATE).is_ok());
+        assert!(stereo.left.is_empty());
+    }
+}
@@ -1,15 +1,21 @@
 //! Freeverb-style reverb effect.
 //!
 //! Implementation of the Freeverb algorithm with 8 parallel comb filters
-//! and 4 serial allpass filters.
+//! and 4 serial allpass filters. Filter lengths are offset by a few samples
+//! drawn from the spec seed, so the network is fixed for a given seed.
+
+use rand::Rng;
 
 use crate::error::{AudioError, AudioResult};
 use crate::mixer::StereoOutput;
+use crate::rng::{create_rng, derive_component_seed};
 
 // Freeverb tuning constants (in samples at 44.1kHz)
 const COMB_TUNINGS: [usize; 8] = [1116, 1188, 1277, 1356, 1422, 1491, 1557, 1617];
 const ALLPASS_TUNINGS: [usize; 4] = [556, 441, 341, 225];
 const STEREO_SPREAD: usize = 23;
+/// Maximum seeded offset added to each filter length (in samples at 44.1kHz).
+const MAX_TUNING_OFFSET: usize = 8;
 
 const FIXED_GAIN: f64 = 0.015;
 const SCALE_WET: f64 = 3.0;
@@ -119,25 +125,36 @@ struct Freeverb {
 }
 
 impl Freeverb {
-    fn new(sample_rate: f64) -> Self {
+    fn new(sample_rate: f64, seed: u32) -> Self {
         let scale = sample_rate / 44100.0;
 
-        let combs_left: Vec<_> = COMB_TUNINGS
+        // Left and right filters share an offset so the stereo spread is unchanged
+        let mut rng = create_rng(derive_component_seed(seed, "reverb_tuning"));
+        let comb_tunings: Vec<usize> = COMB_TUNINGS
+            .iter()
+            .map(|&size| size + rng.gen_range(0..=MAX_TUNING_OFFSET))
+            .collect();
+        let allpass_tunings: Vec<usize> = ALLPASS_TUNINGS
+            .iter()
+            .map(|&size| size + rng.gen_range(0..=MAX_TUNING_OFFSET))
+            .collect();
+
+        let combs_left: Vec<_> = comb_tunings
             .iter()
             .map(|&size| CombFilter::new((size as f64 * scale) as usize))
             .collect();
 
-        let combs_right: Vec<_> = COMB_TUNINGS
+        let combs_right: Vec<_> = comb_tunings
             .iter()
             .map(|&size| CombFilter::new(((size + STEREO_SPREAD) as f64 * scale) as usize))
             .collect();
 
-        let allpasses_left: Vec<_> = ALLPASS_TUNINGS
+        let allpasses_left: Vec<_> = allpass_tunings
             .iter()
             .map(|&size| AllpassFilter::new((size as f64 * scale) as usize))
             .collect();
 
-        let allpasses_right: Vec<_> = ALLPASS_TUNINGS
+        let allpasses_right: Vec<_> = allpass_tunings
             .iter()
             .map(|&size| AllpassFilter::new(((size + STEREO_SPREAD) as f64 * scale) as usize))
             .collect();
@@ -241,18 +258,33 @@ impl Freeverb {
 }
 
 /// Applies reverb effect to stereo audio.
+///
+/// A mono signal (identical channels) comes out with a decorrelated stereo
+/// tail when `width > 0`.
+#[allow(clippy::too_many_arguments)]
 pub fn apply(
     stereo: &mut StereoOutput,
     room_size: f64,
     damping: f64,
     wet: f64,
+    dry: f64,
     width: f64,
     sample_rate: f64,
+    seed: u32,
 ) -> AudioResult<()> {
     // Create a constant room_size curve for the non-modulated case
     let num_samples = stereo.left.len();
     let room_size_curve = vec![room_size; num_samples];
-    apply_with_modulation(stereo, &room_size_curve, damping, wet, width, sample_rate)
+    apply_with_modulation(
+        stereo,
+        &room_size_curve,
+        damping,
+        wet,
+        dry,
+        width,
+        sample_rate,
+        seed,
+    )
 }
 
 /// Applies reverb effect to stereo audio with per-sample room_size modulation.
@@ -261,16 +293,21 @@ pub fn apply(
 /// * `stereo` - Stereo audio to process
 /// * `room_size_curve` - Per-sample room size values (0.0-1.0)
 /// * `damping` - High-frequency absorption (0.0-1.0)
-/// * `wet` - Wet/dry mix (0.0-1.0)
+/// * `wet` - Wet level (0.0-1.0)
+/// * `dry` - Dry level (0.0-1.0)
 /// * `width` - Stereo width (0.0-1.0)
 /// * `sample_rate` - Sample rate in Hz
+/// * `seed` - RNG seed for the filter length offsets
+#[allow(clippy::too_many_arguments)]
 pub fn apply_with_modulation(
     stereo: &mut StereoOutput,
     room_size_curve: &[f64],
     damping: f64,
     wet: f64,
+    dry: f64,
     width: f64,
     sample_rate: f64,
+    seed: u32,
 ) -> AudioResult<()> {
     // Validate parameters
     if !(0.0..=1.0).contains(&damping) {
@@ -285,6 +322,12 @@ pub fn apply_with_modulation(
             format!("must be 0.0-1.0, got {}", wet),
         ));
     }
+    if !(0.0..=1.0).contains(&dry) {
+        return Err(AudioError::invalid_param(
+            "reverb.dry",
+            format!("must be 0.0-1.0, got {}", dry),
+        ));
+    }
     if !(0.0..=1.0).contains(&width) {
         return Err(AudioError::invalid_param(
             "reverb.width",
@@ -292,11 +335,11 @@ pub fn apply_with_modulation(
         ));
     }
 
-    let mut reverb = Freeverb::new(sample_rate);
+    let mut reverb = Freeverb::new(sample_rate, seed);
     reverb.set_damping(damping);
     reverb.set_wet(wet);
     reverb.set_width(width);
-    reverb.set_dry(1.0 - wet);
+    reverb.set_dry(dry);
     reverb.clear();
 
     let num_samples = stereo.left.len();
@@ -1,14 +1,14 @@
 //! Ring modulator effect implementation.
 //!
-//! Multiplies input audio with a carrier sine oscillator to produce
+//! Multiplies input audio with a carrier oscillator to produce
 //! sum and difference frequencies (sidebands). Creates metallic,
 //! robotic, and sci-fi timbres.
 
+use speccade_spec::recipe::audio::Waveform;
+
 use crate::error::{AudioError, AudioResult};
 use crate::mixer::StereoOutput;
-use std::f64::consts::PI;
-
-const TWO_PI: f64 = 2.0 * PI;
+use crate::oscillator::{sawtooth, sine, square, triangle, TWO_PI};
 
 /// Applies ring modulator effect to stereo audio.
 ///
@@ -20,19 +20,23 @@ const TWO_PI: f64 = 2.0 * PI;
 /// # Arguments
 /// * `stereo` - Stereo audio to process in-place
 /// * `frequency` - Carrier oscillator frequency in Hz (20-2000 typical)
+/// * `waveform` - Carrier waveform (`Pulse` uses a 50% duty cycle)
 /// * `mix` - Wet/dry mix (0.0 = dry input, 1.0 = full ring mod)
 /// * `sample_rate` - Sample rate in Hz
 ///
 /// # Algorithm
 /// For each sample:
 /// ```text
-/// carrier = sin(2 * PI * frequency * time)
+/// carrier = waveform(2 * PI * frequency * time)
 /// modulated = input * carrier
 /// output = mix * modulated + (1 - mix) * input
 /// ```
+///
+/// A mix of 0.0 leaves the input untouched, bit for bit.
 pub fn apply(
     stereo: &mut StereoOutput,
     frequency: f64,
+    waveform: Waveform,
     mix: f64,
     sample_rate: f64,
 ) -> AudioResult<()> {
@@ -51,7 +55,7 @@ pub fn apply(
     }
 
     let num_samples = stereo.left.len();
-    if num_samples == 0 {
+    if num_samples == 0 || mix == 0.0 {
         return Ok(());
     }
 
@@ -63,7 +67,7 @@ pub fn apply(
 
     for i in 0..num_samples {
         // Generate carrier oscillator
-        let carrier = (TWO_PI * phase).sin();
+        let carrier = carrier_sample(waveform, TWO_PI * phase);
 
         // Apply ring modulation to both channels
         let wet_left = stereo.left[i] * carrier;
@@ -83,9 +87,21 @@ pub fn apply(
     Ok(())
 }
 
+/// Carrier sample at `phase` radians.
+#[inline]
+fn carrier_sample(waveform: Waveform, phase: f64) -> f64 {
+    match waveform {
+        Waveform::Sine => sine(phase),
+        Waveform::Square | Waveform::Pulse => square(phase, 0.5),
+        Waveform::Sawtooth => sawtooth(phase),
+        Waveform::Triangle => triangle(phase),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_spectrum::{bin, power_spectrum};
 
     #[test]
     fn test_ring_mod_basic() {
@@ -104,7 +120,7 @@ mod tests {
         };
 
         // Apply ring modulator effect
-        apply(&mut stereo, 150.0, 0.8, sample_rate).unwrap();
+        apply(&mut stereo, 150.0, Waveform::Sine, 0.8, sample_rate).unwrap();
 
         // Output should have same length
         assert_eq!(stereo.left.len(), num_samples);
@@ -130,16 +146,67 @@ mod tests {
             right: samples,
         };
 
-        // With mix=0, output should equal input
-        apply(&mut stereo, 500.0, 0.0, sample_rate).unwrap();
+        // With mix=0, output should equal input bit for bit
+        apply(&mut stereo, 500.0, Waveform::Square, 0.0, sample_rate).unwrap();
 
-        for (i, (&out, &orig)) in stereo.left.iter().zip(original.iter()).enumerate() {
-            assert!(
-                (out - orig).abs() < 1e-10,
-                "Dry signal should pass through unchanged at sample {}",
-                i
-            );
-        }
+        assert_eq!(stereo.left, original);
+        assert_eq!(stereo.right, original);
+    }
+
+    /// Magnitude of the Hann-windowed spectrum at the bin nearest `frequency`.
+    fn magnitude_at(samples: &[f64], frequency: f64, sample_rate: f64) -> f64 {
+        power_spectrum(samples)[bin(frequency, samples.len(), sample_rate)].sqrt()
+    }
+
+    #[test]
+    fn test_ring_mod_produces_sum_and_difference_sidebands() {
+        let sample_rate = 44100.0;
+        let num_samples = 44100;
+
+        let samples: Vec<f64> = (0..num_samples)
+            .map(|i| (TWO_PI * 440.0 * i as f64 / sample_rate).sin() * 0.5)
+            .collect();
+        let mut stereo = StereoOutput {
+            left: samples.clone(),
+            right: samples,
+        };
+
+        apply(&mut stereo, 100.0, Waveform::Sine, 1.0, sample_rate).unwrap();
+
+        let lower = magnitude_at(&stereo.left, 340.0, sample_rate);
+        let upper = magnitude_at(&stereo.left, 540.0, sample_rate);
+        let input = magnitude_at(&stereo.left, 440.0, sample_rate);
+        let carrier = magnitude_at(&stereo.left, 100.0, sample_rate);
+
+        // Sidebands carry equal energy; input and carrier are suppressed.
+        assert!((lower - upper).abs() / upper < 0.01);
+        assert!(
+            input < lower * 1e-3,
+            "input {} vs sideband {}",
+            input,
+            lower
+        );
+        assert!(carrier < lower * 1e-3);
+    }
+
+    #[test]
+    fn test_ring_mod_carrier_waveforms_differ() {
+        let sample_rate = 44100.0;
+        let render = |waveform: Waveform| {
+            let mut stereo = StereoOutput {
+                left: vec![0.5; 2205],
+                right: vec![0.5; 2205],
+            };
+            apply(&mut stereo, 100.0, waveform, 1.0, sample_rate).unwrap();
+            stereo.left
+        };
+
+        let sine_out = render(Waveform::Sine);
+        let square_out = render(Waveform::Square);
+        assert_ne!(sine_out, square_out);
+        assert!(square_out.iter().all(|&s| (s.abs() - 0.5).abs() < 1e-12));
+        assert_eq!(square_out, render(Waveform::Pulse));
+        assert_ne!(render(Waveform::Triangle), render(Waveform::Sawtooth));
     }
 
     #[test]
@@ -156,7 +223,7 @@ mod tests {
         };
 
         // Full wet ring mod
-        apply(&mut stereo, 100.0, 1.0, sample_rate).unwrap();
+        apply(&mut stereo, 100.0, Waveform::Sine, 1.0, sample_rate).unwrap();
 
         // Output should oscillate at carrier frequency
         // Check that it's not constant
@@ -189,7 +256,7 @@ mod tests {
             right: samples,
         };
 
-        apply(&mut stereo, 150.0, 0.7, sample_rate).unwrap();
+        apply(&mut stereo, 150.0, Waveform::Sine, 0.7, sample_rate).unwrap();
 
         // With identical input, output should also be identical
         for i in 0..num_samples {
@@ -208,17 +275,17 @@ mod tests {
         };
 
         // Frequency too low
-        assert!(apply(&mut stereo, 0.5, 0.5, 44100.0).is_err());
+        assert!(apply(&mut stereo, 0.5, Waveform::Sine, 0.5, 44100.0).is_err());
 
         // Frequency too high
-        assert!(apply(&mut stereo, 25000.0, 0.5, 44100.0).is_err());
+        assert!(apply(&mut stereo, 25000.0, Waveform::Sine, 0.5, 44100.0).is_err());
 
         // Mix out of range
-        assert!(apply(&mut stereo, 500.0, -0.1, 44100.0).is_err());
-        assert!(apply(&mut stereo, 500.0, 1.5, 44100.0).is_err());
+        assert!(apply(&mut stereo, 500.0, Waveform::Sine, -0.1, 44100.0).is_err());
+        assert!(apply(&mut stereo, 500.0, Waveform::Sine, 1.5, 44100.0).is_err());
 
         // Valid parameters should work
-        assert!(apply(&mut stereo, 500.0, 0.5, 44100.0).is_ok());
+        assert!(apply(&mut stereo, 500.0, Waveform::Sine, 0.5, 44100.0).is_ok());
     }
 
     #[test]
@@ -240,8 +307,8 @@ mod tests {
             right: samples,
         };
 
-        apply(&mut stereo1, 200.0, 0.6, sample_rate).unwrap();
-        apply(&mut stereo2, 200.0, 0.6, sample_rate).unwrap();
+        apply(&mut stereo1, 200.0, Waveform::Sine, 0.6, sample_rate).unwrap();
+        apply(&mut stereo2, 200.0, Waveform::Sine, 0.6, sample_rate).unwrap();
 
         // Results should be identical
         for i in 0..num_samples {
@@ -264,7 +331,7 @@ mod tests {
         };
 
         // Should handle empty input gracefully
-        assert!(apply(&mut stereo, 500.0, 0.5, 44100.0).is_ok());
+        assert!(apply(&mut stereo, 500.0, Waveform::Sine, 0.5, 44100.0).is_ok());
         assert!(stereo.left.is_empty());
         assert!(stereo.right.is_empty());
     }
@@ -0,0 +1,124 @@
+//! Sidechain ducking.
+//!
+//! Turns the level of a source signal into a gain curve for another signal,
+//! like a compressor whose detector listens to a different input.
+
+use crate::error::{AudioError, AudioResult};
+
+/// Computes the ducking gain driven by `source`.
+///
+/// # Arguments
+/// * `source` - Detector input, one value per output sample
+/// * `threshold_db` - Source level above which ducking starts (-60 to 0)
+/// * `ratio` - Reduction ratio (1.0-20.0)
+/// * `attack_ms` - Detector attack time (0.1-100)
+/// * `release_ms` - Detector release time (10-1000)
+/// * `sample_rate` - Sample rate in Hz
+///
+/// # Algorithm
+/// ```text
+/// env = peak follower of |source| (attack when rising, release when falling)
+/// over_db = max(0, 20 * log10(env) - threshold_db)
+/// gain = 10^(-over_db * (1 - 1 / ratio) / 20)
+/// ```
+///
+/// Returns one linear gain in (0, 1] per source sample.
+pub fn duck_gain(
+    source: &[f64],
+    threshold_db: f64,
+    ratio: f64,
+    attack_ms: f64,
+    release_ms: f64,
+    sample_rate: f64,
+) -> AudioResult<Vec<f64>> {
+    if !(-60.0..=0.0).contains(&threshold_db) {
+        return Err(AudioError::invalid_param(
+            "sidechain.threshold_db",
+            format!("must be -60 to 0, got {}", threshold_db),
+        ));
+    }
+    if !(1.0..=20.0).contains(&ratio) {
+        return Err(AudioError::invalid_param(
+            "sidechain.ratio",
+            format!("must be 1.0-20.0, got {}", ratio),
+        ));
+    }
+    if !(0.1..=100.0).contains(&attack_ms) {
+        return Err(AudioError::invalid_param(
+            "sidechain.attack_ms",
+            format!("must be 0.1-100, got {}", attack_ms),
+        ));
+    }
+    if !(10.0..=1000.0).contains(&release_ms) {
+        return Err(AudioError::invalid_param(
+            "sidechain.release_ms",
+            format!("must be 10-1000, got {}", release_ms),
+        ));
+    }
+
+    let attack_coeff = (-1.0 / (attack_ms * 0.001 * sample_rate)).exp();
+    let release_coeff = (-1.0 / (release_ms * 0.001 * sample_rate)).exp();
+    let slope = 1.0 - 1.0 / ratio;
+
+    let mut envelope = 0.0;
+    Ok(source
+        .iter()
+        .map(|&sample| {
+            let level = sample.abs();
+            let coeff = if level > envelope {
+                attack_coeff
+            } else {
+                release_coeff
+            };
+            envelope = coeff * envelope + (1.0 - coeff) * level;
+
+            let envelope_db = 20.0 * envelope.max(1e-10).log10();
+            let over_db = (envelope_db - threshold_db).max(0.0);
+            10.0_f64.powf(-over_db * slope / 20.0)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duck_gain_silent_source_is_unity() {
+        let gain = duck_gain(&[0.0; 1000], -30.0, 8.0, 1.0, 100.0, 44100.0).unwrap();
+        assert!(gain.iter().all(|&g| g == 1.0));
+    }
+
+    #[test]
+    fn test_duck_gain_follows_ratio() {
+        // A steady source 20 dB over the threshold settles at 20 * (1 - 1/4) = 15 dB down.
+        let gain = duck_gain(&[1.0; 44100], -20.0, 4.0, 1.0, 100.0, 44100.0).unwrap();
+        let settled_db = 20.0 * gain.last().unwrap().log10();
+        assert!(
+            (settled_db + 15.0).abs() < 0.01,
+            "settled at {} dB",
+            settled_db
+        );
+    }
+
+    #[test]
+    fn test_duck_gain_recovers_after_source_stops() {
+        let mut source = vec![1.0; 4410];
+        source.extend(vec![0.0; 44100]);
+        let gain = duck_gain(&source, -20.0, 10.0, 1.0, 50.0, 44100.0).unwrap();
+
+        assert!(gain[4409] < 0.2);
+        // Well past the release time the gain is back to unity.
+        assert!(*gain.last().unwrap() > 0.999);
+    }
+
+    #[test]
+    fn test_duck_gain_parameter_validation() {
+        let source = [0.5; 100];
+        assert!(duck_gain(&source, 3.0, 4.0, 1.0, 100.0, 44100.0).is_err());
+        assert!(duck_gain(&source, -20.0, 0.5, 1.0, 100.0, 44100.0).is_err());
+        assert!(duck_gain(&source, -20.0, 4.0, 0.0, 100.0, 44100.0).is_err());
+        assert!(duck_gain(&source, -20.0, 4.0, 1.0, 5000.0, 44100.0).is_err());
+        assert!(duck_gain(&source, -20.0, 4.0, 1.0, 100.0, 44100.0).is_ok());
+    }
+}
@@ -0,0 +1,149 @@
+//! Tremolo effect implementation.
+//!
+//! Modulates the level of both channels with a low-frequency oscillator.
+
+use speccade_spec::recipe::audio::Waveform;
+
+use crate::error::{AudioError, AudioResult};
+use crate::mixer::StereoOutput;
+use crate::modulation::lfo::Lfo;
+use crate::rng::{create_rng, derive_component_seed};
+
+/// Applies tremolo to stereo audio.
+///
+/// # Arguments
+/// * `stereo` - Stereo audio to process in-place
+/// * `rate` - LFO rate in Hz (0.1-20.0)
+/// * `depth` - Modulation depth (0.0-1.0)
+/// * `shape` - LFO waveform
+/// * `sample_rate` - Sample rate in Hz
+/// * `seed` - RNG seed for the LFO
+///
+/// # Algorithm
+/// ```text
+/// lfo = shape(rate, t) in [0, 1]
+/// gain = (1 - depth) + depth * lfo
+/// ```
+///
+/// A depth of 0.0 leaves the input untouched, bit for bit.
+pub fn apply(
+    stereo: &mut StereoOutput,
+    rate: f64,
+    depth: f64,
+    shape: Waveform,
+    sample_rate: f64,
+    seed: u32,
+) -> AudioResult<()> {
+    if !(0.1..=20.0).contains(&rate) {
+        return Err(AudioError::invalid_param(
+            "tremolo.rate",
+            format!("must be 0.1-20.0 Hz, got {}", rate),
+        ));
+    }
+    if !(0.0..=1.0).contains(&depth) {
+        return Err(AudioError::invalid_param(
+            "tremolo.depth",
+            format!("must be 0.0-1.0, got {}", depth),
+        ));
+    }
+
+    if stereo.left.is_empty() || depth == 0.0 {
+        return Ok(());
+    }
+
+    let mut rng = create_rng(derive_component_seed(seed, "tremolo"));
+    let mut lfo = Lfo::new(shape, rate, sample_rate, 0.0);
+
+    for (left, right) in stereo.left.iter_mut().zip(stereo.right.iter_mut()) {
+        let gain = (1.0 - depth) + depth * lfo.next_sample(&mut rng);
+        *left *= gain;
+        *right *= gain;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn constant(num_samples: usize) -> StereoOutput {
+        StereoOutput {
+            left: vec![0.5; num_samples],
+            right: vec![0.5; num_samples],
+        }
+    }
+
+    #[test]
+    fn test_tremolo_dips_at_lfo_rate() {
+        let sample_rate = 44100.0;
+        let rate = 5.0;
+        let mut stereo = constant(44100);
+
+        apply(&mut stereo, rate, 1.0, Waveform::Sine, sample_rate, 42).unwrap();
+
+        // One dip per LFO period, each reaching near silence.
+        let period = (sample_rate / rate) as usize;
+        let mut dips = Vec::new();
+        for (i, chunk) in stereo.left.chunks(period).enumerate() {
+            let (offset, &min) = chunk
+                .iter()
+                .enumerate()
+                .min_by(|a, b| a.1.total_cmp(b.1))
+                .unwrap();
+            assert!(min < 0.01, "period {} min {}", i, min);
+            dips.push(i * period + offset);
+        }
+        assert_eq!(dips.len(), 5);
+        for pair in dips.windows(2) {
+            assert!((pair[1] - pair[0]).abs_diff(period) <= 1);
+        }
+
+        let max = stereo.left.iter().fold(0.0_f64, |a, &b| a.max(b));
+        assert!(max > 0.49);
+        assert_eq!(stereo.left, stereo.right);
+    }
+
+    #[test]
+    fn test_tremolo_depth_limits_dip() {
+        let mut stereo = constant(44100);
+        apply(&mut stereo, 4.0, 0.5, Waveform::Triangle, 44100.0, 42).unwrap();
+
+        let min = stereo.left.iter().fold(f64::MAX, |a, &b| a.min(b));
+        assert!((min - 0.25).abs() < 1e-3, "min {}", min);
+    }
+
+    #[test]
+    fn test_tremolo_zero_depth_bypasses() {
+        let samples: Vec<f64> = (0..1000).map(|i| (i as f64 * 0.01).sin()).collect();
+        let mut stereo = StereoOutput {
+            left: samples.clone(),
+            right: samples.clone(),
+        };
+
+        apply(&mut stereo, 6.0, 0.0, Waveform::Square, 44100.0, 42).unwrap();
+
+        assert_eq!(stereo.left, samples);
+        assert_eq!(stereo.right, samples);
+    }
+
+    #[test]
+    fn test_tremolo_deterministic() {
+        let mut a = constant(4410);
+        let mut b = constant(4410);
+        apply(&mut a, 7.0, 0.8, Waveform::Sawtooth, 44100.0, 7).unwrap();
+        apply(&mut b, 7.0, 0.8, Waveform::Sawtooth, 44100.0, 7).unwrap();
+        assert_eq!(a.left, b.left);
+        assert_eq!(a.right, b.right);
+    }
+
+    #[test]
+    fn test_tremolo_parameter_validation() {
+        let mut stereo = constant(100);
+        assert!(apply(&mut stereo, 0.05, 0.5, Waveform::Sine, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 25.0, 0.5, Waveform::Sine, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 5.0, -0.1, Waveform::Sine, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 5.0, 1.5, Waveform::Sine, 44100.0, 0).is_err());
+        assert!(apply(&mut stereo, 5.0, 0.5, Waveform::Sine, 44100.0, 0).is_ok());
+    }
+}
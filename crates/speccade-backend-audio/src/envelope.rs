@@ -1,7 +1,29 @@
 //! ADSR envelope generator.
 //!
 //! This module provides an Attack-Decay-Sustain-Release envelope generator
-//! for shaping the amplitude of audio signals over time.
+//! for shaping the amplitude of audio signals over time, plus a DAHDSR variant
+//! with a leading delay and a hold plateau. Attack, decay and release stages
+//! can each be shaped by an [`EnvelopeCurve`].
+
+pub use speccade_spec::recipe::audio::EnvelopeCurve;
+
+/// Steepness of the exponential and logarithmic stage curves.
+const EXP_CURVE_STEEPNESS: f64 = 4.0;
+
+/// Maps normalized stage progress `t` (0..1) through `curve`.
+///
+/// The result is also 0..1 and hits both endpoints exactly; linear returns `t`
+/// unchanged so unshaped envelopes render bit-identically.
+pub fn shape_stage(curve: EnvelopeCurve, t: f64) -> f64 {
+    let t = t.clamp(0.0, 1.0);
+    let exp = |t: f64| (EXP_CURVE_STEEPNESS * t).exp_m1() / EXP_CURVE_STEEPNESS.exp_m1();
+    match curve {
+        EnvelopeCurve::Linear => t,
+        EnvelopeCurve::Exponential => exp(t),
+        EnvelopeCurve::Logarithmic => 1.0 - exp(1.0 - t),
+        EnvelopeCurve::Power(exponent) => t.powf(exponent.max(f32::MIN_POSITIVE) as f64),
+    }
+}
 
 /// ADSR envelope parameters.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -14,16 +36,17 @@ pub struct AdsrParams {
     pub sustain: f64,
     /// Release time in seconds.
     pub release: f64,
+    /// Curve of the attack stage.
+    pub attack_curve: EnvelopeCurve,
+    /// Curve of the decay stage.
+    pub decay_curve: EnvelopeCurve,
+    /// Curve of the release stage.
+    pub release_curve: EnvelopeCurve,
 }
 
 impl Default for AdsrParams {
     fn default() -> Self {
-        Self {
-            attack: 0.01,
-            decay: 0.1,
-            sustain: 0.5,
-            release: 0.2,
-        }
+        Self::new(0.01, 0.1, 0.5, 0.2)
     }
 }
 
@@ -35,9 +58,25 @@ impl AdsrParams {
             decay: decay.max(0.0),
             sustain: sustain.clamp(0.0, 1.0),
             release: release.max(0.0),
+            attack_curve: EnvelopeCurve::Linear,
+            decay_curve: EnvelopeCurve::Linear,
+            release_curve: EnvelopeCurve::Linear,
         }
     }
 
+    /// Sets the attack, decay and release curves.
+    pub fn with_curves(
+        mut self,
+        attack: EnvelopeCurve,
+        decay: EnvelopeCurve,
+        release: EnvelopeCurve,
+    ) -> Self {
+        self.attack_curve = attack;
+        self.decay_curve = decay;
+        self.release_curve = release;
+        self
+    }
+
     /// Creates a percussive envelope (no sustain).
     pub fn percussive(attack: f64, decay: f64) -> Self {
         Self {
@@ -45,6 +84,7 @@ impl AdsrParams {
             decay,
             sustain: 0.0,
             release: decay,
+            ..Self::default()
         }
     }
 
@@ -55,6 +95,7 @@ impl AdsrParams {
             decay,
             sustain: 0.0,
             release: decay,
+            ..Self::default()
         }
     }
 
@@ -65,6 +106,7 @@ impl AdsrParams {
             decay: 0.0,
             sustain: 1.0,
             release,
+            ..Self::default()
         }
     }
 }
@@ -141,8 +183,9 @@ impl AdsrEnvelope {
         match self.state {
             EnvelopeState::Attack => {
                 if self.params.attack > 0.0 {
-                    self.level = self.time / self.params.attack;
-                    if self.level >= 1.0 {
+                    let progress = self.time / self.params.attack;
+                    self.level = shape_stage(self.params.attack_curve, progress);
+                    if progress >= 1.0 {
                         self.level = 1.0;
                         self.state = EnvelopeState::Decay;
                         self.time = 0.0;
@@ -158,7 +201,9 @@ impl AdsrEnvelope {
             EnvelopeState::Decay => {
                 if self.params.decay > 0.0 {
                     let progress = self.time / self.params.decay;
-                    self.level = 1.0 - progress * (1.0 - self.params.sustain);
+                    self.level = 1.0
+                        - shape_stage(self.params.decay_curve, progress)
+                            * (1.0 - self.params.sustain);
                     if progress >= 1.0 {
                         self.level = self.params.sustain;
                         self.state = EnvelopeState::Sustain;
@@ -179,7 +224,8 @@ impl AdsrEnvelope {
             EnvelopeState::Release => {
                 if self.params.release > 0.0 {
                     let progress = self.time / self.params.release;
-                    self.level = self.release_level * (1.0 - progress);
+                    self.level = self.release_level
+                        * (1.0 - shape_stage(self.params.release_curve, progress));
                     if progress >= 1.0 {
                         self.level = 0.0;
                         self.state = EnvelopeState::Idle;
@@ -242,6 +288,77 @@ impl AdsrEnvelope {
     }
 }
 
+/// DAHDSR envelope parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DahdsrParams {
+    /// Silence before the attack, in seconds.
+    pub delay: f64,
+    /// Attack time in seconds.
+    pub attack: f64,
+    /// Time held at peak before the decay, in seconds.
+    pub hold: f64,
+    /// Decay time in seconds.
+    pub decay: f64,
+    /// Sustain level (0.0 to 1.0).
+    pub sustain: f64,
+    /// Release time in seconds.
+    pub release: f64,
+    /// Curve of the attack stage.
+    pub attack_curve: EnvelopeCurve,
+    /// Curve of the decay stage.
+    pub decay_curve: EnvelopeCurve,
+    /// Curve of the release stage.
+    pub release_curve: EnvelopeCurve,
+}
+
+impl DahdsrParams {
+    /// Generates a DAHDSR curve of `num_samples` samples.
+    ///
+    /// Stage lengths are rounded to whole samples, so each stage boundary lands
+    /// on an exact sample index. The release starts `release` seconds before the
+    /// end, but never before the decay has finished.
+    pub fn generate(&self, sample_rate: f64, num_samples: usize) -> Vec<f64> {
+        let samples = |seconds: f64| (seconds.max(0.0) * sample_rate).round() as usize;
+        let attack_start = samples(self.delay);
+        let hold_start = attack_start + samples(self.attack);
+        let decay_start = hold_start + samples(self.hold);
+        let sustain_start = decay_start + samples(self.decay);
+        let release_len = samples(self.release);
+        let release_start = num_samples.saturating_sub(release_len).max(sustain_start);
+        let sustain = self.sustain.clamp(0.0, 1.0);
+
+        let level_before_release = |i: usize| {
+            if i < attack_start {
+                0.0
+            } else if i < hold_start {
+                let t = (i - attack_start) as f64 / (hold_start - attack_start) as f64;
+                shape_stage(self.attack_curve, t)
+            } else if i < decay_start {
+                1.0
+            } else if i < sustain_start {
+                let t = (i - decay_start) as f64 / (sustain_start - decay_start) as f64;
+                1.0 - shape_stage(self.decay_curve, t) * (1.0 - sustain)
+            } else {
+                sustain
+            }
+        };
+        let release_level = level_before_release(release_start);
+
+        (0..num_samples)
+            .map(|i| {
+                if i < release_start {
+                    level_before_release(i)
+                } else if release_len == 0 {
+                    0.0
+                } else {
+                    let t = (i - release_start) as f64 / release_len as f64;
+                    (release_level * (1.0 - shape_stage(self.release_curve, t))).max(0.0)
+                }
+            })
+            .collect()
+    }
+}
+
 /// Simple exponential decay envelope.
 ///
 /// Useful for percussive sounds where only decay matters.
@@ -443,4 +560,142 @@ mod tests {
         // Should end at 0
         assert!(envelope[199].abs() < 0.02);
     }
+
+    #[test]
+    fn test_dahdsr_segment_boundaries() {
+        // 1 kHz so every stage is a whole number of samples
+        let params = DahdsrParams {
+            delay: 0.010,
+            attack: 0.020,
+            hold: 0.015,
+            decay: 0.020,
+            sustain: 0.5,
+            release: 0.010,
+            attack_curve: EnvelopeCurve::Linear,
+            decay_curve: EnvelopeCurve::Linear,
+            release_curve: EnvelopeCurve::Linear,
+        };
+        let env = params.generate(1000.0, 100);
+        assert_eq!(env.len(), 100);
+
+        // Delay: silent through sample 9, attack ramp starts from zero at 10
+        assert!(env[..10].iter().all(|&v| v == 0.0));
+        assert_eq!(env[10], 0.0);
+        assert!(env[11] > 0.0);
+
+        // Attack reaches the peak exactly at sample 30, then holds for 15 samples
+        assert!(env[29] < 1.0);
+        assert!(env[30..45].iter().all(|&v| v == 1.0));
+
+        // Decay starts at 45 and lands on sustain at 65
+        assert!(env[46] < 1.0);
+        assert!(env[64] > 0.5);
+        assert!(env[65..90].iter().all(|&v| v == 0.5));
+
+        // Release covers the last 10 samples
+        assert!(env[90] == 0.5);
+        assert!(env[91] < 0.5);
+        assert!(env[99] > 0.0 && env[99] < 0.1);
+    }
+
+    #[test]
+    fn test_dahdsr_without_delay_or_hold_is_adsr_shaped() {
+        let params = DahdsrParams {
+            delay: 0.0,
+            attack: 0.0,
+            hold: 0.0,
+            decay: 0.0,
+            sustain: 1.0,
+            release: 0.0,
+            attack_curve: EnvelopeCurve::Linear,
+            decay_curve: EnvelopeCurve::Linear,
+            release_curve: EnvelopeCurve::Linear,
+        };
+        let env = params.generate(1000.0, 50);
+        assert!(env.iter().all(|&v| v == 1.0));
+    }
+
+    #[test]
+    fn test_shape_stage_endpoints_and_linear_identity() {
+        for curve in [
+            EnvelopeCurve::Linear,
+            EnvelopeCurve::Exponential,
+            EnvelopeCurve::Logarithmic,
+            EnvelopeCurve::Power(0.5),
+            EnvelopeCurve::Power(3.0),
+        ] {
+            assert!(shape_stage(curve, 0.0).abs() < 1e-12);
+            assert!((shape_stage(curve, 1.0) - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(shape_stage(EnvelopeCurve::Linear, 0.37), 0.37);
+        assert!(shape_stage(EnvelopeCurve::Exponential, 0.5) < 0.5);
+        assert!(shape_stage(EnvelopeCurve::Logarithmic, 0.5) > 0.5);
+    }
+
+    #[test]
+    fn test_power_attack_reaches_half_before_midpoint() {
+        let sample_rate = 1000.0;
+        for exponent in [0.25, 0.5, 0.8] {
+            let params = AdsrParams::new(0.1, 0.0, 1.0, 0.0).with_curves(
+                EnvelopeCurve::Power(exponent),
+                EnvelopeCurve::Linear,
+                EnvelopeCurve::Linear,
+            );
+            let env = AdsrEnvelope::generate_fixed_duration(&params, sample_rate, 0.2);
+            let first_half = env.iter().position(|&v| v >= 0.5).unwrap();
+            assert!(
+                first_half < 50,
+                "exponent {exponent}: reached 0.5 at {first_half}"
+            );
+
+            let dahdsr = DahdsrParams {
+                delay: 0.0,
+                attack: 0.1,
+                hold: 0.0,
+                decay: 0.0,
+                sustain: 1.0,
+                release: 0.0,
+                attack_curve: EnvelopeCurve::Power(exponent),
+                decay_curve: EnvelopeCurve::Linear,
+                release_curve: EnvelopeCurve::Linear,
+            }
+            .generate(sample_rate, 200);
+            let first_half = dahdsr.iter().position(|&v| v >= 0.5).unwrap();
+            assert!(
+                first_half < 50,
+                "exponent {exponent}: reached 0.5 at {first_half}"
+            );
+        }
+
+        // A linear attack only reaches 0.5 at the midpoint
+        let linear = AdsrEnvelope::generate_fixed_duration(
+            &AdsrParams::new(0.1, 0.0, 1.0, 0.0),
+            sample_rate,
+            0.2,
+        );
+        assert_eq!(linear.iter().position(|&v| v >= 0.5), Some(50));
+    }
+
+    #[test]
+    fn test_curved_decay_and_release_keep_their_endpoints() {
+        let params = DahdsrParams {
+            delay: 0.0,
+            attack: 0.0,
+            hold: 0.0,
+            decay: 0.05,
+            sustain: 0.25,
+            release: 0.05,
+            attack_curve: EnvelopeCurve::Linear,
+            decay_curve: EnvelopeCurve::Logarithmic,
+            release_curve: EnvelopeCurve::Exponential,
+        };
+        let env = params.generate(1000.0, 200);
+        // Logarithmic decay drops faster than linear early on
+        assert!(env[10] < 1.0 - 0.2 * 0.75);
+        assert_eq!(env[50], 0.25);
+        assert_eq!(env[149], 0.25);
+        // Exponential release lingers above the linear ramp
+        assert!(env[175] > 0.25 * 0.5);
+        assert!(env[199] < 0.02);
+    }
 }
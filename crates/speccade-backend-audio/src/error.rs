@@ -62,6 +62,13 @@ pub enum AudioError {
         /// Error message.
         message: String,
     },
+
+    /// Output encoding error.
+    #[error("encoding error: {message}")]
+    Encoding {
+        /// Error message.
+        message: String,
+    },
 }
 
 impl AudioError {
@@ -92,6 +99,7 @@ impl BackendError for AudioError {
             AudioError::InvalidParameter { .. } => "AUDIO_006",
             AudioError::Io(_) => "AUDIO_007",
             AudioError::Synthesis { .. } => "AUDIO_008",
+            AudioError::Encoding { .. } => "AUDIO_009",
         }
     }
 
@@ -423,6 +423,15 @@ impl DcBlocker {
         Self::new(0.995)
     }
 
+    /// Creates a DC blocker with the given -3 dB cutoff.
+    ///
+    /// # Arguments
+    /// * `cutoff` - Cutoff frequency in Hz
+    /// * `sample_rate` - Audio sample rate in Hz
+    pub fn with_cutoff(cutoff: f64, sample_rate: f64) -> Self {
+        Self::new((-2.0 * PI * cutoff / sample_rate).exp())
+    }
+
     /// Resets the filter state.
     pub fn reset(&mut self) {
         self.x1 = 0.0;
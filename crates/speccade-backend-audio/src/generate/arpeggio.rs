@@ -0,0 +1,137 @@
+//! Arpeggiator: steps an oscillator layer through semitone offsets.
+
+use rand::Rng;
+use speccade_spec::recipe::audio::{Arpeggio, ArpeggioMode, AudioLayer, Synthesis};
+
+use crate::error::{AudioError, AudioResult};
+use crate::rng::{create_rng, derive_component_seed};
+
+use super::modulation;
+
+/// One arpeggio step, starting at `start` (relative to the layer start).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArpeggioStep {
+    /// First sample of the step.
+    pub start: usize,
+    /// Semitone offset played during the step.
+    pub semitones: f64,
+}
+
+/// Validates the arpeggio on `layer` for backend rendering.
+fn validate_arpeggio(layer: &AudioLayer, arp: &Arpeggio, layer_idx: usize) -> AudioResult<()> {
+    let param = format!("layers[{}].arpeggio", layer_idx);
+    if !matches!(
+        layer.synthesis,
+        Synthesis::Oscillator { .. } | Synthesis::MultiOscillator { .. }
+    ) {
+        return Err(AudioError::invalid_param(
+            param,
+            "requires oscillator or multi_oscillator synthesis",
+        ));
+    }
+    if arp.pattern.is_empty() || arp.pattern.iter().any(|s| !s.is_finite()) {
+        return Err(AudioError::invalid_param(
+            param,
+            "pattern must be a non-empty list of finite semitone offsets",
+        ));
+    }
+    if !arp.rate_hz.is_finite() || arp.rate_hz <= 0.0 {
+        return Err(AudioError::invalid_param(
+            param,
+            format!("rate_hz must be positive, got {}", arp.rate_hz),
+        ));
+    }
+    if !arp.gate.is_finite() || arp.gate <= 0.0 || arp.gate > 1.0 {
+        return Err(AudioError::invalid_param(
+            param,
+            format!("gate must be in (0, 1], got {}", arp.gate),
+        ));
+    }
+    Ok(())
+}
+
+/// Computes the arpeggio steps covering `num_samples`.
+///
+/// Step `k` starts at `floor(k * sample_rate / rate_hz)`. `Random` mode draws
+/// each step from a PCG32 stream derived from `seed`.
+pub fn arpeggio_steps(
+    arp: &Arpeggio,
+    num_samples: usize,
+    sample_rate: f64,
+    seed: u32,
+) -> Vec<ArpeggioStep> {
+    let mut sorted = arp.pattern.clone();
+    sorted.sort_by(f64::total_cmp);
+
+    let cycle: Vec<f64> = match arp.mode {
+        ArpeggioMode::Up | ArpeggioMode::Random => sorted,
+        ArpeggioMode::Down => sorted.into_iter().rev().collect(),
+        ArpeggioMode::UpDown => {
+            let inner = sorted.len().saturating_sub(2);
+            let descent: Vec<f64> = sorted.iter().rev().skip(1).take(inner).copied().collect();
+            sorted.into_iter().chain(descent).collect()
+        }
+    };
+
+    let mut rng = create_rng(derive_component_seed(seed, "arpeggio"));
+    let step_len = sample_rate / arp.rate_hz;
+    let mut steps = Vec::new();
+    for k in 0.. {
+        let start = (k as f64 * step_len).floor();
+        if start >= num_samples as f64 {
+            break;
+        }
+        let semitones = match arp.mode {
+            ArpeggioMode::Random => cycle[rng.gen_range(0..cycle.len())],
+            _ => cycle[k % cycle.len()],
+        };
+        steps.push(ArpeggioStep {
+            start: start as usize,
+            semitones,
+        });
+    }
+    steps
+}
+
+/// Renders an arpeggiated oscillator layer (without envelope).
+///
+/// Returns the raw samples and, when `gate < 1`, a per-step retriggered
+/// envelope to use in place of the layer envelope.
+pub fn render_arpeggio(
+    layer: &AudioLayer,
+    arp: &Arpeggio,
+    layer_idx: usize,
+    num_samples: usize,
+    sample_rate: f64,
+    seed: u32,
+) -> AudioResult<(Vec<f64>, Option<Vec<f64>>)> {
+    validate_arpeggio(layer, arp, layer_idx)?;
+
+    let steps = arpeggio_steps(arp, num_samples, sample_rate, seed);
+    let mut pitch_curve = vec![1.0; num_samples];
+    let mut envelope = (arp.gate < 1.0).then(|| vec![0.0; num_samples]);
+
+    for (i, step) in steps.iter().enumerate() {
+        let end = steps.get(i + 1).map_or(num_samples, |next| next.start);
+        let multiplier = 2.0_f64.powf(step.semitones / 12.0);
+        pitch_curve[step.start..end].fill(multiplier);
+
+        if let Some(env) = envelope.as_mut() {
+            let gated = ((end - step.start) as f64 * arp.gate).round() as usize;
+            let step_env = modulation::generate_envelope(&layer.envelope, sample_rate, gated);
+            for (out, value) in env[step.start..step.start + gated].iter_mut().zip(step_env) {
+                *out = value;
+            }
+        }
+    }
+
+    let samples = modulation::apply_pitch_envelope_to_layer_samples(
+        layer,
+        layer_idx,
+        &pitch_curve,
+        num_samples,
+        sample_rate,
+        seed,
+    )?;
+    Ok((samples, envelope))
+}
@@ -1,13 +1,122 @@
 //! Filter application and processing.
 
-use speccade_spec::recipe::audio::Filter;
+use std::borrow::Cow;
+
+use speccade_spec::recipe::audio::{AudioLayer, Filter};
 
 use crate::filter::{
-    generate_cutoff_sweep, BiquadCoeffs, BiquadFilter, CombFilter, FormantFilter, LadderFilter,
-    SweepMode,
+    generate_cutoff_sweep, BiquadCoeffs, BiquadFilter, CombFilter, DcBlocker, FormantFilter,
+    LadderFilter, SweepMode,
 };
 use crate::synthesis::noise::NoiseSynth;
 
+/// Cutoff of the master DC blocker in Hz.
+///
+/// Low enough to leave audible content untouched while settling within a few
+/// tens of milliseconds.
+const DC_BLOCKER_CUTOFF_HZ: f64 = 10.0;
+
+/// Reference note for filter keytracking when the recipe has no `base_note` (C4).
+pub const KEYTRACK_DEFAULT_REFERENCE_NOTE: u8 = 60;
+
+/// Returns the cutoff multiplier for a keytracked filter.
+///
+/// A keytrack of 1.0 moves the cutoff by the same interval as the note, 0.5 by
+/// half that interval, and 0.0 leaves it fixed.
+pub fn keytrack_ratio(keytrack: f64, note_freq: f64, reference_freq: f64) -> f64 {
+    (note_freq / reference_freq).powf(keytrack)
+}
+
+/// Returns a copy of `filter` with keytracking baked into its cutoff/center
+/// (including any sweep target).
+pub fn apply_keytrack(filter: &Filter, note_freq: f64, reference_freq: f64) -> Filter {
+    let ratio = keytrack_ratio(filter.keytrack(), note_freq, reference_freq);
+    match filter {
+        Filter::Lowpass {
+            cutoff,
+            resonance,
+            cutoff_end,
+            ..
+        } => Filter::Lowpass {
+            cutoff: cutoff * ratio,
+            resonance: *resonance,
+            cutoff_end: cutoff_end.map(|c| c * ratio),
+            keytrack: None,
+        },
+        Filter::Highpass {
+            cutoff,
+            resonance,
+            cutoff_end,
+            ..
+        } => Filter::Highpass {
+            cutoff: cutoff * ratio,
+            resonance: *resonance,
+            cutoff_end: cutoff_end.map(|c| c * ratio),
+            keytrack: None,
+        },
+        Filter::Bandpass {
+            center,
+            resonance,
+            center_end,
+            ..
+        } => Filter::Bandpass {
+            center: center * ratio,
+            resonance: *resonance,
+            center_end: center_end.map(|c| c * ratio),
+            keytrack: None,
+        },
+        Filter::Notch {
+            center,
+            resonance,
+            center_end,
+            ..
+        } => Filter::Notch {
+            center: center * ratio,
+            resonance: *resonance,
+            center_end: center_end.map(|c| c * ratio),
+            keytrack: None,
+        },
+        Filter::Ladder {
+            cutoff,
+            resonance,
+            cutoff_end,
+            ..
+        } => Filter::Ladder {
+            cutoff: cutoff * ratio,
+            resonance: *resonance,
+            cutoff_end: cutoff_end.map(|c| c * ratio),
+            keytrack: None,
+        },
+        other => other.clone(),
+    }
+}
+
+/// Resolves layer filter keytracking against the layer's pitch.
+///
+/// Layers without a keytracked filter (or without a pitch) are returned as-is,
+/// so a keytrack of 0.0 reproduces the untracked output exactly.
+pub fn resolve_layer_keytrack(layer: &AudioLayer, reference_freq: f64) -> Cow<'_, AudioLayer> {
+    let (Some(filter), Some(note_freq)) = (&layer.filter, layer.synthesis.fundamental_frequency())
+    else {
+        return Cow::Borrowed(layer);
+    };
+    if filter.keytrack() == 0.0 {
+        return Cow::Borrowed(layer);
+    }
+
+    let mut resolved = layer.clone();
+    resolved.filter = Some(apply_keytrack(filter, note_freq, reference_freq));
+    Cow::Owned(resolved)
+}
+
+/// Removes DC offset with a one-pole high-pass (DC blocker).
+pub fn apply_dc_blocker(samples: &mut [f64], sample_rate: f64) {
+    let mut blocker = DcBlocker::with_cutoff(DC_BLOCKER_CUTOFF_HZ, sample_rate);
+    for sample in samples.iter_mut() {
+        *sample = blocker.process(*sample);
+    }
+}
+
 /// Applies filter configuration to noise synthesizer.
 pub fn apply_noise_filter(mut synth: NoiseSynth, filter: &Filter) -> NoiseSynth {
     match filter {
@@ -72,6 +181,7 @@ pub fn apply_swept_filter(samples: &mut [f64], filter: &Filter, sample_rate: f64
             cutoff,
             resonance,
             cutoff_end,
+            ..
         } => {
             if let Some(end_cutoff) = cutoff_end {
                 // Generate cutoff sweep
@@ -100,6 +210,7 @@ pub fn apply_swept_filter(samples: &mut [f64], filter: &Filter, sample_rate: f64
             cutoff,
             resonance,
             cutoff_end,
+            ..
         } => {
             if let Some(end_cutoff) = cutoff_end {
                 // Generate cutoff sweep
@@ -128,6 +239,7 @@ pub fn apply_swept_filter(samples: &mut [f64], filter: &Filter, sample_rate: f64
             center,
             resonance,
             center_end,
+            ..
         } => {
             if let Some(end_center) = center_end {
                 // Generate center frequency sweep
@@ -157,6 +269,7 @@ pub fn apply_swept_filter(samples: &mut [f64], filter: &Filter, sample_rate: f64
             center,
             resonance,
             center_end,
+            ..
         } => {
             if let Some(end_center) = center_end {
                 // Generate center frequency sweep
@@ -231,6 +344,7 @@ pub fn apply_swept_filter(samples: &mut [f64], filter: &Filter, sample_rate: f64
             cutoff,
             resonance,
             cutoff_end,
+            ..
         } => {
             if let Some(end_cutoff) = cutoff_end {
                 // Generate cutoff sweep
@@ -0,0 +1,170 @@
+//! Per-layer intermediates for debugging audio_v1 renders.
+//!
+//! Inspection renders every layer exactly as [`super::generate`] does, but stops
+//! before the master stages so each layer's contribution can be listened to on
+//! its own.
+
+use speccade_spec::recipe::audio::AudioV1Params;
+use speccade_spec::Spec;
+
+use super::{modulation, render_mix_layers, resolve_base_note, validated_num_samples};
+use crate::error::{AudioError, AudioResult};
+use crate::mixer::{Mixer, MixerOutput};
+
+/// Headroom applied to the dry mix, matching the final output normalization.
+const INSPECT_HEADROOM_DB: f64 = -3.0;
+
+/// Per-layer intermediates of an audio_v1 render.
+#[derive(Debug)]
+pub struct AudioInspection {
+    /// Output sample rate in Hz.
+    pub sample_rate: u32,
+    /// One entry per spec layer, in spec order.
+    pub layers: Vec<InspectedLayer>,
+    /// Sum of all layer contributions.
+    ///
+    /// Equals the final output when the spec has no master stages (master
+    /// filter, effects, DC blocker, channel mode change, loop crossfade, or
+    /// tail fade).
+    pub mix: MixerOutput,
+}
+
+/// Intermediates of a single spec layer.
+#[derive(Debug)]
+pub struct InspectedLayer {
+    /// Index of the layer in the spec.
+    pub index: usize,
+    /// The layer's contribution to the mix, after volume, pan, gain automation,
+    /// and delay. Uses the channel layout of the mix. None when the layer is
+    /// muted or another layer is soloed.
+    pub output: Option<MixerOutput>,
+    /// Amplitude envelope over the whole output, zero during the layer delay.
+    pub envelope: Vec<f64>,
+}
+
+/// Inspects an audio_v1 spec, rendering each layer before mixing.
+///
+/// Layer contributions and the mix share one gain, chosen so the mix peaks at
+/// -3 dBFS like the final output. Summing the layer outputs reproduces `mix`.
+///
+/// # Arguments
+/// * `spec` - The specification containing audio parameters
+///
+/// # Returns
+/// Per-layer outputs, envelopes, and the dry mix
+pub fn inspect(spec: &Spec) -> AudioResult<AudioInspection> {
+    let recipe = spec.recipe.as_ref().ok_or(AudioError::MissingRecipe)?;
+
+    match recipe.kind.as_str() {
+        "audio_v1" => {
+            let params: AudioV1Params =
+                serde_json::from_value(recipe.params.clone()).map_err(|e| {
+                    AudioError::InvalidRecipeType {
+                        expected: "audio_v1".to_string(),
+                        found: format!("{}: {}", recipe.kind, e),
+                    }
+                })?;
+            inspect_params(&params, spec.seed)
+        }
+        _ => Err(AudioError::InvalidRecipeType {
+            expected: "audio_v1".to_string(),
+            found: recipe.kind.clone(),
+        }),
+    }
+}
+
+/// Inspects unified AudioV1Params, rendering each layer before mixing.
+pub fn inspect_params(params: &AudioV1Params, seed: u32) -> AudioResult<AudioInspection> {
+    let num_samples = validated_num_samples(params)?;
+    let sample_rate = params.sample_rate as f64;
+    let base_note_midi = resolve_base_note(params);
+
+    let mix_layers = render_mix_layers(params, seed, num_samples, sample_rate, base_note_midi)?;
+
+    let mut mixer = Mixer::new(num_samples, sample_rate);
+    for (_, mix_layer) in &mix_layers {
+        mixer.add_layer(mix_layer.clone());
+    }
+    let stereo = mixer.is_stereo();
+    let mut mix = mixer.mix();
+    let gain = normalization_gain(&mix);
+    scale(&mut mix, gain);
+
+    let layers = params
+        .layers
+        .iter()
+        .enumerate()
+        .map(|(index, layer)| {
+            let mut layer_mixer = Mixer::new(num_samples, sample_rate);
+            for (_, mix_layer) in mix_layers
+                .iter()
+                .filter(|(idx, mix_layer)| *idx == index && mixer.is_audible(mix_layer))
+            {
+                layer_mixer.add_layer(mix_layer.clone().with_solo(false));
+            }
+
+            let output = (layer_mixer.audible_layers().count() > 0).then(|| {
+                let mut output = if stereo {
+                    MixerOutput::Stereo(layer_mixer.mix_stereo())
+                } else {
+                    MixerOutput::Mono(layer_mixer.mix_mono())
+                };
+                scale(&mut output, gain);
+                output
+            });
+
+            let delay_samples = layer
+                .delay
+                .map(|delay| (delay.max(0.0) * sample_rate).floor() as usize)
+                .unwrap_or(0)
+                .min(num_samples);
+            let mut envelope = vec![0.0; delay_samples];
+            envelope.extend(modulation::generate_envelope(
+                &layer.envelope,
+                sample_rate,
+                num_samples - delay_samples,
+            ));
+
+            InspectedLayer {
+                index,
+                output,
+                envelope,
+            }
+        })
+        .collect();
+
+    Ok(AudioInspection {
+        sample_rate: params.sample_rate,
+        layers,
+        mix,
+    })
+}
+
+/// Returns the gain that brings the peak of `output` to the inspection headroom.
+fn normalization_gain(output: &MixerOutput) -> f64 {
+    let peak = match output {
+        MixerOutput::Mono(samples) => samples.iter().fold(0.0_f64, |a, s| a.max(s.abs())),
+        MixerOutput::Stereo(stereo) => stereo
+            .left
+            .iter()
+            .chain(stereo.right.iter())
+            .fold(0.0_f64, |a, s| a.max(s.abs())),
+    };
+    if peak > 0.0 {
+        10.0_f64.powf(INSPECT_HEADROOM_DB / 20.0) / peak
+    } else {
+        1.0
+    }
+}
+
+/// Multiplies every sample of `output` by `gain`.
+fn scale(output: &mut MixerOutput, gain: f64) {
+    match output {
+        MixerOutput::Mono(samples) => samples.iter_mut().for_each(|s| *s *= gain),
+        MixerOutput::Stereo(stereo) => stereo
+            .left
+            .iter_mut()
+            .chain(stereo.right.iter_mut())
+            .for_each(|s| *s *= gain),
+    }
+}
@@ -1,8 +1,11 @@
 //! Layer generation and synthesis dispatching.
 
-use speccade_spec::recipe::audio::{AudioLayer, Filter, ModulationTarget, Synthesis};
+use speccade_spec::recipe::audio::{
+    AudioLayer, Filter, ModulationDestination, ModulationTarget, Synthesis,
+};
 
 use crate::error::{AudioError, AudioResult};
+use crate::modulation::ModulationMatrix;
 use crate::rng::create_rng;
 
 /// Output from layer generation, supporting both mono and stereo sources.
@@ -63,6 +66,7 @@ use crate::synthesis::pitched_body::PitchedBody;
 use crate::synthesis::pulsar::PulsarSynth;
 use crate::synthesis::ring_mod::RingModSynth;
 use crate::synthesis::spectral::SpectralFreezeSynth;
+use crate::synthesis::transient::TransientSynth;
 use crate::synthesis::vector::{
     VectorPath, VectorPathPoint as VectorPathPointImpl, VectorPosition,
     VectorSource as VectorSourceImpl, VectorSynth,
@@ -73,6 +77,7 @@ use crate::synthesis::waveguide::WaveguideSynth;
 use crate::synthesis::wavetable::{PositionSweep as WavetablePositionSweep, WavetableSynth};
 use crate::synthesis::{FrequencySweep, Synthesizer};
 
+use super::arpeggio;
 use super::converters::*;
 use super::filters;
 use super::modulation;
@@ -240,14 +245,23 @@ pub fn generate_layer(
             frequency,
             freq_sweep,
             duty,
+            anti_alias,
+            detune_voices,
+            detune_spread,
             ..
         } => oscillators::generate_oscillator_samples(
-            waveform,
-            *frequency,
-            freq_sweep.as_ref(),
-            *duty,
+            &oscillators::OscillatorParams {
+                waveform,
+                frequency: *frequency,
+                freq_sweep: freq_sweep.as_ref(),
+                duty: *duty,
+                anti_alias: anti_alias.unwrap_or_default(),
+                detune_voices: detune_voices.unwrap_or(1),
+                detune_spread: detune_spread.unwrap_or(0.0),
+            },
             synthesis_samples,
             sample_rate,
+            seed,
             &mut rng,
         ),
 
@@ -304,6 +318,7 @@ pub fn generate_layer(
 
         Synthesis::Wavetable {
             table,
+            tables,
             frequency,
             position,
             position_sweep,
@@ -316,7 +331,11 @@ pub fn generate_layer(
                 curve: convert_sweep_curve(&ps.curve),
             });
 
-            let synth = WavetableSynth::new(*table, *frequency, *position, sweep, *voices, *detune);
+            let synth = if tables.is_empty() {
+                WavetableSynth::new(*table, *frequency, *position, sweep, *voices, *detune)
+            } else {
+                WavetableSynth::from_frames(tables, *frequency, *position, sweep, *voices, *detune)
+            };
             synth.synthesize(synthesis_samples, sample_rate, &mut rng)
         }
 
@@ -491,8 +510,20 @@ pub fn generate_layer(
             bow_pressure,
             bow_position,
             damping,
+            bow_velocity,
+            freq_sweep,
         } => {
-            let synth = BowedStringSynth::new(*frequency, *bow_pressure, *bow_position, *damping);
+            let mut synth =
+                BowedStringSynth::new(*frequency, *bow_pressure, *bow_position, *damping);
+
+            if let Some(bow_velocity) = bow_velocity {
+                synth = synth.with_bow_velocity(*bow_velocity);
+            }
+            if let Some(sweep) = freq_sweep {
+                let curve = convert_sweep_curve(&sweep.curve);
+                synth = synth.with_sweep(FrequencySweep::new(*frequency, sweep.end_freq, curve));
+            }
+
             synth.synthesize(synthesis_samples, sample_rate, &mut rng)
         }
 
@@ -555,6 +586,15 @@ pub fn generate_layer(
             let synth = SpectralFreezeSynth::new(source.clone());
             synth.synthesize(synthesis_samples, sample_rate, &mut rng)
         }
+
+        Synthesis::Transient {
+            sharpness,
+            pitch,
+            noise_mix,
+        } => {
+            let synth = TransientSynth::new(*sharpness, *pitch, *noise_mix);
+            synth.synthesize(synthesis_samples, sample_rate, &mut rng)
+        }
     };
 
     // Handle stereo granular separately - it has interleaved samples [L, R, L, R, ...]
@@ -596,6 +636,15 @@ pub fn generate_layer(
     }
 
     // Standard mono processing path
+    // Re-render with the arpeggio pitch sequence if specified
+    let mut arpeggio_envelope = None;
+    if let Some(ref arp) = layer.arpeggio {
+        let (arp_samples, gated_envelope) =
+            arpeggio::render_arpeggio(layer, arp, layer_idx, synthesis_samples, sample_rate, seed)?;
+        samples = arp_samples;
+        arpeggio_envelope = gated_envelope;
+    }
+
     // Apply LFO modulation if specified
     if let Some(ref lfo_mod) = layer.lfo {
         use crate::modulation::lfo::{apply_volume_modulation, Lfo};
@@ -719,6 +768,21 @@ pub fn generate_layer(
                     },
                 );
             }
+            ModulationTarget::WavetablePosition { amount } => {
+                // Wavetable position modulation re-renders the morph per sample.
+                // Only valid for Wavetable synthesis.
+                samples = modulation::apply_lfo_wavetable_position_modulation(
+                    modulation::LfoWavetablePositionParams {
+                        layer,
+                        num_samples: synthesis_samples,
+                        sample_rate,
+                        lfo: &mut lfo,
+                        amount: *amount,
+                        depth: lfo_mod.config.depth,
+                        rng: &mut rng,
+                    },
+                );
+            }
             ModulationTarget::DelayTime { .. }
             | ModulationTarget::ReverbSize { .. }
             | ModulationTarget::DistortionDrive { .. } => {
@@ -728,6 +792,18 @@ pub fn generate_layer(
         }
     }
 
+    // Apply modulation matrix pitch routes by time-warping (all synthesis types)
+    let matrix = ModulationMatrix::new(&layer.modulation_matrix);
+    if let Some(semitones) = matrix.render(
+        ModulationDestination::Pitch,
+        synthesis_samples,
+        sample_rate,
+        seed,
+    ) {
+        let multipliers: Vec<f64> = semitones.iter().map(|s| 2.0_f64.powf(s / 12.0)).collect();
+        samples = modulation::apply_pitch_warp(&samples, &multipliers);
+    }
+
     // Apply layer filter if specified (and not already applied by LFO)
     if let Some(ref filter) = layer.filter {
         // Only apply if there's no filter cutoff LFO modulation
@@ -738,12 +814,39 @@ pub fn generate_layer(
             .unwrap_or(false);
 
         if !has_filter_lfo {
-            filters::apply_swept_filter(&mut samples, filter, sample_rate);
+            match matrix.render(
+                ModulationDestination::FilterCutoff,
+                synthesis_samples,
+                sample_rate,
+                seed,
+            ) {
+                Some(offsets) => modulation::apply_filter_cutoff_offsets(
+                    &mut samples,
+                    filter,
+                    &offsets,
+                    sample_rate,
+                ),
+                None => filters::apply_swept_filter(&mut samples, filter, sample_rate),
+            }
+        }
+    }
+
+    // Apply modulation matrix amplitude routes
+    if let Some(offsets) = matrix.render(
+        ModulationDestination::Amplitude,
+        synthesis_samples,
+        sample_rate,
+        seed,
+    ) {
+        for (sample, offset) in samples.iter_mut().zip(offsets) {
+            *sample *= (1.0 + offset).max(0.0);
         }
     }
 
-    // Apply envelope
-    let envelope = modulation::generate_envelope(&layer.envelope, sample_rate, synthesis_samples);
+    // Apply envelope (retriggered per step for a gated arpeggio)
+    let envelope = arpeggio_envelope.unwrap_or_else(|| {
+        modulation::generate_envelope(&layer.envelope, sample_rate, synthesis_samples)
+    });
     for (sample, env) in samples.iter_mut().zip(envelope.iter()) {
         *sample *= env;
     }
@@ -7,10 +7,11 @@ use speccade_spec::recipe::audio::{AudioLayer, Filter, Synthesis, Waveform};
 use crate::error::AudioResult;
 use crate::filter::{BiquadCoeffs, BiquadFilter, CombFilter, FormantFilter, LadderFilter};
 use crate::modulation::lfo::{
-    apply_filter_cutoff_modulation, apply_fm_index_modulation, apply_pitch_modulation_with_depth,
-    apply_pulse_width_modulation, Lfo,
+    apply_fm_index_modulation, apply_pitch_modulation_with_depth, apply_pulse_width_modulation,
+    apply_wavetable_position_modulation, Lfo,
 };
 use crate::oscillator::{PhaseAccumulator, TWO_PI};
+use crate::synthesis::wavetable::WavetableSynth;
 use crate::synthesis::FrequencySweep;
 
 use super::converters::convert_sweep_curve;
@@ -177,6 +178,20 @@ pub fn apply_lfo_pitch_warp(
         ));
     }
 
+    apply_pitch_warp(input, &multipliers)
+}
+
+/// Time-warps `input` by per-interval pitch multipliers.
+///
+/// `multipliers[i]` is the playback rate between samples `i` and `i + 1`. The
+/// rates are rescaled so the whole input is consumed, keeping the output length
+/// equal to the input length.
+pub fn apply_pitch_warp(input: &[f64], multipliers: &[f64]) -> Vec<f64> {
+    let n = input.len();
+    if n <= 1 {
+        return input.to_vec();
+    }
+
     let total: f64 = multipliers.iter().sum();
     let scale = if total.is_finite() && total > 0.0 {
         (n as f64 - 1.0) / total
@@ -188,7 +203,7 @@ pub fn apply_lfo_pitch_warp(
     output.push(input[0]);
 
     let mut pos = 0.0_f64;
-    for m in multipliers {
+    for &m in multipliers.iter().take(n - 1) {
         pos += m * scale;
         let pos = pos.clamp(0.0, n as f64 - 1.0);
 
@@ -447,6 +462,83 @@ pub fn apply_lfo_fm_index_modulation(params: LfoFmIndexParams<'_>) -> Vec<f64> {
     output
 }
 
+/// Parameters for LFO wavetable position modulation.
+pub struct LfoWavetablePositionParams<'a> {
+    /// The audio layer to modulate
+    pub layer: &'a AudioLayer,
+    /// Number of samples to generate
+    pub num_samples: usize,
+    /// Sample rate in Hz
+    pub sample_rate: f64,
+    /// LFO instance
+    pub lfo: &'a mut Lfo,
+    /// Position modulation amount
+    pub amount: f64,
+    /// Modulation depth (0.0-1.0)
+    pub depth: f64,
+    /// RNG for LFO
+    pub rng: &'a mut rand_pcg::Pcg32,
+}
+
+/// Applies LFO wavetable position modulation to a layer.
+///
+/// Only valid for `Synthesis::Wavetable`. Re-synthesizes the wavetable with a
+/// per-sample morph position offset around the base (or swept) position.
+pub fn apply_lfo_wavetable_position_modulation(params: LfoWavetablePositionParams<'_>) -> Vec<f64> {
+    let LfoWavetablePositionParams {
+        layer,
+        num_samples,
+        sample_rate,
+        lfo,
+        amount,
+        depth,
+        rng,
+    } = params;
+
+    match &layer.synthesis {
+        Synthesis::Wavetable {
+            table,
+            tables,
+            frequency,
+            position,
+            position_sweep,
+            voices,
+            detune,
+        } => {
+            let synth = if tables.is_empty() {
+                WavetableSynth::new(*table, *frequency, *position, None, *voices, *detune)
+            } else {
+                WavetableSynth::from_frames(tables, *frequency, *position, None, *voices, *detune)
+            };
+
+            let positions: Vec<f64> = (0..num_samples)
+                .map(|i| {
+                    let base = match position_sweep {
+                        Some(sweep) => {
+                            let t = i as f64 / num_samples.max(1) as f64;
+                            convert_sweep_curve(&sweep.curve).interpolate(
+                                *position,
+                                sweep.end_position,
+                                t,
+                            )
+                        }
+                        None => *position,
+                    };
+                    let lfo_value = lfo.next_sample(rng);
+                    apply_wavetable_position_modulation(base, lfo_value, amount, depth)
+                })
+                .collect();
+
+            synth.synthesize_with_positions(&positions, sample_rate)
+        }
+        _ => {
+            // Unsupported synthesis type - return silence
+            // (validation should have caught this)
+            vec![0.0; num_samples]
+        }
+    }
+}
+
 /// Applies LFO-modulated filter to a sample buffer.
 pub fn apply_lfo_filter_modulation(
     samples: &mut [f64],
@@ -457,16 +549,38 @@ pub fn apply_lfo_filter_modulation(
     sample_rate: f64,
     rng: &mut rand_pcg::Pcg32,
 ) {
+    // The LFO always advances once per sample, even for filters that ignore it,
+    // so its phase stays consistent.
+    let depth = depth.clamp(0.0, 1.0);
+    let offsets: Vec<f64> = (0..samples.len())
+        .map(|_| {
+            let bipolar = (lfo.next_sample(rng) - 0.5) * 2.0;
+            bipolar * amount * depth
+        })
+        .collect();
+    apply_filter_cutoff_offsets(samples, filter, &offsets, sample_rate);
+}
+
+/// Applies a filter whose cutoff (or center frequency) is offset per sample.
+///
+/// `offsets` are in Hz and are added to the filter's base frequency; the result
+/// is floored at 20 Hz. Comb, formant, and shelf filters do not support cutoff
+/// modulation and are applied statically.
+pub fn apply_filter_cutoff_offsets(
+    samples: &mut [f64],
+    filter: &Filter,
+    offsets: &[f64],
+    sample_rate: f64,
+) {
+    let modulated = |base: f64, i: usize| (base + offsets.get(i).copied().unwrap_or(0.0)).max(20.0);
+
     match filter {
         Filter::Lowpass {
             cutoff, resonance, ..
         } => {
             let mut filter_state = BiquadFilter::lowpass(*cutoff, *resonance, sample_rate);
-            for sample in samples.iter_mut() {
-                let lfo_value = lfo.next_sample(rng);
-                let modulated_cutoff =
-                    apply_filter_cutoff_modulation(*cutoff, lfo_value, amount, depth);
-                let coeffs = BiquadCoeffs::lowpass(modulated_cutoff, *resonance, sample_rate);
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let coeffs = BiquadCoeffs::lowpass(modulated(*cutoff, i), *resonance, sample_rate);
                 filter_state.set_coeffs(coeffs);
                 *sample = filter_state.process(*sample);
             }
@@ -475,11 +589,8 @@ pub fn apply_lfo_filter_modulation(
             cutoff, resonance, ..
         } => {
             let mut filter_state = BiquadFilter::highpass(*cutoff, *resonance, sample_rate);
-            for sample in samples.iter_mut() {
-                let lfo_value = lfo.next_sample(rng);
-                let modulated_cutoff =
-                    apply_filter_cutoff_modulation(*cutoff, lfo_value, amount, depth);
-                let coeffs = BiquadCoeffs::highpass(modulated_cutoff, *resonance, sample_rate);
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let coeffs = BiquadCoeffs::highpass(modulated(*cutoff, i), *resonance, sample_rate);
                 filter_state.set_coeffs(coeffs);
                 *sample = filter_state.process(*sample);
             }
@@ -489,11 +600,8 @@ pub fn apply_lfo_filter_modulation(
         } => {
             let q = *resonance;
             let mut filter_state = BiquadFilter::bandpass(*center, q, sample_rate);
-            for sample in samples.iter_mut() {
-                let lfo_value = lfo.next_sample(rng);
-                let modulated_center =
-                    apply_filter_cutoff_modulation(*center, lfo_value, amount, depth);
-                let coeffs = BiquadCoeffs::bandpass(modulated_center, q, sample_rate);
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let coeffs = BiquadCoeffs::bandpass(modulated(*center, i), q, sample_rate);
                 filter_state.set_coeffs(coeffs);
                 *sample = filter_state.process(*sample);
             }
@@ -503,11 +611,8 @@ pub fn apply_lfo_filter_modulation(
         } => {
             let q = *resonance;
             let mut filter_state = BiquadFilter::notch(*center, q, sample_rate);
-            for sample in samples.iter_mut() {
-                let lfo_value = lfo.next_sample(rng);
-                let modulated_center =
-                    apply_filter_cutoff_modulation(*center, lfo_value, amount, depth);
-                let coeffs = BiquadCoeffs::notch(modulated_center, q, sample_rate);
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let coeffs = BiquadCoeffs::notch(modulated(*center, i), q, sample_rate);
                 filter_state.set_coeffs(coeffs);
                 *sample = filter_state.process(*sample);
             }
@@ -520,11 +625,8 @@ pub fn apply_lfo_filter_modulation(
             let q = *resonance;
             let mut filter_state =
                 BiquadFilter::new(BiquadCoeffs::allpass(*frequency, q, sample_rate));
-            for sample in samples.iter_mut() {
-                let lfo_value = lfo.next_sample(rng);
-                let modulated_frequency =
-                    apply_filter_cutoff_modulation(*frequency, lfo_value, amount, depth);
-                let coeffs = BiquadCoeffs::allpass(modulated_frequency, q, sample_rate);
+            for (i, sample) in samples.iter_mut().enumerate() {
+                let coeffs = BiquadCoeffs::allpass(modulated(*frequency, i), q, sample_rate);
                 filter_state.set_coeffs(coeffs);
                 *sample = filter_state.process(*sample);
             }
@@ -534,62 +636,36 @@ pub fn apply_lfo_filter_modulation(
             feedback,
             wet,
         } => {
-            // Comb filter LFO modulation affects delay_ms.
-            // We modulate delay as a ratio similar to cutoff modulation.
-            // Recreating the filter each sample is expensive for comb (due to delay line),
-            // so we apply static comb filtering with the base parameters.
-            // LFO modulation on comb filters is not well-suited to per-sample changes.
-            // Instead, we just apply a static comb filter here.
+            // Recreating the comb filter each sample is expensive (due to the delay line)
+            // and not well-suited to per-sample changes, so it is applied statically.
             let mut filter = CombFilter::new(*delay_ms, *feedback, *wet, sample_rate);
             filter.process_buffer(samples);
-            // Advance LFO to maintain phase consistency
-            for _ in 0..samples.len() {
-                let _ = lfo.next_sample(rng);
-            }
         }
         Filter::Formant { vowel, intensity } => {
-            // Formant filter does not support LFO modulation (static only).
-            // Apply the static formant filter and advance LFO to maintain phase consistency.
+            // Formant filter does not support cutoff modulation (static only).
             let mut filter = FormantFilter::new(*vowel, *intensity, sample_rate);
             filter.process_buffer(samples);
-            // Advance LFO to maintain phase consistency
-            for _ in 0..samples.len() {
-                let _ = lfo.next_sample(rng);
-            }
         }
         Filter::Ladder {
             cutoff, resonance, ..
         } => {
             let mut filter_state = LadderFilter::new(*cutoff, *resonance, sample_rate);
-            for sample in samples.iter_mut() {
-                let lfo_value = lfo.next_sample(rng);
-                let modulated_cutoff =
-                    apply_filter_cutoff_modulation(*cutoff, lfo_value, amount, depth);
-                filter_state.set_cutoff(modulated_cutoff);
+            for (i, sample) in samples.iter_mut().enumerate() {
+                filter_state.set_cutoff(modulated(*cutoff, i));
                 *sample = filter_state.process(*sample);
             }
         }
         Filter::ShelfLow { frequency, gain_db } => {
-            // Shelf filters do not support LFO modulation (static only).
-            // Apply the static shelf filter and advance LFO to maintain phase consistency.
+            // Shelf filters do not support cutoff modulation (static only).
             let mut filter =
                 BiquadFilter::new(BiquadCoeffs::low_shelf(*frequency, *gain_db, sample_rate));
             filter.process_buffer(samples);
-            // Advance LFO to maintain phase consistency
-            for _ in 0..samples.len() {
-                let _ = lfo.next_sample(rng);
-            }
         }
         Filter::ShelfHigh { frequency, gain_db } => {
-            // Shelf filters do not support LFO modulation (static only).
-            // Apply the static shelf filter and advance LFO to maintain phase consistency.
+            // Shelf filters do not support cutoff modulation (static only).
             let mut filter =
                 BiquadFilter::new(BiquadCoeffs::high_shelf(*frequency, *gain_db, sample_rate));
             filter.process_buffer(samples);
-            // Advance LFO to maintain phase consistency
-            for _ in 0..samples.len() {
-                let _ = lfo.next_sample(rng);
-            }
         }
     }
 }
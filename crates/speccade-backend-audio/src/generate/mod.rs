@@ -2,8 +2,10 @@
 //!
 //! This module takes a spec and generates a WAV file deterministically.
 
+mod arpeggio;
 mod converters;
 mod filters;
+mod inspect;
 mod layer;
 mod lfo_granular;
 mod lfo_modulation;
@@ -13,13 +15,18 @@ mod oscillators;
 #[cfg(test)]
 mod tests;
 
-use speccade_spec::recipe::audio::AudioV1Params;
+use speccade_spec::recipe::audio::{AudioLayer, AudioV1Params, ChannelMode, ModulationDestination};
 use speccade_spec::Spec;
 
 use crate::error::{AudioError, AudioResult};
-use crate::mixer::{Layer, Mixer, MixerOutput};
-use crate::wav::WavResult;
-
+use crate::mixer::{
+    resample, Layer, LayerSamples, Mixer, MixerOutput, MonoCompatibility, StereoOutput,
+};
+use crate::modulation::ModulationMatrix;
+use crate::peak;
+use crate::wav::{apply_tail_fade, apply_tail_fade_stereo, TailFadeSettings, WavResult};
+
+pub use inspect::{inspect, inspect_params, AudioInspection, InspectedLayer};
 pub use layer::{generate_layer, LayerOutput};
 pub use modulation::{calculate_loop_point, generate_envelope};
 
@@ -28,6 +35,8 @@ pub use modulation::{calculate_loop_point, generate_envelope};
 pub struct GenerateResult {
     /// WAV file data.
     pub wav: WavResult,
+    /// Interleaved output samples before 16-bit quantization, for other encoders.
+    pub samples: Vec<f64>,
     /// Number of layers processed.
     pub num_layers: usize,
     /// Base note (MIDI note number) if this is an instrument sample.
@@ -38,6 +47,17 @@ pub struct GenerateResult {
     pub loop_end: Option<usize>,
     /// Whether loop points were snapped to zero crossings.
     pub loop_snapped_to_zero_crossing: bool,
+    /// Mono-compatibility of the mix, measured before `channels` is applied.
+    /// None when the mix is mono or silent.
+    pub mono_compatibility: Option<MonoCompatibility>,
+    /// Peak gain reduction of the master compressor in dB.
+    /// None when no master compressor is configured.
+    pub master_gain_reduction_db: Option<f64>,
+    /// Largest absolute sample value of the output in dBFS.
+    pub sample_peak_db: f64,
+    /// 4x-oversampled true (inter-sample) peak of the output in dBTP.
+    /// Can exceed `sample_peak_db` when the waveform overshoots between samples.
+    pub true_peak_db: f64,
 }
 
 /// Generates audio from a spec.
@@ -70,6 +90,10 @@ pub fn generate(spec: &Spec) -> AudioResult<GenerateResult> {
 
 /// Generates a preview of audio from a spec with truncated duration.
 ///
+/// The full spec is rendered and then cut to `preview_duration`, so the preview
+/// samples are identical to the head of the full output (envelopes, effects,
+/// normalization, and seeded randomness all see the full duration).
+///
 /// # Arguments
 /// * `spec` - The specification containing audio parameters
 /// * `preview_duration` - Maximum duration in seconds for the preview
@@ -81,7 +105,7 @@ pub fn generate_preview(spec: &Spec, preview_duration: f64) -> AudioResult<Gener
 
     match recipe.kind.as_str() {
         "audio_v1" => {
-            let mut params: AudioV1Params =
+            let params: AudioV1Params =
                 serde_json::from_value(recipe.params.clone()).map_err(|e| {
                     AudioError::InvalidRecipeType {
                         expected: "audio_v1".to_string(),
@@ -89,12 +113,16 @@ pub fn generate_preview(spec: &Spec, preview_duration: f64) -> AudioResult<Gener
                     }
                 })?;
 
-            // Truncate duration to preview_duration
-            if params.duration_seconds > preview_duration {
-                params.duration_seconds = preview_duration;
+            if !preview_duration.is_finite() || preview_duration <= 0.0 {
+                return Err(AudioError::InvalidDuration {
+                    duration: preview_duration,
+                });
             }
 
-            generate_from_params(&params, spec.seed)
+            let mut rendered = render_unified_params(&params, spec.seed)?;
+            let preview_samples = (preview_duration * params.sample_rate as f64).ceil() as usize;
+            rendered.truncate(preview_samples);
+            Ok(rendered.into_result(&params))
         }
         _ => Err(AudioError::InvalidRecipeType {
             expected: "audio_v1".to_string(),
@@ -124,175 +152,97 @@ pub fn generate_from_params(params: &AudioV1Params, seed: u32) -> AudioResult<Ge
 /// # Returns
 /// Generated WAV file and metadata
 fn generate_from_unified_params(params: &AudioV1Params, seed: u32) -> AudioResult<GenerateResult> {
-    const MAX_AUDIO_DURATION_SECONDS: f64 = 30.0;
-    const MAX_AUDIO_LAYERS: usize = 32;
-    const MAX_NUM_SAMPLES: usize = (MAX_AUDIO_DURATION_SECONDS as usize) * 48_000;
-
-    match params.sample_rate {
-        22050 | 44100 | 48000 => {}
-        other => return Err(AudioError::InvalidSampleRate { rate: other }),
-    }
-    if !params.duration_seconds.is_finite() || params.duration_seconds <= 0.0 {
-        return Err(AudioError::InvalidDuration {
-            duration: params.duration_seconds,
-        });
-    }
-    if params.duration_seconds > MAX_AUDIO_DURATION_SECONDS {
-        return Err(AudioError::invalid_param(
-            "duration_seconds",
-            format!(
-                "must be <= {} seconds, got {}",
-                MAX_AUDIO_DURATION_SECONDS, params.duration_seconds
-            ),
-        ));
-    }
-    if params.layers.len() > MAX_AUDIO_LAYERS {
-        return Err(AudioError::invalid_param(
-            "layers",
-            format!(
-                "must have at most {} entries, got {}",
-                MAX_AUDIO_LAYERS,
-                params.layers.len()
-            ),
-        ));
-    }
-
-    let sample_rate = params.sample_rate as f64;
-    let num_samples_f = params.duration_seconds * sample_rate;
-    if !num_samples_f.is_finite() || num_samples_f <= 0.0 {
-        return Err(AudioError::InvalidDuration {
-            duration: params.duration_seconds,
-        });
-    }
-    let num_samples = num_samples_f.ceil() as usize;
-    if num_samples == 0 || num_samples > MAX_NUM_SAMPLES {
-        return Err(AudioError::invalid_param(
-            "duration_seconds",
-            format!(
-                "produces too many samples ({} > max {})",
-                num_samples, MAX_NUM_SAMPLES
-            ),
-        ));
-    }
-
-    // Get base note as MIDI note number (None means tracker uses a format default)
-    use speccade_spec::recipe::audio::NoteSpec as UnifiedNoteSpec;
-    let base_note_midi: Option<u8> = match &params.base_note {
-        Some(UnifiedNoteSpec::MidiNote(n)) => Some(*n),
-        Some(UnifiedNoteSpec::NoteName(name)) => {
-            speccade_spec::recipe::audio::parse_note_name(name)
-        }
-        None => None, // Tracker uses native default (IT: C5, XM: C4)
-    };
-
-    let mut mixer = Mixer::new(num_samples, sample_rate);
+    Ok(render_unified_params(params, seed)?.into_result(params))
+}
 
-    // Process each layer
-    for (layer_idx, layer) in params.layers.iter().enumerate() {
-        let layer_seed = crate::rng::derive_layer_seed(seed, layer_idx as u32);
-
-        // Check if this is a SupersawUnison layer that needs expansion
-        if let speccade_spec::recipe::audio::Synthesis::SupersawUnison {
-            frequency,
-            voices,
-            detune_cents,
-            spread,
-            detune_curve,
-        } = &layer.synthesis
-        {
-            // Expand SupersawUnison into N virtual layers
-            let supersaw_params = SupersawParams {
-                frequency: *frequency,
-                voices: *voices,
-                detune_cents: *detune_cents,
-                spread: *spread,
-                detune_curve: *detune_curve,
-            };
-            let virtual_layers = generate_supersaw_virtual_layers(
-                layer,
-                layer_idx,
-                num_samples,
-                sample_rate,
-                layer_seed,
-                supersaw_params,
-            )?;
+/// Final output samples and metadata, before WAV encoding.
+struct RenderedAudio {
+    output: MixerOutput,
+    mono_compatibility: Option<MonoCompatibility>,
+    master_gain_reduction_db: Option<f64>,
+    base_note: Option<u8>,
+    loop_point: Option<usize>,
+    loop_end: Option<usize>,
+    loop_snapped_to_zero_crossing: bool,
+}
 
-            for virtual_layer in virtual_layers {
-                mixer.add_layer(virtual_layer);
+impl RenderedAudio {
+    /// Keeps only the first `num_samples` samples.
+    ///
+    /// Loop points that no longer fit inside the truncated audio are dropped.
+    fn truncate(&mut self, num_samples: usize) {
+        match &mut self.output {
+            MixerOutput::Mono(samples) => samples.truncate(num_samples),
+            MixerOutput::Stereo(stereo) => {
+                stereo.left.truncate(num_samples);
+                stereo.right.truncate(num_samples);
             }
-            continue;
         }
 
-        let layer_output = generate_layer(layer, layer_idx, num_samples, sample_rate, layer_seed)?;
+        if self.loop_end.is_some_and(|end| end > num_samples) {
+            self.loop_point = None;
+            self.loop_end = None;
+            self.loop_snapped_to_zero_crossing = false;
+        }
+    }
 
-        // Create the mixer layer based on mono/stereo output
-        let mut mix_layer = match layer_output {
-            layer::LayerOutput::Mono(mut samples) => {
-                // Apply pitch envelope if specified (mono only for now)
-                if let Some(ref pitch_env) = params.pitch_envelope {
-                    let pitch_curve = modulation::generate_pitch_envelope_curve(
-                        pitch_env,
-                        sample_rate,
-                        num_samples,
-                    );
-                    samples = modulation::apply_pitch_envelope_to_layer_samples(
-                        layer,
-                        layer_idx,
-                        &pitch_curve,
-                        num_samples,
-                        sample_rate,
-                        layer_seed,
-                    )?;
-                }
-                Layer::new(samples, layer.volume, layer.pan)
-            }
-            layer::LayerOutput::Stereo { left, right } => {
-                // For stereo layers, pitch envelope is not supported
-                // (granular synthesis with pan_spread doesn't typically use pitch envelope)
-                Layer::new_stereo(left, right, layer.volume, layer.pan)
-            }
+    /// Encodes the samples as WAV.
+    fn into_result(self, params: &AudioV1Params) -> GenerateResult {
+        let (wav, sample_peak, true_peak, samples) = match self.output {
+            MixerOutput::Mono(samples) => (
+                WavResult::from_mono(&samples, params.sample_rate),
+                peak::sample_peak(&samples),
+                peak::true_peak(&samples),
+                samples,
+            ),
+            MixerOutput::Stereo(stereo) => (
+                WavResult::from_stereo_output(&stereo, params.sample_rate),
+                peak::sample_peak(&stereo.left).max(peak::sample_peak(&stereo.right)),
+                peak::true_peak(&stereo.left).max(peak::true_peak(&stereo.right)),
+                stereo.interleave(),
+            ),
         };
 
-        // Pan LFO is applied during mixing. Keep it deterministic and aligned to layer start:
-        // delay time does not advance LFO phase.
-        if let Some(lfo_mod) = &layer.lfo {
-            if let speccade_spec::recipe::audio::ModulationTarget::Pan { amount } = &lfo_mod.target
-            {
-                use crate::modulation::lfo::{apply_pan_modulation, Lfo};
-
-                let initial_phase = lfo_mod.config.phase.unwrap_or(0.0);
-                let mut lfo = Lfo::new(
-                    lfo_mod.config.waveform,
-                    lfo_mod.config.rate,
-                    sample_rate,
-                    initial_phase,
-                );
-                let lfo_seed = crate::rng::derive_component_seed(layer_seed, "layer_pan_lfo");
-                let mut lfo_rng = crate::rng::create_rng(lfo_seed);
-
-                let delay_samples = layer
-                    .delay
-                    .map(|delay| (delay.max(0.0) * sample_rate).floor() as usize)
-                    .unwrap_or(0)
-                    .min(num_samples);
-
-                let mut pan_curve = vec![layer.pan.clamp(-1.0, 1.0); num_samples];
-                for pan_sample in pan_curve.iter_mut().take(num_samples).skip(delay_samples) {
-                    let lfo_value = lfo.next_sample(&mut lfo_rng);
-                    *pan_sample =
-                        apply_pan_modulation(layer.pan, lfo_value, *amount, lfo_mod.config.depth);
-                }
-
-                mix_layer = mix_layer.with_pan_curve(pan_curve);
-            }
+        GenerateResult {
+            wav,
+            samples,
+            num_layers: params.layers.len(),
+            base_note: self.base_note,
+            loop_point: self.loop_point,
+            loop_end: self.loop_end,
+            loop_snapped_to_zero_crossing: self.loop_snapped_to_zero_crossing,
+            mono_compatibility: self.mono_compatibility,
+            master_gain_reduction_db: self.master_gain_reduction_db,
+            sample_peak_db: peak::peak_to_db(sample_peak),
+            true_peak_db: peak::peak_to_db(true_peak),
         }
+    }
+}
+
+/// Renders unified AudioV1Params to normalized output samples.
+fn render_unified_params(params: &AudioV1Params, seed: u32) -> AudioResult<RenderedAudio> {
+    let num_samples = validated_num_samples(params)?;
+    let sample_rate = params.sample_rate as f64;
+    let base_note_midi = resolve_base_note(params);
 
+    let mut mixer = Mixer::new(num_samples, sample_rate);
+    for (_, mix_layer) in render_mix_layers(params, seed, num_samples, sample_rate, base_note_midi)?
+    {
         mixer.add_layer(mix_layer);
     }
 
     // Mix layers
     let mut mixed = mixer.mix();
 
+    // Apply master compressor if specified
+    let master_gain_reduction_db = params
+        .master_compressor
+        .as_ref()
+        .map(|compressor| {
+            crate::effects::dynamics::apply_master_compressor(&mut mixed, compressor, sample_rate)
+        })
+        .transpose()?;
+
     // Apply master filter if specified
     if let Some(ref master_filter) = params.master_filter {
         mixed = match mixed {
@@ -323,6 +273,36 @@ fn generate_from_unified_params(params: &AudioV1Params, seed: u32) -> AudioResul
         }
     }
 
+    // Remove DC offset as a master stage if requested
+    if params.dc_blocker {
+        mixed = match mixed {
+            MixerOutput::Mono(mut samples) => {
+                filters::apply_dc_blocker(&mut samples, sample_rate);
+                MixerOutput::Mono(samples)
+            }
+            MixerOutput::Stereo(mut stereo) => {
+                filters::apply_dc_blocker(&mut stereo.left, sample_rate);
+                filters::apply_dc_blocker(&mut stereo.right, sample_rate);
+                MixerOutput::Stereo(stereo)
+            }
+        };
+    }
+
+    // Measure mono compatibility of a stereo mix before applying the channel layout
+    let mono_compatibility = match &mixed {
+        MixerOutput::Stereo(stereo) => stereo.mono_compatibility(),
+        MixerOutput::Mono(_) => None,
+    };
+    mixed = match (params.channels, mixed) {
+        (ChannelMode::ForceMono, MixerOutput::Stereo(stereo)) => {
+            MixerOutput::Mono(stereo.to_mono())
+        }
+        (ChannelMode::ForceStereo, MixerOutput::Mono(samples)) => {
+            MixerOutput::Stereo(StereoOutput::from_mono(samples))
+        }
+        (_, mixed) => mixed,
+    };
+
     // Determine loop points and apply crossfade if configured
     let loop_config = params.effective_loop_config();
     let (loop_point, loop_end, loop_snapped) = if let Some(ref config) = loop_config {
@@ -357,6 +337,7 @@ fn generate_from_unified_params(params: &AudioV1Params, seed: u32) -> AudioResul
                             samples,
                             &loop_points,
                             config.crossfade_ms,
+                            config.crossfade_shape,
                             sample_rate,
                         );
                     }
@@ -366,6 +347,7 @@ fn generate_from_unified_params(params: &AudioV1Params, seed: u32) -> AudioResul
                             &mut stereo.right,
                             &loop_points,
                             config.crossfade_ms,
+                            config.crossfade_shape,
                             sample_rate,
                         );
                     }
@@ -384,21 +366,39 @@ fn generate_from_unified_params(params: &AudioV1Params, seed: u32) -> AudioResul
         (None, None, false)
     };
 
-    // Convert to WAV
-    let wav = match mixed {
+    // Fade out a still-ringing tail (after normalization, so the threshold is in dBFS)
+    let tail_fade = params
+        .tail_fade
+        .as_ref()
+        .map(|tail_fade| TailFadeSettings::new(tail_fade, params.sample_rate));
+
+    // Normalize to the output level
+    let mut output = match mixed {
         MixerOutput::Mono(mut samples) => {
             crate::mixer::normalize(&mut samples, -3.0);
-            WavResult::from_mono(&samples, params.sample_rate)
+            if let Some(settings) = tail_fade {
+                apply_tail_fade(&mut samples, settings);
+            }
+            MixerOutput::Mono(samples)
         }
         MixerOutput::Stereo(mut stereo) => {
             crate::mixer::normalize_stereo(&mut stereo, -3.0);
-            WavResult::from_stereo_output(&stereo, params.sample_rate)
+            if let Some(settings) = tail_fade {
+                apply_tail_fade_stereo(&mut stereo, settings);
+            }
+            MixerOutput::Stereo(stereo)
         }
     };
 
-    Ok(GenerateResult {
-        wav,
-        num_layers: params.layers.len(),
+    // Hold the true peak under the ceiling as the final master stage
+    if let Some(ref limiter) = params.master_limiter {
+        crate::effects::dynamics::apply_master_limiter(&mut output, limiter, sample_rate)?;
+    }
+
+    Ok(RenderedAudio {
+        output,
+        mono_compatibility,
+        master_gain_reduction_db,
         base_note: base_note_midi,
         loop_point,
         loop_end,
@@ -406,6 +406,389 @@ fn generate_from_unified_params(params: &AudioV1Params, seed: u32) -> AudioResul
     })
 }
 
+/// Validates render limits and returns the output length in samples.
+fn validated_num_samples(params: &AudioV1Params) -> AudioResult<usize> {
+    const MAX_AUDIO_DURATION_SECONDS: f64 = 30.0;
+    const MAX_AUDIO_LAYERS: usize = 32;
+    const MAX_NUM_SAMPLES: usize = (MAX_AUDIO_DURATION_SECONDS as usize) * 48_000;
+
+    match params.sample_rate {
+        22050 | 44100 | 48000 => {}
+        other => return Err(AudioError::InvalidSampleRate { rate: other }),
+    }
+    if !params.duration_seconds.is_finite() || params.duration_seconds <= 0.0 {
+        return Err(AudioError::InvalidDuration {
+            duration: params.duration_seconds,
+        });
+    }
+    if params.duration_seconds > MAX_AUDIO_DURATION_SECONDS {
+        return Err(AudioError::invalid_param(
+            "duration_seconds",
+            format!(
+                "must be <= {} seconds, got {}",
+                MAX_AUDIO_DURATION_SECONDS, params.duration_seconds
+            ),
+        ));
+    }
+    if params.layers.len() > MAX_AUDIO_LAYERS {
+        return Err(AudioError::invalid_param(
+            "layers",
+            format!(
+                "must have at most {} entries, got {}",
+                MAX_AUDIO_LAYERS,
+                params.layers.len()
+            ),
+        ));
+    }
+
+    if let Some(ref tail_fade) = params.tail_fade {
+        if !tail_fade.threshold_db.is_finite() || tail_fade.threshold_db > 0.0 {
+            return Err(AudioError::invalid_param(
+                "tail_fade.threshold_db",
+                format!("must be <= 0 dBFS, got {}", tail_fade.threshold_db),
+            ));
+        }
+        if !tail_fade.fade_ms.is_finite() || tail_fade.fade_ms <= 0.0 {
+            return Err(AudioError::invalid_param(
+                "tail_fade.fade_ms",
+                format!("must be positive, got {}", tail_fade.fade_ms),
+            ));
+        }
+    }
+
+    let num_samples_f = params.duration_seconds * params.sample_rate as f64;
+    if !num_samples_f.is_finite() || num_samples_f <= 0.0 {
+        return Err(AudioError::InvalidDuration {
+            duration: params.duration_seconds,
+        });
+    }
+    let num_samples = num_samples_f.ceil() as usize;
+    if num_samples == 0 || num_samples > MAX_NUM_SAMPLES {
+        return Err(AudioError::invalid_param(
+            "duration_seconds",
+            format!(
+                "produces too many samples ({} > max {})",
+                num_samples, MAX_NUM_SAMPLES
+            ),
+        ));
+    }
+
+    Ok(num_samples)
+}
+
+/// Resolves the base note as a MIDI note number (None means the tracker uses a format default).
+fn resolve_base_note(params: &AudioV1Params) -> Option<u8> {
+    use speccade_spec::recipe::audio::NoteSpec as UnifiedNoteSpec;
+    match &params.base_note {
+        Some(UnifiedNoteSpec::MidiNote(n)) => Some(*n),
+        Some(UnifiedNoteSpec::NoteName(name)) => {
+            speccade_spec::recipe::audio::parse_note_name(name)
+        }
+        None => None, // Tracker uses native default (IT: C5, XM: C4)
+    }
+}
+
+/// Renders each spec layer into mixer layers, tagged with the spec layer index.
+///
+/// Supersaw layers expand into one mixer layer per voice, so an index can
+/// appear more than once.
+fn render_mix_layers(
+    params: &AudioV1Params,
+    seed: u32,
+    num_samples: usize,
+    sample_rate: f64,
+    base_note_midi: Option<u8>,
+) -> AudioResult<Vec<(usize, Layer)>> {
+    let keytrack_reference_freq = speccade_spec::recipe::audio::midi_to_frequency(
+        base_note_midi.unwrap_or(filters::KEYTRACK_DEFAULT_REFERENCE_NOTE),
+    );
+
+    let rendered = map_layers(params.layers.len(), |layer_idx| {
+        render_spec_layer(
+            params,
+            layer_idx,
+            seed,
+            num_samples,
+            sample_rate,
+            keytrack_reference_freq,
+        )
+    });
+
+    // Results come back in layer order, so the mix is the same however they were rendered.
+    let mut mix_layers = Vec::with_capacity(params.layers.len());
+    for (layer_idx, layers) in rendered.into_iter().enumerate() {
+        mix_layers.extend(layers?.into_iter().map(|layer| (layer_idx, layer)));
+    }
+
+    apply_sidechains(params, &mut mix_layers, num_samples, sample_rate)?;
+
+    Ok(mix_layers)
+}
+
+/// Ducks every layer that has a `sidechain` by the level of its source layer.
+///
+/// All detectors listen to the layers before any ducking, so the result does
+/// not depend on the order layers are listed in.
+fn apply_sidechains(
+    params: &AudioV1Params,
+    mix_layers: &mut [(usize, Layer)],
+    num_samples: usize,
+    sample_rate: f64,
+) -> AudioResult<()> {
+    let mut gains = Vec::new();
+    for (layer_idx, layer) in params.layers.iter().enumerate() {
+        let Some(sidechain) = &layer.sidechain else {
+            continue;
+        };
+        let source_layer = sidechain.source_layer;
+        if source_layer == layer_idx || source_layer >= params.layers.len() {
+            return Err(AudioError::invalid_param(
+                format!("layers[{}].sidechain.source_layer", layer_idx),
+                format!(
+                    "must name another layer (0-{}), got {}",
+                    params.layers.len() - 1,
+                    source_layer
+                ),
+            ));
+        }
+
+        // A supersaw source is the sum of its voices.
+        let mut source = vec![0.0; num_samples];
+        for (_, mix_layer) in mix_layers.iter().filter(|(idx, _)| *idx == source_layer) {
+            for (sum, sample) in source.iter_mut().zip(mix_layer.mono_buffer(num_samples)) {
+                *sum += sample;
+            }
+        }
+
+        let gain = crate::effects::sidechain::duck_gain(
+            &source,
+            sidechain.threshold_db,
+            sidechain.ratio,
+            sidechain.attack_ms,
+            sidechain.release_ms,
+            sample_rate,
+        )?;
+        gains.push((layer_idx, gain));
+    }
+
+    for (layer_idx, gain) in &gains {
+        for (_, mix_layer) in mix_layers.iter_mut().filter(|(idx, _)| idx == layer_idx) {
+            mix_layer.duck(gain);
+        }
+    }
+
+    Ok(())
+}
+
+/// Calls `render` for every layer index, on the rayon thread pool.
+///
+/// Each layer renders from its own derived seed, so the results do not depend
+/// on the number of threads.
+#[cfg(feature = "parallel")]
+fn map_layers<T, F>(count: usize, render: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Sync + Send,
+{
+    use rayon::prelude::*;
+    (0..count).into_par_iter().map(render).collect()
+}
+
+/// Calls `render` for every layer index, in order.
+#[cfg(not(feature = "parallel"))]
+fn map_layers<T, F>(count: usize, render: F) -> Vec<T>
+where
+    F: Fn(usize) -> T,
+{
+    (0..count).map(render).collect()
+}
+
+/// Renders one spec layer into mixer layers.
+///
+/// Supersaw layers expand into one mixer layer per voice.
+fn render_spec_layer(
+    params: &AudioV1Params,
+    layer_idx: usize,
+    seed: u32,
+    num_samples: usize,
+    sample_rate: f64,
+    keytrack_reference_freq: f64,
+) -> AudioResult<Vec<Layer>> {
+    let layer = &params.layers[layer_idx];
+    let layer_seed = crate::rng::derive_layer_seed(seed, layer_idx as u32);
+    let layer = &*filters::resolve_layer_keytrack(layer, keytrack_reference_freq);
+
+    // Layers with a `render_rate` are synthesized at that rate and resampled to
+    // the master rate before panning and gain are applied.
+    let render_rate = layer_render_rate(layer, layer_idx, sample_rate)?;
+    let master_samples = num_samples;
+    let num_samples = if render_rate == sample_rate {
+        num_samples
+    } else {
+        (num_samples as f64 * render_rate / sample_rate).ceil() as usize
+    };
+    let to_master = |samples: LayerSamples| match samples {
+        LayerSamples::Mono(samples) => {
+            LayerSamples::Mono(resample(&samples, render_rate, sample_rate, master_samples))
+        }
+        LayerSamples::Stereo { left, right } => LayerSamples::Stereo {
+            left: resample(&left, render_rate, sample_rate, master_samples),
+            right: resample(&right, render_rate, sample_rate, master_samples),
+        },
+    };
+
+    // Check if this is a SupersawUnison layer that needs expansion
+    if let speccade_spec::recipe::audio::Synthesis::SupersawUnison {
+        frequency,
+        voices,
+        detune_cents,
+        spread,
+        detune_curve,
+    } = &layer.synthesis
+    {
+        // Expand SupersawUnison into N virtual layers
+        let supersaw_params = SupersawParams {
+            frequency: *frequency,
+            voices: *voices,
+            detune_cents: *detune_cents,
+            spread: *spread,
+            detune_curve: *detune_curve,
+        };
+        let virtual_layers = generate_supersaw_virtual_layers(
+            layer,
+            layer_idx,
+            num_samples,
+            render_rate,
+            layer_seed,
+            supersaw_params,
+        )?;
+
+        return virtual_layers
+            .into_iter()
+            .map(|mut virtual_layer| {
+                virtual_layer.samples = to_master(virtual_layer.samples);
+                apply_mix_controls(virtual_layer, layer, layer_idx, sample_rate)
+            })
+            .collect();
+    }
+
+    let layer_output = generate_layer(layer, layer_idx, num_samples, render_rate, layer_seed)?;
+
+    // Create the mixer layer based on mono/stereo output
+    let mut mix_layer = match layer_output {
+        layer::LayerOutput::Mono(mut samples) => {
+            // Apply pitch envelope if specified (mono only for now)
+            if let Some(ref pitch_env) = params.pitch_envelope {
+                let pitch_curve =
+                    modulation::generate_pitch_envelope_curve(pitch_env, render_rate, num_samples);
+                samples = modulation::apply_pitch_envelope_to_layer_samples(
+                    layer,
+                    layer_idx,
+                    &pitch_curve,
+                    num_samples,
+                    render_rate,
+                    layer_seed,
+                )?;
+            }
+            Layer::new(samples, layer.volume, layer.pan)
+        }
+        layer::LayerOutput::Stereo { left, right } => {
+            // For stereo layers, pitch envelope is not supported
+            // (granular synthesis with pan_spread doesn't typically use pitch envelope)
+            Layer::new_stereo(left, right, layer.volume, layer.pan)
+        }
+    };
+    mix_layer.samples = to_master(mix_layer.samples);
+    let num_samples = master_samples;
+
+    // Pan LFO is applied during mixing. Keep it deterministic and aligned to layer start:
+    // delay time does not advance LFO phase.
+    if let Some(lfo_mod) = &layer.lfo {
+        if let speccade_spec::recipe::audio::ModulationTarget::Pan { amount } = &lfo_mod.target {
+            use crate::modulation::lfo::{apply_pan_modulation, Lfo};
+
+            let initial_phase = lfo_mod.config.phase.unwrap_or(0.0);
+            let mut lfo = Lfo::new(
+                lfo_mod.config.waveform,
+                lfo_mod.config.rate,
+                sample_rate,
+                initial_phase,
+            );
+            let lfo_seed = crate::rng::derive_component_seed(layer_seed, "layer_pan_lfo");
+            let mut lfo_rng = crate::rng::create_rng(lfo_seed);
+
+            let delay_samples = layer
+                .delay
+                .map(|delay| (delay.max(0.0) * sample_rate).floor() as usize)
+                .unwrap_or(0)
+                .min(num_samples);
+
+            let mut pan_curve = vec![layer.pan.clamp(-1.0, 1.0); num_samples];
+            for pan_sample in pan_curve.iter_mut().take(num_samples).skip(delay_samples) {
+                let lfo_value = lfo.next_sample(&mut lfo_rng);
+                *pan_sample =
+                    apply_pan_modulation(layer.pan, lfo_value, *amount, lfo_mod.config.depth);
+            }
+
+            mix_layer = mix_layer.with_pan_curve(pan_curve);
+        }
+    }
+
+    // Modulation matrix pan routes are added on top of any pan LFO, aligned to the
+    // layer start. Stereo layers keep their static pan.
+    let matrix = ModulationMatrix::new(&layer.modulation_matrix);
+    if !mix_layer.samples.is_stereo() && matrix.targets(ModulationDestination::Pan) {
+        let delay_samples = layer
+            .delay
+            .map(|delay| (delay.max(0.0) * sample_rate).floor() as usize)
+            .unwrap_or(0)
+            .min(num_samples);
+        if let Some(offsets) = matrix.render(
+            ModulationDestination::Pan,
+            num_samples - delay_samples,
+            sample_rate,
+            layer_seed,
+        ) {
+            let mut pan_curve = mix_layer
+                .pan_curve
+                .take()
+                .unwrap_or_else(|| vec![layer.pan.clamp(-1.0, 1.0); num_samples]);
+            for (pan, offset) in pan_curve.iter_mut().skip(delay_samples).zip(offsets) {
+                *pan = (*pan + offset).clamp(-1.0, 1.0);
+            }
+            mix_layer = mix_layer.with_pan_curve(pan_curve);
+        }
+    }
+
+    Ok(vec![apply_mix_controls(
+        mix_layer,
+        layer,
+        layer_idx,
+        sample_rate,
+    )?])
+}
+
+/// Lowest accepted `render_rate` in Hz.
+const MIN_RENDER_RATE: u32 = 1000;
+
+/// Returns the rate a layer is synthesized at: its `render_rate`, or the
+/// recipe sample rate.
+fn layer_render_rate(layer: &AudioLayer, layer_idx: usize, sample_rate: f64) -> AudioResult<f64> {
+    match layer.render_rate {
+        None => Ok(sample_rate),
+        Some(rate) if rate >= MIN_RENDER_RATE && f64::from(rate) <= sample_rate => {
+            Ok(f64::from(rate))
+        }
+        Some(rate) => Err(AudioError::invalid_param(
+            format!("layers[{}].render_rate", layer_idx),
+            format!(
+                "must be {}-{} Hz (the recipe sample rate), got {}",
+                MIN_RENDER_RATE, sample_rate, rate
+            ),
+        )),
+    }
+}
+
 /// Parameters for supersaw voice expansion.
 struct SupersawParams {
     frequency: f64,
@@ -415,6 +798,60 @@ struct SupersawParams {
     detune_curve: speccade_spec::recipe::audio::DetuneCurve,
 }
 
+/// Applies a spec layer's mute, solo, and gain automation to a mixer layer.
+///
+/// Gain automation is aligned to the layer start, like the pan LFO: the layer
+/// delay does not advance the automation time.
+fn apply_mix_controls(
+    mix_layer: Layer,
+    layer: &AudioLayer,
+    layer_idx: usize,
+    sample_rate: f64,
+) -> AudioResult<Layer> {
+    let mut mix_layer = mix_layer.with_muted(layer.muted).with_solo(layer.solo);
+
+    let breakpoints = match layer.gain_automation.as_deref() {
+        Some(breakpoints) if !breakpoints.is_empty() => breakpoints,
+        _ => return Ok(mix_layer),
+    };
+
+    let mut prev_time = 0.0;
+    for (i, point) in breakpoints.iter().enumerate() {
+        if !point.time.is_finite() || point.time < prev_time {
+            return Err(AudioError::invalid_param(
+                format!("layers[{}].gain_automation[{}].time", layer_idx, i),
+                format!(
+                    "must be finite, non-negative, and sorted by time, got {}",
+                    point.time
+                ),
+            ));
+        }
+        if !point.gain.is_finite() || point.gain < 0.0 {
+            return Err(AudioError::invalid_param(
+                format!("layers[{}].gain_automation[{}].gain", layer_idx, i),
+                format!("must be finite and non-negative, got {}", point.gain),
+            ));
+        }
+        prev_time = point.time;
+    }
+
+    let num_samples = mix_layer.samples.len();
+    let delay_samples = layer
+        .delay
+        .map(|delay| (delay.max(0.0) * sample_rate).floor() as usize)
+        .unwrap_or(0)
+        .min(num_samples);
+    let gain_curve = modulation::generate_gain_automation_curve(
+        breakpoints,
+        sample_rate,
+        num_samples,
+        delay_samples,
+    );
+    mix_layer = mix_layer.with_gain_curve(gain_curve);
+
+    Ok(mix_layer)
+}
+
 /// Generates virtual layers for SupersawUnison synthesis.
 ///
 /// Expands a single SupersawUnison layer into N virtual layers (one per voice),
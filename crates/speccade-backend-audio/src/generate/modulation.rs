@@ -1,8 +1,10 @@
 //! Envelope generation and modulation utilities.
 
-use speccade_spec::recipe::audio::{AudioLayer, Envelope, PitchEnvelope, Synthesis, Waveform};
+use speccade_spec::recipe::audio::{
+    AudioLayer, GainBreakpoint, LayerEnvelope, PitchEnvelope, Synthesis, Waveform,
+};
 
-use crate::envelope::{AdsrEnvelope, AdsrParams};
+use crate::envelope::{AdsrEnvelope, AdsrParams, DahdsrParams};
 use crate::error::AudioResult;
 use crate::oscillator::{PhaseAccumulator, TWO_PI};
 
@@ -10,24 +12,43 @@ use super::layer::generate_layer;
 
 // Re-export LFO modulation functions from the dedicated module
 pub use super::lfo_modulation::{
-    apply_lfo_filter_modulation, apply_lfo_fm_index_modulation, apply_lfo_grain_density_modulation,
-    apply_lfo_grain_size_modulation, apply_lfo_pitch_modulation, apply_lfo_pitch_warp,
-    apply_lfo_pulse_width_modulation, LfoFmIndexParams, LfoGrainDensityParams, LfoGrainSizeParams,
-    LfoPitchParams, LfoPulseWidthParams,
+    apply_filter_cutoff_offsets, apply_lfo_filter_modulation, apply_lfo_fm_index_modulation,
+    apply_lfo_grain_density_modulation, apply_lfo_grain_size_modulation,
+    apply_lfo_pitch_modulation, apply_lfo_pitch_warp, apply_lfo_pulse_width_modulation,
+    apply_lfo_wavetable_position_modulation, apply_pitch_warp, LfoFmIndexParams,
+    LfoGrainDensityParams, LfoGrainSizeParams, LfoPitchParams, LfoPulseWidthParams,
+    LfoWavetablePositionParams,
 };
 
-/// Generates an ADSR envelope for the given duration.
-pub fn generate_envelope(env: &Envelope, sample_rate: f64, num_samples: usize) -> Vec<f64> {
-    let params = AdsrParams::new(env.attack, env.decay, env.sustain, env.release);
-    let duration = num_samples as f64 / sample_rate;
-    AdsrEnvelope::generate_fixed_duration(&params, sample_rate, duration)
+/// Generates an ADSR or DAHDSR envelope for the given duration.
+pub fn generate_envelope(env: &LayerEnvelope, sample_rate: f64, num_samples: usize) -> Vec<f64> {
+    match env {
+        LayerEnvelope::Adsr(env) => {
+            let params = AdsrParams::new(env.attack, env.decay, env.sustain, env.release);
+            let duration = num_samples as f64 / sample_rate;
+            AdsrEnvelope::generate_fixed_duration(&params, sample_rate, duration)
+        }
+        LayerEnvelope::Dahdsr(env) => DahdsrParams {
+            delay: env.delay,
+            attack: env.attack,
+            hold: env.hold,
+            decay: env.decay,
+            sustain: env.sustain,
+            release: env.release,
+            attack_curve: env.attack_curve,
+            decay_curve: env.decay_curve,
+            release_curve: env.release_curve,
+        }
+        .generate(sample_rate, num_samples),
+    }
 }
 
 /// Calculates the loop point based on the envelope.
 ///
-/// The loop point is set after the attack + decay phases.
-pub fn calculate_loop_point(env: &Envelope, sample_rate: f64) -> usize {
-    let loop_time = env.attack + env.decay;
+/// The loop point is set after the delay, attack, hold, and decay phases.
+pub fn calculate_loop_point(env: &LayerEnvelope, sample_rate: f64) -> usize {
+    let env = env.to_dahdsr();
+    let loop_time = env.delay + env.attack + env.hold + env.decay;
     (loop_time * sample_rate) as usize
 }
 
@@ -85,6 +106,45 @@ pub fn generate_pitch_envelope_curve(
     curve
 }
 
+/// Generates a per-sample gain curve from gain automation breakpoints.
+///
+/// Breakpoint times are relative to `start_sample` (the layer start after its
+/// delay). Gain is linearly interpolated between breakpoints and held at the
+/// first/last breakpoint gain outside their range. Breakpoints must be sorted
+/// by time.
+pub fn generate_gain_automation_curve(
+    breakpoints: &[GainBreakpoint],
+    sample_rate: f64,
+    num_samples: usize,
+    start_sample: usize,
+) -> Vec<f64> {
+    let (first, last) = match (breakpoints.first(), breakpoints.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return vec![1.0; num_samples],
+    };
+
+    let mut curve = Vec::with_capacity(num_samples);
+    let mut segment = 0;
+    for i in 0..num_samples {
+        let t = i.saturating_sub(start_sample) as f64 / sample_rate;
+        let gain = if t <= first.time {
+            first.gain
+        } else if t >= last.time {
+            last.gain
+        } else {
+            while breakpoints[segment + 1].time <= t {
+                segment += 1;
+            }
+            let a = &breakpoints[segment];
+            let b = &breakpoints[segment + 1];
+            let frac = (t - a.time) / (b.time - a.time);
+            a.gain + (b.gain - a.gain) * frac
+        };
+        curve.push(gain);
+    }
+    curve
+}
+
 /// Applies pitch envelope modulation to a layer's samples.
 ///
 /// This regenerates the layer with pitch modulation applied.
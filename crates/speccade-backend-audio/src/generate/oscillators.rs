@@ -1,41 +1,105 @@
 //! Oscillator generation helpers.
 
-use speccade_spec::recipe::audio::{FreqSweep, OscillatorConfig, Waveform};
+use rand::Rng;
+use speccade_spec::recipe::audio::{AntiAliasMode, FreqSweep, OscillatorConfig, Waveform};
 
-use crate::synthesis::oscillators::{SawSynth, SineSynth, SquareSynth, TriangleSynth};
+use crate::rng::{create_rng, derive_component_seed};
+
+use crate::synthesis::oscillators::{
+    synthesize_oversampled, SawSynth, SineSynth, SquareSynth, TriangleSynth,
+};
 use crate::synthesis::{FrequencySweep, Synthesizer};
 
 use super::converters::convert_sweep_curve;
 
-/// Generates oscillator samples based on waveform type.
+/// Parameters for single-oscillator synthesis.
+pub struct OscillatorParams<'a> {
+    /// Waveform type
+    pub waveform: &'a Waveform,
+    /// Base frequency in Hz
+    pub frequency: f64,
+    /// Optional frequency sweep
+    pub freq_sweep: Option<&'a FreqSweep>,
+    /// Duty cycle for square/pulse waves
+    pub duty: Option<f64>,
+    /// Anti-aliasing strategy
+    pub anti_alias: AntiAliasMode,
+    /// Number of detuned unison voices
+    pub detune_voices: u8,
+    /// Detune of the outermost unison voices in cents
+    pub detune_spread: f64,
+}
+
+/// Generates oscillator samples, stacking detuned unison voices if requested.
+///
+/// Voices are spread linearly across `[-detune_spread, +detune_spread]` cents
+/// around the base frequency and summed at `1 / voices` gain. Each voice starts
+/// at a phase drawn from `seed`, so the stack is deterministic but the voices do
+/// not all line up at the onset. A single voice is rendered at phase zero and
+/// matches the plain oscillator exactly.
 pub fn generate_oscillator_samples(
-    waveform: &Waveform,
-    frequency: f64,
-    freq_sweep: Option<&FreqSweep>,
-    duty: Option<f64>,
+    params: &OscillatorParams<'_>,
+    num_samples: usize,
+    sample_rate: f64,
+    seed: u32,
+    rng: &mut rand_pcg::Pcg32,
+) -> Vec<f64> {
+    let voices = params.detune_voices.max(1) as usize;
+    if voices == 1 {
+        return generate_voice(params, 1.0, 0.0, num_samples, sample_rate, rng);
+    }
+
+    let mut output = vec![0.0; num_samples];
+    for voice_idx in 0..voices {
+        let x = -1.0 + 2.0 * voice_idx as f64 / (voices - 1) as f64;
+        let ratio = 2.0_f64.powf(x * params.detune_spread / 1200.0);
+
+        let voice_seed = derive_component_seed(seed, &format!("unison_voice_{}", voice_idx));
+        let phase = create_rng(voice_seed).gen::<f64>();
+
+        let voice = generate_voice(params, ratio, phase, num_samples, sample_rate, rng);
+        for (out, sample) in output.iter_mut().zip(voice) {
+            *out += sample;
+        }
+    }
+
+    let gain = 1.0 / voices as f64;
+    for sample in &mut output {
+        *sample *= gain;
+    }
+    output
+}
+
+/// Renders one oscillator voice with its frequency scaled by `ratio`.
+fn generate_voice(
+    params: &OscillatorParams<'_>,
+    ratio: f64,
+    phase: f64,
     num_samples: usize,
     sample_rate: f64,
     rng: &mut rand_pcg::Pcg32,
 ) -> Vec<f64> {
-    let sweep = freq_sweep.map(|s| {
+    let frequency = params.frequency * ratio;
+    let sweep = params.freq_sweep.map(|s| {
         let curve = convert_sweep_curve(&s.curve);
-        FrequencySweep::new(frequency, s.end_freq, curve)
+        FrequencySweep::new(frequency, s.end_freq * ratio, curve)
     });
+    let anti_alias = params.anti_alias;
+    let band_limited = anti_alias != AntiAliasMode::None;
 
-    match waveform {
+    match params.waveform {
         Waveform::Sine => {
-            if let Some(s) = sweep {
-                SineSynth::with_sweep(frequency, s.end_freq, s.curve).synthesize(
-                    num_samples,
-                    sample_rate,
-                    rng,
-                )
+            let synth = if let Some(s) = sweep {
+                SineSynth::with_sweep(frequency, s.end_freq, s.curve)
             } else {
-                SineSynth::new(frequency).synthesize(num_samples, sample_rate, rng)
-            }
+                SineSynth::new(frequency)
+            };
+            synth
+                .with_phase(phase)
+                .synthesize(num_samples, sample_rate, rng)
         }
         Waveform::Square | Waveform::Pulse => {
-            let duty_cycle = duty.unwrap_or(0.5);
+            let duty_cycle = params.duty.unwrap_or(0.5);
             let mut synth = if let Some(s) = sweep {
                 SquareSynth::with_sweep(frequency, s.end_freq, s.curve)
             } else {
@@ -43,29 +107,59 @@ pub fn generate_oscillator_samples(
             };
             // Set duty cycle even for sweep case
             synth.duty = duty_cycle;
-            synth.synthesize(num_samples, sample_rate, rng)
+            synth.band_limited = band_limited;
+            render(
+                &synth.with_phase(phase),
+                anti_alias,
+                num_samples,
+                sample_rate,
+                rng,
+            )
         }
         Waveform::Sawtooth => {
-            if let Some(s) = sweep {
-                SawSynth::with_sweep(frequency, s.end_freq, s.curve).synthesize(
-                    num_samples,
-                    sample_rate,
-                    rng,
-                )
+            let mut synth = if let Some(s) = sweep {
+                SawSynth::with_sweep(frequency, s.end_freq, s.curve)
             } else {
-                SawSynth::new(frequency).synthesize(num_samples, sample_rate, rng)
-            }
+                SawSynth::new(frequency)
+            };
+            synth.band_limited = band_limited;
+            render(
+                &synth.with_phase(phase),
+                anti_alias,
+                num_samples,
+                sample_rate,
+                rng,
+            )
         }
         Waveform::Triangle => {
-            if let Some(s) = sweep {
-                TriangleSynth::with_sweep(frequency, s.end_freq, s.curve).synthesize(
-                    num_samples,
-                    sample_rate,
-                    rng,
-                )
+            let synth = if let Some(s) = sweep {
+                TriangleSynth::with_sweep(frequency, s.end_freq, s.curve)
             } else {
-                TriangleSynth::new(frequency).synthesize(num_samples, sample_rate, rng)
-            }
+                TriangleSynth::new(frequency)
+            };
+            render(
+                &synth.with_phase(phase),
+                anti_alias,
+                num_samples,
+                sample_rate,
+                rng,
+            )
+        }
+    }
+}
+
+/// Renders a synthesizer directly or oversampled, depending on the mode.
+fn render<S: Synthesizer>(
+    synth: &S,
+    anti_alias: AntiAliasMode,
+    num_samples: usize,
+    sample_rate: f64,
+    rng: &mut rand_pcg::Pcg32,
+) -> Vec<f64> {
+    match anti_alias {
+        AntiAliasMode::Oversample => synthesize_oversampled(synth, num_samples, sample_rate, rng),
+        AntiAliasMode::None | AntiAliasMode::Polyblep => {
+            synth.synthesize(num_samples, sample_rate, rng)
         }
     }
 }
@@ -1,12 +1,16 @@
 //! Tests for audio generation.
 
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params, DetuneCurve, Envelope, NoiseType, Synthesis, Waveform,
+    Arpeggio, ArpeggioMode, AudioLayer, AudioV1Params, ChannelMode, Compressor, DetuneCurve,
+    Effect, Envelope, Filter, GainBreakpoint, Limiter, ModulationDestination, ModulationRoute,
+    ModulationSource, NoiseType, NoteSpec, Sidechain, StereoWidenerMode, Synthesis, TailFade,
+    Waveform,
 };
 use speccade_spec::recipe::Recipe;
 use speccade_spec::{AssetType, OutputFormat, OutputSpec, Spec};
 
 use super::{generate, generate_from_params, generate_preview};
+use crate::test_spectrum::{power_spectrum, rms};
 
 fn create_test_spec() -> Spec {
     let params = AudioV1Params {
@@ -25,11 +29,19 @@ fn create_test_spec() -> Spec {
                 decay: 0.1,
                 sustain: 0.5,
                 release: 0.2,
-            },
+            }
+            .into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -38,6 +50,11 @@ fn create_test_spec() -> Spec {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     Spec::builder("test-sfx", AssetType::Audio)
@@ -109,6 +126,43 @@ fn test_generate_preview_no_truncation_when_longer() {
     );
 }
 
+#[test]
+fn test_generate_preview_matches_head_of_full_render() {
+    let mut spec = create_test_spec();
+    if let Some(recipe) = spec.recipe.as_mut() {
+        let mut params: AudioV1Params = serde_json::from_value(recipe.params.clone()).unwrap();
+        params.duration_seconds = 2.0;
+        // Seeded noise, a release past the preview point, and a reverb tail all
+        // depend on the full render.
+        let mut noise = params.layers[0].clone();
+        noise.synthesis = Synthesis::NoiseBurst {
+            noise_type: NoiseType::White,
+            filter: None,
+        };
+        noise.volume = 0.3;
+        params.layers.push(noise);
+        params.effects = vec![Effect::Reverb {
+            room_size: 0.8,
+            damping: 0.5,
+            wet: 0.4,
+            dry: None,
+            width: 1.0,
+        }];
+        recipe.params = serde_json::to_value(&params).unwrap();
+    }
+
+    let full = generate(&spec).expect("should generate full");
+    let preview = generate_preview(&spec, 1.0).expect("should generate preview");
+
+    let full_pcm = crate::wav::extract_pcm_data(&full.wav.wav_data).unwrap();
+    let preview_pcm = crate::wav::extract_pcm_data(&preview.wav.wav_data).unwrap();
+
+    // One second of 16-bit stereo.
+    assert!(preview.wav.is_stereo);
+    assert_eq!(preview_pcm.len(), 44100 * 2 * 2);
+    assert_eq!(preview_pcm, &full_pcm[..preview_pcm.len()]);
+}
+
 #[test]
 fn test_generate_different_seeds() {
     let params = AudioV1Params {
@@ -120,11 +174,18 @@ fn test_generate_different_seeds() {
                 noise_type: NoiseType::White,
                 filter: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -133,6 +194,11 @@ fn test_generate_different_seeds() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec1 = Spec::builder("test-sfx", AssetType::Audio)
@@ -172,12 +238,22 @@ fn test_generate_stereo() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.5,
                 pan: -0.8, // Left
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             },
             AudioLayer {
@@ -187,23 +263,300 @@ fn test_generate_stereo() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.5,
                 pan: 0.8, // Right
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             },
         ],
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
     assert!(result.wav.is_stereo);
 }
 
+#[test]
+fn test_generate_tail_fade_ends_in_silence() {
+    let mut params = AudioV1Params {
+        duration_seconds: 0.1,
+        sample_rate: 44100,
+        master_filter: None,
+        pitch_envelope: None,
+        base_note: None,
+        loop_config: None,
+        generate_loop_points: false,
+        layers: vec![AudioLayer {
+            synthesis: Synthesis::Oscillator {
+                waveform: Waveform::Sine,
+                frequency: 330.0,
+                freq_sweep: None,
+                detune: None,
+                duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
+            },
+            envelope: Envelope {
+                attack: 0.005,
+                decay: 0.0,
+                sustain: 1.0,
+                release: 0.0,
+            }
+            .into(),
+            volume: 0.8,
+            pan: 0.0,
+            delay: None,
+            filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
+            lfo: None,
+        }],
+        effects: vec![],
+        post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
+    };
+
+    let tail_peak = |pcm: &[u8]| {
+        pcm[pcm.len() - 441 * 2..]
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]).unsigned_abs())
+            .max()
+            .unwrap()
+    };
+
+    let plain = generate_from_params(&params, 42).expect("should generate");
+    let plain_pcm = crate::wav::extract_pcm_data(&plain.wav.wav_data).unwrap();
+    assert!(
+        tail_peak(plain_pcm) > 10_000,
+        "sustained sine should ring at the end"
+    );
+
+    params.tail_fade = Some(TailFade::default());
+    let faded = generate_from_params(&params, 42).expect("should generate");
+    let faded_pcm = crate::wav::extract_pcm_data(&faded.wav.wav_data).unwrap();
+
+    assert_eq!(faded_pcm.len(), plain_pcm.len());
+    assert_eq!(&faded_pcm[faded_pcm.len() - 2..], &[0, 0]);
+    assert_eq!(
+        faded_pcm[..faded_pcm.len() - 441 * 2],
+        plain_pcm[..plain_pcm.len() - 441 * 2]
+    );
+}
+
+#[test]
+fn test_generate_rejects_positive_tail_threshold() {
+    let spec = create_test_spec();
+    let mut params: AudioV1Params =
+        serde_json::from_value(spec.recipe.as_ref().unwrap().params.clone()).unwrap();
+    params.tail_fade = Some(TailFade {
+        threshold_db: 6.0,
+        fade_ms: 10.0,
+    });
+
+    let err = generate_from_params(&params, 42).unwrap_err();
+    assert!(err.to_string().contains("tail_fade.threshold_db"));
+}
+
+fn single_oscillator_params(waveform: Waveform, duty: Option<f64>) -> AudioV1Params {
+    AudioV1Params {
+        duration_seconds: 0.5,
+        sample_rate: 44100,
+        master_filter: None,
+        pitch_envelope: None,
+        base_note: None,
+        loop_config: None,
+        generate_loop_points: false,
+        layers: vec![AudioLayer {
+            synthesis: Synthesis::Oscillator {
+                waveform,
+                frequency: 220.0,
+                freq_sweep: None,
+                detune: None,
+                duty,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
+            },
+            envelope: Envelope {
+                attack: 0.0,
+                decay: 0.0,
+                sustain: 1.0,
+                release: 0.0,
+            }
+            .into(),
+            volume: 0.8,
+            pan: 0.0,
+            delay: None,
+            filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
+            lfo: None,
+        }],
+        effects: vec![],
+        post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
+    }
+}
+
+/// Decodes a mono 16-bit WAV into normalized samples.
+fn decode_mono(result: &super::GenerateResult) -> Vec<f64> {
+    crate::wav::extract_pcm_data(&result.wav.wav_data)
+        .unwrap()
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]) as f64 / 32767.0)
+        .collect()
+}
+
+fn dc_offset(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+#[test]
+fn test_generate_dc_blocker_removes_offset_from_biased_square() {
+    // A 20% duty pulse spends most of each cycle low, so its mean is strongly negative.
+    let mut params = single_oscillator_params(Waveform::Pulse, Some(0.2));
+
+    let biased = decode_mono(&generate_from_params(&params, 42).expect("should generate"));
+    params.dc_blocker = true;
+    let blocked = decode_mono(&generate_from_params(&params, 42).expect("should generate"));
+
+    assert!(
+        dc_offset(&biased).abs() > 0.1,
+        "biased square should have a large DC offset, got {}",
+        dc_offset(&biased)
+    );
+    assert!(
+        dc_offset(&blocked).abs() < 0.01,
+        "DC offset should be removed, got {}",
+        dc_offset(&blocked)
+    );
+}
+
+#[test]
+fn test_generate_master_compressor_reports_gain_reduction() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    let plain = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(plain.master_gain_reduction_db, None);
+
+    params.master_compressor = Some(Compressor {
+        threshold_db: -24.0,
+        ratio: 4.0,
+        attack_ms: 1.0,
+        release_ms: 100.0,
+        makeup_db: 0.0,
+        knee_db: 6.0,
+    });
+    let compressed = generate_from_params(&params, 42).expect("should generate");
+    let reduction = compressed
+        .master_gain_reduction_db
+        .expect("compressor should report gain reduction");
+    assert!(
+        reduction > 1.0,
+        "expected gain reduction, got {}",
+        reduction
+    );
+    assert_ne!(compressed.wav.pcm_hash, plain.wav.pcm_hash);
+
+    let again = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(again.wav.pcm_hash, compressed.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_master_limiter_holds_true_peak_under_ceiling() {
+    let mut params = single_oscillator_params(Waveform::Sawtooth, None);
+    let plain = generate_from_params(&params, 42).expect("should generate");
+    // Output is normalized to -3 dBFS sample peak
+    assert!(
+        (plain.sample_peak_db - -3.0).abs() < 0.01,
+        "{}",
+        plain.sample_peak_db
+    );
+    assert!(plain.true_peak_db >= plain.sample_peak_db);
+
+    params.master_limiter = Some(Limiter {
+        ceiling_db: -6.0,
+        lookahead_ms: 5.0,
+    });
+    let limited = generate_from_params(&params, 42).expect("should generate");
+    assert!(
+        limited.true_peak_db <= -6.0 + 0.05,
+        "true peak {} dBTP over the ceiling",
+        limited.true_peak_db
+    );
+    assert!(limited.sample_peak_db <= limited.true_peak_db);
+
+    let again = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(again.wav.pcm_hash, limited.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_dc_blocker_leaves_dc_free_signal_unchanged() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+
+    let plain = decode_mono(&generate_from_params(&params, 42).expect("should generate"));
+    params.dc_blocker = true;
+    let blocked = decode_mono(&generate_from_params(&params, 42).expect("should generate"));
+
+    assert!(dc_offset(&plain).abs() < 0.01);
+    assert!(dc_offset(&blocked).abs() < 0.01);
+
+    // A 10 Hz high-pass only adds a small phase shift and onset transient at 220 Hz, which
+    // peak normalization turns into a few percent of level change at most.
+    let rms = |s: &[f64]| (s.iter().map(|x| x * x).sum::<f64>() / s.len() as f64).sqrt();
+    let level_ratio = rms(&blocked) / rms(&plain);
+    assert!(
+        (level_ratio - 1.0).abs() < 0.05,
+        "DC-free signal level changed: ratio {}",
+        level_ratio
+    );
+
+    let diff: Vec<f64> = plain.iter().zip(&blocked).map(|(a, b)| a - b).collect();
+    assert!(
+        rms(&diff) < 0.1 * rms(&plain),
+        "DC-free signal changed too much: diff rms {} vs signal rms {}",
+        rms(&diff),
+        rms(&plain)
+    );
+}
+
 #[test]
 fn test_generate_loop_points_enabled_produces_loop_metadata() {
     let params = AudioV1Params {
@@ -217,17 +570,28 @@ fn test_generate_loop_points_enabled_produces_loop_metadata() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
             envelope: Envelope {
                 attack: 0.01,
                 decay: 0.05,
                 sustain: 0.8,
                 release: 0.1,
-            },
+            }
+            .into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -236,6 +600,11 @@ fn test_generate_loop_points_enabled_produces_loop_metadata() {
         generate_loop_points: true,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
@@ -267,11 +636,19 @@ fn test_generate_supersaw_unison() {
                 decay: 0.1,
                 sustain: 0.7,
                 release: 0.2,
-            },
+            }
+            .into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -280,6 +657,11 @@ fn test_generate_supersaw_unison() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
@@ -303,11 +685,18 @@ fn test_generate_supersaw_unison_determinism() {
                 spread: 0.5,
                 detune_curve: DetuneCurve::Exp2,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -316,6 +705,11 @@ fn test_generate_supersaw_unison_determinism() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result1 = generate_from_params(&params, 42).expect("should generate");
@@ -340,11 +734,18 @@ fn test_generate_supersaw_unison_single_voice() {
                 spread: 0.8,        // Ignored for single voice
                 detune_curve: DetuneCurve::Linear,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0, // Center pan
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -353,6 +754,11 @@ fn test_generate_supersaw_unison_single_voice() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
@@ -375,11 +781,18 @@ fn test_generate_supersaw_unison_with_delay() {
                 spread: 0.6,
                 detune_curve: DetuneCurve::Linear,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: Some(0.1), // 100ms delay
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -388,6 +801,11 @@ fn test_generate_supersaw_unison_with_delay() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
@@ -418,11 +836,19 @@ fn test_granular_stereo_with_pan_spread() {
                 decay: 0.05,
                 sustain: 0.7,
                 release: 0.1,
-            },
+            }
+            .into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -431,6 +857,11 @@ fn test_granular_stereo_with_pan_spread() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
@@ -462,11 +893,18 @@ fn test_granular_mono_without_pan_spread() {
                 position_spread: 0.0,
                 pan_spread: 0.0, // No pan spread = mono output
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0, // Center pan
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -475,6 +913,11 @@ fn test_granular_mono_without_pan_spread() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42).expect("should generate");
@@ -507,11 +950,18 @@ fn test_granular_stereo_determinism() {
                 position_spread: 0.1,
                 pan_spread: 0.5,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -520,6 +970,11 @@ fn test_granular_stereo_determinism() {
         generate_loop_points: false,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result1 = generate_from_params(&params, 42).expect("should generate");
@@ -529,3 +984,777 @@ fn test_granular_stereo_determinism() {
     assert_eq!(result1.wav.pcm_hash, result2.wav.pcm_hash);
     assert!(result1.wav.is_stereo);
 }
+
+/// Peak absolute sample in a window around `time_seconds`.
+fn peak_around(samples: &[f64], sample_rate: f64, time_seconds: f64) -> f64 {
+    let center = (time_seconds * sample_rate) as usize;
+    let half_window = (0.005 * sample_rate) as usize;
+    let start = center.saturating_sub(half_window);
+    let end = (center + half_window).min(samples.len());
+    samples[start..end]
+        .iter()
+        .fold(0.0_f64, |peak, s| peak.max(s.abs()))
+}
+
+#[test]
+fn test_generate_solo_silences_other_layers() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    let mut second = params.layers[0].clone();
+    second.synthesis = Synthesis::Oscillator {
+        waveform: Waveform::Square,
+        frequency: 330.0,
+        freq_sweep: None,
+        detune: None,
+        duty: None,
+        anti_alias: None,
+        detune_voices: None,
+        detune_spread: None,
+    };
+    params.layers.push(second);
+
+    let mixed = generate_from_params(&params, 42).expect("should generate");
+
+    let mut soloed = params.clone();
+    soloed.layers[1].solo = true;
+    let soloed = generate_from_params(&soloed, 42).expect("should generate");
+
+    let mut muted = params.clone();
+    muted.layers[0].muted = true;
+    let muted = generate_from_params(&muted, 42).expect("should generate");
+
+    // Soloing the second layer is the same as muting the first.
+    assert_eq!(soloed.wav.pcm_hash, muted.wav.pcm_hash);
+    assert_ne!(soloed.wav.pcm_hash, mixed.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_gain_automation_breakpoints() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.layers[0].gain_automation = Some(vec![
+        GainBreakpoint {
+            time: 0.0,
+            gain: 1.0,
+        },
+        GainBreakpoint {
+            time: 0.2,
+            gain: 0.0,
+        },
+        GainBreakpoint {
+            time: 0.4,
+            gain: 0.5,
+        },
+    ]);
+
+    let samples = decode_mono(&generate_from_params(&params, 42).expect("should generate"));
+    let sample_rate = params.sample_rate as f64;
+
+    let start = peak_around(&samples, sample_rate, 0.005);
+    let silent = peak_around(&samples, sample_rate, 0.2);
+    let half = peak_around(&samples, sample_rate, 0.4);
+    let held = peak_around(&samples, sample_rate, 0.48);
+
+    assert!(
+        silent < 0.05 * start,
+        "gain 0 breakpoint: {} vs {}",
+        silent,
+        start
+    );
+    assert!(
+        (half / start - 0.5).abs() < 0.05,
+        "gain 0.5 breakpoint: ratio {}",
+        half / start
+    );
+    // The last gain is held after the final breakpoint.
+    assert!(
+        (held / half - 1.0).abs() < 0.05,
+        "held ratio {}",
+        held / half
+    );
+}
+
+#[test]
+fn test_generate_empty_modulation_matrix_is_noop() {
+    let params = single_oscillator_params(Waveform::Sawtooth, None);
+
+    let mut value = serde_json::to_value(&params).unwrap();
+    value["layers"][0]["modulation_matrix"] = serde_json::json!([]);
+    let with_empty: AudioV1Params = serde_json::from_value(value).unwrap();
+    assert!(with_empty.layers[0].modulation_matrix.is_empty());
+
+    let baseline = generate_from_params(&params, 42).expect("should generate");
+    let result = generate_from_params(&with_empty, 42).expect("should generate");
+    assert_eq!(baseline.wav.pcm_hash, result.wav.pcm_hash);
+}
+
+/// Instantaneous frequency per cycle, measured between upward zero crossings.
+fn cycle_frequencies(samples: &[f64], sample_rate: f64) -> Vec<f64> {
+    let crossings: Vec<f64> = samples
+        .windows(2)
+        .enumerate()
+        .filter(|(_, w)| w[0] < 0.0 && w[1] >= 0.0)
+        .map(|(i, w)| i as f64 + w[0] / (w[0] - w[1]))
+        .collect();
+    crossings
+        .windows(2)
+        .map(|w| sample_rate / (w[1] - w[0]))
+        .collect()
+}
+
+#[test]
+fn test_generate_modulation_matrix_pitch_lfo_produces_vibrato() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.duration_seconds = 1.0;
+    params.layers[0].modulation_matrix = vec![ModulationRoute {
+        source: ModulationSource::Lfo {
+            waveform: Waveform::Sine,
+            rate: 4.0,
+            phase: None,
+        },
+        destination: ModulationDestination::Pitch,
+        depth: 2.0,
+    }];
+
+    let samples = decode_mono(&generate_from_params(&params, 42).expect("should generate"));
+    let frequencies = cycle_frequencies(&samples, params.sample_rate as f64);
+
+    // +/-2 semitones around 220 Hz is roughly 196-247 Hz.
+    let min = frequencies.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = frequencies
+        .iter()
+        .copied()
+        .fold(f64::NEG_INFINITY, f64::max);
+    assert!(min > 190.0 && min < 205.0, "min frequency {}", min);
+    assert!(max > 238.0 && max < 255.0, "max frequency {}", max);
+
+    // The frequency oscillates at the LFO rate: 4 cycles over one second.
+    let mean = frequencies.iter().sum::<f64>() / frequencies.len() as f64;
+    let vibrato_cycles = frequencies
+        .windows(2)
+        .filter(|w| w[0] < mean && w[1] >= mean)
+        .count();
+    assert!(
+        (3..=5).contains(&vibrato_cycles),
+        "expected ~4 vibrato cycles, got {}",
+        vibrato_cycles
+    );
+}
+
+fn arpeggio(pattern: Vec<f64>, gate: f64, mode: ArpeggioMode) -> Arpeggio {
+    Arpeggio {
+        pattern,
+        rate_hz: 10.0,
+        gate,
+        mode,
+    }
+}
+
+#[test]
+fn test_generate_layer_arpeggio_changes_pitch_at_step_offsets() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.layers[0].arpeggio = Some(arpeggio(vec![0.0, 4.0, 7.0], 1.0, ArpeggioMode::Up));
+
+    // 10 steps per second at 44.1 kHz: steps start at samples 0, 4410 and 8820.
+    let sample_rate = 44100.0;
+    let samples = super::generate_layer(&params.layers[0], 0, 13230, sample_rate, 42)
+        .expect("should generate")
+        .to_mono();
+
+    let expected = [
+        (0..4410, 220.0),
+        (4410..8820, 220.0 * 2.0_f64.powf(4.0 / 12.0)),
+        (8820..13230, 220.0 * 2.0_f64.powf(7.0 / 12.0)),
+    ];
+    for (range, frequency) in expected {
+        // Skip the cycles that straddle the step boundaries.
+        let step = &samples[range.start + 250..range.end - 250];
+        let frequencies = cycle_frequencies(step, sample_rate);
+        assert!(!frequencies.is_empty());
+        for measured in frequencies {
+            assert!(
+                (measured - frequency).abs() < 1.0,
+                "step at {}: expected {} Hz, got {}",
+                range.start,
+                frequency,
+                measured
+            );
+        }
+    }
+
+    let again = super::generate_layer(&params.layers[0], 0, 13230, sample_rate, 42)
+        .expect("should generate")
+        .to_mono();
+    assert_eq!(samples, again);
+}
+
+#[test]
+fn test_generate_layer_arpeggio_gate_silences_step_tails() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.layers[0].arpeggio = Some(arpeggio(vec![0.0, 7.0], 0.5, ArpeggioMode::Up));
+
+    let samples = super::generate_layer(&params.layers[0], 0, 8820, 44100.0, 42)
+        .expect("should generate")
+        .to_mono();
+
+    // Each 4410-sample step sounds for its first half only.
+    for start in [0, 4410] {
+        let sounding = &samples[start..start + 2205];
+        let gated = &samples[start + 2205..start + 4410];
+        assert!(rms(sounding) > 0.5, "step at {} should sound", start);
+        assert!(gated.iter().all(|s| *s == 0.0), "step at {} tail", start);
+    }
+}
+
+#[test]
+fn test_arpeggio_step_order_by_mode() {
+    let offsets = |mode| {
+        let arp = arpeggio(vec![7.0, 0.0, 4.0], 1.0, mode);
+        super::arpeggio::arpeggio_steps(&arp, 22050, 44100.0, 42)
+            .iter()
+            .map(|step| step.semitones)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(offsets(ArpeggioMode::Up), [0.0, 4.0, 7.0, 0.0, 4.0]);
+    assert_eq!(offsets(ArpeggioMode::Down), [7.0, 4.0, 0.0, 7.0, 4.0]);
+    assert_eq!(offsets(ArpeggioMode::UpDown), [0.0, 4.0, 7.0, 4.0, 0.0]);
+
+    let random = offsets(ArpeggioMode::Random);
+    assert_eq!(random, offsets(ArpeggioMode::Random));
+    assert!(random.iter().all(|s| [0.0, 4.0, 7.0].contains(s)));
+}
+
+#[test]
+fn test_generate_layer_arpeggio_rejects_non_oscillator_synthesis() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.layers[0].synthesis = Synthesis::NoiseBurst {
+        noise_type: NoiseType::White,
+        filter: None,
+    };
+    params.layers[0].arpeggio = Some(arpeggio(vec![0.0, 12.0], 1.0, ArpeggioMode::Up));
+
+    assert!(super::generate_layer(&params.layers[0], 0, 4410, 44100.0, 42).is_err());
+}
+
+fn keytracked_lowpass(keytrack: Option<f64>) -> Filter {
+    Filter::Lowpass {
+        cutoff: 500.0,
+        resonance: 0.707,
+        cutoff_end: None,
+        keytrack,
+    }
+}
+
+#[test]
+fn test_generate_filter_keytrack_zero_matches_untracked() {
+    let mut params = single_oscillator_params(Waveform::Sawtooth, None);
+    params.layers[0].filter = Some(keytracked_lowpass(None));
+    let baseline = generate_from_params(&params, 42).expect("should generate");
+
+    params.layers[0].filter = Some(keytracked_lowpass(Some(0.0)));
+    let result = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(baseline.wav.pcm_hash, result.wav.pcm_hash);
+}
+
+#[test]
+fn test_filter_keytrack_cutoff_follows_note_frequency() {
+    let reference = 220.0;
+    let cutoff_at = |frequency: f64, keytrack: f64| {
+        let mut layer = single_oscillator_params(Waveform::Sawtooth, None).layers[0].clone();
+        if let Synthesis::Oscillator { frequency: f, .. } = &mut layer.synthesis {
+            *f = frequency;
+        }
+        layer.filter = Some(keytracked_lowpass(Some(keytrack)));
+        match super::filters::resolve_layer_keytrack(&layer, reference)
+            .filter
+            .clone()
+        {
+            Some(Filter::Lowpass { cutoff, .. }) => cutoff,
+            other => panic!("expected lowpass, got {:?}", other),
+        }
+    };
+
+    // At the reference note the cutoff is unchanged.
+    assert!((cutoff_at(220.0, 1.0) - 500.0).abs() < 1e-9);
+    // Full keytracking: an octave up doubles the cutoff.
+    assert!((cutoff_at(440.0, 1.0) - 1000.0).abs() < 1e-9);
+    // Half keytracking: an octave up opens the filter by half an octave.
+    assert!((cutoff_at(440.0, 0.5) - 500.0 * 2.0_f64.sqrt()).abs() < 1e-9);
+    // Two octaves up opens it proportionally further.
+    assert!((cutoff_at(880.0, 1.0) - 2000.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_generate_filter_keytrack_opens_filter_for_higher_notes() {
+    // A 1760 Hz sine sits well above the 500 Hz cutoff; full keytracking from
+    // A3 (220 Hz) moves the cutoff to 4 kHz and lets it through.
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.base_note = Some(NoteSpec::NoteName("A3".to_string()));
+    if let Synthesis::Oscillator { frequency, .. } = &mut params.layers[0].synthesis {
+        *frequency = 1760.0;
+    }
+
+    params.layers[0].filter = Some(keytracked_lowpass(None));
+    let fixed = rms(&decode_mono(
+        &generate_from_params(&params, 42).expect("should generate"),
+    ));
+    params.layers[0].filter = Some(keytracked_lowpass(Some(1.0)));
+    let tracked = rms(&decode_mono(
+        &generate_from_params(&params, 42).expect("should generate"),
+    ));
+
+    assert!(
+        tracked > fixed * 2.0,
+        "keytracked rms {} should be well above fixed-cutoff rms {}",
+        tracked,
+        fixed
+    );
+}
+
+fn unison_params(voices: Option<u8>, spread: Option<f64>) -> AudioV1Params {
+    let mut params = single_oscillator_params(Waveform::Sawtooth, None);
+    params.duration_seconds = 1.0;
+    if let Synthesis::Oscillator {
+        detune_voices,
+        detune_spread,
+        ..
+    } = &mut params.layers[0].synthesis
+    {
+        *detune_voices = voices;
+        *detune_spread = spread;
+    }
+    params
+}
+
+#[test]
+fn test_generate_unison_single_voice_matches_plain_oscillator() {
+    let plain = generate_from_params(&unison_params(None, None), 42).expect("should generate");
+    let single =
+        generate_from_params(&unison_params(Some(1), Some(25.0)), 42).expect("should generate");
+    assert_eq!(plain.wav.pcm_hash, single.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_unison_is_deterministic() {
+    let params = unison_params(Some(7), Some(20.0));
+    let a = generate_from_params(&params, 42).expect("should generate");
+    let b = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(a.wav.pcm_hash, b.wav.pcm_hash);
+
+    // Voice phases come from the seed.
+    let c = generate_from_params(&params, 43).expect("should generate");
+    assert_ne!(a.wav.pcm_hash, c.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_unison_widens_spectrum_around_fundamental() {
+    let sample_rate = 44100.0;
+    let plain = power_spectrum(&decode_mono(
+        &generate_from_params(&unison_params(None, None), 42).expect("should generate"),
+    ));
+    let unison = power_spectrum(&decode_mono(
+        &generate_from_params(&unison_params(Some(7), Some(30.0)), 42).expect("should generate"),
+    ));
+    let bin_hz = sample_rate / (2 * plain.len()) as f64;
+
+    // Look at the band around the 220 Hz fundamental.
+    let lo = (190.0 / bin_hz) as usize;
+    let hi = (250.0 / bin_hz) as usize;
+    let peak_bin = |spectrum: &[f64]| {
+        (lo..hi)
+            .max_by(|&a, &b| spectrum[a].total_cmp(&spectrum[b]))
+            .unwrap()
+    };
+    // Fraction of the band's energy more than 2 bins away from the peak.
+    let spread = |spectrum: &[f64]| {
+        let peak = peak_bin(spectrum);
+        let total: f64 = spectrum[lo..hi].iter().sum();
+        let outside: f64 = (lo..hi)
+            .filter(|&k| k.abs_diff(peak) > 2)
+            .map(|k| spectrum[k])
+            .sum();
+        outside / total
+    };
+
+    let plain_peak_hz = peak_bin(&plain) as f64 * bin_hz;
+    let unison_peak_hz = peak_bin(&unison) as f64 * bin_hz;
+    assert!(
+        (plain_peak_hz - 220.0).abs() < 2.0,
+        "plain peak {}",
+        plain_peak_hz
+    );
+    assert!(
+        (unison_peak_hz - 220.0).abs() < 2.0,
+        "unison peak {}",
+        unison_peak_hz
+    );
+
+    let plain_spread = spread(&plain);
+    let unison_spread = spread(&unison);
+    assert!(
+        unison_spread > plain_spread * 2.0,
+        "unison spread {} should exceed plain spread {}",
+        unison_spread,
+        plain_spread
+    );
+}
+
+/// Decodes a stereo 16-bit WAV into normalized left/right samples.
+fn decode_stereo(result: &super::GenerateResult) -> (Vec<f64>, Vec<f64>) {
+    let interleaved: Vec<f64> = crate::wav::extract_pcm_data(&result.wav.wav_data)
+        .unwrap()
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]) as f64 / 32767.0)
+        .collect();
+    interleaved
+        .chunks_exact(2)
+        .map(|frame| (frame[0], frame[1]))
+        .unzip()
+}
+
+/// Two oscillators panned hard left and right.
+fn hard_panned_params() -> AudioV1Params {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    let mut right = params.layers[0].clone();
+    right.synthesis = Synthesis::Oscillator {
+        waveform: Waveform::Sine,
+        frequency: 330.0,
+        freq_sweep: None,
+        detune: None,
+        duty: None,
+        anti_alias: None,
+        detune_voices: None,
+        detune_spread: None,
+    };
+    params.layers[0].pan = -1.0;
+    right.pan = 1.0;
+    params.layers.push(right);
+    params
+}
+
+#[test]
+fn test_generate_force_mono_collapses_stereo_mix() {
+    let mut params = hard_panned_params();
+    let stereo = generate_from_params(&params, 42).expect("should generate");
+    assert!(stereo.wav.is_stereo);
+
+    params.channels = ChannelMode::ForceMono;
+    let mono = generate_from_params(&params, 42).expect("should generate");
+    assert!(!mono.wav.is_stereo);
+
+    // The forced mono output is the (L + R) / 2 sum of the stereo mix, renormalized.
+    let (left, right) = decode_stereo(&stereo);
+    let mut expected: Vec<f64> = left
+        .iter()
+        .zip(&right)
+        .map(|(l, r)| (l + r) * 0.5)
+        .collect();
+    crate::mixer::normalize(&mut expected, -3.0);
+    let actual = decode_mono(&mono);
+    assert_eq!(actual.len(), expected.len());
+    let max_error = actual
+        .iter()
+        .zip(&expected)
+        .map(|(a, e)| (a - e).abs())
+        .fold(0.0_f64, f64::max);
+    assert!(max_error < 1e-3, "max error {}", max_error);
+
+    // Mono compatibility is measured on the stereo mix before the downmix.
+    assert_eq!(mono.mono_compatibility, stereo.mono_compatibility);
+    assert!(!stereo.mono_compatibility.unwrap().has_phase_cancellation());
+}
+
+#[test]
+fn test_generate_force_stereo_duplicates_mono_mix() {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    let mono = generate_from_params(&params, 42).expect("should generate");
+    assert!(!mono.wav.is_stereo);
+    assert!(mono.mono_compatibility.is_none());
+
+    params.channels = ChannelMode::ForceStereo;
+    let stereo = generate_from_params(&params, 42).expect("should generate");
+    assert!(stereo.wav.is_stereo);
+
+    let (left, right) = decode_stereo(&stereo);
+    assert_eq!(left, right);
+    assert_eq!(left, decode_mono(&mono));
+}
+
+#[test]
+fn test_generate_mono_compat_flags_anti_phase_stereo() {
+    // A Haas delay of exactly half a period puts the right channel in anti-phase.
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.layers[0].synthesis = Synthesis::Oscillator {
+        waveform: Waveform::Sine,
+        frequency: 44100.0 / 88.0,
+        freq_sweep: None,
+        detune: None,
+        duty: None,
+        anti_alias: None,
+        detune_voices: None,
+        detune_spread: None,
+    };
+    params.effects = vec![Effect::StereoWidener {
+        width: 1.0,
+        mode: StereoWidenerMode::Haas,
+        delay_ms: 1.0,
+    }];
+
+    let result = generate_from_params(&params, 42).expect("should generate");
+    let compat = result.mono_compatibility.expect("stereo mix");
+    assert!(
+        compat.has_phase_cancellation(),
+        "loss {} dB",
+        compat.loss_db()
+    );
+    assert!(compat.loss_db() > 20.0, "loss {} dB", compat.loss_db());
+}
+
+#[test]
+fn test_inspect_layer_outputs_sum_to_final_mix() {
+    let mut params = hard_panned_params();
+    params.layers[0].pan = -0.4;
+    params.layers[1].pan = 0.7;
+    params.layers[1].volume = 0.5;
+    params.layers[1].delay = Some(0.05);
+
+    let inspection = super::inspect_params(&params, 42).unwrap();
+    assert_eq!(inspection.layers.len(), 2);
+
+    let (final_left, final_right) = decode_stereo(&generate_from_params(&params, 42).unwrap());
+    let layer_outputs: Vec<_> = inspection
+        .layers
+        .iter()
+        .map(|layer| layer.output.as_ref().unwrap().to_stereo())
+        .collect();
+
+    for i in 0..final_left.len() {
+        let left: f64 = layer_outputs.iter().map(|output| output.left[i]).sum();
+        let right: f64 = layer_outputs.iter().map(|output| output.right[i]).sum();
+        assert!((left - final_left[i]).abs() < 1e-4, "left sample {}", i);
+        assert!((right - final_right[i]).abs() < 1e-4, "right sample {}", i);
+    }
+
+    // The delayed layer is silent, with a zero envelope, until it starts
+    let delay_samples = (0.05 * params.sample_rate as f64).floor() as usize;
+    assert!(inspection.layers[1].envelope[..delay_samples]
+        .iter()
+        .all(|&env| env == 0.0));
+    assert!(layer_outputs[1].left[..delay_samples]
+        .iter()
+        .all(|&s| s == 0.0));
+}
+
+/// Renders `params` on a rayon pool with `threads` threads and returns the PCM hash.
+#[cfg(feature = "parallel")]
+fn pcm_hash_with_threads(params: &AudioV1Params, threads: usize) -> String {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .unwrap()
+        .install(|| generate_from_params(params, 42).unwrap().wav.pcm_hash)
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_layers_match_serial_render() {
+    let mut params = single_oscillator_params(Waveform::Sawtooth, None);
+    let base = params.layers[0].clone();
+
+    let mut noise = base.clone();
+    noise.synthesis = Synthesis::NoiseBurst {
+        noise_type: NoiseType::Pink,
+        filter: None,
+    };
+    noise.volume = 0.3;
+    noise.pan = -0.5;
+    params.layers.push(noise);
+
+    let mut fm = base.clone();
+    fm.synthesis = Synthesis::FmSynth {
+        carrier_freq: 330.0,
+        modulator_freq: 660.0,
+        modulation_index: 3.0,
+        freq_sweep: None,
+    };
+    fm.delay = Some(0.1);
+    params.layers.push(fm);
+
+    let mut supersaw = base;
+    supersaw.synthesis = Synthesis::SupersawUnison {
+        frequency: 110.0,
+        voices: 5,
+        detune_cents: 20.0,
+        spread: 0.6,
+        detune_curve: DetuneCurve::Linear,
+    };
+    supersaw.volume = 0.4;
+    params.layers.push(supersaw);
+    assert_eq!(params.layers.len(), 4);
+
+    // A one-thread pool renders the layers one after another.
+    let serial = pcm_hash_with_threads(&params, 1);
+    for threads in [2, 4, 8] {
+        assert_eq!(
+            pcm_hash_with_threads(&params, threads),
+            serial,
+            "{} threads",
+            threads
+        );
+    }
+}
+
+fn render_rate_params(render_rate: Option<u32>) -> AudioV1Params {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    if let Synthesis::Oscillator { frequency, .. } = &mut params.layers[0].synthesis {
+        *frequency = 1000.0;
+    }
+    params.layers[0].render_rate = render_rate;
+    params
+}
+
+#[test]
+fn test_generate_render_rate_upsamples_without_images() {
+    let result =
+        generate_from_params(&render_rate_params(Some(22050)), 42).expect("should generate");
+    assert_eq!(result.wav.sample_rate, 44100);
+    assert_eq!(result.wav.num_samples, 22050);
+
+    let spectrum = power_spectrum(&decode_mono(&result));
+    let bin_hz = 44100.0 / (2 * spectrum.len()) as f64;
+    let fundamental = spectrum[(1000.0 / bin_hz).round() as usize];
+
+    // Nothing above the source Nyquist: the 21050 Hz image must be filtered out.
+    let nyquist_bin = (11025.0 / bin_hz).ceil() as usize;
+    let loudest = spectrum[nyquist_bin..]
+        .iter()
+        .fold(0.0_f64, |a, &b| a.max(b));
+    assert!(
+        loudest < fundamental * 1e-8,
+        "loudest bin above 11025 Hz {} vs fundamental {}",
+        loudest,
+        fundamental
+    );
+}
+
+#[test]
+fn test_generate_render_rate_is_deterministic() {
+    let params = render_rate_params(Some(32000));
+    let a = generate_from_params(&params, 42).expect("should generate");
+    let b = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(a.wav.pcm_hash, b.wav.pcm_hash);
+    assert_eq!(a.wav.num_samples, 22050);
+
+    // A render rate equal to the recipe rate is the plain render.
+    let plain = generate_from_params(&render_rate_params(None), 42).expect("should generate");
+    let same = generate_from_params(&render_rate_params(Some(44100)), 42).expect("should generate");
+    assert_eq!(plain.wav.pcm_hash, same.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_rejects_render_rate_above_sample_rate() {
+    let err = generate_from_params(&render_rate_params(Some(48000)), 42).unwrap_err();
+    assert!(err.to_string().contains("render_rate"), "{}", err);
+    assert!(generate_from_params(&render_rate_params(Some(500)), 42).is_err());
+}
+
+/// A muted 60 Hz kick retriggered every 250 ms ducking a sustained 440 Hz tone.
+fn sidechain_params() -> AudioV1Params {
+    let mut params = single_oscillator_params(Waveform::Sine, None);
+    params.duration_seconds = 1.0;
+
+    let mut kick = params.layers[0].clone();
+    if let Synthesis::Oscillator { frequency, .. } = &mut kick.synthesis {
+        *frequency = 60.0;
+    }
+    kick.envelope = Envelope {
+        attack: 0.0,
+        decay: 0.05,
+        sustain: 0.0,
+        release: 0.0,
+    }
+    .into();
+    kick.arpeggio = Some(Arpeggio {
+        pattern: vec![0.0],
+        rate_hz: 4.0,
+        gate: 0.25,
+        mode: ArpeggioMode::Up,
+    });
+    kick.muted = true;
+
+    let mut tone = params.layers[0].clone();
+    if let Synthesis::Oscillator { frequency, .. } = &mut tone.synthesis {
+        *frequency = 440.0;
+    }
+    tone.sidechain = Some(Sidechain {
+        source_layer: 0,
+        threshold_db: -30.0,
+        ratio: 10.0,
+        attack_ms: 1.0,
+        release_ms: 40.0,
+    });
+
+    params.layers = vec![kick, tone];
+    params
+}
+
+#[test]
+fn test_generate_sidechain_dips_at_source_transients() {
+    let samples =
+        decode_mono(&generate_from_params(&sidechain_params(), 42).expect("should generate"));
+    let sample_rate = 44100.0;
+
+    // Peak level of the ducked tone in 5 ms windows.
+    let window = (0.005 * sample_rate) as usize;
+    let levels: Vec<f64> = samples
+        .chunks(window)
+        .map(|chunk| chunk.iter().fold(0.0_f64, |a, &b| a.max(b.abs())))
+        .collect();
+    let window_at = |seconds: f64| (seconds * sample_rate) as usize / window;
+
+    for step in 0..4 {
+        let onset = step as f64 * 0.25;
+        let (dip_window, dip) = (window_at(onset)..window_at(onset + 0.06))
+            .map(|w| (w, levels[w]))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        let recovered = levels[window_at(onset + 0.2)];
+
+        // Each kick pulls the tone down right after its onset, and the tone
+        // is back before the next one.
+        assert!(
+            dip < recovered * 0.3,
+            "step {}: dip {} vs recovered {}",
+            step,
+            dip,
+            recovered
+        );
+        assert!(
+            dip_window <= window_at(onset + 0.05),
+            "step {}: dip at {} s",
+            step,
+            dip_window as f64 * window as f64 / sample_rate
+        );
+    }
+}
+
+#[test]
+fn test_generate_sidechain_is_deterministic() {
+    let params = sidechain_params();
+    let a = generate_from_params(&params, 42).expect("should generate");
+    let b = generate_from_params(&params, 42).expect("should generate");
+    assert_eq!(a.wav.pcm_hash, b.wav.pcm_hash);
+
+    // Removing the sidechain changes the render.
+    let mut plain = params.clone();
+    plain.layers[1].sidechain = None;
+    let plain = generate_from_params(&plain, 42).expect("should generate");
+    assert_ne!(a.wav.pcm_hash, plain.wav.pcm_hash);
+}
+
+#[test]
+fn test_generate_rejects_sidechain_on_itself() {
+    let mut params = sidechain_params();
+    params.layers[1].sidechain.as_mut().unwrap().source_layer = 1;
+    let err = generate_from_params(&params, 42).unwrap_err();
+    assert!(err.to_string().contains("source_layer"), "{}", err);
+}
@@ -5,8 +5,8 @@
 //!
 //! # Overview
 //!
-//! The audio backend generates WAV files from declarative specifications. It supports
-//! multiple synthesis types that can be layered together:
+//! The audio backend generates WAV or OGG/Vorbis files from declarative specifications. It
+//! supports multiple synthesis types that can be layered together:
 //!
 //! - **FM Synthesis** - Frequency modulation for complex timbres
 //! - **Karplus-Strong** - Plucked string sounds
@@ -20,6 +20,10 @@
 //! byte-identical across runs (on the same platform). The crate uses PCG32 for all
 //! random number generation, with seeds derived via BLAKE3 hashing.
 //!
+//! With the `parallel` feature, layers render on the rayon thread pool. Each layer
+//! uses its own derived seed and layers are mixed in spec order, so the output is
+//! identical to serial rendering for any thread count.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -42,7 +46,9 @@
 //! - [`envelope`] - ADSR envelope generators
 //! - [`filter`] - Biquad filter implementations
 //! - [`mixer`] - Layer mixing with volume/pan
+//! - [`ogg`] - OGG/Vorbis encoding of rendered audio
 //! - [`oscillator`] - Basic waveform generators
+//! - [`oversample`] - Resampling for oversampled processing
 //! - [`rng`] - Deterministic RNG with seed derivation
 //! - [`synthesis`] - Synthesis algorithm implementations
 //! - [`wav`] - Deterministic WAV file writer
@@ -55,21 +61,29 @@ pub mod generate;
 pub mod loop_processing;
 pub mod mixer;
 pub mod modulation;
+pub mod ogg;
 pub mod oscillator;
+pub mod oversample;
+pub mod peak;
 pub mod rng;
 pub mod synthesis;
 pub mod wav;
 
+#[cfg(test)]
+mod test_spectrum;
+
 // Re-export main types at crate root
 pub use error::{AudioError, AudioResult};
 pub use generate::{generate, generate_from_params, generate_preview, GenerateResult};
+pub use ogg::{encode_ogg, OggResult};
 pub use wav::{WavResult, WavWriter};
 
 #[cfg(test)]
 mod integration_tests {
     use super::*;
     use speccade_spec::recipe::audio::{
-        AudioLayer, AudioV1Params, Envelope, NoiseType, Synthesis, Waveform,
+        AudioLayer, AudioV1Params, ChannelMode, Effect, Envelope, GranularSource, NoiseType,
+        Synthesis, Waveform, WaveshaperCurve,
     };
     use speccade_spec::recipe::Recipe;
     use speccade_spec::{AssetType, OutputFormat, OutputSpec, Spec};
@@ -94,11 +108,19 @@ mod integration_tests {
                     decay: 0.05,
                     sustain: 0.3,
                     release: 0.15,
-                },
+                }
+                .into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -107,6 +129,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         Spec::builder("laser-blast-01", AssetType::Audio)
@@ -179,11 +206,18 @@ mod integration_tests {
                     noise_type: NoiseType::White,
                     filter: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -192,6 +226,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("noise-test", AssetType::Audio)
@@ -222,11 +261,18 @@ mod integration_tests {
                         noise_type: NoiseType::White,
                         filter: None,
                     },
-                    envelope: Envelope::default(),
+                    envelope: Envelope::default().into(),
                     volume: 1.0,
                     pan: 0.0,
                     delay: None,
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 }],
                 pitch_envelope: None,
@@ -235,6 +281,11 @@ mod integration_tests {
                 generate_loop_points: false,
                 effects: vec![],
                 post_fx_lfos: vec![],
+                tail_fade: None,
+                master_compressor: None,
+                master_limiter: None,
+                dc_blocker: false,
+                channels: ChannelMode::Auto,
             };
 
             Spec::builder("noise-test", AssetType::Audio)
@@ -276,12 +327,22 @@ mod integration_tests {
                         freq_sweep: None,
                         detune: None,
                         duty: None,
+                        anti_alias: None,
+                        detune_voices: None,
+                        detune_spread: None,
                     },
-                    envelope: Envelope::default(),
+                    envelope: Envelope::default().into(),
                     volume: 0.5,
                     pan: -0.8,
                     delay: None,
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 },
                 AudioLayer {
@@ -291,17 +352,32 @@ mod integration_tests {
                         freq_sweep: None,
                         detune: None,
                         duty: None,
+                        anti_alias: None,
+                        detune_voices: None,
+                        detune_spread: None,
                     },
-                    envelope: Envelope::default(),
+                    envelope: Envelope::default().into(),
                     volume: 0.5,
                     pan: 0.8,
                     delay: None,
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 },
             ],
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("stereo-test", AssetType::Audio)
@@ -337,11 +413,19 @@ mod integration_tests {
                     decay: 0.3,
                     sustain: 0.0,
                     release: 0.2,
-                },
+                }
+                .into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -350,6 +434,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("pluck-test", AssetType::Audio)
@@ -377,11 +466,18 @@ mod integration_tests {
                     base_freq: 440.0,
                     harmonics: vec![1.0, 0.5, 0.25, 0.125],
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -390,6 +486,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("additive-test", AssetType::Audio)
@@ -406,6 +507,80 @@ mod integration_tests {
         assert!(!result.wav.wav_data.is_empty());
     }
 
+    fn granular_spec(seed: u32) -> Spec {
+        let params = AudioV1Params {
+            duration_seconds: 0.3,
+            sample_rate: 44100,
+            master_filter: None,
+            layers: vec![AudioLayer {
+                synthesis: Synthesis::Granular {
+                    source: GranularSource::Tone {
+                        waveform: Waveform::Sine,
+                        frequency: 330.0,
+                    },
+                    grain_size_ms: 40.0,
+                    grain_density: 30.0,
+                    pitch_spread: 2.0,
+                    position_spread: 0.5,
+                    pan_spread: 0.0,
+                },
+                envelope: Envelope::default().into(),
+                volume: 0.8,
+                pan: 0.0,
+                delay: None,
+                filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
+                lfo: None,
+            }],
+            pitch_envelope: None,
+            base_note: None,
+            loop_config: None,
+            generate_loop_points: false,
+            effects: vec![],
+            post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
+        };
+
+        Spec::builder("granular-test", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(seed)
+            .output(OutputSpec::primary(OutputFormat::Wav, "test.wav"))
+            .recipe(Recipe::new(
+                "audio_v1",
+                serde_json::to_value(&params).unwrap(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_granular_synthesis() {
+        let result = generate(&granular_spec(42)).expect("generation should succeed");
+        assert!(!result.wav.wav_data.is_empty());
+        let pcm = wav::extract_pcm_data(&result.wav.wav_data).unwrap();
+        assert!(pcm.iter().any(|&b| b != 0));
+    }
+
+    #[test]
+    fn test_granular_synthesis_determinism() {
+        let a = generate(&granular_spec(42)).expect("generation should succeed");
+        let b = generate(&granular_spec(42)).expect("generation should succeed");
+        assert_eq!(a.wav.pcm_hash, b.wav.pcm_hash);
+
+        // Grain jitter draws from the seeded stream
+        let c = generate(&granular_spec(43)).expect("generation should succeed");
+        assert_ne!(a.wav.pcm_hash, c.wav.pcm_hash);
+    }
+
     #[test]
     fn test_pcm_hash_format() {
         let spec = create_fm_spec(42);
@@ -433,17 +608,28 @@ mod integration_tests {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
                 envelope: Envelope {
                     attack: 0.05,
                     decay: 0.1,
                     sustain: 0.7,
                     release: 0.2,
-                },
+                }
+                .into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -452,6 +638,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("loop-test", AssetType::Audio)
@@ -501,17 +692,28 @@ mod integration_tests {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
                 envelope: Envelope {
                     attack: 0.02,
                     decay: 0.05,
                     sustain: 0.8,
                     release: 0.1,
-                },
+                }
+                .into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -520,6 +722,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("loop-crossfade-test", AssetType::Audio)
@@ -551,12 +758,22 @@ mod integration_tests {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -565,6 +782,11 @@ mod integration_tests {
             generate_loop_points: true, // Deprecated flag
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("deprecated-loop-test", AssetType::Audio)
@@ -599,12 +821,22 @@ mod integration_tests {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -613,6 +845,11 @@ mod integration_tests {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let spec = Spec::builder("loop-determinism-test", AssetType::Audio)
@@ -635,4 +872,134 @@ mod integration_tests {
         // Audio with crossfade must be identical
         assert_eq!(result1.wav.pcm_hash, result2.wav.pcm_hash);
     }
+
+    fn reverb_spec(seed: u32, width: f64) -> Spec {
+        effect_chain_spec(
+            seed,
+            vec![Effect::Reverb {
+                room_size: 0.8,
+                damping: 0.5,
+                wet: 0.5,
+                dry: Some(0.8),
+                width,
+            }],
+        )
+    }
+
+    /// A short noise burst processed by `effects`.
+    fn effect_chain_spec(seed: u32, effects: Vec<Effect>) -> Spec {
+        let params = AudioV1Params {
+            duration_seconds: 0.5,
+            sample_rate: 44100,
+            master_filter: None,
+            layers: vec![AudioLayer {
+                synthesis: Synthesis::NoiseBurst {
+                    noise_type: NoiseType::White,
+                    filter: None,
+                },
+                envelope: Envelope {
+                    attack: 0.001,
+                    decay: 0.05,
+                    sustain: 0.0,
+                    release: 0.05,
+                }
+                .into(),
+                volume: 0.8,
+                pan: 0.0,
+                delay: None,
+                filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
+                lfo: None,
+            }],
+            pitch_envelope: None,
+            base_note: None,
+            loop_config: None,
+            generate_loop_points: false,
+            effects,
+            post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
+        };
+
+        Spec::builder("effect-chain-test", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(seed)
+            .output(OutputSpec::primary(OutputFormat::Wav, "test.wav"))
+            .recipe(Recipe::new(
+                "audio_v1",
+                serde_json::to_value(&params).unwrap(),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn test_reverb_determinism() {
+        let result1 = generate(&reverb_spec(42, 1.0)).expect("first generation");
+        let result2 = generate(&reverb_spec(42, 1.0)).expect("second generation");
+        assert_eq!(result1.wav.pcm_hash, result2.wav.pcm_hash);
+
+        // The comb/allpass network is seeded from the spec seed
+        let other_seed = generate(&reverb_spec(7, 1.0)).expect("other seed");
+        assert_ne!(result1.wav.pcm_hash, other_seed.wav.pcm_hash);
+    }
+
+    #[test]
+    fn test_reverb_expands_mono_into_stereo_tail() {
+        let wide = generate(&reverb_spec(42, 1.0)).expect("wide reverb");
+        assert!(wide.wav.is_stereo);
+        let pcm = wav::extract_pcm_data(&wide.wav.wav_data).unwrap();
+        let frames: Vec<(i16, i16)> = pcm
+            .chunks_exact(4)
+            .map(|b| {
+                (
+                    i16::from_le_bytes([b[0], b[1]]),
+                    i16::from_le_bytes([b[2], b[3]]),
+                )
+            })
+            .collect();
+        assert!(frames.iter().any(|(left, right)| left != right));
+
+        let narrow = generate(&reverb_spec(42, 0.0)).expect("narrow reverb");
+        let pcm = wav::extract_pcm_data(&narrow.wav.wav_data).unwrap();
+        assert!(pcm.chunks_exact(4).all(|b| b[0..2] == b[2..4]));
+    }
+
+    #[test]
+    fn test_waveshaper_zero_drive_is_bypass() {
+        let delay = Effect::Delay {
+            time_ms: 50.0,
+            feedback: 0.3,
+            wet: 0.3,
+            ping_pong: false,
+            tone: None,
+        };
+        let reference = generate(&effect_chain_spec(42, vec![delay.clone()])).unwrap();
+
+        for curve in [WaveshaperCurve::HardClip, WaveshaperCurve::Foldback] {
+            let bypassed = Effect::Waveshaper {
+                drive: 0.0,
+                curve,
+                wet: 1.0,
+            };
+            let result = generate(&effect_chain_spec(42, vec![bypassed, delay.clone()])).unwrap();
+            assert_eq!(result.wav.pcm_hash, reference.wav.pcm_hash);
+        }
+
+        let driven = Effect::Waveshaper {
+            drive: 10.0,
+            curve: WaveshaperCurve::HardClip,
+            wet: 1.0,
+        };
+        let result = generate(&effect_chain_spec(42, vec![driven, delay])).unwrap();
+        assert_ne!(result.wav.pcm_hash, reference.wav.pcm_hash);
+    }
 }
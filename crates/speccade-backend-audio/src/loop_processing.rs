@@ -3,7 +3,7 @@
 //! This module provides algorithms for finding optimal loop points in audio samples
 //! and applying crossfades at loop boundaries to eliminate clicks.
 
-use speccade_spec::recipe::audio::{Envelope, LoopConfig};
+use speccade_spec::recipe::audio::{CrossfadeShape, LayerEnvelope, LoopConfig};
 
 /// Result of loop point calculation.
 #[derive(Debug, Clone, PartialEq)]
@@ -93,7 +93,7 @@ pub fn find_nearest_zero_crossing(samples: &[f64], target: usize, tolerance: usi
 /// region). The loop end defaults to the end of the audio.
 ///
 /// # Arguments
-/// * `envelope` - ADSR envelope parameters
+/// * `envelope` - Layer envelope (ADSR or DAHDSR)
 /// * `loop_config` - Loop configuration
 /// * `samples` - Audio sample buffer
 /// * `sample_rate` - Sample rate in Hz
@@ -101,15 +101,17 @@ pub fn find_nearest_zero_crossing(samples: &[f64], target: usize, tolerance: usi
 /// # Returns
 /// Calculated loop points with optional zero crossing snapping.
 pub fn calculate_loop_points(
-    envelope: &Envelope,
+    envelope: &LayerEnvelope,
     loop_config: &LoopConfig,
     samples: &[f64],
     sample_rate: f64,
 ) -> LoopPoints {
     let num_samples = samples.len();
 
-    // Calculate default loop start (after attack + decay)
-    let attack_decay_samples = ((envelope.attack + envelope.decay) * sample_rate) as usize;
+    // Calculate default loop start (after delay + attack + hold + decay)
+    let envelope = envelope.to_dahdsr();
+    let attack_decay_samples = ((envelope.delay + envelope.attack + envelope.hold + envelope.decay)
+        * sample_rate) as usize;
     let default_start = attack_decay_samples.min(num_samples.saturating_sub(1));
 
     // Get start and end from config or use defaults
@@ -160,15 +162,28 @@ pub fn calculate_loop_points(
     }
 }
 
-/// Applies a cosine crossfade at loop boundaries.
+/// Returns the `(fade_out, fade_in)` gains at position `t` (0-1) of a crossfade.
+fn crossfade_gains(shape: CrossfadeShape, t: f64) -> (f64, f64) {
+    match shape {
+        CrossfadeShape::Linear => (1.0 - t, t),
+        CrossfadeShape::EqualPower => {
+            let angle = t * std::f64::consts::FRAC_PI_2;
+            (angle.cos(), angle.sin())
+        }
+    }
+}
+
+/// Applies a crossfade at loop boundaries.
 ///
 /// This blends the loop end into the loop start to create a seamless transition.
-/// The crossfade uses equal-power (cosine) curves for smooth amplitude preservation.
+/// Equal-power (sine/cosine) gains keep loudness steady across the seam; linear
+/// gains dip at the midpoint for uncorrelated material.
 ///
 /// # Arguments
 /// * `samples` - Audio sample buffer (modified in place)
 /// * `loop_points` - Start and end of the loop region
 /// * `crossfade_ms` - Duration of the crossfade in milliseconds
+/// * `shape` - Gain curve of the crossfade
 /// * `sample_rate` - Sample rate in Hz
 ///
 /// # Returns
@@ -177,6 +192,7 @@ pub fn apply_loop_crossfade(
     samples: &mut [f64],
     loop_points: &LoopPoints,
     crossfade_ms: f32,
+    shape: CrossfadeShape,
     sample_rate: f64,
 ) -> usize {
     if samples.is_empty() || loop_points.start >= loop_points.end {
@@ -200,10 +216,7 @@ pub fn apply_loop_crossfade(
     // The end of the loop fades out while the start of the loop fades in
     for i in 0..actual_crossfade {
         let t = i as f64 / actual_crossfade as f64;
-
-        // Equal-power crossfade using cosine curves
-        let fade_out = (t * std::f64::consts::FRAC_PI_2).cos();
-        let fade_in = (t * std::f64::consts::FRAC_PI_2).sin();
+        let (fade_out, fade_in) = crossfade_gains(shape, t);
 
         // Get indices for the crossfade region
         let end_idx = loop_points.end - actual_crossfade + i;
@@ -229,6 +242,7 @@ pub fn apply_loop_crossfade(
 /// * `right` - Right channel samples (modified in place)
 /// * `loop_points` - Start and end of the loop region
 /// * `crossfade_ms` - Duration of the crossfade in milliseconds
+/// * `shape` - Gain curve of the crossfade
 /// * `sample_rate` - Sample rate in Hz
 ///
 /// # Returns
@@ -238,10 +252,12 @@ pub fn apply_loop_crossfade_stereo(
     right: &mut [f64],
     loop_points: &LoopPoints,
     crossfade_ms: f32,
+    shape: CrossfadeShape,
     sample_rate: f64,
 ) -> usize {
-    let left_crossfade = apply_loop_crossfade(left, loop_points, crossfade_ms, sample_rate);
-    let right_crossfade = apply_loop_crossfade(right, loop_points, crossfade_ms, sample_rate);
+    let left_crossfade = apply_loop_crossfade(left, loop_points, crossfade_ms, shape, sample_rate);
+    let right_crossfade =
+        apply_loop_crossfade(right, loop_points, crossfade_ms, shape, sample_rate);
 
     // Return the minimum (should be the same for both channels)
     left_crossfade.min(right_crossfade)
@@ -311,6 +327,7 @@ pub fn measure_loop_derivative(samples: &[f64], loop_points: &LoopPoints) -> f64
 #[cfg(test)]
 mod tests {
     use super::*;
+    use speccade_spec::recipe::audio::Envelope;
 
     #[test]
     fn test_find_zero_crossing_basic() {
@@ -354,12 +371,12 @@ mod tests {
 
     #[test]
     fn test_calculate_loop_points_default() {
-        let envelope = Envelope {
+        let envelope = LayerEnvelope::Adsr(Envelope {
             attack: 0.01,
             decay: 0.05,
             sustain: 0.5,
             release: 0.1,
-        };
+        });
         let config = LoopConfig::default();
         let samples = vec![0.0; 44100]; // 1 second at 44100 Hz
 
@@ -373,7 +390,7 @@ mod tests {
 
     #[test]
     fn test_calculate_loop_points_explicit() {
-        let envelope = Envelope::default();
+        let envelope = LayerEnvelope::default();
         let config = LoopConfig::with_points(1000, 5000);
         let samples = vec![0.0; 10000];
 
@@ -385,7 +402,7 @@ mod tests {
 
     #[test]
     fn test_calculate_loop_points_with_zero_crossing() {
-        let envelope = Envelope::default();
+        let envelope = LayerEnvelope::default();
         let config = LoopConfig {
             snap_to_zero_crossing: true,
             zero_crossing_tolerance: 100,
@@ -426,7 +443,13 @@ mod tests {
         let disc_before = measure_loop_discontinuity(&samples, &loop_points);
 
         // Apply crossfade
-        let crossfade_len = apply_loop_crossfade(&mut samples, &loop_points, 10.0, 44100.0);
+        let crossfade_len = apply_loop_crossfade(
+            &mut samples,
+            &loop_points,
+            10.0,
+            CrossfadeShape::EqualPower,
+            44100.0,
+        );
 
         assert!(crossfade_len > 0);
 
@@ -473,7 +496,7 @@ mod tests {
 
     #[test]
     fn test_loop_config_disabled() {
-        let envelope = Envelope::default();
+        let envelope = LayerEnvelope::default();
         let config = LoopConfig::disabled();
         let samples = vec![0.0; 1000];
 
@@ -482,4 +505,62 @@ mod tests {
         let points = calculate_loop_points(&envelope, &config, &samples, 44100.0);
         assert!(points.start < points.end);
     }
+
+    /// RMS of the middle third of the crossfade region relative to the RMS of
+    /// the untouched noise, for a 100ms crossfade between uncorrelated noise.
+    fn crossfade_midpoint_rms_ratio(shape: CrossfadeShape) -> f64 {
+        use rand::Rng;
+
+        let mut rng = crate::rng::create_rng(7);
+        let mut samples: Vec<f64> = (0..44100).map(|_| rng.gen_range(-0.5..0.5)).collect();
+        let rms = |s: &[f64]| (s.iter().map(|x| x * x).sum::<f64>() / s.len() as f64).sqrt();
+        let reference = rms(&samples);
+
+        let loop_points = LoopPoints {
+            start: 4410,
+            end: 40000,
+            snapped_to_zero_crossing: false,
+        };
+        let len = apply_loop_crossfade(&mut samples, &loop_points, 100.0, shape, 44100.0);
+        assert_eq!(len, 4410);
+
+        let region_start = loop_points.end - len;
+        rms(&samples[region_start + len / 3..region_start + 2 * len / 3]) / reference
+    }
+
+    #[test]
+    fn test_equal_power_crossfade_keeps_rms_across_seam() {
+        let equal_power = crossfade_midpoint_rms_ratio(CrossfadeShape::EqualPower);
+        let linear = crossfade_midpoint_rms_ratio(CrossfadeShape::Linear);
+
+        assert!(
+            (equal_power - 1.0).abs() < 0.1,
+            "equal-power midpoint RMS ratio {}",
+            equal_power
+        );
+        // Linear gains sum to ~0.52 power over the middle third (about -2.8 dB)
+        assert!(linear < 0.8, "linear midpoint RMS ratio {}", linear);
+    }
+
+    #[test]
+    fn test_crossfade_shape_does_not_affect_zero_crossing_snap() {
+        let samples: Vec<f64> = (0..6000)
+            .map(|i| (i as f64 * 2.0 * std::f64::consts::PI / 500.0).sin())
+            .collect();
+        let equal_power = LoopConfig {
+            start_sample: Some(1010),
+            end_sample: Some(4990),
+            ..LoopConfig::default()
+        };
+        let linear = LoopConfig {
+            crossfade_shape: CrossfadeShape::Linear,
+            ..equal_power.clone()
+        };
+
+        let envelope = LayerEnvelope::default();
+        let a = calculate_loop_points(&envelope, &equal_power, &samples, 44100.0);
+        let b = calculate_loop_points(&envelope, &linear, &samples, 44100.0);
+        assert!(b.snapped_to_zero_crossing);
+        assert_eq!(a, b);
+    }
 }
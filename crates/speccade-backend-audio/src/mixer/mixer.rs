@@ -1,5 +1,7 @@
 //! Audio mixer for combining multiple layers.
 
+use std::borrow::Cow;
+
 use super::types::{Layer, LayerSamples, MixerOutput, StereoOutput};
 
 /// Audio mixer for combining multiple layers.
@@ -11,8 +13,6 @@ pub struct Mixer {
     sample_rate: f64,
     /// Accumulated layers.
     layers: Vec<Layer>,
-    /// Whether any layer has non-zero pan.
-    has_stereo_content: bool,
 }
 
 impl Mixer {
@@ -26,15 +26,11 @@ impl Mixer {
             num_samples,
             sample_rate,
             layers: Vec::new(),
-            has_stereo_content: false,
         }
     }
 
     /// Adds a layer to the mix.
     pub fn add_layer(&mut self, layer: Layer) {
-        if layer.pan.abs() > 1e-6 || layer.pan_curve.is_some() || layer.samples.is_stereo() {
-            self.has_stereo_content = true;
-        }
         self.layers.push(layer);
     }
 
@@ -49,35 +45,36 @@ impl Mixer {
     }
 
     /// Returns whether the mix has stereo content.
+    ///
+    /// Only layers that are audible (see [`Mixer::audible_layers`]) count.
     pub fn is_stereo(&self) -> bool {
-        self.has_stereo_content
+        self.audible_layers().any(|layer| {
+            layer.pan.abs() > 1e-6 || layer.pan_curve.is_some() || layer.samples.is_stereo()
+        })
+    }
+
+    /// Returns the layers that contribute to the mix.
+    ///
+    /// Muted layers are skipped. When any layer is soloed, only soloed layers
+    /// that are not muted are mixed.
+    pub fn audible_layers(&self) -> impl Iterator<Item = &Layer> {
+        self.layers.iter().filter(|layer| self.is_audible(layer))
+    }
+
+    /// Returns whether `layer` would contribute to this mix, given the
+    /// mute and solo flags of the layers already added.
+    pub fn is_audible(&self, layer: &Layer) -> bool {
+        let any_solo = self.layers.iter().any(|layer| layer.solo);
+        !layer.muted && (!any_solo || layer.solo)
     }
 
     /// Mixes all layers to mono output.
     pub fn mix_mono(&self) -> Vec<f64> {
         let mut output = vec![0.0; self.num_samples];
 
-        for layer in &self.layers {
-            let start = layer.delay_samples;
-
-            match &layer.samples {
-                LayerSamples::Mono(samples) => {
-                    for (i, &sample) in samples.iter().enumerate() {
-                        let output_idx = start + i;
-                        if output_idx < self.num_samples {
-                            output[output_idx] += sample * layer.volume;
-                        }
-                    }
-                }
-                LayerSamples::Stereo { left, right } => {
-                    // Mix stereo down to mono by averaging
-                    for (i, (&l, &r)) in left.iter().zip(right.iter()).enumerate() {
-                        let output_idx = start + i;
-                        if output_idx < self.num_samples {
-                            output[output_idx] += (l + r) * 0.5 * layer.volume;
-                        }
-                    }
-                }
+        for layer in self.audible_layers() {
+            for (out, sample) in output.iter_mut().zip(layer.mono_buffer(self.num_samples)) {
+                *out += sample;
             }
         }
 
@@ -88,14 +85,15 @@ impl Mixer {
     pub fn mix_stereo(&self) -> StereoOutput {
         let mut output = StereoOutput::new(self.num_samples);
 
-        for layer in &self.layers {
+        for layer in self.audible_layers() {
             let start = layer.delay_samples;
 
             match &layer.samples {
                 LayerSamples::Mono(samples) => {
                     // For mono sources: apply equal-power panning
+                    let samples = apply_gain_curve(samples, layer.gain_curve.as_deref());
                     self.mix_mono_layer_to_stereo(
-                        samples,
+                        &samples,
                         layer.volume,
                         layer.pan,
                         layer.pan_curve.as_ref(),
@@ -108,9 +106,11 @@ impl Mixer {
                     right: src_right,
                 } => {
                     // For stereo sources: apply stereo image positioning
+                    let src_left = apply_gain_curve(src_left, layer.gain_curve.as_deref());
+                    let src_right = apply_gain_curve(src_right, layer.gain_curve.as_deref());
                     self.mix_stereo_layer_to_stereo(
-                        src_left,
-                        src_right,
+                        &src_left,
+                        &src_right,
                         layer.volume,
                         layer.pan,
                         start,
@@ -211,7 +211,7 @@ impl Mixer {
 
     /// Mixes all layers, automatically choosing mono or stereo based on content.
     pub fn mix(&self) -> MixerOutput {
-        if self.has_stereo_content {
+        if self.is_stereo() {
             MixerOutput::Stereo(self.mix_stereo())
         } else {
             MixerOutput::Mono(self.mix_mono())
@@ -228,3 +228,26 @@ impl Mixer {
         self.num_samples
     }
 }
+
+/// Scales samples by a per-sample gain curve.
+///
+/// Samples past the end of the curve keep the curve's last value. Without a
+/// curve the samples are returned unchanged.
+pub(super) fn apply_gain_curve<'a>(
+    samples: &'a [f64],
+    gain_curve: Option<&[f64]>,
+) -> Cow<'a, [f64]> {
+    match gain_curve {
+        None => Cow::Borrowed(samples),
+        Some(curve) => {
+            let last = curve.last().copied().unwrap_or(1.0);
+            Cow::Owned(
+                samples
+                    .iter()
+                    .enumerate()
+                    .map(|(i, s)| s * curve.get(i).copied().unwrap_or(last))
+                    .collect(),
+            )
+        }
+    }
+}
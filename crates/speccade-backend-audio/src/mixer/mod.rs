@@ -6,6 +6,7 @@
 #[allow(clippy::module_inception)]
 mod mixer;
 mod processing;
+mod resample;
 mod types;
 
 #[cfg(test)]
@@ -19,8 +20,14 @@ mod tests_mixer_output;
 #[cfg(test)]
 mod tests_mixing;
 #[cfg(test)]
+mod tests_mono_compat;
+#[cfg(test)]
+mod tests_mute_solo;
+#[cfg(test)]
 mod tests_normalization;
 #[cfg(test)]
+mod tests_resample;
+#[cfg(test)]
 mod tests_soft_clip;
 #[cfg(test)]
 mod tests_stereo_output;
@@ -28,4 +35,5 @@ mod tests_stereo_output;
 // Re-export public API
 pub use mixer::Mixer;
 pub use processing::{normalize, normalize_stereo, soft_clip, soft_clip_buffer};
-pub use types::{Layer, LayerSamples, MixerOutput, StereoOutput};
+pub use resample::resample;
+pub use types::{Layer, LayerSamples, MixerOutput, MonoCompatibility, StereoOutput};
@@ -0,0 +1,116 @@
+//! Windowed-sinc sample rate conversion.
+//!
+//! Layers rendered below the master rate are converted with a polyphase
+//! windowed-sinc filter. The kernel is a fixed function of the rate ratio, so
+//! identical input always produces identical output.
+
+use std::f64::consts::PI;
+
+/// Zero crossings of the sinc kernel on each side of the center tap.
+const HALF_TAPS: usize = 32;
+
+/// Number of precomputed fractional kernel phases.
+const PHASES: usize = 256;
+
+/// Filter cutoff as a fraction of the lower Nyquist frequency, leaving a
+/// transition band for the window to roll off in.
+const CUTOFF: f64 = 0.9;
+
+/// Resamples `input` from `from_rate` to `to_rate`, producing `out_len` samples.
+///
+/// Output sample `n` is read at input position `n * from_rate / to_rate`.
+/// Samples outside the input are treated as silence. When the rates match the
+/// input is copied (and padded or truncated to `out_len`) untouched.
+pub fn resample(input: &[f64], from_rate: f64, to_rate: f64, out_len: usize) -> Vec<f64> {
+    if from_rate == to_rate {
+        let mut out = input.to_vec();
+        out.resize(out_len, 0.0);
+        return out;
+    }
+
+    // Downsampling narrows the kernel's passband (in input samples) and
+    // widens its support by the same factor.
+    let scale = (to_rate / from_rate).min(1.0);
+    let cutoff = CUTOFF * scale;
+    let half_width = (HALF_TAPS as f64 / scale).ceil() as usize;
+    let table = KernelTable::new(cutoff, half_width);
+
+    let step = from_rate / to_rate;
+    let len = input.len() as isize;
+    (0..out_len)
+        .map(|n| {
+            let position = n as f64 * step;
+            let base = position.floor();
+            let frac = position - base;
+            let base = base as isize;
+
+            let (lower, upper, t) = table.phases(frac);
+            let first = base - half_width as isize + 1;
+            lower
+                .iter()
+                .zip(upper)
+                .enumerate()
+                .filter_map(|(k, (a, b))| {
+                    let idx = first + k as isize;
+                    (0..len)
+                        .contains(&idx)
+                        .then(|| input[idx as usize] * (a + (b - a) * t))
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Precomputed kernel taps for `PHASES + 1` fractional offsets.
+struct KernelTable {
+    /// Taps per phase, `2 * half_width` each.
+    rows: Vec<Vec<f64>>,
+}
+
+impl KernelTable {
+    fn new(cutoff: f64, half_width: usize) -> Self {
+        let rows = (0..=PHASES)
+            .map(|p| {
+                let frac = p as f64 / PHASES as f64;
+                let mut row: Vec<f64> = (0..2 * half_width)
+                    .map(|k| {
+                        // Distance from the read position to tap k.
+                        let x = (k as f64 - half_width as f64 + 1.0) - frac;
+                        cutoff * sinc(cutoff * x) * window(x / half_width as f64)
+                    })
+                    .collect();
+                // Unity gain at DC for every phase.
+                let sum: f64 = row.iter().sum();
+                row.iter_mut().for_each(|h| *h /= sum);
+                row
+            })
+            .collect();
+        Self { rows }
+    }
+
+    /// The two precomputed phases around a fractional read offset in [0, 1),
+    /// and the interpolation weight between them.
+    fn phases(&self, frac: f64) -> (&[f64], &[f64], f64) {
+        let position = frac * PHASES as f64;
+        let p = (position.floor() as usize).min(PHASES - 1);
+        (&self.rows[p], &self.rows[p + 1], position - p as f64)
+    }
+}
+
+/// Normalized sinc: `sin(pi x) / (pi x)`.
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-12 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    }
+}
+
+/// 4-term Blackman-Harris window over `x` in [-1, 1] (zero outside).
+fn window(x: f64) -> f64 {
+    if x.abs() >= 1.0 {
+        return 0.0;
+    }
+    let t = PI * (x + 1.0);
+    0.35875 - 0.48829 * t.cos() + 0.14128 * (2.0 * t).cos() - 0.01168 * (3.0 * t).cos()
+}
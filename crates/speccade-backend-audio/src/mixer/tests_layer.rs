@@ -87,3 +87,27 @@ fn test_layer_with_delay_seconds_fractional() {
     // 0.001 seconds at 48000 Hz = 48 samples
     assert_eq!(layer.delay_samples, 48);
 }
+
+#[test]
+fn test_layer_mono_buffer_matches_mono_mix() {
+    let layer = Layer::new_stereo(vec![0.2, 0.4, 0.6], vec![0.0, 0.2, 0.2], 0.5, 0.0)
+        .with_delay(1)
+        .with_gain_curve(vec![1.0, 0.5, 0.0]);
+    let buffer = layer.mono_buffer(5);
+    for (actual, expected) in buffer.iter().zip([0.0, 0.05, 0.075, 0.0, 0.0]) {
+        assert!((actual - expected).abs() < 1e-12, "{:?}", buffer);
+    }
+
+    let mut mixer = Mixer::new(5, 44100.0);
+    mixer.add_layer(layer.clone());
+    assert_eq!(mixer.mix_mono(), buffer);
+}
+
+#[test]
+fn test_layer_duck_skips_delay_and_keeps_gain_curve() {
+    let mut layer = Layer::new(vec![1.0; 3], 1.0, 0.0)
+        .with_delay(2)
+        .with_gain_curve(vec![0.5, 1.0, 1.0]);
+    layer.duck(&[0.0, 0.0, 0.5, 0.25, 1.0]);
+    assert_eq!(layer.gain_curve, Some(vec![0.25, 0.25, 1.0]));
+}
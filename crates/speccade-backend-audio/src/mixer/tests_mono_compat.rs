@@ -0,0 +1,49 @@
+//! Tests for mono-compatibility measurement.
+
+use super::*;
+
+fn sine(num_samples: usize) -> Vec<f64> {
+    (0..num_samples)
+        .map(|i| (2.0 * std::f64::consts::PI * 440.0 * i as f64 / 44100.0).sin())
+        .collect()
+}
+
+#[test]
+fn test_mono_compat_identical_channels() {
+    let stereo = StereoOutput::from_mono(sine(4410));
+    let compat = stereo.mono_compatibility().unwrap();
+
+    assert!((compat.energy_ratio - 1.0).abs() < 1e-12);
+    assert!(compat.loss_db().abs() < 1e-9);
+    assert!(!compat.has_phase_cancellation());
+}
+
+#[test]
+fn test_mono_compat_anti_phase_triggers_warning() {
+    let left = sine(4410);
+    let right: Vec<f64> = left.iter().map(|s| -s).collect();
+    let stereo = StereoOutput { left, right };
+
+    let compat = stereo.mono_compatibility().unwrap();
+    assert!(compat.energy_ratio < 1e-12);
+    assert!(compat.loss_db() > MonoCompatibility::WARNING_LOSS_DB);
+    assert!(compat.has_phase_cancellation());
+}
+
+#[test]
+fn test_mono_compat_hard_pan_does_not_warn() {
+    // A source panned fully to one side loses 3 dB on mono sum, not a phase problem.
+    let left = sine(4410);
+    let right = vec![0.0; left.len()];
+    let stereo = StereoOutput { left, right };
+
+    let compat = stereo.mono_compatibility().unwrap();
+    assert!((compat.loss_db() - 3.0103).abs() < 0.01);
+    assert!(!compat.has_phase_cancellation());
+}
+
+#[test]
+fn test_mono_compat_silence() {
+    let stereo = StereoOutput::new(100);
+    assert!(stereo.mono_compatibility().is_none());
+}
@@ -0,0 +1,100 @@
+//! Tests for layer mute, solo, and gain curves.
+
+use super::*;
+
+#[test]
+fn test_mute_removes_layer() {
+    let mut mixer = Mixer::new(100, 44100.0);
+    mixer.add_mono(vec![0.3; 100], 1.0);
+    mixer.add_layer(Layer::centered(vec![0.2; 100], 1.0).with_muted(true));
+
+    let output = mixer.mix_mono();
+    assert!(output.iter().all(|&s| (s - 0.3).abs() < 1e-12));
+}
+
+#[test]
+fn test_solo_silences_other_layers() {
+    let mut mixer = Mixer::new(100, 44100.0);
+    mixer.add_mono(vec![0.3; 100], 1.0);
+    mixer.add_layer(Layer::centered(vec![0.2; 100], 1.0).with_solo(true));
+    mixer.add_panned(vec![0.1; 100], 1.0, -1.0);
+
+    // The hard-left layer is not soloed, so the mix stays mono.
+    assert!(!mixer.is_stereo());
+    let output = mixer.mix_mono();
+    assert!(output.iter().all(|&s| (s - 0.2).abs() < 1e-12));
+}
+
+#[test]
+fn test_multiple_solos_are_summed() {
+    let mut mixer = Mixer::new(100, 44100.0);
+    mixer.add_layer(Layer::centered(vec![0.3; 100], 1.0).with_solo(true));
+    mixer.add_layer(Layer::centered(vec![0.2; 100], 1.0).with_solo(true));
+    mixer.add_mono(vec![0.1; 100], 1.0);
+
+    let output = mixer.mix_mono();
+    assert!(output.iter().all(|&s| (s - 0.5).abs() < 1e-12));
+}
+
+#[test]
+fn test_mute_wins_over_solo() {
+    let mut mixer = Mixer::new(100, 44100.0);
+    mixer.add_mono(vec![0.3; 100], 1.0);
+    mixer.add_layer(
+        Layer::centered(vec![0.2; 100], 1.0)
+            .with_solo(true)
+            .with_muted(true),
+    );
+
+    // A muted solo still silences the other layers.
+    let output = mixer.mix_mono();
+    assert!(output.iter().all(|&s| s == 0.0));
+}
+
+#[test]
+fn test_no_mute_or_solo_matches_plain_mix() {
+    let left: Vec<f64> = (0..100).map(|i| (i as f64 * 0.1).sin()).collect();
+    let right: Vec<f64> = (0..100).map(|i| (i as f64 * 0.2).cos()).collect();
+
+    let mut plain = Mixer::new(100, 44100.0);
+    plain.add_panned(left.clone(), 0.8, -0.4);
+    plain.add_layer(Layer::new_stereo(left.clone(), right.clone(), 0.5, 0.2));
+
+    let mut flagged = Mixer::new(100, 44100.0);
+    flagged.add_layer(
+        Layer::new(left.clone(), 0.8, -0.4)
+            .with_muted(false)
+            .with_solo(false),
+    );
+    flagged.add_layer(Layer::new_stereo(left, right, 0.5, 0.2).with_solo(false));
+
+    let plain = plain.mix_stereo();
+    let flagged = flagged.mix_stereo();
+    assert_eq!(plain.left, flagged.left);
+    assert_eq!(plain.right, flagged.right);
+}
+
+#[test]
+fn test_gain_curve_scales_samples() {
+    let curve: Vec<f64> = (0..100).map(|i| i as f64 / 99.0).collect();
+    let mut mixer = Mixer::new(100, 44100.0);
+    mixer.add_layer(Layer::centered(vec![0.5; 100], 1.0).with_gain_curve(curve));
+
+    let output = mixer.mix_mono();
+    assert_eq!(output[0], 0.0);
+    assert!((output[99] - 0.5).abs() < 1e-12);
+    assert!((output[33] - 0.5 * 33.0 / 99.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_gain_curve_applies_to_stereo_layer() {
+    let mut mixer = Mixer::new(4, 44100.0);
+    mixer.add_layer(
+        Layer::centered_stereo(vec![1.0; 4], vec![1.0; 4], 1.0)
+            .with_gain_curve(vec![0.0, 0.5, 1.0, 0.25]),
+    );
+
+    let output = mixer.mix_stereo();
+    assert_eq!(output.left, vec![0.0, 0.5, 1.0, 0.25]);
+    assert_eq!(output.right, vec![0.0, 0.5, 1.0, 0.25]);
+}
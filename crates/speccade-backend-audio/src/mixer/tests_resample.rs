@@ -0,0 +1,63 @@
+//! Tests for sample rate conversion.
+
+use super::*;
+use std::f64::consts::PI;
+
+fn sine(freq: f64, sample_rate: f64, len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|i| (2.0 * PI * freq * i as f64 / sample_rate).sin())
+        .collect()
+}
+
+#[test]
+fn test_resample_same_rate_is_identity() {
+    let input = sine(440.0, 44100.0, 1000);
+    assert_eq!(resample(&input, 44100.0, 44100.0, 1000), input);
+
+    let padded = resample(&input, 44100.0, 44100.0, 1200);
+    assert_eq!(&padded[..1000], &input[..]);
+    assert!(padded[1000..].iter().all(|&s| s == 0.0));
+}
+
+#[test]
+fn test_resample_output_length() {
+    let input = sine(440.0, 22050.0, 2205);
+    assert_eq!(resample(&input, 22050.0, 44100.0, 4410).len(), 4410);
+    assert_eq!(resample(&input, 22050.0, 48000.0, 4800).len(), 4800);
+    assert_eq!(resample(&input, 22050.0, 11025.0, 1102).len(), 1102);
+}
+
+#[test]
+fn test_resample_deterministic() {
+    let input = sine(1234.5, 32000.0, 3200);
+    let a = resample(&input, 32000.0, 44100.0, 4410);
+    let b = resample(&input, 32000.0, 44100.0, 4410);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_resample_preserves_sine() {
+    // Away from the edges, an upsampled in-band sine matches the ideal signal.
+    let input = sine(1000.0, 22050.0, 22050);
+    let output = resample(&input, 22050.0, 44100.0, 44100);
+    let expected = sine(1000.0, 44100.0, 44100);
+
+    let max_error = output[1000..43100]
+        .iter()
+        .zip(&expected[1000..43100])
+        .map(|(a, b)| (a - b).abs())
+        .fold(0.0_f64, f64::max);
+    assert!(max_error < 1e-3, "max error {}", max_error);
+}
+
+#[test]
+fn test_resample_downsample_rejects_above_target_nyquist() {
+    // 15 kHz is representable at 44.1 kHz but not at 22.05 kHz.
+    let input = sine(15000.0, 44100.0, 44100);
+    let output = resample(&input, 44100.0, 22050.0, 22050);
+
+    let peak = output[1000..21050]
+        .iter()
+        .fold(0.0_f64, |a, &b| a.max(b.abs()));
+    assert!(peak < 1e-3, "peak {}", peak);
+}
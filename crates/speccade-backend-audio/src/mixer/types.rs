@@ -1,5 +1,7 @@
 //! Core types for audio mixing.
 
+use super::mixer::apply_gain_curve;
+
 /// Sample data for a layer, supporting both mono and stereo sources.
 #[derive(Debug, Clone)]
 pub enum LayerSamples {
@@ -53,6 +55,13 @@ pub struct Layer {
     pub pan_curve: Option<Vec<f64>>,
     /// Delay in samples before this layer starts.
     pub delay_samples: usize,
+    /// Whether this layer is excluded from the mix.
+    pub muted: bool,
+    /// Whether this layer is soloed. When any layer is soloed, only soloed
+    /// layers are mixed.
+    pub solo: bool,
+    /// Optional per-sample gain multiplier, applied on top of `volume`.
+    pub gain_curve: Option<Vec<f64>>,
 }
 
 impl Layer {
@@ -64,6 +73,9 @@ impl Layer {
             pan: pan.clamp(-1.0, 1.0),
             pan_curve: None,
             delay_samples: 0,
+            muted: false,
+            solo: false,
+            gain_curve: None,
         }
     }
 
@@ -78,6 +90,9 @@ impl Layer {
             pan: pan.clamp(-1.0, 1.0),
             pan_curve: None,
             delay_samples: 0,
+            muted: false,
+            solo: false,
+            gain_curve: None,
         }
     }
 
@@ -108,11 +123,74 @@ impl Layer {
         self
     }
 
+    /// Sets a per-sample gain curve for the layer.
+    pub fn with_gain_curve(mut self, gain_curve: Vec<f64>) -> Self {
+        debug_assert_eq!(
+            gain_curve.len(),
+            self.samples.len(),
+            "gain_curve length must match samples length"
+        );
+        self.gain_curve = Some(gain_curve);
+        self
+    }
+
+    /// Sets whether the layer is muted.
+    pub fn with_muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
+    /// Sets whether the layer is soloed.
+    pub fn with_solo(mut self, solo: bool) -> Self {
+        self.solo = solo;
+        self
+    }
+
     /// Sets a delay in seconds.
     pub fn with_delay_seconds(mut self, delay_seconds: f64, sample_rate: f64) -> Self {
         self.delay_samples = (delay_seconds * sample_rate).round() as usize;
         self
     }
+
+    /// Returns the layer as the mono mix sums it, before summing: gain curve
+    /// and volume applied, stereo averaged, and placed at its delay in a
+    /// buffer of `num_samples`.
+    ///
+    /// Mute and solo are not applied.
+    pub fn mono_buffer(&self, num_samples: usize) -> Vec<f64> {
+        let mut output = vec![0.0; num_samples];
+        let slots = output.iter_mut().skip(self.delay_samples);
+
+        match &self.samples {
+            LayerSamples::Mono(samples) => {
+                let samples = apply_gain_curve(samples, self.gain_curve.as_deref());
+                for (out, &sample) in slots.zip(samples.iter()) {
+                    *out = sample * self.volume;
+                }
+            }
+            LayerSamples::Stereo { left, right } => {
+                let left = apply_gain_curve(left, self.gain_curve.as_deref());
+                let right = apply_gain_curve(right, self.gain_curve.as_deref());
+                for (out, (&l, &r)) in slots.zip(left.iter().zip(right.iter())) {
+                    *out = (l + r) * 0.5 * self.volume;
+                }
+            }
+        }
+
+        output
+    }
+
+    /// Multiplies the layer's gain curve by `gain`, which is indexed like the
+    /// mix output (the layer's delay is skipped). Samples past the end of
+    /// `gain` are left unchanged.
+    pub fn duck(&mut self, gain: &[f64]) {
+        let len = self.samples.len();
+        let mut curve = self.gain_curve.take().unwrap_or_else(|| vec![1.0; len]);
+        for (value, &g) in curve.iter_mut().zip(gain.iter().skip(self.delay_samples)) {
+            *value *= g;
+        }
+        self.gain_curve = Some(curve);
+    }
 }
 
 /// Stereo audio output.
@@ -174,6 +252,56 @@ impl StereoOutput {
     pub fn is_empty(&self) -> bool {
         self.left.is_empty()
     }
+
+    /// Measures how much energy is lost when the channels are summed to mono.
+    ///
+    /// Returns `None` for silent audio.
+    pub fn mono_compatibility(&self) -> Option<MonoCompatibility> {
+        let (sum_energy, channel_energy) = self.left.iter().zip(self.right.iter()).fold(
+            (0.0, 0.0),
+            |(sum_energy, channel_energy), (l, r)| {
+                let mono = (l + r) * 0.5;
+                (
+                    sum_energy + mono * mono,
+                    channel_energy + (l * l + r * r) * 0.5,
+                )
+            },
+        );
+
+        if channel_energy > 0.0 {
+            Some(MonoCompatibility {
+                energy_ratio: sum_energy / channel_energy,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Mono-compatibility of a stereo signal.
+///
+/// Compares the energy of the mono sum `(L + R) / 2` with the average channel
+/// energy. Identical channels give a ratio of 1.0, uncorrelated channels about
+/// 0.5 (-3 dB), and phase-inverted channels 0.0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonoCompatibility {
+    /// Mono-sum energy relative to the average channel energy (0.0-1.0).
+    pub energy_ratio: f64,
+}
+
+impl MonoCompatibility {
+    /// Energy loss above which the mix is flagged for phase cancellation.
+    pub const WARNING_LOSS_DB: f64 = 6.0;
+
+    /// Energy lost on mono sum in dB (0.0 = no loss, infinite for full cancellation).
+    pub fn loss_db(&self) -> f64 {
+        -10.0 * self.energy_ratio.log10()
+    }
+
+    /// Returns true if summing to mono cancels a significant part of the signal.
+    pub fn has_phase_cancellation(&self) -> bool {
+        self.loss_db() > Self::WARNING_LOSS_DB
+    }
 }
 
 /// Output from the mixer (mono or stereo).
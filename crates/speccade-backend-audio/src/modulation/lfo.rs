@@ -204,6 +204,28 @@ pub fn apply_fm_index_modulation(base_index: f64, lfo_value: f64, amount: f64, d
     (base_index + bipolar * strength).max(0.0)
 }
 
+/// Applies wavetable position modulation.
+///
+/// # Arguments
+/// * `base_position` - Base wavetable position (0.0-1.0)
+/// * `lfo_value` - LFO modulation value (0.0-1.0)
+/// * `amount` - Maximum position delta
+/// * `depth` - Modulation depth (0.0-1.0)
+///
+/// # Returns
+/// Modulated position clamped to [0.0, 1.0]
+pub fn apply_wavetable_position_modulation(
+    base_position: f64,
+    lfo_value: f64,
+    amount: f64,
+    depth: f64,
+) -> f64 {
+    let strength = amount * depth.clamp(0.0, 1.0);
+    // Convert LFO value from [0.0, 1.0] to [-1.0, 1.0]
+    let bipolar = (lfo_value - 0.5) * 2.0;
+    (base_position + bipolar * strength).clamp(0.0, 1.0)
+}
+
 /// Applies grain size modulation for granular synthesis.
 ///
 /// # Arguments
@@ -0,0 +1,110 @@
+//! Modulation matrix: routes LFOs and envelopes to layer parameters.
+//!
+//! Each route reads a source (LFO or ADSR envelope), scales it by the route
+//! depth, and adds it to a per-sample curve for its destination. Routes that
+//! share a destination are summed. A destination without routes has no curve,
+//! so an empty matrix leaves the layer untouched.
+
+use speccade_spec::recipe::audio::{ModulationDestination, ModulationRoute, ModulationSource};
+
+use super::lfo::Lfo;
+use crate::envelope::{AdsrEnvelope, AdsrParams};
+use crate::rng::{create_rng, derive_component_seed};
+
+/// Modulation matrix for a single layer.
+#[derive(Debug, Clone, Copy)]
+pub struct ModulationMatrix<'a> {
+    routes: &'a [ModulationRoute],
+}
+
+impl<'a> ModulationMatrix<'a> {
+    /// Creates a modulation matrix from spec routes.
+    pub fn new(routes: &'a [ModulationRoute]) -> Self {
+        Self { routes }
+    }
+
+    /// Returns true if the matrix has no routes.
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// Returns true if any route targets `destination`.
+    pub fn targets(&self, destination: ModulationDestination) -> bool {
+        self.routes
+            .iter()
+            .any(|route| route.destination == destination)
+    }
+
+    /// Renders the summed modulation curve for one destination.
+    ///
+    /// Values are in destination units (see [`ModulationDestination`]) and are
+    /// aligned to the layer start. Returns `None` when no route targets the
+    /// destination.
+    ///
+    /// # Arguments
+    /// * `destination` - Destination to render
+    /// * `num_samples` - Number of samples to render
+    /// * `sample_rate` - Audio sample rate
+    /// * `seed` - Layer seed; each route derives its own RNG stream from it
+    pub fn render(
+        &self,
+        destination: ModulationDestination,
+        num_samples: usize,
+        sample_rate: f64,
+        seed: u32,
+    ) -> Option<Vec<f64>> {
+        if !self.targets(destination) {
+            return None;
+        }
+
+        let mut curve = vec![0.0; num_samples];
+        for (index, route) in self.routes.iter().enumerate() {
+            if route.destination != destination {
+                continue;
+            }
+            let route_seed = derive_component_seed(seed, &format!("mod_matrix_route_{}", index));
+            let source = render_source(&route.source, num_samples, sample_rate, route_seed);
+            for (value, source_value) in curve.iter_mut().zip(source) {
+                *value += source_value * route.depth;
+            }
+        }
+        Some(curve)
+    }
+}
+
+/// Renders a modulation source: LFOs are bipolar [-1, 1], envelopes unipolar [0, 1].
+fn render_source(
+    source: &ModulationSource,
+    num_samples: usize,
+    sample_rate: f64,
+    seed: u32,
+) -> Vec<f64> {
+    match source {
+        ModulationSource::Lfo {
+            waveform,
+            rate,
+            phase,
+        } => {
+            let mut rng = create_rng(seed);
+            let mut lfo = Lfo::new(*waveform, *rate, sample_rate, phase.unwrap_or(0.0));
+            lfo.generate(num_samples, &mut rng)
+                .into_iter()
+                .map(|value| (value - 0.5) * 2.0)
+                .collect()
+        }
+        ModulationSource::Envelope {
+            attack,
+            decay,
+            sustain,
+            release,
+        } => {
+            let params = AdsrParams::new(*attack, *decay, *sustain, *release);
+            let duration = num_samples as f64 / sample_rate;
+            let mut envelope =
+                AdsrEnvelope::generate_fixed_duration(&params, sample_rate, duration);
+            let last = envelope.last().copied().unwrap_or(0.0);
+            envelope.resize(num_samples, last);
+            envelope
+        }
+    }
+}
@@ -3,10 +3,14 @@
 //! This module implements Low Frequency Oscillator (LFO) modulation for various
 //! synthesis parameters. LFOs generate slow-moving waveforms that modulate other
 //! parameters over time, creating effects like vibrato, tremolo, and filter sweeps.
+//! The modulation matrix generalizes this, routing LFOs and envelopes to
+//! arbitrary layer destinations.
 
 pub mod lfo;
+pub mod matrix;
 
 #[cfg(test)]
 mod tests;
 
 pub use lfo::Lfo;
+pub use matrix::ModulationMatrix;
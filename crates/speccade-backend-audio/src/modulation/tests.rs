@@ -1,8 +1,11 @@
 //! LFO modulation unit tests.
 
 use super::lfo::*;
+use super::matrix::ModulationMatrix;
 use crate::rng::create_rng;
-use speccade_spec::recipe::audio::Waveform;
+use speccade_spec::recipe::audio::{
+    ModulationDestination, ModulationRoute, ModulationSource, Waveform,
+};
 
 #[test]
 fn test_lfo_sine_generation() {
@@ -171,6 +174,25 @@ fn test_fm_index_modulation() {
     assert!((mod_half_depth - 5.0).abs() < 0.01); // 4.0 + 2.0 * 0.5 = 5.0
 }
 
+#[test]
+fn test_wavetable_position_modulation() {
+    // At center (LFO = 0.5), should stay at base position
+    let mod_center = apply_wavetable_position_modulation(0.5, 0.5, 0.3, 1.0);
+    assert!((mod_center - 0.5).abs() < 0.01);
+
+    // At max/min, should move by the full amount
+    assert!((apply_wavetable_position_modulation(0.5, 1.0, 0.3, 1.0) - 0.8).abs() < 0.01);
+    assert!((apply_wavetable_position_modulation(0.5, 0.0, 0.3, 1.0) - 0.2).abs() < 0.01);
+
+    // Test clamping to the table
+    assert_eq!(apply_wavetable_position_modulation(0.9, 1.0, 0.5, 1.0), 1.0);
+    assert_eq!(apply_wavetable_position_modulation(0.1, 0.0, 0.5, 1.0), 0.0);
+
+    // Test depth scaling
+    let mod_half_depth = apply_wavetable_position_modulation(0.5, 1.0, 0.4, 0.5);
+    assert!((mod_half_depth - 0.7).abs() < 0.01); // 0.5 + 0.4 * 0.5 = 0.7
+}
+
 #[test]
 fn test_grain_size_modulation() {
     let base_size_ms = 50.0;
@@ -325,3 +347,83 @@ fn test_distortion_drive_modulation() {
     let mod_half_depth = apply_distortion_drive_modulation(base_drive, 1.0, amount, 0.5);
     assert!((mod_half_depth - 12.5).abs() < 0.01); // 10.0 + 5.0 * 0.5 = 12.5
 }
+
+fn lfo_route(rate: f64, destination: ModulationDestination, depth: f64) -> ModulationRoute {
+    ModulationRoute {
+        source: ModulationSource::Lfo {
+            waveform: Waveform::Sine,
+            rate,
+            phase: None,
+        },
+        destination,
+        depth,
+    }
+}
+
+#[test]
+fn test_modulation_matrix_empty_renders_nothing() {
+    let matrix = ModulationMatrix::new(&[]);
+    assert!(matrix.is_empty());
+    assert!(matrix
+        .render(ModulationDestination::Pitch, 100, 44100.0, 42)
+        .is_none());
+}
+
+#[test]
+fn test_modulation_matrix_unrouted_destination_renders_nothing() {
+    let routes = [lfo_route(5.0, ModulationDestination::Pitch, 1.0)];
+    let matrix = ModulationMatrix::new(&routes);
+    assert!(matrix.targets(ModulationDestination::Pitch));
+    assert!(matrix
+        .render(ModulationDestination::Amplitude, 100, 44100.0, 42)
+        .is_none());
+}
+
+#[test]
+fn test_modulation_matrix_sums_routes_with_depth() {
+    let single = [lfo_route(5.0, ModulationDestination::Pan, 0.25)];
+    let doubled = [
+        lfo_route(5.0, ModulationDestination::Pan, 0.25),
+        lfo_route(5.0, ModulationDestination::Pan, 0.25),
+    ];
+
+    let single = ModulationMatrix::new(&single)
+        .render(ModulationDestination::Pan, 4410, 44100.0, 42)
+        .unwrap();
+    let doubled = ModulationMatrix::new(&doubled)
+        .render(ModulationDestination::Pan, 4410, 44100.0, 42)
+        .unwrap();
+
+    assert_eq!(single.len(), 4410);
+    for (a, b) in single.iter().zip(&doubled) {
+        assert!(a.abs() <= 0.25 + 1e-12);
+        assert!((b - 2.0 * a).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_modulation_matrix_envelope_source_is_unipolar() {
+    let routes = [ModulationRoute {
+        source: ModulationSource::Envelope {
+            attack: 0.01,
+            decay: 0.02,
+            sustain: 0.5,
+            release: 0.01,
+        },
+        destination: ModulationDestination::FilterCutoff,
+        depth: 1000.0,
+    }];
+
+    let curve = ModulationMatrix::new(&routes)
+        .render(ModulationDestination::FilterCutoff, 4410, 44100.0, 42)
+        .unwrap();
+
+    assert_eq!(curve.len(), 4410);
+    assert!(curve.iter().all(|&v| (0.0..=1000.0 + 1e-9).contains(&v)));
+    let peak = curve.iter().copied().fold(0.0_f64, f64::max);
+    assert!(
+        peak > 900.0,
+        "envelope should reach full depth, got {}",
+        peak
+    );
+}
@@ -0,0 +1,195 @@
+//! OGG/Vorbis encoding of rendered audio.
+//!
+//! Vorbis is lossy and its bytes depend on the encoder version, so the Tier 1
+//! hash of an OGG output covers the samples before encoding. They are quantized
+//! to 16-bit PCM by the WAV writer, which makes the hash equal to the `pcm_hash`
+//! of the WAV rendering of the same samples. The encoder itself receives the
+//! unquantized samples.
+
+use std::num::{NonZeroU32, NonZeroU8};
+
+use vorbis_rs::VorbisEncoderBuilder;
+
+use crate::error::{AudioError, AudioResult};
+use crate::wav::samples_to_pcm16;
+
+/// Frames handed to the encoder per analysis block.
+const BLOCK_FRAMES: usize = 1024;
+
+/// Result of OGG/Vorbis encoding.
+#[derive(Debug)]
+pub struct OggResult {
+    /// Complete OGG file bytes.
+    pub data: Vec<u8>,
+    /// BLAKE3 hash of the pre-encode samples as 16-bit PCM (for Tier 1 validation).
+    pub pcm_hash: String,
+}
+
+/// Encodes interleaved samples as an OGG/Vorbis stream.
+///
+/// # Arguments
+/// * `samples` - Interleaved samples in [-1.0, 1.0]
+/// * `channels` - Number of interleaved channels
+/// * `sample_rate` - Sample rate in Hz
+pub fn encode_ogg(samples: &[f64], channels: u16, sample_rate: u32) -> AudioResult<OggResult> {
+    let channel_count = u8::try_from(channels)
+        .ok()
+        .and_then(NonZeroU8::new)
+        .ok_or_else(|| AudioError::invalid_param("channels", "must be between 1 and 255"))?;
+    let rate =
+        NonZeroU32::new(sample_rate).ok_or(AudioError::InvalidSampleRate { rate: sample_rate })?;
+    if !samples.len().is_multiple_of(channels as usize) {
+        return Err(AudioError::invalid_param(
+            "samples",
+            format!(
+                "length {} is not a multiple of {} channels",
+                samples.len(),
+                channels
+            ),
+        ));
+    }
+
+    let hash = blake3::hash(&samples_to_pcm16(samples));
+
+    // Derive the stream serial from the audio so identical renders produce identical streams.
+    let serial = i32::from_le_bytes(hash.as_bytes()[..4].try_into().unwrap());
+
+    let mut encoder =
+        VorbisEncoderBuilder::new_with_serial(rate, channel_count, Vec::new(), serial)
+            .build()
+            .map_err(encoding_error)?;
+
+    let channels = channels as usize;
+    for frames in samples.chunks(BLOCK_FRAMES * channels) {
+        let planar: Vec<Vec<f32>> = (0..channels)
+            .map(|c| {
+                frames
+                    .iter()
+                    .skip(c)
+                    .step_by(channels)
+                    .map(|&s| s as f32)
+                    .collect()
+            })
+            .collect();
+        encoder
+            .encode_audio_block(&planar)
+            .map_err(encoding_error)?;
+    }
+    let data = encoder.finish().map_err(encoding_error)?;
+
+    Ok(OggResult {
+        data,
+        pcm_hash: hash.to_hex().to_string(),
+    })
+}
+
+fn encoding_error(err: vorbis_rs::VorbisError) -> AudioError {
+    AudioError::Encoding {
+        message: err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wav::WavResult;
+    use vorbis_rs::VorbisDecoder;
+
+    fn sine(frequency: f64, sample_rate: u32, len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| {
+                0.5 * (2.0 * std::f64::consts::PI * frequency * i as f64 / sample_rate as f64).sin()
+            })
+            .collect()
+    }
+
+    /// Decodes an OGG stream into planar samples.
+    fn decode(data: &[u8]) -> (u8, u32, Vec<Vec<f32>>) {
+        let mut decoder = VorbisDecoder::<&[u8]>::new(data).unwrap();
+        let channels = decoder.channels().get();
+        let sample_rate = decoder.sampling_frequency().get();
+        let mut planar = vec![Vec::new(); channels as usize];
+        while let Some(block) = decoder.decode_audio_block().unwrap() {
+            for (out, channel) in planar.iter_mut().zip(block.samples()) {
+                out.extend_from_slice(channel);
+            }
+        }
+        (channels, sample_rate, planar)
+    }
+
+    #[test]
+    fn test_encode_ogg_round_trips() {
+        let samples = sine(440.0, 44100, 22050);
+        let result = encode_ogg(&samples, 1, 44100).unwrap();
+
+        assert_eq!(&result.data[..4], b"OggS");
+        assert!(result.data.len() < samples.len() * 2, "should compress");
+
+        let (channels, sample_rate, decoded) = decode(&result.data);
+        assert_eq!(channels, 1);
+        assert_eq!(sample_rate, 44100);
+        assert_eq!(decoded[0].len(), samples.len());
+
+        let error = decoded[0]
+            .iter()
+            .zip(&samples)
+            .map(|(&a, b)| (a as f64 - b).powi(2))
+            .sum::<f64>()
+            / samples.len() as f64;
+        assert!(error < 1e-4, "mean squared error {} too high", error);
+    }
+
+    #[test]
+    fn test_encode_ogg_stereo_keeps_channels_apart() {
+        let left = sine(440.0, 48000, 9600);
+        let interleaved: Vec<f64> = left.iter().flat_map(|&s| [s, 0.0]).collect();
+        let result = encode_ogg(&interleaved, 2, 48000).unwrap();
+
+        let (channels, sample_rate, decoded) = decode(&result.data);
+        assert_eq!(channels, 2);
+        assert_eq!(sample_rate, 48000);
+        let energy = |c: &[f32]| c.iter().map(|s| s * s).sum::<f32>();
+        assert!(energy(&decoded[0]) > 100.0 * energy(&decoded[1]));
+    }
+
+    #[test]
+    fn test_encode_ogg_is_deterministic() {
+        let samples = sine(220.0, 22050, 11025);
+        let a = encode_ogg(&samples, 1, 22050).unwrap();
+        let b = encode_ogg(&samples, 1, 22050).unwrap();
+        assert_eq!(a.data, b.data);
+        assert_eq!(a.pcm_hash, b.pcm_hash);
+
+        let c = encode_ogg(&sine(330.0, 22050, 11025), 1, 22050).unwrap();
+        assert_ne!(a.pcm_hash, c.pcm_hash);
+    }
+
+    #[test]
+    fn test_pcm_hash_matches_wav() {
+        let samples = sine(440.0, 44100, 4410);
+        let mono = WavResult::from_mono(&samples, 44100);
+        assert_eq!(
+            encode_ogg(&samples, 1, 44100).unwrap().pcm_hash,
+            mono.pcm_hash
+        );
+
+        let right: Vec<f64> = samples.iter().map(|s| -s).collect();
+        let interleaved: Vec<f64> = samples
+            .iter()
+            .zip(&right)
+            .flat_map(|(&l, &r)| [l, r])
+            .collect();
+        let stereo = WavResult::from_stereo(&samples, &right, 44100);
+        assert_eq!(
+            encode_ogg(&interleaved, 2, 44100).unwrap().pcm_hash,
+            stereo.pcm_hash
+        );
+    }
+
+    #[test]
+    fn test_encode_ogg_rejects_bad_input() {
+        assert!(encode_ogg(&[0.0; 3], 2, 44100).is_err());
+        assert!(encode_ogg(&[0.0; 4], 0, 44100).is_err());
+        assert!(encode_ogg(&[0.0; 4], 1, 0).is_err());
+    }
+}
@@ -10,7 +10,22 @@
 
 use rand_pcg::Pcg32;
 
-use super::Synthesizer;
+use super::{FrequencySweep, Synthesizer};
+
+/// Default bow velocity (normalized).
+pub const DEFAULT_BOW_VELOCITY: f64 = 0.2;
+
+/// Reflection gain at the bridge end (inverting).
+const BRIDGE_REFLECTION: f64 = 0.98;
+
+/// Reflection gain at the nut end (inverting).
+const NUT_REFLECTION: f64 = 0.99;
+
+/// Upper bound for either reflection gain.
+///
+/// With both ends below unity the loop gain stays below one, so the friction
+/// force cannot build up without bound however long the bow is held.
+const MAX_REFLECTION: f64 = 0.995;
 
 /// Bowed string synthesis parameters.
 #[derive(Debug, Clone)]
@@ -23,6 +38,10 @@ pub struct BowedStringSynth {
     pub bow_position: f64,
     /// String damping / high-frequency absorption (0.0-1.0).
     pub damping: f64,
+    /// Bow velocity (0.0-1.0).
+    pub bow_velocity: f64,
+    /// Optional frequency sweep.
+    pub freq_sweep: Option<FrequencySweep>,
 }
 
 impl BowedStringSynth {
@@ -39,9 +58,23 @@ impl BowedStringSynth {
             bow_pressure: bow_pressure.clamp(0.0, 1.0),
             bow_position: bow_position.clamp(0.01, 0.99),
             damping: damping.clamp(0.0, 1.0),
+            bow_velocity: DEFAULT_BOW_VELOCITY,
+            freq_sweep: None,
         }
     }
 
+    /// Sets the bow velocity (clamped to 0.0-1.0).
+    pub fn with_bow_velocity(mut self, bow_velocity: f64) -> Self {
+        self.bow_velocity = bow_velocity.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets a frequency sweep on the string.
+    pub fn with_sweep(mut self, sweep: FrequencySweep) -> Self {
+        self.freq_sweep = Some(sweep);
+        self
+    }
+
     /// Creates a violin-like preset.
     pub fn violin(frequency: f64) -> Self {
         Self::new(frequency, 0.5, 0.12, 0.3)
@@ -61,6 +94,18 @@ impl BowedStringSynth {
     pub fn double_bass(frequency: f64) -> Self {
         Self::new(frequency, 0.7, 0.2, 0.5)
     }
+
+    /// Returns the bridge-side and nut-side delay line lengths for a frequency.
+    fn line_lengths(&self, frequency: f64, sample_rate: f64) -> (usize, usize) {
+        let total_delay = ((sample_rate / frequency).round() as usize).max(2);
+        // bow_position = 0.0 means bow is at bridge (short delay to bridge)
+        // bow_position = 1.0 means bow is at nut (long delay to bridge)
+        let bow_point = (self.bow_position * total_delay as f64).round() as usize;
+        (
+            bow_point.max(1),
+            total_delay.saturating_sub(bow_point).max(1),
+        )
+    }
 }
 
 /// Stick-slip friction model using a waveshaping nonlinearity.
@@ -94,16 +139,38 @@ impl Synthesizer for BowedStringSynth {
             return vec![0.0; num_samples];
         }
 
-        // Split delay line based on bow position
-        // bow_position = 0.0 means bow is at bridge (short delay to bridge)
-        // bow_position = 1.0 means bow is at nut (long delay to bridge)
-        let bow_point = (self.bow_position * total_delay as f64).round() as usize;
-        let delay_to_bridge = bow_point.max(1);
-        let delay_to_nut = (total_delay - bow_point).max(1);
+        // Per-sample frequency (constant unless sweeping); swept frequencies
+        // are floored so the string never gets shorter than two samples
+        let min_freq = sample_rate / 2.0;
+        let freq_curve: Option<Vec<f64>> = self.freq_sweep.as_ref().map(|sweep| {
+            sweep
+                .generate(num_samples)
+                .into_iter()
+                .map(|f| {
+                    if f.is_finite() && f > 0.0 {
+                        f.min(min_freq)
+                    } else {
+                        min_freq
+                    }
+                })
+                .collect()
+        });
+
+        // Split delay line based on bow position. Each line is a ring buffer
+        // sized for the longest string in the run; a wave written now is read
+        // back `length - 1` samples later.
+        let (base_bridge, base_nut) = self.line_lengths(self.frequency, sample_rate);
+        let (bridge_size, nut_size) = match &freq_curve {
+            Some(curve) => curve.iter().fold((base_bridge, base_nut), |(b, n), &f| {
+                let (lb, ln) = self.line_lengths(f, sample_rate);
+                (b.max(lb), n.max(ln))
+            }),
+            None => (base_bridge, base_nut),
+        };
 
         // Two delay lines: bridge side and nut side
-        let mut delay_bridge = vec![0.0; delay_to_bridge];
-        let mut delay_nut = vec![0.0; delay_to_nut];
+        let mut delay_bridge = vec![0.0; bridge_size];
+        let mut delay_nut = vec![0.0; nut_size];
 
         let mut output = Vec::with_capacity(num_samples);
 
@@ -119,16 +186,24 @@ impl Synthesizer for BowedStringSynth {
         let damping_coeff = 0.1 + self.damping * 0.8;
 
         // Bow velocity (normalized, constant for sustained bow)
-        let bow_velocity = 0.2;
+        let bow_velocity = self.bow_velocity.clamp(0.0, 1.0);
+
+        let bridge_reflection = BRIDGE_REFLECTION.min(MAX_REFLECTION);
+        let nut_reflection = NUT_REFLECTION.min(MAX_REFLECTION);
 
         // Attack ramp to avoid clicks
         let attack_samples = (0.01 * sample_rate) as usize;
 
         for i in 0..num_samples {
+            let (bridge_len, nut_len) = match &freq_curve {
+                Some(curve) => self.line_lengths(curve[i], sample_rate),
+                None => (base_bridge, base_nut),
+            };
+
             // Read from delay lines at bow point
             // These represent waves arriving at the bow from each direction
-            let bridge_read = (bridge_write + 1) % delay_to_bridge;
-            let nut_read = (nut_write + 1) % delay_to_nut;
+            let bridge_read = (bridge_write + bridge_size - (bridge_len - 1)) % bridge_size;
+            let nut_read = (nut_write + nut_size - (nut_len - 1)) % nut_size;
 
             let v_from_bridge = delay_bridge[bridge_read];
             let v_from_nut = delay_nut[nut_read];
@@ -164,10 +239,10 @@ impl Synthesizer for BowedStringSynth {
 
             // Reflection at bridge (inverted, represents fixed end)
             // The wave returning from bridge is inverted and slightly attenuated
-            let bridge_return = -filter_bridge * 0.98;
+            let bridge_return = -filter_bridge * bridge_reflection;
 
             // Reflection at nut (inverted, represents fixed end)
-            let nut_return = -filter_nut * 0.99;
+            let nut_return = -filter_nut * nut_reflection;
 
             // Write to delay lines
             delay_bridge[bridge_write] = bridge_return;
@@ -178,8 +253,8 @@ impl Synthesizer for BowedStringSynth {
             output.push(filter_bridge);
 
             // Advance write positions
-            bridge_write = (bridge_write + 1) % delay_to_bridge;
-            nut_write = (nut_write + 1) % delay_to_nut;
+            bridge_write = (bridge_write + 1) % bridge_size;
+            nut_write = (nut_write + 1) % nut_size;
         }
 
         output
@@ -190,6 +265,8 @@ impl Synthesizer for BowedStringSynth {
 mod tests {
     use super::*;
     use crate::rng::create_rng;
+    use crate::synthesis::SweepCurve;
+    use crate::test_spectrum::rms;
 
     #[test]
     fn test_bowed_string_basic() {
@@ -294,4 +371,73 @@ mod tests {
         assert!(bridge_energy > 0.0);
         assert!(middle_energy > 0.0);
     }
+
+    #[test]
+    fn test_bowed_string_sustains_unlike_pluck() {
+        use crate::synthesis::karplus::KarplusStrong;
+
+        let sample_rate = 44100.0;
+        let num_samples = 2 * 44100;
+        let window = 4410;
+
+        let bowed = BowedStringSynth::violin(220.0).synthesize(
+            num_samples,
+            sample_rate,
+            &mut create_rng(42),
+        );
+        let bowed_early = rms(&bowed[window..2 * window]);
+        let bowed_late = rms(&bowed[num_samples - window..]);
+        assert!(bowed_early > 0.0);
+        assert!(
+            bowed_late > 0.8 * bowed_early,
+            "bowed note decayed: {} -> {}",
+            bowed_early,
+            bowed_late
+        );
+
+        let plucked = KarplusStrong::new(220.0, 0.996, 0.5).synthesize(
+            num_samples,
+            sample_rate,
+            &mut create_rng(42),
+        );
+        let plucked_early = rms(&plucked[window..2 * window]);
+        let plucked_late = rms(&plucked[num_samples - window..]);
+        assert!(
+            plucked_late < 0.5 * plucked_early,
+            "pluck should decay: {} -> {}",
+            plucked_early,
+            plucked_late
+        );
+    }
+
+    #[test]
+    fn test_bowed_string_bow_velocity_is_clamped_and_bounded() {
+        let synth = BowedStringSynth::new(440.0, 1.0, 0.5, 0.0).with_bow_velocity(5.0);
+        assert_eq!(synth.bow_velocity, 1.0);
+
+        let samples = synth.synthesize(2 * 44100, 44100.0, &mut create_rng(42));
+        assert!(samples.iter().all(|s| s.is_finite() && s.abs() < 10.0));
+    }
+
+    #[test]
+    fn test_bowed_string_sweep_changes_pitch() {
+        let sample_rate = 44100.0;
+        let zero_crossings = |samples: &[f64]| {
+            samples
+                .windows(2)
+                .filter(|w| (w[0] < 0.0) != (w[1] < 0.0))
+                .count()
+        };
+
+        let steady =
+            BowedStringSynth::violin(220.0).synthesize(44100, sample_rate, &mut create_rng(1));
+        let swept = BowedStringSynth::violin(220.0)
+            .with_sweep(FrequencySweep::new(220.0, 440.0, SweepCurve::Linear))
+            .synthesize(44100, sample_rate, &mut create_rng(1));
+
+        // Same start, higher pitch by the end
+        assert_eq!(steady[..100], swept[..100]);
+        let tail = 44100 - 8820;
+        assert!(zero_crossings(&swept[tail..]) > zero_crossings(&steady[tail..]) * 3 / 2);
+    }
 }
@@ -28,6 +28,7 @@
 //! - `pulsar` - Pulsar synthesis (synchronized grain trains for rhythmic/tonal granular)
 //! - `vosim` - VOSIM synthesis (voice simulation with squared-sine pulse trains)
 //! - `spectral` - Spectral freeze synthesis (FFT-based frozen spectral content)
+//! - `transient` - Transient synthesis (pitched click with seeded noise burst)
 
 pub mod am;
 pub mod bowed_string;
@@ -48,6 +49,7 @@ pub mod pitched_body;
 pub mod pulsar;
 pub mod ring_mod;
 pub mod spectral;
+pub mod transient;
 pub mod vector;
 pub mod vocoder;
 pub mod vosim;
@@ -5,6 +5,7 @@
 use rand_pcg::Pcg32;
 
 use crate::oscillator::{self, PhaseAccumulator, TWO_PI};
+use crate::oversample;
 
 use super::{FrequencySweep, SweepCurve, Synthesizer};
 
@@ -15,6 +16,8 @@ pub struct SineSynth {
     pub frequency: f64,
     /// Optional frequency sweep.
     pub freq_sweep: Option<FrequencySweep>,
+    /// Initial phase as a fraction of a cycle (0.0 to 1.0).
+    pub phase: f64,
 }
 
 impl SineSynth {
@@ -23,6 +26,7 @@ impl SineSynth {
         Self {
             frequency,
             freq_sweep: None,
+            phase: 0.0,
         }
     }
 
@@ -31,14 +35,22 @@ impl SineSynth {
         Self {
             frequency: start_freq,
             freq_sweep: Some(FrequencySweep::new(start_freq, end_freq, curve)),
+            phase: 0.0,
         }
     }
+
+    /// Sets the initial phase as a fraction of a cycle.
+    pub fn with_phase(mut self, phase: f64) -> Self {
+        self.phase = phase;
+        self
+    }
 }
 
 impl Synthesizer for SineSynth {
     fn synthesize(&self, num_samples: usize, sample_rate: f64, _rng: &mut Pcg32) -> Vec<f64> {
         let mut output = Vec::with_capacity(num_samples);
         let mut phase_acc = PhaseAccumulator::new(sample_rate);
+        phase_acc.set_phase_radians(self.phase * TWO_PI);
 
         for i in 0..num_samples {
             let freq = if let Some(ref sweep) = self.freq_sweep {
@@ -66,6 +78,8 @@ pub struct SquareSynth {
     pub freq_sweep: Option<FrequencySweep>,
     /// Use band-limited (PolyBLEP) algorithm for anti-aliasing.
     pub band_limited: bool,
+    /// Initial phase as a fraction of a cycle (0.0 to 1.0).
+    pub phase: f64,
 }
 
 impl SquareSynth {
@@ -76,6 +90,7 @@ impl SquareSynth {
             duty: 0.5,
             freq_sweep: None,
             band_limited: true,
+            phase: 0.0,
         }
     }
 
@@ -86,6 +101,7 @@ impl SquareSynth {
             duty: duty.clamp(0.01, 0.99),
             freq_sweep: None,
             band_limited: true,
+            phase: 0.0,
         }
     }
 
@@ -96,14 +112,21 @@ impl SquareSynth {
             duty: 0.5,
             freq_sweep: Some(FrequencySweep::new(start_freq, end_freq, curve)),
             band_limited: true,
+            phase: 0.0,
         }
     }
+
+    /// Sets the initial phase as a fraction of a cycle.
+    pub fn with_phase(mut self, phase: f64) -> Self {
+        self.phase = phase;
+        self
+    }
 }
 
 impl Synthesizer for SquareSynth {
     fn synthesize(&self, num_samples: usize, sample_rate: f64, _rng: &mut Pcg32) -> Vec<f64> {
         let mut output = Vec::with_capacity(num_samples);
-        let mut phase = 0.0;
+        let mut phase = self.phase.rem_euclid(1.0);
 
         for i in 0..num_samples {
             let freq = if let Some(ref sweep) = self.freq_sweep {
@@ -141,6 +164,8 @@ pub struct SawSynth {
     pub freq_sweep: Option<FrequencySweep>,
     /// Use band-limited (PolyBLEP) algorithm for anti-aliasing.
     pub band_limited: bool,
+    /// Initial phase as a fraction of a cycle (0.0 to 1.0).
+    pub phase: f64,
 }
 
 impl SawSynth {
@@ -150,6 +175,7 @@ impl SawSynth {
             frequency,
             freq_sweep: None,
             band_limited: true,
+            phase: 0.0,
         }
     }
 
@@ -159,14 +185,21 @@ impl SawSynth {
             frequency: start_freq,
             freq_sweep: Some(FrequencySweep::new(start_freq, end_freq, curve)),
             band_limited: true,
+            phase: 0.0,
         }
     }
+
+    /// Sets the initial phase as a fraction of a cycle.
+    pub fn with_phase(mut self, phase: f64) -> Self {
+        self.phase = phase;
+        self
+    }
 }
 
 impl Synthesizer for SawSynth {
     fn synthesize(&self, num_samples: usize, sample_rate: f64, _rng: &mut Pcg32) -> Vec<f64> {
         let mut output = Vec::with_capacity(num_samples);
-        let mut phase = 0.0;
+        let mut phase = self.phase.rem_euclid(1.0);
 
         for i in 0..num_samples {
             let freq = if let Some(ref sweep) = self.freq_sweep {
@@ -202,6 +235,8 @@ pub struct TriangleSynth {
     pub frequency: f64,
     /// Optional frequency sweep.
     pub freq_sweep: Option<FrequencySweep>,
+    /// Initial phase as a fraction of a cycle (0.0 to 1.0).
+    pub phase: f64,
 }
 
 impl TriangleSynth {
@@ -210,6 +245,7 @@ impl TriangleSynth {
         Self {
             frequency,
             freq_sweep: None,
+            phase: 0.0,
         }
     }
 
@@ -218,14 +254,22 @@ impl TriangleSynth {
         Self {
             frequency: start_freq,
             freq_sweep: Some(FrequencySweep::new(start_freq, end_freq, curve)),
+            phase: 0.0,
         }
     }
+
+    /// Sets the initial phase as a fraction of a cycle.
+    pub fn with_phase(mut self, phase: f64) -> Self {
+        self.phase = phase;
+        self
+    }
 }
 
 impl Synthesizer for TriangleSynth {
     fn synthesize(&self, num_samples: usize, sample_rate: f64, _rng: &mut Pcg32) -> Vec<f64> {
         let mut output = Vec::with_capacity(num_samples);
         let mut phase_acc = PhaseAccumulator::new(sample_rate);
+        phase_acc.set_phase_radians(self.phase * TWO_PI);
 
         for i in 0..num_samples {
             let freq = if let Some(ref sweep) = self.freq_sweep {
@@ -242,10 +286,33 @@ impl Synthesizer for TriangleSynth {
     }
 }
 
+/// Oversampling factor used by [`synthesize_oversampled`].
+pub const OVERSAMPLE_FACTOR: usize = 4;
+
+/// Renders a synthesizer at [`OVERSAMPLE_FACTOR`] times the sample rate and
+/// decimates the result back to `num_samples`.
+///
+/// The decimation filter is a zero-phase Blackman-windowed sinc, so the output
+/// stays time-aligned with a direct render.
+pub fn synthesize_oversampled<S: Synthesizer>(
+    synth: &S,
+    num_samples: usize,
+    sample_rate: f64,
+    rng: &mut Pcg32,
+) -> Vec<f64> {
+    let oversampled = synth.synthesize(
+        num_samples * OVERSAMPLE_FACTOR,
+        sample_rate * OVERSAMPLE_FACTOR as f64,
+        rng,
+    );
+    oversample::decimate(&oversampled, OVERSAMPLE_FACTOR, num_samples)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::rng::create_rng;
+    use crate::test_spectrum::power_spectrum;
 
     #[test]
     fn test_sine_synth() {
@@ -317,4 +384,88 @@ mod tests {
 
         assert_eq!(samples1, samples2);
     }
+
+    /// Renders a sawtooth with the given anti-aliasing strategy.
+    fn render_saw(frequency: f64, band_limited: bool, oversample: bool, n: usize) -> Vec<f64> {
+        let mut synth = SawSynth::new(frequency);
+        synth.band_limited = band_limited;
+        let mut rng = create_rng(42);
+        if oversample {
+            synthesize_oversampled(&synth, n, 44100.0, &mut rng)
+        } else {
+            synth.synthesize(n, 44100.0, &mut rng)
+        }
+    }
+
+    /// Fraction of spectral energy that lies away from the true harmonics of
+    /// `frequency`, i.e. energy folded back from above Nyquist.
+    fn aliasing_ratio(samples: &[f64], frequency: f64, sample_rate: f64) -> f64 {
+        let n = samples.len();
+        let spectrum = power_spectrum(samples);
+
+        let bin_hz = sample_rate / n as f64;
+        let harmonic_bins: Vec<f64> = (1..)
+            .map(|k| k as f64 * frequency / bin_hz)
+            .take_while(|&bin| bin < (n / 2) as f64)
+            .collect();
+
+        let mut total = 0.0;
+        let mut aliased = 0.0;
+        for (bin, &energy) in spectrum.iter().enumerate().skip(1) {
+            total += energy;
+            if harmonic_bins.iter().all(|&h| (bin as f64 - h).abs() > 4.0) {
+                aliased += energy;
+            }
+        }
+        aliased / total
+    }
+
+    #[test]
+    fn test_band_limiting_reduces_aliasing_of_high_saw() {
+        let n = 8192;
+        let frequency = 4987.0;
+
+        let naive = aliasing_ratio(&render_saw(frequency, false, false, n), frequency, 44100.0);
+        let polyblep = aliasing_ratio(&render_saw(frequency, true, false, n), frequency, 44100.0);
+        let oversampled = aliasing_ratio(&render_saw(frequency, true, true, n), frequency, 44100.0);
+
+        assert!(
+            polyblep < naive * 0.1,
+            "polyblep aliasing {polyblep} should be well below naive {naive}"
+        );
+        assert!(
+            oversampled < polyblep * 0.1,
+            "oversampled aliasing {oversampled} should be below polyblep {polyblep}"
+        );
+    }
+
+    #[test]
+    fn test_band_limiting_leaves_low_saw_essentially_unchanged() {
+        let n = 4410;
+        let naive = render_saw(110.0, false, false, n);
+        let signal_rms = (naive.iter().map(|s| s * s).sum::<f64>() / n as f64).sqrt();
+
+        for oversample in [false, true] {
+            let band_limited = render_saw(110.0, true, oversample, n);
+            // Skip the decimation filter's start-up region.
+            let diff_rms = (naive[100..]
+                .iter()
+                .zip(&band_limited[100..])
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f64>()
+                / (n - 100) as f64)
+                .sqrt();
+            assert!(
+                diff_rms < 0.1 * signal_rms,
+                "oversample={oversample}: diff rms {diff_rms} vs signal rms {signal_rms}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_oversampled_render_is_deterministic() {
+        let a = render_saw(3000.0, true, true, 2048);
+        let b = render_saw(3000.0, true, true, 2048);
+        assert_eq!(a, b);
+    }
 }
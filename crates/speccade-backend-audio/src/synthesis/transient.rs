@@ -0,0 +1,203 @@
+//! Transient synthesis for short percussive clicks and attacks.
+//!
+//! A transient is a single impulse made of two parts:
+//! - A pitched body: a cosine at `pitch` with an exponential decay, so the
+//!   waveform starts at its peak and produces an audible click.
+//! - A noise burst: seeded white noise with a faster decay than the body.
+//!
+//! `sharpness` sets the decay time of both parts, from a soft ~50 ms thump to a
+//! ~2 ms tick. The noise is only drawn from the RNG when `noise_mix` is above
+//! zero, so a fully tonal transient is identical for every seed.
+
+use std::f64::consts::PI;
+
+use rand::Rng;
+use rand_pcg::Pcg32;
+
+use super::Synthesizer;
+
+/// Decay time constant at sharpness 0.0, in seconds.
+const SOFT_DECAY_SECONDS: f64 = 0.05;
+/// Decay time constant at sharpness 1.0, in seconds.
+const SHARP_DECAY_SECONDS: f64 = 0.002;
+/// Noise decays this many times faster than the body.
+const NOISE_DECAY_RATIO: f64 = 2.0;
+
+/// Transient synthesizer producing a pitched click with optional noise.
+#[derive(Debug, Clone)]
+pub struct TransientSynth {
+    /// Sharpness (0.0-1.0). Higher = faster decay.
+    pub sharpness: f64,
+    /// Pitch of the tonal body in Hz.
+    pub pitch: f64,
+    /// Noise mix (0.0-1.0). 0.0 = fully tonal, 1.0 = pure noise.
+    pub noise_mix: f64,
+}
+
+impl TransientSynth {
+    /// Creates a new transient synthesizer.
+    ///
+    /// # Arguments
+    /// * `sharpness` - Sharpness (0.0-1.0), higher = shorter, harder attack
+    /// * `pitch` - Pitch of the tonal body in Hz
+    /// * `noise_mix` - Noise mix (0.0-1.0), 0.0 = fully tonal
+    pub fn new(sharpness: f64, pitch: f64, noise_mix: f64) -> Self {
+        Self {
+            sharpness: sharpness.clamp(0.0, 1.0),
+            pitch: pitch.max(20.0),
+            noise_mix: noise_mix.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Decay time constant of the body in seconds.
+    fn decay_seconds(&self) -> f64 {
+        // Exponential interpolation so sharpness feels even across the range.
+        SOFT_DECAY_SECONDS * (SHARP_DECAY_SECONDS / SOFT_DECAY_SECONDS).powf(self.sharpness)
+    }
+}
+
+impl Synthesizer for TransientSynth {
+    fn synthesize(&self, num_samples: usize, sample_rate: f64, rng: &mut Pcg32) -> Vec<f64> {
+        if num_samples == 0 {
+            return vec![];
+        }
+
+        let tau = self.decay_seconds();
+        let noise_tau = tau / NOISE_DECAY_RATIO;
+        let body_gain = 1.0 - self.noise_mix;
+        let noise_gain = self.noise_mix;
+
+        let mut output: Vec<f64> = (0..num_samples)
+            .map(|i| {
+                let t = i as f64 / sample_rate;
+                let body = (2.0 * PI * self.pitch * t).cos() * (-t / tau).exp();
+                let noise = if noise_gain > 0.0 {
+                    (rng.gen::<f64>() * 2.0 - 1.0) * (-t / noise_tau).exp()
+                } else {
+                    0.0
+                };
+                body * body_gain + noise * noise_gain
+            })
+            .collect();
+
+        // Normalize output to [-1.0, 1.0]
+        let max = output
+            .iter()
+            .map(|s| s.abs())
+            .fold(0.0_f64, |a, b| a.max(b));
+        if max > 0.0 {
+            for s in &mut output {
+                *s /= max;
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::create_rng;
+
+    /// Time (in samples) of the energy centroid.
+    fn energy_centroid(samples: &[f64]) -> f64 {
+        let total: f64 = samples.iter().map(|s| s * s).sum();
+        let weighted: f64 = samples
+            .iter()
+            .enumerate()
+            .map(|(i, s)| i as f64 * s * s)
+            .sum();
+        weighted / total
+    }
+
+    #[test]
+    fn test_transient_basic() {
+        let synth = TransientSynth::new(0.5, 1000.0, 0.5);
+        let mut rng = create_rng(42);
+        let samples = synth.synthesize(4410, 44100.0, &mut rng);
+
+        assert_eq!(samples.len(), 4410);
+        for &s in &samples {
+            assert!((-1.0..=1.0).contains(&s), "Sample out of range: {}", s);
+        }
+    }
+
+    #[test]
+    fn test_transient_determinism() {
+        let synth = TransientSynth::new(0.7, 800.0, 0.4);
+
+        let mut rng1 = create_rng(42);
+        let mut rng2 = create_rng(42);
+
+        let samples1 = synth.synthesize(4410, 44100.0, &mut rng1);
+        let samples2 = synth.synthesize(4410, 44100.0, &mut rng2);
+
+        assert_eq!(samples1, samples2);
+    }
+
+    #[test]
+    fn test_transient_zero_noise_is_fully_tonal() {
+        let synth = TransientSynth::new(0.5, 1000.0, 0.0);
+
+        let mut rng1 = create_rng(1);
+        let mut rng2 = create_rng(2);
+
+        let samples1 = synth.synthesize(4410, 44100.0, &mut rng1);
+        let samples2 = synth.synthesize(4410, 44100.0, &mut rng2);
+
+        // No noise is drawn, so the seed has no effect.
+        assert_eq!(samples1, samples2);
+        // The body starts at its peak.
+        assert!((samples1[0] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_transient_noise_depends_on_seed() {
+        let synth = TransientSynth::new(0.5, 1000.0, 0.5);
+
+        let mut rng1 = create_rng(1);
+        let mut rng2 = create_rng(2);
+
+        let samples1 = synth.synthesize(4410, 44100.0, &mut rng1);
+        let samples2 = synth.synthesize(4410, 44100.0, &mut rng2);
+
+        assert_ne!(samples1, samples2);
+    }
+
+    #[test]
+    fn test_transient_sharpness_concentrates_energy_earlier() {
+        let soft = TransientSynth::new(0.0, 500.0, 0.3);
+        let sharp = TransientSynth::new(1.0, 500.0, 0.3);
+
+        let mut rng1 = create_rng(42);
+        let mut rng2 = create_rng(42);
+
+        let soft_samples = soft.synthesize(8820, 44100.0, &mut rng1);
+        let sharp_samples = sharp.synthesize(8820, 44100.0, &mut rng2);
+
+        let soft_centroid = energy_centroid(&soft_samples);
+        let sharp_centroid = energy_centroid(&sharp_samples);
+        assert!(
+            sharp_centroid < soft_centroid / 4.0,
+            "sharp centroid {} should be well before soft centroid {}",
+            sharp_centroid,
+            soft_centroid
+        );
+    }
+
+    #[test]
+    fn test_transient_zero_samples() {
+        let synth = TransientSynth::new(0.5, 1000.0, 0.5);
+        let mut rng = create_rng(42);
+        assert!(synth.synthesize(0, 44100.0, &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_transient_clamping() {
+        let synth = TransientSynth::new(1.5, -10.0, -0.5);
+        assert_eq!(synth.sharpness, 1.0);
+        assert!(synth.pitch >= 20.0);
+        assert_eq!(synth.noise_mix, 0.0);
+    }
+}
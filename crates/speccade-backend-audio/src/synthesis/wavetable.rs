@@ -2,6 +2,10 @@
 //!
 //! This module implements wavetable synthesis where pre-computed waveform
 //! frames can be smoothly morphed between by adjusting the position parameter.
+//!
+//! Frames are stored as harmonic series and re-rendered per note with only the
+//! harmonics that fit below Nyquist (octave-spaced mip levels), so high notes
+//! play a band-limited copy of the table instead of aliasing.
 
 use std::f64::consts::PI;
 
@@ -17,6 +21,9 @@ const FRAME_SIZE: usize = 256;
 /// Number of frames per wavetable.
 const NUM_FRAMES: usize = 64;
 
+/// Harmonic limits of the mip levels, from full bandwidth down to a sine.
+const MIP_HARMONICS: [usize; 8] = [FRAME_SIZE / 2 - 1, 64, 32, 16, 8, 4, 2, 1];
+
 /// Wavetable synthesizer.
 #[derive(Debug, Clone)]
 pub struct WavetableSynth {
@@ -30,8 +37,8 @@ pub struct WavetableSynth {
     pub voices: u8,
     /// Detune amount in cents for unison.
     pub detune: f64,
-    /// Pre-generated wavetable data.
-    wavetable: Vec<Vec<f64>>,
+    /// Harmonic content of each frame as (cosine, sine) amplitudes, DC first.
+    harmonics: Vec<Vec<(f64, f64)>>,
 }
 
 /// Position sweep parameters.
@@ -55,9 +62,30 @@ impl WavetableSynth {
         voices: Option<u8>,
         detune: Option<f64>,
     ) -> Self {
-        let wavetable = generate_wavetable(source);
+        Self::from_frames(
+            &generate_wavetable(source),
+            frequency,
+            position,
+            position_sweep,
+            voices,
+            detune,
+        )
+    }
+
+    /// Creates a wavetable synthesizer from custom single-cycle frames.
+    ///
+    /// Frames may have any length; each is resampled to the internal frame size.
+    pub fn from_frames(
+        frames: &[Vec<f64>],
+        frequency: f64,
+        position: f64,
+        position_sweep: Option<PositionSweep>,
+        voices: Option<u8>,
+        detune: Option<f64>,
+    ) -> Self {
         let voices = voices.unwrap_or(1).clamp(1, 8);
         let detune = detune.unwrap_or(0.0);
+        let harmonics = frames.iter().map(|f| frame_harmonics(f)).collect();
 
         Self {
             frequency,
@@ -65,14 +93,28 @@ impl WavetableSynth {
             position_sweep,
             voices,
             detune,
-            wavetable,
+            harmonics,
         }
     }
-}
 
-impl Synthesizer for WavetableSynth {
-    fn synthesize(&self, num_samples: usize, sample_rate: f64, _rng: &mut Pcg32) -> Vec<f64> {
+    /// Renders the note with an explicit morph position per sample.
+    ///
+    /// Used for LFO position modulation; `positions` determines the length.
+    pub fn synthesize_with_positions(&self, positions: &[f64], sample_rate: f64) -> Vec<f64> {
+        self.render(positions.len(), sample_rate, |i| positions[i])
+    }
+
+    /// Renders `num_samples` samples, reading the morph position from `position_at`.
+    fn render(
+        &self,
+        num_samples: usize,
+        sample_rate: f64,
+        position_at: impl Fn(usize) -> f64,
+    ) -> Vec<f64> {
         let mut output = vec![0.0; num_samples];
+        if self.harmonics.is_empty() {
+            return output;
+        }
 
         // Calculate detune spread for unison voices
         let detune_spread: Vec<f64> = if self.voices > 1 {
@@ -87,27 +129,19 @@ impl Synthesizer for WavetableSynth {
             vec![1.0]
         };
 
+        // Pick the mip level for the highest voice so no voice aliases
+        let max_mult = detune_spread.iter().cloned().fold(1.0, f64::max);
+        let max_harmonic = mip_harmonics(self.frequency * max_mult, sample_rate);
+        let wavetable = band_limited_frames(&self.harmonics, max_harmonic);
+
         // Generate each unison voice
         for detune_mult in detune_spread.iter() {
             let mut phase_acc = PhaseAccumulator::new(sample_rate);
             let voice_freq = self.frequency * detune_mult;
 
             for (i, out_sample) in output.iter_mut().enumerate().take(num_samples) {
-                // Calculate position (with optional sweep)
-                let position = if let Some(ref sweep) = self.position_sweep {
-                    let t = i as f64 / num_samples.max(1) as f64;
-                    sweep
-                        .curve
-                        .interpolate(sweep.start_position, sweep.end_position, t)
-                } else {
-                    self.position
-                };
-
-                // Get sample from wavetable at this position
                 let phase = phase_acc.advance(voice_freq);
-                let sample = sample_wavetable(&self.wavetable, phase, position);
-
-                *out_sample += sample;
+                *out_sample += sample_wavetable(&wavetable, phase, position_at(i));
             }
         }
 
@@ -121,6 +155,90 @@ impl Synthesizer for WavetableSynth {
     }
 }
 
+impl Synthesizer for WavetableSynth {
+    fn synthesize(&self, num_samples: usize, sample_rate: f64, _rng: &mut Pcg32) -> Vec<f64> {
+        self.render(num_samples, sample_rate, |i| {
+            // Calculate position (with optional sweep)
+            if let Some(ref sweep) = self.position_sweep {
+                let t = i as f64 / num_samples.max(1) as f64;
+                sweep
+                    .curve
+                    .interpolate(sweep.start_position, sweep.end_position, t)
+            } else {
+                self.position
+            }
+        })
+    }
+}
+
+/// Returns the harmonic limit of the mip level used for `frequency`.
+///
+/// Picks the largest level whose top harmonic stays below Nyquist.
+fn mip_harmonics(frequency: f64, sample_rate: f64) -> usize {
+    let nyquist = sample_rate / 2.0;
+    MIP_HARMONICS
+        .iter()
+        .copied()
+        .find(|&h| h as f64 * frequency < nyquist)
+        .unwrap_or(1)
+}
+
+/// Decomposes a single-cycle frame into (cosine, sine) harmonic amplitudes.
+///
+/// Index 0 holds the DC offset. Harmonics at or above the frame's own Nyquist
+/// are dropped, as are those the internal frame size cannot represent.
+fn frame_harmonics(frame: &[f64]) -> Vec<(f64, f64)> {
+    let n = frame.len();
+    if n == 0 {
+        return vec![(0.0, 0.0)];
+    }
+    let count = n.div_ceil(2).min(FRAME_SIZE / 2);
+    let angles: Vec<(f64, f64)> = (0..n)
+        .map(|i| (2.0 * PI * i as f64 / n as f64).sin_cos())
+        .collect();
+    (0..count)
+        .map(|k| {
+            let (mut a, mut b) = (0.0, 0.0);
+            for (i, &x) in frame.iter().enumerate() {
+                let (sin, cos) = angles[(k * i) % n];
+                a += x * cos;
+                b += x * sin;
+            }
+            let scale = if k == 0 { 1.0 } else { 2.0 } / n as f64;
+            (a * scale, b * scale)
+        })
+        .collect()
+}
+
+/// Renders every frame at `FRAME_SIZE` using harmonics up to `max_harmonic`.
+fn band_limited_frames(harmonics: &[Vec<(f64, f64)>], max_harmonic: usize) -> Vec<Vec<f64>> {
+    let cos_table: Vec<f64> = (0..FRAME_SIZE)
+        .map(|i| (2.0 * PI * i as f64 / FRAME_SIZE as f64).cos())
+        .collect();
+    let sin_table: Vec<f64> = (0..FRAME_SIZE)
+        .map(|i| (2.0 * PI * i as f64 / FRAME_SIZE as f64).sin())
+        .collect();
+
+    harmonics
+        .iter()
+        .map(|frame| {
+            (0..FRAME_SIZE)
+                .map(|i| {
+                    frame
+                        .iter()
+                        .enumerate()
+                        .take(max_harmonic + 1)
+                        .map(|(k, &(a, b))| {
+                            let idx = (k * i) % FRAME_SIZE;
+                            a * cos_table[idx] + b * sin_table[idx]
+                        })
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
 /// Samples from the wavetable at a given phase and position.
 ///
 /// # Arguments
@@ -134,13 +252,14 @@ fn sample_wavetable(wavetable: &[Vec<f64>], phase: f64, position: f64) -> f64 {
     let position = position.clamp(0.0, 1.0);
 
     // Calculate which frames to interpolate between
-    let frame_pos = position * (NUM_FRAMES - 1) as f64;
+    let last = wavetable.len() - 1;
+    let frame_pos = position * last as f64;
     let frame_idx = frame_pos.floor() as usize;
     let frame_frac = frame_pos.fract();
 
     // Get the two frames to interpolate between
-    let frame1 = &wavetable[frame_idx.min(NUM_FRAMES - 1)];
-    let frame2 = &wavetable[(frame_idx + 1).min(NUM_FRAMES - 1)];
+    let frame1 = &wavetable[frame_idx.min(last)];
+    let frame2 = &wavetable[(frame_idx + 1).min(last)];
 
     // Calculate sample position within frame
     let normalized_phase = (phase / (2.0 * PI)).fract();
@@ -494,4 +613,79 @@ mod tests {
             }
         }
     }
+
+    /// Magnitude of the component at `freq` over the whole buffer.
+    fn tone_magnitude(samples: &[f64], freq: f64, sample_rate: f64) -> f64 {
+        let (mut re, mut im) = (0.0, 0.0);
+        for (i, &x) in samples.iter().enumerate() {
+            let angle = 2.0 * PI * freq * i as f64 / sample_rate;
+            re += x * angle.cos();
+            im += x * angle.sin();
+        }
+        (re * re + im * im).sqrt() * 2.0 / samples.len() as f64
+    }
+
+    #[test]
+    fn test_two_table_morph_lands_between_endpoints() {
+        let sine: Vec<f64> = (0..FRAME_SIZE)
+            .map(|i| (2.0 * PI * i as f64 / FRAME_SIZE as f64).sin())
+            .collect();
+        // Falling ramp, so its fundamental is in phase with the sine
+        let saw: Vec<f64> = (0..FRAME_SIZE)
+            .map(|i| 1.0 - 2.0 * i as f64 / FRAME_SIZE as f64)
+            .collect();
+        let frames = vec![sine, saw];
+
+        // 441 Hz at 44.1 kHz is exactly 100 samples per cycle
+        let h2_ratio = |position: f64| {
+            let synth = WavetableSynth::from_frames(&frames, 441.0, position, None, None, None);
+            let mut rng = create_rng(42);
+            let samples = synth.synthesize(4400, 44100.0, &mut rng);
+            tone_magnitude(&samples, 882.0, 44100.0) / tone_magnitude(&samples, 441.0, 44100.0)
+        };
+
+        let at_sine = h2_ratio(0.0);
+        let at_mid = h2_ratio(0.5);
+        let at_saw = h2_ratio(1.0);
+
+        assert!(at_sine < 0.01, "sine endpoint has h2 ratio {}", at_sine);
+        assert!(
+            (at_saw - 0.5).abs() < 0.05,
+            "saw endpoint has h2 ratio {}",
+            at_saw
+        );
+        assert!(
+            at_mid > at_sine + 0.05 && at_mid < at_saw - 0.05,
+            "morph h2 ratio {} not between {} and {}",
+            at_mid,
+            at_sine,
+            at_saw
+        );
+    }
+
+    #[test]
+    fn test_mip_selection_limits_harmonics_below_nyquist() {
+        assert_eq!(mip_harmonics(100.0, 44100.0), FRAME_SIZE / 2 - 1);
+        assert_eq!(mip_harmonics(1000.0, 44100.0), 16);
+        assert_eq!(mip_harmonics(5000.0, 44100.0), 4);
+        assert_eq!(mip_harmonics(20000.0, 44100.0), 1);
+
+        let harmonics = frame_harmonics(&generate_basic_wavetable()[20]);
+        let limited = band_limited_frames(&[harmonics], 4);
+        let rebuilt = frame_harmonics(&limited[0]);
+        for (k, &(a, b)) in rebuilt.iter().enumerate().skip(5) {
+            assert!(a.hypot(b) < 1e-9, "harmonic {} survived band-limiting", k);
+        }
+    }
+
+    #[test]
+    fn test_custom_frames_any_length_deterministic() {
+        let frames = vec![vec![0.0, 1.0, 0.0, -1.0], vec![1.0, 0.5, 0.0, -0.5, -1.0]];
+        let synth = WavetableSynth::from_frames(&frames, 220.0, 0.3, None, None, None);
+        let a = synth.synthesize(2000, 44100.0, &mut create_rng(1));
+        let b = synth.synthesize(2000, 44100.0, &mut create_rng(2));
+        assert_eq!(a, b);
+        assert!(a.iter().all(|s| s.is_finite()));
+        assert!(a.iter().any(|s| s.abs() > 0.1));
+    }
 }
@@ -0,0 +1,33 @@
+//! Spectrum and level helpers shared by the crate's unit tests.
+
+use rustfft::{num_complex::Complex, FftPlanner};
+
+use crate::oscillator::TWO_PI;
+
+/// Power spectrum of the Hann-windowed `samples`.
+///
+/// Returns the `samples.len() / 2` bins below Nyquist; bin `k` is centered on
+/// `k * sample_rate / samples.len()` Hz.
+pub(crate) fn power_spectrum(samples: &[f64]) -> Vec<f64> {
+    let n = samples.len();
+    let mut buffer: Vec<Complex<f64>> = samples
+        .iter()
+        .enumerate()
+        .map(|(i, &s)| {
+            let w = 0.5 - 0.5 * (TWO_PI * i as f64 / n as f64).cos();
+            Complex::new(s * w, 0.0)
+        })
+        .collect();
+    FftPlanner::new().plan_fft_forward(n).process(&mut buffer);
+    buffer[..n / 2].iter().map(|c| c.norm_sqr()).collect()
+}
+
+/// Index of the bin nearest `frequency` in the spectrum of `len` samples.
+pub(crate) fn bin(frequency: f64, len: usize, sample_rate: f64) -> usize {
+    (frequency * len as f64 / sample_rate).round() as usize
+}
+
+/// Root-mean-square level of `samples`.
+pub(crate) fn rms(samples: &[f64]) -> f64 {
+    (samples.iter().map(|s| s * s).sum::<f64>() / samples.len() as f64).sqrt()
+}
@@ -1,14 +1,18 @@
 //! WAV file writer builder pattern.
 
+use speccade_spec::recipe::audio::TailFade;
+
 use crate::mixer::StereoOutput;
 
 use super::format::WavFormat;
+use super::tail::{apply_tail_fade, apply_tail_fade_stereo, TailFadeSettings};
 use super::writer::{samples_to_pcm16, stereo_to_pcm16, write_wav_to_vec};
 
 /// WAV file writer builder.
 #[derive(Debug)]
 pub struct WavWriter {
     format: WavFormat,
+    tail_fade: Option<TailFadeSettings>,
 }
 
 impl WavWriter {
@@ -16,6 +20,7 @@ impl WavWriter {
     pub fn mono(sample_rate: u32) -> Self {
         Self {
             format: WavFormat::mono(sample_rate),
+            tail_fade: None,
         }
     }
 
@@ -23,18 +28,27 @@ impl WavWriter {
     pub fn stereo(sample_rate: u32) -> Self {
         Self {
             format: WavFormat::stereo(sample_rate),
+            tail_fade: None,
         }
     }
 
+    /// Fades out a still-ringing tail before writing.
+    ///
+    /// Audio that already ends below the threshold is written unchanged.
+    pub fn with_tail_fade(mut self, tail_fade: &TailFade) -> Self {
+        self.tail_fade = Some(TailFadeSettings::new(tail_fade, self.format.sample_rate));
+        self
+    }
+
     /// Writes mono samples to a byte vector.
     pub fn write_mono(&self, samples: &[f64]) -> Vec<u8> {
-        let pcm = samples_to_pcm16(samples);
+        let pcm = self.mono_pcm(samples);
         write_wav_to_vec(&self.format, &pcm)
     }
 
     /// Writes stereo samples to a byte vector.
     pub fn write_stereo(&self, left: &[f64], right: &[f64]) -> Vec<u8> {
-        let pcm = stereo_to_pcm16(left, right);
+        let pcm = self.stereo_pcm(left, right);
         write_wav_to_vec(&self.format, &pcm)
     }
 
@@ -51,7 +65,7 @@ impl WavWriter {
     /// # Returns
     /// BLAKE3 hash of the PCM data (not the full WAV file)
     pub fn pcm_hash_mono(&self, samples: &[f64]) -> String {
-        let pcm = samples_to_pcm16(samples);
+        let pcm = self.mono_pcm(samples);
         blake3::hash(&pcm).to_hex().to_string()
     }
 
@@ -64,7 +78,32 @@ impl WavWriter {
     /// # Returns
     /// BLAKE3 hash of the PCM data (not the full WAV file)
     pub fn pcm_hash_stereo(&self, left: &[f64], right: &[f64]) -> String {
-        let pcm = stereo_to_pcm16(left, right);
+        let pcm = self.stereo_pcm(left, right);
         blake3::hash(&pcm).to_hex().to_string()
     }
+
+    fn mono_pcm(&self, samples: &[f64]) -> Vec<u8> {
+        match self.tail_fade {
+            Some(settings) => {
+                let mut samples = samples.to_vec();
+                apply_tail_fade(&mut samples, settings);
+                samples_to_pcm16(&samples)
+            }
+            None => samples_to_pcm16(samples),
+        }
+    }
+
+    fn stereo_pcm(&self, left: &[f64], right: &[f64]) -> Vec<u8> {
+        match self.tail_fade {
+            Some(settings) => {
+                let mut stereo = StereoOutput {
+                    left: left.to_vec(),
+                    right: right.to_vec(),
+                };
+                apply_tail_fade_stereo(&mut stereo, settings);
+                stereo_to_pcm16(&stereo.left, &stereo.right)
+            }
+            None => stereo_to_pcm16(left, right),
+        }
+    }
 }
@@ -8,6 +8,7 @@ mod builder;
 mod format;
 mod pcm;
 mod result;
+mod tail;
 mod writer;
 
 #[cfg(test)]
@@ -18,4 +19,5 @@ pub use builder::WavWriter;
 pub use format::WavFormat;
 pub use pcm::{compute_pcm_hash, extract_pcm_data};
 pub use result::WavResult;
+pub(crate) use tail::{apply_tail_fade, apply_tail_fade_stereo, TailFadeSettings};
 pub use writer::{samples_to_pcm16, stereo_to_pcm16, write_wav, write_wav_to_vec};
@@ -0,0 +1,88 @@
+//! Deterministic tail fade-out.
+//!
+//! A buffer that ends while a reverb or delay tail is still ringing is cut off
+//! abruptly, which is heard as a click at the end of the file. The fade here
+//! ramps the final samples to silence, but only when the end of the buffer is
+//! actually above the silence threshold.
+
+use speccade_spec::recipe::audio::TailFade;
+
+use crate::mixer::StereoOutput;
+
+/// Resolved tail fade settings in sample units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct TailFadeSettings {
+    /// Linear amplitude below which a sample counts as silent.
+    pub threshold: f64,
+    /// Number of samples faded out at the end of the buffer.
+    pub fade_samples: usize,
+}
+
+impl TailFadeSettings {
+    /// Resolves a spec-level [`TailFade`] for the given sample rate.
+    pub fn new(tail_fade: &TailFade, sample_rate: u32) -> Self {
+        let fade_samples = (tail_fade.fade_ms / 1000.0 * sample_rate as f64).round();
+        Self {
+            threshold: 10.0_f64.powf(tail_fade.threshold_db / 20.0),
+            fade_samples: fade_samples.max(1.0) as usize,
+        }
+    }
+}
+
+/// Fades out the end of a mono buffer if it is still above the threshold.
+///
+/// Returns `true` if the fade was applied.
+pub(crate) fn apply_tail_fade(samples: &mut [f64], settings: TailFadeSettings) -> bool {
+    let fade_len = settings.fade_samples.min(samples.len());
+    let start = samples.len() - fade_len;
+
+    if tail_is_silent(samples[start..].iter().copied(), settings.threshold) {
+        return false;
+    }
+
+    for (i, sample) in samples[start..].iter_mut().enumerate() {
+        *sample *= fade_gain(i, fade_len);
+    }
+    true
+}
+
+/// Fades out the end of a stereo buffer if either channel is above the threshold.
+///
+/// Both channels receive the same gain curve so the stereo image is preserved.
+/// Returns `true` if the fade was applied.
+pub(crate) fn apply_tail_fade_stereo(
+    stereo: &mut StereoOutput,
+    settings: TailFadeSettings,
+) -> bool {
+    let len = stereo.left.len().min(stereo.right.len());
+    let fade_len = settings.fade_samples.min(len);
+    let start = len - fade_len;
+
+    let tail = stereo.left[start..len]
+        .iter()
+        .zip(&stereo.right[start..len])
+        .map(|(l, r)| l.abs().max(r.abs()));
+    if tail_is_silent(tail, settings.threshold) {
+        return false;
+    }
+
+    for i in 0..fade_len {
+        let gain = fade_gain(i, fade_len);
+        stereo.left[start + i] *= gain;
+        stereo.right[start + i] *= gain;
+    }
+    true
+}
+
+fn tail_is_silent(mut tail: impl Iterator<Item = f64>, threshold: f64) -> bool {
+    tail.all(|s| s.abs() < threshold)
+}
+
+/// Raised-cosine gain from 1.0 down to exactly 0.0 at the last sample.
+fn fade_gain(index: usize, fade_len: usize) -> f64 {
+    if fade_len <= 1 {
+        return 0.0;
+    }
+    let t = index as f64 / (fade_len - 1) as f64;
+    0.5 * (1.0 + (t * std::f64::consts::PI).cos())
+}
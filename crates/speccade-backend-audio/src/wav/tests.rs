@@ -1,5 +1,7 @@
 //! Tests for the WAV writer module.
 
+use speccade_spec::recipe::audio::TailFade;
+
 use crate::mixer::StereoOutput;
 
 use super::builder::WavWriter;
@@ -727,3 +729,96 @@ fn test_write_wav_to_vec_matches_write_wav() {
 
     assert_eq!(wav_vec, wav_writer);
 }
+
+// =========================================================================
+// Tail fade tests
+// =========================================================================
+
+/// Decodes the PCM payload of a 16-bit WAV into samples.
+fn decode_pcm16(wav: &[u8]) -> Vec<i16> {
+    extract_pcm_data(wav)
+        .unwrap()
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]))
+        .collect()
+}
+
+/// Jump from the last sample to the silence that follows the end of the file.
+fn end_discontinuity(pcm: &[i16]) -> u32 {
+    pcm.last().map(|s| s.unsigned_abs() as u32).unwrap_or(0)
+}
+
+/// Largest sample-to-sample step within the last `n` samples.
+fn max_step(pcm: &[i16], n: usize) -> u32 {
+    pcm[pcm.len() - n..]
+        .windows(2)
+        .map(|w| (w[1] as i32 - w[0] as i32).unsigned_abs())
+        .max()
+        .unwrap_or(0)
+}
+
+/// A 440 Hz sine that is still at a peak when the buffer ends.
+fn ringing_sine(sample_rate: u32, len: usize) -> Vec<f64> {
+    let freq = 440.0;
+    let phase_offset = std::f64::consts::FRAC_PI_2
+        - (len - 1) as f64 * freq / sample_rate as f64 * std::f64::consts::TAU;
+    (0..len)
+        .map(|i| {
+            let t = i as f64 / sample_rate as f64;
+            0.8 * (t * freq * std::f64::consts::TAU + phase_offset).sin()
+        })
+        .collect()
+}
+
+#[test]
+fn test_tail_fade_removes_end_discontinuity() {
+    let samples = ringing_sine(44100, 4410);
+
+    let plain = decode_pcm16(&WavWriter::mono(44100).write_mono(&samples));
+    let faded = decode_pcm16(
+        &WavWriter::mono(44100)
+            .with_tail_fade(&TailFade::default())
+            .write_mono(&samples),
+    );
+
+    // 10ms fade at 44.1kHz.
+    let fade_len = 441;
+    assert!(end_discontinuity(&plain) > 25_000);
+    assert_eq!(end_discontinuity(&faded), 0);
+
+    // The fade must not introduce steps larger than the signal's own slope.
+    assert!(max_step(&faded, fade_len) <= max_step(&plain, fade_len));
+    assert_eq!(
+        plain[..plain.len() - fade_len],
+        faded[..faded.len() - fade_len]
+    );
+}
+
+#[test]
+fn test_tail_fade_leaves_silent_ending_unchanged() {
+    let mut samples = ringing_sine(44100, 4410);
+    for s in samples.iter_mut().skip(3000) {
+        *s = 0.0;
+    }
+
+    let plain = WavWriter::mono(44100).write_mono(&samples);
+    let faded = WavWriter::mono(44100)
+        .with_tail_fade(&TailFade::default())
+        .write_mono(&samples);
+
+    assert_eq!(plain, faded);
+}
+
+#[test]
+fn test_tail_fade_stereo_is_deterministic() {
+    let left = ringing_sine(48000, 4800);
+    let right: Vec<f64> = left.iter().map(|s| s * 0.5).collect();
+    let writer = WavWriter::stereo(48000).with_tail_fade(&TailFade::default());
+
+    let first = writer.write_stereo(&left, &right);
+    let second = writer.write_stereo(&left, &right);
+    assert_eq!(first, second);
+
+    let pcm = decode_pcm16(&first);
+    assert_eq!(pcm[pcm.len() - 2..], [0, 0]);
+}
@@ -2,8 +2,8 @@
 
 use speccade_backend_audio::generate;
 use speccade_spec::recipe::audio::{
-    AudioV1Params, Envelope, NoiseType, NoteSpec, OscillatorConfig, PitchEnvelope, SpectralSource,
-    Synthesis, Waveform,
+    AudioV1Params, ChannelMode, Envelope, NoiseType, NoteSpec, OscillatorConfig, PitchEnvelope,
+    SpectralSource, Synthesis, Waveform,
 };
 use speccade_spec::{AssetType, OutputFormat, OutputSpec, Recipe, Spec};
 
@@ -34,12 +34,22 @@ fn test_instrument_with_detune() {
                 freq_sweep: None,
                 detune: Some(50.0), // 50 cents up
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -49,6 +59,11 @@ fn test_instrument_with_detune() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-detune");
@@ -72,12 +87,22 @@ fn test_instrument_with_duty_cycle() {
                 freq_sweep: None,
                 detune: None,
                 duty: Some(0.25), // 25% duty cycle
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -87,6 +112,11 @@ fn test_instrument_with_duty_cycle() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-duty");
@@ -110,12 +140,22 @@ fn test_instrument_with_pitch_envelope() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -131,6 +171,11 @@ fn test_instrument_with_pitch_envelope() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-pitch-env");
@@ -175,11 +220,18 @@ fn test_instrument_multi_oscillator() {
                 ],
                 freq_sweep: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -189,6 +241,11 @@ fn test_instrument_multi_oscillator() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-multi-osc");
@@ -226,11 +283,18 @@ fn test_instrument_multi_oscillator_with_pitch_envelope() {
                 ],
                 freq_sweep: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -246,6 +310,11 @@ fn test_instrument_multi_oscillator_with_pitch_envelope() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-multi-osc-pitch");
@@ -269,12 +338,22 @@ fn test_instrument_determinism_with_new_features() {
                 freq_sweep: None,
                 detune: Some(10.0),
                 duty: Some(0.3),
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -290,6 +369,11 @@ fn test_instrument_determinism_with_new_features() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec1 = create_instrument_spec(params.clone(), 42, "test-determinism-1");
@@ -318,11 +402,19 @@ fn test_spectral_freeze_noise() {
                 decay: 0.1,
                 sustain: 0.7,
                 release: 0.2,
-            },
+            }
+            .into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -332,6 +424,11 @@ fn test_spectral_freeze_noise() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-spectral-freeze-noise");
@@ -354,11 +451,18 @@ fn test_spectral_freeze_tone() {
                     frequency: 220.0,
                 },
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -368,6 +472,11 @@ fn test_spectral_freeze_tone() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = create_instrument_spec(params, 42, "test-spectral-freeze-tone");
@@ -389,11 +498,18 @@ fn test_spectral_freeze_determinism() {
                     noise_type: NoiseType::Brown,
                 },
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -403,6 +519,11 @@ fn test_spectral_freeze_determinism() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec1 = create_instrument_spec(params.clone(), 123, "test-spectral-determinism-1");
@@ -2,7 +2,7 @@
 
 use speccade_backend_audio::generate::generate_from_params;
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, Envelope, Synthesis,
+    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, ChannelMode, Envelope, Synthesis,
 };
 
 // ============================================================================
@@ -24,11 +24,19 @@ fn test_pitched_body() {
                 decay: 0.5,
                 sustain: 0.0,
                 release: 0.5,
-            },
+            }
+            .into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -38,6 +46,11 @@ fn test_pitched_body() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -64,11 +77,19 @@ fn test_metallic_synthesis() {
                 decay: 0.3,
                 sustain: 0.1,
                 release: 0.5,
-            },
+            }
+            .into(),
             volume: 0.6,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -78,6 +99,11 @@ fn test_metallic_synthesis() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -100,11 +126,19 @@ fn test_metallic_synthesis_many_partials() {
                 decay: 0.4,
                 sustain: 0.0,
                 release: 0.6,
-            },
+            }
+            .into(),
             volume: 0.5,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -114,6 +148,11 @@ fn test_metallic_synthesis_many_partials() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
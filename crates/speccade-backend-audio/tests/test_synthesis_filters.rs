@@ -2,8 +2,8 @@
 
 use speccade_backend_audio::generate::generate_from_params;
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, Envelope, Filter, NoiseType,
-    Synthesis, Waveform,
+    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, ChannelMode, Envelope, Filter,
+    NoiseType, Synthesis, Waveform,
 };
 
 #[test]
@@ -18,18 +18,29 @@ fn test_filter_lowpass() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: Some(Filter::Lowpass {
             cutoff: 2000.0,
             resonance: 0.707,
             cutoff_end: None,
+            keytrack: None,
         }),
         effects: vec![],
         pitch_envelope: None,
@@ -37,6 +48,11 @@ fn test_filter_lowpass() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -55,18 +71,29 @@ fn test_filter_lowpass_with_sweep() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: Some(Filter::Lowpass {
             cutoff: 5000.0,
             resonance: 1.0,
             cutoff_end: Some(500.0),
+            keytrack: None,
         }),
         effects: vec![],
         pitch_envelope: None,
@@ -74,6 +101,11 @@ fn test_filter_lowpass_with_sweep() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -90,17 +122,25 @@ fn test_filter_highpass() {
                 noise_type: NoiseType::White,
                 filter: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.6,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: Some(Filter::Highpass {
             cutoff: 1000.0,
             resonance: 0.5,
             cutoff_end: None,
+            keytrack: None,
         }),
         effects: vec![],
         pitch_envelope: None,
@@ -108,6 +148,11 @@ fn test_filter_highpass() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -124,17 +169,25 @@ fn test_filter_highpass_with_sweep() {
                 noise_type: NoiseType::White,
                 filter: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.6,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: Some(Filter::Highpass {
             cutoff: 100.0,
             resonance: 0.8,
             cutoff_end: Some(3000.0),
+            keytrack: None,
         }),
         effects: vec![],
         pitch_envelope: None,
@@ -142,6 +195,11 @@ fn test_filter_highpass_with_sweep() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -158,17 +216,25 @@ fn test_filter_bandpass() {
                 noise_type: NoiseType::White,
                 filter: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.6,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: Some(Filter::Bandpass {
             center: 1000.0,
             resonance: 0.707,
             center_end: None,
+            keytrack: None,
         }),
         effects: vec![],
         pitch_envelope: None,
@@ -176,6 +242,11 @@ fn test_filter_bandpass() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -192,17 +263,25 @@ fn test_filter_bandpass_with_sweep() {
                 noise_type: NoiseType::White,
                 filter: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.6,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: Some(Filter::Bandpass {
             center: 2000.0,
             resonance: 1.2,
             center_end: Some(500.0),
+            keytrack: None,
         }),
         effects: vec![],
         pitch_envelope: None,
@@ -210,6 +289,11 @@ fn test_filter_bandpass_with_sweep() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -2,8 +2,8 @@
 
 use speccade_backend_audio::generate::generate_from_params;
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, Envelope, FreqSweep, SweepCurve,
-    Synthesis,
+    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, ChannelMode, Envelope, FreqSweep,
+    SweepCurve, Synthesis,
 };
 
 // ============================================================================
@@ -22,11 +22,18 @@ fn test_fm_synth_basic() {
                 modulation_index: 2.0,
                 freq_sweep: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -36,6 +43,11 @@ fn test_fm_synth_basic() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -57,11 +69,18 @@ fn test_fm_synth_with_sweep() {
                     curve: SweepCurve::Exponential,
                 }),
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -71,6 +90,11 @@ fn test_fm_synth_with_sweep() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -92,11 +116,18 @@ fn test_karplus_strong() {
                 decay: 0.996,
                 blend: 0.7,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -106,6 +137,11 @@ fn test_karplus_strong() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -123,11 +159,18 @@ fn test_karplus_strong_high_damping() {
                 decay: 0.99,
                 blend: 0.9,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -137,6 +180,11 @@ fn test_karplus_strong_high_damping() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -157,11 +205,18 @@ fn test_additive_synthesis() {
                 base_freq: 440.0,
                 harmonics: vec![1.0, 0.5, 0.25, 0.125, 0.0625],
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -171,6 +226,11 @@ fn test_additive_synthesis() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -187,11 +247,18 @@ fn test_additive_synthesis_single_harmonic() {
                 base_freq: 440.0,
                 harmonics: vec![1.0],
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -201,6 +268,11 @@ fn test_additive_synthesis_single_harmonic() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
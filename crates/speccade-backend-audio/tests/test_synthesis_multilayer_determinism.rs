@@ -2,8 +2,8 @@
 
 use speccade_backend_audio::generate::generate_from_params;
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, Envelope, Filter, NoiseType,
-    Synthesis, Waveform,
+    AudioLayer, AudioV1Params as AudioSfxLayeredSynthV1Params, ChannelMode, Envelope, Filter,
+    NoiseType, Synthesis, Waveform,
 };
 
 // ============================================================================
@@ -23,12 +23,22 @@ fn test_multi_layer_with_delay() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             },
             AudioLayer {
@@ -38,6 +48,7 @@ fn test_multi_layer_with_delay() {
                         cutoff: 3000.0,
                         resonance: 0.707,
                         cutoff_end: None,
+                        keytrack: None,
                     }),
                 },
                 envelope: Envelope {
@@ -45,11 +56,19 @@ fn test_multi_layer_with_delay() {
                     decay: 0.05,
                     sustain: 0.0,
                     release: 0.1,
-                },
+                }
+                .into(),
                 volume: 0.3,
                 pan: 0.0,
                 delay: Some(0.05),
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             },
         ],
@@ -60,6 +79,11 @@ fn test_multi_layer_with_delay() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -79,12 +103,22 @@ fn test_multi_layer_with_panning() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: -0.5, // Left
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             },
             AudioLayer {
@@ -94,12 +128,22 @@ fn test_multi_layer_with_panning() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.5, // Right
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             },
         ],
@@ -110,6 +154,11 @@ fn test_multi_layer_with_panning() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = generate_from_params(&params, 42);
@@ -130,11 +179,18 @@ fn test_determinism_noise() {
                 noise_type: NoiseType::White,
                 filter: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.6,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -144,6 +200,11 @@ fn test_determinism_noise() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result1 = generate_from_params(&params, 42).expect("first generation");
@@ -164,11 +225,18 @@ fn test_determinism_karplus() {
                 decay: 0.996,
                 blend: 0.7,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         master_filter: None,
@@ -178,6 +246,11 @@ fn test_determinism_karplus() {
         loop_config: None,
         generate_loop_points: false,
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result1 = generate_from_params(&params, 42).expect("first generation");
@@ -4,6 +4,7 @@
 //! rather than requiring byte-identical output (unlike Tier 1 backends).
 
 use serde::{Deserialize, Serialize};
+pub use speccade_spec::report::MetricTolerances;
 use speccade_spec::report::StructuralMetrics;
 
 /// Metrics reported by Blender for a generated mesh or animation.
@@ -372,24 +373,6 @@ impl BoundingBox {
     }
 }
 
-/// Tolerances for Tier 2 metric validation as specified in RFC-0001.
-#[derive(Debug, Clone)]
-pub struct MetricTolerances {
-    /// Bounding box tolerance (+/- units).
-    pub bounding_box: f64,
-    /// Animation duration tolerance (+/- seconds).
-    pub animation_duration: f64,
-}
-
-impl Default for MetricTolerances {
-    fn default() -> Self {
-        Self {
-            bounding_box: 0.001,
-            animation_duration: 0.001,
-        }
-    }
-}
-
 /// Result of comparing two metric sets.
 #[derive(Debug, Clone)]
 pub struct MetricComparison {
@@ -1,12 +1,15 @@
 //! Envelope conversion utilities for tracker formats.
 //!
-//! This module handles converting SpecCade ADSR envelopes to format-specific
-//! envelope representations (XM and IT).
+//! This module handles converting SpecCade ADSR volume envelopes and point-based
+//! pan envelopes to format-specific envelope representations (XM and IT).
 //!
 //! The conversion uses a generic approach with the `EnvelopePoint` trait,
-//! allowing shared ADSR calculation logic for both XM and IT formats.
+//! allowing shared point-list logic for both XM and IT formats.
 
 use speccade_spec::recipe::audio::Envelope;
+use speccade_spec::recipe::music::TrackerEnvelope;
+
+use crate::generate::GenerateError;
 
 use crate::it::{env_flags, ItEnvelope, ItEnvelopePoint};
 use crate::xm::{XmEnvelope, XmEnvelopePoint};
@@ -34,12 +37,23 @@ trait EnvelopePoint {
     /// * `tick` - The tick/frame position in the envelope
     /// * `value` - The envelope value (0-64 range, will be converted to format-specific type)
     fn new(tick: u16, value: u16) -> Self;
+
+    /// Create a new panning envelope point from a pan position (-32 left to 32 right).
+    fn pan(tick: u16, pan: i8) -> Self;
 }
 
 impl EnvelopePoint for XmEnvelopePoint {
     fn new(tick: u16, value: u16) -> Self {
         XmEnvelopePoint { frame: tick, value }
     }
+
+    fn pan(tick: u16, pan: i8) -> Self {
+        // XM panning envelopes run 0-64 with 32 at center.
+        XmEnvelopePoint {
+            frame: tick,
+            value: (pan.clamp(-32, 32) + 32) as u16,
+        }
+    }
 }
 
 impl EnvelopePoint for ItEnvelopePoint {
@@ -49,6 +63,22 @@ impl EnvelopePoint for ItEnvelopePoint {
             value: value as i8,
         }
     }
+
+    fn pan(tick: u16, pan: i8) -> Self {
+        ItEnvelopePoint {
+            tick,
+            value: pan.clamp(-32, 32),
+        }
+    }
+}
+
+/// Convert a point-based pan envelope to format points.
+fn calculate_pan_points<P: EnvelopePoint>(envelope: &TrackerEnvelope) -> Vec<P> {
+    envelope
+        .points
+        .iter()
+        .map(|point| P::pan(point.tick, point.value))
+        .collect()
 }
 
 /// Calculate ADSR envelope points in a format-agnostic way.
@@ -115,6 +145,43 @@ fn calculate_adsr_points<P: EnvelopePoint>(envelope: &Envelope) -> Vec<P> {
     points
 }
 
+/// Build an enabled XM envelope from points, holding at `sustain_point` if set.
+fn xm_envelope(points: Vec<XmEnvelopePoint>, sustain_point: Option<u8>) -> XmEnvelope {
+    XmEnvelope {
+        points,
+        sustain_point: sustain_point.unwrap_or(0),
+        loop_start: 0,
+        loop_end: 0,
+        enabled: true,
+        sustain_enabled: sustain_point.is_some(),
+        loop_enabled: false,
+    }
+}
+
+/// Build an enabled IT envelope from points, holding at `sustain_point` if set.
+fn it_envelope(points: Vec<ItEnvelopePoint>, sustain_point: Option<u8>) -> ItEnvelope {
+    let flags = if sustain_point.is_some() {
+        env_flags::ENABLED | env_flags::SUSTAIN_LOOP
+    } else {
+        env_flags::ENABLED
+    };
+    let sustain_point = sustain_point.unwrap_or(0);
+
+    ItEnvelope {
+        flags,
+        points,
+        loop_begin: 0,
+        loop_end: 0,
+        sustain_begin: sustain_point,
+        sustain_end: sustain_point,
+    }
+}
+
+/// Index of the ADSR sustain point (the decay end), or `None` for one-shot envelopes.
+fn adsr_sustain_point(envelope: &Envelope, num_points: usize) -> Option<u8> {
+    (envelope.sustain > 0.0).then(|| num_points.saturating_sub(2) as u8)
+}
+
 /// Convert ADSR envelope to XM envelope format.
 ///
 /// XM envelopes use frame-based timing with 16-bit values.
@@ -126,23 +193,9 @@ fn calculate_adsr_points<P: EnvelopePoint>(envelope: &Envelope) -> Vec<P> {
 /// # Returns
 /// XM-formatted envelope with points, sustain, and loop settings
 pub fn convert_envelope_to_xm(envelope: &Envelope) -> XmEnvelope {
-    let sustain_enabled = envelope.sustain > 0.0;
     let points: Vec<XmEnvelopePoint> = calculate_adsr_points(envelope);
-    let sustain_point = if sustain_enabled {
-        // Sustain at the last non-zero point (the decay end).
-        (points.len().saturating_sub(2)) as u8
-    } else {
-        0
-    };
-    XmEnvelope {
-        points,
-        sustain_point,
-        loop_start: 0,
-        loop_end: 0,
-        enabled: true,
-        sustain_enabled,
-        loop_enabled: false,
-    }
+    let sustain_point = adsr_sustain_point(envelope, points.len());
+    xm_envelope(points, sustain_point)
 }
 
 /// Convert ADSR envelope to IT envelope format.
@@ -156,28 +209,62 @@ pub fn convert_envelope_to_xm(envelope: &Envelope) -> XmEnvelope {
 /// # Returns
 /// IT-formatted envelope with points, sustain loop, and flags
 pub fn convert_envelope_to_it(envelope: &Envelope) -> ItEnvelope {
-    let sustain_enabled = envelope.sustain > 0.0;
-    let flags = if sustain_enabled {
-        env_flags::ENABLED | env_flags::SUSTAIN_LOOP
-    } else {
-        env_flags::ENABLED
-    };
-
     let points: Vec<ItEnvelopePoint> = calculate_adsr_points(envelope);
-    let sustain_point = if sustain_enabled {
-        (points.len().saturating_sub(2)) as u8
-    } else {
-        0
-    };
+    let sustain_point = adsr_sustain_point(envelope, points.len());
+    it_envelope(points, sustain_point)
+}
 
-    ItEnvelope {
-        flags,
-        points,
-        loop_begin: 0,
-        loop_end: 0,
-        sustain_begin: sustain_point,
-        sustain_end: sustain_point,
+/// Convert a pan envelope to XM envelope format.
+///
+/// XM panning values run 0-64, so pan positions are offset by 32.
+pub fn convert_pan_envelope_to_xm(envelope: &TrackerEnvelope) -> XmEnvelope {
+    xm_envelope(calculate_pan_points(envelope), envelope.sustain_point)
+}
+
+/// Convert a pan envelope to IT envelope format.
+///
+/// IT panning values are signed, -32 (left) to 32 (right).
+pub fn convert_pan_envelope_to_it(envelope: &TrackerEnvelope) -> ItEnvelope {
+    it_envelope(calculate_pan_points(envelope), envelope.sustain_point)
+}
+
+/// Check a point-based envelope against a format's limits.
+///
+/// Points must have strictly increasing ticks, and the format stores at most
+/// `max_points` points.
+pub fn validate_tracker_envelope(
+    envelope: &TrackerEnvelope,
+    max_points: usize,
+    format: &str,
+) -> Result<(), GenerateError> {
+    if envelope.points.is_empty() || envelope.points.len() > max_points {
+        return Err(GenerateError::InvalidParameter(format!(
+            "{} envelopes must have 1-{} points, got {}",
+            format,
+            max_points,
+            envelope.points.len()
+        )));
+    }
+    if let Some(pair) = envelope
+        .points
+        .windows(2)
+        .find(|pair| pair[1].tick <= pair[0].tick)
+    {
+        return Err(GenerateError::InvalidParameter(format!(
+            "envelope point ticks must be strictly increasing ({} follows {})",
+            pair[1].tick, pair[0].tick
+        )));
+    }
+    if let Some(sustain_point) = envelope.sustain_point {
+        if sustain_point as usize >= envelope.points.len() {
+            return Err(GenerateError::InvalidParameter(format!(
+                "envelope sustain_point {} is out of range for {} point(s)",
+                sustain_point,
+                envelope.points.len()
+            )));
+        }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -236,6 +323,64 @@ mod tests {
         assert_eq!(it_env.points[2].value, 32);
     }
 
+    #[test]
+    fn test_pan_envelope_conversion() {
+        use speccade_spec::recipe::music::TrackerEnvelopePoint;
+
+        let env = TrackerEnvelope {
+            points: vec![
+                TrackerEnvelopePoint { tick: 0, value: 0 },
+                TrackerEnvelopePoint {
+                    tick: 10,
+                    value: -32,
+                },
+                TrackerEnvelopePoint {
+                    tick: 30,
+                    value: 16,
+                },
+            ],
+            sustain_point: Some(1),
+        };
+
+        let xm_env = convert_pan_envelope_to_xm(&env);
+        assert!(xm_env.enabled);
+        assert!(xm_env.sustain_enabled);
+        assert_eq!(xm_env.sustain_point, 1);
+        let xm_values: Vec<u16> = xm_env.points.iter().map(|p| p.value).collect();
+        assert_eq!(xm_values, vec![32, 0, 48]);
+        assert_eq!(xm_env.points[2].frame, 30);
+
+        let it_env = convert_pan_envelope_to_it(&env);
+        assert_eq!(it_env.flags, env_flags::ENABLED | env_flags::SUSTAIN_LOOP);
+        assert_eq!(it_env.sustain_begin, 1);
+        let it_values: Vec<i8> = it_env.points.iter().map(|p| p.value).collect();
+        assert_eq!(it_values, vec![0, -32, 16]);
+    }
+
+    #[test]
+    fn test_validate_tracker_envelope_limits() {
+        use speccade_spec::recipe::music::TrackerEnvelopePoint;
+
+        let points: Vec<TrackerEnvelopePoint> = (0..13)
+            .map(|i| TrackerEnvelopePoint {
+                tick: i * 4,
+                value: 0,
+            })
+            .collect();
+        let env = TrackerEnvelope {
+            points,
+            sustain_point: None,
+        };
+        assert!(validate_tracker_envelope(&env, 25, "IT").is_ok());
+        assert!(validate_tracker_envelope(&env, 12, "XM").is_err());
+
+        let out_of_range_sustain = TrackerEnvelope {
+            sustain_point: Some(13),
+            ..env
+        };
+        assert!(validate_tracker_envelope(&out_of_range_sustain, 25, "IT").is_err());
+    }
+
     #[test]
     fn test_zero_attack_envelope() {
         let env = Envelope {
@@ -5,8 +5,8 @@
 use std::path::Path;
 
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params, NoiseType, NoteSpec as AudioNoteSpec, Synthesis as AudioSynthesis,
-    Waveform,
+    AudioLayer, AudioV1Params, ChannelMode, Envelope, NoiseType, NoteSpec as AudioNoteSpec,
+    Synthesis as AudioSynthesis, Waveform,
 };
 use speccade_spec::recipe::music::{
     InstrumentSynthesis, PatternNote, TrackerFormat, TrackerInstrument,
@@ -17,10 +17,13 @@ use crate::note::{midi_to_freq, DEFAULT_IT_SYNTH_MIDI_NOTE, DEFAULT_SYNTH_MIDI_N
 
 pub(super) fn neutralize_audio_layer_envelopes(params: &mut AudioV1Params) {
     for layer in &mut params.layers {
-        layer.envelope.attack = 0.0;
-        layer.envelope.decay = 0.0;
-        layer.envelope.sustain = 1.0;
-        layer.envelope.release = 0.0;
+        layer.envelope = Envelope {
+            attack: 0.0,
+            decay: 0.0,
+            sustain: 1.0,
+            release: 0.0,
+        }
+        .into();
     }
 }
 
@@ -147,7 +150,7 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
 ) -> Result<AudioV1Params, GenerateError> {
     // Deprecated synth is baked as a single-layer audio_v1 oscillator/noise.
     let default_base_midi = match format {
-        TrackerFormat::Xm => DEFAULT_SYNTH_MIDI_NOTE,
+        TrackerFormat::Xm | TrackerFormat::Mod | TrackerFormat::S3m => DEFAULT_SYNTH_MIDI_NOTE,
         TrackerFormat::It => DEFAULT_IT_SYNTH_MIDI_NOTE,
     };
     let base_midi = parse_base_note_midi(
@@ -178,6 +181,9 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
             freq_sweep: None,
             detune: None,
             duty: Some(*duty_cycle),
+            anti_alias: None,
+            detune_voices: None,
+            detune_spread: None,
         },
         InstrumentSynthesis::Square { .. } => AudioSynthesis::Oscillator {
             waveform: Waveform::Square,
@@ -185,6 +191,9 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
             freq_sweep: None,
             detune: None,
             duty: None,
+            anti_alias: None,
+            detune_voices: None,
+            detune_spread: None,
         },
         InstrumentSynthesis::Triangle { .. } => AudioSynthesis::Oscillator {
             waveform: Waveform::Triangle,
@@ -192,6 +201,9 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
             freq_sweep: None,
             detune: None,
             duty: None,
+            anti_alias: None,
+            detune_voices: None,
+            detune_spread: None,
         },
         InstrumentSynthesis::Sawtooth { .. } => AudioSynthesis::Oscillator {
             waveform: Waveform::Sawtooth,
@@ -199,6 +211,9 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
             freq_sweep: None,
             detune: None,
             duty: None,
+            anti_alias: None,
+            detune_voices: None,
+            detune_spread: None,
         },
         InstrumentSynthesis::Sine { .. } => AudioSynthesis::Oscillator {
             waveform: Waveform::Sine,
@@ -206,6 +221,9 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
             freq_sweep: None,
             detune: None,
             duty: None,
+            anti_alias: None,
+            detune_voices: None,
+            detune_spread: None,
         },
         InstrumentSynthesis::Noise { .. } => AudioSynthesis::NoiseBurst {
             noise_type: NoiseType::White,
@@ -230,11 +248,18 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
         sample_rate,
         layers: vec![AudioLayer {
             synthesis,
-            envelope: instr.envelope.clone(),
+            envelope: instr.envelope.clone().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -243,6 +268,11 @@ pub(super) fn deprecated_synthesis_to_audio_v1_params(
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     })
 }
 
@@ -18,14 +18,16 @@ use super::{
     MusicInstrumentLoopReport,
 };
 use crate::note::{midi_to_freq, DEFAULT_IT_SYNTH_MIDI_NOTE, DEFAULT_SYNTH_MIDI_NOTE};
-use crate::synthesis::{derive_instrument_seed, load_wav_sample};
+use crate::synthesis::{
+    decorrelated_stereo_pair, derive_instrument_seed, load_wav_sample, load_wav_sample_resampled,
+};
 
 use super::helpers::{
     deprecated_synthesis_to_audio_v1_params, downmix_pcm16_stereo_to_mono, enforce_max_sample_len,
     load_audio_v1_params_from_ref, neutralize_audio_layer_envelopes, parse_base_note_midi,
 };
 
-/// Bake a tracker instrument into a mono sample.
+/// Bake a tracker instrument into a mono (or, for IT and XM `stereo` instruments, stereo) sample.
 ///
 /// Sources (exactly one):
 /// - `wav`: load PCM from disk
@@ -39,9 +41,28 @@ pub(crate) fn bake_instrument_sample(
     spec_dir: &Path,
     format: TrackerFormat,
 ) -> Result<(BakedInstrumentSample, MusicInstrumentLoopReport), GenerateError> {
-    const MAX_TRACKER_SAMPLE_SECONDS: f64 = 6.0;
+    if !instr.sample_map.is_empty() {
+        return Err(GenerateError::InstrumentError(format!(
+            "Instrument '{}' uses sample_map, which is only supported for XM and IT",
+            instr.name
+        )));
+    }
 
     let instr_seed = derive_instrument_seed(base_seed, index);
+    bake_instrument_sample_with_seed(instr, instr_seed, index, spec_dir, format)
+}
+
+/// Bake a tracker instrument into a mono sample using an already-derived seed.
+///
+/// See [`bake_instrument_sample`]; `index` only labels the loop report.
+pub(crate) fn bake_instrument_sample_with_seed(
+    instr: &TrackerInstrument,
+    instr_seed: u32,
+    index: u32,
+    spec_dir: &Path,
+    format: TrackerFormat,
+) -> Result<(BakedInstrumentSample, MusicInstrumentLoopReport), GenerateError> {
+    const MAX_TRACKER_SAMPLE_SECONDS: f64 = 6.0;
 
     let mut sources = Vec::new();
     if instr.r#ref.is_some() {
@@ -73,7 +94,7 @@ pub(crate) fn bake_instrument_sample(
 
     // Resolve the base MIDI note for pitch mapping.
     let default_base_midi = match format {
-        TrackerFormat::Xm => DEFAULT_SYNTH_MIDI_NOTE,
+        TrackerFormat::Xm | TrackerFormat::Mod | TrackerFormat::S3m => DEFAULT_SYNTH_MIDI_NOTE,
         TrackerFormat::It => DEFAULT_IT_SYNTH_MIDI_NOTE,
     };
 
@@ -89,8 +110,7 @@ pub(crate) fn bake_instrument_sample(
 
     let (mut pcm16_mono, sample_rate, base_midi) = if let Some(ref wav_path) = instr.wav {
         let sample_path = spec_dir.join(wav_path);
-        let (pcm16_mono, sample_rate) =
-            load_wav_sample(&sample_path).map_err(GenerateError::SampleLoadError)?;
+        let (pcm16_mono, sample_rate) = load_wav_for_instrument(&sample_path, instr)?;
 
         let base_midi = parse_base_note_midi(
             instr.base_note.as_deref(),
@@ -103,8 +123,7 @@ pub(crate) fn bake_instrument_sample(
         (pcm16_mono, sample_rate, base_midi)
     } else if let Some(InstrumentSynthesis::Sample { path, base_note }) = instr.synthesis.as_ref() {
         let sample_path = spec_dir.join(path);
-        let (pcm16_mono, sample_rate) =
-            load_wav_sample(&sample_path).map_err(GenerateError::SampleLoadError)?;
+        let (pcm16_mono, sample_rate) = load_wav_for_instrument(&sample_path, instr)?;
 
         let base_midi = parse_base_note_midi(
             instr.base_note.as_deref(),
@@ -471,9 +490,20 @@ pub(crate) fn bake_instrument_sample(
         pitch_deviation_cents: None,
     };
 
+    // Stereo is split from the finished mono sample, so both channels share its loop points.
+    // MOD and S3M keep the mono sample (validation warns).
+    let (pcm16_mono, pcm16_right) =
+        if instr.stereo && matches!(format, TrackerFormat::It | TrackerFormat::Xm) {
+            let (left, right) = decorrelated_stereo_pair(&pcm16_mono, instr_seed);
+            (left, Some(right))
+        } else {
+            (pcm16_mono, None)
+        };
+
     Ok((
         BakedInstrumentSample {
             pcm16_mono,
+            pcm16_right,
             sample_rate,
             base_midi,
             loop_region,
@@ -481,3 +511,19 @@ pub(crate) fn bake_instrument_sample(
         report,
     ))
 }
+
+/// Load a WAV instrument sample as mono PCM16.
+///
+/// With `resample_wav` set, the WAV is resampled to the instrument's `sample_rate`;
+/// otherwise the file's own sample rate is kept.
+fn load_wav_for_instrument(
+    sample_path: &Path,
+    instr: &TrackerInstrument,
+) -> Result<(Vec<u8>, u32), GenerateError> {
+    match instr.sample_rate {
+        Some(rate) if instr.resample_wav => load_wav_sample_resampled(sample_path, rate)
+            .map(|pcm16_mono| (pcm16_mono, rate))
+            .map_err(GenerateError::SampleLoadError),
+        _ => load_wav_sample(sample_path).map_err(GenerateError::SampleLoadError),
+    }
+}
@@ -1,7 +1,7 @@
 //! Main entry point for music generation from SpecCade specs.
 //!
-//! This module provides the public API for generating tracker modules (XM and IT)
-//! from SpecCade `MusicTrackerSongV1Params` specifications.
+//! This module provides the public API for generating tracker modules (XM, IT, MOD
+//! and S3M) from SpecCade `MusicTrackerSongV1Params` specifications.
 //!
 //! # Module Organization
 //!
@@ -9,6 +9,8 @@
 //! - [`crate::envelope`] - Envelope conversion (ADSR to tracker format)
 //! - [`crate::xm_gen`] - XM (FastTracker II) format generation
 //! - [`crate::it_gen`] - IT (Impulse Tracker) format generation
+//! - [`crate::protracker_gen`] - MOD (ProTracker) format generation
+//! - [`crate::s3m_gen`] - S3M (Scream Tracker 3) format generation
 //!
 //! This module serves as the thin dispatcher and re-exports shared types.
 
@@ -25,12 +27,16 @@ use crate::compose::expand_compose;
 
 // Re-export format-specific generators (internal use)
 pub(crate) use crate::it_gen::generate_it;
+pub(crate) use crate::protracker_gen::generate_mod;
+pub(crate) use crate::s3m_gen::generate_s3m;
 pub(crate) use crate::xm_gen::generate_xm;
 
 // Re-export internal modules
 mod helpers;
 mod instrument_baking;
 mod loop_detection;
+mod sample_zones;
+mod wav_import;
 
 #[cfg(test)]
 mod tests;
@@ -38,6 +44,8 @@ mod tests;
 // Re-export key types and functions for internal use
 pub(crate) use helpers::resolve_pattern_note_name;
 pub(crate) use instrument_baking::bake_instrument_sample;
+pub(crate) use sample_zones::{bake_sample_zones, sample_zone_note_map};
+pub use wav_import::{import_wav_instrument, ImportedWavInstrument, WavImportOptions};
 
 /// Error type for music generation.
 #[derive(Debug, Error)]
@@ -221,6 +229,8 @@ pub fn generate_music(
     match params.format {
         TrackerFormat::Xm => generate_xm(params, seed, spec_dir),
         TrackerFormat::It => generate_it(params, seed, spec_dir),
+        TrackerFormat::Mod => generate_mod(params, seed, spec_dir),
+        TrackerFormat::S3m => generate_s3m(params, seed, spec_dir),
     }
 }
 
@@ -241,8 +251,10 @@ pub fn generate_music_compose(
 /// Baked tracker sample data for a `TrackerInstrument`.
 #[derive(Debug, Clone)]
 pub(crate) struct BakedInstrumentSample {
-    /// 16-bit mono PCM bytes (little-endian i16).
+    /// 16-bit mono PCM bytes (little-endian i16); the left channel of stereo samples.
     pub pcm16_mono: Vec<u8>,
+    /// Right channel of stereo samples, same length as `pcm16_mono`.
+    pub pcm16_right: Option<Vec<u8>>,
     /// Natural sample rate of the PCM data.
     pub sample_rate: u32,
     /// Base MIDI note the sample is tuned to.
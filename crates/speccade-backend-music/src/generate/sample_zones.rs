@@ -0,0 +1,126 @@
+//! Multi-sample instrument zones.
+//!
+//! Instruments with a `sample_map` bake one sample per zone and play each note through the
+//! zone covering it, so no single sample has to stretch across the whole keyboard.
+
+use std::path::Path;
+
+use speccade_spec::recipe::audio::parse_note_name;
+use speccade_spec::recipe::music::{SampleZone, TrackerFormat, TrackerInstrument};
+
+use super::instrument_baking::bake_instrument_sample_with_seed;
+use super::{BakedInstrumentSample, GenerateError, MusicInstrumentLoopReport};
+use crate::synthesis::{derive_instrument_seed, derive_zone_seed};
+
+/// MIDI note of tracker note C-0, the first entry of XM and IT note-sample tables.
+const TRACKER_C0_MIDI: u8 = 12;
+
+/// Bake one sample per zone of a multi-sample instrument.
+///
+/// Each zone is rendered from its own `audio_v1` params, seeded with [`derive_zone_seed`] from
+/// the instrument seed, and otherwise inherits the instrument settings (envelope, loop mode,
+/// sample rate).
+pub(crate) fn bake_sample_zones(
+    instr: &TrackerInstrument,
+    base_seed: u32,
+    index: u32,
+    spec_dir: &Path,
+    format: TrackerFormat,
+) -> Result<Vec<(BakedInstrumentSample, MusicInstrumentLoopReport)>, GenerateError> {
+    let instr_seed = derive_instrument_seed(base_seed, index);
+
+    instr
+        .sample_map
+        .iter()
+        .enumerate()
+        .map(|(zone_idx, zone)| {
+            let zone_instr = TrackerInstrument {
+                r#ref: None,
+                wav: None,
+                synthesis: None,
+                synthesis_audio_v1: Some(zone.synthesis_audio_v1.clone()),
+                base_note: zone.base_note.clone().or_else(|| instr.base_note.clone()),
+                sample_map: Vec::new(),
+                ..instr.clone()
+            };
+            bake_instrument_sample_with_seed(
+                &zone_instr,
+                derive_zone_seed(instr_seed, zone_idx as u32),
+                index,
+                spec_dir,
+                format,
+            )
+        })
+        .collect()
+}
+
+/// Build the note-to-zone table of a multi-sample instrument.
+///
+/// Entry `n` is the zone index for tracker note `n` (C-0 first). Notes below the first zone or
+/// above the last play the nearest zone. Zones must be contiguous, lowest first.
+pub(crate) fn sample_zone_note_map(
+    instr: &TrackerInstrument,
+    num_notes: usize,
+) -> Result<Vec<u8>, GenerateError> {
+    let ranges = zone_note_ranges(&instr.name, &instr.sample_map)?;
+
+    Ok((0..num_notes)
+        .map(|note| {
+            let midi = note as i32 + TRACKER_C0_MIDI as i32;
+            ranges
+                .iter()
+                .position(|&(_, high)| midi <= high as i32)
+                .unwrap_or(ranges.len().saturating_sub(1)) as u8
+        })
+        .collect())
+}
+
+/// Parse zone note ranges to MIDI, checking they are ordered, contiguous and non-overlapping.
+fn zone_note_ranges(
+    instr_name: &str,
+    zones: &[SampleZone],
+) -> Result<Vec<(u8, u8)>, GenerateError> {
+    if zones.len() > u8::MAX as usize {
+        return Err(GenerateError::InstrumentError(format!(
+            "Instrument '{}' has {} sample zones, at most {} are supported",
+            instr_name,
+            zones.len(),
+            u8::MAX
+        )));
+    }
+
+    let parse = |name: &str| {
+        parse_note_name(name).ok_or_else(|| {
+            GenerateError::InstrumentError(format!(
+                "Instrument '{}' sample zone has invalid note name '{}'",
+                instr_name, name
+            ))
+        })
+    };
+
+    let mut ranges: Vec<(u8, u8)> = Vec::with_capacity(zones.len());
+    for (idx, zone) in zones.iter().enumerate() {
+        let low = parse(&zone.low_note)?;
+        let high = parse(&zone.high_note)?;
+        if low > high {
+            return Err(GenerateError::InstrumentError(format!(
+                "Instrument '{}' sample zone {} runs backwards ({} to {})",
+                instr_name, idx, zone.low_note, zone.high_note
+            )));
+        }
+        if let Some(&(_, previous_high)) = ranges.last() {
+            if low as u16 != previous_high as u16 + 1 {
+                return Err(GenerateError::InstrumentError(format!(
+                    "Instrument '{}' sample zone {} must start right after zone {} (gap or overlap at {})",
+                    instr_name,
+                    idx,
+                    idx - 1,
+                    zone.low_note
+                )));
+            }
+        }
+        ranges.push((low, high));
+    }
+
+    Ok(ranges)
+}
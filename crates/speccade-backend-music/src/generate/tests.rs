@@ -2,7 +2,7 @@
 
 use super::*;
 use speccade_spec::recipe::audio::{
-    AudioLayer, AudioV1Params, Envelope, NoiseType, NoteSpec as AudioNoteSpec,
+    AudioLayer, AudioV1Params, ChannelMode, Envelope, NoiseType, NoteSpec as AudioNoteSpec,
     Synthesis as AudioSynthesis, Waveform,
 };
 use speccade_spec::recipe::music::{
@@ -112,6 +112,52 @@ fn test_generate_it() {
     assert_eq!(result.hash.len(), 64);
 }
 
+#[test]
+fn test_generate_mod() {
+    let mut params = create_test_params();
+    params.format = TrackerFormat::Mod;
+
+    let spec_dir = Path::new(".");
+    let result = generate_music(&params, 42, spec_dir).unwrap();
+
+    assert_eq!(result.extension, "mod");
+    assert!(!result.data.is_empty());
+    assert_eq!(result.hash.len(), 64);
+}
+
+#[test]
+fn test_generate_s3m() {
+    let mut params = create_test_params();
+    params.format = TrackerFormat::S3m;
+
+    let spec_dir = Path::new(".");
+    let result = generate_music(&params, 42, spec_dir).unwrap();
+
+    assert_eq!(result.extension, "s3m");
+    assert!(!result.data.is_empty());
+    assert_eq!(result.hash.len(), 64);
+}
+
+#[test]
+fn test_determinism_all_formats() {
+    let spec_dir = Path::new(".");
+    for format in [
+        TrackerFormat::Xm,
+        TrackerFormat::It,
+        TrackerFormat::Mod,
+        TrackerFormat::S3m,
+    ] {
+        let mut params = create_test_params();
+        params.format = format;
+
+        let result1 = generate_music(&params, 42, spec_dir).unwrap();
+        let result2 = generate_music(&params, 42, spec_dir).unwrap();
+
+        assert_eq!(result1.hash, result2.hash, "{:?}", format);
+        assert_eq!(result1.data, result2.data, "{:?}", format);
+    }
+}
+
 #[test]
 fn test_determinism() {
     let params = create_test_params();
@@ -237,12 +283,22 @@ fn test_bake_instrument_sample_inline_audio_v1_downmixes_stereo() {
                         freq_sweep: None,
                         detune: None,
                         duty: None,
+                        anti_alias: None,
+                        detune_voices: None,
+                        detune_spread: None,
                     },
-                    envelope: Envelope::default(),
+                    envelope: Envelope::default().into(),
                     volume: 0.5,
                     pan: -1.0,
                     delay: None,
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 },
                 AudioLayer {
@@ -252,12 +308,22 @@ fn test_bake_instrument_sample_inline_audio_v1_downmixes_stereo() {
                         freq_sweep: None,
                         detune: None,
                         duty: None,
+                        anti_alias: None,
+                        detune_voices: None,
+                        detune_spread: None,
                     },
-                    envelope: Envelope::default(),
+                    envelope: Envelope::default().into(),
                     volume: 0.5,
                     pan: 1.0,
                     delay: None,
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 },
             ],
@@ -267,6 +333,11 @@ fn test_bake_instrument_sample_inline_audio_v1_downmixes_stereo() {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         }),
         envelope: Envelope {
             attack: 0.01,
@@ -298,12 +369,22 @@ fn test_loop_policy_uses_tracker_envelope_sustain() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -313,6 +394,11 @@ fn test_loop_policy_uses_tracker_envelope_sustain() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let one_shot = TrackerInstrument {
@@ -365,12 +451,22 @@ fn test_audio_v1_base_note_midi_note_is_used_for_pitch_mapping() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -379,6 +475,11 @@ fn test_audio_v1_base_note_midi_note_is_used_for_pitch_mapping() {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         }),
         envelope: Envelope {
             attack: 0.01,
@@ -409,12 +510,22 @@ fn test_sustained_sine_prefers_forward_loop_with_crossfade() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -423,6 +534,11 @@ fn test_sustained_sine_prefers_forward_loop_with_crossfade() {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         }),
         envelope: Envelope {
             attack: 0.05,
@@ -455,11 +571,18 @@ fn test_sustained_noise_falls_back_to_pingpong_loop() {
                     noise_type: NoiseType::White,
                     filter: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -468,6 +591,11 @@ fn test_sustained_noise_falls_back_to_pingpong_loop() {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         }),
         envelope: Envelope {
             attack: 0.05,
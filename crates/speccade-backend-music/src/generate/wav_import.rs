@@ -0,0 +1,214 @@
+//! Importing WAV one-shots as tracker instruments.
+//!
+//! The importer builds a `wav`-sourced [`TrackerInstrument`] and bakes it through the same path
+//! as module generation, so the imported sample (downmix, resampling, loop points) is exactly
+//! what ends up in a generated XM/IT file.
+
+use std::path::Path;
+
+use speccade_spec::recipe::audio::Envelope;
+use speccade_spec::recipe::music::{TrackerFormat, TrackerInstrument, TrackerLoopMode};
+
+use super::{bake_instrument_sample, GenerateError, MusicInstrumentLoopReport};
+use crate::note::DEFAULT_SAMPLE_RATE;
+
+/// Options for [`import_wav_instrument`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WavImportOptions {
+    /// Tracker sample rate the WAV is resampled to (default: 22050).
+    pub sample_rate: u32,
+    /// Note the sample is tuned to (e.g. "C4"); defaults to the format's base note.
+    pub base_note: Option<String>,
+    /// Tracker envelope; a sustain above zero makes the sample loop in `auto` mode.
+    pub envelope: Envelope,
+    /// Loop mode override (default: `auto`).
+    pub loop_mode: TrackerLoopMode,
+}
+
+impl Default for WavImportOptions {
+    fn default() -> Self {
+        Self {
+            sample_rate: DEFAULT_SAMPLE_RATE,
+            base_note: None,
+            envelope: Envelope::default(),
+            loop_mode: TrackerLoopMode::Auto,
+        }
+    }
+}
+
+/// A WAV sample imported as a tracker instrument.
+#[derive(Debug, Clone)]
+pub struct ImportedWavInstrument {
+    /// Instrument definition, usable in `MusicTrackerSongV1Params::instruments`.
+    pub instrument: TrackerInstrument,
+    /// 16-bit mono PCM bytes (little-endian i16) at `sample_rate`.
+    pub pcm16_mono: Vec<u8>,
+    /// Sample rate of `pcm16_mono`.
+    pub sample_rate: u32,
+    /// Base MIDI note the sample is tuned to.
+    pub base_midi: u8,
+    /// Loop diagnostics, including the chosen loop points.
+    pub loop_report: MusicInstrumentLoopReport,
+}
+
+/// Import a WAV file as a tracker instrument.
+///
+/// The WAV is downmixed to mono, resampled to `options.sample_rate`, and given loop points
+/// using the same rules as module generation. Import is deterministic: the same file and
+/// options always produce the same sample data and loop points.
+///
+/// # Arguments
+/// * `wav_path` - Path to the WAV file (stored as-is in the instrument's `wav` field)
+/// * `name` - Instrument name
+/// * `format` - Target tracker format (affects the default base note)
+/// * `options` - Import options
+pub fn import_wav_instrument(
+    wav_path: &Path,
+    name: &str,
+    format: TrackerFormat,
+    options: &WavImportOptions,
+) -> Result<ImportedWavInstrument, GenerateError> {
+    let instrument = TrackerInstrument {
+        name: name.to_string(),
+        wav: Some(wav_path.to_string_lossy().into_owned()),
+        base_note: options.base_note.clone(),
+        sample_rate: Some(options.sample_rate),
+        resample_wav: true,
+        envelope: options.envelope.clone(),
+        loop_mode: Some(options.loop_mode),
+        ..Default::default()
+    };
+
+    // WAV sources do not use the seed; the path is resolved as given.
+    let (baked, loop_report) = bake_instrument_sample(&instrument, 0, 0, Path::new(""), format)?;
+
+    Ok(ImportedWavInstrument {
+        instrument,
+        pcm16_mono: baked.pcm16_mono,
+        sample_rate: baked.sample_rate,
+        base_midi: baked.base_midi,
+        loop_report,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Writes a 16-bit stereo WAV with the given left channel and a silent right channel.
+    fn write_stereo_wav(path: &Path, sample_rate: u32, frames: usize, left: impl Fn(usize) -> i16) {
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for i in 0..frames {
+            writer.write_sample(left(i)).unwrap();
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    fn sample_values(pcm16_mono: &[u8]) -> Vec<i16> {
+        pcm16_mono
+            .chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn imports_stereo_wav_as_resampled_mono_sample() {
+        let path = std::env::temp_dir().join(format!(
+            "speccade_wav_import_{}_stereo.wav",
+            std::process::id()
+        ));
+        write_stereo_wav(&path, 44100, 4410, |_| 16384);
+
+        let options = WavImportOptions {
+            loop_mode: TrackerLoopMode::None,
+            ..Default::default()
+        };
+        let first = import_wav_instrument(&path, "one-shot", TrackerFormat::Xm, &options).unwrap();
+        let second = import_wav_instrument(&path, "one-shot", TrackerFormat::Xm, &options).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        // 0.1s at 44.1 kHz resampled to 22.05 kHz.
+        assert_eq!(first.sample_rate, 22050);
+        assert_eq!(first.pcm16_mono.len(), 2205 * 2);
+        assert_eq!(first.loop_report.sample_len, 2205);
+
+        // Stereo is averaged to mono: (0.5 + 0.0) / 2 = 0.25 of full scale.
+        let values = sample_values(&first.pcm16_mono);
+        let expected = (0.25 * 32767.0) as i16;
+        assert!(values.iter().all(|&v| v == expected));
+
+        assert_eq!(first.pcm16_mono, second.pcm16_mono);
+        assert_eq!(first.instrument.sample_rate, Some(22050));
+        assert!(first.instrument.resample_wav);
+        assert!(first.loop_report.loop_start.is_none());
+    }
+
+    #[test]
+    fn wav_instrument_sample_rate_without_opt_in_keeps_file_rate() {
+        let path = std::env::temp_dir().join(format!(
+            "speccade_wav_import_{}_legacy.wav",
+            std::process::id()
+        ));
+        write_stereo_wav(&path, 44100, 4410, |i| ((i % 100) as i16 - 50) * 300);
+
+        let bake = |sample_rate| {
+            let instr = TrackerInstrument {
+                name: "legacy".to_string(),
+                wav: Some(path.to_string_lossy().into_owned()),
+                sample_rate,
+                loop_mode: Some(TrackerLoopMode::None),
+                ..Default::default()
+            };
+            bake_instrument_sample(&instr, 0, 0, Path::new(""), TrackerFormat::Xm)
+                .unwrap()
+                .0
+        };
+        let with_rate = bake(Some(22050));
+        let without_rate = bake(None);
+        let (file_pcm, file_rate) = crate::synthesis::load_wav_sample(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        // Existing `wav` instruments ignore `sample_rate` unless `resample_wav` is set.
+        assert_eq!(with_rate.sample_rate, 44100);
+        assert_eq!(with_rate.pcm16_mono, without_rate.pcm16_mono);
+        assert_eq!(with_rate.pcm16_mono, file_pcm);
+        assert_eq!(file_rate, 44100);
+    }
+
+    #[test]
+    fn sustained_import_gets_loop_points() {
+        let path = std::env::temp_dir().join(format!(
+            "speccade_wav_import_{}_loop.wav",
+            std::process::id()
+        ));
+        write_stereo_wav(&path, 22050, 22050, |i| {
+            let phase = i as f64 * 220.0 / 22050.0 * std::f64::consts::TAU;
+            (phase.sin() * 8000.0) as i16
+        });
+
+        let options = WavImportOptions {
+            envelope: Envelope {
+                attack: 0.01,
+                decay: 0.05,
+                sustain: 0.8,
+                release: 0.1,
+            },
+            loop_mode: TrackerLoopMode::Forward,
+            ..Default::default()
+        };
+        let imported = import_wav_instrument(&path, "pad", TrackerFormat::It, &options).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let report = &imported.loop_report;
+        let (start, end) = (report.loop_start.unwrap(), report.loop_end.unwrap());
+        assert!(start < end);
+        assert!(end <= report.sample_len);
+    }
+}
@@ -9,6 +9,9 @@ pub const IT_INSTRUMENT_MAGIC: &[u8; 4] = b"IMPI";
 /// IT instrument header size.
 pub const IT_INSTRUMENT_SIZE: usize = 554;
 
+/// Maximum number of points in an IT envelope.
+pub const IT_MAX_ENVELOPE_POINTS: usize = 25;
+
 /// NNA (New Note Action) modes.
 pub mod nna {
     /// Cut previous note immediately.
@@ -256,6 +259,12 @@ impl ItInstrument {
         self
     }
 
+    /// Set the panning envelope.
+    pub fn with_panning_envelope(mut self, envelope: ItEnvelope) -> Self {
+        self.panning_envelope = envelope;
+        self
+    }
+
     /// Set NNA mode for polyphonic playback.
     pub fn with_nna(mut self, nna: u8, dct: u8, dca: u8) -> Self {
         self.nna = nna;
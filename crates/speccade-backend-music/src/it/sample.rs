@@ -9,6 +9,12 @@ pub const IT_SAMPLE_MAGIC: &[u8; 4] = b"IMPS";
 /// IT sample header size.
 pub const IT_SAMPLE_HEADER_SIZE: usize = 80;
 
+/// Maximum sample auto-vibrato depth.
+pub const IT_MAX_VIBRATO_DEPTH: u8 = 64;
+
+/// Maximum sample auto-vibrato speed.
+pub const IT_MAX_VIBRATO_SPEED: u8 = 64;
+
 /// Sample flags.
 pub mod sample_flags {
     /// Sample has data.
@@ -80,7 +86,7 @@ pub struct ItSample {
     pub vibrato_rate: u8,
     /// Vibrato type (0=sine, 1=ramp down, 2=square, 3=random).
     pub vibrato_type: u8,
-    /// Sample data (16-bit signed PCM).
+    /// Sample data (16-bit signed PCM; left then right channel for stereo samples).
     pub data: Vec<u8>,
 }
 
@@ -122,6 +128,30 @@ impl ItSample {
         }
     }
 
+    /// Create a stereo sample from separate left and right channel data.
+    ///
+    /// IT stores uncompressed stereo samples as the whole left channel followed by the whole
+    /// right channel; `length` counts frames, not channel samples.
+    pub fn new_stereo(name: &str, left: Vec<u8>, right: Vec<u8>, sample_rate: u32) -> Self {
+        let length = (left.len().min(right.len()) / 2) as u32;
+        let mut data = left;
+        data.truncate(length as usize * 2);
+        data.extend_from_slice(&right[..length as usize * 2]);
+        Self {
+            name: name.to_string(),
+            data,
+            length,
+            c5_speed: sample_rate,
+            flags: sample_flags::HAS_DATA | sample_flags::BITS_16 | sample_flags::STEREO,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the sample is stereo.
+    pub fn is_stereo(&self) -> bool {
+        self.flags & sample_flags::STEREO != 0
+    }
+
     /// Set loop parameters.
     pub fn with_loop(mut self, begin: u32, end: u32, pingpong: bool) -> Self {
         self.flags |= sample_flags::LOOP;
@@ -133,6 +163,17 @@ impl ItSample {
         self
     }
 
+    /// Set the auto-vibrato parameters.
+    ///
+    /// IT stores the sweep as the vibrato "rate" field.
+    pub fn with_auto_vibrato(mut self, vibrato_type: u8, speed: u8, depth: u8, sweep: u8) -> Self {
+        self.vibrato_type = vibrato_type;
+        self.vibrato_speed = speed;
+        self.vibrato_depth = depth;
+        self.vibrato_rate = sweep;
+        self
+    }
+
     /// Get the length of sample data in samples (not bytes).
     pub fn length_samples(&self) -> u32 {
         self.length
@@ -284,6 +325,17 @@ mod tests {
         assert_eq!(&buf[0..4], IT_SAMPLE_MAGIC);
     }
 
+    #[test]
+    fn test_stereo_sample_creation() {
+        let sample = ItSample::new_stereo("Test", vec![1u8; 100], vec![2u8; 100], 22050);
+
+        assert!(sample.is_stereo());
+        assert_eq!(sample.length, 50); // frames, not channel samples
+        assert_eq!(sample.data.len(), 200);
+        assert_eq!(&sample.data[..100], &[1u8; 100][..]);
+        assert_eq!(&sample.data[100..], &[2u8; 100][..]);
+    }
+
     #[test]
     fn test_with_loop() {
         let data = vec![0u8; 1000];
@@ -2,31 +2,101 @@
 
 use std::path::Path;
 
-use speccade_spec::recipe::music::{TrackerFormat, TrackerInstrument};
+use speccade_spec::recipe::music::{
+    AutoVibrato, TrackerFormat, TrackerInstrument, VibratoWaveform,
+};
 
-use crate::envelope::convert_envelope_to_it;
-use crate::generate::{bake_instrument_sample, GenerateError, MusicInstrumentLoopReport};
-use crate::it::{ItInstrument, ItSample};
+use crate::envelope::{
+    convert_envelope_to_it, convert_pan_envelope_to_it, validate_tracker_envelope,
+};
+use crate::generate::{
+    bake_instrument_sample, bake_sample_zones, sample_zone_note_map, BakedInstrumentSample,
+    GenerateError, MusicInstrumentLoopReport,
+};
+use crate::it::{
+    ItInstrument, ItSample, IT_MAX_ENVELOPE_POINTS, IT_MAX_VIBRATO_DEPTH, IT_MAX_VIBRATO_SPEED,
+};
 use crate::note::{calculate_c5_speed_for_base_note, it_pitch_deviation_cents};
 
-/// Generate an IT instrument and sample from spec.
+/// Generate an IT instrument and its samples from spec.
 ///
-/// IT format separates instruments from samples, so this returns both.
-/// The instrument maps all notes to the generated sample.
+/// IT format separates instruments from samples, so this returns both. Samples are numbered
+/// from `first_sample` (1-based): plain instruments map every note to their single sample,
+/// multi-sample instruments get one sample per `sample_map` zone and a note-sample table
+/// switching between them. The loop report describes the first sample.
 pub fn generate_it_instrument(
     instr: &TrackerInstrument,
     base_seed: u32,
     index: u32,
+    first_sample: u8,
     spec_dir: &Path,
-) -> Result<(ItInstrument, ItSample, MusicInstrumentLoopReport), GenerateError> {
-    let (baked, loop_report) =
-        bake_instrument_sample(instr, base_seed, index, spec_dir, TrackerFormat::It)?;
+) -> Result<(ItInstrument, Vec<ItSample>, MusicInstrumentLoopReport), GenerateError> {
+    let baked_samples = if instr.sample_map.is_empty() {
+        vec![bake_instrument_sample(
+            instr,
+            base_seed,
+            index,
+            spec_dir,
+            TrackerFormat::It,
+        )?]
+    } else {
+        bake_sample_zones(instr, base_seed, index, spec_dir, TrackerFormat::It)?
+    };
 
+    let mut samples = Vec::with_capacity(baked_samples.len());
+    let mut loop_reports = Vec::with_capacity(baked_samples.len());
+    for (baked, mut loop_report) in baked_samples {
+        let (sample, pitch_cents) = convert_baked_sample_to_it(instr, baked)?;
+        loop_report.pitch_deviation_cents = Some(pitch_cents);
+        samples.push(sample);
+        loop_reports.push(loop_report);
+    }
+
+    if first_sample as usize + samples.len() - 1 > u8::MAX as usize {
+        return Err(GenerateError::InvalidParameter(format!(
+            "IT modules hold at most {} samples (instrument '{}' needs {} from sample {})",
+            u8::MAX,
+            instr.name,
+            samples.len(),
+            first_sample
+        )));
+    }
+
+    // Create instrument
+    let mut it_instr = ItInstrument::new(&instr.name);
+
+    if instr.sample_map.is_empty() {
+        it_instr.map_all_to_sample(first_sample);
+    } else {
+        let note_map = sample_zone_note_map(instr, it_instr.note_sample_table.len())?;
+        for (entry, zone) in it_instr.note_sample_table.iter_mut().zip(note_map) {
+            entry.1 = first_sample + zone;
+        }
+    }
+
+    // Convert envelope
+    it_instr.volume_envelope = convert_envelope_to_it(&instr.envelope);
+    if let Some(pan_envelope) = &instr.pan_envelope {
+        validate_tracker_envelope(pan_envelope, IT_MAX_ENVELOPE_POINTS, "IT")?;
+        it_instr.panning_envelope = convert_pan_envelope_to_it(pan_envelope);
+    }
+
+    Ok((it_instr, samples, loop_reports.swap_remove(0)))
+}
+
+/// Build an IT sample from a baked instrument sample, returning it with its pitch deviation.
+fn convert_baked_sample_to_it(
+    instr: &TrackerInstrument,
+    baked: BakedInstrumentSample,
+) -> Result<(ItSample, f64), GenerateError> {
     // IT samples store "C-5 speed" (playback rate for note C-5), not the sample's native rate.
     let c5_speed = calculate_c5_speed_for_base_note(baked.sample_rate, baked.base_midi);
     let pitch_cents = it_pitch_deviation_cents(baked.sample_rate, baked.base_midi, c5_speed);
 
-    let mut sample = ItSample::new(&instr.name, baked.pcm16_mono, c5_speed);
+    let mut sample = match baked.pcm16_right {
+        Some(right) => ItSample::new_stereo(&instr.name, baked.pcm16_mono, right, c5_speed),
+        None => ItSample::new(&instr.name, baked.pcm16_mono, c5_speed),
+    };
 
     if let Some(loop_region) = baked.loop_region {
         let pingpong = loop_region.mode == crate::generate::LoopMode::PingPong;
@@ -36,17 +106,35 @@ pub fn generate_it_instrument(
     // Set default volume
     sample.default_volume = instr.default_volume.unwrap_or(64).min(64);
 
-    // Create instrument
-    let mut it_instr = ItInstrument::new(&instr.name);
-
-    // Map all notes to this sample (index + 1 since IT is 1-indexed)
-    it_instr.map_all_to_sample((index + 1) as u8);
-
-    // Convert envelope
-    it_instr.volume_envelope = convert_envelope_to_it(&instr.envelope);
+    // IT stores auto-vibrato on the sample rather than the instrument
+    if let Some(auto_vibrato) = &instr.auto_vibrato {
+        let vibrato_type = it_vibrato_type(auto_vibrato)?;
+        sample = sample.with_auto_vibrato(
+            vibrato_type,
+            auto_vibrato.rate,
+            auto_vibrato.depth,
+            auto_vibrato.sweep,
+        );
+    }
 
-    let mut loop_report = loop_report;
-    loop_report.pitch_deviation_cents = Some(pitch_cents);
+    Ok((sample, pitch_cents))
+}
 
-    Ok((it_instr, sample, loop_report))
+/// Map an auto-vibrato waveform to the IT vibrato type, checking depth and speed limits.
+fn it_vibrato_type(auto_vibrato: &AutoVibrato) -> Result<u8, GenerateError> {
+    if auto_vibrato.depth > IT_MAX_VIBRATO_DEPTH || auto_vibrato.rate > IT_MAX_VIBRATO_SPEED {
+        return Err(GenerateError::InvalidParameter(format!(
+            "IT auto-vibrato depth must be 0-{} and rate 0-{}, got depth {} and rate {}",
+            IT_MAX_VIBRATO_DEPTH, IT_MAX_VIBRATO_SPEED, auto_vibrato.depth, auto_vibrato.rate
+        )));
+    }
+    match auto_vibrato.waveform {
+        VibratoWaveform::Sine => Ok(0),
+        VibratoWaveform::RampDown => Ok(1),
+        VibratoWaveform::Square => Ok(2),
+        VibratoWaveform::Random => Ok(3),
+        VibratoWaveform::RampUp => Err(GenerateError::InvalidParameter(
+            "IT auto-vibrato has no ramp_up waveform".to_string(),
+        )),
+    }
 }
@@ -62,10 +62,15 @@ pub fn generate_it(
     // Generate instruments and samples
     let mut instrument_loop_reports = Vec::with_capacity(params.instruments.len());
     for (idx, instr) in params.instruments.iter().enumerate() {
-        let (it_instrument, it_sample, loop_report) =
-            instrument::generate_it_instrument(instr, seed, idx as u32, spec_dir)?;
+        let first_sample = u8::try_from(module.samples.len() + 1).map_err(|_| {
+            GenerateError::InvalidParameter(format!("IT modules hold at most {} samples", u8::MAX))
+        })?;
+        let (it_instrument, it_samples, loop_report) =
+            instrument::generate_it_instrument(instr, seed, idx as u32, first_sample, spec_dir)?;
         module.add_instrument(it_instrument);
-        module.add_sample(it_sample);
+        for it_sample in it_samples {
+            module.add_sample(it_sample);
+        }
         instrument_loop_reports.push(loop_report);
     }
 
@@ -5,8 +5,8 @@ use std::path::Path;
 
 use speccade_spec::recipe::audio::Envelope;
 use speccade_spec::recipe::music::{
-    ArrangementEntry, InstrumentSynthesis, MusicTrackerSongV1Params, PatternNote, TrackerFormat,
-    TrackerInstrument, TrackerPattern,
+    ArrangementEntry, InstrumentSynthesis, MusicTrackerSongV1Params, PatternNote, SampleZone,
+    TrackerFormat, TrackerInstrument, TrackerPattern,
 };
 
 use crate::it::sample_flags;
@@ -90,6 +90,74 @@ fn test_generate_it() {
     assert_eq!(result.hash.len(), 64);
 }
 
+#[test]
+fn test_it_multi_sample_instrument_numbers_zone_samples() {
+    let zone = |low_note: &str, high_note: &str, frequency: f64| SampleZone {
+        low_note: low_note.to_string(),
+        high_note: high_note.to_string(),
+        synthesis_audio_v1: serde_json::from_value(serde_json::json!({
+            "duration_seconds": 0.2,
+            "layers": [{
+                "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": frequency },
+                "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                "volume": 0.8,
+                "pan": 0.0
+            }]
+        }))
+        .unwrap(),
+        base_note: None,
+    };
+    let instrument = TrackerInstrument {
+        name: "Piano".to_string(),
+        sample_map: vec![zone("C0", "B3", 130.81), zone("C4", "G9", 523.25)],
+        ..Default::default()
+    };
+
+    let (it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 1, 3, Path::new(".")).unwrap();
+    assert_eq!(it_samples.len(), 2);
+    assert_ne!(it_samples[0].data, it_samples[1].data);
+
+    let sample_for = |note: &str| it_instr.note_sample_table[note_name_to_it(note) as usize].1;
+    assert_eq!(sample_for("C0"), 3);
+    assert_eq!(sample_for("B3"), 3);
+    assert_eq!(sample_for("C4"), 4);
+    assert_eq!(sample_for("B9"), 4);
+}
+
+#[test]
+fn test_it_stereo_instrument_sets_flag_and_doubles_data() {
+    let mono_instr = create_test_params().instruments.remove(0);
+    let stereo_instr = TrackerInstrument {
+        stereo: true,
+        ..mono_instr.clone()
+    };
+
+    let (_, mono_samples, _) =
+        instrument::generate_it_instrument(&mono_instr, 42, 0, 1, Path::new(".")).unwrap();
+    let (_, stereo_samples, _) =
+        instrument::generate_it_instrument(&stereo_instr, 42, 0, 1, Path::new(".")).unwrap();
+    let (mono, stereo) = (&mono_samples[0], &stereo_samples[0]);
+
+    assert_eq!(mono.flags & sample_flags::STEREO, 0);
+    assert_ne!(stereo.flags & sample_flags::STEREO, 0);
+    assert_eq!(stereo.data.len(), mono.data.len() * 2);
+    // Length counts frames, and loop points are shared by both channels
+    assert_eq!(stereo.length, mono.length);
+    assert_eq!(stereo.loop_begin, mono.loop_begin);
+    assert_eq!(stereo.loop_end, mono.loop_end);
+
+    let (left, right) = stereo.data.split_at(mono.data.len());
+    assert_ne!(left, right);
+
+    // The module still validates with the stereo sample
+    let mut params = create_test_params();
+    params.instruments[0].stereo = true;
+    let result = generate_it(&params, 42, Path::new(".")).unwrap();
+    let report = crate::it::ItValidator::validate(&result.data).unwrap();
+    assert!(report.is_valid, "{:?}", report.errors);
+}
+
 #[test]
 fn test_it_param_validation() {
     let mut params = create_test_params();
@@ -479,8 +547,9 @@ fn test_it_non_periodic_noise_one_shot_does_not_loop() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
     assert_eq!(
         it_sample.flags & sample_flags::LOOP,
         0,
@@ -509,8 +578,9 @@ fn test_synthesized_pulse_instrument_c5_speed() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // c5_speed should be 22050 (sample rate) for MIDI 72 base note (IT's default)
     assert_eq!(it_sample.c5_speed, 22050);
@@ -527,8 +597,9 @@ fn test_synthesized_sine_instrument_c5_speed() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     assert_eq!(it_sample.c5_speed, 22050);
 }
@@ -547,8 +618,9 @@ fn test_synthesized_noise_instrument_c5_speed() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     assert_eq!(it_sample.c5_speed, 22050);
 }
@@ -564,8 +636,9 @@ fn test_synthesized_triangle_instrument_c5_speed() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     assert_eq!(it_sample.c5_speed, 22050);
 }
@@ -581,8 +654,9 @@ fn test_synthesized_sawtooth_instrument_c5_speed() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     assert_eq!(it_sample.c5_speed, 22050);
 }
@@ -635,8 +709,9 @@ fn test_it_variant_a_no_base_note_no_pattern_note() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // c5_speed should be 22050 (sample rate)
     // because: sample at MIDI 72 (C5), IT reference at MIDI 72 (C5)
@@ -675,8 +750,9 @@ fn test_it_variant_b_no_base_note_pattern_note_c5() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // c5_speed should be 22050 - the pattern note doesn't affect this
     // Pattern note only determines which tracker note triggers the sample
@@ -706,8 +782,9 @@ fn test_it_variant_c_base_note_c5_no_pattern_note() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // With base_note = "C5" (MIDI 72 = IT note 60), the sample is at the IT reference pitch.
     // c5_speed = sample_rate because sample is already at C-5
@@ -743,8 +820,9 @@ fn test_it_variant_d_base_note_c5_pattern_note_c4() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // c5_speed is 22050 (configured for C5 base note)
     assert_eq!(it_sample.c5_speed, 22050);
@@ -787,8 +865,9 @@ fn test_it_base_note_a4_non_c_note() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // Sample at MIDI 69 (A4) = IT note 57 (A-4)
     // IT reference is note 60 (C-5)
@@ -818,8 +897,9 @@ fn test_it_base_note_c3_two_octaves_below() {
     };
 
     let spec_dir = Path::new(".");
-    let (_it_instr, it_sample, _) =
-        instrument::generate_it_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (_it_instr, it_samples, _) =
+        instrument::generate_it_instrument(&instrument, 42, 0, 1, spec_dir).unwrap();
+    let it_sample = &it_samples[0];
 
     // Sample at MIDI 48 (C3) = IT note 36 (C-3)
     // IT reference is note 60 (C-5)
@@ -1,6 +1,6 @@
-//! SpecCade Music Backend - Deterministic XM/IT Tracker Module Generation
+//! SpecCade Music Backend - Deterministic XM/IT/MOD/S3M Tracker Module Generation
 //!
-//! This crate provides deterministic generation of tracker module files (XM and IT formats)
+//! This crate provides deterministic generation of tracker module files (XM, IT, MOD and S3M formats)
 //! from SpecCade music specifications. It implements Tier 1 determinism guarantees as
 //! defined in the SpecCade determinism policy.
 //!
@@ -8,6 +8,8 @@
 //!
 //! - **XM Format (FastTracker II)**: Up to 32 channels, 128 instruments, volume/panning envelopes
 //! - **IT Format (Impulse Tracker)**: Up to 64 channels, NNA for polyphony, pitch envelopes
+//! - **MOD Format (ProTracker)**: 4 channels, 31 samples, fixed Amiga period table
+//! - **S3M Format (Scream Tracker 3)**: Up to 16 channels, 99 samples, volume column
 //! - **Deterministic Synthesis**: Instrument samples generated from specs using seeded RNG
 //! - **Full Hash Validation**: BLAKE3 hashes for Tier 1 validation
 //!
@@ -54,6 +56,9 @@
 //! - [`synthesis`]: Instrument sample generation
 //! - [`xm`]: XM (FastTracker II) format writer
 //! - [`it`]: IT (Impulse Tracker) format writer
+//! - [`protracker`]: MOD (ProTracker) format writer
+//! - [`s3m`]: S3M (Scream Tracker 3) format writer
+//! - [`midi`]: Standard MIDI File export
 //! - [`generate`]: Main generation entry point
 
 pub mod compose;
@@ -61,15 +66,23 @@ pub mod envelope;
 pub mod generate;
 pub mod it;
 pub mod it_gen;
+pub mod midi;
 pub mod note;
 pub mod parity;
+pub mod protracker;
+pub mod protracker_gen;
+pub mod s3m;
+pub mod s3m_gen;
 pub mod synthesis;
 pub mod xm;
 pub mod xm_gen;
 
 // Re-export main types
 pub use compose::{expand_compose, ExpandError};
-pub use generate::{generate_music, generate_music_compose, GenerateError, GenerateResult};
+pub use generate::{
+    generate_music, generate_music_compose, import_wav_instrument, GenerateError, GenerateResult,
+    ImportedWavInstrument, WavImportOptions,
+};
 pub use note::{
     calculate_pitch_correction, freq_to_midi, it_note_to_name, midi_to_freq, note_name_to_it,
     note_name_to_xm, xm_note_to_name, DEFAULT_SAMPLE_RATE,
@@ -0,0 +1,269 @@
+//! Standard MIDI File export for tracker songs.
+//!
+//! This module converts a song's arrangement and patterns into a format 1
+//! Standard MIDI File:
+//! - Track 0 carries the song name and tempo
+//! - Each tracker channel becomes its own track
+//! - A note ends at the next note or note-cut in its channel, or at the end of the song
+//!
+//! One tracker tick maps to one MIDI tick. A tracker tick lasts `2.5 / bpm` seconds,
+//! so 24 ticks make one quarter note at `bpm` beats per minute. Effects, automation
+//! and looping are not exported; the arrangement plays once.
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use speccade_spec::recipe::music::{MusicTrackerSongV1Params, TrackerPattern};
+
+use crate::generate::{resolve_pattern_note_name, GenerateError};
+use crate::note::note_name_to_midi;
+
+/// MIDI ticks per quarter note (the SMF header division).
+pub const MIDI_TICKS_PER_QUARTER: u16 = 24;
+
+/// MIDI channels used for tracker channels, skipping the General MIDI drum channel (10).
+const MELODIC_CHANNELS: [u8; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 10, 11, 12, 13, 14, 15];
+
+/// Release velocity written with every note-off.
+const NOTE_OFF_VELOCITY: u8 = 64;
+
+/// A pattern cell that changes what a channel is playing.
+#[derive(Debug, Clone, Copy)]
+enum MidiCell {
+    /// Start a note, ending any note already playing.
+    NoteOn { key: u8, velocity: u8 },
+    /// End the note playing on the channel (note-off or note-cut).
+    NoteOff,
+}
+
+/// Events for one MIDI track, tracking the currently held note.
+struct TrackWriter {
+    channel: u8,
+    body: Vec<u8>,
+    last_tick: u32,
+    held_key: Option<u8>,
+}
+
+impl TrackWriter {
+    fn new(name: &str, channel: u8) -> Self {
+        let mut track = Self {
+            channel,
+            body: Vec::new(),
+            last_tick: 0,
+            held_key: None,
+        };
+        track.meta(0, 0x03, name.as_bytes());
+        track
+    }
+
+    fn event(&mut self, tick: u32, bytes: &[u8]) {
+        write_vlq(&mut self.body, tick - self.last_tick);
+        self.body.extend_from_slice(bytes);
+        self.last_tick = tick;
+    }
+
+    fn meta(&mut self, tick: u32, kind: u8, data: &[u8]) {
+        write_vlq(&mut self.body, tick - self.last_tick);
+        self.body.extend_from_slice(&[0xFF, kind]);
+        write_vlq(&mut self.body, data.len() as u32);
+        self.body.extend_from_slice(data);
+        self.last_tick = tick;
+    }
+
+    fn note_on(&mut self, tick: u32, key: u8, velocity: u8) {
+        self.note_off(tick);
+        self.event(tick, &[0x90 | self.channel, key, velocity]);
+        self.held_key = Some(key);
+    }
+
+    fn note_off(&mut self, tick: u32) {
+        if let Some(key) = self.held_key.take() {
+            self.event(tick, &[0x80 | self.channel, key, NOTE_OFF_VELOCITY]);
+        }
+    }
+
+    /// Releases any held note and closes the track chunk at `end_tick`.
+    fn finish(mut self, end_tick: u32) -> Vec<u8> {
+        self.note_off(end_tick);
+        self.meta(end_tick, 0x2F, &[]);
+
+        let mut chunk = Vec::with_capacity(8 + self.body.len());
+        chunk.extend_from_slice(b"MTrk");
+        chunk.extend_from_slice(&(self.body.len() as u32).to_be_bytes());
+        chunk.extend_from_slice(&self.body);
+        chunk
+    }
+}
+
+/// Generate a Standard MIDI File from tracker song params.
+///
+/// # Arguments
+/// * `params` - Music tracker song parameters
+///
+/// # Returns
+/// The SMF bytes and their BLAKE3 hash
+pub fn generate_midi(
+    params: &MusicTrackerSongV1Params,
+) -> Result<(Vec<u8>, String), GenerateError> {
+    validate_midi_params(params)?;
+
+    let ticks_per_row = params.speed as u32;
+    let mut tracks: Vec<TrackWriter> = (0..params.channels)
+        .map(|channel| {
+            TrackWriter::new(
+                &format!("Channel {}", channel + 1),
+                MELODIC_CHANNELS[channel as usize % MELODIC_CHANNELS.len()],
+            )
+        })
+        .collect();
+
+    let mut tick = 0u32;
+    for entry in &params.arrangement {
+        let pattern = params
+            .patterns
+            .get(&entry.pattern)
+            .ok_or_else(|| GenerateError::PatternNotFound(entry.pattern.clone()))?;
+        let cells = collect_pattern_cells(pattern, params)?;
+
+        for _ in 0..entry.repeat {
+            for (&(row, channel), cell) in &cells {
+                let at = tick + row as u32 * ticks_per_row;
+                let track = &mut tracks[channel as usize];
+                match *cell {
+                    MidiCell::NoteOn { key, velocity } => track.note_on(at, key, velocity),
+                    MidiCell::NoteOff => track.note_off(at),
+                }
+            }
+            tick += pattern.rows as u32 * ticks_per_row;
+        }
+    }
+
+    let mut conductor = TrackWriter::new("SpecCade Song", 0);
+    let micros_per_quarter = 60_000_000 / params.bpm as u32;
+    conductor.meta(0, 0x51, &micros_per_quarter.to_be_bytes()[1..]);
+
+    let mut data = Vec::new();
+    data.extend_from_slice(b"MThd");
+    data.extend_from_slice(&6u32.to_be_bytes());
+    data.extend_from_slice(&1u16.to_be_bytes());
+    data.extend_from_slice(&(tracks.len() as u16 + 1).to_be_bytes());
+    data.extend_from_slice(&MIDI_TICKS_PER_QUARTER.to_be_bytes());
+    data.extend(conductor.finish(tick));
+    for track in tracks {
+        data.extend(track.finish(tick));
+    }
+
+    let hash = blake3::hash(&data).to_hex().to_string();
+    Ok((data, hash))
+}
+
+/// Validate the params MIDI export depends on.
+fn validate_midi_params(params: &MusicTrackerSongV1Params) -> Result<(), GenerateError> {
+    if params.channels < 1 {
+        return Err(GenerateError::InvalidParameter(
+            "MIDI export requires at least 1 channel".to_string(),
+        ));
+    }
+    if params.bpm < 32 || params.bpm > 255 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "bpm must be 32-255, got {}",
+            params.bpm
+        )));
+    }
+    if params.speed < 1 || params.speed > 31 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "speed must be 1-31, got {}",
+            params.speed
+        )));
+    }
+    Ok(())
+}
+
+/// Collect the note-on and note-off cells of a pattern, keyed by (row, channel).
+///
+/// Cells without a note (`---` / `...`) do not affect MIDI output and are skipped.
+fn collect_pattern_cells(
+    pattern: &TrackerPattern,
+    params: &MusicTrackerSongV1Params,
+) -> Result<BTreeMap<(u16, u8), MidiCell>, GenerateError> {
+    let mut cells = BTreeMap::new();
+
+    for (channel, note) in pattern.flat_notes() {
+        if channel >= params.channels {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern note channel {} exceeds configured channel count {}",
+                channel, params.channels
+            )));
+        }
+        if note.row >= pattern.rows {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern note row {} is out of range for pattern rows {}",
+                note.row, pattern.rows
+            )));
+        }
+
+        let note_name = resolve_pattern_note_name(note, &params.instruments, "C4")?;
+        let cell = match note_name.as_ref() {
+            "OFF" | "===" | "^^^" => MidiCell::NoteOff,
+            "---" | "..." => continue,
+            name => {
+                let volume = note
+                    .vol
+                    .or_else(|| {
+                        params
+                            .instruments
+                            .get(note.inst as usize)
+                            .and_then(|instrument| instrument.default_volume)
+                    })
+                    .unwrap_or(64);
+                if volume > 64 {
+                    return Err(GenerateError::InvalidParameter(format!(
+                        "pattern note volume {} out of range (0-64) at row {}, channel {}",
+                        volume, note.row, channel
+                    )));
+                }
+                MidiCell::NoteOn {
+                    key: note_name_to_midi(name),
+                    velocity: volume_to_velocity(volume),
+                }
+            }
+        };
+
+        if cells.insert((note.row, channel), cell).is_some() {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern has more than one note at row {}, channel {}",
+                note.row, channel
+            )));
+        }
+    }
+
+    Ok(cells)
+}
+
+/// Scale a tracker volume (0-64) to a MIDI note-on velocity (1-127).
+///
+/// Velocity 0 would be read as a note-off, so silent notes get velocity 1.
+fn volume_to_velocity(volume: u8) -> u8 {
+    ((volume as u32 * 127 + 32) / 64).clamp(1, 127) as u8
+}
+
+/// Append `value` as a MIDI variable-length quantity.
+fn write_vlq(out: &mut Vec<u8>, value: u32) {
+    let mut groups = [0u8; 5];
+    let mut len = 0;
+    let mut rest = value;
+    loop {
+        groups[len] = (rest & 0x7F) as u8;
+        len += 1;
+        rest >>= 7;
+        if rest == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        let continuation = if i > 0 { 0x80 } else { 0 };
+        out.push(groups[i] | continuation);
+    }
+}
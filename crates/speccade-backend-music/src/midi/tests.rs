@@ -0,0 +1,207 @@
+//! Tests for MIDI export.
+
+use super::*;
+use std::collections::HashMap;
+
+use speccade_spec::recipe::music::{ArrangementEntry, PatternNote, TrackerFormat};
+
+fn single_note_params(notes: Vec<PatternNote>, rows: u16) -> MusicTrackerSongV1Params {
+    let mut channel_notes = HashMap::new();
+    channel_notes.insert("0".to_string(), notes);
+
+    let mut patterns = HashMap::new();
+    patterns.insert(
+        "main".to_string(),
+        TrackerPattern {
+            rows,
+            notes: Some(channel_notes),
+            data: None,
+        },
+    );
+
+    MusicTrackerSongV1Params {
+        format: TrackerFormat::Xm,
+        bpm: 125,
+        speed: 6,
+        channels: 1,
+        patterns,
+        arrangement: vec![ArrangementEntry {
+            pattern: "main".to_string(),
+            repeat: 1,
+        }],
+        ..Default::default()
+    }
+}
+
+fn note(row: u16, name: &str, vol: Option<u8>) -> PatternNote {
+    PatternNote {
+        row,
+        note: name.to_string(),
+        vol,
+        ..Default::default()
+    }
+}
+
+fn read_vlq(data: &[u8], pos: &mut usize) -> u32 {
+    let mut value = 0u32;
+    loop {
+        let byte = data[*pos];
+        *pos += 1;
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+    }
+}
+
+/// Splits an SMF into per-track (delta, status, data) events.
+fn parse_tracks(data: &[u8]) -> Vec<Vec<(u32, u8, Vec<u8>)>> {
+    let mut tracks = Vec::new();
+    let mut pos = 14;
+    while pos < data.len() {
+        assert_eq!(&data[pos..pos + 4], b"MTrk");
+        let len = u32::from_be_bytes(data[pos + 4..pos + 8].try_into().unwrap()) as usize;
+        let body = &data[pos + 8..pos + 8 + len];
+        let mut events = Vec::new();
+        let mut i = 0;
+        while i < body.len() {
+            let delta = read_vlq(body, &mut i);
+            let status = body[i];
+            i += 1;
+            let payload = if status == 0xFF {
+                let kind = body[i];
+                i += 1;
+                let meta_len = read_vlq(body, &mut i) as usize;
+                let mut payload = vec![kind];
+                payload.extend_from_slice(&body[i..i + meta_len]);
+                i += meta_len;
+                payload
+            } else {
+                i += 2;
+                body[i - 2..i].to_vec()
+            };
+            events.push((delta, status, payload));
+        }
+        tracks.push(events);
+        pos += 8 + len;
+    }
+    tracks
+}
+
+fn note_events(track: &[(u32, u8, Vec<u8>)]) -> Vec<(u32, u8, Vec<u8>)> {
+    let mut tick = 0;
+    let mut events = Vec::new();
+    for (delta, status, payload) in track {
+        tick += delta;
+        if matches!(status & 0xF0, 0x80 | 0x90) {
+            events.push((tick, *status, payload.clone()));
+        }
+    }
+    events
+}
+
+#[test]
+fn test_header_and_tempo() {
+    let params = single_note_params(vec![note(0, "C4", None)], 4);
+    let (data, hash) = generate_midi(&params).unwrap();
+
+    assert_eq!(&data[0..4], b"MThd");
+    assert_eq!(u16::from_be_bytes([data[8], data[9]]), 1);
+    assert_eq!(u16::from_be_bytes([data[10], data[11]]), 2);
+    assert_eq!(
+        u16::from_be_bytes([data[12], data[13]]),
+        MIDI_TICKS_PER_QUARTER
+    );
+    assert_eq!(hash, blake3::hash(&data).to_hex().to_string());
+
+    let tracks = parse_tracks(&data);
+    assert!(tracks[0].contains(&(0, 0xFF, vec![0x51, 0x07, 0x53, 0x00])));
+}
+
+#[test]
+fn test_single_note_on_and_off() {
+    let params = single_note_params(vec![note(0, "C-4", Some(64))], 4);
+    let (data, _) = generate_midi(&params).unwrap();
+    let tracks = parse_tracks(&data);
+    assert_eq!(tracks.len(), 2);
+
+    let channel_track = &tracks[1];
+    let notes: Vec<_> = channel_track
+        .iter()
+        .filter(|(_, status, _)| matches!(status & 0xF0, 0x80 | 0x90))
+        .collect();
+    assert_eq!(
+        notes,
+        vec![
+            &(0, 0x90, vec![60, 127]),
+            &(4 * 6, 0x80, vec![60, NOTE_OFF_VELOCITY]),
+        ]
+    );
+    assert_eq!(channel_track.last(), Some(&(0, 0xFF, vec![0x2F])));
+}
+
+#[test]
+fn test_next_note_and_cut_end_notes() {
+    let params = single_note_params(
+        vec![
+            note(0, "C4", Some(32)),
+            note(2, "E4", Some(32)),
+            note(5, "OFF", None),
+            note(6, "---", None),
+        ],
+        8,
+    );
+    let (data, _) = generate_midi(&params).unwrap();
+    let tracks = parse_tracks(&data);
+
+    assert_eq!(
+        note_events(&tracks[1]),
+        vec![
+            (0, 0x90, vec![60, 64]),
+            (12, 0x80, vec![60, NOTE_OFF_VELOCITY]),
+            (12, 0x90, vec![64, 64]),
+            (30, 0x80, vec![64, NOTE_OFF_VELOCITY]),
+        ]
+    );
+}
+
+#[test]
+fn test_channels_skip_drum_channel() {
+    let mut params = single_note_params(Vec::new(), 1);
+    params.channels = 11;
+    let (data, _) = generate_midi(&params).unwrap();
+    assert_eq!(u16::from_be_bytes([data[10], data[11]]), 12);
+
+    let mut notes = HashMap::new();
+    notes.insert("9".to_string(), vec![note(0, "A4", None)]);
+    params.patterns.get_mut("main").unwrap().notes = Some(notes);
+    let (data, _) = generate_midi(&params).unwrap();
+    let tracks = parse_tracks(&data);
+    assert_eq!(note_events(&tracks[10])[0], (0, 0x9A, vec![69, 127]));
+}
+
+#[test]
+fn test_rejects_out_of_range_channel() {
+    let mut params = single_note_params(Vec::new(), 4);
+    let mut notes = HashMap::new();
+    notes.insert("1".to_string(), vec![note(0, "C4", None)]);
+    params.patterns.get_mut("main").unwrap().notes = Some(notes);
+
+    let err = generate_midi(&params).unwrap_err();
+    assert!(err.to_string().contains("channel 1"));
+}
+
+#[test]
+fn test_vlq_encoding() {
+    for (value, expected) in [
+        (0u32, vec![0x00]),
+        (0x7F, vec![0x7F]),
+        (0x80, vec![0x81, 0x00]),
+        (0x3FFF, vec![0xFF, 0x7F]),
+        (0x0FFF_FFFF, vec![0xFF, 0xFF, 0xFF, 0x7F]),
+    ] {
+        let mut out = Vec::new();
+        write_vlq(&mut out, value);
+        assert_eq!(out, expected);
+    }
+}
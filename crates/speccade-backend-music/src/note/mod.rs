@@ -1,12 +1,14 @@
 //! Note and frequency conversion utilities for tracker modules.
 //!
 //! This module provides deterministic conversion between note names, MIDI numbers,
-//! and frequencies for XM and IT tracker formats.
+//! and frequencies for XM and IT tracker formats, plus cents-based detuning and
+//! microtonal scales.
 
 mod constants;
 mod conversion;
 mod frequency;
 mod pitch;
+mod tuning;
 
 #[cfg(test)]
 mod tests;
@@ -28,3 +30,5 @@ pub use pitch::{
     calculate_c5_speed, calculate_c5_speed_for_base_note, calculate_pitch_correction,
     calculate_xm_pitch_correction, it_pitch_deviation_cents, xm_pitch_deviation_cents,
 };
+
+pub use tuning::{detune_cents, MicrotonalScale};
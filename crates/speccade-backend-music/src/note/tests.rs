@@ -571,3 +571,69 @@ fn test_c5_speed_rounding_reduces_error() {
         cents
     );
 }
+
+// ========================================================================
+// Tuning Tests
+// ========================================================================
+
+#[test]
+fn test_detune_cents_octaves() {
+    for freq in [27.5, 261.626, 440.0, 8363.0] {
+        assert_eq!(detune_cents(freq, 0.0), freq);
+        assert!((detune_cents(freq, 1200.0) - freq * 2.0).abs() < 1e-9);
+        assert!((detune_cents(freq, -1200.0) - freq / 2.0).abs() < 1e-9);
+    }
+    // 100 cents is an equal-tempered semitone
+    assert!((detune_cents(midi_to_freq(69), 100.0) - midi_to_freq(70)).abs() < 1e-9);
+}
+
+#[test]
+fn test_equal_temperament_scale() {
+    let tet19 = MicrotonalScale::equal_temperament(19).unwrap();
+    assert_eq!(tet19.len(), 19);
+    assert_eq!(tet19.ratio(0), 1.0);
+    assert!((tet19.ratio(19) - 2.0).abs() < 1e-12);
+    assert!((tet19.ratio(-19) - 0.5).abs() < 1e-12);
+    assert!((tet19.cents(1) - 1200.0 / 19.0).abs() < 1e-9);
+
+    // 12-TET matches MIDI tuning
+    let tet12 = MicrotonalScale::equal_temperament(12).unwrap();
+    for degree in -24..24 {
+        let expected = midi_to_freq((69 + degree) as u8);
+        assert!((tet12.frequency(440.0, degree) - expected).abs() < 1e-9);
+    }
+
+    assert!(MicrotonalScale::equal_temperament(0).is_none());
+}
+
+#[test]
+fn test_custom_scale_ratios() {
+    // Five-limit just major scale
+    let just = [
+        9.0 / 8.0,
+        5.0 / 4.0,
+        4.0 / 3.0,
+        3.0 / 2.0,
+        5.0 / 3.0,
+        15.0 / 8.0,
+    ];
+    let scale = MicrotonalScale::from_ratios(&just, 2.0).unwrap();
+    assert_eq!(scale.len(), 7);
+    assert_eq!(scale.ratio(0), 1.0);
+    assert_eq!(scale.ratio(2), 5.0 / 4.0);
+    assert_eq!(scale.ratio(4), 3.0 / 2.0);
+    assert_eq!(scale.ratio(7), 2.0);
+    assert_eq!(scale.ratio(9), 5.0 / 2.0);
+    assert_eq!(scale.ratio(-1), 15.0 / 16.0);
+    assert_eq!(scale.frequency(200.0, 4), 300.0);
+
+    // Non-octave period (Bohlen-Pierce repeats at the tritave)
+    let bp = MicrotonalScale::from_ratios(&[27.0 / 25.0, 25.0 / 21.0], 3.0).unwrap();
+    assert_eq!(bp.ratio(3), 3.0);
+    assert_eq!(bp.period(), 3.0);
+
+    assert!(MicrotonalScale::from_ratios(&[1.5, 1.25], 2.0).is_none());
+    assert!(MicrotonalScale::from_ratios(&[1.0, 1.5], 2.0).is_none());
+    assert!(MicrotonalScale::from_ratios(&[2.5], 2.0).is_none());
+    assert!(MicrotonalScale::from_ratios(&[1.5], 1.0).is_none());
+}
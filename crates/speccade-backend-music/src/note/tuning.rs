@@ -0,0 +1,116 @@
+//! Cents-based detuning and microtonal scales.
+
+/// Cents in an octave (a 2:1 frequency ratio).
+const CENTS_PER_OCTAVE: f64 = 1200.0;
+
+/// Shift a frequency by a number of cents.
+///
+/// 100 cents is one equal-tempered semitone, so +1200 cents doubles the
+/// frequency and -1200 halves it. Zero cents returns `freq` unchanged.
+///
+/// # Examples
+/// ```
+/// use speccade_backend_music::note::detune_cents;
+///
+/// assert_eq!(detune_cents(440.0, 0.0), 440.0);
+/// assert!((detune_cents(440.0, 1200.0) - 880.0).abs() < 1e-9);
+/// ```
+pub fn detune_cents(freq: f64, cents: f64) -> f64 {
+    if cents == 0.0 {
+        return freq;
+    }
+    freq * 2.0_f64.powf(cents / CENTS_PER_OCTAVE)
+}
+
+/// A repeating scale defined by frequency ratios within one period.
+///
+/// Degree 0 is the root (ratio 1.0). Degrees past the last ratio wrap into the
+/// next period, and negative degrees wrap into the previous one, so a scale
+/// with `n` ratios and a period of 2.0 repeats every octave.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MicrotonalScale {
+    ratios: Vec<f64>,
+    period: f64,
+}
+
+impl MicrotonalScale {
+    /// Creates an equal temperament dividing the octave into `divisions`
+    /// equal steps (e.g. 12 for standard tuning, 19 for 19-TET).
+    ///
+    /// Returns `None` if `divisions` is zero.
+    pub fn equal_temperament(divisions: u32) -> Option<Self> {
+        if divisions == 0 {
+            return None;
+        }
+        let ratios = (0..divisions)
+            .map(|step| 2.0_f64.powf(step as f64 / divisions as f64))
+            .collect();
+        Some(Self {
+            ratios,
+            period: 2.0,
+        })
+    }
+
+    /// Creates a scale from custom ratios, e.g. just intonation.
+    ///
+    /// `ratios` lists the degrees above the root within one period; the root
+    /// (1.0) is implied and must not be included. `period` is the ratio the
+    /// scale repeats at, usually 2.0 for an octave.
+    ///
+    /// Returns `None` unless the ratios are finite, strictly increasing, and
+    /// lie between 1.0 and `period` (both exclusive).
+    pub fn from_ratios(ratios: &[f64], period: f64) -> Option<Self> {
+        if !period.is_finite() || period <= 1.0 {
+            return None;
+        }
+
+        let mut previous = 1.0;
+        for &ratio in ratios {
+            if !ratio.is_finite() || ratio <= previous || ratio >= period {
+                return None;
+            }
+            previous = ratio;
+        }
+
+        let mut all = Vec::with_capacity(ratios.len() + 1);
+        all.push(1.0);
+        all.extend_from_slice(ratios);
+        Some(Self {
+            ratios: all,
+            period,
+        })
+    }
+
+    /// Number of degrees per period, including the root.
+    pub fn len(&self) -> usize {
+        self.ratios.len()
+    }
+
+    /// Always false; every scale contains at least its root.
+    pub fn is_empty(&self) -> bool {
+        self.ratios.is_empty()
+    }
+
+    /// Ratio repeated by the scale (2.0 for an octave).
+    pub fn period(&self) -> f64 {
+        self.period
+    }
+
+    /// Frequency ratio of `degree` relative to the root.
+    pub fn ratio(&self, degree: i32) -> f64 {
+        let len = self.ratios.len() as i32;
+        let periods = degree.div_euclid(len);
+        let step = degree.rem_euclid(len) as usize;
+        self.ratios[step] * self.period.powi(periods)
+    }
+
+    /// Frequency of `degree` above (or below, if negative) `root_freq`.
+    pub fn frequency(&self, root_freq: f64, degree: i32) -> f64 {
+        root_freq * self.ratio(degree)
+    }
+
+    /// Offset of `degree` from the root in cents.
+    pub fn cents(&self, degree: i32) -> f64 {
+        CENTS_PER_OCTAVE * self.ratio(degree).log2()
+    }
+}
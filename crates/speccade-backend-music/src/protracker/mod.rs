@@ -0,0 +1,23 @@
+//! ProTracker MOD format writer.
+//!
+//! This module writes 4-channel ProTracker ("M.K.") modules, the most widely
+//! supported tracker format among retro tools and players.
+//!
+//! # MOD Format Overview
+//!
+//! MOD originates from Ultimate SoundTracker / ProTracker on the Amiga:
+//! - Exactly 4 channels
+//! - Up to 31 samples, 8-bit signed, at most 128 KiB each
+//! - Up to 64 patterns of exactly 64 rows
+//! - Notes are Amiga periods from a fixed table (3 octaves)
+//! - No instrument envelopes, volume column, or header tempo
+//!
+//! The module is named `protracker` because `mod` is a Rust keyword.
+
+mod pattern;
+mod sample;
+mod writer;
+
+pub use pattern::*;
+pub use sample::*;
+pub use writer::*;
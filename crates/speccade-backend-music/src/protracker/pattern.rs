@@ -0,0 +1,164 @@
+//! ProTracker pattern data structures and the Amiga period table.
+
+use std::io::{self, Write};
+
+/// Channels in a 4-channel ("M.K.") module.
+pub const MOD_CHANNELS: u8 = 4;
+
+/// Rows in every MOD pattern.
+pub const MOD_PATTERN_ROWS: u16 = 64;
+
+/// Bytes in one packed MOD pattern (64 rows x 4 channels x 4 bytes).
+pub const MOD_PATTERN_SIZE: usize = 1024;
+
+/// Amiga PAL clock used to convert periods to playback rates.
+pub const AMIGA_PAL_CLOCK: f64 = 7_093_789.2;
+
+/// MIDI note of the first period table entry (ProTracker C-1).
+///
+/// ProTracker C-2 (period 428) lines up with XM C-4, so C-1 is MIDI 48 (C3).
+pub const MOD_PERIOD_BASE_MIDI: u8 = 48;
+
+/// ProTracker periods for finetune 0, C-1 to B-3.
+pub const MOD_PERIOD_TABLE: [u16; 36] = [
+    856, 808, 762, 720, 678, 640, 604, 570, 538, 508, 480, 453, // C-1..B-1
+    428, 404, 381, 360, 339, 320, 302, 285, 269, 254, 240, 226, // C-2..B-2
+    214, 202, 190, 180, 170, 160, 151, 143, 135, 127, 120, 113, // C-3..B-3
+];
+
+/// Returns the period for a MIDI note, or `None` if it is outside the table (C3-B5).
+pub fn period_for_midi(midi_note: u8) -> Option<u16> {
+    let index = midi_note.checked_sub(MOD_PERIOD_BASE_MIDI)? as usize;
+    MOD_PERIOD_TABLE.get(index).copied()
+}
+
+/// Playback rate in Hz of a period on a PAL Amiga.
+pub fn period_to_rate(period: u16) -> f64 {
+    AMIGA_PAL_CLOCK / (2.0 * period as f64)
+}
+
+/// A single cell in a MOD pattern.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ModNote {
+    /// Sample number (0=none, 1-31=sample).
+    pub sample: u8,
+    /// Amiga period (0=no note).
+    pub period: u16,
+    /// Effect command (0x0-0xF).
+    pub effect: u8,
+    /// Effect parameter.
+    pub effect_param: u8,
+}
+
+impl ModNote {
+    /// Create a new empty cell.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Create a note from a MIDI note number, or `None` if it has no period.
+    pub fn from_midi(midi_note: u8, sample: u8) -> Option<Self> {
+        Some(Self {
+            sample,
+            period: period_for_midi(midi_note)?,
+            ..Default::default()
+        })
+    }
+
+    /// Set the effect on this cell.
+    pub fn with_effect(mut self, effect: u8, param: u8) -> Self {
+        self.effect = effect;
+        self.effect_param = param;
+        self
+    }
+
+    /// Returns true if the effect column is unused.
+    pub fn has_no_effect(&self) -> bool {
+        self.effect == 0 && self.effect_param == 0
+    }
+
+    /// Pack the cell into its 4-byte on-disk form.
+    pub fn pack(&self) -> [u8; 4] {
+        [
+            (self.sample & 0xF0) | ((self.period >> 8) as u8 & 0x0F),
+            self.period as u8,
+            ((self.sample & 0x0F) << 4) | (self.effect & 0x0F),
+            self.effect_param,
+        ]
+    }
+}
+
+/// MOD pattern: always 64 rows of 4 channels.
+#[derive(Debug, Clone)]
+pub struct ModPattern {
+    /// Cells indexed by `[row][channel]`.
+    pub notes: Vec<[ModNote; MOD_CHANNELS as usize]>,
+}
+
+impl ModPattern {
+    /// Create an empty pattern.
+    pub fn empty() -> Self {
+        Self {
+            notes: vec![[ModNote::empty(); MOD_CHANNELS as usize]; MOD_PATTERN_ROWS as usize],
+        }
+    }
+
+    /// Set a cell. Out-of-range positions are ignored.
+    pub fn set_note(&mut self, row: u16, channel: u8, note: ModNote) {
+        if let Some(cell) = self
+            .notes
+            .get_mut(row as usize)
+            .and_then(|r| r.get_mut(channel as usize))
+        {
+            *cell = note;
+        }
+    }
+
+    /// Get a cell.
+    pub fn get_note(&self, row: u16, channel: u8) -> Option<&ModNote> {
+        self.notes
+            .get(row as usize)
+            .and_then(|r| r.get(channel as usize))
+    }
+
+    /// Write the packed pattern (1024 bytes).
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for row in &self.notes {
+            for note in row {
+                writer.write_all(&note.pack())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ModPattern {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+/// MOD effect codes (identical to XM effects 0-F).
+pub mod effects {
+    pub use speccade_spec::recipe::music::xm_codes::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn period_table_maps_middle_c_to_428() {
+        assert_eq!(period_for_midi(60), Some(428));
+        assert_eq!(period_for_midi(48), Some(856));
+        assert_eq!(period_for_midi(83), Some(113));
+        assert_eq!(period_for_midi(47), None);
+        assert_eq!(period_for_midi(84), None);
+    }
+
+    #[test]
+    fn note_packs_sample_high_nibble_with_period() {
+        let note = ModNote::from_midi(60, 0x13).unwrap().with_effect(0xC, 0x20);
+        assert_eq!(note.pack(), [0x11, 0xAC, 0x3C, 0x20]);
+    }
+}
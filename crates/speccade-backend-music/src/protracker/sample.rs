@@ -0,0 +1,139 @@
+//! ProTracker sample headers and data.
+
+use byteorder::{BigEndian, WriteBytesExt};
+use std::io::{self, Write};
+
+/// Maximum number of samples in a MOD file.
+pub const MOD_MAX_SAMPLES: usize = 31;
+
+/// Maximum sample length in bytes (lengths are stored as 16-bit word counts).
+pub const MOD_MAX_SAMPLE_BYTES: usize = u16::MAX as usize * 2;
+
+/// Size of one sample header in bytes.
+pub const MOD_SAMPLE_HEADER_SIZE: usize = 30;
+
+/// A MOD sample (8-bit signed mono PCM).
+#[derive(Debug, Clone, Default)]
+pub struct ModSample {
+    /// Sample name (22 characters max).
+    pub name: String,
+    /// 8-bit signed PCM data.
+    pub data: Vec<i8>,
+    /// Finetune (-8 to 7, in eighths of a semitone).
+    pub finetune: i8,
+    /// Default volume (0-64).
+    pub volume: u8,
+    /// Loop start in samples (must be even).
+    pub loop_start: u32,
+    /// Loop length in samples (0 = no loop, otherwise even and >= 2).
+    pub loop_length: u32,
+}
+
+impl ModSample {
+    /// Create a sample from 16-bit signed little-endian PCM, keeping the high byte.
+    pub fn from_pcm16(name: &str, pcm16_mono: &[u8]) -> Self {
+        let data = pcm16_mono
+            .chunks_exact(2)
+            .map(|b| (i16::from_le_bytes([b[0], b[1]]) >> 8) as i8)
+            .collect();
+        Self {
+            name: name.to_string(),
+            data,
+            finetune: 0,
+            volume: 64,
+            loop_start: 0,
+            loop_length: 0,
+        }
+    }
+
+    /// Set a forward loop. Points are rounded down to whole words.
+    pub fn with_loop(mut self, start: u32, end: u32) -> Self {
+        let start = start & !1;
+        let length = end.saturating_sub(start) & !1;
+        if length >= 2 {
+            self.loop_start = start;
+            self.loop_length = length;
+        }
+        self
+    }
+
+    /// Sample data length in bytes, padded to a whole word.
+    pub fn padded_len(&self) -> usize {
+        (self.data.len() + 1) & !1
+    }
+
+    /// Write the 30-byte sample header.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.padded_len() > MOD_MAX_SAMPLE_BYTES {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "MOD sample '{}' is {} bytes; the format allows at most {}",
+                    self.name,
+                    self.data.len(),
+                    MOD_MAX_SAMPLE_BYTES
+                ),
+            ));
+        }
+
+        let mut name_buf = [0u8; 22];
+        let name_bytes = self.name.as_bytes();
+        let copy_len = name_bytes.len().min(22);
+        name_buf[..copy_len].copy_from_slice(&name_bytes[..copy_len]);
+        writer.write_all(&name_buf)?;
+
+        writer.write_u16::<BigEndian>((self.padded_len() / 2) as u16)?;
+        writer.write_u8((self.finetune.clamp(-8, 7) as u8) & 0x0F)?;
+        writer.write_u8(self.volume.min(64))?;
+        if self.loop_length >= 2 {
+            writer.write_u16::<BigEndian>((self.loop_start / 2) as u16)?;
+            writer.write_u16::<BigEndian>((self.loop_length / 2) as u16)?;
+        } else {
+            // ProTracker convention for "no loop"
+            writer.write_u16::<BigEndian>(0)?;
+            writer.write_u16::<BigEndian>(1)?;
+        }
+        Ok(())
+    }
+
+    /// Write the sample data, padded to a whole word.
+    pub fn write_data<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        for &s in &self.data {
+            writer.write_i8(s)?;
+        }
+        if self.data.len() % 2 == 1 {
+            writer.write_i8(0)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_stores_word_lengths_big_endian() {
+        let pcm: Vec<u8> = (0..301i16).flat_map(|i| (i * 100).to_le_bytes()).collect();
+        let sample = ModSample::from_pcm16("Lead", &pcm).with_loop(101, 299);
+
+        let mut header = Vec::new();
+        sample.write_header(&mut header).unwrap();
+        assert_eq!(header.len(), MOD_SAMPLE_HEADER_SIZE);
+        assert_eq!(&header[..4], b"Lead");
+        // 301 samples pad to 151 words
+        assert_eq!(&header[22..24], &[0, 151]);
+        assert_eq!(header[25], 64);
+        // Loop 100..298 in words
+        assert_eq!(&header[26..30], &[0, 50, 0, 99]);
+    }
+
+    #[test]
+    fn header_rejects_oversized_samples() {
+        let sample = ModSample {
+            data: vec![0; MOD_MAX_SAMPLE_BYTES + 2],
+            ..Default::default()
+        };
+        assert!(sample.write_header(&mut Vec::new()).is_err());
+    }
+}
@@ -0,0 +1,294 @@
+//! MOD file writer - assembles all components into a complete 4-channel module.
+
+use std::io::{self, Write};
+
+use super::pattern::{ModPattern, MOD_PATTERN_SIZE};
+use super::sample::{ModSample, MOD_MAX_SAMPLES, MOD_SAMPLE_HEADER_SIZE};
+
+/// Format tag for 4-channel ProTracker modules.
+pub const MOD_MAGIC: &[u8; 4] = b"M.K.";
+
+/// Offset of the format tag (and size of the header before pattern data).
+pub const MOD_MAGIC_OFFSET: usize = 20 + MOD_MAX_SAMPLES * MOD_SAMPLE_HEADER_SIZE + 2 + 128;
+
+/// Maximum number of patterns in an "M.K." module.
+pub const MOD_MAX_PATTERNS: usize = 64;
+
+/// Maximum number of order table entries.
+pub const MOD_MAX_ORDERS: usize = 128;
+
+/// ProTracker module containing all song data.
+#[derive(Debug, Clone)]
+pub struct ModModule {
+    /// Song title (20 characters max).
+    pub title: String,
+    /// Samples (up to 31).
+    pub samples: Vec<ModSample>,
+    /// Patterns (up to 64).
+    pub patterns: Vec<ModPattern>,
+    /// Pattern order table (up to 128 entries).
+    pub orders: Vec<u8>,
+}
+
+impl ModModule {
+    /// Create a new, empty MOD module.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            samples: Vec::new(),
+            patterns: Vec::new(),
+            orders: Vec::new(),
+        }
+    }
+
+    /// Add a sample to the module.
+    pub fn add_sample(&mut self, sample: ModSample) {
+        self.samples.push(sample);
+    }
+
+    /// Add a pattern to the module.
+    pub fn add_pattern(&mut self, pattern: ModPattern) {
+        self.patterns.push(pattern);
+    }
+
+    /// Set the order table (pattern playback order).
+    pub fn set_order_table(&mut self, orders: &[u8]) {
+        self.orders = orders.to_vec();
+    }
+
+    /// Write the complete MOD module to a writer.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.samples.len() > MOD_MAX_SAMPLES {
+            return Err(invalid_input(format!(
+                "MOD supports at most {} samples, got {}",
+                MOD_MAX_SAMPLES,
+                self.samples.len()
+            )));
+        }
+        if self.patterns.len() > MOD_MAX_PATTERNS {
+            return Err(invalid_input(format!(
+                "MOD supports at most {} patterns, got {}",
+                MOD_MAX_PATTERNS,
+                self.patterns.len()
+            )));
+        }
+        if self.orders.is_empty() || self.orders.len() > MOD_MAX_ORDERS {
+            return Err(invalid_input(format!(
+                "MOD order table must have 1-{} entries, got {}",
+                MOD_MAX_ORDERS,
+                self.orders.len()
+            )));
+        }
+        if let Some(&order) = self
+            .orders
+            .iter()
+            .find(|&&o| o as usize >= self.patterns.len())
+        {
+            return Err(invalid_input(format!(
+                "MOD order table references missing pattern {}",
+                order
+            )));
+        }
+
+        // Song title (20 bytes, null-padded)
+        let mut title_buf = [0u8; 20];
+        let title_bytes = self.title.as_bytes();
+        let copy_len = title_bytes.len().min(20);
+        title_buf[..copy_len].copy_from_slice(&title_bytes[..copy_len]);
+        writer.write_all(&title_buf)?;
+
+        // Sample headers (unused slots are empty)
+        let empty = ModSample::default();
+        for i in 0..MOD_MAX_SAMPLES {
+            self.samples.get(i).unwrap_or(&empty).write_header(writer)?;
+        }
+
+        // Song length, restart byte (127 by ProTracker convention), order table
+        writer.write_all(&[self.orders.len() as u8, 127])?;
+        let mut order_buf = [0u8; MOD_MAX_ORDERS];
+        order_buf[..self.orders.len()].copy_from_slice(&self.orders);
+        // Readers count patterns from the highest entry in all 128 slots, so an
+        // unplayed pattern past the highest ordered one is listed after the song.
+        let last_pattern = self.patterns.len() - 1;
+        if self.orders.iter().all(|&o| (o as usize) < last_pattern) {
+            if self.orders.len() == MOD_MAX_ORDERS {
+                return Err(invalid_input(format!(
+                    "MOD pattern {} is never played and the order table is full",
+                    last_pattern
+                )));
+            }
+            order_buf[MOD_MAX_ORDERS - 1] = last_pattern as u8;
+        }
+        writer.write_all(&order_buf)?;
+
+        writer.write_all(MOD_MAGIC)?;
+
+        for pattern in &self.patterns {
+            pattern.write(writer)?;
+        }
+
+        for sample in &self.samples {
+            sample.write_data(writer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the module to a byte vector.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.write(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Compute the BLAKE3 hash of the module bytes.
+    pub fn compute_hash(&self) -> io::Result<String> {
+        let bytes = self.to_bytes()?;
+        Ok(blake3::hash(&bytes).to_hex().to_string())
+    }
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Validate a MOD file's format tag, order table and size.
+pub fn validate_mod_bytes(data: &[u8]) -> Result<(), ModValidationError> {
+    let header_len = MOD_MAGIC_OFFSET + MOD_MAGIC.len();
+    if data.len() < header_len {
+        return Err(ModValidationError::FileTooSmall(data.len()));
+    }
+
+    if &data[MOD_MAGIC_OFFSET..header_len] != MOD_MAGIC {
+        return Err(ModValidationError::InvalidMagic);
+    }
+
+    let song_length = data[MOD_MAGIC_OFFSET - 130] as usize;
+    if song_length == 0 || song_length > MOD_MAX_ORDERS {
+        return Err(ModValidationError::InvalidSongLength(song_length));
+    }
+
+    // Pattern count is implied by the highest pattern in the whole order table
+    let orders = &data[MOD_MAGIC_OFFSET - 128..MOD_MAGIC_OFFSET];
+    let num_patterns = orders.iter().copied().max().unwrap_or(0) as usize + 1;
+
+    let sample_bytes: usize = (0..MOD_MAX_SAMPLES)
+        .map(|i| {
+            let offset = 20 + i * MOD_SAMPLE_HEADER_SIZE + 22;
+            u16::from_be_bytes([data[offset], data[offset + 1]]) as usize * 2
+        })
+        .sum();
+
+    let expected = header_len + num_patterns * MOD_PATTERN_SIZE + sample_bytes;
+    if data.len() < expected {
+        return Err(ModValidationError::Truncated {
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// MOD validation error.
+#[derive(Debug, Clone)]
+pub enum ModValidationError {
+    /// File is too small to hold a MOD header.
+    FileTooSmall(usize),
+    /// Missing "M.K." format tag.
+    InvalidMagic,
+    /// Song length is outside 1-128.
+    InvalidSongLength(usize),
+    /// File is shorter than its patterns and samples require.
+    Truncated { expected: usize, actual: usize },
+}
+
+impl std::fmt::Display for ModValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModValidationError::FileTooSmall(size) => {
+                write!(f, "File too small: {} bytes", size)
+            }
+            ModValidationError::InvalidMagic => {
+                write!(f, "Invalid MOD format tag (expected 'M.K.')")
+            }
+            ModValidationError::InvalidSongLength(len) => {
+                write!(f, "Invalid MOD song length: {}", len)
+            }
+            ModValidationError::Truncated { expected, actual } => {
+                write!(
+                    f,
+                    "MOD file truncated: expected at least {} bytes, got {}",
+                    expected, actual
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ModValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protracker::pattern::ModNote;
+
+    fn test_module() -> ModModule {
+        let mut module = ModModule::new("Test Song");
+        let pcm: Vec<u8> = (0..100i16).flat_map(|i| (i * 300).to_le_bytes()).collect();
+        module.add_sample(ModSample::from_pcm16("Lead", &pcm));
+
+        let mut pattern = ModPattern::empty();
+        pattern.set_note(0, 0, ModNote::from_midi(60, 1).unwrap());
+        module.add_pattern(pattern);
+        module.set_order_table(&[0, 0]);
+        module
+    }
+
+    #[test]
+    fn test_module_layout() {
+        let bytes = test_module().to_bytes().unwrap();
+
+        assert_eq!(&bytes[..9], b"Test Song");
+        assert_eq!(&bytes[1080..1084], MOD_MAGIC);
+        assert_eq!(bytes[950], 2); // song length
+        assert_eq!(bytes.len(), 1084 + 1024 + 100);
+        assert!(validate_mod_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn test_write_rejects_missing_patterns() {
+        let mut module = test_module();
+        module.set_order_table(&[1]);
+        assert!(module.to_bytes().is_err());
+    }
+
+    #[test]
+    fn test_unplayed_patterns_are_counted() {
+        let mut module = test_module();
+        module.add_pattern(ModPattern::empty());
+        let bytes = module.to_bytes().unwrap();
+
+        assert_eq!(bytes[952 + 127], 1);
+        assert_eq!(bytes.len(), 1084 + 2 * 1024 + 100);
+        assert!(validate_mod_bytes(&bytes).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_magic() {
+        let mut bytes = test_module().to_bytes().unwrap();
+        bytes[1080] = b'X';
+        assert!(matches!(
+            validate_mod_bytes(&bytes),
+            Err(ModValidationError::InvalidMagic)
+        ));
+        assert!(validate_mod_bytes(&bytes[..1000]).is_err());
+    }
+
+    #[test]
+    fn test_hash_determinism() {
+        let hash1 = test_module().compute_hash().unwrap();
+        let hash2 = test_module().compute_hash().unwrap();
+        assert_eq!(hash1, hash2);
+    }
+}
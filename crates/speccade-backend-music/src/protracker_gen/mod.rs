@@ -0,0 +1,498 @@
+//! ProTracker MOD format generation.
+//!
+//! This module handles all MOD-specific generation logic including:
+//! - Enforcing the format's fixed limits (4 channels, 31 samples, 64-row patterns)
+//! - Resampling instruments so the fixed Amiga period table plays them in tune
+//! - Pattern conversion, with speed/tempo and loop jumps written as effects
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use speccade_spec::recipe::music::{
+    parse_effect_name, MusicTrackerSongV1Params, TrackerFormat, TrackerInstrument, TrackerPattern,
+};
+
+use crate::generate::{
+    bake_instrument_sample, resolve_pattern_note_name, GenerateError, GenerateResult,
+    MusicInstrumentLoopReport, MusicLoopReport,
+};
+use crate::note::{note_name_to_it, DEFAULT_SYNTH_MIDI_NOTE};
+use crate::protracker::{
+    effects as mod_effects, period_for_midi, period_to_rate, validate_mod_bytes, ModModule,
+    ModNote, ModPattern, ModSample, MOD_CHANNELS, MOD_MAX_ORDERS, MOD_MAX_PATTERNS,
+    MOD_MAX_SAMPLES, MOD_MAX_SAMPLE_BYTES, MOD_PATTERN_ROWS,
+};
+use crate::xm::resample_linear;
+
+/// Speed a MOD player starts with when no `F` effect has been seen.
+const MOD_DEFAULT_SPEED: u8 = 6;
+
+/// Tempo (BPM) a MOD player starts with when no `F` effect has been seen.
+const MOD_DEFAULT_BPM: u16 = 125;
+
+/// Generate a MOD module from params.
+///
+/// Creates a 4-channel ProTracker module including samples, patterns,
+/// and the order table from the arrangement.
+///
+/// # Arguments
+/// * `params` - Music tracker song parameters
+/// * `seed` - Base seed for deterministic synthesis
+/// * `spec_dir` - Directory for resolving relative sample paths
+///
+/// # Returns
+/// Generated MOD module bytes with hash
+pub fn generate_mod(
+    params: &MusicTrackerSongV1Params,
+    seed: u32,
+    spec_dir: &Path,
+) -> Result<GenerateResult, GenerateError> {
+    // Validate parameters
+    validate_mod_params(params)?;
+
+    // Create module
+    let mut module = ModModule::new("SpecCade Song");
+
+    // Generate samples
+    let mut instrument_loop_reports = Vec::with_capacity(params.instruments.len());
+    for (idx, instr) in params.instruments.iter().enumerate() {
+        let (sample, loop_report) = generate_mod_sample(instr, seed, idx as u32, spec_dir)?;
+        module.add_sample(sample);
+        instrument_loop_reports.push(loop_report);
+    }
+
+    // Build pattern index map
+    let mut pattern_index_map: HashMap<String, u8> = HashMap::new();
+
+    // Determinism: `patterns` is a HashMap, so we must iterate in a stable order.
+    let mut pattern_names: Vec<String> = params.patterns.keys().cloned().collect();
+    pattern_names.sort();
+
+    for (pattern_idx, name) in pattern_names.iter().enumerate() {
+        let pattern = params
+            .patterns
+            .get(name)
+            .ok_or_else(|| GenerateError::PatternNotFound(name.clone()))?;
+        let mod_pattern = convert_pattern_to_mod(pattern, params.channels, &params.instruments)?;
+        module.add_pattern(mod_pattern);
+        pattern_index_map.insert(name.clone(), pattern_idx as u8);
+    }
+
+    // Build order table from arrangement
+    let mut order_table = Vec::new();
+    for entry in &params.arrangement {
+        let pattern_idx = pattern_index_map
+            .get(&entry.pattern)
+            .ok_or_else(|| GenerateError::PatternNotFound(entry.pattern.clone()))?;
+        for _ in 0..entry.repeat {
+            order_table.push(*pattern_idx);
+        }
+    }
+    if order_table.is_empty() {
+        order_table.push(0);
+    }
+    if order_table.len() > MOD_MAX_ORDERS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "MOD arrangement must expand to at most {} orders, got {}",
+            MOD_MAX_ORDERS,
+            order_table.len()
+        )));
+    }
+    if module.patterns.is_empty() {
+        module.add_pattern(ModPattern::empty());
+    }
+    module.set_order_table(&order_table);
+
+    // MOD headers carry no speed or tempo, so set them with `F` effects on the
+    // first row played.
+    let mut initial_effects = Vec::new();
+    if params.speed != MOD_DEFAULT_SPEED {
+        initial_effects.push((mod_effects::SET_SPEED_TEMPO, params.speed));
+    }
+    if params.bpm != MOD_DEFAULT_BPM {
+        initial_effects.push((mod_effects::SET_SPEED_TEMPO, params.bpm as u8));
+    }
+    for (effect, param) in initial_effects {
+        place_mod_effect(
+            &mut module,
+            order_table[0],
+            0,
+            effect,
+            param,
+            "initial speed/tempo",
+        )?;
+    }
+
+    // MOD loop behavior: insert a terminal position jump in the last order entry.
+    if params.r#loop {
+        let restart = params.restart_position.unwrap_or(0);
+        if restart as usize >= order_table.len() {
+            return Err(GenerateError::InvalidParameter(format!(
+                "restart_position {} is out of range for arrangement length {}",
+                restart,
+                order_table.len()
+            )));
+        }
+        let last_pattern = *order_table.last().unwrap_or(&0);
+        let last_row = pattern_end_row(&params.patterns, &pattern_names, last_pattern);
+        place_mod_effect(
+            &mut module,
+            last_pattern,
+            last_row,
+            mod_effects::POSITION_JUMP,
+            restart as u8,
+            "loop jump",
+        )?;
+    }
+
+    // Generate bytes
+    let data = module.to_bytes()?;
+    validate_mod_bytes(&data).map_err(|e| {
+        GenerateError::FormatValidation(format!("generated MOD failed validation: {}", e))
+    })?;
+    let hash = blake3::hash(&data).to_hex().to_string();
+
+    Ok(GenerateResult {
+        data,
+        hash,
+        extension: "mod",
+        loop_report: Some(MusicLoopReport {
+            extension: "mod".to_string(),
+            instruments: instrument_loop_reports,
+        }),
+    })
+}
+
+/// Validate MOD-specific parameters.
+fn validate_mod_params(params: &MusicTrackerSongV1Params) -> Result<(), GenerateError> {
+    if params.channels < 1 || params.channels > MOD_CHANNELS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "MOD supports 1-{} channels, got {}",
+            MOD_CHANNELS, params.channels
+        )));
+    }
+    if params.bpm < 32 || params.bpm > 255 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "bpm must be 32-255, got {}",
+            params.bpm
+        )));
+    }
+    if params.speed < 1 || params.speed > 31 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "speed must be 1-31, got {}",
+            params.speed
+        )));
+    }
+    if params.instruments.len() > MOD_MAX_SAMPLES {
+        return Err(GenerateError::InvalidParameter(format!(
+            "MOD supports at most {} instruments, got {}",
+            MOD_MAX_SAMPLES,
+            params.instruments.len()
+        )));
+    }
+    if params.patterns.len() > MOD_MAX_PATTERNS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "MOD supports at most {} patterns, got {}",
+            MOD_MAX_PATTERNS,
+            params.patterns.len()
+        )));
+    }
+    if !params.automation.is_empty() {
+        return Err(GenerateError::InvalidParameter(
+            "automation is not supported for MOD output".to_string(),
+        ));
+    }
+    if let Some(restart_position) = params.restart_position {
+        if restart_position as usize >= MOD_MAX_ORDERS {
+            return Err(GenerateError::InvalidParameter(format!(
+                "MOD restart_position must be 0-{}, got {}",
+                MOD_MAX_ORDERS - 1,
+                restart_position
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Generate a MOD sample from spec.
+///
+/// MOD has no per-sample rate, so the baked sample is resampled to the rate at
+/// which the period table plays its base note in tune. Envelopes are not
+/// representable in MOD and are ignored.
+pub(crate) fn generate_mod_sample(
+    instr: &TrackerInstrument,
+    base_seed: u32,
+    index: u32,
+    spec_dir: &Path,
+) -> Result<(ModSample, MusicInstrumentLoopReport), GenerateError> {
+    let (baked, loop_report) =
+        bake_instrument_sample(instr, base_seed, index, spec_dir, TrackerFormat::Mod)?;
+
+    // Period 428 (ProTracker C-2) plays MIDI 60; shift by the base note's offset.
+    let middle_c_rate = period_to_rate(period_for_midi(DEFAULT_SYNTH_MIDI_NOTE).unwrap_or(428));
+    let ideal_rate = middle_c_rate
+        * 2.0_f64.powf((baked.base_midi as f64 - DEFAULT_SYNTH_MIDI_NOTE as f64) / 12.0);
+    let target_rate = ideal_rate.round().max(1.0) as u32;
+    let pitch_cents = 1200.0 * (target_rate as f64 / ideal_rate).log2();
+
+    let pcm16 = resample_linear(&baked.pcm16_mono, baked.sample_rate, target_rate);
+    let mut sample = ModSample::from_pcm16(&instr.name, &pcm16);
+    if sample.padded_len() > MOD_MAX_SAMPLE_BYTES {
+        return Err(GenerateError::InstrumentError(format!(
+            "Instrument '{}' is {} bytes after resampling to {} Hz; MOD samples are limited to {} bytes",
+            instr.name,
+            sample.padded_len(),
+            target_rate,
+            MOD_MAX_SAMPLE_BYTES
+        )));
+    }
+
+    // MOD only has forward loops; ping-pong regions loop forward.
+    if let Some(loop_region) = baked.loop_region {
+        let ratio = target_rate as f64 / baked.sample_rate as f64;
+        let start = (loop_region.start as f64 * ratio).round() as u32;
+        let end = ((loop_region.end as f64 * ratio).round() as u32).min(sample.data.len() as u32);
+        sample = sample.with_loop(start, end);
+    }
+
+    // Set default volume
+    sample.volume = instr.default_volume.unwrap_or(64).min(64);
+
+    let mut loop_report = loop_report;
+    loop_report.pitch_deviation_cents = Some(pitch_cents);
+
+    Ok((sample, loop_report))
+}
+
+/// Convert a pattern from spec to MOD format.
+///
+/// Patterns shorter than 64 rows end with a pattern break; note volumes become
+/// `C` (set volume) effects since MOD has no volume column.
+pub(crate) fn convert_pattern_to_mod(
+    pattern: &TrackerPattern,
+    num_channels: u8,
+    instruments: &[TrackerInstrument],
+) -> Result<ModPattern, GenerateError> {
+    if pattern.rows == 0 || pattern.rows > MOD_PATTERN_ROWS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "MOD patterns must have 1-{} rows, got {}",
+            MOD_PATTERN_ROWS, pattern.rows
+        )));
+    }
+
+    let mut mod_pattern = ModPattern::empty();
+
+    for (channel, note) in pattern.flat_notes() {
+        if channel >= num_channels {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern note channel {} exceeds configured channel count {}",
+                channel, num_channels
+            )));
+        }
+        if note.row >= pattern.rows {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern note row {} is out of range for pattern rows {}",
+                note.row, pattern.rows
+            )));
+        }
+        if let Some(vol) = note.vol {
+            if vol > 64 {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern note volume {} out of range (0-64) at row {}, channel {}",
+                    vol, note.row, channel
+                )));
+            }
+        }
+
+        let note_name = resolve_pattern_note_name(note, instruments, "C4")?;
+        let note_name = note_name.as_ref();
+
+        let mod_note = if note_name == "OFF" || note_name == "===" || note_name == "^^^" {
+            // MOD has no note-off; cut the note on its first tick instead.
+            ModNote::empty().with_effect(mod_effects::EXTENDED, 0xC0)
+        } else {
+            if note.inst as usize >= instruments.len() {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern references instrument {} but only {} instrument(s) are defined",
+                    note.inst,
+                    instruments.len()
+                )));
+            }
+            let sample_column = note.inst + 1; // MOD samples are 1-indexed
+
+            let mut n = if note_name == "---" || note_name == "..." {
+                ModNote {
+                    sample: sample_column,
+                    ..ModNote::empty()
+                }
+            } else {
+                let midi = note_name_to_it(note_name) as u16 + 12;
+                u8::try_from(midi)
+                    .ok()
+                    .and_then(|midi| ModNote::from_midi(midi, sample_column))
+                    .ok_or_else(|| {
+                        GenerateError::InvalidParameter(format!(
+                            "note '{}' at row {}, channel {} is outside the MOD period table (C3-B5)",
+                            note_name, note.row, channel
+                        ))
+                    })?
+            };
+
+            if note.effect.is_some() && note.effect_name.is_some() {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern note at row {}, channel {} must set either 'effect' or 'effect_name', not both",
+                    note.row, channel
+                )));
+            }
+
+            if let Some([x, y]) = note.effect_xy {
+                if x > 0x0F || y > 0x0F {
+                    return Err(GenerateError::InvalidParameter(format!(
+                        "pattern note effect_xy [{}, {}] out of range (each nibble must be 0-15) at row {}, channel {}",
+                        x, y, note.row, channel
+                    )));
+                }
+            }
+
+            // Apply effect if present.
+            if let Some(effect_code) = note.effect {
+                if effect_code > 0x0F {
+                    return Err(GenerateError::InvalidParameter(format!(
+                        "effect code {} is not supported in MOD (0-15) at row {}, channel {}",
+                        effect_code, note.row, channel
+                    )));
+                }
+                let param = if let Some([x, y]) = note.effect_xy {
+                    (x << 4) | (y & 0x0F)
+                } else {
+                    note.param.unwrap_or(0)
+                };
+                n = n.with_effect(effect_code, param);
+            } else if let Some(ref effect_name) = note.effect_name {
+                let typed_effect = parse_effect_name(effect_name, note.param, note.effect_xy)
+                    .ok_or_else(|| {
+                        GenerateError::InvalidParameter(format!(
+                            "unknown effect_name '{}' at row {}, channel {}",
+                            effect_name, note.row, channel
+                        ))
+                    })?;
+
+                typed_effect.validate_mod().map_err(|e| {
+                    GenerateError::InvalidParameter(format!(
+                        "invalid MOD effect '{}' at row {}, channel {}: {}",
+                        effect_name, note.row, channel, e
+                    ))
+                })?;
+
+                let (code, param) = typed_effect.to_xm().ok_or_else(|| {
+                    GenerateError::InvalidParameter(format!(
+                        "effect '{}' is not supported in MOD at row {}, channel {}",
+                        effect_name, note.row, channel
+                    ))
+                })?;
+                n = n.with_effect(code, param);
+            }
+
+            // MOD has no volume column; volume needs the effect column.
+            if let Some(vol) = note.vol {
+                if !n.has_no_effect() {
+                    return Err(GenerateError::InvalidParameter(format!(
+                        "pattern note at row {}, channel {} sets both a volume and an effect, but MOD has no volume column",
+                        note.row, channel
+                    )));
+                }
+                n = n.with_effect(mod_effects::SET_VOLUME, vol);
+            }
+
+            n
+        };
+
+        mod_pattern.set_note(note.row, channel, mod_note);
+    }
+
+    // End short patterns early with a pattern break on their last row.
+    if pattern.rows < MOD_PATTERN_ROWS {
+        let last_row = pattern.rows - 1;
+        let channel = (0..MOD_CHANNELS)
+            .find(|&ch| {
+                mod_pattern
+                    .get_note(last_row, ch)
+                    .is_some_and(ModNote::has_no_effect)
+            })
+            .ok_or_else(|| {
+                GenerateError::InvalidParameter(format!(
+                    "cannot end {}-row MOD pattern: no channel available on row {} for a pattern break",
+                    pattern.rows, last_row
+                ))
+            })?;
+        let note = mod_pattern
+            .get_note(last_row, channel)
+            .copied()
+            .unwrap_or_default();
+        mod_pattern.set_note(
+            last_row,
+            channel,
+            note.with_effect(mod_effects::PATTERN_BREAK, 0),
+        );
+    }
+
+    Ok(mod_pattern)
+}
+
+/// Returns the last played row of the pattern at `pattern_idx`.
+fn pattern_end_row(
+    patterns: &HashMap<String, TrackerPattern>,
+    pattern_names: &[String],
+    pattern_idx: u8,
+) -> u16 {
+    pattern_names
+        .get(pattern_idx as usize)
+        .and_then(|name| patterns.get(name))
+        .map_or(MOD_PATTERN_ROWS, |p| p.rows.min(MOD_PATTERN_ROWS))
+        .saturating_sub(1)
+}
+
+/// Place an effect on `row` of a pattern, in the first channel whose effect
+/// column is free. A pattern break inserted for a short pattern is replaced,
+/// since a position jump also ends the pattern.
+fn place_mod_effect(
+    module: &mut ModModule,
+    pattern_idx: u8,
+    row: u16,
+    effect: u8,
+    param: u8,
+    purpose: &str,
+) -> Result<(), GenerateError> {
+    let pattern = module
+        .patterns
+        .get_mut(pattern_idx as usize)
+        .ok_or_else(|| {
+            GenerateError::InvalidParameter(format!(
+                "cannot apply MOD {}: order table references missing pattern index {}",
+                purpose, pattern_idx
+            ))
+        })?;
+
+    let replaceable = |note: &ModNote| {
+        note.has_no_effect()
+            || (effect == mod_effects::POSITION_JUMP
+                && note.effect == mod_effects::PATTERN_BREAK
+                && note.effect_param == 0)
+    };
+    let channel = (0..MOD_CHANNELS)
+        .find(|&ch| pattern.get_note(row, ch).is_some_and(|n| n.has_no_effect()))
+        .or_else(|| (0..MOD_CHANNELS).find(|&ch| pattern.get_note(row, ch).is_some_and(replaceable)))
+        .ok_or_else(|| {
+            GenerateError::InvalidParameter(format!(
+                "cannot apply MOD {}: no channel available on row {} of pattern {} (all {} channel(s) already use effects)",
+                purpose, row, pattern_idx, MOD_CHANNELS
+            ))
+        })?;
+
+    let note = pattern.get_note(row, channel).copied().unwrap_or_default();
+    pattern.set_note(row, channel, note.with_effect(effect, param));
+    Ok(())
+}
@@ -0,0 +1,196 @@
+//! Tests for MOD generation module.
+
+use super::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+use speccade_spec::recipe::audio::Envelope;
+use speccade_spec::recipe::music::{
+    ArrangementEntry, InstrumentSynthesis, PatternNote, TrackerFormat,
+};
+
+use crate::protracker::{MOD_MAGIC, MOD_MAGIC_OFFSET};
+
+fn create_test_params() -> MusicTrackerSongV1Params {
+    let instrument = TrackerInstrument {
+        name: "Test Lead".to_string(),
+        synthesis: Some(InstrumentSynthesis::Pulse {
+            duty_cycle: 0.5,
+            base_note: None,
+        }),
+        envelope: Envelope {
+            attack: 0.01,
+            decay: 0.1,
+            sustain: 0.5,
+            release: 0.2,
+        },
+        default_volume: Some(64),
+        ..Default::default()
+    };
+
+    let mut notes = HashMap::new();
+    notes.insert(
+        "0".to_string(),
+        vec![
+            PatternNote {
+                row: 0,
+                note: "C4".to_string(),
+                inst: 0,
+                vol: Some(48),
+                ..Default::default()
+            },
+            PatternNote {
+                row: 4,
+                note: "E4".to_string(),
+                inst: 0,
+                ..Default::default()
+            },
+        ],
+    );
+    let pattern = TrackerPattern {
+        rows: 16,
+        notes: Some(notes),
+        data: None,
+    };
+
+    let mut patterns = HashMap::new();
+    patterns.insert("intro".to_string(), pattern);
+
+    MusicTrackerSongV1Params {
+        format: TrackerFormat::Mod,
+        bpm: 120,
+        speed: 6,
+        channels: 4,
+        r#loop: true,
+        instruments: vec![instrument],
+        patterns,
+        arrangement: vec![ArrangementEntry {
+            pattern: "intro".to_string(),
+            repeat: 2,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_generate_mod() {
+    let params = create_test_params();
+    let result = generate_mod(&params, 42, Path::new(".")).unwrap();
+
+    assert_eq!(result.extension, "mod");
+    assert_eq!(result.hash.len(), 64);
+    assert_eq!(
+        &result.data[MOD_MAGIC_OFFSET..MOD_MAGIC_OFFSET + 4],
+        MOD_MAGIC
+    );
+    // Song length byte precedes the restart byte and order table.
+    assert_eq!(result.data[MOD_MAGIC_OFFSET - 130], 2);
+}
+
+#[test]
+fn test_generate_mod_is_byte_deterministic() {
+    let params = create_test_params();
+    let first = generate_mod(&params, 42, Path::new(".")).unwrap();
+    let second = generate_mod(&params, 42, Path::new(".")).unwrap();
+
+    assert_eq!(first.data, second.data);
+    assert_eq!(first.hash, second.hash);
+    assert_eq!(first.hash, blake3::hash(&first.data).to_hex().to_string());
+}
+
+#[test]
+fn test_mod_rejects_more_than_four_channels() {
+    let mut params = create_test_params();
+    params.channels = 8;
+
+    let err = generate_mod(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("MOD supports 1-4 channels"));
+}
+
+#[test]
+fn test_mod_rejects_more_than_31_instruments() {
+    let mut params = create_test_params();
+    params.instruments = vec![params.instruments[0].clone(); 32];
+
+    let err = generate_mod(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("at most 31 instruments"));
+}
+
+#[test]
+fn test_mod_rejects_patterns_longer_than_64_rows() {
+    let mut params = create_test_params();
+    params.patterns.get_mut("intro").unwrap().rows = 128;
+
+    let err = generate_mod(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("MOD patterns must have 1-64 rows"));
+}
+
+#[test]
+fn test_mod_rejects_notes_outside_period_table() {
+    let mut params = create_test_params();
+    params
+        .patterns
+        .get_mut("intro")
+        .unwrap()
+        .notes
+        .as_mut()
+        .unwrap()
+        .get_mut("0")
+        .unwrap()[0]
+        .note = "C7".to_string();
+
+    let err = generate_mod(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("outside the MOD period table"));
+}
+
+#[test]
+fn test_mod_pattern_converts_volume_to_effect_and_breaks_short_patterns() {
+    let params = create_test_params();
+    let pattern = params.patterns.get("intro").unwrap();
+    let mod_pattern = convert_pattern_to_mod(pattern, 4, &params.instruments).unwrap();
+
+    let first = mod_pattern.get_note(0, 0).unwrap();
+    assert_eq!(first.period, 428);
+    assert_eq!(first.sample, 1);
+    assert_eq!(
+        (first.effect, first.effect_param),
+        (mod_effects::SET_VOLUME, 48)
+    );
+
+    let last_row = mod_pattern.get_note(15, 0).unwrap();
+    assert_eq!(last_row.effect, mod_effects::PATTERN_BREAK);
+}
+
+#[test]
+fn test_mod_writes_speed_and_tempo_effects_on_first_row() {
+    let mut params = create_test_params();
+    params.speed = 4;
+    params.bpm = 140;
+    params.r#loop = false;
+    let result = generate_mod(&params, 42, Path::new(".")).unwrap();
+
+    // First pattern row follows the magic; channel 0 holds the C-4 note with
+    // its volume, so speed and tempo land in channels 1 and 2.
+    let row0 = &result.data[MOD_MAGIC_OFFSET + 4..MOD_MAGIC_OFFSET + 20];
+    assert_eq!(&row0[4..8], &[0, 0, mod_effects::SET_SPEED_TEMPO, 4]);
+    assert_eq!(&row0[8..12], &[0, 0, mod_effects::SET_SPEED_TEMPO, 140]);
+}
+
+#[test]
+fn test_mod_rejects_it_only_effect_name() {
+    let mut params = create_test_params();
+    let note = &mut params
+        .patterns
+        .get_mut("intro")
+        .unwrap()
+        .notes
+        .as_mut()
+        .unwrap()
+        .get_mut("0")
+        .unwrap()[1];
+    note.effect_name = Some("key_off".to_string());
+    note.param = Some(0);
+
+    let err = generate_mod(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("MOD"));
+}
@@ -0,0 +1,21 @@
+//! S3M (Scream Tracker 3) format writer.
+//!
+//! This module writes Scream Tracker 3 modules, widely supported by DOS-era
+//! tools and modern players alike.
+//!
+//! # S3M Format Overview
+//!
+//! S3M was created by Future Crew for Scream Tracker 3. Key features:
+//! - Up to 16 PCM channels
+//! - Up to 99 sample instruments, 8-bit, tuned by a per-sample C2SPD
+//! - Up to 100 patterns of exactly 64 rows, with a volume column
+//! - Effects share IT's lettering (IT extended the S3M effect set)
+//! - No instrument envelopes
+
+mod pattern;
+mod sample;
+mod writer;
+
+pub use pattern::*;
+pub use sample::*;
+pub use writer::*;
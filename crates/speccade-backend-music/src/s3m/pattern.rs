@@ -0,0 +1,219 @@
+//! S3M pattern data structures and packing.
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::io::{self, Write};
+
+/// Rows in every S3M pattern.
+pub const S3M_PATTERN_ROWS: u16 = 64;
+
+/// Note byte for "no note".
+pub const S3M_NOTE_NONE: u8 = 255;
+
+/// Note byte for a note cut (`^^`).
+pub const S3M_NOTE_CUT: u8 = 254;
+
+/// Highest playable octave.
+pub const S3M_MAX_OCTAVE: u8 = 7;
+
+/// Converts a MIDI note to an S3M note byte (`octave << 4 | semitone`).
+///
+/// S3M C-4 (0x40) is middle C (MIDI 60) and plays at the sample's C2SPD.
+/// Returns `None` outside C-0 to B-7 (MIDI 12 to 107).
+pub fn s3m_note_from_midi(midi_note: u8) -> Option<u8> {
+    let note = midi_note.checked_sub(12)?;
+    let octave = note / 12;
+    if octave > S3M_MAX_OCTAVE {
+        return None;
+    }
+    Some((octave << 4) | (note % 12))
+}
+
+/// A single cell in an S3M pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct S3mNote {
+    /// Note byte (`octave << 4 | semitone`, 254=cut, 255=none).
+    pub note: u8,
+    /// Instrument number (0=none, 1-99=instrument).
+    pub instrument: u8,
+    /// Volume column (0-64), if set.
+    pub volume: Option<u8>,
+    /// Effect command (A-Z = 1-26, 0=none).
+    pub effect: u8,
+    /// Effect parameter.
+    pub effect_param: u8,
+}
+
+impl Default for S3mNote {
+    fn default() -> Self {
+        Self {
+            note: S3M_NOTE_NONE,
+            instrument: 0,
+            volume: None,
+            effect: 0,
+            effect_param: 0,
+        }
+    }
+}
+
+impl S3mNote {
+    /// Create a new empty cell.
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    /// Create a note-cut event.
+    pub fn note_cut() -> Self {
+        Self {
+            note: S3M_NOTE_CUT,
+            ..Default::default()
+        }
+    }
+
+    /// Create a note from a MIDI note number, or `None` if it is out of range.
+    pub fn from_midi(midi_note: u8, instrument: u8, volume: Option<u8>) -> Option<Self> {
+        Some(Self {
+            note: s3m_note_from_midi(midi_note)?,
+            instrument,
+            volume: volume.map(|v| v.min(64)),
+            ..Default::default()
+        })
+    }
+
+    /// Set the effect on this cell.
+    pub fn with_effect(mut self, effect: u8, param: u8) -> Self {
+        self.effect = effect;
+        self.effect_param = param;
+        self
+    }
+
+    /// Returns true if the cell has no data.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::empty()
+    }
+}
+
+/// S3M pattern: always 64 rows.
+#[derive(Debug, Clone)]
+pub struct S3mPattern {
+    /// Cells indexed by `[row][channel]`.
+    pub notes: Vec<Vec<S3mNote>>,
+}
+
+impl S3mPattern {
+    /// Create an empty pattern.
+    pub fn empty(num_channels: u8) -> Self {
+        Self {
+            notes: vec![vec![S3mNote::empty(); num_channels as usize]; S3M_PATTERN_ROWS as usize],
+        }
+    }
+
+    /// Set a cell. Out-of-range positions are ignored.
+    pub fn set_note(&mut self, row: u16, channel: u8, note: S3mNote) {
+        if let Some(cell) = self
+            .notes
+            .get_mut(row as usize)
+            .and_then(|r| r.get_mut(channel as usize))
+        {
+            *cell = note;
+        }
+    }
+
+    /// Get a cell.
+    pub fn get_note(&self, row: u16, channel: u8) -> Option<&S3mNote> {
+        self.notes
+            .get(row as usize)
+            .and_then(|r| r.get(channel as usize))
+    }
+
+    /// Pack the pattern rows (without the length prefix).
+    pub fn pack(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+
+        for row in &self.notes {
+            for (channel, note) in row.iter().enumerate() {
+                if note.is_empty() {
+                    continue;
+                }
+
+                let has_note = note.note != S3M_NOTE_NONE || note.instrument != 0;
+                let has_effect = note.effect != 0 || note.effect_param != 0;
+
+                let mut what = channel as u8 & 0x1F;
+                if has_note {
+                    what |= 0x20;
+                }
+                if note.volume.is_some() {
+                    what |= 0x40;
+                }
+                if has_effect {
+                    what |= 0x80;
+                }
+                output.push(what);
+
+                if has_note {
+                    output.push(note.note);
+                    output.push(note.instrument);
+                }
+                if let Some(volume) = note.volume {
+                    output.push(volume);
+                }
+                if has_effect {
+                    output.push(note.effect);
+                    output.push(note.effect_param);
+                }
+            }
+            // End of row
+            output.push(0);
+        }
+
+        output
+    }
+
+    /// Write the pattern with its 16-bit length prefix.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let packed = self.pack();
+        writer.write_u16::<LittleEndian>((packed.len() + 2) as u16)?;
+        writer.write_all(&packed)
+    }
+}
+
+/// S3M effect codes (S3M shares IT's effect lettering, A = 1).
+pub mod effects {
+    pub use speccade_spec::recipe::music::it_codes::*;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn midi_maps_to_octave_and_semitone() {
+        assert_eq!(s3m_note_from_midi(60), Some(0x40));
+        assert_eq!(s3m_note_from_midi(69), Some(0x49));
+        assert_eq!(s3m_note_from_midi(12), Some(0x00));
+        assert_eq!(s3m_note_from_midi(107), Some(0x7B));
+        assert_eq!(s3m_note_from_midi(108), None);
+        assert_eq!(s3m_note_from_midi(11), None);
+    }
+
+    #[test]
+    fn empty_pattern_packs_to_row_terminators() {
+        let pattern = S3mPattern::empty(4);
+        assert_eq!(pattern.pack(), vec![0; 64]);
+    }
+
+    #[test]
+    fn cell_packing_sets_only_present_fields() {
+        let mut pattern = S3mPattern::empty(4);
+        pattern.set_note(0, 2, S3mNote::from_midi(60, 1, Some(48)).unwrap());
+        pattern.set_note(
+            1,
+            1,
+            S3mNote::empty().with_effect(effects::PATTERN_BREAK, 0),
+        );
+
+        let packed = pattern.pack();
+        assert_eq!(&packed[..5], &[0x62, 0x40, 1, 48, 0]);
+        assert_eq!(&packed[5..9], &[0x81, effects::PATTERN_BREAK, 0, 0]);
+    }
+}
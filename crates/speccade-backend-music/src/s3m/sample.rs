@@ -0,0 +1,123 @@
+//! S3M instrument (sample) headers and data.
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::io::{self, Write};
+
+/// Maximum number of instruments in an S3M file.
+pub const S3M_MAX_INSTRUMENTS: usize = 99;
+
+/// Size of one instrument header in bytes.
+pub const S3M_INSTRUMENT_HEADER_SIZE: usize = 0x50;
+
+/// Instrument header magic.
+pub const S3M_SAMPLE_MAGIC: &[u8; 4] = b"SCRS";
+
+/// An S3M sample instrument (8-bit unsigned mono PCM).
+#[derive(Debug, Clone, Default)]
+pub struct S3mSample {
+    /// Sample name (28 characters max).
+    pub name: String,
+    /// 8-bit unsigned PCM data.
+    pub data: Vec<u8>,
+    /// Playback rate for middle C (C-4).
+    pub c2spd: u32,
+    /// Default volume (0-64).
+    pub volume: u8,
+    /// Loop region `(start, end)` in samples, end exclusive.
+    pub loop_region: Option<(u32, u32)>,
+}
+
+impl S3mSample {
+    /// Create a sample from 16-bit signed little-endian PCM, keeping the high byte.
+    pub fn from_pcm16(name: &str, pcm16_mono: &[u8], c2spd: u32) -> Self {
+        let data = pcm16_mono
+            .chunks_exact(2)
+            .map(|b| ((i16::from_le_bytes([b[0], b[1]]) >> 8) as i8 as u8) ^ 0x80)
+            .collect();
+        Self {
+            name: name.to_string(),
+            data,
+            c2spd,
+            volume: 64,
+            loop_region: None,
+        }
+    }
+
+    /// Set a forward loop.
+    pub fn with_loop(mut self, start: u32, end: u32) -> Self {
+        if end > start {
+            self.loop_region = Some((start, end));
+        }
+        self
+    }
+
+    /// Write the 80-byte instrument header.
+    ///
+    /// `data_offset` is the file offset of the sample data (16-byte aligned).
+    pub fn write_header<W: Write>(&self, writer: &mut W, data_offset: usize) -> io::Result<()> {
+        let memseg = data_offset / 16;
+        if !data_offset.is_multiple_of(16) || memseg > 0xFF_FFFF {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("S3M sample data offset {} is not addressable", data_offset),
+            ));
+        }
+
+        writer.write_u8(1)?; // type: sample
+        writer.write_all(&[0u8; 12])?; // DOS filename
+        writer.write_u8((memseg >> 16) as u8)?;
+        writer.write_u16::<LittleEndian>(memseg as u16)?;
+        writer.write_u32::<LittleEndian>(self.data.len() as u32)?;
+        let (loop_start, loop_end) = self.loop_region.unwrap_or((0, 0));
+        writer.write_u32::<LittleEndian>(loop_start)?;
+        writer.write_u32::<LittleEndian>(loop_end)?;
+        writer.write_u8(self.volume.min(64))?;
+        writer.write_u8(0)?; // reserved
+        writer.write_u8(0)?; // pack: unpacked
+        writer.write_u8(u8::from(self.loop_region.is_some()))?; // flags: loop
+        writer.write_u32::<LittleEndian>(self.c2spd)?;
+        writer.write_all(&[0u8; 12])?; // internal
+
+        let mut name_buf = [0u8; 28];
+        let name_bytes = self.name.as_bytes();
+        let copy_len = name_bytes.len().min(27);
+        name_buf[..copy_len].copy_from_slice(&name_bytes[..copy_len]);
+        writer.write_all(&name_buf)?;
+
+        writer.write_all(S3M_SAMPLE_MAGIC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pcm16_converts_to_unsigned_8bit() {
+        let pcm: Vec<u8> = [0i16, i16::MAX, i16::MIN, -256]
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        let sample = S3mSample::from_pcm16("s", &pcm, 8363);
+        assert_eq!(sample.data, vec![128, 255, 0, 127]);
+    }
+
+    #[test]
+    fn header_layout() {
+        let sample = S3mSample::from_pcm16("Bass", &[0; 200], 16726).with_loop(10, 90);
+        let mut header = Vec::new();
+        sample.write_header(&mut header, 0x12340).unwrap();
+
+        assert_eq!(header.len(), S3M_INSTRUMENT_HEADER_SIZE);
+        assert_eq!(header[0], 1);
+        assert_eq!(&header[0x0D..0x10], &[0x00, 0x34, 0x12]);
+        assert_eq!(&header[0x10..0x14], &100u32.to_le_bytes());
+        assert_eq!(&header[0x14..0x1C], &[10, 0, 0, 0, 90, 0, 0, 0]);
+        assert_eq!(header[0x1F], 1);
+        assert_eq!(&header[0x20..0x24], &16726u32.to_le_bytes());
+        assert_eq!(&header[0x30..0x34], b"Bass");
+        assert_eq!(&header[0x4C..0x50], S3M_SAMPLE_MAGIC);
+
+        assert!(sample.write_header(&mut Vec::new(), 0x12341).is_err());
+    }
+}
@@ -0,0 +1,404 @@
+//! S3M file writer - assembles all components into a complete module.
+//!
+//! Layout: header, order list, parapointers, then instrument headers, packed
+//! patterns and sample data, each starting on a 16-byte boundary.
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use std::io::{self, Write};
+
+use super::pattern::S3mPattern;
+use super::sample::{S3mSample, S3M_INSTRUMENT_HEADER_SIZE, S3M_MAX_INSTRUMENTS};
+
+/// S3M file magic (at offset 0x2C).
+pub const S3M_MAGIC: &[u8; 4] = b"SCRM";
+
+/// Offset of the file magic.
+pub const S3M_MAGIC_OFFSET: usize = 0x2C;
+
+/// Size of the fixed header.
+pub const S3M_HEADER_SIZE: usize = 0x60;
+
+/// Tracker version written to the header (Scream Tracker 3.20).
+pub const S3M_TRACKER_VERSION: u16 = 0x1320;
+
+/// Maximum number of PCM channels.
+pub const S3M_MAX_CHANNELS: u8 = 16;
+
+/// Maximum number of patterns.
+pub const S3M_MAX_PATTERNS: usize = 100;
+
+/// Maximum number of order list entries.
+pub const S3M_MAX_ORDERS: usize = 256;
+
+/// Order list end marker.
+const ORDER_END: u8 = 255;
+
+/// Scream Tracker 3 module containing all song data.
+#[derive(Debug, Clone)]
+pub struct S3mModule {
+    /// Song title (27 characters max).
+    pub title: String,
+    /// Number of channels (1-16).
+    pub num_channels: u8,
+    /// Initial speed (ticks per row).
+    pub speed: u8,
+    /// Initial tempo (BPM).
+    pub tempo: u8,
+    /// Global volume (0-64).
+    pub global_volume: u8,
+    /// Sample instruments (up to 99).
+    pub samples: Vec<S3mSample>,
+    /// Patterns (up to 100).
+    pub patterns: Vec<S3mPattern>,
+    /// Pattern order list.
+    pub orders: Vec<u8>,
+}
+
+impl S3mModule {
+    /// Create a new S3M module with the given parameters.
+    pub fn new(title: &str, num_channels: u8, speed: u8, tempo: u8) -> Self {
+        Self {
+            title: title.to_string(),
+            num_channels,
+            speed,
+            tempo,
+            global_volume: 64,
+            samples: Vec::new(),
+            patterns: Vec::new(),
+            orders: Vec::new(),
+        }
+    }
+
+    /// Add a sample instrument to the module.
+    pub fn add_sample(&mut self, sample: S3mSample) {
+        self.samples.push(sample);
+    }
+
+    /// Add a pattern to the module.
+    pub fn add_pattern(&mut self, pattern: S3mPattern) {
+        self.patterns.push(pattern);
+    }
+
+    /// Set the order list (pattern playback order).
+    pub fn set_order_table(&mut self, orders: &[u8]) {
+        self.orders = orders.to_vec();
+    }
+
+    /// Write the complete S3M module to a writer.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        if self.num_channels == 0 || self.num_channels > S3M_MAX_CHANNELS {
+            return Err(invalid_input(format!(
+                "S3M supports 1-{} channels, got {}",
+                S3M_MAX_CHANNELS, self.num_channels
+            )));
+        }
+        if self.samples.len() > S3M_MAX_INSTRUMENTS {
+            return Err(invalid_input(format!(
+                "S3M supports at most {} instruments, got {}",
+                S3M_MAX_INSTRUMENTS,
+                self.samples.len()
+            )));
+        }
+        if self.patterns.len() > S3M_MAX_PATTERNS {
+            return Err(invalid_input(format!(
+                "S3M supports at most {} patterns, got {}",
+                S3M_MAX_PATTERNS,
+                self.patterns.len()
+            )));
+        }
+        if self.orders.is_empty() || self.orders.len() > S3M_MAX_ORDERS - 1 {
+            return Err(invalid_input(format!(
+                "S3M order list must have 1-{} entries, got {}",
+                S3M_MAX_ORDERS - 1,
+                self.orders.len()
+            )));
+        }
+        if let Some(&order) = self
+            .orders
+            .iter()
+            .find(|&&o| o as usize >= self.patterns.len())
+        {
+            return Err(invalid_input(format!(
+                "S3M order list references missing pattern {}",
+                order
+            )));
+        }
+
+        // Order list: end marker appended, padded to an even length
+        let mut orders = self.orders.clone();
+        orders.push(ORDER_END);
+        if orders.len() % 2 == 1 {
+            orders.push(ORDER_END);
+        }
+
+        // Lay out every block before writing so parapointers are known
+        let pointer_table_end =
+            S3M_HEADER_SIZE + orders.len() + 2 * (self.samples.len() + self.patterns.len());
+        let mut offset = align16(pointer_table_end);
+
+        let instrument_offsets: Vec<usize> = (0..self.samples.len())
+            .map(|i| offset + i * S3M_INSTRUMENT_HEADER_SIZE)
+            .collect();
+        offset += self.samples.len() * S3M_INSTRUMENT_HEADER_SIZE;
+
+        let packed_patterns: Vec<Vec<u8>> = self
+            .patterns
+            .iter()
+            .map(|p| {
+                let mut bytes = Vec::new();
+                p.write(&mut bytes).map(|_| bytes)
+            })
+            .collect::<io::Result<_>>()?;
+        let mut pattern_offsets = Vec::with_capacity(packed_patterns.len());
+        for packed in &packed_patterns {
+            offset = align16(offset);
+            pattern_offsets.push(offset);
+            offset += packed.len();
+        }
+
+        let mut sample_offsets = Vec::with_capacity(self.samples.len());
+        for sample in &self.samples {
+            offset = align16(offset);
+            sample_offsets.push(offset);
+            offset += sample.data.len();
+        }
+
+        let parapointer = |offset: usize| -> io::Result<u16> {
+            u16::try_from(offset / 16).map_err(|_| {
+                invalid_input(format!(
+                    "S3M block at offset {} is beyond parapointer range",
+                    offset
+                ))
+            })
+        };
+
+        // Header
+        let mut title_buf = [0u8; 28];
+        let title_bytes = self.title.as_bytes();
+        let copy_len = title_bytes.len().min(27);
+        title_buf[..copy_len].copy_from_slice(&title_bytes[..copy_len]);
+        writer.write_all(&title_buf)?;
+        writer.write_all(&[0x1A, 16, 0, 0])?; // EOF marker, type (module), reserved
+        writer.write_u16::<LittleEndian>(orders.len() as u16)?;
+        writer.write_u16::<LittleEndian>(self.samples.len() as u16)?;
+        writer.write_u16::<LittleEndian>(self.patterns.len() as u16)?;
+        writer.write_u16::<LittleEndian>(0)?; // flags
+        writer.write_u16::<LittleEndian>(S3M_TRACKER_VERSION)?;
+        writer.write_u16::<LittleEndian>(2)?; // samples are unsigned
+        writer.write_all(S3M_MAGIC)?;
+        writer.write_u8(self.global_volume.min(64))?;
+        writer.write_u8(self.speed)?;
+        writer.write_u8(self.tempo)?;
+        writer.write_u8(0x80 | 48)?; // stereo, master volume 48
+        writer.write_u8(0)?; // ultra click removal
+        writer.write_u8(0)?; // no default pan table
+        writer.write_all(&[0u8; 8])?; // reserved
+        writer.write_u16::<LittleEndian>(0)?; // special
+
+        // Channel settings: alternate left (0-7) and right (8-15), rest unused
+        for channel in 0..32u8 {
+            let setting = if channel < self.num_channels {
+                if channel % 2 == 0 {
+                    channel / 2
+                } else {
+                    8 + channel / 2
+                }
+            } else {
+                255
+            };
+            writer.write_u8(setting)?;
+        }
+
+        writer.write_all(&orders)?;
+        for &offset in instrument_offsets.iter().chain(&pattern_offsets) {
+            writer.write_u16::<LittleEndian>(parapointer(offset)?)?;
+        }
+
+        let mut position = pointer_table_end;
+        for (i, sample) in self.samples.iter().enumerate() {
+            position = write_padding(writer, position, instrument_offsets[i])?;
+            sample.write_header(writer, sample_offsets[i])?;
+            position += S3M_INSTRUMENT_HEADER_SIZE;
+        }
+        for (packed, &pattern_offset) in packed_patterns.iter().zip(&pattern_offsets) {
+            position = write_padding(writer, position, pattern_offset)?;
+            writer.write_all(packed)?;
+            position += packed.len();
+        }
+        for (sample, &data_offset) in self.samples.iter().zip(&sample_offsets) {
+            position = write_padding(writer, position, data_offset)?;
+            writer.write_all(&sample.data)?;
+            position += sample.data.len();
+        }
+
+        Ok(())
+    }
+
+    /// Write the module to a byte vector.
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.write(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Compute the BLAKE3 hash of the module bytes.
+    pub fn compute_hash(&self) -> io::Result<String> {
+        let bytes = self.to_bytes()?;
+        Ok(blake3::hash(&bytes).to_hex().to_string())
+    }
+}
+
+/// Zero-fill from `position` up to `target`, returning `target`.
+fn write_padding<W: Write>(writer: &mut W, position: usize, target: usize) -> io::Result<usize> {
+    writer.write_all(&vec![0u8; target - position])?;
+    Ok(target)
+}
+
+fn align16(offset: usize) -> usize {
+    (offset + 15) & !15
+}
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+/// Validate an S3M file's header markers and magic.
+pub fn validate_s3m_bytes(data: &[u8]) -> Result<(), S3mValidationError> {
+    if data.len() < S3M_HEADER_SIZE {
+        return Err(S3mValidationError::FileTooSmall(data.len()));
+    }
+
+    if &data[S3M_MAGIC_OFFSET..S3M_MAGIC_OFFSET + 4] != S3M_MAGIC {
+        return Err(S3mValidationError::InvalidMagic);
+    }
+
+    if data[0x1C] != 0x1A || data[0x1D] != 16 {
+        return Err(S3mValidationError::InvalidFileType(data[0x1D]));
+    }
+
+    let num_orders = u16::from_le_bytes([data[0x20], data[0x21]]) as usize;
+    let num_instruments = u16::from_le_bytes([data[0x22], data[0x23]]) as usize;
+    let num_patterns = u16::from_le_bytes([data[0x24], data[0x25]]) as usize;
+    let expected = S3M_HEADER_SIZE + num_orders + 2 * (num_instruments + num_patterns);
+    if data.len() < expected {
+        return Err(S3mValidationError::Truncated {
+            expected,
+            actual: data.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// S3M validation error.
+#[derive(Debug, Clone)]
+pub enum S3mValidationError {
+    /// File is too small to hold an S3M header.
+    FileTooSmall(usize),
+    /// Missing "SCRM" magic.
+    InvalidMagic,
+    /// Header does not describe an S3M module.
+    InvalidFileType(u8),
+    /// File is shorter than its order list and parapointers require.
+    Truncated { expected: usize, actual: usize },
+}
+
+impl std::fmt::Display for S3mValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            S3mValidationError::FileTooSmall(size) => {
+                write!(f, "File too small: {} bytes", size)
+            }
+            S3mValidationError::InvalidMagic => {
+                write!(f, "Invalid S3M magic identifier")
+            }
+            S3mValidationError::InvalidFileType(file_type) => {
+                write!(f, "Invalid S3M file type: {}", file_type)
+            }
+            S3mValidationError::Truncated { expected, actual } => {
+                write!(
+                    f,
+                    "S3M file truncated: expected at least {} bytes, got {}",
+                    expected, actual
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for S3mValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::s3m::pattern::S3mNote;
+
+    fn test_module() -> S3mModule {
+        let mut module = S3mModule::new("Test Song", 4, 6, 125);
+        let pcm: Vec<u8> = (0..100i16).flat_map(|i| (i * 300).to_le_bytes()).collect();
+        module.add_sample(S3mSample::from_pcm16("Lead", &pcm, 22050));
+
+        let mut pattern = S3mPattern::empty(4);
+        pattern.set_note(0, 0, S3mNote::from_midi(60, 1, None).unwrap());
+        module.add_pattern(pattern);
+        module.set_order_table(&[0, 0]);
+        module
+    }
+
+    fn parapointer(bytes: &[u8], index: usize) -> usize {
+        let num_orders = u16::from_le_bytes([bytes[0x20], bytes[0x21]]) as usize;
+        let offset = S3M_HEADER_SIZE + num_orders + 2 * index;
+        u16::from_le_bytes([bytes[offset], bytes[offset + 1]]) as usize * 16
+    }
+
+    #[test]
+    fn test_module_layout() {
+        let bytes = test_module().to_bytes().unwrap();
+        assert!(validate_s3m_bytes(&bytes).is_ok());
+
+        assert_eq!(&bytes[..9], b"Test Song");
+        // Two orders plus end marker, padded to even
+        assert_eq!(&bytes[0x20..0x26], &[4, 0, 1, 0, 1, 0]);
+        assert_eq!(&bytes[0x60..0x64], &[0, 0, 255, 255]);
+        assert_eq!(&bytes[0x31..0x33], &[6, 125]);
+        assert_eq!(&bytes[0x40..0x45], &[0, 8, 1, 9, 255]);
+
+        let instrument = parapointer(&bytes, 0);
+        assert_eq!(&bytes[instrument + 0x4C..instrument + 0x50], b"SCRS");
+
+        let pattern = parapointer(&bytes, 1);
+        let packed_len = u16::from_le_bytes([bytes[pattern], bytes[pattern + 1]]) as usize;
+        assert_eq!(&bytes[pattern + 2..pattern + 6], &[0x20, 0x40, 1, 0]);
+
+        let memseg = (bytes[instrument + 0x0D] as usize) << 16
+            | u16::from_le_bytes([bytes[instrument + 0x0E], bytes[instrument + 0x0F]]) as usize;
+        let data = memseg * 16;
+        assert!(data >= pattern + packed_len);
+        assert_eq!(bytes.len(), data + 100);
+    }
+
+    #[test]
+    fn test_write_rejects_too_many_channels() {
+        let mut module = test_module();
+        module.num_channels = 17;
+        assert!(module.to_bytes().is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_magic() {
+        let mut bytes = test_module().to_bytes().unwrap();
+        bytes[S3M_MAGIC_OFFSET] = b'X';
+        assert!(matches!(
+            validate_s3m_bytes(&bytes),
+            Err(S3mValidationError::InvalidMagic)
+        ));
+        assert!(validate_s3m_bytes(&bytes[..0x40]).is_err());
+    }
+
+    #[test]
+    fn test_hash_determinism() {
+        let hash1 = test_module().compute_hash().unwrap();
+        let hash2 = test_module().compute_hash().unwrap();
+        assert_eq!(hash1, hash2);
+    }
+}
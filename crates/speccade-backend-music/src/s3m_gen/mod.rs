@@ -0,0 +1,429 @@
+//! S3M (Scream Tracker 3) format generation.
+//!
+//! This module handles all S3M-specific generation logic including:
+//! - Module creation and configuration
+//! - Sample generation, tuned through each sample's C2SPD
+//! - Pattern conversion, padding short patterns to S3M's fixed 64 rows
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use speccade_spec::recipe::music::{
+    parse_effect_name, MusicTrackerSongV1Params, TrackerFormat, TrackerInstrument, TrackerPattern,
+};
+
+use crate::generate::{
+    bake_instrument_sample, resolve_pattern_note_name, GenerateError, GenerateResult,
+    MusicInstrumentLoopReport, MusicLoopReport,
+};
+use crate::note::{note_name_to_it, DEFAULT_SYNTH_MIDI_NOTE};
+use crate::s3m::{
+    effects as s3m_effects, validate_s3m_bytes, S3mModule, S3mNote, S3mPattern, S3mSample,
+    S3M_MAX_CHANNELS, S3M_MAX_INSTRUMENTS, S3M_MAX_ORDERS, S3M_MAX_PATTERNS, S3M_PATTERN_ROWS,
+};
+
+/// Generate an S3M module from params.
+///
+/// Creates a complete S3M tracker module including samples, patterns,
+/// and the order list from the arrangement.
+///
+/// # Arguments
+/// * `params` - Music tracker song parameters
+/// * `seed` - Base seed for deterministic synthesis
+/// * `spec_dir` - Directory for resolving relative sample paths
+///
+/// # Returns
+/// Generated S3M module bytes with hash
+pub fn generate_s3m(
+    params: &MusicTrackerSongV1Params,
+    seed: u32,
+    spec_dir: &Path,
+) -> Result<GenerateResult, GenerateError> {
+    // Validate parameters
+    validate_s3m_params(params)?;
+
+    // Create module
+    let mut module = S3mModule::new(
+        "SpecCade Song",
+        params.channels,
+        params.speed,
+        params.bpm as u8,
+    );
+
+    // Generate samples
+    let mut instrument_loop_reports = Vec::with_capacity(params.instruments.len());
+    for (idx, instr) in params.instruments.iter().enumerate() {
+        let (sample, loop_report) = generate_s3m_sample(instr, seed, idx as u32, spec_dir)?;
+        module.add_sample(sample);
+        instrument_loop_reports.push(loop_report);
+    }
+
+    // Build pattern index map
+    let mut pattern_index_map: HashMap<String, u8> = HashMap::new();
+
+    // Determinism: `patterns` is a HashMap, so we must iterate in a stable order.
+    let mut pattern_names: Vec<String> = params.patterns.keys().cloned().collect();
+    pattern_names.sort();
+
+    let mut pattern_rows = Vec::with_capacity(pattern_names.len());
+    for (pattern_idx, name) in pattern_names.iter().enumerate() {
+        let pattern = params
+            .patterns
+            .get(name)
+            .ok_or_else(|| GenerateError::PatternNotFound(name.clone()))?;
+        let s3m_pattern = convert_pattern_to_s3m(pattern, params.channels, &params.instruments)?;
+        module.add_pattern(s3m_pattern);
+        pattern_rows.push(pattern.rows);
+        pattern_index_map.insert(name.clone(), pattern_idx as u8);
+    }
+
+    // Build order list from arrangement
+    let mut order_table = Vec::new();
+    for entry in &params.arrangement {
+        let pattern_idx = pattern_index_map
+            .get(&entry.pattern)
+            .ok_or_else(|| GenerateError::PatternNotFound(entry.pattern.clone()))?;
+        for _ in 0..entry.repeat {
+            order_table.push(*pattern_idx);
+        }
+    }
+    if order_table.is_empty() {
+        order_table.push(0);
+    }
+    if order_table.len() > S3M_MAX_ORDERS - 1 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "S3M arrangement must expand to at most {} orders, got {}",
+            S3M_MAX_ORDERS - 1,
+            order_table.len()
+        )));
+    }
+    if module.patterns.is_empty() {
+        module.add_pattern(S3mPattern::empty(params.channels));
+        pattern_rows.push(S3M_PATTERN_ROWS);
+    }
+    module.set_order_table(&order_table);
+
+    // S3M loop behavior: insert a terminal position jump in the last order entry.
+    if params.r#loop {
+        let restart = params.restart_position.unwrap_or(0);
+        if restart as usize >= order_table.len() {
+            return Err(GenerateError::InvalidParameter(format!(
+                "restart_position {} is out of range for arrangement length {}",
+                restart,
+                order_table.len()
+            )));
+        }
+        let last_pattern = *order_table.last().unwrap_or(&0);
+        let last_row = pattern_rows[last_pattern as usize] - 1;
+        apply_s3m_loop_jump(
+            &mut module,
+            last_pattern,
+            last_row,
+            restart as u8,
+            params.channels,
+        )?;
+    }
+
+    // Generate bytes
+    let data = module.to_bytes()?;
+    validate_s3m_bytes(&data).map_err(|e| {
+        GenerateError::FormatValidation(format!("generated S3M failed validation: {}", e))
+    })?;
+    let hash = blake3::hash(&data).to_hex().to_string();
+
+    Ok(GenerateResult {
+        data,
+        hash,
+        extension: "s3m",
+        loop_report: Some(MusicLoopReport {
+            extension: "s3m".to_string(),
+            instruments: instrument_loop_reports,
+        }),
+    })
+}
+
+/// Validate S3M-specific parameters.
+fn validate_s3m_params(params: &MusicTrackerSongV1Params) -> Result<(), GenerateError> {
+    if params.channels < 1 || params.channels > S3M_MAX_CHANNELS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "S3M supports 1-{} channels, got {}",
+            S3M_MAX_CHANNELS, params.channels
+        )));
+    }
+    if params.bpm < 32 || params.bpm > 255 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "bpm must be 32-255, got {}",
+            params.bpm
+        )));
+    }
+    if params.speed < 1 || params.speed > 31 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "speed must be 1-31, got {}",
+            params.speed
+        )));
+    }
+    if params.instruments.len() > S3M_MAX_INSTRUMENTS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "S3M supports at most {} instruments, got {}",
+            S3M_MAX_INSTRUMENTS,
+            params.instruments.len()
+        )));
+    }
+    if params.patterns.len() > S3M_MAX_PATTERNS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "S3M supports at most {} patterns, got {}",
+            S3M_MAX_PATTERNS,
+            params.patterns.len()
+        )));
+    }
+    if !params.automation.is_empty() {
+        return Err(GenerateError::InvalidParameter(
+            "automation is not supported for S3M output".to_string(),
+        ));
+    }
+    if let Some(restart_position) = params.restart_position {
+        if restart_position > u8::MAX as u16 {
+            return Err(GenerateError::InvalidParameter(format!(
+                "S3M restart_position must be 0-255, got {}",
+                restart_position
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Generate an S3M sample from spec.
+///
+/// S3M tunes each sample by its C2SPD, the playback rate for middle C (C-4).
+/// Envelopes are not representable in S3M and are ignored.
+pub(crate) fn generate_s3m_sample(
+    instr: &TrackerInstrument,
+    base_seed: u32,
+    index: u32,
+    spec_dir: &Path,
+) -> Result<(S3mSample, MusicInstrumentLoopReport), GenerateError> {
+    let (baked, loop_report) =
+        bake_instrument_sample(instr, base_seed, index, spec_dir, TrackerFormat::S3m)?;
+
+    let semitone_diff = DEFAULT_SYNTH_MIDI_NOTE as f64 - baked.base_midi as f64;
+    let ideal_c2spd = baked.sample_rate as f64 * 2.0_f64.powf(semitone_diff / 12.0);
+    let c2spd = ideal_c2spd.round().max(1.0) as u32;
+    let pitch_cents = 1200.0 * (c2spd as f64 / ideal_c2spd).log2();
+
+    let mut sample = S3mSample::from_pcm16(&instr.name, &baked.pcm16_mono, c2spd);
+
+    // S3M only has forward loops; ping-pong regions loop forward.
+    if let Some(loop_region) = baked.loop_region {
+        sample = sample.with_loop(loop_region.start, loop_region.end);
+    }
+
+    // Set default volume
+    sample.volume = instr.default_volume.unwrap_or(64).min(64);
+
+    let mut loop_report = loop_report;
+    loop_report.pitch_deviation_cents = Some(pitch_cents);
+
+    Ok((sample, loop_report))
+}
+
+/// Convert a pattern from spec to S3M format.
+///
+/// Patterns shorter than 64 rows end with a pattern break on their last row.
+pub(crate) fn convert_pattern_to_s3m(
+    pattern: &TrackerPattern,
+    num_channels: u8,
+    instruments: &[TrackerInstrument],
+) -> Result<S3mPattern, GenerateError> {
+    if pattern.rows == 0 || pattern.rows > S3M_PATTERN_ROWS {
+        return Err(GenerateError::InvalidParameter(format!(
+            "S3M patterns must have 1-{} rows, got {}",
+            S3M_PATTERN_ROWS, pattern.rows
+        )));
+    }
+
+    let mut s3m_pattern = S3mPattern::empty(num_channels);
+
+    for (channel, note) in pattern.flat_notes() {
+        if channel >= num_channels {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern note channel {} exceeds configured channel count {}",
+                channel, num_channels
+            )));
+        }
+        if note.row >= pattern.rows {
+            return Err(GenerateError::InvalidParameter(format!(
+                "pattern note row {} is out of range for pattern rows {}",
+                note.row, pattern.rows
+            )));
+        }
+        if let Some(vol) = note.vol {
+            if vol > 64 {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern note volume {} out of range (0-64) at row {}, channel {}",
+                    vol, note.row, channel
+                )));
+            }
+        }
+
+        let note_name = resolve_pattern_note_name(note, instruments, "C4")?;
+        let note_name = note_name.as_ref();
+
+        let s3m_note = if note_name == "OFF" || note_name == "===" || note_name == "^^^" {
+            // S3M has no note-off; `^^` cuts the note.
+            S3mNote::note_cut()
+        } else {
+            if note.inst as usize >= instruments.len() {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern references instrument {} but only {} instrument(s) are defined",
+                    note.inst,
+                    instruments.len()
+                )));
+            }
+            let instrument_column = note.inst + 1; // S3M instruments are 1-indexed
+
+            let mut n = if note_name == "---" || note_name == "..." {
+                S3mNote {
+                    instrument: instrument_column,
+                    volume: note.vol,
+                    ..S3mNote::empty()
+                }
+            } else {
+                let midi = note_name_to_it(note_name) as u16 + 12;
+                u8::try_from(midi)
+                    .ok()
+                    .and_then(|midi| S3mNote::from_midi(midi, instrument_column, note.vol))
+                    .ok_or_else(|| {
+                        GenerateError::InvalidParameter(format!(
+                            "note '{}' at row {}, channel {} is outside the S3M note range (C0-B7)",
+                            note_name, note.row, channel
+                        ))
+                    })?
+            };
+
+            if note.effect.is_some() && note.effect_name.is_some() {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern note at row {}, channel {} must set either 'effect' or 'effect_name', not both",
+                    note.row, channel
+                )));
+            }
+
+            if let Some([x, y]) = note.effect_xy {
+                if x > 0x0F || y > 0x0F {
+                    return Err(GenerateError::InvalidParameter(format!(
+                        "pattern note effect_xy [{}, {}] out of range (each nibble must be 0-15) at row {}, channel {}",
+                        x, y, note.row, channel
+                    )));
+                }
+            }
+
+            // Apply effect if present.
+            if let Some(effect_code) = note.effect {
+                let param = if let Some([x, y]) = note.effect_xy {
+                    (x << 4) | (y & 0x0F)
+                } else {
+                    note.param.unwrap_or(0)
+                };
+                n = n.with_effect(effect_code, param);
+            } else if let Some(ref effect_name) = note.effect_name {
+                let typed_effect = parse_effect_name(effect_name, note.param, note.effect_xy)
+                    .ok_or_else(|| {
+                        GenerateError::InvalidParameter(format!(
+                            "unknown effect_name '{}' at row {}, channel {}",
+                            effect_name, note.row, channel
+                        ))
+                    })?;
+
+                typed_effect.validate_s3m().map_err(|e| {
+                    GenerateError::InvalidParameter(format!(
+                        "invalid S3M effect '{}' at row {}, channel {}: {}",
+                        effect_name, note.row, channel, e
+                    ))
+                })?;
+
+                let (code, param) = typed_effect.to_it().ok_or_else(|| {
+                    GenerateError::InvalidParameter(format!(
+                        "effect '{}' is not supported in S3M at row {}, channel {}",
+                        effect_name, note.row, channel
+                    ))
+                })?;
+                n = n.with_effect(code, param);
+            }
+
+            n
+        };
+
+        s3m_pattern.set_note(note.row, channel, s3m_note);
+    }
+
+    // End short patterns early with a pattern break on their last row.
+    if pattern.rows < S3M_PATTERN_ROWS {
+        let last_row = pattern.rows - 1;
+        let channel = (0..num_channels)
+            .find(|&ch| {
+                s3m_pattern
+                    .get_note(last_row, ch)
+                    .is_some_and(|n| n.effect == 0)
+            })
+            .ok_or_else(|| {
+                GenerateError::InvalidParameter(format!(
+                    "cannot end {}-row S3M pattern: no channel available on row {} for a pattern break",
+                    pattern.rows, last_row
+                ))
+            })?;
+        let note = s3m_pattern
+            .get_note(last_row, channel)
+            .copied()
+            .unwrap_or_default();
+        s3m_pattern.set_note(
+            last_row,
+            channel,
+            note.with_effect(s3m_effects::PATTERN_BREAK, 0),
+        );
+    }
+
+    Ok(s3m_pattern)
+}
+
+/// Place a position jump on the last played row of the final pattern.
+///
+/// A pattern break inserted for a short pattern is replaced if no other
+/// channel is free, since the jump also ends the pattern.
+fn apply_s3m_loop_jump(
+    module: &mut S3mModule,
+    pattern_idx: u8,
+    row: u16,
+    restart: u8,
+    num_channels: u8,
+) -> Result<(), GenerateError> {
+    let pattern = module
+        .patterns
+        .get_mut(pattern_idx as usize)
+        .ok_or_else(|| {
+            GenerateError::InvalidParameter(format!(
+                "cannot apply S3M loop: order table references missing pattern index {}",
+                pattern_idx
+            ))
+        })?;
+
+    let is_break = |n: &S3mNote| n.effect == s3m_effects::PATTERN_BREAK && n.effect_param == 0;
+    let channel = (0..num_channels)
+        .find(|&ch| pattern.get_note(row, ch).is_some_and(|n| n.effect == 0))
+        .or_else(|| (0..num_channels).find(|&ch| pattern.get_note(row, ch).is_some_and(is_break)))
+        .ok_or_else(|| {
+            GenerateError::InvalidParameter(format!(
+                "cannot apply S3M loop: no channel available on last row {} (all {} channel(s) already use effects)",
+                row, num_channels
+            ))
+        })?;
+
+    let note = pattern.get_note(row, channel).copied().unwrap_or_default();
+    pattern.set_note(
+        row,
+        channel,
+        note.with_effect(s3m_effects::POSITION_JUMP, restart),
+    );
+    Ok(())
+}
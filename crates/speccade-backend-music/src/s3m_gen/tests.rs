@@ -0,0 +1,166 @@
+//! Tests for S3M generation module.
+
+use super::*;
+use std::collections::HashMap;
+use std::path::Path;
+
+use speccade_spec::recipe::audio::Envelope;
+use speccade_spec::recipe::music::{
+    ArrangementEntry, InstrumentSynthesis, PatternNote, TrackerFormat,
+};
+
+use crate::s3m::{S3M_MAGIC, S3M_MAGIC_OFFSET};
+
+fn create_test_params() -> MusicTrackerSongV1Params {
+    let instrument = TrackerInstrument {
+        name: "Test Lead".to_string(),
+        synthesis: Some(InstrumentSynthesis::Pulse {
+            duty_cycle: 0.5,
+            base_note: None,
+        }),
+        envelope: Envelope {
+            attack: 0.01,
+            decay: 0.1,
+            sustain: 0.5,
+            release: 0.2,
+        },
+        default_volume: Some(64),
+        ..Default::default()
+    };
+
+    let mut notes = HashMap::new();
+    notes.insert(
+        "0".to_string(),
+        vec![
+            PatternNote {
+                row: 0,
+                note: "C4".to_string(),
+                inst: 0,
+                vol: Some(48),
+                ..Default::default()
+            },
+            PatternNote {
+                row: 4,
+                note: "OFF".to_string(),
+                inst: 0,
+                ..Default::default()
+            },
+        ],
+    );
+    let pattern = TrackerPattern {
+        rows: 16,
+        notes: Some(notes),
+        data: None,
+    };
+
+    let mut patterns = HashMap::new();
+    patterns.insert("intro".to_string(), pattern);
+
+    MusicTrackerSongV1Params {
+        format: TrackerFormat::S3m,
+        bpm: 120,
+        speed: 6,
+        channels: 8,
+        r#loop: true,
+        instruments: vec![instrument],
+        patterns,
+        arrangement: vec![ArrangementEntry {
+            pattern: "intro".to_string(),
+            repeat: 1,
+        }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_generate_s3m() {
+    let params = create_test_params();
+    let result = generate_s3m(&params, 42, Path::new(".")).unwrap();
+
+    assert_eq!(result.extension, "s3m");
+    assert_eq!(result.hash.len(), 64);
+    assert_eq!(
+        &result.data[S3M_MAGIC_OFFSET..S3M_MAGIC_OFFSET + 4],
+        S3M_MAGIC
+    );
+    // Initial speed and tempo live in the header.
+    assert_eq!(result.data[0x31], 6);
+    assert_eq!(result.data[0x32], 120);
+}
+
+#[test]
+fn test_generate_s3m_is_byte_deterministic() {
+    let params = create_test_params();
+    let first = generate_s3m(&params, 42, Path::new(".")).unwrap();
+    let second = generate_s3m(&params, 42, Path::new(".")).unwrap();
+
+    assert_eq!(first.data, second.data);
+    assert_eq!(first.hash, second.hash);
+    assert_eq!(first.hash, blake3::hash(&first.data).to_hex().to_string());
+}
+
+#[test]
+fn test_s3m_rejects_more_than_16_channels() {
+    let mut params = create_test_params();
+    params.channels = 17;
+
+    let err = generate_s3m(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("S3M supports 1-16 channels"));
+}
+
+#[test]
+fn test_s3m_rejects_patterns_longer_than_64_rows() {
+    let mut params = create_test_params();
+    params.patterns.get_mut("intro").unwrap().rows = 65;
+
+    let err = generate_s3m(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("S3M patterns must have 1-64 rows"));
+}
+
+#[test]
+fn test_s3m_pattern_conversion() {
+    let params = create_test_params();
+    let pattern = params.patterns.get("intro").unwrap();
+    let s3m_pattern = convert_pattern_to_s3m(pattern, 8, &params.instruments).unwrap();
+
+    let first = s3m_pattern.get_note(0, 0).unwrap();
+    assert_eq!(first.note, 0x40);
+    assert_eq!(first.instrument, 1);
+    assert_eq!(first.volume, Some(48));
+
+    assert_eq!(s3m_pattern.get_note(4, 0).unwrap(), &S3mNote::note_cut());
+    assert_eq!(
+        s3m_pattern.get_note(15, 0).unwrap().effect,
+        s3m_effects::PATTERN_BREAK
+    );
+}
+
+#[test]
+fn test_s3m_sample_c2spd_tracks_base_note() {
+    let params = create_test_params();
+    let (sample, report) =
+        generate_s3m_sample(&params.instruments[0], 42, 0, Path::new(".")).unwrap();
+
+    // Base note C4 is middle C, so C2SPD equals the baked sample rate.
+    assert_eq!(sample.c2spd, report.sample_rate);
+    assert_eq!(report.pitch_deviation_cents, Some(0.0));
+}
+
+#[test]
+fn test_s3m_rejects_it_only_effect_name() {
+    let mut params = create_test_params();
+    let note = &mut params
+        .patterns
+        .get_mut("intro")
+        .unwrap()
+        .notes
+        .as_mut()
+        .unwrap()
+        .get_mut("0")
+        .unwrap()[0];
+    note.effect_name = Some("set_volume".to_string());
+    note.param = Some(32);
+
+    let err = generate_s3m(&params, 42, Path::new(".")).err().unwrap();
+    assert!(err.to_string().contains("S3M"));
+}
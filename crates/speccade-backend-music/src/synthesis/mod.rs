@@ -4,6 +4,7 @@
 //! All synthesis is deterministic given the same parameters and seed.
 
 mod envelope;
+mod stereo;
 mod utils;
 mod wav;
 mod waveforms;
@@ -16,8 +17,9 @@ use std::f64::consts::PI;
 use crate::note::midi_to_freq;
 
 // Re-export public items
-pub use utils::derive_instrument_seed;
-pub use wav::load_wav_sample;
+pub use stereo::decorrelated_stereo_pair;
+pub use utils::{derive_instrument_seed, derive_zone_seed};
+pub use wav::{load_wav_sample, load_wav_sample_resampled};
 
 // Use items from submodules
 use envelope::apply_envelope;
@@ -0,0 +1,142 @@
+//! Deterministic stereo decorrelation for tracker samples.
+
+use rand::Rng;
+
+use super::utils::create_rng;
+
+/// Side-signal level relative to the mono (mid) signal.
+const STEREO_WIDTH: f64 = 0.5;
+
+/// All-pass feedback gain.
+const ALLPASS_GAIN: f64 = 0.5;
+
+/// Delay ranges (in samples) of the all-pass stages, one per stage.
+const ALLPASS_DELAY_RANGES: [(usize, usize); 3] = [(37, 113), (131, 257), (263, 521)];
+
+/// Split a mono PCM16 sample into a decorrelated left/right pair.
+///
+/// The side signal is the mono signal run through a chain of all-pass filters whose delays
+/// are drawn from `seed`, so it keeps the mono spectrum but not its phase. Left and right are
+/// mid plus and minus side; their average is the (possibly rescaled) mono signal, so the pair
+/// stays mono-compatible. Both channels keep the input length, so loop points carry over.
+///
+/// Returns `(left, right)` as 16-bit signed little-endian PCM bytes.
+pub fn decorrelated_stereo_pair(pcm16_mono: &[u8], seed: u32) -> (Vec<u8>, Vec<u8>) {
+    let mid: Vec<f64> = pcm16_mono
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]) as f64 / 32767.0)
+        .collect();
+
+    let mut rng = create_rng(seed);
+    let mut side = mid.clone();
+    for (min, max) in ALLPASS_DELAY_RANGES {
+        side = allpass(&side, rng.gen_range(min..=max), ALLPASS_GAIN);
+    }
+
+    let mut left: Vec<f64> = mid
+        .iter()
+        .zip(&side)
+        .map(|(m, s)| m + STEREO_WIDTH * s)
+        .collect();
+    let mut right: Vec<f64> = mid
+        .iter()
+        .zip(&side)
+        .map(|(m, s)| m - STEREO_WIDTH * s)
+        .collect();
+
+    // Widening can push peaks above the mono level; scale back down rather than clip.
+    let peak = |samples: &[f64]| samples.iter().fold(0.0f64, |acc, s| acc.max(s.abs()));
+    let mono_peak = peak(&mid);
+    let stereo_peak = peak(&left).max(peak(&right));
+    if stereo_peak > mono_peak && stereo_peak > 0.0 {
+        let gain = mono_peak / stereo_peak;
+        left.iter_mut()
+            .chain(right.iter_mut())
+            .for_each(|s| *s *= gain);
+    }
+
+    (samples_to_pcm16(&left), samples_to_pcm16(&right))
+}
+
+/// Schroeder all-pass filter: flat magnitude response, frequency-dependent phase.
+fn allpass(input: &[f64], delay: usize, gain: f64) -> Vec<f64> {
+    let mut output = vec![0.0; input.len()];
+    for i in 0..input.len() {
+        let delayed_in = if i >= delay { input[i - delay] } else { 0.0 };
+        let delayed_out = if i >= delay { output[i - delay] } else { 0.0 };
+        output[i] = -gain * input[i] + delayed_in + gain * delayed_out;
+    }
+    output
+}
+
+/// Convert samples to PCM16 bytes, rounding (unlike the truncating mono path) so a
+/// silent or unchanged input survives the round trip exactly.
+fn samples_to_pcm16(samples: &[f64]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|s| ((s.clamp(-1.0, 1.0) * 32767.0).round() as i16).to_le_bytes())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noise_pcm(len: usize) -> Vec<u8> {
+        let mut rng = create_rng(7);
+        (0..len)
+            .flat_map(|_| (((rng.gen::<f64>() * 2.0 - 1.0) * 16000.0).round() as i16).to_le_bytes())
+            .collect()
+    }
+
+    fn to_f64(pcm: &[u8]) -> Vec<f64> {
+        pcm.chunks_exact(2)
+            .map(|b| i16::from_le_bytes([b[0], b[1]]) as f64)
+            .collect()
+    }
+
+    fn correlation(a: &[f64], b: &[f64]) -> f64 {
+        let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm_a: f64 = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let norm_b: f64 = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+        dot / (norm_a * norm_b)
+    }
+
+    #[test]
+    fn test_pair_is_deterministic_and_seeded() {
+        let mono = noise_pcm(4096);
+        let a = decorrelated_stereo_pair(&mono, 1);
+        let b = decorrelated_stereo_pair(&mono, 1);
+        let c = decorrelated_stereo_pair(&mono, 2);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.0.len(), mono.len());
+        assert_eq!(a.1.len(), mono.len());
+    }
+
+    #[test]
+    fn test_pair_is_decorrelated_and_mono_compatible() {
+        let mono = noise_pcm(8192);
+        let (left, right) = decorrelated_stereo_pair(&mono, 42);
+        let (left, right, mono) = (to_f64(&left), to_f64(&right), to_f64(&mono));
+
+        assert!(correlation(&left, &right) < 0.8);
+
+        // The channel average follows the mono signal
+        let mid: Vec<f64> = left
+            .iter()
+            .zip(&right)
+            .map(|(l, r)| (l + r) / 2.0)
+            .collect();
+        assert!(correlation(&mid, &mono) > 0.999);
+
+        let peak = |s: &[f64]| s.iter().fold(0.0f64, |acc, x| acc.max(x.abs()));
+        assert!(peak(&left).max(peak(&right)) <= peak(&mono) + 1.0);
+    }
+
+    #[test]
+    fn test_silence_stays_silent() {
+        let (left, right) = decorrelated_stereo_pair(&[0u8; 200], 3);
+        assert!(left.iter().chain(&right).all(|&b| b == 0));
+    }
+}
@@ -34,6 +34,11 @@ pub fn derive_instrument_seed(base_seed: u32, instrument_index: u32) -> u32 {
     speccade_spec::hash::derive_layer_seed(base_seed, instrument_index)
 }
 
+/// Derive a seed for one zone of a multi-sample instrument from the instrument seed.
+pub fn derive_zone_seed(instrument_seed: u32, zone_index: u32) -> u32 {
+    speccade_spec::hash::derive_layer_seed(instrument_seed, zone_index)
+}
+
 /// Resample audio using deterministic linear interpolation.
 ///
 /// This function resamples audio from one sample rate to another using
@@ -46,7 +51,6 @@ pub fn derive_instrument_seed(base_seed: u32, instrument_index: u32) -> u32 {
 ///
 /// # Returns
 /// Resampled audio at the target sample rate
-#[allow(dead_code)] // Available for optional quality reduction if needed
 pub(super) fn resample_linear(samples: &[f64], from_rate: u32, to_rate: u32) -> Vec<f64> {
     if samples.is_empty() {
         return Vec::new();
@@ -2,7 +2,7 @@
 
 use std::path::Path;
 
-use super::utils::samples_to_bytes;
+use super::utils::{resample_linear, samples_to_bytes};
 
 /// Load a WAV file and return 16-bit PCM bytes (little-endian i16), mono.
 ///
@@ -24,6 +24,38 @@ use super::utils::samples_to_bytes;
 /// - The WAV format is unsupported (non-PCM formats)
 /// - The bit depth is not 8, 16, 24, or 32 bits
 pub fn load_wav_sample(sample_path: &Path) -> Result<(Vec<u8>, u32), String> {
+    let (mono_samples, sample_rate) = load_wav_mono(sample_path)?;
+
+    // Convert to 16-bit PCM bytes, preserving original sample rate
+    Ok((samples_to_bytes(&mono_samples), sample_rate))
+}
+
+/// Load a WAV file as mono 16-bit PCM bytes, resampled to `target_rate`.
+///
+/// Multi-channel audio is downmixed exactly as in [`load_wav_sample`], then
+/// resampled with deterministic linear interpolation. Files already at
+/// `target_rate` are not resampled.
+///
+/// # Arguments
+/// * `sample_path` - Absolute path to the WAV file
+/// * `target_rate` - Sample rate of the returned PCM data
+///
+/// # Errors
+/// Returns an error if the file cannot be loaded (see [`load_wav_sample`]) or
+/// if `target_rate` is zero.
+pub fn load_wav_sample_resampled(sample_path: &Path, target_rate: u32) -> Result<Vec<u8>, String> {
+    if target_rate == 0 {
+        return Err("Target sample rate must be greater than zero".to_string());
+    }
+
+    let (mono_samples, sample_rate) = load_wav_mono(sample_path)?;
+    let resampled = resample_linear(&mono_samples, sample_rate, target_rate);
+
+    Ok(samples_to_bytes(&resampled))
+}
+
+/// Load a WAV file as normalized mono samples, returning its sample rate.
+fn load_wav_mono(sample_path: &Path) -> Result<(Vec<f64>, u32), String> {
     // Read the WAV file
     let mut reader = hound::WavReader::open(sample_path)
         .map_err(|e| format!("Failed to open WAV file '{}': {}", sample_path.display(), e))?;
@@ -65,8 +97,7 @@ pub fn load_wav_sample(sample_path: &Path) -> Result<(Vec<u8>, u32), String> {
         }
     };
 
-    // Convert to 16-bit PCM bytes, preserving original sample rate
-    Ok((samples_to_bytes(&mono_samples), spec.sample_rate))
+    Ok((mono_samples, spec.sample_rate))
 }
 
 /// Convert interleaved multi-channel samples to mono by averaging channels.
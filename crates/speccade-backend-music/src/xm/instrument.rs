@@ -9,6 +9,12 @@ pub const XM_INSTRUMENT_HEADER_SIZE: u32 = 263;
 /// XM sample header size.
 pub const XM_SAMPLE_HEADER_SIZE: u32 = 40;
 
+/// Maximum instrument auto-vibrato depth.
+pub const XM_MAX_VIBRATO_DEPTH: u8 = 15;
+
+/// Maximum instrument auto-vibrato rate.
+pub const XM_MAX_VIBRATO_RATE: u8 = 63;
+
 /// Volume envelope point.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct XmEnvelopePoint {
@@ -122,6 +128,10 @@ pub struct XmInstrument {
     pub volume_fadeout: u16,
     /// Sample data.
     pub sample: XmSample,
+    /// Additional samples of a multi-sample instrument, numbered from 1.
+    pub extra_samples: Vec<XmSample>,
+    /// Note-to-sample table (C-0 first, up to 96 entries); missing entries play `sample`.
+    pub note_map: Vec<u8>,
 }
 
 impl XmInstrument {
@@ -140,6 +150,21 @@ impl XmInstrument {
         self
     }
 
+    /// Set the panning envelope.
+    pub fn with_panning_envelope(mut self, envelope: XmEnvelope) -> Self {
+        self.panning_envelope = envelope;
+        self
+    }
+
+    /// Set the auto-vibrato parameters.
+    pub fn with_auto_vibrato(mut self, vibrato_type: u8, sweep: u8, depth: u8, rate: u8) -> Self {
+        self.vibrato_type = vibrato_type;
+        self.vibrato_sweep = sweep;
+        self.vibrato_depth = depth;
+        self.vibrato_rate = rate;
+        self
+    }
+
     /// Write the instrument to a writer.
     pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
         // Instrument header size
@@ -155,15 +180,17 @@ impl XmInstrument {
         // Instrument type (always 0)
         writer.write_u8(0)?;
 
-        // Number of samples (always 1 for our purposes)
-        writer.write_u16::<LittleEndian>(1)?;
+        // Number of samples
+        writer.write_u16::<LittleEndian>(1 + self.extra_samples.len() as u16)?;
 
         // Sample header size
         writer.write_u32::<LittleEndian>(XM_SAMPLE_HEADER_SIZE)?;
 
         // Note-sample mapping table (96 bytes)
-        // Maps each note to sample 0
-        writer.write_all(&[0u8; 96])?;
+        let mut note_map = [0u8; 96];
+        let map_len = self.note_map.len().min(96);
+        note_map[..map_len].copy_from_slice(&self.note_map[..map_len]);
+        writer.write_all(&note_map)?;
 
         // Volume envelope (48 bytes: 12 points * 4 bytes)
         for i in 0..12 {
@@ -229,11 +256,13 @@ impl XmInstrument {
         // Reserved (22 bytes)
         writer.write_all(&[0u8; 22])?;
 
-        // Write sample header
-        self.sample.write_header(writer)?;
-
-        // Write sample data
-        self.sample.write_data(writer)?;
+        // Write all sample headers, then all sample data
+        for sample in std::iter::once(&self.sample).chain(&self.extra_samples) {
+            sample.write_header(writer)?;
+        }
+        for sample in std::iter::once(&self.sample).chain(&self.extra_samples) {
+            sample.write_data(writer)?;
+        }
 
         Ok(())
     }
@@ -41,7 +41,7 @@ pub(super) fn validate_instruments(
         }
 
         // Parse instrument header
-        let inst_info = parse_instrument_header(data, offset, inst_idx, report)?;
+        let mut inst_info = parse_instrument_header(data, offset, inst_idx, report)?;
 
         // Store sample data sizes for offset calculation
         let mut total_sample_data_size: usize = 0;
@@ -65,9 +65,7 @@ pub(super) fn validate_instruments(
                 let sample_info =
                     parse_sample_header(data, sample_offset, inst_idx, sample_idx, report)?;
                 total_sample_data_size += sample_info.length as usize;
-
-                // Create a mutable copy of inst_info for samples
-                // Note: We'll add samples in a collected manner below
+                inst_info.samples.push(sample_info);
             }
 
             // Skip sample data
@@ -207,7 +205,7 @@ fn parse_instrument_header(
         vibrato_depth,
         vibrato_rate,
         volume_fadeout,
-        samples: Vec::new(), // Filled later
+        samples: Vec::new(), // Filled by validate_instruments
     })
 }
 
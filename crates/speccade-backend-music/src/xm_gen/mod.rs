@@ -14,16 +14,23 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use speccade_spec::recipe::music::{
-    parse_effect_name, MusicTrackerSongV1Params, TrackerFormat, TrackerInstrument, TrackerPattern,
+    parse_effect_name, AutoVibrato, MusicTrackerSongV1Params, TrackerFormat, TrackerInstrument,
+    TrackerPattern, VibratoWaveform,
 };
 
-use crate::envelope::convert_envelope_to_xm;
+use crate::envelope::{
+    convert_envelope_to_xm, convert_pan_envelope_to_xm, validate_tracker_envelope,
+};
 use crate::generate::{
-    bake_instrument_sample, resolve_pattern_note_name, GenerateError, GenerateResult,
-    MusicInstrumentLoopReport, MusicLoopReport,
+    bake_instrument_sample, bake_sample_zones, resolve_pattern_note_name, sample_zone_note_map,
+    BakedInstrumentSample, GenerateError, GenerateResult, MusicInstrumentLoopReport,
+    MusicLoopReport,
 };
 use crate::note::{calculate_xm_pitch_correction, xm_pitch_deviation_cents};
-use crate::xm::{XmInstrument, XmModule, XmNote, XmPattern, XmSample, XmValidator};
+use crate::xm::{
+    XmInstrument, XmModule, XmNote, XmPattern, XmSample, XmValidator, XM_MAX_CHANNELS,
+    XM_MAX_ENVELOPE_POINTS, XM_MAX_INSTRUMENTS, XM_MAX_VIBRATO_DEPTH, XM_MAX_VIBRATO_RATE,
+};
 
 pub use automation::{apply_automation_to_xm_pattern, apply_tempo_change_xm, apply_volume_fade_xm};
 
@@ -47,16 +54,52 @@ pub fn generate_xm(
     // Validate parameters
     validate_xm_params(params)?;
 
+    // Stereo instruments need a mirror channel per channel that plays them, and a
+    // right-hand instrument each. Without room for both they are written as mono.
+    let stereo_channels = params.stereo_channels();
+    let stereo_instruments = params.instruments.iter().filter(|i| i.stereo).count();
+    let split_stereo = params.channels as usize + stereo_channels.len() <= XM_MAX_CHANNELS as usize
+        && params.instruments.len() + stereo_instruments <= XM_MAX_INSTRUMENTS as usize;
+    let mirrors: Vec<(u8, u8)> = if split_stereo {
+        stereo_channels
+            .iter()
+            .enumerate()
+            .map(|(i, &channel)| (channel, params.channels + i as u8))
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let num_channels = params.channels + mirrors.len() as u8;
+
     // Create module
-    let mut module = XmModule::new("SpecCade Song", params.channels, params.speed, params.bpm);
+    let mut module = XmModule::new("SpecCade Song", num_channels, params.speed, params.bpm);
 
-    // Generate instruments
+    // Generate instruments; right-hand instruments follow all the others.
     let mut instrument_loop_reports = Vec::with_capacity(params.instruments.len());
+    let mut right_instruments = Vec::new();
+    let mut right_numbers = vec![None; params.instruments.len()];
     for (idx, instr) in params.instruments.iter().enumerate() {
-        let (xm_instrument, loop_report) =
+        let mono;
+        let instr = if instr.stereo && !split_stereo {
+            mono = TrackerInstrument {
+                stereo: false,
+                ..instr.clone()
+            };
+            &mono
+        } else {
+            instr
+        };
+        let (xm_instrument, right, loop_report) =
             generate_xm_instrument(instr, seed, idx as u32, spec_dir)?;
         module.add_instrument(xm_instrument);
         instrument_loop_reports.push(loop_report);
+        if let Some(right) = right {
+            right_instruments.push(right);
+            right_numbers[idx] = Some((params.instruments.len() + right_instruments.len()) as u8);
+        }
+    }
+    for right in right_instruments {
+        module.add_instrument(right);
     }
 
     // Build pattern index map
@@ -77,6 +120,7 @@ pub fn generate_xm(
 
         // Apply automation to this pattern
         apply_automation_to_xm_pattern(&mut xm_pattern, name, &params.automation, params.channels)?;
+        mirror_stereo_channels(&mut xm_pattern, &mirrors, &right_numbers);
 
         let pattern_idx_u8 = u8::try_from(pattern_idx).map_err(|_| {
             GenerateError::InvalidParameter(format!(
@@ -183,23 +227,145 @@ fn validate_xm_params(params: &MusicTrackerSongV1Params) -> Result<(), GenerateE
     Ok(())
 }
 
+/// Copy each mirrored channel onto its mirror channel.
+///
+/// Notes for stereo instruments play the right-hand instrument on the mirror;
+/// notes for other instruments become note-offs there, so the right half stops
+/// whenever the left half is replaced. Volumes and effects are copied as is.
+fn mirror_stereo_channels(
+    pattern: &mut XmPattern,
+    mirrors: &[(u8, u8)],
+    right_numbers: &[Option<u8>],
+) {
+    if mirrors.is_empty() {
+        return;
+    }
+    for row in &mut pattern.notes {
+        for &(channel, mirror) in mirrors {
+            let mut note = row[channel as usize];
+            if note.instrument != 0 {
+                match right_numbers[note.instrument as usize - 1] {
+                    Some(right) => note.instrument = right,
+                    None => {
+                        note.instrument = 0;
+                        if note.note != 0 {
+                            note.note = XmNote::note_off().note;
+                        }
+                    }
+                }
+            }
+            if row.len() <= mirror as usize {
+                row.resize(mirror as usize + 1, XmNote::empty());
+            }
+            row[mirror as usize] = note;
+        }
+    }
+}
+
 /// Generate an XM instrument from spec.
+///
+/// Multi-sample instruments get one sample per `sample_map` zone and a note-sample table
+/// switching between them; their loop report describes the first zone.
+///
+/// XM has no stereo samples, so a `stereo` instrument is split in two: the returned
+/// instrument holds the left channel panned hard left, and the second, right-hand
+/// instrument holds the right channel panned hard right.
 pub(crate) fn generate_xm_instrument(
     instr: &TrackerInstrument,
     base_seed: u32,
     index: u32,
     spec_dir: &Path,
-) -> Result<(XmInstrument, MusicInstrumentLoopReport), GenerateError> {
-    let (baked, loop_report) =
-        bake_instrument_sample(instr, base_seed, index, spec_dir, TrackerFormat::Xm)?;
+) -> Result<
+    (
+        XmInstrument,
+        Option<XmInstrument>,
+        MusicInstrumentLoopReport,
+    ),
+    GenerateError,
+> {
+    let baked_samples = if instr.sample_map.is_empty() {
+        vec![bake_instrument_sample(
+            instr,
+            base_seed,
+            index,
+            spec_dir,
+            TrackerFormat::Xm,
+        )?]
+    } else {
+        bake_sample_zones(instr, base_seed, index, spec_dir, TrackerFormat::Xm)?
+    };
+
+    let mut samples = Vec::with_capacity(baked_samples.len());
+    let mut right_samples = Vec::new();
+    let mut loop_reports = Vec::with_capacity(baked_samples.len());
+    for (mut baked, mut loop_report) in baked_samples {
+        let right = baked.pcm16_right.take();
+        let (mut sample, pitch_cents) = convert_baked_sample_to_xm(instr, baked);
+        if let Some(right) = right {
+            sample.panning = 0;
+            right_samples.push(XmSample {
+                data: right,
+                panning: 255,
+                ..sample.clone()
+            });
+        }
+        loop_report.pitch_deviation_cents = Some(pitch_cents);
+        samples.push(sample);
+        loop_reports.push(loop_report);
+    }
+    let mut samples = samples.into_iter();
+    let sample = samples.next().ok_or_else(|| {
+        GenerateError::InstrumentError(format!("Instrument '{}' has no samples", instr.name))
+    })?;
 
+    // Create instrument
+    let mut xm_instr = XmInstrument::new(&instr.name, sample);
+    xm_instr.extra_samples = samples.collect();
+    if !instr.sample_map.is_empty() {
+        xm_instr.note_map = sample_zone_note_map(instr, 96)?;
+    }
+
+    // Convert envelope to XM envelope
+    xm_instr.volume_envelope = convert_envelope_to_xm(&instr.envelope);
+    if let Some(pan_envelope) = &instr.pan_envelope {
+        validate_tracker_envelope(pan_envelope, XM_MAX_ENVELOPE_POINTS as usize, "XM")?;
+        xm_instr.panning_envelope = convert_pan_envelope_to_xm(pan_envelope);
+    }
+    if let Some(auto_vibrato) = &instr.auto_vibrato {
+        let vibrato_type = xm_vibrato_type(auto_vibrato)?;
+        xm_instr = xm_instr.with_auto_vibrato(
+            vibrato_type,
+            auto_vibrato.sweep,
+            auto_vibrato.depth,
+            auto_vibrato.rate,
+        );
+    }
+
+    let right_instr = if right_samples.is_empty() {
+        None
+    } else {
+        let mut right_samples = right_samples.into_iter();
+        let mut right_instr = xm_instr.clone();
+        right_instr.name = format!("{} R", instr.name);
+        right_instr.sample = right_samples.next().expect("right samples are not empty");
+        right_instr.extra_samples = right_samples.collect();
+        Some(right_instr)
+    };
+
+    Ok((xm_instr, right_instr, loop_reports.swap_remove(0)))
+}
+
+/// Build an XM sample from a baked instrument sample, returning it with its pitch deviation.
+fn convert_baked_sample_to_xm(
+    instr: &TrackerInstrument,
+    baked: BakedInstrumentSample,
+) -> (XmSample, f64) {
     let (finetune, relative_note) =
         calculate_xm_pitch_correction(baked.sample_rate, baked.base_midi);
 
     let pitch_cents =
         xm_pitch_deviation_cents(baked.sample_rate, baked.base_midi, finetune, relative_note);
 
-    // Create sample
     let mut sample = XmSample::new(&instr.name, baked.pcm16_mono, true);
     sample.finetune = finetune;
     sample.relative_note = relative_note;
@@ -216,16 +382,26 @@ pub(crate) fn generate_xm_instrument(
     // Set default volume
     sample.volume = instr.default_volume.unwrap_or(64).min(64);
 
-    // Create instrument
-    let mut xm_instr = XmInstrument::new(&instr.name, sample);
-
-    // Convert envelope to XM envelope
-    xm_instr.volume_envelope = convert_envelope_to_xm(&instr.envelope);
-
-    let mut loop_report = loop_report;
-    loop_report.pitch_deviation_cents = Some(pitch_cents);
+    (sample, pitch_cents)
+}
 
-    Ok((xm_instr, loop_report))
+/// Map an auto-vibrato waveform to the XM vibrato type, checking depth and rate limits.
+fn xm_vibrato_type(auto_vibrato: &AutoVibrato) -> Result<u8, GenerateError> {
+    if auto_vibrato.depth > XM_MAX_VIBRATO_DEPTH || auto_vibrato.rate > XM_MAX_VIBRATO_RATE {
+        return Err(GenerateError::InvalidParameter(format!(
+            "XM auto-vibrato depth must be 0-{} and rate 0-{}, got depth {} and rate {}",
+            XM_MAX_VIBRATO_DEPTH, XM_MAX_VIBRATO_RATE, auto_vibrato.depth, auto_vibrato.rate
+        )));
+    }
+    match auto_vibrato.waveform {
+        VibratoWaveform::Sine => Ok(0),
+        VibratoWaveform::Square => Ok(1),
+        VibratoWaveform::RampDown => Ok(2),
+        VibratoWaveform::RampUp => Ok(3),
+        VibratoWaveform::Random => Err(GenerateError::InvalidParameter(
+            "XM auto-vibrato has no random waveform".to_string(),
+        )),
+    }
 }
 
 /// Convert a pattern from spec to XM format.
@@ -6,7 +6,8 @@ use std::path::Path;
 
 use speccade_spec::recipe::audio::Envelope;
 use speccade_spec::recipe::music::{
-    ArrangementEntry, InstrumentSynthesis, PatternNote, TrackerFormat,
+    ArrangementEntry, AutoVibrato, InstrumentSynthesis, PatternNote, SampleZone, TrackerEnvelope,
+    TrackerEnvelopePoint, TrackerFormat, VibratoWaveform,
 };
 
 use crate::note::note_name_to_xm;
@@ -86,6 +87,248 @@ fn test_generate_xm() {
     assert_eq!(result.hash.len(), 64);
 }
 
+/// Decodes the (note, instrument) pairs of the first row of the first pattern in an XM file.
+fn first_row_notes(data: &[u8], num_channels: usize) -> Vec<(u8, u8)> {
+    let header_size = u32::from_le_bytes(data[60..64].try_into().unwrap()) as usize;
+    let pattern = 60 + header_size;
+    let pattern_header_size =
+        u32::from_le_bytes(data[pattern..pattern + 4].try_into().unwrap()) as usize;
+    let mut pos = pattern + pattern_header_size;
+
+    let mut cells = Vec::with_capacity(num_channels);
+    for _ in 0..num_channels {
+        let flags = data[pos];
+        let (mut note, mut instrument) = (0, 0);
+        if flags & 0x80 == 0 {
+            note = flags;
+            instrument = data[pos + 1];
+            pos += 5;
+        } else {
+            pos += 1;
+            for bit in 0..5 {
+                if flags & (1 << bit) != 0 {
+                    match bit {
+                        0 => note = data[pos],
+                        1 => instrument = data[pos],
+                        _ => {}
+                    }
+                    pos += 1;
+                }
+            }
+        }
+        cells.push((note, instrument));
+    }
+    cells
+}
+
+#[test]
+fn test_xm_stereo_instrument_splits_into_left_and_right() {
+    let mono = generate_xm(&create_test_params(), 42, Path::new(".")).unwrap();
+    let mono_report = XmValidator::validate(&mono.data).unwrap();
+
+    let mut params = create_test_params();
+    params.instruments[0].stereo = true;
+    let result = generate_xm(&params, 42, Path::new(".")).unwrap();
+    let report = XmValidator::validate(&result.data).unwrap();
+    assert!(report.valid, "{:?}", report.errors);
+
+    // Channel 0 plays the stereo instrument, so it gains one mirror channel,
+    // and the right half is appended as a second instrument
+    let header = report.header.as_ref().unwrap();
+    assert_eq!(header.num_channels, 5);
+    assert_eq!(header.num_instruments, 2);
+
+    let mono_sample = &mono_report.instruments[0].samples[0];
+    let left = &report.instruments[0].samples[0];
+    let right = &report.instruments[1].samples[0];
+    assert_eq!(mono_sample.panning, 128);
+    assert_eq!(left.panning, 0);
+    assert_eq!(right.panning, 255);
+    assert_eq!(left.length, mono_sample.length);
+    assert_eq!(right.length, left.length);
+    assert_eq!(right.loop_start, left.loop_start);
+    assert_eq!(right.loop_length, left.loop_length);
+    assert_eq!(right.relative_note, left.relative_note);
+    assert_eq!(right.finetune, left.finetune);
+
+    let c4 = note_name_to_xm("C4");
+    let row = first_row_notes(&result.data, 5);
+    assert_eq!(row[0], (c4, 1));
+    assert_eq!(row[4], (c4, 2));
+    assert_eq!(row[1], (0, 0));
+}
+
+#[test]
+fn test_xm_stereo_instrument_falls_back_to_mono_without_free_channels() {
+    let mut params = create_test_params();
+    params.channels = 32;
+    params.instruments[0].stereo = true;
+    let result = generate_xm(&params, 42, Path::new(".")).unwrap();
+    let report = XmValidator::validate(&result.data).unwrap();
+
+    let header = report.header.as_ref().unwrap();
+    assert_eq!(header.num_channels, 32);
+    assert_eq!(header.num_instruments, 1);
+    assert_eq!(report.instruments[0].samples[0].panning, 128);
+}
+
+fn pan_sweep_envelope() -> TrackerEnvelope {
+    TrackerEnvelope {
+        points: vec![
+            TrackerEnvelopePoint {
+                tick: 0,
+                value: -32,
+            },
+            TrackerEnvelopePoint {
+                tick: 20,
+                value: 32,
+            },
+        ],
+        sustain_point: None,
+    }
+}
+
+#[test]
+fn test_xm_instrument_writes_pan_envelope() {
+    let mut params = create_test_params();
+    params.instruments[0].pan_envelope = Some(pan_sweep_envelope());
+
+    let (instrument, _, _) =
+        generate_xm_instrument(&params.instruments[0], 42, 0, Path::new(".")).unwrap();
+    let mut bytes = Vec::new();
+    instrument.write(&mut bytes).unwrap();
+
+    // Panning envelope points start after the volume envelope (offset 129 + 48).
+    assert_eq!(&bytes[177..181], &[0, 0, 0, 0]);
+    assert_eq!(&bytes[181..185], &[20, 0, 64, 0]);
+    // Number of panning points, then the panning flags byte (bit 0 = enabled).
+    assert_eq!(bytes[226], 2);
+    assert_eq!(bytes[234] & 1, 1);
+}
+
+#[test]
+fn test_xm_instrument_without_pan_envelope_leaves_flag_clear() {
+    let params = create_test_params();
+    let (instrument, _, _) =
+        generate_xm_instrument(&params.instruments[0], 42, 0, Path::new(".")).unwrap();
+    let mut bytes = Vec::new();
+    instrument.write(&mut bytes).unwrap();
+
+    assert_eq!(bytes[226], 0);
+    assert_eq!(bytes[234], 0);
+}
+
+#[test]
+fn test_xm_instrument_rejects_non_increasing_pan_envelope() {
+    let mut params = create_test_params();
+    let mut envelope = pan_sweep_envelope();
+    envelope.points[1].tick = 0;
+    params.instruments[0].pan_envelope = Some(envelope);
+
+    let err = generate_xm_instrument(&params.instruments[0], 42, 0, Path::new(".")).unwrap_err();
+    assert!(err.to_string().contains("strictly increasing"));
+}
+
+#[test]
+fn test_xm_instrument_writes_auto_vibrato() {
+    let mut params = create_test_params();
+    params.instruments[0].auto_vibrato = Some(AutoVibrato {
+        waveform: VibratoWaveform::RampDown,
+        depth: 12,
+        rate: 40,
+        sweep: 16,
+    });
+
+    let (instrument, _, _) =
+        generate_xm_instrument(&params.instruments[0], 42, 0, Path::new(".")).unwrap();
+    let mut bytes = Vec::new();
+    instrument.write(&mut bytes).unwrap();
+
+    // Vibrato type, sweep, depth, rate follow the envelope flags (offset 235).
+    assert_eq!(&bytes[235..239], &[2, 16, 12, 40]);
+
+    let params_without = create_test_params();
+    let (instrument, _, _) =
+        generate_xm_instrument(&params_without.instruments[0], 42, 0, Path::new(".")).unwrap();
+    let mut bytes = Vec::new();
+    instrument.write(&mut bytes).unwrap();
+    assert_eq!(&bytes[235..239], &[0, 0, 0, 0]);
+}
+
+#[test]
+fn test_xm_instrument_rejects_out_of_range_auto_vibrato() {
+    let mut params = create_test_params();
+    params.instruments[0].auto_vibrato = Some(AutoVibrato {
+        waveform: VibratoWaveform::Sine,
+        depth: 16,
+        rate: 8,
+        sweep: 0,
+    });
+
+    let err = generate_xm_instrument(&params.instruments[0], 42, 0, Path::new(".")).unwrap_err();
+    assert!(err.to_string().contains("auto-vibrato depth"));
+}
+
+/// A sine zone covering `low_note..=high_note`, tuned to `base_note`.
+fn sine_zone(low_note: &str, high_note: &str, base_note: &str, frequency: f64) -> SampleZone {
+    SampleZone {
+        low_note: low_note.to_string(),
+        high_note: high_note.to_string(),
+        synthesis_audio_v1: serde_json::from_value(serde_json::json!({
+            "duration_seconds": 0.2,
+            "layers": [{
+                "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": frequency },
+                "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                "volume": 0.8,
+                "pan": 0.0
+            }]
+        }))
+        .unwrap(),
+        base_note: Some(base_note.to_string()),
+    }
+}
+
+#[test]
+fn test_xm_multi_sample_instrument_switches_zone_at_boundary() {
+    let mut params = create_test_params();
+    let instrument = &mut params.instruments[0];
+    instrument.synthesis = None;
+    instrument.sample_map = vec![
+        sine_zone("C0", "B3", "C3", 130.81),
+        sine_zone("C4", "B7", "C5", 523.25),
+    ];
+
+    let (xm_instr, _, _) = generate_xm_instrument(instrument, 42, 0, Path::new(".")).unwrap();
+    assert_eq!(xm_instr.extra_samples.len(), 1);
+    assert_ne!(xm_instr.sample.data, xm_instr.extra_samples[0].data);
+
+    let mut bytes = Vec::new();
+    xm_instr.write(&mut bytes).unwrap();
+
+    // Number of samples at offset 27, note-sample table at offset 33 (index 0 = C-0).
+    assert_eq!(u16::from_le_bytes([bytes[27], bytes[28]]), 2);
+    let b3 = note_name_to_xm("B3") as usize - 1;
+    let c4 = note_name_to_xm("C4") as usize - 1;
+    assert_eq!(bytes[33], 0);
+    assert_eq!(bytes[33 + b3], 0);
+    assert_eq!(bytes[33 + c4], 1);
+    assert_eq!(bytes[33 + 95], 1);
+}
+
+#[test]
+fn test_xm_multi_sample_instrument_rejects_zone_gap() {
+    let mut params = create_test_params();
+    let instrument = &mut params.instruments[0];
+    instrument.synthesis = None;
+    instrument.sample_map = vec![
+        sine_zone("C0", "B3", "C3", 130.81),
+        sine_zone("D4", "B7", "C5", 523.25),
+    ];
+
+    let err = generate_xm_instrument(instrument, 42, 0, Path::new(".")).unwrap_err();
+    assert!(err.to_string().contains("gap or overlap"));
+}
+
 #[test]
 fn test_xm_param_validation() {
     let mut params = create_test_params();
@@ -414,7 +657,7 @@ fn test_xm_non_periodic_noise_one_shot_does_not_loop() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
     assert_eq!(
         xm_instr.sample.loop_type, 0,
         "Non-periodic noise one-shots should not loop (ringing/pitch artifacts)"
@@ -451,7 +694,7 @@ fn test_synthesized_pulse_instrument_pitch_correction() {
     });
     let spec_dir = Path::new(".");
 
-    let (xm_instr, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
 
     // Access the sample's relative_note (XmInstrument has a single sample)
     assert_eq!(
@@ -467,7 +710,7 @@ fn test_synthesized_sine_instrument_pitch_correction() {
     let instr = create_test_instrument(InstrumentSynthesis::Sine { base_note: None });
     let spec_dir = Path::new(".");
 
-    let (xm_instr, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
 
     assert_eq!(
         xm_instr.sample.relative_note, 16,
@@ -485,7 +728,7 @@ fn test_synthesized_noise_instrument_pitch_correction() {
     });
     let spec_dir = Path::new(".");
 
-    let (xm_instr, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
 
     assert_eq!(
         xm_instr.sample.relative_note, 16,
@@ -500,7 +743,7 @@ fn test_synthesized_triangle_instrument_pitch_correction() {
     let instr = create_test_instrument(InstrumentSynthesis::Triangle { base_note: None });
     let spec_dir = Path::new(".");
 
-    let (xm_instr, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
 
     assert_eq!(
         xm_instr.sample.relative_note, 16,
@@ -515,7 +758,7 @@ fn test_synthesized_sawtooth_instrument_pitch_correction() {
     let instr = create_test_instrument(InstrumentSynthesis::Sawtooth { base_note: None });
     let spec_dir = Path::new(".");
 
-    let (xm_instr, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
 
     assert_eq!(
         xm_instr.sample.relative_note, 16,
@@ -533,7 +776,7 @@ fn test_finetune_value_for_synthesized_instruments() {
     });
     let spec_dir = Path::new(".");
 
-    let (xm_instr, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instr, 42, 0, spec_dir).unwrap();
 
     let finetune = xm_instr.sample.finetune;
     assert!(
@@ -577,7 +820,7 @@ fn test_xm_variant_a_no_base_note_no_pattern_note() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // relative_note should be 16 (sample rate compensation only, since base is at reference)
     // rate_correction: 12 * log2(22050/8363) = 12 * 1.399 = 16.79, floor = 16
@@ -622,7 +865,7 @@ fn test_xm_variant_b_no_base_note_pattern_note_c4() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // relative_note should still be 16 - pattern note doesn't affect this
     assert_eq!(
@@ -650,7 +893,7 @@ fn test_xm_variant_c_base_note_c5_no_pattern_note() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // With base_note = "C5" (MIDI 72 = XM note 60, 0-indexed):
     // rate_correction = 16 (same as before, from 22050/8363 ratio)
@@ -684,7 +927,7 @@ fn test_xm_variant_d_base_note_c5_pattern_note_c4() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // relative_note is 4 (configured for C5 base note)
     assert_eq!(xm_instr.sample.relative_note, 4);
@@ -727,7 +970,7 @@ fn test_xm_base_note_a4_non_c_note() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // Sample at MIDI 69 (A4) = XM note 57 (0-indexed)
     // rate_correction = 16
@@ -757,7 +1000,7 @@ fn test_xm_base_note_c3_two_octaves_below() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // Sample at MIDI 48 (C3) = XM note 36 (0-indexed)
     // rate_correction = 16
@@ -788,7 +1031,7 @@ fn test_xm_base_note_c6_two_octaves_above() {
     };
 
     let spec_dir = Path::new(".");
-    let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+    let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
 
     // Sample at MIDI 84 (C6) = XM note 72 (0-indexed)
     // rate_correction = 16
@@ -822,7 +1065,7 @@ fn test_xm_relative_note_octave_relationships() {
             ..Default::default()
         };
 
-        let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+        let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
         relative_notes.push(xm_instr.sample.relative_note);
     }
 
@@ -864,7 +1107,7 @@ fn test_xm_finetune_consistent_across_base_notes() {
             ..Default::default()
         };
 
-        let (xm_instr, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
+        let (xm_instr, _, _) = generate_xm_instrument(&instrument, 42, 0, spec_dir).unwrap();
         finetunes.push(xm_instr.sample.finetune);
     }
 
@@ -0,0 +1,92 @@
+//! Integration tests for the MOD writer, including validation and determinism.
+
+use speccade_backend_music::protracker::effects;
+use speccade_backend_music::protracker::{
+    validate_mod_bytes, ModModule, ModNote, ModPattern, ModSample, ModValidationError, MOD_MAGIC,
+    MOD_MAGIC_OFFSET, MOD_MAX_ORDERS, MOD_PATTERN_SIZE,
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Build a MOD module with one sample and a short melody.
+fn build_mod_with_notes(title: &str) -> ModModule {
+    let mut module = ModModule::new(title);
+
+    let pcm: Vec<u8> = (0..256i16)
+        .flat_map(|i| ((i - 128) * 200).to_le_bytes())
+        .collect();
+    module.add_sample(ModSample::from_pcm16("Lead", &pcm).with_loop(0, 256));
+
+    let mut pattern = ModPattern::empty();
+    pattern.set_note(0, 0, ModNote::from_midi(60, 1).unwrap());
+    pattern.set_note(4, 1, ModNote::from_midi(64, 1).unwrap());
+    pattern.set_note(
+        8,
+        2,
+        ModNote::from_midi(67, 1)
+            .unwrap()
+            .with_effect(effects::SET_VOLUME, 32),
+    );
+    pattern.set_note(
+        63,
+        3,
+        ModNote::empty().with_effect(effects::POSITION_JUMP, 0),
+    );
+    module.add_pattern(pattern);
+    module.set_order_table(&[0]);
+    module
+}
+
+// =============================================================================
+// Integration / Validation Tests
+// =============================================================================
+
+#[test]
+fn test_mod_validation_success() {
+    let bytes = build_mod_with_notes("Notes").to_bytes().unwrap();
+    assert!(validate_mod_bytes(&bytes).is_ok());
+    assert_eq!(&bytes[MOD_MAGIC_OFFSET..MOD_MAGIC_OFFSET + 4], MOD_MAGIC);
+    assert_eq!(bytes.len(), MOD_MAGIC_OFFSET + 4 + MOD_PATTERN_SIZE + 256);
+}
+
+#[test]
+fn test_mod_validation_truncated() {
+    let bytes = build_mod_with_notes("Notes").to_bytes().unwrap();
+    match validate_mod_bytes(&bytes[..bytes.len() - 1]) {
+        Err(ModValidationError::Truncated { expected, actual }) => {
+            assert_eq!(expected, bytes.len());
+            assert_eq!(actual, bytes.len() - 1);
+        }
+        _ => panic!("Expected Truncated error"),
+    }
+}
+
+#[test]
+fn test_mod_byte_determinism() {
+    let bytes1 = build_mod_with_notes("HashTest").to_bytes().unwrap();
+    let bytes2 = build_mod_with_notes("HashTest").to_bytes().unwrap();
+    assert_eq!(bytes1, bytes2, "Same module should produce identical bytes");
+
+    let hash1 = build_mod_with_notes("HashTest").compute_hash().unwrap();
+    let hash2 = build_mod_with_notes("HashTest").compute_hash().unwrap();
+    assert_eq!(hash1, hash2, "Same module should produce same hash");
+}
+
+#[test]
+fn test_mod_hash_different_content() {
+    let hash1 = build_mod_with_notes("HashTest1").compute_hash().unwrap();
+    let hash2 = build_mod_with_notes("HashTest2").compute_hash().unwrap();
+    assert_ne!(
+        hash1, hash2,
+        "Different modules should produce different hashes"
+    );
+}
+
+#[test]
+fn test_mod_rejects_too_many_orders() {
+    let mut module = build_mod_with_notes("Orders");
+    module.set_order_table(&[0; MOD_MAX_ORDERS + 1]);
+    assert!(module.to_bytes().is_err());
+}
@@ -0,0 +1,84 @@
+//! Integration tests for the S3M writer, including validation and determinism.
+
+use speccade_backend_music::s3m::effects;
+use speccade_backend_music::s3m::{
+    validate_s3m_bytes, S3mModule, S3mNote, S3mPattern, S3mSample, S3mValidationError, S3M_MAGIC,
+    S3M_MAGIC_OFFSET, S3M_MAX_CHANNELS,
+};
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Build an S3M module with one sample and a short melody.
+fn build_s3m_with_notes(title: &str, channels: u8) -> S3mModule {
+    let mut module = S3mModule::new(title, channels, 6, 125);
+
+    let pcm: Vec<u8> = (0..256i16)
+        .flat_map(|i| ((i - 128) * 200).to_le_bytes())
+        .collect();
+    module.add_sample(S3mSample::from_pcm16("Lead", &pcm, 22050).with_loop(0, 256));
+
+    let mut pattern = S3mPattern::empty(channels);
+    pattern.set_note(0, 0, S3mNote::from_midi(60, 1, Some(64)).unwrap());
+    pattern.set_note(4, 0, S3mNote::from_midi(64, 1, None).unwrap());
+    pattern.set_note(8, 0, S3mNote::note_cut());
+    pattern.set_note(
+        63,
+        channels - 1,
+        S3mNote::empty().with_effect(effects::POSITION_JUMP, 0),
+    );
+    module.add_pattern(pattern);
+    module.set_order_table(&[0]);
+    module
+}
+
+// =============================================================================
+// Integration / Validation Tests
+// =============================================================================
+
+#[test]
+fn test_s3m_validation_success() {
+    let bytes = build_s3m_with_notes("Notes", 4).to_bytes().unwrap();
+    assert!(validate_s3m_bytes(&bytes).is_ok());
+    assert_eq!(&bytes[S3M_MAGIC_OFFSET..S3M_MAGIC_OFFSET + 4], S3M_MAGIC);
+}
+
+#[test]
+fn test_s3m_validation_invalid_file_type() {
+    let mut bytes = build_s3m_with_notes("Notes", 4).to_bytes().unwrap();
+    bytes[0x1D] = 17;
+    match validate_s3m_bytes(&bytes) {
+        Err(S3mValidationError::InvalidFileType(t)) => assert_eq!(t, 17),
+        _ => panic!("Expected InvalidFileType error"),
+    }
+}
+
+#[test]
+fn test_s3m_byte_determinism() {
+    let bytes1 = build_s3m_with_notes("HashTest", 8).to_bytes().unwrap();
+    let bytes2 = build_s3m_with_notes("HashTest", 8).to_bytes().unwrap();
+    assert_eq!(bytes1, bytes2, "Same module should produce identical bytes");
+
+    let hash1 = build_s3m_with_notes("HashTest", 8).compute_hash().unwrap();
+    let hash2 = build_s3m_with_notes("HashTest", 8).compute_hash().unwrap();
+    assert_eq!(hash1, hash2, "Same module should produce same hash");
+}
+
+#[test]
+fn test_s3m_hash_different_content() {
+    let hash1 = build_s3m_with_notes("HashTest1", 4).compute_hash().unwrap();
+    let hash2 = build_s3m_with_notes("HashTest2", 4).compute_hash().unwrap();
+    assert_ne!(
+        hash1, hash2,
+        "Different modules should produce different hashes"
+    );
+}
+
+#[test]
+fn test_s3m_max_channels() {
+    let bytes = build_s3m_with_notes("Wide", S3M_MAX_CHANNELS)
+        .to_bytes()
+        .unwrap();
+    assert!(validate_s3m_bytes(&bytes).is_ok());
+}
@@ -364,6 +364,13 @@ mod tests {
                             octaves: 2,
                             persistence: 0.5,
                             lacunarity: 2.0,
+                            distance_metric: Default::default(),
+                            jitter: 1.0,
+                            worley_mode: Default::default(),
+                            frequency: None,
+                            orientation: None,
+                            bandwidth: None,
+                            impulses_per_cell: None,
                         },
                     },
                 },
@@ -0,0 +1,202 @@
+//! Edge padding (dilation) for atlas-safe textures.
+//!
+//! Filtering and mipmapping sample texels outside a UV island or atlas entry, so
+//! empty texels around the valid region bleed into the result. Dilation grows
+//! the valid colors outward into those texels one ring at a time.
+
+use crate::color::Color;
+use crate::maps::{GrayscaleBuffer, TextureBuffer};
+
+/// Mask values above this threshold mark a texel as valid.
+const MASK_THRESHOLD: f64 = 0.5;
+
+/// Orthogonal neighbor offsets, checked before diagonals.
+const ORTHOGONAL: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Diagonal neighbor offsets.
+const DIAGONAL: [(i32, i32); 4] = [(-1, -1), (1, -1), (-1, 1), (1, 1)];
+
+/// Errors from dilation.
+#[derive(Debug, thiserror::Error)]
+pub enum DilationError {
+    /// The mask and texture sizes differ.
+    #[error("Mask is {mask_width}x{mask_height} but texture is {width}x{height}")]
+    DimensionMismatch {
+        width: u32,
+        height: u32,
+        mask_width: u32,
+        mask_height: u32,
+    },
+}
+
+/// Pushes valid colors outward into empty texels.
+///
+/// Texels whose `mask` value is above 0.5 are valid and left untouched. Each of
+/// the `pixels` passes fills every empty texel bordering a valid one with the
+/// average RGB of its valid orthogonal neighbors, or of its valid diagonal
+/// neighbors if it has no orthogonal ones. Filled texels keep their own alpha,
+/// so the visible silhouette doesn't change.
+///
+/// # Arguments
+/// * `buffer` - The texture to pad
+/// * `mask` - Validity mask with the same dimensions as `buffer`
+/// * `pixels` - Maximum distance, in texels, to grow the valid region
+pub fn dilate(
+    buffer: &TextureBuffer,
+    mask: &GrayscaleBuffer,
+    pixels: u32,
+) -> Result<TextureBuffer, DilationError> {
+    if mask.width != buffer.width || mask.height != buffer.height {
+        return Err(DilationError::DimensionMismatch {
+            width: buffer.width,
+            height: buffer.height,
+            mask_width: mask.width,
+            mask_height: mask.height,
+        });
+    }
+
+    let width = buffer.width as i32;
+    let height = buffer.height as i32;
+    let mut out = buffer.clone();
+    let mut valid: Vec<bool> = mask.data.iter().map(|&m| m > MASK_THRESHOLD).collect();
+
+    for _ in 0..pixels {
+        let mut filled = Vec::new();
+        for y in 0..height {
+            for x in 0..width {
+                let idx = (y * width + x) as usize;
+                if valid[idx] {
+                    continue;
+                }
+
+                let average = |offsets: &[(i32, i32)]| {
+                    let mut sum = (0.0, 0.0, 0.0);
+                    let mut count = 0;
+                    for &(dx, dy) in offsets {
+                        let (nx, ny) = (x + dx, y + dy);
+                        if nx < 0 || ny < 0 || nx >= width || ny >= height {
+                            continue;
+                        }
+                        let n = (ny * width + nx) as usize;
+                        if valid[n] {
+                            let c = out.data[n];
+                            sum = (sum.0 + c.r, sum.1 + c.g, sum.2 + c.b);
+                            count += 1;
+                        }
+                    }
+                    (count > 0).then(|| {
+                        let count = count as f64;
+                        (sum.0 / count, sum.1 / count, sum.2 / count)
+                    })
+                };
+
+                if let Some(rgb) = average(&ORTHOGONAL).or_else(|| average(&DIAGONAL)) {
+                    filled.push((idx, rgb));
+                }
+            }
+        }
+
+        if filled.is_empty() {
+            break;
+        }
+        for (idx, (r, g, b)) in filled {
+            out.data[idx] = Color::rgba(r, g, b, out.data[idx].a);
+            valid[idx] = true;
+        }
+    }
+
+    Ok(out)
+}
+
+/// Builds a dilation mask from a texture's alpha channel.
+pub fn mask_from_alpha(buffer: &TextureBuffer) -> GrayscaleBuffer {
+    GrayscaleBuffer {
+        width: buffer.width,
+        height: buffer.height,
+        data: buffer.data.iter().map(|c| c.a).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 5x5 red texture with a transparent 3x3 hole in the middle.
+    fn texture_with_hole() -> TextureBuffer {
+        let mut buffer = TextureBuffer::new(5, 5, Color::rgba(1.0, 0.0, 0.0, 1.0));
+        for y in 1..4 {
+            for x in 1..4 {
+                buffer.set(x, y, Color::rgba(0.0, 0.0, 0.0, 0.0));
+            }
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_opaque_texture_is_unchanged() {
+        let mut buffer = TextureBuffer::new(4, 4, Color::white());
+        buffer.set(1, 2, Color::rgba(0.2, 0.4, 0.6, 1.0));
+        let mask = mask_from_alpha(&buffer);
+
+        let dilated = dilate(&buffer, &mask, 8).unwrap();
+        assert_eq!(dilated.data, buffer.data);
+    }
+
+    #[test]
+    fn test_hole_edge_is_filled_with_nearest_color() {
+        let buffer = texture_with_hole();
+        let mask = mask_from_alpha(&buffer);
+
+        let dilated = dilate(&buffer, &mask, 1).unwrap();
+        for y in 1..4 {
+            for x in 1..4 {
+                let c = dilated.get(x, y);
+                if (x, y) == (2, 2) {
+                    // One pass only reaches the ring bordering valid texels
+                    assert_eq!((c.r, c.g, c.b), (0.0, 0.0, 0.0));
+                } else {
+                    assert_eq!((c.r, c.g, c.b), (1.0, 0.0, 0.0), "texel {},{}", x, y);
+                }
+                assert_eq!(c.a, 0.0, "alpha is preserved");
+            }
+        }
+
+        let dilated = dilate(&buffer, &mask, 2).unwrap();
+        assert_eq!(dilated.get(2, 2).r, 1.0);
+    }
+
+    #[test]
+    fn test_prefers_orthogonal_neighbors_and_is_deterministic() {
+        let mut buffer = TextureBuffer::new(3, 3, Color::rgba(0.0, 0.0, 0.0, 0.0));
+        buffer.set(1, 0, Color::rgba(1.0, 0.0, 0.0, 1.0));
+        buffer.set(0, 1, Color::rgba(0.0, 0.0, 1.0, 1.0));
+        buffer.set(2, 2, Color::rgba(0.0, 1.0, 0.0, 1.0));
+        let mask = mask_from_alpha(&buffer);
+
+        let dilated = dilate(&buffer, &mask, 1).unwrap();
+        // Orthogonal neighbors win over the diagonal green texel
+        let center = dilated.get(1, 1);
+        assert_eq!((center.r, center.g, center.b), (0.5, 0.0, 0.5));
+        assert_eq!(dilate(&buffer, &mask, 1).unwrap().data, dilated.data);
+
+        // With only diagonal valid neighbors, the center falls back to their average
+        let mut buffer = TextureBuffer::new(3, 3, Color::rgba(0.0, 0.0, 0.0, 0.0));
+        buffer.set(0, 0, Color::rgba(1.0, 0.0, 0.0, 1.0));
+        buffer.set(2, 2, Color::rgba(0.0, 1.0, 0.0, 1.0));
+        let mask = mask_from_alpha(&buffer);
+
+        let dilated = dilate(&buffer, &mask, 1).unwrap();
+        let center = dilated.get(1, 1);
+        assert_eq!((center.r, center.g, center.b), (0.5, 0.5, 0.0));
+    }
+
+    #[test]
+    fn test_rejects_mismatched_mask() {
+        let buffer = TextureBuffer::new(4, 4, Color::white());
+        let mask = GrayscaleBuffer::new(4, 3, 1.0);
+        assert!(matches!(
+            dilate(&buffer, &mask, 1),
+            Err(DilationError::DimensionMismatch { .. })
+        ));
+    }
+}
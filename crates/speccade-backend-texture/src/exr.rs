@@ -0,0 +1,302 @@
+//! Deterministic OpenEXR writer.
+//!
+//! Writes single-part scanline images with 32-bit float channels and no
+//! compression, so values above 1.0 or below 0.0 survive without quantization
+//! and the same buffer always encodes to the same bytes.
+
+use thiserror::Error;
+
+use crate::maps::{GrayscaleBuffer, TextureBuffer};
+
+/// OpenEXR magic number.
+const MAGIC: u32 = 20000630;
+
+/// File format version 2, single-part scanline, no flags.
+const VERSION: u32 = 2;
+
+/// Channel pixel type for 32-bit IEEE floats.
+const PIXEL_TYPE_FLOAT: i32 = 2;
+
+/// `NO_COMPRESSION`; each chunk holds exactly one scanline.
+const COMPRESSION_NONE: u8 = 0;
+
+/// `INCREASING_Y` line order.
+const LINE_ORDER_INCREASING_Y: u8 = 0;
+
+/// Errors from EXR operations.
+#[derive(Debug, Error)]
+pub enum ExrError {
+    #[error("Invalid dimensions: {0}")]
+    InvalidDimensions(String),
+}
+
+/// Encodes a grayscale buffer as a single-channel (`Y`) float EXR.
+pub fn write_grayscale_to_vec(buffer: &GrayscaleBuffer) -> Result<Vec<u8>, ExrError> {
+    let channels = [ExrChannel {
+        name: "Y",
+        values: &|i| buffer.data[i],
+    }];
+    encode(buffer.width, buffer.height, &channels)
+}
+
+/// Encodes an RGBA buffer as a four-channel float EXR.
+pub fn write_rgba_to_vec(buffer: &TextureBuffer) -> Result<Vec<u8>, ExrError> {
+    // Channels are stored in alphabetical order, as the format requires
+    let channels = [
+        ExrChannel {
+            name: "A",
+            values: &|i| buffer.data[i].a,
+        },
+        ExrChannel {
+            name: "B",
+            values: &|i| buffer.data[i].b,
+        },
+        ExrChannel {
+            name: "G",
+            values: &|i| buffer.data[i].g,
+        },
+        ExrChannel {
+            name: "R",
+            values: &|i| buffer.data[i].r,
+        },
+    ];
+    encode(buffer.width, buffer.height, &channels)
+}
+
+/// Write a grayscale buffer to a `Vec<u8>` and return the hash.
+pub fn write_grayscale_to_vec_with_hash(
+    buffer: &GrayscaleBuffer,
+) -> Result<(Vec<u8>, String), ExrError> {
+    let data = write_grayscale_to_vec(buffer)?;
+    let hash = blake3::hash(&data).to_hex().to_string();
+    Ok((data, hash))
+}
+
+/// Write an RGBA buffer to a `Vec<u8>` and return the hash.
+pub fn write_rgba_to_vec_with_hash(buffer: &TextureBuffer) -> Result<(Vec<u8>, String), ExrError> {
+    let data = write_rgba_to_vec(buffer)?;
+    let hash = blake3::hash(&data).to_hex().to_string();
+    Ok((data, hash))
+}
+
+/// A named channel and an accessor for its value at a row-major pixel index.
+struct ExrChannel<'a> {
+    name: &'static str,
+    values: &'a dyn Fn(usize) -> f64,
+}
+
+fn encode(width: u32, height: u32, channels: &[ExrChannel]) -> Result<Vec<u8>, ExrError> {
+    if width == 0 || height == 0 || width > i32::MAX as u32 || height > i32::MAX as u32 {
+        return Err(ExrError::InvalidDimensions(format!(
+            "{}x{} (must be between 1 and {})",
+            width,
+            height,
+            i32::MAX
+        )));
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&MAGIC.to_le_bytes());
+    out.extend_from_slice(&VERSION.to_le_bytes());
+
+    let mut chlist = Vec::new();
+    for channel in channels {
+        put_str(&mut chlist, channel.name);
+        chlist.extend_from_slice(&PIXEL_TYPE_FLOAT.to_le_bytes());
+        chlist.extend_from_slice(&[0, 0, 0, 0]); // pLinear + reserved
+        chlist.extend_from_slice(&1i32.to_le_bytes()); // xSampling
+        chlist.extend_from_slice(&1i32.to_le_bytes()); // ySampling
+    }
+    chlist.push(0);
+
+    let mut window = Vec::new();
+    for v in [0, 0, width as i32 - 1, height as i32 - 1] {
+        window.extend_from_slice(&v.to_le_bytes());
+    }
+
+    put_attribute(&mut out, "channels", "chlist", &chlist);
+    put_attribute(&mut out, "compression", "compression", &[COMPRESSION_NONE]);
+    put_attribute(&mut out, "dataWindow", "box2i", &window);
+    put_attribute(&mut out, "displayWindow", "box2i", &window);
+    put_attribute(
+        &mut out,
+        "lineOrder",
+        "lineOrder",
+        &[LINE_ORDER_INCREASING_Y],
+    );
+    put_attribute(&mut out, "pixelAspectRatio", "float", &1.0f32.to_le_bytes());
+    put_attribute(&mut out, "screenWindowCenter", "v2f", &[0; 8]);
+    put_attribute(
+        &mut out,
+        "screenWindowWidth",
+        "float",
+        &1.0f32.to_le_bytes(),
+    );
+    out.push(0); // end of header
+
+    let width = width as usize;
+    let line_bytes = width * channels.len() * 4;
+    let table_start = out.len();
+    let first_chunk = table_start + height as usize * 8;
+    for y in 0..height as usize {
+        let offset = (first_chunk + y * (8 + line_bytes)) as u64;
+        out.extend_from_slice(&offset.to_le_bytes());
+    }
+
+    for y in 0..height as usize {
+        out.extend_from_slice(&(y as i32).to_le_bytes());
+        out.extend_from_slice(&(line_bytes as i32).to_le_bytes());
+        for channel in channels {
+            for x in 0..width {
+                let value = (channel.values)(y * width + x) as f32;
+                out.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+fn put_str(out: &mut Vec<u8>, s: &str) {
+    out.extend_from_slice(s.as_bytes());
+    out.push(0);
+}
+
+fn put_attribute(out: &mut Vec<u8>, name: &str, type_name: &str, value: &[u8]) {
+    put_str(out, name);
+    put_str(out, type_name);
+    out.extend_from_slice(&(value.len() as i32).to_le_bytes());
+    out.extend_from_slice(value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+
+    /// Minimal decoder for the files written above: returns `(width, height,
+    /// channels)` with each channel's values in row-major order.
+    fn decode(data: &[u8]) -> (usize, usize, Vec<(String, Vec<f32>)>) {
+        let i32_at = |i: usize| i32::from_le_bytes(data[i..i + 4].try_into().unwrap());
+        let cstr_at = |i: usize| {
+            let end = i + data[i..].iter().position(|&b| b == 0).unwrap();
+            (String::from_utf8(data[i..end].to_vec()).unwrap(), end + 1)
+        };
+
+        assert_eq!(u32::from_le_bytes(data[0..4].try_into().unwrap()), MAGIC);
+        assert_eq!(u32::from_le_bytes(data[4..8].try_into().unwrap()), VERSION);
+
+        let mut pos = 8;
+        let mut names = Vec::new();
+        let mut window = [0i32; 4];
+        while data[pos] != 0 {
+            let (name, next) = cstr_at(pos);
+            let (_type_name, next) = cstr_at(next);
+            let size = i32_at(next) as usize;
+            let value = next + 4;
+            match name.as_str() {
+                "channels" => {
+                    let mut p = value;
+                    while data[p] != 0 {
+                        let (channel, next) = cstr_at(p);
+                        assert_eq!(i32_at(next), PIXEL_TYPE_FLOAT);
+                        names.push(channel);
+                        p = next + 16;
+                    }
+                }
+                "compression" => assert_eq!(data[value], COMPRESSION_NONE),
+                "dataWindow" => {
+                    for (k, w) in window.iter_mut().enumerate() {
+                        *w = i32_at(value + k * 4);
+                    }
+                }
+                _ => {}
+            }
+            pos = value + size;
+        }
+        pos += 1;
+
+        let width = (window[2] - window[0] + 1) as usize;
+        let height = (window[3] - window[1] + 1) as usize;
+        let mut channels: Vec<(String, Vec<f32>)> =
+            names.into_iter().map(|n| (n, Vec::new())).collect();
+        for y in 0..height {
+            let offset = u64::from_le_bytes(data[pos + y * 8..pos + y * 8 + 8].try_into().unwrap());
+            let mut p = offset as usize;
+            assert_eq!(i32_at(p), y as i32);
+            p += 8;
+            for (_, values) in channels.iter_mut() {
+                for _ in 0..width {
+                    values.push(f32::from_le_bytes(data[p..p + 4].try_into().unwrap()));
+                    p += 4;
+                }
+            }
+        }
+        (width, height, channels)
+    }
+
+    #[test]
+    fn test_grayscale_gradient_round_trips_exactly() {
+        let (width, height) = (37u32, 5u32);
+        let mut buffer = GrayscaleBuffer::new(width, height, 0.0);
+        for y in 0..height {
+            for x in 0..width {
+                // HDR range, including negatives and values above 1.0
+                let v = (x as f64 / (width - 1) as f64) * 8.0 - 2.0 + y as f64 * 0.125;
+                buffer.set(x, y, v);
+            }
+        }
+
+        let (data, hash) = write_grayscale_to_vec_with_hash(&buffer).unwrap();
+        let (w, h, channels) = decode(&data);
+        assert_eq!((w, h), (width as usize, height as usize));
+        assert_eq!(channels.len(), 1);
+        assert_eq!(channels[0].0, "Y");
+        let expected: Vec<f32> = buffer.data.iter().map(|&v| v as f32).collect();
+        assert_eq!(channels[0].1, expected);
+
+        let (data_b, hash_b) = write_grayscale_to_vec_with_hash(&buffer).unwrap();
+        assert_eq!(data, data_b);
+        assert_eq!(hash, hash_b);
+    }
+
+    #[test]
+    fn test_rgba_round_trips_exactly() {
+        let mut buffer = TextureBuffer::new(4, 3, Color::black());
+        for y in 0..3 {
+            for x in 0..4 {
+                let t = (y * 4 + x) as f64 / 11.0;
+                buffer.set(x, y, Color::rgba(t * 4.0, 1.0 - t, -t, 0.5 + t));
+            }
+        }
+
+        let (data, _) = write_rgba_to_vec_with_hash(&buffer).unwrap();
+        let (w, h, channels) = decode(&data);
+        assert_eq!((w, h), (4, 3));
+        let names: Vec<&str> = channels.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, ["A", "B", "G", "R"]);
+
+        for (name, values) in &channels {
+            let expected: Vec<f32> = buffer
+                .data
+                .iter()
+                .map(|c| match name.as_str() {
+                    "A" => c.a,
+                    "B" => c.b,
+                    "G" => c.g,
+                    _ => c.r,
+                } as f32)
+                .collect();
+            assert_eq!(values, &expected, "channel {}", name);
+        }
+    }
+
+    #[test]
+    fn test_rejects_empty_buffer() {
+        let buffer = GrayscaleBuffer::new(0, 4, 0.0);
+        assert!(matches!(
+            write_grayscale_to_vec(&buffer),
+            Err(ExrError::InvalidDimensions(_))
+        ));
+    }
+}
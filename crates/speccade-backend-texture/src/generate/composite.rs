@@ -0,0 +1,337 @@
+//! Layered texture compositing (`texture.composite_v1`).
+//!
+//! Each layer is a procedural sub-graph evaluated exactly like a standalone
+//! `texture.procedural_v1` graph (same resolution, tiling, and seed). Layer
+//! outputs are blended bottom-to-top in declaration order.
+
+use speccade_spec::recipe::texture::{
+    CompositeBlendMode, TextureCompositeV1Params, TextureProceduralV1Params,
+};
+
+use crate::color::{BlendMode, Color};
+use crate::maps::TextureBuffer;
+
+use super::graph::{generate_graph, GraphValue};
+use super::helpers::validate_resolution;
+use super::GenerateError;
+
+/// Generate the composited image for a `texture.composite_v1` recipe.
+///
+/// The result is grayscale when every layer output is grayscale; otherwise
+/// grayscale layers are promoted to opaque color and the result is RGBA.
+pub fn generate_composite(
+    params: &TextureCompositeV1Params,
+    seed: u32,
+) -> Result<GraphValue, GenerateError> {
+    let [width, height] = params.resolution;
+    validate_resolution(width, height)?;
+
+    if params.layers.is_empty() {
+        return Err(GenerateError::InvalidParameter(
+            "texture.composite_v1 requires at least 1 layer".to_string(),
+        ));
+    }
+
+    let mut canvas: Option<GraphValue> = None;
+
+    for (index, layer) in params.layers.iter().enumerate() {
+        if !(0.0..=1.0).contains(&layer.opacity) {
+            return Err(GenerateError::InvalidParameter(format!(
+                "layers[{}].opacity must be in [0, 1], got {}",
+                index, layer.opacity
+            )));
+        }
+
+        let graph = TextureProceduralV1Params {
+            resolution: params.resolution,
+            tileable: params.tileable,
+            nodes: layer.nodes.clone(),
+        };
+        let mut values = generate_graph(&graph, seed)?;
+        let value = values.remove(&layer.output).ok_or_else(|| {
+            GenerateError::InvalidParameter(format!(
+                "layers[{}].output '{}' does not match any node id",
+                index, layer.output
+            ))
+        })?;
+
+        canvas = Some(match canvas {
+            None => apply_base_opacity(value, layer.opacity),
+            Some(below) => blend_layer(below, value, layer.blend_mode, layer.opacity),
+        });
+    }
+
+    Ok(canvas.expect("at least one layer was composited"))
+}
+
+/// Scale the bottom layer against an empty (transparent black) canvas.
+fn apply_base_opacity(value: GraphValue, opacity: f64) -> GraphValue {
+    if opacity >= 1.0 {
+        return value;
+    }
+
+    match value {
+        GraphValue::Grayscale(mut buf) => {
+            for v in &mut buf.data {
+                *v *= opacity;
+            }
+            GraphValue::Grayscale(buf)
+        }
+        GraphValue::Color(mut buf) => {
+            let empty = Color::rgba(0.0, 0.0, 0.0, 0.0);
+            for c in &mut buf.data {
+                *c = empty.lerp(c, opacity);
+            }
+            GraphValue::Color(buf)
+        }
+    }
+}
+
+fn blend_layer(
+    below: GraphValue,
+    layer: GraphValue,
+    mode: CompositeBlendMode,
+    opacity: f64,
+) -> GraphValue {
+    let mode = to_blend_mode(mode);
+
+    match (below, layer) {
+        (GraphValue::Grayscale(mut dst), GraphValue::Grayscale(src)) => {
+            for (d, s) in dst.data.iter_mut().zip(&src.data) {
+                *d = mode
+                    .blend(&Color::gray(*d), &Color::gray(*s), opacity)
+                    .clamp()
+                    .r;
+            }
+            GraphValue::Grayscale(dst)
+        }
+        (below, layer) => {
+            let mut dst = into_color(below);
+            let src = into_color(layer);
+            for (d, s) in dst.data.iter_mut().zip(&src.data) {
+                *d = mode.blend(d, s, opacity).clamp();
+            }
+            GraphValue::Color(dst)
+        }
+    }
+}
+
+fn into_color(value: GraphValue) -> TextureBuffer {
+    match value {
+        GraphValue::Color(buf) => buf,
+        GraphValue::Grayscale(buf) => buf.to_texture_buffer(),
+    }
+}
+
+pub(super) fn to_blend_mode(mode: CompositeBlendMode) -> BlendMode {
+    match mode {
+        CompositeBlendMode::Normal => BlendMode::Normal,
+        CompositeBlendMode::Multiply => BlendMode::Multiply,
+        CompositeBlendMode::Add => BlendMode::Add,
+        CompositeBlendMode::Screen => BlendMode::Screen,
+        CompositeBlendMode::Overlay => BlendMode::Overlay,
+        CompositeBlendMode::SoftLight => BlendMode::SoftLight,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::encode_graph_value_png;
+    use speccade_spec::recipe::texture::{
+        NoiseAlgorithm, NoiseConfig, TextureCompositeLayer, TextureProceduralNode,
+        TextureProceduralOp,
+    };
+
+    fn noise_nodes(id: &str, algorithm: NoiseAlgorithm, scale: f64) -> Vec<TextureProceduralNode> {
+        vec![TextureProceduralNode {
+            id: id.to_string(),
+            op: TextureProceduralOp::Noise {
+                noise: NoiseConfig {
+                    algorithm,
+                    scale,
+                    octaves: 3,
+                    persistence: 0.5,
+                    lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
+                },
+            },
+        }]
+    }
+
+    fn layer(
+        nodes: Vec<TextureProceduralNode>,
+        output: &str,
+        blend_mode: CompositeBlendMode,
+        opacity: f64,
+    ) -> TextureCompositeLayer {
+        TextureCompositeLayer {
+            nodes,
+            output: output.to_string(),
+            blend_mode,
+            opacity,
+        }
+    }
+
+    fn run_graph(nodes: Vec<TextureProceduralNode>, output: &str, seed: u32) -> GraphValue {
+        let params = TextureProceduralV1Params {
+            resolution: [32, 32],
+            tileable: true,
+            nodes,
+        };
+        generate_graph(&params, seed)
+            .unwrap()
+            .remove(output)
+            .unwrap()
+    }
+
+    #[test]
+    fn single_layer_equals_running_graph_directly() {
+        let nodes = noise_nodes("n", NoiseAlgorithm::Perlin, 0.1);
+        let params = TextureCompositeV1Params {
+            resolution: [32, 32],
+            tileable: true,
+            layers: vec![layer(nodes.clone(), "n", CompositeBlendMode::Multiply, 1.0)],
+        };
+
+        let composite = generate_composite(&params, 42).unwrap();
+        let direct = run_graph(nodes, "n", 42);
+
+        let (composite_png, composite_hash) = encode_graph_value_png(&composite).unwrap();
+        let (direct_png, direct_hash) = encode_graph_value_png(&direct).unwrap();
+        assert_eq!(composite_hash, direct_hash);
+        assert_eq!(composite_png, direct_png);
+    }
+
+    #[test]
+    fn two_layers_match_manual_blend() {
+        let base_nodes = noise_nodes("base", NoiseAlgorithm::Perlin, 0.1);
+        let top_nodes = noise_nodes("top", NoiseAlgorithm::Simplex, 0.2);
+        let params = TextureCompositeV1Params {
+            resolution: [32, 32],
+            tileable: true,
+            layers: vec![
+                layer(base_nodes.clone(), "base", CompositeBlendMode::Normal, 1.0),
+                layer(top_nodes.clone(), "top", CompositeBlendMode::Screen, 0.6),
+            ],
+        };
+
+        let composite = generate_composite(&params, 7).unwrap();
+        let composite = composite.as_grayscale().unwrap();
+
+        let base = run_graph(base_nodes, "base", 7);
+        let top = run_graph(top_nodes, "top", 7);
+        let base = base.as_grayscale().unwrap();
+        let top = top.as_grayscale().unwrap();
+
+        for i in 0..composite.data.len() {
+            let b = base.data[i];
+            let t = top.data[i];
+            let screen = 1.0 - (1.0 - b) * (1.0 - t);
+            let expected = (b + (screen - b) * 0.6).clamp(0.0, 1.0);
+            assert!(
+                (composite.data[i] - expected).abs() < 1e-12,
+                "pixel {}: expected {}, got {}",
+                i,
+                expected,
+                composite.data[i]
+            );
+        }
+    }
+
+    #[test]
+    fn blend_order_is_deterministic_and_significant() {
+        let a = layer(
+            noise_nodes("a", NoiseAlgorithm::Perlin, 0.1),
+            "a",
+            CompositeBlendMode::Normal,
+            1.0,
+        );
+        let b = layer(
+            noise_nodes("b", NoiseAlgorithm::Worley, 0.15),
+            "b",
+            CompositeBlendMode::Normal,
+            0.5,
+        );
+
+        let ab = TextureCompositeV1Params {
+            resolution: [32, 32],
+            tileable: true,
+            layers: vec![a.clone(), b.clone()],
+        };
+        let ba = TextureCompositeV1Params {
+            resolution: [32, 32],
+            tileable: true,
+            layers: vec![b, a],
+        };
+
+        let (_, hash_ab_1) = encode_graph_value_png(&generate_composite(&ab, 3).unwrap()).unwrap();
+        let (_, hash_ab_2) = encode_graph_value_png(&generate_composite(&ab, 3).unwrap()).unwrap();
+        let (_, hash_ba) = encode_graph_value_png(&generate_composite(&ba, 3).unwrap()).unwrap();
+
+        assert_eq!(hash_ab_1, hash_ab_2);
+        assert_ne!(hash_ab_1, hash_ba);
+    }
+
+    #[test]
+    fn color_layer_promotes_result_to_color() {
+        let ramp_nodes = vec![
+            TextureProceduralNode {
+                id: "g".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.5 },
+            },
+            TextureProceduralNode {
+                id: "tint".to_string(),
+                op: TextureProceduralOp::ColorRamp {
+                    input: "g".to_string(),
+                    ramp: vec!["#000000".to_string(), "#FF0000".to_string()],
+                },
+            },
+        ];
+        let params = TextureCompositeV1Params {
+            resolution: [8, 8],
+            tileable: true,
+            layers: vec![
+                layer(
+                    vec![TextureProceduralNode {
+                        id: "c".to_string(),
+                        op: TextureProceduralOp::Constant { value: 1.0 },
+                    }],
+                    "c",
+                    CompositeBlendMode::Normal,
+                    1.0,
+                ),
+                layer(ramp_nodes, "tint", CompositeBlendMode::Multiply, 1.0),
+            ],
+        };
+
+        let result = generate_composite(&params, 1).unwrap();
+        let color = result.as_color().expect("expected color output");
+        let px = color.get(0, 0);
+        assert!(px.g.abs() < 1e-9 && px.b.abs() < 1e-9);
+        assert!(px.r > 0.0);
+    }
+
+    #[test]
+    fn unknown_layer_output_is_error() {
+        let params = TextureCompositeV1Params {
+            resolution: [8, 8],
+            tileable: true,
+            layers: vec![layer(
+                noise_nodes("n", NoiseAlgorithm::Perlin, 0.1),
+                "missing",
+                CompositeBlendMode::Normal,
+                1.0,
+            )],
+        };
+
+        let err = generate_composite(&params, 1).unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+}
@@ -0,0 +1,60 @@
+//! Threshold matrices for the `dither` node.
+
+use speccade_spec::recipe::texture::DitherMethod;
+
+/// 4x4 ordered (Bayer) matrix, ranks 0-15.
+#[rustfmt::skip]
+const BAYER4: [u8; 16] = [
+     0,  8,  2, 10,
+    12,  4, 14,  6,
+     3, 11,  1,  9,
+    15,  7, 13,  5,
+];
+
+/// 8x8 ordered (Bayer) matrix, ranks 0-63.
+#[rustfmt::skip]
+const BAYER8: [u8; 64] = [
+     0, 32,  8, 40,  2, 34, 10, 42,
+    48, 16, 56, 24, 50, 18, 58, 26,
+    12, 44,  4, 36, 14, 46,  6, 38,
+    60, 28, 52, 20, 62, 30, 54, 22,
+     3, 35, 11, 43,  1, 33,  9, 41,
+    51, 19, 59, 27, 49, 17, 57, 25,
+    15, 47,  7, 39, 13, 45,  5, 37,
+    63, 31, 55, 23, 61, 29, 53, 21,
+];
+
+/// 16x16 blue-noise tile, ranks 0-255.
+///
+/// Generated once with void-and-cluster (toroidal Gaussian, sigma 1.5) and
+/// embedded so output never depends on a generator; it wraps seamlessly.
+#[rustfmt::skip]
+const BLUE_NOISE16: [u8; 256] = [
+    120,  61, 134, 223,  84,  33, 168,  12, 113, 225,  63, 246, 185, 233,  88, 169,
+     23, 206, 181,  17, 109, 214,  58, 140, 201,  24, 161,  93,  34, 133,  14, 221,
+    144,  73, 250,  49, 158, 187,  81, 251, 100,  51, 142, 210, 172,  57, 191, 106,
+     42, 167, 101, 126, 220,   3, 121,  40, 170, 231,  82,   8, 114, 255,  80, 232,
+    212,  11, 195,  31,  72, 239, 152, 196,  16, 127, 188, 222,  45, 157,  26, 128,
+    154,  87, 235, 143, 179,  94,  54, 108, 237,  65,  29, 105, 139, 207, 184,  66,
+    248,  47, 115,  62, 209,  20, 164, 217,  79, 146, 178, 243,  69,  90,   1, 118,
+     30, 190, 173,   6, 131, 254,  41, 136,  10, 204,  43, 159,  22, 229, 162, 218,
+     77, 148,  99, 226,  74, 182, 117, 192,  86, 247, 119,  97, 197, 130,  53, 103,
+    242,  19, 198,  44, 155,  96,  59, 230,  28, 165,  60,   5, 240,  39, 175, 202,
+    137,  64, 122, 238,  25, 211,   0, 149, 104, 224, 135, 183, 151,  71, 112,   9,
+     91, 213, 166,  85, 186, 111, 249, 174,  48,  75, 208,  32,  89, 205, 236, 160,
+     37, 252,  18,  55, 138,  38,  78, 123, 194,  13, 107, 253, 124,  15,  56, 189,
+     76, 145, 110, 228, 203, 163, 219,  21, 241, 141, 171,  50, 156, 227, 102, 129,
+      2, 199, 176,  68,   7,  98,  52, 150,  92,  36, 215,  83, 200,  27, 177, 216,
+    244,  95,  35, 153, 245, 125, 193, 234,  70, 180, 132,   4, 116,  67, 147,  46,
+];
+
+/// Threshold in (0, 1) for pixel `(x, y)`, tiling the method's matrix.
+pub(super) fn threshold(method: DitherMethod, x: u32, y: u32) -> f64 {
+    let (matrix, size): (&[u8], u32) = match method {
+        DitherMethod::Bayer4 => (&BAYER4, 4),
+        DitherMethod::Bayer8 => (&BAYER8, 8),
+        DitherMethod::BlueNoise => (&BLUE_NOISE16, 16),
+    };
+    let rank = matrix[((y % size) * size + x % size) as usize];
+    (rank as f64 + 0.5) / matrix.len() as f64
+}
@@ -1,6 +1,6 @@
-//! PNG encoding for graph values.
+//! PNG and EXR encoding for graph values.
 
-use crate::png;
+use crate::{exr, png};
 
 use super::super::GenerateError;
 use super::GraphValue;
@@ -20,3 +20,15 @@ pub fn encode_graph_value_png(value: &GraphValue) -> Result<(Vec<u8>, String), G
         }
     }
 }
+
+/// Encode a graph value as 32-bit float EXR bytes (deterministic) and return
+/// `(bytes, blake3_hash)`.
+///
+/// Grayscale values are written to a single `Y` channel and colors to RGBA,
+/// without clamping or quantization.
+pub fn encode_graph_value_exr(value: &GraphValue) -> Result<(Vec<u8>, String), GenerateError> {
+    match value {
+        GraphValue::Grayscale(buf) => Ok(exr::write_grayscale_to_vec_with_hash(buf)?),
+        GraphValue::Color(buf) => Ok(exr::write_rgba_to_vec_with_hash(buf)?),
+    }
+}
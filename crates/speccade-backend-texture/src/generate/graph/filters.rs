@@ -118,6 +118,80 @@ pub(super) fn apply_morphology(
     out
 }
 
+/// Height profile of a bevel along the edges of a mask.
+///
+/// Pixels above 0.5 are inside the mask. Each inside pixel gets
+/// `(d - 0.5) / distance` clamped to [0, 1], where `d` is the Euclidean
+/// distance to the nearest outside pixel; outside pixels are 0. `softness`
+/// blends the linear ramp towards a smoothstep curve. The edge search wraps
+/// when `tileable` is set and ignores the image border otherwise.
+pub(super) fn apply_bevel_height(
+    input: &GrayscaleBuffer,
+    distance: f64,
+    softness: f64,
+    tileable: bool,
+) -> GrayscaleBuffer {
+    let w = input.width as i64;
+    let h = input.height as i64;
+    // Distances beyond the bevel width saturate, so the search can stop there.
+    let reach = distance.ceil() as i64 + 1;
+    let far = (reach + 1) as f64;
+
+    let resolve = |i: i64, len: i64| {
+        if tileable {
+            Some(i.rem_euclid(len))
+        } else {
+            (0..len).contains(&i).then_some(i)
+        }
+    };
+    let inside = |x: i64, y: i64| input.data[(y * w + x) as usize] > 0.5;
+
+    // Separable exact distance transform, limited to `reach`: first the
+    // horizontal distance to an outside pixel in each row, then the minimum of
+    // dy^2 + row_dist^2 down each column.
+    let mut row_dist = vec![far; (w * h) as usize];
+    for y in 0..h {
+        for x in 0..w {
+            if !inside(x, y) {
+                row_dist[(y * w + x) as usize] = 0.0;
+                continue;
+            }
+            for dx in 1..=reach {
+                let hit = [x - dx, x + dx]
+                    .into_iter()
+                    .filter_map(|sx| resolve(sx, w))
+                    .any(|sx| !inside(sx, y));
+                if hit {
+                    row_dist[(y * w + x) as usize] = dx as f64;
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut out = GrayscaleBuffer::new(input.width, input.height, 0.0);
+    for y in 0..h {
+        for x in 0..w {
+            if !inside(x, y) {
+                continue;
+            }
+            let mut best = far * far;
+            for dy in -reach..=reach {
+                if let Some(sy) = resolve(y + dy, h) {
+                    let rd = row_dist[(sy * w + x) as usize];
+                    best = best.min((dy * dy) as f64 + rd * rd);
+                }
+            }
+
+            let t = ((best.sqrt() - 0.5) / distance).clamp(0.0, 1.0);
+            let smooth = t * t * (3.0 - 2.0 * t);
+            out.set(x as u32, y as u32, t + (smooth - t) * softness);
+        }
+    }
+
+    out
+}
+
 /// Apply domain warp using a displacement map.
 /// The displacement grayscale (0-1) is centered at 0.5, meaning:
 /// - 0.0 = maximum negative offset
@@ -150,6 +224,53 @@ pub(super) fn apply_warp(
     out
 }
 
+/// Apply domain warp using separate x and y displacement fields.
+///
+/// Each pixel samples `input` at `(x + amount * warp_x, y + amount * warp_y)`
+/// with bilinear interpolation. Coordinates wrap when `tileable` is set and are
+/// clamped to the edge otherwise.
+pub(super) fn apply_domain_warp(
+    input: &GrayscaleBuffer,
+    warp_x: &GrayscaleBuffer,
+    warp_y: &GrayscaleBuffer,
+    amount: f64,
+    tileable: bool,
+) -> GrayscaleBuffer {
+    let w = input.width;
+    let h = input.height;
+    let mut out = GrayscaleBuffer::new(w, h, 0.0);
+
+    let texel = |x: i64, y: i64| {
+        if tileable {
+            input.get(x.rem_euclid(w as i64) as u32, y.rem_euclid(h as i64) as u32)
+        } else {
+            input.get(
+                x.clamp(0, w as i64 - 1) as u32,
+                y.clamp(0, h as i64 - 1) as u32,
+            )
+        }
+    };
+
+    for y in 0..h {
+        for x in 0..w {
+            let src_x = x as f64 + amount * warp_x.get(x, y);
+            let src_y = y as f64 + amount * warp_y.get(x, y);
+
+            let x0 = src_x.floor();
+            let y0 = src_y.floor();
+            let fx = src_x - x0;
+            let fy = src_y - y0;
+            let (x0, y0) = (x0 as i64, y0 as i64);
+
+            let top = texel(x0, y0) * (1.0 - fx) + texel(x0 + 1, y0) * fx;
+            let bottom = texel(x0, y0 + 1) * (1.0 - fx) + texel(x0 + 1, y0 + 1) * fx;
+            out.set(x, y, top * (1.0 - fy) + bottom * fy);
+        }
+    }
+
+    out
+}
+
 /// Blend mode type.
 pub(super) enum BlendMode {
     Screen,
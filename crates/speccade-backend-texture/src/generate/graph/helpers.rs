@@ -75,6 +75,63 @@ pub(super) fn sample_color_ramp(ramp: &[Color], t: f64) -> Color {
     ramp[idx].lerp(&ramp[idx + 1], frac)
 }
 
+/// Parses `(position, hex_color)` stops, requiring sorted positions in [0, 1].
+pub(super) fn parse_color_stops(
+    stops: &[(f64, String)],
+    name: &str,
+) -> Result<Vec<(f64, Color)>, GenerateError> {
+    if stops.is_empty() {
+        return Err(GenerateError::InvalidParameter(format!(
+            "{} must contain at least 1 stop",
+            name
+        )));
+    }
+
+    let mut parsed = Vec::with_capacity(stops.len());
+    for (i, (position, color)) in stops.iter().enumerate() {
+        if !(0.0..=1.0).contains(position) {
+            return Err(GenerateError::InvalidParameter(format!(
+                "{}[{}] position must be in [0, 1], got {}",
+                name, i, position
+            )));
+        }
+        if let Some(&(previous, _)) = parsed.last() {
+            if *position < previous {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "{}[{}] position {} is before the previous stop at {}; stops must be sorted",
+                    name, i, position, previous
+                )));
+            }
+        }
+        let color = Color::from_hex_rgb(color).map_err(|e| {
+            GenerateError::InvalidParameter(format!("{}[{}] '{}': {}", name, i, color, e))
+        })?;
+        parsed.push((*position, color));
+    }
+    Ok(parsed)
+}
+
+/// Like [`sample_color_ramp`], but with explicit stop positions.
+///
+/// Values before the first stop or after the last one clamp to that stop's color.
+pub(super) fn sample_color_stops(stops: &[(f64, Color)], t: f64) -> Color {
+    debug_assert!(!stops.is_empty(), "stops must not be empty");
+
+    let t = t.clamp(0.0, 1.0);
+    let next = stops.partition_point(|&(position, _)| position <= t);
+    if next == 0 {
+        return stops[0].1;
+    }
+    if next == stops.len() {
+        return stops[stops.len() - 1].1;
+    }
+
+    let (p0, c0) = stops[next - 1];
+    let (p1, c1) = stops[next];
+    // partition_point guarantees p0 <= t < p1, so the span is never zero
+    c0.lerp(&c1, (t - p0) / (p1 - p0))
+}
+
 pub(super) fn nearest_palette_color(palette: &[Color], color: Color) -> Color {
     debug_assert!(!palette.is_empty(), "palette must not be empty");
 
@@ -1,6 +1,6 @@
 //! Map-agnostic procedural texture generator.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use speccade_spec::recipe::texture::TextureProceduralV1Params;
 
@@ -8,21 +8,25 @@ use crate::maps::{GrayscaleBuffer, TextureBuffer};
 
 use super::GenerateError;
 
+mod dither;
 mod encoding;
 mod filters;
 mod helpers;
+mod node_cache;
 mod operations;
 mod ops_color;
 mod ops_filter;
 mod ops_math;
 mod ops_primitive;
 mod ops_stochastic;
+mod topology;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
-pub use encoding::encode_graph_value_png;
+pub use encoding::{encode_graph_value_exr, encode_graph_value_png};
+pub use node_cache::{node_cache_keys, DiskNodeCache, NodeCache};
 
 /// A graph node's evaluated value.
 #[derive(Debug, Clone)]
@@ -51,10 +55,49 @@ impl GraphValue {
 pub fn generate_graph(
     params: &TextureProceduralV1Params,
     seed: u32,
+) -> Result<HashMap<String, GraphValue>, GenerateError> {
+    evaluate_graph(params, seed, HashMap::new())
+}
+
+/// Generate all nodes for a `texture.procedural_v1` recipe, reusing nodes
+/// stored in `cache`.
+///
+/// Nodes whose key (see [`node_cache_keys`]) is already cached are loaded
+/// instead of evaluated; every node that had to be evaluated is stored back.
+/// The result is identical to [`generate_graph`].
+pub fn generate_graph_cached(
+    params: &TextureProceduralV1Params,
+    seed: u32,
+    cache: &mut dyn NodeCache,
+) -> Result<HashMap<String, GraphValue>, GenerateError> {
+    let keys = node_cache_keys(params, seed)?;
+
+    let mut preloaded = HashMap::new();
+    for node in &params.nodes {
+        if let Some(value) = cache.get(&keys[&node.id]) {
+            preloaded.insert(node.id.as_str(), value);
+        }
+    }
+    let hits: HashSet<String> = preloaded.keys().map(|id| id.to_string()).collect();
+
+    let nodes = evaluate_graph(params, seed, preloaded)?;
+    for (id, value) in &nodes {
+        if !hits.contains(id) {
+            cache.put(&keys[id], value);
+        }
+    }
+
+    Ok(nodes)
+}
+
+/// Evaluates every node, starting from already-known node values.
+fn evaluate_graph<'a>(
+    params: &'a TextureProceduralV1Params,
+    seed: u32,
+    known: HashMap<&'a str, GraphValue>,
 ) -> Result<HashMap<String, GraphValue>, GenerateError> {
     use super::helpers::validate_resolution;
     use operations::eval_node;
-    use std::collections::HashSet;
 
     let [width, height] = params.resolution;
     validate_resolution(width, height)?;
@@ -65,25 +108,18 @@ pub fn generate_graph(
         ));
     }
 
-    let mut nodes_by_id: HashMap<&str, &speccade_spec::recipe::texture::TextureProceduralNode> =
-        HashMap::new();
-    for node in &params.nodes {
-        if nodes_by_id.insert(node.id.as_str(), node).is_some() {
-            return Err(GenerateError::InvalidParameter(format!(
-                "duplicate node id: '{}'",
-                node.id
-            )));
-        }
-    }
+    let order = topology::topological_order(&params.nodes)?;
+    let nodes_by_id: HashMap<&str, &speccade_spec::recipe::texture::TextureProceduralNode> =
+        order.iter().map(|node| (node.id.as_str(), *node)).collect();
 
-    let mut cache: HashMap<&str, GraphValue> = HashMap::new();
+    let mut cache: HashMap<&str, GraphValue> = known;
     let mut visiting: HashSet<&str> = HashSet::new();
 
-    // Evaluate everything (small graphs; keeps output binding simple).
-    let node_ids: Vec<&str> = nodes_by_id.keys().copied().collect();
-    for node_id in node_ids {
+    // Inputs come first, so every node finds its inputs already evaluated and
+    // shared inputs are evaluated once.
+    for node in order {
         eval_node(
-            node_id,
+            node.id.as_str(),
             &nodes_by_id,
             &mut cache,
             &mut visiting,
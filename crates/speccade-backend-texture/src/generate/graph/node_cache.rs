@@ -0,0 +1,193 @@
+//! Node-level caching for incremental graph evaluation.
+//!
+//! Each node gets a content key derived from its op, its id (which feeds the
+//! node seed), the graph seed, resolution, tiling, and the keys of its inputs.
+//! Editing a node changes its key and the keys of everything downstream, while
+//! untouched upstream nodes keep theirs and can be loaded instead of recomputed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use speccade_spec::recipe::texture::{TextureProceduralNode, TextureProceduralV1Params};
+
+use crate::maps::{GrayscaleBuffer, TextureBuffer};
+
+use super::super::GenerateError;
+use super::topology::{op_inputs, topological_order};
+use super::GraphValue;
+use crate::color::Color;
+
+/// Bumped whenever node evaluation or the value encoding changes.
+const NODE_CACHE_VERSION: u32 = 1;
+
+/// Value encoding tags.
+const TAG_GRAYSCALE: u8 = 0;
+const TAG_COLOR: u8 = 1;
+
+/// Storage for evaluated graph nodes, keyed by content hash.
+pub trait NodeCache {
+    /// Returns the cached value for `key`, if present.
+    fn get(&mut self, key: &str) -> Option<GraphValue>;
+
+    /// Stores a freshly evaluated value under `key`.
+    fn put(&mut self, key: &str, value: &GraphValue);
+}
+
+/// A [`NodeCache`] that persists node values as files in a directory, so they
+/// can be reused across processes.
+///
+/// The cache is best-effort: unreadable or corrupt entries are treated as
+/// misses and write failures are ignored.
+#[derive(Debug, Clone)]
+pub struct DiskNodeCache {
+    dir: PathBuf,
+}
+
+impl DiskNodeCache {
+    /// Creates a cache rooted at `dir`. The directory is created on first write.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.node", key))
+    }
+}
+
+impl NodeCache for DiskNodeCache {
+    fn get(&mut self, key: &str) -> Option<GraphValue> {
+        let data = fs::read(self.entry_path(key)).ok()?;
+        GraphValue::from_bytes(&data)
+    }
+
+    fn put(&mut self, key: &str, value: &GraphValue) {
+        let path = self.entry_path(key);
+        // Write then rename so concurrent readers never see a partial entry
+        let tmp = path.with_extension(format!("node.{}.tmp", std::process::id()));
+        let written = fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&tmp, value.to_bytes()))
+            .and_then(|_| fs::rename(&tmp, &path));
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+    }
+}
+
+impl GraphValue {
+    /// Encodes the value losslessly for a [`NodeCache`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let (tag, width, height, values): (u8, u32, u32, Vec<f64>) = match self {
+            GraphValue::Grayscale(buf) => (TAG_GRAYSCALE, buf.width, buf.height, buf.data.clone()),
+            GraphValue::Color(buf) => (
+                TAG_COLOR,
+                buf.width,
+                buf.height,
+                buf.data.iter().flat_map(|c| [c.r, c.g, c.b, c.a]).collect(),
+            ),
+        };
+
+        let mut out = Vec::with_capacity(9 + values.len() * 8);
+        out.push(tag);
+        out.extend_from_slice(&width.to_le_bytes());
+        out.extend_from_slice(&height.to_le_bytes());
+        for v in values {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        out
+    }
+
+    /// Decodes a value written by [`GraphValue::to_bytes`], or returns `None`
+    /// if the data is malformed.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        let (&tag, rest) = data.split_first()?;
+        let width = u32::from_le_bytes(rest.get(0..4)?.try_into().ok()?);
+        let height = u32::from_le_bytes(rest.get(4..8)?.try_into().ok()?);
+        let payload = &rest[8..];
+
+        let pixels = (width as usize).checked_mul(height as usize)?;
+        let channels = match tag {
+            TAG_GRAYSCALE => 1,
+            TAG_COLOR => 4,
+            _ => return None,
+        };
+        if payload.len() != pixels.checked_mul(channels * 8)? {
+            return None;
+        }
+
+        let values: Vec<f64> = payload
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+            .collect();
+        Some(if tag == TAG_GRAYSCALE {
+            GraphValue::Grayscale(GrayscaleBuffer {
+                width,
+                height,
+                data: values,
+            })
+        } else {
+            GraphValue::Color(TextureBuffer {
+                width,
+                height,
+                data: values
+                    .chunks_exact(4)
+                    .map(|c| Color::rgba(c[0], c[1], c[2], c[3]))
+                    .collect(),
+            })
+        })
+    }
+}
+
+/// Computes the cache key of every node in the graph.
+pub fn node_cache_keys(
+    params: &TextureProceduralV1Params,
+    seed: u32,
+) -> Result<HashMap<String, String>, GenerateError> {
+    let mut keys: HashMap<&str, String> = HashMap::new();
+    for node in topological_order(&params.nodes)? {
+        let key = node_key(node, params, seed, &keys)?;
+        keys.insert(node.id.as_str(), key);
+    }
+
+    Ok(keys
+        .into_iter()
+        .map(|(id, key)| (id.to_string(), key))
+        .collect())
+}
+
+/// Computes the key of `node`; `keys` must already hold the keys of its inputs.
+fn node_key(
+    node: &TextureProceduralNode,
+    params: &TextureProceduralV1Params,
+    seed: u32,
+    keys: &HashMap<&str, String>,
+) -> Result<String, GenerateError> {
+    let op = serde_json::to_value(&node.op)
+        .map_err(|e| GenerateError::InvalidParameter(e.to_string()))
+        .and_then(|v| {
+            speccade_spec::hash::canonicalize_json(&v)
+                .map_err(|e| GenerateError::InvalidParameter(e.to_string()))
+        })?;
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(
+        format!(
+            "texture.procedural_v1/node/v{}\n{}\n{}\n{}\n{}x{}\n{}\n{}\n",
+            NODE_CACHE_VERSION,
+            env!("CARGO_PKG_VERSION"),
+            node.id,
+            seed,
+            params.resolution[0],
+            params.resolution[1],
+            params.tileable,
+            op
+        )
+        .as_bytes(),
+    );
+    for input in op_inputs(&node.op) {
+        hasher.update(keys[input].as_bytes());
+        hasher.update(b"\n");
+    }
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
@@ -9,18 +9,20 @@ use crate::rng::DeterministicRng;
 use super::super::GenerateError;
 use super::helpers::{expect_color, expect_gray};
 use super::ops_color::{
-    eval_color_ramp, eval_compose_rgba, eval_normal_from_height, eval_palette, eval_to_grayscale,
+    eval_color_ramp, eval_compose_rgba, eval_dither, eval_gradient_map, eval_normal_from_height,
+    eval_palette, eval_posterize, eval_to_grayscale,
 };
 use super::ops_filter::{
-    eval_blend_difference, eval_blend_overlay, eval_blend_screen, eval_blend_soft_light, eval_blur,
-    eval_dilate, eval_erode, eval_uv_rotate, eval_uv_scale, eval_uv_translate, eval_warp,
+    eval_bevel, eval_blend, eval_blend_difference, eval_blend_overlay, eval_blend_screen,
+    eval_blend_soft_light, eval_blur, eval_dilate, eval_domain_warp, eval_erode, eval_uv_rotate,
+    eval_uv_scale, eval_uv_translate, eval_warp,
 };
 use super::ops_math::{
-    eval_add, eval_clamp, eval_invert, eval_lerp, eval_multiply, eval_threshold,
+    eval_add, eval_clamp, eval_invert, eval_lerp, eval_math, eval_multiply, eval_threshold,
 };
 use super::ops_primitive::{
-    eval_checkerboard, eval_constant, eval_gradient, eval_noise, eval_reaction_diffusion,
-    eval_stripes,
+    eval_checkerboard, eval_constant, eval_directional_noise, eval_gradient, eval_noise,
+    eval_pattern, eval_reaction_diffusion, eval_stripes,
 };
 use super::ops_stochastic::{eval_texture_bomb, eval_wang_tiles, BombBlendMode};
 use super::GraphValue;
@@ -71,6 +73,20 @@ pub(super) fn eval_node<'a>(
             eval_noise(width, height, tileable, noise, derived_seed)
         }
 
+        TextureProceduralOp::DirectionalNoise {
+            noise,
+            angle,
+            stretch,
+        } => eval_directional_noise(
+            width,
+            height,
+            tileable,
+            noise,
+            *angle,
+            *stretch,
+            derived_seed,
+        )?,
+
         TextureProceduralOp::ReactionDiffusion {
             steps,
             feed,
@@ -117,6 +133,10 @@ pub(super) fn eval_node<'a>(
             color2,
         } => eval_checkerboard(width, height, *tile_size, *color1, *color2),
 
+        TextureProceduralOp::Pattern { pattern } => {
+            eval_pattern(width, height, tileable, pattern, derived_seed)?
+        }
+
         // -----------------------------------------------------------------
         // Grayscale math ops
         // -----------------------------------------------------------------
@@ -254,6 +274,20 @@ pub(super) fn eval_node<'a>(
             eval_threshold(in_buf, width, height, *threshold)
         }
 
+        TextureProceduralOp::Math { input, op, operand } => {
+            eval_dep!(
+                input,
+                nodes_by_id,
+                cache,
+                visiting,
+                width,
+                height,
+                tileable,
+                seed
+            );
+            eval_math(&cache[input.as_str()], *op, *operand)
+        }
+
         // -----------------------------------------------------------------
         // Filter ops
         // -----------------------------------------------------------------
@@ -302,6 +336,27 @@ pub(super) fn eval_node<'a>(
             eval_dilate(in_buf, *radius)
         }
 
+        TextureProceduralOp::Bevel {
+            input,
+            distance,
+            softness,
+            light_angle,
+            mode,
+        } => {
+            eval_dep!(
+                input,
+                nodes_by_id,
+                cache,
+                visiting,
+                width,
+                height,
+                tileable,
+                seed
+            );
+            let in_buf = expect_gray(cache, input)?;
+            eval_bevel(in_buf, *distance, *softness, *light_angle, *mode, tileable)?
+        }
+
         TextureProceduralOp::Warp {
             input,
             displacement,
@@ -332,6 +387,30 @@ pub(super) fn eval_node<'a>(
             eval_warp(in_buf, disp_buf, *strength)
         }
 
+        TextureProceduralOp::DomainWarp {
+            input,
+            warp_x,
+            warp_y,
+            amount,
+        } => {
+            for dep in [input, warp_x, warp_y] {
+                eval_dep!(
+                    dep,
+                    nodes_by_id,
+                    cache,
+                    visiting,
+                    width,
+                    height,
+                    tileable,
+                    seed
+                );
+            }
+            let in_buf = expect_gray(cache, input)?;
+            let x_buf = expect_gray(cache, warp_x)?;
+            let y_buf = expect_gray(cache, warp_y)?;
+            eval_domain_warp(in_buf, x_buf, y_buf, *amount, tileable)
+        }
+
         // -----------------------------------------------------------------
         // Blend modes
         // -----------------------------------------------------------------
@@ -439,6 +518,38 @@ pub(super) fn eval_node<'a>(
             eval_blend_difference(base_buf, blend_buf)
         }
 
+        TextureProceduralOp::Blend {
+            a,
+            b,
+            mode,
+            mask,
+            opacity,
+        } => {
+            for dep in [a, b].into_iter().chain(mask) {
+                eval_dep!(
+                    dep,
+                    nodes_by_id,
+                    cache,
+                    visiting,
+                    width,
+                    height,
+                    tileable,
+                    seed
+                );
+            }
+            let mask_buf = match mask {
+                Some(mask) => Some(expect_gray(cache, mask)?),
+                None => None,
+            };
+            eval_blend(
+                &cache[a.as_str()],
+                &cache[b.as_str()],
+                *mode,
+                mask_buf,
+                *opacity,
+            )?
+        }
+
         // -----------------------------------------------------------------
         // UV transforms
         // -----------------------------------------------------------------
@@ -528,6 +639,21 @@ pub(super) fn eval_node<'a>(
             eval_color_ramp(in_buf, width, height, ramp)?
         }
 
+        TextureProceduralOp::GradientMap { input, stops } => {
+            eval_dep!(
+                input,
+                nodes_by_id,
+                cache,
+                visiting,
+                width,
+                height,
+                tileable,
+                seed
+            );
+            let in_buf = expect_gray(cache, input)?;
+            eval_gradient_map(in_buf, width, height, stops)?
+        }
+
         TextureProceduralOp::Palette { input, palette } => {
             eval_dep!(
                 input,
@@ -543,6 +669,42 @@ pub(super) fn eval_node<'a>(
             eval_palette(in_buf, palette)?
         }
 
+        TextureProceduralOp::Posterize {
+            input,
+            levels,
+            include_alpha,
+        } => {
+            eval_dep!(
+                input,
+                nodes_by_id,
+                cache,
+                visiting,
+                width,
+                height,
+                tileable,
+                seed
+            );
+            eval_posterize(&cache[input.as_str()], *levels, *include_alpha)?
+        }
+
+        TextureProceduralOp::Dither {
+            input,
+            levels,
+            method,
+        } => {
+            eval_dep!(
+                input,
+                nodes_by_id,
+                cache,
+                visiting,
+                width,
+                height,
+                tileable,
+                seed
+            );
+            eval_dither(&cache[input.as_str()], *levels, *method)?
+        }
+
         TextureProceduralOp::ComposeRgba { r, g, b, a } => {
             eval_dep!(
                 r,
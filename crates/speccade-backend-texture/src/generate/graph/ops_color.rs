@@ -1,10 +1,17 @@
-//! Color transformation operations (to_grayscale, color_ramp, palette, compose_rgba, normal_from_height).
+//! Color transformation operations (to_grayscale, color_ramp, gradient_map, palette, posterize,
+//! dither, compose_rgba, normal_from_height).
+
+use speccade_spec::recipe::texture::DitherMethod;
 
 use crate::color::Color;
 use crate::maps::{GrayscaleBuffer, NormalGenerator, TextureBuffer};
 
 use super::super::GenerateError;
-use super::helpers::{nearest_palette_color, parse_hex_color_list, sample_color_ramp};
+use super::dither::threshold;
+use super::helpers::{
+    nearest_palette_color, parse_color_stops, parse_hex_color_list, sample_color_ramp,
+    sample_color_stops,
+};
 use super::GraphValue;
 
 /// Convert a color buffer to grayscale using luminance.
@@ -32,6 +39,22 @@ pub(super) fn eval_color_ramp(
     Ok(GraphValue::Color(out))
 }
 
+/// Map grayscale values to colors through positioned gradient stops.
+pub(super) fn eval_gradient_map(
+    input: &GrayscaleBuffer,
+    width: u32,
+    height: u32,
+    stops: &[(f64, String)],
+) -> Result<GraphValue, GenerateError> {
+    let stops = parse_color_stops(stops, "stops")?;
+    let mut out = TextureBuffer::new(width, height, Color::black());
+    for i in 0..input.data.len() {
+        let mapped = sample_color_stops(&stops, input.data[i]);
+        out.data[i] = Color::rgba(mapped.r, mapped.g, mapped.b, 1.0);
+    }
+    Ok(GraphValue::Color(out))
+}
+
 /// Quantize colors to nearest palette color.
 pub(super) fn eval_palette(
     input: &TextureBuffer,
@@ -47,6 +70,94 @@ pub(super) fn eval_palette(
     Ok(GraphValue::Color(out))
 }
 
+/// Snap each channel to `levels` evenly spaced steps in [0, 1].
+///
+/// Grayscale input stays grayscale; color input keeps its alpha unless
+/// `include_alpha` is set.
+pub(super) fn eval_posterize(
+    input: &GraphValue,
+    levels: u32,
+    include_alpha: bool,
+) -> Result<GraphValue, GenerateError> {
+    if levels < 2 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "posterize levels must be at least 2, got {}",
+            levels
+        )));
+    }
+
+    let steps = (levels - 1) as f64;
+    let snap = |v: f64| (v.clamp(0.0, 1.0) * steps).round() / steps;
+
+    Ok(match input {
+        GraphValue::Grayscale(buf) => {
+            let mut out = buf.clone();
+            out.data.iter_mut().for_each(|v| *v = snap(*v));
+            GraphValue::Grayscale(out)
+        }
+        GraphValue::Color(buf) => {
+            let mut out = buf.clone();
+            for pixel in &mut out.data {
+                let a = if include_alpha {
+                    snap(pixel.a)
+                } else {
+                    pixel.a
+                };
+                *pixel = Color::rgba(snap(pixel.r), snap(pixel.g), snap(pixel.b), a);
+            }
+            GraphValue::Color(out)
+        }
+    })
+}
+
+/// Quantize each channel to `levels` steps, offsetting pixel `(x, y)` by the
+/// method's tiled threshold before flooring.
+///
+/// Grayscale input stays grayscale; color input keeps its alpha.
+pub(super) fn eval_dither(
+    input: &GraphValue,
+    levels: u32,
+    method: DitherMethod,
+) -> Result<GraphValue, GenerateError> {
+    if levels < 2 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "dither levels must be at least 2, got {}",
+            levels
+        )));
+    }
+
+    let steps = (levels - 1) as f64;
+    let quantize = |v: f64, t: f64| ((v.clamp(0.0, 1.0) * steps + t).floor() / steps).min(1.0);
+
+    Ok(match input {
+        GraphValue::Grayscale(buf) => {
+            let mut out = buf.clone();
+            for y in 0..buf.height {
+                for x in 0..buf.width {
+                    let t = threshold(method, x, y);
+                    out.set(x, y, quantize(buf.get(x, y), t));
+                }
+            }
+            GraphValue::Grayscale(out)
+        }
+        GraphValue::Color(buf) => {
+            let mut out = buf.clone();
+            for y in 0..buf.height {
+                for x in 0..buf.width {
+                    let t = threshold(method, x, y);
+                    let c = buf.get(x, y);
+                    out.set(
+                        x,
+                        y,
+                        Color::rgba(quantize(c.r, t), quantize(c.g, t), quantize(c.b, t), c.a),
+                    );
+                }
+            }
+            GraphValue::Color(out)
+        }
+    })
+}
+
 /// Compose RGBA from separate grayscale channels.
 pub(super) fn eval_compose_rgba(
     r: &GrayscaleBuffer,
@@ -1,10 +1,16 @@
-//! Filter and transform operations (blur, erode, dilate, warp, blend modes, UV transforms).
+//! Filter and transform operations (blur, erode, dilate, bevel, warps, blend modes, UV
+//! transforms).
 
-use crate::maps::GrayscaleBuffer;
+use speccade_spec::recipe::texture::{BevelMode, CompositeBlendMode};
 
+use crate::color::Color;
+use crate::maps::{GrayscaleBuffer, TextureBuffer};
+
+use super::super::composite::to_blend_mode;
+use super::super::GenerateError;
 use super::filters::{
-    apply_blend, apply_blur, apply_morphology, apply_uv_rotate, apply_uv_scale, apply_uv_translate,
-    apply_warp, BlendMode, MorphOp,
+    apply_bevel_height, apply_blend, apply_blur, apply_domain_warp, apply_morphology,
+    apply_uv_rotate, apply_uv_scale, apply_uv_translate, apply_warp, BlendMode, MorphOp,
 };
 use super::GraphValue;
 
@@ -23,6 +29,82 @@ pub(super) fn eval_dilate(input: &GrayscaleBuffer, radius: u32) -> GraphValue {
     GraphValue::Grayscale(apply_morphology(input, radius, MorphOp::Dilate))
 }
 
+/// Shade the edges of a grayscale mask like a lit bevel.
+///
+/// `Grayscale` mode lights the bevel slopes from `light_angle` (degrees,
+/// counter-clockwise from +X with +Y up), leaving flat areas at 0.5. `Normal`
+/// mode encodes the slope normals in the OpenGL Y-up convention.
+pub(super) fn eval_bevel(
+    input: &GrayscaleBuffer,
+    distance: f64,
+    softness: f64,
+    light_angle: f64,
+    mode: BevelMode,
+    tileable: bool,
+) -> Result<GraphValue, GenerateError> {
+    if !distance.is_finite() || distance <= 0.0 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "bevel distance must be positive, got {}",
+            distance
+        )));
+    }
+    if !(0.0..=1.0).contains(&softness) {
+        return Err(GenerateError::InvalidParameter(format!(
+            "bevel softness must be in [0, 1], got {}",
+            softness
+        )));
+    }
+
+    let height = apply_bevel_height(input, distance, softness, tileable);
+    let (w, h) = (height.width as i32, height.height as i32);
+    let sample = |x: i32, y: i32| {
+        if tileable {
+            height.get_wrapped(x, y)
+        } else {
+            height.get(x.clamp(0, w - 1) as u32, y.clamp(0, h - 1) as u32)
+        }
+    };
+
+    // Scale slopes so a straight ramp of any width tilts by 45 degrees.
+    let normal_at = |x: i32, y: i32| {
+        let gx = (sample(x + 1, y) - sample(x - 1, y)) * 0.5 * distance;
+        let gy = (sample(x, y + 1) - sample(x, y - 1)) * 0.5 * distance;
+        // Image Y points down, so height rising downwards faces +Y.
+        let len = (gx * gx + gy * gy + 1.0).sqrt();
+        (-gx / len, gy / len, 1.0 / len)
+    };
+
+    Ok(match mode {
+        BevelMode::Grayscale => {
+            let (light_y, light_x) = light_angle.to_radians().sin_cos();
+            let mut out = GrayscaleBuffer::new(height.width, height.height, 0.0);
+            for y in 0..h {
+                for x in 0..w {
+                    let (nx, ny, _) = normal_at(x, y);
+                    let shade = 0.5 + 0.5 * (nx * light_x + ny * light_y);
+                    out.set(x as u32, y as u32, shade.clamp(0.0, 1.0));
+                }
+            }
+            GraphValue::Grayscale(out)
+        }
+        BevelMode::Normal => {
+            let mut out =
+                TextureBuffer::new(height.width, height.height, Color::rgb(0.5, 0.5, 1.0));
+            for y in 0..h {
+                for x in 0..w {
+                    let (nx, ny, nz) = normal_at(x, y);
+                    out.set(
+                        x as u32,
+                        y as u32,
+                        Color::rgb((nx + 1.0) * 0.5, (ny + 1.0) * 0.5, (nz + 1.0) * 0.5),
+                    );
+                }
+            }
+            GraphValue::Color(out)
+        }
+    })
+}
+
 /// Apply domain warp using a displacement map.
 pub(super) fn eval_warp(
     input: &GrayscaleBuffer,
@@ -32,6 +114,17 @@ pub(super) fn eval_warp(
     GraphValue::Grayscale(apply_warp(input, displacement, strength))
 }
 
+/// Apply domain warp using separate x and y displacement fields.
+pub(super) fn eval_domain_warp(
+    input: &GrayscaleBuffer,
+    warp_x: &GrayscaleBuffer,
+    warp_y: &GrayscaleBuffer,
+    amount: f64,
+    tileable: bool,
+) -> GraphValue {
+    GraphValue::Grayscale(apply_domain_warp(input, warp_x, warp_y, amount, tileable))
+}
+
 /// Apply screen blend mode.
 pub(super) fn eval_blend_screen(base: &GrayscaleBuffer, blend: &GrayscaleBuffer) -> GraphValue {
     GraphValue::Grayscale(apply_blend(base, blend, BlendMode::Screen))
@@ -52,6 +145,60 @@ pub(super) fn eval_blend_difference(base: &GrayscaleBuffer, blend: &GrayscaleBuf
     GraphValue::Grayscale(apply_blend(base, blend, BlendMode::Difference))
 }
 
+/// Blend `b` over `a`, gated per pixel by `mask` and scaled by `opacity`.
+///
+/// Two grayscale inputs stay grayscale; otherwise both are promoted to color.
+/// Results are clamped and keep the alpha of `a`.
+pub(super) fn eval_blend(
+    a: &GraphValue,
+    b: &GraphValue,
+    mode: CompositeBlendMode,
+    mask: Option<&GrayscaleBuffer>,
+    opacity: f64,
+) -> Result<GraphValue, GenerateError> {
+    if !(0.0..=1.0).contains(&opacity) {
+        return Err(GenerateError::InvalidParameter(format!(
+            "blend opacity must be in [0, 1], got {}",
+            opacity
+        )));
+    }
+
+    let mode = to_blend_mode(mode);
+    let weight = |i: usize| opacity * mask.map_or(1.0, |m| m.data[i]);
+
+    Ok(match (a, b) {
+        (GraphValue::Grayscale(base), GraphValue::Grayscale(layer)) => {
+            let mut out = base.clone();
+            for (i, v) in out.data.iter_mut().enumerate() {
+                *v = mode
+                    .blend(&Color::gray(*v), &Color::gray(layer.data[i]), weight(i))
+                    .clamp()
+                    .r;
+            }
+            GraphValue::Grayscale(out)
+        }
+        (a, b) => {
+            let mut out = to_color(a);
+            let layer = to_color(b);
+            for (i, c) in out.data.iter_mut().enumerate() {
+                let blended = mode.blend(c, &layer.data[i], weight(i)).clamp();
+                *c = Color {
+                    a: c.a.clamp(0.0, 1.0),
+                    ..blended
+                };
+            }
+            GraphValue::Color(out)
+        }
+    })
+}
+
+fn to_color(value: &GraphValue) -> TextureBuffer {
+    match value {
+        GraphValue::Color(buf) => buf.clone(),
+        GraphValue::Grayscale(buf) => buf.to_texture_buffer(),
+    }
+}
+
 /// Apply UV scale transform.
 pub(super) fn eval_uv_scale(input: &GrayscaleBuffer, scale_x: f32, scale_y: f32) -> GraphValue {
     GraphValue::Grayscale(apply_uv_scale(input, scale_x as f64, scale_y as f64))
@@ -1,9 +1,15 @@
-//! Mathematical grayscale operations (invert, clamp, add, multiply, lerp, threshold).
+//! Mathematical operations (invert, clamp, add, multiply, lerp, threshold, math).
 
+use speccade_spec::recipe::texture::MathOp;
+
+use crate::color::Color;
 use crate::maps::GrayscaleBuffer;
 
 use super::GraphValue;
 
+/// Smallest divisor magnitude used by `MathOp::Divide`.
+const MIN_DIVISOR: f64 = 1e-6;
+
 /// Invert a grayscale buffer (1 - value).
 pub(super) fn eval_invert(input: &GrayscaleBuffer, width: u32, height: u32) -> GraphValue {
     let mut out = GrayscaleBuffer::new(width, height, 0.0);
@@ -85,3 +91,55 @@ pub(super) fn eval_threshold(
     }
     GraphValue::Grayscale(out)
 }
+
+/// Apply a scalar operation to every pixel.
+///
+/// Grayscale stays grayscale; color inputs have R, G and B transformed and
+/// keep their alpha.
+pub(super) fn eval_math(input: &GraphValue, op: MathOp, operand: f64) -> GraphValue {
+    let apply = |x: f64| apply_math_op(op, x, operand);
+
+    match input {
+        GraphValue::Grayscale(buf) => {
+            let mut out = buf.clone();
+            out.data.iter_mut().for_each(|v| *v = apply(*v));
+            GraphValue::Grayscale(out)
+        }
+        GraphValue::Color(buf) => {
+            let mut out = buf.clone();
+            for pixel in &mut out.data {
+                *pixel = Color::rgba(apply(pixel.r), apply(pixel.g), apply(pixel.b), pixel.a);
+            }
+            GraphValue::Color(out)
+        }
+    }
+}
+
+/// Evaluate `x <op> operand`, keeping the result finite for finite inputs.
+fn apply_math_op(op: MathOp, x: f64, operand: f64) -> f64 {
+    match op {
+        MathOp::Add => x + operand,
+        MathOp::Subtract => x - operand,
+        MathOp::Multiply => x * operand,
+        MathOp::Divide => {
+            let divisor = if operand.abs() < MIN_DIVISOR {
+                MIN_DIVISOR.copysign(operand)
+            } else {
+                operand
+            };
+            x / divisor
+        }
+        MathOp::Min => x.min(operand),
+        MathOp::Max => x.max(operand),
+        MathOp::Power => {
+            // Negative bases have no real fractional power, and zero has no
+            // negative power
+            let base = if operand < 0.0 {
+                x.max(MIN_DIVISOR)
+            } else {
+                x.max(0.0)
+            };
+            base.powf(operand).min(f64::MAX)
+        }
+    }
+}
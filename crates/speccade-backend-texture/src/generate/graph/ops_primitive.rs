@@ -1,13 +1,19 @@
-//! Primitive grayscale node operations (constant, noise, gradient, stripes, checkerboard).
+//! Primitive grayscale node operations (constant, noise, directional_noise, gradient, stripes,
+//! checkerboard, pattern).
 
-use speccade_spec::recipe::texture::{GradientDirection, NoiseConfig, StripeDirection};
+use speccade_spec::recipe::texture::{
+    GradientDirection, NoiseConfig, PatternConfig, StripeDirection,
+};
 
 use super::super::GenerateError;
 use super::GraphValue;
 use crate::generate::helpers::create_noise_generator;
 use crate::maps::GrayscaleBuffer;
 use crate::noise::lerp as lerp_f64;
-use crate::pattern::{CheckerPattern, GradientPattern, Pattern2D, StripesPattern};
+use crate::pattern::{
+    BrickPattern, CheckerPattern, EdgeWearPattern, GradientPattern, Pattern2D, ScratchesPattern,
+    StripesPattern, WoodGrainPattern,
+};
 use crate::rng::DeterministicRng;
 
 /// Generate a constant grayscale buffer filled with a single value.
@@ -22,8 +28,70 @@ pub(super) fn eval_noise(
     tileable: bool,
     noise_config: &NoiseConfig,
     derived_seed: u32,
+) -> GraphValue {
+    eval_noise_field(
+        width,
+        height,
+        tileable,
+        noise_config,
+        derived_seed,
+        |x, y| (x, y),
+    )
+}
+
+/// Generate a noise pattern elongated along `angle` (degrees) by `stretch`.
+pub(super) fn eval_directional_noise(
+    width: u32,
+    height: u32,
+    tileable: bool,
+    noise_config: &NoiseConfig,
+    angle: f64,
+    stretch: f64,
+    derived_seed: u32,
+) -> Result<GraphValue, GenerateError> {
+    if !stretch.is_finite() || stretch <= 0.0 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "directional_noise stretch must be a positive number, got {}",
+            stretch
+        )));
+    }
+    if !angle.is_finite() {
+        return Err(GenerateError::InvalidParameter(format!(
+            "directional_noise angle must be finite, got {}",
+            angle
+        )));
+    }
+
+    // Rotate into the grain frame, then compress the along-grain axis so
+    // features last `stretch` times longer in that direction.
+    let (sin, cos) = angle.to_radians().sin_cos();
+    Ok(eval_noise_field(
+        width,
+        height,
+        tileable,
+        noise_config,
+        derived_seed,
+        |x, y| ((x * cos + y * sin) / stretch, y * cos - x * sin),
+    ))
+}
+
+/// Samples a noise field at scaled texel coordinates mapped through `transform`.
+///
+/// When tileable, each texel cross-blends four samples offset by one period so
+/// opposite edges match for any linear `transform`.
+fn eval_noise_field(
+    width: u32,
+    height: u32,
+    tileable: bool,
+    noise_config: &NoiseConfig,
+    derived_seed: u32,
+    transform: impl Fn(f64, f64) -> (f64, f64),
 ) -> GraphValue {
     let noise_gen = create_noise_generator(noise_config, derived_seed);
+    let sample = |x: f64, y: f64| {
+        let (tx, ty) = transform(x, y);
+        noise_gen.sample_01(tx, ty)
+    };
     let scale = noise_config.scale;
     let mut buf = GrayscaleBuffer::new(width, height, 0.0);
 
@@ -40,10 +108,10 @@ pub(super) fn eval_noise(
                 let u = x as f64 / denom_x;
                 let nx = u * period_x;
 
-                let n00 = noise_gen.sample_01(nx, ny);
-                let n10 = noise_gen.sample_01(nx - period_x, ny);
-                let n01 = noise_gen.sample_01(nx, ny - period_y);
-                let n11 = noise_gen.sample_01(nx - period_x, ny - period_y);
+                let n00 = sample(nx, ny);
+                let n10 = sample(nx - period_x, ny);
+                let n01 = sample(nx, ny - period_y);
+                let n11 = sample(nx - period_x, ny - period_y);
 
                 let n0 = lerp_f64(n00, n10, u);
                 let n1 = lerp_f64(n01, n11, u);
@@ -56,7 +124,7 @@ pub(super) fn eval_noise(
             for x in 0..width {
                 let nx = x as f64 * scale;
                 let ny = y as f64 * scale;
-                buf.set(x, y, noise_gen.sample_01(nx, ny));
+                buf.set(x, y, sample(nx, ny));
             }
         }
     }
@@ -235,7 +303,23 @@ pub(super) fn eval_gradient(
     inner: Option<f64>,
     outer: Option<f64>,
 ) -> GraphValue {
-    let gradient = match direction {
+    let gradient = gradient_pattern(width, height, direction, start, end, center, inner, outer);
+    sample_pattern(width, height, &gradient)
+}
+
+/// Builds a gradient pattern, filling unset parameters with their defaults.
+#[allow(clippy::too_many_arguments)]
+fn gradient_pattern(
+    width: u32,
+    height: u32,
+    direction: &GradientDirection,
+    start: Option<f64>,
+    end: Option<f64>,
+    center: Option<[f64; 2]>,
+    inner: Option<f64>,
+    outer: Option<f64>,
+) -> GradientPattern {
+    match direction {
         GradientDirection::Horizontal => {
             let s = start.unwrap_or(0.0);
             let e = end.unwrap_or(1.0);
@@ -252,15 +336,7 @@ pub(super) fn eval_gradient(
             let o = outer.unwrap_or(0.0);
             GradientPattern::new_radial(width, height, c, i, o)
         }
-    };
-
-    let mut buf = GrayscaleBuffer::new(width, height, 0.0);
-    for y in 0..height {
-        for x in 0..width {
-            buf.set(x, y, gradient.sample(x, y));
-        }
     }
-    GraphValue::Grayscale(buf)
 }
 
 /// Generate a stripes pattern.
@@ -303,3 +379,154 @@ pub(super) fn eval_checkerboard(
     }
     GraphValue::Grayscale(buf)
 }
+
+/// Generate any configured pattern as a grayscale source.
+///
+/// In tileable graphs, periodic patterns must repeat a whole number of times
+/// across the texture so opposite edges match.
+pub(super) fn eval_pattern(
+    width: u32,
+    height: u32,
+    tileable: bool,
+    pattern: &PatternConfig,
+    derived_seed: u32,
+) -> Result<GraphValue, GenerateError> {
+    let pattern: Box<dyn Pattern2D> = match pattern {
+        PatternConfig::Brick {
+            brick_width,
+            brick_height,
+            mortar_width,
+            mortar_depth,
+            brick_variation,
+            row_offset,
+        } => {
+            if *brick_width < 2 || *brick_height < 2 {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "pattern brick size must be at least 2x2 pixels, got {}x{}",
+                    brick_width, brick_height
+                )));
+            }
+            Box::new(
+                BrickPattern::new(width, height)
+                    .with_brick_size(*brick_width, *brick_height)
+                    .with_mortar(*mortar_width, *mortar_depth)
+                    .with_variation(*brick_variation)
+                    .with_row_offset(*row_offset)
+                    .with_seed(derived_seed),
+            )
+        }
+        PatternConfig::Checker {
+            tile_size,
+            color1,
+            color2,
+        } => {
+            if *tile_size == 0 {
+                return Err(GenerateError::InvalidParameter(
+                    "pattern checker tile_size must be positive".to_string(),
+                ));
+            }
+            if tileable {
+                let period = 2 * tile_size;
+                if !width.is_multiple_of(period) || !height.is_multiple_of(period) {
+                    return Err(GenerateError::InvalidParameter(format!(
+                        "tileable checker pattern needs a resolution that is a multiple of {} (2 * tile_size), got {}x{}",
+                        period, width, height
+                    )));
+                }
+            }
+            Box::new(CheckerPattern::new(*tile_size).with_colors(*color1, *color2))
+        }
+        PatternConfig::Stripes {
+            direction,
+            stripe_width,
+            color1,
+            color2,
+        } => {
+            if *stripe_width == 0 {
+                return Err(GenerateError::InvalidParameter(
+                    "pattern stripes stripe_width must be positive".to_string(),
+                ));
+            }
+            let across = match direction {
+                StripeDirection::Horizontal => height,
+                StripeDirection::Vertical => width,
+            };
+            if tileable && !across.is_multiple_of(2 * stripe_width) {
+                return Err(GenerateError::InvalidParameter(format!(
+                    "tileable stripes pattern needs {} pixels across the stripes to be a multiple of {} (2 * stripe_width)",
+                    across,
+                    2 * stripe_width
+                )));
+            }
+            match direction {
+                StripeDirection::Horizontal => Box::new(StripesPattern::new_horizontal(
+                    *stripe_width,
+                    *color1,
+                    *color2,
+                )),
+                StripeDirection::Vertical => Box::new(StripesPattern::new_vertical(
+                    *stripe_width,
+                    *color1,
+                    *color2,
+                )),
+            }
+        }
+        PatternConfig::Gradient {
+            direction,
+            start,
+            end,
+            center,
+            inner,
+            outer,
+        } => Box::new(gradient_pattern(
+            width, height, direction, *start, *end, *center, *inner, *outer,
+        )),
+        PatternConfig::WoodGrain {
+            ring_count,
+            distortion,
+            turbulence,
+            noise_scale,
+        } => Box::new(
+            WoodGrainPattern::new(width, height, derived_seed)
+                .with_ring_count(*ring_count)
+                .with_distortion(*distortion)
+                .with_turbulence(*turbulence)
+                .with_noise_scale(*noise_scale),
+        ),
+        PatternConfig::Scratches {
+            count,
+            length_range,
+            width: scratch_width,
+            depth,
+        } => Box::new(
+            ScratchesPattern::new(width, height, derived_seed)
+                .with_count(*count)
+                .with_length_range(length_range[0], length_range[1])
+                .with_width(*scratch_width)
+                .with_depth(*depth),
+        ),
+        PatternConfig::EdgeWear {
+            amount,
+            noise_scale,
+            threshold,
+        } => Box::new(
+            EdgeWearPattern::new(width, height, derived_seed)
+                .with_amount(*amount)
+                .with_noise_scale(*noise_scale)
+                .with_threshold(*threshold),
+        ),
+    };
+
+    Ok(sample_pattern(width, height, pattern.as_ref()))
+}
+
+/// Samples `pattern` at every texel.
+fn sample_pattern(width: u32, height: u32, pattern: &dyn Pattern2D) -> GraphValue {
+    let mut buf = GrayscaleBuffer::new(width, height, 0.0);
+    for y in 0..height {
+        for x in 0..width {
+            buf.set(x, y, pattern.sample(x, y));
+        }
+    }
+    GraphValue::Grayscale(buf)
+}
@@ -1,6 +1,7 @@
 //! Tests for procedural texture graph generation.
 
 mod test_blend;
+mod test_cache;
 mod test_color;
 mod test_filter;
 mod test_math;
@@ -11,6 +12,8 @@ mod test_uv;
 use crate::color::Color;
 use speccade_spec::recipe::texture::{TextureProceduralNode, TextureProceduralV1Params};
 
+use super::super::GenerateError;
+use super::topology::topological_order;
 use super::{encode_graph_value_png, generate_graph};
 
 fn make_params(tileable: bool, nodes: Vec<TextureProceduralNode>) -> TextureProceduralV1Params {
@@ -47,6 +50,13 @@ fn graph_is_deterministic_for_same_seed() {
                         octaves: 3,
                         persistence: 0.5,
                         lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
                     },
                 },
             },
@@ -85,9 +95,12 @@ fn unknown_node_reference_is_error() {
     );
 
     let err = generate_graph(&params, 1).unwrap_err();
+    assert!(matches!(err, GenerateError::InvalidParameter(_)));
     assert!(
-        err.to_string().contains("unknown node id")
-            || err.to_string().contains("unknown node reference")
+        err.to_string()
+            .contains("node 'bad' references unknown node id 'missing'"),
+        "{}",
+        err
     );
 }
 
@@ -112,7 +125,86 @@ fn cycle_is_error() {
     );
 
     let err = generate_graph(&params, 1).unwrap_err();
-    assert!(err.to_string().contains("cycle detected"));
+    assert!(matches!(err, GenerateError::InvalidParameter(_)));
+    assert!(
+        err.to_string()
+            .contains("cycle detected between nodes 'a' -> 'b' -> 'a'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn cycle_behind_valid_nodes_names_only_cycle() {
+    let invert = |id: &str, input: &str| TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::Invert {
+            input: input.to_string(),
+        },
+    };
+    let params = make_params(
+        true,
+        vec![
+            TextureProceduralNode {
+                id: "base".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.5 },
+            },
+            invert("out", "y"),
+            invert("x", "z"),
+            invert("y", "x"),
+            invert("z", "y"),
+        ],
+    );
+
+    let err = generate_graph(&params, 1).unwrap_err().to_string();
+    assert!(
+        err.contains("'x'") && err.contains("'y'") && err.contains("'z'"),
+        "{}",
+        err
+    );
+    assert!(!err.contains("'base'") && !err.contains("'out'"), "{}", err);
+}
+
+#[test]
+fn diamond_graph_orders_shared_input_first() {
+    let constant = TextureProceduralNode {
+        id: "n".to_string(),
+        op: TextureProceduralOp::Constant { value: 0.25 },
+    };
+    let inverted = TextureProceduralNode {
+        id: "a".to_string(),
+        op: TextureProceduralOp::Invert {
+            input: "n".to_string(),
+        },
+    };
+    let squared = TextureProceduralNode {
+        id: "b".to_string(),
+        op: TextureProceduralOp::Multiply {
+            a: "n".to_string(),
+            b: "n".to_string(),
+        },
+    };
+    let sum = TextureProceduralNode {
+        id: "c".to_string(),
+        op: TextureProceduralOp::Add {
+            a: "a".to_string(),
+            b: "b".to_string(),
+        },
+    };
+    // Declared sink first, so evaluation cannot rely on declaration order.
+    let params = make_params(true, vec![sum, squared, inverted, constant]);
+
+    let order: Vec<&str> = topological_order(&params.nodes)
+        .unwrap()
+        .iter()
+        .map(|node| node.id.as_str())
+        .collect();
+    assert_eq!(order, vec!["n", "b", "a", "c"]);
+
+    let nodes = generate_graph(&params, 7).unwrap();
+    assert_eq!(nodes.len(), 4);
+    let c = nodes["c"].as_grayscale().unwrap();
+    assert!(c.data.iter().all(|&v| approx_eq(v, 0.75 + 0.0625)));
 }
 
 #[test]
@@ -129,6 +221,13 @@ fn obvious_type_mismatch_is_error() {
                         octaves: 2,
                         persistence: 0.5,
                         lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
                     },
                 },
             },
@@ -159,6 +258,13 @@ fn reorder_does_not_change_output() {
                 octaves: 3,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
         },
     };
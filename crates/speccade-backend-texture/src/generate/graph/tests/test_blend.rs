@@ -1,8 +1,12 @@
 //! Tests for blend mode operations.
 
-use speccade_spec::recipe::texture::{TextureProceduralNode, TextureProceduralOp};
+use speccade_spec::recipe::texture::{
+    CompositeBlendMode, NoiseAlgorithm, NoiseConfig, TextureProceduralNode, TextureProceduralOp,
+};
 
-use super::{approx_eq, generate_graph, make_params};
+use crate::color::Color;
+
+use super::{approx_eq, color_approx_eq, generate_graph, make_params};
 
 #[test]
 fn blend_screen_lightens() {
@@ -123,3 +127,180 @@ fn blend_difference_computes_abs() {
     // Difference: |0.8 - 0.3| = 0.5
     assert!(approx_eq(diff.get(0, 0), 0.5));
 }
+
+fn node(id: &str, op: TextureProceduralOp) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op,
+    }
+}
+
+fn blend_op(
+    a: &str,
+    b: &str,
+    mode: CompositeBlendMode,
+    mask: Option<&str>,
+    opacity: f64,
+) -> TextureProceduralOp {
+    TextureProceduralOp::Blend {
+        a: a.to_string(),
+        b: b.to_string(),
+        mode,
+        mask: mask.map(str::to_string),
+        opacity,
+    }
+}
+
+fn noise_op() -> TextureProceduralOp {
+    TextureProceduralOp::Noise {
+        noise: NoiseConfig {
+            algorithm: NoiseAlgorithm::Perlin,
+            scale: 0.1,
+            octaves: 3,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
+        },
+    }
+}
+
+#[test]
+fn blend_multiply_with_white_is_identity() {
+    let params = make_params(
+        true,
+        vec![
+            node("n", noise_op()),
+            node(
+                "tint",
+                TextureProceduralOp::ColorRamp {
+                    input: "n".to_string(),
+                    ramp: vec!["#102030".to_string(), "#f0c080".to_string()],
+                },
+            ),
+            node("white", TextureProceduralOp::Constant { value: 1.0 }),
+            node(
+                "gray_mul",
+                blend_op("n", "white", CompositeBlendMode::Multiply, None, 1.0),
+            ),
+            node(
+                "color_mul",
+                blend_op("tint", "white", CompositeBlendMode::Multiply, None, 1.0),
+            ),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 7).unwrap();
+
+    let n = nodes.get("n").unwrap().as_grayscale().unwrap();
+    let gray_mul = nodes.get("gray_mul").unwrap().as_grayscale().unwrap();
+    for (a, b) in n.data.iter().zip(&gray_mul.data) {
+        assert!(approx_eq(a.clamp(0.0, 1.0), *b));
+    }
+
+    // Grayscale white is promoted to opaque white over the color base.
+    let tint = nodes.get("tint").unwrap().as_color().unwrap();
+    let color_mul = nodes.get("color_mul").unwrap().as_color().unwrap();
+    for (a, b) in tint.data.iter().zip(&color_mul.data) {
+        assert!(color_approx_eq(*a, *b));
+    }
+}
+
+#[test]
+fn blend_normal_with_mask_only_affects_masked_region() {
+    let params = make_params(
+        false,
+        vec![
+            node("black", TextureProceduralOp::Constant { value: 0.0 }),
+            node("white", TextureProceduralOp::Constant { value: 1.0 }),
+            node(
+                "mask",
+                TextureProceduralOp::Checkerboard {
+                    tile_size: 4,
+                    color1: 0.0,
+                    color2: 1.0,
+                },
+            ),
+            node(
+                "mixed",
+                blend_op(
+                    "black",
+                    "white",
+                    CompositeBlendMode::Normal,
+                    Some("mask"),
+                    0.5,
+                ),
+            ),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+    let mask = nodes.get("mask").unwrap().as_grayscale().unwrap();
+    let mixed = nodes.get("mixed").unwrap().as_grayscale().unwrap();
+
+    let mut saw_masked = false;
+    let mut saw_unmasked = false;
+    for (m, v) in mask.data.iter().zip(&mixed.data) {
+        if *m > 0.5 {
+            assert!(approx_eq(*v, 0.5));
+            saw_masked = true;
+        } else {
+            assert!(approx_eq(*v, 0.0));
+            saw_unmasked = true;
+        }
+    }
+    assert!(saw_masked && saw_unmasked);
+}
+
+#[test]
+fn blend_keeps_base_alpha_and_clamps() {
+    let params = make_params(
+        false,
+        vec![
+            node("half", TextureProceduralOp::Constant { value: 0.5 }),
+            node("one", TextureProceduralOp::Constant { value: 1.0 }),
+            node(
+                "base",
+                TextureProceduralOp::ComposeRgba {
+                    r: "one".to_string(),
+                    g: "half".to_string(),
+                    b: "half".to_string(),
+                    a: Some("half".to_string()),
+                },
+            ),
+            node(
+                "added",
+                blend_op("base", "one", CompositeBlendMode::Add, None, 1.0),
+            ),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+    let added = nodes.get("added").unwrap().as_color().unwrap();
+    assert!(color_approx_eq(
+        added.get(3, 3),
+        Color::rgba(1.0, 1.0, 1.0, 0.5)
+    ));
+}
+
+#[test]
+fn blend_rejects_out_of_range_opacity() {
+    let params = make_params(
+        false,
+        vec![
+            node("a", TextureProceduralOp::Constant { value: 0.5 }),
+            node(
+                "bad",
+                blend_op("a", "a", CompositeBlendMode::Normal, None, 1.5),
+            ),
+        ],
+    );
+
+    let err = generate_graph(&params, 1).unwrap_err();
+    assert!(err.to_string().contains("opacity"));
+}
@@ -0,0 +1,166 @@
+//! Tests for node-level caching (incremental graph evaluation).
+
+use std::collections::HashMap;
+
+use speccade_spec::recipe::texture::{
+    NoiseAlgorithm, NoiseConfig, TextureProceduralNode, TextureProceduralOp,
+};
+
+use super::super::{generate_graph_cached, DiskNodeCache, GraphValue, NodeCache};
+use super::{encode_graph_value_png, generate_graph, make_params};
+
+/// In-memory cache that counts how often nodes are loaded and recomputed.
+#[derive(Default)]
+struct CountingCache {
+    entries: HashMap<String, Vec<u8>>,
+    hits: usize,
+    computed: usize,
+}
+
+impl NodeCache for CountingCache {
+    fn get(&mut self, key: &str) -> Option<GraphValue> {
+        let value = GraphValue::from_bytes(self.entries.get(key)?)?;
+        self.hits += 1;
+        Some(value)
+    }
+
+    fn put(&mut self, key: &str, value: &GraphValue) {
+        self.computed += 1;
+        self.entries.insert(key.to_string(), value.to_bytes());
+    }
+}
+
+impl CountingCache {
+    fn reset_counters(&mut self) {
+        self.hits = 0;
+        self.computed = 0;
+    }
+}
+
+fn graph_nodes(threshold: f64) -> Vec<TextureProceduralNode> {
+    vec![
+        TextureProceduralNode {
+            id: "n".to_string(),
+            op: TextureProceduralOp::Noise {
+                noise: NoiseConfig {
+                    algorithm: NoiseAlgorithm::Perlin,
+                    scale: 0.1,
+                    octaves: 3,
+                    persistence: 0.5,
+                    lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
+                },
+            },
+        },
+        TextureProceduralNode {
+            id: "blurred".to_string(),
+            op: TextureProceduralOp::Blur {
+                input: "n".to_string(),
+                radius: 1.5,
+            },
+        },
+        TextureProceduralNode {
+            id: "mask".to_string(),
+            op: TextureProceduralOp::Threshold {
+                input: "blurred".to_string(),
+                threshold,
+            },
+        },
+        TextureProceduralNode {
+            id: "color".to_string(),
+            op: TextureProceduralOp::ColorRamp {
+                input: "mask".to_string(),
+                ramp: vec!["#112233".to_string(), "#ddeeff".to_string()],
+            },
+        },
+    ]
+}
+
+fn encoded(nodes: &HashMap<String, GraphValue>) -> Vec<(String, Vec<u8>)> {
+    let mut out: Vec<_> = nodes
+        .iter()
+        .map(|(id, value)| (id.clone(), encode_graph_value_png(value).unwrap().0))
+        .collect();
+    out.sort();
+    out
+}
+
+#[test]
+fn warm_run_reuses_cached_nodes_and_matches_cold_output() {
+    let params = make_params(true, graph_nodes(0.5));
+    let uncached = encoded(&generate_graph(&params, 9).unwrap());
+
+    let mut cache = CountingCache::default();
+    let cold = generate_graph_cached(&params, 9, &mut cache).unwrap();
+    assert_eq!(cache.hits, 0);
+    assert_eq!(cache.computed, 4);
+
+    cache.reset_counters();
+    let warm = generate_graph_cached(&params, 9, &mut cache).unwrap();
+    assert_eq!(cache.hits, 4);
+    assert_eq!(cache.computed, 0, "warm run should not recompute any node");
+
+    assert_eq!(encoded(&cold), uncached);
+    assert_eq!(encoded(&warm), uncached);
+}
+
+#[test]
+fn edit_recomputes_only_changed_and_downstream_nodes() {
+    let mut cache = CountingCache::default();
+    generate_graph_cached(&make_params(true, graph_nodes(0.5)), 9, &mut cache).unwrap();
+
+    // Changing the threshold invalidates `mask` and its consumer `color`
+    cache.reset_counters();
+    let edited = make_params(true, graph_nodes(0.6));
+    let nodes = generate_graph_cached(&edited, 9, &mut cache).unwrap();
+    assert_eq!(cache.hits, 2);
+    assert_eq!(cache.computed, 2);
+    assert_eq!(
+        encoded(&nodes),
+        encoded(&generate_graph(&edited, 9).unwrap())
+    );
+
+    // A different seed changes every node
+    cache.reset_counters();
+    generate_graph_cached(&edited, 10, &mut cache).unwrap();
+    assert_eq!(cache.hits, 0);
+    assert_eq!(cache.computed, 4);
+}
+
+#[test]
+fn disk_cache_is_reused_across_instances() {
+    let dir = tempfile::tempdir().unwrap();
+    let params = make_params(false, graph_nodes(0.5));
+
+    let cold = generate_graph_cached(&params, 3, &mut DiskNodeCache::new(dir.path())).unwrap();
+    let entries = std::fs::read_dir(dir.path()).unwrap().count();
+    assert_eq!(entries, 4);
+
+    // A fresh instance (as in a new process) reads the persisted nodes
+    let warm = generate_graph_cached(&params, 3, &mut DiskNodeCache::new(dir.path())).unwrap();
+    assert_eq!(encoded(&warm), encoded(&cold));
+
+    // Corrupt entries are treated as misses and rewritten
+    for entry in std::fs::read_dir(dir.path()).unwrap() {
+        std::fs::write(entry.unwrap().path(), b"garbage").unwrap();
+    }
+    let rebuilt = generate_graph_cached(&params, 3, &mut DiskNodeCache::new(dir.path())).unwrap();
+    assert_eq!(encoded(&rebuilt), encoded(&cold));
+}
+
+#[test]
+fn graph_value_bytes_round_trip() {
+    let params = make_params(true, graph_nodes(0.5));
+    for value in generate_graph(&params, 1).unwrap().values() {
+        let decoded = GraphValue::from_bytes(&value.to_bytes()).unwrap();
+        assert_eq!(decoded.to_bytes(), value.to_bytes());
+    }
+    assert!(GraphValue::from_bytes(&[0, 1, 0, 0, 0, 1, 0, 0, 0]).is_none());
+    assert!(GraphValue::from_bytes(&[]).is_none());
+}
@@ -1,9 +1,12 @@
-//! Tests for color operations (to_grayscale, color_ramp, palette, compose_rgba, normal_from_height).
+//! Tests for color operations (to_grayscale, color_ramp, gradient_map, palette, posterize,
+//! dither, compose_rgba, normal_from_height).
 
 use crate::color::Color;
-use speccade_spec::recipe::texture::{TextureProceduralNode, TextureProceduralOp};
+use speccade_spec::recipe::texture::{
+    DitherMethod, GradientDirection, TextureProceduralNode, TextureProceduralOp,
+};
 
-use super::{approx_eq, color_approx_eq, generate_graph, make_params};
+use super::{approx_eq, color_approx_eq, encode_graph_value_png, generate_graph, make_params};
 
 #[test]
 fn to_grayscale_color_ramp_palette_and_compose_rgba_work() {
@@ -100,3 +103,336 @@ fn normal_from_height_constant_is_flat_normal() {
         Color::rgba(0.5, 0.5, 1.0, 1.0)
     ));
 }
+
+fn gradient_map_node(id: &str, input: &str, stops: &[(f64, &str)]) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::GradientMap {
+            input: input.to_string(),
+            stops: stops
+                .iter()
+                .map(|&(position, color)| (position, color.to_string()))
+                .collect(),
+        },
+    }
+}
+
+#[test]
+fn gradient_map_interpolates_between_positioned_stops() {
+    let stops = [(0.0, "#000000"), (0.5, "#ff0000"), (1.0, "#ffffff")];
+    let params = make_params(
+        false,
+        vec![
+            TextureProceduralNode {
+                id: "v".to_string(),
+                op: TextureProceduralOp::Gradient {
+                    direction: GradientDirection::Vertical,
+                    start: Some(0.0),
+                    end: Some(1.0),
+                    center: None,
+                    inner: None,
+                    outer: None,
+                },
+            },
+            TextureProceduralNode {
+                id: "half".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.5 },
+            },
+            gradient_map_node("mapped", "v", &stops),
+            gradient_map_node("mid", "half", &stops),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+
+    let mid = nodes.get("mid").unwrap().as_color().unwrap();
+    assert!(color_approx_eq(
+        mid.get(0, 0),
+        Color::rgba(1.0, 0.0, 0.0, 1.0)
+    ));
+
+    let v = nodes.get("v").unwrap().as_grayscale().unwrap();
+    let mapped = nodes.get("mapped").unwrap().as_color().unwrap();
+    for y in 0..v.height {
+        let t = v.get(0, y);
+        let expected = if t <= 0.5 {
+            Color::rgba(t * 2.0, 0.0, 0.0, 1.0)
+        } else {
+            let u = (t - 0.5) * 2.0;
+            Color::rgba(1.0, u, u, 1.0)
+        };
+        assert!(color_approx_eq(mapped.get(0, y), expected), "row {}", y);
+    }
+    assert!(color_approx_eq(
+        mapped.get(0, 0),
+        Color::rgba(0.0, 0.0, 0.0, 1.0)
+    ));
+    assert!(color_approx_eq(
+        mapped.get(0, v.height - 1),
+        Color::rgba(1.0, 1.0, 1.0, 1.0)
+    ));
+}
+
+#[test]
+fn gradient_map_rejects_unsorted_or_out_of_range_stops() {
+    for stops in [
+        vec![(0.6, "#000000"), (0.4, "#ffffff")],
+        vec![(0.0, "#000000"), (1.5, "#ffffff")],
+        vec![],
+    ] {
+        let params = make_params(
+            false,
+            vec![
+                TextureProceduralNode {
+                    id: "c".to_string(),
+                    op: TextureProceduralOp::Constant { value: 0.5 },
+                },
+                gradient_map_node("mapped", "c", &stops),
+            ],
+        );
+        let err = generate_graph(&params, 1).unwrap_err();
+        assert!(
+            matches!(err, crate::generate::GenerateError::InvalidParameter(_)),
+            "{:?}",
+            err
+        );
+    }
+}
+
+fn posterize_node(
+    id: &str,
+    input: &str,
+    levels: u32,
+    include_alpha: bool,
+) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::Posterize {
+            input: input.to_string(),
+            levels,
+            include_alpha,
+        },
+    }
+}
+
+fn horizontal_gradient_node(id: &str) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::Gradient {
+            direction: GradientDirection::Horizontal,
+            start: Some(0.0),
+            end: Some(1.0),
+            center: None,
+            inner: None,
+            outer: None,
+        },
+    }
+}
+
+#[test]
+fn posterize_two_levels_makes_gradient_black_and_white() {
+    let params = make_params(
+        false,
+        vec![
+            horizontal_gradient_node("g"),
+            posterize_node("p", "g", 2, false),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+    let p = nodes.get("p").unwrap().as_grayscale().unwrap();
+    assert!(p.data.iter().all(|&v| v == 0.0 || v == 1.0));
+    assert_eq!(p.get(0, 0), 0.0);
+    assert_eq!(p.get(p.width - 1, 0), 1.0);
+
+    // Deterministic down to the encoded bytes
+    let again = generate_graph(&params, 1).unwrap();
+    let (png_a, hash_a) = encode_graph_value_png(&nodes["p"]).unwrap();
+    let (png_b, hash_b) = encode_graph_value_png(&again["p"]).unwrap();
+    assert_eq!(png_a, png_b);
+    assert_eq!(hash_a, hash_b);
+}
+
+#[test]
+fn posterize_color_quantizes_channels_and_keeps_alpha() {
+    let params = make_params(
+        false,
+        vec![
+            TextureProceduralNode {
+                id: "r".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.3 },
+            },
+            TextureProceduralNode {
+                id: "g".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.7 },
+            },
+            TextureProceduralNode {
+                id: "a".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.4 },
+            },
+            TextureProceduralNode {
+                id: "rgba".to_string(),
+                op: TextureProceduralOp::ComposeRgba {
+                    r: "r".to_string(),
+                    g: "g".to_string(),
+                    b: "a".to_string(),
+                    a: Some("a".to_string()),
+                },
+            },
+            posterize_node("keep", "rgba", 3, false),
+            posterize_node("all", "rgba", 3, true),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+    let keep = nodes.get("keep").unwrap().as_color().unwrap();
+    assert!(color_approx_eq(
+        keep.get(0, 0),
+        Color::rgba(0.5, 0.5, 0.5, 0.4)
+    ));
+    let all = nodes.get("all").unwrap().as_color().unwrap();
+    assert!(color_approx_eq(
+        all.get(0, 0),
+        Color::rgba(0.5, 0.5, 0.5, 0.5)
+    ));
+}
+
+#[test]
+fn posterize_rejects_fewer_than_two_levels() {
+    for levels in [0, 1] {
+        let params = make_params(
+            false,
+            vec![
+                horizontal_gradient_node("g"),
+                posterize_node("p", "g", levels, false),
+            ],
+        );
+        let err = generate_graph(&params, 1).unwrap_err();
+        assert!(
+            matches!(err, crate::generate::GenerateError::InvalidParameter(_)),
+            "{:?}",
+            err
+        );
+    }
+}
+
+fn dither_node(id: &str, input: &str, levels: u32, method: DitherMethod) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::Dither {
+            input: input.to_string(),
+            levels,
+            method,
+        },
+    }
+}
+
+/// Largest jump between adjacent column averages: the band edges a viewer
+/// sees once the pattern blurs together.
+fn max_column_mean_jump(buf: &crate::maps::GrayscaleBuffer) -> f64 {
+    let means: Vec<f64> = (0..buf.width)
+        .map(|x| (0..buf.height).map(|y| buf.get(x, y)).sum::<f64>() / buf.height as f64)
+        .collect();
+    means
+        .windows(2)
+        .map(|w| (w[1] - w[0]).abs())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn dither_reduces_banding_compared_to_posterize() {
+    let params = make_params(
+        false,
+        vec![
+            horizontal_gradient_node("g"),
+            posterize_node("p", "g", 4, false),
+            dither_node("b4", "g", 4, DitherMethod::Bayer4),
+            dither_node("b8", "g", 4, DitherMethod::Bayer8),
+            dither_node("bn", "g", 4, DitherMethod::BlueNoise),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+    let posterized = max_column_mean_jump(nodes["p"].as_grayscale().unwrap());
+    assert!(posterized > 0.3, "posterize jump {}", posterized);
+
+    for id in ["b4", "b8", "bn"] {
+        let out = nodes[id].as_grayscale().unwrap();
+        // Still quantized to four levels...
+        assert!(out.data.iter().all(|&v| [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]
+            .iter()
+            .any(|&l| approx_eq(v, l))));
+        // ...but with at most half the step between neighbouring columns.
+        let jump = max_column_mean_jump(out);
+        assert!(
+            jump <= posterized * 0.5 + 1e-9,
+            "{} jump {} vs {}",
+            id,
+            jump,
+            posterized
+        );
+    }
+}
+
+#[test]
+fn dither_pattern_tiles_and_keeps_color_alpha() {
+    let params = make_params(
+        true,
+        vec![
+            TextureProceduralNode {
+                id: "c".to_string(),
+                op: TextureProceduralOp::Constant { value: 0.4 },
+            },
+            TextureProceduralNode {
+                id: "rgba".to_string(),
+                op: TextureProceduralOp::ComposeRgba {
+                    r: "c".to_string(),
+                    g: "c".to_string(),
+                    b: "c".to_string(),
+                    a: Some("c".to_string()),
+                },
+            },
+            dither_node("gray", "c", 2, DitherMethod::BlueNoise),
+            dither_node("color", "rgba", 2, DitherMethod::Bayer8),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 1).unwrap();
+
+    // A flat input repeats with the 16x16 tile and keeps its average level.
+    let gray = nodes["gray"].as_grayscale().unwrap();
+    for y in 0..16 {
+        for x in 0..16 {
+            assert_eq!(gray.get(x, y), gray.get(x + 16, y + 16));
+        }
+    }
+    let mean = gray.data.iter().sum::<f64>() / gray.data.len() as f64;
+    assert!((mean - 0.4).abs() < 0.01, "mean {}", mean);
+
+    let color = nodes["color"].as_color().unwrap();
+    assert!(color.data.iter().all(|c| c.a == 0.4 && c.r == c.g));
+    assert_ne!(color.get(0, 0).r, color.get(0, 1).r);
+
+    // Deterministic down to the encoded bytes
+    let again = generate_graph(&params, 1).unwrap();
+    let (_, hash_a) = encode_graph_value_png(&nodes["gray"]).unwrap();
+    let (_, hash_b) = encode_graph_value_png(&again["gray"]).unwrap();
+    assert_eq!(hash_a, hash_b);
+}
+
+#[test]
+fn dither_rejects_fewer_than_two_levels() {
+    let params = make_params(
+        false,
+        vec![
+            horizontal_gradient_node("g"),
+            dither_node("d", "g", 1, DitherMethod::Bayer4),
+        ],
+    );
+    let err = generate_graph(&params, 1).unwrap_err();
+    assert!(
+        matches!(err, crate::generate::GenerateError::InvalidParameter(_)),
+        "{:?}",
+        err
+    );
+}
@@ -1,10 +1,11 @@
-//! Tests for filter operations (blur, erode, dilate, warp).
+//! Tests for filter operations (blur, erode, dilate, bevel, warp, domain warp).
 
 use speccade_spec::recipe::texture::{
-    GradientDirection, TextureProceduralNode, TextureProceduralOp,
+    BevelMode, GradientDirection, NoiseAlgorithm, NoiseConfig, TextureProceduralNode,
+    TextureProceduralOp, TextureProceduralV1Params,
 };
 
-use super::{approx_eq, generate_graph, make_params};
+use super::{approx_eq, encode_graph_value_png, generate_graph, make_params};
 
 #[test]
 fn blur_smooths_values() {
@@ -159,3 +160,172 @@ fn warp_displaces_samples() {
         warp_val
     );
 }
+
+fn domain_warp_nodes(amount: f64) -> Vec<TextureProceduralNode> {
+    let noise = |algorithm| TextureProceduralOp::Noise {
+        noise: NoiseConfig {
+            algorithm,
+            scale: 0.1,
+            octaves: 2,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
+        },
+    };
+    vec![
+        TextureProceduralNode {
+            id: "checker".to_string(),
+            op: TextureProceduralOp::Checkerboard {
+                tile_size: 4,
+                color1: 0.0,
+                color2: 1.0,
+            },
+        },
+        TextureProceduralNode {
+            id: "wx".to_string(),
+            op: noise(NoiseAlgorithm::Perlin),
+        },
+        TextureProceduralNode {
+            id: "wy".to_string(),
+            op: noise(NoiseAlgorithm::Simplex),
+        },
+        TextureProceduralNode {
+            id: "warped".to_string(),
+            op: TextureProceduralOp::DomainWarp {
+                input: "checker".to_string(),
+                warp_x: "wx".to_string(),
+                warp_y: "wy".to_string(),
+                amount,
+            },
+        },
+    ]
+}
+
+#[test]
+fn domain_warp_distorts_checkerboard_deterministically() {
+    let params = make_params(true, domain_warp_nodes(6.0));
+
+    let a = generate_graph(&params, 7).unwrap();
+    let b = generate_graph(&params, 7).unwrap();
+    let (_, checker_hash) = encode_graph_value_png(a.get("checker").unwrap()).unwrap();
+    let (bytes_a, hash_a) = encode_graph_value_png(a.get("warped").unwrap()).unwrap();
+    let (bytes_b, hash_b) = encode_graph_value_png(b.get("warped").unwrap()).unwrap();
+
+    assert_ne!(hash_a, checker_hash, "warp should change the output");
+    assert_eq!(hash_a, hash_b);
+    assert_eq!(bytes_a, bytes_b);
+}
+
+#[test]
+fn domain_warp_with_zero_amount_is_identity() {
+    for tileable in [false, true] {
+        let params = make_params(tileable, domain_warp_nodes(0.0));
+        let nodes = generate_graph(&params, 7).unwrap();
+        let checker = nodes.get("checker").unwrap().as_grayscale().unwrap();
+        let warped = nodes.get("warped").unwrap().as_grayscale().unwrap();
+        assert_eq!(warped.data, checker.data);
+    }
+}
+
+fn bevel_square_params(tileable: bool, mode: BevelMode) -> TextureProceduralV1Params {
+    // 32x32 checkerboard with 16px tiles: one bright square per 2x2 block.
+    make_params(
+        tileable,
+        vec![
+            TextureProceduralNode {
+                id: "mask".to_string(),
+                op: TextureProceduralOp::Checkerboard {
+                    tile_size: 16,
+                    color1: 0.0,
+                    color2: 1.0,
+                },
+            },
+            TextureProceduralNode {
+                id: "bevel".to_string(),
+                op: TextureProceduralOp::Bevel {
+                    input: "mask".to_string(),
+                    distance: 3.0,
+                    softness: 0.0,
+                    light_angle: 135.0,
+                    mode,
+                },
+            },
+        ],
+    )
+}
+
+/// Left column of the bright square in the top tile row.
+fn bright_square_x(mask: &crate::maps::GrayscaleBuffer) -> u32 {
+    if mask.get(0, 0) > 0.5 {
+        0
+    } else {
+        16
+    }
+}
+
+#[test]
+fn bevel_lights_edges_facing_the_light() {
+    let params = bevel_square_params(true, BevelMode::Grayscale);
+    let nodes = generate_graph(&params, 1).unwrap();
+    let ox = bright_square_x(nodes["mask"].as_grayscale().unwrap());
+    let bevel = nodes["bevel"].as_grayscale().unwrap();
+
+    // Light from the top-left brightens the left and top edges and darkens
+    // the right and bottom ones; flat areas stay at 0.5.
+    let left = bevel.get(ox, 8);
+    let right = bevel.get(ox + 15, 8);
+    let top = bevel.get(ox + 8, 0);
+    let bottom = bevel.get(ox + 8, 15);
+    assert!(left > 0.6 && top > 0.6, "left {} top {}", left, top);
+    assert!(
+        right < 0.4 && bottom < 0.4,
+        "right {} bottom {}",
+        right,
+        bottom
+    );
+    assert!(approx_eq(bevel.get(ox + 8, 8), 0.5));
+    assert!(approx_eq(bevel.get((ox + 24) % 32, 8), 0.5));
+
+    // Deterministic down to the encoded bytes
+    let again = generate_graph(&params, 1).unwrap();
+    let (png_a, hash_a) = encode_graph_value_png(&nodes["bevel"]).unwrap();
+    let (png_b, hash_b) = encode_graph_value_png(&again["bevel"]).unwrap();
+    assert_eq!(png_a, png_b);
+    assert_eq!(hash_a, hash_b);
+}
+
+#[test]
+fn bevel_wraps_edge_search_only_when_tileable() {
+    // The bright square touches the top border; its top edge only exists
+    // when the texture wraps.
+    let tiled = generate_graph(&bevel_square_params(true, BevelMode::Grayscale), 1).unwrap();
+    let clamped = generate_graph(&bevel_square_params(false, BevelMode::Grayscale), 1).unwrap();
+    let ox = bright_square_x(tiled["mask"].as_grayscale().unwrap());
+
+    assert!(tiled["bevel"].as_grayscale().unwrap().get(ox + 8, 0) > 0.6);
+    assert!(approx_eq(
+        clamped["bevel"].as_grayscale().unwrap().get(ox + 8, 0),
+        0.5
+    ));
+}
+
+#[test]
+fn bevel_normal_mode_tilts_edges_outward() {
+    let nodes = generate_graph(&bevel_square_params(true, BevelMode::Normal), 1).unwrap();
+    let ox = bright_square_x(nodes["mask"].as_grayscale().unwrap());
+    let normals = nodes["bevel"].as_color().unwrap();
+
+    // OpenGL Y-up: left edge faces -X, top edge faces +Y.
+    assert!(normals.get(ox, 8).r < 0.4);
+    assert!(normals.get(ox + 15, 8).r > 0.6);
+    assert!(normals.get(ox + 8, 0).g > 0.6);
+    assert!(normals.get(ox + 8, 15).g < 0.4);
+    let flat = normals.get(ox + 8, 8);
+    assert!(approx_eq(flat.r, 0.5) && approx_eq(flat.g, 0.5) && approx_eq(flat.b, 1.0));
+}
@@ -1,6 +1,9 @@
-//! Tests for mathematical operations (add, multiply, lerp, threshold).
+//! Tests for mathematical operations (add, multiply, lerp, threshold, math).
 
-use speccade_spec::recipe::texture::{TextureProceduralNode, TextureProceduralOp};
+use speccade_spec::recipe::texture::{
+    GradientDirection, MathOp, TextureProceduralNode, TextureProceduralOp,
+    TextureProceduralV1Params,
+};
 
 use super::{approx_eq, generate_graph, make_params};
 
@@ -54,3 +57,79 @@ fn add_multiply_lerp_compute_expected_values() {
     assert!(approx_eq(mul.get(0, 0), 0.12));
     assert!(approx_eq(lerp.get(0, 0), 0.4));
 }
+
+fn math_over_gradient(op: MathOp, operand: f64) -> TextureProceduralV1Params {
+    make_params(
+        false,
+        vec![
+            TextureProceduralNode {
+                id: "ramp".to_string(),
+                op: TextureProceduralOp::Gradient {
+                    direction: GradientDirection::Horizontal,
+                    start: Some(0.0),
+                    end: Some(1.0),
+                    center: None,
+                    inner: None,
+                    outer: None,
+                },
+            },
+            TextureProceduralNode {
+                id: "out".to_string(),
+                op: TextureProceduralOp::Math {
+                    input: "ramp".to_string(),
+                    op,
+                    operand,
+                },
+            },
+        ],
+    )
+}
+
+#[test]
+fn math_multiply_by_half_halves_luminance() {
+    let params = math_over_gradient(MathOp::Multiply, 0.5);
+    let nodes = generate_graph(&params, 1).unwrap();
+    let ramp = nodes.get("ramp").unwrap().as_grayscale().unwrap();
+    let out = nodes.get("out").unwrap().as_grayscale().unwrap();
+
+    for (input, output) in ramp.data.iter().zip(&out.data) {
+        assert!(approx_eq(*output, input * 0.5));
+    }
+    let mean = |data: &[f64]| data.iter().sum::<f64>() / data.len() as f64;
+    assert!(approx_eq(mean(&out.data), mean(&ramp.data) * 0.5));
+
+    let again = generate_graph(&params, 1).unwrap();
+    assert_eq!(
+        again.get("out").unwrap().as_grayscale().unwrap().data,
+        out.data
+    );
+}
+
+#[test]
+fn math_power_two_darkens_midtones() {
+    let params = math_over_gradient(MathOp::Power, 2.0);
+    let nodes = generate_graph(&params, 1).unwrap();
+    let ramp = nodes.get("ramp").unwrap().as_grayscale().unwrap();
+    let out = nodes.get("out").unwrap().as_grayscale().unwrap();
+
+    for (input, output) in ramp.data.iter().zip(&out.data) {
+        assert!(approx_eq(*output, input * input));
+        if *input > 0.0 && *input < 1.0 {
+            assert!(output < input, "{} should darken, got {}", input, output);
+        }
+    }
+
+    let again = generate_graph(&params, 1).unwrap();
+    assert_eq!(
+        again.get("out").unwrap().as_grayscale().unwrap().data,
+        out.data
+    );
+}
+
+#[test]
+fn math_divide_by_zero_stays_finite() {
+    let params = math_over_gradient(MathOp::Divide, 0.0);
+    let nodes = generate_graph(&params, 1).unwrap();
+    let out = nodes.get("out").unwrap().as_grayscale().unwrap();
+    assert!(out.data.iter().all(|v| v.is_finite()));
+}
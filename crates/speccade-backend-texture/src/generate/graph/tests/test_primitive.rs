@@ -1,11 +1,15 @@
-//! Tests for primitive operations (constant, noise, gradient, stripes, checkerboard).
+//! Tests for primitive operations (constant, noise, directional_noise, gradient, stripes,
+//! checkerboard, pattern).
 
 use speccade_spec::recipe::texture::{
-    GradientDirection, NoiseAlgorithm, NoiseConfig, StripeDirection, TextureProceduralNode,
-    TextureProceduralOp,
+    GradientDirection, NoiseAlgorithm, NoiseConfig, PatternConfig, StripeDirection,
+    TextureProceduralNode, TextureProceduralOp,
 };
 
-use super::{approx_eq, generate_graph, make_params};
+use crate::maps::GrayscaleBuffer;
+use crate::pattern::{CheckerPattern, Pattern2D};
+
+use super::{approx_eq, encode_graph_value_png, generate_graph, make_params};
 
 #[test]
 fn constant_outputs_fill_value() {
@@ -36,6 +40,13 @@ fn tileable_noise_matches_edges() {
                     octaves: 3,
                     persistence: 0.5,
                     lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
                 },
             },
         }],
@@ -125,6 +136,13 @@ fn gabor_noise_node_generates_non_uniform_field() {
                     octaves: 1,
                     persistence: 0.5,
                     lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
                 },
             },
         }],
@@ -220,3 +238,198 @@ fn reaction_diffusion_rejects_invalid_params() {
     let err = generate_graph(&params, 42).unwrap_err();
     assert!(err.to_string().contains("reaction_diffusion.steps"));
 }
+
+fn directional_noise_node(id: &str, angle: f64, stretch: f64) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::DirectionalNoise {
+            noise: NoiseConfig {
+                algorithm: NoiseAlgorithm::Perlin,
+                scale: 0.15,
+                octaves: 2,
+                persistence: 0.5,
+                lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
+            },
+            angle,
+            stretch,
+        },
+    }
+}
+
+/// Mean absolute difference between horizontal and vertical neighbors.
+fn mean_gradients(buf: &GrayscaleBuffer) -> (f64, f64) {
+    let (mut gx, mut gy) = (0.0, 0.0);
+    for y in 0..buf.height - 1 {
+        for x in 0..buf.width - 1 {
+            gx += (buf.get(x + 1, y) - buf.get(x, y)).abs();
+            gy += (buf.get(x, y + 1) - buf.get(x, y)).abs();
+        }
+    }
+    (gx, gy)
+}
+
+#[test]
+fn directional_noise_stretches_along_angle() {
+    let params = make_params(
+        false,
+        vec![
+            directional_noise_node("horizontal", 0.0, 6.0),
+            directional_noise_node("vertical", 90.0, 6.0),
+        ],
+    );
+
+    let nodes = generate_graph(&params, 11).unwrap();
+    let horizontal = nodes.get("horizontal").unwrap();
+    let vertical = nodes.get("vertical").unwrap();
+
+    let (_, hash_h) = encode_graph_value_png(horizontal).unwrap();
+    let (_, hash_v) = encode_graph_value_png(vertical).unwrap();
+    assert_ne!(hash_h, hash_v);
+
+    // Grain along x changes slowly across x and quickly across y, and vice versa
+    let (hx, hy) = mean_gradients(horizontal.as_grayscale().unwrap());
+    let (vx, vy) = mean_gradients(vertical.as_grayscale().unwrap());
+    assert!(hx * 2.0 < hy, "horizontal grain: gx {} gy {}", hx, hy);
+    assert!(vy * 2.0 < vx, "vertical grain: gx {} gy {}", vx, vy);
+
+    let again = generate_graph(&params, 11).unwrap();
+    let (_, hash_again) = encode_graph_value_png(again.get("horizontal").unwrap()).unwrap();
+    assert_eq!(hash_h, hash_again);
+}
+
+#[test]
+fn directional_noise_tileable_wraps_at_any_angle() {
+    let params = make_params(true, vec![directional_noise_node("n", 30.0, 4.0)]);
+    let nodes = generate_graph(&params, 5).unwrap();
+    let buf = nodes.get("n").unwrap().as_grayscale().unwrap();
+
+    for i in 0..buf.width {
+        assert!(approx_eq(buf.get(i, 0), buf.get(i, buf.height - 1)));
+        assert!(approx_eq(buf.get(0, i), buf.get(buf.width - 1, i)));
+    }
+}
+
+#[test]
+fn directional_noise_rejects_non_positive_stretch() {
+    let params = make_params(false, vec![directional_noise_node("n", 0.0, 0.0)]);
+    assert!(generate_graph(&params, 1).is_err());
+}
+
+fn pattern_node(id: &str, pattern: PatternConfig) -> TextureProceduralNode {
+    TextureProceduralNode {
+        id: id.to_string(),
+        op: TextureProceduralOp::Pattern { pattern },
+    }
+}
+
+#[test]
+fn checker_pattern_node_matches_direct_sampling() {
+    let params = make_params(
+        false,
+        vec![pattern_node(
+            "p",
+            PatternConfig::Checker {
+                tile_size: 5,
+                color1: 0.8,
+                color2: 0.2,
+            },
+        )],
+    );
+
+    let nodes = generate_graph(&params, 7).unwrap();
+    let buf = nodes.get("p").unwrap().as_grayscale().unwrap();
+    let checker = CheckerPattern::new(5).with_colors(0.8, 0.2);
+    for y in 0..buf.height {
+        for x in 0..buf.width {
+            assert_eq!(buf.get(x, y), checker.sample(x, y));
+        }
+    }
+}
+
+#[test]
+fn tileable_checker_pattern_repeats_across_edges() {
+    let params = make_params(
+        true,
+        vec![pattern_node(
+            "p",
+            PatternConfig::Checker {
+                tile_size: 4,
+                color1: 1.0,
+                color2: 0.0,
+            },
+        )],
+    );
+
+    let nodes = generate_graph(&params, 7).unwrap();
+    let buf = nodes.get("p").unwrap().as_grayscale().unwrap();
+    let checker = CheckerPattern::new(4);
+    // Continuing the pattern one texture further lands on the same values, so
+    // the seam is indistinguishable from an interior tile boundary.
+    for y in 0..buf.height {
+        for x in 0..buf.width {
+            assert_eq!(buf.get(x, y), checker.sample(x + buf.width, y + buf.height));
+        }
+    }
+}
+
+#[test]
+fn tileable_checker_pattern_rejects_partial_tiles() {
+    let params = make_params(
+        true,
+        vec![pattern_node(
+            "p",
+            PatternConfig::Checker {
+                tile_size: 5,
+                color1: 1.0,
+                color2: 0.0,
+            },
+        )],
+    );
+
+    let err = generate_graph(&params, 7).unwrap_err();
+    assert!(err.to_string().contains("multiple of 10"));
+}
+
+#[test]
+fn seeded_pattern_nodes_are_deterministic() {
+    let params = make_params(
+        false,
+        vec![
+            pattern_node(
+                "brick",
+                PatternConfig::Brick {
+                    brick_width: 12,
+                    brick_height: 6,
+                    mortar_width: 1,
+                    mortar_depth: 0.3,
+                    brick_variation: 0.4,
+                    row_offset: 0.5,
+                },
+            ),
+            pattern_node(
+                "scratches",
+                PatternConfig::Scratches {
+                    count: 10,
+                    length_range: [0.1, 0.3],
+                    width: 1.0,
+                    depth: 0.5,
+                },
+            ),
+        ],
+    );
+
+    let a = generate_graph(&params, 99).unwrap();
+    let b = generate_graph(&params, 99).unwrap();
+    for id in ["brick", "scratches"] {
+        let (_, hash_a) = encode_graph_value_png(a.get(id).unwrap()).unwrap();
+        let (_, hash_b) = encode_graph_value_png(b.get(id).unwrap()).unwrap();
+        assert_eq!(hash_a, hash_b, "{} differs between runs", id);
+    }
+}
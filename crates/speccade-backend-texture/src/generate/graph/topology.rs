@@ -0,0 +1,165 @@
+//! Graph structure: node inputs and evaluation order.
+
+use std::collections::{BTreeSet, HashMap};
+
+use speccade_spec::recipe::texture::{TextureProceduralNode, TextureProceduralOp};
+
+use super::super::GenerateError;
+
+/// Orders `nodes` so every node comes after the nodes it reads from.
+///
+/// Ties keep declaration order, so the order only depends on the graph. Fails
+/// with `InvalidParameter` on duplicate ids, on inputs that name no node, and
+/// on cycles (listing the node ids around the cycle).
+pub(super) fn topological_order(
+    nodes: &[TextureProceduralNode],
+) -> Result<Vec<&TextureProceduralNode>, GenerateError> {
+    let mut index_by_id: HashMap<&str, usize> = HashMap::new();
+    for (i, node) in nodes.iter().enumerate() {
+        if index_by_id.insert(node.id.as_str(), i).is_some() {
+            return Err(GenerateError::InvalidParameter(format!(
+                "duplicate node id: '{}'",
+                node.id
+            )));
+        }
+    }
+
+    // inputs[i]: indices node i reads from; readers[j]: nodes that read node j.
+    let mut inputs: Vec<Vec<usize>> = Vec::with_capacity(nodes.len());
+    let mut readers: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    for (i, node) in nodes.iter().enumerate() {
+        let mut node_inputs = Vec::new();
+        for input in op_inputs(&node.op) {
+            let &j = index_by_id.get(input).ok_or_else(|| {
+                GenerateError::InvalidParameter(format!(
+                    "node '{}' references unknown node id '{}'",
+                    node.id, input
+                ))
+            })?;
+            if !node_inputs.contains(&j) {
+                node_inputs.push(j);
+                readers[j].push(i);
+            }
+        }
+        inputs.push(node_inputs);
+    }
+
+    // Kahn's algorithm, always taking the lowest ready index.
+    let mut pending: Vec<usize> = inputs.iter().map(Vec::len).collect();
+    let mut ready: BTreeSet<usize> = (0..nodes.len()).filter(|&i| pending[i] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(i) = ready.pop_first() {
+        order.push(&nodes[i]);
+        for &reader in &readers[i] {
+            pending[reader] -= 1;
+            if pending[reader] == 0 {
+                ready.insert(reader);
+            }
+        }
+    }
+
+    if order.len() < nodes.len() {
+        let cycle = find_cycle(&inputs, &pending);
+        let ids: Vec<String> = cycle
+            .iter()
+            .map(|&i| format!("'{}'", nodes[i].id))
+            .collect();
+        return Err(GenerateError::InvalidParameter(format!(
+            "cycle detected between nodes {}",
+            ids.join(" -> ")
+        )));
+    }
+
+    Ok(order)
+}
+
+/// Returns one cycle among the nodes Kahn's algorithm could not order, as a
+/// path that starts and ends on the same node.
+fn find_cycle(inputs: &[Vec<usize>], pending: &[usize]) -> Vec<usize> {
+    // Every unordered node has an unordered input, so walking inputs from one
+    // of them must revisit a node.
+    let start = (0..pending.len())
+        .find(|&i| pending[i] > 0)
+        .expect("an unordered node");
+    let mut path = vec![start];
+    let mut current = start;
+    loop {
+        current = *inputs[current]
+            .iter()
+            .find(|&&j| pending[j] > 0)
+            .expect("an unordered input");
+        if let Some(pos) = path.iter().position(|&i| i == current) {
+            // The walk follows inputs; reverse it so the path reads in data-flow order.
+            let mut cycle = path.split_off(pos);
+            cycle.reverse();
+            cycle.insert(0, current);
+            return cycle;
+        }
+        path.push(current);
+    }
+}
+
+/// Returns the ids of the nodes `op` reads from.
+pub(super) fn op_inputs(op: &TextureProceduralOp) -> Vec<&str> {
+    match op {
+        TextureProceduralOp::Constant { .. }
+        | TextureProceduralOp::Noise { .. }
+        | TextureProceduralOp::DirectionalNoise { .. }
+        | TextureProceduralOp::ReactionDiffusion { .. }
+        | TextureProceduralOp::Gradient { .. }
+        | TextureProceduralOp::Stripes { .. }
+        | TextureProceduralOp::Checkerboard { .. }
+        | TextureProceduralOp::Pattern { .. } => Vec::new(),
+        TextureProceduralOp::Invert { input }
+        | TextureProceduralOp::Clamp { input, .. }
+        | TextureProceduralOp::Threshold { input, .. }
+        | TextureProceduralOp::Math { input, .. }
+        | TextureProceduralOp::Blur { input, .. }
+        | TextureProceduralOp::Erode { input, .. }
+        | TextureProceduralOp::Dilate { input, .. }
+        | TextureProceduralOp::UvScale { input, .. }
+        | TextureProceduralOp::UvRotate { input, .. }
+        | TextureProceduralOp::UvTranslate { input, .. }
+        | TextureProceduralOp::ToGrayscale { input }
+        | TextureProceduralOp::ColorRamp { input, .. }
+        | TextureProceduralOp::GradientMap { input, .. }
+        | TextureProceduralOp::Palette { input, .. }
+        | TextureProceduralOp::Posterize { input, .. }
+        | TextureProceduralOp::Dither { input, .. }
+        | TextureProceduralOp::Bevel { input, .. }
+        | TextureProceduralOp::NormalFromHeight { input, .. }
+        | TextureProceduralOp::WangTiles { input, .. }
+        | TextureProceduralOp::TextureBomb { input, .. } => vec![input.as_str()],
+        TextureProceduralOp::Add { a, b } | TextureProceduralOp::Multiply { a, b } => {
+            vec![a.as_str(), b.as_str()]
+        }
+        TextureProceduralOp::Lerp { a, b, t } => vec![a.as_str(), b.as_str(), t.as_str()],
+        TextureProceduralOp::Warp {
+            input,
+            displacement,
+            ..
+        } => vec![input.as_str(), displacement.as_str()],
+        TextureProceduralOp::DomainWarp {
+            input,
+            warp_x,
+            warp_y,
+            ..
+        } => vec![input.as_str(), warp_x.as_str(), warp_y.as_str()],
+        TextureProceduralOp::BlendScreen { base, blend }
+        | TextureProceduralOp::BlendOverlay { base, blend }
+        | TextureProceduralOp::BlendSoftLight { base, blend }
+        | TextureProceduralOp::BlendDifference { base, blend } => {
+            vec![base.as_str(), blend.as_str()]
+        }
+        TextureProceduralOp::Blend { a, b, mask, .. } => {
+            let mut inputs = vec![a.as_str(), b.as_str()];
+            inputs.extend(mask.as_deref());
+            inputs
+        }
+        TextureProceduralOp::ComposeRgba { r, g, b, a } => {
+            let mut inputs = vec![r.as_str(), g.as_str(), b.as_str()];
+            inputs.extend(a.as_deref());
+            inputs
+        }
+    }
+}
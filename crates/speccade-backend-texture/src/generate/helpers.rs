@@ -10,6 +10,7 @@ use speccade_spec::recipe::texture::{MaterialType, TextureMapType, TextureMateri
 use speccade_spec::validation::common as shared_validation;
 
 use super::GenerateError;
+use crate::png::BitDepth;
 
 // Re-export shared utilities with consistent naming for this module
 pub use crate::shared::apply_buffer_transform as apply_transform;
@@ -85,6 +86,18 @@ pub fn validate_base_material(params: &TextureMaterialV1Params) -> Result<(), Ge
     Ok(())
 }
 
+/// Validate the requested height map bit depth and convert it for the PNG writer.
+pub fn height_bit_depth(params: &TextureMaterialV1Params) -> Result<BitDepth, GenerateError> {
+    match params.height_bit_depth {
+        8 => Ok(BitDepth::Eight),
+        16 => Ok(BitDepth::Sixteen),
+        other => Err(GenerateError::InvalidParameter(format!(
+            "height_bit_depth must be 8 or 16, got {}",
+            other
+        ))),
+    }
+}
+
 /// Get default roughness range for a material type.
 pub fn get_default_roughness_range(material_type: &MaterialType) -> [f64; 2] {
     match material_type {
@@ -121,6 +134,8 @@ mod tests {
             layers: Vec::<TextureLayer>::new(),
             palette: None,
             color_ramp: None,
+            height_bit_depth: 8,
+            normal: None,
         }
     }
 
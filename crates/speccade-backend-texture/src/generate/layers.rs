@@ -241,6 +241,13 @@ mod tests {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
             affects: vec![TextureMapType::Roughness],
             strength: 0.0,
@@ -310,6 +317,13 @@ mod tests {
                 octaves: 3,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
             affects: vec![TextureMapType::Height],
             strength: 0.8,
@@ -334,6 +348,13 @@ mod tests {
                 octaves: 2,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
             threshold: 1.0,
             depth: 0.5,
@@ -5,6 +5,7 @@
 
 mod albedo;
 mod color_utils;
+mod composite;
 mod emissive;
 mod graph;
 mod helpers;
@@ -15,6 +16,7 @@ mod metallic;
 mod packed;
 mod roughness;
 mod simple_maps;
+mod tile_variation;
 
 #[cfg(test)]
 mod tests;
@@ -30,21 +32,28 @@ use crate::color::Color;
 use crate::maps::GrayscaleBuffer;
 use crate::rng::DeterministicRng;
 
-pub use graph::{encode_graph_value_png, generate_graph, GraphValue};
+pub use composite::generate_composite;
+pub use graph::{
+    encode_graph_value_exr, encode_graph_value_png, generate_graph, generate_graph_cached,
+    node_cache_keys, DiskNodeCache, GraphValue, NodeCache,
+};
 use helpers::{
-    get_default_metallic, get_default_roughness_range, validate_base_material, validate_map_list,
-    validate_resolution,
+    get_default_metallic, get_default_roughness_range, height_bit_depth, validate_base_material,
+    validate_map_list, validate_resolution,
 };
 use layers::apply_layer_to_height;
 use materials::apply_material_pattern;
 pub use packed::generate_packed_maps;
+pub use tile_variation::generate_tile_variation;
 
 // Re-export map generators
 use albedo::generate_albedo_map;
 use emissive::generate_emissive_map;
 use metallic::generate_metallic_map;
 use roughness::generate_roughness_map;
-use simple_maps::{generate_ao_map, generate_height_output, generate_normal_map};
+use simple_maps::{
+    generate_ao_map, generate_curvature_map, generate_height_output, generate_normal_map,
+};
 
 /// Errors from texture generation.
 #[derive(Debug, Error)]
@@ -52,6 +61,9 @@ pub enum GenerateError {
     #[error("PNG error: {0}")]
     Png(#[from] crate::png::PngError),
 
+    #[error("EXR error: {0}")]
+    Exr(#[from] crate::exr::ExrError),
+
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -65,6 +77,7 @@ impl BackendError for GenerateError {
             GenerateError::Png(_) => "TEXTURE_001",
             GenerateError::Io(_) => "TEXTURE_002",
             GenerateError::InvalidParameter(_) => "TEXTURE_003",
+            GenerateError::Exr(_) => "TEXTURE_004",
         }
     }
 
@@ -108,6 +121,7 @@ pub fn generate_material_maps(
     validate_resolution(width, height)?;
     validate_map_list(&params.maps)?;
     validate_base_material(params)?;
+    let height_bits = height_bit_depth(params)?;
 
     let mut results = HashMap::new();
 
@@ -164,12 +178,15 @@ pub fn generate_material_maps(
                 height,
                 map_seed,
             )?,
-            TextureMapType::Normal => generate_normal_map(&height_map, 1.0)?,
+            TextureMapType::Normal => {
+                generate_normal_map(&height_map, &params.normal.unwrap_or_default())?
+            }
             TextureMapType::Ao => generate_ao_map(&height_map, 1.0)?,
             TextureMapType::Emissive => {
                 generate_emissive_map(&params.layers, width, height, map_seed)?
             }
-            TextureMapType::Height => generate_height_output(&height_map)?,
+            TextureMapType::Height => generate_height_output(&height_map, height_bits)?,
+            TextureMapType::Curvature => generate_curvature_map(&height_map, 1.0, params.tileable)?,
         };
 
         results.insert(*map_type, result);
@@ -220,6 +237,7 @@ pub fn save_texture_result(
             TextureMapType::Ao => "ao",
             TextureMapType::Emissive => "emissive",
             TextureMapType::Height => "height",
+            TextureMapType::Curvature => "curvature",
         };
 
         let filename = format!("{}_{}.png", base_name, suffix);
@@ -1,20 +1,20 @@
-//! Simple map generators (normal, AO, height).
+//! Simple map generators (normal, AO, curvature, height).
 //!
 //! These maps are derived directly from height data without complex layer processing.
 
-use speccade_spec::recipe::texture::TextureMapType;
+use speccade_spec::recipe::texture::{NormalMapConfig, TextureMapType};
 
-use crate::maps::{AoGenerator, GrayscaleBuffer, NormalGenerator};
-use crate::png::{self, PngConfig};
+use crate::maps::{AoGenerator, CurvatureGenerator, GrayscaleBuffer, NormalGenerator};
+use crate::png::{self, BitDepth, PngConfig};
 
 use super::{GenerateError, MapResult};
 
 /// Generate normal map.
 pub fn generate_normal_map(
     height_map: &GrayscaleBuffer,
-    strength: f64,
+    config: &NormalMapConfig,
 ) -> Result<MapResult, GenerateError> {
-    let generator = NormalGenerator::new().with_strength(strength);
+    let generator = NormalGenerator::from_config(config);
     let buffer = generator.generate_from_height(height_map);
 
     let config = PngConfig::default();
@@ -51,9 +51,36 @@ pub fn generate_ao_map(
     })
 }
 
-/// Generate height output (just converts the height map to PNG).
-pub fn generate_height_output(height_map: &GrayscaleBuffer) -> Result<MapResult, GenerateError> {
+/// Generate curvature map, wrapping the kernel at the edges when `tileable`.
+pub fn generate_curvature_map(
+    height_map: &GrayscaleBuffer,
+    strength: f64,
+    tileable: bool,
+) -> Result<MapResult, GenerateError> {
+    let generator = CurvatureGenerator::new()
+        .with_strength(strength)
+        .with_tileable(tileable);
+    let buffer = generator.generate_from_height(height_map);
+
     let config = PngConfig::default();
+    let (data, hash) = png::write_grayscale_to_vec_with_hash(&buffer, &config)?;
+
+    Ok(MapResult {
+        map_type: TextureMapType::Curvature,
+        data,
+        width: height_map.width,
+        height: height_map.height,
+        hash,
+        is_color: false,
+    })
+}
+
+/// Generate height output (just converts the height map to PNG at `bit_depth`).
+pub fn generate_height_output(
+    height_map: &GrayscaleBuffer,
+    bit_depth: BitDepth,
+) -> Result<MapResult, GenerateError> {
+    let config = PngConfig::default().with_bit_depth(bit_depth);
     let (data, hash) = png::write_grayscale_to_vec_with_hash(height_map, &config)?;
 
     Ok(MapResult {
@@ -3,8 +3,8 @@
 use super::*;
 use ::png as png_crate;
 use speccade_spec::recipe::texture::{
-    BaseMaterial, GradientDirection, MaterialType, NoiseAlgorithm, NoiseConfig, StripeDirection,
-    TextureLayer,
+    BaseMaterial, GradientDirection, MaterialType, NoiseAlgorithm, NoiseConfig, NormalMapConfig,
+    StripeDirection, TextureLayer,
 };
 use std::collections::HashSet;
 use std::io::Cursor;
@@ -29,6 +29,8 @@ fn make_params() -> TextureMaterialV1Params {
         layers: vec![],
         palette: None,
         color_ramp: None,
+        height_bit_depth: 8,
+        normal: None,
     }
 }
 
@@ -80,6 +82,34 @@ fn test_generate_material_maps_seed_changes_output() {
     assert_ne!(hash1, hash2);
 }
 
+#[test]
+fn test_material_normal_config_flips_green() {
+    let default = generate_material_maps(&make_params(), 42).unwrap();
+    let mut params = make_params();
+    params.normal = Some(NormalMapConfig {
+        flip_y: true,
+        ..NormalMapConfig::default()
+    });
+    let flipped = generate_material_maps(&params, 42).unwrap();
+
+    let (_, _, _, a) = decode_png_bytes(&default.maps[&TextureMapType::Normal].data);
+    let (_, _, _, b) = decode_png_bytes(&flipped.maps[&TextureMapType::Normal].data);
+    assert_eq!(a.len(), b.len());
+    for (pa, pb) in a.chunks_exact(3).zip(b.chunks_exact(3)) {
+        assert_eq!(pa[0], pb[0]);
+        // Flat pixels (G = 0.5) round to 128 either way
+        assert!(pa[1] == pb[1] || pa[1] as u16 + pb[1] as u16 == 255);
+        assert_eq!(pa[2], pb[2]);
+    }
+    assert_ne!(a, b);
+
+    // Other maps are unaffected
+    assert_eq!(
+        default.maps[&TextureMapType::Albedo].hash,
+        flipped.maps[&TextureMapType::Albedo].hash
+    );
+}
+
 // ========================================================================
 // Validation Tests
 // ========================================================================
@@ -122,6 +152,13 @@ fn test_layer_noise_pattern_generation() {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         affects: vec![TextureMapType::Roughness],
         strength: 0.5,
@@ -243,6 +280,13 @@ fn test_layer_pitting_generation() {
             octaves: 3,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.6,
         depth: 0.2,
@@ -318,6 +362,13 @@ fn test_layer_stains_generation() {
             octaves: 3,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.7,
         color: [0.2, 0.18, 0.15],
@@ -340,6 +391,13 @@ fn test_layer_water_streaks_generation() {
             octaves: 4,
             persistence: 0.6,
             lacunarity: 2.2,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.65,
         direction: StripeDirection::Vertical,
@@ -460,6 +518,13 @@ fn test_noise_algorithm_perlin() {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         affects: vec![TextureMapType::Roughness],
         strength: 0.5,
@@ -479,6 +544,13 @@ fn test_noise_algorithm_simplex() {
             octaves: 6,
             persistence: 0.6,
             lacunarity: 2.2,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         affects: vec![TextureMapType::Roughness],
         strength: 0.7,
@@ -498,6 +570,13 @@ fn test_noise_algorithm_worley() {
             octaves: 3,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         affects: vec![TextureMapType::Roughness],
         strength: 0.4,
@@ -516,6 +595,8 @@ fn test_noise_algorithm_all() {
         NoiseAlgorithm::Value,
         NoiseAlgorithm::Gabor,
         NoiseAlgorithm::Fbm,
+        NoiseAlgorithm::RidgedMultifractal,
+        NoiseAlgorithm::Billow,
     ] {
         let mut params = make_params();
         params.layers = vec![TextureLayer::NoisePattern {
@@ -525,6 +606,13 @@ fn test_noise_algorithm_all() {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
             affects: vec![TextureMapType::Roughness],
             strength: 0.5,
@@ -567,18 +655,43 @@ fn test_save_all_map_types() {
         TextureMapType::Ao,
         TextureMapType::Emissive,
         TextureMapType::Height,
+        TextureMapType::Curvature,
     ];
 
     let result = generate_material_maps(&params, 42).unwrap();
     let tmp = tempfile::tempdir().unwrap();
     let paths = save_texture_result(&result, tmp.path(), "test").unwrap();
 
-    assert_eq!(paths.len(), 7);
+    assert_eq!(paths.len(), 8);
     for map_type in params.maps {
         assert!(paths.contains_key(&map_type));
     }
 }
 
+#[test]
+fn test_height_map_bit_depth() {
+    let mut params = make_params();
+    params.maps = vec![TextureMapType::Height];
+    let eight = generate_material_maps(&params, 42).unwrap();
+    let height8 = &eight.maps[&TextureMapType::Height];
+    let (_, _, _, bytes8) = decode_png_bytes(&height8.data);
+    assert_eq!(bytes8.len(), 32 * 32);
+
+    params.height_bit_depth = 16;
+    let sixteen = generate_material_maps(&params, 42).unwrap();
+    let height16 = &sixteen.maps[&TextureMapType::Height];
+    let (color_type, _, _, bytes16) = decode_png_bytes(&height16.data);
+    assert_eq!(color_type, png_crate::ColorType::Grayscale);
+    assert_eq!(bytes16.len(), 32 * 32 * 2);
+    assert_ne!(height16.hash, height8.hash);
+
+    let again = generate_material_maps(&params, 42).unwrap();
+    assert_eq!(again.maps[&TextureMapType::Height].data, height16.data);
+
+    params.height_bit_depth = 12;
+    assert!(generate_material_maps(&params, 42).is_err());
+}
+
 // ========================================================================
 // Multi-Layer Tests
 // ========================================================================
@@ -594,6 +707,13 @@ fn test_multiple_layers_combined() {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
             affects: vec![TextureMapType::Roughness],
             strength: 0.3,
@@ -692,6 +812,13 @@ fn test_emissive_layers_produce_non_black_output() {
             octaves: 2,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.4,
         color: [1.0, 0.5, 0.0],
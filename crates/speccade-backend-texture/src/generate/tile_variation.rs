@@ -0,0 +1,336 @@
+//! Tiled sheets with per-tile variation (`texture.tile_variation_v1`).
+//!
+//! The tile graph is evaluated once, exactly like a standalone
+//! `texture.procedural_v1` graph at the tile resolution. Each tile of the
+//! sheet then gets its own seeded transform (quarter-turn rotation, mirroring,
+//! hue shift). With every transform disabled the sheet is a plain repeat.
+
+use speccade_spec::recipe::texture::{
+    TextureProceduralV1Params, TextureTileVariationV1Params, TileVariation,
+};
+
+use crate::color::Color;
+use crate::maps::{GrayscaleBuffer, TextureBuffer};
+use crate::rng::DeterministicRng;
+
+use super::graph::{generate_graph, GraphValue};
+use super::helpers::validate_resolution;
+use super::GenerateError;
+
+/// Generate the sheet for a `texture.tile_variation_v1` recipe.
+///
+/// The sheet is grayscale or RGBA to match the tile output.
+pub fn generate_tile_variation(
+    params: &TextureTileVariationV1Params,
+    seed: u32,
+) -> Result<GraphValue, GenerateError> {
+    let [tile_width, tile_height] = params.tile_resolution;
+    validate_resolution(tile_width, tile_height)?;
+
+    let [columns, rows] = params.tiles;
+    if columns == 0 || rows == 0 {
+        return Err(GenerateError::InvalidParameter(format!(
+            "tiles must be positive, got [{}, {}]",
+            columns, rows
+        )));
+    }
+    let sheet_width = tile_width.checked_mul(columns);
+    let sheet_height = tile_height.checked_mul(rows);
+    let (Some(sheet_width), Some(sheet_height)) = (sheet_width, sheet_height) else {
+        return Err(GenerateError::InvalidParameter(format!(
+            "sheet size overflows: {}x{} tiles of {}x{}",
+            columns, rows, tile_width, tile_height
+        )));
+    };
+    validate_resolution(sheet_width, sheet_height)?;
+
+    let hue_shift = params.variation.hue_shift;
+    if !(0.0..=180.0).contains(&hue_shift) {
+        return Err(GenerateError::InvalidParameter(format!(
+            "variation.hue_shift must be in [0, 180], got {}",
+            hue_shift
+        )));
+    }
+
+    let graph = TextureProceduralV1Params {
+        resolution: params.tile_resolution,
+        tileable: params.tileable,
+        nodes: params.nodes.clone(),
+    };
+    let tile = generate_graph(&graph, seed)?
+        .remove(&params.output)
+        .ok_or_else(|| {
+            GenerateError::InvalidParameter(format!(
+                "output '{}' does not match any node id",
+                params.output
+            ))
+        })?;
+
+    let square = tile_width == tile_height;
+    let transforms: Vec<TileTransform> = (0..columns * rows)
+        .map(|index| {
+            let tile_seed = DeterministicRng::derive_layer_seed(seed, index);
+            TileTransform::new(&params.variation, tile_seed, square)
+        })
+        .collect();
+
+    Ok(match tile {
+        GraphValue::Grayscale(buf) => {
+            let mut sheet = GrayscaleBuffer::new(sheet_width, sheet_height, 0.0);
+            fill_sheet(&transforms, params, |x, y, (sx, sy), _| {
+                sheet.set(x, y, buf.get(sx, sy));
+            });
+            GraphValue::Grayscale(sheet)
+        }
+        GraphValue::Color(buf) => {
+            let mut sheet = TextureBuffer::new_black(sheet_width, sheet_height);
+            fill_sheet(&transforms, params, |x, y, (sx, sy), transform| {
+                sheet.set(x, y, transform.shift_hue(buf.get(sx, sy)));
+            });
+            GraphValue::Color(sheet)
+        }
+    })
+}
+
+/// Visits every sheet texel with its source texel in the tile and its tile's transform.
+fn fill_sheet(
+    transforms: &[TileTransform],
+    params: &TextureTileVariationV1Params,
+    mut write: impl FnMut(u32, u32, (u32, u32), &TileTransform),
+) {
+    let [tile_width, tile_height] = params.tile_resolution;
+    let [columns, rows] = params.tiles;
+
+    for row in 0..rows {
+        for column in 0..columns {
+            let transform = &transforms[(row * columns + column) as usize];
+            for y in 0..tile_height {
+                for x in 0..tile_width {
+                    let source = transform.source(x, y, tile_width, tile_height);
+                    write(
+                        column * tile_width + x,
+                        row * tile_height + y,
+                        source,
+                        transform,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Transform applied to one tile of the sheet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TileTransform {
+    /// Clockwise quarter turns (0 to 3).
+    quarter_turns: u32,
+    flip_x: bool,
+    flip_y: bool,
+    /// Hue offset in degrees.
+    hue_offset: f64,
+}
+
+impl TileTransform {
+    /// Draws a tile's transform.
+    ///
+    /// Every draw happens regardless of which transforms are enabled, so turning
+    /// one on doesn't reshuffle the others.
+    fn new(variation: &TileVariation, tile_seed: u32, square: bool) -> Self {
+        let mut rng = DeterministicRng::new(tile_seed);
+        let quarter_turns = rng.gen_range(0..4u32);
+        let flip_x = rng.gen_f64() < 0.5;
+        let flip_y = rng.gen_f64() < 0.5;
+        let hue_offset = rng.gen_signed_f64() * variation.hue_shift;
+
+        Self {
+            // Quarter turns would swap a non-square tile's width and height
+            quarter_turns: match (variation.rotate, square) {
+                (false, _) => 0,
+                (true, true) => quarter_turns,
+                (true, false) => quarter_turns & 2,
+            },
+            flip_x: variation.flip && flip_x,
+            flip_y: variation.flip && flip_y,
+            hue_offset,
+        }
+    }
+
+    /// Maps a texel of the transformed tile back to the source tile.
+    fn source(&self, x: u32, y: u32, width: u32, height: u32) -> (u32, u32) {
+        let (mut sx, mut sy) = match self.quarter_turns {
+            1 => (y, width - 1 - x),
+            2 => (width - 1 - x, height - 1 - y),
+            3 => (height - 1 - y, x),
+            _ => (x, y),
+        };
+        if self.flip_x {
+            sx = width - 1 - sx;
+        }
+        if self.flip_y {
+            sy = height - 1 - sy;
+        }
+        (sx, sy)
+    }
+
+    fn shift_hue(&self, color: Color) -> Color {
+        if self.hue_offset == 0.0 {
+            return color;
+        }
+        let (h, s, v) = color.to_hsv();
+        let shifted = Color::from_hsv(h + self.hue_offset, s, v);
+        Color::rgba(shifted.r, shifted.g, shifted.b, color.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speccade_spec::recipe::texture::{
+        NoiseAlgorithm, NoiseConfig, TextureProceduralNode, TextureProceduralOp,
+    };
+
+    fn noise_nodes() -> Vec<TextureProceduralNode> {
+        vec![TextureProceduralNode {
+            id: "n".to_string(),
+            op: TextureProceduralOp::Noise {
+                noise: NoiseConfig {
+                    algorithm: NoiseAlgorithm::Perlin,
+                    scale: 0.2,
+                    octaves: 3,
+                    persistence: 0.5,
+                    lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
+                },
+            },
+        }]
+    }
+
+    fn params(variation: TileVariation) -> TextureTileVariationV1Params {
+        TextureTileVariationV1Params {
+            tile_resolution: [16, 16],
+            tileable: true,
+            nodes: noise_nodes(),
+            output: "n".to_string(),
+            tiles: [4, 3],
+            variation,
+        }
+    }
+
+    fn grayscale(value: GraphValue) -> GrayscaleBuffer {
+        match value {
+            GraphValue::Grayscale(buf) => buf,
+            GraphValue::Color(_) => panic!("expected grayscale"),
+        }
+    }
+
+    fn tile_block(sheet: &GrayscaleBuffer, column: u32, row: u32) -> Vec<f64> {
+        let mut block = Vec::new();
+        for y in 0..16 {
+            for x in 0..16 {
+                block.push(sheet.get(column * 16 + x, row * 16 + y));
+            }
+        }
+        block
+    }
+
+    #[test]
+    fn no_variation_equals_plain_repeat() {
+        let params = params(TileVariation::default());
+        let sheet = grayscale(generate_tile_variation(&params, 42).unwrap());
+
+        let graph = TextureProceduralV1Params {
+            resolution: [16, 16],
+            tileable: true,
+            nodes: noise_nodes(),
+        };
+        let tile = grayscale(generate_graph(&graph, 42).unwrap().remove("n").unwrap());
+
+        assert_eq!((sheet.width, sheet.height), (64, 48));
+        for y in 0..48 {
+            for x in 0..64 {
+                assert_eq!(sheet.get(x, y), tile.get(x % 16, y % 16));
+            }
+        }
+    }
+
+    #[test]
+    fn variation_produces_distinct_deterministic_tiles() {
+        let variation = TileVariation {
+            rotate: true,
+            flip: true,
+            hue_shift: 0.0,
+        };
+        let a = grayscale(generate_tile_variation(&params(variation.clone()), 42).unwrap());
+        let b = grayscale(generate_tile_variation(&params(variation), 42).unwrap());
+        assert_eq!(a.data, b.data);
+
+        let mut blocks: Vec<Vec<f64>> = Vec::new();
+        for row in 0..3 {
+            for column in 0..4 {
+                let block = tile_block(&a, column, row);
+                if !blocks.contains(&block) {
+                    blocks.push(block);
+                }
+            }
+        }
+        assert!(blocks.len() > 1, "every tile came out identical");
+    }
+
+    #[test]
+    fn hue_shift_varies_color_tiles_only_in_hue() {
+        let mut params = params(TileVariation {
+            rotate: false,
+            flip: false,
+            hue_shift: 90.0,
+        });
+        params.nodes.push(TextureProceduralNode {
+            id: "rgb".to_string(),
+            op: TextureProceduralOp::ColorRamp {
+                input: "n".to_string(),
+                ramp: vec!["#204080".to_string(), "#c06020".to_string()],
+            },
+        });
+        params.output = "rgb".to_string();
+
+        let GraphValue::Color(sheet) = generate_tile_variation(&params, 7).unwrap() else {
+            panic!("expected color");
+        };
+
+        // Same texel of every tile: value is preserved, hue differs somewhere
+        let first = sheet.get(3, 5).to_hsv();
+        let mut hue_differs = false;
+        for row in 0..3 {
+            for column in 0..4 {
+                let (h, _, v) = sheet.get(column * 16 + 3, row * 16 + 5).to_hsv();
+                assert!((v - first.2).abs() < 1e-9);
+                hue_differs |= (h - first.0).abs() > 1.0;
+            }
+        }
+        assert!(hue_differs);
+    }
+
+    #[test]
+    fn non_square_tiles_rotate_by_half_turns() {
+        let mut params = params(TileVariation {
+            rotate: true,
+            flip: false,
+            hue_shift: 0.0,
+        });
+        params.tile_resolution = [16, 8];
+        let sheet = grayscale(generate_tile_variation(&params, 3).unwrap());
+        assert_eq!((sheet.width, sheet.height), (64, 24));
+    }
+
+    #[test]
+    fn rejects_zero_tiles() {
+        let mut params = params(TileVariation::default());
+        params.tiles = [0, 2];
+        assert!(generate_tile_variation(&params, 1).is_err());
+    }
+}
@@ -6,10 +6,16 @@
 //!
 //! # Features
 //!
-//! - **Noise Primitives**: Simplex, Perlin, Worley/Voronoi, Gabor, and FBM
+//! - **Noise Primitives**: Simplex, Perlin, Worley/Voronoi, Gabor, FBM, ridged multifractal, and billow
 //! - **Pattern Primitives**: Brick, checkerboard, wood grain, scratches, edge wear
 //! - **Procedural Graph Ops**: Named DAG nodes producing grayscale or RGBA outputs
+//! - **Node Cache**: Content-keyed node reuse across runs for incremental graph builds
 //! - **Deterministic PNG**: Fixed compression settings for byte-identical output
+//! - **Palette Extraction**: Seeded k-means for representative colors of a texture
+//! - **Dilation**: Edge padding that grows valid colors into empty texels for atlases
+//! - **Float EXR**: Uncompressed 32-bit float output for HDR height and emissive data
+//! - **Mipmaps**: Coverage-preserving mip chains exported as KTX2 or per-level PNGs
+//! - **Seam Verification**: Edge discontinuity metrics and seam scores for tileable textures
 //!
 //! # Example
 //!
@@ -33,6 +39,13 @@
 //!                     octaves: 3,
 //!                     persistence: 0.5,
 //!                     lacunarity: 2.0,
+//!                     distance_metric: Default::default(),
+//!                     jitter: 1.0,
+//!                     worley_mode: Default::default(),
+//!                     frequency: None,
+//!                     orientation: None,
+//!                     bandwidth: None,
+//!                     impulses_per_cell: None,
 //!                 },
 //!             },
 //!         },
@@ -64,19 +77,24 @@
 
 pub mod color;
 pub mod decal;
+pub mod dilation;
+pub mod exr;
 pub mod font;
 pub mod generate;
 pub mod maps;
 pub mod matcap;
 pub mod material_preset;
+pub mod mipmap;
 pub mod noise;
 pub mod normal_map;
 pub mod normal_map_patterns;
 pub mod packing;
+pub mod palette;
 pub mod particle_profile;
 pub mod pattern;
 pub mod png;
 pub mod rng;
+pub mod seam;
 pub mod shared;
 pub mod splat_set;
 pub mod sprite;
@@ -87,30 +105,43 @@ pub mod vfx;
 // Re-export main types for convenience
 pub use color::{BlendMode, Color};
 pub use decal::{generate_decal, DecalError, DecalResult, DecalTextureResult};
+pub use dilation::{dilate, mask_from_alpha, DilationError};
+pub use exr::ExrError;
 pub use font::{generate_bitmap_font, FontBitmapError, FontBitmapResult};
 pub use generate::{
-    encode_graph_value_png, generate_graph, generate_material_maps, generate_packed_maps,
-    save_texture_result, GenerateError, GraphValue, MapResult, TextureResult,
+    encode_graph_value_exr, encode_graph_value_png, generate_composite, generate_graph,
+    generate_graph_cached, generate_material_maps, generate_packed_maps, generate_tile_variation,
+    save_texture_result, DiskNodeCache, GenerateError, GraphValue, MapResult, NodeCache,
+    TextureResult,
 };
 pub use maps::{GrayscaleBuffer, TextureBuffer};
 pub use matcap::{generate_matcap, MatcapError, MatcapResult};
-pub use noise::{Fbm, GaborNoise, Noise2D, PerlinNoise, SimplexNoise, WorleyNoise};
+pub use mipmap::{generate_mip_chain, mip_level_count, MipmapError};
+pub use noise::{
+    Billow, Fbm, GaborNoise, Noise2D, PerlinNoise, RidgedMultifractal, SimplexNoise, WorleyNoise,
+};
 pub use normal_map::{generate_normal_map, save_normal_map, NormalMapError, NormalMapResult};
 pub use packing::{
     extract_channel, pack_channels, resolve_channel_source, ChannelSource, ColorComponent,
     PackedChannels, PackingError,
 };
+pub use palette::extract_palette;
 pub use particle_profile::{
     generate_particle_profile, ParticleProfileError, ParticleProfileResult,
 };
 pub use pattern::{
-    BrickPattern, CheckerPattern, EdgeWearPattern, Pattern2D, ScratchesPattern, WoodGrainPattern,
+    BasketweavePattern, BrickPattern, CheckerPattern, EdgeWearPattern, HerringbonePattern,
+    Pattern2D, ScratchesPattern, WoodGrainPattern,
 };
-pub use png::{PngConfig, PngError};
+pub use png::{BitDepth, PngConfig, PngError};
 pub use rng::DeterministicRng;
+pub use seam::{verify_tileable, EdgeSeamMetrics, SeamEdge, SeamReport, SEAM_SCORE_THRESHOLD};
 pub use splat_set::{generate_splat_set, SplatSetError, SplatSetResult};
 pub use sprite::{generate_sprite_sheet, SpriteSheetError, SpriteSheetResult};
-pub use trimsheet::{generate_trimsheet, TrimsheetError, TrimsheetResult};
+pub use trimsheet::{
+    generate_trimsheet, save_trimsheet_layout, trimsheet_layout_path, TrimsheetError,
+    TrimsheetResult,
+};
 pub use ui::{
     generate_damage_number, generate_icon_set, generate_item_card, generate_nine_slice,
     DamageNumberError, DamageNumberResult, IconSetError, IconSetResult, ItemCardError,
@@ -141,6 +172,13 @@ mod tests {
                             octaves: 2,
                             persistence: 0.5,
                             lacunarity: 2.0,
+                            distance_metric: Default::default(),
+                            jitter: 1.0,
+                            worley_mode: Default::default(),
+                            frequency: None,
+                            orientation: None,
+                            bandwidth: None,
+                            impulses_per_cell: None,
                         },
                     },
                 },
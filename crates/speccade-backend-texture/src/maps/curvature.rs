@@ -0,0 +1,151 @@
+//! Curvature map generator.
+
+use super::GrayscaleBuffer;
+
+/// Laplacian-to-output gain at strength 1.0.
+///
+/// Height maps are in [0, 1], so per-texel second differences are small; this
+/// brings typical ridges and creases well away from neutral gray.
+const CURVATURE_GAIN: f64 = 4.0;
+
+/// Curvature map generator.
+///
+/// Computes the Laplacian of a height map and maps it around neutral gray:
+/// convex areas (ridges, edges) are brighter than 0.5 and concave areas
+/// (valleys, creases) are darker. Useful as an edge-wear or dirt mask.
+pub struct CurvatureGenerator {
+    /// Curvature contrast (0.0 = flat gray, 1.0 = default contrast).
+    pub strength: f64,
+    /// Whether the kernel wraps at the edges (for tileable height maps).
+    pub tileable: bool,
+}
+
+impl CurvatureGenerator {
+    /// Create a new curvature generator.
+    pub fn new() -> Self {
+        Self {
+            strength: 1.0,
+            tileable: true,
+        }
+    }
+
+    /// Set the curvature strength.
+    pub fn with_strength(mut self, strength: f64) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// Set whether the kernel wraps at the edges.
+    ///
+    /// When disabled, edge texels are clamped instead.
+    pub fn with_tileable(mut self, tileable: bool) -> Self {
+        self.tileable = tileable;
+        self
+    }
+
+    /// Generate curvature from a height map.
+    pub fn generate_from_height(&self, height_map: &GrayscaleBuffer) -> GrayscaleBuffer {
+        let width = height_map.width;
+        let height = height_map.height;
+        let mut buffer = GrayscaleBuffer::new(width, height, 0.5);
+
+        for y in 0..height {
+            for x in 0..width {
+                let (x, y) = (x as i32, y as i32);
+                let center = height_map.get(x as u32, y as u32);
+                let neighbors = self.sample(height_map, x - 1, y)
+                    + self.sample(height_map, x + 1, y)
+                    + self.sample(height_map, x, y - 1)
+                    + self.sample(height_map, x, y + 1);
+                let laplacian = neighbors - 4.0 * center;
+
+                // A peak has a negative Laplacian, so subtract to make it bright
+                let curvature = 0.5 - laplacian * CURVATURE_GAIN * self.strength;
+                buffer.set(x as u32, y as u32, curvature.clamp(0.0, 1.0));
+            }
+        }
+
+        buffer
+    }
+
+    fn sample(&self, height_map: &GrayscaleBuffer, x: i32, y: i32) -> f64 {
+        if self.tileable {
+            height_map.get_wrapped(x, y)
+        } else {
+            let x = x.clamp(0, height_map.width as i32 - 1);
+            let y = y.clamp(0, height_map.height as i32 - 1);
+            height_map.get(x as u32, y as u32)
+        }
+    }
+}
+
+impl Default for CurvatureGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2x2 grid of square pyramids, 32 texels each, peaking at each cell center.
+    fn pyramids() -> GrayscaleBuffer {
+        let mut height_map = GrayscaleBuffer::new(64, 64, 0.0);
+        for y in 0..64 {
+            for x in 0..64 {
+                let dx = ((x % 32) as f64 - 15.5).abs();
+                let dy = ((y % 32) as f64 - 15.5).abs();
+                height_map.set(x, y, 1.0 - dx.max(dy) / 15.5);
+            }
+        }
+        height_map
+    }
+
+    #[test]
+    fn test_curvature_pyramid_ridges_and_valleys() {
+        let generator = CurvatureGenerator::new();
+        let curvature = generator.generate_from_height(&pyramids());
+
+        // Diagonal edge of a pyramid is convex
+        assert!(curvature.get(8, 8) > 0.6, "ridge: {}", curvature.get(8, 8));
+        // Seam between two pyramids is concave
+        assert!(
+            curvature.get(32, 8) < 0.4,
+            "valley: {}",
+            curvature.get(32, 8)
+        );
+        // Planar face is neutral
+        assert!((curvature.get(8, 2) - 0.5).abs() < 1e-9);
+
+        let again = generator.generate_from_height(&pyramids());
+        assert_eq!(curvature.data, again.data);
+    }
+
+    #[test]
+    fn test_curvature_flat_is_neutral() {
+        let height_map = GrayscaleBuffer::new(16, 16, 0.7);
+        let curvature = CurvatureGenerator::new().generate_from_height(&height_map);
+        assert!(curvature.data.iter().all(|&v| (v - 0.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_curvature_wraps_only_when_tileable() {
+        // A ramp's wrap-around seam is a cliff only if the kernel wraps
+        let mut height_map = GrayscaleBuffer::new(64, 8, 0.0);
+        for y in 0..8 {
+            for x in 0..64 {
+                height_map.set(x, y, x as f64 / 63.0);
+            }
+        }
+        let wrapped = CurvatureGenerator::new().generate_from_height(&height_map);
+        let clamped = CurvatureGenerator::new()
+            .with_tileable(false)
+            .generate_from_height(&height_map);
+
+        assert!(wrapped.get(0, 4) < 0.1);
+        assert!(wrapped.get(63, 4) > 0.9);
+        assert!((clamped.get(0, 4) - 0.5).abs() < 0.1);
+        assert_eq!(wrapped.get(32, 4), clamped.get(32, 4));
+    }
+}
@@ -4,6 +4,7 @@
 
 mod albedo;
 mod ao;
+mod curvature;
 mod emissive;
 mod metallic;
 mod normal;
@@ -11,6 +12,7 @@ mod roughness;
 
 pub use albedo::AlbedoGenerator;
 pub use ao::AoGenerator;
+pub use curvature::CurvatureGenerator;
 pub use emissive::EmissiveGenerator;
 pub use metallic::MetallicGenerator;
 pub use normal::NormalGenerator;
@@ -191,6 +193,14 @@ impl GrayscaleBuffer {
             .collect()
     }
 
+    /// Convert to 16-bit values (0-65535), clamping to [0, 1].
+    pub fn to_u16(&self) -> Vec<u16> {
+        self.data
+            .iter()
+            .map(|&v| (v.clamp(0.0, 1.0) * 65535.0).round() as u16)
+            .collect()
+    }
+
     /// Convert to TextureBuffer.
     pub fn to_texture_buffer(&self) -> TextureBuffer {
         TextureBuffer::from_grayscale(&self.data, self.width, self.height)
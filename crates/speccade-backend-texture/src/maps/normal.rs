@@ -1,5 +1,7 @@
 //! Normal map generator.
 
+use speccade_spec::recipe::texture::NormalMapConfig;
+
 use super::{GrayscaleBuffer, TextureBuffer};
 use crate::color::Color;
 
@@ -9,6 +11,10 @@ pub struct NormalGenerator {
     pub strength: f64,
     /// Whether to invert the height map.
     pub invert: bool,
+    /// Whether to invert the green channel (DirectX Y-down convention).
+    pub flip_y: bool,
+    /// Whether to invert the red channel.
+    pub flip_x: bool,
 }
 
 impl NormalGenerator {
@@ -17,6 +23,18 @@ impl NormalGenerator {
         Self {
             strength: 1.0,
             invert: false,
+            flip_y: false,
+            flip_x: false,
+        }
+    }
+
+    /// Create a generator from a normal map config.
+    pub fn from_config(config: &NormalMapConfig) -> Self {
+        Self {
+            strength: config.strength,
+            invert: false,
+            flip_y: config.flip_y,
+            flip_x: config.flip_x,
         }
     }
 
@@ -93,6 +111,10 @@ impl NormalGenerator {
         let ny = gy; // OpenGL/wgpu Y-up convention (was -gy for DirectX Y-down)
         let nz = 1.0;
 
+        // Channel convention overrides
+        let nx = if self.flip_x { -nx } else { nx };
+        let ny = if self.flip_y { -ny } else { ny };
+
         // Normalize
         let len = (nx * nx + ny * ny + nz * nz).sqrt();
         let nx = nx / len;
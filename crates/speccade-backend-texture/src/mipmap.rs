@@ -0,0 +1,422 @@
+//! Mipmap chain generation and export.
+//!
+//! Engines that can't (or shouldn't) build mips at import time can take a
+//! pre-generated chain instead. Each level is a 2x2 box downscale of the one
+//! above it; with an alpha cutoff, alpha is rescaled per level so the fraction
+//! of texels passing the cutoff matches level 0 and alpha-tested foliage or
+//! fences don't thin out in the distance. The chain exports as a single KTX2
+//! mip stack or as one PNG per level.
+
+use thiserror::Error;
+
+use crate::color::Color;
+use crate::maps::TextureBuffer;
+use crate::png::{self, PngConfig, PngError};
+
+/// KTX2 file identifier.
+const KTX2_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+
+/// `VK_FORMAT_R8G8B8A8_UNORM`.
+const VK_FORMAT_R8G8B8A8_UNORM: u32 = 37;
+
+/// Bytes per RGBA8 texel.
+const BYTES_PER_TEXEL: usize = 4;
+
+/// Fixed header (identifier + nine `u32` fields) plus the section index.
+const KTX2_HEADER_LEN: usize = 12 + 9 * 4 + 4 * 4 + 2 * 8;
+
+/// Each level index entry holds offset, length, and uncompressed length.
+const KTX2_LEVEL_ENTRY_LEN: usize = 3 * 8;
+
+/// Binary search steps when fitting the alpha scale for coverage.
+const COVERAGE_SEARCH_STEPS: u32 = 16;
+
+/// Largest alpha scale tried when preserving coverage.
+const MAX_ALPHA_SCALE: f64 = 4.0;
+
+/// Errors from mipmap operations.
+#[derive(Debug, Error)]
+pub enum MipmapError {
+    #[error("Invalid dimensions: {0}")]
+    InvalidDimensions(String),
+
+    #[error("Level {level} is {width}x{height}, expected {expected_width}x{expected_height}")]
+    LevelMismatch {
+        level: usize,
+        width: u32,
+        height: u32,
+        expected_width: u32,
+        expected_height: u32,
+    },
+
+    #[error("PNG error: {0}")]
+    Png(#[from] PngError),
+}
+
+/// Returns the number of levels in a full mip chain: `floor(log2(max_dim)) + 1`.
+pub fn mip_level_count(width: u32, height: u32) -> u32 {
+    let max_dim = width.max(height);
+    if max_dim == 0 {
+        0
+    } else {
+        max_dim.ilog2() + 1
+    }
+}
+
+/// Returns the fraction of texels whose alpha is above `cutoff`.
+pub fn alpha_coverage(buffer: &TextureBuffer, cutoff: f64) -> f64 {
+    if buffer.data.is_empty() {
+        return 0.0;
+    }
+    let covered = buffer.data.iter().filter(|c| c.a > cutoff).count();
+    covered as f64 / buffer.data.len() as f64
+}
+
+/// Builds a full mip chain, with level 0 a copy of `buffer`.
+///
+/// Each level halves both dimensions (rounding down, never below 1) using a
+/// 2x2 box filter; odd edges repeat their last row or column. When
+/// `alpha_cutoff` is set, each level's alpha is scaled so its coverage at that
+/// cutoff matches level 0.
+pub fn generate_mip_chain(
+    buffer: &TextureBuffer,
+    alpha_cutoff: Option<f64>,
+) -> Result<Vec<TextureBuffer>, MipmapError> {
+    if buffer.width == 0 || buffer.height == 0 {
+        return Err(MipmapError::InvalidDimensions(format!(
+            "{}x{} (must be at least 1x1)",
+            buffer.width, buffer.height
+        )));
+    }
+
+    let target_coverage = alpha_cutoff.map(|cutoff| alpha_coverage(buffer, cutoff));
+    let count = mip_level_count(buffer.width, buffer.height) as usize;
+
+    let mut levels = Vec::with_capacity(count);
+    levels.push(buffer.clone());
+    for _ in 1..count {
+        let mut next = downscale(levels.last().unwrap());
+        if let (Some(cutoff), Some(target)) = (alpha_cutoff, target_coverage) {
+            preserve_coverage(&mut next, cutoff, target);
+        }
+        levels.push(next);
+    }
+
+    Ok(levels)
+}
+
+/// Halves a buffer with a 2x2 box filter.
+fn downscale(buffer: &TextureBuffer) -> TextureBuffer {
+    let width = (buffer.width / 2).max(1);
+    let height = (buffer.height / 2).max(1);
+    let mut out = TextureBuffer::new_black(width, height);
+
+    for y in 0..height {
+        for x in 0..width {
+            let x0 = (x * 2).min(buffer.width - 1);
+            let x1 = (x * 2 + 1).min(buffer.width - 1);
+            let y0 = (y * 2).min(buffer.height - 1);
+            let y1 = (y * 2 + 1).min(buffer.height - 1);
+
+            let texels = [
+                buffer.get(x0, y0),
+                buffer.get(x1, y0),
+                buffer.get(x0, y1),
+                buffer.get(x1, y1),
+            ];
+            let sum = texels.iter().fold((0.0, 0.0, 0.0, 0.0), |acc, c| {
+                (acc.0 + c.r, acc.1 + c.g, acc.2 + c.b, acc.3 + c.a)
+            });
+            out.set(
+                x,
+                y,
+                Color::rgba(sum.0 / 4.0, sum.1 / 4.0, sum.2 / 4.0, sum.3 / 4.0),
+            );
+        }
+    }
+
+    out
+}
+
+/// Scales alpha so the coverage at `cutoff` comes as close to `target` as possible.
+fn preserve_coverage(buffer: &mut TextureBuffer, cutoff: f64, target: f64) {
+    let coverage_at = |scale: f64| {
+        let covered = buffer
+            .data
+            .iter()
+            .filter(|c| (c.a * scale).min(1.0) > cutoff)
+            .count();
+        covered as f64 / buffer.data.len() as f64
+    };
+
+    // Coverage only grows with the scale, so bisect for the smallest scale
+    // that reaches the target
+    let (mut low, mut high) = (0.0, MAX_ALPHA_SCALE);
+    for _ in 0..COVERAGE_SEARCH_STEPS {
+        let mid = (low + high) / 2.0;
+        if coverage_at(mid) < target {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    for texel in &mut buffer.data {
+        texel.a = (texel.a * high).min(1.0);
+    }
+}
+
+/// Checks that `levels` is a non-empty chain where each level halves the one above.
+fn validate_chain(levels: &[TextureBuffer]) -> Result<(), MipmapError> {
+    let Some(base) = levels.first() else {
+        return Err(MipmapError::InvalidDimensions(
+            "mip chain has no levels".to_string(),
+        ));
+    };
+    if base.width == 0 || base.height == 0 {
+        return Err(MipmapError::InvalidDimensions(format!(
+            "{}x{} (must be at least 1x1)",
+            base.width, base.height
+        )));
+    }
+
+    let (mut expected_width, mut expected_height) = (base.width, base.height);
+    for (level, buffer) in levels.iter().enumerate() {
+        if buffer.width != expected_width || buffer.height != expected_height {
+            return Err(MipmapError::LevelMismatch {
+                level,
+                width: buffer.width,
+                height: buffer.height,
+                expected_width,
+                expected_height,
+            });
+        }
+        expected_width = (expected_width / 2).max(1);
+        expected_height = (expected_height / 2).max(1);
+    }
+
+    Ok(())
+}
+
+/// Encodes a mip chain as an uncompressed `R8G8B8A8_UNORM` KTX2 file.
+///
+/// Values are stored as-is with a linear transfer function. Level 0 comes
+/// first in the level index; level data is laid out smallest-first, as the
+/// format requires.
+pub fn write_ktx2_to_vec(levels: &[TextureBuffer]) -> Result<Vec<u8>, MipmapError> {
+    validate_chain(levels)?;
+
+    let dfd = rgba8_data_format_descriptor();
+    let level_index_len = levels.len() * KTX2_LEVEL_ENTRY_LEN;
+    let dfd_offset = KTX2_HEADER_LEN + level_index_len;
+    let data_start = dfd_offset + dfd.len();
+
+    // Byte ranges for each level, allocated from the smallest level up. Every
+    // level is a whole number of 4-byte texels, so offsets stay aligned.
+    let mut ranges = vec![(0usize, 0usize); levels.len()];
+    let mut offset = data_start;
+    for (index, level) in levels.iter().enumerate().rev() {
+        let len = level.data.len() * BYTES_PER_TEXEL;
+        ranges[index] = (offset, len);
+        offset += len;
+    }
+
+    let base = &levels[0];
+    let mut out = Vec::with_capacity(offset);
+    out.extend_from_slice(&KTX2_IDENTIFIER);
+    for v in [
+        VK_FORMAT_R8G8B8A8_UNORM,
+        1, // typeSize
+        base.width,
+        base.height,
+        0, // pixelDepth
+        0, // layerCount
+        1, // faceCount
+        levels.len() as u32,
+        0, // supercompressionScheme
+    ] {
+        out.extend_from_slice(&v.to_le_bytes());
+    }
+    for v in [dfd_offset as u32, dfd.len() as u32, 0, 0] {
+        out.extend_from_slice(&v.to_le_bytes()); // dfd and kvd offset/length
+    }
+    out.extend_from_slice(&0u64.to_le_bytes()); // sgdByteOffset
+    out.extend_from_slice(&0u64.to_le_bytes()); // sgdByteLength
+
+    for &(offset, len) in &ranges {
+        out.extend_from_slice(&(offset as u64).to_le_bytes());
+        out.extend_from_slice(&(len as u64).to_le_bytes());
+        out.extend_from_slice(&(len as u64).to_le_bytes());
+    }
+    out.extend_from_slice(&dfd);
+
+    for level in levels.iter().rev() {
+        out.extend_from_slice(&level.to_rgba8());
+    }
+
+    Ok(out)
+}
+
+/// Write a mip chain as KTX2 to a `Vec<u8>` and return the hash.
+pub fn write_ktx2_to_vec_with_hash(
+    levels: &[TextureBuffer],
+) -> Result<(Vec<u8>, String), MipmapError> {
+    let data = write_ktx2_to_vec(levels)?;
+    let hash = blake3::hash(&data).to_hex().to_string();
+    Ok((data, hash))
+}
+
+/// Encodes each level of a mip chain as its own RGBA PNG, level 0 first.
+pub fn write_png_levels_to_vec_with_hash(
+    levels: &[TextureBuffer],
+    config: &PngConfig,
+) -> Result<Vec<(Vec<u8>, String)>, MipmapError> {
+    validate_chain(levels)?;
+    levels
+        .iter()
+        .map(|level| png::write_rgba_to_vec_with_hash(level, config).map_err(MipmapError::from))
+        .collect()
+}
+
+/// Builds the Khronos basic data format descriptor for 8-bit RGBA.
+fn rgba8_data_format_descriptor() -> Vec<u8> {
+    const SAMPLE_COUNT: u32 = 4;
+    let block_size = 24 + 16 * SAMPLE_COUNT;
+
+    let mut dfd = Vec::new();
+    dfd.extend_from_slice(&(4 + block_size).to_le_bytes()); // dfdTotalSize
+    dfd.extend_from_slice(&0u32.to_le_bytes()); // vendorId + descriptorType
+    dfd.extend_from_slice(&(2 | (block_size << 16)).to_le_bytes()); // version + blockSize
+    dfd.extend_from_slice(&[
+        1, // KHR_DF_MODEL_RGBSDA
+        1, // KHR_DF_PRIMARIES_BT709
+        1, // KHR_DF_TRANSFER_LINEAR
+        0, // straight alpha
+    ]);
+    dfd.extend_from_slice(&[0; 4]); // texelBlockDimension (1x1x1x1)
+    dfd.extend_from_slice(&[BYTES_PER_TEXEL as u8, 0, 0, 0, 0, 0, 0, 0]); // bytesPlane
+
+    // R, G, B, and A channel ids, one byte each
+    for (index, channel) in [0u8, 1, 2, 15].into_iter().enumerate() {
+        let bit_offset = (index * 8) as u16;
+        dfd.extend_from_slice(&bit_offset.to_le_bytes());
+        dfd.push(7); // bitLength - 1
+        dfd.push(channel);
+        dfd.extend_from_slice(&[0; 4]); // samplePosition
+        dfd.extend_from_slice(&0u32.to_le_bytes()); // sampleLower
+        dfd.extend_from_slice(&255u32.to_le_bytes()); // sampleUpper
+    }
+
+    dfd
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(width: u32, height: u32) -> TextureBuffer {
+        let mut buffer = TextureBuffer::new_black(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let v = (x + y) as f64 / (width + height) as f64;
+                buffer.set(x, y, Color::rgba(v, 1.0 - v, 0.5, v));
+            }
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_mip_level_count() {
+        assert_eq!(mip_level_count(1, 1), 1);
+        assert_eq!(mip_level_count(256, 256), 9);
+        assert_eq!(mip_level_count(256, 64), 9);
+        assert_eq!(mip_level_count(100, 37), 7);
+        assert_eq!(mip_level_count(0, 0), 0);
+    }
+
+    #[test]
+    fn test_mip_chain_halves_each_level() {
+        let source = gradient(64, 16);
+        let levels = generate_mip_chain(&source, None).unwrap();
+
+        assert_eq!(levels.len(), 7);
+        assert_eq!(levels[0].data, source.data);
+        for pair in levels.windows(2) {
+            assert_eq!(pair[1].width, (pair[0].width / 2).max(1));
+            assert_eq!(pair[1].height, (pair[0].height / 2).max(1));
+        }
+        let last = levels.last().unwrap();
+        assert_eq!((last.width, last.height), (1, 1));
+    }
+
+    #[test]
+    fn test_mip_chain_preserves_alpha_coverage() {
+        // Speckled alpha averages toward 0.5 under box filtering, so plain mips
+        // lose most of the texels that pass a high cutoff
+        let mut source = TextureBuffer::new_black(64, 64);
+        for y in 0..64 {
+            for x in 0..64 {
+                let alpha = ((x * 7 + y * 13) % 17) as f64 / 16.0;
+                source.set(x, y, Color::rgba(0.2, 0.6, 0.2, alpha));
+            }
+        }
+        let cutoff = 0.7;
+        let target = alpha_coverage(&source, cutoff);
+
+        let plain = generate_mip_chain(&source, None).unwrap();
+        let preserved = generate_mip_chain(&source, Some(cutoff)).unwrap();
+        for level in 1..4 {
+            let plain_error = (alpha_coverage(&plain[level], cutoff) - target).abs();
+            let preserved_error = (alpha_coverage(&preserved[level], cutoff) - target).abs();
+            assert!(
+                preserved_error < 0.1 && preserved_error < plain_error,
+                "level {}: preserved error {} vs plain error {}",
+                level,
+                preserved_error,
+                plain_error
+            );
+        }
+    }
+
+    #[test]
+    fn test_ktx2_layout() {
+        let levels = generate_mip_chain(&gradient(8, 4), None).unwrap();
+        let data = write_ktx2_to_vec(&levels).unwrap();
+
+        assert_eq!(&data[..12], &KTX2_IDENTIFIER);
+        let read_u32 = |at: usize| u32::from_le_bytes(data[at..at + 4].try_into().unwrap());
+        let read_u64 = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+        assert_eq!(read_u32(12), VK_FORMAT_R8G8B8A8_UNORM);
+        assert_eq!((read_u32(20), read_u32(24)), (8, 4));
+        assert_eq!(read_u32(40), 4); // levelCount
+
+        // Level 0 data matches the source and the file ends after the largest level
+        let offset = read_u64(KTX2_HEADER_LEN) as usize;
+        let len = read_u64(KTX2_HEADER_LEN + 8) as usize;
+        assert_eq!(len, 8 * 4 * 4);
+        assert_eq!(&data[offset..offset + len], levels[0].to_rgba8().as_slice());
+        assert_eq!(offset + len, data.len());
+
+        let (_, hash_a) = write_ktx2_to_vec_with_hash(&levels).unwrap();
+        let (_, hash_b) = write_ktx2_to_vec_with_hash(&levels).unwrap();
+        assert_eq!(hash_a, hash_b);
+    }
+
+    #[test]
+    fn test_export_rejects_broken_chain() {
+        let levels = vec![gradient(8, 8), gradient(3, 4)];
+        assert!(matches!(
+            write_ktx2_to_vec(&levels),
+            Err(MipmapError::LevelMismatch { level: 1, .. })
+        ));
+
+        let pngs = write_png_levels_to_vec_with_hash(
+            &generate_mip_chain(&gradient(4, 4), None).unwrap(),
+            &PngConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(pngs.len(), 3);
+    }
+}
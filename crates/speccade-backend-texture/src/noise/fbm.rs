@@ -1,6 +1,8 @@
 //! Fractal Brownian Motion (FBM) noise.
 //!
 //! FBM layers multiple octaves of noise to create natural-looking patterns.
+//! The ridged multifractal and billow variants reshape each octave before
+//! summing it.
 
 use super::Noise2D;
 
@@ -73,10 +75,133 @@ impl<N: Noise2D + Clone> Noise2D for Fbm<N> {
     }
 }
 
+/// How strongly each ridged octave gates the next one.
+const RIDGED_GAIN: f64 = 2.0;
+
+/// Ridged multifractal generator.
+///
+/// Folds each octave into a crest with `(1 - |n|)²` and scales it by the
+/// previous octave's crest, so fine detail collects along the ridges while
+/// valleys stay smooth. Output is in [-1, 1] with most values low and sharp
+/// peaks along the crests.
+#[derive(Clone)]
+pub struct RidgedMultifractal<N: Noise2D + Clone> {
+    fbm: Fbm<N>,
+}
+
+impl<N: Noise2D + Clone> RidgedMultifractal<N> {
+    /// Create a new ridged multifractal generator with the FBM defaults.
+    pub fn new(noise: N) -> Self {
+        Self {
+            fbm: Fbm::new(noise),
+        }
+    }
+
+    /// Set the number of octaves.
+    pub fn with_octaves(mut self, octaves: u8) -> Self {
+        self.fbm = self.fbm.with_octaves(octaves);
+        self
+    }
+
+    /// Set the persistence (amplitude multiplier per octave).
+    pub fn with_persistence(mut self, persistence: f64) -> Self {
+        self.fbm = self.fbm.with_persistence(persistence);
+        self
+    }
+
+    /// Set the lacunarity (frequency multiplier per octave).
+    pub fn with_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.fbm = self.fbm.with_lacunarity(lacunarity);
+        self
+    }
+}
+
+impl<N: Noise2D + Clone> Noise2D for RidgedMultifractal<N> {
+    fn sample(&self, x: f64, y: f64) -> f64 {
+        let fbm = &self.fbm;
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut max_value = 0.0;
+        let mut weight = 1.0;
+
+        for _ in 0..fbm.octaves {
+            let ridge = 1.0 - fbm.noise.sample(x * frequency, y * frequency).abs();
+            let signal = ridge * ridge * weight;
+            weight = (signal * RIDGED_GAIN).clamp(0.0, 1.0);
+
+            total += signal * amplitude;
+            max_value += amplitude;
+            amplitude *= fbm.persistence;
+            frequency *= fbm.lacunarity;
+        }
+
+        // Sum is in [0, max_value]; map to [-1, 1]
+        (total / max_value) * 2.0 - 1.0
+    }
+}
+
+/// Billow noise generator.
+///
+/// Sums `|n|` per octave instead of `n`, which turns zero crossings into
+/// creases and leaves rounded, puffy lobes between them.
+#[derive(Clone)]
+pub struct Billow<N: Noise2D + Clone> {
+    fbm: Fbm<N>,
+}
+
+impl<N: Noise2D + Clone> Billow<N> {
+    /// Create a new billow generator with the FBM defaults.
+    pub fn new(noise: N) -> Self {
+        Self {
+            fbm: Fbm::new(noise),
+        }
+    }
+
+    /// Set the number of octaves.
+    pub fn with_octaves(mut self, octaves: u8) -> Self {
+        self.fbm = self.fbm.with_octaves(octaves);
+        self
+    }
+
+    /// Set the persistence (amplitude multiplier per octave).
+    pub fn with_persistence(mut self, persistence: f64) -> Self {
+        self.fbm = self.fbm.with_persistence(persistence);
+        self
+    }
+
+    /// Set the lacunarity (frequency multiplier per octave).
+    pub fn with_lacunarity(mut self, lacunarity: f64) -> Self {
+        self.fbm = self.fbm.with_lacunarity(lacunarity);
+        self
+    }
+}
+
+impl<N: Noise2D + Clone> Noise2D for Billow<N> {
+    fn sample(&self, x: f64, y: f64) -> f64 {
+        let fbm = &self.fbm;
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut max_value = 0.0;
+
+        for _ in 0..fbm.octaves {
+            let n = fbm.noise.sample(x * frequency, y * frequency).abs();
+            total += (n * 2.0 - 1.0) * amplitude;
+            max_value += amplitude;
+            amplitude *= fbm.persistence;
+            frequency *= fbm.lacunarity;
+        }
+
+        // Normalize to [-1, 1]
+        total / max_value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::noise::SimplexNoise;
+    use crate::noise::{PerlinNoise, SimplexNoise};
 
     #[test]
     fn test_fbm_deterministic() {
@@ -108,4 +233,51 @@ mod tests {
         assert!((-1.0..=1.0).contains(&v1));
         assert!((-1.0..=1.0).contains(&v2));
     }
+
+    /// Excess kurtosis of samples taken on a regular grid.
+    fn excess_kurtosis<N: Noise2D>(noise: &N) -> f64 {
+        let samples: Vec<f64> = (0..128)
+            .flat_map(|y| (0..128).map(move |x| (x as f64 * 0.07, y as f64 * 0.07)))
+            .map(|(x, y)| noise.sample(x, y))
+            .collect();
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let m2 = samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let m4 = samples.iter().map(|v| (v - mean).powi(4)).sum::<f64>() / n;
+        m4 / (m2 * m2) - 3.0
+    }
+
+    #[test]
+    fn test_ridged_has_sharper_peaks_than_fbm() {
+        let fbm = Fbm::new(PerlinNoise::new(42)).with_octaves(5);
+        let ridged = RidgedMultifractal::new(PerlinNoise::new(42)).with_octaves(5);
+
+        let fbm_kurtosis = excess_kurtosis(&fbm);
+        let ridged_kurtosis = excess_kurtosis(&ridged);
+        assert!(
+            ridged_kurtosis > fbm_kurtosis,
+            "ridged kurtosis {} should exceed fbm kurtosis {}",
+            ridged_kurtosis,
+            fbm_kurtosis
+        );
+    }
+
+    #[test]
+    fn test_ridged_and_billow_deterministic_and_in_range() {
+        let ridged_a = RidgedMultifractal::new(PerlinNoise::new(7)).with_octaves(6);
+        let ridged_b = RidgedMultifractal::new(PerlinNoise::new(7)).with_octaves(6);
+        let billow_a = Billow::new(PerlinNoise::new(7)).with_octaves(6);
+        let billow_b = Billow::new(PerlinNoise::new(7)).with_octaves(6);
+
+        for i in 0..200 {
+            let x = i as f64 * 0.173;
+            let y = i as f64 * 0.091;
+            let r = ridged_a.sample(x, y);
+            let b = billow_a.sample(x, y);
+            assert_eq!(r, ridged_b.sample(x, y));
+            assert_eq!(b, billow_b.sample(x, y));
+            assert!((-1.0..=1.0).contains(&r), "ridged out of range: {}", r);
+            assert!((-1.0..=1.0).contains(&b), "billow out of range: {}", b);
+        }
+    }
 }
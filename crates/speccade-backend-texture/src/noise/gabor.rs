@@ -1,12 +1,20 @@
 //! Gabor noise implementation.
 //!
 //! This is a deterministic sparse-convolution Gabor field suitable for
-//! anisotropic, fiber-like texture detail.
+//! anisotropic, fiber-like texture detail. Each lattice cell scatters a few
+//! Gaussian-windowed cosine kernels at seeded positions, so the spectrum is a
+//! band around the kernel frequency rather than Perlin's broad falloff.
 
 use std::f64::consts::PI;
 
 use super::Noise2D;
 
+/// Envelope-width times distance at which a kernel is truncated (about 1% of its peak).
+const KERNEL_REACH: f64 = 1.2;
+
+/// Smallest accepted bandwidth; keeps the kernel support within a few cells.
+const MIN_BANDWIDTH: f64 = 0.25;
+
 /// 2D Gabor noise generator.
 #[derive(Clone)]
 pub struct GaborNoise {
@@ -14,6 +22,14 @@ pub struct GaborNoise {
     frequency: f64,
     sigma: f64,
     impulses_per_cell: u8,
+    /// Fixed kernel orientation in radians; None picks one per impulse.
+    orientation: Option<f64>,
+    /// Squared kernel support radius in noise units.
+    radius_sq: f64,
+    /// Number of neighboring cells searched in each direction.
+    cell_range: i32,
+    /// Cell counts after which the impulse lattice repeats.
+    period: Option<(i32, i32)>,
 }
 
 impl GaborNoise {
@@ -24,9 +40,52 @@ impl GaborNoise {
             frequency: 0.9,
             sigma: 1.2,
             impulses_per_cell: 3,
+            orientation: None,
+            radius_sq: 4.0,
+            cell_range: 1,
+            period: None,
         }
     }
 
+    /// Set the kernel carrier frequency in cycles per noise unit.
+    pub fn with_frequency(mut self, frequency: f64) -> Self {
+        self.frequency = frequency.max(0.0);
+        self
+    }
+
+    /// Fix every kernel to one orientation in radians (0 = varying along x).
+    pub fn with_orientation(mut self, radians: f64) -> Self {
+        self.orientation = Some(radians);
+        self
+    }
+
+    /// Set the Gaussian envelope width in cycles per noise unit.
+    ///
+    /// The kernel is truncated where its envelope falls to about 1%, and every
+    /// cell within that radius is searched, so narrow bandwidths cost more.
+    pub fn with_bandwidth(mut self, bandwidth: f64) -> Self {
+        self.sigma = bandwidth.max(MIN_BANDWIDTH);
+        let radius = KERNEL_REACH / self.sigma;
+        self.radius_sq = radius * radius;
+        self.cell_range = radius.ceil() as i32;
+        self
+    }
+
+    /// Set the number of kernels per lattice cell (at least 1).
+    pub fn with_impulses_per_cell(mut self, impulses: u8) -> Self {
+        self.impulses_per_cell = impulses.max(1);
+        self
+    }
+
+    /// Repeat the impulse lattice every `cells_x` by `cells_y` cells.
+    ///
+    /// Because the kernel support is bounded, the field is then exactly
+    /// periodic: `sample(x + cells_x, y) == sample(x, y)`.
+    pub fn with_period(mut self, cells_x: u32, cells_y: u32) -> Self {
+        self.period = Some((cells_x.max(1) as i32, cells_y.max(1) as i32));
+        self
+    }
+
     #[inline]
     fn splitmix64(mut x: u64) -> u64 {
         x = x.wrapping_add(0x9e3779b97f4a7c15);
@@ -69,16 +128,26 @@ impl Noise2D for GaborNoise {
         let mut weight_sum = 0.0;
 
         // Sparse convolution over neighboring lattice cells.
-        for cy in (cy0 - 1)..=(cy0 + 1) {
-            for cx in (cx0 - 1)..=(cx0 + 1) {
+        let range = self.cell_range;
+        for cy in (cy0 - range)..=(cy0 + range) {
+            for cx in (cx0 - range)..=(cx0 + range) {
+                // Wrapped cells reuse the impulses of their periodic twin.
+                let (hx, hy) = match self.period {
+                    Some((px, py)) => (cx.rem_euclid(px), cy.rem_euclid(py)),
+                    None => (cx, cy),
+                };
+
                 for k in 0..self.impulses_per_cell {
                     let base = (k as u64) * 8;
 
-                    let ox = self.hash_unit(cx, cy, base + 1);
-                    let oy = self.hash_unit(cx, cy, base + 2);
-                    let theta = self.hash_unit(cx, cy, base + 3) * (2.0 * PI);
-                    let phase = self.hash_unit(cx, cy, base + 4) * (2.0 * PI);
-                    let amp = if (self.hash_u32(cx, cy, base + 5) & 1) == 0 {
+                    let ox = self.hash_unit(hx, hy, base + 1);
+                    let oy = self.hash_unit(hx, hy, base + 2);
+                    let theta = match self.orientation {
+                        Some(theta) => theta,
+                        None => self.hash_unit(hx, hy, base + 3) * (2.0 * PI),
+                    };
+                    let phase = self.hash_unit(hx, hy, base + 4) * (2.0 * PI);
+                    let amp = if (self.hash_u32(hx, hy, base + 5) & 1) == 0 {
                         1.0
                     } else {
                         -1.0
@@ -91,7 +160,7 @@ impl Noise2D for GaborNoise {
                     let r2 = dx * dx + dy * dy;
 
                     // Compact neighborhood support for performance.
-                    if r2 > 4.0 {
+                    if r2 > self.radius_sq {
                         continue;
                     }
 
@@ -155,4 +224,89 @@ mod tests {
             }
         }
     }
+
+    /// Row-averaged power spectrum of a `size` x `size` tile sampled at `scale`
+    /// noise units per pixel, indexed by cycles per tile.
+    fn row_power_spectrum(noise: &GaborNoise, size: usize, scale: f64) -> Vec<f64> {
+        let mut power = vec![0.0; size / 2];
+        for y in 0..size {
+            let row: Vec<f64> = (0..size)
+                .map(|x| noise.sample(x as f64 * scale, y as f64 * scale))
+                .collect();
+            let mean = row.iter().sum::<f64>() / size as f64;
+            for (k, p) in power.iter_mut().enumerate().skip(1) {
+                let (mut re, mut im) = (0.0, 0.0);
+                for (x, v) in row.iter().enumerate() {
+                    let angle = 2.0 * PI * (k * x) as f64 / size as f64;
+                    re += (v - mean) * angle.cos();
+                    im -= (v - mean) * angle.sin();
+                }
+                *p += re * re + im * im;
+            }
+        }
+        power
+    }
+
+    #[test]
+    fn test_gabor_spectral_peak_matches_frequency() {
+        let size = 128;
+        let scale = 0.125;
+
+        for frequency in [1.0, 2.0, 3.0] {
+            let noise = GaborNoise::new(7)
+                .with_frequency(frequency)
+                .with_orientation(0.0)
+                .with_bandwidth(0.5)
+                .with_impulses_per_cell(4);
+            let power = row_power_spectrum(&noise, size, scale);
+            let peak = (1..power.len())
+                .max_by(|&a, &b| power[a].total_cmp(&power[b]))
+                .unwrap();
+
+            // cycles per noise unit * noise units per pixel * pixels per tile
+            let expected = frequency * scale * size as f64;
+            assert!(
+                (peak as f64 - expected).abs() <= expected * 0.15,
+                "frequency {}: spectral peak at bin {}, expected {}",
+                frequency,
+                peak,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_gabor_periodic_lattice_tiles() {
+        let noise = GaborNoise::new(11)
+            .with_frequency(2.0)
+            .with_bandwidth(0.6)
+            .with_period(4, 3);
+        for i in 0..64 {
+            let x = i as f64 * 0.173;
+            let y = i as f64 * 0.091;
+            let v = noise.sample(x, y);
+            assert!((v - noise.sample(x + 4.0, y)).abs() < 1e-9);
+            assert!((v - noise.sample(x, y - 3.0)).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_gabor_fixed_orientation_is_anisotropic() {
+        let noise = GaborNoise::new(5)
+            .with_frequency(2.0)
+            .with_orientation(0.0)
+            .with_bandwidth(0.5);
+
+        // Kernels vary along x only, so neighbors along y are far more alike.
+        let (mut along_x, mut along_y) = (0.0, 0.0);
+        for j in 0..32 {
+            for i in 0..32 {
+                let (x, y) = (i as f64 * 0.1, j as f64 * 0.1);
+                let v = noise.sample(x, y);
+                along_x += (noise.sample(x + 0.1, y) - v).abs();
+                along_y += (noise.sample(x, y + 0.1) - v).abs();
+            }
+        }
+        assert!(along_x > 2.0 * along_y, "x {} vs y {}", along_x, along_y);
+    }
 }
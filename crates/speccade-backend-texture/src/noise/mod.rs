@@ -9,7 +9,7 @@ mod perlin;
 mod simplex;
 mod worley;
 
-pub use fbm::Fbm;
+pub use fbm::{Billow, Fbm, RidgedMultifractal};
 pub use gabor::GaborNoise;
 pub use perlin::PerlinNoise;
 pub use simplex::SimplexNoise;
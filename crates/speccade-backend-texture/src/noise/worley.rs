@@ -27,6 +27,8 @@ pub enum WorleyReturn {
     F2MinusF1,
     /// Return F1 + F2 / 2 (soft cells).
     F1PlusF2,
+    /// Return a random value for the nearest point's cell (flat patches).
+    CellValue,
 }
 
 /// 2D Worley (cellular) noise generator.
@@ -71,15 +73,16 @@ impl WorleyNoise {
         self
     }
 
-    /// Hash function for cell coordinates to get deterministic point positions.
-    fn cell_point(&self, cell_x: i32, cell_y: i32) -> (f64, f64) {
-        // Create a unique seed for this cell
-        let cell_seed = self
-            .seed
+    /// Hash function for cell coordinates, giving each cell a unique seed.
+    fn cell_seed(&self, cell_x: i32, cell_y: i32) -> u32 {
+        self.seed
             .wrapping_add((cell_x as u32).wrapping_mul(374761393))
-            .wrapping_add((cell_y as u32).wrapping_mul(668265263));
+            .wrapping_add((cell_y as u32).wrapping_mul(668265263))
+    }
 
-        let mut rng = DeterministicRng::new(cell_seed);
+    /// Deterministic feature point position for a cell.
+    fn cell_point(&self, cell_x: i32, cell_y: i32) -> (f64, f64) {
+        let mut rng = DeterministicRng::new(self.cell_seed(cell_x, cell_y));
 
         // Generate point within cell with jitter
         let px = cell_x as f64 + 0.5 + (rng.gen_f64() - 0.5) * self.jitter;
@@ -88,6 +91,15 @@ impl WorleyNoise {
         (px, py)
     }
 
+    /// Deterministic random value in [0, 1) for a cell.
+    fn cell_value(&self, cell_x: i32, cell_y: i32) -> f64 {
+        // Drawn after the two point coordinates so it doesn't correlate with them
+        let mut rng = DeterministicRng::new(self.cell_seed(cell_x, cell_y));
+        rng.gen_f64();
+        rng.gen_f64();
+        rng.gen_f64()
+    }
+
     /// Compute distance between two points.
     fn distance(&self, x1: f64, y1: f64, x2: f64, y2: f64) -> f64 {
         let dx = x1 - x2;
@@ -117,9 +129,10 @@ impl Noise2D for WorleyNoise {
         let cell_x = Self::fast_floor(x);
         let cell_y = Self::fast_floor(y);
 
-        // Track the two closest distances
+        // Track the two closest distances and the nearest point's cell
         let mut f1 = f64::MAX;
         let mut f2 = f64::MAX;
+        let mut nearest_cell = (cell_x, cell_y);
 
         // Check the 3x3 neighborhood of cells
         for dy in -1..=1 {
@@ -133,6 +146,7 @@ impl Noise2D for WorleyNoise {
                 if dist < f1 {
                     f2 = f1;
                     f1 = dist;
+                    nearest_cell = (cx, cy);
                 } else if dist < f2 {
                     f2 = dist;
                 }
@@ -145,6 +159,7 @@ impl Noise2D for WorleyNoise {
             WorleyReturn::F2 => f2,
             WorleyReturn::F2MinusF1 => f2 - f1,
             WorleyReturn::F1PlusF2 => (f1 + f2) * 0.5,
+            WorleyReturn::CellValue => self.cell_value(nearest_cell.0, nearest_cell.1),
         };
 
         // Normalize to roughly [-1, 1] range
@@ -186,6 +201,86 @@ mod tests {
         assert!(different);
     }
 
+    #[test]
+    fn test_worley_zero_jitter_is_regular_grid() {
+        let noise = WorleyNoise::new(42).with_jitter(0.0);
+        let other_seed = WorleyNoise::new(7).with_jitter(0.0);
+
+        for i in 0..50 {
+            let (x, y) = (i as f64 * 0.173, i as f64 * 0.091);
+            let (fx, fy) = (x - x.floor(), y - y.floor());
+            let v = noise.sample(x, y);
+
+            // F1 is the distance to the nearest cell center, regardless of seed
+            let expected = ((fx - 0.5).powi(2) + (fy - 0.5).powi(2)).sqrt() * 2.0 - 1.0;
+            assert!((v - expected).abs() < 1e-12, "({}, {})", x, y);
+            assert_eq!(v, other_seed.sample(x, y));
+        }
+    }
+
+    #[test]
+    fn test_worley_jitter_is_deterministic() {
+        for jitter in [0.0, 0.35, 1.0] {
+            let a = WorleyNoise::new(42).with_jitter(jitter);
+            let b = WorleyNoise::new(42).with_jitter(jitter);
+            for i in 0..50 {
+                let (x, y) = (i as f64 * 0.31, i as f64 * 0.17);
+                assert_eq!(a.sample(x, y), b.sample(x, y));
+            }
+        }
+
+        // The default generator uses full jitter
+        let default = WorleyNoise::new(42);
+        let full = WorleyNoise::new(42).with_jitter(1.0);
+        assert_eq!(default.sample(1.3, 2.7), full.sample(1.3, 2.7));
+    }
+
+    #[test]
+    fn test_worley_distance_cell_shapes() {
+        // With no jitter, the feature point of cell (0, 0) sits at (0.5, 0.5), so
+        // samples near it measure the distance to that point.
+        let sample = |func, dx: f64, dy: f64| {
+            WorleyNoise::new(42)
+                .with_jitter(0.0)
+                .with_distance_function(func)
+                .sample(0.5 + dx, 0.5 + dy)
+        };
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+        // All metrics agree along the axes
+        for func in [
+            DistanceFunction::Euclidean,
+            DistanceFunction::Manhattan,
+            DistanceFunction::Chebyshev,
+        ] {
+            assert!(close(sample(func, 0.3, 0.0), sample(func, 0.0, 0.3)));
+            assert!(close(sample(func, 0.3, 0.0), sample(func, -0.3, 0.0)));
+        }
+
+        // Round cells: equal distance on the axis and at 45 degrees
+        let diagonal = 0.3 / 2f64.sqrt();
+        let euclidean = DistanceFunction::Euclidean;
+        assert!(close(
+            sample(euclidean, 0.3, 0.0),
+            sample(euclidean, diagonal, diagonal)
+        ));
+
+        // Diamond cells: the diagonal iso-line is at (0.15, 0.15)
+        let manhattan = DistanceFunction::Manhattan;
+        assert!(close(
+            sample(manhattan, 0.3, 0.0),
+            sample(manhattan, 0.15, 0.15)
+        ));
+        assert!(sample(manhattan, 0.3, 0.3) > sample(manhattan, 0.3, 0.0));
+
+        // Square cells: the corner (0.3, 0.3) is as far as the axis point
+        let chebyshev = DistanceFunction::Chebyshev;
+        assert!(close(
+            sample(chebyshev, 0.3, 0.0),
+            sample(chebyshev, 0.3, 0.3)
+        ));
+    }
+
     #[test]
     fn test_worley_return_types() {
         let seed = 42;
@@ -203,4 +298,58 @@ mod tests {
         // (both are shifted by the same amount)
         assert!(v2 >= v1 || (v2 - v1).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_worley_f2_minus_f1_zero_on_borders() {
+        // With no jitter the feature points sit at cell centers and the border
+        // between cells (0, 0) and (1, 0) is the line x = 1
+        let edges = WorleyNoise::new(42)
+            .with_jitter(0.0)
+            .with_return_type(WorleyReturn::F2MinusF1);
+
+        // F2 - F1 = 0 on the border, which normalizes to -1
+        for y in [0.2, 0.5, 0.8] {
+            assert!((edges.sample(1.0, y) + 1.0).abs() < 1e-12);
+        }
+
+        // It peaks at the feature point and falls off toward the border
+        let center = edges.sample(0.5, 0.5);
+        assert!((center - 1.0).abs() < 1e-12);
+        let mut previous = center;
+        for step in 1..=10 {
+            let v = edges.sample(0.5 + step as f64 * 0.05, 0.5);
+            assert!(v < previous);
+            previous = v;
+        }
+    }
+
+    #[test]
+    fn test_worley_f2_minus_f1_deterministic() {
+        let a = WorleyNoise::new(9).with_return_type(WorleyReturn::F2MinusF1);
+        let b = WorleyNoise::new(9).with_return_type(WorleyReturn::F2MinusF1);
+        for i in 0..100 {
+            let (x, y) = (i as f64 * 0.217, i as f64 * 0.113);
+            let v = a.sample(x, y);
+            assert_eq!(v, b.sample(x, y));
+            assert!(v >= -1.0);
+        }
+    }
+
+    #[test]
+    fn test_worley_cell_value_is_flat_per_cell() {
+        let cells = WorleyNoise::new(42)
+            .with_jitter(0.0)
+            .with_return_type(WorleyReturn::CellValue);
+
+        let mut values = Vec::new();
+        for cell in 0..8 {
+            let base = cells.sample(cell as f64 + 0.5, 0.5);
+            assert!((-1.0..=1.0).contains(&base));
+            for (dx, dy) in [(0.1, 0.1), (0.9, 0.2), (0.3, 0.95)] {
+                assert_eq!(cells.sample(cell as f64 + dx, dy), base);
+            }
+            values.push(base);
+        }
+        assert!(values.windows(2).any(|w| w[0] != w[1]));
+    }
 }
@@ -1,5 +1,7 @@
 //! Height map to normal map conversion.
 
+use speccade_spec::recipe::texture::NormalMapConfig;
+
 use crate::color::Color;
 use crate::maps::{GrayscaleBuffer, TextureBuffer};
 
@@ -12,9 +14,13 @@ use crate::maps::{GrayscaleBuffer, TextureBuffer};
 ///
 /// A flat surface encodes as RGB (128, 128, 255) or normalized (0.5, 0.5, 1.0).
 /// This matches the modern standard used by wgpu, Unity, Blender, and most game engines.
-/// Note: DirectX uses the opposite Y convention (G = down), but we follow OpenGL/wgpu.
+/// DirectX uses the opposite Y convention (G = down); `config.flip_y` inverts
+/// the green channel to match it.
 #[allow(clippy::needless_range_loop)]
-pub(crate) fn height_to_normal(height_map: &GrayscaleBuffer, strength: f64) -> TextureBuffer {
+pub(crate) fn height_to_normal(
+    height_map: &GrayscaleBuffer,
+    config: &NormalMapConfig,
+) -> TextureBuffer {
     let width = height_map.width;
     let height = height_map.height;
     let mut buffer = TextureBuffer::new(width, height, Color::rgb(0.5, 0.5, 1.0));
@@ -43,8 +49,8 @@ pub(crate) fn height_to_normal(height_map: &GrayscaleBuffer, strength: f64) -> T
                 - (samples[0][0] + 2.0 * samples[0][1] + samples[0][2]);
 
             // Scale by strength
-            let gx = gx * strength;
-            let gy = gy * strength;
+            let gx = gx * config.strength;
+            let gy = gy * config.strength;
 
             // Create normal vector in OpenGL/wgpu convention (Y-up)
             // gx > 0 means height increases to the right -> normal tilts left -> nx < 0
@@ -56,6 +62,10 @@ pub(crate) fn height_to_normal(height_map: &GrayscaleBuffer, strength: f64) -> T
             let ny = gy; // Inverted for OpenGL/wgpu Y-up convention (was -gy for DirectX Y-down)
             let nz = 1.0;
 
+            // Channel convention overrides
+            let nx = if config.flip_x { -nx } else { nx };
+            let ny = if config.flip_y { -ny } else { ny };
+
             // Normalize
             let len = (nx * nx + ny * ny + nz * nz).sqrt();
             let nx = nx / len;
@@ -6,7 +6,7 @@
 
 use std::path::Path;
 
-use speccade_spec::recipe::texture::TextureNormalV1Params;
+use speccade_spec::recipe::texture::{NormalMapConfig, TextureNormalV1Params};
 use speccade_spec::validation::common as shared_validation;
 use speccade_spec::BackendError;
 
@@ -95,7 +95,12 @@ pub fn generate_normal_map(
     }
 
     // Convert height map to normal map using Sobel operators
-    let normal_buffer = height_to_normal(&height_map, params.bump_strength);
+    let config = NormalMapConfig {
+        strength: params.bump_strength,
+        flip_y: params.flip_y,
+        flip_x: params.flip_x,
+    };
+    let normal_buffer = height_to_normal(&height_map, &config);
 
     // Encode to PNG with hash
     let config = PngConfig::default();
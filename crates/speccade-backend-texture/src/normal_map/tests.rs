@@ -5,6 +5,7 @@ use speccade_spec::recipe::texture::{
 };
 
 use super::*;
+use speccade_spec::recipe::texture::NormalMapConfig;
 
 #[test]
 fn test_generate_flat_normal() {
@@ -13,6 +14,8 @@ fn test_generate_flat_normal() {
         tileable: false,
         pattern: None,
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -33,6 +36,8 @@ fn test_generate_grid_normal() {
             bevel: 0.5,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -53,6 +58,8 @@ fn test_generate_brick_normal() {
             offset: 0.5,
         }),
         bump_strength: 1.5,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -73,9 +80,18 @@ fn test_deterministic() {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -93,6 +109,8 @@ fn test_generate_normal_map_invalid_resolution() {
         tileable: false,
         pattern: None,
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -107,6 +125,8 @@ fn test_generate_normal_map_invalid_bump_strength() {
         tileable: false,
         pattern: None,
         bump_strength: -1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -130,6 +150,8 @@ fn test_pattern_tiles() {
             seed: 42,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -146,6 +168,8 @@ fn test_pattern_hexagons() {
         tileable: true,
         pattern: Some(NormalMapPattern::Hexagons { size: 20, gap: 2 }),
         bump_strength: 1.2,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -165,6 +189,8 @@ fn test_pattern_rivets() {
             seed: 123,
         }),
         bump_strength: 1.5,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -183,6 +209,8 @@ fn test_pattern_weave() {
             depth: 0.2,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -200,6 +228,8 @@ fn test_pattern_diamond_plate() {
             height: 0.35,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -216,6 +246,8 @@ fn test_all_noise_algorithms() {
         NoiseAlgorithm::Value,
         NoiseAlgorithm::Gabor,
         NoiseAlgorithm::Fbm,
+        NoiseAlgorithm::RidgedMultifractal,
+        NoiseAlgorithm::Billow,
     ] {
         let params = TextureNormalV1Params {
             resolution: [64, 64],
@@ -227,9 +259,18 @@ fn test_all_noise_algorithms() {
                     octaves: 4,
                     persistence: 0.5,
                     lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
                 },
             }),
             bump_strength: 1.0,
+            flip_y: false,
+            flip_x: false,
             processing: None,
         };
 
@@ -254,6 +295,8 @@ fn test_processing_blur() {
             bevel: 0.3,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: Some(NormalMapProcessing {
             blur: Some(1.5),
             invert: false,
@@ -276,6 +319,8 @@ fn test_processing_invert() {
             offset: 0.5,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: Some(NormalMapProcessing {
             blur: None,
             invert: true,
@@ -298,6 +343,8 @@ fn test_processing_blur_and_invert() {
             seed: 42,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: Some(NormalMapProcessing {
             blur: Some(2.0),
             invert: true,
@@ -324,6 +371,8 @@ fn test_various_bump_strengths() {
                 bevel: 0.5,
             }),
             bump_strength: strength,
+            flip_y: false,
+            flip_x: false,
             processing: None,
         };
 
@@ -349,9 +398,18 @@ fn test_tileable_noise() {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -371,9 +429,18 @@ fn test_non_tileable_noise() {
                 octaves: 6,
                 persistence: 0.6,
                 lacunarity: 2.2,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -397,6 +464,8 @@ fn test_seed_affects_bricks() {
             offset: 0.5,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -418,6 +487,8 @@ fn test_seed_affects_tiles() {
             seed,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -442,6 +513,8 @@ fn test_save_normal_map() {
             bevel: 0.5,
         }),
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
 
@@ -472,6 +545,8 @@ fn test_bricks_with_different_sizes() {
                 offset: 0.5,
             }),
             bump_strength: 1.0,
+            flip_y: false,
+            flip_x: false,
             processing: None,
         };
 
@@ -493,6 +568,8 @@ fn test_tiles_with_different_gap_depths() {
                 seed: 42,
             }),
             bump_strength: 1.0,
+            flip_y: false,
+            flip_x: false,
             processing: None,
         };
 
@@ -513,6 +590,8 @@ fn test_weave_with_different_thread_widths() {
                 depth: 0.15,
             }),
             bump_strength: 1.0,
+            flip_y: false,
+            flip_x: false,
             processing: None,
         };
 
@@ -520,3 +599,91 @@ fn test_weave_with_different_thread_widths() {
         assert_eq!(result.width, 128);
     }
 }
+
+/// Height map sloping down the image, with a slight rightward slope.
+fn sloped_height_map() -> GrayscaleBuffer {
+    let mut height_map = GrayscaleBuffer::new(32, 32, 0.0);
+    for y in 0..32 {
+        for x in 0..32 {
+            height_map.set(x, y, y as f64 / 31.0 * 0.8 + x as f64 / 31.0 * 0.1);
+        }
+    }
+    height_map
+}
+
+#[test]
+fn test_flip_y_complements_green_channel() {
+    let height_map = sloped_height_map();
+    let default = height_to_normal(&height_map, &NormalMapConfig::default());
+    let flipped = height_to_normal(
+        &height_map,
+        &NormalMapConfig {
+            flip_y: true,
+            ..NormalMapConfig::default()
+        },
+    );
+
+    for y in 0..32 {
+        for x in 0..32 {
+            let a = default.get(x, y).to_rgb8();
+            let b = flipped.get(x, y).to_rgb8();
+            assert_eq!(b[0], a[0], "red must be unchanged at ({}, {})", x, y);
+            assert_eq!(
+                b[1],
+                255 - a[1],
+                "green must be complemented at ({}, {})",
+                x,
+                y
+            );
+            assert_eq!(b[2], a[2], "blue must be unchanged at ({}, {})", x, y);
+
+            for c in [default.get(x, y), flipped.get(x, y)] {
+                let n = [c.r * 2.0 - 1.0, c.g * 2.0 - 1.0, c.b * 2.0 - 1.0];
+                let len = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+                assert!((len - 1.0).abs() < 1e-9, "normal length {}", len);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flip_x_and_strength() {
+    let height_map = sloped_height_map();
+    let default = height_to_normal(&height_map, &NormalMapConfig::default());
+    let flipped = height_to_normal(
+        &height_map,
+        &NormalMapConfig {
+            flip_x: true,
+            ..NormalMapConfig::default()
+        },
+    );
+    let a = default.get(10, 10);
+    let b = flipped.get(10, 10);
+    assert!((a.r + b.r - 1.0).abs() < 1e-12);
+    assert_eq!(a.g, b.g);
+
+    // Stronger bumps tilt the normal further from flat
+    let strong = height_to_normal(&height_map, &NormalMapConfig::with_strength(4.0));
+    assert!(strong.get(10, 10).b < a.b);
+}
+
+#[test]
+fn test_normal_params_flip_y_changes_output() {
+    let make_params = |flip_y: bool| TextureNormalV1Params {
+        resolution: [64, 64],
+        tileable: true,
+        pattern: Some(NormalMapPattern::Grid {
+            cell_size: 16,
+            line_width: 2,
+            bevel: 0.5,
+        }),
+        bump_strength: 1.0,
+        flip_y,
+        flip_x: false,
+        processing: None,
+    };
+
+    let default = generate_normal_map(&make_params(false), 42).unwrap();
+    let flipped = generate_normal_map(&make_params(true), 42).unwrap();
+    assert_ne!(default.hash, flipped.hash);
+}
@@ -36,6 +36,13 @@ fn test_generate_noise_height() {
         octaves: 4,
         persistence: 0.5,
         lacunarity: 2.0,
+        distance_metric: Default::default(),
+        jitter: 1.0,
+        worley_mode: Default::default(),
+        frequency: None,
+        orientation: None,
+        bandwidth: None,
+        impulses_per_cell: None,
     };
 
     let buffer = generate_noise_height(64, 64, &config, 42, false);
@@ -51,6 +58,13 @@ fn test_generate_noise_height_tileable() {
         octaves: 4,
         persistence: 0.5,
         lacunarity: 2.0,
+        distance_metric: Default::default(),
+        jitter: 1.0,
+        worley_mode: Default::default(),
+        frequency: None,
+        orientation: None,
+        bandwidth: None,
+        impulses_per_cell: None,
     };
 
     let buffer = generate_noise_height(64, 64, &config, 42, true);
@@ -121,6 +135,13 @@ fn test_generate_height_from_pattern_all_types() {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
         },
         NormalMapPattern::DiamondPlate {
@@ -0,0 +1,191 @@
+//! Representative palette extraction.
+//!
+//! Clusters the pixels of a texture with k-means to find its dominant colors,
+//! e.g. for deriving a UI theme from a generated texture. Initialization is
+//! seeded (k-means++ driven by [`DeterministicRng`]), so the same buffer and
+//! seed always produce the same palette.
+
+use crate::color::Color;
+use crate::maps::TextureBuffer;
+use crate::rng::DeterministicRng;
+
+/// Upper bound on Lloyd iterations; clustering usually converges well before.
+const MAX_ITERATIONS: usize = 64;
+
+/// Extracts up to `k` representative colors from `buffer`.
+///
+/// Colors are compared in RGBA space. The palette is ordered by how many pixels
+/// each color represents, most common first. Fewer than `k` colors are returned
+/// when the image has fewer than `k` distinct colors, and an empty palette is
+/// returned for `k == 0` or an empty buffer. With `k == 1` the palette is the
+/// mean color of the image.
+pub fn extract_palette(buffer: &TextureBuffer, k: usize, seed: u32) -> Vec<Color> {
+    let pixels = &buffer.data;
+    if k == 0 || pixels.is_empty() {
+        return Vec::new();
+    }
+
+    let mut centers = initial_centers(pixels, k, seed);
+    let mut assignments = vec![usize::MAX; pixels.len()];
+    let mut counts = vec![0usize; centers.len()];
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (pixel, assignment) in pixels.iter().zip(assignments.iter_mut()) {
+            let nearest = nearest_center(&centers, *pixel);
+            if nearest != *assignment {
+                *assignment = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![[0.0f64; 4]; centers.len()];
+        counts.iter_mut().for_each(|c| *c = 0);
+        for (pixel, &assignment) in pixels.iter().zip(&assignments) {
+            let sum = &mut sums[assignment];
+            sum[0] += pixel.r;
+            sum[1] += pixel.g;
+            sum[2] += pixel.b;
+            sum[3] += pixel.a;
+            counts[assignment] += 1;
+        }
+        for ((center, sum), &count) in centers.iter_mut().zip(&sums).zip(&counts) {
+            // An empty cluster keeps its previous center
+            if count > 0 {
+                let n = count as f64;
+                *center = Color::rgba(sum[0] / n, sum[1] / n, sum[2] / n, sum[3] / n);
+            }
+        }
+    }
+
+    let mut palette: Vec<(usize, Color)> = counts.into_iter().zip(centers).collect();
+    // Stable sort keeps initialization order for equally common colors
+    palette.sort_by_key(|&(count, _)| std::cmp::Reverse(count));
+    palette.into_iter().map(|(_, color)| color).collect()
+}
+
+/// Picks up to `k` initial centers with k-means++ seeding.
+fn initial_centers(pixels: &[Color], k: usize, seed: u32) -> Vec<Color> {
+    let mut rng = DeterministicRng::new(seed);
+    let mut centers = vec![pixels[rng.gen_range(0..pixels.len())]];
+    let mut distances: Vec<f64> = pixels.iter().map(|p| distance_sq(*p, centers[0])).collect();
+
+    while centers.len() < k {
+        let total: f64 = distances.iter().sum();
+        if total <= 0.0 {
+            // Every pixel already matches a center
+            break;
+        }
+
+        let mut target = rng.gen_f64() * total;
+        let mut chosen = distances.len() - 1;
+        for (i, &d) in distances.iter().enumerate() {
+            if d > 0.0 && target < d {
+                chosen = i;
+                break;
+            }
+            target -= d;
+        }
+        // Guard against rounding leaving `chosen` on an existing center
+        if distances[chosen] <= 0.0 {
+            chosen = distances.iter().rposition(|&d| d > 0.0).unwrap_or(chosen);
+        }
+
+        let center = pixels[chosen];
+        centers.push(center);
+        for (d, p) in distances.iter_mut().zip(pixels) {
+            *d = d.min(distance_sq(*p, center));
+        }
+    }
+
+    centers
+}
+
+/// Returns the index of the center closest to `color`, preferring the lowest index on ties.
+fn nearest_center(centers: &[Color], color: Color) -> usize {
+    let mut best = 0;
+    let mut best_distance = f64::INFINITY;
+    for (i, center) in centers.iter().enumerate() {
+        let d = distance_sq(color, *center);
+        if d < best_distance {
+            best = i;
+            best_distance = d;
+        }
+    }
+    best
+}
+
+fn distance_sq(a: Color, b: Color) -> f64 {
+    let dr = a.r - b.r;
+    let dg = a.g - b.g;
+    let db = a.b - b.b;
+    let da = a.a - b.a;
+    dr * dr + dg * dg + db * db + da * da
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient_texture() -> TextureBuffer {
+        let mut buffer = TextureBuffer::new(16, 16, Color::black());
+        for y in 0..16 {
+            for x in 0..16 {
+                let (u, v) = (x as f64 / 15.0, y as f64 / 15.0);
+                buffer.set(x, y, Color::rgb(u, v, 1.0 - u * v));
+            }
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_same_buffer_and_seed_give_same_palette() {
+        let buffer = gradient_texture();
+        let a = extract_palette(&buffer, 5, 42);
+        let b = extract_palette(&buffer, 5, 42);
+        assert_eq!(a.len(), 5);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_k1_returns_mean_color() {
+        let mut buffer = TextureBuffer::new(2, 2, Color::rgba(1.0, 0.0, 0.5, 1.0));
+        buffer.set(1, 0, Color::rgba(0.0, 1.0, 0.5, 1.0));
+        buffer.set(0, 1, Color::rgba(0.5, 0.5, 0.0, 1.0));
+        buffer.set(1, 1, Color::rgba(0.5, 0.5, 1.0, 1.0));
+
+        let palette = extract_palette(&buffer, 1, 7);
+        assert_eq!(palette, vec![Color::rgba(0.5, 0.5, 0.5, 1.0)]);
+    }
+
+    #[test]
+    fn test_two_color_image_yields_both_colors() {
+        let red = Color::rgb(0.9, 0.1, 0.1);
+        let blue = Color::rgb(0.1, 0.2, 0.8);
+        let mut buffer = TextureBuffer::new(8, 8, red);
+        for y in 0..8 {
+            for x in 0..3 {
+                buffer.set(x, y, blue);
+            }
+        }
+
+        for seed in 0..8 {
+            let palette = extract_palette(&buffer, 2, seed);
+            assert_eq!(palette.len(), 2);
+            // Red covers more pixels, so it comes first
+            for (actual, expected) in palette.iter().zip([red, blue]) {
+                assert!(distance_sq(*actual, expected) < 1e-20, "seed {}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_returns_at_most_distinct_colors() {
+        let buffer = TextureBuffer::new(4, 4, Color::white());
+        assert_eq!(extract_palette(&buffer, 3, 1), vec![Color::white()]);
+        assert!(extract_palette(&buffer, 0, 1).is_empty());
+    }
+}
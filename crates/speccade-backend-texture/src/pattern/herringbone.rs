@@ -0,0 +1,226 @@
+//! Herringbone plank pattern generator.
+
+use super::plank_grid::PlankGrid;
+use super::{DetailedPattern2D, Pattern2D, PatternSample};
+use crate::rng::DeterministicRng;
+
+/// Herringbone pattern configuration.
+///
+/// Horizontal and vertical planks alternate in a staircase, each plank
+/// butting against the side of the next. Plank length is rounded to a whole
+/// number of plank widths (plus joints) and the layout is fitted to the
+/// texture, so it always tiles.
+#[derive(Debug, Clone)]
+pub struct HerringbonePattern {
+    /// Plank length in pixels.
+    pub plank_length: u32,
+    /// Plank width in pixels.
+    pub plank_width: u32,
+    /// Mortar width in pixels.
+    pub mortar_width: u32,
+    /// Mortar depth (0.0 = flush, 1.0 = deep).
+    pub mortar_depth: f64,
+    /// Plank height variation.
+    pub plank_variation: f64,
+    /// Seed for variation.
+    pub seed: u32,
+    /// Total width for tiling calculations.
+    pub total_width: u32,
+    /// Total height for tiling calculations.
+    pub total_height: u32,
+}
+
+impl HerringbonePattern {
+    /// Create a new herringbone pattern with default settings.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            plank_length: 62,
+            plank_width: 30,
+            mortar_width: 2,
+            mortar_depth: 0.3,
+            plank_variation: 0.1,
+            seed: 42,
+            total_width: width,
+            total_height: height,
+        }
+    }
+
+    /// Set plank dimensions.
+    pub fn with_plank_size(mut self, length: u32, width: u32) -> Self {
+        self.plank_length = length;
+        self.plank_width = width;
+        self
+    }
+
+    /// Set mortar properties.
+    pub fn with_mortar(mut self, width: u32, depth: f64) -> Self {
+        self.mortar_width = width;
+        self.mortar_depth = depth;
+        self
+    }
+
+    /// Set variation amount.
+    pub fn with_variation(mut self, variation: f64) -> Self {
+        self.plank_variation = variation;
+        self
+    }
+
+    /// Set seed.
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    fn grid(&self) -> PlankGrid {
+        PlankGrid::new(
+            self.plank_length,
+            self.plank_width,
+            self.mortar_width,
+            self.total_width,
+            self.total_height,
+        )
+    }
+}
+
+impl Pattern2D for HerringbonePattern {
+    fn sample(&self, x: u32, y: u32) -> f64 {
+        self.sample_detailed(x, y).height
+    }
+}
+
+impl DetailedPattern2D for HerringbonePattern {
+    fn sample_detailed(&self, x: u32, y: u32) -> PatternSample {
+        let grid = self.grid();
+        let cell = grid.locate(x, y);
+        let n = grid.cells_per_plank;
+
+        // One horizontal plank (cells (0..n, 0)) and one vertical plank
+        // (cells (0, 1..=n)) tile the grid when stepped by (1, 1), so the
+        // diagonal index (cx - cy) mod 2n identifies which plank a cell is in.
+        let diagonal = (cell.cx + grid.cells_x - cell.cy % grid.cells_x) % (2 * n);
+        let (first_x, first_y, is_mortar) = if diagonal < n {
+            // Horizontal plank, `diagonal` cells from its left end.
+            let first_x = (cell.cx + grid.cells_x - diagonal) % grid.cells_x;
+            let at_end = diagonal == n - 1;
+            let mortar = grid.in_joint(cell.fy) || (at_end && grid.in_joint(cell.fx));
+            (first_x, cell.cy, mortar)
+        } else {
+            // Vertical plank, `offset` cells from its top end.
+            let offset = 2 * n - 1 - diagonal;
+            let first_y = (cell.cy + grid.cells_y - offset) % grid.cells_y;
+            let at_end = offset == n - 1;
+            let mortar = grid.in_joint(cell.fx) || (at_end && grid.in_joint(cell.fy));
+            (cell.cx, first_y, mortar)
+        };
+
+        if is_mortar {
+            return PatternSample {
+                height: 1.0 - self.mortar_depth,
+                mask: 0.0, // Mortar mask
+                secondary: 0.0,
+            };
+        }
+
+        let plank_seed = self
+            .seed
+            .wrapping_add(first_x.wrapping_mul(374761393))
+            .wrapping_add(first_y.wrapping_mul(668265263));
+        let variation = DeterministicRng::new(plank_seed).gen_f64() * self.plank_variation;
+
+        PatternSample {
+            height: (1.0 - variation).clamp(0.0, 1.0),
+            mask: 1.0,                                         // Plank mask
+            secondary: grid.plank_id(first_x, first_y) as f64, // Plank ID
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Pixel count of every visible plank, keyed by plank ID.
+    fn plank_areas(pattern: &HerringbonePattern, size: u32) -> HashMap<u64, usize> {
+        let mut areas = HashMap::new();
+        for y in 0..size {
+            for x in 0..size {
+                let sample = pattern.sample_detailed(x, y);
+                if sample.mask == 1.0 {
+                    *areas.entry(sample.secondary as u64).or_insert(0) += 1;
+                }
+            }
+        }
+        areas
+    }
+
+    #[test]
+    fn test_herringbone_deterministic_mask() {
+        let pattern1 = HerringbonePattern::new(128, 128).with_seed(7);
+        let pattern2 = HerringbonePattern::new(128, 128).with_seed(7);
+
+        let mut seen_mortar = false;
+        let mut seen_plank = false;
+        for y in 0..128 {
+            for x in 0..128 {
+                let a = pattern1.sample_detailed(x, y);
+                let b = pattern2.sample_detailed(x, y);
+                assert_eq!(a.height, b.height);
+                assert!(a.mask == 0.0 || a.mask == 1.0);
+                seen_mortar |= a.mask == 0.0;
+                seen_plank |= a.mask == 1.0;
+            }
+        }
+        assert!(seen_mortar && seen_plank);
+    }
+
+    #[test]
+    fn test_herringbone_tiles_seamlessly() {
+        // The layout is fitted to any texture size, so sampling one texture
+        // size past an edge wraps back onto the same pattern.
+        let odd = HerringbonePattern::new(150, 90).with_plank_size(18, 8);
+        for y in 0..90 {
+            for x in 0..150 {
+                let sample = odd.sample_detailed(x, y);
+                assert_eq!(odd.sample_detailed(x + 150, y).height, sample.height);
+                assert_eq!(odd.sample_detailed(x, y + 90).height, sample.height);
+            }
+        }
+
+        // Tiling a texture 2x2 matches the same planks laid over twice the area.
+        let tile = HerringbonePattern::new(160, 160).with_plank_size(18, 8);
+        let double = HerringbonePattern::new(320, 320).with_plank_size(18, 8);
+        for y in 0..320 {
+            for x in 0..320 {
+                assert_eq!(
+                    double.sample_detailed(x, y).mask,
+                    tile.sample_detailed(x % 160, y % 160).mask,
+                    "mask differs at ({}, {})",
+                    x,
+                    y
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_herringbone_plank_count_scales_inversely_with_size() {
+        let large = HerringbonePattern::new(256, 256)
+            .with_plank_size(30, 14)
+            .with_mortar(2, 0.3);
+        let small = HerringbonePattern::new(256, 256)
+            .with_plank_size(14, 6)
+            .with_mortar(2, 0.3);
+
+        let large_areas = plank_areas(&large, 256);
+        let small_areas = plank_areas(&small, 256);
+
+        // Halving the plank size quadruples the plank count.
+        assert_eq!(large_areas.len(), 128);
+        assert_eq!(small_areas.len(), 512);
+
+        // Every plank keeps its configured size: 30x14 and 14x6 pixels.
+        assert!(large_areas.values().all(|&area| area == 30 * 14));
+        assert!(small_areas.values().all(|&area| area == 14 * 6));
+    }
+}
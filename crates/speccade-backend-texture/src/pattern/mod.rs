@@ -1,20 +1,25 @@
 //! Pattern generation primitives.
 //!
 //! Patterns are deterministic functions that generate structural features
-//! like bricks, checkerboards, wood grain, etc.
+//! like bricks, parquet floors, checkerboards, wood grain, etc.
 
+mod basketweave;
 mod brick;
 mod checker;
 mod edge_wear;
 mod gradient;
+mod herringbone;
+mod plank_grid;
 mod scratches;
 mod stripes;
 mod wood;
 
+pub use basketweave::BasketweavePattern;
 pub use brick::BrickPattern;
 pub use checker::CheckerPattern;
 pub use edge_wear::EdgeWearPattern;
 pub use gradient::GradientPattern;
+pub use herringbone::HerringbonePattern;
 pub use scratches::ScratchesPattern;
 pub use stripes::StripesPattern;
 pub use wood::WoodGrainPattern;
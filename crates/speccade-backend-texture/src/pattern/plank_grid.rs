@@ -0,0 +1,95 @@
+//! Shared cell grid for plank-based floor patterns.
+
+/// A square cell grid that plank layouts are laid out on.
+///
+/// Each cell is one plank width plus one mortar joint, and a plank spans a
+/// whole number of cells along its length. Both layouts built on it repeat
+/// every two plank lengths, and the cell count along each axis is snapped to a
+/// multiple of that, so the layout wraps exactly at the texture edges.
+#[derive(Debug, Clone)]
+pub(super) struct PlankGrid {
+    /// Plank length in cells.
+    pub cells_per_plank: u32,
+    /// Number of cells across the texture width.
+    pub cells_x: u32,
+    /// Number of cells across the texture height.
+    pub cells_y: u32,
+    /// Fraction of a cell covered by the plank (the rest is mortar).
+    plank_fraction: f64,
+    width: u32,
+    height: u32,
+}
+
+/// A pixel's position on the grid.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GridCell {
+    /// Cell column, wrapped to `0..cells_x`.
+    pub cx: u32,
+    /// Cell row, wrapped to `0..cells_y`.
+    pub cy: u32,
+    /// Position inside the cell along x, in [0, 1).
+    pub fx: f64,
+    /// Position inside the cell along y, in [0, 1).
+    pub fy: f64,
+}
+
+impl PlankGrid {
+    /// Builds the grid for planks of the given pixel size.
+    ///
+    /// The plank length is rounded to a whole number of cells, and the cell
+    /// count along each axis to a multiple of two plank lengths.
+    pub fn new(
+        plank_length: u32,
+        plank_width: u32,
+        mortar_width: u32,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let cell = (plank_width + mortar_width).max(1) as f64;
+        let cells_per_plank = ((plank_length + mortar_width) as f64 / cell)
+            .round()
+            .max(1.0) as u32;
+        let repeat = 2 * cells_per_plank;
+        let snap = |size: u32| {
+            let repeats = (size as f64 / (cell * repeat as f64)).round().max(1.0) as u32;
+            repeats * repeat
+        };
+
+        Self {
+            cells_per_plank,
+            cells_x: snap(width),
+            cells_y: snap(height),
+            plank_fraction: plank_width as f64 / cell,
+            width: width.max(1),
+            height: height.max(1),
+        }
+    }
+
+    /// Locates the center of pixel `(x, y)` on the grid.
+    pub fn locate(&self, x: u32, y: u32) -> GridCell {
+        let (cx, fx) = Self::axis(x, self.cells_x, self.width);
+        let (cy, fy) = Self::axis(y, self.cells_y, self.height);
+        GridCell { cx, cy, fx, fy }
+    }
+
+    /// Maps a pixel index to (cell, position in cell) along one axis.
+    fn axis(pixel: u32, cells: u32, size: u32) -> (u32, f64) {
+        // Integer math keeps the mapping exact, so wrapped pixels land on
+        // exactly the same cell position.
+        let span = 2 * size as u64;
+        let scaled = (2 * pixel as u64 + 1) * cells as u64;
+        let cell = (scaled / span) % cells as u64;
+        (cell as u32, (scaled % span) as f64 / span as f64)
+    }
+
+    /// Returns true if a position inside a cell falls in the mortar joint on
+    /// the cell's far side.
+    pub fn in_joint(&self, f: f64) -> bool {
+        f >= self.plank_fraction
+    }
+
+    /// Returns a stable id for the plank whose first cell is `(cx, cy)`.
+    pub fn plank_id(&self, cx: u32, cy: u32) -> u32 {
+        cx + cy * self.cells_x
+    }
+}
@@ -3,7 +3,7 @@
 //! Uses fixed compression settings to ensure byte-identical output
 //! for the same input data, as required by SpecCade's Tier 1 determinism.
 
-use png::{BitDepth, ColorType, Compression, Encoder, FilterType};
+use png::{BitDepth as EncoderBitDepth, ColorType, Compression, Encoder, FilterType};
 use std::io::Write;
 use std::path::Path;
 use thiserror::Error;
@@ -23,6 +23,17 @@ pub enum PngError {
     InvalidDimensions(String),
 }
 
+/// Bits per channel for grayscale PNG output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BitDepth {
+    /// 8 bits per channel.
+    #[default]
+    Eight,
+    /// 16 bits per channel, for height and displacement maps that would
+    /// otherwise show terracing.
+    Sixteen,
+}
+
 /// PNG export configuration for deterministic output.
 #[derive(Debug, Clone)]
 pub struct PngConfig {
@@ -30,6 +41,8 @@ pub struct PngConfig {
     pub compression: Compression,
     /// Filter type. Use a fixed value for determinism.
     pub filter: FilterType,
+    /// Bit depth for grayscale output. Color output is always 8-bit.
+    pub bit_depth: BitDepth,
 }
 
 impl Default for PngConfig {
@@ -40,6 +53,7 @@ impl Default for PngConfig {
             // Adaptive filter is deterministic but may vary output
             // Use None for maximum determinism (no filtering)
             filter: FilterType::NoFilter,
+            bit_depth: BitDepth::Eight,
         }
     }
 }
@@ -50,14 +64,22 @@ impl PngConfig {
         Self {
             compression: Compression::Best,
             filter: FilterType::Paeth, // Best compression with Paeth filter
+            bit_depth: BitDepth::Eight,
         }
     }
 
+    /// Set the bit depth used for grayscale output.
+    pub fn with_bit_depth(mut self, bit_depth: BitDepth) -> Self {
+        self.bit_depth = bit_depth;
+        self
+    }
+
     /// Create config optimized for speed (faster, but larger files).
     pub fn fast() -> Self {
         Self {
             compression: Compression::Fast,
             filter: FilterType::NoFilter,
+            bit_depth: BitDepth::Eight,
         }
     }
 }
@@ -78,7 +100,7 @@ pub fn write_rgba_to_writer<W: Write>(
 ) -> Result<(), PngError> {
     let mut encoder = Encoder::new(writer, buffer.width, buffer.height);
     encoder.set_color(ColorType::Rgba);
-    encoder.set_depth(BitDepth::Eight);
+    encoder.set_depth(EncoderBitDepth::Eight);
     encoder.set_compression(config.compression);
     encoder.set_filter(config.filter);
 
@@ -109,7 +131,7 @@ pub fn write_rgb_to_writer<W: Write>(
 ) -> Result<(), PngError> {
     let mut encoder = Encoder::new(writer, buffer.width, buffer.height);
     encoder.set_color(ColorType::Rgb);
-    encoder.set_depth(BitDepth::Eight);
+    encoder.set_depth(EncoderBitDepth::Eight);
     encoder.set_compression(config.compression);
     encoder.set_filter(config.filter);
 
@@ -133,15 +155,19 @@ pub fn write_grayscale(
     write_grayscale_to_writer(buffer, writer, config)
 }
 
-/// Write a grayscale buffer to any writer.
+/// Write a grayscale buffer to any writer, at the bit depth set in `config`.
 pub fn write_grayscale_to_writer<W: Write>(
     buffer: &GrayscaleBuffer,
     writer: W,
     config: &PngConfig,
 ) -> Result<(), PngError> {
+    if config.bit_depth == BitDepth::Sixteen {
+        return write_grayscale16_to_writer(buffer, writer, config);
+    }
+
     let mut encoder = Encoder::new(writer, buffer.width, buffer.height);
     encoder.set_color(ColorType::Grayscale);
-    encoder.set_depth(BitDepth::Eight);
+    encoder.set_depth(EncoderBitDepth::Eight);
     encoder.set_compression(config.compression);
     encoder.set_filter(config.filter);
 
@@ -153,6 +179,31 @@ pub fn write_grayscale_to_writer<W: Write>(
     Ok(())
 }
 
+/// Write a grayscale buffer to any writer as a 16-bit PNG.
+pub fn write_grayscale16_to_writer<W: Write>(
+    buffer: &GrayscaleBuffer,
+    writer: W,
+    config: &PngConfig,
+) -> Result<(), PngError> {
+    let mut encoder = Encoder::new(writer, buffer.width, buffer.height);
+    encoder.set_color(ColorType::Grayscale);
+    encoder.set_depth(EncoderBitDepth::Sixteen);
+    encoder.set_compression(config.compression);
+    encoder.set_filter(config.filter);
+
+    let mut png_writer = encoder.write_header()?;
+
+    // PNG stores 16-bit samples big-endian
+    let data: Vec<u8> = buffer
+        .to_u16()
+        .into_iter()
+        .flat_map(u16::to_be_bytes)
+        .collect();
+    png_writer.write_image_data(&data)?;
+
+    Ok(())
+}
+
 /// Write raw bytes to a PNG file.
 pub fn write_raw(
     data: &[u8],
@@ -190,7 +241,7 @@ pub fn write_raw(
 
     let mut encoder = Encoder::new(writer, width, height);
     encoder.set_color(color_type);
-    encoder.set_depth(BitDepth::Eight);
+    encoder.set_depth(EncoderBitDepth::Eight);
     encoder.set_compression(config.compression);
     encoder.set_filter(config.filter);
 
@@ -227,6 +278,17 @@ pub fn write_grayscale_to_vec_with_hash(
     Ok((data, hash))
 }
 
+/// Write 16-bit grayscale to a `Vec<u8>` and return the hash.
+pub fn write_grayscale16_to_vec_with_hash(
+    buffer: &GrayscaleBuffer,
+    config: &PngConfig,
+) -> Result<(Vec<u8>, String), PngError> {
+    let mut data = Vec::new();
+    write_grayscale16_to_writer(buffer, &mut data, config)?;
+    let hash = hash_png(&data);
+    Ok((data, hash))
+}
+
 /// Write RGB to a `Vec<u8>` and return the hash.
 pub fn write_rgb_to_vec_with_hash(
     buffer: &TextureBuffer,
@@ -282,6 +344,53 @@ mod tests {
         assert_eq!(hash1, hash2, "PNG hashes should be identical");
     }
 
+    /// Decodes a grayscale PNG and returns its samples widened to u16.
+    fn decode_gray(data: &[u8]) -> Vec<u16> {
+        let decoder = png::Decoder::new(data);
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).unwrap();
+        let buf = &buf[..info.buffer_size()];
+        match info.bit_depth {
+            EncoderBitDepth::Sixteen => buf
+                .chunks_exact(2)
+                .map(|b| u16::from_be_bytes([b[0], b[1]]))
+                .collect(),
+            _ => buf.iter().map(|&b| b as u16).collect(),
+        }
+    }
+
+    #[test]
+    fn test_grayscale16_resolves_smooth_gradient() {
+        // A gentle ramp spanning a few 8-bit steps
+        let mut buffer = GrayscaleBuffer::new(1024, 1, 0.0);
+        for x in 0..1024 {
+            buffer.set(x, 0, 0.25 + x as f64 / 1023.0 * 0.05);
+        }
+
+        let eight = PngConfig::default();
+        let (data8, _) = write_grayscale_to_vec_with_hash(&buffer, &eight).unwrap();
+        let samples8 = decode_gray(&data8);
+        assert!(
+            samples8.windows(2).any(|w| w[0] == w[1]),
+            "8-bit output should terrace"
+        );
+
+        let sixteen = PngConfig::default().with_bit_depth(BitDepth::Sixteen);
+        let (data16, hash16) = write_grayscale16_to_vec_with_hash(&buffer, &sixteen).unwrap();
+        let samples16 = decode_gray(&data16);
+        assert_eq!(samples16.len(), 1024);
+        assert!(
+            samples16.windows(2).all(|w| w[0] < w[1]),
+            "16-bit output should have no repeated adjacent values"
+        );
+
+        // The config's bit depth routes the generic writer to 16-bit too
+        let (again, again_hash) = write_grayscale_to_vec_with_hash(&buffer, &sixteen).unwrap();
+        assert_eq!(again, data16, "16-bit PNG data should be identical");
+        assert_eq!(again_hash, hash16);
+    }
+
     #[test]
     fn test_different_configs_different_output() {
         let buffer = TextureBuffer::new(64, 64, Color::gray(0.5));
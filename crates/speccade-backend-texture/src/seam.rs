@@ -0,0 +1,283 @@
+//! Seamless-tiling verification.
+//!
+//! Measures how well opposite edges of a texture meet when it is tiled. Each
+//! wrap seam (right edge against left, bottom edge against top) is compared to
+//! the texture's own neighbour-to-neighbour variation, so busy textures are
+//! not penalized for being busy and smooth textures still reveal hard seams.
+//!
+//! This is the single seam metric: the `texture/tile-seam` lint rule and the
+//! tiling section of texture analysis both report it.
+
+use crate::color::Color;
+use crate::maps::TextureBuffer;
+
+/// Variation floor (one 8-bit step) that keeps seam scores finite on flat textures.
+const SEAM_EPSILON: f64 = 1.0 / 255.0;
+
+/// Seam score above which a seam is considered visible.
+pub const SEAM_SCORE_THRESHOLD: f64 = 3.0;
+
+/// A wrap seam of a tiled texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeamEdge {
+    /// The right column meeting the left column.
+    LeftRight,
+    /// The bottom row meeting the top row.
+    TopBottom,
+}
+
+impl SeamEdge {
+    /// Returns a short name for reports (e.g. `left-right`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SeamEdge::LeftRight => "left-right",
+            SeamEdge::TopBottom => "top-bottom",
+        }
+    }
+}
+
+/// Discontinuity metrics for one wrap seam.
+///
+/// Differences are the largest per-channel (RGBA) absolute difference between
+/// two pixels, in the range 0-1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EdgeSeamMetrics {
+    /// Largest difference between facing edge pixels.
+    pub max_discontinuity: f64,
+    /// Mean difference between facing edge pixels.
+    pub mean_discontinuity: f64,
+    /// Mean difference between neighbouring pixels along the same axis inside the texture.
+    pub interior_mean: f64,
+    /// Seam mean relative to the interior mean; around 1 or below is seamless.
+    pub seam_score: f64,
+}
+
+/// Result of [`verify_tileable`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeamReport {
+    /// Right edge against left edge.
+    pub left_right: EdgeSeamMetrics,
+    /// Bottom edge against top edge.
+    pub top_bottom: EdgeSeamMetrics,
+    /// Largest edge discontinuity over both seams.
+    pub max_discontinuity: f64,
+    /// Mean edge discontinuity over all facing pixel pairs of both seams.
+    pub mean_discontinuity: f64,
+    /// Seam score of the worst seam.
+    pub seam_score: f64,
+}
+
+impl SeamReport {
+    /// Returns true if no seam scores above [`SEAM_SCORE_THRESHOLD`].
+    pub fn is_seamless(&self) -> bool {
+        self.seam_score <= SEAM_SCORE_THRESHOLD
+    }
+
+    /// Returns the seam with the highest score (left-right on ties).
+    pub fn worst_edge(&self) -> SeamEdge {
+        if self.top_bottom.seam_score > self.left_right.seam_score {
+            SeamEdge::TopBottom
+        } else {
+            SeamEdge::LeftRight
+        }
+    }
+
+    /// Returns the metrics of the given seam.
+    pub fn edge(&self, edge: SeamEdge) -> &EdgeSeamMetrics {
+        match edge {
+            SeamEdge::LeftRight => &self.left_right,
+            SeamEdge::TopBottom => &self.top_bottom,
+        }
+    }
+}
+
+/// Measures how seamlessly `buffer` tiles.
+///
+/// Both edge conventions used by the generators pass: edges that duplicate
+/// each other and edges that continue into each other both score low.
+pub fn verify_tileable(buffer: &TextureBuffer) -> SeamReport {
+    let (w, h) = (buffer.width, buffer.height);
+    if w == 0 || h == 0 {
+        let clean = EdgeSeamMetrics {
+            max_discontinuity: 0.0,
+            mean_discontinuity: 0.0,
+            interior_mean: 0.0,
+            seam_score: 0.0,
+        };
+        return SeamReport {
+            left_right: clean,
+            top_bottom: clean,
+            max_discontinuity: 0.0,
+            mean_discontinuity: 0.0,
+            seam_score: 0.0,
+        };
+    }
+
+    let left_right = edge_metrics(
+        (0..h).map(|y| (buffer.get(0, y), buffer.get(w - 1, y))),
+        (0..h)
+            .flat_map(|y| (1..w).map(move |x| (x, y)))
+            .map(|(x, y)| (buffer.get(x - 1, y), buffer.get(x, y))),
+    );
+    let top_bottom = edge_metrics(
+        (0..w).map(|x| (buffer.get(x, 0), buffer.get(x, h - 1))),
+        (1..h)
+            .flat_map(|y| (0..w).map(move |x| (x, y)))
+            .map(|(x, y)| (buffer.get(x, y - 1), buffer.get(x, y))),
+    );
+
+    let mean_discontinuity = (left_right.mean_discontinuity * h as f64
+        + top_bottom.mean_discontinuity * w as f64)
+        / (w + h) as f64;
+
+    SeamReport {
+        left_right,
+        top_bottom,
+        max_discontinuity: left_right
+            .max_discontinuity
+            .max(top_bottom.max_discontinuity),
+        mean_discontinuity,
+        seam_score: left_right.seam_score.max(top_bottom.seam_score),
+    }
+}
+
+fn edge_metrics(
+    edge_pairs: impl Iterator<Item = (Color, Color)>,
+    interior_pairs: impl Iterator<Item = (Color, Color)>,
+) -> EdgeSeamMetrics {
+    let (max_discontinuity, mean_discontinuity) = max_and_mean(edge_pairs);
+    let (_, interior_mean) = max_and_mean(interior_pairs);
+
+    EdgeSeamMetrics {
+        max_discontinuity,
+        mean_discontinuity,
+        interior_mean,
+        seam_score: mean_discontinuity / (interior_mean + SEAM_EPSILON),
+    }
+}
+
+fn max_and_mean(pairs: impl Iterator<Item = (Color, Color)>) -> (f64, f64) {
+    let mut max = 0.0f64;
+    let mut sum = 0.0;
+    let mut count = 0usize;
+    for (a, b) in pairs {
+        let diff = pixel_difference(a, b);
+        max = max.max(diff);
+        sum += diff;
+        count += 1;
+    }
+
+    if count == 0 {
+        (0.0, 0.0)
+    } else {
+        (max, sum / count as f64)
+    }
+}
+
+/// Largest absolute per-channel difference between two pixels.
+fn pixel_difference(a: Color, b: Color) -> f64 {
+    (a.r - b.r)
+        .abs()
+        .max((a.g - b.g).abs())
+        .max((a.b - b.b).abs())
+        .max((a.a - b.a).abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::generate_graph;
+    use speccade_spec::recipe::texture::{
+        NoiseAlgorithm, NoiseConfig, TextureProceduralNode, TextureProceduralOp,
+        TextureProceduralV1Params,
+    };
+
+    fn fbm_texture(tileable: bool) -> TextureBuffer {
+        let params = TextureProceduralV1Params {
+            resolution: [64, 64],
+            tileable,
+            nodes: vec![TextureProceduralNode {
+                id: "n".to_string(),
+                op: TextureProceduralOp::Noise {
+                    noise: NoiseConfig {
+                        algorithm: NoiseAlgorithm::Perlin,
+                        scale: 0.05,
+                        octaves: 4,
+                        persistence: 0.5,
+                        lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
+                    },
+                },
+            }],
+        };
+
+        let nodes = generate_graph(&params, 7).unwrap();
+        nodes["n"].as_grayscale().unwrap().to_texture_buffer()
+    }
+
+    fn horizontal_gradient(width: u32, height: u32) -> TextureBuffer {
+        let mut buffer = TextureBuffer::new_black(width, height);
+        for y in 0..height {
+            for x in 0..width {
+                let v = x as f64 / (width - 1) as f64;
+                buffer.set(x, y, Color::rgb(v, v, v));
+            }
+        }
+        buffer
+    }
+
+    #[test]
+    fn test_tileable_fbm_is_seamless() {
+        let report = verify_tileable(&fbm_texture(true));
+        assert!(
+            report.seam_score < 1.0,
+            "seam score {} too high",
+            report.seam_score
+        );
+        assert!(report.max_discontinuity < 0.05);
+    }
+
+    #[test]
+    fn test_gradient_has_left_right_seam() {
+        let report = verify_tileable(&horizontal_gradient(64, 32));
+
+        assert_eq!(report.worst_edge(), SeamEdge::LeftRight);
+        assert!((report.left_right.max_discontinuity - 1.0).abs() < 1e-9);
+        assert!((report.left_right.mean_discontinuity - 1.0).abs() < 1e-9);
+        assert!(report.left_right.seam_score > 10.0);
+        assert!(!report.is_seamless());
+
+        // Rows are identical, so the vertical seam is clean
+        assert_eq!(report.top_bottom.max_discontinuity, 0.0);
+        assert_eq!(report.top_bottom.seam_score, 0.0);
+
+        assert_eq!(report.seam_score, report.left_right.seam_score);
+        assert_eq!(report.edge(SeamEdge::LeftRight), &report.left_right);
+    }
+
+    #[test]
+    fn test_non_tileable_fbm_scores_worse() {
+        let tiled = verify_tileable(&fbm_texture(true));
+        let untiled = verify_tileable(&fbm_texture(false));
+        assert!(tiled.is_seamless());
+        assert!(!untiled.is_seamless());
+        assert!(untiled.seam_score > tiled.seam_score);
+        assert!(untiled.mean_discontinuity > tiled.mean_discontinuity);
+    }
+
+    #[test]
+    fn test_flat_and_tiny_buffers() {
+        let flat = verify_tileable(&TextureBuffer::new(16, 16, Color::gray(0.5)));
+        assert_eq!(flat.seam_score, 0.0);
+        assert_eq!(flat.max_discontinuity, 0.0);
+
+        let single = verify_tileable(&TextureBuffer::new(1, 1, Color::white()));
+        assert_eq!(single.seam_score, 0.0);
+    }
+}
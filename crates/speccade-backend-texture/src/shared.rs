@@ -3,10 +3,15 @@
 //! This module contains common functions used by multiple texture generation
 //! modules to avoid code duplication.
 
-use speccade_spec::recipe::texture::{NoiseAlgorithm, NoiseConfig};
+use speccade_spec::recipe::texture::{
+    NoiseAlgorithm, NoiseConfig, WorleyDistanceMetric, WorleyMode,
+};
 
 use crate::maps::GrayscaleBuffer;
-use crate::noise::{Fbm, GaborNoise, Noise2D, PerlinNoise, SimplexNoise, WorleyNoise};
+use crate::noise::{
+    Billow, DistanceFunction, Fbm, GaborNoise, Noise2D, PerlinNoise, RidgedMultifractal,
+    SimplexNoise, WorleyNoise, WorleyReturn,
+};
 use crate::pattern::Pattern2D;
 
 /// Create a noise generator from configuration.
@@ -27,15 +32,32 @@ pub fn create_noise_generator(config: &NoiseConfig, seed: u32) -> Box<dyn Noise2
     let base_noise: Box<dyn Noise2D> = match config.algorithm {
         NoiseAlgorithm::Perlin => Box::new(PerlinNoise::new(seed)),
         NoiseAlgorithm::Simplex => Box::new(SimplexNoise::new(seed)),
-        NoiseAlgorithm::Worley => Box::new(WorleyNoise::new(seed)),
+        NoiseAlgorithm::Worley => Box::new(
+            WorleyNoise::new(seed)
+                .with_jitter(config.jitter)
+                .with_distance_function(worley_distance(config.distance_metric))
+                .with_return_type(worley_return(config.worley_mode)),
+        ),
         NoiseAlgorithm::Value => Box::new(PerlinNoise::new(seed)), // Use Perlin as fallback
-        NoiseAlgorithm::Gabor => Box::new(GaborNoise::new(seed)),
+        NoiseAlgorithm::Gabor => Box::new(gabor_noise(config, seed)),
         NoiseAlgorithm::Fbm => Box::new(
             Fbm::new(PerlinNoise::new(seed))
                 .with_octaves(config.octaves)
                 .with_persistence(config.persistence)
                 .with_lacunarity(config.lacunarity),
         ),
+        NoiseAlgorithm::RidgedMultifractal => Box::new(
+            RidgedMultifractal::new(PerlinNoise::new(seed))
+                .with_octaves(config.octaves)
+                .with_persistence(config.persistence)
+                .with_lacunarity(config.lacunarity),
+        ),
+        NoiseAlgorithm::Billow => Box::new(
+            Billow::new(PerlinNoise::new(seed))
+                .with_octaves(config.octaves)
+                .with_persistence(config.persistence)
+                .with_lacunarity(config.lacunarity),
+        ),
     };
 
     // Wrap in FBM if octaves > 1 and not already FBM
@@ -158,6 +180,43 @@ where
     }
 }
 
+/// Builds a Gabor generator, overriding only the kernel parameters set in `config`.
+fn gabor_noise(config: &NoiseConfig, seed: u32) -> GaborNoise {
+    let mut noise = GaborNoise::new(seed);
+    if let Some(frequency) = config.frequency {
+        noise = noise.with_frequency(frequency);
+    }
+    if let Some(orientation) = config.orientation {
+        noise = noise.with_orientation(orientation.to_radians());
+    }
+    if let Some(bandwidth) = config.bandwidth {
+        noise = noise.with_bandwidth(bandwidth);
+    }
+    if let Some(impulses) = config.impulses_per_cell {
+        noise = noise.with_impulses_per_cell(impulses);
+    }
+    noise
+}
+
+/// Maps a recipe distance metric to the Worley noise distance function.
+fn worley_distance(metric: WorleyDistanceMetric) -> DistanceFunction {
+    match metric {
+        WorleyDistanceMetric::Euclidean => DistanceFunction::Euclidean,
+        WorleyDistanceMetric::Manhattan => DistanceFunction::Manhattan,
+        WorleyDistanceMetric::Chebyshev => DistanceFunction::Chebyshev,
+    }
+}
+
+/// Maps a recipe Worley mode to the Worley noise return type.
+fn worley_return(mode: WorleyMode) -> WorleyReturn {
+    match mode {
+        WorleyMode::F1 => WorleyReturn::F1,
+        WorleyMode::F2 => WorleyReturn::F2,
+        WorleyMode::F2MinusF1 => WorleyReturn::F2MinusF1,
+        WorleyMode::CellValue => WorleyReturn::CellValue,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +229,13 @@ mod tests {
             octaves: 1,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -185,6 +251,13 @@ mod tests {
             octaves: 1,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -192,6 +265,105 @@ mod tests {
         assert!((0.0..=1.0).contains(&val));
     }
 
+    #[test]
+    fn test_create_noise_generator_worley_distance_metric() {
+        let config = |distance_metric| NoiseConfig {
+            algorithm: NoiseAlgorithm::Worley,
+            scale: 0.1,
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            distance_metric,
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
+        };
+
+        let euclidean = create_noise_generator(&config(WorleyDistanceMetric::Euclidean), 42);
+        let manhattan = create_noise_generator(&config(WorleyDistanceMetric::Manhattan), 42);
+        let chebyshev = create_noise_generator(&config(WorleyDistanceMetric::Chebyshev), 42);
+        let reference = WorleyNoise::new(42);
+
+        let mut manhattan_differs = false;
+        let mut chebyshev_differs = false;
+        for i in 0..64 {
+            let (x, y) = (i as f64 * 0.37, i as f64 * 0.21);
+            // Euclidean reproduces the default Worley output
+            assert_eq!(euclidean.sample(x, y), reference.sample(x, y));
+            manhattan_differs |= manhattan.sample(x, y) != euclidean.sample(x, y);
+            chebyshev_differs |= chebyshev.sample(x, y) != euclidean.sample(x, y);
+        }
+        assert!(manhattan_differs);
+        assert!(chebyshev_differs);
+    }
+
+    #[test]
+    fn test_create_noise_generator_worley_jitter() {
+        let config = |jitter| NoiseConfig {
+            algorithm: NoiseAlgorithm::Worley,
+            scale: 0.1,
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
+        };
+
+        // Full jitter reproduces the default random placement
+        let full = create_noise_generator(&config(1.0), 42);
+        let reference = WorleyNoise::new(42);
+        // Zero jitter puts every feature point at its cell center, so samples
+        // repeat with a period of one cell
+        let grid = create_noise_generator(&config(0.0), 42);
+
+        for i in 0..64 {
+            let (x, y) = (i as f64 * 0.37, i as f64 * 0.21);
+            assert_eq!(full.sample(x, y), reference.sample(x, y));
+            let (fx, fy) = (x.fract(), y.fract());
+            assert!((grid.sample(x, y) - grid.sample(fx + 3.0, fy - 5.0)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_create_noise_generator_worley_mode() {
+        let config = |worley_mode| NoiseConfig {
+            algorithm: NoiseAlgorithm::Worley,
+            scale: 0.1,
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode,
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
+        };
+
+        for (mode, return_type) in [
+            (WorleyMode::F1, WorleyReturn::F1),
+            (WorleyMode::F2, WorleyReturn::F2),
+            (WorleyMode::F2MinusF1, WorleyReturn::F2MinusF1),
+            (WorleyMode::CellValue, WorleyReturn::CellValue),
+        ] {
+            let noise = create_noise_generator(&config(mode), 42);
+            let reference = WorleyNoise::new(42).with_return_type(return_type);
+            for i in 0..32 {
+                let (x, y) = (i as f64 * 0.37, i as f64 * 0.21);
+                assert_eq!(noise.sample(x, y), reference.sample(x, y));
+            }
+        }
+    }
+
     #[test]
     fn test_create_noise_generator_worley() {
         let config = NoiseConfig {
@@ -200,6 +372,13 @@ mod tests {
             octaves: 1,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -215,6 +394,13 @@ mod tests {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -230,6 +416,13 @@ mod tests {
             octaves: 1,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -237,6 +430,39 @@ mod tests {
         assert!((0.0..=1.0).contains(&val));
     }
 
+    #[test]
+    fn test_create_noise_generator_gabor_params() {
+        let config = NoiseConfig {
+            algorithm: NoiseAlgorithm::Gabor,
+            scale: 0.1,
+            octaves: 1,
+            persistence: 0.5,
+            lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: Some(2.0),
+            orientation: Some(90.0),
+            bandwidth: Some(0.5),
+            impulses_per_cell: Some(5),
+        };
+
+        let noise = create_noise_generator(&config, 42);
+        let reference = GaborNoise::new(42)
+            .with_frequency(2.0)
+            .with_orientation(90.0f64.to_radians())
+            .with_bandwidth(0.5)
+            .with_impulses_per_cell(5);
+        let default = GaborNoise::new(42);
+        let mut differs = false;
+        for i in 0..32 {
+            let (x, y) = (i as f64 * 0.37, i as f64 * 0.21);
+            assert_eq!(noise.sample(x, y), reference.sample(x, y));
+            differs |= noise.sample(x, y) != default.sample(x, y);
+        }
+        assert!(differs);
+    }
+
     #[test]
     fn test_create_noise_generator_multi_octave_perlin() {
         let config = NoiseConfig {
@@ -245,6 +471,13 @@ mod tests {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -260,6 +493,13 @@ mod tests {
             octaves: 6,
             persistence: 0.6,
             lacunarity: 2.2,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise = create_noise_generator(&config, 42);
@@ -275,6 +515,13 @@ mod tests {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise1 = create_noise_generator(&config, 42);
@@ -293,6 +540,13 @@ mod tests {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         };
 
         let noise1 = create_noise_generator(&config, 42);
@@ -8,11 +8,14 @@
 //! - Deterministic shelf packing (tiles sorted by height, then width, then id)
 //! - Mip-safe gutters (edge pixels are replicated into padding)
 //! - UV metadata output for each packed tile
+//! - UV layout sidecar (`<atlas>.layout.json`) with normalized and pixel rects
 
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use speccade_spec::recipe::texture::{
-    TextureTrimsheetV1Params, TileSource, TileUvRect, TrimsheetMetadata, TrimsheetTile,
+    TextureTrimsheetV1Params, TileSource, TileUvRect, TrimsheetLayout, TrimsheetMetadata,
+    TrimsheetRegion, TrimsheetTile,
 };
 use thiserror::Error;
 
@@ -44,6 +47,14 @@ pub enum TrimsheetError {
     /// Invalid parameters.
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
+
+    /// IO error while writing the layout sidecar.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// JSON serialization error while writing the layout sidecar.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
 }
 
 /// Result of trimsheet generation.
@@ -55,6 +66,8 @@ pub struct TrimsheetResult {
     pub hash: String,
     /// Metadata with UV coordinates for each tile.
     pub metadata: TrimsheetMetadata,
+    /// UV and pixel rects for each tile, matching the rendered atlas.
+    pub layout: TrimsheetLayout,
 }
 
 /// Internal representation of a packed tile placement.
@@ -170,13 +183,63 @@ pub fn generate_trimsheet(
         tiles: tile_uvs,
     };
 
+    let layout = build_layout(&packed, width, height);
+
     Ok(TrimsheetResult {
         png_data,
         hash,
         metadata,
+        layout,
     })
 }
 
+/// Build the UV layout from the same placements used to render the atlas.
+fn build_layout(packed: &[PackedTile], width: u32, height: u32) -> TrimsheetLayout {
+    let regions = packed
+        .iter()
+        .map(|p| TrimsheetRegion {
+            id: p.id.clone(),
+            u_min: p.x as f64 / width as f64,
+            v_min: p.y as f64 / height as f64,
+            u_max: (p.x + p.width) as f64 / width as f64,
+            v_max: (p.y + p.height) as f64 / height as f64,
+            x: p.x,
+            y: p.y,
+            width: p.width,
+            height: p.height,
+        })
+        .collect();
+
+    TrimsheetLayout {
+        atlas_width: width,
+        atlas_height: height,
+        regions,
+    }
+}
+
+/// Path of the layout sidecar for an atlas PNG (`atlas.png` -> `atlas.layout.json`).
+pub fn trimsheet_layout_path(png_path: &Path) -> PathBuf {
+    png_path.with_extension("layout.json")
+}
+
+/// Save a trimsheet layout as pretty-printed JSON next to the atlas PNG.
+///
+/// Returns the path of the written sidecar.
+pub fn save_trimsheet_layout(
+    layout: &TrimsheetLayout,
+    png_path: &Path,
+) -> Result<PathBuf, TrimsheetError> {
+    let path = trimsheet_layout_path(png_path);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let json = serde_json::to_string_pretty(layout)?;
+    std::fs::write(&path, json)?;
+
+    Ok(path)
+}
+
 /// Pack tiles using a deterministic shelf packing algorithm.
 ///
 /// Tiles are sorted by height (descending), then width (descending), then id
@@ -646,4 +709,88 @@ mod tests {
         assert_eq!(parsed.atlas_width, result.metadata.atlas_width);
         assert_eq!(parsed.tiles.len(), result.metadata.tiles.len());
     }
+
+    #[test]
+    fn test_layout_matches_packed_pixels() {
+        let params = TextureTrimsheetV1Params {
+            resolution: [128, 128],
+            padding: 0,
+            tiles: vec![
+                make_tile("a", 64, 32, [1.0, 0.0, 0.0, 1.0]),
+                make_tile("b", 32, 32, [0.0, 1.0, 0.0, 1.0]),
+                make_tile("c", 48, 16, [0.0, 0.0, 1.0, 1.0]),
+                make_tile("d", 16, 64, [1.0, 1.0, 0.0, 1.0]),
+            ],
+        };
+
+        let result = generate_trimsheet(&params, 42).unwrap();
+        let layout = &result.layout;
+        assert_eq!(layout.regions.len(), params.tiles.len());
+
+        // Rects must not overlap
+        for (i, a) in layout.regions.iter().enumerate() {
+            for b in &layout.regions[i + 1..] {
+                let disjoint = a.x + a.width <= b.x
+                    || b.x + b.width <= a.x
+                    || a.y + a.height <= b.y
+                    || b.y + b.height <= a.y;
+                assert!(disjoint, "regions '{}' and '{}' overlap", a.id, b.id);
+            }
+        }
+
+        // Total region area must equal the opaque pixels in the atlas
+        let decoder = png::Decoder::new(result.png_data.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).unwrap();
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        let occupied = buf[..info.buffer_size()]
+            .chunks_exact(4)
+            .filter(|px| px[3] != 0)
+            .count() as u32;
+        let area: u32 = layout.regions.iter().map(|r| r.width * r.height).sum();
+        assert_eq!(area, occupied);
+
+        // Every region pixel is opaque, and UVs agree with pixel rects
+        let stride = layout.atlas_width as usize * 4;
+        for r in &layout.regions {
+            for y in r.y..r.y + r.height {
+                for x in r.x..r.x + r.width {
+                    assert_ne!(buf[y as usize * stride + x as usize * 4 + 3], 0);
+                }
+            }
+            assert_eq!(r.u_min, r.x as f64 / 128.0);
+            assert_eq!(r.v_max, (r.y + r.height) as f64 / 128.0);
+        }
+    }
+
+    #[test]
+    fn test_save_trimsheet_layout_writes_sidecar() {
+        let params = TextureTrimsheetV1Params {
+            resolution: [256, 256],
+            padding: 2,
+            tiles: vec![
+                make_tile("grass", 64, 64, [0.2, 0.6, 0.2, 1.0]),
+                make_tile("stone", 32, 32, [0.5, 0.5, 0.5, 1.0]),
+            ],
+        };
+
+        let result = generate_trimsheet(&params, 42).unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let png_path = tmp.path().join("atlas").join("trims.png");
+
+        let path = save_trimsheet_layout(&result.layout, &png_path).unwrap();
+        assert_eq!(path, tmp.path().join("atlas").join("trims.layout.json"));
+
+        let parsed: TrimsheetLayout =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(parsed, result.layout);
+
+        // Layout is deterministic
+        let again = generate_trimsheet(&params, 42).unwrap();
+        assert_eq!(
+            serde_json::to_string(&again.layout).unwrap(),
+            serde_json::to_string(&result.layout).unwrap()
+        );
+    }
 }
@@ -203,6 +203,23 @@ pub fn extract_wav_samples(wav_data: &[u8]) -> Result<(Vec<f32>, u32), AudioAnal
     Ok((mono_samples, header.sample_rate))
 }
 
+/// Downsample a WAV file to a peak envelope of at most `points` values.
+///
+/// Channels are averaged to mono, the samples are split into `points` equal
+/// blocks, and each value is the largest absolute sample in its block.
+pub fn waveform_envelope(wav_data: &[u8], points: usize) -> Result<Vec<f64>, AudioAnalysisError> {
+    let (samples, _) = extract_wav_samples(wav_data)?;
+    let points = points.clamp(1, samples.len());
+
+    Ok((0..points)
+        .map(|i| {
+            let start = i * samples.len() / points;
+            let end = (i + 1) * samples.len() / points;
+            round_f64(calculate_peak(&samples[start..end]) as f64, FLOAT_PRECISION)
+        })
+        .collect())
+}
+
 /// Analyze a WAV file and return metrics.
 pub fn analyze_wav(wav_data: &[u8]) -> Result<AudioMetrics, AudioAnalysisError> {
     let header = parse_wav_header(wav_data)?;
@@ -94,6 +94,25 @@ fn test_deterministic_output() {
     assert_eq!(json1, json2);
 }
 
+#[test]
+fn test_waveform_envelope_tracks_block_peaks() {
+    // Loud first half, quiet second half
+    let mut samples = vec![0.8f32; 500];
+    samples.extend(vec![-0.2f32; 500]);
+    let wav = create_test_wav(&samples, 44100, 1);
+
+    let envelope = waveform_envelope(&wav, 4).unwrap();
+    assert_eq!(envelope.len(), 4);
+    assert!((envelope[0] - 0.8).abs() < 1e-3);
+    assert!((envelope[1] - 0.8).abs() < 1e-3);
+    assert!((envelope[2] - 0.2).abs() < 1e-3);
+    assert!((envelope[3] - 0.2).abs() < 1e-3);
+
+    // Never more points than samples
+    let short = create_test_wav(&[0.5, -0.5], 44100, 1);
+    assert_eq!(waveform_envelope(&short, 16).unwrap().len(), 2);
+}
+
 #[test]
 fn test_metrics_to_btree_sorted_keys() {
     let samples: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.01).sin()).collect();
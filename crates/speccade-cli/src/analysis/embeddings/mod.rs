@@ -4,10 +4,14 @@
 //! and other downstream ML applications. Embeddings are hand-crafted features without
 //! external ML model dependencies.
 //!
-//! ## Audio Embedding Format (48 dimensions)
-//! - Spectral bins (16 bands): normalized energy per octave band
-//! - RMS envelope (16 frames): normalized amplitude over time
-//! - Spectral features (16): centroid, spread, rolloff, flatness, crest, etc.
+//! The vector layout is fixed: every index always holds the same feature, so
+//! vectors from different runs and assets can be stored in one index and compared
+//! directly. Any change to the layout bumps [`EMBEDDING_VERSION`].
+//!
+//! ## Audio Embedding Format (48 dimensions, version 1)
+//! - `[0..16)` Spectral bins: normalized energy per octave band, low to high
+//! - `[16..32)` RMS envelope: normalized amplitude over 16 equal time frames
+//! - `[32..48)` Spectral features, in the order of [`AUDIO_SPECTRAL_FEATURES`]
 //!
 //! ## Texture Embedding Format (48 dimensions)
 //! - Color histogram (16): combined luminance histogram bins
@@ -21,6 +25,104 @@ mod texture;
 pub use audio::EMBEDDING_DIM as AUDIO_EMBEDDING_DIM;
 pub use texture::EMBEDDING_DIM as TEXTURE_EMBEDDING_DIM;
 
+/// Version of the embedding layout. Bumped whenever an index changes meaning.
+pub const EMBEDDING_VERSION: u32 = 1;
+
+/// Names of the audio spectral features stored at indices 32..48.
+pub const AUDIO_SPECTRAL_FEATURES: [&str; 16] = [
+    "spectral_centroid",
+    "spectral_spread",
+    "spectral_rolloff",
+    "spectral_flatness",
+    "spectral_crest",
+    "zero_crossing_rate",
+    "peak",
+    "rms",
+    "crest_factor",
+    "dc_offset",
+    "low_energy_ratio",
+    "mid_energy_ratio",
+    "high_energy_ratio",
+    "spectral_entropy",
+    "dominant_frequency",
+    "bandwidth",
+];
+
+/// Returns the feature name stored at `index` of an audio embedding.
+pub fn audio_feature_name(index: usize) -> Option<String> {
+    match index {
+        0..=15 => Some(format!("band_{:02}", index)),
+        16..=31 => Some(format!("envelope_{:02}", index - 16)),
+        32..=47 => Some(AUDIO_SPECTRAL_FEATURES[index - 32].to_string()),
+        _ => None,
+    }
+}
+
+/// Normalization applied to an embedding before output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EmbeddingNorm {
+    /// Raw feature values in [0, 1]
+    #[default]
+    None,
+    /// Scale the vector to unit Euclidean length
+    L2,
+}
+
+impl EmbeddingNorm {
+    /// Parse normalization mode from string.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "none" => Some(Self::None),
+            "l2" => Some(Self::L2),
+            _ => None,
+        }
+    }
+}
+
+/// Output options for embeddings (target dimension and normalization).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmbeddingOptions {
+    /// Zero-pad vectors to this length (must be >= the native dimension)
+    pub dim: Option<usize>,
+    /// Normalization applied after padding
+    pub norm: EmbeddingNorm,
+}
+
+impl EmbeddingOptions {
+    /// Checks that the requested dimension can hold every embedding kind.
+    pub fn validate(&self) -> Result<(), String> {
+        let native = AUDIO_EMBEDDING_DIM.max(TEXTURE_EMBEDDING_DIM);
+        match self.dim {
+            Some(dim) if dim < native => Err(format!(
+                "--embeddings-dim must be at least {} (got {})",
+                native, dim
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Pads and normalizes a native embedding.
+    ///
+    /// Padding is appended after the native features, so existing indices keep
+    /// their meaning. An all-zero vector stays all-zero under L2 normalization.
+    pub fn apply(&self, mut embedding: Vec<f64>) -> Vec<f64> {
+        if let Some(dim) = self.dim {
+            if dim > embedding.len() {
+                embedding.resize(dim, 0.0);
+            }
+        }
+        if self.norm == EmbeddingNorm::L2 {
+            let norm = embedding.iter().map(|v| v * v).sum::<f64>().sqrt();
+            if norm > 0.0 {
+                for v in &mut embedding {
+                    *v = ((*v / norm) * 1e6).round() / 1e6;
+                }
+            }
+        }
+        embedding
+    }
+}
+
 /// Compute audio embedding from mono samples.
 ///
 /// Returns a 48-dimension feature vector capturing:
@@ -40,3 +142,86 @@ pub fn compute_audio_embedding(samples: &[f32], sample_rate: u32) -> Vec<f64> {
 pub fn compute_texture_embedding(pixels: &[u8], width: u32, height: u32, channels: u8) -> Vec<f64> {
     texture::compute(pixels, width, height, channels)
 }
+
+/// Cosine similarity of two embeddings of the same asset type.
+///
+/// Returns a value in -1.0..=1.0, or 0.0 if either embedding is all zeros.
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        (dot / (norm_a * norm_b)).clamp(-1.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine(len: usize) -> Vec<f32> {
+        (0..len).map(|i| (i as f32 * 0.1).sin() * 0.5).collect()
+    }
+
+    #[test]
+    fn test_audio_embedding_fixed_length_and_deterministic() {
+        let options = EmbeddingOptions {
+            dim: Some(64),
+            norm: EmbeddingNorm::L2,
+        };
+        for len in [0, 100, 4410, 44100] {
+            let a = options.apply(compute_audio_embedding(&sine(len), 44100));
+            let b = options.apply(compute_audio_embedding(&sine(len), 44100));
+            assert_eq!(a.len(), 64);
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn test_l2_normalization_unit_length() {
+        let options = EmbeddingOptions {
+            dim: None,
+            norm: EmbeddingNorm::L2,
+        };
+        let embedding = options.apply(compute_audio_embedding(&sine(4410), 44100));
+        assert_eq!(embedding.len(), AUDIO_EMBEDDING_DIM);
+        let norm = embedding.iter().map(|v| v * v).sum::<f64>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-4, "norm = {}", norm);
+
+        let silent = options.apply(vec![0.0; AUDIO_EMBEDDING_DIM]);
+        assert!(silent.iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_padding_preserves_feature_indices() {
+        let native = compute_audio_embedding(&sine(4410), 44100);
+        let padded = EmbeddingOptions {
+            dim: Some(128),
+            norm: EmbeddingNorm::None,
+        }
+        .apply(native.clone());
+        assert_eq!(&padded[..AUDIO_EMBEDDING_DIM], &native[..]);
+        assert!(padded[AUDIO_EMBEDDING_DIM..].iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_dim_smaller_than_native_rejected() {
+        let options = EmbeddingOptions {
+            dim: Some(16),
+            norm: EmbeddingNorm::None,
+        };
+        assert!(options.validate().is_err());
+        assert!(EmbeddingOptions::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_audio_feature_names() {
+        assert_eq!(audio_feature_name(0).as_deref(), Some("band_00"));
+        assert_eq!(audio_feature_name(31).as_deref(), Some("envelope_15"));
+        assert_eq!(audio_feature_name(32).as_deref(), Some("spectral_centroid"));
+        assert_eq!(audio_feature_name(47).as_deref(), Some("bandwidth"));
+        assert_eq!(audio_feature_name(AUDIO_EMBEDDING_DIM), None);
+    }
+}
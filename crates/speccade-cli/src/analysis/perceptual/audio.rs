@@ -1,6 +1,6 @@
 //! Audio perceptual comparison metrics.
 //!
-//! Provides spectral correlation for audio comparison.
+//! Provides spectral correlation and residual level for audio comparison.
 
 use super::{round_f64, FLOAT_PRECISION};
 use rustfft::{num_complex::Complex, FftPlanner};
@@ -58,6 +58,36 @@ pub fn calculate_spectral_correlation(
     }
 }
 
+/// Floor for the residual level, returned when the signals are identical.
+pub const RESIDUAL_FLOOR_DB: f64 = -120.0;
+
+/// Calculate the level of the difference signal `a - b` relative to `a`, in dB.
+///
+/// Samples past the end of the shorter signal count as zero. Returns
+/// [`RESIDUAL_FLOOR_DB`] for identical signals, and 0 dB when `a` is silent
+/// but `b` is not.
+pub fn calculate_residual_db(samples_a: &[f32], samples_b: &[f32]) -> f64 {
+    let len = samples_a.len().max(samples_b.len());
+    let at = |s: &[f32], i: usize| s.get(i).copied().unwrap_or(0.0) as f64;
+
+    let mut residual = 0.0;
+    let mut reference = 0.0;
+    for i in 0..len {
+        let (a, b) = (at(samples_a, i), at(samples_b, i));
+        residual += (a - b) * (a - b);
+        reference += a * a;
+    }
+
+    if residual == 0.0 {
+        return RESIDUAL_FLOOR_DB;
+    }
+    if reference == 0.0 {
+        return 0.0;
+    }
+    let db = 10.0 * (residual / reference).log10();
+    round_f64(db.max(RESIDUAL_FLOOR_DB), FLOAT_PRECISION)
+}
+
 /// Compute spectral centroid sequence over time windows.
 fn compute_centroid_sequence(
     samples: &[f32],
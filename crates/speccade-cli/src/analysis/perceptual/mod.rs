@@ -21,7 +21,7 @@ pub use color::HistogramDiff;
 pub use ssim::calculate_ssim;
 
 // Re-export for internal use
-pub use audio::calculate_spectral_correlation;
+pub use audio::{calculate_residual_db, calculate_spectral_correlation, RESIDUAL_FLOOR_DB};
 pub use color::{calculate_delta_e, calculate_histogram_diff};
 
 /// Precision for floating point values in output.
@@ -57,6 +57,8 @@ pub struct AudioCompareResult {
     pub peak_delta_db: f64,
     /// Loudness (RMS) difference as percentage
     pub loudness_delta_percent: f64,
+    /// Level of the difference signal relative to A, in dB
+    pub residual_db: f64,
 }
 
 /// Compare two images and return perceptual metrics.
@@ -123,5 +125,6 @@ pub fn compare_audio(
         rms_delta_db,
         peak_delta_db,
         loudness_delta_percent,
+        residual_db: calculate_residual_db(samples_a, samples_b),
     }
 }
@@ -1,6 +1,6 @@
 //! Unit tests for perceptual comparison metrics.
 
-use super::audio::calculate_spectral_correlation;
+use super::audio::{calculate_residual_db, calculate_spectral_correlation, RESIDUAL_FLOOR_DB};
 use super::color::{calculate_delta_e, rgb_to_lab};
 use super::ssim::calculate_ssim;
 
@@ -132,3 +132,18 @@ fn test_spectral_correlation_short_signal() {
     let corr = calculate_spectral_correlation(&samples, &samples, 44100);
     assert!(corr.is_finite(), "Should handle short signals gracefully");
 }
+
+#[test]
+fn test_residual_db_identical_is_floor() {
+    let samples: Vec<f32> = (0..4410).map(|i| (i as f32 * 0.1).sin()).collect();
+    assert_eq!(calculate_residual_db(&samples, &samples), RESIDUAL_FLOOR_DB);
+}
+
+#[test]
+fn test_residual_db_scaled_signal() {
+    let samples_a: Vec<f32> = (0..4410).map(|i| (i as f32 * 0.1).sin()).collect();
+    let samples_b: Vec<f32> = samples_a.iter().map(|s| s * 0.9).collect();
+    // Residual is 10% of the reference amplitude: -20 dB
+    let db = calculate_residual_db(&samples_a, &samples_b);
+    assert!((db + 20.0).abs() < 0.01, "expected -20 dB, got {}", db);
+}
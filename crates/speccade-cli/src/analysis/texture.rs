@@ -5,6 +5,7 @@
 //! across runs on the same input.
 
 use serde::{Deserialize, Serialize};
+use speccade_backend_texture::{verify_tileable, Color, TextureBuffer};
 use std::collections::BTreeMap;
 
 /// Precision for floating point values in output (6 decimal places).
@@ -25,6 +26,8 @@ pub struct TextureMetrics {
     pub histogram: TextureHistogramStats,
     /// Contrast metrics
     pub contrast: TextureContrastMetrics,
+    /// Tiling seam metrics
+    pub tiling: TextureTilingMetrics,
 }
 
 /// Texture format metadata.
@@ -80,6 +83,31 @@ pub struct TextureContrastMetrics {
     pub weber_contrast: f64,
 }
 
+/// Tiling seam metrics.
+///
+/// Computed by [`speccade_backend_texture::verify_tileable`], the same check the
+/// `texture/tile-seam` lint rule uses. Seam deltas are the largest per-channel
+/// (RGBA) differences between opposite edge pixels, in the range 0-1. Seam
+/// scores relate the mean seam delta to the texture's interior variation;
+/// around 1 or below is seamless.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextureTilingMetrics {
+    /// Largest left/right edge delta
+    pub horizontal_max_seam_delta: f64,
+    /// Mean left/right edge delta
+    pub horizontal_mean_seam_delta: f64,
+    /// Left/right seam score
+    pub horizontal_seam_score: f64,
+    /// Largest top/bottom edge delta
+    pub vertical_max_seam_delta: f64,
+    /// Mean top/bottom edge delta
+    pub vertical_mean_seam_delta: f64,
+    /// Top/bottom seam score
+    pub vertical_seam_score: f64,
+    /// Whether no seam scores above [`speccade_backend_texture::SEAM_SCORE_THRESHOLD`]
+    pub seamless: bool,
+}
+
 /// Error type for texture analysis.
 #[derive(Debug, Clone)]
 pub enum TextureAnalysisError {
@@ -314,6 +342,38 @@ fn calculate_contrast(pixels: &[u8], channels: u8) -> TextureContrastMetrics {
     }
 }
 
+/// Calculate tiling seam metrics by comparing opposite edges.
+fn calculate_tiling(pixels: &[u8], width: u32, height: u32, channels: u8) -> TextureTilingMetrics {
+    let step = channels as usize;
+    let mut buffer = TextureBuffer::new_black(width, height);
+    for (i, pixel) in pixels.chunks_exact(step).enumerate() {
+        let rgba = match channels {
+            1 => [pixel[0], pixel[0], pixel[0], 255],
+            2 => [pixel[0], pixel[0], pixel[0], pixel[1]],
+            3 => [pixel[0], pixel[1], pixel[2], 255],
+            _ => [pixel[0], pixel[1], pixel[2], pixel[3]],
+        };
+        let (x, y) = (i as u32 % width, i as u32 / width);
+        if y < height {
+            buffer.set(x, y, Color::from_rgba8(rgba));
+        }
+    }
+
+    let report = verify_tileable(&buffer);
+    TextureTilingMetrics {
+        horizontal_max_seam_delta: round_f64(report.left_right.max_discontinuity, FLOAT_PRECISION),
+        horizontal_mean_seam_delta: round_f64(
+            report.left_right.mean_discontinuity,
+            FLOAT_PRECISION,
+        ),
+        horizontal_seam_score: round_f64(report.left_right.seam_score, FLOAT_PRECISION),
+        vertical_max_seam_delta: round_f64(report.top_bottom.max_discontinuity, FLOAT_PRECISION),
+        vertical_mean_seam_delta: round_f64(report.top_bottom.mean_discontinuity, FLOAT_PRECISION),
+        vertical_seam_score: round_f64(report.top_bottom.seam_score, FLOAT_PRECISION),
+        seamless: report.is_seamless(),
+    }
+}
+
 /// Extract pixel data from PNG for embedding computation.
 ///
 /// Returns raw pixels, dimensions (width, height), and channel count.
@@ -385,6 +445,9 @@ pub fn analyze_png(png_data: &[u8]) -> Result<TextureMetrics, TextureAnalysisErr
     // Calculate contrast
     let contrast = calculate_contrast(&pixels, channels);
 
+    // Calculate tiling seams
+    let tiling = calculate_tiling(&pixels, header.width, header.height, channels);
+
     Ok(TextureMetrics {
         format: TextureFormatMetadata {
             width: header.width,
@@ -400,6 +463,7 @@ pub fn analyze_png(png_data: &[u8]) -> Result<TextureMetrics, TextureAnalysisErr
             alpha: alpha_stats,
         },
         contrast,
+        tiling,
     })
 }
 
@@ -495,6 +559,38 @@ pub fn metrics_to_btree(metrics: &TextureMetrics) -> BTreeMap<String, serde_json
 
     map.insert("histogram".to_string(), serde_json::json!(histogram));
 
+    // Tiling section
+    let mut tiling = BTreeMap::new();
+    tiling.insert(
+        "horizontal_max_seam_delta".to_string(),
+        serde_json::json!(metrics.tiling.horizontal_max_seam_delta),
+    );
+    tiling.insert(
+        "horizontal_mean_seam_delta".to_string(),
+        serde_json::json!(metrics.tiling.horizontal_mean_seam_delta),
+    );
+    tiling.insert(
+        "horizontal_seam_score".to_string(),
+        serde_json::json!(metrics.tiling.horizontal_seam_score),
+    );
+    tiling.insert(
+        "seamless".to_string(),
+        serde_json::json!(metrics.tiling.seamless),
+    );
+    tiling.insert(
+        "vertical_max_seam_delta".to_string(),
+        serde_json::json!(metrics.tiling.vertical_max_seam_delta),
+    );
+    tiling.insert(
+        "vertical_mean_seam_delta".to_string(),
+        serde_json::json!(metrics.tiling.vertical_mean_seam_delta),
+    );
+    tiling.insert(
+        "vertical_seam_score".to_string(),
+        serde_json::json!(metrics.tiling.vertical_seam_score),
+    );
+    map.insert("tiling".to_string(), serde_json::json!(tiling));
+
     map
 }
 
@@ -599,7 +695,94 @@ mod tests {
         let keys: Vec<_> = btree.keys().collect();
 
         // Keys should be alphabetically sorted
-        assert_eq!(keys, vec!["contrast", "format", "histogram"]);
+        assert_eq!(keys, vec!["contrast", "format", "histogram", "tiling"]);
+    }
+
+    /// Renders a 32x32 Perlin noise texture through the graph backend and encodes it as PNG.
+    fn generate_noise_png(tileable: bool) -> Vec<u8> {
+        use speccade_spec::recipe::texture::{
+            NoiseAlgorithm, NoiseConfig, TextureProceduralNode, TextureProceduralOp,
+            TextureProceduralV1Params,
+        };
+
+        let params = TextureProceduralV1Params {
+            resolution: [32, 32],
+            tileable,
+            nodes: vec![TextureProceduralNode {
+                id: "n".to_string(),
+                op: TextureProceduralOp::Noise {
+                    noise: NoiseConfig {
+                        algorithm: NoiseAlgorithm::Perlin,
+                        scale: 0.12,
+                        octaves: 3,
+                        persistence: 0.5,
+                        lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
+                    },
+                },
+            }],
+        };
+        let nodes = speccade_backend_texture::generate_graph(&params, 42).unwrap();
+        let (png, _) = speccade_backend_texture::encode_graph_value_png(&nodes["n"]).unwrap();
+        png
+    }
+
+    #[test]
+    fn test_tileable_texture_scores_lower_seams() {
+        let tileable = analyze_png(&generate_noise_png(true)).unwrap().tiling;
+        let non_tileable = analyze_png(&generate_noise_png(false)).unwrap().tiling;
+
+        assert!(tileable.horizontal_max_seam_delta < non_tileable.horizontal_max_seam_delta);
+        assert!(tileable.horizontal_mean_seam_delta < non_tileable.horizontal_mean_seam_delta);
+        assert!(tileable.vertical_max_seam_delta < non_tileable.vertical_max_seam_delta);
+        assert!(tileable.vertical_mean_seam_delta < non_tileable.vertical_mean_seam_delta);
+        assert!(tileable.horizontal_seam_score < non_tileable.horizontal_seam_score);
+        assert!(tileable.vertical_seam_score < non_tileable.vertical_seam_score);
+    }
+
+    /// Runs the `texture/tile-seam` lint rule on a PNG that has no spec.
+    fn lint_tile_seam(png: &[u8]) -> Vec<speccade_lint::LintIssue> {
+        use speccade_lint::rules::texture::TileSeamRule;
+        use speccade_lint::{AssetData, LintRule};
+
+        let asset = AssetData {
+            path: std::path::Path::new("texture.png"),
+            bytes: png,
+        };
+        TileSeamRule.check(&asset, None)
+    }
+
+    #[test]
+    fn test_tiling_agrees_with_tile_seam_lint() {
+        let seamless = generate_noise_png(true);
+        let gradient: Vec<u8> = (0..32)
+            .flat_map(|_| (0..32).map(|x| (x * 255 / 31) as u8))
+            .collect();
+        let seamed = create_test_png(32, 32, 0, &gradient);
+
+        let seamless_tiling = analyze_png(&seamless).unwrap().tiling;
+        assert!(seamless_tiling.seamless);
+        assert!(lint_tile_seam(&seamless).is_empty());
+
+        let seamed_tiling = analyze_png(&seamed).unwrap().tiling;
+        assert!(!seamed_tiling.seamless);
+        let issues = lint_tile_seam(&seamed);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].asset_location.as_deref(), Some("edge:left-right"));
+        assert!(issues[0]
+            .actual_value
+            .as_deref()
+            .unwrap()
+            .starts_with(&format!(
+                "seam_score={:.2}",
+                seamed_tiling.horizontal_seam_score
+            )));
     }
 
     #[test]
@@ -13,6 +13,9 @@ use speccade_spec::{canonical_spec_hash, OutputResult, Spec};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Subdirectory of the cache directory holding texture graph node entries.
+pub const NODE_CACHE_DIR_NAME: &str = "nodes";
+
 /// Cache key components for deterministic cache lookups.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheKey {
@@ -92,6 +95,11 @@ impl CacheManager {
         dirs::cache_dir().map(|d| d.join("speccade").join("generate"))
     }
 
+    /// Get the directory holding cached texture graph nodes (`generate --node-cache`)
+    pub fn node_cache_dir(&self) -> PathBuf {
+        self.cache_dir.join(NODE_CACHE_DIR_NAME)
+    }
+
     /// Get the path to a cache entry directory
     pub fn entry_path(&self, key: &CacheKey) -> PathBuf {
         let hash = key.compute_hash();
@@ -137,14 +145,20 @@ impl CacheManager {
                 .with_context(|| format!("Failed to copy cached file: {}", cache_file.display()))?;
 
             // Reconstruct OutputResult with relative path (same as what dispatch produces)
-            outputs.push(OutputResult {
+            let mut output = OutputResult {
                 kind: cached.kind,
                 format: cached.format,
                 path: PathBuf::from(&cached.cache_path),
                 hash: cached.hash.clone(),
                 metrics: cached.metrics.clone(),
                 preview: cached.preview,
-            });
+                file_hash: None,
+                size_bytes: None,
+            };
+            output.record_file_checksum(out_root).with_context(|| {
+                format!("Failed to hash restored file: {}", output_path.display())
+            })?;
+            outputs.push(output);
         }
 
         Ok(Some(outputs))
@@ -227,6 +241,12 @@ impl CacheManager {
             }
         }
 
+        let node_dir = self.node_cache_dir();
+        if node_dir.exists() {
+            fs::remove_dir_all(&node_dir)
+                .with_context(|| format!("Failed to remove node cache: {}", node_dir.display()))?;
+        }
+
         Ok(count)
     }
 
@@ -394,6 +414,8 @@ mod tests {
             hash: Some("testhash".to_string()),
             metrics: None,
             preview: None,
+            file_hash: None,
+            size_bytes: None,
         }];
 
         // Store in cache
@@ -442,6 +464,8 @@ mod tests {
             hash: None,
             metrics: None,
             preview: None,
+            file_hash: None,
+            size_bytes: None,
         }];
 
         // Store two entries
@@ -484,6 +508,8 @@ mod tests {
             hash: None,
             metrics: None,
             preview: None,
+            file_hash: None,
+            size_bytes: None,
         }];
         cache_mgr.put(&key, &outputs, tmp_out.path()).unwrap();
 
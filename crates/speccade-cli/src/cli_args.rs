@@ -47,22 +47,98 @@ pub(crate) enum Commands {
         #[arg(long)]
         embeddings: bool,
 
+        /// Zero-pad embeddings to this dimension (must be >= the native 48)
+        #[arg(long, requires = "embeddings")]
+        embeddings_dim: Option<usize>,
+
+        /// Embedding normalization (none, l2)
+        #[arg(long, default_value = "none", value_parser = ["none", "l2"], requires = "embeddings")]
+        embeddings_norm: String,
+
         /// Start WebSocket analysis server on the specified port (default: 9123)
         #[cfg(feature = "serve")]
         #[arg(long)]
         serve: Option<Option<u16>>,
     },
 
-    /// Compare two asset files and output perceptual difference metrics
+    /// Compare two asset files (or two output trees) and output perceptual difference metrics
     Compare {
         /// Path to the first file (reference)
+        #[arg(
+            short,
+            long,
+            required_unless_present = "dir_a",
+            conflicts_with = "dir_a"
+        )]
+        a: Option<String>,
+
+        /// Path to the second file (comparison target)
+        #[arg(
+            short,
+            long,
+            required_unless_present = "dir_a",
+            conflicts_with = "dir_a"
+        )]
+        b: Option<String>,
+
+        /// Reference directory; files are paired with --dir-b by relative path
+        #[arg(long, requires = "dir_b", conflicts_with_all = ["a", "b"])]
+        dir_a: Option<String>,
+
+        /// Comparison target directory
+        #[arg(long, requires = "dir_a", conflicts_with_all = ["a", "b"])]
+        dir_b: Option<String>,
+
+        /// Fail if the texture SSIM drops by more than this (1 - SSIM)
+        #[arg(long)]
+        max_ssim_delta: Option<f64>,
+
+        /// Fail if the texture mean DeltaE exceeds this
+        #[arg(long)]
+        max_delta_e: Option<f64>,
+
+        /// Fail if the audio residual (A - B relative to A) exceeds this level in dB
+        #[arg(long, allow_hyphen_values = true)]
+        max_residual_db: Option<f64>,
+
+        /// Fail if the audio RMS levels differ by more than this many dB
+        #[arg(long)]
+        max_rms_delta_db: Option<f64>,
+
+        /// Output machine-readable JSON diagnostics (no colored output)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Compare two spec files after canonicalization and list the fields that differ
+    Diff {
+        /// Path to the first spec (before)
         #[arg(short, long)]
         a: String,
 
-        /// Path to the second file (comparison target)
+        /// Path to the second spec (after)
         #[arg(short, long)]
         b: String,
 
+        /// Output a machine-readable JSON patch list (no colored output)
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Rank the assets in a directory by embedding similarity to a query file
+    Similar {
+        /// Path to the query file (WAV or PNG)
+        #[arg(short, long)]
+        query: String,
+
+        /// Directory to recursively scan for assets of the query's type
+        #[arg(long)]
+        input_dir: String,
+
+        /// Number of most similar assets to report
+        #[arg(long, default_value_t = 10)]
+        top_k: usize,
+
         /// Output machine-readable JSON diagnostics (no colored output)
         #[arg(long)]
         json: bool,
@@ -119,6 +195,10 @@ pub(crate) enum Commands {
         /// Output machine-readable JSON diagnostics (no colored output)
         #[arg(long)]
         json: bool,
+
+        /// Apply safe automatic fixes and write the spec back (JSON specs only)
+        #[arg(long)]
+        fix: bool,
     },
 
     /// Generate assets from a spec file
@@ -143,7 +223,7 @@ pub(crate) enum Commands {
         #[arg(long)]
         json: bool,
 
-        /// Generate preview of specified duration in seconds (for fast iteration)
+        /// Generate a preview of the first N seconds (audio previews match the head of the full render)
         #[arg(long)]
         preview: Option<f64>,
 
@@ -155,10 +235,14 @@ pub(crate) enum Commands {
         #[arg(long)]
         profile: bool,
 
-        /// Generate N SFX variations by incrementing seed (outputs variations.json manifest)
+        /// Generate N SFX variations with derived seeds (outputs variations.json manifest)
         #[arg(long)]
         variations: Option<u32>,
 
+        /// How variation seeds are derived: increment (seed + i) or hashed (BLAKE3 of seed and index)
+        #[arg(long, default_value = "increment", value_parser = ["increment", "hashed"])]
+        variation_seeds: String,
+
         /// Maximum peak level in dB; reject variations exceeding this (e.g., 0.0 for no clipping)
         #[arg(long, allow_hyphen_values = true)]
         max_peak_db: Option<f64>,
@@ -170,6 +254,26 @@ pub(crate) enum Commands {
         /// Force saving .blend files alongside GLB output (Blender mesh pipelines only)
         #[arg(long)]
         save_blend: bool,
+
+        /// Also write every generated output into a deterministic zip archive at this path
+        #[arg(long, value_name = "ZIP")]
+        bundle: Option<String>,
+
+        /// Cache evaluated texture graph nodes on disk and reuse unchanged ones across runs
+        #[arg(long)]
+        node_cache: bool,
+
+        /// Lint every generated output and fail (exit code 3) if any lint errors are found
+        #[arg(long)]
+        lint: bool,
+
+        /// Like --lint, but lint warnings also fail the command
+        #[arg(long)]
+        lint_strict: bool,
+
+        /// Override the spec seed for this run (reflected in the report and output hashes)
+        #[arg(long)]
+        seed: Option<u32>,
     },
 
     /// Generate all assets from a directory of spec files
@@ -193,6 +297,10 @@ pub(crate) enum Commands {
         /// Force regeneration of all specs (skip freshness check)
         #[arg(short = 'f', long)]
         force: bool,
+
+        /// Number of specs to generate in parallel (Blender specs always run one at a time)
+        #[arg(short = 'j', long, default_value_t = 1)]
+        jobs: usize,
     },
 
     /// Run a profile-based asset pipeline across the spec corpus
@@ -273,7 +381,8 @@ pub(crate) enum Commands {
         scale: Option<u32>,
     },
 
-    /// Generate a 6-view validation grid PNG for 3D assets (FRONT, BACK, TOP, LEFT, RIGHT, ISO)
+    /// Generate a 6-view validation grid PNG for 3D assets (FRONT, BACK, TOP, LEFT, RIGHT, ISO),
+    /// or a labeled grid of every generated map for textures
     PreviewGrid {
         /// Path to the spec file (.star or .json)
         #[arg(short, long)]
@@ -283,7 +392,7 @@ pub(crate) enum Commands {
         #[arg(short, long)]
         out: Option<String>,
 
-        /// Panel size in pixels for each view (default: 256, grid is 3x2 panels)
+        /// Panel size in pixels for each view or map (default: 256, 3D grid is 3x2 panels)
         #[arg(long, default_value = "256")]
         panel_size: u32,
     },
@@ -314,7 +423,7 @@ pub(crate) enum Commands {
         json: bool,
     },
 
-    /// Inspect intermediate build artifacts (texture nodes, expanded params)
+    /// Inspect intermediate build artifacts (texture nodes, audio layers, expanded params)
     Inspect {
         /// Path to the spec file (JSON or Starlark)
         #[arg(short, long)]
@@ -352,6 +461,17 @@ pub(crate) enum Commands {
         command: StdlibCommands,
     },
 
+    /// Emit the JSON Schema for a recipe kind's params
+    Schema {
+        /// Recipe kind (e.g. audio_v1, texture.material_v1)
+        #[arg(short, long)]
+        kind: String,
+
+        /// Output file path (default: stdout)
+        #[arg(short, long)]
+        out: Option<String>,
+    },
+
     /// Manage generation cache
     Cache {
         #[command(subcommand)]
@@ -380,6 +500,11 @@ pub(crate) enum Commands {
         #[arg(long)]
         constraints: String,
 
+        /// Path to the generated asset, for constraints that compare its
+        /// contents against a reference (e.g. spectral_similarity)
+        #[arg(long)]
+        asset: Option<String>,
+
         /// Output machine-readable JSON diagnostics (no colored output)
         #[arg(long)]
         json: bool,
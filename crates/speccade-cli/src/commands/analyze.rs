@@ -10,6 +10,7 @@ use std::path::Path;
 use std::process::ExitCode;
 use walkdir::WalkDir;
 
+use crate::analysis::embeddings::EmbeddingOptions;
 use crate::analysis::{audio, detect_asset_type, embeddings, mesh, texture, AssetAnalysisType};
 
 use super::analyze_csv::format_csv;
@@ -40,28 +41,43 @@ impl OutputFormat {
     }
 }
 
+/// Options for the analyze command.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzeOptions<'a> {
+    /// Path to the input file to analyze (WAV, PNG, or glTF)
+    pub input_path: Option<&'a str>,
+    /// Optional path to spec file (generate then analyze)
+    pub spec_path: Option<&'a str>,
+    /// Optional directory to recursively scan for assets (batch mode)
+    pub input_dir: Option<&'a str>,
+    /// Optional output file path (default: stdout)
+    pub output_path: Option<&'a str>,
+    /// Whether to output machine-readable JSON
+    pub json_output: bool,
+    /// Output format for batch mode (json, jsonl, csv)
+    pub output_format: &'a str,
+    /// Whether to include feature embeddings
+    pub include_embeddings: bool,
+    /// Target dimension and normalization for embeddings
+    pub embedding_options: EmbeddingOptions,
+}
+
 /// Run the analyze command
 ///
-/// # Arguments
-/// * `input_path` - Path to the input file to analyze (WAV or PNG)
-/// * `spec_path` - Optional path to spec file (generate then analyze)
-/// * `input_dir` - Optional directory to recursively scan for assets (batch mode)
-/// * `output_path` - Optional output file path (default: stdout)
-/// * `json_output` - Whether to output machine-readable JSON
-/// * `output_format` - Output format for batch mode (json, jsonl, csv)
-/// * `include_embeddings` - Whether to include feature embeddings
-///
 /// # Returns
 /// Exit code: 0 on success, 1 on error
-pub fn run(
-    input_path: Option<&str>,
-    spec_path: Option<&str>,
-    input_dir: Option<&str>,
-    output_path: Option<&str>,
-    json_output: bool,
-    output_format: &str,
-    include_embeddings: bool,
-) -> Result<ExitCode> {
+pub fn run(options: &AnalyzeOptions) -> Result<ExitCode> {
+    let AnalyzeOptions {
+        input_path,
+        spec_path,
+        input_dir,
+        output_path,
+        json_output,
+        output_format,
+        include_embeddings,
+        ref embedding_options,
+    } = *options;
+
     // --spec mode is not supported
     if spec_path.is_some() {
         if json_output {
@@ -80,11 +96,21 @@ pub fn run(
         }
     }
 
+    embedding_options
+        .validate()
+        .map_err(|e| anyhow::anyhow!(e))?;
+
     // Batch mode: --input-dir takes precedence
     if let Some(dir) = input_dir {
         let format = OutputFormat::parse(output_format)
             .ok_or_else(|| anyhow::anyhow!("Invalid output format: {}", output_format))?;
-        return run_batch(dir, output_path, format, include_embeddings);
+        return run_batch(
+            dir,
+            output_path,
+            format,
+            include_embeddings,
+            embedding_options,
+        );
     }
 
     // Single file mode
@@ -92,9 +118,9 @@ pub fn run(
         input_path.ok_or_else(|| anyhow::anyhow!("Either --input or --input-dir is required"))?;
 
     if json_output {
-        run_json(input, output_path, include_embeddings)
+        run_json(input, output_path, include_embeddings, embedding_options)
     } else {
-        run_human(input, output_path, include_embeddings)
+        run_human(input, output_path, include_embeddings, embedding_options)
     }
 }
 
@@ -103,6 +129,7 @@ fn run_human(
     input_path: &str,
     output_path: Option<&str>,
     include_embeddings: bool,
+    embedding_options: &EmbeddingOptions,
 ) -> Result<ExitCode> {
     let path = Path::new(input_path);
 
@@ -134,7 +161,10 @@ fn run_human(
             let emb = if include_embeddings {
                 let (samples, sample_rate) = audio::extract_wav_samples(&data)
                     .map_err(|e| anyhow::anyhow!("Audio extraction failed: {}", e))?;
-                Some(embeddings::compute_audio_embedding(&samples, sample_rate))
+                Some(
+                    embedding_options
+                        .apply(embeddings::compute_audio_embedding(&samples, sample_rate)),
+                )
             } else {
                 None
             };
@@ -146,9 +176,11 @@ fn run_human(
             let emb = if include_embeddings {
                 let (pixels, width, height, channels) = texture::extract_png_pixels(&data)
                     .map_err(|e| anyhow::anyhow!("Texture extraction failed: {}", e))?;
-                Some(embeddings::compute_texture_embedding(
-                    &pixels, width, height, channels,
-                ))
+                Some(
+                    embedding_options.apply(embeddings::compute_texture_embedding(
+                        &pixels, width, height, channels,
+                    )),
+                )
             } else {
                 None
             };
@@ -197,6 +229,7 @@ fn run_json(
     input_path: &str,
     output_path: Option<&str>,
     include_embeddings: bool,
+    embedding_options: &EmbeddingOptions,
 ) -> Result<ExitCode> {
     let path = Path::new(input_path);
 
@@ -244,9 +277,10 @@ fn run_json(
             Ok(m) => {
                 let emb = if include_embeddings {
                     match audio::extract_wav_samples(&data) {
-                        Ok((samples, sample_rate)) => {
-                            Some(embeddings::compute_audio_embedding(&samples, sample_rate))
-                        }
+                        Ok((samples, sample_rate)) => Some(
+                            embedding_options
+                                .apply(embeddings::compute_audio_embedding(&samples, sample_rate)),
+                        ),
                         Err(e) => {
                             let error = JsonError::new(
                                 error_codes::AUDIO_ANALYSIS,
@@ -278,9 +312,9 @@ fn run_json(
             Ok(m) => {
                 let emb = if include_embeddings {
                     match texture::extract_png_pixels(&data) {
-                        Ok((pixels, width, height, channels)) => Some(
+                        Ok((pixels, width, height, channels)) => Some(embedding_options.apply(
                             embeddings::compute_texture_embedding(&pixels, width, height, channels),
-                        ),
+                        )),
                         Err(e) => {
                             let error = JsonError::new(
                                 error_codes::TEXTURE_ANALYSIS,
@@ -360,6 +394,7 @@ fn run_batch(
     output_path: Option<&str>,
     format: OutputFormat,
     include_embeddings: bool,
+    embedding_options: &EmbeddingOptions,
 ) -> Result<ExitCode> {
     let dir = Path::new(dir_path);
     if !dir.is_dir() {
@@ -395,7 +430,7 @@ fn run_batch(
     // Analyze each file
     let results: Vec<BatchAnalyzeItem> = files
         .iter()
-        .map(|path| analyze_single_file(path, include_embeddings))
+        .map(|path| analyze_single_file(path, include_embeddings, embedding_options))
         .collect();
 
     // Output based on format
@@ -417,7 +452,11 @@ fn run_batch(
 }
 
 /// Analyze a single file and return a BatchAnalyzeItem.
-fn analyze_single_file(path: &Path, include_embeddings: bool) -> BatchAnalyzeItem {
+fn analyze_single_file(
+    path: &Path,
+    include_embeddings: bool,
+    embedding_options: &EmbeddingOptions,
+) -> BatchAnalyzeItem {
     let path_str = path.display().to_string();
 
     // Detect asset type
@@ -455,9 +494,10 @@ fn analyze_single_file(path: &Path, include_embeddings: bool) -> BatchAnalyzeIte
             Ok(m) => {
                 let emb = if include_embeddings {
                     match audio::extract_wav_samples(&data) {
-                        Ok((samples, sample_rate)) => {
-                            Some(embeddings::compute_audio_embedding(&samples, sample_rate))
-                        }
+                        Ok((samples, sample_rate)) => Some(
+                            embedding_options
+                                .apply(embeddings::compute_audio_embedding(&samples, sample_rate)),
+                        ),
                         Err(e) => {
                             let error = JsonError::new(
                                 error_codes::AUDIO_ANALYSIS,
@@ -483,9 +523,9 @@ fn analyze_single_file(path: &Path, include_embeddings: bool) -> BatchAnalyzeIte
             Ok(m) => {
                 let emb = if include_embeddings {
                     match texture::extract_png_pixels(&data) {
-                        Ok((pixels, width, height, channels)) => Some(
+                        Ok((pixels, width, height, channels)) => Some(embedding_options.apply(
                             embeddings::compute_texture_embedding(&pixels, width, height, channels),
-                        ),
+                        )),
                         Err(e) => {
                             let error = JsonError::new(
                                 error_codes::TEXTURE_ANALYSIS,
@@ -606,15 +646,12 @@ mod tests {
         let wav_data = create_test_wav(&samples, 44100);
         fs::write(&wav_path, &wav_data).unwrap();
 
-        let code = run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            None,
-            true,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
     }
@@ -628,15 +665,12 @@ mod tests {
         let png_data = create_test_png(1, 1, &pixels);
         fs::write(&png_path, &png_data).unwrap();
 
-        let code = run(
-            Some(png_path.to_str().unwrap()),
-            None,
-            None,
-            None,
-            true,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some(png_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
     }
@@ -647,30 +681,24 @@ mod tests {
         let txt_path = tmp.path().join("test.txt");
         fs::write(&txt_path, "hello").unwrap();
 
-        let code = run(
-            Some(txt_path.to_str().unwrap()),
-            None,
-            None,
-            None,
-            true,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some(txt_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::from(1));
     }
 
     #[test]
     fn test_analyze_file_not_found() {
-        let code = run(
-            Some("/nonexistent/file.wav"),
-            None,
-            None,
-            None,
-            true,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some("/nonexistent/file.wav"),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::from(1));
     }
@@ -685,15 +713,13 @@ mod tests {
         let wav_data = create_test_wav(&samples, 44100);
         fs::write(&wav_path, &wav_data).unwrap();
 
-        let code = run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out_path.to_str().unwrap()),
-            true,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -717,26 +743,22 @@ mod tests {
         fs::write(&wav_path, &wav_data).unwrap();
 
         // Run twice
-        run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out1_path.to_str().unwrap()),
-            true,
-            "json",
-            false,
-        )
+        run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            output_path: Some(out1_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
 
-        run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out2_path.to_str().unwrap()),
-            true,
-            "json",
-            false,
-        )
+        run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            output_path: Some(out2_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
 
         // Compare outputs
@@ -755,15 +777,14 @@ mod tests {
         let wav_data = create_test_wav(&samples, 44100);
         fs::write(&wav_path, &wav_data).unwrap();
 
-        let code = run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out_path.to_str().unwrap()),
-            true,
-            "json",
-            true, // include embeddings
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            include_embeddings: true,
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -796,15 +817,14 @@ mod tests {
         let png_data = create_test_png(4, 4, &pixels);
         fs::write(&png_path, &png_data).unwrap();
 
-        let code = run(
-            Some(png_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out_path.to_str().unwrap()),
-            true,
-            "json",
-            true, // include embeddings
-        )
+        let code = run(&AnalyzeOptions {
+            input_path: Some(png_path.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            include_embeddings: true,
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -838,26 +858,24 @@ mod tests {
         fs::write(&wav_path, &wav_data).unwrap();
 
         // Run twice with embeddings
-        run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out1_path.to_str().unwrap()),
-            true,
-            "json",
-            true,
-        )
+        run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            output_path: Some(out1_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            include_embeddings: true,
+            ..Default::default()
+        })
         .unwrap();
 
-        run(
-            Some(wav_path.to_str().unwrap()),
-            None,
-            None,
-            Some(out2_path.to_str().unwrap()),
-            true,
-            "json",
-            true,
-        )
+        run(&AnalyzeOptions {
+            input_path: Some(wav_path.to_str().unwrap()),
+            output_path: Some(out2_path.to_str().unwrap()),
+            json_output: true,
+            output_format: "json",
+            include_embeddings: true,
+            ..Default::default()
+        })
         .unwrap();
 
         // Compare outputs - should be identical
@@ -887,15 +905,12 @@ mod tests {
 
         let out_path = tmp.path().join("results.json");
 
-        let code = run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out_path.to_str().unwrap()),
-            false,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -923,15 +938,12 @@ mod tests {
 
         let out_path = tmp.path().join("results.jsonl");
 
-        let code = run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out_path.to_str().unwrap()),
-            false,
-            "jsonl",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            output_format: "jsonl",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -958,15 +970,12 @@ mod tests {
 
         let out_path = tmp.path().join("results.csv");
 
-        let code = run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out_path.to_str().unwrap()),
-            false,
-            "csv",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            output_format: "csv",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -996,15 +1005,12 @@ mod tests {
 
         let out_path = tmp.path().join("results.json");
 
-        let code = run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out_path.to_str().unwrap()),
-            false,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS); // batch always succeeds overall
 
@@ -1029,15 +1035,12 @@ mod tests {
 
         let out_path = tmp.path().join("results.json");
 
-        let code = run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out_path.to_str().unwrap()),
-            false,
-            "json",
-            false,
-        )
+        let code = run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -1064,15 +1067,13 @@ mod tests {
 
         let out_path = tmp.path().join("results.json");
 
-        let code = run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out_path.to_str().unwrap()),
-            false,
-            "json",
-            true, // include embeddings
-        )
+        let code = run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out_path.to_str().unwrap()),
+            output_format: "json",
+            include_embeddings: true,
+            ..Default::default()
+        })
         .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
@@ -1109,26 +1110,20 @@ mod tests {
         let out2_path = tmp.path().join("results2.json");
 
         // Run twice
-        run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out1_path.to_str().unwrap()),
-            false,
-            "json",
-            false,
-        )
+        run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out1_path.to_str().unwrap()),
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
 
-        run(
-            None,
-            None,
-            Some(subdir.to_str().unwrap()),
-            Some(out2_path.to_str().unwrap()),
-            false,
-            "json",
-            false,
-        )
+        run(&AnalyzeOptions {
+            input_dir: Some(subdir.to_str().unwrap()),
+            output_path: Some(out2_path.to_str().unwrap()),
+            output_format: "json",
+            ..Default::default()
+        })
         .unwrap();
 
         // Compare outputs - should be identical
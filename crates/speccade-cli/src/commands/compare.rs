@@ -16,25 +16,99 @@ use super::json_output::{
     HistogramDiffMetrics, JsonError, MeshCompareMetrics, TextureCompareMetrics,
 };
 
+/// Pass/fail limits for a comparison, for use as a CI gate.
+///
+/// Unset limits are not checked. Byte-identical inputs always pass.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompareThresholds {
+    /// Maximum allowed SSIM drop (1 - SSIM) for textures.
+    pub max_ssim_delta: Option<f64>,
+    /// Maximum allowed mean DeltaE for textures.
+    pub max_delta_e: Option<f64>,
+    /// Maximum allowed residual level (A - B relative to A) in dB for audio.
+    pub max_residual_db: Option<f64>,
+    /// Maximum allowed absolute RMS level difference in dB for audio.
+    pub max_rms_delta_db: Option<f64>,
+}
+
+impl CompareThresholds {
+    /// Returns a message for each threshold the metrics exceed.
+    pub fn violations(&self, metrics: &CompareMetrics) -> Vec<String> {
+        let mut violations = Vec::new();
+        let mut check = |name: &str, value: f64, limit: Option<f64>| {
+            if let Some(limit) = limit {
+                if value > limit {
+                    violations.push(format!("{} {:.6} exceeds threshold {}", name, value, limit));
+                }
+            }
+        };
+
+        match metrics {
+            CompareMetrics::Texture(m) => {
+                check("SSIM delta", 1.0 - m.ssim, self.max_ssim_delta);
+                check("mean DeltaE", m.delta_e_mean, self.max_delta_e);
+            }
+            CompareMetrics::Audio(m) => {
+                check("residual dB", m.residual_db, self.max_residual_db);
+                check("RMS delta dB", m.rms_delta_db.abs(), self.max_rms_delta_db);
+            }
+            CompareMetrics::Mesh(_) => {}
+        }
+        violations
+    }
+
+    /// Returns true if any limit is set for the kind of asset `metrics` describe.
+    pub fn applies_to(&self, metrics: &CompareMetrics) -> bool {
+        match metrics {
+            CompareMetrics::Texture(_) => {
+                self.max_ssim_delta.is_some() || self.max_delta_e.is_some()
+            }
+            CompareMetrics::Audio(_) => {
+                self.max_residual_db.is_some() || self.max_rms_delta_db.is_some()
+            }
+            CompareMetrics::Mesh(_) => false,
+        }
+    }
+}
+
 /// Run the compare command
 ///
 /// # Arguments
 /// * `path_a` - Path to the first file (reference)
 /// * `path_b` - Path to the second file (comparison target)
+/// * `thresholds` - Limits that fail the comparison when exceeded
 /// * `json_output` - Whether to output machine-readable JSON
 ///
 /// # Returns
-/// Exit code: 0 on success, 1 on error
-pub fn run(path_a: &str, path_b: &str, json_output: bool) -> Result<ExitCode> {
+/// Exit code: 0 on success, 1 on error or if a threshold is exceeded
+pub fn run(
+    path_a: &str,
+    path_b: &str,
+    thresholds: &CompareThresholds,
+    json_output: bool,
+) -> Result<ExitCode> {
     if json_output {
-        run_json(path_a, path_b)
+        run_json(path_a, path_b, thresholds)
+    } else {
+        run_human(path_a, path_b, thresholds)
+    }
+}
+
+/// Checks thresholds against computed metrics, skipping byte-identical inputs.
+fn threshold_violations(
+    thresholds: &CompareThresholds,
+    identical: bool,
+    metrics: &CompareMetrics,
+) -> Vec<String> {
+    if identical {
+        Vec::new()
     } else {
-        run_human(path_a, path_b)
+        thresholds.violations(metrics)
     }
 }
 
 /// Run compare with human-readable (colored) output
-fn run_human(path_a: &str, path_b: &str) -> Result<ExitCode> {
+fn run_human(path_a: &str, path_b: &str, thresholds: &CompareThresholds) -> Result<ExitCode> {
     let file_a = Path::new(path_a);
     let file_b = Path::new(path_b);
 
@@ -91,14 +165,16 @@ fn run_human(path_a: &str, path_b: &str) -> Result<ExitCode> {
     // Perform type-specific comparison
     println!("\n{}", "Comparison Metrics:".cyan().bold());
 
-    match type_a {
+    let metrics = match type_a {
         AssetAnalysisType::Texture => {
             let result = compare_textures(&data_a, &data_b)?;
             print_texture_metrics(&result);
+            CompareMetrics::Texture(result)
         }
         AssetAnalysisType::Audio => {
             let result = compare_audio(&data_a, &data_b)?;
             print_audio_metrics(&result);
+            CompareMetrics::Audio(result)
         }
         AssetAnalysisType::Mesh => {
             println!(
@@ -106,14 +182,26 @@ fn run_human(path_a: &str, path_b: &str) -> Result<ExitCode> {
                 "Mesh comparison not yet implemented. Only byte-identical check performed."
                     .yellow()
             );
+            CompareMetrics::Mesh(MeshCompareMetrics {
+                byte_identical_only: true,
+            })
+        }
+    };
+
+    let violations = threshold_violations(thresholds, identical, &metrics);
+    if !violations.is_empty() {
+        println!("\n{}", "Threshold exceeded:".red().bold());
+        for violation in &violations {
+            println!("  {} {}", "FAIL".red(), violation);
         }
+        return Ok(ExitCode::from(1));
     }
 
     Ok(ExitCode::SUCCESS)
 }
 
 /// Run compare with machine-readable JSON output
-fn run_json(path_a: &str, path_b: &str) -> Result<ExitCode> {
+fn run_json(path_a: &str, path_b: &str, thresholds: &CompareThresholds) -> Result<ExitCode> {
     let file_a = Path::new(path_a);
     let file_b = Path::new(path_b);
 
@@ -239,6 +327,7 @@ fn run_json(path_a: &str, path_b: &str) -> Result<ExitCode> {
         }
     };
 
+    let threshold_violations = threshold_violations(thresholds, identical, &metrics);
     let result = CompareResult {
         path_a: path_a.to_string(),
         path_b: path_b.to_string(),
@@ -247,16 +336,21 @@ fn run_json(path_a: &str, path_b: &str) -> Result<ExitCode> {
         hash_b,
         identical,
         metrics,
+        threshold_violations,
     };
 
     let output = CompareOutput::success(result);
     println!("{}", serde_json::to_string_pretty(&output)?);
 
-    Ok(ExitCode::SUCCESS)
+    if output.success {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::from(1))
+    }
 }
 
 /// Compare two texture files and return metrics.
-fn compare_textures(data_a: &[u8], data_b: &[u8]) -> Result<TextureCompareMetrics> {
+pub(super) fn compare_textures(data_a: &[u8], data_b: &[u8]) -> Result<TextureCompareMetrics> {
     // Analyze both textures
     let metrics_a = texture::analyze_png(data_a)
         .map_err(|e| anyhow::anyhow!("Failed to analyze texture A: {}", e))?;
@@ -309,7 +403,7 @@ fn compare_textures(data_a: &[u8], data_b: &[u8]) -> Result<TextureCompareMetric
 }
 
 /// Compare two audio files and return metrics.
-fn compare_audio(data_a: &[u8], data_b: &[u8]) -> Result<AudioCompareMetrics> {
+pub(super) fn compare_audio(data_a: &[u8], data_b: &[u8]) -> Result<AudioCompareMetrics> {
     // Analyze both audio files
     let metrics_a = audio::analyze_wav(data_a)
         .map_err(|e| anyhow::anyhow!("Failed to analyze audio A: {}", e))?;
@@ -344,6 +438,7 @@ fn compare_audio(data_a: &[u8], data_b: &[u8]) -> Result<AudioCompareMetrics> {
         rms_delta_db: result.rms_delta_db,
         peak_delta_db: result.peak_delta_db,
         loudness_delta_percent: result.loudness_delta_percent,
+        residual_db: result.residual_db,
     })
 }
 
@@ -422,6 +517,7 @@ fn print_audio_metrics(metrics: &AudioCompareMetrics) {
         "Loudness Delta:".cyan(),
         metrics.loudness_delta_percent
     );
+    println!("  {} {:.2} dB", "Residual:".cyan(), metrics.residual_db);
 }
 
 #[cfg(test)]
@@ -546,7 +642,13 @@ mod tests {
         fs::write(&path_a, &wav).unwrap();
         fs::write(&path_b, &wav).unwrap();
 
-        let code = run(path_a.to_str().unwrap(), path_b.to_str().unwrap(), true).unwrap();
+        let code = run(
+            path_a.to_str().unwrap(),
+            path_b.to_str().unwrap(),
+            &CompareThresholds::default(),
+            true,
+        )
+        .unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
     }
 
@@ -567,6 +669,7 @@ mod tests {
         let result = run(
             wav_path.to_str().unwrap(),
             png_path.to_str().unwrap(),
+            &CompareThresholds::default(),
             false,
         );
         assert!(result.is_err());
@@ -607,7 +710,93 @@ mod tests {
 
     #[test]
     fn test_compare_file_not_found() {
-        let code = run("/nonexistent/file.wav", "/other/file.wav", true).unwrap();
+        let code = run(
+            "/nonexistent/file.wav",
+            "/other/file.wav",
+            &CompareThresholds::default(),
+            true,
+        )
+        .unwrap();
         assert_eq!(code, ExitCode::from(1));
     }
+
+    #[test]
+    fn test_compare_identical_inputs_pass_any_threshold() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("a.wav");
+        let samples: Vec<f32> = (0..4410).map(|i| (i as f32 * 0.1).sin()).collect();
+        fs::write(&path, create_test_wav(&samples, 44100)).unwrap();
+
+        let strict = CompareThresholds {
+            max_residual_db: Some(-200.0),
+            max_rms_delta_db: Some(0.0),
+            ..Default::default()
+        };
+        for json in [true, false] {
+            let code = run(
+                path.to_str().unwrap(),
+                path.to_str().unwrap(),
+                &strict,
+                json,
+            )
+            .unwrap();
+            assert_eq!(code, ExitCode::SUCCESS);
+        }
+    }
+
+    #[test]
+    fn test_compare_audio_threshold_exit_codes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path_a = tmp.path().join("a.wav");
+        let path_b = tmp.path().join("b.wav");
+        let samples_a: Vec<f32> = (0..4410).map(|i| (i as f32 * 0.1).sin() * 0.5).collect();
+        // 10% amplitude difference: residual around -20 dB
+        let samples_b: Vec<f32> = samples_a.iter().map(|s| s * 0.9).collect();
+        fs::write(&path_a, create_test_wav(&samples_a, 44100)).unwrap();
+        fs::write(&path_b, create_test_wav(&samples_b, 44100)).unwrap();
+        let (a, b) = (path_a.to_str().unwrap(), path_b.to_str().unwrap());
+
+        let loose = CompareThresholds {
+            max_residual_db: Some(-10.0),
+            ..Default::default()
+        };
+        assert_eq!(run(a, b, &loose, true).unwrap(), ExitCode::SUCCESS);
+        assert_eq!(run(a, b, &loose, false).unwrap(), ExitCode::SUCCESS);
+
+        let tight = CompareThresholds {
+            max_residual_db: Some(-30.0),
+            ..Default::default()
+        };
+        assert_eq!(run(a, b, &tight, true).unwrap(), ExitCode::from(1));
+        assert_eq!(run(a, b, &tight, false).unwrap(), ExitCode::from(1));
+    }
+
+    #[test]
+    fn test_compare_texture_threshold_violations() {
+        let pixels_a: Vec<u8> = (0..16 * 16 * 4).map(|i| (i % 256) as u8).collect();
+        let pixels_b: Vec<u8> = pixels_a.iter().map(|p| p.saturating_add(40)).collect();
+        let metrics = CompareMetrics::Texture(
+            compare_textures(
+                &create_test_png(16, 16, &pixels_a),
+                &create_test_png(16, 16, &pixels_b),
+            )
+            .unwrap(),
+        );
+
+        let loose = CompareThresholds {
+            max_ssim_delta: Some(1.0),
+            max_delta_e: Some(100.0),
+            ..Default::default()
+        };
+        assert!(loose.violations(&metrics).is_empty());
+
+        let tight = CompareThresholds {
+            max_ssim_delta: Some(0.0001),
+            ..Default::default()
+        };
+        let violations = tight.violations(&metrics);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].contains("SSIM delta"), "{}", violations[0]);
+        assert!(violations[0].contains("exceeds threshold"));
+    }
 }
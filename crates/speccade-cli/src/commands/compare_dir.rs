@@ -0,0 +1,453 @@
+//! Directory compare implementation
+//!
+//! Compares two output trees file-by-file for regression sweeps. Files are paired
+//! by relative path; changed files of a supported format get the same perceptual
+//! metrics and threshold checks as the single-file `compare` command.
+
+use anyhow::Result;
+use colored::Colorize;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use walkdir::WalkDir;
+
+use crate::analysis::{detect_asset_type, AssetAnalysisType};
+
+use super::compare::{compare_audio, compare_textures, CompareThresholds};
+use super::json_output::{
+    error_codes, CompareDirEntry, CompareDirOutput, CompareDirResult, CompareDirStatus,
+    CompareDirSummary, CompareMetrics, JsonError, MeshCompareMetrics,
+};
+
+/// Run the directory compare command
+///
+/// # Arguments
+/// * `dir_a` - Reference directory
+/// * `dir_b` - Comparison target directory
+/// * `thresholds` - Limits that changed files are checked against
+/// * `json_output` - Whether to output machine-readable JSON
+///
+/// # Returns
+/// Exit code: 0 if every file has an identical counterpart or a changed one within
+/// the thresholds, 1 if any other file changed, is missing a counterpart, or the
+/// directories could not be read
+pub fn run(
+    dir_a: &str,
+    dir_b: &str,
+    thresholds: &CompareThresholds,
+    json_output: bool,
+) -> Result<ExitCode> {
+    let result = compare_dirs(dir_a, dir_b, thresholds);
+
+    if json_output {
+        let output = match result {
+            Ok(result) => CompareDirOutput::success(result),
+            Err(e) => {
+                let error = JsonError::new(error_codes::FILE_READ, e.to_string());
+                let output = CompareDirOutput::failure(vec![error]);
+                println!("{}", serde_json::to_string_pretty(&output)?);
+                return Ok(ExitCode::from(1));
+            }
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        let fails = output
+            .result
+            .as_ref()
+            .is_some_and(|r| r.summary.has_failures());
+        return Ok(exit_code(fails));
+    }
+
+    let result = result?;
+    print_human(&result);
+    Ok(exit_code(result.summary.has_failures()))
+}
+
+fn exit_code(fails: bool) -> ExitCode {
+    if fails {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Pairs the files of two directories by relative path and compares each pair.
+///
+/// A changed file counts as within the thresholds only if at least one limit
+/// applies to its asset type and none are exceeded.
+pub fn compare_dirs(
+    dir_a: &str,
+    dir_b: &str,
+    thresholds: &CompareThresholds,
+) -> Result<CompareDirResult> {
+    let root_a = Path::new(dir_a);
+    let root_b = Path::new(dir_b);
+    for (flag, dir) in [("--dir-a", dir_a), ("--dir-b", dir_b)] {
+        if !Path::new(dir).is_dir() {
+            anyhow::bail!("{} path is not a directory: {}", flag, dir);
+        }
+    }
+
+    let files_a = relative_files(root_a);
+    let files_b = relative_files(root_b);
+
+    let files: Vec<CompareDirEntry> = files_a
+        .union(&files_b)
+        .map(|rel| match (files_a.contains(rel), files_b.contains(rel)) {
+            (true, false) => missing_entry(rel, CompareDirStatus::MissingInB),
+            (false, true) => missing_entry(rel, CompareDirStatus::MissingInA),
+            _ => compare_pair(rel, &root_a.join(rel), &root_b.join(rel), thresholds),
+        })
+        .collect();
+
+    let summary = CompareDirSummary::from_entries(&files);
+    Ok(CompareDirResult {
+        dir_a: dir_a.to_string(),
+        dir_b: dir_b.to_string(),
+        files,
+        summary,
+    })
+}
+
+/// Lists the files under `root` as sorted relative paths with forward slashes.
+fn relative_files(root: &Path) -> BTreeSet<String> {
+    WalkDir::new(root)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            e.path().strip_prefix(root).ok().map(|rel| {
+                rel.components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+        })
+        .collect()
+}
+
+fn missing_entry(rel: &str, status: CompareDirStatus) -> CompareDirEntry {
+    CompareDirEntry {
+        path: rel.to_string(),
+        status,
+        asset_type: detect_asset_type(Path::new(rel)).map(|t| t.as_str().to_string()),
+        metrics: None,
+        error: None,
+        threshold_violations: Vec::new(),
+        within_thresholds: false,
+    }
+}
+
+/// Compares a file present in both directories.
+fn compare_pair(
+    rel: &str,
+    path_a: &Path,
+    path_b: &Path,
+    thresholds: &CompareThresholds,
+) -> CompareDirEntry {
+    let asset_type = detect_asset_type(Path::new(rel));
+    let mut entry = CompareDirEntry {
+        path: rel.to_string(),
+        status: CompareDirStatus::Changed,
+        asset_type: asset_type.map(|t| t.as_str().to_string()),
+        metrics: None,
+        error: None,
+        threshold_violations: Vec::new(),
+        within_thresholds: false,
+    };
+
+    let (data_a, data_b) = match (fs::read(path_a), fs::read(path_b)) {
+        (Ok(a), Ok(b)) => (a, b),
+        (Err(e), _) | (_, Err(e)) => {
+            entry.error = Some(format!("Failed to read file: {}", e));
+            return entry;
+        }
+    };
+
+    if data_a == data_b {
+        entry.status = CompareDirStatus::Identical;
+        return entry;
+    }
+
+    let metrics = match asset_type {
+        Some(AssetAnalysisType::Texture) => {
+            compare_textures(&data_a, &data_b).map(CompareMetrics::Texture)
+        }
+        Some(AssetAnalysisType::Audio) => {
+            compare_audio(&data_a, &data_b).map(CompareMetrics::Audio)
+        }
+        Some(AssetAnalysisType::Mesh) => Ok(CompareMetrics::Mesh(MeshCompareMetrics {
+            byte_identical_only: true,
+        })),
+        None => return entry,
+    };
+    match metrics {
+        Ok(metrics) => {
+            entry.threshold_violations = thresholds.violations(&metrics);
+            entry.within_thresholds =
+                thresholds.applies_to(&metrics) && entry.threshold_violations.is_empty();
+            entry.metrics = Some(metrics);
+        }
+        Err(e) => entry.error = Some(e.to_string()),
+    }
+    entry
+}
+
+/// Print the directory comparison in human-readable format.
+fn print_human(result: &CompareDirResult) {
+    println!("{}", "Comparing directories:".cyan().bold());
+    println!("  {} {}", "A:".dimmed(), result.dir_a);
+    println!("  {} {}", "B:".dimmed(), result.dir_b);
+
+    let differing: Vec<_> = result
+        .files
+        .iter()
+        .filter(|entry| entry.status != CompareDirStatus::Identical)
+        .collect();
+    if !differing.is_empty() {
+        println!();
+    }
+    for entry in differing {
+        match entry.status {
+            CompareDirStatus::Changed => {
+                println!("  {} {}", "~".yellow(), entry.path);
+                if let Some(ref metrics) = entry.metrics {
+                    println!("      {}", metrics_summary(metrics).dimmed());
+                }
+                if let Some(ref error) = entry.error {
+                    println!("      {}", error.red());
+                }
+                for violation in &entry.threshold_violations {
+                    println!("      {} {}", "FAIL".red(), violation);
+                }
+                if entry.within_thresholds {
+                    println!("      {}", "within thresholds".green());
+                }
+            }
+            CompareDirStatus::MissingInA => {
+                println!(
+                    "  {} {} {}",
+                    "+".red(),
+                    entry.path,
+                    "(missing in A)".dimmed()
+                )
+            }
+            CompareDirStatus::MissingInB => {
+                println!(
+                    "  {} {} {}",
+                    "-".red(),
+                    entry.path,
+                    "(missing in B)".dimmed()
+                )
+            }
+            CompareDirStatus::Identical => {}
+        }
+    }
+
+    let summary = &result.summary;
+    println!(
+        "\n{} {} identical, {} changed, {} missing in A, {} missing in B ({} total)",
+        "Summary:".cyan().bold(),
+        summary.identical,
+        summary.changed,
+        summary.missing_in_a,
+        summary.missing_in_b,
+        summary.total
+    );
+    if summary.within_thresholds > 0 {
+        println!(
+            "  {} of the changed files are within thresholds",
+            summary.within_thresholds
+        );
+    }
+
+    if summary.has_failures() {
+        println!("{}", "DIFFERENCES FOUND".red().bold());
+    } else if summary.has_differences() {
+        println!("{}", "Differences within thresholds".green().bold());
+    } else {
+        println!("{}", "Directories match".green().bold());
+    }
+}
+
+/// One-line summary of comparison metrics.
+fn metrics_summary(metrics: &CompareMetrics) -> String {
+    match metrics {
+        CompareMetrics::Texture(m) => format!(
+            "ssim={:.4} delta_e_mean={:.2} delta_e_max={:.2}",
+            m.ssim, m.delta_e_mean, m.delta_e_max
+        ),
+        CompareMetrics::Audio(m) => format!(
+            "spectral_correlation={:.4} rms_delta={:.2} dB peak_delta={:.2} dB",
+            m.spectral_correlation, m.rms_delta_db, m.peak_delta_db
+        ),
+        CompareMetrics::Mesh(_) => "byte comparison only".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_png(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+        let mut png_data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png_data, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(pixels).unwrap();
+        drop(writer);
+        png_data
+    }
+
+    fn write(root: &Path, rel: &str, data: &[u8]) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, data).unwrap();
+    }
+
+    #[test]
+    fn test_compare_dirs_reports_identical_changed_and_missing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir_a = tmp.path().join("a");
+        let dir_b = tmp.path().join("b");
+
+        let gray = create_test_png(4, 4, &[128u8; 4 * 4 * 4]);
+        let white = create_test_png(4, 4, &[255u8; 4 * 4 * 4]);
+        write(&dir_a, "textures/same.png", &gray);
+        write(&dir_b, "textures/same.png", &gray);
+        write(&dir_a, "textures/changed.png", &gray);
+        write(&dir_b, "textures/changed.png", &white);
+        write(&dir_a, "only_a.txt", b"a");
+        write(&dir_b, "only_b.txt", b"b");
+
+        let result = compare_dirs(
+            dir_a.to_str().unwrap(),
+            dir_b.to_str().unwrap(),
+            &CompareThresholds::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            result.summary,
+            CompareDirSummary {
+                total: 4,
+                identical: 1,
+                changed: 1,
+                missing_in_a: 1,
+                missing_in_b: 1,
+                within_thresholds: 0,
+            }
+        );
+
+        let status = |path: &str| {
+            result
+                .files
+                .iter()
+                .find(|entry| entry.path == path)
+                .unwrap()
+                .status
+        };
+        assert_eq!(status("textures/same.png"), CompareDirStatus::Identical);
+        assert_eq!(status("textures/changed.png"), CompareDirStatus::Changed);
+        assert_eq!(status("only_a.txt"), CompareDirStatus::MissingInB);
+        assert_eq!(status("only_b.txt"), CompareDirStatus::MissingInA);
+
+        let changed = result
+            .files
+            .iter()
+            .find(|entry| entry.path == "textures/changed.png")
+            .unwrap();
+        assert!(matches!(
+            changed.metrics,
+            Some(CompareMetrics::Texture(ref m)) if m.delta_e_mean > 0.0
+        ));
+
+        let code = run(
+            dir_a.to_str().unwrap(),
+            dir_b.to_str().unwrap(),
+            &CompareThresholds::default(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::from(1));
+    }
+
+    #[test]
+    fn test_compare_dirs_matching_trees_succeed() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir_a = tmp.path().join("a");
+        let dir_b = tmp.path().join("b");
+
+        let png = create_test_png(4, 4, &[64u8; 4 * 4 * 4]);
+        for dir in [&dir_a, &dir_b] {
+            write(dir, "nested/texture.png", &png);
+            write(dir, "notes.txt", b"same");
+        }
+
+        let code = run(
+            dir_a.to_str().unwrap(),
+            dir_b.to_str().unwrap(),
+            &CompareThresholds::default(),
+            false,
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn test_compare_dirs_rejects_missing_directory() {
+        let tmp = tempfile::tempdir().unwrap();
+        let code = run(
+            tmp.path().to_str().unwrap(),
+            "/nonexistent/compare/dir",
+            &CompareThresholds::default(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::from(1));
+    }
+
+    #[test]
+    fn test_compare_dirs_applies_thresholds_per_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir_a = tmp.path().join("a");
+        let dir_b = tmp.path().join("b");
+
+        let gray = create_test_png(4, 4, &[128u8; 4 * 4 * 4]);
+        let near_gray = create_test_png(4, 4, &[130u8; 4 * 4 * 4]);
+        let white = create_test_png(4, 4, &[255u8; 4 * 4 * 4]);
+        write(&dir_a, "slight.png", &gray);
+        write(&dir_b, "slight.png", &near_gray);
+        write(&dir_a, "large.png", &gray);
+        write(&dir_b, "large.png", &white);
+        let (a, b) = (dir_a.to_str().unwrap(), dir_b.to_str().unwrap());
+
+        let thresholds = CompareThresholds {
+            max_delta_e: Some(5.0),
+            ..Default::default()
+        };
+        let result = compare_dirs(a, b, &thresholds).unwrap();
+        let entry = |path: &str| result.files.iter().find(|e| e.path == path).unwrap();
+        assert!(entry("slight.png").within_thresholds);
+        assert!(entry("slight.png").threshold_violations.is_empty());
+        assert!(!entry("large.png").within_thresholds);
+        assert_eq!(entry("large.png").threshold_violations.len(), 1);
+        assert_eq!(result.summary.within_thresholds, 1);
+        assert!(result.summary.has_failures());
+        assert_eq!(run(a, b, &thresholds, true).unwrap(), ExitCode::from(1));
+
+        // Only the slight change left: the run passes under the threshold but
+        // still fails without one, or when only audio limits are set.
+        fs::remove_file(dir_a.join("large.png")).unwrap();
+        fs::remove_file(dir_b.join("large.png")).unwrap();
+        assert_eq!(run(a, b, &thresholds, true).unwrap(), ExitCode::SUCCESS);
+        let none = CompareThresholds::default();
+        assert_eq!(run(a, b, &none, true).unwrap(), ExitCode::from(1));
+        let audio_only = CompareThresholds {
+            max_rms_delta_db: Some(1.0),
+            ..Default::default()
+        };
+        assert_eq!(run(a, b, &audio_only, true).unwrap(), ExitCode::from(1));
+    }
+}
@@ -0,0 +1,337 @@
+//! Diff command implementation
+//!
+//! Compares two spec files after canonicalization and reports field-level changes,
+//! calling out seed-only edits separately from recipe changes.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+use speccade_spec::{canonical_spec_hash, Spec};
+use std::path::Path;
+use std::process::ExitCode;
+
+use super::json_output::{
+    error_codes, input_error_to_json, DiffChange, DiffOp, DiffOutput, DiffResult, JsonError,
+};
+use crate::input::load_spec;
+
+/// Run the diff command
+///
+/// # Arguments
+/// * `path_a` - Path to the first spec (before)
+/// * `path_b` - Path to the second spec (after)
+/// * `json_output` - Whether to output machine-readable JSON
+///
+/// # Returns
+/// Exit code: 0 on success (whether or not the specs differ), 1 on error
+pub fn run(path_a: &str, path_b: &str, json_output: bool) -> Result<ExitCode> {
+    if json_output {
+        run_json(path_a, path_b)
+    } else {
+        run_human(path_a, path_b)
+    }
+}
+
+/// Run diff with human-readable (colored) output
+fn run_human(path_a: &str, path_b: &str) -> Result<ExitCode> {
+    let spec_a = load_spec(Path::new(path_a))
+        .with_context(|| format!("Failed to load spec A: {}", path_a))?
+        .spec;
+    let spec_b = load_spec(Path::new(path_b))
+        .with_context(|| format!("Failed to load spec B: {}", path_b))?
+        .spec;
+
+    let diff = diff_specs(path_a, &spec_a, path_b, &spec_b)?;
+
+    println!("{}", "Comparing specs:".cyan().bold());
+    println!("  {} {}", "A:".dimmed(), path_a);
+    println!("  {} {}", "B:".dimmed(), path_b);
+    println!("{} {}", "Hash A:".dimmed(), diff.hash_a);
+    println!("{} {}", "Hash B:".dimmed(), diff.hash_b);
+    println!();
+
+    if diff.identical {
+        println!(
+            "{}",
+            "Specs are identical after canonicalization".green().bold()
+        );
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if diff.seed_only {
+        println!("{}", "Only the seed changed".yellow().bold());
+    } else if diff.recipe_changed {
+        println!("{}", "Recipe params changed".yellow().bold());
+    } else {
+        println!("{}", "Metadata changed (recipe unchanged)".yellow().bold());
+    }
+
+    // Group changes under their top-level field so the output reads as a tree.
+    let mut section = "";
+    for change in &diff.changes {
+        let top = top_level_field(&change.path);
+        if top != section {
+            println!("{}", top.bold());
+            section = top;
+        }
+        match change.op {
+            DiffOp::Added => println!(
+                "  {} {}: {}",
+                "+".green(),
+                change.path,
+                format_value(change.after.as_ref())
+            ),
+            DiffOp::Removed => println!(
+                "  {} {}: {}",
+                "-".red(),
+                change.path,
+                format_value(change.before.as_ref())
+            ),
+            DiffOp::Changed => println!(
+                "  {} {}: {} -> {}",
+                "~".yellow(),
+                change.path,
+                format_value(change.before.as_ref()),
+                format_value(change.after.as_ref())
+            ),
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Run diff with machine-readable JSON output
+fn run_json(path_a: &str, path_b: &str) -> Result<ExitCode> {
+    let mut errors = Vec::new();
+    let spec_a = load_spec(Path::new(path_a))
+        .map_err(|e| errors.push(input_error_to_json(&e, Some(path_a))))
+        .ok();
+    let spec_b = load_spec(Path::new(path_b))
+        .map_err(|e| errors.push(input_error_to_json(&e, Some(path_b))))
+        .ok();
+
+    let output = match (spec_a, spec_b) {
+        (Some(a), Some(b)) => match diff_specs(path_a, &a.spec, path_b, &b.spec) {
+            Ok(diff) => DiffOutput::success(diff),
+            Err(e) => DiffOutput::failure(vec![JsonError::new(
+                error_codes::JSON_SERIALIZE,
+                format!("Failed to canonicalize specs: {}", e),
+            )]),
+        },
+        _ => DiffOutput::failure(errors),
+    };
+
+    let json =
+        serde_json::to_string_pretty(&output).expect("DiffOutput serialization should not fail");
+    println!("{}", json);
+
+    if output.success {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        Ok(ExitCode::from(1))
+    }
+}
+
+/// Computes the semantic diff between two specs.
+fn diff_specs(path_a: &str, spec_a: &Spec, path_b: &str, spec_b: &Spec) -> Result<DiffResult> {
+    let hash_a = canonical_spec_hash(spec_a).context("Failed to hash spec A")?;
+    let hash_b = canonical_spec_hash(spec_b).context("Failed to hash spec B")?;
+    let value_a = spec_a.to_value().context("Failed to serialize spec A")?;
+    let value_b = spec_b.to_value().context("Failed to serialize spec B")?;
+
+    let mut changes = Vec::new();
+    diff_values("", &value_a, &value_b, &mut changes);
+
+    let seed_only = !changes.is_empty() && changes.iter().all(|c| c.path == "seed");
+    let recipe_changed = changes.iter().any(|c| top_level_field(&c.path) == "recipe");
+
+    Ok(DiffResult {
+        path_a: path_a.to_string(),
+        path_b: path_b.to_string(),
+        identical: hash_a == hash_b,
+        hash_a,
+        hash_b,
+        seed_only,
+        recipe_changed,
+        changes,
+    })
+}
+
+/// Recursively collects changes between two JSON values.
+///
+/// Object keys are visited in sorted order, and arrays are compared index by index.
+fn diff_values(path: &str, a: &Value, b: &Value, changes: &mut Vec<DiffChange>) {
+    match (a, b) {
+        (Value::Object(map_a), Value::Object(map_b)) => {
+            let mut keys: Vec<&String> = map_a.keys().chain(map_b.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match (map_a.get(key), map_b.get(key)) {
+                    (Some(va), Some(vb)) => diff_values(&child, va, vb, changes),
+                    (Some(va), None) => changes.push(removed(child, va)),
+                    (None, Some(vb)) => changes.push(added(child, vb)),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Array(arr_a), Value::Array(arr_b)) => {
+            for i in 0..arr_a.len().max(arr_b.len()) {
+                let child = format!("{}[{}]", path, i);
+                match (arr_a.get(i), arr_b.get(i)) {
+                    (Some(va), Some(vb)) => diff_values(&child, va, vb, changes),
+                    (Some(va), None) => changes.push(removed(child, va)),
+                    (None, Some(vb)) => changes.push(added(child, vb)),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ if a != b => changes.push(DiffChange {
+            op: DiffOp::Changed,
+            path: path.to_string(),
+            before: Some(a.clone()),
+            after: Some(b.clone()),
+        }),
+        _ => {}
+    }
+}
+
+fn added(path: String, value: &Value) -> DiffChange {
+    DiffChange {
+        op: DiffOp::Added,
+        path,
+        before: None,
+        after: Some(value.clone()),
+    }
+}
+
+fn removed(path: String, value: &Value) -> DiffChange {
+    DiffChange {
+        op: DiffOp::Removed,
+        path,
+        before: Some(value.clone()),
+        after: None,
+    }
+}
+
+/// Returns the top-level field of a change path (e.g. `recipe` for `recipe.params.bits`).
+fn top_level_field(path: &str) -> &str {
+    path.split(['.', '[']).next().unwrap_or(path)
+}
+
+fn format_value(value: Option<&Value>) -> String {
+    value.map(Value::to_string).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speccade_spec::{AssetType, OutputFormat, OutputSpec, Recipe};
+
+    fn beep_spec(seed: u32, volume: f64) -> Spec {
+        Spec::builder("diff-test-01", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(seed)
+            .output(OutputSpec::primary(OutputFormat::Wav, "beep.wav"))
+            .recipe(Recipe::new(
+                "audio_v1",
+                serde_json::json!({
+                    "duration_seconds": 0.5,
+                    "sample_rate": 22050,
+                    "layers": [{
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.01, "decay": 0.1, "sustain": 0.5, "release": 0.2 },
+                        "volume": volume,
+                        "pan": 0.0
+                    }]
+                }),
+            ))
+            .build()
+    }
+
+    #[test]
+    fn diff_identical_specs_has_no_changes() {
+        let diff = diff_specs("a.json", &beep_spec(1, 0.5), "b.json", &beep_spec(1, 0.5)).unwrap();
+        assert!(diff.identical);
+        assert_eq!(diff.hash_a, diff.hash_b);
+        assert!(diff.changes.is_empty());
+        assert!(!diff.seed_only);
+    }
+
+    #[test]
+    fn diff_seed_only_change() {
+        let diff = diff_specs("a.json", &beep_spec(1, 0.5), "b.json", &beep_spec(2, 0.5)).unwrap();
+        assert!(!diff.identical);
+        assert_ne!(diff.hash_a, diff.hash_b);
+        assert!(diff.seed_only);
+        assert!(!diff.recipe_changed);
+        assert_eq!(
+            diff.changes,
+            vec![DiffChange {
+                op: DiffOp::Changed,
+                path: "seed".to_string(),
+                before: Some(serde_json::json!(1)),
+                after: Some(serde_json::json!(2)),
+            }]
+        );
+    }
+
+    #[test]
+    fn diff_recipe_param_change() {
+        let diff = diff_specs("a.json", &beep_spec(1, 0.5), "b.json", &beep_spec(1, 0.8)).unwrap();
+        assert!(!diff.seed_only);
+        assert!(diff.recipe_changed);
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].op, DiffOp::Changed);
+        assert_eq!(diff.changes[0].path, "recipe.params.layers[0].volume");
+    }
+
+    #[test]
+    fn diff_reports_added_and_removed_fields() {
+        let mut a = beep_spec(1, 0.5);
+        a.description = Some("a beep".to_string());
+        let mut b = beep_spec(1, 0.5);
+        b.style_tags = Some(vec!["retro".to_string()]);
+
+        let diff = diff_specs("a.json", &a, "b.json", &b).unwrap();
+        assert!(!diff.recipe_changed);
+        assert!(!diff.seed_only);
+        let ops: Vec<(DiffOp, &str)> = diff
+            .changes
+            .iter()
+            .map(|c| (c.op, c.path.as_str()))
+            .collect();
+        assert_eq!(
+            ops,
+            [
+                (DiffOp::Removed, "description"),
+                (DiffOp::Added, "style_tags")
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_json_output_lists_patches() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path_a = tmp.path().join("a.json");
+        let path_b = tmp.path().join("b.json");
+        std::fs::write(&path_a, beep_spec(1, 0.5).to_json_pretty().unwrap()).unwrap();
+        std::fs::write(&path_b, beep_spec(2, 0.5).to_json_pretty().unwrap()).unwrap();
+
+        let code = run(path_a.to_str().unwrap(), path_b.to_str().unwrap(), true).unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn diff_missing_file_fails() {
+        assert!(run("/nonexistent/a.json", "/nonexistent/b.json", false).is_err());
+        let code = run("/nonexistent/a.json", "/nonexistent/b.json", true).unwrap();
+        assert_eq!(code, ExitCode::from(1));
+    }
+}
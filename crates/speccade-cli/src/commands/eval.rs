@@ -10,7 +10,7 @@ use std::process::ExitCode;
 use super::json_output::{
     compile_warnings_to_json, input_error_to_json, EvalOutput, JsonError, JsonWarning,
 };
-use crate::input::{load_spec, InputError};
+use crate::input::{load_spec_for_eval, InputError};
 
 /// Run the eval command
 ///
@@ -34,7 +34,7 @@ fn run_human(spec_path: &str, pretty: bool) -> Result<ExitCode> {
     let path = Path::new(spec_path);
 
     // Load the spec
-    let result = load_spec(path).map_err(|e| {
+    let result = load_spec_for_eval(path).map_err(|e| {
         match &e {
             InputError::FileRead { path, .. } => {
                 eprintln!(
@@ -122,7 +122,7 @@ fn run_json(spec_path: &str, _pretty: bool) -> Result<ExitCode> {
     let path = Path::new(spec_path);
 
     // Load the spec
-    let load_result = load_spec(path);
+    let load_result = load_spec_for_eval(path);
 
     let output = match load_result {
         Ok(result) => {
@@ -230,6 +230,40 @@ mod tests {
         assert_eq!(code, ExitCode::SUCCESS);
     }
 
+    #[cfg(feature = "starlark")]
+    #[test]
+    fn eval_starlark_spec_without_seed_derives_one() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spec_path = tmp.path().join("spec.star");
+
+        let starlark_source = r#"
+{
+    "spec_version": 1,
+    "asset_id": "starlark-seedless-test-01",
+    "asset_type": "audio",
+    "license": "CC0-1.0",
+    "outputs": [
+        {
+            "kind": "primary",
+            "format": "wav",
+            "path": "sounds/test.wav"
+        }
+    ]
+}
+"#;
+        std::fs::write(&spec_path, starlark_source).unwrap();
+
+        let result = load_spec_for_eval(&spec_path).unwrap();
+        assert_eq!(
+            result.spec.seed,
+            speccade_spec::hash::derive_seed_from_asset_id("starlark-seedless-test-01")
+        );
+        assert_eq!(result.warnings.len(), 1);
+
+        let code = run(spec_path.to_str().unwrap(), false, false).unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+    }
+
     #[cfg(feature = "starlark")]
     #[test]
     fn eval_starlark_with_variables_success() {
@@ -300,7 +334,7 @@ seed = 123
         // We can't easily capture stdout in a unit test, but we can at least
         // verify the run_json function produces valid JSON by testing the output type
         let path = Path::new(spec_path.to_str().unwrap());
-        let load_result = load_spec(path).unwrap();
+        let load_result = load_spec_for_eval(path).unwrap();
         let spec_json = serde_json::to_value(&load_result.spec).unwrap();
         let output = EvalOutput::success(spec_json, load_result.source_hash, vec![]);
 
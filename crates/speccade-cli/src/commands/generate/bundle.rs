@@ -0,0 +1,95 @@
+//! Deterministic zip bundles of generated outputs.
+//!
+//! Bundles are written with [`DeterministicZip`], so the same outputs always
+//! produce a byte-identical archive.
+
+use anyhow::{Context, Result};
+use speccade_spec::DeterministicZip;
+use std::path::Path;
+
+use crate::commands::json_output::{BundleEntry, BundleManifest, VariationsManifest};
+
+/// Archive path of the manifest inside a bundle.
+pub const BUNDLE_MANIFEST_NAME: &str = "bundle.json";
+
+/// Generated files collected for a bundle, keyed by archive path.
+#[derive(Debug, Default)]
+pub struct Bundle {
+    files: DeterministicZip,
+}
+
+impl Bundle {
+    /// Creates an empty bundle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads `out_root/rel_path` into the bundle.
+    ///
+    /// Files are read eagerly so later stages that move outputs (such as
+    /// variation renames) don't affect what was already collected.
+    pub fn add_file(&mut self, out_root: &Path, rel_path: &Path) -> Result<()> {
+        let name = archive_name(rel_path);
+        if name == BUNDLE_MANIFEST_NAME {
+            anyhow::bail!("Output path is reserved for the bundle manifest: {}", name);
+        }
+        let path = out_root.join(rel_path);
+        let data = std::fs::read(&path)
+            .with_context(|| format!("Failed to read output for bundle: {}", path.display()))?;
+        self.files.add_file(name, data)?;
+        Ok(())
+    }
+
+    /// Adds the variations that passed and the `variations.json` manifest.
+    pub fn add_variations(&mut self, out_root: &Path, manifest: &VariationsManifest) -> Result<()> {
+        for path in manifest.variations.iter().filter_map(|v| v.path.as_deref()) {
+            self.add_file(out_root, Path::new(path))?;
+        }
+        self.add_file(out_root, Path::new("variations.json"))
+    }
+
+    /// Builds the manifest listing every collected file and its hash.
+    pub fn manifest(&self, asset_id: &str) -> BundleManifest {
+        BundleManifest {
+            asset_id: asset_id.to_string(),
+            files: self
+                .files
+                .files()
+                .map(|(path, data)| BundleEntry {
+                    path: path.to_string(),
+                    hash: blake3::hash(data).to_hex().to_string(),
+                    size: data.len() as u64,
+                })
+                .collect(),
+        }
+    }
+
+    /// Encodes the collected files and their manifest as a zip archive.
+    pub fn to_zip(&self, asset_id: &str) -> Result<Vec<u8>> {
+        let manifest = serde_json::to_vec_pretty(&self.manifest(asset_id))
+            .context("Failed to serialize bundle manifest")?;
+
+        let mut zip = self.files.clone();
+        zip.add_file(BUNDLE_MANIFEST_NAME, manifest)?;
+        Ok(zip.to_bytes()?)
+    }
+
+    /// Writes the bundle archive to `path`, creating parent directories.
+    pub fn write(&self, asset_id: &str, path: &Path) -> Result<()> {
+        let zip = self.to_zip(asset_id)?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, zip)
+            .with_context(|| format!("Failed to write bundle: {}", path.display()))
+    }
+}
+
+/// Converts a relative output path to a forward-slash archive name.
+fn archive_name(rel_path: &Path) -> String {
+    rel_path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
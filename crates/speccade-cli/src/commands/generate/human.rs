@@ -2,17 +2,19 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
+use speccade_spec::report::LintReportData;
 use speccade_spec::{
-    canonical_recipe_hash, canonical_spec_hash, derive_variant_spec_seed,
-    validate_for_generate_with_budget, BackendError, BudgetProfile, OutputFormat, ReportBuilder,
-    ReportError,
+    canonical_recipe_hash, canonical_spec_hash, validate_for_generate_with_budget, BackendError,
+    BudgetProfile, OutputFormat, ReportBuilder, ReportError,
 };
 use std::path::Path;
 use std::process::ExitCode;
 use std::time::Instant;
 
+use super::bundle::Bundle;
+use super::lint_gate::LintGate;
 use super::quality::QualityConstraints;
-use super::variations::{generate_variations_human, write_manifest};
+use super::variations::{generate_variations_human, write_manifest, VariationSeedMode};
 use crate::cache::{CacheKey, CacheManager};
 use crate::commands::reporting;
 use crate::dispatch::{dispatch_generate, dispatch_generate_profiled};
@@ -29,8 +31,12 @@ pub fn run_human(
     no_cache: bool,
     profile: bool,
     variations: Option<u32>,
+    variation_seeds: VariationSeedMode,
     constraints: Option<QualityConstraints>,
     save_blend: bool,
+    bundle_path: Option<&str>,
+    lint_gate: Option<LintGate>,
+    seed_override: Option<u32>,
 ) -> Result<ExitCode> {
     let start = Instant::now();
     let out_root = out_root.unwrap_or(".");
@@ -60,6 +66,14 @@ pub fn run_human(
     if profile {
         println!("{} enabled", "Profile:".cyan().bold());
     }
+    if let Some(seed) = seed_override {
+        println!("{} {}", "Seed override:".cyan().bold(), seed);
+    }
+    match lint_gate {
+        Some(LintGate::Errors) => println!("{} errors", "Lint gate:".cyan().bold()),
+        Some(LintGate::Strict) => println!("{} errors and warnings", "Lint gate:".cyan().bold()),
+        None => {}
+    }
     if let Some(n) = variations {
         println!("{} {}", "Variations:".cyan().bold(), n);
         if let Some(ref c) = constraints {
@@ -81,6 +95,11 @@ pub fn run_human(
     } = load_spec(Path::new(spec_path))
         .with_context(|| format!("Failed to load spec file: {}", spec_path))?;
 
+    // Replace the spec seed before hashing so the report reflects the override
+    if let Some(seed) = seed_override {
+        spec.seed = seed;
+    }
+
     // Inject save_blend into recipe params if --save-blend flag is set
     if save_blend {
         if let Some(ref mut recipe) = spec.recipe {
@@ -269,6 +288,8 @@ pub fn run_human(
     let base_duration_ms = base_gen_start.elapsed().as_millis() as u64;
 
     let mut any_generation_failed = false;
+    let mut lint_failures = 0;
+    let mut bundle = bundle_path.map(|_| Bundle::new());
 
     match base_result {
         Ok(dispatch_result) => {
@@ -309,14 +330,21 @@ pub fn run_human(
             }
 
             // Run lint on generated outputs
-            if let Some(lint_data) = reporting::run_lint_on_outputs(&outputs, &spec, out_root, true)
-            {
+            let lint_data = reporting::run_lint_on_outputs(&outputs, &spec, out_root, true);
+            lint_failures += lint_gate_failures(lint_gate, lint_data.as_ref());
+            if let Some(lint_data) = lint_data {
                 report_builder = report_builder.lint(lint_data);
             }
 
             let report = report_builder.ok(true).build();
             reporting::write_report(&report, &base_report_path)?;
 
+            if let Some(ref mut bundle) = bundle {
+                for output in &outputs {
+                    bundle.add_file(Path::new(out_root), &output.path)?;
+                }
+            }
+
             let status = if cache_hit {
                 format!("{} (cache)", "SUCCESS".green().bold())
             } else {
@@ -378,9 +406,7 @@ pub fn run_human(
                 let variant_report_path =
                     reporting::report_path_variant(spec_path, &spec.asset_id, variant_id);
 
-                let mut variant_spec = spec.clone();
-                variant_spec.seed =
-                    derive_variant_spec_seed(spec.seed, variant.seed_offset, variant_id);
+                let variant_spec = spec.for_variant(variant)?;
 
                 let variant_spec_hash =
                     canonical_spec_hash(&variant_spec).unwrap_or_else(|_| "unknown".to_string());
@@ -397,6 +423,15 @@ pub fn run_human(
                 match variant_result {
                     Ok(outputs) => {
                         let output_count = outputs.len();
+                        if let Some(ref mut bundle) = bundle {
+                            let variant_dir = Path::new("variants").join(variant_id);
+                            for output in &outputs {
+                                bundle.add_file(
+                                    Path::new(out_root),
+                                    &variant_dir.join(&output.path),
+                                )?;
+                            }
+                        }
                         let mut report_builder = with_provenance(ReportBuilder::new(
                             variant_spec_hash,
                             backend_version.clone(),
@@ -413,8 +448,22 @@ pub fn run_human(
                             &validation_result,
                         );
 
-                        for output in outputs {
-                            report_builder = report_builder.output(output);
+                        for output in &outputs {
+                            report_builder = report_builder.output(output.clone());
+                        }
+
+                        // Variant outputs are only linted when a lint gate is requested.
+                        if lint_gate.is_some() {
+                            let lint_data = reporting::run_lint_on_outputs(
+                                &outputs,
+                                &variant_spec,
+                                &variant_out_root,
+                                true,
+                            );
+                            lint_failures += lint_gate_failures(lint_gate, lint_data.as_ref());
+                            if let Some(lint_data) = lint_data {
+                                report_builder = report_builder.lint(lint_data);
+                            }
                         }
 
                         let report = report_builder.ok(true).build();
@@ -481,6 +530,7 @@ pub fn run_human(
                 out_root,
                 Path::new(spec_path),
                 num_variations,
+                variation_seeds,
                 preview_duration,
                 constraints.as_ref(),
             );
@@ -504,6 +554,9 @@ pub fn run_human(
                     "Manifest written to:".dimmed(),
                     Path::new(out_root).join("variations.json").display()
                 );
+                if let Some(ref mut bundle) = bundle {
+                    bundle.add_variations(Path::new(out_root), &result.manifest)?;
+                }
             }
 
             if result.any_failed {
@@ -512,13 +565,34 @@ pub fn run_human(
         }
     }
 
+    if let (Some(bundle), Some(path)) = (bundle.as_ref(), bundle_path) {
+        bundle.write(&spec.asset_id, Path::new(path))?;
+        println!("{} {}", "Bundle written to:".dimmed(), path);
+    }
+
     if any_generation_failed {
         Ok(ExitCode::from(2))
+    } else if let Some(gate) = lint_gate.filter(|_| lint_failures > 0) {
+        println!(
+            "\n{} {} lint issue(s) rejected by {}",
+            "LINT GATE FAILED".red().bold(),
+            lint_failures,
+            gate.flag()
+        );
+        Ok(ExitCode::from(3))
     } else {
         Ok(ExitCode::SUCCESS)
     }
 }
 
+/// Counts the issues in `lint` rejected by `gate`.
+fn lint_gate_failures(gate: Option<LintGate>, lint: Option<&LintReportData>) -> usize {
+    match (gate, lint) {
+        (Some(gate), Some(lint)) => gate.failing_issues(lint).count(),
+        _ => 0,
+    }
+}
+
 /// Print validation errors to the console.
 fn print_validation_errors(result: &speccade_spec::ValidationResult) {
     if !result.errors.is_empty() {
@@ -1,17 +1,19 @@
 //! JSON output mode for the generate command.
 
 use anyhow::Result;
+use speccade_spec::report::LintReportData;
 use speccade_spec::{
-    canonical_recipe_hash, canonical_spec_hash, derive_variant_spec_seed,
-    validate_for_generate_with_budget, BackendError, BudgetProfile, OutputFormat, ReportBuilder,
-    ReportError,
+    canonical_recipe_hash, canonical_spec_hash, validate_for_generate_with_budget, BackendError,
+    BudgetProfile, OutputFormat, ReportBuilder, ReportError,
 };
 use std::path::Path;
 use std::process::ExitCode;
 use std::time::Instant;
 
+use super::bundle::Bundle;
+use super::lint_gate::LintGate;
 use super::quality::QualityConstraints;
-use super::variations::{generate_variations_json, write_manifest};
+use super::variations::{generate_variations_json, write_manifest, VariationSeedMode};
 use crate::cache::{CacheKey, CacheManager};
 use crate::commands::json_output::{
     compile_warnings_to_json, error_codes, input_error_to_json, validation_error_to_json,
@@ -33,8 +35,12 @@ pub fn run_json(
     no_cache: bool,
     profile: bool,
     variations: Option<u32>,
+    variation_seeds: VariationSeedMode,
     constraints: Option<QualityConstraints>,
     save_blend: bool,
+    bundle_path: Option<&str>,
+    lint_gate: Option<LintGate>,
+    seed_override: Option<u32>,
 ) -> Result<ExitCode> {
     let start = Instant::now();
     let out_root_str = out_root.unwrap_or(".");
@@ -81,6 +87,11 @@ pub fn run_json(
         }
     };
 
+    // Replace the spec seed before hashing so the report reflects the override
+    if let Some(seed) = seed_override {
+        spec.seed = seed;
+    }
+
     // Inject save_blend into recipe params if --save-blend flag is set
     if save_blend {
         if let Some(ref mut recipe) = spec.recipe {
@@ -249,15 +260,23 @@ pub fn run_json(
             }
 
             // Run lint on generated outputs (no text printing in JSON mode)
-            if let Some(lint_data) =
-                reporting::run_lint_on_outputs(&outputs, &spec, out_root_str, false)
-            {
+            let mut lint_errors = Vec::new();
+            let lint_data = reporting::run_lint_on_outputs(&outputs, &spec, out_root_str, false);
+            push_lint_gate_errors(lint_gate, lint_data.as_ref(), None, &mut lint_errors);
+            if let Some(lint_data) = lint_data {
                 report_builder = report_builder.lint(lint_data);
             }
 
             let report = report_builder.ok(true).build();
             reporting::write_report(&report, &base_report_path)?;
 
+            let mut bundle = bundle_path.map(|_| Bundle::new());
+            if let Some(ref mut bundle) = bundle {
+                for output in &outputs {
+                    bundle.add_file(Path::new(out_root_str), &output.path)?;
+                }
+            }
+
             // Convert outputs to GeneratedFile
             let generated_files: Vec<GeneratedFile> = outputs
                 .iter()
@@ -282,6 +301,9 @@ pub fn run_json(
                 &backend_version,
                 &validation_result,
                 &with_provenance,
+                &mut bundle,
+                lint_gate,
+                &mut lint_errors,
             )?;
 
             // Batch variation generation (JSON mode)
@@ -295,12 +317,17 @@ pub fn run_json(
                         out_root_str,
                         Path::new(spec_path),
                         num_variations,
+                        variation_seeds,
                         preview_duration,
                         constraints.as_ref(),
                     );
 
                     // Write manifest file
-                    let _ = write_manifest(&result.manifest, Path::new(out_root_str));
+                    let manifest_written =
+                        write_manifest(&result.manifest, Path::new(out_root_str)).is_ok();
+                    if let Some(bundle) = bundle.as_mut().filter(|_| manifest_written) {
+                        bundle.add_variations(Path::new(out_root_str), &result.manifest)?;
+                    }
 
                     if result.any_failed {
                         any_variation_failed = true;
@@ -308,6 +335,10 @@ pub fn run_json(
                 }
             }
 
+            if let (Some(bundle), Some(path)) = (bundle.as_ref(), bundle_path) {
+                bundle.write(&spec.asset_id, Path::new(path))?;
+            }
+
             let total_duration_ms = start.elapsed().as_millis() as u64;
 
             let result = GenerateResult {
@@ -322,9 +353,15 @@ pub fn run_json(
                 report_path: base_report_path,
                 duration_ms: total_duration_ms,
                 variants: variant_results,
+                bundle: bundle_path.map(|s| s.to_string()),
             };
 
-            let output = GenerateOutput::success(result, spec_hash, source_hash, all_warnings);
+            let mut output = GenerateOutput::success(result, spec_hash, source_hash, all_warnings);
+            let lint_failed = !lint_errors.is_empty();
+            if lint_failed {
+                output.success = false;
+                output.errors = lint_errors;
+            }
 
             let json = serde_json::to_string_pretty(&output)
                 .expect("GenerateOutput serialization should not fail");
@@ -332,6 +369,8 @@ pub fn run_json(
 
             if any_variant_failed || any_variation_failed {
                 Ok(ExitCode::from(2))
+            } else if lint_failed {
+                Ok(ExitCode::from(3))
             } else {
                 Ok(ExitCode::SUCCESS)
             }
@@ -384,6 +423,9 @@ fn generate_variants_json<F>(
     backend_version: &str,
     validation_result: &speccade_spec::ValidationResult,
     with_provenance: &F,
+    bundle: &mut Option<Bundle>,
+    lint_gate: Option<LintGate>,
+    lint_errors: &mut Vec<JsonError>,
 ) -> Result<(Vec<VariantResult>, bool)>
 where
     F: Fn(ReportBuilder) -> ReportBuilder,
@@ -406,9 +448,7 @@ where
             let variant_report_path =
                 reporting::report_path_variant(spec_path, &spec.asset_id, variant_id);
 
-            let mut variant_spec = spec.clone();
-            variant_spec.seed =
-                derive_variant_spec_seed(spec.seed, variant.seed_offset, variant_id);
+            let variant_spec = spec.for_variant(variant)?;
 
             let variant_spec_hash =
                 canonical_spec_hash(&variant_spec).unwrap_or_else(|_| "unknown".to_string());
@@ -424,6 +464,16 @@ where
 
             match variant_result {
                 Ok(variant_outputs) => {
+                    if let Some(bundle) = bundle.as_mut() {
+                        let variant_dir = Path::new("variants").join(variant_id);
+                        for output in &variant_outputs {
+                            bundle.add_file(
+                                Path::new(out_root_str),
+                                &variant_dir.join(&output.path),
+                            )?;
+                        }
+                    }
+
                     let mut report_builder = with_provenance(ReportBuilder::new(
                         variant_spec_hash.clone(),
                         backend_version.to_string(),
@@ -442,6 +492,25 @@ where
                         report_builder = report_builder.output(output.clone());
                     }
 
+                    // Variant outputs are only linted when a lint gate is requested.
+                    if lint_gate.is_some() {
+                        let lint_data = reporting::run_lint_on_outputs(
+                            &variant_outputs,
+                            &variant_spec,
+                            &variant_out_root,
+                            false,
+                        );
+                        push_lint_gate_errors(
+                            lint_gate,
+                            lint_data.as_ref(),
+                            Some(variant_id),
+                            lint_errors,
+                        );
+                        if let Some(lint_data) = lint_data {
+                            report_builder = report_builder.lint(lint_data);
+                        }
+                    }
+
                     let report = report_builder.ok(true).build();
                     reporting::write_report(&report, &variant_report_path)?;
 
@@ -507,3 +576,30 @@ where
 
     Ok((variant_results, any_variant_failed))
 }
+
+/// Appends a JSON error for every issue in `lint` rejected by `gate`.
+fn push_lint_gate_errors(
+    gate: Option<LintGate>,
+    lint: Option<&LintReportData>,
+    variant_id: Option<&str>,
+    errors: &mut Vec<JsonError>,
+) {
+    let (Some(gate), Some(lint)) = (gate, lint) else {
+        return;
+    };
+    for issue in gate.failing_issues(lint) {
+        let scope = variant_id
+            .map(|id| format!("variant '{}': ", id))
+            .unwrap_or_default();
+        errors.push(JsonError::new(
+            error_codes::LINT_GATE,
+            format!(
+                "{}[{}] {} (rejected by {})",
+                scope,
+                issue.rule_id,
+                issue.message,
+                gate.flag()
+            ),
+        ));
+    }
+}
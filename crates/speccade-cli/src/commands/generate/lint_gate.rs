@@ -0,0 +1,50 @@
+//! Lint gating for generated outputs (`generate --lint` / `--lint-strict`).
+
+use speccade_spec::report::{LintIssueData, LintReportData};
+
+/// Which lint findings fail a generate run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintGate {
+    /// Fail on lint errors.
+    Errors,
+    /// Fail on lint errors or warnings.
+    Strict,
+}
+
+impl LintGate {
+    /// Creates a lint gate from CLI flags (`--lint-strict` implies `--lint`).
+    pub fn from_flags(lint: bool, lint_strict: bool) -> Option<Self> {
+        if lint_strict {
+            Some(Self::Strict)
+        } else if lint {
+            Some(Self::Errors)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the CLI flag that selects this gate.
+    pub fn flag(&self) -> &'static str {
+        match self {
+            Self::Errors => "--lint",
+            Self::Strict => "--lint-strict",
+        }
+    }
+
+    /// Returns the issues in `report` that fail this gate.
+    pub fn failing_issues<'a>(
+        &self,
+        report: &'a LintReportData,
+    ) -> impl Iterator<Item = &'a LintIssueData> {
+        let warnings: &[LintIssueData] = match self {
+            Self::Errors => &[],
+            Self::Strict => &report.warnings,
+        };
+        report.errors.iter().chain(warnings)
+    }
+
+    /// Returns true if `report` passes this gate.
+    pub fn passes(&self, report: &LintReportData) -> bool {
+        self.failing_issues(report).next().is_none()
+    }
+}
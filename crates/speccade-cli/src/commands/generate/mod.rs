@@ -2,8 +2,10 @@
 //!
 //! Generates assets from a spec file using the appropriate backend.
 
+mod bundle;
 mod human;
 mod json;
+mod lint_gate;
 pub mod quality;
 mod variations;
 
@@ -13,7 +15,19 @@ mod tests;
 use anyhow::Result;
 use std::process::ExitCode;
 
+pub use lint_gate::LintGate;
 pub use quality::QualityConstraints;
+pub use variations::VariationSeedMode;
+
+/// Enables the cross-process texture graph node cache for this process.
+///
+/// Evaluated `texture.procedural_v1` nodes are stored under the content cache
+/// and reused by later runs whose upstream graph is unchanged.
+pub fn enable_node_cache() -> Result<()> {
+    let cache = crate::cache::CacheManager::new()?;
+    crate::dispatch::enable_node_cache(cache.node_cache_dir());
+    Ok(())
+}
 
 /// Run the generate command
 ///
@@ -27,12 +41,16 @@ pub use quality::QualityConstraints;
 /// * `no_cache` - Whether to bypass cache (default: false, cache enabled)
 /// * `profile` - Whether to include per-stage timing in the report
 /// * `variations` - Optional number of SFX variations to generate
+/// * `variation_seeds` - How variation seeds are derived from the spec seed
 /// * `max_peak_db` - Optional maximum peak level in dB for variation quality gating
 /// * `max_dc_offset` - Optional maximum DC offset for variation quality gating
 /// * `save_blend` - Force saving .blend files alongside GLB output
+/// * `bundle` - Optional path of a zip archive to collect every generated output into
+/// * `lint_gate` - Optional lint gate; lint findings it rejects fail the run
+/// * `seed` - Optional seed that replaces the spec seed for this run
 ///
 /// # Returns
-/// Exit code: 0 success, 1 spec error, 2 generation error
+/// Exit code: 0 success, 1 spec error, 2 generation error, 3 lint gate failure
 #[allow(clippy::too_many_arguments)]
 pub fn run(
     spec_path: &str,
@@ -44,9 +62,13 @@ pub fn run(
     no_cache: bool,
     profile: bool,
     variations: Option<u32>,
+    variation_seeds: VariationSeedMode,
     max_peak_db: Option<f64>,
     max_dc_offset: Option<f64>,
     save_blend: bool,
+    bundle: Option<&str>,
+    lint_gate: Option<LintGate>,
+    seed: Option<u32>,
 ) -> Result<ExitCode> {
     let constraints = QualityConstraints::from_options(max_peak_db, max_dc_offset);
 
@@ -60,8 +82,12 @@ pub fn run(
             no_cache,
             profile,
             variations,
+            variation_seeds,
             constraints,
             save_blend,
+            bundle,
+            lint_gate,
+            seed,
         )
     } else {
         human::run_human(
@@ -73,8 +99,12 @@ pub fn run(
             no_cache,
             profile,
             variations,
+            variation_seeds,
             constraints,
             save_blend,
+            bundle,
+            lint_gate,
+            seed,
         )
     }
 }
@@ -1,10 +1,16 @@
 //! Tests for the generate command.
 
-use super::run;
-use crate::commands::json_output::VariationsManifest;
+use super::bundle::BUNDLE_MANIFEST_NAME;
+use super::{run, LintGate, VariationSeedMode};
+use crate::analysis::audio::extract_wav_samples;
+use crate::commands::json_output::{BundleManifest, VariationsManifest};
 use crate::commands::reporting;
-use speccade_spec::recipe::audio::{AudioLayer, AudioV1Params, Envelope, Synthesis, Waveform};
+use speccade_spec::hash::derive_variant_seed;
+use speccade_spec::recipe::audio::{
+    AudioLayer, AudioV1Params, ChannelMode, Envelope, NoiseType, Synthesis, Waveform,
+};
 use speccade_spec::{AssetType, OutputFormat, OutputSpec, Recipe, Spec, VariantSpec};
+use std::collections::BTreeSet;
 use std::process::ExitCode;
 
 fn write_spec(dir: &tempfile::TempDir, filename: &str, spec: &Spec) -> std::path::PathBuf {
@@ -36,9 +42,13 @@ fn generate_rejects_missing_recipe_and_writes_report() {
         false,
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::from(1));
@@ -81,9 +91,13 @@ fn generate_reports_validation_errors_for_invalid_params() {
         false,
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::from(1));
@@ -111,12 +125,22 @@ fn generate_audio_v1_writes_output_and_report() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -125,6 +149,11 @@ fn generate_audio_v1_writes_output_and_report() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = Spec::builder("test-asset-03", AssetType::Audio)
@@ -151,9 +180,13 @@ fn generate_audio_v1_writes_output_and_report() {
         true, // no_cache to ensure fresh generation
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::SUCCESS);
@@ -185,12 +218,22 @@ fn generate_expands_variants_into_separate_output_roots_and_reports() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -199,6 +242,11 @@ fn generate_expands_variants_into_separate_output_roots_and_reports() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = Spec::builder("test-variants-01", AssetType::Audio)
@@ -228,9 +276,13 @@ fn generate_expands_variants_into_separate_output_roots_and_reports() {
         false,
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::SUCCESS);
@@ -296,12 +348,22 @@ fn generate_json_output_success() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.8,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -310,6 +372,11 @@ fn generate_json_output_success() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = Spec::builder("gen-json-test-01", AssetType::Audio)
@@ -336,9 +403,13 @@ fn generate_json_output_success() {
         false,
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::SUCCESS);
@@ -368,9 +439,13 @@ fn generate_json_output_validation_failure() {
         false,
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::from(1));
@@ -389,9 +464,13 @@ fn generate_json_output_file_not_found() {
         false,
         false,
         None,
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::from(1));
@@ -412,12 +491,22 @@ fn generate_variations_creates_manifest_and_outputs() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.5,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -426,6 +515,11 @@ fn generate_variations_creates_manifest_and_outputs() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = Spec::builder("test-variations-01", AssetType::Audio)
@@ -451,9 +545,13 @@ fn generate_variations_creates_manifest_and_outputs() {
         false,
         false,
         Some(3), // Generate 3 variations
+        VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::SUCCESS);
@@ -504,12 +602,22 @@ fn generate_variations_with_peak_constraint_records_constraint() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 0.1, // Very low volume to ensure all pass
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -518,6 +626,11 @@ fn generate_variations_with_peak_constraint_records_constraint() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let spec = Spec::builder("test-variations-peak", AssetType::Audio)
@@ -543,9 +656,13 @@ fn generate_variations_with_peak_constraint_records_constraint() {
         true, // no_cache to force fresh generation
         false,
         Some(2),
+        VariationSeedMode::Increment,
         Some(-3.0), // Generous threshold for low-volume audio
         None,
         false,
+        None,
+        None,
+        None,
     )
     .unwrap();
     assert_eq!(code, ExitCode::SUCCESS);
@@ -566,3 +683,537 @@ fn generate_variations_with_peak_constraint_records_constraint() {
     assert_eq!(manifest.total, 2);
     assert_eq!(manifest.passed, 2);
 }
+
+/// Generates `count` variations of a noise burst and returns the manifest and
+/// the samples of each variation, in index order.
+fn generate_noise_variations(
+    seed_mode: VariationSeedMode,
+    seed: u32,
+    count: u32,
+) -> (VariationsManifest, Vec<Vec<f32>>) {
+    let tmp = tempfile::tempdir().unwrap();
+
+    let mut spec = noise_burst_spec();
+    spec.seed = seed;
+    let spec_path = write_spec(&tmp, "spec.json", &spec);
+    let out_root = tmp.path().to_str().unwrap();
+    let code = run(
+        spec_path.to_str().unwrap(),
+        Some(out_root),
+        false,
+        None,
+        false,
+        None,
+        true,
+        false,
+        Some(count),
+        seed_mode,
+        None,
+        None,
+        false,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(code, ExitCode::SUCCESS);
+
+    let manifest_json = std::fs::read_to_string(tmp.path().join("variations.json")).unwrap();
+    let manifest: VariationsManifest = serde_json::from_str(&manifest_json).unwrap();
+    let samples = (0..count)
+        .map(|i| {
+            let wav = std::fs::read(
+                tmp.path()
+                    .join(format!("test_variations_noise_var_{}.wav", i)),
+            )
+            .unwrap();
+            extract_wav_samples(&wav).unwrap().0
+        })
+        .collect();
+    (manifest, samples)
+}
+
+/// A short white noise burst, seeded with 42.
+fn noise_burst_spec() -> Spec {
+    let params = AudioV1Params {
+        base_note: None,
+        duration_seconds: 0.1,
+        sample_rate: 22050,
+        layers: vec![AudioLayer {
+            synthesis: Synthesis::NoiseBurst {
+                noise_type: NoiseType::White,
+                filter: None,
+            },
+            envelope: Envelope::default().into(),
+            volume: 0.5,
+            pan: 0.0,
+            delay: None,
+            filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
+            lfo: None,
+        }],
+        pitch_envelope: None,
+        loop_config: None,
+        generate_loop_points: false,
+        master_filter: None,
+        effects: vec![],
+        post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
+    };
+
+    Spec::builder("test-variations-noise", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::to_value(&params).unwrap(),
+        ))
+        .build()
+}
+
+/// Mean RMS difference over every pair of variations.
+fn average_pairwise_difference(variations: &[Vec<f32>]) -> f64 {
+    let mut total = 0.0;
+    let mut pairs = 0;
+    for (i, a) in variations.iter().enumerate() {
+        for b in &variations[i + 1..] {
+            let sum_sq: f64 = a
+                .iter()
+                .zip(b)
+                .map(|(x, y)| (*x as f64 - *y as f64).powi(2))
+                .sum();
+            total += (sum_sq / a.len() as f64).sqrt();
+            pairs += 1;
+        }
+    }
+    total / pairs as f64
+}
+
+#[test]
+fn generate_variations_hashed_seeds_are_deterministic_and_decorrelated() {
+    let (increment, increment_samples) =
+        generate_noise_variations(VariationSeedMode::Increment, 42, 4);
+    let (hashed, hashed_samples) = generate_noise_variations(VariationSeedMode::Hashed, 42, 4);
+    let (hashed_again, _) = generate_noise_variations(VariationSeedMode::Hashed, 42, 4);
+
+    assert_eq!(increment.seed_mode, "increment");
+    assert_eq!(hashed.seed_mode, "hashed");
+    for (i, entry) in hashed.variations.iter().enumerate() {
+        assert_eq!(entry.seed, derive_variant_seed(42, &format!("var_{}", i)));
+        assert_eq!(entry.hash, hashed_again.variations[i].hash);
+    }
+
+    // Variations of neighboring base seeds, as produced when sweeping seeds.
+    // Incremented seeds overlap (42..=45 and 43..=46 share three), so the pooled
+    // set contains identical pairs; hashed seeds never do.
+    let pooled = |mode: VariationSeedMode, samples: Vec<Vec<f32>>| {
+        let (_, neighbor) = generate_noise_variations(mode, 43, 4);
+        samples.into_iter().chain(neighbor).collect::<Vec<_>>()
+    };
+    let increment_diff =
+        average_pairwise_difference(&pooled(VariationSeedMode::Increment, increment_samples));
+    let hashed_diff =
+        average_pairwise_difference(&pooled(VariationSeedMode::Hashed, hashed_samples));
+    assert!(increment_diff > 0.05, "increment diff {}", increment_diff);
+    assert!(
+        hashed_diff > increment_diff + 0.03,
+        "hashed diff {} vs increment diff {}",
+        hashed_diff,
+        increment_diff
+    );
+
+    // Incremented seeds collide between neighboring base seeds; hashed ones do not.
+    let seeds = |mode: VariationSeedMode, base: u32| -> BTreeSet<u32> {
+        (0..4).map(|i| mode.variation_seed(base, i)).collect()
+    };
+    let inc = VariationSeedMode::Increment;
+    let hsh = VariationSeedMode::Hashed;
+    assert!(!seeds(inc, 42).is_disjoint(&seeds(inc, 43)));
+    assert!(seeds(hsh, 42).is_disjoint(&seeds(hsh, 43)));
+}
+
+/// Reads the stored entries of a bundle archive, in archive order.
+fn read_bundle_entries(zip: &[u8]) -> Vec<(String, Vec<u8>)> {
+    let u16_at = |i: usize| u16::from_le_bytes([zip[i], zip[i + 1]]) as usize;
+    let u32_at = |i: usize| u32::from_le_bytes(zip[i..i + 4].try_into().unwrap()) as usize;
+
+    let mut entries = Vec::new();
+    let mut pos = 0;
+    while u32_at(pos) == 0x0403_4b50 {
+        assert_eq!(u16_at(pos + 8), 0, "bundle entries are stored");
+        let size = u32_at(pos + 22);
+        let name_len = u16_at(pos + 26);
+        let extra_len = u16_at(pos + 28);
+        let name_start = pos + 30;
+        let data_start = name_start + name_len + extra_len;
+        let name = String::from_utf8(zip[name_start..name_start + name_len].to_vec()).unwrap();
+        entries.push((name, zip[data_start..data_start + size].to_vec()));
+        pos = data_start + size;
+    }
+    entries
+}
+
+/// Generates a two-variant audio spec with two variations into a fresh output
+/// root and returns the bytes of its bundle.
+fn generate_bundle() -> Vec<u8> {
+    let tmp = tempfile::tempdir().unwrap();
+
+    let params = AudioV1Params {
+        base_note: None,
+        duration_seconds: 0.05,
+        sample_rate: 22050,
+        layers: vec![AudioLayer {
+            synthesis: Synthesis::NoiseBurst {
+                noise_type: NoiseType::White,
+                filter: None,
+            },
+            envelope: Envelope::default().into(),
+            volume: 0.5,
+            pan: 0.0,
+            delay: None,
+            filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
+            lfo: None,
+        }],
+        pitch_envelope: None,
+        loop_config: None,
+        generate_loop_points: false,
+        master_filter: None,
+        effects: vec![],
+        post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
+    };
+
+    let spec = Spec::builder("test-bundle-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::to_value(&params).unwrap(),
+        ))
+        .variants(vec![
+            VariantSpec::new("soft", 0),
+            VariantSpec::new("hard", 1),
+        ])
+        .build();
+
+    let spec_path = write_spec(&tmp, "spec.json", &spec);
+    let out_root = tmp.path().join("out");
+    let bundle_path = tmp.path().join("bundle").join("test.zip");
+    let code = run(
+        spec_path.to_str().unwrap(),
+        Some(out_root.to_str().unwrap()),
+        true,
+        None,
+        false,
+        None,
+        true,
+        false,
+        Some(2),
+        VariationSeedMode::Increment,
+        None,
+        None,
+        false,
+        Some(bundle_path.to_str().unwrap()),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(code, ExitCode::SUCCESS);
+
+    std::fs::read(&bundle_path).unwrap()
+}
+
+#[test]
+fn generate_bundle_is_byte_identical_and_lists_every_output() {
+    let first = generate_bundle();
+    let second = generate_bundle();
+    assert!(first == second, "bundle bytes differ between runs");
+
+    let (files, manifest): (Vec<_>, Vec<_>) = read_bundle_entries(&first)
+        .into_iter()
+        .partition(|(name, _)| name != BUNDLE_MANIFEST_NAME);
+    assert_eq!(manifest.len(), 1);
+    let manifest: BundleManifest = serde_json::from_slice(&manifest[0].1).unwrap();
+    assert_eq!(manifest.asset_id, "test-bundle-01");
+
+    let expected: BTreeSet<&str> = [
+        "test.wav",
+        "test_bundle_01_var_0.wav",
+        "test_bundle_01_var_1.wav",
+        "test_preview.waveform.png",
+        "variants/hard/test.wav",
+        "variants/hard/test_preview.waveform.png",
+        "variants/soft/test.wav",
+        "variants/soft/test_preview.waveform.png",
+        "variations.json",
+    ]
+    .into_iter()
+    .collect();
+    let listed: BTreeSet<&str> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(listed, expected);
+
+    // Every other archive entry is listed, in order, with its hash and size
+    assert_eq!(files.len(), manifest.files.len());
+    for ((name, data), entry) in files.iter().zip(&manifest.files) {
+        assert_eq!(name, &entry.path);
+        assert_eq!(entry.size, data.len() as u64);
+        assert_eq!(entry.hash, blake3::hash(data).to_hex().to_string());
+    }
+}
+
+/// Writes an audio spec with a single oscillator layer and runs generate with
+/// the given lint gate.
+fn generate_with_lint_gate(
+    waveform: Waveform,
+    sustain: f64,
+    lint_gate: Option<LintGate>,
+) -> (ExitCode, speccade_spec::Report) {
+    let tmp = tempfile::tempdir().unwrap();
+
+    let params = AudioV1Params {
+        base_note: None,
+        duration_seconds: 0.2,
+        sample_rate: 22050,
+        layers: vec![AudioLayer {
+            synthesis: Synthesis::Oscillator {
+                waveform,
+                frequency: 220.0,
+                freq_sweep: None,
+                detune: None,
+                duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
+            },
+            // With no sustain the tone decays to silence before the end.
+            envelope: Envelope {
+                attack: 0.01,
+                decay: 0.15,
+                sustain,
+                release: 0.02,
+            }
+            .into(),
+            volume: 1.0,
+            pan: 0.0,
+            delay: None,
+            filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
+            lfo: None,
+        }],
+        pitch_envelope: None,
+        loop_config: None,
+        generate_loop_points: false,
+        master_filter: None,
+        effects: vec![],
+        post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
+    };
+
+    let spec = Spec::builder("test-lint-gate-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::to_value(&params).unwrap(),
+        ))
+        .build();
+
+    let spec_path = write_spec(&tmp, "spec.json", &spec);
+    let code = run(
+        spec_path.to_str().unwrap(),
+        Some(tmp.path().to_str().unwrap()),
+        false,
+        None,
+        false,
+        None,
+        true,
+        false,
+        None,
+        VariationSeedMode::Increment,
+        None,
+        None,
+        false,
+        None,
+        lint_gate,
+        None,
+    )
+    .unwrap();
+
+    let report_path = reporting::report_path(spec_path.to_str().unwrap(), &spec.asset_id);
+    let json = std::fs::read_to_string(&report_path).unwrap();
+    (code, serde_json::from_str(&json).unwrap())
+}
+
+#[test]
+fn lint_strict_flag_implies_lint() {
+    assert_eq!(LintGate::from_flags(false, false), None);
+    assert_eq!(LintGate::from_flags(true, false), Some(LintGate::Errors));
+    assert_eq!(LintGate::from_flags(false, true), Some(LintGate::Strict));
+    assert_eq!(LintGate::from_flags(true, true), Some(LintGate::Strict));
+}
+
+#[test]
+fn generate_lint_strict_passes_clean_output() {
+    let (code, report) = generate_with_lint_gate(Waveform::Sine, 0.0, Some(LintGate::Strict));
+    let lint = report.lint.expect("lint data in report");
+    assert_eq!(
+        code,
+        ExitCode::SUCCESS,
+        "unexpected lint issues: {:?}",
+        lint
+    );
+}
+
+#[test]
+fn generate_lint_strict_fails_on_clipping_output() {
+    let (code, report) = generate_with_lint_gate(Waveform::Square, 1.0, Some(LintGate::Strict));
+    assert_eq!(code, ExitCode::from(3));
+    // The base output still generates and carries the lint findings.
+    assert!(report.ok);
+    assert!(report
+        .lint
+        .is_some_and(|lint| lint.warning_count + lint.error_count > 0));
+}
+
+#[test]
+fn generate_without_lint_gate_ignores_lint_findings() {
+    let (code, report) = generate_with_lint_gate(Waveform::Square, 1.0, None);
+    assert_eq!(code, ExitCode::SUCCESS);
+    assert!(report.lint.is_some_and(|lint| lint.has_issues()));
+}
+
+/// Generates the noise burst with an optional seed override and returns its report.
+fn generate_noise_with_seed(seed: Option<u32>) -> speccade_spec::Report {
+    let tmp = tempfile::tempdir().unwrap();
+    let spec = noise_burst_spec();
+    let spec_path = write_spec(&tmp, "spec.json", &spec);
+
+    let code = run(
+        spec_path.to_str().unwrap(),
+        Some(tmp.path().to_str().unwrap()),
+        false,
+        None,
+        false,
+        None,
+        true,
+        false,
+        None,
+        VariationSeedMode::Increment,
+        None,
+        None,
+        false,
+        None,
+        None,
+        seed,
+    )
+    .unwrap();
+    assert_eq!(code, ExitCode::SUCCESS);
+
+    let report_path = reporting::report_path(spec_path.to_str().unwrap(), &spec.asset_id);
+    serde_json::from_str(&std::fs::read_to_string(&report_path).unwrap()).unwrap()
+}
+
+#[test]
+fn generate_seed_override_replaces_spec_seed() {
+    let default = generate_noise_with_seed(None);
+    let same = generate_noise_with_seed(Some(42));
+    let overridden = generate_noise_with_seed(Some(7));
+
+    assert_eq!(default.seed, Some(42));
+    assert_eq!(overridden.seed, Some(7));
+    assert_eq!(same.spec_hash, default.spec_hash);
+    assert_eq!(same.outputs[0].hash, default.outputs[0].hash);
+    assert_ne!(overridden.spec_hash, default.spec_hash);
+    assert_ne!(overridden.outputs[0].hash, default.outputs[0].hash);
+}
+
+#[cfg(feature = "starlark")]
+#[test]
+fn generate_rejects_starlark_spec_without_seed() {
+    let tmp = tempfile::tempdir().unwrap();
+    let spec_path = tmp.path().join("spec.star");
+    std::fs::write(
+        &spec_path,
+        r#"
+spec(
+    asset_id = "seedless-generate-01",
+    asset_type = "audio",
+    outputs = [output("seedless.wav", "wav")],
+    recipe = {
+        "kind": "audio_v1",
+        "params": {
+            "duration_seconds": 0.1,
+            "sample_rate": 22050,
+            "layers": [
+                audio_layer(
+                    synthesis = oscillator(440, "sine"),
+                    envelope = envelope(0.01, 0.02, 0.5, 0.02)
+                )
+            ]
+        }
+    }
+)
+"#,
+    )
+    .unwrap();
+
+    let err = run(
+        spec_path.to_str().unwrap(),
+        Some(tmp.path().to_str().unwrap()),
+        false,
+        None,
+        false,
+        None,
+        false,
+        false,
+        None,
+        VariationSeedMode::Increment,
+        None,
+        None,
+        false,
+        None,
+        None,
+        None,
+    )
+    .unwrap_err();
+    assert!(
+        format!("{:#}", err).contains("missing field `seed`"),
+        "{:#}",
+        err
+    );
+    assert!(!tmp.path().join("seedless.wav").exists());
+}
@@ -1,6 +1,7 @@
 //! Batch SFX variation generation logic.
 
 use colored::Colorize;
+use speccade_spec::hash::derive_variant_seed;
 use speccade_spec::{OutputFormat, Spec};
 use std::path::Path;
 
@@ -9,6 +10,45 @@ use crate::analysis::audio::analyze_wav;
 use crate::commands::json_output::{VariationEntry, VariationsManifest};
 use crate::dispatch::dispatch_generate;
 
+/// How variation seeds are derived from the spec seed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VariationSeedMode {
+    /// Variation `i` uses `seed + i`. Neighboring base seeds share variations:
+    /// variation 1 of seed 42 is variation 0 of seed 43.
+    #[default]
+    Increment,
+    /// Variation `i` uses `derive_variant_seed(seed, "var_{i}")`, so every
+    /// base seed gets its own independent set of variation seeds.
+    Hashed,
+}
+
+impl VariationSeedMode {
+    /// Parses a seed mode name (`increment` or `hashed`).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "increment" => Some(Self::Increment),
+            "hashed" => Some(Self::Hashed),
+            _ => None,
+        }
+    }
+
+    /// Returns the mode name recorded in the manifest.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Increment => "increment",
+            Self::Hashed => "hashed",
+        }
+    }
+
+    /// Returns the seed for variation `index` of a spec with `base_seed`.
+    pub fn variation_seed(&self, base_seed: u32, index: u32) -> u32 {
+        match self {
+            Self::Increment => base_seed.wrapping_add(index),
+            Self::Hashed => derive_variant_seed(base_seed, &format!("var_{}", index)),
+        }
+    }
+}
+
 /// Result of batch variation generation.
 pub struct VariationBatchResult {
     /// The manifest containing all variation results
@@ -23,17 +63,19 @@ pub fn generate_variations_human(
     out_root: &str,
     spec_path: &Path,
     num_variations: u32,
+    seed_mode: VariationSeedMode,
     preview_duration: Option<f64>,
     constraints: Option<&QualityConstraints>,
 ) -> VariationBatchResult {
     let manifest_constraints = constraints.map(|c| c.to_manifest_constraints());
     let mut manifest =
-        VariationsManifest::new(spec.asset_id.clone(), spec.seed, manifest_constraints);
+        VariationsManifest::new(spec.asset_id.clone(), spec.seed, manifest_constraints)
+            .with_seed_mode(seed_mode.as_str());
 
     let out_root_path = Path::new(out_root);
 
     for i in 0..num_variations {
-        let var_seed = spec.seed.wrapping_add(i);
+        let var_seed = seed_mode.variation_seed(spec.seed, i);
         let var_filename = format!("{}_var_{}.wav", spec.asset_id.replace('-', "_"), i);
         let var_path = out_root_path.join(&var_filename);
 
@@ -196,17 +238,19 @@ pub fn generate_variations_json(
     out_root: &str,
     spec_path: &Path,
     num_variations: u32,
+    seed_mode: VariationSeedMode,
     preview_duration: Option<f64>,
     constraints: Option<&QualityConstraints>,
 ) -> VariationBatchResult {
     let manifest_constraints = constraints.map(|c| c.to_manifest_constraints());
     let mut manifest =
-        VariationsManifest::new(spec.asset_id.clone(), spec.seed, manifest_constraints);
+        VariationsManifest::new(spec.asset_id.clone(), spec.seed, manifest_constraints)
+            .with_seed_mode(seed_mode.as_str());
 
     let out_root_path = Path::new(out_root);
 
     for i in 0..num_variations {
-        let var_seed = spec.seed.wrapping_add(i);
+        let var_seed = seed_mode.variation_seed(spec.seed, i);
         let var_filename = format!("{}_var_{}.wav", spec.asset_id.replace('-', "_"), i);
         let var_path = out_root_path.join(&var_filename);
 
@@ -9,8 +9,12 @@ use serde::{Deserialize, Serialize};
 use speccade_spec::{canonical_spec_hash, validate_for_generate};
 use std::collections::HashMap;
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
 use std::time::Instant;
 use walkdir::WalkDir;
 
@@ -98,6 +102,8 @@ pub struct AssetTypeSummary {
 /// * `out_root` - Output root directory (default: ./test-outputs)
 /// * `include_blender` - Whether to include Blender-based assets
 /// * `verbose` - Whether to show verbose output
+/// * `force` - Whether to regenerate specs that are already fresh
+/// * `jobs` - Number of specs to generate in parallel
 ///
 /// # Returns
 /// Exit code: 0 success, 1 if any specs failed
@@ -107,6 +113,7 @@ pub fn run(
     include_blender: bool,
     verbose: bool,
     force: bool,
+    jobs: usize,
 ) -> Result<ExitCode> {
     let backend_version = format!("speccade-cli v{}", env!("CARGO_PKG_VERSION"));
     let start = Instant::now();
@@ -134,6 +141,7 @@ pub fn run(
         "Include Blender:".blue().bold(),
         if include_blender { "yes" } else { "no" }
     );
+    println!("{} {}", "Jobs:".blue().bold(), jobs.max(1));
     println!();
 
     // Create output directory
@@ -165,13 +173,7 @@ pub fn run(
             .is_some_and(|name| name.to_string_lossy().starts_with('_'));
 
         if is_spec_ext && !is_report && !is_library {
-            // Check if this is a Blender asset type
-            let is_blender = BLENDER_ASSET_TYPES.iter().any(|t| {
-                path.components()
-                    .any(|c| c.as_os_str().to_string_lossy() == *t)
-            });
-
-            if is_blender && !include_blender {
+            if is_blender_spec(path) && !include_blender {
                 skipped_blender.push(path.to_path_buf());
             } else {
                 spec_files.push(path.to_path_buf());
@@ -196,56 +198,24 @@ pub fn run(
     }
     println!();
 
-    // Statistics
-    let mut results: Vec<SpecResult> = Vec::new();
-    let mut success_count = 0;
-    let mut failure_count = 0;
-    let mut fresh_skipped_count = 0;
-
-    // Process each spec
-    for spec_file in &spec_files {
-        let result = process_spec(spec_file, out_path, verbose, force, &backend_version);
-
-        if result.skipped_fresh {
-            fresh_skipped_count += 1;
-            if verbose {
-                println!(
-                    "  {} {} {}",
-                    "SKIPPED".yellow(),
-                    "(fresh)".dimmed(),
-                    result.asset_id
-                );
-            } else {
-                print!("{}", "s".yellow());
-            }
-        } else if result.success {
-            success_count += 1;
-            if verbose {
-                println!(
-                    "  {} {} ({}ms)",
-                    "SUCCESS".green(),
-                    result.asset_id,
-                    result.duration_ms
-                );
-            } else {
-                print!("{}", ".".green());
-            }
-        } else {
-            failure_count += 1;
-            if verbose {
-                println!(
-                    "  {} {} - {}",
-                    "FAILED".red(),
-                    result.asset_id,
-                    result.error.as_deref().unwrap_or("unknown error")
-                );
-            } else {
-                print!("{}", "x".red());
-            }
-        }
+    // Process specs, reporting each one as it finishes; the returned results are
+    // sorted by asset_id whatever the completion order.
+    let results = process_specs(
+        &spec_files,
+        out_path,
+        verbose,
+        force,
+        &backend_version,
+        jobs,
+        |result| print_progress(result, verbose),
+    );
 
-        results.push(result);
-    }
+    let success_count = results
+        .iter()
+        .filter(|r| r.success && !r.skipped_fresh)
+        .count();
+    let failure_count = results.iter().filter(|r| !r.success).count();
+    let fresh_skipped_count = results.iter().filter(|r| r.skipped_fresh).count();
 
     if !verbose {
         println!(); // Newline after progress dots
@@ -385,6 +355,93 @@ pub fn run(
     }
 }
 
+/// Prints the progress line (verbose) or marker for a finished spec.
+fn print_progress(result: &SpecResult, verbose: bool) {
+    if result.skipped_fresh {
+        if verbose {
+            println!(
+                "  {} {} {}",
+                "SKIPPED".yellow(),
+                "(fresh)".dimmed(),
+                result.asset_id
+            );
+        } else {
+            print!("{}", "s".yellow());
+        }
+    } else if result.success {
+        if verbose {
+            println!(
+                "  {} {} ({}ms)",
+                "SUCCESS".green(),
+                result.asset_id,
+                result.duration_ms
+            );
+        } else {
+            print!("{}", ".".green());
+        }
+    } else if verbose {
+        println!(
+            "  {} {} - {}",
+            "FAILED".red(),
+            result.asset_id,
+            result.error.as_deref().unwrap_or("unknown error")
+        );
+    } else {
+        print!("{}", "x".red());
+    }
+    let _ = std::io::stdout().flush();
+}
+
+/// Process spec files on up to `jobs` worker threads.
+///
+/// Each spec is generated independently, so output is the same as a serial run. Blender specs
+/// spawn a subprocess each and are run one at a time after the rest, so they never compete with
+/// each other for cores. `on_result` is called once per spec as soon as it finishes, one call
+/// at a time, in completion order. The returned results are sorted by asset_id (then spec path).
+fn process_specs(
+    spec_files: &[PathBuf],
+    out_root: &Path,
+    verbose: bool,
+    force: bool,
+    backend_version: &str,
+    jobs: usize,
+    on_result: impl Fn(&SpecResult) + Sync,
+) -> Vec<SpecResult> {
+    let (blender_specs, rust_specs): (Vec<&PathBuf>, Vec<&PathBuf>) =
+        spec_files.iter().partition(|path| is_blender_spec(path));
+
+    let workers = jobs.clamp(1, rust_specs.len().max(1));
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::with_capacity(spec_files.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                while let Some(spec_file) = rust_specs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let result = process_spec(spec_file, out_root, verbose, force, backend_version);
+                    let mut results = results.lock().unwrap();
+                    on_result(&result);
+                    results.push(result);
+                }
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    for spec_file in blender_specs {
+        let result = process_spec(spec_file, out_root, verbose, force, backend_version);
+        on_result(&result);
+        results.push(result);
+    }
+
+    results.sort_by(|a, b| {
+        a.asset_id
+            .cmp(&b.asset_id)
+            .then_with(|| a.spec_path.cmp(&b.spec_path))
+    });
+    results
+}
+
 /// Process a single spec file
 fn process_spec(
     spec_path: &Path,
@@ -517,6 +574,14 @@ fn process_spec(
     result
 }
 
+/// Whether a spec path belongs to a Blender asset type directory.
+fn is_blender_spec(path: &Path) -> bool {
+    BLENDER_ASSET_TYPES.iter().any(|t| {
+        path.components()
+            .any(|c| c.as_os_str().to_string_lossy() == *t)
+    })
+}
+
 /// Extract asset type from path
 fn extract_asset_type(path: &Path) -> Option<String> {
     // Look for known asset types in path components
@@ -700,6 +765,8 @@ mod tests {
                 hash: Some("fakehash".to_string()),
                 metrics: None,
                 preview: None,
+                file_hash: None,
+                size_bytes: None,
             });
         }
         let report = builder.ok(true).build();
@@ -871,6 +938,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parallel_and_serial_runs_produce_identical_hashes() {
+        use speccade_spec::{AssetType, OutputFormat, OutputSpec, Recipe};
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let spec_dir = tmp.path().join("audio");
+        fs::create_dir_all(&spec_dir).unwrap();
+
+        // Written in reverse so file order differs from asset_id order.
+        let mut spec_files = Vec::new();
+        for i in (0..6).rev() {
+            let asset_id = format!("parallel-{:02}", i);
+            let spec = speccade_spec::Spec::builder(&asset_id, AssetType::Audio)
+                .license("CC0-1.0")
+                .seed(100 + i)
+                .output(OutputSpec::primary(
+                    OutputFormat::Wav,
+                    format!("{}.wav", asset_id),
+                ))
+                .recipe(Recipe::new(
+                    "audio_v1",
+                    serde_json::json!({
+                        "duration_seconds": 0.1,
+                        "sample_rate": 22050,
+                        "layers": [{
+                            "synthesis": { "type": "noise_burst", "noise_type": "white" },
+                            "envelope": { "attack": 0.01, "decay": 0.02, "sustain": 0.5, "release": 0.05 },
+                            "volume": 0.5,
+                            "pan": 0.0
+                        }]
+                    }),
+                ))
+                .build();
+            let spec_path = spec_dir.join(format!("z{}.json", i));
+            fs::write(&spec_path, spec.to_json_pretty().unwrap()).unwrap();
+            spec_files.push(spec_path);
+        }
+
+        let hashes = |out_dir: &str, jobs: usize| -> Vec<(String, Vec<String>)> {
+            let out_root = tmp.path().join(out_dir);
+            fs::create_dir_all(&out_root).unwrap();
+            let written = || {
+                fs::read_dir(&out_root)
+                    .unwrap()
+                    .filter(|e| e.as_ref().unwrap().path().extension() == Some("wav".as_ref()))
+                    .count()
+            };
+            let reported = Mutex::new(Vec::new());
+            let results = process_specs(
+                &spec_files,
+                &out_root,
+                false,
+                true,
+                "test-v1.0.0",
+                jobs,
+                |r| {
+                    reported
+                        .lock()
+                        .unwrap()
+                        .push((r.asset_id.clone(), written()))
+                },
+            );
+            // Every spec is reported exactly once, as soon as it finishes: a
+            // serial run reports each spec before generating the next.
+            let mut reported = reported.into_inner().unwrap();
+            if jobs == 1 {
+                let counts: Vec<usize> = reported.iter().map(|(_, n)| *n).collect();
+                assert_eq!(counts, (1..=spec_files.len()).collect::<Vec<_>>());
+            }
+            reported.sort();
+            assert_eq!(reported.len(), results.len());
+            assert!(reported
+                .iter()
+                .zip(&results)
+                .all(|((id, _), r)| *id == r.asset_id));
+            results
+                .into_iter()
+                .map(|r| {
+                    assert!(r.success, "{}: {:?}", r.asset_id, r.error);
+                    (r.asset_id, r.output_hashes)
+                })
+                .collect()
+        };
+
+        let serial = hashes("serial", 1);
+        let parallel = hashes("parallel", 4);
+
+        assert_eq!(serial, parallel);
+        let ids: Vec<&str> = serial.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(
+            ids,
+            [
+                "parallel-00",
+                "parallel-01",
+                "parallel-02",
+                "parallel-03",
+                "parallel-04",
+                "parallel-05"
+            ]
+        );
+        assert!(serial.iter().all(|(_, h)| !h.is_empty()));
+    }
+
     #[test]
     fn test_freshness_regenerates_when_report_has_empty_outputs() {
         let tmp = tempfile::TempDir::new().unwrap();
@@ -0,0 +1,170 @@
+//! Audio inspection helpers for audio_v1 specs.
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use speccade_backend_audio::mixer::MixerOutput;
+use speccade_backend_audio::WavResult;
+use speccade_spec::{OutputFormat, OutputKind};
+use std::fs;
+use std::path::Path;
+
+use super::super::json_output::IntermediateFile;
+use crate::analysis::audio::{analyze_wav, waveform_envelope};
+
+/// File name of the spectral/waveform report, relative to the output directory.
+pub const ANALYSIS_FILENAME: &str = "audio_inspect.json";
+
+/// Number of points in each downsampled waveform envelope.
+const WAVEFORM_POINTS: usize = 256;
+
+/// Spectral and waveform report written next to the inspected outputs.
+#[derive(Debug, Serialize)]
+struct AudioInspectReport {
+    sample_rate: u32,
+    /// One entry per spec layer, in spec order.
+    layers: Vec<LayerReport>,
+    /// Sum of all layers before master stages.
+    pre_master: SignalSummary,
+    /// Final output after master stages.
+    master: SignalSummary,
+}
+
+/// Summary of one spec layer; muted layers carry no signal summary.
+#[derive(Debug, Serialize)]
+struct LayerReport {
+    index: usize,
+    muted: bool,
+    #[serde(flatten)]
+    summary: Option<SignalSummary>,
+}
+
+/// Level, spectrum, and shape of a rendered signal.
+#[derive(Debug, Serialize)]
+struct SignalSummary {
+    rms_db: f64,
+    peak_db: f64,
+    centroid_hz: f64,
+    /// Peak amplitude per block, 0.0-1.0.
+    waveform: Vec<f64>,
+}
+
+impl SignalSummary {
+    fn from_wav(wav: &WavResult) -> Result<Self> {
+        let metrics = analyze_wav(&wav.wav_data).with_context(|| "Failed to analyze audio")?;
+        let waveform = waveform_envelope(&wav.wav_data, WAVEFORM_POINTS)
+            .with_context(|| "Failed to compute waveform envelope")?;
+
+        Ok(Self {
+            rms_db: metrics.quality.rms_db,
+            peak_db: metrics.quality.peak_db,
+            centroid_hz: metrics.spectral.centroid_hz,
+            waveform,
+        })
+    }
+}
+
+/// Inspect audio_v1 spec - write per-layer WAVs and envelopes before mixing,
+/// plus a JSON report with per-layer and master level/spectrum summaries.
+///
+/// Returns the intermediates, the report path (relative to `out_dir`), and the
+/// final outputs.
+pub fn inspect_audio(
+    spec: &speccade_spec::Spec,
+    intermediates_dir: &Path,
+    out_dir: &str,
+) -> Result<(Vec<IntermediateFile>, String, Vec<IntermediateFile>)> {
+    let inspection = speccade_backend_audio::generate::inspect(spec)
+        .with_context(|| "Failed to render layers")?;
+
+    let mut intermediates = Vec::new();
+    let mut layer_reports = Vec::new();
+    for layer in &inspection.layers {
+        // Muted layers (or layers silenced by a solo) contribute nothing to the mix
+        let mut summary = None;
+        if let Some(ref output) = layer.output {
+            let wav = to_wav(output, inspection.sample_rate);
+            summary = Some(SignalSummary::from_wav(&wav)?);
+            intermediates.push(write_intermediate_wav(
+                intermediates_dir,
+                &format!("layer_{}", layer.index),
+                &wav,
+            )?);
+        }
+        layer_reports.push(LayerReport {
+            index: layer.index,
+            muted: summary.is_none(),
+            summary,
+        });
+
+        let envelope = WavResult::from_mono(&layer.envelope, inspection.sample_rate);
+        intermediates.push(write_intermediate_wav(
+            intermediates_dir,
+            &format!("layer_{}_envelope", layer.index),
+            &envelope,
+        )?);
+    }
+
+    // Render the final mix, for comparison with the layers
+    let result =
+        speccade_backend_audio::generate(spec).with_context(|| "Failed to render final mix")?;
+
+    let report = AudioInspectReport {
+        sample_rate: inspection.sample_rate,
+        layers: layer_reports,
+        pre_master: SignalSummary::from_wav(&to_wav(&inspection.mix, inspection.sample_rate))?,
+        master: SignalSummary::from_wav(&result.wav)?,
+    };
+    let report_path = Path::new(out_dir).join(ANALYSIS_FILENAME);
+    fs::write(&report_path, serde_json::to_string_pretty(&report)?)
+        .with_context(|| format!("Failed to write analysis: {}", report_path.display()))?;
+
+    // Write the final mix to the primary output
+    let mut final_outputs = Vec::new();
+    let primary = spec
+        .outputs
+        .iter()
+        .find(|o| o.kind == OutputKind::Primary && o.format == OutputFormat::Wav);
+    if let Some(output_spec) = primary {
+        let output_path = Path::new(out_dir).join(&output_spec.path);
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&output_path, &result.wav.wav_data)
+            .with_context(|| format!("Failed to write output: {}", output_spec.path))?;
+
+        final_outputs.push(IntermediateFile {
+            id: "mix".to_string(),
+            format: "wav".to_string(),
+            path: output_spec.path.clone(),
+            hash: Some(result.wav.pcm_hash),
+        });
+    }
+
+    Ok((intermediates, ANALYSIS_FILENAME.to_string(), final_outputs))
+}
+
+fn to_wav(output: &MixerOutput, sample_rate: u32) -> WavResult {
+    match output {
+        MixerOutput::Mono(samples) => WavResult::from_mono(samples, sample_rate),
+        MixerOutput::Stereo(stereo) => WavResult::from_stereo_output(stereo, sample_rate),
+    }
+}
+
+/// Writes a WAV intermediate and returns its artifact entry.
+fn write_intermediate_wav(
+    intermediates_dir: &Path,
+    id: &str,
+    wav: &WavResult,
+) -> Result<IntermediateFile> {
+    let filename = format!("{}.wav", id);
+    let path = intermediates_dir.join(&filename);
+    fs::write(&path, &wav.wav_data)
+        .with_context(|| format!("Failed to write intermediate: {}", path.display()))?;
+
+    Ok(IntermediateFile {
+        id: id.to_string(),
+        format: "wav".to_string(),
+        path: format!("intermediates/{}", filename),
+        hash: Some(wav.pcm_hash.clone()),
+    })
+}
@@ -1,9 +1,11 @@
 //! Inspect command implementation
 //!
 //! Generates intermediate build artifacts for debugging and inspection.
-//! Supports texture.procedural_v1 (per-node PNGs) and music.tracker_song_compose_v1
+//! Supports texture.procedural_v1 (per-node PNGs), audio_v1 (per-layer WAVs and
+//! envelopes plus a level/spectrum report), and music.tracker_song_compose_v1
 //! (expanded params JSON).
 
+mod audio;
 mod compose;
 mod texture;
 
@@ -24,6 +26,7 @@ use super::json_output::{
 };
 use crate::input::{load_spec, LoadResult};
 
+use audio::inspect_audio;
 use compose::inspect_compose;
 use texture::inspect_texture_procedural;
 
@@ -137,28 +140,35 @@ fn run_human(spec_path: &str, out_dir: &str) -> Result<ExitCode> {
         .with_context(|| "Failed to create intermediates directory")?;
 
     // Dispatch based on recipe kind
-    let (intermediates, expanded_params_path, final_outputs) = match recipe.kind.as_str() {
-        "texture.procedural_v1" => {
-            let (ints, finals) = inspect_texture_procedural(&spec, &intermediates_dir, out_dir)?;
-            (ints, None, finals)
-        }
-        "music.tracker_song_compose_v1" => {
-            let (ints, expanded, finals) = inspect_compose(&spec, &intermediates_dir, out_dir)?;
-            (ints, Some(expanded), finals)
-        }
-        _ => {
-            println!(
-                "\n{} inspect is not supported for recipe kind: {}",
-                "SKIPPED".yellow().bold(),
-                recipe.kind
-            );
-            println!(
-                "{}",
-                "Supported: texture.procedural_v1, music.tracker_song_compose_v1".dimmed()
-            );
-            return Ok(ExitCode::SUCCESS);
-        }
-    };
+    let (intermediates, expanded_params_path, analysis_path, final_outputs) =
+        match recipe.kind.as_str() {
+            "texture.procedural_v1" => {
+                let (ints, finals) =
+                    inspect_texture_procedural(&spec, &intermediates_dir, out_dir)?;
+                (ints, None, None, finals)
+            }
+            "audio_v1" => {
+                let (ints, analysis, finals) = inspect_audio(&spec, &intermediates_dir, out_dir)?;
+                (ints, None, Some(analysis), finals)
+            }
+            "music.tracker_song_compose_v1" => {
+                let (ints, expanded, finals) = inspect_compose(&spec, &intermediates_dir, out_dir)?;
+                (ints, Some(expanded), None, finals)
+            }
+            _ => {
+                println!(
+                    "\n{} inspect is not supported for recipe kind: {}",
+                    "SKIPPED".yellow().bold(),
+                    recipe.kind
+                );
+                println!(
+                    "{}",
+                    "Supported: texture.procedural_v1, audio_v1, music.tracker_song_compose_v1"
+                        .dimmed()
+                );
+                return Ok(ExitCode::SUCCESS);
+            }
+        };
 
     let duration_ms = start.elapsed().as_millis() as u64;
 
@@ -172,6 +182,10 @@ fn run_human(spec_path: &str, out_dir: &str) -> Result<ExitCode> {
         println!("\n{} {}", "Expanded params:".green().bold(), expanded_path);
     }
 
+    if let Some(ref analysis_path) = analysis_path {
+        println!("\n{} {}", "Analysis:".green().bold(), analysis_path);
+    }
+
     if !final_outputs.is_empty() {
         println!("\n{}", "Final outputs:".green().bold());
         for out_file in &final_outputs {
@@ -301,6 +315,7 @@ fn run_json(spec_path: &str, out_dir: &str) -> Result<ExitCode> {
                         intermediates,
                         final_outputs,
                         expanded_params_path: None,
+                        analysis_path: None,
                         duration_ms,
                     }
                 }
@@ -317,6 +332,34 @@ fn run_json(spec_path: &str, out_dir: &str) -> Result<ExitCode> {
                 }
             }
         }
+        "audio_v1" => match inspect_audio(&spec, &intermediates_dir, out_dir) {
+            Ok((intermediates, analysis_path, final_outputs)) => {
+                let duration_ms = start.elapsed().as_millis() as u64;
+                InspectResult {
+                    asset_id: spec.asset_id.clone(),
+                    asset_type: spec.asset_type.to_string(),
+                    source_kind: source_kind.as_str().to_string(),
+                    recipe_kind: recipe.kind.clone(),
+                    out_dir: out_dir.to_string(),
+                    intermediates,
+                    final_outputs,
+                    expanded_params_path: None,
+                    analysis_path: Some(analysis_path),
+                    duration_ms,
+                }
+            }
+            Err(e) => {
+                let error = JsonError::new(error_codes::GENERATION_ERROR, e.to_string());
+                let output = InspectOutput::failure(
+                    vec![error],
+                    all_warnings,
+                    Some(spec_hash),
+                    Some(source_hash),
+                );
+                println!("{}", serde_json::to_string_pretty(&output)?);
+                return Ok(ExitCode::from(1));
+            }
+        },
         "music.tracker_song_compose_v1" => {
             match inspect_compose(&spec, &intermediates_dir, out_dir) {
                 Ok((intermediates, expanded_path, final_outputs)) => {
@@ -330,6 +373,7 @@ fn run_json(spec_path: &str, out_dir: &str) -> Result<ExitCode> {
                         intermediates,
                         final_outputs,
                         expanded_params_path: Some(expanded_path),
+                        analysis_path: None,
                         duration_ms,
                     }
                 }
@@ -358,6 +402,7 @@ fn run_json(spec_path: &str, out_dir: &str) -> Result<ExitCode> {
                 intermediates: vec![],
                 final_outputs: vec![],
                 expanded_params_path: None,
+                analysis_path: None,
                 duration_ms,
             }
         }
@@ -89,6 +89,93 @@ fn inspect_texture_json_output() {
     assert_eq!(code, ExitCode::SUCCESS);
 }
 
+#[test]
+fn inspect_texture_writes_one_png_per_node_matching_node_outputs() {
+    let tmp = tempfile::tempdir().unwrap();
+
+    let params = serde_json::json!({
+        "resolution": [16, 16],
+        "tileable": true,
+        "nodes": [
+            { "id": "noise", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } },
+            { "id": "mask", "type": "threshold", "input": "noise", "threshold": 0.5 }
+        ]
+    });
+
+    let mut output = OutputSpec::primary(OutputFormat::Png, "mask.png");
+    output.source = Some("mask".to_string());
+    let spec = Spec::builder("inspect-tex-test-03", AssetType::Texture)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(output)
+        .recipe(Recipe::new("texture.procedural_v1", params))
+        .build();
+
+    let spec_path = write_spec(&tmp, "spec.json", &spec);
+    let out_a = tmp.path().join("out_a");
+    let out_b = tmp.path().join("out_b");
+    for out_dir in [&out_a, &out_b] {
+        let code = run(spec_path.to_str().unwrap(), out_dir.to_str().unwrap(), true).unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+    }
+
+    let mut written: Vec<_> = std::fs::read_dir(out_a.join("intermediates"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+        .collect();
+    written.sort();
+    assert_eq!(written, vec!["mask.png", "noise.png"]);
+
+    let graph_params = spec
+        .recipe
+        .as_ref()
+        .unwrap()
+        .as_texture_procedural()
+        .unwrap();
+    let nodes = speccade_backend_texture::generate_graph(&graph_params, spec.seed).unwrap();
+    for node_id in ["noise", "mask"] {
+        let (expected, _) =
+            speccade_backend_texture::encode_graph_value_png(&nodes[node_id]).unwrap();
+        let path = format!("intermediates/{}.png", node_id);
+        assert_eq!(std::fs::read(out_a.join(&path)).unwrap(), expected);
+        assert_eq!(std::fs::read(out_b.join(&path)).unwrap(), expected);
+    }
+}
+
+#[test]
+fn inspect_texture_rejects_node_id_with_path_separator() {
+    let tmp = tempfile::tempdir().unwrap();
+
+    let mut output = OutputSpec::primary(OutputFormat::Png, "mask.png");
+    output.source = Some("sub/mask".to_string());
+
+    let recipe = Recipe::new(
+        "texture.procedural_v1",
+        serde_json::json!({
+            "resolution": [16, 16],
+            "tileable": true,
+            "nodes": [
+                { "id": "sub/mask", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } }
+            ]
+        }),
+    );
+
+    let spec = Spec::builder("inspect-tex-test-04", AssetType::Texture)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(output)
+        .recipe(recipe)
+        .build();
+
+    let spec_path = write_spec(&tmp, "spec.json", &spec);
+    let out_dir = tmp.path().join("out");
+
+    let code = run(spec_path.to_str().unwrap(), out_dir.to_str().unwrap(), true).unwrap();
+
+    assert_eq!(code, ExitCode::from(1));
+    assert!(!out_dir.join("intermediates/sub").exists());
+}
+
 #[test]
 fn inspect_compose_generates_expanded_params() {
     let tmp = tempfile::tempdir().unwrap();
@@ -148,8 +235,133 @@ fn inspect_compose_generates_expanded_params() {
     let _: serde_json::Value = serde_json::from_str(&content).unwrap();
 }
 
+/// Decodes a 16-bit WAV into interleaved integer samples.
+fn read_pcm16(path: &std::path::Path) -> Vec<i32> {
+    let wav = std::fs::read(path).unwrap();
+    speccade_backend_audio::wav::extract_pcm_data(&wav)
+        .unwrap()
+        .chunks_exact(2)
+        .map(|b| i16::from_le_bytes([b[0], b[1]]) as i32)
+        .collect()
+}
+
+fn two_layer_audio_spec() -> Spec {
+    let layer = |frequency: f64, pan: f64, delay: f64| {
+        serde_json::json!({
+            "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": frequency },
+            "envelope": { "attack": 0.01, "decay": 0.05, "sustain": 0.6, "release": 0.05 },
+            "volume": 0.7,
+            "pan": pan,
+            "delay": delay
+        })
+    };
+
+    let recipe = Recipe::new(
+        "audio_v1",
+        serde_json::json!({
+            "duration_seconds": 0.25,
+            "sample_rate": 22050,
+            "layers": [layer(220.0, -0.5, 0.0), layer(330.0, 0.6, 0.05)]
+        }),
+    );
+
+    Spec::builder("inspect-audio-test-02", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "mix.wav"))
+        .recipe(recipe)
+        .build()
+}
+
+#[test]
+fn inspect_audio_layer_wavs_sum_to_final_mix() {
+    let tmp = tempfile::tempdir().unwrap();
+    let spec_path = write_spec(&tmp, "spec.json", &two_layer_audio_spec());
+
+    let out_a = tmp.path().join("out_a");
+    let out_b = tmp.path().join("out_b");
+    for out_dir in [&out_a, &out_b] {
+        let code = run(spec_path.to_str().unwrap(), out_dir.to_str().unwrap(), true).unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+    }
+
+    for name in [
+        "intermediates/layer_0.wav",
+        "intermediates/layer_1.wav",
+        "intermediates/layer_0_envelope.wav",
+        "intermediates/layer_1_envelope.wav",
+        "mix.wav",
+    ] {
+        assert_eq!(
+            std::fs::read(out_a.join(name)).unwrap(),
+            std::fs::read(out_b.join(name)).unwrap(),
+            "{} should be deterministic",
+            name
+        );
+    }
+    assert!(!out_a.join("intermediates/layer_2.wav").exists());
+
+    let layer_0 = read_pcm16(&out_a.join("intermediates/layer_0.wav"));
+    let layer_1 = read_pcm16(&out_a.join("intermediates/layer_1.wav"));
+    let mix = read_pcm16(&out_a.join("mix.wav"));
+    assert_eq!(layer_0.len(), mix.len());
+    assert_eq!(layer_1.len(), mix.len());
+
+    // Each WAV rounds independently, so allow one step per file
+    for (i, &expected) in mix.iter().enumerate() {
+        let sum = layer_0[i] + layer_1[i];
+        assert!(
+            (sum - expected).abs() <= 2,
+            "sample {}: layers sum to {}, mix is {}",
+            i,
+            sum,
+            expected
+        );
+    }
+}
+
+#[test]
+fn inspect_audio_writes_per_layer_analysis() {
+    let tmp = tempfile::tempdir().unwrap();
+    let spec_path = write_spec(&tmp, "spec.json", &two_layer_audio_spec());
+    let out_dir = tmp.path().join("out");
+
+    let code = run(spec_path.to_str().unwrap(), out_dir.to_str().unwrap(), true).unwrap();
+    assert_eq!(code, ExitCode::SUCCESS);
+
+    let report: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(out_dir.join("audio_inspect.json")).unwrap())
+            .unwrap();
+    assert_eq!(report["sample_rate"], 22050);
+
+    let layers = report["layers"].as_array().unwrap();
+    assert_eq!(layers.len(), 2);
+    assert!(layers.iter().all(|layer| layer["muted"] == false));
+    let summaries = layers
+        .iter()
+        .chain([&report["pre_master"], &report["master"]]);
+    for summary in summaries {
+        let rms_db = summary["rms_db"].as_f64().unwrap();
+        let peak_db = summary["peak_db"].as_f64().unwrap();
+        let centroid_hz = summary["centroid_hz"].as_f64().unwrap();
+        assert!(rms_db > -60.0 && rms_db <= 0.0, "rms_db {}", rms_db);
+        assert!(peak_db >= rms_db && peak_db <= 0.0, "peak_db {}", peak_db);
+        assert!(centroid_hz > 0.0 && centroid_hz < 11025.0);
+
+        let waveform = summary["waveform"].as_array().unwrap();
+        assert!(!waveform.is_empty());
+        assert!(waveform
+            .iter()
+            .all(|v| (0.0..=1.0).contains(&v.as_f64().unwrap())));
+    }
+
+    // The higher layer is brighter
+    let centroid = |i: usize| layers[i]["centroid_hz"].as_f64().unwrap();
+    assert!(centroid(1) > centroid(0));
+}
+
 #[test]
-fn inspect_unsupported_recipe_returns_success() {
+fn inspect_audio_without_layers_writes_only_final_output() {
     let tmp = tempfile::tempdir().unwrap();
 
     let recipe = Recipe::new(
@@ -178,8 +390,14 @@ fn inspect_unsupported_recipe_returns_success() {
     )
     .unwrap();
 
-    // Should succeed (just skip with message)
     assert_eq!(code, ExitCode::SUCCESS);
+    assert!(out_dir.join("test.wav").exists());
+    assert_eq!(
+        std::fs::read_dir(out_dir.join("intermediates"))
+            .unwrap()
+            .count(),
+        0
+    );
 }
 
 #[test]
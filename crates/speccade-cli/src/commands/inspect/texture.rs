@@ -31,6 +31,14 @@ pub fn inspect_texture_procedural(
     node_ids.sort(); // Stable ordering
 
     for node_id in node_ids {
+        // Each node maps to exactly one file directly under intermediates/
+        if node_id.contains('/') || !speccade_spec::is_safe_output_path(node_id) {
+            anyhow::bail!(
+                "node id '{}' cannot be used as an intermediate file name",
+                node_id
+            );
+        }
+
         let value = &nodes[node_id];
         let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(value)
             .with_context(|| format!("Failed to encode node '{}' as PNG", node_id))?;
@@ -184,6 +184,9 @@ pub struct InspectResult {
     /// Expanded params JSON path (for compose specs)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expanded_params_path: Option<String>,
+    /// Analysis report JSON path (for audio specs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub analysis_path: Option<String>,
     /// Duration in milliseconds
     pub duration_ms: u64,
 }
@@ -267,6 +270,9 @@ pub struct CompareResult {
     pub identical: bool,
     /// Comparison metrics (type-specific)
     pub metrics: CompareMetrics,
+    /// Thresholds that were exceeded (empty if none were set or all passed)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub threshold_violations: Vec<String>,
 }
 
 /// Type-specific comparison metrics.
@@ -331,13 +337,17 @@ pub struct AudioCompareMetrics {
     pub peak_delta_db: f64,
     /// Loudness difference as percentage ((A - B) / B * 100)
     pub loudness_delta_percent: f64,
+    /// Level of the difference signal (A - B) relative to A, in dB
+    pub residual_db: f64,
 }
 
 impl CompareOutput {
-    /// Creates a successful compare output.
+    /// Creates a compare output from a completed comparison.
+    ///
+    /// `success` is false if the result exceeded any threshold.
     pub fn success(result: CompareResult) -> Self {
         Self {
-            success: true,
+            success: result.threshold_violations.is_empty(),
             errors: Vec::new(),
             result: Some(result),
         }
@@ -352,3 +362,206 @@ impl CompareOutput {
         }
     }
 }
+
+/// JSON output for the `compare --dir-a --dir-b` mode.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareDirOutput {
+    /// Whether the directories could be compared
+    pub success: bool,
+    /// Errors encountered during comparison
+    pub errors: Vec<JsonError>,
+    /// Comparison result (on success)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<CompareDirResult>,
+}
+
+/// Directory comparison result details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareDirResult {
+    /// Path to directory A
+    pub dir_a: String,
+    /// Path to directory B
+    pub dir_b: String,
+    /// Per-file results, sorted by relative path
+    pub files: Vec<CompareDirEntry>,
+    /// Summary counts
+    pub summary: CompareDirSummary,
+}
+
+/// Outcome for a file paired by relative path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareDirStatus {
+    /// Both files exist and are byte-identical
+    Identical,
+    /// Both files exist and differ
+    Changed,
+    /// File exists only in directory B
+    MissingInA,
+    /// File exists only in directory A
+    MissingInB,
+}
+
+/// Comparison of a single file paired by relative path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareDirEntry {
+    /// Path relative to both directories (forward slashes)
+    pub path: String,
+    /// Comparison outcome
+    pub status: CompareDirStatus,
+    /// Asset type (audio/texture/mesh), if the format is supported
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asset_type: Option<String>,
+    /// Comparison metrics for changed files of a supported format
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<CompareMetrics>,
+    /// Why metrics could not be computed for a changed file
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Thresholds the changed file exceeded
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub threshold_violations: Vec<String>,
+    /// Whether the changed file was checked against thresholds and passed them all
+    #[serde(default)]
+    pub within_thresholds: bool,
+}
+
+/// Summary counts for a directory comparison.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompareDirSummary {
+    /// Total number of paired paths
+    pub total: usize,
+    /// Number of byte-identical files
+    pub identical: usize,
+    /// Number of changed files
+    pub changed: usize,
+    /// Number of files missing from directory A
+    pub missing_in_a: usize,
+    /// Number of files missing from directory B
+    pub missing_in_b: usize,
+    /// Number of changed files that stayed within the thresholds
+    #[serde(default)]
+    pub within_thresholds: usize,
+}
+
+impl CompareDirSummary {
+    /// Counts the outcomes of `entries`.
+    pub fn from_entries(entries: &[CompareDirEntry]) -> Self {
+        let mut summary = Self {
+            total: entries.len(),
+            ..Self::default()
+        };
+        for entry in entries {
+            match entry.status {
+                CompareDirStatus::Identical => summary.identical += 1,
+                CompareDirStatus::Changed => {
+                    summary.changed += 1;
+                    if entry.within_thresholds {
+                        summary.within_thresholds += 1;
+                    }
+                }
+                CompareDirStatus::MissingInA => summary.missing_in_a += 1,
+                CompareDirStatus::MissingInB => summary.missing_in_b += 1,
+            }
+        }
+        summary
+    }
+
+    /// Returns true if any file changed or lacks a counterpart.
+    pub fn has_differences(&self) -> bool {
+        self.identical != self.total
+    }
+
+    /// Returns true if any difference is not covered by the thresholds.
+    pub fn has_failures(&self) -> bool {
+        self.identical + self.within_thresholds != self.total
+    }
+}
+
+impl CompareDirOutput {
+    /// Creates a successful directory compare output.
+    pub fn success(result: CompareDirResult) -> Self {
+        Self {
+            success: true,
+            errors: Vec::new(),
+            result: Some(result),
+        }
+    }
+
+    /// Creates a failed directory compare output.
+    pub fn failure(errors: Vec<JsonError>) -> Self {
+        Self {
+            success: false,
+            errors,
+            result: None,
+        }
+    }
+}
+
+/// JSON output for the `similar` command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarOutput {
+    /// Whether the search completed
+    pub success: bool,
+    /// Errors encountered during the search
+    pub errors: Vec<JsonError>,
+    /// Search result (on success)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<SimilarResult>,
+}
+
+/// Nearest-neighbor search result details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarResult {
+    /// Path to the query file
+    pub query: String,
+    /// Asset type of the query (audio/texture); only assets of this type are ranked
+    pub asset_type: String,
+    /// Directory that was searched
+    pub input_dir: String,
+    /// Number of assets of the query type that were ranked
+    pub candidates: usize,
+    /// Top matches, most similar first (ties ordered by path)
+    pub matches: Vec<SimilarMatch>,
+    /// Assets of the query type that could not be embedded
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<SimilarSkipped>,
+}
+
+/// A ranked asset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarMatch {
+    /// Path to the asset
+    pub path: String,
+    /// Cosine similarity of the asset's embedding to the query embedding (-1.0 to 1.0)
+    pub similarity: f64,
+}
+
+/// An asset left out of the ranking.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarSkipped {
+    /// Path to the asset
+    pub path: String,
+    /// Why no embedding could be computed
+    pub error: String,
+}
+
+impl SimilarOutput {
+    /// Creates a successful similar output.
+    pub fn success(result: SimilarResult) -> Self {
+        Self {
+            success: true,
+            errors: Vec::new(),
+            result: Some(result),
+        }
+    }
+
+    /// Creates a failed similar output.
+    pub fn failure(errors: Vec<JsonError>) -> Self {
+        Self {
+            success: false,
+            errors,
+            result: None,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-//! Manifest types for batch SFX variation generation.
+//! Manifest types for batch SFX variation generation and output bundles.
 
 use serde::{Deserialize, Serialize};
 
@@ -13,8 +13,11 @@ pub struct VariationsManifest {
     pub passed: u32,
     /// Number of variations that failed quality constraints
     pub failed: u32,
-    /// Base seed used for the first variation
+    /// Base seed the variation seeds are derived from
     pub base_seed: u32,
+    /// How variation seeds were derived from the base seed (increment or hashed)
+    #[serde(default = "default_seed_mode")]
+    pub seed_mode: String,
     /// Quality constraints applied (if any)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub constraints: Option<VariationConstraints>,
@@ -22,6 +25,11 @@ pub struct VariationsManifest {
     pub variations: Vec<VariationEntry>,
 }
 
+/// Manifests written before seed modes existed always incremented the seed.
+fn default_seed_mode() -> String {
+    "increment".to_string()
+}
+
 /// Quality constraints for variation generation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VariationConstraints {
@@ -68,11 +76,18 @@ impl VariationsManifest {
             passed: 0,
             failed: 0,
             base_seed,
+            seed_mode: default_seed_mode(),
             constraints,
             variations: Vec::new(),
         }
     }
 
+    /// Sets how variation seeds are derived from the base seed.
+    pub fn with_seed_mode(mut self, seed_mode: impl Into<String>) -> Self {
+        self.seed_mode = seed_mode.into();
+        self
+    }
+
     /// Adds a variation entry and updates counts.
     pub fn add_variation(&mut self, entry: VariationEntry) {
         self.total += 1;
@@ -85,6 +100,26 @@ impl VariationsManifest {
     }
 }
 
+/// Manifest stored inside a `generate --bundle` archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// Asset ID from the source spec
+    pub asset_id: String,
+    /// Bundled files, sorted by path
+    pub files: Vec<BundleEntry>,
+}
+
+/// A single file in a bundle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// Archive path, relative to the output root with forward slashes
+    pub path: String,
+    /// BLAKE3 hash of the file contents
+    pub hash: String,
+    /// File size in bytes
+    pub size: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -127,6 +162,7 @@ mod tests {
         assert!(json.contains("\"passed\": 1"));
         assert!(json.contains("\"failed\": 1"));
         assert!(json.contains("\"base_seed\": 42"));
+        assert!(json.contains("\"seed_mode\": \"increment\""));
         assert!(json.contains("\"max_peak_db\": -3.0"));
         assert!(json.contains("\"path\": \"test_var_0.wav\""));
         assert!(json.contains("\"reason\": \"peak exceeded\""));
@@ -12,17 +12,21 @@ mod records;
 // Re-export all public types for backwards compatibility
 pub use analysis::{
     AnalyzeOutput, AnalyzeResult, AudioCompareMetrics, BatchAnalyzeItem, BatchAnalyzeOutput,
-    BatchAnalyzeSummary, CompareMetrics, CompareOutput, CompareResult, HistogramDiffMetrics,
-    InspectOutput, InspectResult, IntermediateFile, MeshCompareMetrics, TextureCompareMetrics,
+    BatchAnalyzeSummary, CompareDirEntry, CompareDirOutput, CompareDirResult, CompareDirStatus,
+    CompareDirSummary, CompareMetrics, CompareOutput, CompareResult, HistogramDiffMetrics,
+    InspectOutput, InspectResult, IntermediateFile, MeshCompareMetrics, SimilarMatch,
+    SimilarOutput, SimilarResult, SimilarSkipped, TextureCompareMetrics,
 };
 pub use convert::{
     compile_warnings_to_json, input_error_to_json, validation_error_to_json,
     validation_warning_to_json,
 };
-pub use manifest::{VariationConstraints, VariationEntry, VariationsManifest};
+pub use manifest::{
+    BundleEntry, BundleManifest, VariationConstraints, VariationEntry, VariationsManifest,
+};
 pub use records::{
-    EvalOutput, ExpandOutput, GenerateOutput, GenerateResult, GeneratedFile, ValidateOutput,
-    ValidateResult, VariantResult,
+    DiffChange, DiffOp, DiffOutput, DiffResult, EvalOutput, ExpandOutput, GenerateOutput,
+    GenerateResult, GeneratedFile, ValidateOutput, ValidateResult, VariantResult,
 };
 
 use serde::{Deserialize, Serialize};
@@ -60,6 +64,8 @@ pub mod error_codes {
     pub const TEXTURE_ANALYSIS: &str = "CLI_013";
     /// Mesh analysis error
     pub const MESH_ANALYSIS: &str = "CLI_014";
+    /// Generated output rejected by `generate --lint` / `--lint-strict`
+    pub const LINT_GATE: &str = "CLI_017";
 }
 
 /// Warning codes for CLI operations.
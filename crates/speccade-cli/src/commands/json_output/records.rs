@@ -1,4 +1,4 @@
-//! Output record types for core CLI commands (eval, validate, generate, expand).
+//! Output record types for core CLI commands (eval, validate, generate, expand, diff).
 
 use super::{JsonError, JsonWarning};
 use serde::{Deserialize, Serialize};
@@ -192,6 +192,10 @@ pub struct GenerateResult {
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub variants: Vec<VariantResult>,
+    /// Path of the zip bundle (if --bundle was given)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bundle: Option<String>,
 }
 
 /// Result for a single variant generation.
@@ -303,6 +307,86 @@ impl ExpandOutput {
     }
 }
 
+/// JSON output for the `diff` command.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffOutput {
+    /// Whether both specs loaded and were compared
+    pub success: bool,
+    /// Errors encountered while loading the specs
+    pub errors: Vec<JsonError>,
+    /// Diff result (on success)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<DiffResult>,
+}
+
+impl DiffOutput {
+    /// Creates a successful diff output.
+    pub fn success(result: DiffResult) -> Self {
+        Self {
+            success: true,
+            errors: Vec::new(),
+            result: Some(result),
+        }
+    }
+
+    /// Creates a failed diff output.
+    pub fn failure(errors: Vec<JsonError>) -> Self {
+        Self {
+            success: false,
+            errors,
+            result: None,
+        }
+    }
+}
+
+/// Semantic difference between two canonicalized specs.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffResult {
+    /// Path to spec A
+    pub path_a: String,
+    /// Path to spec B
+    pub path_b: String,
+    /// Canonical spec hash of A
+    pub hash_a: String,
+    /// Canonical spec hash of B
+    pub hash_b: String,
+    /// Whether the specs are identical after canonicalization
+    pub identical: bool,
+    /// Whether the seed is the only field that changed
+    pub seed_only: bool,
+    /// Whether anything under `recipe` changed
+    pub recipe_changed: bool,
+    /// Field-level changes, ordered by path
+    pub changes: Vec<DiffChange>,
+}
+
+/// A single field-level change between two specs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiffChange {
+    /// Kind of change
+    pub op: DiffOp,
+    /// Path to the field (e.g. `recipe.params.layers[0].volume`)
+    pub path: String,
+    /// Value in spec A (absent for additions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// Value in spec B (absent for removals)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+}
+
+/// Kind of field-level change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffOp {
+    /// Field only present in spec B
+    Added,
+    /// Field only present in spec A
+    Removed,
+    /// Field present in both with different values
+    Changed,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,6 +457,7 @@ mod tests {
             report_path: "test.report.json".to_string(),
             duration_ms: 250,
             variants: vec![],
+            bundle: None,
         };
 
         let output = GenerateOutput::success(
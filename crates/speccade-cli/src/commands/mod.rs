@@ -6,8 +6,10 @@ pub mod audit;
 pub mod batch_validate;
 pub mod cache;
 pub mod compare;
+pub mod compare_dir;
 pub mod contract;
 pub mod coverage;
+pub mod diff;
 pub mod doctor;
 pub mod eval;
 pub mod expand;
@@ -20,12 +22,15 @@ pub mod lint;
 pub mod pipeline;
 pub mod preview;
 pub mod preview_grid;
+pub mod schema;
 #[cfg(feature = "serve")]
 pub mod serve;
+pub mod similar;
 pub mod stdlib;
 pub mod template;
 pub mod validate;
 pub mod validate_asset;
+mod validate_fix;
 pub mod verify;
 
 mod reporting;
@@ -40,7 +45,9 @@ mod tests {
         let _ = audit::run;
         let _ = batch_validate::run;
         let _ = compare::run;
+        let _ = compare_dir::run;
         let _ = contract::run_verify;
+        let _ = diff::run;
         let _ = doctor::run;
         let _ = eval::run;
         let _ = expand::run;
@@ -52,6 +59,8 @@ mod tests {
         let _ = pipeline::run;
         let _ = preview::run;
         let _ = preview_grid::run;
+        let _ = schema::run;
+        let _ = similar::run;
         let _ = template::list;
         let _ = validate::run;
         let _ = validate_asset::run;
@@ -559,7 +559,7 @@ fn execute_spec_stage(
 
     let (exit, artifact_path, message) = match stage {
         PipelineStage::Validate => (
-            validate::run(&spec.path, false, None, false)?,
+            validate::run(&spec.path, false, None, false, false)?,
             None,
             "validate completed".to_string(),
         ),
@@ -574,9 +574,13 @@ fn execute_spec_stage(
                 false,
                 false,
                 None,
+                generate::VariationSeedMode::Increment,
                 None,
                 None,
                 false,
+                None,
+                None,
+                None,
             )?,
             None,
             "generate completed".to_string(),
@@ -1,11 +1,14 @@
 //! Preview grid command implementation
 //!
-//! Generates a multi-angle validation grid PNG for 3D assets.
+//! Generates a multi-angle validation grid PNG for 3D assets, or a labeled
+//! montage of every generated map for textures.
 
 use anyhow::{Context, Result};
 use colored::Colorize;
 use image::{DynamicImage, ImageBuffer, Rgba, RgbaImage};
 use speccade_backend_blender::{GenerationMode, Orchestrator};
+use speccade_spec::recipe::texture::{TextureMapType, TextureMaterialV1Params};
+use speccade_spec::{OutputFormat, Spec};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
@@ -18,6 +21,9 @@ const VIEW_LABELS: [&str; 6] = ["FRONT", "BACK", "TOP", "LEFT", "RIGHT", "ISO"];
 /// Grid padding between panels
 const GRID_PADDING: u32 = 4;
 
+/// Maximum number of columns in a texture map montage
+const TEXTURE_GRID_COLUMNS: usize = 4;
+
 /// Run the preview-grid command
 ///
 /// # Arguments
@@ -35,12 +41,12 @@ pub fn run(spec_path: &str, out: Option<&str>, panel_size: u32) -> Result<ExitCo
 
     let mut spec = load_result.spec;
 
-    // Verify it's a supported 3D asset type
+    // Verify it's a supported 3D asset type or a texture
     match spec.asset_type.as_str() {
-        "static_mesh" | "skeletal_mesh" | "skeletal_animation" | "sprite" => {}
+        "static_mesh" | "skeletal_mesh" | "skeletal_animation" | "sprite" | "texture" => {}
         other => {
             anyhow::bail!(
-                "preview-grid only supports 3D assets (static_mesh, skeletal_mesh, skeletal_animation, sprite), got: {}",
+                "preview-grid only supports 3D assets (static_mesh, skeletal_mesh, skeletal_animation, sprite) and textures, got: {}",
                 other
             );
         }
@@ -62,7 +68,7 @@ pub fn run(spec_path: &str, out: Option<&str>, panel_size: u32) -> Result<ExitCo
             .find(|&name| {
                 matches!(
                     name,
-                    "static_mesh" | "skeletal_mesh" | "skeletal_animation" | "sprite"
+                    "static_mesh" | "skeletal_mesh" | "skeletal_animation" | "sprite" | "texture"
                 )
             })
             .unwrap_or("mesh");
@@ -72,6 +78,10 @@ pub fn run(spec_path: &str, out: Option<&str>, panel_size: u32) -> Result<ExitCo
             .join(format!("{}.grid.png", stem))
     };
 
+    if spec.asset_type.as_str() == "texture" {
+        return run_texture(&spec, &spec_path_pb, &out_path, panel_size);
+    }
+
     // Inject panel_size into recipe params for Blender
     if let Some(ref mut recipe) = spec.recipe {
         if let Some(params) = recipe.params.as_object_mut() {
@@ -219,6 +229,142 @@ fn composite_frames_to_grid(frames_dir: &Path, out_path: &Path, panel_size: u32)
     Ok(())
 }
 
+/// Render a labeled montage of every map a texture spec generates.
+fn run_texture(
+    spec: &Spec,
+    spec_path: &Path,
+    out_path: &Path,
+    panel_size: u32,
+) -> Result<ExitCode> {
+    let panels = texture_map_panels(spec, spec_path)?;
+    if panels.is_empty() {
+        anyhow::bail!("Texture spec produced no PNG maps to preview");
+    }
+
+    if let Some(parent) = out_path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create output directory: {}", parent.display()))?;
+    }
+
+    let grid = composite_map_grid(&panels, panel_size);
+    grid.save(out_path)
+        .with_context(|| format!("Failed to save grid: {}", out_path.display()))?;
+
+    println!(
+        "{} Grid of {} maps saved to: {}",
+        "OK".green().bold(),
+        panels.len(),
+        out_path.display()
+    );
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Generate a texture spec's maps as `(label, image)` panels in output order.
+///
+/// `texture.material_v1` recipes go through `generate_material_maps` directly;
+/// other texture recipes are generated into a temporary directory and every
+/// PNG output becomes a panel labeled by its `source` (or file stem).
+fn texture_map_panels(spec: &Spec, spec_path: &Path) -> Result<Vec<(String, DynamicImage)>> {
+    let recipe = spec
+        .recipe
+        .as_ref()
+        .context("Texture spec has no recipe to preview")?;
+
+    if recipe.kind == "texture.material_v1" {
+        let params: TextureMaterialV1Params = serde_json::from_value(recipe.params.clone())
+            .context("Invalid texture.material_v1 params")?;
+        let result = speccade_backend_texture::generate_material_maps(&params, spec.seed)?;
+
+        return params
+            .maps
+            .iter()
+            .map(|map_type| {
+                let map = &result.maps[map_type];
+                let image = image::load_from_memory(&map.data).with_context(|| {
+                    format!("Failed to decode {} map", map_type_name(*map_type))
+                })?;
+                Ok((map_type_name(*map_type).to_string(), image))
+            })
+            .collect();
+    }
+
+    let temp_dir = tempfile::tempdir()?;
+    let outputs = crate::dispatch::dispatch_generate(
+        spec,
+        temp_dir.path().to_str().unwrap_or("."),
+        spec_path,
+        None,
+    )?;
+
+    outputs
+        .iter()
+        .filter(|output| output.format == OutputFormat::Png)
+        .map(|output| {
+            let source = spec
+                .outputs
+                .iter()
+                .find(|o| Path::new(&o.path) == output.path)
+                .and_then(|o| o.source.clone());
+            let label = source.unwrap_or_else(|| {
+                output
+                    .path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("map")
+                    .to_string()
+            });
+            let map_path = temp_dir.path().join(&output.path);
+            let image = image::open(&map_path)
+                .with_context(|| format!("Failed to load map: {}", map_path.display()))?;
+            Ok((label, image))
+        })
+        .collect()
+}
+
+/// Caption used for a material map panel.
+fn map_type_name(map_type: TextureMapType) -> &'static str {
+    match map_type {
+        TextureMapType::Albedo => "albedo",
+        TextureMapType::Normal => "normal",
+        TextureMapType::Roughness => "roughness",
+        TextureMapType::Metallic => "metallic",
+        TextureMapType::Ao => "ao",
+        TextureMapType::Emissive => "emissive",
+        TextureMapType::Height => "height",
+        TextureMapType::Curvature => "curvature",
+    }
+}
+
+/// Lay out map panels left to right, up to `TEXTURE_GRID_COLUMNS` per row.
+///
+/// Maps are scaled to `panel_size` with nearest-neighbor filtering so texels
+/// stay crisp, and each panel is captioned with its label.
+fn composite_map_grid(panels: &[(String, DynamicImage)], panel_size: u32) -> RgbaImage {
+    let columns = panels.len().clamp(1, TEXTURE_GRID_COLUMNS) as u32;
+    let rows = panels.len().div_ceil(TEXTURE_GRID_COLUMNS).max(1) as u32;
+
+    let grid_width = panel_size * columns + GRID_PADDING * (columns + 1);
+    let grid_height = panel_size * rows + GRID_PADDING * (rows + 1);
+    let mut grid: RgbaImage = ImageBuffer::from_pixel(grid_width, grid_height, Rgba([0, 0, 0, 0]));
+
+    for (i, (label, image)) in panels.iter().enumerate() {
+        let col = i as u32 % columns;
+        let row = i as u32 / columns;
+        let x = GRID_PADDING + col * (panel_size + GRID_PADDING);
+        let y = GRID_PADDING + row * (panel_size + GRID_PADDING);
+
+        let resized = image
+            .resize_exact(panel_size, panel_size, image::imageops::FilterType::Nearest)
+            .to_rgba8();
+        image::imageops::replace(&mut grid, &resized, x as i64, y as i64);
+
+        draw_label(&mut grid, x + 4, y + 4, &label.to_ascii_uppercase());
+    }
+
+    grid
+}
+
 /// Draw a label with text on the image using a simple bitmap font
 fn draw_label(img: &mut RgbaImage, x: u32, y: u32, label: &str) {
     let char_width = 6_u32;
@@ -247,7 +393,7 @@ fn draw_label(img: &mut RgbaImage, x: u32, y: u32, label: &str) {
     }
 }
 
-/// Simple 5x7 bitmap font patterns for A-Z and space
+/// Simple 5x7 bitmap font patterns for A-Z, 0-9, space, `_` and `-`
 fn get_char_bitmap(ch: char) -> Option<[u8; 7]> {
     match ch.to_ascii_uppercase() {
         'A' => Some([
@@ -277,12 +423,18 @@ fn get_char_bitmap(ch: char) -> Option<[u8; 7]> {
         'I' => Some([
             0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
         ]),
+        'J' => Some([
+            0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100,
+        ]),
         'K' => Some([
             0b10001, 0b10010, 0b11100, 0b10010, 0b10001, 0b10001, 0b10001,
         ]),
         'L' => Some([
             0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111,
         ]),
+        'M' => Some([
+            0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001,
+        ]),
         'N' => Some([
             0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001, 0b10001,
         ]),
@@ -292,6 +444,9 @@ fn get_char_bitmap(ch: char) -> Option<[u8; 7]> {
         'P' => Some([
             0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000,
         ]),
+        'Q' => Some([
+            0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101,
+        ]),
         'R' => Some([
             0b11110, 0b10001, 0b10001, 0b11110, 0b10010, 0b10001, 0b10001,
         ]),
@@ -304,6 +459,57 @@ fn get_char_bitmap(ch: char) -> Option<[u8; 7]> {
         'U' => Some([
             0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110,
         ]),
+        'V' => Some([
+            0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100,
+        ]),
+        'W' => Some([
+            0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010,
+        ]),
+        'X' => Some([
+            0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001,
+        ]),
+        'Y' => Some([
+            0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100, 0b00100,
+        ]),
+        'Z' => Some([
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111,
+        ]),
+        '0' => Some([
+            0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110,
+        ]),
+        '1' => Some([
+            0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+        ]),
+        '2' => Some([
+            0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111,
+        ]),
+        '3' => Some([
+            0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110,
+        ]),
+        '4' => Some([
+            0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010,
+        ]),
+        '5' => Some([
+            0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110,
+        ]),
+        '6' => Some([
+            0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110,
+        ]),
+        '7' => Some([
+            0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000,
+        ]),
+        '8' => Some([
+            0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110,
+        ]),
+        '9' => Some([
+            0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100,
+        ]),
+        '_' => Some([
+            0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111,
+        ]),
+        '-' => Some([
+            0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000,
+        ]),
         ' ' => Some([
             0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000,
         ]),
@@ -437,6 +643,74 @@ spec(
         assert!(err.contains("preview-grid only supports 3D assets"));
     }
 
+    #[test]
+    fn test_texture_material_maps_grid() {
+        let tmp = tempfile::tempdir().unwrap();
+        let spec_path = tmp.path().join("spec.json");
+        let out_path = tmp.path().join("crate.grid.png");
+
+        let spec_json = r#"{
+            "spec_version": 1,
+            "asset_id": "test-material-grid",
+            "asset_type": "texture",
+            "license": "CC0-1.0",
+            "seed": 42,
+            "recipe": {
+                "kind": "texture.material_v1",
+                "params": {
+                    "resolution": [16, 16],
+                    "tileable": true,
+                    "maps": ["albedo", "roughness", "normal"],
+                    "base_material": {
+                        "type": "wood",
+                        "base_color": [0.6, 0.4, 0.2]
+                    }
+                }
+            },
+            "outputs": [
+                {
+                    "kind": "primary",
+                    "format": "png",
+                    "path": "crate.png"
+                }
+            ]
+        }"#;
+
+        std::fs::write(&spec_path, spec_json).unwrap();
+
+        let panel_size = 32;
+        let result = run(
+            spec_path.to_str().unwrap(),
+            Some(out_path.to_str().unwrap()),
+            panel_size,
+        );
+        assert!(result.is_ok(), "Unexpected error: {:?}", result.err());
+
+        let grid = image::open(&out_path).unwrap().to_rgba8();
+        assert_eq!(grid.width(), 3 * panel_size + 4 * GRID_PADDING);
+        assert_eq!(grid.height(), panel_size + 2 * GRID_PADDING);
+
+        for panel in 0..3 {
+            let x0 = GRID_PADDING + panel * (panel_size + GRID_PADDING);
+            let populated = (0..panel_size)
+                .flat_map(|dy| (0..panel_size).map(move |dx| (x0 + dx, GRID_PADDING + dy)))
+                .filter(|&(x, y)| grid.get_pixel(x, y)[3] > 0)
+                .count();
+            assert_eq!(
+                populated,
+                (panel_size * panel_size) as usize,
+                "panel {} is not fully populated",
+                panel
+            );
+        }
+
+        // Padding between panels stays empty.
+        assert_eq!(
+            grid.get_pixel(GRID_PADDING + panel_size, GRID_PADDING)[3],
+            0
+        );
+    }
+
     #[test]
     fn test_nonexistent_file() {
         let result = run("/nonexistent/spec.json", None, 256);
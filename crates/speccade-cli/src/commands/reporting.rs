@@ -2,7 +2,7 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use speccade_lint::RuleRegistry;
 use speccade_spec::report::LintReportData;
-use speccade_spec::{OutputResult, Spec};
+use speccade_spec::{OutputKind, OutputResult, Spec};
 use std::fs;
 use std::path::Path;
 
@@ -10,7 +10,9 @@ use crate::commands::lint::lint_report_to_data;
 
 /// Run lint on generated output files and return the combined lint report data.
 ///
-/// This is non-blocking: lint failures do not cause generation to fail.
+/// Preview outputs (waveform images, thumbnails) are skipped; they visualize an
+/// asset rather than being one. Lint findings never fail generation on their
+/// own; `generate --lint` gates on the returned data.
 /// Returns `None` if no lintable outputs exist or lint cannot run.
 pub(crate) fn run_lint_on_outputs(
     outputs: &[OutputResult],
@@ -22,6 +24,9 @@ pub(crate) fn run_lint_on_outputs(
     let mut combined_report: Option<speccade_lint::LintReport> = None;
 
     for output in outputs {
+        if output.kind == OutputKind::Preview {
+            continue;
+        }
         let asset_path = Path::new(out_root).join(&output.path);
         if !asset_path.exists() {
             continue;
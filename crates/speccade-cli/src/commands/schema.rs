@@ -0,0 +1,73 @@
+//! Schema command implementation
+//!
+//! Emits the JSON Schema for a recipe kind's params, derived from the
+//! `speccade-spec` types.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::process::ExitCode;
+
+use speccade_spec::recipe::{recipe_params_schema, schema_kinds};
+
+/// Run the schema command
+///
+/// # Arguments
+/// * `kind` - Recipe kind (e.g. `audio_v1`, `texture.material_v1`)
+/// * `out` - Output file path (default: stdout)
+///
+/// # Returns
+/// Exit code: 0 success, 1 error
+pub fn run(kind: &str, out: Option<&str>) -> Result<ExitCode> {
+    let Some(schema) = recipe_params_schema(kind) else {
+        bail!(
+            "unknown recipe kind '{}' (expected one of: {})",
+            kind,
+            schema_kinds().join(", ")
+        );
+    };
+
+    let json = serde_json::to_string_pretty(&schema).context("Failed to serialize schema")?;
+
+    match out {
+        Some(out_path) => {
+            fs::write(out_path, format!("{}\n", json))
+                .with_context(|| format!("Failed to write schema file: {}", out_path))?;
+            eprintln!(
+                "{} Wrote {} params schema to: {}",
+                "success:".green().bold(),
+                kind,
+                out_path
+            );
+        }
+        None => println!("{}", json),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_writes_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("audio_v1.schema.json");
+
+        let code = run("audio_v1", Some(out.to_str().unwrap())).unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+
+        let schema: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&out).unwrap()).unwrap();
+        assert_eq!(schema["title"], "AudioV1Params");
+        assert!(schema["properties"]["layers"].is_object());
+    }
+
+    #[test]
+    fn test_schema_rejects_unknown_kind() {
+        let err = run("audio_v9", None).unwrap_err().to_string();
+        assert!(err.contains("audio_v9"));
+        assert!(err.contains("texture.material_v1"));
+    }
+}
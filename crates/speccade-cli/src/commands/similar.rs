@@ -0,0 +1,289 @@
+//! Similar command implementation
+//!
+//! Ranks the assets in a directory by how close their feature embeddings are to
+//! the embedding of a query file. Only assets of the query's type are ranked.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use walkdir::WalkDir;
+
+use crate::analysis::{audio, detect_asset_type, embeddings, texture, AssetAnalysisType};
+
+use super::json_output::{
+    error_codes, JsonError, SimilarMatch, SimilarOutput, SimilarResult, SimilarSkipped,
+};
+
+/// Run the similar command
+///
+/// # Arguments
+/// * `query_path` - Path to the query asset (WAV or PNG)
+/// * `input_dir` - Directory to recursively scan for assets of the same type
+/// * `top_k` - Maximum number of matches to report
+/// * `json_output` - Whether to output machine-readable JSON
+///
+/// # Returns
+/// Exit code: 0 on success, 1 if the query or directory could not be read
+pub fn run(query_path: &str, input_dir: &str, top_k: usize, json_output: bool) -> Result<ExitCode> {
+    let result = find_similar(query_path, input_dir, top_k);
+
+    if json_output {
+        let output = match result {
+            Ok(result) => SimilarOutput::success(result),
+            Err(e) => {
+                let error = JsonError::new(error_codes::FILE_READ, format!("{:#}", e));
+                SimilarOutput::failure(vec![error])
+            }
+        };
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(if output.success {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::from(1)
+        });
+    }
+
+    let result = result?;
+    print_human(&result);
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Ranks the assets under `input_dir` by cosine similarity to `query_path`.
+///
+/// Matches are sorted by descending similarity, with ties broken by path, so the
+/// ranking is deterministic.
+pub fn find_similar(query_path: &str, input_dir: &str, top_k: usize) -> Result<SimilarResult> {
+    let query = Path::new(query_path);
+    let asset_type = detect_asset_type(query).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Unsupported query format. Expected .wav or .png, got: {}",
+            query
+                .extension()
+                .and_then(|e| e.to_str())
+                .unwrap_or("(none)")
+        )
+    })?;
+    if asset_type == AssetAnalysisType::Mesh {
+        anyhow::bail!("Mesh embeddings are not supported: {}", query_path);
+    }
+
+    let dir = Path::new(input_dir);
+    if !dir.is_dir() {
+        anyhow::bail!("--input-dir path is not a directory: {}", input_dir);
+    }
+
+    let query_data =
+        fs::read(query).with_context(|| format!("Failed to read query: {}", query_path))?;
+    let query_embedding = compute_embedding(&query_data, asset_type)
+        .with_context(|| format!("Failed to embed query: {}", query_path))?;
+
+    let mut files: Vec<_> = WalkDir::new(dir)
+        .follow_links(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| detect_asset_type(e.path()) == Some(asset_type))
+        .map(|e| e.path().to_path_buf())
+        .collect();
+    files.sort();
+
+    let mut matches = Vec::new();
+    let mut skipped = Vec::new();
+    for path in &files {
+        let path_str = path.display().to_string();
+        let embedding = fs::read(path)
+            .map_err(|e| anyhow::anyhow!("Failed to read: {}", e))
+            .and_then(|data| compute_embedding(&data, asset_type));
+        match embedding {
+            Ok(embedding) => matches.push(SimilarMatch {
+                path: path_str,
+                similarity: embeddings::cosine_similarity(&query_embedding, &embedding),
+            }),
+            Err(e) => skipped.push(SimilarSkipped {
+                path: path_str,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    let candidates = matches.len();
+    matches.sort_by(|a, b| {
+        b.similarity
+            .total_cmp(&a.similarity)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    matches.truncate(top_k);
+
+    Ok(SimilarResult {
+        query: query_path.to_string(),
+        asset_type: asset_type.as_str().to_string(),
+        input_dir: input_dir.to_string(),
+        candidates,
+        matches,
+        skipped,
+    })
+}
+
+/// Computes the feature embedding of an audio or texture file.
+fn compute_embedding(data: &[u8], asset_type: AssetAnalysisType) -> Result<Vec<f64>> {
+    match asset_type {
+        AssetAnalysisType::Audio => {
+            let (samples, sample_rate) = audio::extract_wav_samples(data)
+                .map_err(|e| anyhow::anyhow!("Audio extraction failed: {}", e))?;
+            Ok(embeddings::compute_audio_embedding(&samples, sample_rate))
+        }
+        AssetAnalysisType::Texture => {
+            let (pixels, width, height, channels) = texture::extract_png_pixels(data)
+                .map_err(|e| anyhow::anyhow!("Texture extraction failed: {}", e))?;
+            Ok(embeddings::compute_texture_embedding(
+                &pixels, width, height, channels,
+            ))
+        }
+        AssetAnalysisType::Mesh => anyhow::bail!("Mesh embeddings are not supported"),
+    }
+}
+
+/// Print the ranking in human-readable format.
+fn print_human(result: &SimilarResult) {
+    println!("{} {}", "Query:".cyan().bold(), result.query);
+    println!("{} {}", "Type:".dimmed(), result.asset_type);
+    println!(
+        "{} {} ({} candidates)",
+        "Searched:".dimmed(),
+        result.input_dir,
+        result.candidates
+    );
+
+    if result.matches.is_empty() {
+        println!("\n{}", "No assets of the query type found".yellow());
+    } else {
+        println!();
+        for (rank, m) in result.matches.iter().enumerate() {
+            println!("  {:>3}. {:.4}  {}", rank + 1, m.similarity, m.path);
+        }
+    }
+
+    for skipped in &result.skipped {
+        println!(
+            "  {} {} {}",
+            "!".yellow(),
+            skipped.path,
+            skipped.error.dimmed()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_png(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+        let mut png_data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png_data, width, height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(pixels).unwrap();
+        drop(writer);
+        png_data
+    }
+
+    /// Writes a small set of distinct 16x16 textures to `dir`.
+    fn write_texture_set(dir: &Path) {
+        let size = 16u32;
+        let pixel = |f: &dyn Fn(u32, u32) -> [u8; 4]| {
+            let mut pixels = Vec::new();
+            for y in 0..size {
+                for x in 0..size {
+                    pixels.extend_from_slice(&f(x, y));
+                }
+            }
+            create_test_png(size, size, &pixels)
+        };
+
+        let gradient = pixel(&|x, _| [(x * 16) as u8, 64, 255 - (x * 16) as u8, 255]);
+        let checker = pixel(&|x, y| {
+            if (x / 4 + y / 4) % 2 == 0 {
+                [255, 255, 255, 255]
+            } else {
+                [0, 0, 0, 255]
+            }
+        });
+        let red = pixel(&|_, _| [220, 30, 30, 255]);
+        let noise = pixel(&|x, y| {
+            let v = ((x * 73 + y * 151) % 256) as u8;
+            [v, v.wrapping_mul(3), v.wrapping_mul(7), 255]
+        });
+
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("gradient.png"), gradient).unwrap();
+        fs::write(dir.join("checker.png"), checker).unwrap();
+        fs::write(dir.join("nested/red.png"), red).unwrap();
+        fs::write(dir.join("nested/noise.png"), noise).unwrap();
+        fs::write(dir.join("notes.txt"), b"not an asset").unwrap();
+    }
+
+    #[test]
+    fn test_query_ranks_itself_first() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_texture_set(tmp.path());
+        let dir = tmp.path().to_str().unwrap();
+
+        for name in ["gradient.png", "checker.png", "nested/noise.png"] {
+            let query = tmp.path().join(name);
+            let result = find_similar(query.to_str().unwrap(), dir, 10).unwrap();
+
+            assert_eq!(result.asset_type, "texture");
+            assert_eq!(result.candidates, 4);
+            assert_eq!(result.matches.len(), 4);
+            assert_eq!(result.matches[0].path, query.display().to_string());
+            assert!((result.matches[0].similarity - 1.0).abs() < 1e-9);
+            assert!(result
+                .matches
+                .windows(2)
+                .all(|w| w[0].similarity >= w[1].similarity));
+        }
+    }
+
+    #[test]
+    fn test_similar_is_deterministic_and_respects_top_k() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_texture_set(tmp.path());
+        let dir = tmp.path().to_str().unwrap();
+        let query = tmp.path().join("checker.png");
+        let query = query.to_str().unwrap();
+
+        let first = find_similar(query, dir, 2).unwrap();
+        let second = find_similar(query, dir, 2).unwrap();
+        assert_eq!(first.matches.len(), 2);
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            serde_json::to_string(&second).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_similar_rejects_unsupported_query() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_texture_set(tmp.path());
+        let query = tmp.path().join("notes.txt");
+
+        let code = run(
+            query.to_str().unwrap(),
+            tmp.path().to_str().unwrap(),
+            5,
+            true,
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::from(1));
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        assert!((embeddings::cosine_similarity(&[1.0, 2.0], &[2.0, 4.0]) - 1.0).abs() < 1e-12);
+        assert!(embeddings::cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]).abs() < 1e-12);
+        assert_eq!(embeddings::cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+    }
+}
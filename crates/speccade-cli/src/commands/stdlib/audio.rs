@@ -5,6 +5,7 @@ use super::{func, param, FunctionInfo};
 const WAVEFORMS: &[&str] = &["sine", "square", "sawtooth", "triangle", "pulse"];
 const NOISE_TYPES: &[&str] = &["white", "pink", "brown"];
 const SWEEP_CURVES: &[&str] = &["linear", "exponential", "logarithmic"];
+const ANTI_ALIAS_MODES: &[&str] = &["none", "polyblep", "oversample"];
 
 pub(super) fn register_functions() -> Vec<FunctionInfo> {
     vec![
@@ -20,6 +21,9 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("curve", "string", opt, "linear", enum: SWEEP_CURVES),
                 param!("detune", "float", opt_none),
                 param!("duty", "float", opt_none, range: Some(0.0), Some(1.0)),
+                param!("anti_alias", "string", opt_none, enum: ANTI_ALIAS_MODES),
+                param!("detune_voices", "int", opt_none, range: Some(1.0), Some(16.0)),
+                param!("detune_spread", "float", opt_none, range: Some(0.0), None),
             ],
             "A dict matching the Synthesis::Oscillator IR structure.",
             r#"oscillator(440, "sawtooth", 220, "exponential")"#
@@ -243,6 +247,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("bow_pressure", "float", req, range: Some(0.0), Some(1.0)),
                 param!("bow_position", "float", req, range: Some(0.0), Some(1.0)),
                 param!("damping", "float", req, range: Some(0.0), Some(1.0)),
+                param!("bow_velocity", "float", opt_none, range: Some(0.0), Some(1.0)),
             ],
             "A dict matching the Synthesis::BowedString IR structure."
         ),
@@ -289,6 +294,18 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             "A dict matching the Synthesis::PitchedBody IR structure.",
             "pitched_body(start_freq=880, end_freq=110)"
         ),
+        func!(
+            "transient",
+            "audio.synthesis",
+            "Creates a Transient synthesis block (short percussive click).",
+            vec![
+                param!("sharpness", "float", req, range: Some(0.0), Some(1.0)),
+                param!("pitch", "float", req, range: Some(0.0), None),
+                param!("noise_mix", "float", opt, 0.3, range: Some(0.0), Some(1.0)),
+            ],
+            "A dict matching the Synthesis::Transient IR structure.",
+            "transient(sharpness=0.8, pitch=1200, noise_mix=0.4)"
+        ),
         // === AUDIO FILTERS ===
         func!(
             "lowpass",
@@ -298,6 +315,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("cutoff", "float", req, range: Some(0.0), None),
                 param!("resonance", "float", opt, 0.707, range: Some(0.0), None),
                 param!("sweep_to", "float", opt_none, range: Some(0.0), None),
+                param!("keytrack", "float", opt_none, range: Some(-2.0), Some(2.0)),
             ],
             "A filter dict.",
             "lowpass(5000, 0.707)"
@@ -309,6 +327,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             vec![
                 param!("cutoff", "float", req, range: Some(0.0), None),
                 param!("resonance", "float", opt, 0.707, range: Some(0.0), None),
+                param!("keytrack", "float", opt_none, range: Some(-2.0), Some(2.0)),
             ],
             "A filter dict.",
             "highpass(200)"
@@ -320,6 +339,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             vec![
                 param!("center", "float", req, range: Some(0.0), None),
                 param!("bandwidth", "float", req, range: Some(0.0), None),
+                param!("keytrack", "float", opt_none, range: Some(-2.0), Some(2.0)),
             ],
             "A filter dict.",
             "bandpass(1000, 200)"
@@ -334,6 +354,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("wet", "float", opt, 0.3, range: Some(0.0), Some(1.0)),
                 param!("room_size", "float", opt, 0.8, range: Some(0.0), Some(1.0)),
                 param!("width", "float", opt, 1.0, range: Some(0.0), Some(1.0)),
+                param!("dry", "float", opt_none, range: Some(0.0), Some(1.0)),
             ],
             "An effect dict.",
             "reverb(0.5, 0.3)"
@@ -347,6 +368,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("feedback", "float", opt, 0.4, range: Some(0.0), Some(1.0)),
                 param!("wet", "float", opt, 0.3, range: Some(0.0), Some(1.0)),
                 param!("ping_pong", "bool", opt, false),
+                param!("tone", "float", opt_none, range: Some(0.0), None),
             ],
             "An effect dict.",
             "delay(500, 0.5, 0.4)"
@@ -374,6 +396,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("depth", "float", req, range: Some(0.0), Some(1.0)),
                 param!("wet", "float", req, range: Some(0.0), Some(1.0)),
                 param!("voices", "int", opt, 2, range: Some(1.0), Some(4.0)),
+                param!("spread", "float", opt_none, range: Some(0.0), Some(1.0)),
             ],
             "An effect dict.",
             "chorus(1.5, 0.3, 0.25)"
@@ -385,6 +408,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             vec![
                 param!("bits", "int", req, range: Some(1.0), Some(16.0)),
                 param!("sample_rate_reduction", "float", opt, 1.0, range: Some(1.0), None),
+                param!("downsample", "int", opt, 1, range: Some(1.0), None),
             ],
             "An effect dict.",
             "bitcrush(8, 4.0)"
@@ -426,6 +450,9 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("filter", "dict", opt_none),
                 param!("volume", "float", opt, 1.0, range: Some(0.0), Some(1.0)),
                 param!("pan", "float", opt, 0.0, range: Some(-1.0), Some(1.0)),
+                param!("muted", "bool", opt, false),
+                param!("solo", "bool", opt, false),
+                param!("gain_automation", "list", opt_none),
             ],
             "A layer dict.",
             "audio_layer(oscillator(440), envelope(0.01, 0.1, 0.7, 0.2))"
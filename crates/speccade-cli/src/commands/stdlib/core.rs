@@ -1,4 +1,4 @@
-//! Core stdlib functions (spec, output, envelope)
+//! Core stdlib functions (spec, output, sweep, envelope)
 
 use super::{func, param, FunctionInfo};
 use speccade_spec::{AssetType, OutputFormat, OutputKind};
@@ -16,12 +16,13 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             vec![
                 param!("asset_id", "string", req),
                 param!("asset_type", "string", req, enum: &asset_types),
-                param!("seed", "int", req, range: Some(0.0), Some(4294967295.0)),
+                param!("seed", "int", opt_none, range: Some(0.0), Some(4294967295.0)),
                 param!("outputs", "list", req),
                 param!("recipe", "dict", opt_none),
                 param!("license", "string", opt, "CC0-1.0"),
                 param!("description", "string", opt_none),
                 param!("style_tags", "list", opt_none),
+                param!("variants", "list", opt_none),
             ],
             "A complete spec dict ready for serialization.",
             r#"spec(asset_id="laser-01", asset_type="audio", seed=42, outputs=[...], recipe={...})"#
@@ -39,17 +40,31 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             "An output dict for the spec outputs list.",
             r#"output("sounds/laser.wav", "wav")"#
         ),
+        func!(
+            "sweep",
+            "core",
+            "Sweeps a recipe parameter across values, producing one variant per value.",
+            vec![
+                param!("param_path", "string", req),
+                param!("values", "list", req),
+                param!("prefix", "string", opt_none),
+            ],
+            "A list of variant dicts for spec(variants = ...).",
+            r#"sweep("recipe.params.nodes[0].noise.scale", [0.05, 0.1, 0.2])"#
+        ),
         func!(
             "envelope",
             "core",
-            "Creates an ADSR envelope configuration.",
+            "Creates an ADSR envelope configuration, with optional delay and hold stages (DAHDSR).",
             vec![
                 param!("attack", "float", opt, 0.01, range: Some(0.0), None),
                 param!("decay", "float", opt, 0.1, range: Some(0.0), None),
                 param!("sustain", "float", opt, 0.7, range: Some(0.0), Some(1.0)),
                 param!("release", "float", opt, 0.2, range: Some(0.0), None),
+                param!("delay", "float", opt, 0.0, range: Some(0.0), None),
+                param!("hold", "float", opt, 0.0, range: Some(0.0), None),
             ],
-            "An ADSR envelope dict.",
+            "An envelope dict.",
             "envelope(0.01, 0.1, 0.7, 0.2)"
         ),
     ]
@@ -26,6 +26,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("wav", "string", opt_none),
                 param!("envelope", "dict", opt_none),
                 param!("loop_mode", "string", opt_none, enum: &["auto", "none", "forward", "pingpong"]),
+                param!("stereo", "bool", opt, false),
             ],
             "An instrument dict.",
             r#"tracker_instrument(name="bass", synthesis=instrument_synthesis("sawtooth"))"#
@@ -71,7 +72,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             "music.song",
             "Creates a complete tracker song recipe.",
             vec![
-                param!("format", "string", req, enum: &["xm", "it"]),
+                param!("format", "string", req, enum: &["xm", "it", "mod", "s3m"]),
                 param!("bpm", "int", req, range: Some(32.0), Some(255.0)),
                 param!("speed", "int", req, range: Some(1.0), Some(31.0)),
                 param!("channels", "int", req, range: Some(1.0), Some(64.0)),
@@ -90,7 +91,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
                 param!("asset_id", "string", req),
                 param!("seed", "int", req, range: Some(0.0), Some(4294967295.0)),
                 param!("output_path", "string", req),
-                param!("format", "string", req, enum: &["xm", "it"]),
+                param!("format", "string", req, enum: &["xm", "it", "mod", "s3m"]),
                 param!("bpm", "int", req, range: Some(32.0), Some(255.0)),
                 param!("speed", "int", req, range: Some(1.0), Some(31.0)),
                 param!("channels", "int", req, range: Some(1.0), Some(64.0)),
@@ -11,7 +11,7 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             "Creates a noise texture node.",
             vec![
                 param!("id", "string", req),
-                param!("algorithm", "string", opt, "perlin", enum: &["perlin", "simplex", "worley", "value", "gabor", "fbm"]),
+                param!("algorithm", "string", opt, "perlin", enum: &["perlin", "simplex", "worley", "value", "gabor", "fbm", "ridged_multifractal", "billow"]),
                 param!("scale", "float", opt, 0.1, range: Some(0.0), None),
                 param!("octaves", "int", opt, 4, range: Some(1.0), None),
                 param!("persistence", "float", opt, 0.5),
@@ -95,5 +95,43 @@ pub(super) fn register_functions() -> Vec<FunctionInfo> {
             "A texture graph params dict.",
             r#"texture_graph([64, 64], [noise_node("base", "perlin")])"#
         ),
+        // === TEXTURE GRAPH BUILDERS ===
+        func!(
+            "noise",
+            "texture.graph",
+            "Creates a noise node list with an automatically generated id.",
+            vec![
+                param!("algorithm", "string", opt, "perlin", enum: &["perlin", "simplex", "worley", "value", "gabor", "fbm", "ridged_multifractal", "billow"]),
+                param!("scale", "float", opt, 0.1, range: Some(0.0), None),
+                param!("octaves", "int", opt, 4, range: Some(1.0), None),
+                param!("persistence", "float", opt, 0.5),
+                param!("lacunarity", "float", opt, 2.0),
+            ],
+            "A list of texture node dicts (last node is the output).",
+            r#"noise("perlin", 0.05)"#
+        ),
+        func!(
+            "threshold",
+            "texture.graph",
+            "Thresholds an input into {0, 1}, generating the node id automatically.",
+            vec![
+                param!("input", "list", req),
+                param!("threshold", "float", opt, 0.5),
+            ],
+            "A list of texture node dicts (last node is the output).",
+            r#"threshold(noise("worley", 0.2), 0.6)"#
+        ),
+        func!(
+            "blend",
+            "texture.graph",
+            "Blends two inputs, generating the node id automatically.",
+            vec![
+                param!("base", "list", req),
+                param!("top", "list", req),
+                param!("mode", "string", opt, "multiply", enum: &["add", "multiply", "screen", "overlay", "soft_light", "difference"]),
+            ],
+            "A list of texture node dicts (last node is the output).",
+            r#"blend(noise("perlin", 0.05), noise("worley", 0.2), "screen")"#
+        ),
     ]
 }
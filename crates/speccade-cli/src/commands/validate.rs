@@ -2,10 +2,10 @@
 //!
 //! Validates a spec file and writes a report.
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use speccade_spec::{
-    canonical_recipe_hash, canonical_spec_hash, validate_for_generate_with_budget,
+    canonical_recipe_hash, canonical_spec_hash, validate_for_generate_with_budget, validate_spec,
     validate_spec_with_budget, BudgetProfile, ReportBuilder,
 };
 use std::path::Path;
@@ -17,7 +17,8 @@ use super::json_output::{
     validation_warning_to_json, JsonError, JsonWarning, ValidateOutput, ValidateResult,
 };
 use super::reporting;
-use crate::input::{load_spec, LoadResult};
+use super::validate_fix::{apply_fixes, FixNote};
+use crate::input::{load_spec, LoadResult, SourceKind};
 
 /// Run the validate command
 ///
@@ -26,6 +27,7 @@ use crate::input::{load_spec, LoadResult};
 /// * `artifacts` - Whether to also validate artifact references
 /// * `budget_name` - Optional budget profile name (default, strict, zx-8bit)
 /// * `json_output` - Whether to output machine-readable JSON diagnostics
+/// * `fix` - Whether to apply safe fixes and write the spec back first
 ///
 /// # Returns
 /// Exit code: 0 if valid, 1 if invalid
@@ -34,7 +36,12 @@ pub fn run(
     artifacts: bool,
     budget_name: Option<&str>,
     json_output: bool,
+    fix: bool,
 ) -> Result<ExitCode> {
+    if fix {
+        fix_in_place(spec_path, json_output)?;
+    }
+
     if json_output {
         run_json(spec_path, artifacts, budget_name)
     } else {
@@ -42,6 +49,75 @@ pub fn run(
     }
 }
 
+/// Apply safe fixes to a JSON spec file and write it back.
+///
+/// Progress goes to stderr in JSON mode so stdout stays machine-readable.
+/// Load failures are left for the validation pass to report.
+fn fix_in_place(spec_path: &str, json_output: bool) -> Result<()> {
+    let say = |line: String| {
+        if json_output {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    };
+
+    let Ok(LoadResult {
+        mut spec,
+        source_kind,
+        ..
+    }) = load_spec(Path::new(spec_path))
+    else {
+        return Ok(());
+    };
+    if source_kind != SourceKind::Json {
+        bail!(
+            "--fix only rewrites JSON specs; {} is a {} source",
+            spec_path,
+            source_kind
+        );
+    }
+
+    say(format!("{} {}", "Fixing:".cyan().bold(), spec_path));
+
+    let result = validate_spec(&spec);
+    let fixes = apply_fixes(&mut spec, &result);
+
+    let note_line = |note: &FixNote| {
+        let path_info = note
+            .path
+            .as_ref()
+            .map(|p| format!(" at {}", p))
+            .unwrap_or_default();
+        format!("[{}]{}: {}", note.code, path_info.dimmed(), note.message)
+    };
+    for note in &fixes.applied {
+        say(format!("  {} {}", "+".green(), note_line(note)));
+    }
+    for note in &fixes.skipped {
+        say(format!("  {} {}", "x".red(), note_line(note)));
+    }
+
+    if fixes.applied.is_empty() {
+        say(format!("{}", "No automatic fixes applied".dimmed()));
+        return Ok(());
+    }
+
+    let json = spec
+        .to_json_pretty()
+        .context("Failed to serialize fixed spec")?;
+    std::fs::write(spec_path, format!("{}\n", json))
+        .with_context(|| format!("Failed to write fixed spec: {}", spec_path))?;
+    say(format!(
+        "{} Applied {} fix(es) to {}\n",
+        "FIXED".green().bold(),
+        fixes.applied.len(),
+        spec_path
+    ));
+
+    Ok(())
+}
+
 /// Run validate with human-readable (colored) output
 fn run_human(spec_path: &str, artifacts: bool, budget_name: Option<&str>) -> Result<ExitCode> {
     let start = Instant::now();
@@ -359,7 +435,7 @@ mod tests {
 
         let spec_path = write_spec(&tmp, "spec.json", &spec);
 
-        let code = run(spec_path.to_str().unwrap(), false, None, false).unwrap();
+        let code = run(spec_path.to_str().unwrap(), false, None, false, false).unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
 
         let report_path = reporting::report_path(spec_path.to_str().unwrap(), &spec.asset_id);
@@ -382,7 +458,7 @@ mod tests {
 
         let spec_path = write_spec(&tmp, "spec.json", &spec);
 
-        let code = run(spec_path.to_str().unwrap(), true, None, false).unwrap();
+        let code = run(spec_path.to_str().unwrap(), true, None, false, false).unwrap();
         assert_eq!(code, ExitCode::from(1));
 
         let report_path = reporting::report_path(spec_path.to_str().unwrap(), &spec.asset_id);
@@ -406,14 +482,14 @@ mod tests {
         let spec_path = write_spec(&tmp, "spec.json", &spec);
 
         // Run with json=true - should succeed
-        let code = run(spec_path.to_str().unwrap(), false, None, true).unwrap();
+        let code = run(spec_path.to_str().unwrap(), false, None, true, false).unwrap();
         assert_eq!(code, ExitCode::SUCCESS);
     }
 
     #[test]
     fn validate_json_output_failure() {
         // Run with json=true on nonexistent file - should return exit code 1
-        let code = run("/nonexistent/spec.json", false, None, true).unwrap();
+        let code = run("/nonexistent/spec.json", false, None, true, false).unwrap();
         assert_eq!(code, ExitCode::from(1));
     }
 
@@ -431,7 +507,51 @@ mod tests {
         let spec_path = write_spec(&tmp, "spec.json", &spec);
 
         // Run with artifacts=true which requires a recipe - should fail
-        let code = run(spec_path.to_str().unwrap(), true, None, true).unwrap();
+        let code = run(spec_path.to_str().unwrap(), true, None, true, false).unwrap();
+        assert_eq!(code, ExitCode::from(1));
+    }
+
+    #[test]
+    fn validate_fix_rewrites_extension_mismatch() {
+        let tmp = tempfile::tempdir().unwrap();
+
+        let spec = Spec::builder("validate-fix-test-01", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(42)
+            .description("test asset")
+            .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.png"))
+            .build();
+
+        let spec_path = write_spec(&tmp, "spec.json", &spec);
+
+        // Without --fix the mismatch fails validation and the file is untouched
+        let code = run(spec_path.to_str().unwrap(), false, None, false, false).unwrap();
+        assert_eq!(code, ExitCode::from(1));
+
+        let code = run(spec_path.to_str().unwrap(), false, None, false, true).unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+
+        let fixed = Spec::from_json(&std::fs::read_to_string(&spec_path).unwrap()).unwrap();
+        assert_eq!(fixed.outputs[0].path, "sounds/test.wav");
+        assert_eq!(fixed.asset_id, spec.asset_id);
+    }
+
+    #[test]
+    fn validate_fix_leaves_invalid_asset_id_alone() {
+        let tmp = tempfile::tempdir().unwrap();
+
+        let spec = Spec::builder("Bad Asset ID", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(42)
+            .description("test asset")
+            .output(OutputSpec::primary(OutputFormat::Wav, "test.wav"))
+            .build();
+
+        let spec_path = write_spec(&tmp, "spec.json", &spec);
+        let before = std::fs::read_to_string(&spec_path).unwrap();
+
+        let code = run(spec_path.to_str().unwrap(), false, None, false, true).unwrap();
         assert_eq!(code, ExitCode::from(1));
+        assert_eq!(std::fs::read_to_string(&spec_path).unwrap(), before);
     }
 }
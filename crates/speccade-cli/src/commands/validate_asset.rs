@@ -111,9 +111,13 @@ pub fn run(spec_path: &str, out_root: Option<&str>, _full_report: bool) -> Resul
         false,
         false,
         None,
+        generate::VariationSeedMode::Increment,
         None,
         None,
         false,
+        None,
+        None,
+        None,
     );
     let gen_elapsed = step_start.elapsed();
 
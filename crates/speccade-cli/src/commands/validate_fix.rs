@@ -0,0 +1,196 @@
+//! Auto-fixes for `validate --fix`.
+//!
+//! Maps validation codes to deterministic rewrites of the spec. Anything that
+//! needs a judgement call is reported back untouched.
+
+use speccade_spec::{ErrorCode, Spec, ValidationError, ValidationResult, WarningCode};
+
+/// License written for specs that declare none (W001).
+const DEFAULT_LICENSE: &str = "CC0-1.0";
+
+/// A single issue and what `--fix` did about it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct FixNote {
+    /// Validation code (e.g. "E009").
+    pub code: &'static str,
+    /// JSON path of the issue.
+    pub path: Option<String>,
+    /// What changed, or why nothing did.
+    pub message: String,
+}
+
+/// Outcome of applying fixes to a spec.
+#[derive(Debug, Default)]
+pub(super) struct FixReport {
+    /// Fixes applied to the spec.
+    pub applied: Vec<FixNote>,
+    /// Errors left for the author.
+    pub skipped: Vec<FixNote>,
+}
+
+/// Applies the safe fixes for the issues in `result` to `spec`.
+///
+/// Fixed issues:
+/// - W001: empty license becomes `CC0-1.0`
+/// - E009: the output path extension is rewritten to match its format,
+///   unless that would collide with another output path
+/// - E007: an output identical to an earlier one is dropped
+pub(super) fn apply_fixes(spec: &mut Spec, result: &ValidationResult) -> FixReport {
+    let mut report = FixReport::default();
+
+    for warning in &result.warnings {
+        if warning.code == WarningCode::MissingLicense && spec.license.is_empty() {
+            spec.license = DEFAULT_LICENSE.to_string();
+            report.applied.push(FixNote {
+                code: warning.code.code(),
+                path: warning.path.clone(),
+                message: format!("set license to '{}'", DEFAULT_LICENSE),
+            });
+        }
+    }
+
+    // Decide duplicates against the untouched outputs, before any renames.
+    let mut duplicates = Vec::new();
+    for error in &result.errors {
+        let index = error.path.as_deref().and_then(output_index);
+        if let (ErrorCode::DuplicateOutputPath, Some(i)) = (error.code, index) {
+            let note = if spec.outputs[..i].contains(&spec.outputs[i]) {
+                duplicates.push(i);
+                Ok(format!(
+                    "removed duplicate output '{}'",
+                    spec.outputs[i].path
+                ))
+            } else {
+                Err("outputs share a path but differ; pick one by hand".to_string())
+            };
+            push_note(&mut report, error, note);
+        }
+    }
+
+    for error in &result.errors {
+        let index = error.path.as_deref().and_then(output_index);
+        let note = match (error.code, index) {
+            (ErrorCode::DuplicateOutputPath, Some(_)) => continue,
+            // Dropped below, so there is nothing to rename.
+            (ErrorCode::PathFormatMismatch, Some(i)) if duplicates.contains(&i) => continue,
+            (ErrorCode::PathFormatMismatch, Some(i)) => fix_extension(spec, i, &duplicates),
+            _ => Err(format!("not auto-fixable: {}", error.message)),
+        };
+        push_note(&mut report, error, note);
+    }
+
+    // Highest index first so the remaining indices stay valid.
+    duplicates.sort_unstable();
+    for i in duplicates.into_iter().rev() {
+        spec.outputs.remove(i);
+    }
+
+    report
+}
+
+/// Rewrites the extension of `outputs[i].path` to match its format.
+///
+/// Outputs listed in `removed` are ignored when checking for collisions.
+fn fix_extension(spec: &mut Spec, i: usize, removed: &[usize]) -> Result<String, String> {
+    let output = &spec.outputs[i];
+    let (dir, file) = output
+        .path
+        .rsplit_once('/')
+        .unwrap_or(("", output.path.as_str()));
+    let stem = file.rsplit_once('.').map_or(file, |(stem, _)| stem);
+    if stem.is_empty() {
+        return Err(format!("no file name in '{}'", output.path));
+    }
+
+    let file = format!("{}.{}", stem, output.expected_extension());
+    let fixed = if dir.is_empty() {
+        file
+    } else {
+        format!("{}/{}", dir, file)
+    };
+    let taken = spec
+        .outputs
+        .iter()
+        .enumerate()
+        .any(|(j, o)| o.path == fixed && !removed.contains(&j));
+    if taken {
+        return Err(format!("'{}' is already used by another output", fixed));
+    }
+
+    let message = format!("renamed '{}' to '{}'", output.path, fixed);
+    spec.outputs[i].path = fixed;
+    Ok(message)
+}
+
+/// Parses the index out of an `outputs[N].path` error path.
+fn output_index(path: &str) -> Option<usize> {
+    path.strip_prefix("outputs[")?
+        .strip_suffix("].path")?
+        .parse()
+        .ok()
+}
+
+fn push_note(report: &mut FixReport, error: &ValidationError, note: Result<String, String>) {
+    let (list, message) = match note {
+        Ok(message) => (&mut report.applied, message),
+        Err(message) => (&mut report.skipped, message),
+    };
+    list.push(FixNote {
+        code: error.code.code(),
+        path: error.path.clone(),
+        message,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use speccade_spec::{validate_spec, AssetType, OutputFormat, OutputSpec};
+
+    fn fix(spec: &mut Spec) -> FixReport {
+        let result = validate_spec(spec);
+        apply_fixes(spec, &result)
+    }
+
+    #[test]
+    fn fixes_license_extension_and_exact_duplicates() {
+        let mut spec = Spec::builder("fix-test-01", AssetType::Audio)
+            .seed(1)
+            .output(OutputSpec::primary(OutputFormat::Wav, "sounds/hit.ogg"))
+            .output(OutputSpec::primary(OutputFormat::Wav, "sounds/miss"))
+            .output(OutputSpec::primary(OutputFormat::Wav, "sounds/miss"))
+            .build();
+        spec.license.clear();
+
+        let report = fix(&mut spec);
+
+        assert_eq!(spec.license, "CC0-1.0");
+        assert_eq!(spec.outputs.len(), 2);
+        assert_eq!(spec.outputs[0].path, "sounds/hit.wav");
+        assert_eq!(spec.outputs[1].path, "sounds/miss.wav");
+        let codes: Vec<_> = report.applied.iter().map(|n| n.code).collect();
+        assert!(codes.contains(&"W001"));
+        assert!(codes.contains(&"E007"));
+        assert!(codes.contains(&"E009"));
+    }
+
+    #[test]
+    fn leaves_ambiguous_issues_alone() {
+        let mut spec = Spec::builder("fix-test-02", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(1)
+            .output(OutputSpec::primary(OutputFormat::Wav, "hit.wav"))
+            .output(OutputSpec::primary(OutputFormat::Wav, "hit.png"))
+            .output(OutputSpec::primary(OutputFormat::Png, "hit.wav"))
+            .build();
+        let before = spec.clone();
+
+        let report = fix(&mut spec);
+
+        // Renaming hit.png would collide, and the duplicate hit.wav differs.
+        assert_eq!(spec, before);
+        assert!(report.applied.is_empty());
+        assert!(report.skipped.iter().any(|n| n.code == "E009"));
+        assert!(report.skipped.iter().any(|n| n.code == "E007"));
+    }
+}
@@ -5,9 +5,12 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use speccade_spec::{evaluate_constraints, ConstraintSet, OutputMetrics, Report, VerifyResult};
+use speccade_spec::{
+    evaluate_constraints_with_files, ConstraintFiles, ConstraintSet, OutputMetrics, Report,
+    VerifyResult,
+};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
 use super::json_output::{error_codes, JsonError};
@@ -58,20 +61,44 @@ impl VerifyOutput {
 /// # Arguments
 /// * `report_path` - Path to the *.report.json file
 /// * `constraints_path` - Path to the *.constraints.json file
+/// * `asset_path` - Optional path to the generated asset, for constraints that
+///   compare file contents (e.g. spectral similarity)
 /// * `json_output` - Whether to output machine-readable JSON
 ///
 /// # Returns
 /// Exit code: 0 if all constraints pass, 1 if any fail or error occurs
-pub fn run(report_path: &str, constraints_path: &str, json_output: bool) -> Result<ExitCode> {
+pub fn run(
+    report_path: &str,
+    constraints_path: &str,
+    asset_path: Option<&str>,
+    json_output: bool,
+) -> Result<ExitCode> {
+    let files = constraint_files(constraints_path, asset_path);
     if json_output {
-        run_json(report_path, constraints_path)
+        run_json(report_path, constraints_path, &files)
     } else {
-        run_human(report_path, constraints_path)
+        run_human(report_path, constraints_path, &files)
+    }
+}
+
+/// Builds the file context for constraint evaluation. Reference paths in the
+/// constraints file resolve relative to the constraints file's directory.
+fn constraint_files(constraints_path: &str, asset_path: Option<&str>) -> ConstraintFiles {
+    ConstraintFiles {
+        asset: asset_path.map(PathBuf::from),
+        reference_dir: Path::new(constraints_path)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default(),
     }
 }
 
 /// Run verify with human-readable (colored) output.
-fn run_human(report_path: &str, constraints_path: &str) -> Result<ExitCode> {
+fn run_human(
+    report_path: &str,
+    constraints_path: &str,
+    files: &ConstraintFiles,
+) -> Result<ExitCode> {
     println!("{} {}", "Report:".cyan().bold(), report_path);
     println!("{} {}", "Constraints:".cyan().bold(), constraints_path);
 
@@ -103,7 +130,8 @@ fn run_human(report_path: &str, constraints_path: &str) -> Result<ExitCode> {
 
         // Create a result with all constraints skipped
         let empty_metrics = OutputMetrics::new();
-        let result = evaluate_constraints(&asset_id, &empty_metrics, &constraints);
+        let result =
+            evaluate_constraints_with_files(&asset_id, &empty_metrics, &constraints, files);
 
         print_results(&result);
 
@@ -120,7 +148,7 @@ fn run_human(report_path: &str, constraints_path: &str) -> Result<ExitCode> {
     let metrics = metrics.unwrap_or_default();
 
     // Evaluate constraints
-    let result = evaluate_constraints(&asset_id, &metrics, &constraints);
+    let result = evaluate_constraints_with_files(&asset_id, &metrics, &constraints, files);
 
     // Print results
     print_results(&result);
@@ -147,7 +175,11 @@ fn run_human(report_path: &str, constraints_path: &str) -> Result<ExitCode> {
 }
 
 /// Run verify with machine-readable JSON output.
-fn run_json(report_path: &str, constraints_path: &str) -> Result<ExitCode> {
+fn run_json(
+    report_path: &str,
+    constraints_path: &str,
+    files: &ConstraintFiles,
+) -> Result<ExitCode> {
     // Load report
     let report = match load_report(Path::new(report_path)) {
         Ok(r) => r,
@@ -196,7 +228,7 @@ fn run_json(report_path: &str, constraints_path: &str) -> Result<ExitCode> {
     let metrics = extract_metrics(&report).unwrap_or_default();
 
     // Evaluate constraints
-    let result = evaluate_constraints(&asset_id, &metrics, &constraints);
+    let result = evaluate_constraints_with_files(&asset_id, &metrics, &constraints, files);
 
     // Build output
     let output = if result.overall_pass {
@@ -335,6 +367,7 @@ mod tests {
         let code = run(
             report_path.to_str().unwrap(),
             constraints_path.to_str().unwrap(),
+            None,
             false,
         )
         .unwrap();
@@ -363,6 +396,7 @@ mod tests {
         let code = run(
             report_path.to_str().unwrap(),
             constraints_path.to_str().unwrap(),
+            None,
             false,
         )
         .unwrap();
@@ -385,6 +419,7 @@ mod tests {
         let code = run(
             report_path.to_str().unwrap(),
             constraints_path.to_str().unwrap(),
+            None,
             true,
         )
         .unwrap();
@@ -407,6 +442,7 @@ mod tests {
         let code = run(
             report_path.to_str().unwrap(),
             constraints_path.to_str().unwrap(),
+            None,
             true,
         )
         .unwrap();
@@ -424,6 +460,7 @@ mod tests {
         let code = run(
             "/nonexistent/report.json",
             constraints_path.to_str().unwrap(),
+            None,
             true,
         )
         .unwrap();
@@ -440,6 +477,7 @@ mod tests {
         let code = run(
             report_path.to_str().unwrap(),
             "/nonexistent/constraints.json",
+            None,
             true,
         )
         .unwrap();
@@ -464,6 +502,7 @@ mod tests {
         let code = run(
             report_path.to_str().unwrap(),
             constraints_path.to_str().unwrap(),
+            None,
             false,
         )
         .unwrap();
@@ -480,6 +519,46 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_verify_spectral_similarity_skipped_without_asset() {
+        let tmp = tempdir().unwrap();
+
+        let report = create_test_report(Some(OutputMetrics::new()));
+        let report_path = write_report(&tmp, "test.report.json", &report);
+
+        let constraints = ConstraintSet::from_constraints(vec![Constraint::SpectralSimilarity {
+            reference_path: "ref.wav".to_string(),
+            min_score: 0.9,
+        }]);
+        let constraints_path = write_constraints(&tmp, "test.constraints.json", &constraints);
+
+        let code = run(
+            report_path.to_str().unwrap(),
+            constraints_path.to_str().unwrap(),
+            None,
+            true,
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::SUCCESS);
+
+        // With an asset but no reference file, the constraint fails
+        let code = run(
+            report_path.to_str().unwrap(),
+            constraints_path.to_str().unwrap(),
+            Some(report_path.to_str().unwrap()),
+            true,
+        )
+        .unwrap();
+        assert_eq!(code, ExitCode::from(1));
+    }
+
+    #[test]
+    fn test_constraint_files_resolve_relative_to_constraints() {
+        let files = constraint_files("specs/hit.constraints.json", Some("out/hit.wav"));
+        assert_eq!(files.reference_dir, Path::new("specs"));
+        assert_eq!(files.asset.as_deref(), Some(Path::new("out/hit.wav")));
+    }
+
     #[test]
     fn test_load_report_invalid_json() {
         let tmp = tempdir().unwrap();
@@ -0,0 +1,107 @@
+//! Compile-time budget checks.
+//!
+//! When [`CompilerConfig::budget`](super::CompilerConfig::budget) is set, the
+//! compiled spec is validated against the budget profile and any budget
+//! violations are reported as compile errors, located in the Starlark source
+//! where the offending field can be found.
+
+use speccade_spec::validation::{validate_spec_with_budget, BudgetProfile};
+use speccade_spec::{ErrorCode, Spec};
+
+use super::error::CompileError;
+
+/// Validates `spec` against `budget`, failing on the first budget violation.
+///
+/// Only `E017` (budget exceeded) errors are reported; other validation errors
+/// are left to `speccade validate`.
+pub(super) fn check_budget(
+    filename: &str,
+    source: &str,
+    spec: &Spec,
+    budget: &BudgetProfile,
+) -> Result<(), CompileError> {
+    let result = validate_spec_with_budget(spec, budget);
+
+    let violation = result
+        .errors
+        .iter()
+        .find(|e| e.code == ErrorCode::BudgetExceeded);
+
+    match violation {
+        None => Ok(()),
+        Some(error) => {
+            let location = error
+                .path
+                .as_deref()
+                .and_then(|path| find_field_location(source, path))
+                .map(|(line, column)| format!("{}:{}:{}", filename, line, column))
+                .unwrap_or_else(|| filename.to_string());
+            let message = match &error.path {
+                Some(path) => format!("{} (at {})", error.message, path),
+                None => error.message.clone(),
+            };
+            Err(CompileError::BudgetExceeded { location, message })
+        }
+    }
+}
+
+/// Finds the 1-based line and column of the field named by a spec path.
+///
+/// Looks for the last key of `path` written as a dict key (`"key":`) or a
+/// keyword argument (`key =`). Returns `None` when the field is only set
+/// positionally or is not spelled out in the source.
+fn find_field_location(source: &str, path: &str) -> Option<(usize, usize)> {
+    let key = path
+        .rsplit('.')
+        .next()
+        .and_then(|segment| segment.split('[').next())
+        .filter(|key| !key.is_empty())?;
+
+    let dict_key = format!("\"{}\"", key);
+    for (index, line) in source.lines().enumerate() {
+        if let Some(column) = line.find(&dict_key) {
+            let rest = line[column + dict_key.len()..].trim_start();
+            if rest.starts_with(':') {
+                return Some((index + 1, column + 1));
+            }
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(rest) = trimmed.strip_prefix(key) {
+            let rest = rest.trim_start();
+            if rest.starts_with('=') && !rest.starts_with("==") {
+                return Some((index + 1, line.len() - trimmed.len() + 1));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_field_location_dict_key() {
+        let source = "x = 1\nparams = {\n    \"resolution\": [4096, 4096],\n}\n";
+        assert_eq!(
+            find_field_location(source, "recipe.params.resolution"),
+            Some((3, 5))
+        );
+    }
+
+    #[test]
+    fn test_find_field_location_kwarg() {
+        let source = "spec(\n    seed = 1,\n    outputs = [],\n)\n";
+        assert_eq!(find_field_location(source, "outputs[0]"), Some((3, 5)));
+    }
+
+    #[test]
+    fn test_find_field_location_missing() {
+        let source = "texture_graph([4096, 4096], [])\n";
+        assert_eq!(
+            find_field_location(source, "recipe.params.resolution"),
+            None
+        );
+    }
+}
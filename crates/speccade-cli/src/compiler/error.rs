@@ -4,7 +4,7 @@
 //!
 //! | Range | Category | Description |
 //! |-------|----------|-------------|
-//! | S001-S009 | Compiler | Syntax, runtime, timeout errors |
+//! | S001-S009 | Compiler | Syntax, runtime, timeout, budget errors |
 //! | S101-S199 | Stdlib | Function argument validation |
 //! | S201-S299 | Reserved | Future stdlib categories |
 
@@ -13,7 +13,7 @@ use thiserror::Error;
 /// Errors from Starlark compilation.
 ///
 /// Error codes use a stable S-series format:
-/// - S001-S009: Compiler errors (syntax, runtime, timeout)
+/// - S001-S009: Compiler errors (syntax, runtime, timeout, budget)
 /// - S101-S199: Stdlib argument validation errors
 #[derive(Debug, Error)]
 pub enum CompileError {
@@ -41,6 +41,10 @@ pub enum CompileError {
     #[error("S006: invalid spec: {message}")]
     InvalidSpec { message: String },
 
+    /// S007: Compiled spec exceeds the configured budget profile.
+    #[error("S007: budget exceeded at {location}: {message}")]
+    BudgetExceeded { location: String, message: String },
+
     /// S101: Invalid stdlib function argument.
     #[error("S101: {function}(): missing required argument '{param}'")]
     StdlibArgument { function: String, param: String },
@@ -82,6 +86,7 @@ impl CompileError {
             CompileError::NotADict { .. } => "S004",
             CompileError::JsonConversion { .. } => "S005",
             CompileError::InvalidSpec { .. } => "S006",
+            CompileError::BudgetExceeded { .. } => "S007",
             CompileError::StdlibArgument { .. } => "S101",
             CompileError::StdlibType { .. } => "S102",
             CompileError::StdlibRange { .. } => "S103",
@@ -97,7 +102,8 @@ impl CompileError {
             | CompileError::Timeout { .. }
             | CompileError::NotADict { .. }
             | CompileError::JsonConversion { .. }
-            | CompileError::InvalidSpec { .. } => "compiler",
+            | CompileError::InvalidSpec { .. }
+            | CompileError::BudgetExceeded { .. } => "compiler",
             CompileError::StdlibArgument { .. }
             | CompileError::StdlibType { .. }
             | CompileError::StdlibRange { .. }
@@ -6,7 +6,8 @@
 use super::convert::starlark_to_json;
 use super::error::CompileError;
 use super::stdlib::register_stdlib;
-use super::{CompileResult, CompilerConfig};
+use super::{CompileResult, CompileWarning, CompilerConfig};
+use speccade_spec::hash::derive_seed_from_asset_id;
 use speccade_spec::Spec;
 use starlark::environment::{GlobalsBuilder, Module};
 use starlark::eval::Evaluator;
@@ -69,17 +70,52 @@ fn eval_starlark_sync(
     }
 
     // Convert to JSON
-    let json_value = starlark_to_json(value)?;
+    let mut json_value = starlark_to_json(value)?;
+    let mut warnings = Vec::new();
+
+    // Fill in a stable seed when the spec does not declare one
+    if config.derive_missing_seed {
+        if let Some(message) = derive_missing_seed(&mut json_value) {
+            warnings.push(CompileWarning::new(message));
+        }
+    }
 
     // Parse as Spec
     let spec = Spec::from_value(json_value).map_err(|e| CompileError::InvalidSpec {
         message: e.to_string(),
     })?;
 
-    Ok(CompileResult {
-        spec,
-        warnings: Vec::new(),
-    })
+    Ok(CompileResult { spec, warnings })
+}
+
+/// Note recorded in `migration_notes` when the seed was derived.
+const DERIVED_SEED_NOTE: &str = "seed derived from asset_id (spec has no explicit seed)";
+
+/// Inserts a seed derived from `asset_id` when the spec omits `seed`.
+///
+/// The derivation is recorded in `migration_notes` so the emitted IR shows
+/// that the seed was not authored. Explicit seeds (including `0`) are left
+/// untouched. Returns a warning message when a seed was derived.
+fn derive_missing_seed(json_value: &mut serde_json::Value) -> Option<String> {
+    let obj = json_value.as_object_mut()?;
+    if obj.get("seed").is_some_and(|seed| !seed.is_null()) {
+        return None;
+    }
+    let asset_id = obj.get("asset_id")?.as_str()?.to_string();
+    let seed = derive_seed_from_asset_id(&asset_id);
+
+    obj.insert("seed".to_string(), serde_json::Value::from(seed));
+    let notes = obj
+        .entry("migration_notes")
+        .or_insert_with(|| serde_json::Value::Array(Vec::new()));
+    if let Some(notes) = notes.as_array_mut() {
+        notes.push(serde_json::Value::from(DERIVED_SEED_NOTE));
+    }
+
+    Some(format!(
+        "no seed specified; derived seed {} from asset_id '{}'",
+        seed, asset_id
+    ))
 }
 
 /// Evaluates Starlark source with timeout.
@@ -285,6 +321,68 @@ undefined_variable
         assert!(matches!(result, Err(CompileError::InvalidSpec { .. })));
     }
 
+    const SEEDLESS_SPEC: &str = r#"
+{
+    "spec_version": 1,
+    "asset_id": "seedless-test-01",
+    "asset_type": "audio",
+    "license": "CC0-1.0",
+    "outputs": [
+        {
+            "kind": "primary",
+            "format": "wav",
+            "path": "sounds/test.wav"
+        }
+    ]
+}
+"#;
+
+    fn deriving_config() -> CompilerConfig {
+        CompilerConfig {
+            derive_missing_seed: true,
+            ..CompilerConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_missing_seed_is_derived_from_asset_id() {
+        let config = deriving_config();
+        let first = eval_starlark_sync("test.star", SEEDLESS_SPEC, &config).unwrap();
+        let second = eval_starlark_sync("test.star", SEEDLESS_SPEC, &config).unwrap();
+
+        assert_eq!(
+            first.spec.seed,
+            derive_seed_from_asset_id("seedless-test-01")
+        );
+        assert_eq!(first.spec.seed, second.spec.seed);
+        assert_eq!(
+            first.spec.migration_notes,
+            Some(vec![DERIVED_SEED_NOTE.to_string()])
+        );
+        assert_eq!(first.warnings.len(), 1);
+        assert!(first.warnings[0].message.contains("seedless-test-01"));
+    }
+
+    #[test]
+    fn test_explicit_seed_wins() {
+        let source = SEEDLESS_SPEC.replace(
+            r#""license": "CC0-1.0","#,
+            r#""license": "CC0-1.0", "seed": 0,"#,
+        );
+        let result = eval_starlark_sync("test.star", &source, &deriving_config()).unwrap();
+
+        assert_eq!(result.spec.seed, 0);
+        assert!(result.spec.migration_notes.is_none());
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_missing_seed_is_rejected_by_default() {
+        let err =
+            eval_starlark_sync("test.star", SEEDLESS_SPEC, &CompilerConfig::default()).unwrap_err();
+        assert!(matches!(err, CompileError::InvalidSpec { .. }));
+    }
+
     #[test]
     fn test_timeout() {
         // This test verifies the timeout mechanism works
@@ -308,6 +406,8 @@ undefined_variable
         let config = CompilerConfig {
             timeout_seconds: 30,
             enable_load: false,
+            budget: None,
+            derive_missing_seed: false,
         };
         let result = eval_with_timeout("test.star", source, &config);
         // Should succeed since the code is fast
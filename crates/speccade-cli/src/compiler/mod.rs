@@ -7,6 +7,7 @@
 //! - Evaluates the code with safety limits (timeout, no recursion)
 //! - Converts the resulting Starlark dict to JSON
 //! - Validates and parses the JSON as a Spec
+//! - Optionally checks the spec against a budget profile
 //!
 //! # Safety
 //!
@@ -39,6 +40,7 @@
 //! println!("Asset ID: {}", result.spec.asset_id);
 //! ```
 
+mod budget;
 mod convert;
 mod error;
 mod eval;
@@ -46,6 +48,7 @@ pub mod stdlib;
 
 pub use error::CompileError;
 
+use speccade_spec::validation::BudgetProfile;
 use speccade_spec::Spec;
 
 /// Current Starlark stdlib version.
@@ -62,6 +65,14 @@ pub struct CompilerConfig {
     pub timeout_seconds: u64,
     /// Whether to enable Starlark `load()` statements (default: false).
     pub enable_load: bool,
+    /// Budget profile to check the compiled spec against (default: none).
+    ///
+    /// When set, budget violations fail compilation with `S007`.
+    pub budget: Option<BudgetProfile>,
+    /// Whether to derive a seed from `asset_id` when the spec omits one (default: false).
+    ///
+    /// Only `eval` enables this; other commands reject specs without a seed.
+    pub derive_missing_seed: bool,
 }
 
 impl Default for CompilerConfig {
@@ -69,6 +80,8 @@ impl Default for CompilerConfig {
         Self {
             timeout_seconds: DEFAULT_TIMEOUT_SECONDS,
             enable_load: false,
+            budget: None,
+            derive_missing_seed: false,
         }
     }
 }
@@ -133,7 +146,13 @@ pub fn compile(
     source: &str,
     config: &CompilerConfig,
 ) -> Result<CompileResult, CompileError> {
-    eval::eval_with_timeout(filename, source, config)
+    let result = eval::eval_with_timeout(filename, source, config)?;
+
+    if let Some(budget) = &config.budget {
+        budget::check_budget(filename, source, &result.spec, budget)?;
+    }
+
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -145,6 +164,7 @@ mod tests {
         let config = CompilerConfig::default();
         assert_eq!(config.timeout_seconds, DEFAULT_TIMEOUT_SECONDS);
         assert!(!config.enable_load);
+        assert!(config.budget.is_none());
     }
 
     #[test]
@@ -222,4 +242,60 @@ outputs = [make_output("laser"), make_output("explosion")]
         assert_eq!(warning.message, "test warning");
         assert_eq!(warning.location, Some("line 10".to_string()));
     }
+
+    /// Texture graph with 129 nodes: over the strict profile's 128-node budget.
+    const OVER_BUDGET_TEXTURE: &str = r#"
+spec(
+    asset_id = "budget-test-01",
+    asset_type = "texture",
+    seed = 1,
+    outputs = [output("textures/big.png", "png", source = "c0")],
+    recipe = {
+        "kind": "texture.procedural_v1",
+        "params": {
+            "resolution": [64, 64],
+            "tileable": True,
+            "nodes": [constant_node("c%d" % i, 0.5) for i in range(129)],
+        },
+    },
+)
+"#;
+
+    #[test]
+    fn test_compile_over_budget_fails_with_strict_profile() {
+        let config = CompilerConfig {
+            budget: Some(BudgetProfile::strict()),
+            ..CompilerConfig::default()
+        };
+        let err = compile("big.star", OVER_BUDGET_TEXTURE, &config).unwrap_err();
+
+        assert_eq!(err.code(), "S007");
+        let msg = err.to_string();
+        assert!(msg.contains("129 nodes"), "{}", msg);
+        assert!(msg.contains("profile: strict"), "{}", msg);
+        assert!(msg.contains("recipe.params.nodes"), "{}", msg);
+        match err {
+            CompileError::BudgetExceeded { location, .. } => {
+                assert_eq!(location, "big.star:12:13");
+            }
+            other => panic!("expected BudgetExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_compile_within_budget_passes() {
+        let source = OVER_BUDGET_TEXTURE.replace("range(129)", "range(4)");
+        let config = CompilerConfig {
+            budget: Some(BudgetProfile::strict()),
+            ..CompilerConfig::default()
+        };
+        let result = compile("small.star", &source, &config).unwrap();
+        assert_eq!(result.spec.asset_id, "budget-test-01");
+    }
+
+    #[test]
+    fn test_compile_without_budget_skips_check() {
+        let result = compile("big.star", OVER_BUDGET_TEXTURE, &CompilerConfig::default());
+        assert!(result.is_ok());
+    }
 }
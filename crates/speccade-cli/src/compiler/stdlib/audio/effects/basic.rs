@@ -1,11 +1,13 @@
-//! Basic audio effects: reverb, delay, compressor, chorus, phaser, bitcrush
+//! Basic audio effects: reverb, convolution_reverb, delay, compressor, chorus, phaser, bitcrush
 
 use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
 use starlark::starlark_module;
-use starlark::values::{dict::Dict, Heap, Value, ValueLike};
+use starlark::values::{dict::Dict, none::NoneType, Heap, Value, ValueLike};
 
-use crate::compiler::stdlib::validation::{validate_positive, validate_unit_range};
+use crate::compiler::stdlib::validation::{
+    extract_float, validate_enum, validate_positive, validate_unit_range,
+};
 
 /// Helper to create a hashed key for dict insertion.
 fn hashed_key<'v>(heap: &'v Heap, key: &str) -> starlark::collections::Hashed<Value<'v>> {
@@ -33,6 +35,7 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
         #[starlark(default = 0.3)] wet: f64,
         #[starlark(default = 0.8)] room_size: f64,
         #[starlark(default = 1.0)] width: f64,
+        #[starlark(default = NoneType)] dry: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_unit_range(wet, "reverb", "wet").map_err(|e| anyhow::anyhow!(e))?;
@@ -53,6 +56,63 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
         dict.insert_hashed(hashed_key(heap, "wet"), heap.alloc(wet).to_value());
         dict.insert_hashed(hashed_key(heap, "width"), heap.alloc(width).to_value());
 
+        // Add dry level if provided (defaults to 1 - wet)
+        if !dry.is_none() {
+            let dry = extract_float(dry, "reverb", "dry").map_err(|e| anyhow::anyhow!(e))?;
+            validate_unit_range(dry, "reverb", "dry").map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(hashed_key(heap, "dry"), heap.alloc(dry).to_value());
+        }
+
+        Ok(dict)
+    }
+
+    /// Creates a convolution reverb using a named impulse response.
+    ///
+    /// # Arguments
+    /// * `impulse` - Impulse response: "small_room", "hall", "plate" (default: "small_room")
+    /// * `wet` - Wet level 0.0-1.0 (default: 0.3)
+    /// * `dry` - Dry level 0.0-1.0 (default: 1.0 - wet)
+    ///
+    /// # Returns
+    /// A dict matching the Effect::ConvolutionReverb IR structure.
+    ///
+    /// # Example
+    /// ```starlark
+    /// convolution_reverb()
+    /// convolution_reverb(impulse = "hall", wet = 0.4)
+    /// convolution_reverb(impulse = "plate", wet = 0.5, dry = 0.8)
+    /// ```
+    fn convolution_reverb<'v>(
+        #[starlark(require = named, default = "small_room")] impulse: &str,
+        #[starlark(require = named, default = 0.3)] wet: f64,
+        #[starlark(require = named, default = NoneType)] dry: Value<'v>,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        const IMPULSES: &[&str] = &["small_room", "hall", "plate"];
+        validate_enum(impulse, IMPULSES, "convolution_reverb", "impulse")
+            .map_err(|e| anyhow::anyhow!(e))?;
+        validate_unit_range(wet, "convolution_reverb", "wet").map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "type"),
+            heap.alloc_str("convolution_reverb").to_value(),
+        );
+        dict.insert_hashed(
+            hashed_key(heap, "impulse"),
+            heap.alloc_str(impulse).to_value(),
+        );
+        dict.insert_hashed(hashed_key(heap, "wet"), heap.alloc(wet).to_value());
+
+        if !dry.is_none() {
+            let dry =
+                extract_float(dry, "convolution_reverb", "dry").map_err(|e| anyhow::anyhow!(e))?;
+            validate_unit_range(dry, "convolution_reverb", "dry")
+                .map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(hashed_key(heap, "dry"), heap.alloc(dry).to_value());
+        }
+
         Ok(dict)
     }
 
@@ -63,6 +123,7 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
     /// * `feedback` - Feedback amount 0.0-1.0 (default: 0.4)
     /// * `wet` - Wet/dry mix 0.0-1.0 (default: 0.3)
     /// * `ping_pong` - Enable stereo ping-pong mode (default: False)
+    /// * `tone` - Optional feedback lowpass cutoff in Hz (default: None)
     ///
     /// # Returns
     /// A dict matching the Effect::Delay IR structure.
@@ -72,12 +133,14 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
     /// delay()
     /// delay(500, 0.5, 0.4)
     /// delay(250, 0.4, 0.3, True)  # Ping-pong stereo delay
+    /// delay(375, 0.6, 0.3, tone = 2500)  # Darker repeats
     /// ```
     fn delay<'v>(
         #[starlark(default = 250.0)] time_ms: f64,
         #[starlark(default = 0.4)] feedback: f64,
         #[starlark(default = 0.3)] wet: f64,
         #[starlark(default = false)] ping_pong: bool,
+        #[starlark(default = NoneType)] tone: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(time_ms, "delay", "time_ms").map_err(|e| anyhow::anyhow!(e))?;
@@ -98,6 +161,13 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
             heap.alloc(ping_pong).to_value(),
         );
 
+        // Add feedback tone if provided
+        if !tone.is_none() {
+            let tone = extract_float(tone, "delay", "tone").map_err(|e| anyhow::anyhow!(e))?;
+            validate_positive(tone, "delay", "tone").map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(hashed_key(heap, "tone"), heap.alloc(tone).to_value());
+        }
+
         Ok(dict)
     }
 
@@ -166,6 +236,7 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
     /// * `depth` - Modulation depth 0.0-1.0
     /// * `wet` - Wet/dry mix 0.0-1.0
     /// * `voices` - Number of chorus voices (1-4, default: 2)
+    /// * `spread` - Optional stereo spread of the voices 0.0-1.0 (default: None)
     ///
     /// # Returns
     /// A dict matching the Effect::Chorus IR structure.
@@ -174,12 +245,14 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
     /// ```starlark
     /// chorus(1.5, 0.3, 0.25)
     /// chorus(0.8, 0.6, 0.4, voices = 4)
+    /// chorus(0.8, 0.6, 0.4, voices = 4, spread = 1.0)  # Widen a mono source
     /// ```
     fn chorus<'v>(
         rate: f64,
         depth: f64,
         wet: f64,
         #[starlark(default = 2)] voices: i32,
+        #[starlark(default = NoneType)] spread: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(rate, "chorus", "rate").map_err(|e| anyhow::anyhow!(e))?;
@@ -203,6 +276,13 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
         dict.insert_hashed(hashed_key(heap, "wet"), heap.alloc(wet).to_value());
         dict.insert_hashed(hashed_key(heap, "voices"), heap.alloc(voices).to_value());
 
+        if !spread.is_none() {
+            let spread =
+                extract_float(spread, "chorus", "spread").map_err(|e| anyhow::anyhow!(e))?;
+            validate_unit_range(spread, "chorus", "spread").map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(hashed_key(heap, "spread"), heap.alloc(spread).to_value());
+        }
+
         Ok(dict)
     }
 
@@ -212,12 +292,14 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
     /// * `rate` - LFO rate in Hz
     /// * `depth` - Modulation depth 0.0-1.0
     /// * `stages` - Number of allpass stages 2-12
-    /// * `wet` - Wet/dry mix 0.0-1.0
+    /// * `wet` - Wet/dry mix 0.0-1.0 (0.5 gives the deepest notches)
+    /// * `feedback` - Optional feedback -0.95 to 0.95 (default: None)
     fn phaser<'v>(
         rate: f64,
         depth: f64,
         stages: i32,
         wet: f64,
+        #[starlark(default = NoneType)] feedback: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(rate, "phaser", "rate").map_err(|e| anyhow::anyhow!(e))?;
@@ -241,6 +323,21 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
         dict.insert_hashed(hashed_key(heap, "stages"), heap.alloc(stages).to_value());
         dict.insert_hashed(hashed_key(heap, "wet"), heap.alloc(wet).to_value());
 
+        if !feedback.is_none() {
+            let feedback =
+                extract_float(feedback, "phaser", "feedback").map_err(|e| anyhow::anyhow!(e))?;
+            if !(-0.95..=0.95).contains(&feedback) {
+                return Err(anyhow::anyhow!(
+                    "S103: phaser(): 'feedback' must be -0.95 to 0.95, got {}",
+                    feedback
+                ));
+            }
+            dict.insert_hashed(
+                hashed_key(heap, "feedback"),
+                heap.alloc(feedback).to_value(),
+            );
+        }
+
         Ok(dict)
     }
 
@@ -249,9 +346,11 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
     /// # Arguments
     /// * `bits` - Bit depth 1-16
     /// * `sample_rate_reduction` - Sample rate reduction factor (1.0 = no reduction)
+    /// * `downsample` - Integer decimation factor; each sample is held this many frames (1 = none)
     fn bitcrush<'v>(
         bits: i32,
         #[starlark(default = 1.0)] sample_rate_reduction: f64,
+        #[starlark(default = 1)] downsample: i32,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         if !(1..=16).contains(&bits) {
@@ -266,6 +365,12 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
                 sample_rate_reduction
             ));
         }
+        if downsample < 1 {
+            return Err(anyhow::anyhow!(
+                "S103: bitcrush(): 'downsample' must be >= 1, got {}",
+                downsample
+            ));
+        }
 
         let mut dict = new_dict(heap);
 
@@ -278,6 +383,10 @@ fn register_basic_effects(builder: &mut GlobalsBuilder) {
             hashed_key(heap, "sample_rate_reduction"),
             heap.alloc(sample_rate_reduction).to_value(),
         );
+        dict.insert_hashed(
+            hashed_key(heap, "downsample"),
+            heap.alloc(downsample).to_value(),
+        );
 
         Ok(dict)
     }
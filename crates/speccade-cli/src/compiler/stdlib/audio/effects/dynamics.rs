@@ -1,4 +1,4 @@
-//! Dynamics and EQ effects: limiter, parametric_eq, eq_band, transient_shaper
+//! Dynamics and EQ effects: limiter, parametric_eq, eq_band, transient_shaper, sidechain
 
 use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
@@ -257,4 +257,78 @@ fn register_dynamics_effects(builder: &mut GlobalsBuilder) {
 
         Ok(dict)
     }
+
+    /// Creates a sidechain ducker for `audio_layer(sidechain = ...)`.
+    ///
+    /// The level of `source_layer` drives gain reduction on the layer it is
+    /// attached to, e.g. a kick ducking a bass.
+    ///
+    /// # Arguments
+    /// * `source_layer` - Index of the layer whose level drives the ducking
+    /// * `threshold_db` - Source level where ducking starts, -60 to 0 (default: -24)
+    /// * `ratio` - Reduction ratio, 1-20 (default: 6)
+    /// * `attack_ms` - Detector attack in ms, 0.1-100 (default: 1)
+    /// * `release_ms` - Detector release in ms, 10-1000 (default: 150)
+    ///
+    /// # Returns
+    /// A dict matching the Sidechain IR structure.
+    ///
+    /// # Example
+    /// ```starlark
+    /// audio_layer(oscillator(55), sidechain = sidechain(0))
+    /// sidechain(0, threshold_db = -30, ratio = 10, release_ms = 80)
+    /// ```
+    fn sidechain<'v>(
+        source_layer: i32,
+        #[starlark(default = -24.0)] threshold_db: f64,
+        #[starlark(default = 6.0)] ratio: f64,
+        #[starlark(default = 1.0)] attack_ms: f64,
+        #[starlark(default = 150.0)] release_ms: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        if source_layer < 0 {
+            return Err(anyhow::anyhow!(
+                "S103: sidechain(): 'source_layer' must be >= 0, got {}",
+                source_layer
+            ));
+        }
+        for (name, value, min, max) in [
+            ("threshold_db", threshold_db, -60.0, 0.0),
+            ("ratio", ratio, 1.0, 20.0),
+            ("attack_ms", attack_ms, 0.1, 100.0),
+            ("release_ms", release_ms, 10.0, 1000.0),
+        ] {
+            if !(min..=max).contains(&value) {
+                return Err(anyhow::anyhow!(
+                    "S103: sidechain(): '{}' must be {} to {}, got {}",
+                    name,
+                    min,
+                    max,
+                    value
+                ));
+            }
+        }
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "source_layer"),
+            heap.alloc(source_layer).to_value(),
+        );
+        dict.insert_hashed(
+            hashed_key(heap, "threshold_db"),
+            heap.alloc(threshold_db).to_value(),
+        );
+        dict.insert_hashed(hashed_key(heap, "ratio"), heap.alloc(ratio).to_value());
+        dict.insert_hashed(
+            hashed_key(heap, "attack_ms"),
+            heap.alloc(attack_ms).to_value(),
+        );
+        dict.insert_hashed(
+            hashed_key(heap, "release_ms"),
+            heap.alloc(release_ms).to_value(),
+        );
+
+        Ok(dict)
+    }
 }
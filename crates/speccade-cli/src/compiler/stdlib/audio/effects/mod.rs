@@ -1,7 +1,7 @@
 //! Effect functions for audio processing
 //!
 //! This module provides functions for creating audio effects like reverb, delay,
-//! compression, modulation, and spatial effects.
+//! compression, modulation, spatial, and spectral effects.
 
 use starlark::environment::GlobalsBuilder;
 
@@ -9,6 +9,7 @@ mod basic;
 mod dynamics;
 mod modulation;
 mod spatial;
+mod spectral;
 
 /// Registers all effects functions into a GlobalsBuilder.
 pub fn register(builder: &mut GlobalsBuilder) {
@@ -16,4 +17,5 @@ pub fn register(builder: &mut GlobalsBuilder) {
     dynamics::register(builder);
     modulation::register(builder);
     spatial::register(builder);
+    spectral::register(builder);
 }
@@ -1,12 +1,16 @@
-//! Modulation effects: flanger, waveshaper, auto_filter, rotary_speaker, ring_modulator
+//! Modulation effects: flanger, waveshaper, auto_filter, rotary_speaker, ring_modulator,
+//! tremolo, auto_pan
 
 use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
 use starlark::starlark_module;
-use starlark::values::{dict::Dict, Heap, Value, ValueLike};
+use starlark::values::{dict::Dict, none::NoneType, Heap, Value, ValueLike};
 
 use crate::compiler::stdlib::validation::{validate_enum, validate_unit_range};
 
+/// Valid ring modulator carrier and tremolo LFO waveforms.
+const WAVEFORMS: &[&str] = &["sine", "square", "sawtooth", "triangle", "pulse"];
+
 /// Helper to create a hashed key for dict insertion.
 fn hashed_key<'v>(heap: &'v Heap, key: &str) -> starlark::collections::Hashed<Value<'v>> {
     heap.alloc_str(key)
@@ -89,8 +93,8 @@ fn register_modulation_effects(builder: &mut GlobalsBuilder) {
     /// Creates a waveshaper distortion effect.
     ///
     /// # Arguments
-    /// * `drive` - Drive amount 1.0-100.0
-    /// * `curve` - Shaping curve: "tanh", "soft_clip", "hard_clip", "sine"
+    /// * `drive` - Drive amount 1.0-100.0, or 0.0 to bypass
+    /// * `curve` - Shaping curve: "tanh", "soft_clip", "hard_clip", "sine", "foldback", "cubic"
     /// * `wet` - Wet/dry mix 0.0-1.0
     fn waveshaper<'v>(
         drive: f64,
@@ -98,13 +102,20 @@ fn register_modulation_effects(builder: &mut GlobalsBuilder) {
         #[starlark(default = 1.0)] wet: f64,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
-        if !(1.0..=100.0).contains(&drive) {
+        if drive != 0.0 && !(1.0..=100.0).contains(&drive) {
             return Err(anyhow::anyhow!(
-                "S103: waveshaper(): 'drive' must be 1.0-100.0, got {}",
+                "S103: waveshaper(): 'drive' must be 0.0 or 1.0-100.0, got {}",
                 drive
             ));
         }
-        const CURVES: &[&str] = &["tanh", "soft_clip", "hard_clip", "sine"];
+        const CURVES: &[&str] = &[
+            "tanh",
+            "soft_clip",
+            "hard_clip",
+            "sine",
+            "foldback",
+            "cubic",
+        ];
         validate_enum(curve, CURVES, "waveshaper", "curve").map_err(|e| anyhow::anyhow!(e))?;
         validate_unit_range(wet, "waveshaper", "wet").map_err(|e| anyhow::anyhow!(e))?;
 
@@ -244,6 +255,7 @@ fn register_modulation_effects(builder: &mut GlobalsBuilder) {
     /// # Arguments
     /// * `frequency` - Carrier oscillator frequency in Hz (20-2000 typical)
     /// * `mix` - Wet/dry mix (0.0-1.0)
+    /// * `waveform` - Optional carrier waveform: "sine" (default), "square", "sawtooth", "triangle", "pulse"
     ///
     /// # Returns
     /// A dict matching the Effect::RingModulator IR structure.
@@ -252,11 +264,13 @@ fn register_modulation_effects(builder: &mut GlobalsBuilder) {
     /// ```starlark
     /// ring_modulator(frequency = 200.0, mix = 0.5)  # Metallic tones
     /// ring_modulator(frequency = 50.0, mix = 1.0)  # Full ring mod effect
+    /// ring_modulator(frequency = 80.0, mix = 0.7, waveform = "square")  # Harsh sci-fi buzz
     /// ```
     #[starlark(speculative_exec_safe)]
     fn ring_modulator<'v>(
         #[starlark(require = named)] frequency: f64,
         #[starlark(require = named)] mix: f64,
+        #[starlark(require = named, default = NoneType)] waveform: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         if !(20.0..=2000.0).contains(&frequency) {
@@ -279,6 +293,112 @@ fn register_modulation_effects(builder: &mut GlobalsBuilder) {
         );
         dict.insert_hashed(hashed_key(heap, "mix"), heap.alloc(mix).to_value());
 
+        if !waveform.is_none() {
+            let waveform = waveform.unpack_str().ok_or_else(|| {
+                anyhow::anyhow!("S102: ring_modulator(): 'waveform' must be a string")
+            })?;
+            validate_enum(waveform, WAVEFORMS, "ring_modulator", "waveform")
+                .map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(
+                hashed_key(heap, "waveform"),
+                heap.alloc_str(waveform).to_value(),
+            );
+        }
+
+        Ok(dict)
+    }
+
+    /// Creates a tremolo effect that modulates the level with an LFO.
+    ///
+    /// # Arguments
+    /// * `rate` - LFO rate in Hz (0.1-20.0)
+    /// * `depth` - Modulation depth (0.0-1.0); 0.0 bypasses
+    /// * `shape` - Optional LFO waveform: "sine" (default), "square", "sawtooth", "triangle", "pulse"
+    ///
+    /// # Returns
+    /// A dict matching the Effect::Tremolo IR structure.
+    ///
+    /// # Example
+    /// ```starlark
+    /// tremolo(rate = 5.0, depth = 0.6)  # Classic amp tremolo
+    /// tremolo(rate = 8.0, depth = 1.0, shape = "square")  # Hard chop
+    /// ```
+    #[starlark(speculative_exec_safe)]
+    fn tremolo<'v>(
+        #[starlark(require = named)] rate: f64,
+        #[starlark(require = named)] depth: f64,
+        #[starlark(require = named, default = NoneType)] shape: Value<'v>,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        if !(0.1..=20.0).contains(&rate) {
+            return Err(anyhow::anyhow!(
+                "S103: tremolo(): 'rate' must be 0.1-20.0, got {}",
+                rate
+            ));
+        }
+        validate_unit_range(depth, "tremolo", "depth").map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "type"),
+            heap.alloc_str("tremolo").to_value(),
+        );
+        dict.insert_hashed(hashed_key(heap, "rate"), heap.alloc(rate).to_value());
+        dict.insert_hashed(hashed_key(heap, "depth"), heap.alloc(depth).to_value());
+
+        if !shape.is_none() {
+            let shape = shape
+                .unpack_str()
+                .ok_or_else(|| anyhow::anyhow!("S102: tremolo(): 'shape' must be a string"))?;
+            validate_enum(shape, WAVEFORMS, "tremolo", "shape").map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(hashed_key(heap, "shape"), heap.alloc_str(shape).to_value());
+        }
+
+        Ok(dict)
+    }
+
+    /// Creates an auto-pan effect that sweeps the stereo balance with a sine LFO.
+    ///
+    /// # Arguments
+    /// * `rate` - LFO rate in Hz (0.1-20.0)
+    /// * `depth` - Pan swing (0.0-1.0); 0.0 bypasses
+    /// * `phase` - Initial LFO phase in cycles (0.0-1.0, default 0.0)
+    ///
+    /// # Returns
+    /// A dict matching the Effect::AutoPan IR structure.
+    ///
+    /// # Example
+    /// ```starlark
+    /// auto_pan(rate = 0.5, depth = 0.8)  # Slow sweep
+    /// auto_pan(rate = 4.0, depth = 1.0, phase = 0.25)  # Fast ping-pong starting right
+    /// ```
+    #[starlark(speculative_exec_safe)]
+    fn auto_pan<'v>(
+        #[starlark(require = named)] rate: f64,
+        #[starlark(require = named)] depth: f64,
+        #[starlark(require = named, default = 0.0)] phase: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        if !(0.1..=20.0).contains(&rate) {
+            return Err(anyhow::anyhow!(
+                "S103: auto_pan(): 'rate' must be 0.1-20.0, got {}",
+                rate
+            ));
+        }
+        validate_unit_range(depth, "auto_pan", "depth").map_err(|e| anyhow::anyhow!(e))?;
+        validate_unit_range(phase, "auto_pan", "phase").map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "type"),
+            heap.alloc_str("auto_pan").to_value(),
+        );
+        dict.insert_hashed(hashed_key(heap, "rate"), heap.alloc(rate).to_value());
+        dict.insert_hashed(hashed_key(heap, "depth"), heap.alloc(depth).to_value());
+        dict.insert_hashed(hashed_key(heap, "phase"), heap.alloc(phase).to_value());
+
         Ok(dict)
     }
 }
@@ -0,0 +1,105 @@
+//! Spectral effects: pitch_shift, freeze
+
+use starlark::collections::SmallMap;
+use starlark::environment::GlobalsBuilder;
+use starlark::starlark_module;
+use starlark::values::{dict::Dict, Heap, Value, ValueLike};
+
+/// Helper to create a hashed key for dict insertion.
+fn hashed_key<'v>(heap: &'v Heap, key: &str) -> starlark::collections::Hashed<Value<'v>> {
+    heap.alloc_str(key)
+        .to_value()
+        .get_hashed()
+        .expect("string hashing cannot fail")
+}
+
+/// Helper to create an empty dict on the heap.
+fn new_dict<'v>(_heap: &'v Heap) -> Dict<'v> {
+    let map: SmallMap<Value<'v>, Value<'v>> = SmallMap::new();
+    Dict::new(map)
+}
+
+/// Registers spectral effects functions into a GlobalsBuilder.
+pub fn register(builder: &mut GlobalsBuilder) {
+    register_spectral_effects(builder);
+}
+
+#[starlark_module]
+fn register_spectral_effects(builder: &mut GlobalsBuilder) {
+    /// Creates a phase-vocoder pitch shift effect that keeps the original duration.
+    ///
+    /// # Arguments
+    /// * `semitones` - Pitch shift in semitones (-24 to +24)
+    ///
+    /// # Returns
+    /// A dict matching the Effect::PitchShift IR structure.
+    ///
+    /// # Example
+    /// ```starlark
+    /// pitch_shift(semitones = 12.0)  # Up one octave
+    /// pitch_shift(semitones = -7.0)  # Down a fifth
+    /// ```
+    #[starlark(speculative_exec_safe)]
+    fn pitch_shift<'v>(
+        #[starlark(require = named)] semitones: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        if !(-24.0..=24.0).contains(&semitones) {
+            return Err(anyhow::anyhow!(
+                "S103: pitch_shift(): 'semitones' must be -24 to +24, got {}",
+                semitones
+            ));
+        }
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "type"),
+            heap.alloc_str("pitch_shift").to_value(),
+        );
+        dict.insert_hashed(
+            hashed_key(heap, "semitones"),
+            heap.alloc(semitones).to_value(),
+        );
+
+        Ok(dict)
+    }
+
+    /// Creates a phase-vocoder spectral freeze that sustains the sound at a point in time.
+    ///
+    /// # Arguments
+    /// * `at_seconds` - Capture time in seconds (>= 0)
+    ///
+    /// # Returns
+    /// A dict matching the Effect::Freeze IR structure.
+    ///
+    /// # Example
+    /// ```starlark
+    /// freeze(at_seconds = 0.25)  # Hold the spectrum from 250ms onward
+    /// ```
+    #[starlark(speculative_exec_safe)]
+    fn freeze<'v>(
+        #[starlark(require = named)] at_seconds: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        if !at_seconds.is_finite() || at_seconds < 0.0 {
+            return Err(anyhow::anyhow!(
+                "S103: freeze(): 'at_seconds' must be >= 0, got {}",
+                at_seconds
+            ));
+        }
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "type"),
+            heap.alloc_str("freeze").to_value(),
+        );
+        dict.insert_hashed(
+            hashed_key(heap, "at_seconds"),
+            heap.alloc(at_seconds).to_value(),
+        );
+
+        Ok(dict)
+    }
+}
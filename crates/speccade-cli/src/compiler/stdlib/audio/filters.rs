@@ -39,6 +39,28 @@ fn extract_float(value: Value, function: &str, param: &str) -> anyhow::Result<f6
     ))
 }
 
+/// Adds an optional keytracking amount to a filter dict.
+fn insert_keytrack<'v>(
+    dict: &mut Dict<'v>,
+    heap: &'v Heap,
+    keytrack: Value<'v>,
+    function: &str,
+) -> anyhow::Result<()> {
+    if keytrack.is_none() {
+        return Ok(());
+    }
+    let amount = extract_float(keytrack, function, "keytrack")?;
+    if !(-2.0..=2.0).contains(&amount) {
+        return Err(anyhow::anyhow!(
+            "S103: {}(): 'keytrack' must be in range -2.0 to 2.0, got {}",
+            function,
+            amount
+        ));
+    }
+    dict.insert_hashed(hashed_key(heap, "keytrack"), heap.alloc(amount).to_value());
+    Ok(())
+}
+
 /// Registers filters functions into a GlobalsBuilder.
 pub fn register(builder: &mut GlobalsBuilder) {
     register_filters_functions(builder);
@@ -50,6 +72,7 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
         cutoff: f64,
         #[starlark(default = 0.707)] resonance: f64,
         #[starlark(default = NoneType)] sweep_to: Value<'v>,
+        #[starlark(default = NoneType)] keytrack: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(cutoff, "lowpass", "cutoff").map_err(|e| anyhow::anyhow!(e))?;
@@ -77,6 +100,8 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
             );
         }
 
+        insert_keytrack(&mut dict, heap, keytrack, "lowpass")?;
+
         Ok(dict)
     }
 
@@ -86,6 +111,7 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
     /// * `cutoff` - Cutoff frequency in Hz
     /// * `resonance` - Q factor / resonance (default: 0.707)
     /// * `sweep_to` - Optional target cutoff for sweep
+    /// * `keytrack` - Optional cutoff keytracking amount (-2.0 to 2.0; 1.0 follows the note pitch)
     ///
     /// # Returns
     /// A dict matching the Filter::Highpass IR structure.
@@ -99,6 +125,7 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
         cutoff: f64,
         #[starlark(default = 0.707)] resonance: f64,
         #[starlark(default = NoneType)] sweep_to: Value<'v>,
+        #[starlark(default = NoneType)] keytrack: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(cutoff, "highpass", "cutoff").map_err(|e| anyhow::anyhow!(e))?;
@@ -127,6 +154,8 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
             );
         }
 
+        insert_keytrack(&mut dict, heap, keytrack, "highpass")?;
+
         Ok(dict)
     }
 
@@ -136,6 +165,7 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
     /// * `center` - Center frequency in Hz
     /// * `resonance` - Q factor / resonance (default: 1.0)
     /// * `sweep_to` - Optional target center frequency for sweep
+    /// * `keytrack` - Optional center keytracking amount (-2.0 to 2.0; 1.0 follows the note pitch)
     ///
     /// # Returns
     /// A filter dict.
@@ -149,6 +179,7 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
         center: f64,
         #[starlark(default = 1.0)] resonance: f64,
         #[starlark(default = NoneType)] sweep_to: Value<'v>,
+        #[starlark(default = NoneType)] keytrack: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(center, "bandpass", "center").map_err(|e| anyhow::anyhow!(e))?;
@@ -176,6 +207,8 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
             );
         }
 
+        insert_keytrack(&mut dict, heap, keytrack, "bandpass")?;
+
         Ok(dict)
     }
 
@@ -185,10 +218,12 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
     /// * `center` - Center frequency in Hz
     /// * `resonance` - Q factor / resonance (default: 1.0)
     /// * `sweep_to` - Optional target center frequency for sweep
+    /// * `keytrack` - Optional center keytracking amount (-2.0 to 2.0; 1.0 follows the note pitch)
     fn notch<'v>(
         center: f64,
         #[starlark(default = 1.0)] resonance: f64,
         #[starlark(default = NoneType)] sweep_to: Value<'v>,
+        #[starlark(default = NoneType)] keytrack: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(center, "notch", "center").map_err(|e| anyhow::anyhow!(e))?;
@@ -212,6 +247,8 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
             );
         }
 
+        insert_keytrack(&mut dict, heap, keytrack, "notch")?;
+
         Ok(dict)
     }
 
@@ -326,10 +363,12 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
     /// * `cutoff` - Cutoff frequency in Hz
     /// * `resonance` - Resonance 0.0-1.0 (maps to 0-4x feedback)
     /// * `sweep_to` - Optional target cutoff for sweep
+    /// * `keytrack` - Optional cutoff keytracking amount (-2.0 to 2.0; 1.0 follows the note pitch)
     fn ladder<'v>(
         cutoff: f64,
         #[starlark(default = 0.0)] resonance: f64,
         #[starlark(default = NoneType)] sweep_to: Value<'v>,
+        #[starlark(default = NoneType)] keytrack: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(cutoff, "ladder", "cutoff").map_err(|e| anyhow::anyhow!(e))?;
@@ -356,6 +395,8 @@ fn register_filters_functions(builder: &mut GlobalsBuilder) {
             );
         }
 
+        insert_keytrack(&mut dict, heap, keytrack, "ladder")?;
+
         Ok(dict)
     }
 
@@ -44,6 +44,8 @@
 use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
 use starlark::starlark_module;
+use starlark::values::list::{AllocList, ListRef};
+use starlark::values::tuple::TupleRef;
 use starlark::values::{dict::Dict, dict::DictRef, none::NoneType, Heap, Value, ValueLike};
 
 use super::super::validation::{validate_pan_range, validate_unit_range};
@@ -101,6 +103,57 @@ fn extract_int(value: Value, function: &str, param: &str) -> anyhow::Result<i64>
     ))
 }
 
+/// Converts a list of `(time, gain)` pairs into gain automation breakpoint dicts.
+fn gain_automation_points<'v>(value: Value<'v>, heap: &'v Heap) -> anyhow::Result<Value<'v>> {
+    let list = ListRef::from_value(value).ok_or_else(|| {
+        anyhow::anyhow!(
+            "S102: audio_layer(): 'gain_automation' expected list of (time, gain) pairs, got {}",
+            value.get_type()
+        )
+    })?;
+
+    let mut points = Vec::with_capacity(list.len());
+    let mut prev_time = 0.0;
+    for item in list.iter() {
+        let pair: Vec<Value<'v>> = if let Some(tuple) = TupleRef::from_value(item) {
+            tuple.content().to_vec()
+        } else if let Some(inner) = ListRef::from_value(item) {
+            inner.iter().collect()
+        } else {
+            Vec::new()
+        };
+        if pair.len() != 2 {
+            return Err(anyhow::anyhow!(
+                "S102: audio_layer(): 'gain_automation' expected (time, gain) pair, got {}",
+                item.to_str()
+            ));
+        }
+
+        let time = extract_float(pair[0], "audio_layer", "gain_automation")?;
+        let gain = extract_float(pair[1], "audio_layer", "gain_automation")?;
+        if time < prev_time {
+            return Err(anyhow::anyhow!(
+                "S103: audio_layer(): 'gain_automation' times must be >= 0 and sorted, got {}",
+                time
+            ));
+        }
+        if gain < 0.0 {
+            return Err(anyhow::anyhow!(
+                "S103: audio_layer(): 'gain_automation' gains must be >= 0, got {}",
+                gain
+            ));
+        }
+        prev_time = time;
+
+        let mut point = new_dict(heap);
+        point.insert_hashed(hashed_key(heap, "time"), heap.alloc(time).to_value());
+        point.insert_hashed(hashed_key(heap, "gain"), heap.alloc(gain).to_value());
+        points.push(heap.alloc(point).to_value());
+    }
+
+    Ok(heap.alloc(AllocList(points)))
+}
+
 /// Registers layers functions into a GlobalsBuilder.
 pub fn register(builder: &mut GlobalsBuilder) {
     register_layers_functions(builder);
@@ -116,6 +169,12 @@ fn register_layers_functions(builder: &mut GlobalsBuilder) {
         #[starlark(default = NoneType)] filter: Value<'v>,
         #[starlark(default = NoneType)] lfo: Value<'v>,
         #[starlark(default = NoneType)] delay: Value<'v>,
+        #[starlark(default = false)] muted: bool,
+        #[starlark(default = false)] solo: bool,
+        #[starlark(default = NoneType)] gain_automation: Value<'v>,
+        #[starlark(default = NoneType)] arpeggio: Value<'v>,
+        #[starlark(default = NoneType)] render_rate: Value<'v>,
+        #[starlark(default = NoneType)] sidechain: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_unit_range(volume, "audio_layer", "volume").map_err(|e| anyhow::anyhow!(e))?;
@@ -167,6 +226,44 @@ fn register_layers_functions(builder: &mut GlobalsBuilder) {
             dict.insert_hashed(hashed_key(heap, "delay"), heap.alloc(delay_val).to_value());
         }
 
+        // Optional: mute/solo (omitted when false)
+        if muted {
+            dict.insert_hashed(hashed_key(heap, "muted"), heap.alloc(true).to_value());
+        }
+        if solo {
+            dict.insert_hashed(hashed_key(heap, "solo"), heap.alloc(true).to_value());
+        }
+
+        // Optional: gain automation
+        if !gain_automation.is_none() {
+            dict.insert_hashed(
+                hashed_key(heap, "gain_automation"),
+                gain_automation_points(gain_automation, heap)?,
+            );
+        }
+
+        // Optional: arpeggio
+        if !arpeggio.is_none() {
+            dict.insert_hashed(hashed_key(heap, "arpeggio"), arpeggio);
+        }
+
+        // Optional: render rate (the upper bound is the recipe sample rate)
+        if !render_rate.is_none() {
+            let rate = extract_int(render_rate, "audio_layer", "render_rate")?;
+            if rate < 1000 {
+                return Err(anyhow::anyhow!(
+                    "S103: audio_layer(): 'render_rate' must be >= 1000, got {}",
+                    rate
+                ));
+            }
+            dict.insert_hashed(hashed_key(heap, "render_rate"), heap.alloc(rate).to_value());
+        }
+
+        // Optional: sidechain ducking
+        if !sidechain.is_none() {
+            dict.insert_hashed(hashed_key(heap, "sidechain"), sidechain);
+        }
+
         Ok(dict)
     }
 
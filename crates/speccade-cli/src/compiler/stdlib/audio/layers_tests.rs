@@ -375,3 +375,80 @@ fn test_complete_instrument_sample_workflow() {
     let sustain_layer = &sustain_sample["recipe"]["params"]["layers"][0];
     assert!(sustain_layer.get("loop_config").is_some());
 }
+
+// ========================================================================
+// audio_layer mute/solo/gain_automation Tests
+// ========================================================================
+
+#[test]
+fn test_audio_layer_mute_solo_omitted_by_default() {
+    let result = eval_to_json(r#"audio_layer(oscillator(440))"#).unwrap();
+    assert!(result.get("muted").is_none());
+    assert!(result.get("solo").is_none());
+    assert!(result.get("gain_automation").is_none());
+}
+
+#[test]
+fn test_audio_layer_mute_solo() {
+    let result =
+        eval_to_json(r#"audio_layer(oscillator(440), muted = True, solo = True)"#).unwrap();
+    assert_eq!(result["muted"], true);
+    assert_eq!(result["solo"], true);
+}
+
+#[test]
+fn test_audio_layer_gain_automation() {
+    let result = eval_to_json(
+        r#"audio_layer(oscillator(440), gain_automation = [(0.0, 1.0), (0.5, 0), [1, 0.25]])"#,
+    )
+    .unwrap();
+    let points = result["gain_automation"].as_array().unwrap();
+    assert_eq!(points.len(), 3);
+    assert_eq!(points[1]["time"].as_f64().unwrap(), 0.5);
+    assert_eq!(points[1]["gain"].as_f64().unwrap(), 0.0);
+    assert_eq!(points[2]["time"].as_f64().unwrap(), 1.0);
+    assert_eq!(points[2]["gain"].as_f64().unwrap(), 0.25);
+}
+
+#[test]
+fn test_audio_layer_gain_automation_unsorted_fails() {
+    let result =
+        eval_to_json(r#"audio_layer(oscillator(440), gain_automation = [(0.5, 1.0), (0.1, 0.0)])"#);
+    let err = result.unwrap_err();
+    assert!(err.contains("S103"));
+    assert!(err.contains("gain_automation"));
+}
+
+#[test]
+fn test_audio_layer_gain_automation_bad_pair_fails() {
+    let result = eval_to_json(r#"audio_layer(oscillator(440), gain_automation = [(0.5,)])"#);
+    let err = result.unwrap_err();
+    assert!(err.contains("S102"));
+}
+
+#[test]
+fn test_audio_layer_render_rate() {
+    let result = eval_to_json(r#"audio_layer(oscillator(440), render_rate = 22050)"#).unwrap();
+    assert_eq!(result["render_rate"].as_i64().unwrap(), 22050);
+
+    let result = eval_to_json(r#"audio_layer(oscillator(440))"#).unwrap();
+    assert!(result.get("render_rate").is_none());
+
+    let err = eval_to_json(r#"audio_layer(oscillator(440), render_rate = 500)"#).unwrap_err();
+    assert!(err.contains("S103"));
+}
+
+#[test]
+fn test_audio_layer_sidechain() {
+    let result =
+        eval_to_json(r#"audio_layer(oscillator(55), sidechain = sidechain(0, ratio = 10))"#)
+            .unwrap();
+    let sidechain = &result["sidechain"];
+    assert_eq!(sidechain["source_layer"].as_i64().unwrap(), 0);
+    assert_eq!(sidechain["threshold_db"].as_f64().unwrap(), -24.0);
+    assert_eq!(sidechain["ratio"].as_f64().unwrap(), 10.0);
+
+    let err = eval_to_json(r#"sidechain(0, threshold_db = 6)"#).unwrap_err();
+    assert!(err.contains("S103"));
+    assert!(err.contains("threshold_db"));
+}
@@ -289,6 +289,21 @@ audio_spec(
         assert!(err.contains("seed"));
     }
 
+    #[test]
+    fn test_envelope_delay_and_hold() {
+        let result = eval_to_json(r#"envelope(0.01, 0.1, 0.7, 0.2)"#).unwrap();
+        assert!(result.get("delay").is_none());
+        assert!(result.get("hold").is_none());
+
+        let result =
+            eval_to_json(r#"envelope(0.01, 0.1, 0.7, 0.2, delay = 0.05, hold = 0.1)"#).unwrap();
+        assert_eq!(result["delay"], 0.05);
+        assert_eq!(result["hold"], 0.1);
+
+        let err = eval_to_json(r#"envelope(hold = -0.1)"#).unwrap_err();
+        assert!(err.contains("S103"));
+    }
+
     #[test]
     fn test_notch_filter_basic() {
         let result = eval_to_json(r#"notch(1000.0, 2.0)"#).unwrap();
@@ -373,4 +388,35 @@ audio_spec(
         assert_eq!(result["filter"]["type"], "allpass");
         assert_eq!(result["filter"]["frequency"], 1000.0);
     }
+
+    #[test]
+    fn test_arpeggio_defaults() {
+        let result = eval_to_json(r#"arpeggio([0, 4, 7], 12.0)"#).unwrap();
+        assert_eq!(result["pattern"], serde_json::json!([0.0, 4.0, 7.0]));
+        assert_eq!(result["rate_hz"], 12.0);
+        assert_eq!(result["gate"], 1.0);
+        assert_eq!(result["mode"], "up");
+    }
+
+    #[test]
+    fn test_layer_with_arpeggio() {
+        let result = eval_to_json(
+            r#"audio_layer(oscillator(220.0), arpeggio = arpeggio([0, 12], 8.0, gate = 0.5, mode = "up_down"))"#,
+        )
+        .unwrap();
+        assert_eq!(result["arpeggio"]["gate"], 0.5);
+        assert_eq!(result["arpeggio"]["mode"], "up_down");
+    }
+
+    #[test]
+    fn test_arpeggio_invalid_params() {
+        for source in [
+            r#"arpeggio([], 8.0)"#,
+            r#"arpeggio([0, 7], 0.0)"#,
+            r#"arpeggio([0, 7], 8.0, gate = 1.5)"#,
+            r#"arpeggio([0, 7], 8.0, mode = "sideways")"#,
+        ] {
+            assert!(eval_to_json(source).is_err(), "{}", source);
+        }
+    }
 }
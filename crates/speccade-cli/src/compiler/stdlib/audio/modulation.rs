@@ -1,8 +1,9 @@
-//! Modulation functions (envelopes and LFOs)
+//! Modulation functions (envelopes, LFOs and arpeggios)
 
 use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
 use starlark::starlark_module;
+use starlark::values::list::{AllocList, ListRef};
 use starlark::values::{dict::Dict, none::NoneType, Heap, Value, ValueLike};
 
 use super::super::validation::{validate_enum, validate_positive, validate_unit_range};
@@ -54,9 +55,23 @@ fn register_modulation_functions(builder: &mut GlobalsBuilder) {
         #[starlark(default = 0.1)] decay: f64,
         #[starlark(default = 0.5)] sustain: f64,
         #[starlark(default = 0.2)] release: f64,
+        #[starlark(require = named, default = 0.0)] delay: f64,
+        #[starlark(require = named, default = 0.0)] hold: f64,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         // Validate ranges
+        if delay < 0.0 {
+            return Err(anyhow::anyhow!(
+                "S103: envelope(): 'delay' must be >= 0, got {}",
+                delay
+            ));
+        }
+        if hold < 0.0 {
+            return Err(anyhow::anyhow!(
+                "S103: envelope(): 'hold' must be >= 0, got {}",
+                hold
+            ));
+        }
         validate_positive(attack, "envelope", "attack")
             .or_else(|_| {
                 if attack == 0.0 {
@@ -94,7 +109,14 @@ fn register_modulation_functions(builder: &mut GlobalsBuilder) {
 
         let mut dict = new_dict(heap);
 
+        // Delay and hold are only emitted when set, keeping plain ADSR output unchanged.
+        if delay > 0.0 {
+            dict.insert_hashed(hashed_key(heap, "delay"), heap.alloc(delay).to_value());
+        }
         dict.insert_hashed(hashed_key(heap, "attack"), heap.alloc(attack).to_value());
+        if hold > 0.0 {
+            dict.insert_hashed(hashed_key(heap, "hold"), heap.alloc(hold).to_value());
+        }
         dict.insert_hashed(hashed_key(heap, "decay"), heap.alloc(decay).to_value());
         dict.insert_hashed(hashed_key(heap, "sustain"), heap.alloc(sustain).to_value());
         dict.insert_hashed(hashed_key(heap, "release"), heap.alloc(release).to_value());
@@ -179,6 +201,7 @@ fn register_modulation_functions(builder: &mut GlobalsBuilder) {
             "fm_index",
             "grain_size",
             "grain_density",
+            "wavetable_position",
             "delay_time",
             "reverb_size",
             "distortion_drive",
@@ -283,4 +306,61 @@ fn register_modulation_functions(builder: &mut GlobalsBuilder) {
 
         Ok(dict)
     }
+
+    /// Creates an arpeggio that steps an oscillator layer through semitone offsets.
+    ///
+    /// This matches the `Arpeggio` structure used by `audio_v1`.
+    ///
+    /// # Arguments
+    /// * `pattern` - Semitone offsets from the layer frequency (at least one)
+    /// * `rate_hz` - Steps per second (must be positive)
+    /// * `gate` - Fraction of each step that sounds (0.0-1.0, default: 1.0)
+    /// * `mode` - Step order: "up", "down", "up_down" or "random" (default: "up")
+    ///
+    /// # Returns
+    /// A dict matching `Arpeggio`.
+    ///
+    /// # Example
+    /// ```starlark
+    /// arpeggio([0, 4, 7], 12.0)
+    /// arpeggio([0, 3, 7, 12], 16.0, gate = 0.5, mode = "up_down")
+    /// ```
+    fn arpeggio<'v>(
+        pattern: Value<'v>,
+        rate_hz: f64,
+        #[starlark(default = 1.0)] gate: f64,
+        #[starlark(default = "up")] mode: &str,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        const MODES: &[&str] = &["up", "down", "up_down", "random"];
+
+        let list = ListRef::from_value(pattern).ok_or_else(|| {
+            anyhow::anyhow!(
+                "S102: arpeggio(): 'pattern' expected list of semitone offsets, got {}",
+                pattern.get_type()
+            )
+        })?;
+        if list.is_empty() {
+            return Err(anyhow::anyhow!(
+                "S103: arpeggio(): 'pattern' must contain at least one offset"
+            ));
+        }
+        let offsets = list
+            .iter()
+            .map(|v| extract_float(v, "arpeggio", "pattern"))
+            .collect::<anyhow::Result<Vec<f64>>>()?;
+        validate_positive(rate_hz, "arpeggio", "rate_hz").map_err(|e| anyhow::anyhow!(e))?;
+        validate_positive(gate, "arpeggio", "gate").map_err(|e| anyhow::anyhow!(e))?;
+        validate_unit_range(gate, "arpeggio", "gate").map_err(|e| anyhow::anyhow!(e))?;
+        validate_enum(mode, MODES, "arpeggio", "mode").map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(hashed_key(heap, "pattern"), heap.alloc(AllocList(offsets)));
+        dict.insert_hashed(hashed_key(heap, "rate_hz"), heap.alloc(rate_hz).to_value());
+        dict.insert_hashed(hashed_key(heap, "gate"), heap.alloc(gate).to_value());
+        dict.insert_hashed(hashed_key(heap, "mode"), heap.alloc_str(mode).to_value());
+
+        Ok(dict)
+    }
 }
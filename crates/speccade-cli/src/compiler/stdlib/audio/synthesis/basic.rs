@@ -48,6 +48,9 @@ const NOISE_TYPES: &[&str] = &["white", "pink", "brown"];
 /// Valid sweep curves.
 const SWEEP_CURVES: &[&str] = &["linear", "exponential", "logarithmic"];
 
+/// Valid oscillator anti-aliasing modes.
+const ANTI_ALIAS_MODES: &[&str] = &["none", "polyblep", "oversample"];
+
 /// Registers basic synthesis functions into a GlobalsBuilder.
 pub fn register(builder: &mut GlobalsBuilder) {
     register_basic_synthesis(builder);
@@ -62,6 +65,9 @@ fn register_basic_synthesis(builder: &mut GlobalsBuilder) {
         #[starlark(default = "linear")] curve: &str,
         #[starlark(default = NoneType)] detune: Value<'v>,
         #[starlark(default = NoneType)] duty: Value<'v>,
+        #[starlark(default = NoneType)] anti_alias: Value<'v>,
+        #[starlark(default = NoneType)] detune_voices: Value<'v>,
+        #[starlark(default = NoneType)] detune_spread: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(frequency, "oscillator", "frequency").map_err(|e| anyhow::anyhow!(e))?;
@@ -119,6 +125,52 @@ fn register_basic_synthesis(builder: &mut GlobalsBuilder) {
             dict.insert_hashed(hashed_key(heap, "duty"), heap.alloc(duty_val).to_value());
         }
 
+        // Add anti_alias if provided
+        if !anti_alias.is_none() {
+            let mode = anti_alias.unpack_str().ok_or_else(|| {
+                anyhow::anyhow!("S102: oscillator(): 'anti_alias' must be a string")
+            })?;
+            validate_enum(mode, ANTI_ALIAS_MODES, "oscillator", "anti_alias")
+                .map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(
+                hashed_key(heap, "anti_alias"),
+                heap.alloc_str(mode).to_value(),
+            );
+        }
+
+        // Add unison voices if provided
+        if !detune_voices.is_none() {
+            let voices = detune_voices.unpack_i32().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "S102: oscillator(): 'detune_voices' expected int, got {}",
+                    detune_voices.get_type()
+                )
+            })?;
+            if !(1..=16).contains(&voices) {
+                return Err(anyhow::anyhow!(
+                    "S103: oscillator(): 'detune_voices' must be in range 1 to 16, got {}",
+                    voices
+                ));
+            }
+            dict.insert_hashed(
+                hashed_key(heap, "detune_voices"),
+                heap.alloc(voices).to_value(),
+            );
+        }
+        if !detune_spread.is_none() {
+            let spread = extract_float(detune_spread, "oscillator", "detune_spread")?;
+            if spread < 0.0 {
+                return Err(anyhow::anyhow!(
+                    "S103: oscillator(): 'detune_spread' must be >= 0, got {}",
+                    spread
+                ));
+            }
+            dict.insert_hashed(
+                hashed_key(heap, "detune_spread"),
+                heap.alloc(spread).to_value(),
+            );
+        }
+
         Ok(dict)
     }
 
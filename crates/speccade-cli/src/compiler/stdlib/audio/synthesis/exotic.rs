@@ -542,6 +542,7 @@ fn register_exotic_synthesis(builder: &mut GlobalsBuilder) {
     /// * `bow_pressure` - Bow pressure/force 0.0-1.0
     /// * `bow_position` - Bow position on string 0.0-1.0 (0 = bridge, 1 = nut)
     /// * `damping` - String damping 0.0-1.0
+    /// * `bow_velocity` - Optional bow velocity 0.0-1.0 (default: 0.2)
     ///
     /// # Example
     /// ```starlark
@@ -558,6 +559,7 @@ fn register_exotic_synthesis(builder: &mut GlobalsBuilder) {
         #[starlark(require = named)] bow_pressure: f64,
         #[starlark(require = named)] bow_position: f64,
         #[starlark(require = named)] damping: f64,
+        #[starlark(require = named, default = NoneType)] bow_velocity: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_positive(frequency, "bowed_string", "frequency")
@@ -588,6 +590,16 @@ fn register_exotic_synthesis(builder: &mut GlobalsBuilder) {
         );
         dict.insert_hashed(hashed_key(heap, "damping"), heap.alloc(damping).to_value());
 
+        if !bow_velocity.is_none() {
+            let bow_velocity = extract_float(bow_velocity, "bowed_string", "bow_velocity")?;
+            validate_unit_range(bow_velocity, "bowed_string", "bow_velocity")
+                .map_err(|e| anyhow::anyhow!(e))?;
+            dict.insert_hashed(
+                hashed_key(heap, "bow_velocity"),
+                heap.alloc(bow_velocity).to_value(),
+            );
+        }
+
         Ok(dict)
     }
 
@@ -829,4 +841,45 @@ fn register_exotic_synthesis(builder: &mut GlobalsBuilder) {
 
         Ok(dict)
     }
+
+    /// Creates a Transient synthesis block (short percussive click).
+    ///
+    /// # Arguments
+    /// * `sharpness` - Attack sharpness 0.0-1.0; higher decays faster
+    /// * `pitch` - Pitch of the tonal body in Hz
+    /// * `noise_mix` - Noise amount 0.0-1.0; 0.0 is fully tonal (default: 0.3)
+    ///
+    /// # Example
+    /// ```starlark
+    /// transient(sharpness = 0.8, pitch = 1200, noise_mix = 0.4)
+    /// ```
+    #[starlark(speculative_exec_safe)]
+    fn transient<'v>(
+        #[starlark(require = named)] sharpness: f64,
+        #[starlark(require = named)] pitch: f64,
+        #[starlark(require = named, default = 0.3)] noise_mix: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Dict<'v>> {
+        validate_unit_range(sharpness, "transient", "sharpness").map_err(|e| anyhow::anyhow!(e))?;
+        validate_positive(pitch, "transient", "pitch").map_err(|e| anyhow::anyhow!(e))?;
+        validate_unit_range(noise_mix, "transient", "noise_mix").map_err(|e| anyhow::anyhow!(e))?;
+
+        let mut dict = new_dict(heap);
+
+        dict.insert_hashed(
+            hashed_key(heap, "type"),
+            heap.alloc_str("transient").to_value(),
+        );
+        dict.insert_hashed(
+            hashed_key(heap, "sharpness"),
+            heap.alloc(sharpness).to_value(),
+        );
+        dict.insert_hashed(hashed_key(heap, "pitch"), heap.alloc(pitch).to_value());
+        dict.insert_hashed(
+            hashed_key(heap, "noise_mix"),
+            heap.alloc(noise_mix).to_value(),
+        );
+
+        Ok(dict)
+    }
 }
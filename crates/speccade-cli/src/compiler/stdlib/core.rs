@@ -6,7 +6,9 @@ use starlark::collections::SmallMap;
 use starlark::environment::GlobalsBuilder;
 use starlark::starlark_module;
 use starlark::values::list::AllocList;
-use starlark::values::{dict::Dict, list::UnpackList, none::NoneType, Heap, Value, ValueLike};
+use starlark::values::{
+    dict::Dict, list::UnpackList, none::NoneType, Heap, UnpackValue, Value, ValueLike,
+};
 
 use speccade_spec::AssetType;
 
@@ -105,12 +107,13 @@ fn register_core_functions(builder: &mut GlobalsBuilder) {
     /// # Arguments
     /// * `asset_id` - Kebab-case identifier for the asset
     /// * `asset_type` - Asset type: "audio", "music", "texture", "sprite", "vfx", "ui", "font", "static_mesh", "skeletal_mesh", "skeletal_animation"
-    /// * `seed` - Deterministic seed (0 to 2^32-1)
+    /// * `seed` - Optional deterministic seed (0 to 2^32-1); omit or pass None to let `eval` derive one from `asset_id`
     /// * `outputs` - List of output specifications from `output()`
     /// * `recipe` - Optional recipe specification dict
     /// * `description` - Optional asset description
     /// * `tags` - Optional list of style tags (stored as `style_tags` in the output spec)
     /// * `license` - Optional SPDX license identifier (default: "CC0-1.0")
+    /// * `variants` - Optional list of variant dicts (e.g. from `sweep()`)
     ///
     /// # Returns
     /// A dict matching the Spec IR structure with spec_version: 1.
@@ -127,12 +130,13 @@ fn register_core_functions(builder: &mut GlobalsBuilder) {
     fn spec<'v>(
         asset_id: &str,
         asset_type: &str,
-        seed: i64,
+        #[starlark(default = NoneType)] seed: Value<'v>,
         outputs: UnpackList<Value<'v>>,
         #[starlark(default = NoneType)] recipe: Value<'v>,
         #[starlark(default = NoneType)] description: Value<'v>,
         #[starlark(default = NoneType)] tags: Value<'v>,
         #[starlark(default = "CC0-1.0")] license: &str,
+        #[starlark(default = NoneType)] variants: Value<'v>,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         // Validate asset_id
@@ -147,14 +151,23 @@ fn register_core_functions(builder: &mut GlobalsBuilder) {
             ));
         }
 
-        // Validate seed is in valid range (0 to 2^32-1)
-        if seed < 0 || seed > u32::MAX as i64 {
+        // Validate seed is None or an int in valid range (0 to 2^32-1)
+        let seed = if seed.is_none() {
+            None
+        } else if let Some(value) = u32::unpack_value(seed) {
+            Some(value)
+        } else if seed.get_type() == "int" {
             return Err(anyhow::anyhow!(
                 "S103: spec(): 'seed' must be in range 0 to {}, got {}",
                 u32::MAX,
-                seed
+                seed.to_str()
             ));
-        }
+        } else {
+            return Err(anyhow::anyhow!(
+                "S102: spec(): 'seed' expected int or None, got {}",
+                seed.get_type()
+            ));
+        };
 
         // Validate outputs is non-empty
         if outputs.items.is_empty() {
@@ -186,8 +199,10 @@ fn register_core_functions(builder: &mut GlobalsBuilder) {
             heap.alloc_str(license).to_value(),
         );
 
-        // seed
-        dict.insert_hashed(hashed_key(heap, "seed"), heap.alloc(seed).to_value());
+        // seed (omitted when None; `eval` derives one from asset_id)
+        if let Some(seed) = seed {
+            dict.insert_hashed(hashed_key(heap, "seed"), heap.alloc(seed).to_value());
+        }
 
         // outputs (convert from list)
         let outputs_list = heap.alloc(AllocList(outputs.items));
@@ -208,6 +223,11 @@ fn register_core_functions(builder: &mut GlobalsBuilder) {
             dict.insert_hashed(hashed_key(heap, "style_tags"), tags);
         }
 
+        // Optional: variants
+        if !variants.is_none() {
+            dict.insert_hashed(hashed_key(heap, "variants"), variants);
+        }
+
         // Optional: recipe
         if !recipe.is_none() {
             dict.insert_hashed(hashed_key(heap, "recipe"), recipe);
@@ -215,6 +235,120 @@ fn register_core_functions(builder: &mut GlobalsBuilder) {
 
         Ok(dict)
     }
+
+    /// Sweeps a recipe parameter across values, producing one variant per value.
+    ///
+    /// Each variant overrides `param_path` with its value and gets a derived id
+    /// `{prefix}_{index}` and `seed_offset = index`. Expanded variants use
+    /// `derive_variant_spec_seed(seed, seed_offset, variant_id)` as their seed.
+    ///
+    /// # Arguments
+    /// * `param_path` - Parameter path starting with "recipe.params." (e.g. "recipe.params.nodes[0].noise.scale")
+    /// * `values` - Values to sweep (at least one)
+    /// * `prefix` - Variant id prefix (default: last key of `param_path`)
+    ///
+    /// # Returns
+    /// A list of variant dicts for `spec(variants = ...)`.
+    ///
+    /// # Example
+    /// ```starlark
+    /// sweep("recipe.params.nodes[0].noise.scale", [0.05, 0.1, 0.2])
+    /// ```
+    fn sweep<'v>(
+        param_path: &str,
+        values: UnpackList<Value<'v>>,
+        #[starlark(default = NoneType)] prefix: Value<'v>,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Value<'v>> {
+        let params = param_path
+            .strip_prefix(SWEEP_PATH_ROOT)
+            .filter(|rest| !rest.is_empty())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "S101: sweep(): 'param_path' must start with '{}', got '{}'",
+                    SWEEP_PATH_ROOT,
+                    param_path
+                )
+            })?;
+        if values.items.is_empty() {
+            return Err(anyhow::anyhow!(
+                "S101: sweep(): 'values' must have at least one value"
+            ));
+        }
+
+        let prefix = if prefix.is_none() {
+            sweep_id_prefix(params)
+        } else {
+            let prefix = prefix.unpack_str().ok_or_else(|| {
+                anyhow::anyhow!(
+                    "S102: sweep(): 'prefix' expected string, got {}",
+                    prefix.get_type()
+                )
+            })?;
+            if !is_valid_variant_id(prefix) {
+                return Err(anyhow::anyhow!(
+                    "S103: sweep(): 'prefix' must match [a-z][a-z0-9_-]*, got '{}'",
+                    prefix
+                ));
+            }
+            prefix.to_string()
+        };
+
+        let mut variants = Vec::with_capacity(values.items.len());
+        for (index, value) in values.items.into_iter().enumerate() {
+            let mut overrides = new_dict(heap);
+            overrides.insert_hashed(hashed_key(heap, param_path), value);
+
+            let mut variant = new_dict(heap);
+            variant.insert_hashed(
+                hashed_key(heap, "variant_id"),
+                heap.alloc_str(&format!("{}_{}", prefix, index)).to_value(),
+            );
+            variant.insert_hashed(
+                hashed_key(heap, "seed_offset"),
+                heap.alloc(index as i32).to_value(),
+            );
+            variant.insert_hashed(hashed_key(heap, "overrides"), heap.alloc(overrides));
+            variants.push(heap.alloc(variant));
+        }
+
+        Ok(heap.alloc(AllocList(variants)))
+    }
+}
+
+/// Root that every `sweep()` parameter path must start with.
+const SWEEP_PATH_ROOT: &str = "recipe.params.";
+
+/// Returns true if `id` matches the variant id pattern `[a-z][a-z0-9_-]*`.
+fn is_valid_variant_id(id: &str) -> bool {
+    let mut chars = id.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+}
+
+/// Derives a variant id prefix from the last key of a parameter path.
+fn sweep_id_prefix(params_path: &str) -> String {
+    let last_key = params_path
+        .rsplit('.')
+        .next()
+        .and_then(|segment| segment.split('[').next())
+        .unwrap_or_default();
+    let sanitized: String = last_key
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    if is_valid_variant_id(&sanitized) {
+        sanitized
+    } else {
+        format!("v{}", sanitized)
+    }
 }
 
 #[cfg(test)]
@@ -364,6 +498,71 @@ spec(
         assert_eq!(result["seed"].as_u64(), Some(4294967295));
     }
 
+    #[test]
+    fn test_spec_seed_none_is_omitted() {
+        let result = eval_to_json(
+            r#"
+spec(
+    asset_id = "no-seed-01",
+    asset_type = "audio",
+    seed = None,
+    outputs = [output("test.wav", "wav")]
+)
+"#,
+        )
+        .unwrap();
+
+        assert!(result.get("seed").is_none());
+    }
+
+    #[test]
+    fn test_spec_seed_can_be_omitted() {
+        let result = eval_to_json(
+            r#"
+spec(
+    asset_id = "no-seed-02",
+    asset_type = "audio",
+    outputs = [output("test.wav", "wav")]
+)
+"#,
+        )
+        .unwrap();
+
+        assert!(result.get("seed").is_none());
+    }
+
+    #[test]
+    fn test_spec_seed_above_u32_max() {
+        let err = eval_to_json(
+            r#"
+spec(
+    asset_id = "test",
+    asset_type = "audio",
+    seed = 4294967296,
+    outputs = [output("test.wav", "wav")]
+)
+"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("S103"));
+    }
+
+    #[test]
+    fn test_spec_seed_wrong_type() {
+        let err = eval_to_json(
+            r#"
+spec(
+    asset_id = "test",
+    asset_type = "audio",
+    seed = "42",
+    outputs = [output("test.wav", "wav")]
+)
+"#,
+        )
+        .unwrap_err();
+        assert!(err.contains("S102"));
+    }
+
     #[test]
     fn test_spec_supports_skeletal_asset_types() {
         let result = eval_to_json(
@@ -380,4 +579,64 @@ spec(
 
         assert_eq!(result["asset_type"], "skeletal_mesh");
     }
+
+    #[test]
+    fn test_sweep_expands_variants_with_overrides() {
+        let result = eval_to_json(
+            r#"
+spec(
+    asset_id = "sweep-01",
+    asset_type = "texture",
+    seed = 7,
+    outputs = [output("textures/n.png", "png", source = "n")],
+    recipe = {
+        "kind": "texture.procedural_v1",
+        "params": texture_graph([32, 32], [noise_node("n", "perlin", 0.1)]),
+    },
+    variants = sweep("recipe.params.nodes[0].noise.scale", [0.05, 0.1, 0.2]),
+)
+"#,
+        )
+        .unwrap();
+
+        let spec = speccade_spec::Spec::from_value(result).unwrap();
+        let variants = spec.variants.clone().unwrap();
+        assert_eq!(variants.len(), 3);
+
+        for (index, (variant, scale)) in variants.iter().zip([0.05, 0.1, 0.2]).enumerate() {
+            assert_eq!(variant.variant_id, format!("scale_{}", index));
+            assert_eq!(variant.seed_offset, index as u32);
+
+            let expanded = spec.for_variant(variant).unwrap();
+            assert_eq!(
+                expanded.seed,
+                speccade_spec::derive_variant_spec_seed(7, index as u32, &variant.variant_id)
+            );
+            let params = &expanded.recipe.as_ref().unwrap().params;
+            assert_eq!(params["nodes"][0]["noise"]["scale"], scale);
+        }
+    }
+
+    #[test]
+    fn test_sweep_is_deterministic() {
+        let source = r#"sweep("recipe.params.tileable", [True, False], prefix = "tile")"#;
+        let first = eval_to_json(source).unwrap();
+        let second = eval_to_json(source).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first[0]["variant_id"], "tile_0");
+        assert_eq!(first[1]["variant_id"], "tile_1");
+        assert_eq!(first[1]["overrides"]["recipe.params.tileable"], false);
+    }
+
+    #[test]
+    fn test_sweep_invalid_path() {
+        let err = eval_to_json(r#"sweep("seed", [1, 2])"#).unwrap_err();
+        assert!(err.contains("S101"));
+    }
+
+    #[test]
+    fn test_sweep_empty_values() {
+        let err = eval_to_json(r#"sweep("recipe.params.scale", [])"#).unwrap_err();
+        assert!(err.contains("S101"));
+    }
 }
@@ -90,6 +90,7 @@ fn register_instrument_functions(builder: &mut GlobalsBuilder) {
     /// * `envelope` - ADSR envelope dict (from `envelope()`)
     /// * `loop_mode` - Sample loop mode: "auto", "none", "forward", "pingpong"
     /// * `default_volume` - Default volume (0-64)
+    /// * `stereo` - Bake a decorrelated stereo sample (IT and XM)
     /// * `comment` - Optional comment for documentation
     ///
     /// # Returns
@@ -115,6 +116,7 @@ fn register_instrument_functions(builder: &mut GlobalsBuilder) {
         #[starlark(default = NoneType)] loop_mode: Value<'v>,
         #[starlark(default = NoneType)] default_volume: Value<'v>,
         #[starlark(default = NoneType)] comment: Value<'v>,
+        #[starlark(default = false)] stereo: bool,
         heap: &'v Heap,
     ) -> anyhow::Result<Dict<'v>> {
         validate_non_empty(name, "tracker_instrument", "name").map_err(|e| anyhow::anyhow!(e))?;
@@ -211,6 +213,11 @@ fn register_instrument_functions(builder: &mut GlobalsBuilder) {
             }
         }
 
+        // Optional: stereo (omitted when false so mono specs are unchanged)
+        if stereo {
+            dict.insert_hashed(hashed_key(heap, "stereo"), heap.alloc(true).to_value());
+        }
+
         // Optional: comment
         if !comment.is_none() {
             if let Some(comment_str) = comment.unpack_str() {
@@ -290,6 +297,15 @@ tracker_instrument(name = "kick", wav = "samples/kick.wav")
         assert_eq!(result["wav"], "samples/kick.wav");
     }
 
+    #[test]
+    fn test_tracker_instrument_stereo() {
+        let result = eval_to_json(r#"tracker_instrument(name = "pad", stereo = True)"#).unwrap();
+        assert_eq!(result["stereo"], true);
+
+        let result = eval_to_json(r#"tracker_instrument(name = "pad")"#).unwrap();
+        assert!(result.get("stereo").is_none());
+    }
+
     #[test]
     fn test_tracker_instrument_mutual_exclusivity() {
         let result = eval_to_json(
@@ -10,7 +10,17 @@ use crate::compiler::stdlib::validation::{validate_enum, validate_non_empty};
 use super::util::{hashed_key, new_dict};
 
 /// Valid tracker formats.
-const TRACKER_FORMATS: &[&str] = &["xm", "it"];
+const TRACKER_FORMATS: &[&str] = &["xm", "it", "mod", "s3m"];
+
+/// Maximum channel count for a (validated) tracker format.
+fn max_channels_for_format(format: &str) -> i32 {
+    match format {
+        "xm" => 32,
+        "mod" => 4,
+        "s3m" => 16,
+        _ => 64,
+    }
+}
 
 /// Registers song functions into a GlobalsBuilder.
 pub fn register(builder: &mut GlobalsBuilder) {
@@ -71,10 +81,10 @@ fn register_song_functions(builder: &mut GlobalsBuilder) {
     /// Creates a complete tracker song recipe.
     ///
     /// # Arguments
-    /// * `format` - Tracker format: "xm" or "it"
+    /// * `format` - Tracker format: "xm", "it", "mod" or "s3m"
     /// * `bpm` - Beats per minute (32-255)
     /// * `speed` - Tracker speed (ticks per row, 1-31)
-    /// * `channels` - Number of channels (XM: 1-32, IT: 1-64)
+    /// * `channels` - Number of channels (XM: 1-32, IT: 1-64, MOD: 1-4, S3M: 1-16)
     /// * `instruments` - List of instrument dicts from `tracker_instrument()`
     /// * `patterns` - Dict of pattern_name -> pattern dict from `tracker_pattern()`
     /// * `arrangement` - List of arrangement entries from `arrangement_entry()`
@@ -141,7 +151,7 @@ fn register_song_functions(builder: &mut GlobalsBuilder) {
         }
 
         // Validate channels based on format
-        let max_channels = if format == "xm" { 32 } else { 64 };
+        let max_channels = max_channels_for_format(format);
         if !(1..=max_channels).contains(&channels) {
             return Err(anyhow::anyhow!(
                 "S103: tracker_song(): 'channels' must be 1-{} for {} format, got {}",
@@ -238,10 +248,10 @@ fn register_song_functions(builder: &mut GlobalsBuilder) {
     /// * `asset_id` - Kebab-case identifier for the asset
     /// * `seed` - Deterministic seed (0 to 2^32-1)
     /// * `output_path` - Output file path
-    /// * `format` - Tracker format: "xm" or "it"
+    /// * `format` - Tracker format: "xm", "it", "mod" or "s3m"
     /// * `bpm` - Beats per minute (32-255)
     /// * `speed` - Tracker speed (ticks per row, 1-31)
-    /// * `channels` - Number of channels (XM: 1-32, IT: 1-64)
+    /// * `channels` - Number of channels (XM: 1-32, IT: 1-64, MOD: 1-4, S3M: 1-16)
     /// * `instruments` - List of instrument dicts from `tracker_instrument()`
     /// * `patterns` - Dict of pattern_name -> pattern dict from `tracker_pattern()`
     /// * `arrangement` - List of arrangement entries from `arrangement_entry()`
@@ -322,7 +332,7 @@ fn register_song_functions(builder: &mut GlobalsBuilder) {
         }
 
         // Validate channels
-        let max_channels = if format == "xm" { 32 } else { 64 };
+        let max_channels = max_channels_for_format(format);
         if !(1..=max_channels).contains(&channels) {
             return Err(anyhow::anyhow!(
                 "S103: music_spec(): 'channels' must be 1-{} for {} format, got {}",
@@ -487,7 +497,7 @@ tracker_song(
         let result = eval_to_json(
             r#"
 tracker_song(
-    format = "mod",
+    format = "669",
     bpm = 120,
     speed = 6,
     channels = 4,
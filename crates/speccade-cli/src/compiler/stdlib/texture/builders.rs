@@ -0,0 +1,481 @@
+//! Ergonomic texture graph builders with automatic node ids.
+//!
+//! `noise()`, `threshold()` and `blend()` return node *lists* rather than single
+//! nodes. The last node in a list is its output, so builders nest naturally:
+//!
+//! ```starlark
+//! nodes = blend(noise("perlin", 0.05), threshold(noise("worley", 0.2), 0.6), "screen")
+//! ```
+//!
+//! Node ids are content-addressed: `<type>_<hash>` where the hash covers the
+//! node's op (including the ids of its inputs). Ids are therefore deterministic,
+//! independent of where a node appears in the graph, and identical sub-graphs
+//! collapse to a single set of nodes when lists are merged.
+
+use serde_json::json;
+use starlark::environment::GlobalsBuilder;
+use starlark::starlark_module;
+use starlark::values::list::{AllocList, ListRef};
+use starlark::values::{dict::Dict, dict::DictRef, Heap, Value};
+
+use super::super::validation::{validate_enum, validate_positive_int};
+use super::{hashed_key, new_dict};
+
+/// Valid noise algorithms (mirrors `noise_node()`).
+const NOISE_ALGORITHMS: &[&str] = &[
+    "perlin",
+    "simplex",
+    "worley",
+    "value",
+    "gabor",
+    "fbm",
+    "ridged_multifractal",
+    "billow",
+];
+
+/// Valid `blend()` modes.
+const BLEND_MODES: &[&str] = &[
+    "add",
+    "multiply",
+    "screen",
+    "overlay",
+    "soft_light",
+    "difference",
+];
+
+/// Number of hex digits of the content hash used in generated ids.
+const ID_HASH_LEN: usize = 8;
+
+/// Registers texture graph builder functions into a GlobalsBuilder.
+pub fn register(builder: &mut GlobalsBuilder) {
+    register_texture_builder_functions(builder);
+}
+
+/// A node list being assembled, with ids tracked for de-duplication.
+struct NodeList<'v> {
+    nodes: Vec<Value<'v>>,
+    ids: Vec<String>,
+}
+
+impl<'v> NodeList<'v> {
+    fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            ids: Vec::new(),
+        }
+    }
+
+    /// Appends `nodes`, skipping any whose id is already present.
+    ///
+    /// Generated ids are content hashes, so a repeated id means an identical node.
+    fn extend(&mut self, nodes: Vec<(String, Value<'v>)>) {
+        for (id, node) in nodes {
+            if !self.ids.contains(&id) {
+                self.ids.push(id);
+                self.nodes.push(node);
+            }
+        }
+    }
+
+    fn push(&mut self, id: String, node: Value<'v>) {
+        self.extend(vec![(id, node)]);
+    }
+
+    fn into_value(self, heap: &'v Heap) -> Value<'v> {
+        heap.alloc(AllocList(self.nodes))
+    }
+}
+
+/// Reads the `id` field of a node dict.
+fn node_id<'v>(
+    node: Value<'v>,
+    heap: &'v Heap,
+    function: &str,
+    param: &str,
+) -> anyhow::Result<String> {
+    let dict = DictRef::from_value(node).ok_or_else(|| {
+        anyhow::anyhow!(
+            "S102: {}(): '{}' must contain node dicts, got {}",
+            function,
+            param,
+            node.get_type()
+        )
+    })?;
+    let id_key = heap.alloc_str("id").to_value();
+    match dict.get(id_key) {
+        Ok(Some(id)) => id.unpack_str().map(|s| s.to_string()).ok_or_else(|| {
+            anyhow::anyhow!(
+                "S102: {}(): '{}' node 'id' must be a string",
+                function,
+                param
+            )
+        }),
+        _ => Err(anyhow::anyhow!(
+            "S101: {}(): '{}' contains a node without an 'id'",
+            function,
+            param
+        )),
+    }
+}
+
+/// Resolves a builder input into its nodes and output id.
+///
+/// Accepts a node list (output is the last node), a single node dict, or a
+/// string id referencing a node defined elsewhere in the graph.
+fn resolve_input<'v>(
+    input: Value<'v>,
+    heap: &'v Heap,
+    function: &str,
+    param: &str,
+) -> anyhow::Result<(Vec<(String, Value<'v>)>, String)> {
+    if let Some(id) = input.unpack_str() {
+        if id.is_empty() {
+            return Err(anyhow::anyhow!(
+                "S101: {}(): '{}' must not be empty",
+                function,
+                param
+            ));
+        }
+        return Ok((Vec::new(), id.to_string()));
+    }
+
+    let items: Vec<Value<'v>> = if let Some(list) = ListRef::from_value(input) {
+        list.iter().collect()
+    } else if DictRef::from_value(input).is_some() {
+        vec![input]
+    } else {
+        return Err(anyhow::anyhow!(
+            "S102: {}(): '{}' expected node list, node dict, or node id, got {}",
+            function,
+            param,
+            input.get_type()
+        ));
+    };
+
+    let mut nodes = Vec::with_capacity(items.len());
+    for item in items {
+        nodes.push((node_id(item, heap, function, param)?, item));
+    }
+
+    let output = nodes
+        .last()
+        .map(|(id, _)| id.clone())
+        .ok_or_else(|| anyhow::anyhow!("S101: {}(): '{}' must not be empty", function, param))?;
+
+    Ok((nodes, output))
+}
+
+/// Derives a stable node id from the node's op.
+fn auto_id(op: &serde_json::Value) -> anyhow::Result<String> {
+    let node_type = op["type"].as_str().unwrap_or("node");
+    let hash = speccade_spec::hash::canonical_value_hash(op)
+        .map_err(|e| anyhow::anyhow!("failed to hash texture node: {}", e))?;
+    Ok(format!("{}_{}", node_type, &hash[..ID_HASH_LEN]))
+}
+
+/// Allocates a node dict with `id` followed by the fields of `op`.
+fn alloc_node<'v>(id: &str, op: &serde_json::Value, heap: &'v Heap) -> Value<'v> {
+    let mut dict = new_dict(heap);
+    dict.insert_hashed(hashed_key(heap, "id"), heap.alloc_str(id).to_value());
+    if let Some(fields) = op.as_object() {
+        for (key, value) in fields {
+            dict.insert_hashed(hashed_key(heap, key), alloc_json(value, heap));
+        }
+    }
+    heap.alloc(dict)
+}
+
+/// Allocates a JSON value on the Starlark heap.
+fn alloc_json<'v>(value: &serde_json::Value, heap: &'v Heap) -> Value<'v> {
+    match value {
+        serde_json::Value::String(s) => heap.alloc_str(s).to_value(),
+        serde_json::Value::Bool(b) => heap.alloc(*b),
+        // Integers outside the i32 range become Starlark big ints rather than
+        // being truncated.
+        serde_json::Value::Number(n) => match (n.as_i64(), n.as_u64()) {
+            (Some(i), _) => heap.alloc(i),
+            (None, Some(u)) => heap.alloc(u),
+            _ => heap.alloc(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::Object(fields) => {
+            let mut dict: Dict<'v> = new_dict(heap);
+            for (key, value) in fields {
+                dict.insert_hashed(hashed_key(heap, key), alloc_json(value, heap));
+            }
+            heap.alloc(dict)
+        }
+        serde_json::Value::Array(items) => {
+            let items: Vec<Value<'v>> = items.iter().map(|v| alloc_json(v, heap)).collect();
+            heap.alloc(AllocList(items))
+        }
+        serde_json::Value::Null => Value::new_none(),
+    }
+}
+
+/// Appends a generated node for `op` and returns the finished list.
+fn finish<'v>(
+    mut list: NodeList<'v>,
+    op: serde_json::Value,
+    heap: &'v Heap,
+) -> anyhow::Result<Value<'v>> {
+    let id = auto_id(&op)?;
+    let node = alloc_node(&id, &op, heap);
+    list.push(id, node);
+    Ok(list.into_value(heap))
+}
+
+#[starlark_module]
+fn register_texture_builder_functions(builder: &mut GlobalsBuilder) {
+    /// Creates a noise node list with an automatically generated id.
+    ///
+    /// # Arguments
+    /// * `algorithm` - Noise algorithm: "perlin", "simplex", "worley", "value", "gabor", "fbm",
+    ///   "ridged_multifractal", "billow"
+    /// * `scale` - Noise scale factor (default: 0.1)
+    /// * `octaves` - Number of octaves for fractal noise (default: 4)
+    /// * `persistence` - Amplitude decay per octave (default: 0.5)
+    /// * `lacunarity` - Frequency multiplier per octave (default: 2.0)
+    ///
+    /// # Returns
+    /// A list containing one TextureProceduralNode with Noise op.
+    ///
+    /// # Example
+    /// ```starlark
+    /// noise("perlin", 0.05)
+    /// ```
+    fn noise<'v>(
+        #[starlark(default = "perlin")] algorithm: &str,
+        #[starlark(default = 0.1)] scale: f64,
+        #[starlark(default = 4)] octaves: i32,
+        #[starlark(default = 0.5)] persistence: f64,
+        #[starlark(default = 2.0)] lacunarity: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Value<'v>> {
+        validate_enum(algorithm, NOISE_ALGORITHMS, "noise", "algorithm")
+            .map_err(|e| anyhow::anyhow!(e))?;
+        if scale <= 0.0 {
+            return Err(anyhow::anyhow!(
+                "S103: noise(): 'scale' must be positive, got {}",
+                scale
+            ));
+        }
+        validate_positive_int(octaves as i64, "noise", "octaves")
+            .map_err(|e| anyhow::anyhow!(e))?;
+
+        let op = json!({
+            "type": "noise",
+            "noise": {
+                "algorithm": algorithm,
+                "scale": scale,
+                "octaves": octaves,
+                "persistence": persistence,
+                "lacunarity": lacunarity,
+            },
+        });
+        finish(NodeList::new(), op, heap)
+    }
+
+    /// Thresholds an input into {0, 1}, generating the node id automatically.
+    ///
+    /// # Arguments
+    /// * `input` - Node list (last node is the input), node dict, or node id
+    /// * `threshold` - Threshold value (default: 0.5)
+    ///
+    /// # Returns
+    /// The input's nodes followed by a TextureProceduralNode with Threshold op.
+    ///
+    /// # Example
+    /// ```starlark
+    /// threshold(noise("worley", 0.2), 0.6)
+    /// ```
+    fn threshold<'v>(
+        input: Value<'v>,
+        #[starlark(default = 0.5)] threshold: f64,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Value<'v>> {
+        let (nodes, input_id) = resolve_input(input, heap, "threshold", "input")?;
+
+        let mut list = NodeList::new();
+        list.extend(nodes);
+
+        let op = json!({
+            "type": "threshold",
+            "input": input_id,
+            "threshold": threshold,
+        });
+        finish(list, op, heap)
+    }
+
+    /// Blends two inputs, generating the node id automatically.
+    ///
+    /// # Arguments
+    /// * `base` - Node list (last node is the output), node dict, or node id
+    /// * `top` - Node list (last node is the output), node dict, or node id
+    /// * `mode` - "add", "multiply", "screen", "overlay", "soft_light", "difference" (default: "multiply")
+    ///
+    /// # Returns
+    /// The nodes of `base` and `top` (shared nodes once) followed by the blend node.
+    ///
+    /// # Example
+    /// ```starlark
+    /// blend(noise("perlin", 0.05), noise("worley", 0.2), "screen")
+    /// ```
+    fn blend<'v>(
+        base: Value<'v>,
+        top: Value<'v>,
+        #[starlark(default = "multiply")] mode: &str,
+        heap: &'v Heap,
+    ) -> anyhow::Result<Value<'v>> {
+        validate_enum(mode, BLEND_MODES, "blend", "mode").map_err(|e| anyhow::anyhow!(e))?;
+
+        let (base_nodes, base_id) = resolve_input(base, heap, "blend", "base")?;
+        let (top_nodes, top_id) = resolve_input(top, heap, "blend", "top")?;
+
+        let mut list = NodeList::new();
+        list.extend(base_nodes);
+        list.extend(top_nodes);
+
+        let op = match mode {
+            "add" | "multiply" => json!({ "type": mode, "a": base_id, "b": top_id }),
+            _ => json!({
+                "type": format!("blend_{}", mode),
+                "base": base_id,
+                "blend": top_id,
+            }),
+        };
+        finish(list, op, heap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::tests::eval_to_json;
+    use serde_json::json;
+    use speccade_spec::recipe::texture::TextureProceduralV1Params;
+
+    fn expected_id(op: serde_json::Value) -> String {
+        super::auto_id(&op).unwrap()
+    }
+
+    #[test]
+    fn test_builders_match_hand_written_graph() {
+        let built = eval_to_json(
+            r#"
+texture_graph(
+    [64, 64],
+    blend(noise("perlin", 0.05), threshold(noise("worley", 0.2, 1), 0.6), "screen"),
+)
+"#,
+        )
+        .unwrap();
+
+        let perlin = json!({
+            "type": "noise",
+            "noise": { "algorithm": "perlin", "scale": 0.05, "octaves": 4, "persistence": 0.5, "lacunarity": 2.0 }
+        });
+        let worley = json!({
+            "type": "noise",
+            "noise": { "algorithm": "worley", "scale": 0.2, "octaves": 1, "persistence": 0.5, "lacunarity": 2.0 }
+        });
+        let perlin_id = expected_id(perlin.clone());
+        let worley_id = expected_id(worley.clone());
+        let mask = json!({ "type": "threshold", "input": worley_id, "threshold": 0.6 });
+        let mask_id = expected_id(mask.clone());
+        let screen = json!({ "type": "blend_screen", "base": perlin_id, "blend": mask_id });
+        let screen_id = expected_id(screen.clone());
+
+        let with_id = |id: &str, op: serde_json::Value| {
+            let mut node = op;
+            node["id"] = json!(id);
+            node
+        };
+        let hand_written = json!({
+            "resolution": [64, 64],
+            "tileable": true,
+            "nodes": [
+                with_id(&perlin_id, perlin),
+                with_id(&worley_id, worley),
+                with_id(&mask_id, mask),
+                with_id(&screen_id, screen),
+            ]
+        });
+
+        assert_eq!(built, hand_written);
+        assert!(perlin_id.starts_with("noise_"));
+        assert!(screen_id.starts_with("blend_screen_"));
+
+        // The built graph must parse as a procedural recipe.
+        let params: TextureProceduralV1Params = serde_json::from_value(built).unwrap();
+        assert_eq!(params.nodes.len(), 4);
+    }
+
+    #[test]
+    fn test_builder_ids_are_stable() {
+        let first = eval_to_json(r#"threshold(noise("simplex", 0.1), 0.4)"#).unwrap();
+        let second = eval_to_json(r#"threshold(noise("simplex", 0.1), 0.4)"#).unwrap();
+        assert_eq!(first, second);
+
+        // Ids do not depend on where the sub-graph is used.
+        let nested =
+            eval_to_json(r#"blend(noise("value"), threshold(noise("simplex", 0.1), 0.4))"#)
+                .unwrap();
+        let nested = nested.as_array().unwrap();
+        assert_eq!(nested[1]["id"], first[0]["id"]);
+        assert_eq!(nested[2]["id"], first[1]["id"]);
+
+        // Different params produce different ids.
+        let other = eval_to_json(r#"threshold(noise("simplex", 0.1), 0.5)"#).unwrap();
+        assert_ne!(other[1]["id"], first[1]["id"]);
+    }
+
+    #[test]
+    fn test_blend_shares_common_subgraph() {
+        let result = eval_to_json(
+            r#"
+n = noise("perlin", 0.1)
+blend(n, threshold(n, 0.5), "add")
+"#,
+        )
+        .unwrap();
+        let nodes = result.as_array().unwrap();
+        assert_eq!(nodes.len(), 3);
+        assert_eq!(nodes[2]["type"], "add");
+        assert_eq!(nodes[2]["a"], nodes[0]["id"]);
+        assert_eq!(nodes[2]["b"], nodes[1]["id"]);
+    }
+
+    #[test]
+    fn test_builders_accept_node_ids_and_dicts() {
+        let result =
+            eval_to_json(r#"blend("base", noise_node("detail", "simplex"), "multiply")"#).unwrap();
+        let nodes = result.as_array().unwrap();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0]["id"], "detail");
+        assert_eq!(nodes[1]["a"], "base");
+        assert_eq!(nodes[1]["b"], "detail");
+    }
+
+    #[test]
+    fn test_blend_invalid_mode() {
+        let err = eval_to_json(r#"blend(noise(), noise("value"), "burn")"#).unwrap_err();
+        assert!(err.contains("S104"));
+    }
+
+    #[test]
+    fn test_alloc_json_keeps_large_integers() {
+        let heap = starlark::values::Heap::new();
+        for n in [json!(7), json!(-5_000_000_000i64), json!(u64::MAX)] {
+            let value = super::alloc_json(&n, &heap);
+            assert_eq!(value.get_type(), "int");
+            assert_eq!(value.to_str(), n.to_string());
+        }
+        let value = super::alloc_json(&json!(0.25), &heap);
+        assert_eq!(value.get_type(), "float");
+    }
+
+    #[test]
+    fn test_threshold_empty_input() {
+        let err = eval_to_json("threshold([])").unwrap_err();
+        assert!(err.contains("S101"));
+    }
+}
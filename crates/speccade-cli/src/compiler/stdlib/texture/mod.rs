@@ -2,6 +2,7 @@
 //!
 //! Provides helper functions for creating texture graph nodes and graphs.
 
+mod builders;
 mod decal;
 mod graph;
 mod matcap;
@@ -39,6 +40,7 @@ pub(crate) const TEXTURE_FORMATS: &[&str] = &["png", "jpg", "exr", "tga"];
 pub fn register(builder: &mut GlobalsBuilder) {
     nodes::register(builder);
     graph::register(builder);
+    builders::register(builder);
     spec::register(builder);
     trimsheet::register(builder);
     decal::register(builder);
@@ -26,7 +26,16 @@ fn new_dict<'v>(_heap: &'v Heap) -> Dict<'v> {
 }
 
 /// Valid noise algorithms.
-const NOISE_ALGORITHMS: &[&str] = &["perlin", "simplex", "worley", "value", "gabor", "fbm"];
+const NOISE_ALGORITHMS: &[&str] = &[
+    "perlin",
+    "simplex",
+    "worley",
+    "value",
+    "gabor",
+    "fbm",
+    "ridged_multifractal",
+    "billow",
+];
 
 /// Valid gradient directions.
 const GRADIENT_DIRECTIONS: &[&str] = &["horizontal", "vertical", "radial"];
@@ -48,7 +57,8 @@ fn register_texture_node_functions(builder: &mut GlobalsBuilder) {
     ///
     /// # Arguments
     /// * `id` - Unique node identifier
-    /// * `algorithm` - Noise algorithm: "perlin", "simplex", "worley", "value", "gabor", "fbm"
+    /// * `algorithm` - Noise algorithm: "perlin", "simplex", "worley", "value", "gabor", "fbm",
+    ///   "ridged_multifractal", "billow"
     /// * `scale` - Noise scale factor (default: 0.1)
     /// * `octaves` - Number of octaves for fractal noise (default: 4)
     /// * `persistence` - Amplitude decay per octave (default: 0.5)
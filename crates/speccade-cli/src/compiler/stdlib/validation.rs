@@ -175,9 +175,6 @@ pub fn extract_optional_float(value: Value) -> Option<f64> {
 }
 
 /// Extracts a required float from a Starlark Value.
-///
-/// Reserved for future use by stdlib functions that need float parameters from dynamic values.
-#[allow(dead_code)]
 pub fn extract_float(value: Value, function: &str, param: &str) -> Result<f64, String> {
     if let Some(f) = value.unpack_i32() {
         return Ok(f as f64);
@@ -2,8 +2,10 @@
 
 use super::waveform::{generate_waveform_png, preview_path_from_primary};
 use super::{get_primary_output, write_output_bytes, DispatchError, DispatchResult};
+use speccade_backend_audio::encode_ogg;
 use speccade_backend_audio::wav::extract_pcm_data;
-use speccade_spec::{OutputFormat, OutputKind, OutputResult, Spec, StageTiming};
+use speccade_backend_audio::GenerateResult;
+use speccade_spec::{OutputFormat, OutputKind, OutputResult, OutputSpec, Spec, StageTiming};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
@@ -20,32 +22,29 @@ pub(super) fn generate_audio(
         speccade_backend_audio::generate(spec)
             .map_err(|e| DispatchError::BackendError(format!("Audio generation failed: {}", e)))?
     };
+    warn_mono_compatibility(&result);
+    report_master_compression(&result);
+    warn_inter_sample_clipping(&result);
 
-    // Write WAV file to the output path from spec
+    // Write WAV or OGG file to the output path from spec
     let primary_output = get_primary_output(spec)?;
-    if primary_output.format != OutputFormat::Wav {
-        return Err(DispatchError::BackendError(format!(
-            "audio_v1 requires primary output format 'wav', got '{}'",
-            primary_output.format
-        )));
-    }
-    write_output_bytes(out_root, &primary_output.path, &result.wav.wav_data)?;
+    let pcm_hash = write_primary_audio(out_root, primary_output, &result)?;
 
     let mut outputs = Vec::new();
 
-    // Primary WAV output
-    let mut wav_output = OutputResult::tier1(
+    // Primary audio output
+    let mut audio_output = OutputResult::tier1(
         OutputKind::Primary,
-        OutputFormat::Wav,
+        primary_output.format,
         PathBuf::from(&primary_output.path),
-        result.wav.pcm_hash.clone(),
+        pcm_hash,
     );
 
     // Mark as preview if generated with preview duration
     if preview_duration.is_some() {
-        wav_output.preview = Some(true);
+        audio_output.preview = Some(true);
     }
-    outputs.push(wav_output);
+    outputs.push(audio_output);
 
     // Generate waveform preview PNG
     if let Some(pcm_data) = extract_pcm_data(&result.wav.wav_data) {
@@ -83,21 +82,18 @@ pub(super) fn generate_audio_profiled(
         speccade_backend_audio::generate(spec)
             .map_err(|e| DispatchError::BackendError(format!("Audio generation failed: {}", e)))?
     };
+    warn_mono_compatibility(&result);
+    report_master_compression(&result);
+    warn_inter_sample_clipping(&result);
     stages.push(StageTiming::new(
         "render_audio",
         render_start.elapsed().as_millis() as u64,
     ));
 
-    // Stage: encode_output (write WAV)
+    // Stage: encode_output (write WAV or OGG)
     let encode_start = Instant::now();
     let primary_output = get_primary_output(spec)?;
-    if primary_output.format != OutputFormat::Wav {
-        return Err(DispatchError::BackendError(format!(
-            "audio_v1 requires primary output format 'wav', got '{}'",
-            primary_output.format
-        )));
-    }
-    write_output_bytes(out_root, &primary_output.path, &result.wav.wav_data)?;
+    let pcm_hash = write_primary_audio(out_root, primary_output, &result)?;
     stages.push(StageTiming::new(
         "encode_output",
         encode_start.elapsed().as_millis() as u64,
@@ -105,17 +101,17 @@ pub(super) fn generate_audio_profiled(
 
     let mut outputs = Vec::new();
 
-    let mut wav_output = OutputResult::tier1(
+    let mut audio_output = OutputResult::tier1(
         OutputKind::Primary,
-        OutputFormat::Wav,
+        primary_output.format,
         PathBuf::from(&primary_output.path),
-        result.wav.pcm_hash.clone(),
+        pcm_hash,
     );
 
     if preview_duration.is_some() {
-        wav_output.preview = Some(true);
+        audio_output.preview = Some(true);
     }
-    outputs.push(wav_output);
+    outputs.push(audio_output);
 
     // Stage: generate_waveform (preview PNG)
     let waveform_start = Instant::now();
@@ -140,3 +136,62 @@ pub(super) fn generate_audio_profiled(
 
     Ok(DispatchResult::with_stages(outputs, stages))
 }
+
+/// Writes the rendered audio in the primary output's format and returns its PCM hash.
+///
+/// OGG outputs are hashed on the samples before encoding, so the hash matches the WAV rendering.
+fn write_primary_audio(
+    out_root: &Path,
+    primary_output: &OutputSpec,
+    result: &GenerateResult,
+) -> Result<String, DispatchError> {
+    match primary_output.format {
+        OutputFormat::Wav => {
+            write_output_bytes(out_root, &primary_output.path, &result.wav.wav_data)?;
+            Ok(result.wav.pcm_hash.clone())
+        }
+        OutputFormat::Ogg => {
+            let channels = if result.wav.is_stereo { 2 } else { 1 };
+            let ogg = encode_ogg(&result.samples, channels, result.wav.sample_rate)
+                .map_err(|e| DispatchError::BackendError(format!("OGG encoding failed: {}", e)))?;
+            write_output_bytes(out_root, &primary_output.path, &ogg.data)?;
+            Ok(ogg.pcm_hash)
+        }
+        other => Err(DispatchError::BackendError(format!(
+            "audio_v1 requires primary output format 'wav' or 'ogg', got '{}'",
+            other
+        ))),
+    }
+}
+
+/// Warns when summing the stereo mix to mono cancels a significant part of the signal.
+fn warn_mono_compatibility(result: &GenerateResult) {
+    if let Some(compat) = result.mono_compatibility {
+        if compat.has_phase_cancellation() {
+            eprintln!(
+                "[audio_v1] Warning: stereo mix loses {:.1} dB when summed to mono (phase cancellation)",
+                compat.loss_db()
+            );
+        }
+    }
+}
+
+/// Warns when the output's true peak clips even though its samples may not.
+fn warn_inter_sample_clipping(result: &GenerateResult) {
+    if result.true_peak_db > 0.0 {
+        eprintln!(
+            "[audio_v1] Warning: true peak {:+.1} dBTP exceeds 0 dBTP (sample peak {:+.1} dBFS); add a master_limiter to prevent inter-sample clipping",
+            result.true_peak_db, result.sample_peak_db
+        );
+    }
+}
+
+/// Reports how hard the master compressor worked, when one is configured.
+fn report_master_compression(result: &GenerateResult) {
+    if let Some(reduction_db) = result.master_gain_reduction_db {
+        eprintln!(
+            "[audio_v1] Master compressor peak gain reduction: {:.1} dB",
+            reduction_db
+        );
+    }
+}
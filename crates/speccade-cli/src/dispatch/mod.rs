@@ -13,6 +13,8 @@ mod ui;
 mod vfx;
 mod waveform;
 
+pub use texture::enable_node_cache;
+
 use speccade_spec::{BackendError, OutputKind, OutputResult, Spec, StageTiming};
 use std::fmt;
 use std::fs;
@@ -91,7 +93,7 @@ pub fn dispatch_generate(
     })?;
 
     // Dispatch based on recipe kind prefix
-    match kind.as_str() {
+    let mut outputs = match kind.as_str() {
         // Unified audio backend (handles both SFX and instruments)
         "audio_v1" => audio::generate_audio(spec, out_root_path, preview_duration),
 
@@ -121,6 +123,14 @@ pub fn dispatch_generate(
             texture::generate_texture_material_preset(spec, out_root_path)
         }
 
+        // Layered composite texture backend
+        "texture.composite_v1" => texture::generate_texture_composite(spec, out_root_path),
+
+        // Tiled sheet texture backend
+        "texture.tile_variation_v1" => {
+            texture::generate_texture_tile_variation(spec, out_root_path)
+        }
+
         // Sprite sheet backend
         "sprite.sheet_v1" => sprite::generate_sprite_sheet(spec, out_root_path),
 
@@ -194,7 +204,27 @@ pub fn dispatch_generate(
 
         // Unknown recipe kind
         _ => Err(DispatchError::BackendNotImplemented(kind.clone())),
+    }?;
+
+    record_file_checksums(&mut outputs, out_root_path)?;
+    Ok(outputs)
+}
+
+/// Records the on-disk BLAKE3 hash and size of each output written under `out_root`.
+fn record_file_checksums(
+    outputs: &mut [OutputResult],
+    out_root: &Path,
+) -> Result<(), DispatchError> {
+    for output in outputs {
+        output.record_file_checksum(out_root).map_err(|e| {
+            DispatchError::BackendError(format!(
+                "Failed to hash output {}: {}",
+                output.path.display(),
+                e
+            ))
+        })?;
     }
+    Ok(())
 }
 
 /// Result of a dispatch with optional profiling timings.
@@ -252,7 +282,7 @@ pub fn dispatch_generate_profiled(
     })?;
 
     // Dispatch based on recipe kind prefix with optional timing instrumentation
-    match kind.as_str() {
+    let mut result = match kind.as_str() {
         "audio_v1" => {
             if profile {
                 audio::generate_audio_profiled(spec, out_root_path, preview_duration)
@@ -328,6 +358,23 @@ pub fn dispatch_generate_profiled(
             }
         }
 
+        "texture.composite_v1" => {
+            if profile {
+                texture::generate_texture_composite_profiled(spec, out_root_path)
+            } else {
+                texture::generate_texture_composite(spec, out_root_path).map(DispatchResult::new)
+            }
+        }
+
+        "texture.tile_variation_v1" => {
+            if profile {
+                texture::generate_texture_tile_variation_profiled(spec, out_root_path)
+            } else {
+                texture::generate_texture_tile_variation(spec, out_root_path)
+                    .map(DispatchResult::new)
+            }
+        }
+
         "sprite.sheet_v1" => {
             if profile {
                 sprite::generate_sprite_sheet_profiled(spec, out_root_path)
@@ -446,7 +493,10 @@ pub fn dispatch_generate_profiled(
         }
 
         _ => Err(DispatchError::BackendNotImplemented(kind.clone())),
-    }
+    }?;
+
+    record_file_checksums(&mut result.outputs, out_root_path)?;
+    Ok(result)
 }
 
 pub(crate) fn get_primary_output(spec: &Spec) -> Result<&speccade_spec::OutputSpec, DispatchError> {
@@ -492,6 +542,8 @@ pub fn is_backend_available(kind: &str) -> bool {
             | "texture.splat_set_v1"
             | "texture.matcap_v1"
             | "texture.material_preset_v1"
+            | "texture.composite_v1"
+            | "texture.tile_variation_v1"
             | "sprite.sheet_v1"
             | "sprite.animation_v1"
             | "vfx.flipbook_v1"
@@ -593,6 +645,8 @@ mod tests {
         assert!(is_backend_available("texture.procedural_v1"));
         assert!(is_backend_available("texture.trimsheet_v1"));
         assert!(is_backend_available("texture.material_preset_v1"));
+        assert!(is_backend_available("texture.composite_v1"));
+        assert!(is_backend_available("texture.tile_variation_v1"));
         assert!(is_backend_available("static_mesh.blender_primitives_v1"));
         assert!(is_backend_available("static_mesh.shrinkwrap_v1"));
         assert!(is_backend_available("static_mesh.boolean_kit_v1"));
@@ -645,6 +699,92 @@ mod tests {
         assert!(output_path.exists());
         let bytes = std::fs::read(&output_path).unwrap();
         assert!(!bytes.is_empty());
+
+        // The final file checksum is recorded for later integrity checks
+        assert_eq!(
+            outputs[0].file_hash.as_deref(),
+            Some(blake3::hash(&bytes).to_hex().as_str())
+        );
+        assert_eq!(outputs[0].size_bytes, Some(bytes.len() as u64));
+
+        let report = speccade_spec::ReportBuilder::new("spec".to_string(), "test".to_string())
+            .output(outputs[0].clone())
+            .ok(true)
+            .build();
+        assert!(speccade_spec::verify_outputs(&report, tmp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_texture_composite_generates_outputs() {
+        let tmp = tempfile::tempdir().unwrap();
+
+        let recipe = Recipe::new(
+            "texture.composite_v1",
+            serde_json::json!({
+                "resolution": [16, 16],
+                "tileable": true,
+                "layers": [
+                    {
+                        "nodes": [{ "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.08 } }],
+                        "output": "n"
+                    },
+                    {
+                        "nodes": [{ "id": "g", "type": "gradient", "direction": "horizontal" }],
+                        "output": "g",
+                        "blend_mode": "multiply",
+                        "opacity": 0.5
+                    }
+                ]
+            }),
+        );
+
+        let spec = Spec::builder("test-composite-01", AssetType::Texture)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(
+                OutputFormat::Png,
+                "textures/composite.png",
+            ))
+            .recipe(recipe)
+            .build();
+
+        let spec_path = tmp.path().join("test.spec.json");
+        let outputs =
+            dispatch_generate(&spec, tmp.path().to_str().unwrap(), &spec_path, None).unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].format, OutputFormat::Png);
+        assert!(tmp.path().join("textures/composite.png").exists());
+    }
+
+    #[test]
+    fn test_dispatch_texture_tile_variation_generates_outputs() {
+        let tmp = tempfile::tempdir().unwrap();
+
+        let recipe = Recipe::new(
+            "texture.tile_variation_v1",
+            serde_json::json!({
+                "tile_resolution": [16, 16],
+                "tileable": true,
+                "nodes": [{ "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.08 } }],
+                "output": "n",
+                "tiles": [3, 2],
+                "variation": { "rotate": true, "flip": true }
+            }),
+        );
+
+        let spec = Spec::builder("test-tile-variation-01", AssetType::Texture)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(OutputFormat::Png, "textures/sheet.png"))
+            .recipe(recipe)
+            .build();
+
+        let spec_path = tmp.path().join("test.spec.json");
+        let outputs =
+            dispatch_generate(&spec, tmp.path().to_str().unwrap(), &spec_path, None).unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].format, OutputFormat::Png);
+        assert!(tmp.path().join("textures/sheet.png").exists());
     }
 
     #[test]
@@ -773,4 +913,63 @@ mod tests {
         let png2 = std::fs::read(tmp2.path().join("atlas.png")).unwrap();
         assert_eq!(png1, png2, "PNG bytes should be identical");
     }
+
+    #[test]
+    fn test_dispatch_audio_ogg_primary_output() {
+        let recipe = Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.2,
+                "sample_rate": 22050,
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.01, "decay": 0.05, "sustain": 0.5, "release": 0.05 },
+                        "volume": 0.8,
+                        "pan": 0.0
+                    }
+                ]
+            }),
+        );
+        let build = |format, path: &str| {
+            Spec::builder("test-audio-ogg-01", AssetType::Audio)
+                .license("CC0-1.0")
+                .seed(42)
+                .output(OutputSpec::primary(format, path))
+                .recipe(recipe.clone())
+                .build()
+        };
+
+        let tmp = tempfile::tempdir().unwrap();
+        let out_root = tmp.path().to_str().unwrap();
+        let spec_path = tmp.path().join("test.spec.json");
+        let ogg_outputs = dispatch_generate(
+            &build(OutputFormat::Ogg, "sounds/beep.ogg"),
+            out_root,
+            &spec_path,
+            None,
+        )
+        .unwrap();
+        let wav_outputs = dispatch_generate(
+            &build(OutputFormat::Wav, "sounds/beep.wav"),
+            out_root,
+            &spec_path,
+            None,
+        )
+        .unwrap();
+
+        let ogg = &ogg_outputs[0];
+        assert_eq!(ogg.kind, OutputKind::Primary);
+        assert_eq!(ogg.format, OutputFormat::Ogg);
+        let bytes = std::fs::read(tmp.path().join("sounds/beep.ogg")).unwrap();
+        assert_eq!(&bytes[..4], b"OggS");
+        assert_eq!(
+            ogg.file_hash.as_deref(),
+            Some(blake3::hash(&bytes).to_hex().as_str())
+        );
+
+        // The Tier 1 hash covers the samples, so it matches the WAV rendering
+        assert!(ogg.hash.is_some());
+        assert_eq!(ogg.hash, wav_outputs[0].hash);
+    }
 }
@@ -1,7 +1,9 @@
 //! Music backend dispatch handler
 
 use super::{write_output_bytes, DispatchError, DispatchResult};
-use speccade_backend_music::{it::ItValidator, xm::XmValidator};
+use speccade_backend_music::{
+    it::ItValidator, protracker::validate_mod_bytes, s3m::validate_s3m_bytes, xm::XmValidator,
+};
 use speccade_spec::recipe::music::{MusicTrackerSongV1Params, TrackerFormat};
 use speccade_spec::{OutputFormat, OutputKind, OutputResult, Spec, StageTiming};
 use std::path::{Path, PathBuf};
@@ -49,6 +51,12 @@ fn validate_generated_module_bytes(format: OutputFormat, data: &[u8]) -> Result<
             }
             Ok(())
         }
+        OutputFormat::Mod => validate_mod_bytes(data).map_err(|e| {
+            DispatchError::BackendError(format!("Generated MOD failed validation: {}", e))
+        }),
+        OutputFormat::S3m => validate_s3m_bytes(data).map_err(|e| {
+            DispatchError::BackendError(format!("Generated S3M failed validation: {}", e))
+        }),
         other => Err(DispatchError::BackendError(format!(
             "Unsupported format for music validation: {}",
             other
@@ -56,6 +64,34 @@ fn validate_generated_module_bytes(format: OutputFormat, data: &[u8]) -> Result<
     }
 }
 
+/// Maps a primary output format to the tracker format that produces it.
+fn tracker_format_for_output(format: OutputFormat) -> Option<TrackerFormat> {
+    match format {
+        OutputFormat::Xm => Some(TrackerFormat::Xm),
+        OutputFormat::It => Some(TrackerFormat::It),
+        OutputFormat::Mod => Some(TrackerFormat::Mod),
+        OutputFormat::S3m => Some(TrackerFormat::S3m),
+        _ => None,
+    }
+}
+
+/// Export the song as a Standard MIDI File for a `mid` primary output.
+fn write_midi_output(
+    params: &MusicTrackerSongV1Params,
+    output: &speccade_spec::OutputSpec,
+    out_root: &Path,
+) -> Result<OutputResult, DispatchError> {
+    let (data, hash) = speccade_backend_music::midi::generate_midi(params)
+        .map_err(|e| DispatchError::BackendError(format!("MIDI export failed: {}", e)))?;
+    write_output_bytes(out_root, &output.path, &data)?;
+    Ok(OutputResult::tier1(
+        OutputKind::Primary,
+        OutputFormat::Mid,
+        PathBuf::from(&output.path),
+        hash,
+    ))
+}
+
 /// Generate music using the music backend
 pub(super) fn generate_music(
     spec: &Spec,
@@ -108,6 +144,8 @@ fn generate_music_from_params(
     let expected = match params.format {
         TrackerFormat::Xm => OutputFormat::Xm,
         TrackerFormat::It => OutputFormat::It,
+        TrackerFormat::Mod => OutputFormat::Mod,
+        TrackerFormat::S3m => OutputFormat::S3m,
     };
 
     // Single-output mode.
@@ -154,40 +192,29 @@ fn generate_music_from_params(
         return Ok(outputs);
     }
 
-    // Multi-output mode: one XM and/or one IT primary output.
-    let mut seen_xm = false;
-    let mut seen_it = false;
+    // Multi-output mode: at most one primary output per tracker format, plus MIDI.
+    let mut seen_formats = Vec::new();
     let mut results = Vec::new();
 
     for output in primary_outputs {
-        let format = match output.format {
-            OutputFormat::Xm => {
-                if seen_xm {
-                    return Err(DispatchError::BackendError(format!(
-                        "Duplicate primary output format 'xm' for {}",
-                        recipe_kind
-                    )));
-                }
-                seen_xm = true;
-                TrackerFormat::Xm
-            }
-            OutputFormat::It => {
-                if seen_it {
-                    return Err(DispatchError::BackendError(format!(
-                        "Duplicate primary output format 'it' for {}",
-                        recipe_kind
-                    )));
-                }
-                seen_it = true;
-                TrackerFormat::It
-            }
-            _ => {
-                return Err(DispatchError::BackendError(format!(
-                    "{} primary outputs must have format 'xm' or 'it', got '{}'",
-                    recipe_kind, output.format
-                )))
-            }
-        };
+        if output.format == OutputFormat::Mid {
+            results.push(write_midi_output(params, output, out_root)?);
+            continue;
+        }
+
+        let format = tracker_format_for_output(output.format).ok_or_else(|| {
+            DispatchError::BackendError(format!(
+                "{} primary outputs must have format 'xm', 'it', 'mod', 's3m' or 'mid', got '{}'",
+                recipe_kind, output.format
+            ))
+        })?;
+        if seen_formats.contains(&format) {
+            return Err(DispatchError::BackendError(format!(
+                "Duplicate primary output format '{}' for {}",
+                output.format, recipe_kind
+            )));
+        }
+        seen_formats.push(format);
 
         let mut per_output_params = params.clone();
         per_output_params.format = format;
@@ -201,6 +228,8 @@ fn generate_music_from_params(
         let actual_format = match gen.extension {
             "xm" => OutputFormat::Xm,
             "it" => OutputFormat::It,
+            "mod" => OutputFormat::Mod,
+            "s3m" => OutputFormat::S3m,
             _ => {
                 return Err(DispatchError::BackendError(format!(
                     "Unknown music format: {}",
@@ -339,6 +368,8 @@ fn generate_music_from_params_profiled(
     let expected = match params.format {
         TrackerFormat::Xm => OutputFormat::Xm,
         TrackerFormat::It => OutputFormat::It,
+        TrackerFormat::Mod => OutputFormat::Mod,
+        TrackerFormat::S3m => OutputFormat::S3m,
     };
 
     // Single-output mode
@@ -405,39 +436,34 @@ fn generate_music_from_params_profiled(
     }
 
     // Multi-output mode
-    let mut seen_xm = false;
-    let mut seen_it = false;
+    let mut seen_formats = Vec::new();
     let mut results = Vec::new();
 
     for output in primary_outputs {
-        let format = match output.format {
-            OutputFormat::Xm => {
-                if seen_xm {
-                    return Err(DispatchError::BackendError(format!(
-                        "Duplicate primary output format 'xm' for {}",
-                        recipe_kind
-                    )));
-                }
-                seen_xm = true;
-                TrackerFormat::Xm
-            }
-            OutputFormat::It => {
-                if seen_it {
-                    return Err(DispatchError::BackendError(format!(
-                        "Duplicate primary output format 'it' for {}",
-                        recipe_kind
-                    )));
-                }
-                seen_it = true;
-                TrackerFormat::It
-            }
-            _ => {
-                return Err(DispatchError::BackendError(format!(
-                    "{} primary outputs must have format 'xm' or 'it', got '{}'",
-                    recipe_kind, output.format
-                )))
-            }
-        };
+        if output.format == OutputFormat::Mid {
+            // Stage: export_midi
+            let export_start = Instant::now();
+            results.push(write_midi_output(params, output, out_root)?);
+            stages.push(StageTiming::new(
+                "export_midi",
+                export_start.elapsed().as_millis() as u64,
+            ));
+            continue;
+        }
+
+        let format = tracker_format_for_output(output.format).ok_or_else(|| {
+            DispatchError::BackendError(format!(
+                "{} primary outputs must have format 'xm', 'it', 'mod', 's3m' or 'mid', got '{}'",
+                recipe_kind, output.format
+            ))
+        })?;
+        if seen_formats.contains(&format) {
+            return Err(DispatchError::BackendError(format!(
+                "Duplicate primary output format '{}' for {}",
+                output.format, recipe_kind
+            )));
+        }
+        seen_formats.push(format);
 
         let mut per_output_params = params.clone();
         per_output_params.format = format;
@@ -456,6 +482,8 @@ fn generate_music_from_params_profiled(
         let actual_format = match gen.extension {
             "xm" => OutputFormat::Xm,
             "it" => OutputFormat::It,
+            "mod" => OutputFormat::Mod,
+            "s3m" => OutputFormat::S3m,
             _ => {
                 return Err(DispatchError::BackendError(format!(
                     "Unknown music format: {}",
@@ -4,10 +4,41 @@ use super::output_helpers::{
     get_metadata_outputs, get_primary_outputs, write_metadata_outputs, write_primary_png_outputs,
 };
 use super::{write_output_bytes, DispatchError, DispatchResult};
+use speccade_backend_texture::{DiskNodeCache, GraphValue};
+use speccade_spec::recipe::texture::TextureProceduralV1Params;
 use speccade_spec::{OutputFormat, OutputKind, OutputResult, Spec, StageTiming};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::time::Instant;
 
+/// Directory of the cross-process graph node cache, set by `generate --node-cache`.
+static NODE_CACHE_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Enables node-level caching of `texture.procedural_v1` graphs under `dir` for
+/// the rest of the process.
+pub fn enable_node_cache(dir: PathBuf) {
+    let _ = NODE_CACHE_DIR.set(dir);
+}
+
+/// Evaluates a procedural graph, reusing cached nodes when the node cache is enabled.
+fn eval_procedural_graph(
+    params: &TextureProceduralV1Params,
+    seed: u32,
+) -> Result<HashMap<String, GraphValue>, DispatchError> {
+    let nodes = match NODE_CACHE_DIR.get() {
+        Some(dir) => speccade_backend_texture::generate_graph_cached(
+            params,
+            seed,
+            &mut DiskNodeCache::new(dir),
+        ),
+        None => speccade_backend_texture::generate_graph(params, seed),
+    };
+    nodes.map_err(|e| {
+        DispatchError::BackendError(format!("Procedural texture generation failed: {}", e))
+    })
+}
+
 /// Generate trimsheet atlas outputs using the texture backend.
 pub(super) fn generate_texture_trimsheet(
     spec: &Spec,
@@ -93,9 +124,7 @@ pub(super) fn generate_texture_procedural(
         DispatchError::BackendError(format!("Invalid texture procedural params: {}", e))
     })?;
 
-    let nodes = speccade_backend_texture::generate_graph(&params, spec.seed).map_err(|e| {
-        DispatchError::BackendError(format!("Procedural texture generation failed: {}", e))
-    })?;
+    let nodes = eval_procedural_graph(&params, spec.seed)?;
 
     let primary_outputs: Vec<(usize, &speccade_spec::OutputSpec)> = spec
         .outputs
@@ -113,9 +142,9 @@ pub(super) fn generate_texture_procedural(
     let mut outputs = Vec::with_capacity(primary_outputs.len());
 
     for (output_index, output_spec) in primary_outputs {
-        if output_spec.format != OutputFormat::Png {
+        if !matches!(output_spec.format, OutputFormat::Png | OutputFormat::Exr) {
             return Err(DispatchError::BackendError(format!(
-                "texture.procedural_v1 primary outputs must have format 'png' (outputs[{}].format)",
+                "texture.procedural_v1 primary outputs must have format 'png' or 'exr' (outputs[{}].format)",
                 output_index
             )));
         }
@@ -134,14 +163,13 @@ pub(super) fn generate_texture_procedural(
             ))
         })?;
 
-        let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(value)
-            .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))?;
+        let (data, hash) = encode_graph_output(value, output_spec.format)?;
 
-        write_output_bytes(out_root, &output_spec.path, &png_data)?;
+        write_output_bytes(out_root, &output_spec.path, &data)?;
 
         outputs.push(OutputResult::tier1(
             output_spec.kind,
-            OutputFormat::Png,
+            output_spec.format,
             PathBuf::from(&output_spec.path),
             hash,
         ));
@@ -150,6 +178,20 @@ pub(super) fn generate_texture_procedural(
     Ok(outputs)
 }
 
+/// Encodes a graph node value as PNG or float EXR, returning `(bytes, hash)`.
+fn encode_graph_output(
+    value: &GraphValue,
+    format: OutputFormat,
+) -> Result<(Vec<u8>, String), DispatchError> {
+    if format == OutputFormat::Exr {
+        speccade_backend_texture::encode_graph_value_exr(value)
+            .map_err(|e| DispatchError::BackendError(format!("EXR encoding failed: {}", e)))
+    } else {
+        speccade_backend_texture::encode_graph_value_png(value)
+            .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))
+    }
+}
+
 /// Generate procedural texture outputs with profiling instrumentation.
 pub(super) fn generate_texture_procedural_profiled(
     spec: &Spec,
@@ -170,9 +212,7 @@ pub(super) fn generate_texture_procedural_profiled(
 
     // Stage: render_graph
     let render_start = Instant::now();
-    let nodes = speccade_backend_texture::generate_graph(&params, spec.seed).map_err(|e| {
-        DispatchError::BackendError(format!("Procedural texture generation failed: {}", e))
-    })?;
+    let nodes = eval_procedural_graph(&params, spec.seed)?;
     stages.push(StageTiming::new(
         "render_graph",
         render_start.elapsed().as_millis() as u64,
@@ -196,9 +236,9 @@ pub(super) fn generate_texture_procedural_profiled(
     let mut outputs = Vec::with_capacity(primary_outputs.len());
 
     for (output_index, output_spec) in primary_outputs {
-        if output_spec.format != OutputFormat::Png {
+        if !matches!(output_spec.format, OutputFormat::Png | OutputFormat::Exr) {
             return Err(DispatchError::BackendError(format!(
-                "texture.procedural_v1 primary outputs must have format 'png' (outputs[{}].format)",
+                "texture.procedural_v1 primary outputs must have format 'png' or 'exr' (outputs[{}].format)",
                 output_index
             )));
         }
@@ -217,14 +257,13 @@ pub(super) fn generate_texture_procedural_profiled(
             ))
         })?;
 
-        let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(value)
-            .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))?;
+        let (data, hash) = encode_graph_output(value, output_spec.format)?;
 
-        write_output_bytes(out_root, &output_spec.path, &png_data)?;
+        write_output_bytes(out_root, &output_spec.path, &data)?;
 
         outputs.push(OutputResult::tier1(
             output_spec.kind,
-            OutputFormat::Png,
+            output_spec.format,
             PathBuf::from(&output_spec.path),
             hash,
         ));
@@ -237,6 +276,142 @@ pub(super) fn generate_texture_procedural_profiled(
     Ok(DispatchResult::with_stages(outputs, stages))
 }
 
+/// Generate layered composite texture outputs using the texture backend.
+pub(super) fn generate_texture_composite(
+    spec: &Spec,
+    out_root: &Path,
+) -> Result<Vec<OutputResult>, DispatchError> {
+    let recipe = spec.recipe.as_ref().ok_or(DispatchError::NoRecipe)?;
+    let params = recipe.as_texture_composite().map_err(|e| {
+        DispatchError::BackendError(format!("Invalid texture composite params: {}", e))
+    })?;
+
+    let value = speccade_backend_texture::generate_composite(&params, spec.seed).map_err(|e| {
+        DispatchError::BackendError(format!("Composite texture generation failed: {}", e))
+    })?;
+
+    let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(&value)
+        .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))?;
+
+    let primary_outputs = get_primary_outputs(spec, OutputFormat::Png, "texture.composite_v1")?;
+
+    write_primary_png_outputs(out_root, &primary_outputs, &png_data, &hash)
+}
+
+/// Generate layered composite texture outputs with profiling instrumentation.
+pub(super) fn generate_texture_composite_profiled(
+    spec: &Spec,
+    out_root: &Path,
+) -> Result<DispatchResult, DispatchError> {
+    let mut stages = Vec::new();
+
+    // Stage: parse_params
+    let parse_start = Instant::now();
+    let recipe = spec.recipe.as_ref().ok_or(DispatchError::NoRecipe)?;
+    let params = recipe.as_texture_composite().map_err(|e| {
+        DispatchError::BackendError(format!("Invalid texture composite params: {}", e))
+    })?;
+    stages.push(StageTiming::new(
+        "parse_params",
+        parse_start.elapsed().as_millis() as u64,
+    ));
+
+    // Stage: render_layers
+    let render_start = Instant::now();
+    let value = speccade_backend_texture::generate_composite(&params, spec.seed).map_err(|e| {
+        DispatchError::BackendError(format!("Composite texture generation failed: {}", e))
+    })?;
+    stages.push(StageTiming::new(
+        "render_layers",
+        render_start.elapsed().as_millis() as u64,
+    ));
+
+    // Stage: encode_outputs
+    let encode_start = Instant::now();
+    let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(&value)
+        .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))?;
+
+    let primary_outputs = get_primary_outputs(spec, OutputFormat::Png, "texture.composite_v1")?;
+    let outputs = write_primary_png_outputs(out_root, &primary_outputs, &png_data, &hash)?;
+
+    stages.push(StageTiming::new(
+        "encode_outputs",
+        encode_start.elapsed().as_millis() as u64,
+    ));
+
+    Ok(DispatchResult::with_stages(outputs, stages))
+}
+
+/// Generate tiled sheet texture outputs using the texture backend.
+pub(super) fn generate_texture_tile_variation(
+    spec: &Spec,
+    out_root: &Path,
+) -> Result<Vec<OutputResult>, DispatchError> {
+    let recipe = spec.recipe.as_ref().ok_or(DispatchError::NoRecipe)?;
+    let params = recipe.as_texture_tile_variation().map_err(|e| {
+        DispatchError::BackendError(format!("Invalid texture tile variation params: {}", e))
+    })?;
+
+    let value =
+        speccade_backend_texture::generate_tile_variation(&params, spec.seed).map_err(|e| {
+            DispatchError::BackendError(format!("Tile variation generation failed: {}", e))
+        })?;
+
+    let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(&value)
+        .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))?;
+
+    let primary_outputs =
+        get_primary_outputs(spec, OutputFormat::Png, "texture.tile_variation_v1")?;
+
+    write_primary_png_outputs(out_root, &primary_outputs, &png_data, &hash)
+}
+
+/// Generate tiled sheet texture outputs with profiling instrumentation.
+pub(super) fn generate_texture_tile_variation_profiled(
+    spec: &Spec,
+    out_root: &Path,
+) -> Result<DispatchResult, DispatchError> {
+    let mut stages = Vec::new();
+
+    // Stage: parse_params
+    let parse_start = Instant::now();
+    let recipe = spec.recipe.as_ref().ok_or(DispatchError::NoRecipe)?;
+    let params = recipe.as_texture_tile_variation().map_err(|e| {
+        DispatchError::BackendError(format!("Invalid texture tile variation params: {}", e))
+    })?;
+    stages.push(StageTiming::new(
+        "parse_params",
+        parse_start.elapsed().as_millis() as u64,
+    ));
+
+    // Stage: render_sheet
+    let render_start = Instant::now();
+    let value =
+        speccade_backend_texture::generate_tile_variation(&params, spec.seed).map_err(|e| {
+            DispatchError::BackendError(format!("Tile variation generation failed: {}", e))
+        })?;
+    stages.push(StageTiming::new(
+        "render_sheet",
+        render_start.elapsed().as_millis() as u64,
+    ));
+
+    // Stage: encode_outputs
+    let encode_start = Instant::now();
+    let (png_data, hash) = speccade_backend_texture::encode_graph_value_png(&value)
+        .map_err(|e| DispatchError::BackendError(format!("PNG encoding failed: {}", e)))?;
+
+    let primary_outputs =
+        get_primary_outputs(spec, OutputFormat::Png, "texture.tile_variation_v1")?;
+    let outputs = write_primary_png_outputs(out_root, &primary_outputs, &png_data, &hash)?;
+
+    stages.push(StageTiming::new(
+        "encode_outputs",
+        encode_start.elapsed().as_millis() as u64,
+    ));
+
+    Ok(DispatchResult::with_stages(outputs, stages))
+}
+
 /// Generate decal texture outputs using the texture backend.
 ///
 /// Decals output:
@@ -180,6 +180,20 @@ impl std::error::Error for InputError {
 /// println!("Loaded {} spec", result.source_kind.as_str());
 /// ```
 pub fn load_spec(path: &Path) -> Result<LoadResult, InputError> {
+    load_spec_inner(path, false)
+}
+
+/// Load a spec for the `eval` command.
+///
+/// Same as [`load_spec`], except that a Starlark spec without a `seed` gets one
+/// derived from its `asset_id`. The derivation is recorded in `migration_notes`
+/// and reported as a warning.
+pub fn load_spec_for_eval(path: &Path) -> Result<LoadResult, InputError> {
+    load_spec_inner(path, true)
+}
+
+#[cfg_attr(not(feature = "starlark"), allow(unused_variables))]
+fn load_spec_inner(path: &Path, derive_missing_seed: bool) -> Result<LoadResult, InputError> {
     let extension = path
         .extension()
         .and_then(|e| e.to_str())
@@ -188,7 +202,9 @@ pub fn load_spec(path: &Path) -> Result<LoadResult, InputError> {
     match extension.as_deref() {
         Some(ext) if JSON_EXTENSIONS.contains(&ext) => load_json_spec(path),
         #[cfg(feature = "starlark")]
-        Some(ext) if STARLARK_EXTENSIONS.contains(&ext) => load_starlark_spec(path),
+        Some(ext) if STARLARK_EXTENSIONS.contains(&ext) => {
+            load_starlark_spec(path, derive_missing_seed)
+        }
         #[cfg(not(feature = "starlark"))]
         Some(ext) if STARLARK_EXTENSIONS.contains(&ext) => Err(InputError::StarlarkNotEnabled),
         _ => Err(InputError::UnknownExtension { extension }),
@@ -221,7 +237,7 @@ fn load_json_spec(path: &Path) -> Result<LoadResult, InputError> {
 
 /// Load a spec from a Starlark file.
 #[cfg(feature = "starlark")]
-fn load_starlark_spec(path: &Path) -> Result<LoadResult, InputError> {
+fn load_starlark_spec(path: &Path, derive_missing_seed: bool) -> Result<LoadResult, InputError> {
     // Read file content
     let content = std::fs::read_to_string(path).map_err(|e| InputError::FileRead {
         path: path.to_path_buf(),
@@ -238,7 +254,10 @@ fn load_starlark_spec(path: &Path) -> Result<LoadResult, InputError> {
         .unwrap_or("unknown.star");
 
     // Compile Starlark to Spec
-    let config = compiler::CompilerConfig::default();
+    let config = compiler::CompilerConfig {
+        derive_missing_seed,
+        ..compiler::CompilerConfig::default()
+    };
     let compile_result = compiler::compile(filename, &content, &config).map_err(|e| match e {
         compiler::CompileError::Timeout { seconds } => InputError::Timeout { seconds },
         other => InputError::StarlarkCompile {
@@ -12,7 +12,9 @@ use std::process::ExitCode;
 use cli_args::*;
 
 // Use modules from the library crate
+use speccade_cli::analysis::embeddings::{EmbeddingNorm, EmbeddingOptions};
 use speccade_cli::commands;
+use speccade_cli::commands::analyze::AnalyzeOptions;
 
 fn main() -> ExitCode {
     let cli = Cli::parse();
@@ -27,6 +29,8 @@ fn main() -> ExitCode {
             json,
             output_format,
             embeddings,
+            embeddings_dim,
+            embeddings_norm,
             serve,
         } => {
             // If --serve flag is provided, start the WebSocket server
@@ -34,15 +38,19 @@ fn main() -> ExitCode {
                 let port = port_opt.unwrap_or(commands::serve::DEFAULT_PORT);
                 commands::serve::run(port)
             } else {
-                commands::analyze::run(
-                    input.as_deref(),
-                    spec.as_deref(),
-                    input_dir.as_deref(),
-                    output.as_deref(),
-                    json,
-                    &output_format,
-                    embeddings,
-                )
+                commands::analyze::run(&AnalyzeOptions {
+                    input_path: input.as_deref(),
+                    spec_path: spec.as_deref(),
+                    input_dir: input_dir.as_deref(),
+                    output_path: output.as_deref(),
+                    json_output: json,
+                    output_format: &output_format,
+                    include_embeddings: embeddings,
+                    embedding_options: EmbeddingOptions {
+                        dim: embeddings_dim,
+                        norm: EmbeddingNorm::parse(&embeddings_norm).unwrap_or_default(),
+                    },
+                })
             }
         }
         #[cfg(not(feature = "serve"))]
@@ -54,16 +62,55 @@ fn main() -> ExitCode {
             json,
             output_format,
             embeddings,
-        } => commands::analyze::run(
-            input.as_deref(),
-            spec.as_deref(),
-            input_dir.as_deref(),
-            output.as_deref(),
+            embeddings_dim,
+            embeddings_norm,
+        } => commands::analyze::run(&AnalyzeOptions {
+            input_path: input.as_deref(),
+            spec_path: spec.as_deref(),
+            input_dir: input_dir.as_deref(),
+            output_path: output.as_deref(),
+            json_output: json,
+            output_format: &output_format,
+            include_embeddings: embeddings,
+            embedding_options: EmbeddingOptions {
+                dim: embeddings_dim,
+                norm: EmbeddingNorm::parse(&embeddings_norm).unwrap_or_default(),
+            },
+        }),
+        Commands::Compare {
+            a,
+            b,
+            dir_a,
+            dir_b,
+            max_ssim_delta,
+            max_delta_e,
+            max_residual_db,
+            max_rms_delta_db,
             json,
-            &output_format,
-            embeddings,
-        ),
-        Commands::Compare { a, b, json } => commands::compare::run(&a, &b, json),
+        } => {
+            let thresholds = commands::compare::CompareThresholds {
+                max_ssim_delta,
+                max_delta_e,
+                max_residual_db,
+                max_rms_delta_db,
+            };
+            match (a, b, dir_a, dir_b) {
+                (None, None, Some(dir_a), Some(dir_b)) => {
+                    commands::compare_dir::run(&dir_a, &dir_b, &thresholds, json)
+                }
+                (Some(a), Some(b), None, None) => commands::compare::run(&a, &b, &thresholds, json),
+                _ => Err(anyhow::anyhow!(
+                    "Either --a and --b, or --dir-a and --dir-b, are required (not both)"
+                )),
+            }
+        }
+        Commands::Diff { a, b, json } => commands::diff::run(&a, &b, json),
+        Commands::Similar {
+            query,
+            input_dir,
+            top_k,
+            json,
+        } => commands::similar::run(&query, &input_dir, top_k, json),
         Commands::Audit {
             input_dir,
             tolerances,
@@ -76,7 +123,8 @@ fn main() -> ExitCode {
             artifacts,
             budget,
             json,
-        } => commands::validate::run(&spec, artifacts, budget.as_deref(), json),
+            fix,
+        } => commands::validate::run(&spec, artifacts, budget.as_deref(), json, fix),
         Commands::Generate {
             spec,
             out_root,
@@ -87,35 +135,54 @@ fn main() -> ExitCode {
             no_cache,
             profile,
             variations,
+            variation_seeds,
             max_peak_db,
             max_dc_offset,
             save_blend,
-        } => commands::generate::run(
-            &spec,
-            out_root.as_deref(),
-            expand_variants,
-            budget.as_deref(),
-            json,
-            preview,
-            no_cache,
-            profile,
-            variations,
-            max_peak_db,
-            max_dc_offset,
-            save_blend,
-        ),
+            bundle,
+            node_cache,
+            lint,
+            lint_strict,
+            seed,
+        } => commands::generate::VariationSeedMode::parse(&variation_seeds)
+            .ok_or_else(|| anyhow::anyhow!("Unknown variation seed mode: {}", variation_seeds))
+            .and_then(|seed_mode| {
+                if node_cache {
+                    commands::generate::enable_node_cache()?;
+                }
+                commands::generate::run(
+                    &spec,
+                    out_root.as_deref(),
+                    expand_variants,
+                    budget.as_deref(),
+                    json,
+                    preview,
+                    no_cache,
+                    profile,
+                    variations,
+                    seed_mode,
+                    max_peak_db,
+                    max_dc_offset,
+                    save_blend,
+                    bundle.as_deref(),
+                    commands::generate::LintGate::from_flags(lint, lint_strict),
+                    seed,
+                )
+            }),
         Commands::GenerateAll {
             spec_dir,
             out_root,
             include_blender,
             verbose,
             force,
+            jobs,
         } => commands::generate_all::run(
             spec_dir.as_deref(),
             out_root.as_deref(),
             include_blender,
             verbose,
             force,
+            jobs,
         ),
         Commands::Pipeline {
             profile,
@@ -192,6 +259,7 @@ fn main() -> ExitCode {
                 commands::stdlib::run_dump(dump_format)
             }
         },
+        Commands::Schema { kind, out } => commands::schema::run(&kind, out.as_deref()),
         Commands::Cache { command } => match command {
             CacheCommands::Clear => commands::cache::clear(),
             CacheCommands::Info => commands::cache::info(),
@@ -212,8 +280,9 @@ fn main() -> ExitCode {
         Commands::Verify {
             report,
             constraints,
+            asset,
             json,
-        } => commands::verify::run(&report, &constraints, json),
+        } => commands::verify::run(&report, &constraints, asset.as_deref(), json),
         Commands::Lint {
             input,
             spec,
@@ -271,9 +340,9 @@ mod tests {
         ])
         .unwrap();
         match cli.command {
-            Commands::Compare { a, b, json } => {
-                assert_eq!(a, "file1.wav");
-                assert_eq!(b, "file2.wav");
+            Commands::Compare { a, b, json, .. } => {
+                assert_eq!(a.as_deref(), Some("file1.wav"));
+                assert_eq!(b.as_deref(), Some("file2.wav"));
                 assert!(!json);
             }
             _ => panic!("expected compare command"),
@@ -293,15 +362,96 @@ mod tests {
         ])
         .unwrap();
         match cli.command {
-            Commands::Compare { a, b, json } => {
-                assert_eq!(a, "file1.png");
-                assert_eq!(b, "file2.png");
+            Commands::Compare { a, b, json, .. } => {
+                assert_eq!(a.as_deref(), Some("file1.png"));
+                assert_eq!(b.as_deref(), Some("file2.png"));
                 assert!(json);
             }
             _ => panic!("expected compare command"),
         }
     }
 
+    #[test]
+    fn test_cli_parses_diff() {
+        let cli = Cli::try_parse_from([
+            "speccade", "diff", "--a", "old.json", "--b", "new.json", "--json",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Diff { a, b, json } => {
+                assert_eq!(a, "old.json");
+                assert_eq!(b, "new.json");
+                assert!(json);
+            }
+            _ => panic!("expected diff command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_similar() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "similar",
+            "--query",
+            "kick.wav",
+            "--input-dir",
+            "out",
+            "--top-k",
+            "3",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Similar {
+                query,
+                input_dir,
+                top_k,
+                json,
+            } => {
+                assert_eq!(query, "kick.wav");
+                assert_eq!(input_dir, "out");
+                assert_eq!(top_k, 3);
+                assert!(!json);
+            }
+            _ => panic!("expected similar command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_compare_dirs() {
+        let cli = Cli::try_parse_from([
+            "speccade", "compare", "--dir-a", "out_a", "--dir-b", "out_b",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Compare {
+                a, b, dir_a, dir_b, ..
+            } => {
+                assert!(a.is_none() && b.is_none());
+                assert_eq!(dir_a.as_deref(), Some("out_a"));
+                assert_eq!(dir_b.as_deref(), Some("out_b"));
+            }
+            _ => panic!("expected compare command"),
+        }
+
+        let err = Cli::try_parse_from(["speccade", "compare", "--dir-a", "out_a"])
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("--dir-b"));
+
+        for mixed in [
+            [
+                "--a", "x.png", "--b", "y.png", "--dir-a", "out_a", "--dir-b", "out_b",
+            ],
+            [
+                "--dir-a", "out_a", "--dir-b", "out_b", "--a", "x.png", "--b", "y.png",
+            ],
+        ] {
+            let args = ["speccade", "compare"].into_iter().chain(mixed);
+            let err = Cli::try_parse_from(args).err().unwrap();
+            assert!(err.to_string().contains("cannot be used with"), "{}", err);
+        }
+    }
+
     #[test]
     fn test_cli_requires_a_and_b_for_compare() {
         let err = Cli::try_parse_from(["speccade", "compare", "--a", "file.wav"])
@@ -365,11 +515,13 @@ mod tests {
                 artifacts,
                 budget,
                 json,
+                fix,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(!artifacts);
                 assert!(budget.is_none());
                 assert!(!json);
+                assert!(!fix);
             }
             _ => panic!("expected validate command"),
         }
@@ -386,11 +538,13 @@ mod tests {
                 artifacts,
                 budget,
                 json,
+                fix,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(artifacts);
                 assert!(budget.is_none());
                 assert!(!json);
+                assert!(!fix);
             }
             _ => panic!("expected validate command"),
         }
@@ -413,11 +567,13 @@ mod tests {
                 artifacts,
                 budget,
                 json,
+                fix,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(!artifacts);
                 assert_eq!(budget.as_deref(), Some("strict"));
                 assert!(!json);
+                assert!(!fix);
             }
             _ => panic!("expected validate command"),
         }
@@ -433,11 +589,26 @@ mod tests {
                 artifacts,
                 budget,
                 json,
+                fix,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(!artifacts);
                 assert!(budget.is_none());
                 assert!(json);
+                assert!(!fix);
+            }
+            _ => panic!("expected validate command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_validate_with_fix() {
+        let cli =
+            Cli::try_parse_from(["speccade", "validate", "--spec", "spec.json", "--fix"]).unwrap();
+        match cli.command {
+            Commands::Validate { spec, fix, .. } => {
+                assert_eq!(spec, "spec.json");
+                assert!(fix);
             }
             _ => panic!("expected validate command"),
         }
@@ -465,9 +636,15 @@ mod tests {
                 no_cache,
                 profile,
                 variations,
+                variation_seeds,
                 max_peak_db,
                 max_dc_offset,
                 save_blend: _,
+                bundle,
+                node_cache,
+                lint,
+                lint_strict,
+                seed,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert_eq!(out_root.as_deref(), Some("out"));
@@ -478,8 +655,14 @@ mod tests {
                 assert!(!no_cache);
                 assert!(!profile);
                 assert!(variations.is_none());
+                assert_eq!(variation_seeds, "increment");
                 assert!(max_peak_db.is_none());
                 assert!(max_dc_offset.is_none());
+                assert!(bundle.is_none());
+                assert!(!node_cache);
+                assert!(!lint);
+                assert!(!lint_strict);
+                assert!(seed.is_none());
             }
             _ => panic!("expected generate command"),
         }
@@ -507,9 +690,15 @@ mod tests {
                 no_cache,
                 profile,
                 variations,
+                variation_seeds,
                 max_peak_db,
                 max_dc_offset,
                 save_blend: _,
+                bundle: _,
+                node_cache: _,
+                lint: _,
+                lint_strict: _,
+                seed: _,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(out_root.is_none());
@@ -520,6 +709,7 @@ mod tests {
                 assert!(!no_cache);
                 assert!(!profile);
                 assert!(variations.is_none());
+                assert_eq!(variation_seeds, "increment");
                 assert!(max_peak_db.is_none());
                 assert!(max_dc_offset.is_none());
             }
@@ -542,9 +732,15 @@ mod tests {
                 no_cache,
                 profile,
                 variations,
+                variation_seeds,
                 max_peak_db,
                 max_dc_offset,
                 save_blend: _,
+                bundle: _,
+                node_cache: _,
+                lint: _,
+                lint_strict: _,
+                seed: _,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(out_root.is_none());
@@ -555,6 +751,7 @@ mod tests {
                 assert!(!no_cache);
                 assert!(!profile);
                 assert!(variations.is_none());
+                assert_eq!(variation_seeds, "increment");
                 assert!(max_peak_db.is_none());
                 assert!(max_dc_offset.is_none());
             }
@@ -577,9 +774,15 @@ mod tests {
                 no_cache,
                 profile,
                 variations,
+                variation_seeds,
                 max_peak_db,
                 max_dc_offset,
                 save_blend: _,
+                bundle: _,
+                node_cache: _,
+                lint: _,
+                lint_strict: _,
+                seed: _,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(out_root.is_none());
@@ -590,6 +793,7 @@ mod tests {
                 assert!(!no_cache);
                 assert!(profile);
                 assert!(variations.is_none());
+                assert_eq!(variation_seeds, "increment");
                 assert!(max_peak_db.is_none());
                 assert!(max_dc_offset.is_none());
             }
@@ -606,6 +810,8 @@ mod tests {
             "spec.json",
             "--variations",
             "5",
+            "--variation-seeds",
+            "hashed",
         ])
         .unwrap();
         match cli.command {
@@ -619,9 +825,15 @@ mod tests {
                 no_cache,
                 profile,
                 variations,
+                variation_seeds,
                 max_peak_db,
                 max_dc_offset,
                 save_blend: _,
+                bundle: _,
+                node_cache: _,
+                lint: _,
+                lint_strict: _,
+                seed: _,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(out_root.is_none());
@@ -632,6 +844,7 @@ mod tests {
                 assert!(!no_cache);
                 assert!(!profile);
                 assert_eq!(variations, Some(5));
+                assert_eq!(variation_seeds, "hashed");
                 assert!(max_peak_db.is_none());
                 assert!(max_dc_offset.is_none());
             }
@@ -665,9 +878,15 @@ mod tests {
                 no_cache,
                 profile,
                 variations,
+                variation_seeds,
                 max_peak_db,
                 max_dc_offset,
                 save_blend: _,
+                bundle: _,
+                node_cache: _,
+                lint: _,
+                lint_strict: _,
+                seed: _,
             } => {
                 assert_eq!(spec, "spec.json");
                 assert!(out_root.is_none());
@@ -678,6 +897,7 @@ mod tests {
                 assert!(!no_cache);
                 assert!(!profile);
                 assert_eq!(variations, Some(10));
+                assert_eq!(variation_seeds, "increment");
                 assert!((max_peak_db.unwrap() - (-3.0)).abs() < 0.001);
                 assert!((max_dc_offset.unwrap() - 0.01).abs() < 0.0001);
             }
@@ -685,6 +905,79 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_generate_with_bundle() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "generate",
+            "--spec",
+            "spec.json",
+            "--bundle",
+            "out/asset.zip",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Generate { bundle, .. } => {
+                assert_eq!(bundle.as_deref(), Some("out/asset.zip"));
+            }
+            _ => panic!("expected generate command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_generate_with_node_cache() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "generate",
+            "--spec",
+            "spec.json",
+            "--node-cache",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Generate { node_cache, .. } => assert!(node_cache),
+            _ => panic!("expected generate command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_generate_with_lint_strict() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "generate",
+            "--spec",
+            "spec.json",
+            "--lint-strict",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Generate {
+                lint, lint_strict, ..
+            } => {
+                assert!(!lint);
+                assert!(lint_strict);
+            }
+            _ => panic!("expected generate command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_generate_with_seed() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "generate",
+            "--spec",
+            "spec.json",
+            "--seed",
+            "1234",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Generate { seed, .. } => assert_eq!(seed, Some(1234)),
+            _ => panic!("expected generate command"),
+        }
+    }
+
     #[test]
     fn test_cli_parses_expand() {
         let cli = Cli::try_parse_from(["speccade", "expand", "--spec", "spec.json"]).unwrap();
@@ -795,12 +1088,14 @@ mod tests {
                 include_blender,
                 verbose,
                 force,
+                jobs,
             } => {
                 assert!(spec_dir.is_none());
                 assert!(out_root.is_none());
                 assert!(!include_blender);
                 assert!(!verbose);
                 assert!(!force);
+                assert_eq!(jobs, 1);
             }
             _ => panic!("expected generate-all command"),
         }
@@ -817,6 +1112,8 @@ mod tests {
             "/path/to/output",
             "--include-blender",
             "--verbose",
+            "--jobs",
+            "8",
         ])
         .unwrap();
         match cli.command {
@@ -826,12 +1123,14 @@ mod tests {
                 include_blender,
                 verbose,
                 force,
+                jobs,
             } => {
                 assert_eq!(spec_dir.as_deref(), Some("/path/to/specs"));
                 assert_eq!(out_root.as_deref(), Some("/path/to/output"));
                 assert!(include_blender);
                 assert!(verbose);
                 assert!(!force);
+                assert_eq!(jobs, 8);
             }
             _ => panic!("expected generate-all command"),
         }
@@ -847,6 +1146,7 @@ mod tests {
                 include_blender,
                 verbose,
                 force,
+                ..
             } => {
                 assert!(spec_dir.is_none());
                 assert!(out_root.is_none());
@@ -1098,6 +1398,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_schema() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "schema",
+            "--kind",
+            "texture.material_v1",
+            "--out",
+            "material.schema.json",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Schema { kind, out } => {
+                assert_eq!(kind, "texture.material_v1");
+                assert_eq!(out.as_deref(), Some("material.schema.json"));
+            }
+            _ => panic!("expected schema command"),
+        }
+    }
+
     #[test]
     fn test_cli_parses_analyze_with_input() {
         let cli = Cli::try_parse_from(["speccade", "analyze", "--input", "sound.wav"]).unwrap();
@@ -1253,6 +1573,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_analyze_embedding_options() {
+        let cli = Cli::try_parse_from([
+            "speccade",
+            "analyze",
+            "--input",
+            "sound.wav",
+            "--embeddings",
+            "--embeddings-dim",
+            "64",
+            "--embeddings-norm",
+            "l2",
+        ])
+        .unwrap();
+        match cli.command {
+            Commands::Analyze {
+                embeddings,
+                embeddings_dim,
+                embeddings_norm,
+                ..
+            } => {
+                assert!(embeddings);
+                assert_eq!(embeddings_dim, Some(64));
+                assert_eq!(embeddings_norm, "l2");
+            }
+            _ => panic!("expected analyze command"),
+        }
+
+        // Embedding options require --embeddings
+        assert!(Cli::try_parse_from([
+            "speccade",
+            "analyze",
+            "--input",
+            "sound.wav",
+            "--embeddings-dim",
+            "64",
+        ])
+        .is_err());
+    }
+
     #[test]
     fn test_cli_parses_analyze_with_csv_format() {
         let cli = Cli::try_parse_from([
@@ -1495,10 +1855,12 @@ mod tests {
             Commands::Verify {
                 report,
                 constraints,
+                asset,
                 json,
             } => {
                 assert_eq!(report, "test.report.json");
                 assert_eq!(constraints, "test.constraints.json");
+                assert!(asset.is_none());
                 assert!(!json);
             }
             _ => panic!("expected verify command"),
@@ -1522,6 +1884,7 @@ mod tests {
                 report,
                 constraints,
                 json,
+                ..
             } => {
                 assert_eq!(report, "test.report.json");
                 assert_eq!(constraints, "test.constraints.json");
@@ -123,6 +123,11 @@ pub fn eval_spec(source: String, filename: String) -> EvalOutput {
                 CompileError::InvalidSpec { message } => {
                     (e.code().to_string(), message.clone(), None)
                 }
+                CompileError::BudgetExceeded { location, message } => (
+                    e.code().to_string(),
+                    message.clone(),
+                    Some(location.clone()),
+                ),
                 CompileError::StdlibArgument { function, param } => (
                     e.code().to_string(),
                     format!("{}(): missing required argument '{}'", function, param),
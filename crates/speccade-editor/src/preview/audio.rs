@@ -4,7 +4,8 @@
 
 use super::{PreviewResult, PreviewSettings};
 use crate::commands::lint::lint_asset_bytes;
-use speccade_spec::Spec;
+use speccade_spec::{OutputFormat, Spec};
+use std::path::Path;
 
 /// Generate an audio preview from a spec.
 ///
@@ -41,8 +42,20 @@ pub fn generate_audio_preview(spec: &Spec, settings: &PreviewSettings) -> Previe
 
     let preview_duration = Some(settings.audio_max_duration);
 
+    // Previews are played and linted as WAV, even for specs that write OGG
+    let mut spec = spec.clone();
+    for output in &mut spec.outputs {
+        if output.format == OutputFormat::Ogg {
+            output.format = OutputFormat::Wav;
+            output.path = Path::new(&output.path)
+                .with_extension("wav")
+                .to_string_lossy()
+                .into_owned();
+        }
+    }
+
     match dispatch_generate(
-        spec,
+        &spec,
         tmp_path.to_str().unwrap(),
         &spec_path,
         preview_duration,
@@ -51,7 +64,7 @@ pub fn generate_audio_preview(spec: &Spec, settings: &PreviewSettings) -> Previe
             // Find the primary WAV output
             let wav_output = outputs
                 .iter()
-                .find(|o| matches!(o.format, speccade_spec::OutputFormat::Wav));
+                .find(|o| matches!(o.format, OutputFormat::Wav));
 
             match wav_output {
                 Some(output) => {
@@ -60,7 +73,7 @@ pub fn generate_audio_preview(spec: &Spec, settings: &PreviewSettings) -> Previe
                     match std::fs::read(&wav_path) {
                         Ok(wav_bytes) => {
                             // Run lint on the generated audio
-                            let lint_result = lint_asset_bytes(&wav_path, &wav_bytes, Some(spec));
+                            let lint_result = lint_asset_bytes(&wav_path, &wav_bytes, Some(&spec));
 
                             // Include sample count and duration in metadata
                             let metadata = serde_json::json!({
@@ -91,7 +104,7 @@ pub fn generate_audio_preview(spec: &Spec, settings: &PreviewSettings) -> Previe
 #[cfg(test)]
 mod tests {
     use super::*;
-    use speccade_spec::{AssetType, OutputFormat, OutputSpec, Recipe};
+    use speccade_spec::{AssetType, OutputSpec, Recipe};
 
     #[test]
     fn test_audio_preview_no_recipe() {
@@ -126,4 +139,34 @@ mod tests {
         assert!(result.error.is_some());
         assert!(result.error.unwrap().contains("not an audio recipe"));
     }
+
+    #[test]
+    fn test_audio_preview_ogg_output_previews_as_wav() {
+        let recipe = Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.01, "decay": 0.02, "sustain": 0.5, "release": 0.02 },
+                        "volume": 0.8,
+                        "pan": 0.0
+                    }
+                ]
+            }),
+        );
+        let spec = Spec::builder("test-audio", AssetType::Audio)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(OutputFormat::Ogg, "sounds/test.ogg"))
+            .recipe(recipe)
+            .build();
+
+        let settings = PreviewSettings::default();
+        let result = generate_audio_preview(&spec, &settings);
+
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.mime_type.as_deref(), Some("audio/wav"));
+    }
 }
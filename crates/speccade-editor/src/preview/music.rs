@@ -44,19 +44,23 @@ pub fn generate_music_preview(spec: &Spec, spec_path: &std::path::Path) -> Previ
         Err(e) => return PreviewResult::failure("music", format!("Generation failed: {}", e)),
     };
 
-    let module_output = outputs.iter().find(|o| {
-        o.kind == OutputKind::Primary && matches!(o.format, OutputFormat::Xm | OutputFormat::It)
-    });
+    let module_output = outputs
+        .iter()
+        .find(|o| o.kind == OutputKind::Primary && o.format.is_music());
 
     let module_output = match module_output {
         Some(o) => o,
-        None => return PreviewResult::failure("music", "No XM/IT primary output generated"),
+        None => {
+            return PreviewResult::failure("music", "No tracker module primary output generated")
+        }
     };
 
     let (format_str, mime) = match module_output.format {
         OutputFormat::Xm => ("xm", "audio/x-xm"),
         OutputFormat::It => ("it", "audio/x-it"),
-        _ => return PreviewResult::failure("music", "No XM/IT primary output generated"),
+        OutputFormat::Mod => ("mod", "audio/x-mod"),
+        OutputFormat::S3m => ("s3m", "audio/x-s3m"),
+        _ => return PreviewResult::failure("music", "No tracker module primary output generated"),
     };
 
     let module_path = tmp_path.join(&module_output.path);
@@ -4,6 +4,7 @@
 
 use crate::report::{AssetType, LintIssue, Severity};
 use crate::rules::{AssetData, LintRule};
+use speccade_backend_texture::{verify_tileable, Color, TextureBuffer, SEAM_SCORE_THRESHOLD};
 use speccade_spec::Spec;
 
 /// Returns all texture lint rules.
@@ -97,6 +98,18 @@ impl ImageData {
         }
     }
 
+    /// Converts the image to a float RGBA buffer for backend analysis.
+    fn to_texture_buffer(&self) -> TextureBuffer {
+        let mut buffer = TextureBuffer::new_black(self.width, self.height);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let (r, g, b) = self.rgb_at(x, y);
+                buffer.set(x, y, Color::from_rgba8([r, g, b, self.alpha_at(x, y)]));
+            }
+        }
+        buffer
+    }
+
     /// Returns the alpha value at (x, y), or 255 if no alpha channel.
     fn alpha_at(&self, x: u32, y: u32) -> u8 {
         if !self.has_alpha() {
@@ -303,34 +316,6 @@ fn compute_average_local_variance(image: &ImageData, window_size: u32) -> f64 {
     }
 }
 
-/// Computes the maximum edge discontinuity (for tileability checking).
-/// Compares left edge with right edge and top edge with bottom edge.
-fn compute_edge_discontinuity(image: &ImageData) -> f64 {
-    if image.width == 0 || image.height == 0 {
-        return 0.0;
-    }
-
-    // Compare left and right edges
-    let mut lr_max_diff = 0.0f64;
-    for y in 0..image.height {
-        let left_lum = image.pixel_luminance(0, y) as f64;
-        let right_lum = image.pixel_luminance(image.width - 1, y) as f64;
-        let diff = (left_lum - right_lum).abs();
-        lr_max_diff = lr_max_diff.max(diff);
-    }
-
-    // Compare top and bottom edges
-    let mut tb_max_diff = 0.0f64;
-    for x in 0..image.width {
-        let top_lum = image.pixel_luminance(x, 0) as f64;
-        let bottom_lum = image.pixel_luminance(x, image.height - 1) as f64;
-        let diff = (top_lum - bottom_lum).abs();
-        tb_max_diff = tb_max_diff.max(diff);
-    }
-
-    lr_max_diff.max(tb_max_diff)
-}
-
 /// Returns true if n is a power of two.
 fn is_power_of_two(n: u32) -> bool {
     n > 0 && (n & (n - 1)) == 0
@@ -589,7 +574,10 @@ impl LintRule for BandingRule {
 }
 
 /// Rule: texture/tile-seam
-/// Detects edge discontinuity for tileable textures.
+/// Detects visible wrap seams in textures meant to tile.
+///
+/// With a spec, only textures that declare `tileable: true` are checked; without
+/// one, every texture is.
 pub struct TileSeamRule;
 
 impl LintRule for TileSeamRule {
@@ -609,33 +597,60 @@ impl LintRule for TileSeamRule {
         Severity::Warning
     }
 
-    fn check(&self, asset: &AssetData, _spec: Option<&Spec>) -> Vec<LintIssue> {
+    fn check(&self, asset: &AssetData, spec: Option<&Spec>) -> Vec<LintIssue> {
+        // Textures whose spec doesn't promise to tile are not held to seam checks
+        if spec.is_some_and(|spec| !Self::spec_is_tileable(spec)) {
+            return vec![];
+        }
+
         let image = match decode_png(asset.bytes) {
             Ok(img) => img,
             Err(_) => return vec![],
         };
 
-        let max_discontinuity = compute_edge_discontinuity(&image);
-        let threshold = 50.0; // Threshold for noticeable seam
+        let report = verify_tileable(&image.to_texture_buffer());
+        if report.is_seamless() {
+            return vec![];
+        }
 
-        if max_discontinuity > threshold {
-            vec![LintIssue::new(
-                self.id(),
-                self.default_severity(),
-                format!(
-                    "Image has visible tile seams (max edge diff: {:.1})",
-                    max_discontinuity
-                ),
-                "Enable seamless mode",
-            )
-            .with_actual_value(format!("edge_diff={:.1}", max_discontinuity))
-            .with_expected_range("<= 50")]
+        let edge = report.worst_edge();
+        let metrics = report.edge(edge);
+        let issue = LintIssue::new(
+            self.id(),
+            self.default_severity(),
+            format!(
+                "Image has a visible {} tile seam (seam score: {:.2})",
+                edge.as_str(),
+                metrics.seam_score
+            ),
+            "Make every noise and pattern wrap at the texture edges, or set tileable to false",
+        )
+        .with_asset_location(format!("edge:{}", edge.as_str()))
+        .with_actual_value(format!(
+            "seam_score={:.2}, max_diff={:.3}, mean_diff={:.3}",
+            metrics.seam_score, metrics.max_discontinuity, metrics.mean_discontinuity
+        ))
+        .with_expected_range(format!("seam_score <= {}", SEAM_SCORE_THRESHOLD));
+
+        if spec.is_some() {
+            vec![issue.with_spec_path("recipe.params.tileable")]
         } else {
-            vec![]
+            vec![issue]
         }
     }
 }
 
+impl TileSeamRule {
+    /// Checks if the spec recipe declares `tileable: true`.
+    fn spec_is_tileable(spec: &Spec) -> bool {
+        spec.recipe
+            .as_ref()
+            .and_then(|recipe| recipe.params.get("tileable"))
+            .and_then(|tileable| tileable.as_bool())
+            .unwrap_or(false)
+    }
+}
+
 /// Rule: texture/noisy
 /// Detects excessive high-frequency noise.
 pub struct NoisyRule;
@@ -1113,17 +1128,17 @@ mod tests {
 
     #[test]
     fn test_tile_seam_rule_triggers() {
-        // 4x4 image with big edge discontinuity
+        // 8x8 image with big edge discontinuity
         // Left edge is black, right edge is white
-        let mut pixels = vec![0u8; 4 * 4 * 3];
+        let mut pixels = vec![0u8; 8 * 8 * 3];
         // Set right column to white
-        for y in 0..4 {
-            let idx = (y * 4 + 3) * 3;
+        for y in 0..8 {
+            let idx = (y * 8 + 7) * 3;
             pixels[idx] = 255;
             pixels[idx + 1] = 255;
             pixels[idx + 2] = 255;
         }
-        let png_data = create_test_png(4, 4, &pixels, png::ColorType::Rgb);
+        let png_data = create_test_png(8, 8, &pixels, png::ColorType::Rgb);
         let asset = make_asset_data(&png_data);
 
         let rule = TileSeamRule;
@@ -1147,6 +1162,76 @@ mod tests {
         assert!(issues.is_empty());
     }
 
+    fn tileable_spec(tileable: bool) -> Spec {
+        Spec::builder("seam-test-01", speccade_spec::AssetType::Texture)
+            .license("CC0-1.0")
+            .seed(1)
+            .output(speccade_spec::OutputSpec::primary(
+                speccade_spec::OutputFormat::Png,
+                "seam.png",
+            ))
+            .recipe(speccade_spec::Recipe::new(
+                "texture.procedural_v1",
+                serde_json::json!({ "resolution": [16, 16], "tileable": tileable, "nodes": [] }),
+            ))
+            .build()
+    }
+
+    /// 16x16 RGB image; `value(x, y)` gives the gray level of each pixel.
+    fn gray_png(value: impl Fn(u32, u32) -> u8) -> Vec<u8> {
+        let mut pixels = Vec::new();
+        for y in 0..16 {
+            for x in 0..16 {
+                let v = value(x, y);
+                pixels.extend_from_slice(&[v, v, v]);
+            }
+        }
+        create_test_png(16, 16, &pixels, png::ColorType::Rgb)
+    }
+
+    #[test]
+    fn test_tile_seam_rule_flags_tileable_gradient() {
+        let png_data = gray_png(|x, _| (x * 17) as u8);
+        let asset = make_asset_data(&png_data);
+        let spec = tileable_spec(true);
+
+        let issues = TileSeamRule.check(&asset, Some(&spec));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].rule_id, "texture/tile-seam");
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert_eq!(issues[0].asset_location.as_deref(), Some("edge:left-right"));
+        assert_eq!(
+            issues[0].spec_path.as_deref(),
+            Some("recipe.params.tileable")
+        );
+    }
+
+    #[test]
+    fn test_tile_seam_rule_skips_spec_without_tileable() {
+        let png_data = gray_png(|x, _| (x * 17) as u8);
+        let asset = make_asset_data(&png_data);
+
+        // Without a spec the texture is checked; a non-tileable spec opts out
+        let issues = TileSeamRule.check(&asset, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].spec_path.is_none());
+        assert!(TileSeamRule
+            .check(&asset, Some(&tileable_spec(false)))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_tile_seam_rule_passes_wrapping_texture() {
+        // Triangle wave across x and y: opposite edges match
+        let tri = |i: u32| (i.min(15 - i) * 30) as u8;
+        let png_data = gray_png(|x, y| tri(x) / 2 + tri(y) / 2);
+        let asset = make_asset_data(&png_data);
+
+        let issues = TileSeamRule.check(&asset, Some(&tileable_spec(true)));
+        assert!(issues.is_empty());
+    }
+
     #[test]
     fn test_noisy_rule_triggers() {
         // 8x8 image with high local variance (random-like pattern)
@@ -0,0 +1,319 @@
+//! Reproducible zip archives.
+//!
+//! [`DeterministicZip`] collects named files and encodes them so that the same
+//! file set always produces the same bytes, regardless of insertion order or
+//! when and where the archive is written:
+//!
+//! - entries are sorted by name
+//! - every entry is stored uncompressed
+//! - every timestamp is 1980-01-01 00:00:00, the zip epoch
+//! - no extra fields, comments, or host-specific attributes are written
+//!
+//! The output is a plain (non-zip64) archive readable by any standard unzip
+//! tool, which limits it to 65535 entries and 4 GiB in total.
+
+use std::collections::BTreeMap;
+
+/// DOS date for 1980-01-01.
+const DOS_DATE_1980: u16 = (1 << 5) | 1;
+
+/// General purpose flag marking entry names as UTF-8.
+const FLAG_UTF8: u16 = 1 << 11;
+
+/// Zip version 2.0, used for both "made by" (MS-DOS host) and "needed to extract".
+const ZIP_VERSION: u16 = 20;
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+/// CRC-32 (IEEE) lookup table.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+/// Errors from building a deterministic zip archive.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ZipError {
+    /// The entry name is empty, absolute, uses backslashes, or escapes the archive.
+    #[error("invalid zip entry name: '{0}'")]
+    InvalidName(String),
+    /// An entry with the same name was already added.
+    #[error("duplicate zip entry: '{0}'")]
+    DuplicateEntry(String),
+    /// The archive has more entries than a non-zip64 archive can hold.
+    #[error("too many zip entries: {0} (maximum 65535)")]
+    TooManyEntries(usize),
+    /// An entry or the archive as a whole exceeds the 4 GiB zip limit.
+    #[error("zip archive exceeds the 4 GiB limit at '{0}'")]
+    TooLarge(String),
+}
+
+/// A set of files to be written as a byte-reproducible zip archive.
+#[derive(Debug, Clone, Default)]
+pub struct DeterministicZip {
+    entries: BTreeMap<String, Vec<u8>>,
+}
+
+impl DeterministicZip {
+    /// Creates an empty archive.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file.
+    ///
+    /// `name` is the path inside the archive, relative and separated by forward
+    /// slashes (e.g. `textures/albedo.png`).
+    pub fn add_file(
+        &mut self,
+        name: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+    ) -> Result<(), ZipError> {
+        let name = name.into();
+        let valid = !name.is_empty()
+            && !name.starts_with('/')
+            && !name.contains('\\')
+            && name
+                .split('/')
+                .all(|c| !c.is_empty() && c != "." && c != "..");
+        if !valid {
+            return Err(ZipError::InvalidName(name));
+        }
+        if self.entries.contains_key(&name) {
+            return Err(ZipError::DuplicateEntry(name));
+        }
+        self.entries.insert(name, data.into());
+        Ok(())
+    }
+
+    /// Returns the number of files in the archive.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if no files have been added.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the files in archive order.
+    pub fn files(&self) -> impl Iterator<Item = (&str, &[u8])> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_slice()))
+    }
+
+    /// Encodes the archive.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ZipError> {
+        let count = u16::try_from(self.entries.len())
+            .map_err(|_| ZipError::TooManyEntries(self.entries.len()))?;
+
+        let mut out = Vec::new();
+        let mut central = Vec::new();
+        for (name, data) in &self.entries {
+            let too_large = || ZipError::TooLarge(name.clone());
+            let offset = u32::try_from(out.len()).map_err(|_| too_large())?;
+            let size = u32::try_from(data.len()).map_err(|_| too_large())?;
+            let name_len = u16::try_from(name.len()).map_err(|_| too_large())?;
+            let crc = crc32(data);
+
+            put_u32(&mut out, LOCAL_HEADER_SIGNATURE);
+            put_u16(&mut out, ZIP_VERSION);
+            put_entry_fields(&mut out, crc, size, name_len);
+            out.extend_from_slice(name.as_bytes());
+            out.extend_from_slice(data);
+
+            put_u32(&mut central, CENTRAL_HEADER_SIGNATURE);
+            put_u16(&mut central, ZIP_VERSION);
+            put_u16(&mut central, ZIP_VERSION);
+            put_entry_fields(&mut central, crc, size, name_len);
+            put_u16(&mut central, 0); // comment length
+            put_u16(&mut central, 0); // disk number
+            put_u16(&mut central, 0); // internal attributes
+            put_u32(&mut central, 0); // external attributes
+            put_u32(&mut central, offset);
+            central.extend_from_slice(name.as_bytes());
+        }
+
+        let too_large = || ZipError::TooLarge("central directory".to_string());
+        let central_offset = u32::try_from(out.len()).map_err(|_| too_large())?;
+        let central_size = u32::try_from(central.len()).map_err(|_| too_large())?;
+        out.extend_from_slice(&central);
+
+        put_u32(&mut out, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        put_u16(&mut out, 0); // this disk
+        put_u16(&mut out, 0); // disk with the central directory
+        put_u16(&mut out, count);
+        put_u16(&mut out, count);
+        put_u32(&mut out, central_size);
+        put_u32(&mut out, central_offset);
+        put_u16(&mut out, 0); // comment length
+
+        Ok(out)
+    }
+}
+
+/// Writes the fields shared by local and central directory headers, from the
+/// general purpose flags through the extra field length.
+fn put_entry_fields(out: &mut Vec<u8>, crc: u32, size: u32, name_len: u16) {
+    put_u16(out, FLAG_UTF8);
+    put_u16(out, 0); // compression method: stored
+    put_u16(out, 0); // modification time: 00:00:00
+    put_u16(out, DOS_DATE_1980);
+    put_u32(out, crc);
+    put_u32(out, size); // compressed size
+    put_u32(out, size); // uncompressed size
+    put_u16(out, name_len);
+    put_u16(out, 0); // extra field length
+}
+
+fn put_u16(out: &mut Vec<u8>, value: u16) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+fn put_u32(out: &mut Vec<u8>, value: u32) {
+    out.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Computes the CRC-32 checksum zip uses for entry data.
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0u32, |crc, &b| {
+        CRC32_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes an archive through its central directory, checking every CRC.
+    fn read_zip(zip: &[u8]) -> Vec<(String, Vec<u8>)> {
+        let u16_at = |i: usize| u16::from_le_bytes([zip[i], zip[i + 1]]) as usize;
+        let u32_at = |i: usize| u32::from_le_bytes(zip[i..i + 4].try_into().unwrap()) as usize;
+
+        let eocd = zip.len() - 22;
+        assert_eq!(u32_at(eocd) as u32, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        let count = u16_at(eocd + 10);
+        let mut pos = u32_at(eocd + 16);
+
+        (0..count)
+            .map(|_| {
+                assert_eq!(u32_at(pos) as u32, CENTRAL_HEADER_SIGNATURE);
+                let crc = u32_at(pos + 16) as u32;
+                let size = u32_at(pos + 24);
+                let name_len = u16_at(pos + 28);
+                let local = u32_at(pos + 42);
+                let name = String::from_utf8(zip[pos + 46..pos + 46 + name_len].to_vec()).unwrap();
+                pos += 46 + name_len;
+
+                assert_eq!(u32_at(local) as u32, LOCAL_HEADER_SIGNATURE);
+                let data_start = local + 30 + u16_at(local + 26) + u16_at(local + 28);
+                let data = zip[data_start..data_start + size].to_vec();
+                assert_eq!(crc32(&data), crc);
+                (name, data)
+            })
+            .collect()
+    }
+
+    fn sample_files() -> Vec<(&'static str, Vec<u8>)> {
+        vec![
+            ("mesh.glb", b"glTF binary".to_vec()),
+            ("textures/albedo.png", vec![0x89, b'P', b'N', b'G', 0, 1, 2]),
+            ("textures/normal.png", vec![7; 300]),
+            ("manifest.json", b"{}".to_vec()),
+            ("empty.txt", Vec::new()),
+        ]
+    }
+
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_insertion_order_does_not_change_bytes() {
+        let files = sample_files();
+
+        let mut forward = DeterministicZip::new();
+        for (name, data) in &files {
+            forward.add_file(*name, data.clone()).unwrap();
+        }
+        let mut reversed = DeterministicZip::new();
+        for (name, data) in files.iter().rev() {
+            reversed.add_file(*name, data.clone()).unwrap();
+        }
+
+        let a = forward.to_bytes().unwrap();
+        let b = reversed.to_bytes().unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a, forward.to_bytes().unwrap());
+    }
+
+    #[test]
+    fn test_round_trip_preserves_files_in_sorted_order() {
+        let files = sample_files();
+        let mut zip = DeterministicZip::new();
+        for (name, data) in &files {
+            zip.add_file(*name, data.clone()).unwrap();
+        }
+        assert_eq!(zip.len(), files.len());
+
+        let mut expected: Vec<_> = files
+            .into_iter()
+            .map(|(name, data)| (name.to_string(), data))
+            .collect();
+        expected.sort();
+        assert_eq!(read_zip(&zip.to_bytes().unwrap()), expected);
+    }
+
+    #[test]
+    fn test_empty_archive_is_valid() {
+        let zip = DeterministicZip::new();
+        assert!(zip.is_empty());
+        let bytes = zip.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 22);
+        assert!(read_zip(&bytes).is_empty());
+    }
+
+    #[test]
+    fn test_rejects_invalid_and_duplicate_names() {
+        let mut zip = DeterministicZip::new();
+        for name in [
+            "",
+            "/abs.png",
+            "a\\b.png",
+            "../up.png",
+            "a//b.png",
+            "./a.png",
+        ] {
+            assert_eq!(
+                zip.add_file(name, vec![1]),
+                Err(ZipError::InvalidName(name.to_string()))
+            );
+        }
+
+        zip.add_file("a.png", vec![1]).unwrap();
+        assert_eq!(
+            zip.add_file("a.png", vec![2]),
+            Err(ZipError::DuplicateEntry("a.png".to_string()))
+        );
+    }
+}
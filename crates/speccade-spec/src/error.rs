@@ -100,6 +100,8 @@ pub enum WarningCode {
     SeedNearOverflow,
     /// W004: Unused recipe params
     UnusedRecipeParams,
+    /// W005: Usage close to a budget limit
+    NearBudgetLimit,
 }
 
 impl WarningCode {
@@ -110,6 +112,7 @@ impl WarningCode {
             WarningCode::MissingDescription => "W002",
             WarningCode::SeedNearOverflow => "W003",
             WarningCode::UnusedRecipeParams => "W004",
+            WarningCode::NearBudgetLimit => "W005",
         }
     }
 }
@@ -223,6 +226,17 @@ pub enum SpecError {
     #[error("canonicalization error: {0}")]
     Canonicalization(String),
 
+    /// A variant override could not be applied.
+    #[error("variant '{variant_id}': invalid override '{path}': {message}")]
+    InvalidVariantOverride {
+        /// The variant being expanded.
+        variant_id: String,
+        /// The override path.
+        path: String,
+        /// Why the override could not be applied.
+        message: String,
+    },
+
     /// I/O error.
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
@@ -435,6 +449,7 @@ mod tests {
     fn test_warning_codes() {
         assert_eq!(WarningCode::MissingLicense.code(), "W001");
         assert_eq!(WarningCode::MissingDescription.code(), "W002");
+        assert_eq!(WarningCode::NearBudgetLimit.code(), "W005");
     }
 
     #[test]
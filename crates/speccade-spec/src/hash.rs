@@ -272,6 +272,29 @@ pub fn derive_variant_spec_seed(base_seed: u32, seed_offset: u32, variant_id: &s
     u32::from_le_bytes(bytes)
 }
 
+/// Derives a default seed for a spec that does not declare one.
+///
+/// Used by authoring tools so that seedless specs still evaluate to a stable
+/// seed. The seed depends only on the asset identifier:
+///
+/// ```text
+/// seed = truncate_u32(BLAKE3(asset_id))
+/// ```
+///
+/// # Example
+/// ```
+/// use speccade_spec::hash::derive_seed_from_asset_id;
+///
+/// let seed = derive_seed_from_asset_id("laser-blast-01");
+/// assert_eq!(seed, derive_seed_from_asset_id("laser-blast-01"));
+/// assert_ne!(seed, derive_seed_from_asset_id("laser-blast-02"));
+/// ```
+pub fn derive_seed_from_asset_id(asset_id: &str) -> u32 {
+    let hash = blake3::hash(asset_id.as_bytes());
+    let bytes: [u8; 4] = hash.as_bytes()[0..4].try_into().unwrap();
+    u32::from_le_bytes(bytes)
+}
+
 /// Computes a BLAKE3 hash of arbitrary data.
 ///
 /// # Arguments
@@ -437,6 +460,17 @@ mod tests {
         assert_eq!(derive_variant_seed(base_seed, "hard"), hard_seed);
     }
 
+    #[test]
+    fn test_derive_seed_from_asset_id() {
+        let seed = derive_seed_from_asset_id("laser-blast-01");
+
+        // Same asset id should always produce the same seed
+        assert_eq!(derive_seed_from_asset_id("laser-blast-01"), seed);
+
+        // Different asset ids should produce different seeds
+        assert_ne!(derive_seed_from_asset_id("laser-blast-02"), seed);
+    }
+
     #[test]
     fn test_derive_layer_seed_different_base() {
         let seed_42_0 = derive_layer_seed(42, 0);
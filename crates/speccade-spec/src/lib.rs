@@ -38,6 +38,7 @@
 //!
 //! # Modules
 //!
+//! - [`deterministic_zip`]: Byte-reproducible zip archives
 //! - [`error`]: Error and warning types for validation
 //! - [`extension`]: Extension system types for external backends
 //! - [`hash`]: Canonical hashing and seed derivation
@@ -47,6 +48,7 @@
 //! - [`spec`]: Main spec type and builder
 //! - [`validation`]: Spec validation functions
 
+pub mod deterministic_zip;
 pub mod error;
 pub mod extension;
 pub mod hash;
@@ -57,24 +59,27 @@ pub mod spec;
 pub mod validation;
 
 // Re-export commonly used types at the crate root
+pub use deterministic_zip::{DeterministicZip, ZipError};
 pub use error::{
     BackendError, ErrorCode, GenerationError, SpecError, ValidationError, ValidationResult,
     ValidationWarning, WarningCode,
 };
 pub use hash::{
-    canonical_recipe_hash, canonical_spec_hash, derive_layer_seed, derive_variant_seed,
-    derive_variant_spec_seed,
+    canonical_recipe_hash, canonical_spec_hash, derive_layer_seed, derive_seed_from_asset_id,
+    derive_variant_seed, derive_variant_spec_seed,
 };
 pub use output::{EngineTarget, OutputFormat, OutputKind, OutputSpec, VariantSpec};
 pub use recipe::{Recipe, RecipeParamsError};
 pub use report::{
-    BakedMapInfo, BakingMetrics, BoundingBox, CollisionBoundingBox, CollisionMeshMetrics,
-    NavmeshMetrics, OutputMetrics, OutputResult, Report, ReportBuilder, ReportError, ReportWarning,
-    StageTiming, StaticMeshLodLevelMetrics, REPORT_VERSION,
+    verify_outputs, BakedMapInfo, BakingMetrics, BoundingBox, CollisionBoundingBox,
+    CollisionMeshMetrics, NavmeshMetrics, OutputMetrics, OutputMismatch, OutputMismatchKind,
+    OutputResult, Report, ReportBuilder, ReportError, ReportWarning, StageTiming,
+    StaticMeshLodLevelMetrics, REPORT_VERSION,
 };
 pub use spec::{AssetType, Spec, SpecBuilder, MAX_SEED, SPEC_VERSION};
 pub use validation::constraints::{
-    evaluate_constraints, Constraint, ConstraintResult, ConstraintSet, VerifyResult,
+    evaluate_constraints, evaluate_constraints_with_files, Constraint, ConstraintFiles,
+    ConstraintResult, ConstraintSet, VerifyResult,
 };
 pub use validation::{
     is_safe_output_path, is_valid_asset_id, validate_for_generate,
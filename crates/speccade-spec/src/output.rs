@@ -1,5 +1,7 @@
 //! Output specification types.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Output kind (what role the output serves).
@@ -46,12 +48,22 @@ impl std::fmt::Display for OutputKind {
 pub enum OutputFormat {
     /// WAV audio format.
     Wav,
+    /// OGG/Vorbis audio format.
+    Ogg,
     /// FastTracker II Extended Module.
     Xm,
     /// Impulse Tracker module.
     It,
+    /// ProTracker module.
+    Mod,
+    /// Scream Tracker 3 module.
+    S3m,
+    /// Standard MIDI File exported from a tracker song.
+    Mid,
     /// PNG image format.
     Png,
+    /// OpenEXR image format with 32-bit float channels.
+    Exr,
     /// Binary glTF format.
     Glb,
     /// Text glTF format.
@@ -65,9 +77,14 @@ impl OutputFormat {
     pub fn all() -> &'static [OutputFormat] {
         &[
             OutputFormat::Wav,
+            OutputFormat::Ogg,
             OutputFormat::Xm,
             OutputFormat::It,
+            OutputFormat::Mod,
+            OutputFormat::S3m,
+            OutputFormat::Mid,
             OutputFormat::Png,
+            OutputFormat::Exr,
             OutputFormat::Glb,
             OutputFormat::Gltf,
             OutputFormat::Json,
@@ -78,9 +95,14 @@ impl OutputFormat {
     pub fn extension(&self) -> &'static str {
         match self {
             OutputFormat::Wav => "wav",
+            OutputFormat::Ogg => "ogg",
             OutputFormat::Xm => "xm",
             OutputFormat::It => "it",
+            OutputFormat::Mod => "mod",
+            OutputFormat::S3m => "s3m",
+            OutputFormat::Mid => "mid",
             OutputFormat::Png => "png",
+            OutputFormat::Exr => "exr",
             OutputFormat::Glb => "glb",
             OutputFormat::Gltf => "gltf",
             OutputFormat::Json => "json",
@@ -89,17 +111,20 @@ impl OutputFormat {
 
     /// Checks if this format is an audio format.
     pub fn is_audio(&self) -> bool {
-        matches!(self, OutputFormat::Wav)
+        matches!(self, OutputFormat::Wav | OutputFormat::Ogg)
     }
 
     /// Checks if this format is a music/tracker format.
     pub fn is_music(&self) -> bool {
-        matches!(self, OutputFormat::Xm | OutputFormat::It)
+        matches!(
+            self,
+            OutputFormat::Xm | OutputFormat::It | OutputFormat::Mod | OutputFormat::S3m
+        )
     }
 
     /// Checks if this format is an image format.
     pub fn is_image(&self) -> bool {
-        matches!(self, OutputFormat::Png)
+        matches!(self, OutputFormat::Png | OutputFormat::Exr)
     }
 
     /// Checks if this format is a 3D model format.
@@ -181,6 +206,11 @@ pub struct VariantSpec {
     pub variant_id: String,
     /// Numeric seed offset used for deterministic seed derivation.
     pub seed_offset: u32,
+    /// Recipe parameter overrides applied when the variant is expanded.
+    ///
+    /// Keys are parameter paths such as `recipe.params.nodes[0].noise.scale`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overrides: BTreeMap<String, serde_json::Value>,
 }
 
 impl VariantSpec {
@@ -189,8 +219,15 @@ impl VariantSpec {
         Self {
             variant_id: variant_id.into(),
             seed_offset,
+            overrides: BTreeMap::new(),
         }
     }
+
+    /// Adds a recipe parameter override.
+    pub fn with_override(mut self, path: impl Into<String>, value: serde_json::Value) -> Self {
+        self.overrides.insert(path.into(), value);
+        self
+    }
 }
 
 /// Target game engine.
@@ -222,22 +259,36 @@ mod tests {
     #[test]
     fn test_output_format_extension() {
         assert_eq!(OutputFormat::Wav.extension(), "wav");
+        assert_eq!(OutputFormat::Ogg.extension(), "ogg");
         assert_eq!(OutputFormat::Png.extension(), "png");
+        assert_eq!(OutputFormat::Exr.extension(), "exr");
         assert_eq!(OutputFormat::Glb.extension(), "glb");
+        assert_eq!(OutputFormat::Mod.extension(), "mod");
+        assert_eq!(OutputFormat::S3m.extension(), "s3m");
+        assert_eq!(OutputFormat::Mid.extension(), "mid");
     }
 
     #[test]
     fn test_output_format_categories() {
         assert!(OutputFormat::Wav.is_audio());
+        assert!(OutputFormat::Ogg.is_audio());
         assert!(!OutputFormat::Png.is_audio());
 
         assert!(OutputFormat::Xm.is_music());
         assert!(OutputFormat::It.is_music());
+        assert!(OutputFormat::Mod.is_music());
+        assert!(OutputFormat::S3m.is_music());
         assert!(!OutputFormat::Wav.is_music());
+        assert!(!OutputFormat::Ogg.is_music());
+        assert!(!OutputFormat::Mid.is_music());
 
         assert!(OutputFormat::Glb.is_mesh());
         assert!(OutputFormat::Gltf.is_mesh());
         assert!(!OutputFormat::Png.is_mesh());
+
+        assert!(OutputFormat::Png.is_image());
+        assert!(OutputFormat::Exr.is_image());
+        assert!(!OutputFormat::Glb.is_image());
     }
 
     #[test]
@@ -1,5 +1,6 @@
 //! Bone collection types for organizing bones in groups.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::error::AnimatorRigError;
@@ -9,7 +10,7 @@ fn default_true() -> bool {
 }
 
 /// Bone collection definition for organizing bones in groups.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BoneCollection {
     /// Name of the collection (e.g., "IK Controls", "FK Controls").
@@ -69,7 +70,7 @@ impl BoneCollection {
 }
 
 /// Standard bone collection presets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BoneCollectionPreset {
     /// IK control bones (targets and poles).
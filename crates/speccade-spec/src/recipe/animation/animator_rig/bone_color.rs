@@ -1,9 +1,10 @@
 //! Bone color types and schemes for color coding bones.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// RGB color value for bone coloring (0.0-1.0 range).
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BoneColor {
     /// Red component (0.0-1.0).
@@ -57,7 +58,7 @@ impl Default for BoneColor {
 }
 
 /// Bone color scheme for automatic bone coloring.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(tag = "scheme", rename_all = "snake_case", deny_unknown_fields)]
 pub enum BoneColorScheme {
     /// Standard scheme: left=blue, right=red, center=yellow.
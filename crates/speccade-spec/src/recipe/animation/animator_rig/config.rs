@@ -1,5 +1,6 @@
 //! Main animator rig configuration types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::bone_collection::{BoneCollection, BoneCollectionPreset};
@@ -12,7 +13,7 @@ fn default_true() -> bool {
 }
 
 /// Armature display type in Blender viewport.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ArmatureDisplay {
     /// Octahedral bone shapes (default).
@@ -45,7 +46,7 @@ impl ArmatureDisplay {
 ///
 /// This configuration controls how the rig appears to animators in Blender,
 /// including bone collections, custom shapes, and color coding.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AnimatorRigConfig {
     /// Whether to organize bones into collections.
@@ -1,9 +1,10 @@
 //! Widget shape styles for bone visualization.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Widget shape styles for bone visualization.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum WidgetStyle {
     /// Wireframe circle (default, good for rotation controls).
@@ -1,5 +1,6 @@
 //! Animation clip types including keyframes and bone transforms.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::{AnimationExportSettings, InterpolationMode};
@@ -11,7 +12,7 @@ use crate::recipe::character::SkeletonPreset;
 // =============================================================================
 
 /// Bone transform at a keyframe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BoneTransform {
     /// Position offset [X, Y, Z].
@@ -64,7 +65,7 @@ impl BoneTransform {
 // =============================================================================
 
 /// Keyframe definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AnimationKeyframe {
     /// Time in seconds.
@@ -74,7 +75,7 @@ pub struct AnimationKeyframe {
 }
 
 /// Keyframe for IK target animation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkKeyframe {
     /// Time in seconds.
@@ -85,7 +86,7 @@ pub struct IkKeyframe {
 }
 
 /// Transform for an IK target at a keyframe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkTargetTransform {
     /// World position [X, Y, Z].
@@ -130,7 +131,7 @@ impl IkTargetTransform {
 // =============================================================================
 
 /// Parameters for the `skeletal_animation.blender_clip_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletalAnimationBlenderClipV1Params {
     /// Skeleton rig to animate.
@@ -1,5 +1,6 @@
 //! Common types shared across animation modules.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 // =============================================================================
 
 /// Interpolation mode for animation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum InterpolationMode {
     /// Linear interpolation.
@@ -20,7 +21,7 @@ pub enum InterpolationMode {
 }
 
 /// Timing curve types for phase interpolation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TimingCurve {
     /// Linear interpolation.
@@ -45,7 +46,7 @@ pub enum TimingCurve {
 // =============================================================================
 
 /// Axis specification for constraints.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 pub enum ConstraintAxis {
     /// X axis (pitch).
     #[default]
@@ -68,7 +69,7 @@ impl ConstraintAxis {
 }
 
 /// Aim axis options for aim constraints.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 pub enum AimAxis {
     /// Positive X axis.
     #[default]
@@ -110,7 +111,7 @@ impl AimAxis {
 // =============================================================================
 
 /// Export settings for animations.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AnimationExportSettings {
     /// Bake all transforms to keyframes.
@@ -143,7 +144,7 @@ impl Default for AnimationExportSettings {
 }
 
 /// Validation conventions configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct ConventionsConfig {
     /// Fail on validation errors (strict mode).
@@ -156,7 +157,7 @@ pub struct ConventionsConfig {
 // =============================================================================
 
 /// Common animation presets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AnimationPreset {
     /// Idle breathing animation.
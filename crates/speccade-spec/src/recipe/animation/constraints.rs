@@ -1,5 +1,6 @@
 //! Bone constraint types for skeletal rigs.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::ConstraintAxis;
@@ -12,7 +13,7 @@ use super::common::ConstraintAxis;
 ///
 /// These constraints map to Blender's constraint system and are used to create
 /// realistic joint limits for skeletal animations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum BoneConstraint {
     /// Hinge constraint - allows rotation around a single axis only.
@@ -287,7 +288,7 @@ impl std::fmt::Display for BoneConstraintError {
 impl std::error::Error for BoneConstraintError {}
 
 /// Configuration for bone constraints on a rig.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct ConstraintConfig {
     /// List of bone constraints to apply.
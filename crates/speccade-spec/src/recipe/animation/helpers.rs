@@ -4,6 +4,7 @@
 //! IK targets and constraints for common locomotion patterns like
 //! walk cycles, run cycles, and idle sway animations.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -14,7 +15,7 @@ use super::skeletal::{FootSystem, IkChain, IkPreset};
 // =============================================================================
 
 /// Available animation helper presets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum AnimationHelperPreset {
     /// Walk cycle animation with foot plants and arm swing.
@@ -82,7 +83,7 @@ impl std::fmt::Display for AnimationHelperPreset {
 // =============================================================================
 
 /// Skeleton types for animation helpers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum SkeletonType {
     /// Standard humanoid rig with arms and legs.
@@ -109,7 +110,7 @@ impl SkeletonType {
 // =============================================================================
 
 /// Per-limb IK target configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkTargetSettings {
     /// Pole angle in degrees for knee/elbow direction.
@@ -161,7 +162,7 @@ impl IkTargetSettings {
 // =============================================================================
 
 /// Settings for locomotion cycle generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CycleSettings {
     /// Distance traveled per cycle in world units.
@@ -320,7 +321,7 @@ impl CycleSettings {
 /// This recipe generates procedural locomotion animations using IK targets
 /// and constraint presets. It creates cyclic animations suitable for
 /// walk cycles, run cycles, and idle animations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AnimationHelpersV1Params {
     /// Skeleton type (humanoid or quadruped).
@@ -1,5 +1,6 @@
 //! Pose and animation phase types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::TimingCurve;
@@ -9,7 +10,7 @@ use super::common::TimingCurve;
 // =============================================================================
 
 /// A named pose definition containing bone rotations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PoseDefinition {
     /// Bone transforms in this pose.
@@ -39,7 +40,7 @@ impl Default for PoseDefinition {
 }
 
 /// Bone transform within a pose.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct PoseBoneTransform {
     /// Pitch rotation in degrees (X axis).
@@ -99,7 +100,7 @@ impl PoseBoneTransform {
 // =============================================================================
 
 /// IK target keyframe within a phase.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PhaseIkTarget {
     /// Frame number for this keyframe.
@@ -130,7 +131,7 @@ impl PhaseIkTarget {
 
 /// Animation phase definition.
 /// Defines a segment of the animation with timing and pose/IK targets.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AnimationPhase {
     /// Name of this phase (e.g., "contact", "passing", "lift").
@@ -1,5 +1,6 @@
 //! Animation preview rendering configuration.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -19,7 +20,7 @@ fn default_background() -> [f32; 4] {
 }
 
 /// Camera angle preset for animation preview rendering.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PreviewCameraAngle {
     /// Classic 3/4 view from front-right, slightly above.
@@ -55,7 +56,7 @@ impl PreviewCameraAngle {
 }
 
 /// Configuration for rendering an animation preview as a GIF.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PreviewRender {
     /// Camera angle preset.
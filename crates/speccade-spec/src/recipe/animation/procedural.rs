@@ -1,5 +1,6 @@
 //! Procedural animation layer types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 // =============================================================================
 
 /// Types of procedural animation layers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProceduralLayerType {
     /// Breathing animation (subtle chest/torso expansion).
@@ -21,7 +22,7 @@ pub enum ProceduralLayerType {
 }
 
 /// Rotation axis for procedural layers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProceduralAxis {
     /// Pitch rotation (X axis).
@@ -46,7 +47,7 @@ impl ProceduralAxis {
 
 /// Procedural animation layer configuration.
 /// Adds automatic motion overlays to bones.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ProceduralLayer {
     /// Type of procedural animation.
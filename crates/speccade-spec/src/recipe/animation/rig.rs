@@ -1,5 +1,6 @@
 //! Rigged animation recipe parameters.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -23,7 +24,7 @@ fn default_fps() -> u8 {
 
 /// Parameters for the `skeletal_animation.blender_rigged_v1` recipe.
 /// This is the IK-enabled version of the animation recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletalAnimationBlenderRiggedV1Params {
     /// Skeleton rig to animate.
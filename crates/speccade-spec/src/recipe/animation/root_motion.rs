@@ -1,9 +1,10 @@
 //! Root motion handling modes and settings for animation export.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Root motion handling mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum RootMotionMode {
     /// Keep root motion as-is (default).
@@ -18,7 +19,7 @@ pub enum RootMotionMode {
 }
 
 /// Root motion settings for animation export.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RootMotionSettings {
     /// Which root motion handling mode to use.
@@ -1,5 +1,6 @@
 //! Aim constraints and twist bone configuration.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::super::common::{AimAxis, ConstraintAxis};
@@ -10,7 +11,7 @@ use super::super::common::{AimAxis, ConstraintAxis};
 
 /// Configuration for an aim (look-at) constraint.
 /// Makes a bone always point toward a target.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AimConstraint {
     /// Name of this aim constraint.
@@ -80,7 +81,7 @@ impl AimConstraint {
 
 /// Configuration for twist bone distribution.
 /// Distributes rotation from a source bone across twist bones.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TwistBone {
     /// Name of this twist setup.
@@ -3,6 +3,7 @@
 //! Provides curl, spread, and individual finger control with anatomically
 //! correct conventions (positive curl = flexion/closing fist).
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -13,7 +14,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Creates simplified controls for animating fingers with curl (flexion)
 /// and spread (abduction) parameters instead of individual bone rotations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FingerControls {
     /// Name of this control set (e.g., "hand_l_fingers").
@@ -64,7 +65,7 @@ fn default_max_spread() -> f64 {
 }
 
 /// Hand side.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum HandSide {
     Left,
@@ -82,7 +83,7 @@ impl HandSide {
 }
 
 /// Finger names.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum FingerName {
     Thumb,
@@ -109,7 +110,7 @@ impl FingerName {
 ///
 /// All values are normalized (0.0 to 1.0) and will be scaled by the
 /// max_curl_degrees and max_spread_degrees from FingerControls.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct FingerPose {
     /// Global curl for all fingers (0.0 = flat, 1.0 = full curl/fist).
@@ -202,7 +203,7 @@ impl FingerPose {
 }
 
 /// Keyframe for finger animation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FingerKeyframe {
     /// Time in seconds.
@@ -1,5 +1,6 @@
 //! Foot system configuration for IK rigs.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 /// Configuration for an IK foot roll system.
 /// Provides automatic heel-toe roll during foot plants.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FootSystem {
     /// Name of this foot system (e.g., "foot_l", "foot_r").
@@ -1,5 +1,6 @@
 //! IK chain types and configuration.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 // =============================================================================
 
 /// IK preset types for common rig configurations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum IkPreset {
     /// Humanoid leg IK (hip -> knee -> foot).
@@ -79,7 +80,7 @@ impl IkPreset {
 }
 
 /// Configuration for an IK target (the end effector target).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkTargetConfig {
     /// Name of the target control bone/empty.
@@ -122,7 +123,7 @@ impl IkTargetConfig {
 }
 
 /// Configuration for an IK pole target (controls bend direction).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PoleConfig {
     /// Name of the pole target control bone/empty.
@@ -167,7 +168,7 @@ impl PoleConfig {
 }
 
 /// IK chain definition for a single limb or bone chain.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkChain {
     /// Unique name for this IK chain.
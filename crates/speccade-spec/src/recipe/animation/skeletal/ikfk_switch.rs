@@ -2,6 +2,7 @@
 //!
 //! Allows instant pose transfer between IK and FK control without popping.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -12,7 +13,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// Enables animators to switch between IK and FK control modes with
 /// automatic pose snapping to prevent visual popping.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkFkSwitch {
     /// Name of this switch (e.g., "arm_l_ikfk").
@@ -27,7 +28,7 @@ pub struct IkFkSwitch {
 }
 
 /// IK/FK control mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum IkFkMode {
     /// Inverse Kinematics mode (target-driven).
@@ -38,7 +39,7 @@ pub enum IkFkMode {
 }
 
 /// Keyframe for IK/FK switch with optional snapping.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct IkFkKeyframe {
     /// Frame number for this switch.
@@ -1,5 +1,6 @@
 //! Complete rig setup configuration for skeletal animation.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::super::constraints::{BoneConstraint, BoneConstraintError, ConstraintConfig};
@@ -17,7 +18,7 @@ use super::space_switch::SpaceSwitch;
 // =============================================================================
 
 /// Complete rig setup configuration for an armature.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct RigSetup {
     /// IK presets to apply.
@@ -1,5 +1,6 @@
 //! Stretch and bake settings for skeletal animation.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -8,7 +9,7 @@ use serde::{Deserialize, Serialize};
 
 /// Stretch settings for IK chains.
 /// Allows bones to stretch beyond their rest length.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct StretchSettings {
     /// Whether stretch is enabled.
@@ -34,7 +35,7 @@ fn default_min_stretch() -> f64 {
 }
 
 /// Volume preservation mode for stretch.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum VolumePreservation {
     /// No volume preservation.
@@ -78,7 +79,7 @@ impl StretchSettings {
 // =============================================================================
 
 /// Settings for baking animation to keyframes.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BakeSettings {
     /// Simplify curves after baking (remove redundant keyframes).
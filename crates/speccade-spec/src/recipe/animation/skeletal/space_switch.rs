@@ -3,6 +3,7 @@
 //! Allows bones to dynamically change what their transforms are relative to.
 //! Essential for picking up objects, planting hands, and character interactions.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // =============================================================================
@@ -16,7 +17,7 @@ use serde::{Deserialize, Serialize};
 /// - Picking up objects (hand switches to object space)
 /// - Planting hands on walls (hand switches to world space)
 /// - Two-character interactions
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpaceSwitch {
     /// Name of this switch (e.g., "hand_l_space").
@@ -31,7 +32,7 @@ pub struct SpaceSwitch {
 }
 
 /// A parent space option.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ParentSpace {
     /// Display name (e.g., "World", "Root", "Head").
     pub name: String,
@@ -41,7 +42,7 @@ pub struct ParentSpace {
 }
 
 /// Type of parent space.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SpaceKind {
     /// World space (no parent).
@@ -61,7 +62,7 @@ pub enum SpaceKind {
 }
 
 /// Keyframe for space switch.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpaceSwitchKeyframe {
     /// Frame number for this switch.
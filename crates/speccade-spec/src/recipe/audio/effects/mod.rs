@@ -1,12 +1,15 @@
 //! Audio effect types for the effect chain.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::Waveform;
+
 #[cfg(test)]
 mod tests;
 
 /// Audio effect in the processing chain.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum Effect {
     /// Parametric EQ effect with cascaded biquad filters.
@@ -20,8 +23,11 @@ pub enum Effect {
         room_size: f64,
         /// High frequency damping (0.0-1.0).
         damping: f64,
-        /// Wet/dry mix (0.0-1.0).
+        /// Wet level (0.0-1.0).
         wet: f64,
+        /// Dry level (0.0-1.0). Defaults to `1.0 - wet`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dry: Option<f64>,
         /// Stereo width (0.0-1.0).
         #[serde(default = "default_width")]
         width: f64,
@@ -37,20 +43,30 @@ pub enum Effect {
         /// Enable ping-pong stereo delay.
         #[serde(default)]
         ping_pong: bool,
+        /// Cutoff in Hz of a one-pole lowpass in the feedback path, so each
+        /// repeat is darker than the last. None leaves the feedback unfiltered.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tone: Option<f64>,
     },
-    /// Chorus effect.
+    /// Chorus effect. A `wet` of 0.0 bypasses the effect.
     Chorus {
         /// LFO rate in Hz.
         rate: f64,
         /// Modulation depth (0.0-1.0).
         depth: f64,
-        /// Wet/dry mix (0.0-1.0).
+        /// Wet/dry mix (0.0-1.0). Also accepted as `mix`.
+        #[serde(alias = "mix")]
         wet: f64,
         /// Number of voices (1-4).
         #[serde(default = "default_chorus_voices")]
         voices: u8,
+        /// Stereo spread of the voices (0.0-1.0). Widens mono input to stereo.
+        /// None keeps the fixed left/right LFO offset.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        spread: Option<f64>,
     },
-    /// Phaser effect.
+    /// Phaser effect: allpass stages swept by an LFO, mixed with the dry
+    /// signal to cut moving notches.
     Phaser {
         /// LFO rate in Hz.
         rate: f64,
@@ -58,25 +74,36 @@ pub enum Effect {
         depth: f64,
         /// Number of allpass stages (2-12).
         stages: u8,
-        /// Wet/dry mix (0.0-1.0).
+        /// Feedback from the allpass chain output back into its input
+        /// (-0.95 to 0.95). None means no feedback.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        feedback: Option<f64>,
+        /// Wet/dry mix (0.0-1.0); 0.5 gives the deepest notches. Also accepted
+        /// as `mix`.
+        #[serde(alias = "mix")]
         wet: f64,
     },
-    /// Bitcrusher effect.
+    /// Bitcrusher effect. `bits: 16` with no rate reduction is a bypass.
+    #[serde(alias = "bitcrusher")]
     Bitcrush {
-        /// Bit depth (1-16).
+        /// Bit depth (1-16), quantizing to `2^bits` amplitude levels.
         bits: u8,
         /// Sample rate reduction factor (1.0 = no reduction).
         #[serde(default = "default_sr_reduction")]
         sample_rate_reduction: f64,
+        /// Integer decimation factor: each sample is held for this many frames (1 = none).
+        #[serde(default = "default_downsample")]
+        downsample: u32,
     },
-    /// Waveshaper distortion.
+    /// Waveshaper distortion, processed at 2x oversampling.
     Waveshaper {
-        /// Drive amount (1.0-100.0).
+        /// Drive amount (1.0-100.0). 0.0 bypasses the effect.
         drive: f64,
         /// Shaping curve.
         #[serde(default)]
         curve: WaveshaperCurve,
-        /// Wet/dry mix (0.0-1.0).
+        /// Wet/dry mix (0.0-1.0). Also accepted as `mix`.
+        #[serde(alias = "mix")]
         wet: f64,
     },
     /// Dynamics compressor.
@@ -132,6 +159,19 @@ pub enum Effect {
         /// Maximum attenuation depth in dB (-80 to 0).
         range_db: f64,
     },
+    /// Downward noise gate for cutting quiet tails (a gate/expander with an infinite ratio).
+    Gate {
+        /// Threshold in dB where gate opens (-60 to 0).
+        threshold_db: f64,
+        /// Attack time in ms to open gate (0.1-50).
+        attack_ms: f64,
+        /// Hold time in ms to stay open after signal drops (0-500).
+        hold_ms: f64,
+        /// Release time in ms to close gate (10-2000).
+        release_ms: f64,
+        /// Attenuation below threshold in dB (-80 to 0, 0 = bypass).
+        range_db: f64,
+    },
     /// Stereo widener effect for enhancing stereo image.
     StereoWidener {
         /// Stereo width (0.0 = mono, 1.0 = normal, >1.0 = wider). Range: 0.0-2.0.
@@ -202,10 +242,14 @@ pub enum Effect {
     },
     /// Ring modulator effect that multiplies audio with a carrier oscillator.
     RingModulator {
-        /// Carrier oscillator frequency in Hz (20-2000 typical).
+        /// Carrier oscillator frequency in Hz (20-2000 typical). Also accepted as `carrier_freq`.
+        #[serde(alias = "carrier_freq")]
         frequency: f64,
         /// Wet/dry mix (0.0-1.0).
         mix: f64,
+        /// Carrier waveform. None is a sine carrier; `pulse` uses a 50% duty cycle.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        waveform: Option<Waveform>,
     },
     /// Granular delay effect for shimmer and pitchy delays.
     GranularDelay {
@@ -230,9 +274,86 @@ pub enum Effect {
         /// Release time in ms for gain recovery (10-500).
         release_ms: f64,
     },
+    /// Phase-vocoder pitch shifter that keeps the original duration.
+    PitchShift {
+        /// Pitch shift in semitones (-24 to +24).
+        semitones: f64,
+    },
+    /// Phase-vocoder spectral freeze.
+    ///
+    /// Captures the spectrum at `at_seconds` and sustains it for the rest of the
+    /// buffer. Audio before the capture point passes through unchanged.
+    Freeze {
+        /// Capture time in seconds (>= 0). Clamped to the end of the buffer.
+        at_seconds: f64,
+    },
+    /// Convolution reverb using a named impulse response from the built-in library.
+    ConvolutionReverb {
+        /// Impulse response to convolve with.
+        impulse: ReverbImpulse,
+        /// Wet level (0.0-1.0).
+        wet: f64,
+        /// Dry level (0.0-1.0). Defaults to `1.0 - wet`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        dry: Option<f64>,
+    },
+    /// Tremolo: LFO-driven amplitude modulation of both channels.
+    Tremolo {
+        /// LFO rate in Hz (0.1-20.0).
+        rate: f64,
+        /// Modulation depth (0.0-1.0). At 1.0 the level dips to silence; 0.0 bypasses.
+        depth: f64,
+        /// LFO waveform. None is a sine LFO.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        shape: Option<Waveform>,
+    },
+    /// Auto-pan: sine LFO that sweeps the stereo balance (mono input becomes stereo).
+    AutoPan {
+        /// LFO rate in Hz (0.1-20.0).
+        rate: f64,
+        /// Pan swing (0.0-1.0). At 1.0 the signal moves fully left and right; 0.0 bypasses.
+        depth: f64,
+        /// Initial LFO phase in cycles (0.0-1.0). 0.0 starts centered and moving right.
+        #[serde(default)]
+        phase: f64,
+    },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+/// Named impulse responses available to the convolution reverb.
+///
+/// Each response is generated procedurally from a fixed seed, so it is the same
+/// for every spec and every run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReverbImpulse {
+    /// Small, damped room with a short tail (~0.4s).
+    #[default]
+    SmallRoom,
+    /// Concert hall with sparse early reflections and a long tail (~2.4s).
+    Hall,
+    /// Bright, dense plate with no pre-delay (~1.6s).
+    Plate,
+}
+
+impl ReverbImpulse {
+    /// All named impulse responses.
+    pub const ALL: [ReverbImpulse; 3] = [
+        ReverbImpulse::SmallRoom,
+        ReverbImpulse::Hall,
+        ReverbImpulse::Plate,
+    ];
+
+    /// Name used in specs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReverbImpulse::SmallRoom => "small_room",
+            ReverbImpulse::Hall => "hall",
+            ReverbImpulse::Plate => "plate",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum WaveshaperCurve {
     #[default]
@@ -240,10 +361,14 @@ pub enum WaveshaperCurve {
     SoftClip,
     HardClip,
     Sine,
+    /// Reflects the signal back off the +/-1.0 rails instead of clipping it.
+    Foldback,
+    /// Cubic saturation, smoother than soft_clip near full scale.
+    Cubic,
 }
 
 /// Cabinet type for cabinet simulation effect.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 pub enum CabinetType {
     /// Classic 1x12 combo amp (bright, focused).
     #[default]
@@ -264,7 +389,7 @@ pub enum CabinetType {
 }
 
 /// Processing mode for stereo widener effect.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum StereoWidenerMode {
     /// L/R crossmix: new_L = (1 + width) * L - width * R
@@ -285,12 +410,15 @@ fn default_chorus_voices() -> u8 {
 fn default_sr_reduction() -> f64 {
     1.0
 }
+fn default_downsample() -> u32 {
+    1
+}
 fn default_haas_delay_ms() -> f64 {
     10.0
 }
 
 /// A single tap in a multi-tap delay effect.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DelayTap {
     /// Delay time for this tap in milliseconds (1-2000).
@@ -307,7 +435,7 @@ pub struct DelayTap {
 }
 
 /// A single band in a parametric EQ.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EqBand {
     /// Center/corner frequency in Hz.
@@ -321,7 +449,7 @@ pub struct EqBand {
 }
 
 /// Type of EQ band for parametric EQ.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum EqBandType {
     /// Low shelf: boost/cut frequencies below the frequency.
@@ -214,6 +214,29 @@ fn test_gate_expander_from_json() {
     }
 }
 
+#[test]
+fn test_gate_serde_roundtrip() {
+    let effect = Effect::Gate {
+        threshold_db: -36.0,
+        attack_ms: 0.5,
+        hold_ms: 20.0,
+        release_ms: 80.0,
+        range_db: -48.0,
+    };
+
+    let json = serde_json::to_string(&effect).unwrap();
+    assert!(json.contains("\"type\":\"gate\""));
+    assert!(json.contains("\"threshold_db\":-36.0"));
+    assert!(json.contains("\"attack_ms\":0.5"));
+    assert!(json.contains("\"hold_ms\":20.0"));
+    assert!(json.contains("\"release_ms\":80.0"));
+    assert!(json.contains("\"range_db\":-48.0"));
+    assert!(!json.contains("ratio"));
+
+    let parsed: Effect = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, effect);
+}
+
 #[test]
 fn test_stereo_widener_serde_roundtrip() {
     let effect = Effect::StereoWidener {
@@ -700,12 +723,14 @@ fn test_ring_modulator_serde_roundtrip() {
     let effect = Effect::RingModulator {
         frequency: 150.0,
         mix: 0.8,
+        waveform: None,
     };
 
     let json = serde_json::to_string(&effect).unwrap();
     assert!(json.contains("\"type\":\"ring_modulator\""));
     assert!(json.contains("\"frequency\":150.0"));
     assert!(json.contains("\"mix\":0.8"));
+    assert!(!json.contains("waveform"));
 
     let parsed: Effect = serde_json::from_str(&json).unwrap();
     assert_eq!(parsed, effect);
@@ -721,14 +746,43 @@ fn test_ring_modulator_from_json() {
 
     let effect: Effect = serde_json::from_str(json).unwrap();
     match effect {
-        Effect::RingModulator { frequency, mix } => {
+        Effect::RingModulator {
+            frequency,
+            mix,
+            waveform,
+        } => {
             assert_eq!(frequency, 200.0);
             assert_eq!(mix, 0.5);
+            assert_eq!(waveform, None);
         }
         _ => panic!("Expected RingModulator variant"),
     }
 }
 
+#[test]
+fn test_ring_modulator_carrier_freq_alias_and_waveform() {
+    let json = r#"{
+        "type": "ring_modulator",
+        "carrier_freq": 100.0,
+        "waveform": "triangle",
+        "mix": 1.0
+    }"#;
+
+    let effect: Effect = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        effect,
+        Effect::RingModulator {
+            frequency: 100.0,
+            mix: 1.0,
+            waveform: Some(Waveform::Triangle),
+        }
+    );
+
+    let json = serde_json::to_string(&effect).unwrap();
+    assert!(json.contains("\"frequency\":100.0"));
+    assert!(json.contains("\"waveform\":\"triangle\""));
+}
+
 #[test]
 fn test_true_peak_limiter_serde_roundtrip() {
     let effect = Effect::TruePeakLimiter {
@@ -809,3 +863,171 @@ fn test_true_peak_limiter_broadcast_preset() {
         _ => panic!("Expected TruePeakLimiter variant"),
     }
 }
+
+#[test]
+fn test_pitch_shift_serde_roundtrip() {
+    let effect = Effect::PitchShift { semitones: 12.0 };
+
+    let json = serde_json::to_string(&effect).unwrap();
+    assert!(json.contains("\"type\":\"pitch_shift\""));
+    assert!(json.contains("\"semitones\":12.0"));
+
+    let parsed: Effect = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, effect);
+}
+
+#[test]
+fn test_freeze_from_json() {
+    let json = r#"{
+        "type": "freeze",
+        "at_seconds": 0.25
+    }"#;
+
+    let effect: Effect = serde_json::from_str(json).unwrap();
+    match effect {
+        Effect::Freeze { at_seconds } => {
+            assert_eq!(at_seconds, 0.25);
+        }
+        _ => panic!("Expected Freeze variant"),
+    }
+}
+
+#[test]
+fn test_convolution_reverb_serde_roundtrip() {
+    let json = r#"{"type":"convolution_reverb","impulse":"small_room","wet":0.4}"#;
+    let parsed: Effect = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        parsed,
+        Effect::ConvolutionReverb {
+            impulse: ReverbImpulse::SmallRoom,
+            wet: 0.4,
+            dry: None,
+        }
+    );
+
+    for impulse in ReverbImpulse::ALL {
+        let effect = Effect::ConvolutionReverb {
+            impulse,
+            wet: 0.3,
+            dry: Some(0.8),
+        };
+        let json = serde_json::to_string(&effect).unwrap();
+        assert!(json.contains(&format!("\"impulse\":\"{}\"", impulse.as_str())));
+        assert_eq!(serde_json::from_str::<Effect>(&json).unwrap(), effect);
+    }
+
+    let unknown = r#"{"type":"convolution_reverb","impulse":"cathedral","wet":0.4}"#;
+    assert!(serde_json::from_str::<Effect>(unknown).is_err());
+}
+
+#[test]
+fn test_reverb_dry_is_optional() {
+    let json = r#"{"type":"reverb","room_size":0.7,"damping":0.5,"wet":0.3}"#;
+    let parsed: Effect = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        parsed,
+        Effect::Reverb {
+            dry: None,
+            width,
+            ..
+        } if width == 1.0
+    ));
+    assert!(!serde_json::to_string(&parsed).unwrap().contains("dry"));
+
+    let effect = Effect::Reverb {
+        room_size: 0.7,
+        damping: 0.5,
+        wet: 0.3,
+        dry: Some(0.9),
+        width: 0.5,
+    };
+    let parsed: Effect = serde_json::from_str(&serde_json::to_string(&effect).unwrap()).unwrap();
+    assert_eq!(parsed, effect);
+}
+
+#[test]
+fn test_phaser_mix_alias_and_optional_feedback() {
+    let json = r#"{
+        "type": "phaser",
+        "rate": 0.5,
+        "depth": 0.7,
+        "stages": 6,
+        "feedback": 0.4,
+        "mix": 0.5
+    }"#;
+
+    let effect: Effect = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        effect,
+        Effect::Phaser {
+            rate: 0.5,
+            depth: 0.7,
+            stages: 6,
+            feedback: Some(0.4),
+            wet: 0.5,
+        }
+    );
+
+    // `feedback: None` is omitted on output
+    let effect = Effect::Phaser {
+        rate: 0.5,
+        depth: 0.7,
+        stages: 6,
+        feedback: None,
+        wet: 0.6,
+    };
+    let json = serde_json::to_string(&effect).unwrap();
+    assert!(!json.contains("feedback"));
+    let parsed: Effect = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, effect);
+}
+
+#[test]
+fn test_tremolo_serde_roundtrip() {
+    let effect = Effect::Tremolo {
+        rate: 5.0,
+        depth: 0.6,
+        shape: Some(Waveform::Triangle),
+    };
+
+    let json = serde_json::to_string(&effect).unwrap();
+    assert!(json.contains("\"type\":\"tremolo\""));
+    assert!(json.contains("\"shape\":\"triangle\""));
+
+    let parsed: Effect = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, effect);
+}
+
+#[test]
+fn test_tremolo_shape_defaults_to_none() {
+    let json = r#"{"type": "tremolo", "rate": 4.0, "depth": 0.5}"#;
+    let effect: Effect = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        effect,
+        Effect::Tremolo {
+            rate: 4.0,
+            depth: 0.5,
+            shape: None,
+        }
+    );
+    assert!(!serde_json::to_string(&effect).unwrap().contains("shape"));
+}
+
+#[test]
+fn test_auto_pan_serde_roundtrip() {
+    let json = r#"{"type": "auto_pan", "rate": 0.5, "depth": 1.0}"#;
+    let effect: Effect = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        effect,
+        Effect::AutoPan {
+            rate: 0.5,
+            depth: 1.0,
+            phase: 0.0,
+        }
+    );
+
+    let json = serde_json::to_string(&effect).unwrap();
+    assert!(json.contains("\"type\":\"auto_pan\""));
+    let parsed: Effect = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, effect);
+}
@@ -14,31 +14,36 @@
 pub mod effects;
 pub mod synthesis;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // Re-export synthesis types
 pub use synthesis::{
-    midi_to_frequency, parse_note_name, CombExcitation, DetuneCurve, Envelope, Filter,
-    FormantConfig, FormantVowel, FreqSweep, GranularSource, LfoConfig, LfoModulation, LoopConfig,
-    ModalExcitation, ModalMode, ModulationTarget, NoiseType, NoteSpec, OscillatorConfig,
-    PdWaveform, PitchEnvelope, PositionSweep, SpectralSource, SweepCurve, Synthesis,
-    VectorPathPoint, VectorSource, VectorSourceType, VocoderBand, VocoderBandSpacing,
-    VocoderCarrierType, Waveform, WavetableSource,
+    midi_to_frequency, parse_note_name, AntiAliasMode, Arpeggio, ArpeggioMode, ChannelMode,
+    CombExcitation, Compressor, CrossfadeShape, DetuneCurve, Envelope, EnvelopeCurve, EnvelopeV2,
+    Filter, FormantConfig, FormantVowel, FreqSweep, GainBreakpoint, GranularSource, LayerEnvelope,
+    LfoConfig, LfoModulation, Limiter, LoopConfig, ModalExcitation, ModalMode,
+    ModulationDestination, ModulationRoute, ModulationSource, ModulationTarget, NoiseType,
+    NoteSpec, OscillatorConfig, PdWaveform, PitchEnvelope, PositionSweep, Sidechain,
+    SpectralSource, SweepCurve, Synthesis, TailFade, VectorPathPoint, VectorSource,
+    VectorSourceType, VocoderBand, VocoderBandSpacing, VocoderCarrierType, Waveform,
+    WavetableSource,
 };
 
 // Re-export effect types
 pub use effects::{
-    CabinetType, DelayTap, Effect, EqBand, EqBandType, StereoWidenerMode, WaveshaperCurve,
+    CabinetType, DelayTap, Effect, EqBand, EqBandType, ReverbImpulse, StereoWidenerMode,
+    WaveshaperCurve,
 };
 
 /// A single synthesis layer in an audio recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AudioLayer {
     /// Synthesis parameters.
     pub synthesis: Synthesis,
-    /// ADSR envelope.
-    pub envelope: Envelope,
+    /// Amplitude envelope (ADSR or DAHDSR).
+    pub envelope: LayerEnvelope,
     /// Volume level (0.0 to 1.0).
     pub volume: f64,
     /// Stereo pan (-1.0 = left, 0.0 = center, 1.0 = right).
@@ -52,6 +57,29 @@ pub struct AudioLayer {
     /// Optional LFO modulation applied to this layer.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub lfo: Option<LfoModulation>,
+    /// Excludes this layer from the mix (default: false).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub muted: bool,
+    /// Solos this layer (default: false). When any layer is soloed, only
+    /// soloed layers are mixed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub solo: bool,
+    /// Optional gain automation breakpoints, sorted by time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gain_automation: Option<Vec<GainBreakpoint>>,
+    /// Modulation matrix routes (default: empty).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modulation_matrix: Vec<ModulationRoute>,
+    /// Optional arpeggiator stepping the oscillator pitch (oscillator layers only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arpeggio: Option<Arpeggio>,
+    /// Sample rate in Hz to synthesize this layer at before it is resampled to
+    /// the recipe `sample_rate` (default: the recipe rate).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub render_rate: Option<u32>,
+    /// Ducks this layer by the level of another layer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sidechain: Option<Sidechain>,
 }
 
 /// Parameters for the `audio_v1` unified audio recipe.
@@ -76,7 +104,7 @@ pub struct AudioLayer {
 /// - Crossfade is applied at loop boundaries for seamless looping
 ///
 /// The deprecated `generate_loop_points: true` is still supported and creates a default LoopConfig.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AudioV1Params {
     /// Base note this sample is tuned to (MIDI note or note name like "C4").
@@ -104,6 +132,9 @@ pub struct AudioV1Params {
     /// Optional master filter applied after mixing all layers.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub master_filter: Option<Filter>,
+    /// Optional compressor applied after mixing, before the master filter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master_compressor: Option<Compressor>,
     /// Effect chain applied after mixing all layers.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub effects: Vec<Effect>,
@@ -114,6 +145,18 @@ pub struct AudioV1Params {
     /// Each target may appear at most once.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub post_fx_lfos: Vec<LfoModulation>,
+    /// Optional fade-out of a still-ringing tail at the end of the audio.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tail_fade: Option<TailFade>,
+    /// Optional true-peak brick-wall limiter applied last, after normalization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master_limiter: Option<Limiter>,
+    /// Whether to remove DC offset with a one-pole high-pass after mixing and effects.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dc_blocker: bool,
+    /// Output channel layout (default: auto).
+    #[serde(default, skip_serializing_if = "ChannelMode::is_auto")]
+    pub channels: ChannelMode,
 }
 
 impl AudioV1Params {
@@ -157,17 +200,28 @@ mod tests {
                 freq_sweep: None,
                 detune: None,
                 duty: Some(0.5),
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
             envelope: Envelope {
                 attack: 0.02,
                 decay: 0.15,
                 sustain: 0.6,
                 release: 0.3,
-            },
+            }
+            .into(),
             volume: 0.75,
             pan: -0.5,
             delay: Some(0.25),
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         };
 
@@ -185,12 +239,22 @@ mod tests {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
-            envelope: Envelope::default(),
+            envelope: Envelope::default().into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         };
 
@@ -217,6 +281,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         assert_eq!(params.base_note, None);
@@ -237,12 +306,22 @@ mod tests {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: Some(0.1),
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -251,6 +330,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let json = serde_json::to_string(&params).unwrap();
@@ -273,6 +357,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let json = serde_json::to_string(&params).unwrap();
@@ -293,11 +382,18 @@ mod tests {
                     decay: 0.996,
                     blend: 0.7,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: Some(PitchEnvelope {
@@ -312,6 +408,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let json = serde_json::to_string(&params).unwrap();
@@ -337,9 +438,15 @@ mod tests {
                 cutoff: 2000.0,
                 resonance: 0.707,
                 cutoff_end: None,
+                keytrack: None,
             }),
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let json = serde_json::to_string(&params).unwrap();
@@ -365,17 +472,28 @@ mod tests {
                         }),
                         detune: None,
                         duty: None,
+                        anti_alias: None,
+                        detune_voices: None,
+                        detune_spread: None,
                     },
                     envelope: Envelope {
                         attack: 0.01,
                         decay: 0.2,
                         sustain: 0.6,
                         release: 0.3,
-                    },
+                    }
+                    .into(),
                     volume: 0.8,
                     pan: -0.3,
                     delay: None,
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 },
                 AudioLayer {
@@ -385,6 +503,7 @@ mod tests {
                             cutoff: 5000.0,
                             resonance: 0.707,
                             cutoff_end: Some(500.0),
+                            keytrack: None,
                         }),
                     },
                     envelope: Envelope {
@@ -392,11 +511,19 @@ mod tests {
                         decay: 0.05,
                         sustain: 0.0,
                         release: 0.1,
-                    },
+                    }
+                    .into(),
                     volume: 0.4,
                     pan: 0.0,
                     delay: Some(0.05),
                     filter: None,
+                    muted: false,
+                    solo: false,
+                    gain_automation: None,
+                    modulation_matrix: vec![],
+                    arpeggio: None,
+                    render_rate: None,
+                    sidechain: None,
                     lfo: None,
                 },
             ],
@@ -413,9 +540,15 @@ mod tests {
                 cutoff: 100.0,
                 resonance: 0.5,
                 cutoff_end: None,
+                keytrack: None,
             }),
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let json = serde_json::to_string_pretty(&params).unwrap();
@@ -455,11 +588,19 @@ mod tests {
                     decay: 0.2,
                     sustain: 0.7,
                     release: 0.3,
-                },
+                }
+                .into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -468,6 +609,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
 
         let json = serde_json::to_string_pretty(&params).unwrap();
@@ -484,6 +630,20 @@ mod tests {
         // Default values should be omitted except enabled
         assert!(json.contains("enabled"));
         assert!(!json.contains("crossfade_ms")); // Default 10.0 should be omitted
+        assert!(!json.contains("crossfade_shape")); // Default equal power should be omitted
+
+        // Test non-default crossfade shape
+        let config = LoopConfig {
+            crossfade_shape: CrossfadeShape::Linear,
+            ..LoopConfig::default()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""crossfade_shape":"linear""#));
+        let parsed: LoopConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.crossfade_shape, CrossfadeShape::Linear);
+        let parsed: LoopConfig =
+            serde_json::from_str(r#"{"crossfade_shape": "equal_power"}"#).unwrap();
+        assert_eq!(parsed.crossfade_shape, CrossfadeShape::EqualPower);
 
         // Test custom crossfade
         let config = LoopConfig::with_crossfade(25.0);
@@ -514,6 +674,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
         assert!(params.effective_loop_config().is_none());
 
@@ -539,6 +704,11 @@ mod tests {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         };
         let config = params.effective_loop_config().unwrap();
         assert!((config.crossfade_ms - 50.0).abs() < 0.001);
@@ -587,4 +757,65 @@ mod tests {
         let result: Result<AudioV1Params, _> = serde_json::from_str(json);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_layer_envelope_untagged_parse() {
+        let adsr: LayerEnvelope =
+            serde_json::from_str(r#"{"attack":0.01,"decay":0.1,"sustain":0.5,"release":0.2}"#)
+                .unwrap();
+        assert_eq!(adsr, LayerEnvelope::Adsr(Envelope::default()));
+
+        let dahdsr: LayerEnvelope = serde_json::from_str(
+            r#"{"delay":0.05,"attack":0.01,"hold":0.2,"decay":0.1,"sustain":0.5,"release":0.2}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            dahdsr,
+            LayerEnvelope::Dahdsr(EnvelopeV2 {
+                delay: 0.05,
+                attack: 0.01,
+                hold: 0.2,
+                decay: 0.1,
+                sustain: 0.5,
+                release: 0.2,
+                attack_curve: EnvelopeCurve::Linear,
+                decay_curve: EnvelopeCurve::Linear,
+                release_curve: EnvelopeCurve::Linear,
+            })
+        );
+
+        // ADSR serializes exactly as before, so existing specs hash the same
+        let json = serde_json::to_string(&LayerEnvelope::default()).unwrap();
+        assert_eq!(json, serde_json::to_string(&Envelope::default()).unwrap());
+
+        // Only `hold` set still selects DAHDSR
+        let hold_only: LayerEnvelope = serde_json::from_str(
+            r#"{"attack":0.0,"hold":0.1,"decay":0.1,"sustain":0.0,"release":0.0}"#,
+        )
+        .unwrap();
+        assert_eq!(hold_only.to_dahdsr().hold, 0.1);
+        assert_eq!(hold_only.to_dahdsr().delay, 0.0);
+
+        let unknown: Result<LayerEnvelope, _> = serde_json::from_str(
+            r#"{"attack":0.0,"decay":0.1,"sustain":0.0,"release":0.0,"curve":"exp"}"#,
+        );
+        assert!(unknown.is_err());
+    }
+
+    #[test]
+    fn test_layer_envelope_curves_parse() {
+        let env: LayerEnvelope = serde_json::from_str(
+            r#"{"attack":0.1,"decay":0.2,"sustain":0.5,"release":0.3,"attack_curve":{"power":0.5},"release_curve":"logarithmic"}"#,
+        )
+        .unwrap();
+        let dahdsr = env.to_dahdsr();
+        assert_eq!(dahdsr.attack_curve, EnvelopeCurve::Power(0.5));
+        assert_eq!(dahdsr.decay_curve, EnvelopeCurve::Linear);
+        assert_eq!(dahdsr.release_curve, EnvelopeCurve::Logarithmic);
+
+        // Linear curves are omitted on output
+        let json = serde_json::to_string(&env).unwrap();
+        assert!(!json.contains("decay_curve"));
+        assert!(json.contains(r#""attack_curve":{"power":0.5}"#));
+    }
 }
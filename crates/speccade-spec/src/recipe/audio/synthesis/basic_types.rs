@@ -1,9 +1,10 @@
 //! Basic synthesis types: waveforms, envelopes, filters, and note specifications.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Basic waveform types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Waveform {
     /// Sine wave.
@@ -18,8 +19,25 @@ pub enum Waveform {
     Pulse,
 }
 
+/// Anti-aliasing strategy for oscillator waveforms.
+///
+/// Only affects waveforms with discontinuities (square, pulse, sawtooth) and,
+/// for `Oversample`, the triangle wave. Sine waves are always alias-free.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AntiAliasMode {
+    /// Naive waveforms with hard discontinuities (aliases at high frequencies).
+    None,
+    /// PolyBLEP correction at each discontinuity.
+    #[default]
+    Polyblep,
+    /// PolyBLEP rendered at 4x the sample rate and decimated through a
+    /// windowed-sinc lowpass.
+    Oversample,
+}
+
 /// Frequency sweep parameters.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FreqSweep {
     /// Target frequency at end of sweep.
@@ -29,7 +47,7 @@ pub struct FreqSweep {
 }
 
 /// Sweep curve type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SweepCurve {
     /// Linear interpolation.
@@ -41,7 +59,7 @@ pub enum SweepCurve {
 }
 
 /// ADSR envelope parameters.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Envelope {
     /// Attack time in seconds.
@@ -65,8 +83,113 @@ impl Default for Envelope {
     }
 }
 
+/// Shape of a single envelope stage.
+///
+/// The curve maps the normalized stage progress `t` (0..1) before the level is
+/// interpolated between the stage's start and end values.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EnvelopeCurve {
+    /// Straight ramp.
+    #[default]
+    Linear,
+    /// Slow start, fast finish.
+    Exponential,
+    /// Fast start, slow finish.
+    Logarithmic,
+    /// `t^exponent`; exponents below 1 move early, above 1 move late.
+    Power(f32),
+}
+
+impl EnvelopeCurve {
+    /// Returns true for the default linear curve.
+    pub fn is_linear(&self) -> bool {
+        matches!(self, Self::Linear)
+    }
+}
+
+/// DAHDSR envelope parameters: ADSR with a leading delay and a hold stage.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EnvelopeV2 {
+    /// Silence before the attack starts, in seconds.
+    #[serde(default)]
+    pub delay: f64,
+    /// Attack time in seconds.
+    pub attack: f64,
+    /// Time held at peak level before the decay starts, in seconds.
+    #[serde(default)]
+    pub hold: f64,
+    /// Decay time in seconds.
+    pub decay: f64,
+    /// Sustain level (0.0 to 1.0).
+    pub sustain: f64,
+    /// Release time in seconds.
+    pub release: f64,
+    /// Curve of the attack stage.
+    #[serde(default, skip_serializing_if = "EnvelopeCurve::is_linear")]
+    pub attack_curve: EnvelopeCurve,
+    /// Curve of the decay stage.
+    #[serde(default, skip_serializing_if = "EnvelopeCurve::is_linear")]
+    pub decay_curve: EnvelopeCurve,
+    /// Curve of the release stage.
+    #[serde(default, skip_serializing_if = "EnvelopeCurve::is_linear")]
+    pub release_curve: EnvelopeCurve,
+}
+
+/// Amplitude envelope of an audio layer.
+///
+/// Plain ADSR objects keep parsing as [`Envelope`]; objects with `delay`,
+/// `hold` or a stage curve parse as [`EnvelopeV2`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum LayerEnvelope {
+    /// Classic four-stage ADSR.
+    Adsr(Envelope),
+    /// Six-stage DAHDSR.
+    Dahdsr(EnvelopeV2),
+}
+
+impl Default for LayerEnvelope {
+    fn default() -> Self {
+        Self::Adsr(Envelope::default())
+    }
+}
+
+impl From<Envelope> for LayerEnvelope {
+    fn from(env: Envelope) -> Self {
+        Self::Adsr(env)
+    }
+}
+
+impl From<EnvelopeV2> for LayerEnvelope {
+    fn from(env: EnvelopeV2) -> Self {
+        Self::Dahdsr(env)
+    }
+}
+
+impl LayerEnvelope {
+    /// Returns the envelope as DAHDSR (ADSR has zero delay and hold).
+    pub fn to_dahdsr(&self) -> EnvelopeV2 {
+        match self {
+            Self::Adsr(env) => EnvelopeV2 {
+                delay: 0.0,
+                attack: env.attack,
+                hold: 0.0,
+                decay: env.decay,
+                sustain: env.sustain,
+                release: env.release,
+                attack_curve: EnvelopeCurve::Linear,
+                decay_curve: EnvelopeCurve::Linear,
+                release_curve: EnvelopeCurve::Linear,
+            },
+            Self::Dahdsr(env) => env.clone(),
+        }
+    }
+}
+
 /// Pitch envelope for modulating frequency over time.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PitchEnvelope {
     /// Attack time in seconds.
@@ -94,7 +217,7 @@ impl Default for PitchEnvelope {
 }
 
 /// Configuration for a single oscillator in a multi-oscillator stack.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct OscillatorConfig {
     /// Waveform type.
@@ -118,7 +241,7 @@ fn default_oscillator_volume() -> f64 {
 }
 
 /// Noise type for noise-based synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum NoiseType {
     /// White noise (equal energy per frequency).
@@ -132,7 +255,7 @@ pub enum NoiseType {
 use super::FormantVowel;
 
 /// Filter configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum Filter {
     /// Low-pass filter.
@@ -144,6 +267,9 @@ pub enum Filter {
         /// Optional target cutoff frequency for sweep.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         cutoff_end: Option<f64>,
+        /// Cutoff keytracking amount (layer filters only; 0.0 = fixed, 1.0 = follows pitch).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keytrack: Option<f64>,
     },
     /// High-pass filter.
     Highpass {
@@ -154,6 +280,9 @@ pub enum Filter {
         /// Optional target cutoff frequency for sweep.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         cutoff_end: Option<f64>,
+        /// Cutoff keytracking amount (layer filters only; 0.0 = fixed, 1.0 = follows pitch).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keytrack: Option<f64>,
     },
     /// Band-pass filter.
     Bandpass {
@@ -164,6 +293,9 @@ pub enum Filter {
         /// Optional target center frequency for sweep.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         center_end: Option<f64>,
+        /// Center keytracking amount (layer filters only; 0.0 = fixed, 1.0 = follows pitch).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keytrack: Option<f64>,
     },
     /// Notch (band-reject) filter.
     Notch {
@@ -174,6 +306,9 @@ pub enum Filter {
         /// Optional target center frequency for sweep.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         center_end: Option<f64>,
+        /// Center keytracking amount (layer filters only; 0.0 = fixed, 1.0 = follows pitch).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keytrack: Option<f64>,
     },
     /// Allpass filter (phase shifting, no magnitude change).
     Allpass {
@@ -216,6 +351,9 @@ pub enum Filter {
         /// Optional target cutoff frequency for sweep.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         cutoff_end: Option<f64>,
+        /// Cutoff keytracking amount (layer filters only; 0.0 = fixed, 1.0 = follows pitch).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        keytrack: Option<f64>,
     },
     /// Low shelf filter (bass boost/cut).
     ///
@@ -239,8 +377,22 @@ pub enum Filter {
     },
 }
 
+impl Filter {
+    /// Returns the keytracking amount, or 0.0 for filters without keytracking.
+    pub fn keytrack(&self) -> f64 {
+        match self {
+            Filter::Lowpass { keytrack, .. }
+            | Filter::Highpass { keytrack, .. }
+            | Filter::Bandpass { keytrack, .. }
+            | Filter::Notch { keytrack, .. }
+            | Filter::Ladder { keytrack, .. } => keytrack.unwrap_or(0.0),
+            _ => 0.0,
+        }
+    }
+}
+
 /// Note specification - can be MIDI number or note name.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum NoteSpec {
     /// MIDI note number (0-127).
@@ -276,7 +428,7 @@ pub fn midi_to_frequency(midi_note: u8) -> f64 {
 ///
 /// When enabled, the audio generator will find optimal loop points and apply
 /// crossfading to eliminate clicks at loop boundaries.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LoopConfig {
     /// Whether looping is enabled.
@@ -295,6 +447,9 @@ pub struct LoopConfig {
         skip_serializing_if = "is_default_crossfade"
     )]
     pub crossfade_ms: f32,
+    /// Gain curve of the loop crossfade (default: equal power).
+    #[serde(default, skip_serializing_if = "is_default_crossfade_shape")]
+    pub crossfade_shape: CrossfadeShape,
     /// Snap loop points to nearest zero crossings (default: true).
     /// Helps eliminate discontinuity clicks even without crossfade.
     #[serde(default = "default_snap_to_zero_crossing")]
@@ -308,6 +463,23 @@ pub struct LoopConfig {
     pub zero_crossing_tolerance: u32,
 }
 
+/// Gain curve used when crossfading across a loop point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CrossfadeShape {
+    /// Linear gains (`1 - t` and `t`). Uncorrelated material dips about 3 dB
+    /// at the midpoint.
+    Linear,
+    /// Sine/cosine gains whose squares sum to one, keeping loudness steady
+    /// across the seam.
+    #[default]
+    EqualPower,
+}
+
+fn is_default_crossfade_shape(shape: &CrossfadeShape) -> bool {
+    *shape == CrossfadeShape::default()
+}
+
 fn default_loop_enabled() -> bool {
     true
 }
@@ -339,6 +511,7 @@ impl Default for LoopConfig {
             start_sample: None,
             end_sample: None,
             crossfade_ms: 10.0,
+            crossfade_shape: CrossfadeShape::EqualPower,
             snap_to_zero_crossing: true,
             zero_crossing_tolerance: 1000,
         }
@@ -377,6 +550,192 @@ impl LoopConfig {
     }
 }
 
+/// Tail fade-out applied to the end of the rendered audio.
+///
+/// Reverb and delay tails can still be ringing when the buffer ends, which
+/// leaves a hard cut (click) at the end of the file. When the final
+/// `fade_ms` of audio rise above `threshold_db`, they are faded to silence.
+/// Audio that already ends below the threshold is left unchanged.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TailFade {
+    /// Level in dBFS below which the tail counts as silent (default: -60).
+    #[serde(default = "default_tail_threshold_db")]
+    pub threshold_db: f64,
+    /// Fade-out duration in milliseconds (default: 10ms).
+    #[serde(default = "default_tail_fade_ms")]
+    pub fade_ms: f64,
+}
+
+fn default_tail_threshold_db() -> f64 {
+    -60.0
+}
+
+fn default_tail_fade_ms() -> f64 {
+    10.0
+}
+
+impl Default for TailFade {
+    fn default() -> Self {
+        Self {
+            threshold_db: default_tail_threshold_db(),
+            fade_ms: default_tail_fade_ms(),
+        }
+    }
+}
+
+/// Feed-forward peak compressor on the master bus.
+///
+/// Runs after mixing and before the master filter, so layered sounds can be
+/// tamed before they clip.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Compressor {
+    /// Threshold in dBFS (-60 to 0).
+    pub threshold_db: f64,
+    /// Compression ratio (1.0-20.0).
+    pub ratio: f64,
+    /// Attack time in ms (0.1-100).
+    pub attack_ms: f64,
+    /// Release time in ms (10-1000).
+    pub release_ms: f64,
+    /// Makeup gain in dB.
+    #[serde(default)]
+    pub makeup_db: f64,
+    /// Soft-knee width in dB centered on the threshold (0 = hard knee).
+    #[serde(default)]
+    pub knee_db: f64,
+}
+
+/// Brick-wall limiter on the master bus.
+///
+/// Runs last, after normalization and the tail fade, and holds the true
+/// (inter-sample) peak of the output at or below `ceiling_db`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Limiter {
+    /// Maximum true-peak output level in dBTP (-24 to 0, default: -1).
+    #[serde(default = "default_limiter_ceiling_db")]
+    pub ceiling_db: f64,
+    /// Lookahead in ms over which gain is ramped down before a peak (0.1-20, default: 5).
+    #[serde(default = "default_limiter_lookahead_ms")]
+    pub lookahead_ms: f64,
+}
+
+fn default_limiter_ceiling_db() -> f64 {
+    -1.0
+}
+
+fn default_limiter_lookahead_ms() -> f64 {
+    5.0
+}
+
+impl Default for Limiter {
+    fn default() -> Self {
+        Self {
+            ceiling_db: default_limiter_ceiling_db(),
+            lookahead_ms: default_limiter_lookahead_ms(),
+        }
+    }
+}
+
+/// Output channel layout for an audio recipe.
+///
+/// By default the output is stereo only when a layer or effect produces stereo
+/// content (non-center pan, stereo synthesis, or stereo effects).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelMode {
+    /// Mono or stereo depending on the mix content.
+    #[default]
+    Auto,
+    /// Always mono; stereo mixes are summed to mono.
+    ForceMono,
+    /// Always stereo; mono mixes are duplicated to both channels.
+    ForceStereo,
+}
+
+impl ChannelMode {
+    /// Returns true for the default `Auto` mode.
+    pub fn is_auto(&self) -> bool {
+        *self == ChannelMode::Auto
+    }
+}
+
+/// A breakpoint in a layer's gain automation curve.
+///
+/// Gain is linearly interpolated between breakpoints and held at the first and
+/// last breakpoint values outside their range.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GainBreakpoint {
+    /// Time in seconds, relative to the layer start (after `delay`).
+    pub time: f64,
+    /// Linear gain multiplier (0.0 = silent, 1.0 = unchanged).
+    pub gain: f64,
+}
+
+/// Arpeggiator that steps an oscillator layer through semitone offsets.
+///
+/// Steps advance at `rate_hz`, aligned to the layer start (after `delay`).
+/// With `gate < 1` every step retriggers the layer envelope and is silent for
+/// the final `1 - gate` of its length; with `gate = 1` the envelope spans the
+/// whole layer and only the pitch changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Arpeggio {
+    /// Semitone offsets from the layer frequency (at least one).
+    pub pattern: Vec<f64>,
+    /// Steps per second (must be positive).
+    pub rate_hz: f64,
+    /// Fraction of each step that sounds, in (0, 1] (default: 1.0).
+    #[serde(default = "default_arpeggio_gate")]
+    pub gate: f64,
+    /// Order in which pattern offsets are played (default: up).
+    #[serde(default)]
+    pub mode: ArpeggioMode,
+}
+
+fn default_arpeggio_gate() -> f64 {
+    1.0
+}
+
+/// Step order of an [`Arpeggio`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ArpeggioMode {
+    /// Lowest to highest offset, then repeat.
+    #[default]
+    Up,
+    /// Highest to lowest offset, then repeat.
+    Down,
+    /// Lowest to highest and back down, without repeating the turning points.
+    UpDown,
+    /// A seeded random offset for every step.
+    Random,
+}
+
+/// Sidechain ducking driven by another layer of the same recipe.
+///
+/// The level of `source_layer` (after its volume and gain automation, before
+/// mute/solo and before its own ducking) is followed with the attack and release
+/// times and turned into gain reduction on the layer this is attached to, like a
+/// compressor whose detector listens to the source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Sidechain {
+    /// Index of the layer whose level drives the ducking (not this layer).
+    pub source_layer: usize,
+    /// Source level in dB above which ducking starts (-60 to 0).
+    pub threshold_db: f64,
+    /// Reduction ratio (1-20).
+    pub ratio: f64,
+    /// Attack time in ms (0.1-100).
+    pub attack_ms: f64,
+    /// Release time in ms (10-1000).
+    pub release_ms: f64,
+}
+
 /// Parses a note name (e.g., "C4", "A#3", "Bb5") to a MIDI note number.
 pub fn parse_note_name(name: &str) -> Option<u8> {
     let name = name.trim();
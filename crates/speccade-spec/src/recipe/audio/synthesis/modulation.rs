@@ -1,11 +1,12 @@
 //! LFO and modulation types for synthesis.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::basic_types::Waveform;
 
 /// LFO (Low Frequency Oscillator) configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LfoConfig {
     /// Waveform type for the LFO.
@@ -20,7 +21,7 @@ pub struct LfoConfig {
 }
 
 /// Modulation target specification.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "target", rename_all = "snake_case", deny_unknown_fields)]
 pub enum ModulationTarget {
     /// Modulate pitch (vibrato).
@@ -84,6 +85,15 @@ pub enum ModulationTarget {
         /// The effective density is `clamp(base_density + bipolar_lfo * amount * depth, 1.0, 100.0)`.
         amount: f64,
     },
+    /// Modulate wavetable morph position.
+    ///
+    /// Only valid for `Synthesis::Wavetable`.
+    WavetablePosition {
+        /// Maximum position delta.
+        ///
+        /// The effective position is `clamp(base_position + bipolar_lfo * amount * depth, 0.0, 1.0)`.
+        amount: f64,
+    },
     /// Modulate delay time (post-FX only).
     ///
     /// Only valid in `AudioV1Params.post_fx_lfos`, not in `AudioLayer.lfo`.
@@ -117,7 +127,7 @@ pub enum ModulationTarget {
 }
 
 /// LFO modulation configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LfoModulation {
     /// LFO configuration.
@@ -125,3 +135,59 @@ pub struct LfoModulation {
     /// Modulation target.
     pub target: ModulationTarget,
 }
+
+/// Source signal for a modulation matrix route.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ModulationSource {
+    /// Low-frequency oscillator. Produces a bipolar value in [-1.0, 1.0].
+    Lfo {
+        /// Waveform type for the LFO.
+        waveform: Waveform,
+        /// LFO rate in Hz.
+        rate: f64,
+        /// Initial phase offset (0.0-1.0, optional).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        phase: Option<f64>,
+    },
+    /// ADSR envelope over the layer duration. Produces a unipolar value in [0.0, 1.0].
+    Envelope {
+        /// Attack time in seconds.
+        attack: f64,
+        /// Decay time in seconds.
+        decay: f64,
+        /// Sustain level (0.0-1.0).
+        sustain: f64,
+        /// Release time in seconds.
+        release: f64,
+    },
+}
+
+/// Destination parameter for a modulation matrix route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ModulationDestination {
+    /// Pitch; depth is in semitones.
+    Pitch,
+    /// Amplitude; depth is a linear gain offset (gain = `1 + value * depth`, floored at 0).
+    Amplitude,
+    /// Layer filter cutoff; depth is in Hz. Requires `layers[].filter`.
+    FilterCutoff,
+    /// Stereo pan; depth is a pan offset around the base `layer.pan`.
+    Pan,
+}
+
+/// A single modulation matrix route.
+///
+/// Routes targeting the same destination are summed per sample before being
+/// applied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ModulationRoute {
+    /// Modulation source.
+    pub source: ModulationSource,
+    /// Modulated parameter.
+    pub destination: ModulationDestination,
+    /// Route depth in destination units (may be negative to invert the source).
+    pub depth: f64,
+}
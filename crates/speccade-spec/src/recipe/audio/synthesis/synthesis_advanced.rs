@@ -1,11 +1,12 @@
 //! Advanced synthesis types: Phase Distortion, Modal, Vocoder, Formant, Vector, and Supersaw/Unison synthesis.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Detune distribution curve for unison synthesis.
 ///
 /// Controls how detune is distributed across voices in supersaw/unison synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum DetuneCurve {
     /// Linear detune distribution.
@@ -21,7 +22,7 @@ pub enum DetuneCurve {
 ///
 /// Different distortion curves produce different timbral characteristics
 /// in Phase Distortion synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PdWaveform {
     /// Resonant-like tone using power curve distortion.
@@ -39,7 +40,7 @@ pub enum PdWaveform {
 ///
 /// Modal synthesis simulates physical objects by modeling their resonant modes.
 /// Each mode represents a frequency at which the object naturally vibrates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ModalMode {
     /// Frequency ratio relative to the fundamental (1.0 = fundamental).
@@ -54,7 +55,7 @@ pub struct ModalMode {
 ///
 /// The excitation determines how the resonant modes are initially excited,
 /// affecting the attack character of the sound.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ModalExcitation {
     /// Single impulse excitation (sharp attack, like striking with a hard mallet).
@@ -66,7 +67,7 @@ pub enum ModalExcitation {
 }
 
 /// Band spacing mode for vocoder filter bank.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum VocoderBandSpacing {
     /// Linear spacing between bands (equal Hz between centers).
@@ -76,7 +77,7 @@ pub enum VocoderBandSpacing {
 }
 
 /// Carrier waveform type for vocoder synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum VocoderCarrierType {
     /// Sawtooth wave - rich in harmonics, classic vocoder sound.
@@ -88,7 +89,7 @@ pub enum VocoderCarrierType {
 }
 
 /// A single vocoder band configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct VocoderBand {
     /// Center frequency of the band in Hz.
@@ -102,7 +103,7 @@ pub struct VocoderBand {
 }
 
 /// Configuration for a single formant in formant synthesis.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FormantConfig {
     /// Center frequency of the formant in Hz.
@@ -114,7 +115,7 @@ pub struct FormantConfig {
 }
 
 /// Vowel preset for formant synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum FormantVowel {
     /// /a/ (ah) as in "father".
@@ -130,7 +131,7 @@ pub enum FormantVowel {
 }
 
 /// Source waveform type for vector synthesis corners.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum VectorSourceType {
     /// Sine wave.
@@ -148,7 +149,7 @@ pub enum VectorSourceType {
 }
 
 /// A single source in the vector synthesis grid.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct VectorSource {
     /// Type of waveform for this source.
@@ -163,7 +164,7 @@ fn default_freq_ratio() -> f64 {
 }
 
 /// A point in a vector path animation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct VectorPathPoint {
     /// X position (0.0-1.0).
@@ -1,11 +1,12 @@
 //! Core synthesis types and simpler synthesis variants.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::basic_types::{Filter, FreqSweep, NoiseType, OscillatorConfig, Waveform};
+use super::basic_types::{AntiAliasMode, Filter, FreqSweep, NoiseType, OscillatorConfig, Waveform};
 
 /// Synthesis type configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum Synthesis {
     /// FM synthesis.
@@ -86,6 +87,15 @@ pub enum Synthesis {
         /// Duty cycle for square/pulse waves (0.0 to 1.0, default 0.5).
         #[serde(default, skip_serializing_if = "Option::is_none")]
         duty: Option<f64>,
+        /// Anti-aliasing strategy (default: polyblep).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        anti_alias: Option<AntiAliasMode>,
+        /// Number of detuned unison voices (1-16, default 1).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        detune_voices: Option<u8>,
+        /// Detune of the outermost unison voices in cents (default 0.0).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        detune_spread: Option<f64>,
     },
     /// Multi-oscillator stack (subtractive synthesis).
     MultiOscillator {
@@ -135,6 +145,11 @@ pub enum Synthesis {
     Wavetable {
         /// Wavetable source.
         table: WavetableSource,
+        /// Custom single-cycle frames, morphed in order by `position`.
+        ///
+        /// When non-empty, these replace the built-in `table` frames.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tables: Vec<Vec<f64>>,
         /// Base frequency in Hz.
         frequency: f64,
         /// Position in wavetable (0.0-1.0).
@@ -310,6 +325,12 @@ pub enum Synthesis {
         bow_position: f64,
         /// String damping / high-frequency absorption (0.0-1.0).
         damping: f64,
+        /// Bow velocity (0.0-1.0, default: 0.2).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bow_velocity: Option<f64>,
+        /// Optional frequency sweep.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        freq_sweep: Option<FreqSweep>,
     },
     /// Membrane drum synthesis for toms, hand drums, congas, bongos, etc.
     ///
@@ -404,10 +425,63 @@ pub enum Synthesis {
         /// Source material for spectral capture.
         source: SpectralSource,
     },
+    /// Transient synthesis for short percussive clicks and attacks.
+    ///
+    /// Produces a deterministic impulse made of a decaying pitched body and a
+    /// seeded noise burst, shaped afterwards by the layer envelope. Useful as an
+    /// attack layer on top of drums, plucks, and UI sounds.
+    Transient {
+        /// Sharpness (0.0-1.0). Higher values decay faster, concentrating energy at the onset.
+        sharpness: f64,
+        /// Pitch of the tonal body in Hz.
+        pitch: f64,
+        /// Noise mix (0.0-1.0). 0.0 is fully tonal, 1.0 is pure noise.
+        noise_mix: f64,
+    },
+}
+
+impl Synthesis {
+    /// Returns the nominal pitch of the synthesis in Hz, if it has one.
+    ///
+    /// Sweeps, detune, and unison spread are ignored; this is the frequency the
+    /// spec was written at. Noise-like types (noise burst, granular, spectral
+    /// freeze) have no pitch and return `None`.
+    pub fn fundamental_frequency(&self) -> Option<f64> {
+        match self {
+            Synthesis::FmSynth { carrier_freq, .. }
+            | Synthesis::AmSynth { carrier_freq, .. }
+            | Synthesis::RingModSynth { carrier_freq, .. }
+            | Synthesis::Vocoder { carrier_freq, .. } => Some(*carrier_freq),
+            Synthesis::Additive { base_freq, .. } | Synthesis::Metallic { base_freq, .. } => {
+                Some(*base_freq)
+            }
+            Synthesis::PitchedBody { start_freq, .. } => Some(*start_freq),
+            Synthesis::Transient { pitch, .. } => Some(*pitch),
+            Synthesis::KarplusStrong { frequency, .. }
+            | Synthesis::Oscillator { frequency, .. }
+            | Synthesis::MultiOscillator { frequency, .. }
+            | Synthesis::Wavetable { frequency, .. }
+            | Synthesis::PdSynth { frequency, .. }
+            | Synthesis::Modal { frequency, .. }
+            | Synthesis::Formant { frequency, .. }
+            | Synthesis::Vector { frequency, .. }
+            | Synthesis::SupersawUnison { frequency, .. }
+            | Synthesis::Waveguide { frequency, .. }
+            | Synthesis::BowedString { frequency, .. }
+            | Synthesis::MembraneDrum { frequency, .. }
+            | Synthesis::FeedbackFm { frequency, .. }
+            | Synthesis::CombFilterSynth { frequency, .. }
+            | Synthesis::Pulsar { frequency, .. }
+            | Synthesis::Vosim { frequency, .. } => Some(*frequency),
+            Synthesis::NoiseBurst { .. }
+            | Synthesis::Granular { .. }
+            | Synthesis::SpectralFreeze { .. } => None,
+        }
+    }
 }
 
 /// Granular synthesis source material.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum GranularSource {
     /// Noise-based grains.
@@ -419,7 +493,7 @@ pub enum GranularSource {
 }
 
 /// Spectral synthesis source material.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum SpectralSource {
     /// Noise-based spectral content.
@@ -429,7 +503,7 @@ pub enum SpectralSource {
 }
 
 /// Wavetable source for wavetable synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum WavetableSource {
     /// sine -> saw -> square -> pulse morphing
@@ -447,7 +521,7 @@ pub enum WavetableSource {
 }
 
 /// Position sweep for wavetable synthesis.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PositionSweep {
     /// Target position at end of sweep (0.0-1.0).
@@ -470,7 +544,7 @@ fn default_vector_position() -> f64 {
 }
 
 /// Excitation type for comb filter synthesis.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CombExcitation {
     /// Single impulse excitation (sharp attack).
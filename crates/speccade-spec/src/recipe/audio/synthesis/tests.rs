@@ -86,6 +86,7 @@ fn test_filter_lowpass() {
         cutoff: 2000.0,
         resonance: 0.707,
         cutoff_end: None,
+        keytrack: None,
     };
 
     let json = serde_json::to_string(&filter).unwrap();
@@ -100,6 +101,7 @@ fn test_filter_highpass() {
         cutoff: 500.0,
         resonance: 0.5,
         cutoff_end: Some(2000.0),
+        keytrack: None,
     };
 
     let json = serde_json::to_string(&filter).unwrap();
@@ -114,6 +116,7 @@ fn test_filter_bandpass() {
         center: 1000.0,
         resonance: 2.0,
         center_end: None,
+        keytrack: None,
     };
 
     let json = serde_json::to_string(&filter).unwrap();
@@ -129,6 +132,7 @@ fn test_filter_bandpass_with_sweep() {
         center: 500.0,
         resonance: 1.5,
         center_end: Some(2000.0),
+        keytrack: None,
     };
 
     let json = serde_json::to_string(&filter).unwrap();
@@ -144,6 +148,7 @@ fn test_filter_notch() {
         center: 1000.0,
         resonance: 2.0,
         center_end: None,
+        keytrack: None,
     };
 
     let json = serde_json::to_string(&filter).unwrap();
@@ -159,6 +164,7 @@ fn test_filter_notch_with_sweep() {
         center: 500.0,
         resonance: 1.5,
         center_end: Some(2000.0),
+        keytrack: None,
     };
 
     let json = serde_json::to_string(&filter).unwrap();
@@ -210,6 +216,9 @@ fn test_synthesis_oscillator() {
         freq_sweep: None,
         detune: None,
         duty: None,
+        anti_alias: None,
+        detune_voices: None,
+        detune_spread: None,
     };
 
     let json = serde_json::to_string(&synth).unwrap();
@@ -218,6 +227,36 @@ fn test_synthesis_oscillator() {
     assert_eq!(parsed, synth);
 }
 
+#[test]
+fn test_synthesis_oscillator_anti_alias() {
+    let synth = Synthesis::Oscillator {
+        waveform: Waveform::Sawtooth,
+        frequency: 4000.0,
+        freq_sweep: None,
+        detune: None,
+        duty: None,
+        anti_alias: Some(AntiAliasMode::Oversample),
+        detune_voices: None,
+        detune_spread: None,
+    };
+
+    let json = serde_json::to_string(&synth).unwrap();
+    assert!(json.contains("\"anti_alias\":\"oversample\""));
+    let parsed: Synthesis = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, synth);
+
+    let json = r#"{"type":"oscillator","waveform":"square","frequency":440.0,"anti_alias":"none"}"#;
+    let parsed: Synthesis = serde_json::from_str(json).unwrap();
+    assert!(matches!(
+        parsed,
+        Synthesis::Oscillator {
+            anti_alias: Some(AntiAliasMode::None),
+            ..
+        }
+    ));
+    assert_eq!(AntiAliasMode::default(), AntiAliasMode::Polyblep);
+}
+
 #[test]
 fn test_synthesis_karplus_strong() {
     let synth = Synthesis::KarplusStrong {
@@ -240,6 +279,7 @@ fn test_synthesis_noise_burst() {
             cutoff: 1000.0,
             resonance: 0.5,
             cutoff_end: None,
+            keytrack: None,
         }),
     };
 
@@ -487,3 +527,48 @@ fn test_synthesis_supersaw_unison_default_curve() {
         _ => panic!("Expected SupersawUnison variant"),
     }
 }
+
+// ========================================================================
+// Arpeggio Tests
+// ========================================================================
+
+#[test]
+fn test_arpeggio_from_json_defaults() {
+    let json = r#"{"pattern": [0.0, 4.0, 7.0], "rate_hz": 8.0}"#;
+    let arp: Arpeggio = serde_json::from_str(json).unwrap();
+
+    assert_eq!(arp.pattern, vec![0.0, 4.0, 7.0]);
+    assert_eq!(arp.rate_hz, 8.0);
+    assert_eq!(arp.gate, 1.0);
+    assert_eq!(arp.mode, ArpeggioMode::Up);
+}
+
+#[test]
+fn test_arpeggio_serde_roundtrip() {
+    let arp = Arpeggio {
+        pattern: vec![0.0, 3.0, 7.0, 12.0],
+        rate_hz: 16.0,
+        gate: 0.5,
+        mode: ArpeggioMode::UpDown,
+    };
+
+    let json = serde_json::to_string(&arp).unwrap();
+    assert!(json.contains(r#""mode":"up_down""#));
+    let parsed: Arpeggio = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, arp);
+}
+
+#[test]
+fn test_sidechain_serde_roundtrip() {
+    let json = r#"{"source_layer": 0, "threshold_db": -30.0, "ratio": 8.0, "attack_ms": 1.0, "release_ms": 120.0}"#;
+    let sidechain: Sidechain = serde_json::from_str(json).unwrap();
+    assert_eq!(sidechain.source_layer, 0);
+    assert_eq!(sidechain.ratio, 8.0);
+
+    let parsed: Sidechain =
+        serde_json::from_str(&serde_json::to_string(&sidechain).unwrap()).unwrap();
+    assert_eq!(parsed, sidechain);
+
+    // The detector settings are required.
+    assert!(serde_json::from_str::<Sidechain>(r#"{"source_layer": 0}"#).is_err());
+}
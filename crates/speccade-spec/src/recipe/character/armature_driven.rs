@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::recipe::mesh::{MaterialSlot, MeshPrimitive};
@@ -13,7 +14,7 @@ use super::{SkeletalMeshConstraints, SkeletalMeshExportSettings, SkeletonBone, S
 // ============================================================================
 
 /// Connection mode for bone mesh boundaries.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ConnectionMode {
     /// No topological connection (current behavior) - mesh ends are independent.
@@ -33,7 +34,7 @@ impl Default for ConnectionMode {
 // ============================================================================
 
 /// A single extrusion step - either shorthand (just distance) or full definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ExtrusionStep {
     /// Shorthand: just extrusion distance as fraction of bone length.
@@ -43,7 +44,7 @@ pub enum ExtrusionStep {
 }
 
 /// Full extrusion step definition with all modifiers.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ExtrusionStepDef {
     /// Extrusion distance as fraction of bone length (required, must be > 0).
@@ -71,7 +72,7 @@ pub struct ExtrusionStepDef {
 }
 
 /// Scale can be uniform or per-axis [x, y].
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ScaleValue {
     /// Uniform scale factor.
@@ -81,7 +82,7 @@ pub enum ScaleValue {
 }
 
 /// Tilt can be single value (X only) or both axes [x, y].
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum TiltValue {
     /// Single axis tilt (X rotation in degrees).
@@ -91,7 +92,7 @@ pub enum TiltValue {
 }
 
 /// Bulge can be uniform or asymmetric [side, front_back].
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum StepBulgeValue {
     /// Uniform bulge multiplier.
@@ -101,7 +102,7 @@ pub enum StepBulgeValue {
 }
 
 /// Skinning mode for mesh-bone weight assignment.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SkinningMode {
     /// Automatic smooth skinning with distance-based weights (default).
@@ -116,7 +117,7 @@ pub enum SkinningMode {
 
 /// A bone mesh defined by composing shapes via boolean operations.
 /// Alternative to extrusion steps.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePart {
     /// Base shape.
@@ -135,7 +136,7 @@ pub struct BonePart {
 }
 
 /// Controls how a bone part's dimensions map to world units.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePartScale {
     /// Axes allowed to follow bone length.
@@ -152,7 +153,7 @@ pub struct BonePartScale {
     pub amount_from_z: Option<BonePartScaleAmountFromZ>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BonePartScaleAxis {
     X,
@@ -160,7 +161,7 @@ pub enum BonePartScaleAxis {
     Z,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePartScaleAmountFromZ {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -173,7 +174,7 @@ pub struct BonePartScaleAmountFromZ {
 
 /// A shape source for bone part composition.
 /// Disambiguated by unique key: `primitive`, `asset`, or `asset_ref`.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum BonePartShape {
     Primitive(BonePartPrimitive),
@@ -181,7 +182,7 @@ pub enum BonePartShape {
     AssetRef(BonePartAssetRef),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePartPrimitive {
     pub primitive: MeshPrimitive,
@@ -198,7 +199,7 @@ pub struct BonePartPrimitive {
     pub rotation: Option<[f64; 3]>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePartAsset {
     pub asset: String,
@@ -214,7 +215,7 @@ pub struct BonePartAsset {
     pub scale: Option<f64>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePartAssetRef {
     pub asset_ref: String,
@@ -231,7 +232,7 @@ pub struct BonePartAssetRef {
 }
 
 /// A boolean operation in a bone part composition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BonePartOperation {
     /// Boolean operation type.
@@ -242,7 +243,7 @@ pub struct BonePartOperation {
 }
 
 /// Boolean operation type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BonePartOpType {
     Union,
@@ -251,7 +252,7 @@ pub enum BonePartOpType {
 }
 
 /// Parameters for the `skeletal_mesh.armature_driven_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletalMeshArmatureDrivenV1Params {
     /// Predefined skeleton rig.
@@ -290,7 +291,7 @@ pub struct SkeletalMeshArmatureDrivenV1Params {
 /// Bone mesh definition.
 ///
 /// Supports either a concrete mesh definition or a mirror reference.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ArmatureDrivenBoneMeshDef {
     Mirror(ArmatureDrivenMirrorRef),
@@ -298,14 +299,14 @@ pub enum ArmatureDrivenBoneMeshDef {
 }
 
 /// Mirror reference (e.g. `{ "mirror": "arm_upper_L" }`).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenMirrorRef {
     pub mirror: String,
 }
 
 /// Mesh definition for a single bone.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenBoneMesh {
     /// Cross-section profile name (e.g. `circle(8)`, `hexagon(6)`).
@@ -364,7 +365,7 @@ pub struct ArmatureDrivenBoneMesh {
 }
 
 /// Length value in bone-relative units, elliptical units, or absolute units.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum BoneRelativeLength {
     /// Uniform radius, in bone-relative units.
@@ -378,7 +379,7 @@ pub enum BoneRelativeLength {
 /// Modifier entry.
 ///
 /// Matches YAML style like `- bevel: { width: 0.02, segments: 2 }`.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ArmatureDrivenModifier {
     Bevel {
@@ -393,20 +394,20 @@ pub enum ArmatureDrivenModifier {
     },
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenBevel {
     pub width: f64,
     pub segments: u8,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenSubdivide {
     pub cuts: u8,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenBoolean {
     pub operation: String,
@@ -414,7 +415,7 @@ pub struct ArmatureDrivenBoolean {
 }
 
 /// Attachment entry.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ArmatureDrivenAttachment {
     Primitive(ArmatureDrivenPrimitiveAttachment),
@@ -424,7 +425,7 @@ pub enum ArmatureDrivenAttachment {
     Asset(ArmatureDrivenAssetAttachment),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenPrimitiveAttachment {
     pub primitive: MeshPrimitive,
@@ -444,7 +445,7 @@ pub struct ArmatureDrivenPrimitiveAttachment {
     pub material_index: Option<u32>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenExtrudeAttachment {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -460,7 +461,7 @@ pub struct ArmatureDrivenExtrudeAttachment {
     pub taper: Option<f64>,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenAssetAttachment {
     pub asset: String,
@@ -476,14 +477,14 @@ pub struct ArmatureDrivenAssetAttachment {
 }
 
 /// Boolean shape definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ArmatureDrivenBoolShapeDef {
     Mirror(ArmatureDrivenMirrorRef),
     Shape(ArmatureDrivenBoolShape),
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArmatureDrivenBoolShape {
     pub primitive: MeshPrimitive,
@@ -1,11 +1,12 @@
 //! Modern body_parts array system.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::recipe::mesh::MeshPrimitive;
 
 /// Body part definition attached to a bone.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BodyPart {
     /// Name of the bone this part is attached to.
@@ -18,7 +19,7 @@ pub struct BodyPart {
 }
 
 /// Mesh configuration for a body part.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BodyPartMesh {
     /// Base primitive type.
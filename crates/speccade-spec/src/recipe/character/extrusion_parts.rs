@@ -1,9 +1,10 @@
 //! Extrusion-based parts system for skeletal mesh generation.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Extrusion part definition for procedural mesh generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ExtrusionPart {
     /// Associated bone name (optional for mirrored parts).
@@ -48,7 +49,7 @@ pub struct ExtrusionPart {
 }
 
 /// Base radius can be uniform (single value) or tapered ([bottom, top]).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum BaseRadius {
     /// Uniform radius.
@@ -64,7 +65,7 @@ impl Default for BaseRadius {
 }
 
 /// Skinning type for a part.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum SkinningType {
     /// Soft skinning with smooth weight blending.
@@ -75,7 +76,7 @@ pub enum SkinningType {
 }
 
 /// Sub-part for thumbs/fingers - can be a single dict or list of dicts.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum SubPartOrList {
     /// Single sub-part.
@@ -85,7 +86,7 @@ pub enum SubPartOrList {
 }
 
 /// Sub-part definition (for thumbs, fingers, etc.).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SubPart {
     /// Sub-part bone name.
@@ -115,7 +116,7 @@ pub struct SubPart {
 }
 
 /// An instance of a part at a specific position and rotation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Instance {
     /// Instance position [X, Y, Z].
@@ -132,7 +133,7 @@ pub struct Instance {
 
 /// A step in the extrusion process.
 /// Can be a string shorthand (e.g., "0.1") or a full step definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum Step {
     /// Shorthand: just an extrusion distance as string.
@@ -142,7 +143,7 @@ pub enum Step {
 }
 
 /// Full step definition with all possible transformations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StepDefinition {
     /// Extrusion distance.
@@ -166,7 +167,7 @@ pub struct StepDefinition {
 }
 
 /// Scale factor can be uniform or per-axis.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ScaleFactor {
     /// Uniform scale.
@@ -182,7 +183,7 @@ impl Default for ScaleFactor {
 }
 
 /// Bulge factor for asymmetric scaling.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum BulgeFactor {
     /// Uniform bulge.
@@ -192,7 +193,7 @@ pub enum BulgeFactor {
 }
 
 /// Tilt factor for rotation around X/Y axes.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum TiltFactor {
     /// Uniform tilt (applied to X axis).
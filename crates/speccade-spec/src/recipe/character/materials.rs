@@ -1,11 +1,12 @@
 //! Material slots and skinning settings.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::recipe::mesh::{MeshConstraints, MeshExportSettings};
 
 /// Skinning and weight painting settings.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkinningSettings {
     /// Maximum bone influences per vertex (1-8).
@@ -34,7 +35,7 @@ impl Default for SkinningSettings {
 }
 
 /// Export settings for skeletal meshes.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletalMeshExportSettings {
     /// Include armature in export.
@@ -85,7 +86,7 @@ impl From<SkeletalMeshExportSettings> for MeshExportSettings {
 }
 
 /// Constraints for skeletal meshes.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletalMeshConstraints {
     /// Maximum triangle count.
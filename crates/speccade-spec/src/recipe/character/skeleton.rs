@@ -1,9 +1,10 @@
 //! Skeleton types and presets.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Predefined skeleton rigs.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SkeletonPreset {
     /// Basic humanoid skeleton with 20 bones (no fingers).
@@ -184,7 +185,7 @@ impl SkeletonPreset {
 }
 
 /// A bone in a custom skeleton definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletonBone {
     /// Unique bone name.
@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::recipe::mesh::MaterialSlot;
@@ -9,7 +10,7 @@ use crate::recipe::mesh::MaterialSlot;
 use super::{SkeletalMeshConstraints, SkeletalMeshExportSettings, SkeletonBone, SkeletonPreset};
 
 /// Parameters for the `skeletal_mesh.skinned_mesh_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkeletalMeshSkinnedMeshV1Params {
     /// External mesh file path.
@@ -45,7 +46,7 @@ pub struct SkeletalMeshSkinnedMeshV1Params {
 }
 
 /// Mesh-to-armature binding configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SkinnedMeshBindingConfig {
     pub mode: SkinnedMeshBindingMode,
@@ -64,7 +65,7 @@ fn default_max_bone_influences() -> u8 {
 }
 
 /// Binding mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SkinnedMeshBindingMode {
     /// Each vertex belongs 100% to one bone (vertex groups).
@@ -2,10 +2,11 @@
 
 use std::collections::HashMap;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Texturing configuration for UV unwrapping and material regions.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Texturing {
     /// UV unwrapping mode.
@@ -17,7 +18,7 @@ pub struct Texturing {
 }
 
 /// UV unwrapping mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum UvMode {
     /// Smart UV project (automatic island detection).
@@ -36,7 +37,7 @@ pub enum UvMode {
 }
 
 /// A texture region definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureRegion {
     /// Parts included in this region.
@@ -54,7 +55,7 @@ pub struct TextureRegion {
 }
 
 /// Color specification for a region.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum RegionColor {
     /// Hex color string (e.g., "#FF0000").
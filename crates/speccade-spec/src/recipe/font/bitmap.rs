@@ -3,13 +3,14 @@
 //! Renders ASCII characters as pixel bitmaps, packs into an atlas,
 //! and outputs glyph metrics JSON for runtime text rendering.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `font.bitmap_v1` recipe.
 ///
 /// Renders glyphs using hardcoded pixel patterns (5x7, 8x8, etc.),
 /// packs them into an atlas, and outputs glyph metrics.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FontBitmapV1Params {
     /// Character set to include (ASCII range).
@@ -42,7 +43,7 @@ fn default_color() -> [f64; 4] {
 }
 
 /// Font style (monospace or proportional spacing).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum FontStyle {
     /// Monospace: all glyphs have the same advance width.
@@ -53,7 +54,7 @@ pub enum FontStyle {
 }
 
 /// Metadata for a single glyph in the atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct GlyphMetadata {
     /// Character code (e.g., 65 for 'A').
     pub char_code: u32,
@@ -81,7 +82,7 @@ pub struct GlyphMetadata {
 }
 
 /// Metadata output for a bitmap font atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct FontBitmapMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
@@ -4,6 +4,7 @@
 //! hard-surface meshes using boolean operations (union, difference, intersect)
 //! with cleanup for artifact removal using Blender as a Tier 2 backend.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::MeshExportSettings;
@@ -19,7 +20,7 @@ pub const DEFAULT_MERGE_DISTANCE: f64 = 0.001;
 /// This recipe combines multiple mesh primitives or referenced meshes using
 /// boolean operations (union, difference, intersect) to create hard-surface
 /// models suitable for vehicles, buildings, mechanical parts, etc.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StaticMeshBooleanKitV1Params {
     /// The base mesh to apply operations to.
@@ -41,7 +42,7 @@ pub struct StaticMeshBooleanKitV1Params {
 }
 
 /// Source for a mesh in boolean operations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum MeshSource {
     /// A primitive mesh with optional transform.
@@ -51,7 +52,7 @@ pub enum MeshSource {
 }
 
 /// A primitive mesh with optional transform.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PrimitiveMesh {
     /// The primitive type (cube, sphere, cylinder, cone, torus, plane, ico_sphere).
@@ -87,7 +88,7 @@ fn is_zero_array(arr: &[f64; 3]) -> bool {
 }
 
 /// Reference to an external mesh asset.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MeshReference {
     /// Asset ID of the referenced mesh.
@@ -104,7 +105,7 @@ pub struct MeshReference {
 }
 
 /// A boolean operation to apply.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BooleanOperation {
     /// The type of boolean operation.
@@ -114,7 +115,7 @@ pub struct BooleanOperation {
 }
 
 /// Type of boolean operation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BooleanOperationType {
     /// Union: combine meshes, keeping all geometry.
@@ -126,7 +127,7 @@ pub enum BooleanOperationType {
 }
 
 /// Boolean solver selection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BooleanSolver {
     /// Exact solver: slower but more reliable for complex geometry.
@@ -144,7 +145,7 @@ impl BooleanSolver {
 }
 
 /// Post-boolean cleanup settings.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BooleanCleanup {
     /// Distance threshold for merging duplicate vertices.
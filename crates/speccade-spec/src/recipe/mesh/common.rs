@@ -1,9 +1,10 @@
 //! Common/shared mesh types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Normals automation preset.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum NormalsPreset {
     /// Auto-smooth normals based on angle threshold.
@@ -19,7 +20,7 @@ pub enum NormalsPreset {
 }
 
 /// Normals generation settings.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NormalsSettings {
     /// Normals preset to apply.
@@ -33,7 +34,7 @@ pub struct NormalsSettings {
 }
 
 /// Material slot definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MaterialSlot {
     /// Material name.
@@ -56,7 +57,7 @@ pub struct MaterialSlot {
 }
 
 /// GLB/glTF export settings.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MeshExportSettings {
     /// Apply all modifiers before export.
@@ -87,7 +88,7 @@ pub(crate) fn default_true() -> bool {
 }
 
 /// Type of bake map to generate.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BakeType {
     /// Normal map (tangent-space by default).
@@ -104,7 +105,7 @@ pub enum BakeType {
 ///
 /// Used to bake normal maps, AO, curvature, etc. from a high-poly source
 /// onto the UVs of a low-poly target mesh.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BakingSettings {
     /// Types of maps to bake.
@@ -166,7 +167,7 @@ impl Default for MeshExportSettings {
 }
 
 /// Mesh constraints for validation.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MeshConstraints {
     /// Maximum triangle count.
@@ -181,7 +182,7 @@ pub struct MeshConstraints {
 }
 
 /// Collision mesh type.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CollisionType {
     /// Convex hull collision (fast, wraps around the mesh).
@@ -194,7 +195,7 @@ pub enum CollisionType {
 }
 
 /// Collision mesh generation settings.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CollisionMeshSettings {
     /// Type of collision mesh to generate.
@@ -227,7 +228,7 @@ impl Default for CollisionMeshSettings {
 /// These settings control how mesh geometry is analyzed for walkability,
 /// producing metadata about walkable surfaces and potential stair geometry.
 /// Note: This produces classification metadata only, not actual navmesh generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NavmeshSettings {
     /// Maximum slope angle in degrees for a surface to be considered walkable.
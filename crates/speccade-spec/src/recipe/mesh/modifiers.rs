@@ -1,9 +1,10 @@
 //! Mesh modifiers and transforms.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Blender modifier.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum MeshModifier {
     /// Bevel modifier.
@@ -73,7 +74,7 @@ pub enum MeshModifier {
 }
 
 /// UV projection method.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged, deny_unknown_fields)]
 pub enum UvProjection {
     /// Simple projection method (backwards compatible).
@@ -103,7 +104,7 @@ pub enum UvProjection {
 }
 
 /// UV projection method.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum UvProjectionMethod {
     /// Box/cube projection.
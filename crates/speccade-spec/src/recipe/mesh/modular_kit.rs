@@ -4,6 +4,7 @@
 //! modular architectural and mechanical components (walls, doors, pipes) using
 //! Blender as a Tier 2 backend.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::MeshExportSettings;
@@ -15,7 +16,7 @@ pub const MAX_WALL_CUTOUTS: usize = 100;
 pub const MAX_PIPE_SEGMENTS: usize = 50;
 
 /// Parameters for the `static_mesh.modular_kit_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StaticMeshModularKitV1Params {
     /// Kit type to generate (wall, pipe, or door).
@@ -26,7 +27,7 @@ pub struct StaticMeshModularKitV1Params {
 }
 
 /// Kit type variants for modular mesh generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum ModularKitType {
     /// Wall section with optional door/window cutouts.
@@ -38,7 +39,7 @@ pub enum ModularKitType {
 }
 
 /// Parameters for wall kit generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WallKitParams {
     /// Wall width in units.
@@ -94,7 +95,7 @@ fn is_zero(v: &f64) -> bool {
 }
 
 /// A cutout in a wall (for doors or windows).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WallCutout {
     /// Cutout type (door or window).
@@ -127,7 +128,7 @@ fn is_default_frame_thickness(v: &f64) -> bool {
 }
 
 /// Type of wall cutout.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CutoutType {
     /// Door cutout (extends to floor).
@@ -137,7 +138,7 @@ pub enum CutoutType {
 }
 
 /// Parameters for pipe kit generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PipeKitParams {
     /// Outer diameter of the pipe.
@@ -178,7 +179,7 @@ fn is_default_pipe_vertices(v: &u32) -> bool {
 }
 
 /// A segment of a pipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum PipeSegment {
     /// Straight pipe section.
@@ -208,7 +209,7 @@ pub enum PipeSegment {
 }
 
 /// Parameters for door kit generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DoorKitParams {
     /// Door opening width.
@@ -263,7 +264,7 @@ fn is_default_panel_thickness(v: &f64) -> bool {
 }
 
 /// Side on which the door hinges.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum HingeSide {
     /// Door hinges on the left side.
@@ -4,6 +4,7 @@
 //! organic shapes using metaballs, remeshing, smoothing, and displacement noise
 //! in Blender (Tier 2 backend).
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::MeshExportSettings;
@@ -24,7 +25,7 @@ pub const MAX_REMESH_VOXEL_SIZE: f64 = 1.0;
 ///
 /// This recipe creates organic shapes using metaballs as the base,
 /// followed by voxel remeshing, smoothing, and optional displacement noise.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StaticMeshOrganicSculptV1Params {
     /// Metaball sources that define the organic shape.
@@ -47,7 +48,7 @@ pub struct StaticMeshOrganicSculptV1Params {
 ///
 /// Metaballs are implicit surfaces that blend together smoothly,
 /// useful for creating organic shapes like blobs, characters, or liquids.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MetaballSource {
     /// Position [X, Y, Z] in world coordinates.
@@ -73,7 +74,7 @@ fn is_default_stiffness(v: &f64) -> bool {
 }
 
 /// Displacement noise settings for adding surface detail.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DisplacementNoise {
     /// Displacement strength (positive value).
@@ -1,9 +1,10 @@
 //! Primitive mesh types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Base mesh primitives.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MeshPrimitive {
     /// Cube/box.
@@ -4,6 +4,7 @@
 //! one mesh onto another (e.g., armor onto body, clothing onto character)
 //! using Blender's shrinkwrap modifier (Tier 2 backend).
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::MeshExportSettings;
@@ -24,7 +25,7 @@ pub const DEFAULT_OFFSET: f64 = 0.0;
 pub const DEFAULT_SMOOTH_ITERATIONS: u8 = 0;
 
 /// Shrinkwrap mode (corresponds to Blender's shrinkwrap modifier modes).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ShrinkwrapMode {
     /// Snap to the nearest point on the target surface.
@@ -40,7 +41,7 @@ pub enum ShrinkwrapMode {
 }
 
 /// Validation settings for post-shrinkwrap mesh quality checks.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ShrinkwrapValidation {
     /// Maximum allowed self-intersections (0 = fail on any intersection).
@@ -80,7 +81,7 @@ impl Default for ShrinkwrapValidation {
 /// - Fitting armor plates onto character bodies
 /// - Draping cloth/clothing onto characters
 /// - Attaching accessories to curved surfaces
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StaticMeshShrinkwrapV1Params {
     /// Asset ID or path to the base/target mesh (the surface to wrap onto).
@@ -1,5 +1,6 @@
 //! Static mesh recipe definitions.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::{
@@ -10,7 +11,7 @@ use super::modifiers::{MeshModifier, UvProjection};
 use super::primitives::MeshPrimitive;
 
 /// Decimate method for LOD generation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum LodDecimateMethod {
     /// Collapse edges (default, best quality).
@@ -21,7 +22,7 @@ pub enum LodDecimateMethod {
 }
 
 /// A single LOD level specification.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LodLevel {
     /// LOD level index (0 = highest detail / original).
@@ -33,7 +34,7 @@ pub struct LodLevel {
 }
 
 /// LOD chain configuration for multi-LOD mesh export.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct LodChainSettings {
     /// List of LOD levels to generate.
@@ -48,7 +49,7 @@ fn is_default_decimate_method(method: &LodDecimateMethod) -> bool {
 }
 
 /// Parameters for the `static_mesh.blender_primitives_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct StaticMeshBlenderPrimitivesV1Params {
     /// Base Blender primitive.
@@ -98,7 +99,7 @@ pub struct StaticMeshBlenderPrimitivesV1Params {
 }
 
 /// A primitive attached to a base mesh at a specific position and rotation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MeshAttachment {
     /// Primitive type.
     pub primitive: MeshPrimitive,
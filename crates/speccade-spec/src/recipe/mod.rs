@@ -9,6 +9,7 @@ pub mod character;
 pub mod font;
 pub mod mesh;
 pub mod music;
+pub mod schema;
 pub mod sprite;
 pub mod texture;
 pub mod ui;
@@ -20,15 +21,17 @@ pub use character::*;
 pub use font::*;
 pub use mesh::*;
 pub use music::*;
+pub use schema::*;
 pub use sprite::*;
 pub use texture::*;
 pub use ui::*;
 pub use vfx::*;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Recipe kind identifier.
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RecipeKind {
     /// `audio_v1` - Unified audio synthesis.
@@ -58,6 +61,12 @@ pub enum RecipeKind {
     /// `texture.material_preset_v1` - Material preset for PBR textures with style presets.
     #[serde(rename = "texture.material_preset_v1")]
     TextureMaterialPresetV1,
+    /// `texture.composite_v1` - Layered texture that blends multiple procedural sub-graphs.
+    #[serde(rename = "texture.composite_v1")]
+    TextureCompositeV1,
+    /// `texture.tile_variation_v1` - Sheet of a repeated procedural tile with per-tile variation.
+    #[serde(rename = "texture.tile_variation_v1")]
+    TextureTileVariationV1,
     /// `static_mesh.blender_primitives_v1` - Static mesh from Blender primitives.
     #[serde(rename = "static_mesh.blender_primitives_v1")]
     StaticMeshBlenderPrimitivesV1,
@@ -133,6 +142,8 @@ impl RecipeKind {
             RecipeKind::TextureSplatSetV1,
             RecipeKind::TextureMatcapV1,
             RecipeKind::TextureMaterialPresetV1,
+            RecipeKind::TextureCompositeV1,
+            RecipeKind::TextureTileVariationV1,
             RecipeKind::StaticMeshBlenderPrimitivesV1,
             RecipeKind::StaticMeshModularKitV1,
             RecipeKind::StaticMeshOrganicSculptV1,
@@ -168,6 +179,8 @@ impl RecipeKind {
             RecipeKind::TextureSplatSetV1 => "texture.splat_set_v1",
             RecipeKind::TextureMatcapV1 => "texture.matcap_v1",
             RecipeKind::TextureMaterialPresetV1 => "texture.material_preset_v1",
+            RecipeKind::TextureCompositeV1 => "texture.composite_v1",
+            RecipeKind::TextureTileVariationV1 => "texture.tile_variation_v1",
             RecipeKind::StaticMeshBlenderPrimitivesV1 => "static_mesh.blender_primitives_v1",
             RecipeKind::StaticMeshModularKitV1 => "static_mesh.modular_kit_v1",
             RecipeKind::StaticMeshOrganicSculptV1 => "static_mesh.organic_sculpt_v1",
@@ -203,6 +216,8 @@ impl RecipeKind {
             RecipeKind::TextureSplatSetV1 => "texture",
             RecipeKind::TextureMatcapV1 => "texture",
             RecipeKind::TextureMaterialPresetV1 => "texture",
+            RecipeKind::TextureCompositeV1 => "texture",
+            RecipeKind::TextureTileVariationV1 => "texture",
             RecipeKind::StaticMeshBlenderPrimitivesV1 => "static_mesh",
             RecipeKind::StaticMeshModularKitV1 => "static_mesh",
             RecipeKind::StaticMeshOrganicSculptV1 => "static_mesh",
@@ -239,6 +254,8 @@ impl RecipeKind {
             | RecipeKind::TextureSplatSetV1
             | RecipeKind::TextureMatcapV1
             | RecipeKind::TextureMaterialPresetV1
+            | RecipeKind::TextureCompositeV1
+            | RecipeKind::TextureTileVariationV1
             | RecipeKind::SpriteSheetV1
             | RecipeKind::SpriteAnimationV1
             | RecipeKind::VfxFlipbookV1
@@ -270,7 +287,7 @@ impl std::fmt::Display for RecipeKind {
 }
 
 /// Recipe specification containing kind and params.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Recipe {
     /// The recipe kind identifier.
@@ -300,6 +317,8 @@ impl Recipe {
             "texture.splat_set_v1" => Some(RecipeKind::TextureSplatSetV1),
             "texture.matcap_v1" => Some(RecipeKind::TextureMatcapV1),
             "texture.material_preset_v1" => Some(RecipeKind::TextureMaterialPresetV1),
+            "texture.composite_v1" => Some(RecipeKind::TextureCompositeV1),
+            "texture.tile_variation_v1" => Some(RecipeKind::TextureTileVariationV1),
             "static_mesh.blender_primitives_v1" => Some(RecipeKind::StaticMeshBlenderPrimitivesV1),
             "static_mesh.modular_kit_v1" => Some(RecipeKind::StaticMeshModularKitV1),
             "static_mesh.organic_sculpt_v1" => Some(RecipeKind::StaticMeshOrganicSculptV1),
@@ -397,6 +416,18 @@ impl Recipe {
         serde_json::from_value(self.params.clone())
     }
 
+    /// Attempts to parse params as composite texture params.
+    pub fn as_texture_composite(&self) -> Result<TextureCompositeV1Params, serde_json::Error> {
+        serde_json::from_value(self.params.clone())
+    }
+
+    /// Attempts to parse params as tile variation texture params.
+    pub fn as_texture_tile_variation(
+        &self,
+    ) -> Result<TextureTileVariationV1Params, serde_json::Error> {
+        serde_json::from_value(self.params.clone())
+    }
+
     /// Attempts to parse params as static mesh Blender primitives params.
     pub fn as_static_mesh_blender_primitives(
         &self,
@@ -589,6 +620,19 @@ impl Recipe {
                         error_message: e.to_string(),
                     })?;
             }
+            "texture.composite_v1" => {
+                self.as_texture_composite().map_err(|e| RecipeParamsError {
+                    recipe_kind: self.kind.clone(),
+                    error_message: e.to_string(),
+                })?;
+            }
+            "texture.tile_variation_v1" => {
+                self.as_texture_tile_variation()
+                    .map_err(|e| RecipeParamsError {
+                        recipe_kind: self.kind.clone(),
+                        error_message: e.to_string(),
+                    })?;
+            }
             "static_mesh.blender_primitives_v1" => {
                 self.as_static_mesh_blender_primitives()
                     .map_err(|e| RecipeParamsError {
@@ -830,8 +874,10 @@ mod tests {
     #[test]
     fn test_recipe_kind_all_contains_every_variant() {
         let kinds = RecipeKind::all();
-        assert_eq!(kinds.len(), 29);
+        assert_eq!(kinds.len(), 31);
         assert!(kinds.contains(&RecipeKind::TextureMaterialPresetV1));
+        assert!(kinds.contains(&RecipeKind::TextureCompositeV1));
+        assert!(kinds.contains(&RecipeKind::TextureTileVariationV1));
         assert!(kinds.contains(&RecipeKind::StaticMeshShrinkwrapV1));
         assert!(kinds.contains(&RecipeKind::StaticMeshBooleanKitV1));
     }
@@ -1,9 +1,10 @@
 //! Song arrangement and sequence types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Entry in the song arrangement.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ArrangementEntry {
     /// Pattern name.
@@ -1,9 +1,10 @@
 //! Common music types: tracker format.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Tracker module format.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub enum TrackerFormat {
     /// FastTracker II Extended Module format.
@@ -11,6 +12,10 @@ pub enum TrackerFormat {
     Xm,
     /// Impulse Tracker format.
     It,
+    /// ProTracker MOD format (4 channels, 31 samples).
+    Mod,
+    /// Scream Tracker 3 format.
+    S3m,
 }
 
 impl TrackerFormat {
@@ -19,6 +24,8 @@ impl TrackerFormat {
         match self {
             TrackerFormat::Xm => "xm",
             TrackerFormat::It => "it",
+            TrackerFormat::Mod => "mod",
+            TrackerFormat::S3m => "s3m",
         }
     }
 }
@@ -1,5 +1,6 @@
 //! Draft compose (Pattern IR) recipe types for tracker music.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
@@ -8,7 +9,7 @@ use super::{
 };
 
 /// Channel reference (index or alias name).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ChannelRef {
     Index(u8),
@@ -22,7 +23,7 @@ impl Default for ChannelRef {
 }
 
 /// Instrument reference (index or alias name).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum InstrumentRef {
     Index(u8),
@@ -36,7 +37,7 @@ impl Default for InstrumentRef {
 }
 
 /// Musical timebase for mapping bars/beats to tracker rows.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TimeBase {
     pub beats_per_bar: u16,
@@ -44,7 +45,7 @@ pub struct TimeBase {
 }
 
 /// Beat position within a pattern (0-indexed).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BeatPos {
     pub bar: u16,
@@ -55,7 +56,7 @@ pub struct BeatPos {
 }
 
 /// Beat delta for stepping within a pattern (can be negative).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BeatDelta {
     pub beats: i32,
@@ -63,7 +64,7 @@ pub struct BeatDelta {
 }
 
 /// Supported key scales for harmony helpers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum HarmonyScale {
     Major,
@@ -71,7 +72,7 @@ pub enum HarmonyScale {
 }
 
 /// Key definition for harmony helpers.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct HarmonyKey {
     pub root: String,
@@ -79,14 +80,14 @@ pub struct HarmonyKey {
 }
 
 /// Symbol-form chord spec.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ChordSpecSymbol {
     pub symbol: String,
 }
 
 /// Interval-form chord spec (escape hatch).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ChordSpecIntervals {
     pub root: String,
@@ -96,7 +97,7 @@ pub struct ChordSpecIntervals {
 }
 
 /// Chord specification (symbol or interval form).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum ChordSpec {
     Symbol(ChordSpecSymbol),
@@ -104,7 +105,7 @@ pub enum ChordSpec {
 }
 
 /// Harmony chord change entry.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct HarmonyChordEntry {
     pub at: BeatPos,
@@ -112,7 +113,7 @@ pub struct HarmonyChordEntry {
 }
 
 /// Harmony helpers block (key + chord changes).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Harmony {
     pub key: HarmonyKey,
@@ -121,7 +122,7 @@ pub struct Harmony {
 }
 
 /// Parameters for the `music.tracker_song_compose_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct MusicTrackerSongComposeV1Params {
     /// Song internal name (used in IT/XM module).
@@ -184,7 +185,7 @@ pub struct MusicTrackerSongComposeV1Params {
 }
 
 /// Pattern definition for compose specs.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ComposePattern {
     /// Number of rows in the pattern.
@@ -211,7 +212,7 @@ pub struct ComposePattern {
 }
 
 /// Merge policy for overlapping cells.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MergePolicy {
     /// Error on any double-write to the same cell.
@@ -224,7 +225,7 @@ pub enum MergePolicy {
 }
 
 /// Sequence mode for `emit_seq` values.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SeqMode {
     /// Cycle through values (wraps).
@@ -234,7 +235,7 @@ pub enum SeqMode {
 }
 
 /// Sequence of values aligned to emitted events.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct Seq<T> {
     pub mode: SeqMode,
@@ -242,7 +243,7 @@ pub struct Seq<T> {
 }
 
 /// Kind of pitch sequence (degree/chord-tone authoring).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PitchSeqKind {
     ScaleDegree,
@@ -250,7 +251,7 @@ pub enum PitchSeqKind {
 }
 
 /// Pitch sequence aligned to emitted events (compiled to note names during expansion).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PitchSeq {
     pub kind: PitchSeqKind,
@@ -263,7 +264,7 @@ pub struct PitchSeq {
 }
 
 /// Mirror axis for the `mirror` operator.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MirrorAxis {
     /// Mirror in time (retrograde).
@@ -272,7 +273,7 @@ pub enum MirrorAxis {
 }
 
 /// Filter criteria for the `filter` operator.
-#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct FilterCriteria {
     /// Include only events at row >= value.
@@ -304,14 +305,14 @@ impl FilterCriteria {
 }
 
 /// Interleave part for the `interleave` operator.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct InterleavePart {
     pub body: PatternExpr,
 }
 
 /// Pattern IR expression.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
 pub enum PatternExpr {
     Stack {
@@ -421,7 +422,7 @@ pub enum PatternExpr {
 }
 
 /// Concatenation part with declared length.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ConcatPart {
     pub len_rows: u16,
@@ -429,7 +430,7 @@ pub struct ConcatPart {
 }
 
 /// Weighted choice entry.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct WeightedChoice {
     pub weight: u32,
@@ -437,7 +438,7 @@ pub struct WeightedChoice {
 }
 
 /// Time expression (row selector).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
 pub enum TimeExpr {
     Range {
@@ -476,7 +477,7 @@ pub enum TimeExpr {
 }
 
 /// Scale type for quantize_pitch transform.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum QuantizeScale {
     Major,
@@ -504,7 +505,7 @@ impl QuantizeScale {
 }
 
 /// Transform operators for Pattern IR.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
 pub enum TransformOp {
     /// Transpose note names by semitones.
@@ -589,7 +590,7 @@ pub enum TransformOp {
 }
 
 /// Cell template emitted by Pattern IR (row comes from TimeExpr).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct CellTemplate {
     /// Channel number (0-indexed).
@@ -600,6 +601,7 @@ pub struct CellTemplate {
         deserialize_with = "deserialize_note_opt",
         skip_serializing_if = "Option::is_none"
     )]
+    #[schemars(schema_with = "note_opt_schema")]
     pub note: Option<String>,
     /// Instrument index.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -668,6 +670,11 @@ where
     deserializer.deserialize_any(NoteOptVisitor)
 }
 
+/// Schema for an optional note: a note name, a MIDI note number or null.
+fn note_opt_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({ "type": ["string", "integer", "null"] })
+}
+
 fn midi_to_note_name(midi: u8) -> String {
     const NOTES: [&str; 12] = [
         "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
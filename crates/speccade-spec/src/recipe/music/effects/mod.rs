@@ -15,13 +15,14 @@ pub use conversion::{decode_it_effect, decode_xm_effect, parse_effect_name};
 pub use tracker_options::{AutomationEntry, ItOptions, PatternEffect};
 pub use validation::EffectValidationError;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Typed tracker effect command with validated parameters.
 ///
 /// Effects can be specified either as typed variants or as raw effect codes.
 /// The typed variants provide validation and documentation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum TrackerEffect {
     /// Arpeggio effect - rapid cycling between note, note+x, note+y semitones.
@@ -219,3 +219,42 @@ fn test_serialization_retrigger() {
     let parsed: TrackerEffect = serde_json::from_str(&json).unwrap();
     assert_eq!(effect, parsed);
 }
+
+#[test]
+fn test_validate_mod_rejects_xm_only_effects() {
+    assert!(TrackerEffect::Vibrato { speed: 4, depth: 8 }
+        .validate_mod()
+        .is_ok());
+    assert!(TrackerEffect::SetTempo { bpm: 125 }.validate_mod().is_ok());
+    assert!(matches!(
+        TrackerEffect::SetGlobalVolume { volume: 32 }.validate_mod(),
+        Err(EffectValidationError::UnsupportedFormat { ref format, .. }) if format == "MOD"
+    ));
+    assert!(TrackerEffect::KeyOff { tick: 2 }.validate_mod().is_err());
+    assert!(TrackerEffect::Raw {
+        code: 0x10,
+        param: 0
+    }
+    .validate_mod()
+    .is_err());
+}
+
+#[test]
+fn test_validate_s3m_rejects_it_only_effects() {
+    assert!(TrackerEffect::Arpeggio { x: 3, y: 7 }
+        .validate_s3m()
+        .is_ok());
+    assert!(TrackerEffect::SetGlobalVolume { volume: 32 }
+        .validate_s3m()
+        .is_ok());
+    assert!(TrackerEffect::SetPanning { pan: 0x40 }
+        .validate_s3m()
+        .is_ok());
+    assert!(matches!(
+        TrackerEffect::SetChannelVolume { volume: 32 }.validate_s3m(),
+        Err(EffectValidationError::UnsupportedFormat { ref format, .. }) if format == "S3M"
+    ));
+    assert!(TrackerEffect::Panbrello { speed: 2, depth: 2 }
+        .validate_s3m()
+        .is_err());
+}
@@ -1,9 +1,10 @@
 //! Tracker module options and auxiliary types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// IT-specific module options.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ItOptions {
     /// Stereo output flag.
@@ -40,7 +41,7 @@ impl Default for ItOptions {
 }
 
 /// Automation entry for volume fades and tempo changes.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum AutomationEntry {
     /// Volume fade automation.
@@ -74,7 +75,7 @@ pub enum AutomationEntry {
 }
 
 /// String-based effect pattern for inline pattern definitions.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct PatternEffect {
     /// Effect type (e.g., "vibrato", "volume_slide").
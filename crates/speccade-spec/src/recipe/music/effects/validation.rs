@@ -1,4 +1,4 @@
-//! Effect validation for XM and IT formats.
+//! Effect validation for XM, IT, MOD and S3M formats.
 
 use super::TrackerEffect;
 
@@ -336,4 +336,54 @@ impl TrackerEffect {
         }
         Ok(())
     }
+
+    /// Validate effect parameters for ProTracker MOD format.
+    ///
+    /// MOD shares XM's effect numbering but only has effects 0-F.
+    pub fn validate_mod(&self) -> Result<(), EffectValidationError> {
+        self.validate_xm().map_err(|err| match err {
+            EffectValidationError::UnsupportedFormat { effect, .. } => {
+                EffectValidationError::UnsupportedFormat {
+                    effect,
+                    format: "MOD".to_string(),
+                }
+            }
+            other => other,
+        })?;
+        match self.to_xm() {
+            Some((code, _)) if code <= 0x0F => Ok(()),
+            _ => Err(EffectValidationError::UnsupportedFormat {
+                effect: format!("{:?}", self),
+                format: "MOD".to_string(),
+            }),
+        }
+    }
+
+    /// Validate effect parameters for Scream Tracker 3 (S3M) format.
+    ///
+    /// S3M shares IT's effect lettering; effects IT added later (M, N, P, W, Y, Z)
+    /// are rejected.
+    pub fn validate_s3m(&self) -> Result<(), EffectValidationError> {
+        self.validate_it().map_err(|err| match err {
+            EffectValidationError::UnsupportedFormat { effect, .. } => {
+                EffectValidationError::UnsupportedFormat {
+                    effect,
+                    format: "S3M".to_string(),
+                }
+            }
+            other => other,
+        })?;
+        match self.to_it() {
+            Some((code, _)) if is_s3m_effect(code) => Ok(()),
+            _ => Err(EffectValidationError::UnsupportedFormat {
+                effect: format!("{:?}", self),
+                format: "S3M".to_string(),
+            }),
+        }
+    }
+}
+
+/// Returns true if an IT effect code (A = 1) also exists in S3M: A-L, O, Q-V, X.
+fn is_s3m_effect(code: u8) -> bool {
+    matches!(code, 1..=12 | 15 | 17..=22 | 24)
 }
@@ -1,11 +1,12 @@
 //! Instrument specifications for music.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::recipe::audio::{AudioV1Params, Envelope};
 
 /// Instrument definition for tracker modules.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct TrackerInstrument {
     /// Instrument name.
@@ -34,6 +35,13 @@ pub struct TrackerInstrument {
     /// Path to WAV sample file (mutually exclusive with ref and synthesis).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub wav: Option<String>,
+    /// Multi-sample zones, each with its own note range and synthesis (XM and IT only;
+    /// mutually exclusive with `ref`, `wav`, `synthesis_audio_v1`, and `synthesis`).
+    ///
+    /// Zones are listed from lowest to highest and must be contiguous. Notes below the first
+    /// zone or above the last play the nearest zone.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sample_map: Vec<SampleZone>,
     /// Base note for the instrument (e.g., "C4", "A#3").
     /// Used for pitch correction when synthesis or wav sample is at a specific pitch.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -41,9 +49,20 @@ pub struct TrackerInstrument {
     /// Sample rate for synthesized instruments (default: 22050).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sample_rate: Option<u32>,
+    /// Resample a `wav` sample to `sample_rate` instead of keeping the file's own rate.
+    ///
+    /// Set by the WAV importer; without it `sample_rate` does not affect `wav` samples.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub resample_wav: bool,
     /// ADSR envelope.
     #[serde(default = "default_envelope")]
     pub envelope: Envelope,
+    /// Optional panning envelope (XM and IT only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pan_envelope: Option<TrackerEnvelope>,
+    /// Optional auto-vibrato applied by the tracker at playback (XM and IT only).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_vibrato: Option<AutoVibrato>,
     /// Optional tracker sample loop override.
     ///
     /// By default, Speccade loops sustained instruments (envelope `sustain > 0`) and leaves
@@ -58,10 +77,92 @@ pub struct TrackerInstrument {
     /// Optional volume (0-64).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub default_volume: Option<u8>,
+    /// Bake a decorrelated stereo sample instead of a mono one (IT and XM).
+    ///
+    /// XM splits it into a left and a right instrument on mirrored channels. MOD and
+    /// S3M have no stereo samples; they keep the mono sample and validation warns.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub stereo: bool,
+}
+
+/// A note range of a multi-sample instrument and the sample that plays it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SampleZone {
+    /// Lowest note of the zone, inclusive (e.g., "C0").
+    pub low_note: String,
+    /// Highest note of the zone, inclusive (e.g., "B3").
+    pub high_note: String,
+    /// Inline `audio_v1` synthesis params for this zone's sample.
+    pub synthesis_audio_v1: AudioV1Params,
+    /// Base note of this zone's sample (defaults to the instrument `base_note`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_note: Option<String>,
+}
+
+/// Point-based envelope for tracker instruments.
+///
+/// Points are in tracker ticks from note start; ticks must be strictly increasing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(deny_unknown_fields)]
+pub struct TrackerEnvelope {
+    /// Envelope points.
+    pub points: Vec<TrackerEnvelopePoint>,
+    /// Index of the point held until note-off.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sustain_point: Option<u8>,
+}
+
+/// A single tracker envelope point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TrackerEnvelopePoint {
+    /// Tick from note start.
+    pub tick: u16,
+    /// Pan position, -32 (left) to 32 (right).
+    pub value: i8,
+}
+
+/// Instrument auto-vibrato, applied by the tracker to every note it plays.
+///
+/// Ranges depend on the format: XM allows `depth` 0-15 and `rate` 0-63, IT allows
+/// `depth` and `rate` 0-64.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AutoVibrato {
+    /// Vibrato waveform.
+    #[serde(default)]
+    pub waveform: VibratoWaveform,
+    /// Vibrato depth.
+    pub depth: u8,
+    /// Vibrato rate (speed).
+    pub rate: u8,
+    /// Ticks taken to ramp up to full depth (0 = immediate).
+    #[serde(default)]
+    pub sweep: u8,
+}
+
+/// Auto-vibrato waveform.
+///
+/// XM has no `random` waveform and IT has no `ramp_up` waveform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum VibratoWaveform {
+    /// Sine wave.
+    #[default]
+    Sine,
+    /// Square wave.
+    Square,
+    /// Ramp down (sawtooth).
+    RampDown,
+    /// Ramp up (XM only).
+    RampUp,
+    /// Random (IT only).
+    Random,
 }
 
 /// Loop mode override for tracker samples.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TrackerLoopMode {
     /// Default behavior: loop sustained instruments and choose the best loop mode.
@@ -85,7 +186,7 @@ pub(crate) fn default_envelope() -> Envelope {
 }
 
 /// Synthesis type for tracker instruments.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum InstrumentSynthesis {
     /// Pulse/square wave with variable duty cycle.
@@ -18,10 +18,11 @@ pub use effects::*;
 pub use instrument::*;
 pub use pattern::*;
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `music.tracker_song_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct MusicTrackerSongV1Params {
     /// Song internal name (used in IT/XM module).
@@ -67,3 +68,27 @@ pub struct MusicTrackerSongV1Params {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub it_options: Option<ItOptions>,
 }
+
+impl MusicTrackerSongV1Params {
+    /// Channels that play a `stereo` instrument in any pattern, in ascending order.
+    ///
+    /// XM has no stereo samples, so each of these channels is mirrored onto an
+    /// extra channel that plays the right half of the stereo sample.
+    pub fn stereo_channels(&self) -> Vec<u8> {
+        let mut channels: Vec<u8> = self
+            .patterns
+            .values()
+            .flat_map(|pattern| pattern.flat_notes())
+            .filter(|(_, note)| !matches!(note.note.as_str(), "OFF" | "===" | "^^^"))
+            .filter(|(_, note)| {
+                self.instruments
+                    .get(note.inst as usize)
+                    .is_some_and(|instrument| instrument.stereo)
+            })
+            .map(|(channel, _)| channel)
+            .collect();
+        channels.sort_unstable();
+        channels.dedup();
+        channels
+    }
+}
@@ -1,10 +1,11 @@
 //! Pattern definitions for tracker modules.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::HashMap;
 
 /// Pattern definition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct TrackerPattern {
     /// Number of rows in the pattern.
@@ -52,7 +53,7 @@ impl TrackerPattern {
 }
 
 /// A single note event in a pattern.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(deny_unknown_fields)]
 pub struct PatternNote {
     /// Row number (0-indexed).
@@ -63,6 +64,7 @@ pub struct PatternNote {
     pub channel: Option<u8>,
     /// Note name (e.g., "C4", "---" for note off, "..." for no note) or MIDI note number.
     #[serde(default, deserialize_with = "deserialize_note")]
+    #[schemars(schema_with = "note_schema")]
     pub note: String,
     /// Instrument index.
     #[serde(default, alias = "instrument")]
@@ -121,6 +123,11 @@ where
     deserializer.deserialize_any(NoteVisitor)
 }
 
+/// Schema for the note field: a note name or a MIDI note number.
+fn note_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({ "type": ["string", "integer"] })
+}
+
 /// Convert MIDI note number to note name.
 fn midi_to_note_name(midi: u8) -> String {
     const NOTES: [&str; 12] = [
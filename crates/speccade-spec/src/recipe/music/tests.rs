@@ -1,7 +1,7 @@
 //! Tests for music/tracker recipe types - basic serialization.
 
 use super::*;
-use crate::recipe::audio::{AudioLayer, AudioV1Params, Envelope, Synthesis, Waveform};
+use crate::recipe::audio::{AudioLayer, AudioV1Params, ChannelMode, Envelope, Synthesis, Waveform};
 use std::collections::HashMap;
 
 // ==================== Top-Level Keys Tests ====================
@@ -40,10 +40,26 @@ fn test_format_it_serialization() {
     assert_eq!(parsed, TrackerFormat::It);
 }
 
+#[test]
+fn test_format_mod_and_s3m_serialization() {
+    assert_eq!(
+        serde_json::to_string(&TrackerFormat::Mod).unwrap(),
+        r#""mod""#
+    );
+    assert_eq!(
+        serde_json::to_string(&TrackerFormat::S3m).unwrap(),
+        r#""s3m""#
+    );
+    let parsed: TrackerFormat = serde_json::from_str(r#""s3m""#).unwrap();
+    assert_eq!(parsed, TrackerFormat::S3m);
+}
+
 #[test]
 fn test_tracker_format_extension() {
     assert_eq!(TrackerFormat::Xm.extension(), "xm");
     assert_eq!(TrackerFormat::It.extension(), "it");
+    assert_eq!(TrackerFormat::Mod.extension(), "mod");
+    assert_eq!(TrackerFormat::S3m.extension(), "s3m");
 }
 
 #[test]
@@ -293,12 +309,22 @@ fn test_instrument_synthesis_audio_v1_serialization() {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 1.0,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
             pitch_envelope: None,
@@ -307,6 +333,11 @@ fn test_instrument_synthesis_audio_v1_serialization() {
             master_filter: None,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
         }),
         ..Default::default()
     };
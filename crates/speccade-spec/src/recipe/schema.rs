@@ -0,0 +1,329 @@
+//! JSON Schema export for recipe params.
+//!
+//! Schemas are derived from the param types with `schemars`, so they follow the
+//! same serde attributes that drive parsing: tagged enums become `oneOf` with a
+//! `const` discriminator, defaulted fields are optional, and
+//! `deny_unknown_fields` closes the object.
+
+use schemars::{JsonSchema, Schema};
+
+use super::*;
+
+/// Recipe kinds with typed params that have no [`RecipeKind`] variant.
+const EXTRA_SCHEMA_KINDS: &[&str] = &["texture.material_v1"];
+
+/// Returns every recipe kind [`recipe_params_schema`] accepts.
+pub fn schema_kinds() -> Vec<&'static str> {
+    let mut kinds: Vec<&str> = RecipeKind::all().iter().map(RecipeKind::as_str).collect();
+    kinds.extend_from_slice(EXTRA_SCHEMA_KINDS);
+    kinds.sort_unstable();
+    kinds
+}
+
+/// Returns the JSON Schema for the params of the recipe kind `kind`.
+///
+/// Returns `None` for unknown kinds.
+pub fn recipe_params_schema(kind: &str) -> Option<Schema> {
+    if kind == "texture.material_v1" {
+        return Some(schema_for::<TextureMaterialV1Params>());
+    }
+
+    let kind = RecipeKind::all().iter().find(|k| k.as_str() == kind)?;
+    let schema = match kind {
+        RecipeKind::AudioV1 => schema_for::<AudioV1Params>(),
+        RecipeKind::MusicTrackerSongV1 => schema_for::<MusicTrackerSongV1Params>(),
+        RecipeKind::MusicTrackerSongComposeV1 => schema_for::<MusicTrackerSongComposeV1Params>(),
+        RecipeKind::TextureProceduralV1 => schema_for::<TextureProceduralV1Params>(),
+        RecipeKind::TextureTrimsheetV1 => schema_for::<TextureTrimsheetV1Params>(),
+        RecipeKind::TextureDecalV1 => schema_for::<TextureDecalV1Params>(),
+        RecipeKind::TextureSplatSetV1 => schema_for::<TextureSplatSetV1Params>(),
+        RecipeKind::TextureMatcapV1 => schema_for::<TextureMatcapV1Params>(),
+        RecipeKind::TextureMaterialPresetV1 => schema_for::<TextureMaterialPresetV1Params>(),
+        RecipeKind::TextureCompositeV1 => schema_for::<TextureCompositeV1Params>(),
+        RecipeKind::TextureTileVariationV1 => schema_for::<TextureTileVariationV1Params>(),
+        RecipeKind::StaticMeshBlenderPrimitivesV1 => {
+            schema_for::<StaticMeshBlenderPrimitivesV1Params>()
+        }
+        RecipeKind::StaticMeshModularKitV1 => schema_for::<StaticMeshModularKitV1Params>(),
+        RecipeKind::StaticMeshOrganicSculptV1 => schema_for::<StaticMeshOrganicSculptV1Params>(),
+        RecipeKind::StaticMeshShrinkwrapV1 => schema_for::<StaticMeshShrinkwrapV1Params>(),
+        RecipeKind::StaticMeshBooleanKitV1 => schema_for::<StaticMeshBooleanKitV1Params>(),
+        RecipeKind::SkeletalMeshArmatureDrivenV1 => {
+            schema_for::<SkeletalMeshArmatureDrivenV1Params>()
+        }
+        RecipeKind::SkeletalMeshSkinnedMeshV1 => schema_for::<SkeletalMeshSkinnedMeshV1Params>(),
+        RecipeKind::SkeletalAnimationBlenderClipV1 => {
+            schema_for::<SkeletalAnimationBlenderClipV1Params>()
+        }
+        RecipeKind::SkeletalAnimationBlenderRiggedV1 => {
+            schema_for::<SkeletalAnimationBlenderRiggedV1Params>()
+        }
+        RecipeKind::SkeletalAnimationHelpersV1 => schema_for::<AnimationHelpersV1Params>(),
+        RecipeKind::SpriteSheetV1 => schema_for::<SpriteSheetV1Params>(),
+        RecipeKind::SpriteAnimationV1 => schema_for::<SpriteAnimationV1Params>(),
+        RecipeKind::SpriteRenderFromMeshV1 => schema_for::<SpriteRenderFromMeshV1Params>(),
+        RecipeKind::VfxFlipbookV1 => schema_for::<VfxFlipbookV1Params>(),
+        RecipeKind::VfxParticleProfileV1 => schema_for::<VfxParticleProfileV1Params>(),
+        RecipeKind::UiNineSliceV1 => schema_for::<UiNineSliceV1Params>(),
+        RecipeKind::UiIconSetV1 => schema_for::<UiIconSetV1Params>(),
+        RecipeKind::UiItemCardV1 => schema_for::<UiItemCardV1Params>(),
+        RecipeKind::UiDamageNumberV1 => schema_for::<UiDamageNumberV1Params>(),
+        RecipeKind::FontBitmapV1 => schema_for::<FontBitmapV1Params>(),
+    };
+    Some(schema)
+}
+
+fn schema_for<T: JsonSchema>() -> Schema {
+    schemars::schema_for!(T)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    /// Checks `instance` against the subset of JSON Schema that schemars emits.
+    fn check(schema: &Value, root: &Value, instance: &Value, path: &str) -> Result<(), String> {
+        let schema = match schema {
+            Value::Bool(true) => return Ok(()),
+            Value::Bool(false) => return Err(format!("{}: rejected by false schema", path)),
+            Value::Object(map) => map,
+            other => return Err(format!("{}: bad schema {}", path, other)),
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.trim_start_matches("#/$defs/");
+            check(&root["$defs"][name], root, instance, path)?;
+        }
+
+        if let Some(types) = schema.get("type") {
+            let allowed: Vec<&str> = match types {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(ts) => ts.iter().filter_map(Value::as_str).collect(),
+                _ => vec![],
+            };
+            let matches = |t: &str| match t {
+                "null" => instance.is_null(),
+                "boolean" => instance.is_boolean(),
+                "string" => instance.is_string(),
+                "number" => instance.is_number(),
+                "integer" => instance.is_i64() || instance.is_u64(),
+                "array" => instance.is_array(),
+                "object" => instance.is_object(),
+                _ => false,
+            };
+            if !allowed.iter().any(|t| matches(t)) {
+                return Err(format!(
+                    "{}: expected {:?}, got {}",
+                    path, allowed, instance
+                ));
+            }
+        }
+
+        if let Some(expected) = schema.get("const") {
+            if instance != expected {
+                return Err(format!("{}: expected {}, got {}", path, expected, instance));
+            }
+        }
+        if let Some(Value::Array(options)) = schema.get("enum") {
+            if !options.contains(instance) {
+                return Err(format!("{}: {} not in enum", path, instance));
+            }
+        }
+
+        if let Some(value) = instance.as_f64() {
+            if let Some(min) = schema.get("minimum").and_then(Value::as_f64) {
+                if value < min {
+                    return Err(format!("{}: {} below minimum {}", path, value, min));
+                }
+            }
+            if let Some(max) = schema.get("maximum").and_then(Value::as_f64) {
+                if value > max {
+                    return Err(format!("{}: {} above maximum {}", path, value, max));
+                }
+            }
+        }
+
+        if let Some(object) = instance.as_object() {
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for required in schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                if !object.contains_key(required) {
+                    return Err(format!("{}: missing required '{}'", path, required));
+                }
+            }
+            for (key, value) in object {
+                let child = format!("{}.{}", path, key);
+                match properties.and_then(|p| p.get(key)) {
+                    Some(property) => check(property, root, value, &child)?,
+                    None => {
+                        if let Some(additional) = schema.get("additionalProperties") {
+                            check(additional, root, value, &child)?;
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(items) = instance.as_array() {
+            if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+                if (items.len() as u64) < min {
+                    return Err(format!("{}: fewer than {} items", path, min));
+                }
+            }
+            if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+                if items.len() as u64 > max {
+                    return Err(format!("{}: more than {} items", path, max));
+                }
+            }
+            let prefix = schema.get("prefixItems").and_then(Value::as_array);
+            for (i, item) in items.iter().enumerate() {
+                let child = format!("{}[{}]", path, i);
+                if let Some(item_schema) = prefix
+                    .and_then(|p| p.get(i))
+                    .or_else(|| schema.get("items"))
+                {
+                    check(item_schema, root, item, &child)?;
+                }
+            }
+        }
+
+        if let Some(Value::Array(all)) = schema.get("allOf") {
+            for sub in all {
+                check(sub, root, instance, path)?;
+            }
+        }
+        if let Some(Value::Array(any)) = schema.get("anyOf") {
+            if !any
+                .iter()
+                .any(|sub| check(sub, root, instance, path).is_ok())
+            {
+                return Err(format!("{}: matched no anyOf branch", path));
+            }
+        }
+        if let Some(Value::Array(one)) = schema.get("oneOf") {
+            let errors: Vec<String> = one
+                .iter()
+                .filter_map(|sub| check(sub, root, instance, path).err())
+                .collect();
+            if one.len() - errors.len() != 1 {
+                return Err(format!(
+                    "{}: matched {} oneOf branches ({:?})",
+                    path,
+                    one.len() - errors.len(),
+                    errors
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate(kind: &str, params: &Value) -> Result<(), String> {
+        let schema = serde_json::to_value(recipe_params_schema(kind).unwrap()).unwrap();
+        check(&schema, &schema, params, "params")
+    }
+
+    /// Params of the RFC-0001 Section 6.1 audio example.
+    fn rfc_audio_params() -> Value {
+        json!({
+            "duration_seconds": 0.3,
+            "sample_rate": 44100,
+            "layers": [
+                {
+                    "synthesis": {
+                        "type": "fm_synth",
+                        "carrier_freq": 440.0,
+                        "modulator_freq": 880.0,
+                        "modulation_index": 2.5,
+                        "freq_sweep": {
+                            "end_freq": 110.0,
+                            "curve": "exponential"
+                        }
+                    },
+                    "envelope": {
+                        "attack": 0.01,
+                        "decay": 0.05,
+                        "sustain": 0.3,
+                        "release": 0.15
+                    },
+                    "volume": 0.8,
+                    "pan": 0.0
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_audio_schema_validates_rfc_example_params() {
+        let params = rfc_audio_params();
+        serde_json::from_value::<AudioV1Params>(params.clone()).unwrap();
+
+        validate("audio_v1", &params).unwrap();
+    }
+
+    #[test]
+    fn test_audio_schema_rejects_invalid_params() {
+        let mut unknown_synthesis = rfc_audio_params();
+        unknown_synthesis["layers"][0]["synthesis"]["type"] = json!("theremin");
+        assert!(validate("audio_v1", &unknown_synthesis).is_err());
+
+        let mut unknown_field = rfc_audio_params();
+        unknown_field["layers"][0]["synthesis"]["carrier"] = json!(440.0);
+        assert!(validate("audio_v1", &unknown_field).is_err());
+
+        let mut missing_layers = rfc_audio_params();
+        missing_layers.as_object_mut().unwrap().remove("layers");
+        assert!(validate("audio_v1", &missing_layers).is_err());
+    }
+
+    #[test]
+    fn test_tagged_enums_use_one_of_with_discriminator() {
+        let audio = serde_json::to_value(recipe_params_schema("audio_v1").unwrap()).unwrap();
+        let texture =
+            serde_json::to_value(recipe_params_schema("texture.procedural_v1").unwrap()).unwrap();
+
+        for (variants, expected) in [
+            (&audio["$defs"]["Synthesis"]["oneOf"], "fm_synth"),
+            (&texture["$defs"]["TextureProceduralNode"]["oneOf"], "noise"),
+        ] {
+            let tags: Vec<&str> = variants
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|v| v["properties"]["type"]["const"].as_str().unwrap())
+                .collect();
+            assert!(tags.contains(&expected), "{:?}", tags);
+        }
+    }
+
+    #[test]
+    fn test_texture_schema_validates_procedural_graph() {
+        let params = json!({
+            "resolution": [64, 64],
+            "tileable": true,
+            "nodes": [
+                { "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } },
+                { "id": "m", "type": "math", "input": "n", "op": "multiply", "operand": 0.5 }
+            ]
+        });
+        serde_json::from_value::<TextureProceduralV1Params>(params.clone()).unwrap();
+
+        validate("texture.procedural_v1", &params).unwrap();
+    }
+
+    #[test]
+    fn test_every_schema_kind_has_a_schema() {
+        let kinds = schema_kinds();
+        assert!(kinds.contains(&"texture.material_v1"));
+        for kind in kinds {
+            assert!(recipe_params_schema(kind).is_some(), "{}", kind);
+        }
+        assert!(recipe_params_schema("audio_v9").is_none());
+    }
+}
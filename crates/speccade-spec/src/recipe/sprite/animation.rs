@@ -3,12 +3,13 @@
 //! Defines animation clips that reference frames from a spritesheet,
 //! with timing and loop mode configuration.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `sprite.animation_v1` recipe.
 ///
 /// Defines an animation clip with frame references and timing.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpriteAnimationV1Params {
     /// Animation clip name.
@@ -31,7 +32,7 @@ fn default_fps() -> u32 {
 }
 
 /// Animation playback loop mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum AnimationLoopMode {
     /// Repeat from start after last frame.
@@ -46,7 +47,7 @@ pub enum AnimationLoopMode {
 }
 
 /// A frame reference in an animation clip.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct AnimationFrame {
     /// Reference to frame ID in the spritesheet.
@@ -59,7 +60,7 @@ pub struct AnimationFrame {
 }
 
 /// Metadata output for a sprite animation clip.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SpriteAnimationMetadata {
     /// Animation clip name.
     pub name: String,
@@ -78,7 +79,7 @@ pub struct SpriteAnimationMetadata {
 }
 
 /// A frame in the animation metadata with resolved duration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct AnimationFrameResolved {
     /// Reference to frame ID in the spritesheet.
     pub frame_id: String,
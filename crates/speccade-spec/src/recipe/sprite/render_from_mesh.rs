@@ -4,12 +4,13 @@
 //! camera and lighting presets, then packs the resulting frames into a sprite
 //! atlas with metadata.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::super::mesh::StaticMeshBlenderPrimitivesV1Params;
 
 /// Camera preset for mesh-to-sprite rendering.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum CameraPreset {
     /// Orthographic projection (no perspective distortion).
@@ -33,7 +34,7 @@ impl CameraPreset {
 }
 
 /// Lighting preset for mesh-to-sprite rendering.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum LightingPreset {
     /// Classic three-point lighting (key, fill, back).
@@ -66,7 +67,7 @@ impl LightingPreset {
 ///
 /// Renders a 3D mesh from multiple rotation angles and packs the resulting
 /// frames into a sprite atlas with metadata.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpriteRenderFromMeshV1Params {
     /// Mesh to render (inline static_mesh params).
@@ -124,7 +125,7 @@ fn default_camera_elevation() -> f64 {
 }
 
 /// Frame metadata for a rendered sprite in the atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SpriteRenderFrame {
     /// Frame identifier (e.g., "angle_0", "angle_45").
     pub id: String,
@@ -143,7 +144,7 @@ pub struct SpriteRenderFrame {
 }
 
 /// Metadata output for a rendered sprite atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SpriteRenderFromMeshMetadata {
     /// Atlas dimensions [width, height] in pixels.
     pub atlas_dimensions: [u32; 2],
@@ -3,13 +3,14 @@
 //! Packs sprite frames into an atlas with deterministic shelf packing,
 //! mip-safe gutters, and metadata output (UVs, pivots, dimensions).
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `sprite.sheet_v1` recipe.
 ///
 /// Packs multiple sprite frames into a single atlas texture with
 /// deterministic shelf packing and mip-safe gutters.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SpriteSheetV1Params {
     /// Atlas resolution [width, height] in pixels.
@@ -28,7 +29,7 @@ fn default_padding() -> u32 {
 }
 
 /// A sprite frame definition for atlas packing.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SpriteFrame {
     /// Unique identifier for this frame.
     pub id: String,
@@ -54,7 +55,7 @@ fn default_pivot() -> [f64; 2] {
 }
 
 /// Source for sprite frame content.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum SpriteFrameSource {
     /// Solid color fill (v1 only supports solid colors).
@@ -70,7 +71,7 @@ pub enum SpriteFrameSource {
 }
 
 /// UV rectangle for a packed frame in normalized [0, 1] coordinates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SpriteFrameUv {
     /// Frame identifier.
     pub id: String,
@@ -98,7 +99,7 @@ pub struct SpriteFrameUv {
 }
 
 /// Metadata output for a packed spritesheet atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SpriteSheetMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
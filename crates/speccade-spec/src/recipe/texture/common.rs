@@ -1,9 +1,10 @@
 //! Common types shared across texture recipe modules.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Types of PBR texture maps.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum TextureMapType {
     /// Base color / diffuse map.
@@ -20,10 +21,12 @@ pub enum TextureMapType {
     Emissive,
     /// Height/displacement map.
     Height,
+    /// Curvature map (convex edges bright, concave areas dark, flat at 0.5).
+    Curvature,
 }
 
 /// Noise configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NoiseConfig {
     /// Noise algorithm.
@@ -39,6 +42,50 @@ pub struct NoiseConfig {
     /// Lacunarity for fractal noise.
     #[serde(default = "default_lacunarity")]
     pub lacunarity: f64,
+    /// Distance metric for `worley` noise; ignored by other algorithms.
+    #[serde(default, skip_serializing_if = "is_default_distance_metric")]
+    pub distance_metric: WorleyDistanceMetric,
+    /// How far `worley` feature points deviate from their cell centers, from
+    /// 0.0 (regular grid) to 1.0 (fully random). Ignored by other algorithms.
+    #[serde(default = "default_jitter", skip_serializing_if = "is_default_jitter")]
+    pub jitter: f64,
+    /// What `worley` noise measures at each point; ignored by other algorithms.
+    #[serde(default, skip_serializing_if = "is_default_worley_mode")]
+    pub worley_mode: WorleyMode,
+    /// Carrier frequency of `gabor` kernels, in cycles per noise unit (one
+    /// unit is `1 / scale` pixels). Defaults to 0.9. Ignored by other algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<f64>,
+    /// Orientation of `gabor` kernels in degrees (0 = stripes varying along x).
+    /// None gives every kernel a random orientation (isotropic noise).
+    /// Ignored by other algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<f64>,
+    /// Width of the `gabor` Gaussian envelope in cycles per noise unit. Larger
+    /// values give smaller kernels and a wider frequency band. Defaults to 1.2.
+    /// Ignored by other algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bandwidth: Option<f64>,
+    /// Number of `gabor` kernels scattered per lattice cell. Defaults to 3.
+    /// Ignored by other algorithms.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impulses_per_cell: Option<u8>,
+}
+
+pub(crate) fn default_jitter() -> f64 {
+    1.0
+}
+
+fn is_default_jitter(v: &f64) -> bool {
+    *v == default_jitter()
+}
+
+fn is_default_distance_metric(metric: &WorleyDistanceMetric) -> bool {
+    *metric == WorleyDistanceMetric::default()
+}
+
+fn is_default_worley_mode(mode: &WorleyMode) -> bool {
+    *mode == WorleyMode::default()
 }
 
 pub(crate) fn default_octaves() -> u8 {
@@ -54,7 +101,7 @@ pub(crate) fn default_lacunarity() -> f64 {
 }
 
 /// Noise algorithm types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum NoiseAlgorithm {
     /// Perlin noise.
@@ -69,10 +116,42 @@ pub enum NoiseAlgorithm {
     Gabor,
     /// Fractal Brownian motion.
     Fbm,
+    /// Ridged multifractal (sharp crests, for rock and mountain ridges).
+    RidgedMultifractal,
+    /// Billow (rounded puffs, for clouds).
+    Billow,
+}
+
+/// Distance metric used to measure Worley cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WorleyDistanceMetric {
+    /// Euclidean distance (round cells, default).
+    #[default]
+    Euclidean,
+    /// Manhattan distance (diamond-shaped cells).
+    Manhattan,
+    /// Chebyshev distance (square cells).
+    Chebyshev,
+}
+
+/// Value returned by Worley noise at each point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WorleyMode {
+    /// Distance to the nearest feature point (default).
+    #[default]
+    F1,
+    /// Distance to the second-nearest feature point.
+    F2,
+    /// Difference between the two, which is zero on cell borders (cracks, scales).
+    F2MinusF1,
+    /// A random value per cell, flat across each cell (cellular patches).
+    CellValue,
 }
 
 /// Gradient direction types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum GradientDirection {
     /// Horizontal gradient (left to right).
@@ -84,7 +163,7 @@ pub enum GradientDirection {
 }
 
 /// Stripe direction types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeDirection {
     /// Horizontal stripes.
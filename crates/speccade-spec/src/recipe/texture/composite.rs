@@ -0,0 +1,113 @@
+//! Layered/blended texture recipe types.
+//!
+//! `texture.composite_v1` evaluates several procedural sub-graphs and blends
+//! their outputs bottom-to-top into a single image.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::procedural::TextureProceduralNode;
+
+/// Parameters for the `texture.composite_v1` recipe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TextureCompositeV1Params {
+    /// Texture resolution [width, height] in pixels (shared by all layers).
+    pub resolution: [u32; 2],
+    /// Whether the texture should tile seamlessly (shared by all layers).
+    pub tileable: bool,
+    /// Layers, composited in order (index 0 is the bottom of the stack).
+    pub layers: Vec<TextureCompositeLayer>,
+}
+
+/// A single composite layer: a procedural sub-graph plus blend settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TextureCompositeLayer {
+    /// Sub-graph nodes (same node set as `texture.procedural_v1`).
+    pub nodes: Vec<TextureProceduralNode>,
+    /// Id of the node in `nodes` whose value this layer contributes.
+    pub output: String,
+    /// How this layer is blended onto the layers below it.
+    ///
+    /// The bottom layer has nothing beneath it, so its blend mode has no effect.
+    #[serde(default)]
+    pub blend_mode: CompositeBlendMode,
+    /// Layer opacity (0.0 to 1.0).
+    #[serde(default = "default_layer_opacity")]
+    pub opacity: f64,
+}
+
+/// Blend mode for composite layers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CompositeBlendMode {
+    /// Replace the layers below.
+    #[default]
+    Normal,
+    /// Multiply with the layers below.
+    Multiply,
+    /// Add to the layers below (clamped).
+    Add,
+    /// Screen: 1 - (1 - base) * (1 - blend).
+    Screen,
+    /// Overlay: multiply or screen depending on the base value.
+    Overlay,
+    /// Soft light.
+    SoftLight,
+}
+
+fn default_layer_opacity() -> f64 {
+    1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composite_params_roundtrip() {
+        let json = r#"
+        {
+          "resolution": [32, 32],
+          "tileable": true,
+          "layers": [
+            {
+              "nodes": [{ "id": "base", "type": "constant", "value": 0.25 }],
+              "output": "base"
+            },
+            {
+              "nodes": [{ "id": "top", "type": "constant", "value": 0.75 }],
+              "output": "top",
+              "blend_mode": "multiply",
+              "opacity": 0.5
+            }
+          ]
+        }
+        "#;
+
+        let params: TextureCompositeV1Params = serde_json::from_str(json).unwrap();
+        assert_eq!(params.layers.len(), 2);
+        assert_eq!(params.layers[0].blend_mode, CompositeBlendMode::Normal);
+        assert_eq!(params.layers[0].opacity, 1.0);
+        assert_eq!(params.layers[1].blend_mode, CompositeBlendMode::Multiply);
+        assert_eq!(params.layers[1].opacity, 0.5);
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        let reparsed: TextureCompositeV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
+    #[test]
+    fn composite_layer_rejects_unknown_fields() {
+        let json = r#"
+        {
+          "nodes": [{ "id": "c", "type": "constant", "value": 0.5 }],
+          "output": "c",
+          "mode": "add"
+        }
+        "#;
+
+        assert!(serde_json::from_str::<TextureCompositeLayer>(json).is_err());
+    }
+}
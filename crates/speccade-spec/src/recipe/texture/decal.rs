@@ -3,12 +3,13 @@
 //! `texture.decal_v1` produces RGBA decal textures with optional normal map and
 //! roughness outputs, plus placement metadata for projection.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::procedural::TextureProceduralNode;
 
 /// Parameters for the `texture.decal_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureDecalV1Params {
     /// Texture resolution [width, height] in pixels.
@@ -34,7 +35,7 @@ pub struct TextureDecalV1Params {
 ///
 /// This metadata is emitted as a JSON sidecar and can be used by game engines
 /// to correctly project and place decals.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DecalMetadata {
     /// Aspect ratio (width / height) for maintaining correct proportions.
@@ -83,7 +84,7 @@ impl Default for DecalMetadata {
 /// Metadata output for a generated decal.
 ///
 /// This is written to `{asset_id}.decal.json` alongside the texture outputs.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct DecalOutputMetadata {
     /// Texture resolution [width, height] in pixels.
     pub resolution: [u32; 2],
@@ -124,6 +125,13 @@ mod tests {
                             octaves: 2,
                             persistence: 0.5,
                             lacunarity: 2.0,
+                            distance_metric: Default::default(),
+                            jitter: 1.0,
+                            worley_mode: Default::default(),
+                            frequency: None,
+                            orientation: None,
+                            bandwidth: None,
+                            impulses_per_cell: None,
                         },
                     },
                 },
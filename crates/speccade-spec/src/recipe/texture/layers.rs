@@ -1,11 +1,12 @@
 //! Texture layer types for procedural texture generation.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::{GradientDirection, NoiseConfig, StripeDirection, TextureMapType};
 
 /// Procedural texture layer.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum TextureLayer {
     /// Noise-based pattern layer.
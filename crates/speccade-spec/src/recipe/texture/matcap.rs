@@ -4,10 +4,11 @@
 //! Matcaps (material capture) encode lighting and shading in a 2D texture that maps surface
 //! normals to colors, providing a fast way to achieve stylized looks.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `texture.matcap_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureMatcapV1Params {
     /// Texture resolution [width, height] in pixels (typically square, e.g., 256x256 or 512x512).
@@ -32,7 +33,7 @@ pub struct TextureMatcapV1Params {
 }
 
 /// Matcap preset defining the base lighting and shading style.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MatcapPreset {
     /// Basic toon shading with clear light/shadow separation.
@@ -54,7 +55,7 @@ pub enum MatcapPreset {
 }
 
 /// Outline configuration for edge detection.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct OutlineConfig {
     /// Outline width in pixels (1-10).
@@ -66,7 +67,7 @@ pub struct OutlineConfig {
 /// Curvature mask configuration.
 ///
 /// Highlights areas of high curvature (edges/ridges) based on procedural approximation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CurvatureMaskConfig {
     /// Whether curvature masking is enabled.
@@ -80,7 +81,7 @@ pub struct CurvatureMaskConfig {
 /// Cavity mask configuration.
 ///
 /// Darkens concave areas (cavities) based on procedural approximation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct CavityMaskConfig {
     /// Whether cavity masking is enabled.
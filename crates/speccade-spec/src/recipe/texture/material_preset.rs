@@ -4,10 +4,11 @@
 //! metallic, normal) from a predefined material style preset with optional overrides.
 //! This provides a "preset + parameterization" approach for consistent art direction.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `texture.material_preset_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureMaterialPresetV1Params {
     /// Material preset defining the base style.
@@ -39,7 +40,7 @@ fn default_tileable() -> bool {
 }
 
 /// Material preset type defining the base PBR material style.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MaterialPresetType {
     /// Flat albedo with rim highlights and stepped roughness for stylized metal.
@@ -135,7 +136,7 @@ impl MaterialPresetType {
 /// Metadata output for a generated material preset.
 ///
 /// This is written as a JSON sidecar alongside the texture outputs.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MaterialPresetOutputMetadata {
     /// Texture resolution [width, height] in pixels.
     pub resolution: [u32; 2],
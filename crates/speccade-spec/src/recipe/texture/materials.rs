@@ -1,9 +1,10 @@
 //! Material presets and patterns for texture recipes.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Base material properties.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BaseMaterial {
     /// Material type.
@@ -26,7 +27,7 @@ pub struct BaseMaterial {
 }
 
 /// Brick pattern configuration.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct BrickPatternParams {
     /// Brick width in pixels.
@@ -45,7 +46,7 @@ fn default_brick_offset() -> f64 {
 }
 
 /// Normal map parameters for structured materials.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NormalParams {
     /// Bump strength multiplier.
@@ -65,7 +66,7 @@ fn default_mortar_depth() -> f64 {
 }
 
 /// Base material types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MaterialType {
     /// Metal surface.
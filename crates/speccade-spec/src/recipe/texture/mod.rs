@@ -1,6 +1,7 @@
 //! Texture recipe types (procedural graphs and material generators).
 
 mod common;
+mod composite;
 mod decal;
 mod layers;
 mod matcap;
@@ -12,9 +13,11 @@ mod packing;
 mod pbr_maps;
 mod procedural;
 mod splat_set;
+mod tile_variation;
 mod trimsheet;
 
 pub use common::*;
+pub use composite::*;
 pub use decal::*;
 pub use layers::*;
 pub use matcap::*;
@@ -26,6 +29,7 @@ pub use packing::*;
 pub use pbr_maps::*;
 pub use procedural::*;
 pub use splat_set::*;
+pub use tile_variation::*;
 pub use trimsheet::*;
 
 #[cfg(test)]
@@ -1,11 +1,12 @@
 //! Normal map specific types for texture recipes.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::NoiseConfig;
 
 /// Parameters for the `texture.normal_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureNormalV1Params {
     /// Texture resolution [width, height] in pixels.
@@ -18,6 +19,12 @@ pub struct TextureNormalV1Params {
     /// Bump strength (0.0 to 1.0).
     #[serde(default = "default_bump_strength")]
     pub bump_strength: f64,
+    /// Invert the green channel (DirectX/Unreal Y-down convention).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub flip_y: bool,
+    /// Invert the red channel.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub flip_x: bool,
     /// Post-processing options.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub processing: Option<NormalMapProcessing>,
@@ -27,8 +34,47 @@ fn default_bump_strength() -> f64 {
     1.0
 }
 
+/// Strength and channel convention of a height-derived normal map.
+///
+/// The default encodes OpenGL-style (Y-up) normals at strength 1.0. Set
+/// `flip_y` for engines that expect a DirectX-style green channel, such as
+/// Unreal.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NormalMapConfig {
+    /// Multiplier applied to the height gradient.
+    #[serde(default = "default_bump_strength")]
+    pub strength: f64,
+    /// Invert the green channel (DirectX/Unreal Y-down convention).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub flip_y: bool,
+    /// Invert the red channel.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub flip_x: bool,
+}
+
+impl Default for NormalMapConfig {
+    fn default() -> Self {
+        Self {
+            strength: default_bump_strength(),
+            flip_y: false,
+            flip_x: false,
+        }
+    }
+}
+
+impl NormalMapConfig {
+    /// Creates a config with the given strength and the default (OpenGL) convention.
+    pub fn with_strength(strength: f64) -> Self {
+        Self {
+            strength,
+            ..Self::default()
+        }
+    }
+}
+
 /// Post-processing options for normal maps.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NormalMapProcessing {
     /// Gaussian blur sigma for height map smoothing.
@@ -44,7 +90,7 @@ fn default_invert() -> bool {
 }
 
 /// Pattern configuration for normal maps.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum NormalMapPattern {
     /// Grid pattern.
@@ -1,5 +1,6 @@
 //! Packed texture types for channel packing recipes.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -7,7 +8,7 @@ use std::collections::HashMap;
 ///
 /// Each map definition specifies how to generate a single texture map
 /// that can then be packed into output channels.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum MapDefinition {
     /// A solid grayscale value.
@@ -109,7 +110,7 @@ impl MapDefinition {
 ///
 /// This recipe generates multiple texture maps and packs them into
 /// the output channels as specified by the output's `channels` field.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TexturePackedV1Params {
     /// Texture resolution [width, height] in pixels.
@@ -1,5 +1,6 @@
 //! Packed output specification for combining multiple channel sources.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -11,7 +12,7 @@ use crate::validation::common::CommonValidationError;
 /// This struct defines which source maps/values go into each output channel.
 /// Each channel can reference a map key, extract a specific component, or use
 /// a constant value.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct PackedChannels {
     /// Red channel source.
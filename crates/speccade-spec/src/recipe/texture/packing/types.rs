@@ -1,9 +1,10 @@
 //! Core types for channel packing: color components and channel sources.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// A color component that can be extracted from an RGB/RGBA map.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
 #[serde(rename_all = "lowercase")]
 pub enum ColorComponent {
     /// Red channel.
@@ -31,7 +32,7 @@ impl ColorComponent {
 /// - Simple key reference: `"my_height_map"` - uses the map directly (luminance for RGB maps)
 /// - Extended reference: `{ "key": "my_map", "component": "r", "invert": true }`
 /// - Constant value: `{ "constant": 0.5 }` - fills with a constant 0.0-1.0 value
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(untagged)]
 pub enum ChannelSource {
     /// Simple key reference (for grayscale maps, uses luminance for RGB).
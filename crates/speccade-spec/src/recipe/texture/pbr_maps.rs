@@ -1,13 +1,15 @@
 //! PBR material maps types for texture recipes.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::TextureMapType;
 use super::layers::TextureLayer;
 use super::materials::BaseMaterial;
+use super::normal::NormalMapConfig;
 
 /// Parameters for the `texture.material_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureMaterialV1Params {
     /// Texture resolution [width, height] in pixels.
@@ -28,4 +30,22 @@ pub struct TextureMaterialV1Params {
     /// Interpolated color ramp (hex colors).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub color_ramp: Option<Vec<String>>,
+    /// Bits per channel of the height map PNG (8 or 16). Use 16 for height
+    /// maps that drive displacement, where 8-bit output shows terracing.
+    #[serde(
+        default = "default_height_bit_depth",
+        skip_serializing_if = "is_default_height_bit_depth"
+    )]
+    pub height_bit_depth: u8,
+    /// Strength and channel convention of the normal map.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normal: Option<NormalMapConfig>,
+}
+
+fn default_height_bit_depth() -> u8 {
+    8
+}
+
+fn is_default_height_bit_depth(bits: &u8) -> bool {
+    *bits == default_height_bit_depth()
 }
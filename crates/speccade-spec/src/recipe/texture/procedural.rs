@@ -3,12 +3,14 @@
 //! `texture.procedural_v1` is a deterministic DAG of named nodes producing
 //! grayscale or RGBA images.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::common::{GradientDirection, NoiseConfig, StripeDirection};
+use super::composite::CompositeBlendMode;
 
 /// Parameters for the `texture.procedural_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureProceduralV1Params {
     /// Texture resolution [width, height] in pixels.
@@ -20,7 +22,7 @@ pub struct TextureProceduralV1Params {
 }
 
 /// A named graph node.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TextureProceduralNode {
     /// Stable node id.
     pub id: String,
@@ -30,7 +32,7 @@ pub struct TextureProceduralNode {
 }
 
 /// Graph node operations.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
 pub enum TextureProceduralOp {
     // ---------------------------------------------------------------------
@@ -42,6 +44,18 @@ pub enum TextureProceduralOp {
     /// Noise field (grayscale).
     Noise { noise: NoiseConfig },
 
+    /// Anisotropic noise field (grayscale), elongated along a direction.
+    ///
+    /// Sample coordinates are rotated into the grain frame and compressed along
+    /// it, so features are `stretch` times longer along `angle`.
+    DirectionalNoise {
+        noise: NoiseConfig,
+        /// Grain direction in degrees, counter-clockwise from +x.
+        angle: f64,
+        /// Elongation factor along the grain; must be positive (1.0 = isotropic).
+        stretch: f64,
+    },
+
     /// Reaction-diffusion field (Gray-Scott, grayscale).
     ReactionDiffusion {
         /// Number of simulation steps.
@@ -97,6 +111,12 @@ pub enum TextureProceduralOp {
         color2: f64,
     },
 
+    /// Any pattern generator sampled as a grayscale source.
+    ///
+    /// Seeded patterns (brick variation, wood grain, scratches, edge wear) draw
+    /// from the node's derived seed.
+    Pattern { pattern: PatternConfig },
+
     // ---------------------------------------------------------------------
     // Grayscale ops
     // ---------------------------------------------------------------------
@@ -118,6 +138,20 @@ pub enum TextureProceduralOp {
     /// Threshold grayscale into {0,1}.
     Threshold { input: String, threshold: f64 },
 
+    /// Apply `op` with a constant `operand` to every pixel.
+    ///
+    /// Accepts grayscale or color input and produces the same kind; color
+    /// inputs have the operand applied to R, G and B while alpha is kept.
+    /// Results are not clamped to [0, 1] but always stay finite: dividing by
+    /// an operand closer to zero than 1e-6 divides by 1e-6 (with the
+    /// operand's sign) instead, and `power` raises `max(x, 0)` (at least 1e-6
+    /// for negative operands).
+    Math {
+        input: String,
+        op: MathOp,
+        operand: f64,
+    },
+
     // ---------------------------------------------------------------------
     // Blur/filter ops
     // ---------------------------------------------------------------------
@@ -130,6 +164,30 @@ pub enum TextureProceduralOp {
     /// Dilate (morphological maximum within radius).
     Dilate { input: String, radius: u32 },
 
+    /// Shade the edges of a grayscale mask like a lit bevel.
+    ///
+    /// Pixels above 0.5 are inside the mask. Height ramps from 0 at the mask
+    /// edge to 1 at `distance` pixels inside it; the edge search wraps when the
+    /// graph is tileable and ignores the image border otherwise. `grayscale`
+    /// mode produces emboss-style shading (0.5 on flat areas), `normal` mode a
+    /// tangent-space normal map of the bevel.
+    Bevel {
+        input: String,
+        /// Bevel width in pixels (must be positive).
+        distance: f64,
+        /// Blends the bevel profile from a straight ramp (0.0) to a
+        /// smoothstep curve (1.0).
+        #[serde(default)]
+        softness: f64,
+        /// Light direction in degrees, counter-clockwise from +X with +Y up
+        /// (135 = top-left). Only used in `grayscale` mode.
+        #[serde(default = "default_bevel_light_angle")]
+        light_angle: f64,
+        /// Output kind (defaults to grayscale).
+        #[serde(default)]
+        mode: BevelMode,
+    },
+
     // ---------------------------------------------------------------------
     // Warp/distortion ops
     // ---------------------------------------------------------------------
@@ -140,6 +198,20 @@ pub enum TextureProceduralOp {
         strength: f32,
     },
 
+    /// Domain warp using separate x and y displacement fields (grayscale).
+    ///
+    /// Samples `input` at `(x + amount * warp_x, y + amount * warp_y)` with
+    /// bilinear interpolation, wrapping coordinates when the graph is tileable.
+    DomainWarp {
+        input: String,
+        /// Node providing the horizontal displacement.
+        warp_x: String,
+        /// Node providing the vertical displacement.
+        warp_y: String,
+        /// Displacement scale in pixels.
+        amount: f64,
+    },
+
     // ---------------------------------------------------------------------
     // Blend modes
     // ---------------------------------------------------------------------
@@ -155,6 +227,27 @@ pub enum TextureProceduralOp {
     /// Difference blend: |base - blend|.
     BlendDifference { base: String, blend: String },
 
+    /// Blend `b` over `a` with a composite blend mode.
+    ///
+    /// Accepts grayscale or color inputs; a grayscale input is promoted to
+    /// opaque color when the other is color. The result is clamped to [0, 1]
+    /// and keeps the alpha of `a`.
+    Blend {
+        /// Base layer.
+        a: String,
+        /// Layer blended over the base.
+        b: String,
+        /// Blend mode (defaults to normal).
+        #[serde(default)]
+        mode: CompositeBlendMode,
+        /// Optional grayscale node gating the blend per pixel.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        mask: Option<String>,
+        /// Blend strength (0.0 to 1.0).
+        #[serde(default = "default_blend_opacity")]
+        opacity: f64,
+    },
+
     // ---------------------------------------------------------------------
     // UV transforms
     // ---------------------------------------------------------------------
@@ -184,9 +277,45 @@ pub enum TextureProceduralOp {
     /// Map grayscale -> color using a hex ramp.
     ColorRamp { input: String, ramp: Vec<String> },
 
+    /// Map grayscale -> color through `(position, hex_color)` stops.
+    ///
+    /// Positions must be sorted and within [0, 1]; values between stops are
+    /// linearly interpolated and values outside the first/last stop clamp.
+    GradientMap {
+        input: String,
+        stops: Vec<(f64, String)>,
+    },
+
     /// Quantize color to nearest palette entry.
     Palette { input: String, palette: Vec<String> },
 
+    /// Snap each channel to `levels` evenly spaced steps in [0, 1].
+    ///
+    /// Accepts grayscale or color input and produces the same kind. Color
+    /// channels are quantized independently; alpha is kept unless
+    /// `include_alpha` is set.
+    Posterize {
+        input: String,
+        /// Number of steps per channel (at least 2).
+        levels: u32,
+        /// Also quantize the alpha channel of color inputs.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        include_alpha: bool,
+    },
+
+    /// Posterize to `levels` steps after offsetting each pixel by a tiled
+    /// threshold matrix, trading banding for a fine pattern.
+    ///
+    /// Accepts grayscale or color input and produces the same kind; alpha is
+    /// kept. The matrices tile every 4, 8, or 16 pixels.
+    Dither {
+        input: String,
+        /// Number of steps per channel (at least 2).
+        levels: u32,
+        /// Threshold matrix.
+        method: DitherMethod,
+    },
+
     /// Compose RGBA from grayscale channels.
     ComposeRgba {
         r: String,
@@ -242,10 +371,252 @@ pub enum TextureProceduralOp {
     },
 }
 
+/// Output kind of a `bevel` node.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BevelMode {
+    /// Lit grayscale shading, 0.5 on flat areas.
+    #[default]
+    Grayscale,
+    /// Tangent-space normal map (OpenGL Y-up convention).
+    Normal,
+}
+
+/// Arithmetic applied by a `math` node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MathOp {
+    /// `x + operand`.
+    Add,
+    /// `x - operand`.
+    Subtract,
+    /// `x * operand`.
+    Multiply,
+    /// `x / operand`.
+    Divide,
+    /// `min(x, operand)`.
+    Min,
+    /// `max(x, operand)`.
+    Max,
+    /// `x ^ operand`.
+    Power,
+}
+
+/// Threshold matrix used by a `dither` node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DitherMethod {
+    /// 4x4 ordered (Bayer) matrix.
+    Bayer4,
+    /// 8x8 ordered (Bayer) matrix.
+    Bayer8,
+    /// Fixed 16x16 blue-noise tile.
+    BlueNoise,
+}
+
+/// Pattern generator configuration for the `pattern` graph node.
+///
+/// Omitted parameters take the pattern's own defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum PatternConfig {
+    /// Running-bond brick courses separated by recessed mortar.
+    Brick {
+        /// Brick width in pixels.
+        #[serde(default = "default_brick_width")]
+        brick_width: u32,
+        /// Brick height in pixels.
+        #[serde(default = "default_brick_height")]
+        brick_height: u32,
+        /// Mortar width in pixels.
+        #[serde(default = "default_mortar_width")]
+        mortar_width: u32,
+        /// Mortar depth (0.0 = flush, 1.0 = deep).
+        #[serde(default = "default_mortar_depth")]
+        mortar_depth: f64,
+        /// Per-brick height variation.
+        #[serde(default = "default_brick_variation")]
+        brick_variation: f64,
+        /// Horizontal offset of alternate rows as a fraction of a brick.
+        #[serde(default = "default_row_offset")]
+        row_offset: f64,
+    },
+
+    /// Checkerboard of alternating tiles.
+    ///
+    /// Tiles seamlessly when the resolution is a multiple of `2 * tile_size`.
+    Checker {
+        /// Tile size in pixels.
+        tile_size: u32,
+        /// Value of the tile at the origin.
+        #[serde(default = "default_pattern_color1")]
+        color1: f64,
+        /// Value of the alternate tiles.
+        #[serde(default)]
+        color2: f64,
+    },
+
+    /// Alternating stripes.
+    ///
+    /// Tiles seamlessly when the resolution across the stripes is a multiple
+    /// of `2 * stripe_width`.
+    Stripes {
+        direction: StripeDirection,
+        /// Stripe width in pixels.
+        stripe_width: u32,
+        /// Value of the first stripe.
+        #[serde(default = "default_pattern_color1")]
+        color1: f64,
+        /// Value of the alternate stripes.
+        #[serde(default)]
+        color2: f64,
+    },
+
+    /// Linear or radial gradient.
+    Gradient {
+        direction: GradientDirection,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        start: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        end: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        center: Option<[f64; 2]>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        inner: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        outer: Option<f64>,
+    },
+
+    /// Concentric wood grain rings distorted by noise.
+    WoodGrain {
+        /// Number of rings.
+        #[serde(default = "default_wood_ring_count")]
+        ring_count: u32,
+        /// Ring distortion amount.
+        #[serde(default = "default_wood_distortion")]
+        distortion: f64,
+        /// Turbulence amount.
+        #[serde(default = "default_wood_turbulence")]
+        turbulence: f64,
+        /// Scale of the distortion noise.
+        #[serde(default = "default_wood_noise_scale")]
+        noise_scale: f64,
+    },
+
+    /// Randomly placed scratch lines.
+    Scratches {
+        /// Number of scratches.
+        #[serde(default = "default_scratch_count")]
+        count: u32,
+        /// Scratch length range [min, max] as a fraction of the texture size.
+        #[serde(default = "default_scratch_length_range")]
+        length_range: [f64; 2],
+        /// Scratch width in pixels.
+        #[serde(default = "default_scratch_width")]
+        width: f64,
+        /// Scratch depth (0.0 to 1.0).
+        #[serde(default = "default_scratch_depth")]
+        depth: f64,
+    },
+
+    /// Wear mask concentrated on the edges of a procedural height field.
+    EdgeWear {
+        /// Wear amount (0.0 to 1.0).
+        #[serde(default = "default_edge_wear_amount")]
+        amount: f64,
+        /// Scale of the variation noise.
+        #[serde(default = "default_edge_wear_noise_scale")]
+        noise_scale: f64,
+        /// Edge detection threshold.
+        #[serde(default = "default_edge_wear_threshold")]
+        threshold: f64,
+    },
+}
+
+fn default_brick_width() -> u32 {
+    64
+}
+
+fn default_brick_height() -> u32 {
+    32
+}
+
+fn default_mortar_width() -> u32 {
+    4
+}
+
+fn default_mortar_depth() -> f64 {
+    0.3
+}
+
+fn default_brick_variation() -> f64 {
+    0.1
+}
+
+fn default_row_offset() -> f64 {
+    0.5
+}
+
+fn default_pattern_color1() -> f64 {
+    1.0
+}
+
+fn default_wood_ring_count() -> u32 {
+    8
+}
+
+fn default_wood_distortion() -> f64 {
+    0.3
+}
+
+fn default_wood_turbulence() -> f64 {
+    0.1
+}
+
+fn default_wood_noise_scale() -> f64 {
+    0.02
+}
+
+fn default_scratch_count() -> u32 {
+    50
+}
+
+fn default_scratch_length_range() -> [f64; 2] {
+    [0.05, 0.2]
+}
+
+fn default_scratch_width() -> f64 {
+    1.5
+}
+
+fn default_scratch_depth() -> f64 {
+    0.5
+}
+
+fn default_edge_wear_amount() -> f64 {
+    0.5
+}
+
+fn default_edge_wear_noise_scale() -> f64 {
+    0.05
+}
+
+fn default_edge_wear_threshold() -> f64 {
+    0.3
+}
+
 fn default_normal_strength() -> f64 {
     1.0
 }
 
+fn default_blend_opacity() -> f64 {
+    1.0
+}
+
+fn default_bevel_light_angle() -> f64 {
+    135.0
+}
+
 fn default_wang_blend_width() -> f64 {
     0.1
 }
@@ -306,6 +677,13 @@ mod tests {
                             octaves: 2,
                             persistence: 0.5,
                             lacunarity: 2.0,
+                            distance_metric: Default::default(),
+                            jitter: 1.0,
+                            worley_mode: Default::default(),
+                            frequency: None,
+                            orientation: None,
+                            bandwidth: None,
+                            impulses_per_cell: None,
                         },
                     },
                 },
@@ -474,6 +852,54 @@ mod tests {
         assert_eq!(reparsed, params);
     }
 
+    #[test]
+    fn blend_node_defaults_and_roundtrip() {
+        let json = r#"
+        {
+          "resolution": [16, 16],
+          "tileable": true,
+          "nodes": [
+            { "id": "a", "type": "constant", "value": 0.3 },
+            { "id": "b", "type": "constant", "value": 0.7 },
+            { "id": "m", "type": "constant", "value": 1.0 },
+            { "id": "plain", "type": "blend", "a": "a", "b": "b" },
+            { "id": "masked", "type": "blend", "a": "a", "b": "b", "mode": "multiply", "mask": "m", "opacity": 0.5 }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+
+        let plain = params.nodes.iter().find(|n| n.id == "plain").unwrap();
+        assert_eq!(
+            plain.op,
+            TextureProceduralOp::Blend {
+                a: "a".to_string(),
+                b: "b".to_string(),
+                mode: CompositeBlendMode::Normal,
+                mask: None,
+                opacity: 1.0,
+            }
+        );
+
+        let masked = params.nodes.iter().find(|n| n.id == "masked").unwrap();
+        assert_eq!(
+            masked.op,
+            TextureProceduralOp::Blend {
+                a: "a".to_string(),
+                b: "b".to_string(),
+                mode: CompositeBlendMode::Multiply,
+                mask: Some("m".to_string()),
+                opacity: 0.5,
+            }
+        );
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        assert!(!reserialized.contains("\"mask\":null"));
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
     #[test]
     fn blend_modes_roundtrip() {
         let json = r#"
@@ -633,6 +1059,181 @@ mod tests {
         assert!((*blend_width - 0.25).abs() < 1e-6);
     }
 
+    #[test]
+    fn domain_warp_roundtrip() {
+        let json = r#"
+        {
+          "resolution": [64, 64],
+          "tileable": true,
+          "nodes": [
+            { "id": "src", "type": "checkerboard", "tile_size": 8, "color1": 0.0, "color2": 1.0 },
+            { "id": "wx", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.05 } },
+            { "id": "wy", "type": "noise", "noise": { "algorithm": "simplex", "scale": 0.05 } },
+            { "id": "warped", "type": "domain_warp", "input": "src", "warp_x": "wx", "warp_y": "wy", "amount": 6.0 }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+        let node = params.nodes.iter().find(|n| n.id == "warped").unwrap();
+
+        let TextureProceduralOp::DomainWarp {
+            input,
+            warp_x,
+            warp_y,
+            amount,
+        } = &node.op
+        else {
+            panic!("expected domain_warp op");
+        };
+
+        assert_eq!(input, "src");
+        assert_eq!(warp_x, "wx");
+        assert_eq!(warp_y, "wy");
+        assert_eq!(*amount, 6.0);
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
+    #[test]
+    fn posterize_roundtrip() {
+        let json = r#"
+        {
+          "resolution": [32, 32],
+          "tileable": false,
+          "nodes": [
+            { "id": "g", "type": "gradient", "direction": "horizontal" },
+            { "id": "p", "type": "posterize", "input": "g", "levels": 4 }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+        let node = params.nodes.iter().find(|n| n.id == "p").unwrap();
+
+        let TextureProceduralOp::Posterize {
+            input,
+            levels,
+            include_alpha,
+        } = &node.op
+        else {
+            panic!("expected posterize op");
+        };
+
+        assert_eq!(input, "g");
+        assert_eq!(*levels, 4);
+        assert!(!include_alpha);
+
+        // `include_alpha: false` is omitted on output
+        let reserialized = serde_json::to_string(&params).unwrap();
+        assert!(!reserialized.contains("include_alpha"));
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
+    #[test]
+    fn math_roundtrip() {
+        let json = r#"
+        {
+          "resolution": [16, 16],
+          "tileable": false,
+          "nodes": [
+            { "id": "c", "type": "constant", "value": 0.5 },
+            { "id": "m", "type": "math", "input": "c", "op": "power", "operand": 2.0 }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            params.nodes[1].op,
+            TextureProceduralOp::Math {
+                input: "c".to_string(),
+                op: MathOp::Power,
+                operand: 2.0,
+            }
+        );
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
+    #[test]
+    fn dither_roundtrip() {
+        let json = r#"
+        {
+          "resolution": [16, 16],
+          "tileable": true,
+          "nodes": [
+            { "id": "g", "type": "gradient", "direction": "horizontal" },
+            { "id": "d", "type": "dither", "input": "g", "levels": 4, "method": "blue_noise" }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            params.nodes[1].op,
+            TextureProceduralOp::Dither {
+                input: "g".to_string(),
+                levels: 4,
+                method: DitherMethod::BlueNoise,
+            }
+        );
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        assert!(reserialized.contains(r#""method":"blue_noise""#));
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+
+        let bayer: DitherMethod = serde_json::from_str(r#""bayer8""#).unwrap();
+        assert_eq!(bayer, DitherMethod::Bayer8);
+    }
+
+    #[test]
+    fn bevel_roundtrip_and_defaults() {
+        let json = r#"
+        {
+          "resolution": [32, 32],
+          "tileable": true,
+          "nodes": [
+            { "id": "c", "type": "checkerboard", "tile_size": 8, "color1": 0.0, "color2": 1.0 },
+            { "id": "b", "type": "bevel", "input": "c", "distance": 3.0 },
+            { "id": "n", "type": "bevel", "input": "c", "distance": 2.0, "softness": 0.5, "light_angle": 45.0, "mode": "normal" }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+
+        let TextureProceduralOp::Bevel {
+            distance,
+            softness,
+            light_angle,
+            mode,
+            ..
+        } = &params.nodes[1].op
+        else {
+            panic!("expected bevel op");
+        };
+        assert_eq!(*distance, 3.0);
+        assert_eq!(*softness, 0.0);
+        assert_eq!(*light_angle, 135.0);
+        assert_eq!(*mode, BevelMode::Grayscale);
+
+        let TextureProceduralOp::Bevel { mode, .. } = &params.nodes[2].op else {
+            panic!("expected bevel op");
+        };
+        assert_eq!(*mode, BevelMode::Normal);
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
     #[test]
     fn texture_bomb_roundtrip() {
         let json = r#"
@@ -801,4 +1402,47 @@ mod tests {
         assert!((*dt - 1.0).abs() < 1e-6);
         assert!((*seed_density - 0.03).abs() < 1e-6);
     }
+
+    #[test]
+    fn pattern_node_parses_with_pattern_defaults() {
+        let json = r#"
+        {
+          "resolution": [64, 64],
+          "tileable": true,
+          "nodes": [
+            { "id": "c", "type": "pattern", "pattern": { "type": "checker", "tile_size": 8 } },
+            { "id": "b", "type": "pattern", "pattern": { "type": "brick", "brick_width": 32 } }
+          ]
+        }
+        "#;
+
+        let params: TextureProceduralV1Params = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            params.nodes[0].op,
+            TextureProceduralOp::Pattern {
+                pattern: PatternConfig::Checker {
+                    tile_size: 8,
+                    color1: 1.0,
+                    color2: 0.0,
+                },
+            }
+        );
+        let TextureProceduralOp::Pattern {
+            pattern:
+                PatternConfig::Brick {
+                    brick_width,
+                    brick_height,
+                    mortar_width,
+                    ..
+                },
+        } = &params.nodes[1].op
+        else {
+            panic!("expected brick pattern");
+        };
+        assert_eq!((*brick_width, *brick_height, *mortar_width), (32, 32, 4));
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        let reparsed: TextureProceduralV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
 }
@@ -4,10 +4,11 @@
 //! material layers (grass, dirt, rock, etc.), blend masks (RGBA splat masks),
 //! per-layer PBR outputs, and macro variation overlays.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `texture.splat_set_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureSplatSetV1Params {
     /// Texture resolution [width, height] in pixels.
@@ -44,7 +45,7 @@ fn default_macro_intensity() -> f64 {
 }
 
 /// A single terrain layer in the splat set.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SplatLayer {
     /// Unique layer identifier (e.g., "grass", "dirt", "rock").
@@ -82,7 +83,7 @@ fn default_detail_intensity() -> f64 {
 }
 
 /// Mode for generating splat mask blending.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SplatMaskMode {
     /// Pure noise-based blending (uniform distribution).
@@ -99,7 +100,7 @@ pub enum SplatMaskMode {
 /// Metadata output for a generated splat set.
 ///
 /// This is written to `{asset_id}.splat.json` alongside the texture outputs.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SplatSetOutputMetadata {
     /// Texture resolution [width, height] in pixels.
     pub resolution: [u32; 2],
@@ -114,7 +115,7 @@ pub struct SplatSetOutputMetadata {
 }
 
 /// Metadata for a single splat layer in the output.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SplatLayerMetadata {
     /// Layer identifier.
     pub id: String,
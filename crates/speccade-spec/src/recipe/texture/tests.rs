@@ -16,6 +16,8 @@ fn test_texture_params_name() {
         layers: vec![],
         palette: None,
         color_ramp: None,
+        height_bit_depth: 8,
+        normal: None,
     };
     // Name is part of the recipe spec, not params
     assert_eq!(params.resolution, [256, 256]);
@@ -31,12 +33,33 @@ fn test_texture_params_resolution() {
         layers: vec![],
         palette: None,
         color_ramp: None,
+        height_bit_depth: 8,
+        normal: None,
     };
     let json = serde_json::to_string(&params).unwrap();
     let parsed: TextureMaterialV1Params = serde_json::from_str(&json).unwrap();
     assert_eq!(parsed.resolution, [512, 1024]);
 }
 
+#[test]
+fn test_texture_params_height_bit_depth() {
+    let json = r#"{"resolution": [64, 64], "tileable": true, "maps": ["height"]}"#;
+    let parsed: TextureMaterialV1Params = serde_json::from_str(json).unwrap();
+    assert_eq!(parsed.height_bit_depth, 8);
+    // The default is omitted so existing specs keep their hashes
+    assert!(!serde_json::to_string(&parsed)
+        .unwrap()
+        .contains("height_bit_depth"));
+
+    let json =
+        r#"{"resolution": [64, 64], "tileable": true, "maps": ["height"], "height_bit_depth": 16}"#;
+    let parsed: TextureMaterialV1Params = serde_json::from_str(json).unwrap();
+    assert_eq!(parsed.height_bit_depth, 16);
+    let reparsed: TextureMaterialV1Params =
+        serde_json::from_str(&serde_json::to_string(&parsed).unwrap()).unwrap();
+    assert_eq!(reparsed, parsed);
+}
+
 #[test]
 fn test_texture_params_format_serialization() {
     let params = TextureMaterialV1Params {
@@ -47,6 +70,8 @@ fn test_texture_params_format_serialization() {
         layers: vec![],
         palette: None,
         color_ramp: None,
+        height_bit_depth: 8,
+        normal: None,
     };
     let json = serde_json::to_string(&params).unwrap();
     assert!(json.contains("resolution"));
@@ -73,6 +98,8 @@ fn test_texture_params_layers() {
         layers: vec![layer.clone()],
         palette: None,
         color_ramp: None,
+        height_bit_depth: 8,
+        normal: None,
     };
     let json = serde_json::to_string(&params).unwrap();
     let parsed: TextureMaterialV1Params = serde_json::from_str(&json).unwrap();
@@ -93,6 +120,8 @@ fn test_texture_params_palette() {
             "#0000FF".to_string(),
         ]),
         color_ramp: None,
+        height_bit_depth: 8,
+        normal: None,
     };
     let json = serde_json::to_string(&params).unwrap();
     let parsed: TextureMaterialV1Params = serde_json::from_str(&json).unwrap();
@@ -109,6 +138,8 @@ fn test_texture_params_color_ramp() {
         layers: vec![],
         palette: None,
         color_ramp: Some(vec!["#000000".to_string(), "#FFFFFF".to_string()]),
+        height_bit_depth: 8,
+        normal: None,
     };
     let json = serde_json::to_string(&params).unwrap();
     let parsed: TextureMaterialV1Params = serde_json::from_str(&json).unwrap();
@@ -129,6 +160,13 @@ fn test_layer_solid_serde() {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         affects: vec![TextureMapType::Albedo],
         strength: 1.0,
@@ -146,6 +184,8 @@ fn test_layer_noise_all_algorithms() {
         NoiseAlgorithm::Value,
         NoiseAlgorithm::Gabor,
         NoiseAlgorithm::Fbm,
+        NoiseAlgorithm::RidgedMultifractal,
+        NoiseAlgorithm::Billow,
     ] {
         let layer = TextureLayer::NoisePattern {
             noise: NoiseConfig {
@@ -154,6 +194,13 @@ fn test_layer_noise_all_algorithms() {
                 octaves: 4,
                 persistence: 0.5,
                 lacunarity: 2.0,
+                distance_metric: Default::default(),
+                jitter: 1.0,
+                worley_mode: Default::default(),
+                frequency: None,
+                orientation: None,
+                bandwidth: None,
+                impulses_per_cell: None,
             },
             affects: vec![TextureMapType::Albedo],
             strength: 1.0,
@@ -293,6 +340,13 @@ fn test_layer_pitting() {
             octaves: 3,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.6,
         depth: 0.2,
@@ -367,6 +421,13 @@ fn test_layer_stains() {
             octaves: 3,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.7,
         color: [0.2, 0.18, 0.15],
@@ -388,6 +449,13 @@ fn test_layer_water_streaks() {
             octaves: 4,
             persistence: 0.6,
             lacunarity: 2.2,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         threshold: 0.65,
         direction: StripeDirection::Vertical,
@@ -423,6 +491,13 @@ fn test_layer_blend_mode_default() {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
         affects: vec![TextureMapType::Albedo],
         strength: 0.5,
@@ -458,6 +533,13 @@ fn test_noise_config_perlin() {
         octaves: 6,
         persistence: 0.6,
         lacunarity: 2.5,
+        distance_metric: Default::default(),
+        jitter: 1.0,
+        worley_mode: Default::default(),
+        frequency: None,
+        orientation: None,
+        bandwidth: None,
+        impulses_per_cell: None,
     };
     let json = serde_json::to_string(&noise).unwrap();
     assert!(json.contains("perlin"));
@@ -471,6 +553,13 @@ fn test_noise_config_simplex() {
         octaves: 4,
         persistence: 0.5,
         lacunarity: 2.0,
+        distance_metric: Default::default(),
+        jitter: 1.0,
+        worley_mode: Default::default(),
+        frequency: None,
+        orientation: None,
+        bandwidth: None,
+        impulses_per_cell: None,
     };
     let json = serde_json::to_string(&noise).unwrap();
     assert!(json.contains("simplex"));
@@ -484,6 +573,13 @@ fn test_noise_config_worley() {
         octaves: 3,
         persistence: 0.5,
         lacunarity: 2.0,
+        distance_metric: Default::default(),
+        jitter: 1.0,
+        worley_mode: Default::default(),
+        frequency: None,
+        orientation: None,
+        bandwidth: None,
+        impulses_per_cell: None,
     };
     let json = serde_json::to_string(&noise).unwrap();
     assert!(json.contains("worley"));
@@ -497,6 +593,13 @@ fn test_noise_config_defaults() {
         octaves: 4,
         persistence: 0.5,
         lacunarity: 2.0,
+        distance_metric: Default::default(),
+        jitter: 1.0,
+        worley_mode: Default::default(),
+        frequency: None,
+        orientation: None,
+        bandwidth: None,
+        impulses_per_cell: None,
     };
     assert_eq!(noise.octaves, common::default_octaves());
     assert_eq!(noise.persistence, common::default_persistence());
@@ -510,6 +613,91 @@ fn test_noise_config_serde_defaults_when_omitted() {
     assert_eq!(noise.octaves, common::default_octaves());
     assert_eq!(noise.persistence, common::default_persistence());
     assert_eq!(noise.lacunarity, common::default_lacunarity());
+    assert_eq!(noise.distance_metric, WorleyDistanceMetric::Euclidean);
+    assert_eq!(noise.jitter, common::default_jitter());
+}
+
+#[test]
+fn test_noise_config_jitter_roundtrip() {
+    let json = r#"{"algorithm":"worley","scale":0.1,"jitter":0.0}"#;
+    let noise: NoiseConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(noise.jitter, 0.0);
+    let serialized = serde_json::to_string(&noise).unwrap();
+    assert!(serialized.contains("\"jitter\":0.0"));
+    assert_eq!(
+        serde_json::from_str::<NoiseConfig>(&serialized).unwrap(),
+        noise
+    );
+
+    // Full jitter is the default and is omitted
+    let noise = NoiseConfig {
+        jitter: 1.0,
+        ..noise
+    };
+    assert!(!serde_json::to_string(&noise).unwrap().contains("jitter"));
+}
+
+#[test]
+fn test_noise_config_distance_metric_roundtrip() {
+    let json = r#"{"algorithm":"worley","scale":0.1,"distance_metric":"manhattan"}"#;
+    let noise: NoiseConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(noise.distance_metric, WorleyDistanceMetric::Manhattan);
+    let reparsed: NoiseConfig =
+        serde_json::from_str(&serde_json::to_string(&noise).unwrap()).unwrap();
+    assert_eq!(reparsed, noise);
+
+    // The default metric is omitted so existing specs serialize unchanged
+    let noise = NoiseConfig {
+        distance_metric: WorleyDistanceMetric::Euclidean,
+        ..noise
+    };
+    assert!(!serde_json::to_string(&noise)
+        .unwrap()
+        .contains("distance_metric"));
+}
+
+#[test]
+fn test_noise_config_worley_mode_roundtrip() {
+    let json = r#"{"algorithm":"worley","scale":0.1,"worley_mode":"f2_minus_f1"}"#;
+    let noise: NoiseConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(noise.worley_mode, WorleyMode::F2MinusF1);
+    let reparsed: NoiseConfig =
+        serde_json::from_str(&serde_json::to_string(&noise).unwrap()).unwrap();
+    assert_eq!(reparsed, noise);
+
+    let json = r#"{"algorithm":"worley","scale":0.1,"worley_mode":"cell_value"}"#;
+    let noise: NoiseConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(noise.worley_mode, WorleyMode::CellValue);
+
+    // F1 is the default and is omitted
+    let noise = NoiseConfig {
+        worley_mode: WorleyMode::F1,
+        ..noise
+    };
+    assert!(!serde_json::to_string(&noise)
+        .unwrap()
+        .contains("worley_mode"));
+}
+
+#[test]
+fn test_noise_config_gabor_params_roundtrip() {
+    let json = r#"{"algorithm":"gabor","scale":0.1,"frequency":2.5,"orientation":30.0,"bandwidth":0.6,"impulses_per_cell":6}"#;
+    let noise: NoiseConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(noise.frequency, Some(2.5));
+    assert_eq!(noise.orientation, Some(30.0));
+    assert_eq!(noise.bandwidth, Some(0.6));
+    assert_eq!(noise.impulses_per_cell, Some(6));
+    let reparsed: NoiseConfig =
+        serde_json::from_str(&serde_json::to_string(&noise).unwrap()).unwrap();
+    assert_eq!(reparsed, noise);
+
+    // Unset kernel parameters are omitted so existing specs serialize unchanged
+    let json = r#"{"algorithm":"gabor","scale":0.1}"#;
+    let noise: NoiseConfig = serde_json::from_str(json).unwrap();
+    let serialized = serde_json::to_string(&noise).unwrap();
+    for field in ["frequency", "orientation", "bandwidth", "impulses_per_cell"] {
+        assert!(!serialized.contains(field), "{} should be omitted", field);
+    }
 }
 
 #[test]
@@ -530,6 +718,8 @@ fn test_normal_map_params_name() {
         tileable: false,
         pattern: None,
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
     assert_eq!(params.resolution, [256, 256]);
@@ -542,6 +732,8 @@ fn test_normal_map_params_resolution() {
         tileable: true,
         pattern: None,
         bump_strength: 1.5,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
     let json = serde_json::to_string(&params).unwrap();
@@ -556,12 +748,43 @@ fn test_normal_map_params_format() {
         tileable: true,
         pattern: None,
         bump_strength: 1.0,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
     let json = serde_json::to_string(&params).unwrap();
     assert!(json.contains("resolution"));
 }
 
+#[test]
+fn test_normal_map_flip_fields_are_optional() {
+    let json = r#"{"resolution":[64,64],"tileable":true}"#;
+    let params: TextureNormalV1Params = serde_json::from_str(json).unwrap();
+    assert!(!params.flip_y);
+    assert!(!params.flip_x);
+    // Default convention is omitted, so existing specs keep their hashes
+    let out = serde_json::to_string(&params).unwrap();
+    assert!(!out.contains("flip_"));
+
+    let json = r#"{"resolution":[64,64],"tileable":true,"flip_y":true}"#;
+    let params: TextureNormalV1Params = serde_json::from_str(json).unwrap();
+    assert!(params.flip_y);
+}
+
+#[test]
+fn test_normal_map_config_defaults() {
+    let config: NormalMapConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(config, NormalMapConfig::default());
+    assert_eq!(config.strength, 1.0);
+
+    let config: NormalMapConfig =
+        serde_json::from_str(r#"{"strength":2.0,"flip_y":true}"#).unwrap();
+    assert_eq!(config.strength, 2.0);
+    assert!(config.flip_y);
+    assert!(!config.flip_x);
+    assert!(serde_json::from_str::<NormalMapConfig>(r#"{"flip_z":true}"#).is_err());
+}
+
 #[test]
 fn test_normal_map_pattern_bricks() {
     let pattern = NormalMapPattern::Bricks {
@@ -604,6 +827,13 @@ fn test_normal_map_pattern_noise() {
             octaves: 4,
             persistence: 0.5,
             lacunarity: 2.0,
+            distance_metric: Default::default(),
+            jitter: 1.0,
+            worley_mode: Default::default(),
+            frequency: None,
+            orientation: None,
+            bandwidth: None,
+            impulses_per_cell: None,
         },
     };
     let json = serde_json::to_string(&pattern).unwrap();
@@ -671,6 +901,8 @@ fn test_normal_map_processing_strength() {
         tileable: false,
         pattern: None,
         bump_strength: 2.5,
+        flip_y: false,
+        flip_x: false,
         processing: None,
     };
     assert_eq!(params.bump_strength, 2.5);
@@ -790,6 +1022,7 @@ fn test_texture_map_type_all() {
         TextureMapType::Ao,
         TextureMapType::Emissive,
         TextureMapType::Height,
+        TextureMapType::Curvature,
     ] {
         let json = serde_json::to_string(&map_type).unwrap();
         let parsed: TextureMapType = serde_json::from_str(&json).unwrap();
@@ -828,6 +1061,13 @@ fn test_full_texture_params_roundtrip() {
                     octaves: 4,
                     persistence: 0.5,
                     lacunarity: 2.0,
+                    distance_metric: Default::default(),
+                    jitter: 1.0,
+                    worley_mode: Default::default(),
+                    frequency: None,
+                    orientation: None,
+                    bandwidth: None,
+                    impulses_per_cell: None,
                 },
                 affects: vec![TextureMapType::Roughness],
                 strength: 0.3,
@@ -842,6 +1082,8 @@ fn test_full_texture_params_roundtrip() {
         ],
         palette: Some(vec!["#FF0000".to_string(), "#00FF00".to_string()]),
         color_ramp: Some(vec!["#000000".to_string(), "#FFFFFF".to_string()]),
+        height_bit_depth: 8,
+        normal: None,
     };
 
     let json = serde_json::to_string_pretty(&params).unwrap();
@@ -863,6 +1105,8 @@ fn test_full_normal_map_params_roundtrip() {
             offset: 0.5,
         }),
         bump_strength: 1.5,
+        flip_y: false,
+        flip_x: false,
         processing: Some(NormalMapProcessing {
             blur: Some(1.0),
             invert: false,
@@ -0,0 +1,98 @@
+//! Tiled sheet recipe types.
+//!
+//! `texture.tile_variation_v1` evaluates one procedural tile graph and repeats
+//! it across a larger sheet, with seeded per-tile transforms to break up
+//! obvious repetition.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::procedural::TextureProceduralNode;
+
+/// Parameters for the `texture.tile_variation_v1` recipe.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TextureTileVariationV1Params {
+    /// Tile resolution [width, height] in pixels.
+    pub tile_resolution: [u32; 2],
+    /// Whether the tile graph should tile seamlessly.
+    pub tileable: bool,
+    /// Tile graph nodes (same node set as `texture.procedural_v1`).
+    pub nodes: Vec<TextureProceduralNode>,
+    /// Id of the node in `nodes` used as the tile.
+    pub output: String,
+    /// Number of tiles [columns, rows] in the sheet.
+    pub tiles: [u32; 2],
+    /// Per-tile transforms; all disabled by default (plain tiling).
+    #[serde(default)]
+    pub variation: TileVariation,
+}
+
+/// Seeded per-tile transforms for `texture.tile_variation_v1`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TileVariation {
+    /// Rotate each tile by a random multiple of 90 degrees (180 for non-square tiles).
+    #[serde(default)]
+    pub rotate: bool,
+    /// Randomly mirror each tile horizontally and/or vertically.
+    #[serde(default)]
+    pub flip: bool,
+    /// Maximum hue shift in degrees (0 to 180) applied to color tiles.
+    #[serde(default)]
+    pub hue_shift: f64,
+}
+
+impl TileVariation {
+    /// Returns true if no transform is enabled.
+    pub fn is_none(&self) -> bool {
+        !self.rotate && !self.flip && self.hue_shift == 0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile_variation_params_roundtrip() {
+        let json = r#"
+        {
+          "tile_resolution": [32, 32],
+          "tileable": true,
+          "nodes": [{ "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } }],
+          "output": "n",
+          "tiles": [4, 2],
+          "variation": { "rotate": true, "hue_shift": 20.0 }
+        }
+        "#;
+
+        let params: TextureTileVariationV1Params = serde_json::from_str(json).unwrap();
+        assert_eq!(params.tiles, [4, 2]);
+        assert!(params.variation.rotate);
+        assert!(!params.variation.flip);
+        assert_eq!(params.variation.hue_shift, 20.0);
+        assert!(!params.variation.is_none());
+
+        let reserialized = serde_json::to_string(&params).unwrap();
+        let reparsed: TextureTileVariationV1Params = serde_json::from_str(&reserialized).unwrap();
+        assert_eq!(reparsed, params);
+    }
+
+    #[test]
+    fn tile_variation_defaults_to_plain_tiling() {
+        let json = r#"
+        {
+          "tile_resolution": [8, 8],
+          "tileable": false,
+          "nodes": [{ "id": "c", "type": "constant", "value": 0.5 }],
+          "output": "c",
+          "tiles": [2, 2]
+        }
+        "#;
+
+        let params: TextureTileVariationV1Params = serde_json::from_str(json).unwrap();
+        assert!(params.variation.is_none());
+        assert!(serde_json::from_str::<TileVariation>(r#"{ "mirror": true }"#).is_err());
+    }
+}
@@ -3,10 +3,11 @@
 //! `texture.trimsheet_v1` packs multiple tile definitions into a single atlas
 //! texture with deterministic shelf packing, mip-safe gutters, and UV metadata output.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `texture.trimsheet_v1` recipe.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct TextureTrimsheetV1Params {
     /// Atlas resolution [width, height] in pixels.
@@ -23,7 +24,7 @@ fn default_padding() -> u32 {
 }
 
 /// A tile definition for trimsheet packing.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TrimsheetTile {
     /// Unique identifier for this tile.
     pub id: String,
@@ -37,7 +38,7 @@ pub struct TrimsheetTile {
 }
 
 /// Source for tile content.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(untagged)]
 pub enum TileSource {
     /// Solid color fill.
@@ -53,7 +54,7 @@ pub enum TileSource {
 }
 
 /// UV rectangle for a packed tile in normalized [0, 1] coordinates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TileUvRect {
     /// Tile identifier.
     pub id: String,
@@ -72,7 +73,7 @@ pub struct TileUvRect {
 }
 
 /// Metadata output for a packed trimsheet atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct TrimsheetMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
@@ -84,6 +85,42 @@ pub struct TrimsheetMetadata {
     pub tiles: Vec<TileUvRect>,
 }
 
+/// A packed region of a trimsheet, in both normalized UV and pixel coordinates.
+///
+/// Rects cover the inner tile content only; the gutter around it is excluded.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TrimsheetRegion {
+    /// Tile identifier.
+    pub id: String,
+    /// Left edge U coordinate (0-1).
+    pub u_min: f64,
+    /// Top edge V coordinate (0-1), measured from the top row of the image.
+    pub v_min: f64,
+    /// Right edge U coordinate (0-1).
+    pub u_max: f64,
+    /// Bottom edge V coordinate (0-1), measured from the top row of the image.
+    pub v_max: f64,
+    /// Left edge in pixels.
+    pub x: u32,
+    /// Top edge in pixels.
+    pub y: u32,
+    /// Region width in pixels.
+    pub width: u32,
+    /// Region height in pixels.
+    pub height: u32,
+}
+
+/// UV layout of a packed trimsheet, written as a JSON sidecar next to the atlas PNG.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TrimsheetLayout {
+    /// Atlas width in pixels.
+    pub atlas_width: u32,
+    /// Atlas height in pixels.
+    pub atlas_height: u32,
+    /// Packed regions, in tile declaration order.
+    pub regions: Vec<TrimsheetRegion>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,6 +3,7 @@
 //! Damage number sprites are UI elements for displaying combat feedback (damage, healing, etc.)
 //! with customizable styles. Multiple style variants are packed into a single atlas.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::DEFAULT_UI_PADDING;
@@ -11,7 +12,7 @@ use super::DEFAULT_UI_PADDING;
 ///
 /// Generates an atlas of damage number digit sprites with multiple style variants.
 /// Each style has its own color scheme for text, outline, and optional glow effect.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct UiDamageNumberV1Params {
     /// Base glyph size [width, height] in pixels for each digit.
@@ -34,7 +35,7 @@ fn default_padding() -> u32 {
 }
 
 /// A damage number style variant defining visual appearance.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DamageNumberStyle {
     /// Style type identifier.
@@ -58,7 +59,7 @@ pub struct DamageNumberStyle {
 }
 
 /// Style type enumeration for damage numbers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum DamageNumberStyleType {
     /// Normal damage (default style).
@@ -96,7 +97,7 @@ impl std::fmt::Display for DamageNumberStyleType {
 }
 
 /// Glyph entry in the metadata output.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct DamageNumberGlyph {
     /// Character represented (e.g., "0", "1", "+", "-", ".").
     pub char_code: String,
@@ -112,7 +113,7 @@ pub struct DamageNumberGlyph {
 }
 
 /// Style entry in the metadata output.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct DamageNumberStyleMetadata {
     /// Style type identifier.
     pub style: String,
@@ -128,7 +129,7 @@ pub struct DamageNumberStyleMetadata {
 }
 
 /// Metadata output for a damage number atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct DamageNumberMetadata {
     /// Atlas dimensions [width, height] in pixels.
     pub atlas_size: [u32; 2],
@@ -3,6 +3,7 @@
 //! Icon sets pack multiple icon frames into a sprite atlas with labeled entries,
 //! ideal for UI icon libraries, button sets, and status indicators.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::DEFAULT_UI_PADDING;
@@ -11,7 +12,7 @@ use super::DEFAULT_UI_PADDING;
 ///
 /// Packs icon frames into a sprite atlas with deterministic shelf packing.
 /// Icons are rendered as solid color shapes (placeholders in v1).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct UiIconSetV1Params {
     /// Atlas resolution [width, height] in pixels.
@@ -30,7 +31,7 @@ fn default_ui_padding() -> u32 {
 }
 
 /// An icon entry definition for atlas packing.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct IconEntry {
     /// Unique identifier for this icon (e.g., "close", "settings", "heart").
     pub id: String,
@@ -52,7 +53,7 @@ pub struct IconEntry {
 }
 
 /// UV rectangle for a packed icon in normalized [0, 1] coordinates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct IconUv {
     /// Icon identifier.
     pub id: String,
@@ -81,7 +82,7 @@ pub struct IconUv {
 }
 
 /// Metadata output for an icon set atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct IconSetMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
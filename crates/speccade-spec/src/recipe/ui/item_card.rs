@@ -3,6 +3,7 @@
 //! Item cards are UI templates with customizable slots for icon, rarity indicator,
 //! and background. Multiple rarity variants are packed into a single atlas.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::DEFAULT_UI_PADDING;
@@ -11,7 +12,7 @@ use super::DEFAULT_UI_PADDING;
 ///
 /// Generates an atlas of item card templates for different rarity tiers.
 /// Each variant includes background, border, and slot regions for runtime composition.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct UiItemCardV1Params {
     /// Card resolution [width, height] in pixels for each individual card.
@@ -49,7 +50,7 @@ fn default_corner_radius() -> u32 {
 }
 
 /// A rarity tier preset defining visual appearance.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct RarityPreset {
     /// Rarity tier identifier.
@@ -68,7 +69,7 @@ pub struct RarityPreset {
 }
 
 /// Rarity tier enumeration.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RarityTier {
     /// Common items (default, lowest tier).
@@ -114,7 +115,7 @@ impl std::fmt::Display for RarityTier {
 }
 
 /// Slot layout definitions for an item card.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct ItemCardSlots {
     /// Icon slot region [x, y, width, height] in pixels.
@@ -129,7 +130,7 @@ pub struct ItemCardSlots {
 }
 
 /// UV rectangle in normalized [0, 1] coordinates for atlas sampling.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ItemCardUv {
     /// Left edge U coordinate (0-1).
     pub u_min: f64,
@@ -157,7 +158,7 @@ impl ItemCardUv {
 }
 
 /// Slot region in pixel coordinates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct SlotRegion {
     /// X offset within the card.
     pub x: u32,
@@ -182,7 +183,7 @@ impl SlotRegion {
 }
 
 /// Variant entry in the metadata output.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ItemCardVariant {
     /// Rarity tier identifier.
     pub tier: String,
@@ -195,7 +196,7 @@ pub struct ItemCardVariant {
 }
 
 /// Slot regions for a card variant.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ItemCardSlotRegions {
     /// Icon slot region.
     pub icon: SlotRegion,
@@ -206,7 +207,7 @@ pub struct ItemCardSlotRegions {
 }
 
 /// Metadata output for an item card atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ItemCardMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
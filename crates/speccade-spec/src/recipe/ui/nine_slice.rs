@@ -3,6 +3,7 @@
 //! Nine-slice scaling divides a panel into 9 regions (corners, edges, center)
 //! to enable scalable UI elements that preserve corner detail.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::DEFAULT_UI_PADDING;
@@ -11,7 +12,7 @@ use super::DEFAULT_UI_PADDING;
 ///
 /// Generates a nine-slice panel texture with corner/edge/center regions.
 /// The panel is packed into an atlas with region metadata for runtime scaling.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct UiNineSliceV1Params {
     /// Atlas resolution [width, height] in pixels.
@@ -39,7 +40,7 @@ fn default_ui_padding() -> u32 {
 ///
 /// Defines the visual content for each of the 9 regions (corners, edges, center).
 /// All regions use solid colors in v1 (future versions may support procedural textures).
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct NineSliceRegions {
     /// Corner dimensions in pixels [width, height].
@@ -93,7 +94,7 @@ pub struct NineSliceRegions {
 ///
 /// Contains UV coordinates and dimensions for each of the 9 regions,
 /// allowing runtime UI systems to scale panels correctly.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct NineSliceMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
@@ -109,7 +110,7 @@ pub struct NineSliceMetadata {
 }
 
 /// UV coordinates for nine-slice regions in normalized [0, 1] space.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct NineSliceUvRegions {
     /// Top-left corner UV rectangle.
     pub top_left: UvRect,
@@ -140,7 +141,7 @@ pub struct NineSliceUvRegions {
 }
 
 /// UV rectangle in normalized [0, 1] coordinates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct UvRect {
     /// Left edge U coordinate (0-1).
     pub u_min: f64,
@@ -3,13 +3,14 @@
 //! Defines parameters for generating flipbook-style VFX animations (explosions,
 //! smoke, particles, etc.) with deterministic frame generation and atlas packing.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `vfx.flipbook_v1` recipe.
 ///
 /// Generates a flipbook animation sequence for visual effects. Frames are
 /// generated procedurally and packed into an atlas with deterministic packing.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct VfxFlipbookV1Params {
     /// Atlas resolution [width, height] in pixels.
@@ -51,7 +52,7 @@ fn default_fps() -> u32 {
 }
 
 /// Effect type for flipbook generation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum FlipbookEffectType {
     /// Expanding explosion effect with radial gradient and noise.
@@ -68,7 +69,7 @@ pub enum FlipbookEffectType {
 }
 
 /// Playback loop mode for flipbook animations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum FlipbookLoopMode {
     /// Play once and stop on last frame.
@@ -83,7 +84,7 @@ pub enum FlipbookLoopMode {
 }
 
 /// UV rectangle for a packed flipbook frame in normalized [0, 1] coordinates.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct FlipbookFrameUv {
     /// Frame index in the animation sequence.
     pub index: u32,
@@ -108,7 +109,7 @@ pub struct FlipbookFrameUv {
 }
 
 /// Metadata output for a packed flipbook atlas.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct VfxFlipbookMetadata {
     /// Atlas width in pixels.
     pub atlas_width: u32,
@@ -4,6 +4,7 @@
 //! recipe that outputs JSON describing blend modes, color grading, and distortion
 //! properties for particle effects.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Parameters for the `vfx.particle_profile_v1` recipe.
@@ -11,7 +12,7 @@ use serde::{Deserialize, Serialize};
 /// Generates metadata describing a particle rendering profile for VFX systems.
 /// This is a metadata-only output (JSON) that provides rendering hints for game
 /// engines, not actual texture generation.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct VfxParticleProfileV1Params {
     /// The particle profile type (determines blend mode and rendering behavior).
@@ -34,7 +35,7 @@ pub struct VfxParticleProfileV1Params {
 }
 
 /// Particle profile type determining blend mode and rendering behavior.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ParticleProfileType {
     /// Additive blending (bright, glowing effects like fire, sparks, magic).
@@ -57,7 +58,7 @@ pub enum ParticleProfileType {
 }
 
 /// Metadata output for a particle profile.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct VfxParticleProfileMetadata {
     /// The profile type.
     pub profile: ParticleProfileType,
@@ -79,7 +80,7 @@ pub struct VfxParticleProfileMetadata {
 }
 
 /// Shader configuration hints for game engines.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ShaderHints {
     /// Whether depth writing should be disabled.
     pub depth_write: bool,
@@ -1,7 +1,8 @@
 //! Builder pattern for creating reports.
 
 use super::{
-    LintReportData, OutputResult, Report, ReportError, ReportWarning, StageTiming, REPORT_VERSION,
+    determinism_tier, LintReportData, MetricTolerances, OutputResult, Report, ReportError,
+    ReportWarning, StageTiming, REPORT_VERSION, TIER_METRIC_VALIDATED,
 };
 use crate::error::{ValidationError, ValidationWarning};
 use crate::spec::{AssetType, Spec};
@@ -20,6 +21,8 @@ pub struct ReportBuilder {
     source_kind: Option<String>,
     source_hash: Option<String>,
     stdlib_version: Option<String>,
+    determinism_tier: Option<u8>,
+    metric_tolerances: Option<MetricTolerances>,
     ok: bool,
     errors: Vec<ReportError>,
     warnings: Vec<ReportWarning>,
@@ -68,6 +71,8 @@ impl ReportBuilder {
             source_kind: None,
             source_hash: None,
             stdlib_version: None,
+            determinism_tier: None,
+            metric_tolerances: None,
             ok: true,
             errors: Vec::new(),
             warnings: Vec::new(),
@@ -89,12 +94,39 @@ impl ReportBuilder {
     }
 
     /// Adds provenance metadata from a spec.
+    ///
+    /// Also records the determinism tier of the spec's recipe kind, with
+    /// default metric tolerances for Tier 2 recipes.
     pub fn spec_metadata(mut self, spec: &Spec) -> Self {
         self.asset_id = Some(spec.asset_id.clone());
         self.asset_type = Some(spec.asset_type);
         self.license = Some(spec.license.clone());
         self.seed = Some(spec.seed);
         self.recipe_kind = spec.recipe.as_ref().map(|r| r.kind.clone());
+        if let Some(kind) = spec.recipe.as_ref().and_then(|r| r.parse_kind()) {
+            self = self.determinism_tier(determinism_tier(&kind));
+        }
+        self
+    }
+
+    /// Sets the determinism tier.
+    ///
+    /// Setting Tier 2 records default metric tolerances unless tolerances were
+    /// already set; other tiers clear them.
+    pub fn determinism_tier(mut self, tier: u8) -> Self {
+        self.determinism_tier = Some(tier);
+        if tier == TIER_METRIC_VALIDATED {
+            self.metric_tolerances
+                .get_or_insert_with(MetricTolerances::default);
+        } else {
+            self.metric_tolerances = None;
+        }
+        self
+    }
+
+    /// Sets the metric tolerances used for Tier 2 verification.
+    pub fn metric_tolerances(mut self, tolerances: MetricTolerances) -> Self {
+        self.metric_tolerances = Some(tolerances);
         self
     }
 
@@ -259,6 +291,8 @@ impl ReportBuilder {
             source_kind: self.source_kind,
             source_hash: self.source_hash,
             stdlib_version: self.stdlib_version,
+            determinism_tier: self.determinism_tier,
+            metric_tolerances: self.metric_tolerances,
             ok: self.ok,
             errors: self.errors,
             warnings: self.warnings,
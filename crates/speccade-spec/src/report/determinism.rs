@@ -0,0 +1,158 @@
+//! Determinism tier metadata and cross-run verification.
+//!
+//! Tier 1 backends (audio, music, texture, ...) produce byte-identical outputs,
+//! so two runs are compared by output hash. Tier 2 backends (Blender) are only
+//! semantically stable, so two runs are compared by metrics: counts must match
+//! exactly and continuous values within [`MetricTolerances`].
+
+use super::{OutputMetrics, Report};
+use crate::recipe::RecipeKind;
+use serde::{Deserialize, Serialize};
+
+/// Determinism tier for byte-identical backends.
+pub const TIER_BYTE_IDENTICAL: u8 = 1;
+
+/// Determinism tier for metric-validated backends.
+pub const TIER_METRIC_VALIDATED: u8 = 2;
+
+/// Tolerances for Tier 2 metric validation as specified in RFC-0001.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricTolerances {
+    /// Bounding box tolerance (+/- units).
+    pub bounding_box: f64,
+    /// Animation duration tolerance (+/- seconds).
+    pub animation_duration: f64,
+}
+
+impl Default for MetricTolerances {
+    fn default() -> Self {
+        Self {
+            bounding_box: 0.001,
+            animation_duration: 0.001,
+        }
+    }
+}
+
+/// Returns the determinism tier of a recipe kind.
+pub fn determinism_tier(kind: &RecipeKind) -> u8 {
+    if kind.is_tier1() {
+        TIER_BYTE_IDENTICAL
+    } else {
+        TIER_METRIC_VALIDATED
+    }
+}
+
+impl Report {
+    /// Compares this report with a report from another run of the same spec.
+    ///
+    /// Outputs are matched by path. Tier 1 reports compare output hashes;
+    /// Tier 2 reports compare output metrics using the recorded tolerances
+    /// (or the defaults if none were recorded). Returns a description of each
+    /// mismatch, so an empty list means the runs agree.
+    pub fn cross_run_mismatches(&self, other: &Report) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if self.determinism_tier != other.determinism_tier {
+            mismatches.push(format!(
+                "determinism_tier: {:?} vs {:?}",
+                self.determinism_tier, other.determinism_tier
+            ));
+            return mismatches;
+        }
+
+        let tolerances = self.metric_tolerances.clone().unwrap_or_default();
+        for output in &self.outputs {
+            let path = output.path.display();
+            let Some(rerun) = other.outputs.iter().find(|o| o.path == output.path) else {
+                mismatches.push(format!("{}: missing from other run", path));
+                continue;
+            };
+
+            if self.determinism_tier == Some(TIER_METRIC_VALIDATED) {
+                match (&output.metrics, &rerun.metrics) {
+                    (Some(a), Some(b)) => {
+                        for mismatch in compare_metrics(a, b, &tolerances) {
+                            mismatches.push(format!("{}: {}", path, mismatch));
+                        }
+                    }
+                    (None, None) => {}
+                    _ => mismatches.push(format!("{}: metrics present in only one run", path)),
+                }
+            } else if output.hash != rerun.hash {
+                mismatches.push(format!(
+                    "{}: hash {} vs {}",
+                    path,
+                    output.hash.as_deref().unwrap_or("none"),
+                    rerun.hash.as_deref().unwrap_or("none")
+                ));
+            }
+        }
+        for output in &other.outputs {
+            if !self.outputs.iter().any(|o| o.path == output.path) {
+                mismatches.push(format!("{}: missing from this run", output.path.display()));
+            }
+        }
+        mismatches
+    }
+}
+
+/// Compares the Tier 2 metrics two runs are expected to agree on.
+fn compare_metrics(
+    a: &OutputMetrics,
+    b: &OutputMetrics,
+    tolerances: &MetricTolerances,
+) -> Vec<String> {
+    let mut mismatches = Vec::new();
+
+    // Counts must match exactly
+    let counts = [
+        ("triangle_count", a.triangle_count, b.triangle_count),
+        ("uv_island_count", a.uv_island_count, b.uv_island_count),
+        ("bone_count", a.bone_count, b.bone_count),
+        (
+            "material_slot_count",
+            a.material_slot_count,
+            b.material_slot_count,
+        ),
+        (
+            "animation_frame_count",
+            a.animation_frame_count,
+            b.animation_frame_count,
+        ),
+    ];
+    for (name, a, b) in counts {
+        if a != b {
+            mismatches.push(format!("{}: {:?} vs {:?}", name, a, b));
+        }
+    }
+
+    let bounds = [
+        ("bounds_min", a.bounds_min, b.bounds_min),
+        ("bounds_max", a.bounds_max, b.bounds_max),
+    ];
+    for (name, a, b) in bounds {
+        let within = match (a, b) {
+            (Some(a), Some(b)) => a
+                .iter()
+                .zip(b)
+                .all(|(x, y)| (x - y).abs() <= tolerances.bounding_box),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        if !within {
+            mismatches.push(format!("{}: {:?} vs {:?}", name, a, b));
+        }
+    }
+
+    let within = match (a.animation_duration_seconds, b.animation_duration_seconds) {
+        (Some(x), Some(y)) => (x as f64 - y as f64).abs() <= tolerances.animation_duration,
+        (x, y) => x.is_none() && y.is_none(),
+    };
+    if !within {
+        mismatches.push(format!(
+            "animation_duration_seconds: {:?} vs {:?}",
+            a.animation_duration_seconds, b.animation_duration_seconds
+        ));
+    }
+
+    mismatches
+}
@@ -0,0 +1,86 @@
+//! Integrity checks of generated files against the checksums in a report.
+
+use super::Report;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// An output whose file on disk no longer matches its report entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutputMismatch {
+    /// Relative path of the output, as recorded in the report.
+    pub path: PathBuf,
+    /// What differs.
+    #[serde(flatten)]
+    pub kind: OutputMismatchKind,
+}
+
+/// The way an output differs from its recorded checksum.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mismatch", rename_all = "snake_case")]
+pub enum OutputMismatchKind {
+    /// The file could not be read.
+    Unreadable {
+        /// The I/O error message.
+        message: String,
+    },
+    /// The file size differs from `size_bytes`.
+    Size {
+        /// Recorded size in bytes.
+        expected: u64,
+        /// Size on disk in bytes.
+        actual: u64,
+    },
+    /// The file content hashes differently from `file_hash`.
+    Hash {
+        /// Recorded BLAKE3 hash.
+        expected: String,
+        /// BLAKE3 hash of the file on disk.
+        actual: String,
+    },
+}
+
+/// Re-hashes the outputs of `report` under `root` and returns every mismatch.
+///
+/// Only outputs with a recorded `file_hash` are checked; an empty result
+/// means every checked file is byte-identical to what was generated.
+pub fn verify_outputs(report: &Report, root: &Path) -> Vec<OutputMismatch> {
+    let mut mismatches = Vec::new();
+
+    for output in &report.outputs {
+        let Some(expected_hash) = &output.file_hash else {
+            continue;
+        };
+        let mismatch = |kind| OutputMismatch {
+            path: output.path.clone(),
+            kind,
+        };
+
+        let bytes = match std::fs::read(root.join(&output.path)) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                mismatches.push(mismatch(OutputMismatchKind::Unreadable {
+                    message: e.to_string(),
+                }));
+                continue;
+            }
+        };
+
+        if let Some(expected) = output.size_bytes {
+            let actual = bytes.len() as u64;
+            if actual != expected {
+                mismatches.push(mismatch(OutputMismatchKind::Size { expected, actual }));
+                continue;
+            }
+        }
+
+        let actual_hash = blake3::hash(&bytes).to_hex().to_string();
+        if &actual_hash != expected_hash {
+            mismatches.push(mismatch(OutputMismatchKind::Hash {
+                expected: expected_hash.clone(),
+                actual: actual_hash,
+            }));
+        }
+    }
+
+    mismatches
+}
@@ -5,7 +5,9 @@
 //! `speccade validate` operations, including errors, warnings, and output metadata.
 
 mod builder;
+mod determinism;
 mod error;
+mod integrity;
 mod lint;
 mod output;
 mod structural;
@@ -15,7 +17,11 @@ mod timing;
 mod tests;
 
 pub use builder::ReportBuilder;
+pub use determinism::{
+    determinism_tier, MetricTolerances, TIER_BYTE_IDENTICAL, TIER_METRIC_VALIDATED,
+};
 pub use error::{ReportError, ReportWarning};
+pub use integrity::{verify_outputs, OutputMismatch, OutputMismatchKind};
 pub use lint::{LintIssueData, LintReportData};
 pub use output::{
     BakedMapInfo, BakingMetrics, BoundingBox, CollisionBoundingBox, CollisionMeshMetrics,
@@ -74,6 +80,12 @@ pub struct Report {
     /// Starlark stdlib version (for Starlark sources; cache invalidation key).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stdlib_version: Option<String>,
+    /// Determinism tier of the backend (1 = byte-identical, 2 = metric-validated).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub determinism_tier: Option<u8>,
+    /// Tolerances used to compare Tier 2 metrics across runs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric_tolerances: Option<MetricTolerances>,
     /// Whether the operation succeeded without errors.
     pub ok: bool,
     /// List of errors that occurred.
@@ -3,7 +3,7 @@
 use super::structural::StructuralMetrics;
 use crate::output::{OutputFormat, OutputKind};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Result entry for a single output artifact.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -24,6 +24,15 @@ pub struct OutputResult {
     /// Whether this output was generated in preview mode (truncated for fast iteration).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub preview: Option<bool>,
+    /// Hex-encoded BLAKE3 hash of the file as written to disk.
+    ///
+    /// Unlike `hash`, which may cover decoded content (PCM for audio), this
+    /// always covers the final file bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_hash: Option<String>,
+    /// Size of the file as written to disk, in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
 }
 
 impl OutputResult {
@@ -36,6 +45,8 @@ impl OutputResult {
             hash: Some(hash),
             metrics: None,
             preview: None,
+            file_hash: None,
+            size_bytes: None,
         }
     }
 
@@ -53,8 +64,18 @@ impl OutputResult {
             hash: None,
             metrics: Some(metrics),
             preview: None,
+            file_hash: None,
+            size_bytes: None,
         }
     }
+
+    /// Records `file_hash` and `size_bytes` from the file at `root/path`.
+    pub fn record_file_checksum(&mut self, root: &Path) -> std::io::Result<()> {
+        let bytes = std::fs::read(root.join(&self.path))?;
+        self.file_hash = Some(blake3::hash(&bytes).to_hex().to_string());
+        self.size_bytes = Some(bytes.len() as u64);
+        Ok(())
+    }
 }
 
 /// Validation metrics for Tier 2 outputs (GLB meshes).
@@ -315,3 +315,230 @@ fn test_report_with_stages_serializes() {
     assert_eq!(stages[0].stage, "test_stage");
     assert_eq!(stages[0].duration_ms, 42);
 }
+
+fn spec_with_recipe(asset_type: crate::spec::AssetType, kind: &str) -> crate::spec::Spec {
+    crate::spec::Spec::builder("tier-test", asset_type)
+        .license("CC0-1.0")
+        .seed(7)
+        .recipe(crate::recipe::Recipe::new(kind, serde_json::json!({})))
+        .build()
+}
+
+#[test]
+fn test_texture_report_is_tier1_without_tolerances() {
+    let spec = spec_with_recipe(crate::spec::AssetType::Texture, "texture.procedural_v1");
+    let report = ReportBuilder::new("hash".to_string(), "backend v1.0".to_string())
+        .spec_metadata(&spec)
+        .build();
+
+    assert_eq!(report.determinism_tier, Some(TIER_BYTE_IDENTICAL));
+    assert_eq!(report.metric_tolerances, None);
+    let json = report.to_json().unwrap();
+    assert!(json.contains("\"determinism_tier\":1"));
+    assert!(!json.contains("metric_tolerances"));
+}
+
+#[test]
+fn test_mesh_report_is_tier2_with_tolerances() {
+    let spec = spec_with_recipe(
+        crate::spec::AssetType::StaticMesh,
+        "static_mesh.blender_primitives_v1",
+    );
+    let report = ReportBuilder::new("hash".to_string(), "backend v1.0".to_string())
+        .spec_metadata(&spec)
+        .build();
+
+    assert_eq!(report.determinism_tier, Some(TIER_METRIC_VALIDATED));
+    assert_eq!(report.metric_tolerances, Some(MetricTolerances::default()));
+
+    let parsed = Report::from_json(&report.to_json().unwrap()).unwrap();
+    assert_eq!(parsed, report);
+
+    // Explicit tolerances survive setting the tier
+    let tolerances = MetricTolerances {
+        bounding_box: 0.01,
+        animation_duration: 0.05,
+    };
+    let report = ReportBuilder::new("hash".to_string(), "backend v1.0".to_string())
+        .metric_tolerances(tolerances.clone())
+        .spec_metadata(&spec)
+        .build();
+    assert_eq!(report.metric_tolerances, Some(tolerances));
+}
+
+#[test]
+fn test_determinism_tier_per_recipe_kind() {
+    for kind in crate::recipe::RecipeKind::all() {
+        let expected = if kind.is_tier1() { 1 } else { 2 };
+        assert_eq!(determinism_tier(kind), expected, "{}", kind);
+    }
+    assert_eq!(determinism_tier(&crate::recipe::RecipeKind::AudioV1), 1);
+    assert_eq!(
+        determinism_tier(&crate::recipe::RecipeKind::MusicTrackerSongV1),
+        1
+    );
+    assert_eq!(
+        determinism_tier(&crate::recipe::RecipeKind::SkeletalAnimationBlenderClipV1),
+        2
+    );
+}
+
+#[test]
+fn test_report_without_tier_parses() {
+    let report = ReportBuilder::new("hash".to_string(), "backend v1.0".to_string()).build();
+    let json = report.to_json().unwrap();
+    assert!(!json.contains("determinism_tier"));
+    assert_eq!(Report::from_json(&json).unwrap().determinism_tier, None);
+}
+
+#[test]
+fn test_cross_run_tier1_compares_hashes() {
+    let run = |hash: &str| {
+        ReportBuilder::new("hash".to_string(), "backend v1.0".to_string())
+            .determinism_tier(TIER_BYTE_IDENTICAL)
+            .output(OutputResult::tier1(
+                OutputKind::Primary,
+                OutputFormat::Png,
+                PathBuf::from("albedo.png"),
+                hash.to_string(),
+            ))
+            .build()
+    };
+
+    assert!(run("abc").cross_run_mismatches(&run("abc")).is_empty());
+    let mismatches = run("abc").cross_run_mismatches(&run("abd"));
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].contains("albedo.png"));
+}
+
+#[test]
+fn test_cross_run_tier2_uses_tolerances() {
+    let run = |max_x: f64, triangles: u32| {
+        ReportBuilder::new("hash".to_string(), "backend v1.0".to_string())
+            .determinism_tier(TIER_METRIC_VALIDATED)
+            .output(OutputResult::tier2(
+                OutputKind::Primary,
+                OutputFormat::Glb,
+                PathBuf::from("mesh.glb"),
+                OutputMetrics::new()
+                    .with_triangle_count(triangles)
+                    .with_bounds_min([-1.0, -1.0, -1.0])
+                    .with_bounds_max([max_x, 1.0, 1.0]),
+            ))
+            .build()
+    };
+
+    let base = run(1.0, 12);
+    assert!(base.cross_run_mismatches(&run(1.0005, 12)).is_empty());
+
+    let mismatches = base.cross_run_mismatches(&run(1.01, 12));
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].contains("bounds_max"));
+
+    let mismatches = base.cross_run_mismatches(&run(1.0, 14));
+    assert_eq!(mismatches.len(), 1);
+    assert!(mismatches[0].contains("triangle_count"));
+}
+
+fn report_with_checksummed_outputs(root: &std::path::Path, files: &[(&str, &[u8])]) -> Report {
+    let mut builder = ReportBuilder::new("abc123".to_string(), "test-backend v1.0".to_string());
+    for (name, bytes) in files {
+        std::fs::write(root.join(name), bytes).unwrap();
+        let mut output = OutputResult::tier1(
+            OutputKind::Primary,
+            OutputFormat::Png,
+            PathBuf::from(name),
+            "content-hash".to_string(),
+        );
+        output.record_file_checksum(root).unwrap();
+        builder = builder.output(output);
+    }
+    builder.ok(true).build()
+}
+
+#[test]
+fn test_record_file_checksum_hashes_file_bytes() {
+    let tmp = tempfile::tempdir().unwrap();
+    let report = report_with_checksummed_outputs(tmp.path(), &[("a.png", b"hello")]);
+
+    let output = &report.outputs[0];
+    assert_eq!(
+        output.file_hash.as_deref(),
+        Some(blake3::hash(b"hello").to_hex().as_str())
+    );
+    assert_eq!(output.size_bytes, Some(5));
+    // The content hash is left alone
+    assert_eq!(output.hash.as_deref(), Some("content-hash"));
+
+    let json = report.to_json().unwrap();
+    let parsed = Report::from_json(&json).unwrap();
+    assert_eq!(parsed.outputs[0], *output);
+}
+
+#[test]
+fn test_verify_outputs_passes_untouched_files() {
+    let tmp = tempfile::tempdir().unwrap();
+    let report = report_with_checksummed_outputs(
+        tmp.path(),
+        &[("a.png", b"first file"), ("b.png", b"second file")],
+    );
+
+    assert!(verify_outputs(&report, tmp.path()).is_empty());
+}
+
+#[test]
+fn test_verify_outputs_detects_tampered_and_missing_files() {
+    let tmp = tempfile::tempdir().unwrap();
+    let report = report_with_checksummed_outputs(
+        tmp.path(),
+        &[
+            ("same.png", b"untouched"),
+            ("flipped.png", b"original"),
+            ("grown.png", b"original"),
+            ("gone.png", b"original"),
+        ],
+    );
+
+    // Same length, different bytes
+    std::fs::write(tmp.path().join("flipped.png"), b"0riginal").unwrap();
+    std::fs::write(tmp.path().join("grown.png"), b"original!").unwrap();
+    std::fs::remove_file(tmp.path().join("gone.png")).unwrap();
+
+    let mismatches = verify_outputs(&report, tmp.path());
+    assert_eq!(mismatches.len(), 3, "{:?}", mismatches);
+
+    assert_eq!(mismatches[0].path, PathBuf::from("flipped.png"));
+    assert!(matches!(
+        &mismatches[0].kind,
+        OutputMismatchKind::Hash { expected, actual } if expected != actual
+    ));
+    assert_eq!(mismatches[1].path, PathBuf::from("grown.png"));
+    assert_eq!(
+        mismatches[1].kind,
+        OutputMismatchKind::Size {
+            expected: 8,
+            actual: 9
+        }
+    );
+    assert_eq!(mismatches[2].path, PathBuf::from("gone.png"));
+    assert!(matches!(
+        mismatches[2].kind,
+        OutputMismatchKind::Unreadable { .. }
+    ));
+}
+
+#[test]
+fn test_verify_outputs_skips_outputs_without_file_hash() {
+    let tmp = tempfile::tempdir().unwrap();
+    let report = ReportBuilder::new("abc123".to_string(), "test-backend v1.0".to_string())
+        .output(OutputResult::tier1(
+            OutputKind::Primary,
+            OutputFormat::Wav,
+            PathBuf::from("never-written.wav"),
+            "pcm-hash".to_string(),
+        ))
+        .ok(true)
+        .build();
+
+    assert!(verify_outputs(&report, tmp.path()).is_empty());
+}
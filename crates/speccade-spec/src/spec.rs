@@ -2,8 +2,11 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::SpecError;
+use crate::hash::derive_variant_spec_seed;
 use crate::output::{EngineTarget, OutputSpec, VariantSpec};
 use crate::recipe::Recipe;
+use crate::validation::validate_spec;
 
 /// Current spec version.
 pub const SPEC_VERSION: u32 = 1;
@@ -223,6 +226,179 @@ impl Spec {
     pub fn output_paths(&self) -> impl Iterator<Item = &str> {
         self.outputs.iter().map(|o| o.path.as_str())
     }
+
+    /// Expands a variant into a standalone spec.
+    ///
+    /// The variant spec uses a seed derived via [`derive_variant_spec_seed`] and has
+    /// the variant's `overrides` applied to the recipe params.
+    ///
+    /// # Errors
+    /// Returns [`SpecError::InvalidVariantOverride`] if an override cannot be
+    /// applied or the resulting spec fails [`validate_spec`].
+    pub fn for_variant(&self, variant: &VariantSpec) -> Result<Spec, SpecError> {
+        let mut spec = self.clone();
+        spec.seed = derive_variant_spec_seed(self.seed, variant.seed_offset, &variant.variant_id);
+
+        for (path, value) in &variant.overrides {
+            let invalid = |message: String| SpecError::InvalidVariantOverride {
+                variant_id: variant.variant_id.clone(),
+                path: path.clone(),
+                message,
+            };
+
+            let recipe = spec
+                .recipe
+                .as_mut()
+                .ok_or_else(|| invalid("spec has no recipe".to_string()))?;
+            let params_path = path
+                .strip_prefix(VARIANT_OVERRIDE_ROOT)
+                .ok_or_else(|| invalid(format!("must start with '{}'", VARIANT_OVERRIDE_ROOT)))?;
+            set_param(&mut recipe.params, params_path, value.clone()).map_err(invalid)?;
+        }
+
+        let result = validate_spec(&spec);
+        if !result.is_ok() {
+            return Err(SpecError::InvalidVariantOverride {
+                variant_id: variant.variant_id.clone(),
+                path: variant
+                    .overrides
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                message: result
+                    .errors
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            });
+        }
+        Ok(spec)
+    }
+
+    /// Applies a JSON Merge Patch (RFC 7386) to this spec and validates the result.
+    ///
+    /// Objects in `patch` are merged key by key, `null` removes a key, and any
+    /// other value (including arrays) replaces the target outright. This lets a
+    /// shared base spec carry per-asset overrides; an empty patch (`{}`) returns
+    /// the base unchanged.
+    ///
+    /// # Errors
+    /// Returns [`SpecError::JsonParse`] if the patched document is no longer a
+    /// spec, or [`SpecError::ValidationFailed`] if it fails [`validate_spec`].
+    pub fn with_overrides(&self, patch: &serde_json::Value) -> Result<Spec, SpecError> {
+        let mut value = self.to_value()?;
+        merge_patch(&mut value, patch);
+        let spec = Spec::from_value(value)?;
+
+        let result = validate_spec(&spec);
+        if !result.is_ok() {
+            return Err(SpecError::ValidationFailed(result.errors.len()));
+        }
+        Ok(spec)
+    }
+}
+
+/// Applies a JSON Merge Patch (RFC 7386) to `target` in place.
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let target = target.as_object_mut().expect("target was made an object");
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(
+                target.entry(key.clone()).or_insert(serde_json::Value::Null),
+                value,
+            );
+        }
+    }
+}
+
+/// Root that every variant override path must start with.
+const VARIANT_OVERRIDE_ROOT: &str = "recipe.params.";
+
+/// A single step in a parameter path.
+enum PathSegment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Parses a parameter path like `nodes[0].noise.scale` into segments.
+fn parse_param_path(path: &str) -> Result<Vec<PathSegment<'_>>, String> {
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (key, mut rest) = match part.find('[') {
+            Some(i) => part.split_at(i),
+            None => (part, ""),
+        };
+        if key.is_empty() {
+            return Err("path contains an empty key".to_string());
+        }
+        segments.push(PathSegment::Key(key));
+
+        while !rest.is_empty() {
+            let close = rest
+                .find(']')
+                .filter(|_| rest.starts_with('['))
+                .ok_or_else(|| format!("malformed index in '{}'", part))?;
+            let index = rest[1..close]
+                .parse::<usize>()
+                .map_err(|_| format!("malformed index in '{}'", part))?;
+            segments.push(PathSegment::Index(index));
+            rest = &rest[close + 1..];
+        }
+    }
+    Ok(segments)
+}
+
+/// Sets the value at `path` within `params`.
+///
+/// Every intermediate segment must already exist; the final key may be new.
+fn set_param(
+    params: &mut serde_json::Value,
+    path: &str,
+    value: serde_json::Value,
+) -> Result<(), String> {
+    let segments = parse_param_path(path)?;
+    let (last, parents) = segments
+        .split_last()
+        .expect("split always yields a segment");
+
+    let mut current = params;
+    for segment in parents {
+        current = match segment {
+            PathSegment::Key(key) => current
+                .get_mut(*key)
+                .ok_or_else(|| format!("key '{}' not found", key))?,
+            PathSegment::Index(index) => current
+                .get_mut(*index)
+                .ok_or_else(|| format!("index {} out of range", index))?,
+        };
+    }
+
+    match last {
+        PathSegment::Key(key) => {
+            let object = current
+                .as_object_mut()
+                .ok_or_else(|| format!("cannot set key '{}' on a non-object", key))?;
+            object.insert((*key).to_string(), value);
+        }
+        PathSegment::Index(index) => {
+            let slot = current
+                .get_mut(*index)
+                .ok_or_else(|| format!("index {} out of range", index))?;
+            *slot = value;
+        }
+    }
+    Ok(())
 }
 
 /// Builder for constructing Spec instances.
@@ -478,4 +654,152 @@ mod tests {
         assert_eq!(primary_count, 2);
         assert_eq!(spec.output_count(), 3);
     }
+
+    fn variant_test_spec() -> Spec {
+        let mut spec = Spec::builder("texture-variants-01", AssetType::Texture)
+            .seed(7)
+            .output(OutputSpec::primary(OutputFormat::Png, "textures/n.png"))
+            .recipe(Recipe::new(
+                "texture.procedural_v1",
+                serde_json::json!({
+                    "resolution": [32, 32],
+                    "tileable": true,
+                    "nodes": [{
+                        "id": "n",
+                        "type": "noise",
+                        "noise": { "algorithm": "perlin", "scale": 0.1 }
+                    }]
+                }),
+            ))
+            .build();
+        spec.outputs[0].source = Some("n".to_string());
+        spec
+    }
+
+    #[test]
+    fn test_for_variant_applies_seed_and_overrides() {
+        let spec = variant_test_spec();
+        let variant = VariantSpec::new("scale_1", 1)
+            .with_override("recipe.params.nodes[0].noise.scale", serde_json::json!(0.2))
+            .with_override("recipe.params.tileable", serde_json::json!(false));
+
+        let expanded = spec.for_variant(&variant).unwrap();
+        assert_eq!(expanded.seed, derive_variant_spec_seed(7, 1, "scale_1"));
+
+        let params = &expanded.recipe.as_ref().unwrap().params;
+        assert_eq!(params["nodes"][0]["noise"]["scale"], 0.2);
+        assert_eq!(params["tileable"], false);
+
+        // The base spec is untouched.
+        let base_params = &spec.recipe.as_ref().unwrap().params;
+        assert_eq!(base_params["nodes"][0]["noise"]["scale"], 0.1);
+    }
+
+    #[test]
+    fn test_for_variant_rejects_overrides_that_fail_validation() {
+        let spec = variant_test_spec();
+        let variant = VariantSpec::new("bad_noise", 0).with_override(
+            "recipe.params.nodes[0].noise.algorithm",
+            serde_json::json!("not_a_noise"),
+        );
+
+        let err = spec.for_variant(&variant).unwrap_err();
+        match err {
+            SpecError::InvalidVariantOverride {
+                variant_id, path, ..
+            } => {
+                assert_eq!(variant_id, "bad_noise");
+                assert_eq!(path, "recipe.params.nodes[0].noise.algorithm");
+            }
+            other => panic!("expected InvalidVariantOverride, got {}", other),
+        }
+    }
+
+    #[test]
+    fn test_for_variant_rejects_invalid_override_paths() {
+        let spec = variant_test_spec();
+        for path in [
+            "seed",
+            "recipe.params.nodes[3].noise.scale",
+            "recipe.params.missing.scale",
+            "recipe.params.nodes[x]",
+        ] {
+            let variant = VariantSpec::new("bad", 0).with_override(path, serde_json::json!(1));
+            let err = spec.for_variant(&variant).unwrap_err();
+            assert!(
+                matches!(err, SpecError::InvalidVariantOverride { .. }),
+                "{}: {}",
+                path,
+                err
+            );
+        }
+    }
+
+    fn override_base_spec() -> Spec {
+        let mut spec = variant_test_spec();
+        spec.license = "CC0-1.0".to_string();
+        spec.description = Some("Shared noise base".to_string());
+        spec
+    }
+
+    #[test]
+    fn test_with_overrides_empty_patch_is_noop() {
+        let base = override_base_spec();
+        assert!(validate_spec(&base).is_ok());
+
+        let merged = base.with_overrides(&serde_json::json!({})).unwrap();
+        assert_eq!(merged, base);
+    }
+
+    #[test]
+    fn test_with_overrides_seed_changes_only_seed() {
+        let base = override_base_spec();
+        let merged = base
+            .with_overrides(&serde_json::json!({ "seed": 99 }))
+            .unwrap();
+
+        assert_eq!(merged.seed, 99);
+        let mut expected = base.clone();
+        expected.seed = 99;
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn test_with_overrides_merges_nested_fields() {
+        let base = override_base_spec();
+        let patch = serde_json::json!({
+            "asset_id": "texture-variants-02",
+            "description": null,
+            "recipe": { "params": { "tileable": false, "resolution": [64, 64] } }
+        });
+
+        let merged = base.with_overrides(&patch).unwrap();
+        assert!(validate_spec(&merged).is_ok());
+        assert_eq!(merged.asset_id, "texture-variants-02");
+        assert!(merged.description.is_none());
+
+        let params = &merged.recipe.as_ref().unwrap().params;
+        assert_eq!(params["tileable"], false);
+        assert_eq!(params["resolution"], serde_json::json!([64, 64]));
+        // Keys the patch does not mention are kept
+        assert_eq!(
+            params["nodes"],
+            base.recipe.as_ref().unwrap().params["nodes"]
+        );
+    }
+
+    #[test]
+    fn test_with_overrides_rejects_invalid_result() {
+        let base = override_base_spec();
+
+        let err = base
+            .with_overrides(&serde_json::json!({ "asset_id": "Not Valid!" }))
+            .unwrap_err();
+        assert!(matches!(err, SpecError::ValidationFailed(_)), "{}", err);
+
+        let err = base
+            .with_overrides(&serde_json::json!({ "seed": "forty-two" }))
+            .unwrap_err();
+        assert!(matches!(err, SpecError::JsonParse(_)), "{}", err);
+    }
 }
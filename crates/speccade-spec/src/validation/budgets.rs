@@ -12,6 +12,14 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+/// Fraction of a budget limit above which usage is reported as a near-limit warning.
+pub const NEAR_BUDGET_LIMIT_FRACTION: f64 = 0.9;
+
+/// Returns `true` when `actual` is within the limit but within 10% of it.
+pub fn is_near_budget_limit(actual: f64, limit: f64) -> bool {
+    actual <= limit && actual >= limit * NEAR_BUDGET_LIMIT_FRACTION
+}
+
 /// Audio generation budget limits.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AudioBudget {
@@ -438,6 +446,14 @@ impl fmt::Display for BudgetCategory {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_near_budget_limit() {
+        assert!(!is_near_budget_limit(100.0, 256.0));
+        assert!(is_near_budget_limit(240.0, 256.0));
+        assert!(is_near_budget_limit(256.0, 256.0));
+        assert!(!is_near_budget_limit(257.0, 256.0));
+    }
+
     #[test]
     fn test_budget_profile_default() {
         let profile = BudgetProfile::default();
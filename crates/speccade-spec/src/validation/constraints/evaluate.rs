@@ -1,9 +1,22 @@
 //! Constraint evaluation logic.
 
+use std::path::{Path, PathBuf};
+
 use crate::report::OutputMetrics;
 
+use super::spectral::spectral_similarity;
 use super::{Constraint, ConstraintResult, ConstraintSet, VerifyResult};
 
+/// Files available to constraints that compare asset contents rather than
+/// report metrics.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConstraintFiles {
+    /// The generated asset (e.g. the primary WAV output).
+    pub asset: Option<PathBuf>,
+    /// Directory that relative reference paths resolve against.
+    pub reference_dir: PathBuf,
+}
+
 impl Constraint {
     /// Evaluates this constraint against the given metrics.
     pub fn evaluate(&self, metrics: &OutputMetrics) -> ConstraintResult {
@@ -150,6 +163,33 @@ impl Constraint {
             Constraint::MaxRootMotionDelta { value } => {
                 evaluate_max_root_motion_delta(self, metrics.root_motion_delta, *value)
             }
+            // ========== Audio comparison constraints ==========
+            Constraint::SpectralSimilarity { .. } => {
+                ConstraintResult::skipped(self, "generated asset not available")
+            }
+        }
+    }
+
+    /// Evaluates this constraint, reading asset files for constraints that need them.
+    pub fn evaluate_with_files(
+        &self,
+        metrics: &OutputMetrics,
+        files: &ConstraintFiles,
+    ) -> ConstraintResult {
+        match self {
+            Constraint::SpectralSimilarity {
+                reference_path,
+                min_score,
+            } => match &files.asset {
+                Some(asset) => evaluate_spectral_similarity(
+                    self,
+                    asset,
+                    &files.reference_dir.join(reference_path),
+                    *min_score,
+                ),
+                None => self.evaluate(metrics),
+            },
+            _ => self.evaluate(metrics),
         }
     }
 }
@@ -307,6 +347,36 @@ fn evaluate_max_root_motion_delta(
     }
 }
 
+/// Helper for evaluating the spectral similarity constraint.
+fn evaluate_spectral_similarity(
+    constraint: &Constraint,
+    asset: &Path,
+    reference: &Path,
+    min: f64,
+) -> ConstraintResult {
+    let read = |path: &Path| {
+        std::fs::read(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+    };
+    let score = read(asset)
+        .and_then(|a| read(reference).map(|r| (a, r)))
+        .and_then(|(a, r)| spectral_similarity(&a, &r));
+
+    match score {
+        Ok(score) if score >= min => {
+            ConstraintResult::pass(constraint, Some(serde_json::json!(score)))
+        }
+        Ok(score) => ConstraintResult::fail(
+            constraint,
+            Some(serde_json::json!(score)),
+            format!(
+                "spectral similarity {:.4} is below minimum {:.4}",
+                score, min
+            ),
+        ),
+        Err(e) => ConstraintResult::fail(constraint, None, e),
+    }
+}
+
 /// Evaluates a set of constraints against output metrics from a report.
 pub fn evaluate_constraints(
     asset_id: &str,
@@ -321,3 +391,20 @@ pub fn evaluate_constraints(
 
     VerifyResult::new(asset_id.to_string(), results)
 }
+
+/// Evaluates a set of constraints against output metrics from a report, plus
+/// constraints that compare the generated asset against reference files.
+pub fn evaluate_constraints_with_files(
+    asset_id: &str,
+    metrics: &OutputMetrics,
+    constraints: &ConstraintSet,
+    files: &ConstraintFiles,
+) -> VerifyResult {
+    let results: Vec<ConstraintResult> = constraints
+        .constraints
+        .iter()
+        .map(|c| c.evaluate_with_files(metrics, files))
+        .collect();
+
+    VerifyResult::new(asset_id.to_string(), results)
+}
@@ -4,6 +4,7 @@
 //! using metrics from the generation report.
 
 mod evaluate;
+mod spectral;
 mod types;
 
 #[cfg(test)]
@@ -12,7 +13,8 @@ mod tests;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-pub use evaluate::evaluate_constraints;
+pub use evaluate::{evaluate_constraints, evaluate_constraints_with_files, ConstraintFiles};
+pub use spectral::spectral_similarity;
 pub use types::{ConstraintResult, VerifyResult};
 
 /// A constraint that can be evaluated against report metrics.
@@ -116,6 +118,17 @@ pub enum Constraint {
         /// The maximum magnitude of root motion delta allowed.
         value: f64,
     },
+
+    // ========== Audio comparison constraints ==========
+    /// Minimum log-mel spectral similarity between the generated audio and a
+    /// reference WAV (0.0-1.0).
+    SpectralSimilarity {
+        /// Path to the reference WAV. Relative paths resolve against
+        /// [`ConstraintFiles::reference_dir`].
+        reference_path: String,
+        /// The minimum similarity score required.
+        min_score: f64,
+    },
 }
 
 impl fmt::Display for Constraint {
@@ -159,6 +172,10 @@ impl fmt::Display for Constraint {
             Constraint::MaxRootMotionDelta { value } => {
                 write!(f, "max_root_motion_delta({})", value)
             }
+            Constraint::SpectralSimilarity {
+                reference_path,
+                min_score,
+            } => write!(f, "spectral_similarity({}, {})", reference_path, min_score),
         }
     }
 }
@@ -0,0 +1,258 @@
+//! Log-mel spectral similarity between two WAV files.
+//!
+//! Each file is mixed to mono and turned into a log-mel spectrogram. Frames are
+//! averaged into a fixed number of time segments so files of different lengths
+//! compare, and levels are floored 80 dB below the file's loudest band so
+//! near-silent bands don't dominate. The score is the cosine similarity of the
+//! two feature vectors: 1.0 for identical spectra, near 0.0 for sounds that
+//! share little energy in the same bands at the same time.
+
+use std::f64::consts::PI;
+
+/// Number of mel bands.
+const MEL_BANDS: usize = 32;
+
+/// Number of time segments frames are averaged into.
+const TIME_SEGMENTS: usize = 8;
+
+/// Dynamic range kept below the loudest band, in dB.
+const DYNAMIC_RANGE_DB: f64 = 80.0;
+
+/// Upper edge of the mel filterbank, so files at different sample rates compare.
+const MAX_MEL_FREQ: f64 = 11025.0;
+
+/// Lower edge of the mel filterbank.
+const MIN_MEL_FREQ: f64 = 20.0;
+
+/// Computes the log-mel cosine similarity of two WAV files.
+///
+/// Returns a score in [0.0, 1.0], or an error if either file is not a
+/// readable PCM or float WAV.
+pub fn spectral_similarity(a: &[u8], b: &[u8]) -> Result<f64, String> {
+    let (a_samples, a_rate) = decode_wav_mono(a)?;
+    let (b_samples, b_rate) = decode_wav_mono(b)?;
+    let a_features = log_mel_features(&a_samples, a_rate);
+    let b_features = log_mel_features(&b_samples, b_rate);
+    Ok(cosine_similarity(&a_features, &b_features))
+}
+
+/// Computes the segment-averaged log-mel feature vector of mono samples.
+fn log_mel_features(samples: &[f64], sample_rate: u32) -> Vec<f64> {
+    let sample_rate = sample_rate as f64;
+    // ~46 ms frames with 50% overlap
+    let frame_size = ((sample_rate * 0.046) as usize).next_power_of_two().max(64);
+    let hop = frame_size / 2;
+    let filterbank = mel_filterbank(frame_size, sample_rate);
+    let window: Vec<f64> = (0..frame_size)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / frame_size as f64).cos())
+        .collect();
+
+    let frame_count = if samples.len() <= frame_size {
+        1
+    } else {
+        (samples.len() - frame_size).div_ceil(hop) + 1
+    };
+
+    let mut segments = vec![[0.0f64; MEL_BANDS]; TIME_SEGMENTS];
+    let mut segment_frames = [0usize; TIME_SEGMENTS];
+    let mut buf = vec![(0.0, 0.0); frame_size];
+    for frame in 0..frame_count {
+        let start = frame * hop;
+        for (i, slot) in buf.iter_mut().enumerate() {
+            let sample = samples.get(start + i).copied().unwrap_or(0.0);
+            *slot = (sample * window[i], 0.0);
+        }
+        fft(&mut buf);
+
+        let segment = frame * TIME_SEGMENTS / frame_count;
+        for (band, filter) in filterbank.iter().enumerate() {
+            let energy: f64 = filter
+                .iter()
+                .map(|&(bin, weight)| {
+                    let (re, im) = buf[bin];
+                    weight * (re * re + im * im)
+                })
+                .sum();
+            segments[segment][band] += energy;
+        }
+        segment_frames[segment] += 1;
+    }
+
+    let mut db: Vec<f64> = segments
+        .iter()
+        .zip(segment_frames)
+        .flat_map(|(bands, frames)| {
+            let frames = frames.max(1) as f64;
+            bands.map(|energy| 10.0 * (energy / frames + 1e-20).log10())
+        })
+        .collect();
+
+    let max = db.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let floor = max - DYNAMIC_RANGE_DB;
+    for v in &mut db {
+        *v = (*v - floor).max(0.0);
+    }
+    db
+}
+
+/// Builds triangular mel filters as sparse `(bin, weight)` lists.
+fn mel_filterbank(frame_size: usize, sample_rate: f64) -> Vec<Vec<(usize, f64)>> {
+    let hz_to_mel = |hz: f64| 2595.0 * (1.0 + hz / 700.0).log10();
+    let mel_to_hz = |mel: f64| 700.0 * (10.0_f64.powf(mel / 2595.0) - 1.0);
+
+    let max_freq = MAX_MEL_FREQ.min(sample_rate / 2.0);
+    let (mel_lo, mel_hi) = (hz_to_mel(MIN_MEL_FREQ), hz_to_mel(max_freq));
+    let edges: Vec<f64> = (0..MEL_BANDS + 2)
+        .map(|i| mel_to_hz(mel_lo + (mel_hi - mel_lo) * i as f64 / (MEL_BANDS + 1) as f64))
+        .collect();
+
+    let bin_hz = sample_rate / frame_size as f64;
+    (0..MEL_BANDS)
+        .map(|band| {
+            let (lo, center, hi) = (edges[band], edges[band + 1], edges[band + 2]);
+            let mut filter: Vec<(usize, f64)> = (1..frame_size / 2)
+                .filter_map(|bin| {
+                    let hz = bin as f64 * bin_hz;
+                    let weight = if hz <= lo || hz >= hi {
+                        0.0
+                    } else if hz <= center {
+                        (hz - lo) / (center - lo)
+                    } else {
+                        (hi - hz) / (hi - center)
+                    };
+                    (weight > 0.0).then_some((bin, weight))
+                })
+                .collect();
+            // Narrow low bands can fall between bins; use the nearest one
+            if filter.is_empty() {
+                let bin = ((center / bin_hz).round() as usize).clamp(1, frame_size / 2 - 1);
+                filter.push((bin, 1.0));
+            }
+            filter
+        })
+        .collect()
+}
+
+/// In-place iterative radix-2 FFT. `buf.len()` must be a power of two.
+fn fft(buf: &mut [(f64, f64)]) {
+    let n = buf.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buf.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f64;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (w_re, w_im) = ((angle * k as f64).cos(), (angle * k as f64).sin());
+                let (a_re, a_im) = buf[start + k];
+                let (b_re, b_im) = buf[start + k + len / 2];
+                let (t_re, t_im) = (b_re * w_re - b_im * w_im, b_re * w_im + b_im * w_re);
+                buf[start + k] = (a_re + t_re, a_im + t_im);
+                buf[start + k + len / 2] = (a_re - t_re, a_im - t_im);
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Cosine similarity of two non-negative vectors. Two silent inputs match fully.
+fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+    match (norm_a > 0.0, norm_b > 0.0) {
+        (true, true) => (dot / (norm_a * norm_b)).clamp(0.0, 1.0),
+        (false, false) => 1.0,
+        _ => 0.0,
+    }
+}
+
+/// Decodes a PCM (8/16/24/32-bit) or 32-bit float WAV, mixing channels to mono.
+fn decode_wav_mono(data: &[u8]) -> Result<(Vec<f64>, u32), String> {
+    if data.len() < 12 || &data[0..4] != b"RIFF" || &data[8..12] != b"WAVE" {
+        return Err("not a RIFF/WAVE file".to_string());
+    }
+
+    let mut format = None;
+    let mut samples = None;
+    let mut offset = 12;
+    while offset + 8 <= data.len() {
+        let id = &data[offset..offset + 4];
+        let size = u32::from_le_bytes(data[offset + 4..offset + 8].try_into().unwrap()) as usize;
+        let body = &data[offset + 8..(offset + 8 + size).min(data.len())];
+        match id {
+            b"fmt " if body.len() >= 16 => {
+                let tag = u16::from_le_bytes([body[0], body[1]]);
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let rate = u32::from_le_bytes(body[4..8].try_into().unwrap());
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                format = Some((tag, channels, rate, bits));
+            }
+            b"data" => samples = Some(body),
+            _ => {}
+        }
+        // Chunks are padded to an even size
+        offset += 8 + size + (size & 1);
+    }
+
+    let (tag, channels, rate, bits) = format.ok_or("missing fmt chunk")?;
+    let body = samples.ok_or("missing data chunk")?;
+    if channels == 0 || rate == 0 {
+        return Err("invalid channel count or sample rate".to_string());
+    }
+
+    let decode: fn(&[u8]) -> f64 = match (tag, bits) {
+        (1, 8) => |b| (b[0] as f64 - 128.0) / 128.0,
+        (1, 16) => |b| i16::from_le_bytes([b[0], b[1]]) as f64 / 32768.0,
+        (1, 24) => |b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f64 / 8_388_608.0,
+        (1, 32) => |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64 / 2_147_483_648.0,
+        (3, 32) => |b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f64,
+        _ => {
+            return Err(format!(
+                "unsupported WAV format (tag {}, {} bits)",
+                tag, bits
+            ))
+        }
+    };
+
+    let width = bits as usize / 8;
+    let frame = width * channels as usize;
+    let mono = body
+        .chunks_exact(frame)
+        .map(|f| f.chunks_exact(width).map(decode).sum::<f64>() / channels as f64)
+        .collect();
+    Ok((mono, rate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fft_matches_single_bin() {
+        let n = 64;
+        let mut buf: Vec<(f64, f64)> = (0..n)
+            .map(|i| ((2.0 * PI * 5.0 * i as f64 / n as f64).cos(), 0.0))
+            .collect();
+        fft(&mut buf);
+        let magnitudes: Vec<f64> = buf.iter().map(|(re, im)| re.hypot(*im)).collect();
+        assert!((magnitudes[5] - n as f64 / 2.0).abs() < 1e-9);
+        assert!(magnitudes[6] < 1e-9);
+    }
+
+    #[test]
+    fn test_decode_rejects_non_wav() {
+        assert!(spectral_similarity(b"not a wav", b"not a wav").is_err());
+    }
+}
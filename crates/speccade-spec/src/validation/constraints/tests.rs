@@ -2,7 +2,10 @@
 
 use crate::report::OutputMetrics;
 
-use super::{evaluate_constraints, Constraint, ConstraintResult, ConstraintSet, VerifyResult};
+use super::{
+    evaluate_constraints, evaluate_constraints_with_files, spectral_similarity, Constraint,
+    ConstraintFiles, ConstraintResult, ConstraintSet, VerifyResult,
+};
 
 #[test]
 fn test_constraint_display() {
@@ -748,3 +751,150 @@ fn test_evaluate_animation_motion_constraints_mixed_failures() {
     assert!(!result.results[2].passed); // velocity spikes failed
     assert!(!result.results[3].passed); // root motion delta failed
 }
+
+// ========== Audio comparison constraints ==========
+
+/// Encodes mono samples as a 16-bit PCM WAV.
+fn wav_bytes(samples: &[f64], sample_rate: u32) -> Vec<u8> {
+    let data: Vec<u8> = samples
+        .iter()
+        .flat_map(|s| ((s.clamp(-1.0, 1.0) * 32767.0).round() as i16).to_le_bytes())
+        .collect();
+    let mut out = Vec::new();
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+    out.extend_from_slice(b"WAVEfmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    out.extend_from_slice(&1u16.to_le_bytes()); // mono
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&(sample_rate * 2).to_le_bytes());
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    out.extend_from_slice(&data);
+    out
+}
+
+/// A decaying 220 Hz tone with a few harmonics.
+fn pluck(sample_rate: u32) -> Vec<f64> {
+    (0..sample_rate / 2)
+        .map(|i| {
+            let t = i as f64 / sample_rate as f64;
+            let tone: f64 = (1..=4)
+                .map(|h| (2.0 * std::f64::consts::PI * 220.0 * h as f64 * t).sin() / h as f64)
+                .sum();
+            0.4 * tone * (-6.0 * t).exp()
+        })
+        .collect()
+}
+
+/// Bright noise that swells in, from a fixed LCG.
+fn noise_swell(sample_rate: u32) -> Vec<f64> {
+    let mut state: u32 = 12345;
+    (0..sample_rate / 2)
+        .map(|i| {
+            state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            let noise = (state >> 8) as f64 / (1u32 << 24) as f64 * 2.0 - 1.0;
+            noise * 0.5 * i as f64 / (sample_rate / 2) as f64
+        })
+        .collect()
+}
+
+#[test]
+fn test_spectral_similarity_self_is_one() {
+    let wav = wav_bytes(&pluck(22050), 22050);
+    let score = spectral_similarity(&wav, &wav).unwrap();
+    assert!((score - 1.0).abs() < 1e-9, "self similarity {}", score);
+
+    // A slightly quieter copy still matches closely
+    let quieter: Vec<f64> = pluck(22050).iter().map(|s| s * 0.8).collect();
+    let score = spectral_similarity(&wav, &wav_bytes(&quieter, 22050)).unwrap();
+    assert!(score > 0.98, "gain change similarity {}", score);
+}
+
+#[test]
+fn test_spectral_similarity_dissimilar_pair_scores_low() {
+    let pluck = wav_bytes(&pluck(22050), 22050);
+    let noise = wav_bytes(&noise_swell(22050), 22050);
+    let score = spectral_similarity(&pluck, &noise).unwrap();
+    assert!(score < 0.6, "dissimilar similarity {}", score);
+    assert_eq!(score, spectral_similarity(&noise, &pluck).unwrap());
+}
+
+#[test]
+fn test_spectral_similarity_constraint() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("ref.wav"), wav_bytes(&pluck(22050), 22050)).unwrap();
+    std::fs::write(dir.path().join("same.wav"), wav_bytes(&pluck(22050), 22050)).unwrap();
+    std::fs::write(
+        dir.path().join("noise.wav"),
+        wav_bytes(&noise_swell(22050), 22050),
+    )
+    .unwrap();
+
+    let constraint = Constraint::SpectralSimilarity {
+        reference_path: "ref.wav".to_string(),
+        min_score: 0.9,
+    };
+    assert_eq!(constraint.to_string(), "spectral_similarity(ref.wav, 0.9)");
+    let files = |asset: &str| ConstraintFiles {
+        asset: Some(dir.path().join(asset)),
+        reference_dir: dir.path().to_path_buf(),
+    };
+    let metrics = OutputMetrics::new();
+
+    let result = constraint.evaluate_with_files(&metrics, &files("same.wav"));
+    assert!(result.passed);
+    assert!(result.actual.is_some());
+
+    let result = constraint.evaluate_with_files(&metrics, &files("noise.wav"));
+    assert!(!result.passed);
+    assert!(result.message.unwrap().contains("below minimum"));
+
+    // A missing reference fails rather than silently passing
+    let missing = Constraint::SpectralSimilarity {
+        reference_path: "missing.wav".to_string(),
+        min_score: 0.9,
+    };
+    assert!(
+        !missing
+            .evaluate_with_files(&metrics, &files("same.wav"))
+            .passed
+    );
+
+    // Without the generated asset the constraint is skipped
+    let result = evaluate_constraints(
+        "test",
+        &metrics,
+        &ConstraintSet::from_constraints(vec![constraint.clone()]),
+    );
+    assert!(result.overall_pass);
+    assert!(result.results[0]
+        .message
+        .as_ref()
+        .unwrap()
+        .contains("not available"));
+
+    let result = evaluate_constraints_with_files(
+        "test",
+        &metrics,
+        &ConstraintSet::from_constraints(vec![constraint]),
+        &files("noise.wav"),
+    );
+    assert!(!result.overall_pass);
+}
+
+#[test]
+fn test_spectral_similarity_serialization() {
+    let json = r#"{"constraints":[{"type":"spectral_similarity","reference_path":"refs/hit.wav","min_score":0.85}]}"#;
+    let parsed = ConstraintSet::from_json(json).unwrap();
+    assert_eq!(
+        parsed.constraints[0],
+        Constraint::SpectralSimilarity {
+            reference_path: "refs/hit.wav".to_string(),
+            min_score: 0.85,
+        }
+    );
+}
@@ -33,8 +33,8 @@ pub use common::{
 
 // Re-export budget types for convenience
 pub use budgets::{
-    AudioBudget, BudgetCategory, BudgetError, BudgetProfile, GeneralBudget, MeshBudget,
-    MusicBudget, TextureBudget,
+    is_near_budget_limit, AudioBudget, BudgetCategory, BudgetError, BudgetProfile, GeneralBudget,
+    MeshBudget, MusicBudget, TextureBudget, NEAR_BUDGET_LIMIT_FRACTION,
 };
 
 // Re-export path safety functions
@@ -328,6 +328,8 @@ pub fn validate_for_generate_with_budget(spec: &Spec, budget: &BudgetProfile) ->
             "texture.splat_set_v1",
             "texture.matcap_v1",
             "texture.material_preset_v1",
+            "texture.composite_v1",
+            "texture.tile_variation_v1",
             "sprite.sheet_v1",
             "sprite.animation_v1",
             "vfx.flipbook_v1",
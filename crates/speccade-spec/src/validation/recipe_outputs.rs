@@ -1,9 +1,9 @@
 //! Recipe-specific output validation.
 
-use crate::error::{ErrorCode, ValidationError, ValidationResult};
+use crate::error::{ErrorCode, ValidationError, ValidationResult, ValidationWarning, WarningCode};
 use crate::output::{OutputFormat, OutputKind};
 use crate::spec::Spec;
-use crate::validation::BudgetProfile;
+use crate::validation::{is_near_budget_limit, BudgetCategory, BudgetProfile};
 
 use super::recipe_outputs_audio::validate_audio_outputs_with_budget;
 use super::recipe_outputs_mesh::{
@@ -20,9 +20,10 @@ use super::recipe_outputs_sprite::{
     validate_sprite_sheet_outputs,
 };
 use super::recipe_outputs_texture::{
-    validate_texture_decal_outputs, validate_texture_matcap_outputs,
-    validate_texture_material_preset_outputs, validate_texture_procedural_outputs_with_budget,
-    validate_texture_splat_set_outputs, validate_texture_trimsheet_outputs,
+    validate_texture_composite_outputs_with_budget, validate_texture_decal_outputs,
+    validate_texture_matcap_outputs, validate_texture_material_preset_outputs,
+    validate_texture_procedural_outputs_with_budget, validate_texture_splat_set_outputs,
+    validate_texture_tile_variation_outputs_with_budget, validate_texture_trimsheet_outputs,
 };
 use super::recipe_outputs_ui::{
     validate_font_bitmap_outputs, validate_ui_damage_number_outputs, validate_ui_icon_set_outputs,
@@ -64,6 +65,12 @@ pub(super) fn validate_outputs_for_recipe_with_budget(
         "texture.material_preset_v1" => {
             validate_texture_material_preset_outputs(spec, recipe, result)
         }
+        "texture.composite_v1" => {
+            validate_texture_composite_outputs_with_budget(spec, recipe, budget, result)
+        }
+        "texture.tile_variation_v1" => {
+            validate_texture_tile_variation_outputs_with_budget(spec, recipe, budget, result)
+        }
         "static_mesh.blender_primitives_v1" => {
             validate_static_mesh_blender_primitives(recipe, result);
             validate_single_primary_output_format_one_of(
@@ -120,7 +127,7 @@ pub(super) fn validate_outputs_for_recipe_with_budget(
             result.add_error(ValidationError::with_path(
                 ErrorCode::UnsupportedRecipeKind,
                 format!(
-                    "unsupported texture recipe kind '{}'; use 'texture.procedural_v1', 'texture.trimsheet_v1', 'texture.decal_v1', 'texture.splat_set_v1', 'texture.matcap_v1', or 'texture.material_preset_v1'",
+                    "unsupported texture recipe kind '{}'; use 'texture.procedural_v1', 'texture.trimsheet_v1', 'texture.decal_v1', 'texture.splat_set_v1', 'texture.matcap_v1', 'texture.material_preset_v1', 'texture.composite_v1', or 'texture.tile_variation_v1'",
                     recipe.kind
                 ),
                 "recipe.kind",
@@ -208,3 +215,26 @@ pub(crate) fn validate_single_primary_output_format_one_of(
         }
     }
 }
+
+/// Warns when `actual` is within the budget `limit` but close enough to it that a small edit
+/// would exceed it.
+pub(crate) fn warn_if_near_budget_limit(
+    category: BudgetCategory,
+    what: &str,
+    actual: f64,
+    limit: f64,
+    budget: &BudgetProfile,
+    path: impl Into<String>,
+    result: &mut ValidationResult,
+) {
+    if is_near_budget_limit(actual, limit) {
+        result.add_warning(ValidationWarning::with_path(
+            WarningCode::NearBudgetLimit,
+            format!(
+                "{} budget nearly reached: {} is {}, limit is {} (profile: {})",
+                category, what, actual, limit, budget.name
+            ),
+            path,
+        ));
+    }
+}
@@ -1,14 +1,18 @@
 //! Audio recipe output validation.
 
 use crate::error::{ErrorCode, ValidationError, ValidationResult};
-use crate::output::OutputFormat;
-use crate::recipe::audio::{Effect, ModulationTarget, Synthesis, Waveform};
+use crate::output::{OutputFormat, OutputKind};
+use crate::recipe::audio::{
+    Effect, EnvelopeCurve, ModulationDestination, ModulationSource, ModulationTarget, Synthesis,
+    Waveform,
+};
 use crate::spec::Spec;
 use crate::validation::{
-    validate_non_negative, validate_positive, validate_range, validate_unit_interval, BudgetProfile,
+    validate_non_negative, validate_positive, validate_range, validate_unit_interval,
+    BudgetCategory, BudgetProfile,
 };
 
-use super::recipe_outputs::validate_single_primary_output_format;
+use super::recipe_outputs::{validate_single_primary_output_format, warn_if_near_budget_limit};
 
 /// Returns true if the target is a post-FX only target (not valid on layer LFOs).
 fn is_post_fx_target(target: &ModulationTarget) -> bool {
@@ -32,6 +36,7 @@ fn is_layer_only_target(target: &ModulationTarget) -> bool {
             | ModulationTarget::FmIndex { .. }
             | ModulationTarget::GrainSize { .. }
             | ModulationTarget::GrainDensity { .. }
+            | ModulationTarget::WavetablePosition { .. }
     )
 }
 
@@ -46,6 +51,7 @@ fn target_discriminant(target: &ModulationTarget) -> &'static str {
         ModulationTarget::FmIndex { .. } => "fm_index",
         ModulationTarget::GrainSize { .. } => "grain_size",
         ModulationTarget::GrainDensity { .. } => "grain_density",
+        ModulationTarget::WavetablePosition { .. } => "wavetable_position",
         ModulationTarget::DelayTime { .. } => "delay_time",
         ModulationTarget::ReverbSize { .. } => "reverb_size",
         ModulationTarget::DistortionDrive { .. } => "distortion_drive",
@@ -150,6 +156,16 @@ pub(super) fn validate_audio_outputs_with_budget(
             ),
             "recipe.params.duration_seconds",
         ));
+    } else {
+        warn_if_near_budget_limit(
+            BudgetCategory::Audio,
+            "duration_seconds",
+            params.duration_seconds,
+            max_audio_duration_seconds,
+            budget,
+            "recipe.params.duration_seconds",
+            result,
+        );
     }
 
     if !allowed_sample_rates.contains(&params.sample_rate) {
@@ -176,6 +192,18 @@ pub(super) fn validate_audio_outputs_with_budget(
         ));
     }
 
+    if params
+        .master_filter
+        .as_ref()
+        .is_some_and(|filter| filter.keytrack() != 0.0)
+    {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            "keytrack is only supported on layers[].filter (the master filter has no pitch)",
+            "recipe.params.master_filter.keytrack",
+        ));
+    }
+
     // Count expanded layers (supersaw_unison voices expand to multiple virtual layers)
     let expanded_layer_count: usize = params
         .layers
@@ -196,6 +224,15 @@ pub(super) fn validate_audio_outputs_with_budget(
             "recipe.params.layers",
         ));
     }
+    warn_if_near_budget_limit(
+        BudgetCategory::Audio,
+        "expanded layer count",
+        expanded_layer_count as f64,
+        max_audio_layers as f64,
+        budget,
+        "recipe.params.layers",
+        result,
+    );
 
     for (i, layer) in params.layers.iter().enumerate() {
         if let Err(e) = validate_unit_interval("volume", layer.volume) {
@@ -231,33 +268,236 @@ pub(super) fn validate_audio_outputs_with_budget(
             }
         }
 
-        if let Err(e) = validate_non_negative("attack", layer.envelope.attack) {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::InvalidRecipeParams,
-                e.to_string(),
-                format!("recipe.params.layers[{}].envelope.attack", i),
-            ));
+        if let Some(filter) = &layer.filter {
+            let keytrack = filter.keytrack();
+            if let Err(e) = validate_range("keytrack", keytrack, -2.0, 2.0) {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    e.to_string(),
+                    format!("recipe.params.layers[{}].filter.keytrack", i),
+                ));
+            } else if keytrack != 0.0 && layer.synthesis.fundamental_frequency().is_none() {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    "keytrack requires a pitched synthesis type (otherwise it is a no-op)",
+                    format!("recipe.params.layers[{}].filter.keytrack", i),
+                ));
+            }
         }
-        if let Err(e) = validate_non_negative("decay", layer.envelope.decay) {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::InvalidRecipeParams,
-                e.to_string(),
-                format!("recipe.params.layers[{}].envelope.decay", i),
-            ));
+
+        if let Synthesis::Oscillator {
+            detune_voices,
+            detune_spread,
+            ..
+        } = &layer.synthesis
+        {
+            if let Some(voices) = detune_voices {
+                if !(1..=16).contains(voices) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("detune_voices must be in [1, 16], got {}", voices),
+                        format!("recipe.params.layers[{}].synthesis.detune_voices", i),
+                    ));
+                }
+            }
+            if let Some(spread) = detune_spread {
+                if let Err(e) = validate_non_negative("detune_spread", *spread) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        e.to_string(),
+                        format!("recipe.params.layers[{}].synthesis.detune_spread", i),
+                    ));
+                }
+            }
         }
-        if let Err(e) = validate_unit_interval("sustain", layer.envelope.sustain) {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::InvalidRecipeParams,
-                e.to_string(),
-                format!("recipe.params.layers[{}].envelope.sustain", i),
-            ));
+
+        if let Synthesis::Wavetable { tables, .. } = &layer.synthesis {
+            for (j, frame) in tables.iter().enumerate() {
+                if frame.len() < 2 {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!(
+                            "wavetable frames need at least 2 samples, got {}",
+                            frame.len()
+                        ),
+                        format!("recipe.params.layers[{}].synthesis.tables[{}]", i, j),
+                    ));
+                } else if frame.iter().any(|v| !v.is_finite()) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        "wavetable frame samples must be finite",
+                        format!("recipe.params.layers[{}].synthesis.tables[{}]", i, j),
+                    ));
+                }
+            }
         }
-        if let Err(e) = validate_non_negative("release", layer.envelope.release) {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::InvalidRecipeParams,
-                e.to_string(),
-                format!("recipe.params.layers[{}].envelope.release", i),
-            ));
+
+        if let Some(breakpoints) = &layer.gain_automation {
+            let mut prev_time = 0.0;
+            for (j, point) in breakpoints.iter().enumerate() {
+                if let Err(e) = validate_non_negative("time", point.time) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        e.to_string(),
+                        format!("recipe.params.layers[{}].gain_automation[{}].time", i, j),
+                    ));
+                } else if point.time < prev_time {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!(
+                            "breakpoints must be sorted by time ({} < {})",
+                            point.time, prev_time
+                        ),
+                        format!("recipe.params.layers[{}].gain_automation[{}].time", i, j),
+                    ));
+                } else {
+                    prev_time = point.time;
+                }
+                if let Err(e) = validate_non_negative("gain", point.gain) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        e.to_string(),
+                        format!("recipe.params.layers[{}].gain_automation[{}].gain", i, j),
+                    ));
+                }
+            }
+        }
+
+        if let Some(arpeggio) = &layer.arpeggio {
+            let path = format!("recipe.params.layers[{}].arpeggio", i);
+            if !matches!(
+                layer.synthesis,
+                Synthesis::Oscillator { .. } | Synthesis::MultiOscillator { .. }
+            ) {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    "arpeggio requires oscillator or multi_oscillator synthesis",
+                    path.clone(),
+                ));
+            }
+            if layer
+                .lfo
+                .as_ref()
+                .is_some_and(|lfo| matches!(lfo.target, ModulationTarget::Pitch { .. }))
+            {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    "arpeggio cannot be combined with a pitch LFO on the same layer",
+                    path.clone(),
+                ));
+            }
+            if arpeggio.pattern.is_empty() {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    "pattern must contain at least one semitone offset",
+                    format!("{}.pattern", path),
+                ));
+            }
+            for (j, offset) in arpeggio.pattern.iter().enumerate() {
+                if let Err(e) = validate_range("pattern", *offset, -48.0, 48.0) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        e.to_string(),
+                        format!("{}.pattern[{}]", path, j),
+                    ));
+                }
+            }
+            if let Err(e) = validate_positive("rate_hz", arpeggio.rate_hz) {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    e.to_string(),
+                    format!("{}.rate_hz", path),
+                ));
+            }
+            if let Err(e) = validate_positive("gate", arpeggio.gate)
+                .and_then(|()| validate_unit_interval("gate", arpeggio.gate))
+            {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    e.to_string(),
+                    format!("{}.gate", path),
+                ));
+            }
+        }
+
+        if let Some(render_rate) = layer.render_rate {
+            if !(1000..=params.sample_rate).contains(&render_rate) {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!(
+                        "render_rate must be 1000-{} Hz (the recipe sample rate), got {}",
+                        params.sample_rate, render_rate
+                    ),
+                    format!("recipe.params.layers[{}].render_rate", i),
+                ));
+            }
+        }
+
+        if let Some(sidechain) = &layer.sidechain {
+            let path = format!("recipe.params.layers[{}].sidechain", i);
+            if sidechain.source_layer == i || sidechain.source_layer >= params.layers.len() {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!(
+                        "source_layer must name another layer (0-{}), got {}",
+                        params.layers.len() - 1,
+                        sidechain.source_layer
+                    ),
+                    format!("{}.source_layer", path),
+                ));
+            }
+            for (name, value, min, max) in [
+                ("threshold_db", sidechain.threshold_db, -60.0, 0.0),
+                ("ratio", sidechain.ratio, 1.0, 20.0),
+                ("attack_ms", sidechain.attack_ms, 0.1, 100.0),
+                ("release_ms", sidechain.release_ms, 10.0, 1000.0),
+            ] {
+                if let Err(e) = validate_range(name, value, min, max) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        e.to_string(),
+                        format!("{}.{}", path, name),
+                    ));
+                }
+            }
+        }
+
+        let envelope = layer.envelope.to_dahdsr();
+        for (name, value) in [
+            ("delay", envelope.delay),
+            ("attack", envelope.attack),
+            ("hold", envelope.hold),
+            ("decay", envelope.decay),
+            ("sustain", envelope.sustain),
+            ("release", envelope.release),
+        ] {
+            let check = if name == "sustain" {
+                validate_unit_interval(name, value)
+            } else {
+                validate_non_negative(name, value)
+            };
+            if let Err(e) = check {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    e.to_string(),
+                    format!("recipe.params.layers[{}].envelope.{}", i, name),
+                ));
+            }
+        }
+        for (name, curve) in [
+            ("attack_curve", envelope.attack_curve),
+            ("decay_curve", envelope.decay_curve),
+            ("release_curve", envelope.release_curve),
+        ] {
+            if let EnvelopeCurve::Power(exponent) = curve {
+                if !(exponent.is_finite() && exponent > 0.0) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("power curve exponent must be positive, got {}", exponent),
+                        format!("recipe.params.layers[{}].envelope.{}", i, name),
+                    ));
+                }
+            }
         }
 
         if let Some(lfo) = &layer.lfo {
@@ -452,6 +692,25 @@ pub(super) fn validate_audio_outputs_with_budget(
                         ));
                     }
                 }
+                ModulationTarget::WavetablePosition { amount } => {
+                    // Validate amount is positive
+                    if let Err(e) = validate_positive("amount", *amount) {
+                        result.add_error(ValidationError::with_path(
+                            ErrorCode::InvalidRecipeParams,
+                            e.to_string(),
+                            format!("recipe.params.layers[{}].lfo.target.amount", i),
+                        ));
+                    }
+
+                    // Validate synthesis compatibility - only Wavetable is valid
+                    if !matches!(layer.synthesis, Synthesis::Wavetable { .. }) {
+                        result.add_error(ValidationError::with_path(
+                            ErrorCode::InvalidRecipeParams,
+                            "wavetable_position LFO target is only valid for Wavetable synthesis",
+                            format!("recipe.params.layers[{}].lfo.target", i),
+                        ));
+                    }
+                }
                 ModulationTarget::DelayTime { .. }
                 | ModulationTarget::ReverbSize { .. }
                 | ModulationTarget::DistortionDrive { .. } => {
@@ -460,6 +719,84 @@ pub(super) fn validate_audio_outputs_with_budget(
                 }
             }
         }
+
+        for (j, route) in layer.modulation_matrix.iter().enumerate() {
+            let route_path = format!("recipe.params.layers[{}].modulation_matrix[{}]", i, j);
+
+            if !route.depth.is_finite() {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!("depth must be finite, got {}", route.depth),
+                    format!("{}.depth", route_path),
+                ));
+            }
+
+            match &route.source {
+                ModulationSource::Lfo { rate, phase, .. } => {
+                    if let Err(e) = validate_positive("rate", *rate) {
+                        result.add_error(ValidationError::with_path(
+                            ErrorCode::InvalidRecipeParams,
+                            e.to_string(),
+                            format!("{}.source.rate", route_path),
+                        ));
+                    }
+                    if let Some(phase) = phase {
+                        if let Err(e) = validate_unit_interval("phase", *phase) {
+                            result.add_error(ValidationError::with_path(
+                                ErrorCode::InvalidRecipeParams,
+                                e.to_string(),
+                                format!("{}.source.phase", route_path),
+                            ));
+                        }
+                    }
+                }
+                ModulationSource::Envelope {
+                    attack,
+                    decay,
+                    sustain,
+                    release,
+                } => {
+                    for (name, value) in
+                        [("attack", attack), ("decay", decay), ("release", release)]
+                    {
+                        if let Err(e) = validate_non_negative(name, *value) {
+                            result.add_error(ValidationError::with_path(
+                                ErrorCode::InvalidRecipeParams,
+                                e.to_string(),
+                                format!("{}.source.{}", route_path, name),
+                            ));
+                        }
+                    }
+                    if let Err(e) = validate_unit_interval("sustain", *sustain) {
+                        result.add_error(ValidationError::with_path(
+                            ErrorCode::InvalidRecipeParams,
+                            e.to_string(),
+                            format!("{}.source.sustain", route_path),
+                        ));
+                    }
+                }
+            }
+
+            if route.destination == ModulationDestination::FilterCutoff {
+                if layer.filter.is_none() {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        "filter_cutoff route requires layers[].filter to be present (otherwise this route is a no-op)",
+                        format!("{}.destination", route_path),
+                    ));
+                }
+                if matches!(
+                    layer.lfo.as_ref().map(|lfo| &lfo.target),
+                    Some(ModulationTarget::FilterCutoff { .. })
+                ) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        "filter_cutoff route cannot be combined with a filter_cutoff layer LFO",
+                        format!("{}.destination", route_path),
+                    ));
+                }
+            }
+        }
     }
 
     // ========================================================================
@@ -599,5 +936,16 @@ pub(super) fn validate_audio_outputs_with_budget(
         }
     }
 
-    validate_single_primary_output_format(spec, OutputFormat::Wav, result);
+    // The primary output is WAV, or OGG/Vorbis for lossy delivery.
+    let primary_format = spec
+        .outputs
+        .iter()
+        .find(|o| o.kind == OutputKind::Primary)
+        .map(|o| o.format);
+    let expected_format = if primary_format == Some(OutputFormat::Ogg) {
+        OutputFormat::Ogg
+    } else {
+        OutputFormat::Wav
+    };
+    validate_single_primary_output_format(spec, expected_format, result);
 }
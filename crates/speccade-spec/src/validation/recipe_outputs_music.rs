@@ -1,12 +1,12 @@
 //! Music recipe output validation.
 
-use crate::error::{ErrorCode, ValidationError, ValidationResult};
+use crate::error::{ErrorCode, ValidationError, ValidationResult, ValidationWarning, WarningCode};
 use crate::output::{OutputFormat, OutputKind};
 use crate::recipe::music::{parse_effect_name, TrackerFormat};
 use crate::spec::Spec;
-use crate::validation::BudgetProfile;
+use crate::validation::{BudgetCategory, BudgetProfile};
 
-use super::recipe_outputs::validate_primary_output_present;
+use super::recipe_outputs::{validate_primary_output_present, warn_if_near_budget_limit};
 
 /// Validates music outputs with the default budget profile.
 #[allow(dead_code)]
@@ -122,6 +122,11 @@ fn validate_music_outputs_common_with_budget(
     let max_instruments = match format {
         crate::recipe::music::TrackerFormat::Xm => budget.music.xm_max_instruments as usize,
         crate::recipe::music::TrackerFormat::It => budget.music.it_max_instruments as usize,
+        // MOD and S3M share the XM and IT budgets, capped by their format limits.
+        crate::recipe::music::TrackerFormat::Mod => {
+            (budget.music.xm_max_instruments as usize).min(31)
+        }
+        crate::recipe::music::TrackerFormat::S3m => budget.music.it_max_instruments as usize,
     };
 
     if instruments.len() > max_instruments {
@@ -137,6 +142,15 @@ fn validate_music_outputs_common_with_budget(
             "recipe.params.instruments",
         ));
     }
+    warn_if_near_budget_limit(
+        BudgetCategory::Music,
+        "instrument count",
+        instruments.len() as f64,
+        max_instruments as f64,
+        budget,
+        "recipe.params.instruments",
+        result,
+    );
 
     // Validate instrument sources are well-formed (matches backend behavior).
     for (idx, instrument) in instruments.iter().enumerate() {
@@ -153,12 +167,15 @@ fn validate_music_outputs_common_with_budget(
         if instrument.synthesis.is_some() {
             sources.push("synthesis");
         }
+        if !instrument.sample_map.is_empty() {
+            sources.push("sample_map");
+        }
 
         if sources.len() != 1 {
             result.add_error(ValidationError::with_path(
                 ErrorCode::InvalidRecipeParams,
                 format!(
-                    "music instrument must set exactly one of: ref, wav, synthesis_audio_v1, synthesis (got: {})",
+                    "music instrument must set exactly one of: ref, wav, synthesis_audio_v1, synthesis, sample_map (got: {})",
                     if sources.is_empty() {
                         "none".to_string()
                     } else {
@@ -168,11 +185,50 @@ fn validate_music_outputs_common_with_budget(
                 format!("recipe.params.instruments[{}]", idx),
             ));
         }
+
+        if let Some(pan_envelope) = &instrument.pan_envelope {
+            validate_tracker_envelope(
+                pan_envelope,
+                &format!("recipe.params.instruments[{}].pan_envelope", idx),
+                result,
+            );
+        }
+
+        if !instrument.sample_map.is_empty() {
+            validate_sample_map(
+                &instrument.sample_map,
+                format,
+                &format!("recipe.params.instruments[{}].sample_map", idx),
+                result,
+            );
+        }
+
+        if instrument.stereo && matches!(format, TrackerFormat::Mod | TrackerFormat::S3m) {
+            result.add_warning(ValidationWarning::with_path(
+                WarningCode::UnusedRecipeParams,
+                format!(
+                    "stereo samples are not supported by {:?}; the instrument is baked as mono",
+                    format
+                ),
+                format!("recipe.params.instruments[{}].stereo", idx),
+            ));
+        }
+
+        if let Some(auto_vibrato) = &instrument.auto_vibrato {
+            validate_auto_vibrato(
+                auto_vibrato,
+                format,
+                &format!("recipe.params.instruments[{}].auto_vibrato", idx),
+                result,
+            );
+        }
     }
 
     let expected_format = match format {
         crate::recipe::music::TrackerFormat::Xm => OutputFormat::Xm,
         crate::recipe::music::TrackerFormat::It => OutputFormat::It,
+        crate::recipe::music::TrackerFormat::Mod => OutputFormat::Mod,
+        crate::recipe::music::TrackerFormat::S3m => OutputFormat::S3m,
     };
 
     let primary_outputs: Vec<(usize, &crate::output::OutputSpec)> = spec
@@ -201,69 +257,221 @@ fn validate_music_outputs_common_with_budget(
         return;
     }
 
-    // Multi-output mode: allow at most one XM and one IT primary output.
-    let mut seen_xm = false;
-    let mut seen_it = false;
+    // Multi-output mode: allow at most one primary output per tracker format, plus MIDI.
+    let mut seen_formats: Vec<OutputFormat> = Vec::new();
 
     for (index, output) in &primary_outputs {
-        match output.format {
-            OutputFormat::Xm => {
-                if seen_xm {
-                    result.add_error(ValidationError::with_path(
-                        ErrorCode::OutputValidationFailed,
-                        format!("duplicate primary output format 'xm' for {}", recipe_kind),
-                        format!("outputs[{}].format", index),
-                    ));
-                }
-                seen_xm = true;
-            }
-            OutputFormat::It => {
-                if seen_it {
-                    result.add_error(ValidationError::with_path(
-                        ErrorCode::OutputValidationFailed,
-                        format!("duplicate primary output format 'it' for {}", recipe_kind),
-                        format!("outputs[{}].format", index),
-                    ));
-                }
-                seen_it = true;
+        if !output.format.is_music() && output.format != OutputFormat::Mid {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                format!(
+                    "{} primary outputs must have format 'xm', 'it', 'mod', 's3m' or 'mid'",
+                    recipe_kind
+                ),
+                format!("outputs[{}].format", index),
+            ));
+            continue;
+        }
+        if seen_formats.contains(&output.format) {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                format!(
+                    "duplicate primary output format '{}' for {}",
+                    output.format, recipe_kind
+                ),
+                format!("outputs[{}].format", index),
+            ));
+        }
+        seen_formats.push(output.format);
+    }
+
+    // Defensive: ensure the recipe's declared format is among the requested outputs.
+    if !primary_outputs
+        .iter()
+        .any(|(_, o)| o.format == expected_format)
+    {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::OutputValidationFailed,
+            format!(
+                "recipe.params.format '{}' must match one of the primary output formats",
+                expected_format
+            ),
+            "recipe.params.format",
+        ));
+    }
+}
+
+/// Validates a point-based tracker envelope: ticks strictly increase, pan values stay
+/// within -32..=32 and the sustain point names an existing point.
+fn validate_tracker_envelope(
+    envelope: &crate::recipe::music::TrackerEnvelope,
+    path: &str,
+    result: &mut ValidationResult,
+) {
+    if envelope.points.is_empty() {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            "envelope must have at least one point",
+            format!("{}.points", path),
+        ));
+        return;
+    }
+
+    for (idx, pair) in envelope.points.windows(2).enumerate() {
+        if pair[1].tick <= pair[0].tick {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!(
+                    "envelope point ticks must be strictly increasing ({} follows {})",
+                    pair[1].tick, pair[0].tick
+                ),
+                format!("{}.points[{}].tick", path, idx + 1),
+            ));
+        }
+    }
+
+    for (idx, point) in envelope.points.iter().enumerate() {
+        if !(-32..=32).contains(&point.value) {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!("pan envelope value must be -32 to 32, got {}", point.value),
+                format!("{}.points[{}].value", path, idx),
+            ));
+        }
+    }
+
+    if let Some(sustain_point) = envelope.sustain_point {
+        if sustain_point as usize >= envelope.points.len() {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!(
+                    "sustain_point {} is out of range for {} envelope point(s)",
+                    sustain_point,
+                    envelope.points.len()
+                ),
+                format!("{}.sustain_point", path),
+            ));
+        }
+    }
+}
+
+/// Validates multi-sample zones: every note name parses, each zone's range is ordered and
+/// consecutive zones meet without gaps or overlaps.
+fn validate_sample_map(
+    zones: &[crate::recipe::music::SampleZone],
+    format: crate::recipe::music::TrackerFormat,
+    path: &str,
+    result: &mut ValidationResult,
+) {
+    use crate::recipe::audio::parse_note_name;
+    use crate::recipe::music::TrackerFormat;
+
+    if matches!(format, TrackerFormat::Mod | TrackerFormat::S3m) {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!("sample_map is not supported by {:?}", format),
+            path,
+        ));
+        return;
+    }
+
+    let mut previous_high: Option<u8> = None;
+    for (idx, zone) in zones.iter().enumerate() {
+        let zone_path = format!("{}[{}]", path, idx);
+        let parse = |name: &str, field: &str, result: &mut ValidationResult| {
+            let midi = parse_note_name(name);
+            if midi.is_none() {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!("invalid note name '{}'", name),
+                    format!("{}.{}", zone_path, field),
+                ));
             }
-            _ => {
+            midi
+        };
+        let low = parse(&zone.low_note, "low_note", result);
+        let high = parse(&zone.high_note, "high_note", result);
+        let (Some(low), Some(high)) = (low, high) else {
+            previous_high = None;
+            continue;
+        };
+
+        if low > high {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!(
+                    "zone low_note {} is above high_note {}",
+                    zone.low_note, zone.high_note
+                ),
+                zone_path.clone(),
+            ));
+        }
+
+        if let Some(previous_high) = previous_high {
+            if low != previous_high.saturating_add(1) {
+                let problem = if low <= previous_high {
+                    "overlaps"
+                } else {
+                    "leaves a gap after"
+                };
                 result.add_error(ValidationError::with_path(
-                    ErrorCode::OutputValidationFailed,
-                    format!(
-                        "{} primary outputs must have format 'xm' or 'it'",
-                        recipe_kind
-                    ),
-                    format!("outputs[{}].format", index),
+                    ErrorCode::InvalidRecipeParams,
+                    format!("zone {} {} zone {}", idx, problem, idx - 1),
+                    format!("{}.low_note", zone_path),
                 ));
             }
         }
+        previous_high = Some(high);
     }
+}
 
-    if primary_outputs.len() > 2 {
+/// Validates auto-vibrato parameters against the target format's header limits.
+///
+/// MOD and S3M have no instrument vibrato and ignore it.
+fn validate_auto_vibrato(
+    auto_vibrato: &crate::recipe::music::AutoVibrato,
+    format: crate::recipe::music::TrackerFormat,
+    path: &str,
+    result: &mut ValidationResult,
+) {
+    use crate::recipe::music::{TrackerFormat, VibratoWaveform};
+
+    let (max_depth, max_rate, unsupported_waveform) = match format {
+        TrackerFormat::Xm => (15, 63, Some(VibratoWaveform::Random)),
+        TrackerFormat::It => (64, 64, Some(VibratoWaveform::RampUp)),
+        TrackerFormat::Mod | TrackerFormat::S3m => return,
+    };
+
+    if auto_vibrato.depth > max_depth {
         result.add_error(ValidationError::with_path(
-            ErrorCode::OutputValidationFailed,
+            ErrorCode::InvalidRecipeParams,
             format!(
-                "{} supports at most 2 primary outputs (xm + it), got {}",
-                recipe_kind,
-                primary_outputs.len()
+                "auto_vibrato depth must be 0 to {} for {:?}, got {}",
+                max_depth, format, auto_vibrato.depth
             ),
-            "outputs",
+            format!("{}.depth", path),
         ));
     }
 
-    // Defensive: ensure the recipe's declared format is among the requested outputs.
-    if !primary_outputs
-        .iter()
-        .any(|(_, o)| o.format == expected_format)
-    {
+    if auto_vibrato.rate > max_rate {
         result.add_error(ValidationError::with_path(
-            ErrorCode::OutputValidationFailed,
+            ErrorCode::InvalidRecipeParams,
             format!(
-                "recipe.params.format '{}' must match one of the primary output formats",
-                expected_format
+                "auto_vibrato rate must be 0 to {} for {:?}, got {}",
+                max_rate, format, auto_vibrato.rate
             ),
-            "recipe.params.format",
+            format!("{}.rate", path),
+        ));
+    }
+
+    if unsupported_waveform == Some(auto_vibrato.waveform) {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!(
+                "auto_vibrato waveform {:?} is not supported by {:?}",
+                auto_vibrato.waveform, format
+            ),
+            format!("{}.waveform", path),
         ));
     }
 }
@@ -294,6 +502,8 @@ fn validate_common_tracker_params(
     let max_channels = match format {
         TrackerFormat::Xm => 32,
         TrackerFormat::It => 64,
+        TrackerFormat::Mod => 4,
+        TrackerFormat::S3m => 16,
     };
     if channels == 0 || channels > max_channels {
         result.add_error(ValidationError::with_path(
@@ -350,6 +560,26 @@ fn validate_tracker_song_semantics(
                     "recipe.params.patterns",
                 ));
             }
+
+            // Stereo needs a mirror channel per channel that plays it and a
+            // right-hand instrument each; without room it falls back to mono.
+            let stereo_instruments = params.instruments.iter().filter(|i| i.stereo).count();
+            let stereo_fits = params.channels as usize + params.stereo_channels().len() <= 32
+                && params.instruments.len() + stereo_instruments <= 128;
+            if !stereo_fits {
+                for (idx, _) in params
+                    .instruments
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, i)| i.stereo)
+                {
+                    result.add_warning(ValidationWarning::with_path(
+                        WarningCode::UnusedRecipeParams,
+                        "XM has no room for the extra channels and instruments of stereo samples; the instrument is baked as mono",
+                        format!("recipe.params.instruments[{}].stereo", idx),
+                    ));
+                }
+            }
         }
         TrackerFormat::It => {
             if params.instruments.len() > 99 {
@@ -373,6 +603,50 @@ fn validate_tracker_song_semantics(
                 ));
             }
         }
+        TrackerFormat::Mod => {
+            if params.instruments.len() > 31 {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!(
+                        "MOD supports at most 31 instruments/samples, got {}",
+                        params.instruments.len()
+                    ),
+                    "recipe.params.instruments",
+                ));
+            }
+            if params.patterns.len() > 64 {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!(
+                        "MOD supports at most 64 patterns, got {}",
+                        params.patterns.len()
+                    ),
+                    "recipe.params.patterns",
+                ));
+            }
+        }
+        TrackerFormat::S3m => {
+            if params.instruments.len() > 99 {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!(
+                        "S3M supports at most 99 instruments/samples, got {}",
+                        params.instruments.len()
+                    ),
+                    "recipe.params.instruments",
+                ));
+            }
+            if params.patterns.len() > 100 {
+                result.add_error(ValidationError::with_path(
+                    ErrorCode::InvalidRecipeParams,
+                    format!(
+                        "S3M supports at most 100 patterns, got {}",
+                        params.patterns.len()
+                    ),
+                    "recipe.params.patterns",
+                ));
+            }
+        }
     }
 
     for (name, pattern) in &params.patterns {
@@ -404,6 +678,16 @@ fn validate_tracker_song_semantics(
                 format!("recipe.params.patterns.{}.rows", name),
             ));
         }
+        if matches!(params.format, TrackerFormat::Mod | TrackerFormat::S3m) && pattern.rows > 64 {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!(
+                    "pattern '{}' has {} rows; {:?} supports at most 64",
+                    name, pattern.rows, params.format
+                ),
+                format!("recipe.params.patterns.{}.rows", name),
+            ));
+        }
 
         for (channel, note) in pattern.flat_notes() {
             if channel >= params.channels {
@@ -484,6 +768,8 @@ fn validate_tracker_song_semantics(
                         let validation = match params.format {
                             TrackerFormat::Xm => effect.validate_xm(),
                             TrackerFormat::It => effect.validate_it(),
+                            TrackerFormat::Mod => effect.validate_mod(),
+                            TrackerFormat::S3m => effect.validate_s3m(),
                         };
                         if let Err(err) = validation {
                             result.add_error(ValidationError::with_path(
@@ -4,11 +4,12 @@ use std::collections::{HashMap, HashSet};
 
 use crate::error::{ErrorCode, ValidationError, ValidationResult};
 use crate::output::{OutputFormat, OutputKind};
+use crate::recipe::texture::TextureProceduralNode;
 use crate::recipe::Recipe;
 use crate::spec::Spec;
-use crate::validation::BudgetProfile;
+use crate::validation::{BudgetCategory, BudgetProfile};
 
-use super::recipe_outputs::validate_primary_output_present;
+use super::recipe_outputs::{validate_primary_output_present, warn_if_near_budget_limit};
 
 /// Validates texture procedural outputs with the default budget profile.
 #[allow(dead_code)]
@@ -65,14 +66,313 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
             "recipe.params.nodes",
         ));
     }
+    warn_if_near_budget_limit(
+        BudgetCategory::Texture,
+        "graph node count",
+        params.nodes.len() as f64,
+        max_graph_nodes as f64,
+        budget,
+        "recipe.params.nodes",
+        result,
+    );
 
+    let node_ids = validate_procedural_graph(&params.nodes, "recipe.params.nodes", result);
+
+    // Outputs: primary PNG/EXR outputs must declare source and refer to a node id.
+    for (i, output) in spec.outputs.iter().enumerate() {
+        if output.kind != OutputKind::Primary {
+            continue;
+        }
+
+        if !matches!(output.format, OutputFormat::Png | OutputFormat::Exr) {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                "texture.procedural_v1 primary outputs must have format 'png' or 'exr'",
+                format!("outputs[{}].format", i),
+            ));
+        }
+
+        let Some(source) = output.source.as_deref() else {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                "texture.procedural_v1 primary outputs must set 'source' to a node id",
+                format!("outputs[{}].source", i),
+            ));
+            continue;
+        };
+
+        if !node_ids.contains(source) {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                format!(
+                    "outputs[{}].source '{}' does not match any recipe.params.nodes[].id",
+                    i, source
+                ),
+                format!("outputs[{}].source", i),
+            ));
+        }
+    }
+}
+
+/// Validates outputs for `texture.composite_v1` recipe.
+pub(super) fn validate_texture_composite_outputs_with_budget(
+    spec: &Spec,
+    recipe: &Recipe,
+    budget: &BudgetProfile,
+    result: &mut ValidationResult,
+) {
+    let params = match recipe.as_texture_composite() {
+        Ok(params) => params,
+        Err(e) => {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!("invalid params for {}: {}", recipe.kind, e),
+                "recipe.params",
+            ));
+            return;
+        }
+    };
+
+    validate_primary_output_present(spec, result);
+
+    if params.resolution[0] == 0 || params.resolution[1] == 0 {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!(
+                "resolution must be positive, got [{}, {}]",
+                params.resolution[0], params.resolution[1]
+            ),
+            "recipe.params.resolution",
+        ));
+    }
+
+    if params.layers.is_empty() {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            "texture.composite_v1 requires at least one layer".to_string(),
+            "recipe.params.layers",
+        ));
+        return;
+    }
+
+    // The node budget applies to the total across all layers.
+    let total_nodes: usize = params.layers.iter().map(|l| l.nodes.len()).sum();
+    if total_nodes > budget.texture.max_graph_nodes {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::BudgetExceeded,
+            format!(
+                "composite layers have {} nodes in total, exceeds budget limit of {} (profile: {})",
+                total_nodes, budget.texture.max_graph_nodes, budget.name
+            ),
+            "recipe.params.layers",
+        ));
+    }
+    warn_if_near_budget_limit(
+        BudgetCategory::Texture,
+        "graph node count",
+        total_nodes as f64,
+        budget.texture.max_graph_nodes as f64,
+        budget,
+        "recipe.params.layers",
+        result,
+    );
+
+    for (i, layer) in params.layers.iter().enumerate() {
+        let nodes_path = format!("recipe.params.layers[{}].nodes", i);
+
+        if layer.nodes.is_empty() {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                "composite layer requires at least one node".to_string(),
+                nodes_path,
+            ));
+            continue;
+        }
+
+        let node_ids = validate_procedural_graph(&layer.nodes, &nodes_path, result);
+
+        if !node_ids.contains(layer.output.as_str()) {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!(
+                    "layer output '{}' does not match any node id in the layer",
+                    layer.output
+                ),
+                format!("recipe.params.layers[{}].output", i),
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&layer.opacity) {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!("opacity must be in [0, 1], got {}", layer.opacity),
+                format!("recipe.params.layers[{}].opacity", i),
+            ));
+        }
+    }
+
+    for (i, output) in spec.outputs.iter().enumerate() {
+        if output.kind == OutputKind::Primary && output.format != OutputFormat::Png {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                "texture.composite_v1 primary outputs must have format 'png'",
+                format!("outputs[{}].format", i),
+            ));
+        }
+    }
+}
+
+/// Validates outputs for `texture.tile_variation_v1` recipe.
+pub(super) fn validate_texture_tile_variation_outputs_with_budget(
+    spec: &Spec,
+    recipe: &Recipe,
+    budget: &BudgetProfile,
+    result: &mut ValidationResult,
+) {
+    let params = match recipe.as_texture_tile_variation() {
+        Ok(params) => params,
+        Err(e) => {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::InvalidRecipeParams,
+                format!("invalid params for {}: {}", recipe.kind, e),
+                "recipe.params",
+            ));
+            return;
+        }
+    };
+
+    validate_primary_output_present(spec, result);
+
+    let [tile_width, tile_height] = params.tile_resolution;
+    let [columns, rows] = params.tiles;
+    if tile_width == 0 || tile_height == 0 {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!(
+                "tile_resolution must be positive, got [{}, {}]",
+                tile_width, tile_height
+            ),
+            "recipe.params.tile_resolution",
+        ));
+    }
+    if columns == 0 || rows == 0 {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!("tiles must be positive, got [{}, {}]", columns, rows),
+            "recipe.params.tiles",
+        ));
+    }
+
+    // The sheet, not the tile, is what gets allocated and written.
+    let sheet_width = tile_width as u64 * columns as u64;
+    let sheet_height = tile_height as u64 * rows as u64;
+    if sheet_width.max(sheet_height) > budget.texture.max_dimension as u64
+        || sheet_width * sheet_height > budget.texture.max_pixels
+    {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::BudgetExceeded,
+            format!(
+                "sheet is {}x{}, exceeds budget limit of {} per side and {} pixels (profile: {})",
+                sheet_width,
+                sheet_height,
+                budget.texture.max_dimension,
+                budget.texture.max_pixels,
+                budget.name
+            ),
+            "recipe.params.tiles",
+        ));
+    }
+    warn_if_near_budget_limit(
+        BudgetCategory::Texture,
+        "sheet pixel count",
+        (sheet_width * sheet_height) as f64,
+        budget.texture.max_pixels as f64,
+        budget,
+        "recipe.params.tiles",
+        result,
+    );
+
+    if !(0.0..=180.0).contains(&params.variation.hue_shift) {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!(
+                "hue_shift must be in [0, 180], got {}",
+                params.variation.hue_shift
+            ),
+            "recipe.params.variation.hue_shift",
+        ));
+    }
+
+    if params.nodes.is_empty() {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            "texture.tile_variation_v1 requires at least one node".to_string(),
+            "recipe.params.nodes",
+        ));
+        return;
+    }
+
+    if params.nodes.len() > budget.texture.max_graph_nodes {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::BudgetExceeded,
+            format!(
+                "texture graph has {} nodes, exceeds budget limit of {} (profile: {})",
+                params.nodes.len(),
+                budget.texture.max_graph_nodes,
+                budget.name
+            ),
+            "recipe.params.nodes",
+        ));
+    }
+    warn_if_near_budget_limit(
+        BudgetCategory::Texture,
+        "graph node count",
+        params.nodes.len() as f64,
+        budget.texture.max_graph_nodes as f64,
+        budget,
+        "recipe.params.nodes",
+        result,
+    );
+
+    let node_ids = validate_procedural_graph(&params.nodes, "recipe.params.nodes", result);
+    if !node_ids.contains(params.output.as_str()) {
+        result.add_error(ValidationError::with_path(
+            ErrorCode::InvalidRecipeParams,
+            format!(
+                "output '{}' does not match any recipe.params.nodes[].id",
+                params.output
+            ),
+            "recipe.params.output",
+        ));
+    }
+
+    for (i, output) in spec.outputs.iter().enumerate() {
+        if output.kind == OutputKind::Primary && output.format != OutputFormat::Png {
+            result.add_error(ValidationError::with_path(
+                ErrorCode::OutputValidationFailed,
+                "texture.tile_variation_v1 primary outputs must have format 'png'",
+                format!("outputs[{}].format", i),
+            ));
+        }
+    }
+}
+
+/// Validates a procedural node graph: unique ids, node references, input types, and acyclicity.
+///
+/// `nodes_path` is the spec path of the node list (e.g. `recipe.params.nodes`) and prefixes
+/// all reported error paths. Returns the set of declared node ids.
+pub(super) fn validate_procedural_graph<'a>(
+    nodes: &'a [TextureProceduralNode],
+    nodes_path: &str,
+    result: &mut ValidationResult,
+) -> HashSet<&'a str> {
     let mut node_ids: HashSet<&str> = HashSet::new();
-    for (i, node) in params.nodes.iter().enumerate() {
+    for (i, node) in nodes.iter().enumerate() {
         if !node_ids.insert(node.id.as_str()) {
             result.add_error(ValidationError::with_path(
                 ErrorCode::InvalidRecipeParams,
                 format!("duplicate node id: '{}'", node.id),
-                format!("recipe.params.nodes[{}].id", i),
+                format!("{}[{}].id", nodes_path, i),
             ));
         }
     }
@@ -104,20 +404,23 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
 
     // Type information for each node is fixed based on op kind (this enables simple type checks).
     let mut node_types: HashMap<&str, GraphValueType> = HashMap::new();
-    for node in &params.nodes {
+    for node in nodes {
         use crate::recipe::texture::TextureProceduralOp;
 
         let node_type = match &node.op {
             TextureProceduralOp::ColorRamp { .. }
+            | TextureProceduralOp::GradientMap { .. }
             | TextureProceduralOp::Palette { .. }
             | TextureProceduralOp::ComposeRgba { .. }
             | TextureProceduralOp::NormalFromHeight { .. } => GraphValueType::Color,
             TextureProceduralOp::Constant { .. }
             | TextureProceduralOp::Noise { .. }
+            | TextureProceduralOp::DirectionalNoise { .. }
             | TextureProceduralOp::ReactionDiffusion { .. }
             | TextureProceduralOp::Gradient { .. }
             | TextureProceduralOp::Stripes { .. }
             | TextureProceduralOp::Checkerboard { .. }
+            | TextureProceduralOp::Pattern { .. }
             | TextureProceduralOp::Invert { .. }
             | TextureProceduralOp::Clamp { .. }
             | TextureProceduralOp::Add { .. }
@@ -129,6 +432,7 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
             | TextureProceduralOp::Erode { .. }
             | TextureProceduralOp::Dilate { .. }
             | TextureProceduralOp::Warp { .. }
+            | TextureProceduralOp::DomainWarp { .. }
             | TextureProceduralOp::BlendScreen { .. }
             | TextureProceduralOp::BlendOverlay { .. }
             | TextureProceduralOp::BlendSoftLight { .. }
@@ -138,11 +442,48 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
             | TextureProceduralOp::UvTranslate { .. }
             | TextureProceduralOp::WangTiles { .. }
             | TextureProceduralOp::TextureBomb { .. } => GraphValueType::Grayscale,
+            TextureProceduralOp::Bevel { mode, .. } => match mode {
+                crate::recipe::texture::BevelMode::Grayscale => GraphValueType::Grayscale,
+                crate::recipe::texture::BevelMode::Normal => GraphValueType::Color,
+            },
+            // Resolved from its inputs below.
+            TextureProceduralOp::Blend { .. }
+            | TextureProceduralOp::Posterize { .. }
+            | TextureProceduralOp::Dither { .. }
+            | TextureProceduralOp::Math { .. } => GraphValueType::Grayscale,
         };
 
         node_types.insert(node.id.as_str(), node_type);
     }
 
+    // `blend` produces color when either input does and `posterize`/`dither`/`math`
+    // keep their input type; propagate until stable (each pass settles at least one more
+    // level of the DAG).
+    for _ in 0..nodes.len() {
+        let mut changed = false;
+        for node in nodes {
+            use crate::recipe::texture::TextureProceduralOp;
+
+            let inputs = match &node.op {
+                TextureProceduralOp::Blend { a, b, .. } => vec![a, b],
+                TextureProceduralOp::Posterize { input, .. }
+                | TextureProceduralOp::Dither { input, .. }
+                | TextureProceduralOp::Math { input, .. } => vec![input],
+                _ => continue,
+            };
+            let is_color = inputs
+                .iter()
+                .any(|id| node_types.get(id.as_str()) == Some(&GraphValueType::Color));
+            if is_color && node_types.get(node.id.as_str()) != Some(&GraphValueType::Color) {
+                node_types.insert(node.id.as_str(), GraphValueType::Color);
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+
     let validate_input_type =
         |expected: GraphValueType, id: &str, path: String, result: &mut ValidationResult| {
             let Some(actual) = node_types.get(id).copied() else {
@@ -164,7 +505,7 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
 
     let mut deps: HashMap<&str, Vec<&str>> = HashMap::new();
 
-    for (i, node) in params.nodes.iter().enumerate() {
+    for (i, node) in nodes.iter().enumerate() {
         use crate::recipe::texture::TextureProceduralOp;
 
         match &node.op {
@@ -173,9 +514,10 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
             | TextureProceduralOp::Threshold { input, .. }
             | TextureProceduralOp::ToGrayscale { input }
             | TextureProceduralOp::ColorRamp { input, .. }
+            | TextureProceduralOp::GradientMap { input, .. }
             | TextureProceduralOp::Palette { input, .. }
             | TextureProceduralOp::NormalFromHeight { input, .. } => {
-                validate_ref(input, format!("recipe.params.nodes[{}].input", i), result);
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
                 // Input types
                 match &node.op {
                     TextureProceduralOp::ToGrayscale { .. }
@@ -183,11 +525,12 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
                         validate_input_type(
                             GraphValueType::Color,
                             input,
-                            format!("recipe.params.nodes[{}].input", i),
+                            format!("{}[{}].input", nodes_path, i),
                             result,
                         );
                     }
                     TextureProceduralOp::ColorRamp { .. }
+                    | TextureProceduralOp::GradientMap { .. }
                     | TextureProceduralOp::NormalFromHeight { .. }
                     | TextureProceduralOp::Invert { .. }
                     | TextureProceduralOp::Clamp { .. }
@@ -195,7 +538,7 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
                         validate_input_type(
                             GraphValueType::Grayscale,
                             input,
-                            format!("recipe.params.nodes[{}].input", i),
+                            format!("{}[{}].input", nodes_path, i),
                             result,
                         );
                     }
@@ -205,77 +548,77 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
                 deps.insert(node.id.as_str(), vec![input.as_str()]);
             }
             TextureProceduralOp::Add { a, b } | TextureProceduralOp::Multiply { a, b } => {
-                validate_ref(a, format!("recipe.params.nodes[{}].a", i), result);
-                validate_ref(b, format!("recipe.params.nodes[{}].b", i), result);
+                validate_ref(a, format!("{}[{}].a", nodes_path, i), result);
+                validate_ref(b, format!("{}[{}].b", nodes_path, i), result);
                 validate_input_type(
                     GraphValueType::Grayscale,
                     a,
-                    format!("recipe.params.nodes[{}].a", i),
+                    format!("{}[{}].a", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     b,
-                    format!("recipe.params.nodes[{}].b", i),
+                    format!("{}[{}].b", nodes_path, i),
                     result,
                 );
 
                 deps.insert(node.id.as_str(), vec![a.as_str(), b.as_str()]);
             }
             TextureProceduralOp::Lerp { a, b, t } => {
-                validate_ref(a, format!("recipe.params.nodes[{}].a", i), result);
-                validate_ref(b, format!("recipe.params.nodes[{}].b", i), result);
-                validate_ref(t, format!("recipe.params.nodes[{}].t", i), result);
+                validate_ref(a, format!("{}[{}].a", nodes_path, i), result);
+                validate_ref(b, format!("{}[{}].b", nodes_path, i), result);
+                validate_ref(t, format!("{}[{}].t", nodes_path, i), result);
                 validate_input_type(
                     GraphValueType::Grayscale,
                     a,
-                    format!("recipe.params.nodes[{}].a", i),
+                    format!("{}[{}].a", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     b,
-                    format!("recipe.params.nodes[{}].b", i),
+                    format!("{}[{}].b", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     t,
-                    format!("recipe.params.nodes[{}].t", i),
+                    format!("{}[{}].t", nodes_path, i),
                     result,
                 );
 
                 deps.insert(node.id.as_str(), vec![a.as_str(), b.as_str(), t.as_str()]);
             }
             TextureProceduralOp::ComposeRgba { r, g, b, a } => {
-                validate_ref(r, format!("recipe.params.nodes[{}].r", i), result);
-                validate_ref(g, format!("recipe.params.nodes[{}].g", i), result);
-                validate_ref(b, format!("recipe.params.nodes[{}].b", i), result);
+                validate_ref(r, format!("{}[{}].r", nodes_path, i), result);
+                validate_ref(g, format!("{}[{}].g", nodes_path, i), result);
+                validate_ref(b, format!("{}[{}].b", nodes_path, i), result);
                 validate_input_type(
                     GraphValueType::Grayscale,
                     r,
-                    format!("recipe.params.nodes[{}].r", i),
+                    format!("{}[{}].r", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     g,
-                    format!("recipe.params.nodes[{}].g", i),
+                    format!("{}[{}].g", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     b,
-                    format!("recipe.params.nodes[{}].b", i),
+                    format!("{}[{}].b", nodes_path, i),
                     result,
                 );
 
                 if let Some(a) = a.as_deref() {
-                    validate_ref(a, format!("recipe.params.nodes[{}].a", i), result);
+                    validate_ref(a, format!("{}[{}].a", nodes_path, i), result);
                     validate_input_type(
                         GraphValueType::Grayscale,
                         a,
-                        format!("recipe.params.nodes[{}].a", i),
+                        format!("{}[{}].a", nodes_path, i),
                         result,
                     );
                 }
@@ -288,10 +631,12 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
             }
             TextureProceduralOp::Constant { .. }
             | TextureProceduralOp::Noise { .. }
+            | TextureProceduralOp::DirectionalNoise { .. }
             | TextureProceduralOp::ReactionDiffusion { .. }
             | TextureProceduralOp::Gradient { .. }
             | TextureProceduralOp::Stripes { .. }
-            | TextureProceduralOp::Checkerboard { .. } => {
+            | TextureProceduralOp::Checkerboard { .. }
+            | TextureProceduralOp::Pattern { .. } => {
                 deps.insert(node.id.as_str(), Vec::new());
             }
             // Single grayscale input ops
@@ -301,11 +646,11 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
             | TextureProceduralOp::UvScale { input, .. }
             | TextureProceduralOp::UvRotate { input, .. }
             | TextureProceduralOp::UvTranslate { input, .. } => {
-                validate_ref(input, format!("recipe.params.nodes[{}].input", i), result);
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
                 validate_input_type(
                     GraphValueType::Grayscale,
                     input,
-                    format!("recipe.params.nodes[{}].input", i),
+                    format!("{}[{}].input", nodes_path, i),
                     result,
                 );
                 deps.insert(node.id.as_str(), vec![input.as_str()]);
@@ -316,22 +661,22 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
                 displacement,
                 ..
             } => {
-                validate_ref(input, format!("recipe.params.nodes[{}].input", i), result);
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
                 validate_ref(
                     displacement,
-                    format!("recipe.params.nodes[{}].displacement", i),
+                    format!("{}[{}].displacement", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     input,
-                    format!("recipe.params.nodes[{}].input", i),
+                    format!("{}[{}].input", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     displacement,
-                    format!("recipe.params.nodes[{}].displacement", i),
+                    format!("{}[{}].displacement", nodes_path, i),
                     result,
                 );
                 deps.insert(
@@ -339,35 +684,133 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
                     vec![input.as_str(), displacement.as_str()],
                 );
             }
+            // DomainWarp: input + x/y displacement fields (all grayscale)
+            TextureProceduralOp::DomainWarp {
+                input,
+                warp_x,
+                warp_y,
+                ..
+            } => {
+                for (field, id) in [("input", input), ("warp_x", warp_x), ("warp_y", warp_y)] {
+                    let path = format!("{}[{}].{}", nodes_path, i, field);
+                    validate_ref(id, path.clone(), result);
+                    validate_input_type(GraphValueType::Grayscale, id, path, result);
+                }
+                deps.insert(
+                    node.id.as_str(),
+                    vec![input.as_str(), warp_x.as_str(), warp_y.as_str()],
+                );
+            }
             // Blend modes: base + blend (both grayscale)
             TextureProceduralOp::BlendScreen { base, blend }
             | TextureProceduralOp::BlendOverlay { base, blend }
             | TextureProceduralOp::BlendSoftLight { base, blend }
             | TextureProceduralOp::BlendDifference { base, blend } => {
-                validate_ref(base, format!("recipe.params.nodes[{}].base", i), result);
-                validate_ref(blend, format!("recipe.params.nodes[{}].blend", i), result);
+                validate_ref(base, format!("{}[{}].base", nodes_path, i), result);
+                validate_ref(blend, format!("{}[{}].blend", nodes_path, i), result);
                 validate_input_type(
                     GraphValueType::Grayscale,
                     base,
-                    format!("recipe.params.nodes[{}].base", i),
+                    format!("{}[{}].base", nodes_path, i),
                     result,
                 );
                 validate_input_type(
                     GraphValueType::Grayscale,
                     blend,
-                    format!("recipe.params.nodes[{}].blend", i),
+                    format!("{}[{}].blend", nodes_path, i),
                     result,
                 );
                 deps.insert(node.id.as_str(), vec![base.as_str(), blend.as_str()]);
             }
+            // Blend: base + layer (grayscale or color) with an optional grayscale mask
+            TextureProceduralOp::Blend {
+                a,
+                b,
+                mask,
+                opacity,
+                ..
+            } => {
+                validate_ref(a, format!("{}[{}].a", nodes_path, i), result);
+                validate_ref(b, format!("{}[{}].b", nodes_path, i), result);
+                let mut node_deps = vec![a.as_str(), b.as_str()];
+                if let Some(mask) = mask {
+                    let path = format!("{}[{}].mask", nodes_path, i);
+                    validate_ref(mask, path.clone(), result);
+                    validate_input_type(GraphValueType::Grayscale, mask, path, result);
+                    node_deps.push(mask.as_str());
+                }
+                if !(0.0..=1.0).contains(opacity) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("opacity must be in [0, 1], got {}", opacity),
+                        format!("{}[{}].opacity", nodes_path, i),
+                    ));
+                }
+                deps.insert(node.id.as_str(), node_deps);
+            }
+            // Posterize/Dither: grayscale or color input, at least two levels
+            TextureProceduralOp::Posterize { input, levels, .. }
+            | TextureProceduralOp::Dither { input, levels, .. } => {
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
+                if *levels < 2 {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("levels must be at least 2, got {}", levels),
+                        format!("{}[{}].levels", nodes_path, i),
+                    ));
+                }
+                deps.insert(node.id.as_str(), vec![input.as_str()]);
+            }
+            // Math: grayscale or color input, finite operand
+            TextureProceduralOp::Math { input, operand, .. } => {
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
+                if !operand.is_finite() {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("operand must be finite, got {}", operand),
+                        format!("{}[{}].operand", nodes_path, i),
+                    ));
+                }
+                deps.insert(node.id.as_str(), vec![input.as_str()]);
+            }
+            // Bevel: grayscale mask, positive width, softness in [0, 1]
+            TextureProceduralOp::Bevel {
+                input,
+                distance,
+                softness,
+                ..
+            } => {
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
+                validate_input_type(
+                    GraphValueType::Grayscale,
+                    input,
+                    format!("{}[{}].input", nodes_path, i),
+                    result,
+                );
+                if !distance.is_finite() || *distance <= 0.0 {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("distance must be positive, got {}", distance),
+                        format!("{}[{}].distance", nodes_path, i),
+                    ));
+                }
+                if !(0.0..=1.0).contains(softness) {
+                    result.add_error(ValidationError::with_path(
+                        ErrorCode::InvalidRecipeParams,
+                        format!("softness must be in [0, 1], got {}", softness),
+                        format!("{}[{}].softness", nodes_path, i),
+                    ));
+                }
+                deps.insert(node.id.as_str(), vec![input.as_str()]);
+            }
             // Stochastic tiling: WangTiles and TextureBomb (grayscale input)
             TextureProceduralOp::WangTiles { input, .. }
             | TextureProceduralOp::TextureBomb { input, .. } => {
-                validate_ref(input, format!("recipe.params.nodes[{}].input", i), result);
+                validate_ref(input, format!("{}[{}].input", nodes_path, i), result);
                 validate_input_type(
                     GraphValueType::Grayscale,
                     input,
-                    format!("recipe.params.nodes[{}].input", i),
+                    format!("{}[{}].input", nodes_path, i),
                     result,
                 );
                 deps.insert(node.id.as_str(), vec![input.as_str()]);
@@ -428,44 +871,11 @@ pub(super) fn validate_texture_procedural_outputs_with_budget(
         result.add_error(ValidationError::with_path(
             ErrorCode::InvalidRecipeParams,
             format!("cycle detected: {}", cycle.join(" -> ")),
-            "recipe.params.nodes",
+            nodes_path.to_string(),
         ));
     }
 
-    // Outputs: primary PNG outputs must declare source and refer to a node id.
-    for (i, output) in spec.outputs.iter().enumerate() {
-        if output.kind != OutputKind::Primary {
-            continue;
-        }
-
-        if output.format != OutputFormat::Png {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::OutputValidationFailed,
-                "texture.procedural_v1 primary outputs must have format 'png'",
-                format!("outputs[{}].format", i),
-            ));
-        }
-
-        let Some(source) = output.source.as_deref() else {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::OutputValidationFailed,
-                "texture.procedural_v1 primary outputs must set 'source' to a node id",
-                format!("outputs[{}].source", i),
-            ));
-            continue;
-        };
-
-        if !node_ids.contains(source) {
-            result.add_error(ValidationError::with_path(
-                ErrorCode::OutputValidationFailed,
-                format!(
-                    "outputs[{}].source '{}' does not match any recipe.params.nodes[].id",
-                    i, source
-                ),
-                format!("outputs[{}].source", i),
-            ));
-        }
-    }
+    node_ids
 }
 
 /// Validates outputs for `texture.trimsheet_v1` recipe.
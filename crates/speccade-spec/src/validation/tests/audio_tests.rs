@@ -34,6 +34,31 @@ fn test_audio_requires_wav_primary_output() {
         .any(|e| e.code == crate::error::ErrorCode::OutputValidationFailed));
 }
 
+#[test]
+fn test_audio_accepts_ogg_primary_output() {
+    let mut spec = make_valid_spec();
+    spec.outputs = vec![OutputSpec::primary(OutputFormat::Ogg, "sounds/test.ogg")];
+    spec.recipe = Some(crate::recipe::Recipe::new(
+        "audio_v1",
+        serde_json::json!({
+            "duration_seconds": 0.1,
+            "layers": []
+        }),
+    ));
+
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "{:?}", result.errors);
+
+    // Still exactly one primary output.
+    spec.outputs
+        .push(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"));
+    let result = validate_for_generate(&spec);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.code == crate::error::ErrorCode::OutputValidationFailed));
+}
+
 #[test]
 fn test_audio_lfo_rejects_depth_out_of_range() {
     let spec = crate::spec::Spec::builder("test-audio-lfo-01", AssetType::Audio)
@@ -443,6 +468,110 @@ fn test_audio_lfo_rejects_grain_density_with_zero_amount() {
         .any(|e| e.code == crate::error::ErrorCode::InvalidRecipeParams));
 }
 
+#[test]
+fn test_audio_lfo_allows_wavetable_position_on_custom_wavetable() {
+    let spec = crate::spec::Spec::builder("test-audio-wt-pos-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": {
+                            "type": "wavetable",
+                            "table": "basic",
+                            "tables": [[0.0, 1.0, 0.0, -1.0], [-1.0, -0.5, 0.0, 0.5]],
+                            "frequency": 220.0,
+                            "position": 0.5
+                        },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "lfo": {
+                            "config": { "waveform": "sine", "rate": 2.0, "depth": 1.0 },
+                            "target": { "target": "wavetable_position", "amount": 0.5 }
+                        }
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(result.is_ok(), "{:?}", result.errors);
+}
+
+#[test]
+fn test_audio_lfo_rejects_wavetable_position_on_non_wavetable() {
+    let spec = crate::spec::Spec::builder("test-audio-wt-pos-02", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "lfo": {
+                            "config": { "waveform": "sine", "rate": 2.0, "depth": 1.0 },
+                            "target": { "target": "wavetable_position", "amount": 0.5 }
+                        }
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(!result.is_ok());
+    assert!(result.errors.iter().any(|e| e
+        .message
+        .contains("wavetable_position LFO target is only valid for Wavetable")));
+}
+
+#[test]
+fn test_audio_wavetable_rejects_degenerate_frame() {
+    let spec = crate::spec::Spec::builder("test-audio-wt-frame-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": {
+                            "type": "wavetable",
+                            "table": "basic",
+                            "tables": [[0.0, 1.0, 0.0, -1.0], [0.5]],
+                            "frequency": 220.0
+                        },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(!result.is_ok());
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.layers[0].synthesis.tables[1]")));
+}
+
 // ============================================================================
 // Post-FX LFO Tests
 // ============================================================================
@@ -633,3 +762,344 @@ fn test_post_fx_lfo_rejects_delay_time_without_delay_effect() {
         .message
         .contains("delay_time LFO requires at least one delay effect")));
 }
+
+#[test]
+fn test_audio_modulation_matrix_rejects_filter_cutoff_route_without_filter() {
+    let spec = crate::spec::Spec::builder("test-audio-mod-matrix-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "modulation_matrix": [
+                            {
+                                "source": { "type": "lfo", "waveform": "sine", "rate": 5.0 },
+                                "destination": "pitch",
+                                "depth": 0.5
+                            },
+                            {
+                                "source": { "type": "envelope", "attack": 0.01, "decay": 0.05, "sustain": 0.5, "release": 0.0 },
+                                "destination": "filter_cutoff",
+                                "depth": 1000.0
+                            }
+                        ]
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(!result.is_ok());
+    assert!(result.errors.iter().any(|e| {
+        e.message.contains("filter_cutoff route requires")
+            && e.path.as_deref() == Some("recipe.params.layers[0].modulation_matrix[1].destination")
+    }));
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+}
+
+#[test]
+fn test_audio_filter_keytrack_requires_pitched_layer() {
+    let spec = crate::spec::Spec::builder("test-audio-keytrack-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "base_note": "C4",
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sawtooth", "frequency": 440.0 },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "filter": { "type": "lowpass", "cutoff": 800.0, "resonance": 0.707, "keytrack": 1.0 }
+                    },
+                    {
+                        "synthesis": { "type": "noise_burst", "noise_type": "white" },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "filter": { "type": "lowpass", "cutoff": 800.0, "resonance": 0.707, "keytrack": 1.0 }
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(!result.is_ok());
+    assert!(result.errors.iter().any(|e| {
+        e.message.contains("keytrack requires a pitched synthesis")
+            && e.path.as_deref() == Some("recipe.params.layers[1].filter.keytrack")
+    }));
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+}
+
+#[test]
+fn test_audio_oscillator_rejects_detune_voices_out_of_range() {
+    let spec = crate::spec::Spec::builder("test-audio-unison-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": {
+                            "type": "oscillator",
+                            "waveform": "sawtooth",
+                            "frequency": 220.0,
+                            "detune_voices": 32,
+                            "detune_spread": 15.0
+                        },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(!result.is_ok());
+    assert!(result.errors.iter().any(|e| {
+        e.message.contains("detune_voices must be in [1, 16]")
+            && e.path.as_deref() == Some("recipe.params.layers[0].synthesis.detune_voices")
+    }));
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+}
+
+#[test]
+fn test_audio_envelope_rejects_non_positive_power_curve() {
+    let spec = crate::spec::Spec::builder("test-audio-env-curve-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.1,
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 220.0 },
+                        "envelope": {
+                            "attack": 0.01,
+                            "decay": 0.05,
+                            "sustain": 0.5,
+                            "release": 0.02,
+                            "decay_curve": { "power": 0.0 }
+                        },
+                        "volume": 1.0,
+                        "pan": 0.0
+                    }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_spec(&spec);
+    assert!(result.errors.iter().any(|e| {
+        e.message.contains("power curve exponent must be positive")
+            && e.path.as_deref() == Some("recipe.params.layers[0].envelope.decay_curve")
+    }));
+    assert_eq!(result.errors.len(), 1, "{:?}", result.errors);
+}
+
+fn arpeggio_spec(synthesis: serde_json::Value, arpeggio: serde_json::Value) -> crate::spec::Spec {
+    crate::spec::Spec::builder("test-audio-arp-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.5,
+                "layers": [
+                    {
+                        "synthesis": synthesis,
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "arpeggio": arpeggio
+                    }
+                ]
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn test_audio_arpeggio_accepts_oscillator_layer() {
+    let spec = arpeggio_spec(
+        serde_json::json!({ "type": "oscillator", "waveform": "square", "frequency": 220.0 }),
+        serde_json::json!({ "pattern": [0.0, 4.0, 7.0], "rate_hz": 12.0, "gate": 0.5, "mode": "up_down" }),
+    );
+
+    let result = validate_spec(&spec);
+    assert!(result.is_ok(), "{:?}", result.errors);
+}
+
+#[test]
+fn test_audio_arpeggio_rejects_non_oscillator_synthesis() {
+    let spec = arpeggio_spec(
+        serde_json::json!({ "type": "noise_burst", "noise_type": "white" }),
+        serde_json::json!({ "pattern": [0.0, 12.0], "rate_hz": 8.0 }),
+    );
+
+    let result = validate_spec(&spec);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.layers[0].arpeggio")));
+}
+
+#[test]
+fn test_audio_arpeggio_rejects_invalid_params() {
+    let spec = arpeggio_spec(
+        serde_json::json!({ "type": "oscillator", "waveform": "sine", "frequency": 440.0 }),
+        serde_json::json!({ "pattern": [], "rate_hz": 0.0, "gate": 1.5 }),
+    );
+
+    let result = validate_spec(&spec);
+    for field in ["pattern", "rate_hz", "gate"] {
+        let path = format!("recipe.params.layers[0].arpeggio.{}", field);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path.as_deref() == Some(path.as_str())),
+            "missing error for {}: {:?}",
+            path,
+            result.errors
+        );
+    }
+}
+
+fn render_rate_spec(render_rate: u32) -> crate::spec::Spec {
+    crate::spec::Spec::builder("test-audio-rate-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.5,
+                "sample_rate": 44100,
+                "layers": [
+                    {
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0,
+                        "render_rate": render_rate
+                    }
+                ]
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn test_audio_render_rate_bounds() {
+    assert!(validate_spec(&render_rate_spec(22050)).is_ok());
+    assert!(validate_spec(&render_rate_spec(44100)).is_ok());
+
+    for rate in [500, 48000] {
+        let result = validate_spec(&render_rate_spec(rate));
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path.as_deref() == Some("recipe.params.layers[0].render_rate")),
+            "rate {}: {:?}",
+            rate,
+            result.errors
+        );
+    }
+}
+
+fn sidechain_spec(sidechain: serde_json::Value) -> crate::spec::Spec {
+    let layer = |frequency: f64| {
+        serde_json::json!({
+            "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": frequency },
+            "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+            "volume": 1.0,
+            "pan": 0.0
+        })
+    };
+    let mut ducked = layer(110.0);
+    ducked["sidechain"] = sidechain;
+
+    crate::spec::Spec::builder("test-audio-duck-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "sounds/test.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.5,
+                "layers": [layer(55.0), ducked]
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn test_audio_sidechain_accepts_other_layer() {
+    let spec = sidechain_spec(serde_json::json!({
+        "source_layer": 0, "threshold_db": -24.0, "ratio": 6.0, "attack_ms": 2.0, "release_ms": 150.0
+    }));
+
+    let result = validate_spec(&spec);
+    assert!(result.is_ok(), "{:?}", result.errors);
+}
+
+#[test]
+fn test_audio_sidechain_rejects_invalid_params() {
+    // Itself, and a layer that does not exist.
+    for source_layer in [1, 5] {
+        let spec = sidechain_spec(serde_json::json!({
+            "source_layer": source_layer, "threshold_db": -24.0, "ratio": 6.0, "attack_ms": 2.0, "release_ms": 150.0
+        }));
+        let result = validate_spec(&spec);
+        assert!(
+            result.errors.iter().any(
+                |e| e.path.as_deref() == Some("recipe.params.layers[1].sidechain.source_layer")
+            ),
+            "source_layer {}: {:?}",
+            source_layer,
+            result.errors
+        );
+    }
+
+    let spec = sidechain_spec(serde_json::json!({
+        "source_layer": 0, "threshold_db": 6.0, "ratio": 0.5, "attack_ms": 0.0, "release_ms": 5000.0
+    }));
+    let result = validate_spec(&spec);
+    for field in ["threshold_db", "ratio", "attack_ms", "release_ms"] {
+        let path = format!("recipe.params.layers[1].sidechain.{}", field);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path.as_deref() == Some(path.as_str())),
+            "missing error for {}: {:?}",
+            field,
+            result.errors
+        );
+    }
+}
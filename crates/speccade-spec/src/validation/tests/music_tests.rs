@@ -83,6 +83,34 @@ fn test_music_allows_dual_xm_it_primary_outputs() {
     assert!(result.is_ok(), "{:?}", result.errors);
 }
 
+#[test]
+fn test_music_allows_midi_alongside_tracker_output() {
+    let spec = crate::spec::Spec::builder("test-song-11", AssetType::Music)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Xm, "songs/test.xm"))
+        .output(OutputSpec::primary(OutputFormat::Mid, "songs/test.mid"))
+        .recipe(crate::recipe::Recipe::new(
+            "music.tracker_song_v1",
+            serde_json::json!({
+                "format": "xm",
+                "bpm": 120,
+                "speed": 6,
+                "channels": 4,
+                "patterns": {
+                    "intro": { "rows": 4 }
+                },
+                "arrangement": [
+                    { "pattern": "intro", "repeat": 1 }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "{:?}", result.errors);
+}
+
 #[test]
 fn test_music_instrument_requires_exactly_one_source() {
     let missing_source = crate::spec::Spec::builder("test-song-04", AssetType::Music)
@@ -136,6 +164,213 @@ fn test_music_instrument_requires_exactly_one_source() {
         .any(|e| e.code == crate::error::ErrorCode::InvalidRecipeParams));
 }
 
+#[test]
+fn test_music_pan_envelope_requires_increasing_ticks() {
+    let spec_with_pan_envelope = |points: serde_json::Value| {
+        crate::spec::Spec::builder("test-song-12", AssetType::Music)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(OutputFormat::Xm, "songs/test.xm"))
+            .recipe(crate::recipe::Recipe::new(
+                "music.tracker_song_v1",
+                serde_json::json!({
+                    "format": "xm",
+                    "bpm": 120,
+                    "speed": 6,
+                    "channels": 4,
+                    "instruments": [{
+                        "name": "lead",
+                        "synthesis": { "type": "sine" },
+                        "pan_envelope": { "points": points, "sustain_point": 1 }
+                    }],
+                    "patterns": {
+                        "intro": { "rows": 4 }
+                    },
+                    "arrangement": [
+                        { "pattern": "intro", "repeat": 1 }
+                    ]
+                }),
+            ))
+            .build()
+    };
+
+    let increasing = spec_with_pan_envelope(serde_json::json!([
+        { "tick": 0, "value": -32 },
+        { "tick": 8, "value": 32 }
+    ]));
+    let result = validate_for_generate(&increasing);
+    assert!(result.is_ok(), "{:?}", result.errors);
+
+    let repeated_tick = spec_with_pan_envelope(serde_json::json!([
+        { "tick": 8, "value": -32 },
+        { "tick": 8, "value": 32 }
+    ]));
+    let result = validate_for_generate(&repeated_tick);
+    assert!(result.errors.iter().any(|e| {
+        e.code == crate::error::ErrorCode::InvalidRecipeParams
+            && e.path.as_deref() == Some("recipe.params.instruments[0].pan_envelope.points[1].tick")
+    }));
+}
+
+#[test]
+fn test_music_auto_vibrato_depth_limited_by_format() {
+    let spec_with_vibrato = |format: &str, output_format: OutputFormat, depth: u8| {
+        crate::spec::Spec::builder("test-song-13", AssetType::Music)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(
+                output_format,
+                format!("songs/test.{}", format),
+            ))
+            .recipe(crate::recipe::Recipe::new(
+                "music.tracker_song_v1",
+                serde_json::json!({
+                    "format": format,
+                    "bpm": 120,
+                    "speed": 6,
+                    "channels": 4,
+                    "instruments": [{
+                        "name": "lead",
+                        "synthesis": { "type": "sine" },
+                        "auto_vibrato": { "waveform": "sine", "depth": depth, "rate": 32 }
+                    }],
+                    "patterns": {
+                        "intro": { "rows": 4 }
+                    },
+                    "arrangement": [
+                        { "pattern": "intro", "repeat": 1 }
+                    ]
+                }),
+            ))
+            .build()
+    };
+
+    let result = validate_for_generate(&spec_with_vibrato("xm", OutputFormat::Xm, 15));
+    assert!(result.is_ok(), "{:?}", result.errors);
+    let result = validate_for_generate(&spec_with_vibrato("it", OutputFormat::It, 32));
+    assert!(result.is_ok(), "{:?}", result.errors);
+
+    let result = validate_for_generate(&spec_with_vibrato("xm", OutputFormat::Xm, 32));
+    assert!(result.errors.iter().any(|e| {
+        e.code == crate::error::ErrorCode::InvalidRecipeParams
+            && e.path.as_deref() == Some("recipe.params.instruments[0].auto_vibrato.depth")
+    }));
+}
+
+#[test]
+fn test_music_stereo_instrument_warns_without_stereo_support() {
+    let spec_with_stereo = |format: &str, output_format: OutputFormat, channels: u8| {
+        crate::spec::Spec::builder("test-song-14", AssetType::Music)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(
+                output_format,
+                format!("songs/test.{}", format),
+            ))
+            .recipe(crate::recipe::Recipe::new(
+                "music.tracker_song_v1",
+                serde_json::json!({
+                    "format": format,
+                    "bpm": 120,
+                    "speed": 6,
+                    "channels": channels,
+                    "instruments": [{
+                        "name": "pad",
+                        "synthesis": { "type": "sine" },
+                        "stereo": true
+                    }],
+                    "patterns": {
+                        "intro": {
+                            "rows": 4,
+                            "notes": { "0": [{ "row": 0, "note": "C4", "inst": 0 }] }
+                        }
+                    },
+                    "arrangement": [
+                        { "pattern": "intro", "repeat": 1 }
+                    ]
+                }),
+            ))
+            .build()
+    };
+    let warns_stereo = |result: &ValidationResult| {
+        result.warnings.iter().any(|w| {
+            w.code == crate::error::WarningCode::UnusedRecipeParams
+                && w.path.as_deref() == Some("recipe.params.instruments[0].stereo")
+        })
+    };
+
+    for (format, output_format) in [("it", OutputFormat::It), ("xm", OutputFormat::Xm)] {
+        let result = validate_for_generate(&spec_with_stereo(format, output_format, 4));
+        assert!(result.is_ok(), "{:?}", result.errors);
+        assert!(!warns_stereo(&result), "{} should not warn", format);
+    }
+
+    // XM falls back to mono when the mirror channel does not fit
+    let result = validate_for_generate(&spec_with_stereo("xm", OutputFormat::Xm, 32));
+    assert!(result.is_ok(), "{:?}", result.errors);
+    assert!(warns_stereo(&result), "full XM should warn");
+
+    let result = validate_for_generate(&spec_with_stereo("mod", OutputFormat::Mod, 4));
+    assert!(result.is_ok(), "{:?}", result.errors);
+    assert!(warns_stereo(&result), "mod should warn");
+}
+
+#[test]
+fn test_music_sample_map_rejects_gaps_and_overlaps() {
+    let spec_with_zones = |second_low: &str| {
+        let zone = |low: &str, high: &str| {
+            serde_json::json!({
+                "low_note": low,
+                "high_note": high,
+                "synthesis_audio_v1": {
+                    "duration_seconds": 0.1,
+                    "layers": [{
+                        "synthesis": { "type": "oscillator", "waveform": "sine", "frequency": 440.0 },
+                        "envelope": { "attack": 0.0, "decay": 0.0, "sustain": 1.0, "release": 0.0 },
+                        "volume": 1.0,
+                        "pan": 0.0
+                    }]
+                }
+            })
+        };
+        crate::spec::Spec::builder("test-song-14", AssetType::Music)
+            .license("CC0-1.0")
+            .seed(42)
+            .output(OutputSpec::primary(OutputFormat::Xm, "songs/test.xm"))
+            .recipe(crate::recipe::Recipe::new(
+                "music.tracker_song_v1",
+                serde_json::json!({
+                    "format": "xm",
+                    "bpm": 120,
+                    "speed": 6,
+                    "channels": 4,
+                    "instruments": [{
+                        "name": "piano",
+                        "sample_map": [zone("C0", "B3"), zone(second_low, "B7")]
+                    }],
+                    "patterns": {
+                        "intro": { "rows": 4 }
+                    },
+                    "arrangement": [
+                        { "pattern": "intro", "repeat": 1 }
+                    ]
+                }),
+            ))
+            .build()
+    };
+
+    let result = validate_for_generate(&spec_with_zones("C4"));
+    assert!(result.is_ok(), "{:?}", result.errors);
+
+    for second_low in ["D4", "A3"] {
+        let result = validate_for_generate(&spec_with_zones(second_low));
+        assert!(result.errors.iter().any(|e| {
+            e.code == crate::error::ErrorCode::InvalidRecipeParams
+                && e.path.as_deref() == Some("recipe.params.instruments[0].sample_map[1].low_note")
+        }));
+    }
+}
+
 #[test]
 fn test_music_dual_outputs_rejects_duplicate_primary_format() {
     let spec = crate::spec::Spec::builder("test-song-03", AssetType::Music)
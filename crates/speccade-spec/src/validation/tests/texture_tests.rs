@@ -84,10 +84,38 @@ fn test_texture_procedural_rejects_cycles() {
 
     let result = validate_for_generate(&spec);
     assert!(!result.is_ok());
-    assert!(result
-        .errors
-        .iter()
-        .any(|e| e.message.contains("cycle detected")));
+    assert!(result.errors.iter().any(|e| {
+        e.code == ErrorCode::InvalidRecipeParams && e.message.contains("cycle detected")
+    }));
+}
+
+#[test]
+fn test_texture_procedural_rejects_unknown_node_reference() {
+    let mut output = OutputSpec::primary(OutputFormat::Png, "textures/a.png");
+    output.source = Some("a".to_string());
+
+    let spec = crate::spec::Spec::builder("procedural-unknown-ref-01", AssetType::Texture)
+        .license("CC0-1.0")
+        .seed(1)
+        .output(output)
+        .recipe(Recipe::new(
+            "texture.procedural_v1",
+            serde_json::json!({
+                "resolution": [8, 8],
+                "tileable": true,
+                "nodes": [
+                    { "id": "a", "type": "invert", "input": "ghost" }
+                ]
+            }),
+        ))
+        .build();
+
+    let result = validate_for_generate(&spec);
+    assert!(result.errors.iter().any(|e| {
+        e.code == ErrorCode::InvalidRecipeParams
+            && e.message.contains("unknown node reference 'ghost'")
+            && e.path.as_deref() == Some("recipe.params.nodes[0].input")
+    }));
 }
 
 #[test]
@@ -154,3 +182,351 @@ fn test_texture_procedural_accepts_reaction_diffusion() {
     let result = validate_for_generate(&spec);
     assert!(result.is_ok(), "errors: {:?}", result.errors);
 }
+
+fn make_texture_blend_spec(consumer: serde_json::Value) -> crate::spec::Spec {
+    let mut output = OutputSpec::primary(OutputFormat::Png, "textures/out.png");
+    output.source = Some("out".to_string());
+
+    crate::spec::Spec::builder("procedural-blend-01", AssetType::Texture)
+        .license("CC0-1.0")
+        .seed(1)
+        .output(output)
+        .recipe(Recipe::new(
+            "texture.procedural_v1",
+            serde_json::json!({
+                "resolution": [8, 8],
+                "tileable": true,
+                "nodes": [
+                    consumer,
+                    { "id": "mixed", "type": "blend", "a": "gray", "b": "tint", "mask": "gray", "opacity": 0.5 },
+                    { "id": "tint", "type": "color_ramp", "input": "gray", "ramp": ["#000000", "#ff0000"] },
+                    { "id": "gray", "type": "constant", "value": 0.5 }
+                ]
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn test_texture_procedural_blend_output_type_follows_inputs() {
+    let spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "to_grayscale", "input": "mixed" }),
+    );
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+
+    let spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "invert", "input": "mixed" }),
+    );
+    let result = validate_for_generate(&spec);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.message.contains("type mismatch")));
+}
+
+#[test]
+fn test_texture_procedural_blend_rejects_color_mask_and_bad_opacity() {
+    let spec = make_texture_blend_spec(serde_json::json!({
+        "id": "out", "type": "blend", "a": "gray", "b": "gray", "mask": "tint", "opacity": 1.5
+    }));
+    let result = validate_for_generate(&spec);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.message.contains("type mismatch")
+            && e.path.as_deref() == Some("recipe.params.nodes[0].mask")));
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.nodes[0].opacity")));
+}
+
+#[test]
+fn test_texture_procedural_posterize_keeps_input_type_and_checks_levels() {
+    // Posterize of a color blend stays color, so to_grayscale accepts it
+    let mut spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "to_grayscale", "input": "poster" }),
+    );
+    let nodes = spec.recipe.as_mut().unwrap().params["nodes"]
+        .as_array_mut()
+        .unwrap();
+    nodes.push(
+        serde_json::json!({ "id": "poster", "type": "posterize", "input": "mixed", "levels": 4 }),
+    );
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+
+    let spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "posterize", "input": "gray", "levels": 1 }),
+    );
+    let result = validate_for_generate(&spec);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.nodes[0].levels")));
+}
+
+#[test]
+fn test_texture_procedural_math_keeps_input_type() {
+    // Math on a color blend stays color, so to_grayscale accepts it
+    let mut spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "to_grayscale", "input": "scaled" }),
+    );
+    let nodes = spec.recipe.as_mut().unwrap().params["nodes"]
+        .as_array_mut()
+        .unwrap();
+    nodes.push(serde_json::json!({
+        "id": "scaled", "type": "math", "input": "mixed", "op": "multiply", "operand": 0.5
+    }));
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+
+    // ...while math on grayscale stays grayscale
+    let spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "math", "input": "gray", "op": "add", "operand": 0.1 }),
+    );
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+}
+
+#[test]
+fn test_texture_procedural_dither_keeps_input_type_and_checks_levels() {
+    // Dither of a color blend stays color, so to_grayscale accepts it
+    let mut spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "to_grayscale", "input": "dithered" }),
+    );
+    let nodes = spec.recipe.as_mut().unwrap().params["nodes"]
+        .as_array_mut()
+        .unwrap();
+    nodes.push(serde_json::json!({
+        "id": "dithered", "type": "dither", "input": "mixed", "levels": 4, "method": "bayer4"
+    }));
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+
+    let spec = make_texture_blend_spec(serde_json::json!({
+        "id": "out", "type": "dither", "input": "gray", "levels": 1, "method": "blue_noise"
+    }));
+    let result = validate_for_generate(&spec);
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.nodes[0].levels")));
+}
+
+#[test]
+fn test_texture_procedural_bevel_mode_sets_output_type_and_checks_params() {
+    // Normal-mode bevel produces color, so to_grayscale accepts it
+    let mut spec = make_texture_blend_spec(
+        serde_json::json!({ "id": "out", "type": "to_grayscale", "input": "bev" }),
+    );
+    let nodes = spec.recipe.as_mut().unwrap().params["nodes"]
+        .as_array_mut()
+        .unwrap();
+    nodes.push(serde_json::json!({
+        "id": "bev", "type": "bevel", "input": "gray", "distance": 2.0, "mode": "normal"
+    }));
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+
+    let spec = make_texture_blend_spec(serde_json::json!({
+        "id": "out", "type": "bevel", "input": "tint", "distance": 0.0, "softness": 2.0
+    }));
+    let result = validate_for_generate(&spec);
+    for field in ["input", "distance", "softness"] {
+        let path = format!("recipe.params.nodes[0].{}", field);
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path.as_deref() == Some(path.as_str())),
+            "missing error at {}: {:?}",
+            path,
+            result.errors
+        );
+    }
+}
+
+fn make_texture_composite_spec(layers: serde_json::Value) -> crate::spec::Spec {
+    crate::spec::Spec::builder("composite-test-01", AssetType::Texture)
+        .license("CC0-1.0")
+        .seed(7)
+        .output(OutputSpec::primary(
+            OutputFormat::Png,
+            "textures/composite.png",
+        ))
+        .recipe(Recipe::new(
+            "texture.composite_v1",
+            serde_json::json!({
+                "resolution": [16, 16],
+                "tileable": true,
+                "layers": layers
+            }),
+        ))
+        .build()
+}
+
+#[test]
+fn test_texture_composite_valid_spec() {
+    let spec = make_texture_composite_spec(serde_json::json!([
+        {
+            "nodes": [{ "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } }],
+            "output": "n"
+        },
+        {
+            "nodes": [{ "id": "c", "type": "constant", "value": 0.5 }],
+            "output": "c",
+            "blend_mode": "overlay",
+            "opacity": 0.5
+        }
+    ]));
+
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+}
+
+#[test]
+fn test_texture_composite_rejects_unknown_layer_output() {
+    let spec = make_texture_composite_spec(serde_json::json!([
+        {
+            "nodes": [{ "id": "c", "type": "constant", "value": 0.5 }],
+            "output": "missing"
+        }
+    ]));
+
+    let result = validate_for_generate(&spec);
+    assert!(!result.is_ok());
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.layers[0].output")));
+}
+
+#[test]
+fn test_texture_composite_reports_layer_graph_errors_with_layer_path() {
+    let spec = make_texture_composite_spec(serde_json::json!([
+        {
+            "nodes": [{ "id": "c", "type": "constant", "value": 0.5 }],
+            "output": "c"
+        },
+        {
+            "nodes": [{ "id": "inv", "type": "invert", "input": "nope" }],
+            "output": "inv",
+            "opacity": 1.5
+        }
+    ]));
+
+    let result = validate_for_generate(&spec);
+    assert!(!result.is_ok());
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.layers[1].nodes[0].input")));
+    assert!(result
+        .errors
+        .iter()
+        .any(|e| e.path.as_deref() == Some("recipe.params.layers[1].opacity")));
+}
+
+fn make_texture_tile_variation_spec(params: serde_json::Value) -> crate::spec::Spec {
+    crate::spec::Spec::builder("tile-variation-test-01", AssetType::Texture)
+        .license("CC0-1.0")
+        .seed(7)
+        .output(OutputSpec::primary(OutputFormat::Png, "textures/sheet.png"))
+        .recipe(Recipe::new("texture.tile_variation_v1", params))
+        .build()
+}
+
+#[test]
+fn test_texture_tile_variation_valid_spec() {
+    let spec = make_texture_tile_variation_spec(serde_json::json!({
+        "tile_resolution": [32, 32],
+        "tileable": true,
+        "nodes": [{ "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } }],
+        "output": "n",
+        "tiles": [4, 4],
+        "variation": { "rotate": true, "flip": true, "hue_shift": 30.0 }
+    }));
+
+    let result = validate_for_generate(&spec);
+    assert!(result.is_ok(), "errors: {:?}", result.errors);
+}
+
+#[test]
+fn test_texture_tile_variation_rejects_bad_params() {
+    let spec = make_texture_tile_variation_spec(serde_json::json!({
+        "tile_resolution": [1024, 1024],
+        "tileable": true,
+        "nodes": [{ "id": "c", "type": "constant", "value": 0.5 }],
+        "output": "missing",
+        "tiles": [8, 1],
+        "variation": { "hue_shift": 270.0 }
+    }));
+
+    let result = validate_for_generate(&spec);
+    assert!(!result.is_ok());
+    for path in [
+        "recipe.params.output",
+        "recipe.params.tiles",
+        "recipe.params.variation.hue_shift",
+    ] {
+        assert!(
+            result
+                .errors
+                .iter()
+                .any(|e| e.path.as_deref() == Some(path)),
+            "missing error at {}: {:?}",
+            path,
+            result.errors
+        );
+    }
+}
+
+#[test]
+fn test_texture_procedural_near_budget_limit_warns() {
+    let has_near_limit_warning = |result: &ValidationResult| {
+        result
+            .warnings
+            .iter()
+            .any(|w| w.code == crate::error::WarningCode::NearBudgetLimit)
+    };
+
+    let far_under = validate_for_generate_with_budget(
+        &make_valid_texture_procedural_spec(),
+        &BudgetProfile::default(),
+    );
+    assert!(far_under.is_ok(), "errors: {:?}", far_under.errors);
+    assert!(
+        !has_near_limit_warning(&far_under),
+        "warnings: {:?}",
+        far_under.warnings
+    );
+
+    // 240 of the default 256 graph nodes.
+    let mut spec = make_valid_texture_procedural_spec();
+    let mut nodes = vec![
+        serde_json::json!({ "id": "n", "type": "noise", "noise": { "algorithm": "perlin", "scale": 0.1 } }),
+        serde_json::json!({ "id": "mask", "type": "threshold", "input": "n", "threshold": 0.5 }),
+    ];
+    nodes.extend(
+        (0..238).map(
+            |i| serde_json::json!({ "id": format!("c{}", i), "type": "constant", "value": 0.5 }),
+        ),
+    );
+    spec.recipe.as_mut().unwrap().params["nodes"] = serde_json::Value::Array(nodes);
+
+    let near_limit = validate_for_generate_with_budget(&spec, &BudgetProfile::default());
+    assert!(near_limit.is_ok(), "errors: {:?}", near_limit.errors);
+    let warning = near_limit
+        .warnings
+        .iter()
+        .find(|w| w.code == crate::error::WarningCode::NearBudgetLimit)
+        .expect("expected a near-budget-limit warning");
+    assert!(
+        warning.message.starts_with("texture budget"),
+        "{}",
+        warning.message
+    );
+    assert_eq!(warning.path.as_deref(), Some("recipe.params.nodes"));
+}
@@ -0,0 +1,208 @@
+//! Batch determinism checks over a directory of spec files.
+//!
+//! Every `.json` / `.star` spec under a directory is discovered, generated
+//! through the normal CLI dispatch path several times, and compared byte for
+//! byte. Dropping a new spec into the directory is enough to cover it.
+
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::determinism::core::verify_determinism;
+use crate::determinism::report::{DeterminismError, DeterminismReport, DeterminismReportEntry};
+use crate::harness::should_run_blender_tests;
+
+/// Directory of committed specs covered by the batch determinism test.
+pub fn fixture_specs_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join("specs")
+}
+
+/// Recursively list spec files (`.json` / `.star`) under `dir`, sorted by path.
+///
+/// Generation reports (`*.report.json`) are ignored.
+pub fn discover_specs(dir: &Path) -> Vec<PathBuf> {
+    let mut specs: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .filter(|p| {
+            p.extension()
+                .map(|e| e == "json" || e == "star")
+                .unwrap_or(false)
+        })
+        .filter(|p| {
+            !p.file_name()
+                .map(|name| name.to_string_lossy().contains(".report."))
+                .unwrap_or(false)
+        })
+        .collect();
+
+    specs.sort();
+    specs
+}
+
+/// Outcome of a batch run.
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    /// Determinism results for every spec that was generated.
+    pub report: DeterminismReport,
+    /// Specs that were skipped (Blender-backed kinds when Blender tests are off).
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Determinism check over every spec in a directory.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use speccade_tests::determinism::{fixture_specs_dir, BatchDeterminism};
+///
+/// let batch = BatchDeterminism::new(fixture_specs_dir()).run();
+/// batch.report.assert_all_deterministic();
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchDeterminism {
+    /// Directory searched for specs.
+    pub specs_dir: PathBuf,
+    /// Number of generations per spec.
+    pub runs: usize,
+    /// Whether Blender-backed (Tier 2) specs are generated instead of skipped.
+    pub include_blender: bool,
+}
+
+impl BatchDeterminism {
+    /// Create a batch over `specs_dir`.
+    ///
+    /// Blender-backed specs are included only when `SPECCADE_RUN_BLENDER_TESTS` is set.
+    pub fn new<P: AsRef<Path>>(specs_dir: P) -> Self {
+        Self {
+            specs_dir: specs_dir.as_ref().to_path_buf(),
+            runs: 2,
+            include_blender: should_run_blender_tests(),
+        }
+    }
+
+    /// Set the number of generations per spec.
+    pub fn runs(mut self, runs: usize) -> Self {
+        assert!(runs >= 2, "Must run at least 2 times");
+        self.runs = runs;
+        self
+    }
+
+    /// Set whether Blender-backed specs are generated.
+    pub fn include_blender(mut self, include: bool) -> Self {
+        self.include_blender = include;
+        self
+    }
+
+    /// Generate every discovered spec through the CLI dispatcher and compare runs.
+    pub fn run(&self) -> BatchReport {
+        let include_blender = self.include_blender;
+        self.run_with(|path| generate_spec_outputs(path, include_blender))
+    }
+
+    /// Like [`run`](Self::run), with a custom generator.
+    ///
+    /// The generator returns the bytes to compare for a spec, or `None` to skip it.
+    pub fn run_with<F>(&self, generate: F) -> BatchReport
+    where
+        F: Fn(&Path) -> Result<Option<Vec<u8>>, DeterminismError>,
+    {
+        let mut batch = BatchReport::default();
+
+        for spec_path in discover_specs(&self.specs_dir) {
+            let first = match generate(&spec_path) {
+                Ok(Some(bytes)) => bytes,
+                Ok(None) => {
+                    batch.skipped.push(spec_path);
+                    continue;
+                }
+                Err(e) => {
+                    batch.report.add_entry(DeterminismReportEntry {
+                        spec_path,
+                        result: Err(e),
+                    });
+                    continue;
+                }
+            };
+
+            // The first generation is the reference; later errors show up as empty output
+            let reference = RefCell::new(Some(first));
+            let error = RefCell::new(None);
+            let result = verify_determinism(
+                || {
+                    if let Some(bytes) = reference.borrow_mut().take() {
+                        return bytes;
+                    }
+                    match generate(&spec_path) {
+                        Ok(bytes) => bytes.unwrap_or_default(),
+                        Err(e) => {
+                            error.borrow_mut().get_or_insert(e);
+                            Vec::new()
+                        }
+                    }
+                },
+                self.runs,
+            );
+
+            let result = match error.into_inner() {
+                Some(e) => Err(e),
+                None => Ok(result),
+            };
+            batch
+                .report
+                .add_entry(DeterminismReportEntry { spec_path, result });
+        }
+
+        batch
+    }
+}
+
+/// Generate a spec into a temporary directory and return all its outputs.
+///
+/// Outputs are concatenated in path order, each prefixed by its relative path,
+/// so a renamed or missing output also counts as a difference. Returns `None`
+/// for Blender-backed (Tier 2) kinds unless `include_blender` is set.
+pub fn generate_spec_outputs(
+    spec_path: &Path,
+    include_blender: bool,
+) -> Result<Option<Vec<u8>>, DeterminismError> {
+    if !spec_path.exists() {
+        return Err(DeterminismError::SpecNotFound);
+    }
+
+    let spec = speccade_cli::input::load_spec(spec_path)
+        .map_err(|e| DeterminismError::ParseError(e.to_string()))?
+        .spec;
+
+    let tier1 = spec
+        .recipe
+        .as_ref()
+        .and_then(|recipe| recipe.parse_kind())
+        .map(|kind| kind.is_tier1())
+        .unwrap_or(false);
+    if !tier1 && !include_blender {
+        return Ok(None);
+    }
+
+    let out_dir = tempfile::tempdir().map_err(|e| DeterminismError::IoError(e.to_string()))?;
+    let out_root = out_dir.path().to_string_lossy().to_string();
+    let mut outputs = speccade_cli::dispatch::dispatch_generate(&spec, &out_root, spec_path, None)
+        .map_err(|e| DeterminismError::GenerationFailed(e.to_string()))?;
+    outputs.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut bytes = Vec::new();
+    for output in &outputs {
+        let data = std::fs::read(out_dir.path().join(&output.path))
+            .map_err(|e| DeterminismError::IoError(format!("{}: {}", output.path.display(), e)))?;
+        bytes.extend_from_slice(output.path.to_string_lossy().as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&data);
+    }
+
+    Ok(Some(bytes))
+}
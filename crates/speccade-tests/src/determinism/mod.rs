@@ -12,6 +12,7 @@
 //! - Multiple runs of the same generation function
 //! - Different asset types (audio, texture, music, mesh)
 //! - Multiple spec files in batch
+//! - Every spec in a fixtures directory, discovered automatically
 //!
 //! # Example
 //!
@@ -31,6 +32,7 @@
 //! assert!(report.all_deterministic());
 //! ```
 
+pub mod batch;
 pub mod builder;
 pub mod core;
 pub mod fixture;
@@ -42,6 +44,9 @@ pub mod report;
 mod tests;
 
 // Re-export core types and functions for convenience
+pub use batch::{
+    discover_specs, fixture_specs_dir, generate_spec_outputs, BatchDeterminism, BatchReport,
+};
 pub use builder::DeterminismBuilder;
 pub use core::{
     assert_deterministic, compute_hash, verify_determinism, verify_hash_determinism,
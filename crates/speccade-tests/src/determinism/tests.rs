@@ -154,6 +154,79 @@ mod tests {
         assert!(!report.all_deterministic());
     }
 
+    fn batch_dir(names: &[&str]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for name in names {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_discover_specs_recurses_and_sorts() {
+        let dir = batch_dir(&[
+            "b.star",
+            "nested/a.json",
+            "a.star",
+            "a.report.json",
+            "notes.txt",
+        ]);
+
+        let specs = crate::determinism::batch::discover_specs(dir.path());
+        let names: Vec<_> = specs
+            .iter()
+            .map(|p| p.strip_prefix(dir.path()).unwrap().to_path_buf())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                PathBuf::from("a.star"),
+                PathBuf::from("b.star"),
+                PathBuf::from("nested").join("a.json"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batch_reports_non_deterministic_spec_path() {
+        use crate::determinism::batch::BatchDeterminism;
+
+        let dir = batch_dir(&["stable.star", "flaky.star", "mesh.star"]);
+        let counter = AtomicU32::new(0);
+
+        let batch = BatchDeterminism::new(dir.path()).runs(3).run_with(|path| {
+            match path.file_name().unwrap().to_str().unwrap() {
+                "flaky.star" => Ok(Some(vec![counter.fetch_add(1, Ordering::SeqCst) as u8])),
+                "mesh.star" => Ok(None),
+                _ => Ok(Some(vec![7u8; 16])),
+            }
+        });
+
+        assert_eq!(batch.skipped, vec![dir.path().join("mesh.star")]);
+        assert_eq!(batch.report.total_count(), 2);
+        let failures = batch.report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].spec_path, dir.path().join("flaky.star"));
+        assert!(batch.report.to_string().contains("flaky.star"));
+    }
+
+    #[test]
+    fn test_batch_records_generation_errors() {
+        use crate::determinism::batch::BatchDeterminism;
+
+        let dir = batch_dir(&["broken.json"]);
+        let batch = BatchDeterminism::new(dir.path())
+            .run_with(|_| Err(DeterminismError::GenerationFailed("boom".to_string())));
+
+        assert!(!batch.report.all_deterministic());
+        assert!(matches!(
+            batch.report.entries[0].result,
+            Err(DeterminismError::GenerationFailed(_))
+        ));
+    }
+
     // Test macro usage
     use crate::test_determinism;
 
@@ -0,0 +1,220 @@
+//! Golden-hash manifest for catching unintended output drift.
+//!
+//! Determinism tests prove that a backend gives the same bytes twice on the
+//! same machine, but not that it gives the *same bytes as last week*. The
+//! manifest closes that gap: it maps a fixture id to the BLAKE3 hash of the
+//! fixture's generated output, is checked into the repository, and is compared
+//! against on every test run. Hashes are platform independent, so the same
+//! manifest is used on every OS in CI.
+//!
+//! The manifest lives at `crates/speccade-tests/fixtures/golden_hashes.json`
+//! and is a flat JSON object with keys in sorted order:
+//!
+//! ```json
+//! {
+//!   "audio_noise_hit": "4f1c...",
+//!   "texture_noise_mask": "9a02..."
+//! }
+//! ```
+//!
+//! ## Regenerating
+//!
+//! When an output change is intended (an algorithm fix, a new default), rerun
+//! the golden tests with the update toggle set. [`assert_golden`] then records
+//! the actual hash instead of comparing it:
+//!
+//! ```bash
+//! SPECCADE_UPDATE_GOLDEN_MANIFEST=1 cargo test -p speccade-tests --test golden_manifest
+//! ```
+//!
+//! Review the manifest diff and commit it together with the change that
+//! caused it. A fixture without an entry fails until the manifest is
+//! regenerated, so new fixtures cannot slip in unrecorded.
+//!
+//! This is separate from the per-spec `.hash` files under
+//! `golden/speccade/expected/hashes`, which `tests/golden_hash_verification.rs`
+//! checks and regenerates with `SPECCADE_UPDATE_GOLDEN_HASHES=1`. The two
+//! toggles are independent, so updating one set of hashes never silently
+//! rewrites the other.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Environment variable that switches [`assert_golden`] to update mode.
+pub const UPDATE_ENV_VAR: &str = "SPECCADE_UPDATE_GOLDEN_MANIFEST";
+
+/// Serializes manifest rewrites between tests running on parallel threads.
+static MANIFEST_LOCK: Mutex<()> = Mutex::new(());
+
+/// Path of the checked-in golden-hash manifest.
+pub fn manifest_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join("golden_hashes.json")
+}
+
+/// Whether golden hashes should be rewritten instead of compared.
+pub fn should_update_golden() -> bool {
+    std::env::var(UPDATE_ENV_VAR)
+        .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Fixture id to expected hash mapping.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoldenManifest {
+    /// Expected hashes, keyed by fixture id.
+    pub entries: BTreeMap<String, String>,
+}
+
+impl GoldenManifest {
+    /// Loads a manifest from disk. A missing file is an empty manifest.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let entries = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+        Ok(Self { entries })
+    }
+
+    /// Writes the manifest as pretty JSON with sorted keys and a trailing newline.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let json = serde_json::to_string_pretty(&self.entries)
+            .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
+        fs::write(path, format!("{}\n", json))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Returns the expected hash for a fixture, if recorded.
+    pub fn get(&self, fixture_id: &str) -> Option<&str> {
+        self.entries.get(fixture_id).map(String::as_str)
+    }
+
+    /// Records the expected hash for a fixture.
+    pub fn insert(&mut self, fixture_id: impl Into<String>, hash: impl Into<String>) {
+        self.entries.insert(fixture_id.into(), hash.into());
+    }
+
+    /// Compares an actual hash against the recorded one.
+    pub fn check(&self, fixture_id: &str, actual_hash: &str) -> Result<(), String> {
+        match self.get(fixture_id) {
+            Some(expected) if expected == actual_hash => Ok(()),
+            Some(expected) => Err(format!(
+                "Golden hash mismatch for '{}':\n  expected: {}\n  actual:   {}\n\
+                 If this change is intended, rerun with {}=1 and commit the manifest.",
+                fixture_id, expected, actual_hash, UPDATE_ENV_VAR
+            )),
+            None => Err(format!(
+                "No golden hash recorded for '{}' (actual: {}).\n\
+                 Rerun with {}=1 to record it.",
+                fixture_id, actual_hash, UPDATE_ENV_VAR
+            )),
+        }
+    }
+}
+
+/// Asserts that `actual_hash` matches the manifest entry for `fixture_id`.
+///
+/// With [`UPDATE_ENV_VAR`] set, the entry is written to the manifest instead.
+pub fn assert_golden(fixture_id: &str, actual_hash: &str) {
+    assert_golden_in(
+        &manifest_path(),
+        fixture_id,
+        actual_hash,
+        should_update_golden(),
+    );
+}
+
+/// Like [`assert_golden`], against an explicit manifest path and update mode.
+pub fn assert_golden_in(path: &Path, fixture_id: &str, actual_hash: &str, update: bool) {
+    let _guard = MANIFEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut manifest = GoldenManifest::load(path).unwrap_or_else(|e| panic!("{}", e));
+
+    if update {
+        if manifest.get(fixture_id) != Some(actual_hash) {
+            manifest.insert(fixture_id, actual_hash);
+            manifest.save(path).unwrap_or_else(|e| panic!("{}", e));
+            println!("Updated golden hash for {}: {}", fixture_id, actual_hash);
+        }
+        return;
+    }
+
+    if let Err(msg) = manifest.check(fixture_id, actual_hash) {
+        panic!("{}", msg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_roundtrip_is_sorted() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("hashes.json");
+
+        let mut manifest = GoldenManifest::default();
+        manifest.insert("zeta", "22");
+        manifest.insert("alpha", "11");
+        manifest.save(&path).unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.find("alpha").unwrap() < content.find("zeta").unwrap());
+        assert!(content.ends_with("}\n"));
+        assert_eq!(GoldenManifest::load(&path).unwrap(), manifest);
+    }
+
+    #[test]
+    fn missing_manifest_is_empty() {
+        let tmp = tempfile::tempdir().unwrap();
+        let manifest = GoldenManifest::load(&tmp.path().join("none.json")).unwrap();
+        assert!(manifest.entries.is_empty());
+    }
+
+    #[test]
+    fn check_reports_mismatch_and_missing() {
+        let mut manifest = GoldenManifest::default();
+        manifest.insert("a", "abc");
+
+        assert!(manifest.check("a", "abc").is_ok());
+        let mismatch = manifest.check("a", "def").unwrap_err();
+        assert!(mismatch.contains("expected: abc"));
+        assert!(mismatch.contains(UPDATE_ENV_VAR));
+        assert!(manifest
+            .check("b", "abc")
+            .unwrap_err()
+            .contains("No golden hash"));
+    }
+
+    #[test]
+    fn update_mode_records_hash() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("hashes.json");
+
+        assert_golden_in(&path, "fixture", "1234", true);
+        assert_eq!(
+            GoldenManifest::load(&path).unwrap().get("fixture"),
+            Some("1234")
+        );
+        assert_golden_in(&path, "fixture", "1234", false);
+    }
+
+    #[test]
+    #[should_panic(expected = "Golden hash mismatch")]
+    fn assert_golden_panics_on_drift() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("hashes.json");
+
+        assert_golden_in(&path, "fixture", "1234", true);
+        assert_golden_in(&path, "fixture", "5678", false);
+    }
+}
@@ -292,6 +292,15 @@ pub fn validate_png_file_info(path: &Path) -> Result<PngInfo, String> {
     format_validators::validate_png(&data).map_err(|e| e.to_string())
 }
 
+/// Validate an OpenEXR file starts with the EXR magic number.
+pub fn validate_exr_file(path: &Path) -> Result<(), String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    if data.len() < 8 || data[..4] != [0x76, 0x2f, 0x31, 0x01] {
+        return Err("Invalid EXR magic number".to_string());
+    }
+    Ok(())
+}
+
 /// Validate an XM tracker module file is properly formed.
 pub fn validate_xm_file(path: &Path) -> Result<(), String> {
     let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
@@ -320,6 +329,55 @@ pub fn validate_it_file_info(path: &Path) -> Result<ItInfo, String> {
     format_validators::validate_it(&data).map_err(|e| e.to_string())
 }
 
+/// Validate a ProTracker MOD file is properly formed.
+pub fn validate_mod_file(path: &Path) -> Result<(), String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    speccade_backend_music::protracker::validate_mod_bytes(&data).map_err(|e| e.to_string())
+}
+
+/// Validate an OGG file starts with a Vorbis identification page.
+pub fn validate_ogg_file(path: &Path) -> Result<(), String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    if data.len() < 27 || &data[0..4] != b"OggS" {
+        return Err("Missing Ogg page header".to_string());
+    }
+    if data[5] & 0x02 == 0 {
+        return Err("First Ogg page is not a beginning of stream".to_string());
+    }
+    let packet_start = 27 + data[26] as usize;
+    if data.get(packet_start..packet_start + 7) != Some(b"\x01vorbis".as_slice()) {
+        return Err("Missing Vorbis identification header".to_string());
+    }
+    Ok(())
+}
+
+/// Validate an S3M tracker module file is properly formed.
+pub fn validate_s3m_file(path: &Path) -> Result<(), String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    speccade_backend_music::s3m::validate_s3m_bytes(&data).map_err(|e| e.to_string())
+}
+
+/// Validate a Standard MIDI File has a header chunk followed by track chunks.
+pub fn validate_mid_file(path: &Path) -> Result<(), String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    if data.len() < 14 || &data[0..4] != b"MThd" {
+        return Err("Missing MIDI header chunk".to_string());
+    }
+    let track_count = u16::from_be_bytes([data[10], data[11]]);
+    let mut pos = 14;
+    for track in 0..track_count {
+        if data.len() < pos + 8 || &data[pos..pos + 4] != b"MTrk" {
+            return Err(format!("Missing MIDI track chunk {}", track));
+        }
+        let len = u32::from_be_bytes([data[pos + 4], data[pos + 5], data[pos + 6], data[pos + 7]]);
+        pos += 8 + len as usize;
+    }
+    if pos != data.len() {
+        return Err("MIDI track chunks do not span the file".to_string());
+    }
+    Ok(())
+}
+
 /// Validate a GLB file is properly formed.
 pub fn validate_glb_file(path: &Path) -> Result<(), String> {
     let data = fs::read(path).map_err(|e| format!("Failed to read file: {}", e))?;
@@ -356,9 +414,14 @@ pub fn validate_output_format(path: &Path, format: OutputFormat) -> Result<(), S
 
     match format {
         OutputFormat::Wav => validate_wav_file(path),
+        OutputFormat::Ogg => validate_ogg_file(path),
         OutputFormat::Png => validate_png_file(path),
+        OutputFormat::Exr => validate_exr_file(path),
         OutputFormat::Xm => validate_xm_file(path),
         OutputFormat::It => validate_it_file(path),
+        OutputFormat::Mod => validate_mod_file(path),
+        OutputFormat::S3m => validate_s3m_file(path),
+        OutputFormat::Mid => validate_mid_file(path),
         OutputFormat::Glb => validate_glb_file(path),
         OutputFormat::Gltf => validate_gltf_file(path),
         OutputFormat::Json => Ok(()), // JSON is text, no binary validation needed
@@ -393,4 +456,19 @@ mod tests {
         // Just ensure the function doesn't panic
         let _ = should_run_blender_tests();
     }
+
+    #[test]
+    fn test_validate_ogg_file() {
+        let harness = TestHarness::new();
+        let samples = vec![0.0; 4410];
+        let ogg = speccade_backend_audio::encode_ogg(&samples, 1, 44100).unwrap();
+
+        let ogg_path = harness.path().join("silence.ogg");
+        fs::write(&ogg_path, &ogg.data).unwrap();
+        assert!(validate_output_format(&ogg_path, OutputFormat::Ogg).is_ok());
+
+        let bad_path = harness.path().join("bad.ogg");
+        fs::write(&bad_path, b"RIFF0000WAVE").unwrap();
+        assert!(validate_ogg_file(&bad_path).is_err());
+    }
 }
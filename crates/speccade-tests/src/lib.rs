@@ -34,11 +34,22 @@
 //!     generate_audio(&spec).wav_data
 //! });
 //! ```
+//!
+//! ## Golden Hashes
+//!
+//! The `golden` module compares generated output hashes against a manifest
+//! checked into the repository, so unintended output drift fails CI:
+//!
+//! ```bash
+//! # Regenerate the manifest after an intended output change
+//! SPECCADE_UPDATE_GOLDEN_MANIFEST=1 cargo test -p speccade-tests --test golden_manifest
+//! ```
 
 pub mod audio_analysis;
 pub mod determinism;
 pub mod fixtures;
 pub mod format_validators;
+pub mod golden;
 pub mod harness;
 
 // Re-export commonly used items
@@ -46,3 +57,4 @@ pub use determinism::{
     assert_deterministic, compute_hash, verify_determinism, verify_hash_determinism,
     DeterminismError, DeterminismFixture, DeterminismReport, DeterminismResult,
 };
+pub use golden::{assert_golden, GoldenManifest};
@@ -0,0 +1,37 @@
+//! Batch determinism test over the committed fixture specs.
+//!
+//! Every `.json` / `.star` spec under `crates/speccade-tests/fixtures/specs`
+//! is generated twice through the CLI dispatcher and must come out
+//! byte-identical. Adding a spec to that directory is enough to cover it.
+//! Blender-backed specs are skipped unless `SPECCADE_RUN_BLENDER_TESTS=1`.
+//!
+//! ## Running Tests
+//!
+//! ```bash
+//! cargo test -p speccade-tests --test batch_determinism
+//! ```
+
+use speccade_tests::determinism::{discover_specs, fixture_specs_dir, BatchDeterminism};
+
+#[test]
+fn fixture_specs_are_discovered() {
+    let specs = discover_specs(&fixture_specs_dir());
+    assert!(
+        specs.len() >= 2,
+        "expected committed fixture specs in {:?}, found {:?}",
+        fixture_specs_dir(),
+        specs
+    );
+}
+
+#[test]
+fn all_fixture_specs_are_deterministic() {
+    let batch = BatchDeterminism::new(fixture_specs_dir()).run();
+
+    println!("{}", batch.report);
+    for path in &batch.skipped {
+        println!("  [SKIP] {:?} (Blender tests disabled)", path);
+    }
+
+    batch.report.assert_all_deterministic();
+}
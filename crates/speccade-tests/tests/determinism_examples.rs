@@ -19,7 +19,7 @@ mod audio {
     use super::*;
     use speccade_backend_audio::generate_from_params;
     use speccade_spec::recipe::audio::{
-        AudioLayer, AudioV1Params, Envelope, NoiseType, Synthesis, Waveform,
+        AudioLayer, AudioV1Params, ChannelMode, Envelope, NoiseType, Synthesis, Waveform,
     };
 
     fn create_sine_params() -> AudioV1Params {
@@ -33,6 +33,11 @@ mod audio {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
             layers: vec![AudioLayer {
                 synthesis: Synthesis::Oscillator {
                     waveform: Waveform::Sine,
@@ -40,12 +45,22 @@ mod audio {
                     freq_sweep: None,
                     detune: None,
                     duty: None,
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
         }
@@ -62,16 +77,28 @@ mod audio {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
             layers: vec![AudioLayer {
                 synthesis: Synthesis::NoiseBurst {
                     noise_type: NoiseType::White,
                     filter: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.8,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
         }
@@ -88,6 +115,11 @@ mod audio {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
             layers: vec![AudioLayer {
                 synthesis: Synthesis::FmSynth {
                     carrier_freq: 440.0,
@@ -100,11 +132,19 @@ mod audio {
                     decay: 0.05,
                     sustain: 0.5,
                     release: 0.1,
-                },
+                }
+                .into(),
                 volume: 0.7,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
         }
@@ -178,6 +218,11 @@ mod audio {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
             layers: vec![AudioLayer {
                 synthesis: Synthesis::Oscillator {
                     waveform: Waveform::Square,
@@ -185,12 +230,22 @@ mod audio {
                     freq_sweep: None,
                     detune: None,
                     duty: Some(0.5),
+                    anti_alias: None,
+                    detune_voices: None,
+                    detune_spread: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.5,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
         };
@@ -210,16 +265,28 @@ mod audio {
             generate_loop_points: false,
             effects: vec![],
             post_fx_lfos: vec![],
+            tail_fade: None,
+            master_compressor: None,
+            master_limiter: None,
+            dc_blocker: false,
+            channels: ChannelMode::Auto,
             layers: vec![AudioLayer {
                 synthesis: Synthesis::NoiseBurst {
                     noise_type: NoiseType::Pink,
                     filter: None,
                 },
-                envelope: Envelope::default(),
+                envelope: Envelope::default().into(),
                 volume: 0.5,
                 pan: 0.0,
                 delay: None,
                 filter: None,
+                muted: false,
+                solo: false,
+                gain_automation: None,
+                modulation_matrix: vec![],
+                arpeggio: None,
+                render_rate: None,
+                sidechain: None,
                 lfo: None,
             }],
         };
@@ -262,6 +329,8 @@ mod texture {
             layers: vec![],
             palette: None,
             color_ramp: None,
+            height_bit_depth: 8,
+            normal: None,
         }
     }
 
@@ -315,6 +384,8 @@ mod texture {
             layers: vec![],
             palette: None,
             color_ramp: None,
+            height_bit_depth: 8,
+            normal: None,
         };
         let tex_result = generate_material_maps(&params, 123).unwrap();
         tex_result
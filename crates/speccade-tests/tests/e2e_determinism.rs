@@ -9,7 +9,9 @@
 //! cargo test -p speccade-tests --test e2e_determinism
 //! ```
 
-use speccade_spec::recipe::audio::{AudioLayer, AudioV1Params, Envelope, Synthesis, Waveform};
+use speccade_spec::recipe::audio::{
+    AudioLayer, AudioV1Params, ChannelMode, Envelope, Synthesis, Waveform,
+};
 use speccade_spec::recipe::texture::{
     NoiseAlgorithm, NoiseConfig, TextureProceduralNode, TextureProceduralOp,
     TextureProceduralV1Params,
@@ -80,6 +82,13 @@ fn test_texture_determinism() {
                         octaves: 3,
                         persistence: 0.5,
                         lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
                     },
                 },
             },
@@ -120,6 +129,13 @@ fn test_different_seeds_different_output() {
                         octaves: 3,
                         persistence: 0.5,
                         lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
                     },
                 },
             },
@@ -162,17 +178,28 @@ fn test_audio_params_determinism() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
             envelope: Envelope {
                 attack: 0.01,
                 decay: 0.05,
                 sustain: 0.6,
                 release: 0.1,
-            },
+            }
+            .into(),
             volume: 0.7,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -181,6 +208,11 @@ fn test_audio_params_determinism() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result1 = speccade_backend_audio::generate_from_params(&params, 999).unwrap();
@@ -15,7 +15,9 @@
 
 use std::fs;
 
-use speccade_spec::recipe::audio::{AudioLayer, AudioV1Params, Envelope, Synthesis, Waveform};
+use speccade_spec::recipe::audio::{
+    AudioLayer, AudioV1Params, ChannelMode, Envelope, Synthesis, Waveform,
+};
 use speccade_spec::recipe::music::{
     ArrangementEntry, InstrumentSynthesis, MusicTrackerSongV1Params, PatternNote, TrackerFormat,
     TrackerInstrument, TrackerPattern,
@@ -25,6 +27,7 @@ use speccade_spec::recipe::texture::{
     TextureProceduralV1Params,
 };
 use speccade_spec::{AssetType, OutputFormat, OutputKind, OutputSpec, Recipe, Spec};
+use speccade_tests::audio_analysis::{left_channel, parse_wav_header, parse_wav_samples};
 use speccade_tests::fixtures::GoldenFixtures;
 use speccade_tests::harness::{
     is_blender_available, parse_spec_file, should_run_blender_tests, validate_glb_file,
@@ -110,17 +113,28 @@ fn test_generate_audio_from_params() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
             envelope: Envelope {
                 attack: 0.01,
                 decay: 0.05,
                 sustain: 0.6,
                 release: 0.1,
-            },
+            }
+            .into(),
             volume: 0.7,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -129,6 +143,11 @@ fn test_generate_audio_from_params() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = speccade_backend_audio::generate_from_params(&params, 42);
@@ -156,17 +175,28 @@ fn test_generate_audio_with_loop_points() {
                 freq_sweep: None,
                 detune: None,
                 duty: None,
+                anti_alias: None,
+                detune_voices: None,
+                detune_spread: None,
             },
             envelope: Envelope {
                 attack: 0.01,
                 decay: 0.1,
                 sustain: 0.7,
                 release: 0.2,
-            },
+            }
+            .into(),
             volume: 1.0,
             pan: 0.0,
             delay: None,
             filter: None,
+            muted: false,
+            solo: false,
+            gain_automation: None,
+            modulation_matrix: vec![],
+            arpeggio: None,
+            render_rate: None,
+            sidechain: None,
             lfo: None,
         }],
         pitch_envelope: None,
@@ -175,6 +205,11 @@ fn test_generate_audio_with_loop_points() {
         master_filter: None,
         effects: vec![],
         post_fx_lfos: vec![],
+        tail_fade: None,
+        master_compressor: None,
+        master_limiter: None,
+        dc_blocker: false,
+        channels: ChannelMode::Auto,
     };
 
     let result = speccade_backend_audio::generate_from_params(&params, 42);
@@ -188,6 +223,98 @@ fn test_generate_audio_with_loop_points() {
     assert!(!gen_result.wav.wav_data.is_empty());
 }
 
+/// Renders a steady 441 Hz sine (100 samples per cycle) through `effects` and
+/// returns the left channel of the output.
+fn render_sine_through(effects: serde_json::Value) -> Vec<f32> {
+    let spec = Spec::builder("test-waveshaper-01", AssetType::Audio)
+        .license("CC0-1.0")
+        .seed(42)
+        .output(OutputSpec::primary(OutputFormat::Wav, "audio/sine.wav"))
+        .recipe(Recipe::new(
+            "audio_v1",
+            serde_json::json!({
+                "duration_seconds": 0.3,
+                "sample_rate": 44100,
+                "layers": [{
+                    "synthesis": {
+                        "type": "oscillator",
+                        "waveform": "sine",
+                        "frequency": 441.0
+                    },
+                    "envelope": {
+                        "attack": 0.005,
+                        "decay": 0.01,
+                        "sustain": 1.0,
+                        "release": 0.01
+                    },
+                    "volume": 0.8,
+                    "pan": 0.0
+                }],
+                "effects": effects
+            }),
+        ))
+        .build();
+
+    let wav = speccade_backend_audio::generate(&spec)
+        .expect("audio generation")
+        .wav
+        .wav_data;
+    let header = parse_wav_header(&wav).expect("wav header");
+    let samples = parse_wav_samples(&wav).expect("wav samples");
+    if header.channels == 2 {
+        left_channel(&samples)
+    } else {
+        samples
+    }
+}
+
+/// Amplitude of `frequency` in `samples`, from a single DFT bin.
+fn harmonic_amplitude(samples: &[f32], frequency: f64, sample_rate: f64) -> f64 {
+    let (re, im) = samples
+        .iter()
+        .enumerate()
+        .fold((0.0, 0.0), |(re, im), (i, &x)| {
+            let phase = std::f64::consts::TAU * frequency * i as f64 / sample_rate;
+            (re + x as f64 * phase.cos(), im - x as f64 * phase.sin())
+        });
+    2.0 * (re * re + im * im).sqrt() / samples.len() as f64
+}
+
+/// Test that hard clipping a sine adds odd harmonics but not even ones.
+#[test]
+fn test_generate_audio_waveshaper_hard_clip_adds_odd_harmonics() {
+    let fundamental = 441.0;
+    let sample_rate = 44100.0;
+    // Ratio of a harmonic to the fundamental over 44 whole cycles of the sustain
+    let ratio = |samples: &[f32], harmonic: f64| {
+        let window = &samples[4410..8820];
+        harmonic_amplitude(window, fundamental * harmonic, sample_rate)
+            / harmonic_amplitude(window, fundamental, sample_rate)
+    };
+
+    let clean = render_sine_through(serde_json::json!([]));
+    assert!(ratio(&clean, 3.0) < 0.01);
+
+    let clipped = render_sine_through(serde_json::json!([{
+        "type": "waveshaper",
+        "drive": 20.0,
+        "curve": "hard_clip",
+        "mix": 1.0
+    }]));
+    assert!(
+        ratio(&clipped, 3.0) > 0.1,
+        "3rd harmonic ratio {}",
+        ratio(&clipped, 3.0)
+    );
+    assert!(
+        ratio(&clipped, 5.0) > 0.05,
+        "5th harmonic ratio {}",
+        ratio(&clipped, 5.0)
+    );
+    // A symmetric curve adds no even harmonics
+    assert!(ratio(&clipped, 2.0) < 0.01);
+}
+
 /// Test procedural texture graph generation produces valid PNG output.
 #[test]
 fn test_generate_texture_procedural() {
@@ -206,6 +333,13 @@ fn test_generate_texture_procedural() {
                         octaves: 3,
                         persistence: 0.5,
                         lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
                     },
                 },
             },
@@ -261,6 +395,13 @@ fn test_generate_texture_normal_from_height() {
                         octaves: 2,
                         persistence: 0.5,
                         lacunarity: 2.0,
+                        distance_metric: Default::default(),
+                        jitter: 1.0,
+                        worley_mode: Default::default(),
+                        frequency: None,
+                        orientation: None,
+                        bandwidth: None,
+                        impulses_per_cell: None,
                     },
                 },
             },
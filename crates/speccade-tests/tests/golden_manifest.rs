@@ -0,0 +1,63 @@
+//! Golden-hash manifest tests for representative Tier-1 fixtures.
+//!
+//! Each test generates one committed fixture spec from
+//! `crates/speccade-tests/fixtures/specs` and compares the BLAKE3 hash of its
+//! outputs against `crates/speccade-tests/fixtures/golden_hashes.json`.
+//!
+//! ## Running Tests
+//!
+//! ```bash
+//! cargo test -p speccade-tests --test golden_manifest
+//!
+//! # Record new hashes after an intended output change
+//! SPECCADE_UPDATE_GOLDEN_MANIFEST=1 cargo test -p speccade-tests --test golden_manifest
+//! ```
+
+use speccade_tests::determinism::{compute_hash, fixture_specs_dir, generate_spec_outputs};
+use speccade_tests::golden::{assert_golden, manifest_path, GoldenManifest};
+
+/// Generate a fixture spec and return the hash of its outputs.
+fn fixture_hash(file_name: &str) -> String {
+    let path = fixture_specs_dir().join(file_name);
+    let bytes = generate_spec_outputs(&path, false)
+        .unwrap_or_else(|e| panic!("Failed to generate {}: {}", file_name, e))
+        .unwrap_or_else(|| panic!("{} is not a Tier-1 fixture", file_name));
+    compute_hash(&bytes)
+}
+
+#[test]
+fn golden_manifest_is_valid() {
+    let manifest = GoldenManifest::load(&manifest_path()).unwrap();
+    for (fixture_id, hash) in &manifest.entries {
+        assert_eq!(
+            hash.len(),
+            64,
+            "hash for '{}' is not BLAKE3 hex",
+            fixture_id
+        );
+        assert!(
+            hash.chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)),
+            "hash for '{}' is not lowercase hex",
+            fixture_id
+        );
+    }
+}
+
+#[test]
+fn golden_audio_noise_hit() {
+    assert_golden("audio_noise_hit", &fixture_hash("audio_noise_hit.star"));
+}
+
+#[test]
+fn golden_texture_noise_mask() {
+    assert_golden(
+        "texture_noise_mask",
+        &fixture_hash("texture_noise_mask.star"),
+    );
+}
+
+#[test]
+fn golden_music_simple_loop() {
+    assert_golden("music_simple_loop", &fixture_hash("music_simple_loop.star"));
+}
@@ -102,6 +102,7 @@ export const STARLARK_BUILTINS = [
   "vosim",
   "spectral_freeze",
   "pitched_body",
+  "transient",
 
   // Audio Filters
   "lowpass",
@@ -117,6 +118,7 @@ export const STARLARK_BUILTINS = [
 
   // Audio Effects
   "reverb",
+  "convolution_reverb",
   "delay",
   "compressor",
   "limiter",
@@ -135,6 +137,8 @@ export const STARLARK_BUILTINS = [
   "rotary_speaker",
   "ring_modulator",
   "granular_delay",
+  "pitch_shift",
+  "freeze",
 
   // Audio Modulation
   "lfo",